@@ -0,0 +1,409 @@
+//! Playback effects: per-sound and per-bus low-pass filtering, a per-bus
+//! reverb send, and the random pitch/volume variation applied per play.
+//!
+//! Every playing sound runs through a [`BusEffects`] source. Per-sound
+//! settings (the sound's own low-pass cutoff) are fixed when it starts;
+//! bus settings live in a shared [`BusParams`] read by the audio thread, so
+//! changing them affects sounds that are already playing — duck the music
+//! bus behind a low-pass when the player dives underwater, remove it when
+//! they surface.
+
+use std::f32::consts::TAU;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use rodio::source::SeekError;
+use rodio::Source;
+
+use crate::manager::{clamp_speed, clamp_volume};
+use crate::sound::SoundSettings;
+
+/// Lowest accepted low-pass cutoff, in Hz.
+pub(crate) const MIN_CUTOFF_HZ: u32 = 20;
+/// Widest random pitch variation, in semitones either way.
+pub(crate) const MAX_PITCH_VARIATION: f32 = 12.0;
+/// Reverb delay-line length.
+const REVERB_DELAY: Duration = Duration::from_millis(53);
+/// How much of the delayed signal is fed back into the delay line; sets how
+/// long the reverb tail rings.
+const REVERB_FEEDBACK: f32 = 0.5;
+/// Samples between re-reads of the bus parameters and filter coefficient.
+const PARAM_REFRESH: u32 = 512;
+
+/// An audio bus: every sound plays on one, and bus-level volume and effects
+/// apply to everything playing on it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AudioBus {
+    /// Sound effects (`play` / `play_with_settings`)
+    Sfx,
+    /// Background music (`play_music` and friends)
+    Music,
+}
+
+/// Live effect parameters of one bus, shared with the audio thread.
+#[derive(Debug, Default)]
+pub(crate) struct BusParams {
+    /// Low-pass cutoff in Hz; 0 means off
+    low_pass_hz: AtomicU32,
+    /// Reverb send level, stored as `f32` bits
+    reverb_send: AtomicU32,
+}
+
+impl BusParams {
+    pub(crate) fn low_pass(&self) -> Option<u32> {
+        match self.low_pass_hz.load(Ordering::Relaxed) {
+            0 => None,
+            hz => Some(hz),
+        }
+    }
+
+    pub(crate) fn set_low_pass(&self, cutoff_hz: Option<u32>) {
+        let hz = cutoff_hz.map_or(0, |hz| hz.max(MIN_CUTOFF_HZ));
+        self.low_pass_hz.store(hz, Ordering::Relaxed);
+    }
+
+    pub(crate) fn reverb_send(&self) -> f32 {
+        f32::from_bits(self.reverb_send.load(Ordering::Relaxed))
+    }
+
+    pub(crate) fn set_reverb_send(&self, send: f32) {
+        self.reverb_send.store(clamp_send(send).to_bits(), Ordering::Relaxed);
+    }
+}
+
+/// Clamp a reverb send level to 0.0..=1.0 (NaN counts as no send).
+pub(crate) fn clamp_send(send: f32) -> f32 {
+    if send.is_nan() {
+        0.0
+    } else {
+        send.clamp(0.0, 1.0)
+    }
+}
+
+/// The lower of two optional low-pass cutoffs — a sound filtered on a
+/// filtered bus hears whichever filter is darker.
+fn combined_cutoff(sound: Option<u32>, bus: Option<u32>) -> Option<u32> {
+    match (sound, bus) {
+        (Some(a), Some(b)) => Some(a.min(b)),
+        (a, b) => a.or(b),
+    }
+}
+
+/// One-pole low-pass smoothing factor for `cutoff_hz` at `sample_rate`.
+fn low_pass_coefficient(cutoff_hz: u32, sample_rate: u32) -> f32 {
+    let rate = sample_rate.max(1) as f32;
+    (1.0 - (-TAU * cutoff_hz as f32 / rate).exp()).clamp(0.0, 1.0)
+}
+
+/// Applies a sound's low-pass cutoff and its bus's low-pass and reverb send
+/// to a source.
+///
+/// The filter is a one-pole low-pass per channel (6 dB/octave) — gentle
+/// enough to sound like occlusion rather than a telephone. The reverb is a
+/// single feedback delay: cheap, and enough to place sounds in a room or a
+/// cave. The reverb tail is cut when the source ends.
+pub(crate) struct BusEffects<I> {
+    input: I,
+    bus: Arc<BusParams>,
+    sound_low_pass: Option<u32>,
+    /// Live muffling of the sound (occlusion); only its low-pass is used
+    muffle: Option<Arc<BusParams>>,
+    /// Filter coefficient; `None` while no low-pass applies
+    coefficient: Option<f32>,
+    send: f32,
+    /// Low-pass state per channel
+    filtered: Vec<f32>,
+    /// Interleaved reverb delay line, allocated on the first non-zero send
+    delay: Vec<f32>,
+    delay_pos: usize,
+    channel: usize,
+    until_refresh: u32,
+}
+
+impl<I> BusEffects<I>
+where
+    I: Source<Item = f32>,
+{
+    pub(crate) fn new(input: I, bus: Arc<BusParams>, sound_low_pass: Option<u32>) -> Self {
+        let mut effects = Self {
+            input,
+            bus,
+            sound_low_pass: sound_low_pass.map(|hz| hz.max(MIN_CUTOFF_HZ)),
+            muffle: None,
+            coefficient: None,
+            send: 0.0,
+            filtered: Vec::new(),
+            delay: Vec::new(),
+            delay_pos: 0,
+            channel: 0,
+            until_refresh: 0,
+        };
+        effects.refresh();
+        effects
+    }
+
+    /// Also follow `muffle`'s low-pass, which can change while playing.
+    pub(crate) fn with_muffle(mut self, muffle: Arc<BusParams>) -> Self {
+        self.muffle = Some(muffle);
+        self.refresh();
+        self
+    }
+
+    /// Re-read the bus parameters and follow channel-count changes.
+    fn refresh(&mut self) {
+        self.until_refresh = PARAM_REFRESH;
+        let channels = usize::from(self.input.channels().max(1));
+        let sample_rate = self.input.sample_rate();
+        let muffle = self.muffle.as_ref().and_then(|muffle| muffle.low_pass());
+        self.coefficient = combined_cutoff(combined_cutoff(self.sound_low_pass, self.bus.low_pass()), muffle)
+            .map(|hz| low_pass_coefficient(hz, sample_rate));
+        self.send = self.bus.reverb_send();
+
+        if self.filtered.len() != channels {
+            self.filtered = vec![0.0; channels];
+            self.channel = 0;
+            self.delay.clear();
+        }
+        if self.send > 0.0 && self.delay.is_empty() {
+            let frames = (REVERB_DELAY.as_secs_f32() * sample_rate as f32).max(1.0) as usize;
+            self.delay = vec![0.0; frames * channels];
+            self.delay_pos = 0;
+        }
+    }
+
+    fn process(&mut self, sample: f32) -> f32 {
+        let mut out = sample;
+        if let Some(a) = self.coefficient {
+            if let Some(state) = self.filtered.get_mut(self.channel) {
+                *state += a * (sample - *state);
+                out = *state;
+            }
+        }
+        self.channel = (self.channel + 1) % self.filtered.len().max(1);
+
+        // Keep the delay line running once allocated so turning the send
+        // down lets the tail fade instead of freezing it for later.
+        if let Some(slot) = self.delay.get_mut(self.delay_pos) {
+            let echo = *slot;
+            *slot = out + echo * REVERB_FEEDBACK;
+            out += echo * self.send;
+            self.delay_pos = (self.delay_pos + 1) % self.delay.len();
+        }
+        out.clamp(-1.0, 1.0)
+    }
+}
+
+impl<I> Iterator for BusEffects<I>
+where
+    I: Source<Item = f32>,
+{
+    type Item = f32;
+
+    fn next(&mut self) -> Option<f32> {
+        if self.until_refresh == 0 {
+            self.refresh();
+        }
+        self.until_refresh -= 1;
+        let sample = self.input.next()?;
+        Some(self.process(sample))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.input.size_hint()
+    }
+}
+
+impl<I> Source for BusEffects<I>
+where
+    I: Source<Item = f32>,
+{
+    fn current_frame_len(&self) -> Option<usize> {
+        self.input.current_frame_len()
+    }
+
+    fn channels(&self) -> u16 {
+        self.input.channels()
+    }
+
+    fn sample_rate(&self) -> u32 {
+        self.input.sample_rate()
+    }
+
+    fn total_duration(&self) -> Option<Duration> {
+        self.input.total_duration()
+    }
+
+    fn try_seek(&mut self, pos: Duration) -> Result<(), SeekError> {
+        self.input.try_seek(pos)?;
+        self.filtered.iter_mut().for_each(|state| *state = 0.0);
+        self.delay.iter_mut().for_each(|slot| *slot = 0.0);
+        self.channel = 0;
+        Ok(())
+    }
+}
+
+/// Small xorshift generator for per-play variation. Not for anything that
+/// needs real randomness; it only has to keep repeated footsteps from
+/// sounding identical.
+#[derive(Debug, Clone)]
+pub(crate) struct VariationRng {
+    state: u64,
+}
+
+impl VariationRng {
+    pub(crate) fn new(seed: u64) -> Self {
+        // xorshift never leaves the all-zero state
+        Self { state: seed.max(1) }
+    }
+
+    /// Seed from the clock, so each run varies differently.
+    pub(crate) fn from_time() -> Self {
+        let nanos = common::time::SystemTime::now()
+            .duration_since(common::time::UNIX_EPOCH)
+            .map_or(0x9E37_79B9_7F4A_7C15, |elapsed| elapsed.as_nanos() as u64);
+        Self::new(nanos)
+    }
+
+    /// Uniform value in `-1.0..=1.0`.
+    fn next_signed(&mut self) -> f32 {
+        let mut x = self.state;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.state = x;
+        // Top 24 bits give an exact f32 in 0..1
+        ((x >> 40) as f32 / (1u64 << 24) as f32) * 2.0 - 1.0
+    }
+
+    /// Uniform value in `-amount..=amount`.
+    pub(crate) fn spread(&mut self, amount: f32) -> f32 {
+        if amount > 0.0 {
+            self.next_signed() * amount
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Resolve one play's `(volume, playback rate)` from `settings`, rolling
+/// the random pitch and volume variation. Everything is clamped here, since
+/// `SoundSettings` fields are public.
+pub(crate) fn varied_playback(settings: &SoundSettings, rng: &mut VariationRng) -> (f32, f32) {
+    let volume_spread = rng.spread(settings.volume_variation.clamp(0.0, 1.0));
+    let volume = clamp_volume(settings.volume * (1.0 + volume_spread));
+    let semitones = rng.spread(settings.pitch_variation.clamp(0.0, MAX_PITCH_VARIATION));
+    let pitch = settings.pitch.max(0.1) * semitones_to_ratio(semitones);
+    (volume, clamp_speed(settings.speed * pitch))
+}
+
+/// Playback-rate multiplier for a pitch shift of `semitones`.
+pub(crate) fn semitones_to_ratio(semitones: f32) -> f32 {
+    2f32.powf(semitones / 12.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rodio::buffer::SamplesBuffer;
+
+    fn square_wave(len: usize) -> Vec<f32> {
+        (0..len).map(|i| if i % 2 == 0 { 0.5 } else { -0.5 }).collect()
+    }
+
+    fn run(bus: &Arc<BusParams>, sound_low_pass: Option<u32>, samples: Vec<f32>) -> Vec<f32> {
+        let source = SamplesBuffer::new(1, 44_100, samples);
+        BusEffects::new(source, Arc::clone(bus), sound_low_pass).collect()
+    }
+
+    #[test]
+    fn test_no_effects_passes_samples_through() {
+        let bus = Arc::new(BusParams::default());
+        let input = square_wave(64);
+        assert_eq!(run(&bus, None, input.clone()), input);
+    }
+
+    #[test]
+    fn test_low_pass_attenuates_high_frequencies() {
+        let bus = Arc::new(BusParams::default());
+        // A Nyquist-rate square wave is all high frequency
+        let output = run(&bus, Some(200), square_wave(2048));
+        let peak = output[1024..].iter().fold(0.0f32, |peak, s| peak.max(s.abs()));
+        assert!(peak < 0.05, "peak after low-pass was {peak}");
+
+        // The bus filter alone does the same, and the darker cutoff wins
+        bus.set_low_pass(Some(200));
+        let bus_only = run(&bus, None, square_wave(2048));
+        assert_eq!(bus_only, output);
+        // ...as does a live muffle on an unfiltered bus
+        let muffle = Arc::new(BusParams::default());
+        muffle.set_low_pass(Some(200));
+        let source = SamplesBuffer::new(1, 44_100, square_wave(2048));
+        let muffled: Vec<f32> = BusEffects::new(source, Arc::default(), None).with_muffle(muffle).collect();
+        assert_eq!(muffled, output);
+        assert_eq!(combined_cutoff(Some(8000), Some(500)), Some(500));
+        assert_eq!(combined_cutoff(None, Some(500)), Some(500));
+    }
+
+    #[test]
+    fn test_reverb_send_adds_delayed_echo() {
+        let bus = Arc::new(BusParams::default());
+        bus.set_reverb_send(0.5);
+        let mut impulse = vec![0.0; 44_100 / 5];
+        impulse[0] = 1.0;
+        let output = run(&bus, None, impulse);
+
+        let delay = (REVERB_DELAY.as_secs_f32() * 44_100.0) as usize;
+        assert_eq!(output[0], 1.0);
+        assert!(output[1..delay].iter().all(|s| *s == 0.0));
+        assert!((output[delay] - 0.5).abs() < 1e-6, "first echo at send level");
+        assert!(output[delay * 2] > 0.0 && output[delay * 2] < output[delay], "echoes decay");
+    }
+
+    #[test]
+    fn test_bus_params_clamp() {
+        let bus = BusParams::default();
+        assert_eq!(bus.low_pass(), None);
+        bus.set_low_pass(Some(1));
+        assert_eq!(bus.low_pass(), Some(MIN_CUTOFF_HZ));
+        bus.set_low_pass(None);
+        assert_eq!(bus.low_pass(), None);
+        bus.set_reverb_send(3.0);
+        assert_eq!(bus.reverb_send(), 1.0);
+        bus.set_reverb_send(f32::NAN);
+        assert_eq!(bus.reverb_send(), 0.0);
+    }
+
+    #[test]
+    fn test_variation_rng_stays_in_range_and_is_seeded() {
+        let mut a = VariationRng::new(7);
+        let mut b = VariationRng::new(7);
+        for _ in 0..1000 {
+            let value = a.spread(2.0);
+            assert!((-2.0..=2.0).contains(&value));
+            assert_eq!(value, b.spread(2.0));
+        }
+        assert_eq!(a.spread(0.0), 0.0);
+        assert!((semitones_to_ratio(12.0) - 2.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_varied_playback_stays_within_variation() {
+        let settings = SoundSettings::new()
+            .with_volume(0.5)
+            .with_semitones(12.0)
+            .with_pitch_variation(2.0)
+            .with_volume_variation(0.2);
+        let mut rng = VariationRng::new(42);
+        let (low, high) = (semitones_to_ratio(10.0) - 1e-4, semitones_to_ratio(14.0) + 1e-4);
+        for _ in 0..200 {
+            let (volume, rate) = varied_playback(&settings, &mut rng);
+            assert!((0.399..=0.601).contains(&volume), "volume {volume}");
+            assert!((low..=high).contains(&rate), "rate {rate}");
+        }
+
+        // Without variation the settings come through exactly
+        let plain = SoundSettings::new().with_speed(1.5).with_pitch(0.5);
+        assert_eq!(varied_playback(&plain, &mut rng), (1.0, 0.75));
+    }
+}
@@ -1,7 +1,9 @@
 //! Audio system for the insiculous_2d game engine.
 //!
 //! This crate provides audio playback functionality including:
-//! - Sound effect playback with volume and speed control
+//! - Sound effect playback with volume, pitch and speed control, plus
+//!   random per-play pitch/volume variation
+//! - Low-pass filtering per sound and per bus, and a reverb send per bus
 //! - Background music playback (looping or one-shot)
 //! - Audio resource management and caching
 //!
@@ -39,10 +41,12 @@
 //! # }
 //! ```
 
+mod effects;
 mod error;
 mod manager;
 mod sound;
 
+pub use effects::AudioBus;
 pub use error::{AudioError, AudioResult};
 pub use manager::AudioManager;
 pub use sound::{SoundHandle, SoundSettings};
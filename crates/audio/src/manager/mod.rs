@@ -8,16 +8,21 @@ use std::sync::Arc;
 
 use rodio::{Decoder, OutputStream, OutputStreamHandle, Sink, Source};
 
+use crate::effects::{varied_playback, AudioBus, BusEffects, BusParams, VariationRng};
 use crate::error::{AudioError, AudioResult};
 use crate::sound::{SoundHandle, SoundSettings};
 
+mod occlusion;
+
+use occlusion::SoundMuffle;
+
 /// Clamp a volume value to the valid 0.0..=1.0 range.
-fn clamp_volume(volume: f32) -> f32 {
+pub(crate) fn clamp_volume(volume: f32) -> f32 {
     volume.clamp(0.0, 1.0)
 }
 
 /// Floor a playback speed at 0.1 (rodio misbehaves at zero/negative speeds).
-fn clamp_speed(speed: f32) -> f32 {
+pub(crate) fn clamp_speed(speed: f32) -> f32 {
     speed.max(0.1)
 }
 
@@ -38,7 +43,8 @@ struct ActiveSound {
     base_volume: f32,
 }
 
-/// Live connection to an audio output device.
+/// Live connection to an audio output device (on the web, a Web Audio
+/// context).
 struct AudioOutput {
     /// Audio output stream (must be kept alive).
     _stream: OutputStream,
@@ -78,6 +84,14 @@ pub struct AudioManager {
     sfx_volume: f32,
     /// Volume for background music.
     music_volume: f32,
+    /// Live effect parameters of the SFX bus, shared with playing sounds.
+    sfx_bus: Arc<BusParams>,
+    /// Live effect parameters of the music bus.
+    music_bus: Arc<BusParams>,
+    /// Source of the per-play pitch/volume variation.
+    variation: VariationRng,
+    /// Per-sound muffling set with `set_muffle`, by sound id.
+    muffles: HashMap<u32, SoundMuffle>,
 }
 
 impl AudioManager {
@@ -85,15 +99,29 @@ impl AudioManager {
     ///
     /// This initializes the audio device and output stream.
     pub fn new() -> AudioResult<Self> {
-        let (stream, stream_handle) = OutputStream::try_default()
-            .map_err(|e| AudioError::DeviceInitError(e.to_string()))?;
-
+        let output = Self::open_output()?;
         log::debug!("Audio system initialized");
+        Ok(Self::with_output(Some(output)))
+    }
 
-        Ok(Self::with_output(Some(AudioOutput {
-            _stream: stream,
-            handle: stream_handle,
-        })))
+    /// Connect a disabled manager to the output device, keeping loaded
+    /// sounds and volume settings. No-op when already enabled.
+    ///
+    /// Browsers only let audio start from a user gesture, so web builds
+    /// start disabled and the engine calls this on the first key press,
+    /// click or touch. Sounds played before then stay silent.
+    pub fn enable(&mut self) -> AudioResult<()> {
+        if self.output.is_none() {
+            self.output = Some(Self::open_output()?);
+            log::debug!("Audio output enabled");
+        }
+        Ok(())
+    }
+
+    fn open_output() -> AudioResult<AudioOutput> {
+        let (stream, stream_handle) = OutputStream::try_default()
+            .map_err(|e| AudioError::DeviceInitError(e.to_string()))?;
+        Ok(AudioOutput { _stream: stream, handle: stream_handle })
     }
 
     /// Create a disabled audio manager that has no output device.
@@ -133,6 +161,10 @@ impl AudioManager {
             master_volume: 1.0,
             sfx_volume: 1.0,
             music_volume: 1.0,
+            sfx_bus: Arc::default(),
+            music_bus: Arc::default(),
+            variation: VariationRng::from_time(),
+            muffles: HashMap::new(),
         }
     }
 
@@ -189,10 +221,12 @@ impl AudioManager {
         self.play_with_settings(&handle, SoundSettings::default())
     }
 
-    /// Play a sound with custom settings.
+    /// Play a sound with custom settings on the SFX bus.
     ///
-    /// Volume is clamped to 0.0..=1.0 and speed floored at 0.1 here, so
-    /// directly-set `SoundSettings` fields cannot bypass the valid ranges.
+    /// Pitch and volume variation are rolled per call. Volume is clamped to
+    /// 0.0..=1.0 and the playback rate (`speed * pitch`) floored at 0.1
+    /// here, so directly-set `SoundSettings` fields cannot bypass the valid
+    /// ranges.
     pub fn play_with_settings(
         &mut self,
         handle: &SoundHandle,
@@ -212,18 +246,20 @@ impl AudioManager {
         // Decode straight from the shared cached bytes — no buffer copy.
         let cursor = Cursor::new(Arc::clone(&sound_data.bytes));
         let source = Decoder::new(cursor)
-            .map_err(|e| AudioError::DecodeError(e.to_string()))?;
+            .map_err(|e| AudioError::DecodeError(e.to_string()))?
+            .convert_samples::<f32>();
 
-        // Clamp at point of use: SoundSettings fields are public, so builder
-        // clamps can be bypassed.
-        let base_volume = clamp_volume(settings.volume);
-        sink.set_volume(base_volume * self.sfx_volume * self.master_volume);
-        sink.set_speed(clamp_speed(settings.speed));
+        let (base_volume, rate) = varied_playback(&settings, &mut self.variation);
+        let muffle = self.muffles.entry(handle.id).or_default();
+        sink.set_volume(base_volume * muffle.volume * self.sfx_volume * self.master_volume);
+        sink.set_speed(rate);
 
+        let bus = Arc::clone(&self.sfx_bus);
+        let muffle = Arc::clone(&muffle.params);
         if settings.looping {
-            sink.append(source.repeat_infinite());
+            sink.append(BusEffects::new(source.repeat_infinite(), bus, settings.low_pass).with_muffle(muffle));
         } else {
-            sink.append(source);
+            sink.append(BusEffects::new(source, bus, settings.low_pass).with_muffle(muffle));
         }
 
         self.active_sounds.push(ActiveSound {
@@ -280,7 +316,8 @@ impl AudioManager {
         let file = File::open(path)?;
 
         let source = Decoder::new(BufReader::new(file))
-            .map_err(|e| AudioError::DecodeError(format!("{}: {}", path.display(), e)))?;
+            .map_err(|e| AudioError::DecodeError(format!("{}: {}", path.display(), e)))?
+            .convert_samples::<f32>();
 
         // Disabled mode: file was validated above, playback is a no-op.
         let Some(output) = &self.output else {
@@ -292,10 +329,11 @@ impl AudioManager {
 
         let base_volume = clamp_volume(volume);
         sink.set_volume(base_volume * self.music_volume * self.master_volume);
+        let bus = Arc::clone(&self.music_bus);
         if looping {
-            sink.append(source.repeat_infinite());
+            sink.append(BusEffects::new(source.repeat_infinite(), bus, None));
         } else {
-            sink.append(source);
+            sink.append(BusEffects::new(source, bus, None));
         }
 
         self.music_sink = Some(sink);
@@ -395,6 +433,50 @@ impl AudioManager {
         self.music_volume
     }
 
+    fn bus_params(&self, bus: AudioBus) -> &BusParams {
+        match bus {
+            AudioBus::Sfx => self.sfx_bus.as_ref(),
+            AudioBus::Music => self.music_bus.as_ref(),
+        }
+    }
+
+    /// Low-pass filter everything on `bus` at `cutoff_hz` (floored at
+    /// 20 Hz); `None` removes the filter. Applies to sounds already playing.
+    pub fn set_bus_low_pass(&mut self, bus: AudioBus, cutoff_hz: Option<u32>) {
+        self.bus_params(bus).set_low_pass(cutoff_hz);
+    }
+
+    /// Get the low-pass cutoff of `bus`, if filtered.
+    #[must_use]
+    pub fn bus_low_pass(&self, bus: AudioBus) -> Option<u32> {
+        self.bus_params(bus).low_pass()
+    }
+
+    /// Set how much of `bus` is sent to its reverb (0.0 = dry, clamped to
+    /// 0.0..=1.0). Applies to sounds already playing.
+    pub fn set_bus_reverb_send(&mut self, bus: AudioBus, send: f32) {
+        self.bus_params(bus).set_reverb_send(send);
+    }
+
+    /// Get the reverb send level of `bus`.
+    #[must_use]
+    pub fn bus_reverb_send(&self, bus: AudioBus) -> f32 {
+        self.bus_params(bus).reverb_send()
+    }
+
+    /// The handle of loaded sound `id` (e.g. an `AudioSource::sound_id`),
+    /// or `None` if no such sound is loaded.
+    #[must_use]
+    pub fn handle(&self, id: u32) -> Option<SoundHandle> {
+        self.sounds.contains_key(&id).then(|| SoundHandle::from_id(id))
+    }
+
+    /// Reseed the per-play pitch/volume variation, for reproducible runs
+    /// (replays, tests). Seeded from the clock by default.
+    pub fn set_variation_seed(&mut self, seed: u64) {
+        self.variation = VariationRng::new(seed);
+    }
+
     /// Re-derive sink volumes for the music track and every live SFX
     /// instance from `base * bus * master`.
     fn update_all_volumes(&mut self) {
@@ -402,9 +484,10 @@ impl AudioManager {
             sink.set_volume(self.music_base_volume * self.music_volume * self.master_volume);
         }
         for active in &self.active_sounds {
+            let muffle = self.muffle_volume(active.handle.id);
             active
                 .sink
-                .set_volume(active.base_volume * self.sfx_volume * self.master_volume);
+                .set_volume(active.base_volume * muffle * self.sfx_volume * self.master_volume);
         }
     }
 
@@ -429,6 +512,7 @@ impl AudioManager {
     /// `play` calls with this handle will fail.
     pub fn unload(&mut self, handle: SoundHandle) {
         self.sounds.remove(&handle.id);
+        self.muffles.remove(&handle.id);
     }
 
     /// Unload all cached sounds.
@@ -436,256 +520,9 @@ impl AudioManager {
     /// As with [`AudioManager::unload`], already-playing instances continue.
     pub fn unload_all(&mut self) {
         self.sounds.clear();
+        self.muffles.clear();
     }
 }
 
 #[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_sound_settings_builder() {
-        let settings = SoundSettings::new()
-            .with_volume(0.5)
-            .with_speed(1.5)
-            .with_looping(true);
-
-        assert!((settings.volume - 0.5).abs() < f32::EPSILON);
-        assert!((settings.speed - 1.5).abs() < f32::EPSILON);
-        assert!(settings.looping);
-    }
-
-    #[test]
-    fn test_sound_settings_volume_clamping() {
-        let settings = SoundSettings::new().with_volume(2.0);
-        assert!((settings.volume - 1.0).abs() < f32::EPSILON);
-
-        let settings = SoundSettings::new().with_volume(-1.0);
-        assert!(settings.volume.abs() < f32::EPSILON);
-    }
-
-    #[test]
-    fn test_sound_settings_speed_floored_at_point_one() {
-        let settings = SoundSettings::new().with_speed(0.01);
-        assert!((settings.speed - 0.1).abs() < f32::EPSILON);
-
-        let settings = SoundSettings::new().with_speed(-3.0);
-        assert!((settings.speed - 0.1).abs() < f32::EPSILON);
-    }
-
-    #[test]
-    fn test_clamp_helpers_enforce_valid_ranges() {
-        assert!((clamp_volume(2.0) - 1.0).abs() < f32::EPSILON);
-        assert!(clamp_volume(-0.5).abs() < f32::EPSILON);
-        assert!((clamp_volume(0.7) - 0.7).abs() < f32::EPSILON);
-
-        assert!((clamp_speed(0.0) - 0.1).abs() < f32::EPSILON);
-        assert!((clamp_speed(-1.0) - 0.1).abs() < f32::EPSILON);
-        assert!((clamp_speed(2.5) - 2.5).abs() < f32::EPSILON);
-    }
-
-    #[test]
-    fn test_sound_ids_are_manager_local_and_deterministic() {
-        // Ids come from an instance-local counter, so two independent managers
-        // hand out the same first id (no process-global drift across managers).
-        let mut first = AudioManager::disabled();
-        let mut second = AudioManager::disabled();
-
-        let a = first.load_sound_from_bytes(tiny_wav()).unwrap();
-        let b = second.load_sound_from_bytes(tiny_wav()).unwrap();
-        assert_eq!(a.id(), b.id(), "fresh managers must start from the same id");
-
-        // Ids still climb within a single manager.
-        let a2 = first.load_sound_from_bytes(tiny_wav()).unwrap();
-        assert_ne!(a.id(), a2.id(), "ids within one manager must be unique");
-    }
-
-    /// Minimal valid WAV file (44-byte header + one silent 16-bit sample).
-    fn tiny_wav() -> Vec<u8> {
-        let mut bytes = Vec::new();
-        bytes.extend_from_slice(b"RIFF");
-        bytes.extend_from_slice(&38u32.to_le_bytes()); // chunk size
-        bytes.extend_from_slice(b"WAVEfmt ");
-        bytes.extend_from_slice(&16u32.to_le_bytes()); // fmt chunk size
-        bytes.extend_from_slice(&1u16.to_le_bytes()); // PCM
-        bytes.extend_from_slice(&1u16.to_le_bytes()); // mono
-        bytes.extend_from_slice(&44100u32.to_le_bytes()); // sample rate
-        bytes.extend_from_slice(&88200u32.to_le_bytes()); // byte rate
-        bytes.extend_from_slice(&2u16.to_le_bytes()); // block align
-        bytes.extend_from_slice(&16u16.to_le_bytes()); // bits per sample
-        bytes.extend_from_slice(b"data");
-        bytes.extend_from_slice(&2u32.to_le_bytes()); // data size
-        bytes.extend_from_slice(&0i16.to_le_bytes()); // one silent sample
-        bytes
-    }
-
-    /// Write `tiny_wav` to a unique temp file and return its path.
-    fn write_temp_wav(tag: &str) -> std::path::PathBuf {
-        let path = std::env::temp_dir().join(format!(
-            "insiculous_audio_test_{}_{}.wav",
-            tag,
-            std::process::id()
-        ));
-        std::fs::write(&path, tiny_wav()).expect("temp dir must be writable");
-        path
-    }
-
-    #[test]
-    fn test_disabled_manager_reports_not_enabled() {
-        let manager = AudioManager::disabled();
-        assert!(!manager.is_enabled());
-    }
-
-    #[test]
-    fn test_disabled_manager_loads_and_plays_as_noop() {
-        let mut manager = AudioManager::disabled();
-        let handle = manager.load_sound_from_bytes(tiny_wav()).unwrap();
-        assert!(manager.play(handle).is_ok());
-        assert_eq!(manager.active_sound_count(), 0, "no-op playback must not track sinks");
-    }
-
-    #[test]
-    fn test_disabled_manager_still_rejects_invalid_handles() {
-        let mut manager = AudioManager::disabled();
-        let bogus = SoundHandle::from_id(9999);
-        assert!(manager.play(bogus).is_err());
-    }
-
-    #[test]
-    fn test_disabled_manager_music_controls_are_safe() {
-        let mut manager = AudioManager::disabled();
-        manager.stop_music();
-        manager.pause_music();
-        manager.resume_music();
-        assert!(!manager.is_music_playing());
-        manager.update();
-    }
-
-    #[test]
-    fn test_new_or_disabled_never_fails() {
-        // With or without an audio device, construction must succeed.
-        let _manager = AudioManager::new_or_disabled();
-    }
-
-    #[test]
-    fn test_load_sound_from_file_succeeds() {
-        let path = write_temp_wav("load_ok");
-        let mut manager = AudioManager::disabled();
-        let result = manager.load_sound(&path);
-        std::fs::remove_file(&path).ok();
-
-        let handle = result.expect("valid wav file must load");
-        assert!(manager.play(handle).is_ok());
-    }
-
-    #[test]
-    fn test_load_sound_missing_file_returns_io_error() {
-        let mut manager = AudioManager::disabled();
-        let missing = std::env::temp_dir().join("insiculous_audio_test_definitely_missing.wav");
-        let err = manager.load_sound(&missing).expect_err("missing file must fail");
-        assert!(
-            matches!(err, AudioError::IoError(_)),
-            "expected IoError, got: {err:?}"
-        );
-    }
-
-    #[test]
-    fn test_load_sound_from_invalid_bytes_returns_decode_error() {
-        let mut manager = AudioManager::disabled();
-        let err = manager
-            .load_sound_from_bytes(vec![0xDE, 0xAD, 0xBE, 0xEF])
-            .expect_err("garbage bytes must fail to decode");
-        assert!(
-            matches!(err, AudioError::DecodeError(_)),
-            "expected DecodeError, got: {err:?}"
-        );
-    }
-
-    #[test]
-    fn test_unloaded_sound_can_no_longer_be_played() {
-        let mut manager = AudioManager::disabled();
-        let handle = manager.load_sound_from_bytes(tiny_wav()).unwrap();
-        assert!(manager.play(handle).is_ok());
-
-        manager.unload(handle);
-        let err = manager.play(handle).expect_err("unloaded handle must be rejected");
-        assert!(matches!(err, AudioError::InvalidHandle(_)));
-    }
-
-    #[test]
-    fn test_unload_all_invalidates_every_handle() {
-        let mut manager = AudioManager::disabled();
-        let first = manager.load_sound_from_bytes(tiny_wav()).unwrap();
-        let second = manager.load_sound_from_bytes(tiny_wav()).unwrap();
-
-        manager.unload_all();
-
-        assert!(manager.play(first).is_err());
-        assert!(manager.play(second).is_err());
-    }
-
-    #[test]
-    fn test_stop_on_unknown_handle_is_noop() {
-        let mut manager = AudioManager::disabled();
-        let bogus = SoundHandle::from_id(9999);
-        manager.stop(bogus);
-        assert_eq!(manager.active_sound_count(), 0);
-    }
-
-    #[test]
-    fn test_stop_and_stop_all_are_safe_when_nothing_plays() {
-        let mut manager = AudioManager::disabled();
-        let handle = manager.load_sound_from_bytes(tiny_wav()).unwrap();
-        manager.play(handle).unwrap();
-
-        manager.stop(handle);
-        manager.stop_all();
-        assert_eq!(manager.active_sound_count(), 0);
-    }
-
-    #[test]
-    fn test_volume_setters_clamp_out_of_range_values() {
-        let mut manager = AudioManager::disabled();
-
-        manager.set_master_volume(2.0);
-        assert!((manager.master_volume() - 1.0).abs() < f32::EPSILON);
-        manager.set_master_volume(-1.0);
-        assert!(manager.master_volume().abs() < f32::EPSILON);
-
-        manager.set_sfx_volume(5.0);
-        assert!((manager.sfx_volume() - 1.0).abs() < f32::EPSILON);
-        manager.set_sfx_volume(-0.2);
-        assert!(manager.sfx_volume().abs() < f32::EPSILON);
-
-        manager.set_music_volume(1.5);
-        assert!((manager.music_volume() - 1.0).abs() < f32::EPSILON);
-        manager.set_music_volume(-0.5);
-        assert!(manager.music_volume().abs() < f32::EPSILON);
-    }
-
-    #[test]
-    fn test_disabled_manager_music_loads_but_reports_not_playing() {
-        let path = write_temp_wav("music_once");
-        let mut manager = AudioManager::disabled();
-
-        let looping = manager.play_music(&path);
-        let once = manager.play_music_once(&path, 0.5);
-        std::fs::remove_file(&path).ok();
-
-        assert!(looping.is_ok());
-        assert!(once.is_ok());
-        // Documented behavior: disabled mode validates the file but never
-        // reports music as playing.
-        assert!(!manager.is_music_playing());
-    }
-
-    #[test]
-    fn test_play_music_missing_file_returns_io_error() {
-        let mut manager = AudioManager::disabled();
-        let missing = std::env::temp_dir().join("insiculous_audio_test_no_such_music.ogg");
-        let err = manager
-            .play_music_once(&missing, 1.0)
-            .expect_err("missing music file must fail");
-        assert!(matches!(err, AudioError::IoError(_)));
-    }
-}
+mod tests;
@@ -0,0 +1,80 @@
+//! Per-sound muffling: the volume and low-pass state behind
+//! `set_muffle`, which engine_core's audio occlusion drives.
+
+use std::sync::Arc;
+
+use crate::effects::BusParams;
+use crate::sound::SoundHandle;
+
+use super::{clamp_volume, AudioManager};
+
+/// Live muffling of one loaded sound (e.g. occlusion behind a wall),
+/// applied to every instance of it, including ones already playing.
+pub(super) struct SoundMuffle {
+    /// Volume multiplier, 0.0..=1.0
+    pub(super) volume: f32,
+    /// Low-pass cutoff, shared with the playing instances' effects
+    pub(super) params: Arc<BusParams>,
+}
+
+impl Default for SoundMuffle {
+    fn default() -> Self {
+        Self { volume: 1.0, params: Arc::default() }
+    }
+}
+
+impl AudioManager {
+    /// Muffle every instance of `handle` — playing now or started later —
+    /// with a volume multiplier (clamped to 0.0..=1.0) and an optional
+    /// low-pass cutoff (floored at 20 Hz). Used for occlusion: a sound
+    /// behind a wall plays quieter and duller. `set_muffle(handle, 1.0, None)`
+    /// restores it.
+    pub fn set_muffle(&mut self, handle: SoundHandle, volume: f32, low_pass: Option<u32>) {
+        let muffle = self.muffles.entry(handle.id).or_default();
+        muffle.volume = clamp_volume(volume);
+        muffle.params.set_low_pass(low_pass);
+        let volume = muffle.volume * self.sfx_volume * self.master_volume;
+        for active in self.active_sounds.iter().filter(|active| active.handle == handle) {
+            active.sink.set_volume(active.base_volume * volume);
+        }
+    }
+
+    /// The volume multiplier and low-pass cutoff set with
+    /// [`set_muffle`](Self::set_muffle) (`(1.0, None)` when unmuffled).
+    #[must_use]
+    pub fn muffle(&self, handle: SoundHandle) -> (f32, Option<u32>) {
+        self.muffles
+            .get(&handle.id)
+            .map_or((1.0, None), |muffle| (muffle.volume, muffle.params.low_pass()))
+    }
+
+    /// The muffle volume multiplier of sound `id` (1.0 when unmuffled).
+    pub(super) fn muffle_volume(&self, id: u32) -> f32 {
+        self.muffles.get(&id).map_or(1.0, |muffle| muffle.volume)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::manager::tests::tiny_wav;
+
+    #[test]
+    fn test_muffle_is_per_sound_and_clamped() {
+        let mut manager = AudioManager::disabled();
+        let wall = manager.load_sound_from_bytes(tiny_wav()).unwrap();
+        let open = manager.load_sound_from_bytes(tiny_wav()).unwrap();
+        assert_eq!(manager.handle(wall.id()), Some(wall));
+        assert_eq!(manager.handle(99), None);
+
+        manager.set_muffle(wall, 1.5, Some(5));
+        assert_eq!(manager.muffle(wall), (1.0, Some(20)), "volume clamped, cutoff floored");
+        manager.set_muffle(wall, 0.4, Some(800));
+        assert_eq!(manager.muffle(wall), (0.4, Some(800)));
+        assert_eq!(manager.muffle(open), (1.0, None));
+        manager.play(wall).unwrap();
+
+        manager.unload(wall);
+        assert_eq!(manager.muffle(wall), (1.0, None));
+    }
+}
@@ -1,5 +1,7 @@
 //! Sound data types and playback settings.
 
+use crate::effects::{semitones_to_ratio, MAX_PITCH_VARIATION, MIN_CUTOFF_HZ};
+
 /// Unique identifier for a loaded sound.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct SoundHandle {
@@ -30,6 +32,20 @@ pub struct SoundSettings {
     pub volume: f32,
     /// Playback speed (1.0 = normal, 2.0 = double speed).
     pub speed: f32,
+    /// Pitch multiplier (1.0 = unchanged, 2.0 = an octave up). Pitch is
+    /// shifted by resampling, like a tape, so it multiplies `speed`: a
+    /// higher pitch also plays faster.
+    pub pitch: f32,
+    /// Random pitch offset per play, in semitones either way (0.0..=12.0).
+    /// A semitone or two keeps repeated footsteps and hits from sounding
+    /// mechanical.
+    pub pitch_variation: f32,
+    /// Random volume offset per play, as a fraction of `volume` either way
+    /// (0.0..=1.0).
+    pub volume_variation: f32,
+    /// Low-pass cutoff in Hz (`None` = unfiltered). Muffles the sound, for
+    /// occlusion or underwater; stacks with the bus low-pass.
+    pub low_pass: Option<u32>,
     /// Whether the sound should loop.
     pub looping: bool,
 }
@@ -39,6 +55,10 @@ impl Default for SoundSettings {
         Self {
             volume: 1.0,
             speed: 1.0,
+            pitch: 1.0,
+            pitch_variation: 0.0,
+            volume_variation: 0.0,
+            low_pass: None,
             looping: false,
         }
     }
@@ -71,6 +91,42 @@ impl SoundSettings {
         self
     }
 
+    /// Set the pitch multiplier (floored at 0.1).
+    #[must_use]
+    pub fn with_pitch(mut self, pitch: f32) -> Self {
+        self.pitch = pitch.max(0.1);
+        self
+    }
+
+    /// Set the pitch as a shift in semitones (12 = an octave up).
+    #[must_use]
+    pub fn with_semitones(self, semitones: f32) -> Self {
+        self.with_pitch(semitones_to_ratio(semitones))
+    }
+
+    /// Set the random per-play pitch variation, in semitones either way
+    /// (clamped to 0.0..=12.0).
+    #[must_use]
+    pub fn with_pitch_variation(mut self, semitones: f32) -> Self {
+        self.pitch_variation = semitones.clamp(0.0, MAX_PITCH_VARIATION);
+        self
+    }
+
+    /// Set the random per-play volume variation, as a fraction of the volume
+    /// either way (clamped to 0.0..=1.0).
+    #[must_use]
+    pub fn with_volume_variation(mut self, fraction: f32) -> Self {
+        self.volume_variation = fraction.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Low-pass filter the sound at `cutoff_hz` (floored at 20 Hz).
+    #[must_use]
+    pub fn with_low_pass(mut self, cutoff_hz: u32) -> Self {
+        self.low_pass = Some(cutoff_hz.max(MIN_CUTOFF_HZ));
+        self
+    }
+
     /// Set whether the sound should loop.
     #[must_use]
     pub fn with_looping(mut self, looping: bool) -> Self {
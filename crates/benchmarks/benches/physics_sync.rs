@@ -0,0 +1,46 @@
+//! ECS ↔ rapier synchronization against body count: creating every body
+//! on the first update, then the steady-state step and write-back.
+
+use benchmarks::{physics_world, sample_size, ENTITY_COUNTS};
+use criterion::{criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion, Throughput};
+use ecs::System;
+use physics::PhysicsSystem;
+
+const DELTA_TIME: f32 = 1.0 / 60.0;
+
+/// First update of a fresh system: every entity becomes a rapier body.
+fn benchmark_register(c: &mut Criterion) {
+    let mut group = c.benchmark_group("physics_sync/register");
+    for count in ENTITY_COUNTS {
+        group.sample_size(sample_size(count)).throughput(Throughput::Elements(count as u64));
+        group.bench_function(BenchmarkId::from_parameter(count), |b| {
+            b.iter_batched(
+                || (physics_world(count), PhysicsSystem::new()),
+                |(mut world, mut physics)| {
+                    physics.update(&mut world, DELTA_TIME);
+                    (world, physics)
+                },
+                BatchSize::LargeInput,
+            );
+        });
+    }
+    group.finish();
+}
+
+/// Later updates: step the bodies and copy their transforms back.
+fn benchmark_step(c: &mut Criterion) {
+    let mut group = c.benchmark_group("physics_sync/step");
+    for count in ENTITY_COUNTS {
+        let mut world = physics_world(count);
+        let mut physics = PhysicsSystem::new();
+        physics.update(&mut world, DELTA_TIME);
+        group.sample_size(sample_size(count)).throughput(Throughput::Elements(count as u64));
+        group.bench_function(BenchmarkId::from_parameter(count), |b| {
+            b.iter(|| physics.update(&mut world, DELTA_TIME));
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, benchmark_register, benchmark_step);
+criterion_main!(benches);
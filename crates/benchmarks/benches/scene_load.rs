@@ -0,0 +1,41 @@
+//! Scene loading against entity count: RON parsing, and instantiating the
+//! parsed scene into a fresh world.
+
+use benchmarks::{sample_size, scene_ron, WhiteTextures, ENTITY_COUNTS};
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion, Throughput};
+use ecs::World;
+use engine_core::SceneLoader;
+
+fn benchmark_parse(c: &mut Criterion) {
+    let mut group = c.benchmark_group("scene_load/parse");
+    for count in ENTITY_COUNTS {
+        let ron = scene_ron(count);
+        group.sample_size(sample_size(count)).throughput(Throughput::Bytes(ron.len() as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(count), &ron, |b, ron| {
+            b.iter(|| SceneLoader::parse(black_box(ron)).unwrap());
+        });
+    }
+    group.finish();
+}
+
+fn benchmark_instantiate(c: &mut Criterion) {
+    let mut group = c.benchmark_group("scene_load/instantiate");
+    for count in ENTITY_COUNTS {
+        let scene = SceneLoader::parse(&scene_ron(count)).unwrap();
+        group.sample_size(sample_size(count)).throughput(Throughput::Elements(count as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(count), &scene, |b, scene| {
+            b.iter_batched(
+                World::new,
+                |mut world| {
+                    SceneLoader::instantiate(scene, &mut world, &mut WhiteTextures).unwrap();
+                    world
+                },
+                BatchSize::LargeInput,
+            );
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, benchmark_parse, benchmark_instantiate);
+criterion_main!(benches);
@@ -0,0 +1,47 @@
+//! Built-in sprite extraction (ECS `Sprite` → batched render sprites)
+//! against world size, without culling so every sprite is built.
+
+use std::collections::HashSet;
+
+use benchmarks::{sample_size, sprite_world, ENTITY_COUNTS};
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use engine_core::extraction::{ExtractContext, SpriteExtractors};
+use engine_core::particles::ParticleManager;
+use engine_core::texture_import::TextureSizes;
+use glam::Vec2;
+use renderer::{Camera, SpriteBatcher, SpriteLimits};
+
+fn benchmark_extract(c: &mut Criterion) {
+    let extractors = SpriteExtractors::new();
+    let texture_sizes = TextureSizes::new();
+    let particles = ParticleManager::default();
+    let hidden = HashSet::new();
+    let camera = Camera::new(Vec2::ZERO, Vec2::new(800.0, 600.0));
+    // No soft limit: 100k sprites is the point, not a warning
+    let mut sprites = SpriteBatcher::with_limits(SpriteLimits::soft(usize::MAX));
+
+    let mut group = c.benchmark_group("sprite_extraction/extract");
+    for count in ENTITY_COUNTS {
+        let world = sprite_world(count);
+        let ctx = ExtractContext {
+            world: &world,
+            texture_sizes: &texture_sizes,
+            particles: &particles,
+            hidden: &hidden,
+            camera: &camera,
+            view: None,
+        };
+        group.sample_size(sample_size(count)).throughput(Throughput::Elements(count as u64));
+        group.bench_function(BenchmarkId::from_parameter(count), |b| {
+            b.iter(|| {
+                sprites.clear();
+                extractors.extract(&ctx, &mut sprites);
+                black_box(sprites.sprite_count())
+            });
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, benchmark_extract);
+criterion_main!(benches);
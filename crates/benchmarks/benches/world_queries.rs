@@ -0,0 +1,48 @@
+//! ECS query cost against world size: the per-frame lookups every system
+//! and extractor makes.
+
+use benchmarks::{sample_size, sprite_world, ENTITY_COUNTS};
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use ecs::sprite_components::{Sprite, Transform2D};
+use ecs::Pair;
+
+/// `query_entities` for a two-component match, then a typed read of each hit.
+fn benchmark_query_and_get(c: &mut Criterion) {
+    let mut group = c.benchmark_group("world_queries/query_and_get");
+    for count in ENTITY_COUNTS {
+        let world = sprite_world(count);
+        group.sample_size(sample_size(count)).throughput(Throughput::Elements(count as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(count), &world, |b, world| {
+            b.iter(|| {
+                let mut sum = 0.0;
+                for entity in world.query_entities::<Pair<Transform2D, Sprite>>() {
+                    if let Some(transform) = world.get::<Transform2D>(entity) {
+                        sum += transform.position.x;
+                    }
+                }
+                black_box(sum)
+            });
+        });
+    }
+    group.finish();
+}
+
+/// Mutable iteration over one component store, like a movement system.
+fn benchmark_iter_mut(c: &mut Criterion) {
+    let mut group = c.benchmark_group("world_queries/iter_mut");
+    for count in ENTITY_COUNTS {
+        let mut world = sprite_world(count);
+        group.sample_size(sample_size(count)).throughput(Throughput::Elements(count as u64));
+        group.bench_function(BenchmarkId::from_parameter(count), |b| {
+            b.iter(|| {
+                for (_, transform) in world.iter_mut::<Transform2D>() {
+                    transform.position.x += 1.0;
+                }
+            });
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, benchmark_query_and_get, benchmark_iter_mut);
+criterion_main!(benches);
@@ -0,0 +1,110 @@
+//! Shared fixtures for the entity-count stress benchmarks.
+//!
+//! Every bench runs at each of [`ENTITY_COUNTS`] so a change's cost can be
+//! read against world size. The worlds built here are deterministic: the
+//! same count always lays out the same entities, so runs compare cleanly
+//! across commits.
+//!
+//! ```bash
+//! cargo bench -p benchmarks                     # all four suites
+//! cargo bench -p benchmarks --bench scene_load  # one suite
+//! ```
+
+use ecs::sprite_components::{Sprite, Transform2D};
+use ecs::World;
+use engine_core::scene_data::SceneLoadError;
+use engine_core::scene_migration::SCENE_FORMAT_VERSION;
+use engine_core::TextureResolver;
+use glam::{Vec2, Vec4};
+use physics::{Collider, RigidBody};
+use renderer::TextureHandle;
+
+/// World sizes every bench is measured at.
+pub const ENTITY_COUNTS: [usize; 3] = [1_000, 10_000, 100_000];
+
+/// Distance between neighbouring entities, in world pixels. Wide enough
+/// that the physics boxes never touch.
+pub const SPACING: f32 = 24.0;
+
+/// Side of the physics boxes, in world pixels.
+const BOX_SIZE: f32 = 16.0;
+
+/// Criterion samples per bench: the default 100 for small worlds, fewer
+/// once a single iteration takes long enough that 100 would stall the run.
+pub fn sample_size(count: usize) -> usize {
+    if count >= 100_000 { 10 } else if count >= 10_000 { 20 } else { 100 }
+}
+
+/// Position of entity `index` of `count`: row-major on a square grid
+/// centered on the origin.
+pub fn grid_position(index: usize, count: usize) -> Vec2 {
+    let side = (count as f32).sqrt().ceil().max(1.0) as usize;
+    let (col, row) = (index % side, index / side);
+    let half = (side as f32 - 1.0) / 2.0;
+    Vec2::new(col as f32 - half, row as f32 - half) * SPACING
+}
+
+/// A tint that varies per entity so sprites don't all look alike.
+fn tint(index: usize) -> Vec4 {
+    let hue = (index as f32 * 0.618).fract();
+    Vec4::new(hue, 1.0 - hue, 0.5, 1.0)
+}
+
+/// `count` white-quad sprites on the grid.
+pub fn sprite_world(count: usize) -> World {
+    let mut world = World::new();
+    for index in 0..count {
+        world
+            .spawn((
+                Transform2D::new(grid_position(index, count)),
+                Sprite::new(TextureHandle::WHITE.id).with_color(tint(index)),
+            ))
+            .id();
+    }
+    world
+}
+
+/// `count` dynamic boxes on the grid, none touching, so a physics update
+/// measures syncing bodies rather than resolving contacts.
+pub fn physics_world(count: usize) -> World {
+    let mut world = World::new();
+    for index in 0..count {
+        world
+            .spawn((
+                Transform2D::new(grid_position(index, count)),
+                RigidBody::new_dynamic(),
+                Collider::box_collider(BOX_SIZE, BOX_SIZE),
+            ))
+            .id();
+    }
+    world
+}
+
+/// RON for a scene of `count` named sprite entities on the grid, in the
+/// current scene format (no migration on load).
+pub fn scene_ron(count: usize) -> String {
+    let mut ron = format!("SceneData(\n    format_version: {SCENE_FORMAT_VERSION},\n    name: \"stress_{count}\",\n    entities: [\n");
+    for index in 0..count {
+        let position = grid_position(index, count);
+        let color = tint(index);
+        ron.push_str(&format!(
+            "        EntityData(name: Some(\"e{index}\"), components: [\
+             Transform2D(position: ({:.1}, {:.1})), \
+             Sprite(texture: \"#white\", color: ({:.3}, {:.3}, {:.3}, 1.0))]),\n",
+            position.x, position.y, color.x, color.y, color.z,
+        ));
+    }
+    ron.push_str("    ],\n)\n");
+    ron
+}
+
+/// Resolves every texture reference to the white texture, so scene loads
+/// measure parsing and instantiation without an asset manager or GPU.
+#[derive(Debug, Default)]
+pub struct WhiteTextures;
+
+impl TextureResolver for WhiteTextures {
+    fn resolve_texture(&mut self, _texture_ref: &str) -> Result<TextureHandle, SceneLoadError> {
+        Ok(TextureHandle::WHITE)
+    }
+}
@@ -97,6 +97,13 @@ impl Camera {
         view
     }
 
+    /// The view matrix without the camera translation: zoom and rotation
+    /// about the camera. Multiply it with positions already made relative to
+    /// the camera (see [`CameraUniform::camera_relative`]).
+    pub fn relative_view_matrix(&self) -> Mat4 {
+        Mat4::from_scale(Vec3::new(self.zoom, self.zoom, 1.0)) * Mat4::from_rotation_z(self.rotation)
+    }
+
     /// Build the orthographic projection matrix.
     pub fn projection_matrix(&self) -> Mat4 {
         let half_width = self.viewport_size.x * 0.5;
@@ -179,6 +186,7 @@ impl Camera {
 /// Camera uniform data for GPU upload.
 ///
 /// This struct is designed to be directly uploadable to GPU buffers.
+/// Shaders place a world point `p` at `view_projection * (p - origin)`.
 #[repr(C)]
 #[derive(Debug, Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
 pub struct CameraUniform {
@@ -186,8 +194,9 @@ pub struct CameraUniform {
     pub view_projection: [[f32; 4]; 4],
     /// Camera position for shader calculations
     pub position: [f32; 2],
-    /// Padding to align to 16 bytes
-    pub _padding: [f32; 2],
+    /// World point subtracted from every vertex before `view_projection`
+    /// (zero unless camera-relative)
+    pub origin: [f32; 2],
 }
 
 impl CameraUniform {
@@ -196,9 +205,28 @@ impl CameraUniform {
         Self {
             view_projection: camera.view_projection_matrix().to_cols_array_2d(),
             position: camera.position.to_array(),
-            _padding: [0.0, 0.0],
+            origin: [0.0, 0.0],
+        }
+    }
+
+    /// Camera-relative uniform: vertices are offset by the camera position
+    /// before the (translation-free) view-projection. The subtraction of two
+    /// nearby large coordinates is exact in f32, so sprites far from the
+    /// world origin don't wobble the way they do when a large translation
+    /// is folded into the matrix.
+    pub fn camera_relative(camera: &Camera) -> Self {
+        Self {
+            view_projection: (camera.projection_matrix() * camera.relative_view_matrix()).to_cols_array_2d(),
+            position: camera.position.to_array(),
+            origin: camera.position.to_array(),
         }
     }
+
+    /// Clip-space position of world point `world`, as the shaders compute it.
+    pub fn clip_position(&self, world: Vec2) -> Vec4 {
+        let relative = world - Vec2::from_array(self.origin);
+        Mat4::from_cols_array_2d(&self.view_projection) * Vec4::new(relative.x, relative.y, 0.0, 1.0)
+    }
 }
 
 #[cfg(test)]
@@ -251,4 +279,22 @@ mod tests {
         let uniform = CameraUniform::from_camera(&camera);
         assert_eq!(uniform.position, [50.0, 100.0]);
     }
+
+    #[test]
+    fn test_camera_relative_uniform_matches_absolute_and_stays_precise_far_out() {
+        let camera = Camera::new(Vec2::new(50.0, 100.0), Vec2::new(800.0, 600.0)).with_zoom(2.0).with_rotation(0.3);
+        let point = Vec2::new(120.0, 80.0);
+        let absolute = CameraUniform::from_camera(&camera).clip_position(point);
+        let relative = CameraUniform::camera_relative(&camera).clip_position(point);
+        assert!((absolute - relative).abs().max_element() < 1e-5);
+
+        // A quarter pixel right of a camera a million units out
+        let far = Camera::new(Vec2::new(1_000_000.0, -1_000_000.0), Vec2::new(800.0, 600.0));
+        let point = far.position + Vec2::new(0.25, 0.0);
+        let expected = 0.25 / 400.0;
+        let relative = CameraUniform::camera_relative(&far).clip_position(point);
+        assert_eq!(relative.x, expected);
+        let absolute = CameraUniform::from_camera(&far).clip_position(point);
+        assert_ne!(absolute.x, expected, "the absolute path rounds the offset away");
+    }
 }
@@ -1,11 +1,17 @@
 //! Unified color type for the engine.
+//!
+//! Colors are authored in sRGB — the values a color picker or hex code
+//! gives — like the texels of a texture. The renderer decodes both to
+//! linear light before blending; [`srgb_to_linear`] and
+//! [`linear_to_srgb`] are the transfer functions it uses.
 
 use glam::Vec4;
 use serde::{Deserialize, Serialize};
 
 /// RGBA color representation.
 ///
-/// All components are in the range 0.0 to 1.0.
+/// All components are in the range 0.0 to 1.0. RGB is sRGB-encoded; alpha
+/// is linear coverage.
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct Color {
     /// Red component (0.0 - 1.0)
@@ -144,6 +150,30 @@ impl Color {
         [self.r, self.g, self.b, self.a]
     }
 
+    /// Decode the sRGB channels to linear light (alpha unchanged), for
+    /// math that has to happen in linear space such as physically correct
+    /// blending.
+    #[inline]
+    pub fn to_linear(self) -> Self {
+        Self {
+            r: srgb_to_linear(self.r),
+            g: srgb_to_linear(self.g),
+            b: srgb_to_linear(self.b),
+            a: self.a,
+        }
+    }
+
+    /// Encode linear-light channels back to sRGB (alpha unchanged).
+    #[inline]
+    pub fn from_linear(linear: Color) -> Self {
+        Self {
+            r: linear_to_srgb(linear.r),
+            g: linear_to_srgb(linear.g),
+            b: linear_to_srgb(linear.b),
+            a: linear.a,
+        }
+    }
+
     /// Convert to 8-bit RGBA array.
     #[inline]
     pub fn to_rgba8(self) -> [u8; 4] {
@@ -156,6 +186,27 @@ impl Color {
     }
 }
 
+/// The sRGB electro-optical transfer function: an encoded channel (0..1)
+/// to linear light.
+#[inline]
+pub fn srgb_to_linear(encoded: f32) -> f32 {
+    if encoded <= 0.04045 {
+        encoded / 12.92
+    } else {
+        ((encoded + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Inverse of [`srgb_to_linear`]: linear light (0..1) to an sRGB channel.
+#[inline]
+pub fn linear_to_srgb(linear: f32) -> f32 {
+    if linear <= 0.0031308 {
+        linear * 12.92
+    } else {
+        1.055 * linear.powf(1.0 / 2.4) - 0.055
+    }
+}
+
 // Conversions to/from glam types
 impl From<Color> for Vec4 {
     #[inline]
@@ -218,6 +269,23 @@ mod tests {
         assert!((mid.r - 0.5).abs() < 0.01);
     }
 
+    #[test]
+    fn test_srgb_linear_round_trip() {
+        assert_eq!(srgb_to_linear(0.0), 0.0);
+        assert!((srgb_to_linear(1.0) - 1.0).abs() < 1e-6);
+        // Mid-grey in sRGB is about a fifth of the light of white
+        assert!((srgb_to_linear(0.5) - 0.214).abs() < 0.001);
+        for i in 0..=255 {
+            let encoded = i as f32 / 255.0;
+            assert!((linear_to_srgb(srgb_to_linear(encoded)) - encoded).abs() < 1e-5);
+        }
+
+        let color = Color::new(0.5, 1.0, 0.0, 0.5).to_linear();
+        assert!((color.r - 0.214).abs() < 0.001);
+        assert_eq!(color.a, 0.5, "alpha is not gamma encoded");
+        assert!((Color::from_linear(color).r - 0.5).abs() < 1e-5);
+    }
+
     #[test]
     fn test_color_conversions() {
         let color = Color::new(0.1, 0.2, 0.3, 0.4);
@@ -24,7 +24,7 @@ pub mod prelude {
 }
 
 // Re-export at crate root for convenience
-pub use color::Color;
+pub use color::{linear_to_srgb, srgb_to_linear, Color};
 pub use hash::{hash_f32, hash_u32};
 pub use transform::Transform2D;
 pub use camera::Camera;
@@ -1,7 +1,15 @@
 //! Time resource for tracking game timing.
 //!
 //! This module provides a simple time resource for tracking delta time
-//! and elapsed time in games.
+//! and elapsed time in games, plus the wall clocks engine code should use
+//! instead of `std::time`: [`Instant`] and [`SystemTime`] panic on
+//! `wasm32-unknown-unknown`, so there they come from the browser clock
+//! (same API).
+
+#[cfg(not(target_arch = "wasm32"))]
+pub use std::time::{Instant, SystemTime, UNIX_EPOCH};
+#[cfg(target_arch = "wasm32")]
+pub use web_time::{Instant, SystemTime, UNIX_EPOCH};
 
 /// Time resource for tracking delta time and elapsed time.
 ///
@@ -2,6 +2,7 @@
 
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 use ecs::prelude::*;
+use ecs::Pair;
 
 #[derive(Debug, Clone)]
 struct Position {
@@ -114,10 +115,71 @@ fn benchmark_component_operations(c: &mut Criterion) {
     group.finish();
 }
 
+/// Entities in the scalability benchmarks
+const LARGE_WORLD: usize = 50_000;
+
+/// World of `LARGE_WORLD` entities: all with Position + Velocity, every third
+/// with Health.
+fn large_world() -> World {
+    let mut world = World::new();
+    for i in 0..LARGE_WORLD {
+        let entity = world.create_entity();
+        world.add_component(&entity, Position { x: i as f32, y: i as f32 }).unwrap();
+        world.add_component(&entity, Velocity { dx: 1.0, dy: 1.0 }).unwrap();
+        if i % 3 == 0 {
+            world.add_component(&entity, Health { current: 100, max: 100 }).unwrap();
+        }
+    }
+    world
+}
+
+/// Benchmark query iteration over tens of thousands of entities
+fn benchmark_large_world(c: &mut Criterion) {
+    let mut group = c.benchmark_group("large_world");
+    let mut world = large_world();
+
+    group.bench_function("query_pair_then_get", |b| {
+        b.iter(|| {
+            let mut sum = 0.0;
+            for entity in world.query_entities::<Pair<Position, Velocity>>() {
+                if let Some(pos) = world.get::<Position>(entity) {
+                    sum += pos.x;
+                }
+            }
+            black_box(sum);
+        });
+    });
+
+    group.bench_function("query_sparse_component", |b| {
+        b.iter(|| black_box(world.query_entities::<Pair<Health, Position>>().len()));
+    });
+
+    group.bench_function("integrate_positions", |b| {
+        b.iter(|| {
+            for entity in world.query_entities::<Pair<Position, Velocity>>() {
+                let Some((dx, dy)) = world.get::<Velocity>(entity).map(|v| (v.dx, v.dy)) else {
+                    continue;
+                };
+                if let Some(pos) = world.get_mut::<Position>(entity) {
+                    pos.x += dx;
+                    pos.y += dy;
+                }
+            }
+        });
+    });
+
+    group.bench_function("iter_single", |b| {
+        b.iter(|| black_box(world.iter::<Position>().map(|(_, pos)| pos.x).sum::<f32>()));
+    });
+
+    group.finish();
+}
+
 criterion_group!(
     benches,
     benchmark_ecs,
     benchmark_entity_creation,
-    benchmark_component_operations
+    benchmark_component_operations,
+    benchmark_large_world
 );
 criterion_main!(benches);
@@ -0,0 +1,595 @@
+//! Keyframe animation of component fields.
+//!
+//! An [`AnimationClip`] holds [`AnimationTrack`]s, each an
+//! [`AnimationCurve`] of keyframes driving one [`AnimationTarget`] — a
+//! component field such as `position.x`, `color.a`, or `zoom`. The
+//! [`Animator`] component plays a clip (once, looping, or ping-pong) and
+//! [`AnimatorSystem`] advances every animator and writes the sampled values
+//! into the entity's `Transform2D`, `Sprite`, and `Camera`.
+//!
+//! Clips are plain serde data, so they load from RON asset files and ride
+//! along in scenes inside the `Animator` component:
+//!
+//! ```
+//! use ecs::animation::{AnimationClip, AnimationCurve, AnimationTarget, Animator, Keyframe, PlaybackMode};
+//!
+//! let bob = AnimationClip::new("bob").with_track(
+//!     AnimationTarget::PositionY,
+//!     AnimationCurve::new(vec![Keyframe::linear(0.0, 0.0), Keyframe::ease_in_out(0.5, 8.0), Keyframe::linear(1.0, 0.0)]),
+//! );
+//! assert_eq!(bob.duration(), 1.0);
+//! assert_eq!(bob.tracks[0].curve.sample(0.25), Some(4.0));
+//! let animator = Animator::new(bob).with_mode(PlaybackMode::PingPong);
+//! assert!(animator.playing);
+//! ```
+
+use std::str::FromStr;
+
+use ecs_macros::ComponentMeta as DeriveComponentMeta;
+use serde::{Deserialize, Serialize};
+
+use crate::component_registry::ComponentMeta;
+use crate::entity::EntityId;
+use crate::query::Single;
+use crate::sprite_components::{Camera, Sprite, Transform2D};
+use crate::system::System;
+use crate::world::World;
+
+/// How a keyframe's value blends into the next keyframe's.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum Interpolation {
+    /// Hold the value until the next keyframe
+    Step,
+    /// Straight line
+    #[default]
+    Linear,
+    /// Start slow, end fast (quadratic)
+    EaseIn,
+    /// Start fast, end slow (quadratic)
+    EaseOut,
+    /// Slow at both ends (smoothstep)
+    EaseInOut,
+}
+
+impl Interpolation {
+    /// Eased progress for linear progress `t` in `[0, 1]`.
+    pub fn ease(self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Interpolation::Step => 0.0,
+            Interpolation::Linear => t,
+            Interpolation::EaseIn => t * t,
+            Interpolation::EaseOut => t * (2.0 - t),
+            Interpolation::EaseInOut => t * t * (3.0 - 2.0 * t),
+        }
+    }
+}
+
+/// One keyframe: `value` at `time` seconds, blending towards the next
+/// keyframe with `interpolation`.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Keyframe {
+    /// Time in seconds from the start of the clip
+    pub time: f32,
+    /// Field value at this time
+    pub value: f32,
+    /// Blend from this keyframe to the next
+    #[serde(default)]
+    pub interpolation: Interpolation,
+}
+
+impl Keyframe {
+    /// Keyframe blending linearly into the next.
+    pub fn linear(time: f32, value: f32) -> Self {
+        Self { time, value, interpolation: Interpolation::Linear }
+    }
+
+    /// Keyframe holding its value until the next.
+    pub fn step(time: f32, value: f32) -> Self {
+        Self { time, value, interpolation: Interpolation::Step }
+    }
+
+    /// Keyframe easing in and out of the next.
+    pub fn ease_in_out(time: f32, value: f32) -> Self {
+        Self { time, value, interpolation: Interpolation::EaseInOut }
+    }
+
+    /// Set the interpolation towards the next keyframe.
+    pub fn with_interpolation(mut self, interpolation: Interpolation) -> Self {
+        self.interpolation = interpolation;
+        self
+    }
+}
+
+/// Keyframes sorted by time. Before the first keyframe the curve holds the
+/// first value, after the last it holds the last.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[serde(from = "Vec<Keyframe>", into = "Vec<Keyframe>")]
+pub struct AnimationCurve {
+    keyframes: Vec<Keyframe>,
+}
+
+impl From<Vec<Keyframe>> for AnimationCurve {
+    fn from(keyframes: Vec<Keyframe>) -> Self {
+        Self::new(keyframes)
+    }
+}
+
+impl From<AnimationCurve> for Vec<Keyframe> {
+    fn from(curve: AnimationCurve) -> Self {
+        curve.keyframes
+    }
+}
+
+impl AnimationCurve {
+    /// Curve through `keyframes` (sorted by time; non-finite times dropped).
+    pub fn new(mut keyframes: Vec<Keyframe>) -> Self {
+        keyframes.retain(|key| key.time.is_finite());
+        keyframes.sort_by(|a, b| a.time.total_cmp(&b.time));
+        Self { keyframes }
+    }
+
+    /// Add a keyframe, keeping the curve sorted (builder-style).
+    pub fn with_key(mut self, keyframe: Keyframe) -> Self {
+        self.insert(keyframe);
+        self
+    }
+
+    /// Add a keyframe, replacing one at exactly the same time.
+    pub fn insert(&mut self, keyframe: Keyframe) {
+        if !keyframe.time.is_finite() {
+            return;
+        }
+        match self.keyframes.binary_search_by(|key| key.time.total_cmp(&keyframe.time)) {
+            Ok(index) => self.keyframes[index] = keyframe,
+            Err(index) => self.keyframes.insert(index, keyframe),
+        }
+    }
+
+    /// The keyframes, sorted by time.
+    pub fn keyframes(&self) -> &[Keyframe] {
+        &self.keyframes
+    }
+
+    /// Time of the last keyframe (0 for an empty curve).
+    pub fn duration(&self) -> f32 {
+        self.keyframes.last().map_or(0.0, |key| key.time.max(0.0))
+    }
+
+    /// Value at `time`, or `None` for an empty curve.
+    pub fn sample(&self, time: f32) -> Option<f32> {
+        let first = self.keyframes.first()?;
+        let next_index = self.keyframes.partition_point(|key| key.time <= time);
+        if next_index == 0 {
+            return Some(first.value);
+        }
+        let key = self.keyframes[next_index - 1];
+        let Some(next) = self.keyframes.get(next_index) else {
+            return Some(key.value);
+        };
+        let span = next.time - key.time;
+        let t = if span > 0.0 { (time - key.time) / span } else { 1.0 };
+        Some(key.value + (next.value - key.value) * key.interpolation.ease(t))
+    }
+}
+
+/// A component field an [`AnimationTrack`] drives.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum AnimationTarget {
+    /// `Transform2D.position.x`
+    PositionX,
+    /// `Transform2D.position.y`
+    PositionY,
+    /// `Transform2D.rotation` (radians)
+    Rotation,
+    /// `Transform2D.scale.x`
+    ScaleX,
+    /// `Transform2D.scale.y`
+    ScaleY,
+    /// `Sprite.color.x` (red)
+    ColorR,
+    /// `Sprite.color.y` (green)
+    ColorG,
+    /// `Sprite.color.z` (blue)
+    ColorB,
+    /// `Sprite.color.w` (alpha)
+    ColorA,
+    /// `Sprite.emissive`
+    Emissive,
+    /// `Camera.zoom`
+    Zoom,
+}
+
+impl AnimationTarget {
+    /// Every target, in declaration order.
+    pub const ALL: [AnimationTarget; 11] = [
+        Self::PositionX, Self::PositionY, Self::Rotation, Self::ScaleX, Self::ScaleY,
+        Self::ColorR, Self::ColorG, Self::ColorB, Self::ColorA, Self::Emissive, Self::Zoom,
+    ];
+
+    /// Field path, e.g. `"position.x"` or `"color.a"`.
+    pub fn path(self) -> &'static str {
+        match self {
+            Self::PositionX => "position.x",
+            Self::PositionY => "position.y",
+            Self::Rotation => "rotation",
+            Self::ScaleX => "scale.x",
+            Self::ScaleY => "scale.y",
+            Self::ColorR => "color.r",
+            Self::ColorG => "color.g",
+            Self::ColorB => "color.b",
+            Self::ColorA => "color.a",
+            Self::Emissive => "emissive",
+            Self::Zoom => "zoom",
+        }
+    }
+
+    /// Current value of the field on `entity`, if it has the component.
+    pub fn read(self, world: &World, entity: EntityId) -> Option<f32> {
+        match self {
+            Self::PositionX => world.get::<Transform2D>(entity).map(|t| t.position.x),
+            Self::PositionY => world.get::<Transform2D>(entity).map(|t| t.position.y),
+            Self::Rotation => world.get::<Transform2D>(entity).map(|t| t.rotation),
+            Self::ScaleX => world.get::<Transform2D>(entity).map(|t| t.scale.x),
+            Self::ScaleY => world.get::<Transform2D>(entity).map(|t| t.scale.y),
+            Self::ColorR => world.get::<Sprite>(entity).map(|s| s.color.x),
+            Self::ColorG => world.get::<Sprite>(entity).map(|s| s.color.y),
+            Self::ColorB => world.get::<Sprite>(entity).map(|s| s.color.z),
+            Self::ColorA => world.get::<Sprite>(entity).map(|s| s.color.w),
+            Self::Emissive => world.get::<Sprite>(entity).map(|s| s.emissive),
+            Self::Zoom => world.get::<Camera>(entity).map(|c| c.zoom),
+        }
+    }
+
+    /// Write `value` into the field on `entity`. Returns `false` when the
+    /// entity lacks the target component.
+    pub fn write(self, world: &mut World, entity: EntityId, value: f32) -> bool {
+        match self {
+            Self::PositionX | Self::PositionY | Self::Rotation | Self::ScaleX | Self::ScaleY => {
+                let Some(transform) = world.get_mut::<Transform2D>(entity) else { return false };
+                match self {
+                    Self::PositionX => transform.position.x = value,
+                    Self::PositionY => transform.position.y = value,
+                    Self::Rotation => transform.rotation = value,
+                    Self::ScaleX => transform.scale.x = value,
+                    _ => transform.scale.y = value,
+                }
+            }
+            Self::ColorR | Self::ColorG | Self::ColorB | Self::ColorA | Self::Emissive => {
+                let Some(sprite) = world.get_mut::<Sprite>(entity) else { return false };
+                match self {
+                    Self::ColorR => sprite.color.x = value,
+                    Self::ColorG => sprite.color.y = value,
+                    Self::ColorB => sprite.color.z = value,
+                    Self::ColorA => sprite.color.w = value,
+                    _ => sprite.emissive = value,
+                }
+            }
+            Self::Zoom => {
+                let Some(camera) = world.get_mut::<Camera>(entity) else { return false };
+                camera.zoom = value;
+            }
+        }
+        true
+    }
+}
+
+impl FromStr for AnimationTarget {
+    type Err = String;
+
+    /// Parse a field path (`"position.x"`, `"color.a"`, `"zoom"`, ...).
+    fn from_str(path: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|target| target.path() == path)
+            .ok_or_else(|| format!("unknown animation target '{path}'"))
+    }
+}
+
+/// A curve driving one target field.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AnimationTrack {
+    /// Field the curve writes
+    pub target: AnimationTarget,
+    /// Keyframes over time
+    pub curve: AnimationCurve,
+}
+
+/// A named set of tracks played together. Its duration is the latest
+/// keyframe across all tracks.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct AnimationClip {
+    /// Clip name (for lookups and the editor)
+    pub name: String,
+    /// Tracks, applied in order (a later track on the same target wins)
+    pub tracks: Vec<AnimationTrack>,
+}
+
+impl AnimationClip {
+    /// Empty clip called `name`.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self { name: name.into(), tracks: Vec::new() }
+    }
+
+    /// Add a track (builder-style).
+    pub fn with_track(mut self, target: AnimationTarget, curve: AnimationCurve) -> Self {
+        self.tracks.push(AnimationTrack { target, curve });
+        self
+    }
+
+    /// Length of the clip in seconds.
+    pub fn duration(&self) -> f32 {
+        self.tracks.iter().map(|track| track.curve.duration()).fold(0.0, f32::max)
+    }
+
+    /// Every track's value at `time`.
+    pub fn sample(&self, time: f32) -> Vec<(AnimationTarget, f32)> {
+        self.tracks
+            .iter()
+            .filter_map(|track| track.curve.sample(time).map(|value| (track.target, value)))
+            .collect()
+    }
+}
+
+/// What an [`Animator`] does when it reaches the end of its clip.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum PlaybackMode {
+    /// Stop on the last frame
+    Once,
+    /// Jump back to the start
+    #[default]
+    Loop,
+    /// Play backwards to the start, then forwards again
+    PingPong,
+}
+
+/// Component: plays an [`AnimationClip`] on its entity.
+#[derive(Debug, Clone, Serialize, Deserialize, DeriveComponentMeta)]
+pub struct Animator {
+    /// The clip being played
+    pub clip: AnimationClip,
+    /// End-of-clip behavior
+    #[serde(default)]
+    pub mode: PlaybackMode,
+    /// Playback rate (1.0 = normal speed)
+    #[serde(default = "default_speed")]
+    pub speed: f32,
+    /// Whether the clip is advancing
+    #[serde(default = "default_playing")]
+    pub playing: bool,
+    /// Playhead in seconds
+    #[serde(default)]
+    pub time: f32,
+    /// Playing backwards (the return leg of a ping-pong)
+    #[serde(default)]
+    pub reversed: bool,
+}
+
+fn default_speed() -> f32 { 1.0 }
+fn default_playing() -> bool { true }
+
+impl Default for Animator {
+    fn default() -> Self {
+        Self::new(AnimationClip::default())
+    }
+}
+
+impl Animator {
+    /// Animator playing `clip` from the start, looping.
+    pub fn new(clip: AnimationClip) -> Self {
+        Self { clip, mode: PlaybackMode::Loop, speed: 1.0, playing: true, time: 0.0, reversed: false }
+    }
+
+    /// Set the end-of-clip behavior.
+    pub fn with_mode(mut self, mode: PlaybackMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Set the playback rate.
+    pub fn with_speed(mut self, speed: f32) -> Self {
+        self.speed = speed;
+        self
+    }
+
+    /// Resume playback.
+    pub fn play(&mut self) {
+        self.playing = true;
+    }
+
+    /// Pause playback, keeping the playhead.
+    pub fn pause(&mut self) {
+        self.playing = false;
+    }
+
+    /// Rewind to the start and play.
+    pub fn restart(&mut self) {
+        self.time = 0.0;
+        self.reversed = false;
+        self.playing = true;
+    }
+
+    /// Swap in a new clip and restart.
+    pub fn set_clip(&mut self, clip: AnimationClip) {
+        self.clip = clip;
+        self.restart();
+    }
+
+    /// Whether a [`PlaybackMode::Once`] clip has played to its end.
+    pub fn is_finished(&self) -> bool {
+        self.mode == PlaybackMode::Once && !self.playing && self.time >= self.clip.duration()
+    }
+
+    /// Advance the playhead by `delta_time` seconds (scaled by `speed`).
+    pub fn advance(&mut self, delta_time: f32) {
+        let duration = self.clip.duration();
+        if !self.playing || duration <= 0.0 {
+            return;
+        }
+        let step = delta_time * self.speed;
+        match self.mode {
+            PlaybackMode::Once => {
+                self.time = (self.time + step).clamp(0.0, duration);
+                if self.time >= duration {
+                    self.playing = false;
+                }
+            }
+            PlaybackMode::Loop => self.time = (self.time + step).rem_euclid(duration),
+            PlaybackMode::PingPong => {
+                // Unfold onto a 0..2*duration cycle, then fold back
+                let phase = if self.reversed { 2.0 * duration - self.time } else { self.time };
+                let phase = (phase + step).rem_euclid(2.0 * duration);
+                self.reversed = phase > duration;
+                self.time = if self.reversed { 2.0 * duration - phase } else { phase };
+            }
+        }
+    }
+}
+
+/// System: advances every [`Animator`] and writes its clip's sampled
+/// values into the entity's components. Add it to the world's system
+/// registry or own one and call `update` from the game loop.
+#[derive(Debug, Default)]
+pub struct AnimatorSystem;
+
+impl AnimatorSystem {
+    /// Create a new animator system.
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl System for AnimatorSystem {
+    fn update(&mut self, world: &mut World, delta_time: f32) {
+        for entity in world.query_active::<Single<Animator>>() {
+            // Sample first: the clip lives in the Animator, the targets in
+            // other components on the same entity.
+            let values = match world.get_mut::<Animator>(entity) {
+                Some(animator) if animator.playing => {
+                    animator.advance(delta_time);
+                    animator.clip.sample(animator.time)
+                }
+                _ => continue,
+            };
+            for (target, value) in values {
+                target.write(world, entity, value);
+            }
+        }
+    }
+
+    fn name(&self) -> &str {
+        "AnimatorSystem"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use glam::Vec2;
+
+    fn approx(a: f32, b: f32) -> bool {
+        (a - b).abs() < 1e-4
+    }
+
+    #[test]
+    fn test_curve_interpolates_and_holds_its_ends() {
+        let curve = AnimationCurve::new(vec![Keyframe::linear(1.0, 10.0), Keyframe::step(0.0, 0.0), Keyframe::linear(2.0, 20.0)]);
+        assert_eq!(curve.duration(), 2.0);
+        assert_eq!(curve.sample(-1.0), Some(0.0), "before the first key");
+        assert_eq!(curve.sample(0.5), Some(0.0), "step holds");
+        assert_eq!(curve.sample(1.0), Some(10.0));
+        assert_eq!(curve.sample(1.5), Some(15.0), "linear");
+        assert_eq!(curve.sample(9.0), Some(20.0), "after the last key");
+        assert_eq!(AnimationCurve::default().sample(0.0), None);
+
+        let eased = AnimationCurve::new(vec![Keyframe::ease_in_out(0.0, 0.0), Keyframe::linear(1.0, 1.0)]);
+        assert!(eased.sample(0.25).is_some_and(|v| v < 0.25), "slow start");
+        assert_eq!(eased.sample(0.5), Some(0.5));
+    }
+
+    #[test]
+    fn test_insert_replaces_key_at_same_time() {
+        let mut curve = AnimationCurve::new(vec![Keyframe::linear(0.0, 0.0), Keyframe::linear(1.0, 1.0)]);
+        curve.insert(Keyframe::linear(0.5, 4.0));
+        curve.insert(Keyframe::linear(1.0, 2.0));
+        let times: Vec<f32> = curve.keyframes().iter().map(|key| key.time).collect();
+        assert_eq!(times, vec![0.0, 0.5, 1.0]);
+        assert_eq!(curve.sample(1.0), Some(2.0));
+    }
+
+    #[test]
+    fn test_target_paths_round_trip() {
+        for target in AnimationTarget::ALL {
+            assert_eq!(target.path().parse::<AnimationTarget>(), Ok(target));
+        }
+        assert!("position.z".parse::<AnimationTarget>().is_err());
+    }
+
+    #[test]
+    fn test_playback_modes_wrap_the_playhead() {
+        let clip = AnimationClip::new("x").with_track(
+            AnimationTarget::PositionX,
+            AnimationCurve::new(vec![Keyframe::linear(0.0, 0.0), Keyframe::linear(1.0, 1.0)]),
+        );
+
+        let mut looping = Animator::new(clip.clone());
+        looping.advance(1.25);
+        assert!(approx(looping.time, 0.25));
+
+        let mut once = Animator::new(clip.clone()).with_mode(PlaybackMode::Once);
+        once.advance(1.5);
+        assert_eq!(once.time, 1.0);
+        assert!(once.is_finished());
+
+        let mut ping_pong = Animator::new(clip).with_mode(PlaybackMode::PingPong);
+        ping_pong.advance(1.25);
+        assert!(approx(ping_pong.time, 0.75) && ping_pong.reversed, "bounced off the end");
+        ping_pong.advance(1.0);
+        assert!(approx(ping_pong.time, 0.25) && !ping_pong.reversed, "bounced off the start");
+    }
+
+    #[test]
+    fn test_system_writes_transform_sprite_and_camera_fields() {
+        let mut world = World::new();
+        let entity = world.create_entity();
+        world.add_component(&entity, Transform2D::new(Vec2::ZERO)).unwrap();
+        world.add_component(&entity, Sprite::new(0)).unwrap();
+        world.add_component(&entity, Camera::default()).unwrap();
+        let ramp = || AnimationCurve::new(vec![Keyframe::linear(0.0, 0.0), Keyframe::linear(2.0, 1.0)]);
+        let clip = AnimationClip::new("fade")
+            .with_track(AnimationTarget::PositionX, ramp())
+            .with_track(AnimationTarget::ColorA, ramp())
+            .with_track(AnimationTarget::Zoom, ramp());
+        world.add_component(&entity, Animator::new(clip).with_mode(PlaybackMode::Once)).unwrap();
+
+        let mut system = AnimatorSystem::new();
+        system.update(&mut world, 1.0);
+        assert_eq!(AnimationTarget::PositionX.read(&world, entity), Some(0.5));
+        assert_eq!(AnimationTarget::ColorA.read(&world, entity), Some(0.5));
+        assert_eq!(AnimationTarget::Zoom.read(&world, entity), Some(0.5));
+
+        // Paused animators leave their targets alone
+        world.get_mut::<Animator>(entity).unwrap().pause();
+        world.get_mut::<Transform2D>(entity).unwrap().position.x = 7.0;
+        system.update(&mut world, 1.0);
+        assert_eq!(AnimationTarget::PositionX.read(&world, entity), Some(7.0));
+    }
+
+    #[test]
+    fn test_clip_loads_from_ron() {
+        let ron_str = r#"(
+            name: "pulse",
+            tracks: [
+                (target: ColorA, curve: [(time: 0.0, value: 1.0), (time: 0.5, value: 0.0, interpolation: EaseOut)]),
+            ],
+        )"#;
+        let clip: AnimationClip = ron::from_str(ron_str).unwrap();
+        assert_eq!(clip.duration(), 0.5);
+        assert_eq!(clip.tracks[0].curve.keyframes()[0].interpolation, Interpolation::Linear);
+
+        let animator: Animator = ron::from_str(&format!("(clip: {ron_str})")).unwrap();
+        assert_eq!((animator.mode, animator.speed, animator.playing), (PlaybackMode::Loop, 1.0, true));
+        let round_trip: AnimationClip = ron::from_str(&ron::to_string(&clip).unwrap()).unwrap();
+        assert_eq!(round_trip, clip);
+    }
+}
@@ -16,7 +16,8 @@ pub struct AudioSource {
     pub sound_id: u32,
     /// Volume multiplier (0.0 = silent, 1.0 = full volume)
     pub volume: f32,
-    /// Playback speed multiplier (1.0 = normal)
+    /// Pitch multiplier (1.0 = normal); maps to `audio::SoundSettings::pitch`,
+    /// which resamples, so it also changes playback speed
     pub pitch: f32,
     /// Whether the sound should loop
     pub looping: bool,
@@ -186,6 +187,103 @@ impl AudioListener {
     }
 }
 
+/// Low-pass cutoff (Hz) of an unoccluded sound — effectively no filter.
+pub const OPEN_CUTOFF_HZ: u32 = 20_000;
+
+/// Muffles an entity's [`AudioSource`] when colliders block the line to the
+/// active [`AudioListener`].
+///
+/// With the engine's physics enabled, each frame a ray from the listener to
+/// the source counts the solid colliders on `layers` in between; every one
+/// adds `per_blocker` occlusion. `amount` eases toward that target at
+/// `fade_speed` per second, and the sound plays at [`volume`](Self::volume)
+/// through a [`low_pass`](Self::low_pass) filter that darkens as it rises.
+#[derive(Debug, Clone, Serialize, Deserialize, DeriveComponentMeta)]
+pub struct AudioOcclusion {
+    /// Whether occlusion is checked for this source
+    pub enabled: bool,
+    /// Collision-group bits of the colliders that block sound
+    pub layers: u32,
+    /// Occlusion each blocking collider adds (two walls at 0.5 fully occlude)
+    pub per_blocker: f32,
+    /// Volume multiplier when fully occluded
+    pub occluded_volume: f32,
+    /// Low-pass cutoff in Hz when fully occluded
+    pub occluded_low_pass: u32,
+    /// Occlusion change per second, so walls fade sounds in and out smoothly
+    pub fade_speed: f32,
+    /// Current occlusion, 0.0 (clear line) to 1.0; written by the engine
+    #[serde(default)]
+    pub amount: f32,
+}
+
+impl Default for AudioOcclusion {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            layers: u32::MAX,
+            per_blocker: 0.5,
+            occluded_volume: 0.3,
+            occluded_low_pass: 800,
+            fade_speed: 4.0,
+            amount: 0.0,
+        }
+    }
+}
+
+impl AudioOcclusion {
+    /// Occlusion against colliders on every layer, with default muffling.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only colliders whose collision groups share a bit with `layers` block.
+    pub fn with_layers(mut self, layers: u32) -> Self {
+        self.layers = layers;
+        self
+    }
+
+    /// Volume multiplier and low-pass cutoff at full occlusion.
+    pub fn with_muffling(mut self, volume: f32, low_pass_hz: u32) -> Self {
+        self.occluded_volume = volume.clamp(0.0, 1.0);
+        self.occluded_low_pass = low_pass_hz;
+        self
+    }
+
+    /// Occlusion added by each blocking collider.
+    pub fn with_per_blocker(mut self, per_blocker: f32) -> Self {
+        self.per_blocker = per_blocker.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Occlusion for `blockers` colliders in the way, capped at 1.0.
+    pub fn target(&self, blockers: usize) -> f32 {
+        (blockers as f32 * self.per_blocker).clamp(0.0, 1.0)
+    }
+
+    /// Ease `amount` toward `target` by at most `fade_speed * dt`.
+    pub fn step(&mut self, target: f32, dt: f32) {
+        let max_change = self.fade_speed.max(0.0) * dt.max(0.0);
+        self.amount += (target - self.amount).clamp(-max_change, max_change);
+    }
+
+    /// Volume multiplier for the current `amount`.
+    pub fn volume(&self) -> f32 {
+        1.0 - self.amount * (1.0 - self.occluded_volume)
+    }
+
+    /// Low-pass cutoff for the current `amount`, interpolated in octaves
+    /// from [`OPEN_CUTOFF_HZ`]; `None` while unoccluded.
+    pub fn low_pass(&self) -> Option<u32> {
+        if self.amount <= 0.0 {
+            return None;
+        }
+        let open = OPEN_CUTOFF_HZ as f32;
+        let closed = self.occluded_low_pass.clamp(1, OPEN_CUTOFF_HZ) as f32;
+        Some((open * (closed / open).powf(self.amount)).round() as u32)
+    }
+}
+
 /// One-shot sound effect request.
 ///
 /// This component triggers a sound effect to play once and then the component
@@ -324,6 +422,28 @@ mod tests {
         assert!(effect.spatial);
     }
 
+    #[test]
+    fn test_audio_occlusion_fades_toward_blocked_target() {
+        let mut occlusion = AudioOcclusion::new().with_muffling(0.2, 500);
+        assert_eq!(occlusion.low_pass(), None);
+        assert!((occlusion.volume() - 1.0).abs() < f32::EPSILON);
+        assert!((occlusion.target(3) - 1.0).abs() < f32::EPSILON, "capped");
+
+        // One wall: half occlusion, reached over an eighth of a second
+        let target = occlusion.target(1);
+        occlusion.step(target, 0.1);
+        assert!((occlusion.amount - 0.4).abs() < 1e-5);
+        occlusion.step(target, 0.1);
+        assert!((occlusion.amount - 0.5).abs() < 1e-5);
+        assert!((occlusion.volume() - 0.6).abs() < 1e-5);
+        assert_eq!(occlusion.low_pass(), Some(3162), "halfway in octaves");
+
+        occlusion.step(1.0, 1.0);
+        assert_eq!(occlusion.low_pass(), Some(500));
+        occlusion.step(0.0, 1.0);
+        assert_eq!(occlusion.low_pass(), None);
+    }
+
     #[test]
     fn test_volume_clamping() {
         let source = AudioSource::new(1).with_volume(2.0);
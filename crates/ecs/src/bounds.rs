@@ -0,0 +1,269 @@
+//! Play-area limits: keep entities inside a region or remove the ones that
+//! leave it.
+//!
+//! [`WorldBounds`] is attached to the entity it limits (a ship that wraps
+//! around the screen, a player clamped to the arena, a bullet despawned once
+//! it leaves). [`KillZone`] is attached to a zone entity and catches every
+//! entity that enters its area (the pit under a platformer level).
+//! [`BoundsSystem`] enforces both; add it to the world's system registry or
+//! own an instance and call `update` from the game loop, after movement.
+//!
+//! Both act on `Transform2D` positions, which are world positions only for
+//! root entities, so children are left to their root. Bodies driven by
+//! physics are moved by the simulation, not their transform — give those
+//! the despawning behaviours rather than clamp or wrap.
+
+use std::collections::HashSet;
+
+use common::Rect;
+use glam::Vec2;
+use serde::{Deserialize, Serialize};
+
+use crate::entity::EntityId;
+use crate::hierarchy::Parent;
+use crate::query::{Pair, Single};
+use crate::sprite_components::Transform2D;
+use crate::system::System;
+use crate::world::World;
+
+/// What happens to an entity outside its [`WorldBounds`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum BoundsMode {
+    /// Hold it at the nearest edge
+    #[default]
+    Clamp,
+    /// Move it to the opposite edge (Asteroids-style screen wrap)
+    Wrap,
+    /// Despawn it along with its children
+    Despawn,
+}
+
+/// Component: keep the owning entity's position inside `area`.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct WorldBounds {
+    /// Allowed region, in world units
+    pub area: Rect,
+    /// Behaviour once the position leaves `area`
+    pub mode: BoundsMode,
+}
+
+impl WorldBounds {
+    /// Hold the entity at the edges of `area`.
+    pub fn clamp(area: Rect) -> Self {
+        Self { area, mode: BoundsMode::Clamp }
+    }
+
+    /// Wrap the entity around to the opposite edge of `area`.
+    pub fn wrap(area: Rect) -> Self {
+        Self { area, mode: BoundsMode::Wrap }
+    }
+
+    /// Despawn the entity once it leaves `area`.
+    pub fn despawn(area: Rect) -> Self {
+        Self { area, mode: BoundsMode::Despawn }
+    }
+
+    /// Where `position` ends up under this bound, or `None` when the entity
+    /// should be despawned.
+    pub fn apply(&self, position: Vec2) -> Option<Vec2> {
+        let (min, max) = (self.area.min(), self.area.max());
+        match self.mode {
+            BoundsMode::Clamp => Some(position.clamp(min, max.max(min))),
+            BoundsMode::Wrap => Some(Vec2::new(wrap(position.x, min.x, max.x), wrap(position.y, min.y, max.y))),
+            BoundsMode::Despawn => self.area.contains(position).then_some(position),
+        }
+    }
+}
+
+/// What a [`KillZone`] does to entities that enter it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum KillZoneAction {
+    /// Despawn the entity along with its children (after emitting the event)
+    #[default]
+    Despawn,
+    /// Only emit [`KillZoneEntered`]; the game decides (respawn, damage, ...)
+    EmitEvent,
+}
+
+/// Component: a region that catches every entity entering it. The zone
+/// entity itself needs no transform.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct KillZone {
+    /// Region, in world units
+    pub area: Rect,
+    /// What happens to entities inside
+    pub action: KillZoneAction,
+}
+
+impl KillZone {
+    /// Despawn whatever enters `area`.
+    pub fn despawn(area: Rect) -> Self {
+        Self { area, action: KillZoneAction::Despawn }
+    }
+
+    /// Emit [`KillZoneEntered`] for whatever enters `area`.
+    pub fn event(area: Rect) -> Self {
+        Self { area, action: KillZoneAction::EmitEvent }
+    }
+}
+
+/// Event: `entity` entered the kill zone `zone`. Emitted once per entry;
+/// with [`KillZoneAction::Despawn`] the entity is already gone when read.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KillZoneEntered {
+    /// The zone entity
+    pub zone: EntityId,
+    /// The entity that entered it
+    pub entity: EntityId,
+}
+
+/// System enforcing [`WorldBounds`] and [`KillZone`] (see the module docs).
+#[derive(Debug, Default)]
+pub struct BoundsSystem {
+    /// `(zone, entity)` pairs inside an event-only zone, so each entry
+    /// fires once
+    inside: HashSet<(EntityId, EntityId)>,
+}
+
+impl BoundsSystem {
+    /// Create a new bounds system.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn update_bounds(world: &mut World) {
+        for entity in world.query_active::<Pair<Transform2D, WorldBounds>>() {
+            if world.get::<Parent>(entity).is_some() {
+                continue;
+            }
+            let bounds = world.get::<WorldBounds>(entity).copied();
+            let Some((bounds, current)) = bounds.zip(world.get::<Transform2D>(entity).map(|t| t.position)) else {
+                continue;
+            };
+            match bounds.apply(current) {
+                None => {
+                    world.despawn_recursive(entity).ok();
+                }
+                Some(position) if position != current => {
+                    if let Some(transform) = world.get_mut::<Transform2D>(entity) {
+                        transform.position = position;
+                    }
+                }
+                Some(_) => {}
+            }
+        }
+    }
+
+    fn update_kill_zones(&mut self, world: &mut World) {
+        let zones: Vec<(EntityId, KillZone)> = world
+            .query_active::<Single<KillZone>>()
+            .into_iter()
+            .filter_map(|zone| world.get::<KillZone>(zone).map(|kill_zone| (zone, *kill_zone)))
+            .collect();
+        if zones.is_empty() {
+            self.inside.clear();
+            return;
+        }
+
+        let mut still_inside = HashSet::new();
+        for entity in world.query_active::<Single<Transform2D>>() {
+            if world.get::<Parent>(entity).is_some() || world.get::<KillZone>(entity).is_some() {
+                continue;
+            }
+            let Some(position) = world.get::<Transform2D>(entity).map(|t| t.position) else {
+                continue;
+            };
+            for (zone, kill_zone) in &zones {
+                if !kill_zone.area.contains(position) {
+                    continue;
+                }
+                match kill_zone.action {
+                    KillZoneAction::Despawn => {
+                        world.emit_event(KillZoneEntered { zone: *zone, entity });
+                        world.despawn_recursive(entity).ok();
+                        break;
+                    }
+                    KillZoneAction::EmitEvent => {
+                        if !self.inside.contains(&(*zone, entity)) {
+                            world.emit_event(KillZoneEntered { zone: *zone, entity });
+                        }
+                        still_inside.insert((*zone, entity));
+                    }
+                }
+            }
+        }
+        self.inside = still_inside;
+    }
+}
+
+impl System for BoundsSystem {
+    fn update(&mut self, world: &mut World, _delta_time: f32) {
+        Self::update_bounds(world);
+        self.update_kill_zones(world);
+    }
+
+    fn name(&self) -> &str {
+        "BoundsSystem"
+    }
+}
+
+/// Wrap `value` into `min..max`; empty ranges pin it to `min`.
+fn wrap(value: f32, min: f32, max: f32) -> f32 {
+    let span = max - min;
+    if span > 0.0 {
+        min + (value - min).rem_euclid(span)
+    } else {
+        min
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_world_bounds_clamp_wrap_and_despawn() {
+        let area = Rect::new(0.0, 0.0, 100.0, 50.0);
+        assert_eq!(WorldBounds::clamp(area).apply(Vec2::new(120.0, -5.0)), Some(Vec2::new(100.0, 0.0)));
+        assert_eq!(WorldBounds::wrap(area).apply(Vec2::new(110.0, -5.0)), Some(Vec2::new(10.0, 45.0)));
+        assert_eq!(WorldBounds::despawn(area).apply(Vec2::new(50.0, 25.0)), Some(Vec2::new(50.0, 25.0)));
+        assert_eq!(WorldBounds::despawn(area).apply(Vec2::new(50.0, 60.0)), None);
+
+        let mut world = World::new();
+        let ship = world.spawn((Transform2D::new(Vec2::new(-10.0, 20.0)), WorldBounds::wrap(area))).id();
+        let bullet = world.spawn((Transform2D::new(Vec2::new(0.0, 80.0)), WorldBounds::despawn(area))).id();
+        BoundsSystem::new().update(&mut world, 0.016);
+        assert_eq!(world.get::<Transform2D>(ship).unwrap().position, Vec2::new(90.0, 20.0));
+        assert!(world.validate_entity(&bullet).is_err());
+    }
+
+    #[test]
+    fn test_kill_zone_despawns_or_reports_each_entry_once() {
+        let mut world = World::new();
+        let pit = world.spawn((KillZone::despawn(Rect::new(-100.0, 100.0, 200.0, 50.0)),)).id();
+        let water = world.spawn((KillZone::event(Rect::new(200.0, 0.0, 50.0, 50.0)),)).id();
+        let falling = world.spawn((Transform2D::new(Vec2::new(0.0, 120.0)),)).id();
+        let swimmer = world.spawn((Transform2D::new(Vec2::new(210.0, 10.0)),)).id();
+        let bystander = world.spawn((Transform2D::new(Vec2::ZERO),)).id();
+
+        let mut system = BoundsSystem::new();
+        system.update(&mut world, 0.016);
+        assert!(world.validate_entity(&falling).is_err());
+        assert!(world.validate_entity(&bystander).is_ok());
+        let events = world.read_events::<KillZoneEntered>();
+        assert_eq!(events.len(), 2);
+        assert!(events.contains(&KillZoneEntered { zone: pit, entity: falling }));
+        assert!(events.contains(&KillZoneEntered { zone: water, entity: swimmer }));
+
+        // Staying in an event zone doesn't repeat the event; leaving and
+        // coming back does
+        world.flush_events();
+        system.update(&mut world, 0.016);
+        assert!(world.read_events::<KillZoneEntered>().is_empty());
+        world.get_mut::<Transform2D>(swimmer).unwrap().position = Vec2::ZERO;
+        system.update(&mut world, 0.016);
+        world.get_mut::<Transform2D>(swimmer).unwrap().position = Vec2::new(220.0, 20.0);
+        system.update(&mut world, 0.016);
+        assert_eq!(world.read_events::<KillZoneEntered>().len(), 1);
+    }
+}
@@ -0,0 +1,80 @@
+//! Component bundles: groups of components added to an entity together.
+//!
+//! Tuples of components are bundles, so an entity can be spawned in one
+//! call instead of a chain of `add_component`s:
+//!
+//! ```
+//! # use ecs::{World, Transform2D, Sprite, Name};
+//! # use glam::Vec2;
+//! let mut world = World::new();
+//! let entity = world
+//!     .spawn((Transform2D::new(Vec2::new(10.0, 20.0)), Sprite::new(0), Name::new("player")))
+//!     .id();
+//! assert!(world.has_component::<Sprite>(&entity).unwrap());
+//! ```
+//!
+//! A single component is the one-element tuple `(component,)`: every type
+//! is already a [`Component`], so a bare tuple could not also be a bundle.
+//! Named archetypes implement [`Bundle`] by delegating to a tuple:
+//!
+//! ```
+//! # use ecs::{Bundle, EcsError, EntityId, World, Transform2D, Sprite};
+//! struct SpriteBundle {
+//!     transform: Transform2D,
+//!     sprite: Sprite,
+//! }
+//!
+//! impl Bundle for SpriteBundle {
+//!     fn insert_into(self, world: &mut World, entity: EntityId) -> Result<(), EcsError> {
+//!         (self.transform, self.sprite).insert_into(world, entity)
+//!     }
+//! }
+//! ```
+
+use crate::component::Component;
+use crate::entity::EntityId;
+use crate::world::World;
+use crate::EcsError;
+
+/// A set of components added to one entity at once.
+///
+/// Implemented for `()` and tuples of up to 12 components. When a tuple
+/// holds the same component type twice, the later value wins (same as
+/// calling `add_component` twice).
+pub trait Bundle {
+    /// Add every component of the bundle to `entity`.
+    ///
+    /// Fails on the first component if the entity is dead or stale.
+    fn insert_into(self, world: &mut World, entity: EntityId) -> Result<(), EcsError>;
+}
+
+impl Bundle for () {
+    fn insert_into(self, world: &mut World, entity: EntityId) -> Result<(), EcsError> {
+        world.validate_entity(&entity)
+    }
+}
+
+macro_rules! impl_tuple_bundle {
+    ($($name:ident $value:ident),+) => {
+        impl<$($name: Component),+> Bundle for ($($name,)+) {
+            fn insert_into(self, world: &mut World, entity: EntityId) -> Result<(), EcsError> {
+                let ($($value,)+) = self;
+                $(world.add_component(&entity, $value)?;)+
+                Ok(())
+            }
+        }
+    };
+}
+
+impl_tuple_bundle!(A a);
+impl_tuple_bundle!(A a, B b);
+impl_tuple_bundle!(A a, B b, C c);
+impl_tuple_bundle!(A a, B b, C c, D d);
+impl_tuple_bundle!(A a, B b, C c, D d, E e);
+impl_tuple_bundle!(A a, B b, C c, D d, E e, F f);
+impl_tuple_bundle!(A a, B b, C c, D d, E e, F f, G g);
+impl_tuple_bundle!(A a, B b, C c, D d, E e, F f, G g, H h);
+impl_tuple_bundle!(A a, B b, C c, D d, E e, F f, G g, H h, I i);
+impl_tuple_bundle!(A a, B b, C c, D d, E e, F f, G g, H h, I i, J j);
+impl_tuple_bundle!(A a, B b, C c, D d, E e, F f, G g, H h, I i, J j, K k);
+impl_tuple_bundle!(A a, B b, C c, D d, E e, F f, G g, H h, I i, J j, K k, L l);
@@ -0,0 +1,176 @@
+//! Component change detection.
+//!
+//! Every stored component carries the world *change tick* at which it was
+//! added and last mutably accessed. Sync systems remember a tick and later
+//! ask what happened after it, so they only touch dirty entities:
+//!
+//! ```
+//! use ecs::{Changed, Single, World};
+//! use ecs::sprite_components::Transform2D;
+//!
+//! let mut world = World::new();
+//! let entity = world.spawn((Transform2D::default(),)).id();
+//! // A system finishes its pass and remembers where it stopped.
+//! let synced = world.increment_change_tick();
+//!
+//! if let Some(transform) = world.get_mut::<Transform2D>(entity) {
+//!     transform.position.x += 1.0;
+//! }
+//! let dirty = world.query_filtered::<Single<Transform2D>, Changed<Transform2D>>(synced);
+//! assert_eq!(dirty, vec![entity]);
+//! ```
+//!
+//! Changes are recorded conservatively: `get_mut` marks the component
+//! changed whether or not the caller writes through the reference. Systems
+//! that must ignore no-op accesses still value-compare (see the physics
+//! sync baselines).
+
+use std::marker::PhantomData;
+
+use crate::component::Component;
+use crate::entity::EntityId;
+use crate::world::World;
+
+/// Change ticks of one stored component.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ComponentTicks {
+    /// Tick at which the component was added
+    pub added: u32,
+    /// Tick of the last mutable access (or replacement)
+    pub changed: u32,
+}
+
+impl ComponentTicks {
+    /// Ticks for a component added at `tick`.
+    pub fn new(tick: u32) -> Self {
+        Self { added: tick, changed: tick }
+    }
+
+    /// Whether the component was added after `since`.
+    pub fn is_added(&self, since: u32) -> bool {
+        self.added > since
+    }
+
+    /// Whether the component was added or changed after `since`.
+    pub fn is_changed(&self, since: u32) -> bool {
+        self.changed > since
+    }
+}
+
+/// Filter applied per entity by [`World::query_filtered`].
+pub trait QueryFilter {
+    /// Whether `entity` passes the filter for changes after `since`.
+    fn matches(world: &World, entity: EntityId, since: u32) -> bool;
+}
+
+/// Matches entities whose `T` was added after the given tick.
+pub struct Added<T: Component> {
+    _phantom: PhantomData<T>,
+}
+
+impl<T: Component> QueryFilter for Added<T> {
+    fn matches(world: &World, entity: EntityId, since: u32) -> bool {
+        world.component_ticks::<T>(entity).is_some_and(|ticks| ticks.is_added(since))
+    }
+}
+
+/// Matches entities whose `T` was added or changed after the given tick.
+pub struct Changed<T: Component> {
+    _phantom: PhantomData<T>,
+}
+
+impl<T: Component> QueryFilter for Changed<T> {
+    fn matches(world: &World, entity: EntityId, since: u32) -> bool {
+        world.component_ticks::<T>(entity).is_some_and(|ticks| ticks.is_changed(since))
+    }
+}
+
+/// Both filters must match.
+impl<A: QueryFilter, B: QueryFilter> QueryFilter for (A, B) {
+    fn matches(world: &World, entity: EntityId, since: u32) -> bool {
+        A::matches(world, entity, since) && B::matches(world, entity, since)
+    }
+}
+
+/// Either filter may match.
+pub struct Or<A: QueryFilter, B: QueryFilter> {
+    _phantom: PhantomData<(A, B)>,
+}
+
+impl<A: QueryFilter, B: QueryFilter> QueryFilter for Or<A, B> {
+    fn matches(world: &World, entity: EntityId, since: u32) -> bool {
+        A::matches(world, entity, since) || B::matches(world, entity, since)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::query::{Pair, Single};
+    use crate::sprite_components::{Sprite, Transform2D};
+
+    #[test]
+    fn test_added_matches_only_components_added_after_the_tick() {
+        let mut world = World::new();
+        let old = world.spawn((Transform2D::default(),)).id();
+        let since = world.increment_change_tick();
+        let new = world.spawn((Transform2D::default(),)).id();
+
+        assert_eq!(world.query_filtered::<Single<Transform2D>, Added<Transform2D>>(since), vec![new]);
+        assert!(!world.is_added_since::<Transform2D>(old, since));
+    }
+
+    #[test]
+    fn test_get_mut_marks_changed_but_not_added() {
+        let mut world = World::new();
+        let entity = world.spawn((Transform2D::default(),)).id();
+        let since = world.increment_change_tick();
+
+        world.get_mut::<Transform2D>(entity);
+        assert!(world.is_changed_since::<Transform2D>(entity, since));
+        assert!(!world.is_added_since::<Transform2D>(entity, since));
+    }
+
+    #[test]
+    fn test_read_access_does_not_mark_changed() {
+        let mut world = World::new();
+        let entity = world.spawn((Transform2D::default(),)).id();
+        let since = world.increment_change_tick();
+
+        world.get::<Transform2D>(entity);
+        assert!(!world.is_changed_since::<Transform2D>(entity, since));
+    }
+
+    #[test]
+    fn test_is_changed_covers_the_current_frame_only() {
+        let mut world = World::new();
+        let entity = world.spawn((Transform2D::default(),)).id();
+        assert!(world.is_added::<Transform2D>(entity));
+
+        world.clear_trackers();
+        assert!(!world.is_changed::<Transform2D>(entity));
+        world.get_mut::<Transform2D>(entity);
+        assert!(world.is_changed::<Transform2D>(entity));
+    }
+
+    #[test]
+    fn test_filter_tuple_requires_both_and_or_requires_either() {
+        let mut world = World::new();
+        let both = world.spawn((Transform2D::default(), Sprite::new(0))).id();
+        let since = world.increment_change_tick();
+        world.get_mut::<Sprite>(both);
+
+        type Both = (Changed<Transform2D>, Changed<Sprite>);
+        type Either = Or<Changed<Transform2D>, Changed<Sprite>>;
+        assert!(world.query_filtered::<Pair<Transform2D, Sprite>, Both>(since).is_empty());
+        assert_eq!(world.query_filtered::<Pair<Transform2D, Sprite>, Either>(since), vec![both]);
+    }
+
+    #[test]
+    fn test_removed_component_has_no_ticks() {
+        let mut world = World::new();
+        let entity = world.spawn((Transform2D::default(),)).id();
+        world.remove_component::<Transform2D>(&entity).expect("remove");
+        assert!(world.component_ticks::<Transform2D>(entity).is_none());
+    }
+}
@@ -0,0 +1,145 @@
+//! Deferred world operations.
+//!
+//! Removing entities halfway through a pass over a query changes the world
+//! under the rest of the pass: later entities may already be gone, and a
+//! plain `remove_entity` leaves the removed entity's children behind as
+//! orphaned roots. [`Commands`] records spawns, despawns and component
+//! changes instead and applies them later, in the order they were queued.
+//!
+//! Every [`World`] carries a queue, [`World::commands`], which the game loop
+//! flushes once per frame right after `update()`; systems that want their
+//! changes applied sooner keep their own `Commands` and call
+//! [`apply`](Commands::apply) at the end of the pass.
+//!
+//! ```
+//! use ecs::{Commands, Lifetime, Name, Single, World, WorldHierarchyExt};
+//!
+//! let mut world = World::new();
+//! let ship = world.spawn((Name::new("ship"), Lifetime::new(0.0))).id();
+//! let turret = world.create_entity();
+//! world.set_parent(turret, ship).unwrap();
+//!
+//! let mut commands = Commands::new();
+//! for entity in world.query_entities::<Single<Lifetime>>() {
+//!     if world.get::<Lifetime>(entity).is_some_and(|l| l.remaining <= 0.0) {
+//!         commands.despawn_recursive(entity);
+//!     }
+//! }
+//! commands.spawn((Name::new("explosion"),));
+//! commands.apply(&mut world);
+//! assert_eq!(world.entity_count(), 1);
+//! ```
+
+use std::fmt;
+
+use crate::bundle::Bundle;
+use crate::component::Component;
+use crate::entity::EntityId;
+use crate::world::World;
+
+/// One deferred operation.
+type Command = Box<dyn FnOnce(&mut World) + Send + Sync>;
+
+/// A queue of world operations applied later (see the module docs).
+///
+/// Operations on entities that are gone by the time the queue is applied
+/// are skipped with a trace log, so two systems despawning the same entity
+/// is harmless.
+#[derive(Default)]
+pub struct Commands {
+    queue: Vec<Command>,
+}
+
+impl Commands {
+    /// An empty queue.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of queued operations.
+    pub fn len(&self) -> usize {
+        self.queue.len()
+    }
+
+    /// Whether nothing is queued.
+    pub fn is_empty(&self) -> bool {
+        self.queue.is_empty()
+    }
+
+    /// Spawn an entity with `bundle`.
+    pub fn spawn<B: Bundle + Send + Sync + 'static>(&mut self, bundle: B) {
+        self.add(move |world| {
+            world.spawn(bundle);
+        });
+    }
+
+    /// Spawn an entity with `bundle`, then hand its ID to `then` (to parent
+    /// it, register it, or queue more work for it).
+    pub fn spawn_then<B, F>(&mut self, bundle: B, then: F)
+    where
+        B: Bundle + Send + Sync + 'static,
+        F: FnOnce(&mut World, EntityId) + Send + Sync + 'static,
+    {
+        self.add(move |world| {
+            let entity = world.spawn(bundle).id();
+            then(world, entity);
+        });
+    }
+
+    /// Remove `entity`; its children become roots (see [`World::remove_entity`]).
+    pub fn despawn(&mut self, entity: EntityId) {
+        self.add(move |world| {
+            if let Err(e) = world.remove_entity(&entity) {
+                log::trace!("Skipped deferred despawn of {}: {}", entity, e);
+            }
+        });
+    }
+
+    /// Remove `entity` and all of its descendants (see [`World::despawn_recursive`]).
+    pub fn despawn_recursive(&mut self, entity: EntityId) {
+        self.add(move |world| {
+            if let Err(e) = world.despawn_recursive(entity) {
+                log::trace!("Skipped deferred recursive despawn of {}: {}", entity, e);
+            }
+        });
+    }
+
+    /// Add (or replace) a component on `entity`.
+    pub fn insert<T: Component>(&mut self, entity: EntityId, component: T) {
+        self.add(move |world| {
+            if let Err(e) = world.add_component(&entity, component) {
+                log::trace!("Skipped deferred insert on {}: {}", entity, e);
+            }
+        });
+    }
+
+    /// Remove the `T` component from `entity`.
+    pub fn remove<T: Component>(&mut self, entity: EntityId) {
+        self.add(move |world| {
+            world.remove_component::<T>(&entity).ok();
+        });
+    }
+
+    /// Queue an arbitrary operation.
+    pub fn add(&mut self, command: impl FnOnce(&mut World) + Send + Sync + 'static) {
+        self.queue.push(Box::new(command));
+    }
+
+    /// Move every operation queued in `other` to the end of this queue.
+    pub fn append(&mut self, other: &mut Commands) {
+        self.queue.append(&mut other.queue);
+    }
+
+    /// Apply the queued operations to `world` in order, leaving the queue empty.
+    pub fn apply(&mut self, world: &mut World) {
+        for command in self.queue.drain(..) {
+            command(world);
+        }
+    }
+}
+
+impl fmt::Debug for Commands {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Commands").field("len", &self.queue.len()).finish()
+    }
+}
@@ -1,11 +1,15 @@
 //! Component management for the ECS.
 //!
-//! This module provides HashMap-based per-type component storage.
+//! This module provides the [`Component`] trait and the per-world
+//! [`ComponentRegistry`], which keeps one sparse-set
+//! [`ComponentStore`](crate::storage::ComponentStore) per component type.
 
 use std::any::{Any, TypeId};
 use std::collections::HashMap;
 
+use crate::change_detection::ComponentTicks;
 use crate::entity::EntityId;
+use crate::storage::{AnyStore, ComponentStore};
 
 /// A trait for components in the ECS
 pub trait Component: Any + Send + Sync {
@@ -33,75 +37,11 @@ impl<T: Any + Send + Sync> Component for T {
     }
 }
 
-/// HashMap-based per-type component storage
-#[derive(Default)]
-pub struct ComponentStore {
-    /// The components, indexed by entity ID
-    components: HashMap<EntityId, Box<dyn Component>>,
-}
-
-impl ComponentStore {
-    /// Create a new component storage
-    pub fn new() -> Self {
-        Self {
-            components: HashMap::new(),
-        }
-    }
-
-    /// Add a component for an entity
-    pub fn add<T: Component>(&mut self, entity_id: EntityId, component: T) {
-        self.components.insert(entity_id, Box::new(component));
-    }
-
-    /// Remove a component for an entity
-    pub fn remove(&mut self, entity_id: &EntityId) -> Option<Box<dyn Component>> {
-        self.components.remove(entity_id)
-    }
-
-    /// Get a typed reference to a component for an entity
-    pub fn get_typed<T: Component>(&self, entity_id: &EntityId) -> Option<&T> {
-        // Use .as_ref() to get &dyn Component before calling as_any(),
-        // otherwise the blanket impl on Box<dyn Component> would be called
-        // instead of going through virtual dispatch to the concrete type.
-        self.components
-            .get(entity_id)
-            .and_then(|c| c.as_ref().as_any().downcast_ref::<T>())
-    }
-
-    /// Get a typed mutable reference to a component for an entity
-    pub fn get_typed_mut<T: Component>(&mut self, entity_id: &EntityId) -> Option<&mut T> {
-        // Use .as_mut() to get &mut dyn Component before calling as_any_mut()
-        self.components
-            .get_mut(entity_id)
-            .and_then(|c| c.as_mut().as_any_mut().downcast_mut::<T>())
-    }
-
-    /// Check if an entity has a component stored
-    pub fn has_entity(&self, entity_id: &EntityId) -> bool {
-        self.components.contains_key(entity_id)
-    }
-
-    /// Get the number of components
-    pub fn len(&self) -> usize {
-        self.components.len()
-    }
-
-    /// Check if there are no components
-    pub fn is_empty(&self) -> bool {
-        self.components.is_empty()
-    }
-
-    /// Remove all components for an entity
-    pub fn remove_all(&mut self, entity_id: &EntityId) {
-        self.components.remove(entity_id);
-    }
-}
-
 /// A registry for all component types
 #[derive(Default)]
 pub struct ComponentRegistry {
     /// The component storages, indexed by component type ID
-    storages: HashMap<TypeId, ComponentStore>,
+    storages: HashMap<TypeId, Box<dyn AnyStore>>,
 }
 
 impl ComponentRegistry {
@@ -114,41 +54,75 @@ impl ComponentRegistry {
 
     /// Register a component type
     pub fn register<T: Component>(&mut self) {
-        self.storages.entry(TypeId::of::<T>()).or_default();
+        self.storages
+            .entry(TypeId::of::<T>())
+            .or_insert_with(|| Box::new(ComponentStore::<T>::new()));
     }
 
-    /// Add a component for an entity
-    pub fn add<T: Component>(&mut self, entity_id: EntityId, component: T) {
+    /// The store for `T`, if any `T` was ever added or registered
+    pub fn store<T: Component>(&self) -> Option<&ComponentStore<T>> {
         self.storages
-            .entry(TypeId::of::<T>())
-            .or_default()
-            .add(entity_id, component);
+            .get(&TypeId::of::<T>())?
+            .store_any()
+            .downcast_ref::<ComponentStore<T>>()
+    }
+
+    /// The store for `T`, mutably
+    pub fn store_mut<T: Component>(&mut self) -> Option<&mut ComponentStore<T>> {
+        self.storages
+            .get_mut(&TypeId::of::<T>())?
+            .store_any_mut()
+            .downcast_mut::<ComponentStore<T>>()
+    }
+
+    /// The store for `T` mutably, alongside a shared view of the store for
+    /// `U` (`None` for `U` when both are the same type)
+    pub fn store_mut_with<T: Component, U: Component>(
+        &mut self,
+    ) -> (Option<&mut ComponentStore<T>>, Option<&ComponentStore<U>>) {
+        let (t, u) = (TypeId::of::<T>(), TypeId::of::<U>());
+        if t == u {
+            return (self.store_mut::<T>(), None);
+        }
+        let [first, second] = self.storages.get_disjoint_mut([&t, &u]);
+        (
+            first.and_then(|store| store.store_any_mut().downcast_mut::<ComponentStore<T>>()),
+            second.and_then(|store| store.store_any().downcast_ref::<ComponentStore<U>>()),
+        )
+    }
+
+    /// Add a component for an entity at change tick `tick`
+    pub fn add<T: Component>(&mut self, entity_id: EntityId, component: T, tick: u32) {
+        self.register::<T>();
+        if let Some(store) = self.store_mut::<T>() {
+            store.insert(entity_id, component, tick);
+        }
     }
 
-    /// Remove a component for an entity
-    pub fn remove<T: Component>(&mut self, entity_id: &EntityId) -> Option<Box<dyn Component>> {
-        let type_id = TypeId::of::<T>();
-        self.storages.get_mut(&type_id)?.remove(entity_id)
+    /// Remove and return an entity's component
+    pub fn remove<T: Component>(&mut self, entity_id: &EntityId) -> Option<T> {
+        self.store_mut::<T>()?.remove(entity_id)
     }
 
     /// Get a typed reference to a component for an entity
     pub fn get_typed<T: Component>(&self, entity_id: &EntityId) -> Option<&T> {
-        let type_id = TypeId::of::<T>();
-        self.storages.get(&type_id)?.get_typed::<T>(entity_id)
+        self.store::<T>()?.get(entity_id)
     }
 
-    /// Get a typed mutable reference to a component for an entity
-    pub fn get_typed_mut<T: Component>(&mut self, entity_id: &EntityId) -> Option<&mut T> {
-        let type_id = TypeId::of::<T>();
-        self.storages.get_mut(&type_id)?.get_typed_mut::<T>(entity_id)
+    /// Get a typed mutable reference to a component for an entity, marking
+    /// it changed at `tick`
+    pub fn get_typed_mut<T: Component>(&mut self, entity_id: &EntityId, tick: u32) -> Option<&mut T> {
+        self.store_mut::<T>()?.get_mut(entity_id, tick)
+    }
+
+    /// Added/changed ticks of an entity's `T` component
+    pub fn ticks<T: Component>(&self, entity_id: &EntityId) -> Option<ComponentTicks> {
+        self.store::<T>()?.ticks(entity_id)
     }
 
     /// Check if an entity has a component
     pub fn has<T: Component>(&self, entity_id: &EntityId) -> bool {
-        let type_id = TypeId::of::<T>();
-        self.storages
-            .get(&type_id)
-            .is_some_and(|s| s.has_entity(entity_id))
+        self.store::<T>().is_some_and(|store| store.contains(entity_id))
     }
 
     /// Check if an entity has a component of a specific type ID
@@ -157,13 +131,22 @@ impl ComponentRegistry {
     pub fn has_type(&self, entity_id: &EntityId, type_id: TypeId) -> bool {
         self.storages
             .get(&type_id)
-            .is_some_and(|storage| storage.has_entity(entity_id))
+            .is_some_and(|storage| storage.contains_entity(entity_id))
+    }
+
+    /// Entities with a component of a specific type ID, in storage order
+    /// (empty for types never stored)
+    pub fn entities_with(&self, type_id: TypeId) -> &[EntityId] {
+        self.storages
+            .get(&type_id)
+            .map(|storage| storage.stored_entities())
+            .unwrap_or(&[])
     }
 
     /// Remove all components for an entity
     pub fn remove_all(&mut self, entity_id: &EntityId) {
         for storage in self.storages.values_mut() {
-            storage.remove_all(entity_id);
+            storage.remove_entity(entity_id);
         }
     }
 
@@ -5,11 +5,23 @@
 
 use std::any::{Any, TypeId};
 use std::collections::HashMap;
-use std::sync::OnceLock;
+use std::sync::{OnceLock, PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+use crate::entity::EntityId;
+use crate::validation::{ComponentError, Validate};
+use crate::world::World;
 
 /// Factory function type for creating components from JSON
 pub type ComponentFactoryFn = fn(serde_json::Value) -> Result<Box<dyn Any + Send + Sync>, String>;
 
+/// Inserter function type: deserialize a component from JSON and add it to
+/// an entity (type-erased `add_component`)
+pub type ComponentInsertFn = fn(&mut World, EntityId, serde_json::Value) -> Result<(), String>;
+
+/// Validator function type: `None` when the entity lacks the component,
+/// otherwise the component's [`Validate`] result
+pub type ComponentValidateFn = fn(&World, EntityId) -> Option<Result<(), String>>;
+
 /// Metadata about a component type for editor inspection and serialization
 pub trait ComponentMeta: Send + Sync + 'static {
     /// The component's display name (e.g., "Transform2D")
@@ -24,12 +36,14 @@ pub trait ComponentMeta: Send + Sync + 'static {
 }
 
 /// Global registry of component types
-static COMPONENT_REGISTRY: OnceLock<ComponentRegistry> = OnceLock::new();
+static COMPONENT_REGISTRY: OnceLock<RwLock<ComponentRegistry>> = OnceLock::new();
 
 /// Runtime registry for component type lookup by name
 pub struct ComponentRegistry {
     types: HashMap<&'static str, TypeId>,
     factories: HashMap<&'static str, ComponentFactoryFn>,
+    inserters: HashMap<&'static str, ComponentInsertFn>,
+    validators: HashMap<&'static str, ComponentValidateFn>,
 }
 
 impl ComponentRegistry {
@@ -38,6 +52,8 @@ impl ComponentRegistry {
         Self {
             types: HashMap::new(),
             factories: HashMap::new(),
+            inserters: HashMap::new(),
+            validators: HashMap::new(),
         }
     }
 
@@ -52,6 +68,32 @@ impl ComponentRegistry {
                 .map(|c| Box::new(c) as Box<dyn Any + Send + Sync>)
                 .map_err(|e| e.to_string())
         });
+        self.inserters.insert(name, |world, entity, json| {
+            let component = serde_json::from_value::<T>(json).map_err(|e| e.to_string())?;
+            world.add_component(&entity, component).map_err(|e| e.to_string())
+        });
+    }
+
+    /// Register `T`'s [`Validate`] impl under its component name. Independent
+    /// of [`register`](Self::register), so types that aren't loaded through
+    /// the registry (physics components) can still be validated.
+    pub fn register_validator<T: ComponentMeta + Validate>(&mut self) {
+        self.validators
+            .insert(T::type_name(), |world, entity| world.get::<T>(entity).map(Validate::validate));
+    }
+
+    /// Run the registered validators against `entity`'s components, in
+    /// component name order.
+    pub fn validate_entity(&self, world: &World, entity: EntityId) -> Vec<ComponentError> {
+        let mut names: Vec<_> = self.validators.keys().copied().collect();
+        names.sort_unstable();
+        names
+            .into_iter()
+            .filter_map(|component| match self.validators[component](world, entity)? {
+                Ok(()) => None,
+                Err(message) => Some(ComponentError { entity, component, message }),
+            })
+            .collect()
     }
 
     /// Check if a component type is registered
@@ -80,6 +122,20 @@ impl ComponentRegistry {
             .ok_or_else(|| format!("Unknown component type: {}", name))
             .and_then(|factory| factory(json))
     }
+
+    /// Create a component by name from JSON and add it to `entity`
+    pub fn insert_component(
+        &self,
+        world: &mut World,
+        entity: EntityId,
+        name: &str,
+        json: serde_json::Value,
+    ) -> Result<(), String> {
+        self.inserters
+            .get(name)
+            .ok_or_else(|| format!("Unknown component type: {}", name))
+            .and_then(|insert| insert(world, entity, json))
+    }
 }
 
 impl Default for ComponentRegistry {
@@ -88,26 +144,66 @@ impl Default for ComponentRegistry {
     }
 }
 
-/// Get or initialize the global component registry
-pub fn global_registry() -> &'static ComponentRegistry {
+/// Read access to the global component registry (built-ins plus anything
+/// added with [`register_global_component`])
+pub fn global_registry() -> RwLockReadGuard<'static, ComponentRegistry> {
+    registry_lock().read().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Register a component type in the global registry at runtime, so scenes
+/// can load it by name (plugins, game crates). Re-registering a name
+/// replaces the previous entry.
+pub fn register_global_component<T: ComponentMeta + for<'de> serde::Deserialize<'de> + Send + Sync + 'static>() {
+    registry_write().register::<T>();
+}
+
+/// Write access to the global component registry.
+pub(crate) fn registry_write() -> RwLockWriteGuard<'static, ComponentRegistry> {
+    registry_lock().write().unwrap_or_else(PoisonError::into_inner)
+}
+
+fn registry_lock() -> &'static RwLock<ComponentRegistry> {
     COMPONENT_REGISTRY.get_or_init(|| {
         let mut registry = ComponentRegistry::new();
 
         // Register built-in ECS components
-        use crate::audio_components::{AudioListener, AudioSource, PlaySoundEffect};
-        use crate::sprite_components::{Camera, Name, Sprite, SpriteAnimation, Transform2D};
+        use crate::animation::Animator;
+        use crate::guid::Guid;
+        use crate::parallax::ParallaxLayer;
+        use crate::sprite_sort::SortBias;
+        use crate::audio_components::{AudioListener, AudioOcclusion, AudioSource, PlaySoundEffect};
+        use crate::sprite_components::{Camera, Name, Sprite, SpriteAnimation, SpriteMask, Transform2D};
         use crate::tilemap::Tilemap;
+        use crate::trail::Trail2D;
+        use crate::world_text::WorldText;
         registry.register::<Transform2D>();
         registry.register::<Sprite>();
         registry.register::<SpriteAnimation>();
+        registry.register::<SpriteMask>();
+        registry.register::<Animator>();
         registry.register::<Camera>();
         registry.register::<Name>();
+        registry.register::<Guid>();
         registry.register::<Tilemap>();
+        registry.register::<Trail2D>();
+        registry.register::<WorldText>();
+        registry.register::<ParallaxLayer>();
+        registry.register::<SortBias>();
         registry.register::<AudioSource>();
         registry.register::<AudioListener>();
         registry.register::<PlaySoundEffect>();
-
-        registry
+        registry.register::<AudioOcclusion>();
+
+        registry.register_validator::<Transform2D>();
+        registry.register_validator::<Sprite>();
+        registry.register_validator::<SpriteAnimation>();
+        registry.register_validator::<SpriteMask>();
+        registry.register_validator::<Camera>();
+        registry.register_validator::<ParallaxLayer>();
+        registry.register_validator::<SortBias>();
+        registry.register_validator::<AudioOcclusion>();
+
+        RwLock::new(registry)
     })
 }
 
@@ -262,11 +358,16 @@ mod tests {
         assert!(registry.is_registered("Transform2D"));
         assert!(registry.is_registered("Sprite"));
         assert!(registry.is_registered("SpriteAnimation"));
+        assert!(registry.is_registered("SpriteMask"));
+        assert!(registry.is_registered("Animator"));
         assert!(registry.is_registered("Camera"));
         assert!(registry.is_registered("Name"));
+        assert!(registry.is_registered("Guid"));
         assert!(registry.is_registered("AudioSource"));
         assert!(registry.is_registered("AudioListener"));
         assert!(registry.is_registered("Tilemap"));
+        assert!(registry.is_registered("Trail2D"));
+        assert!(registry.is_registered("WorldText"));
         assert!(registry.is_registered("PlaySoundEffect"));
     }
 
@@ -304,4 +405,28 @@ mod tests {
         assert!(source.spatial);
         assert!((source.max_distance - 800.0).abs() < f32::EPSILON);
     }
+
+    #[test]
+    fn test_runtime_registered_component_inserts_into_world() {
+        define_component! {
+            /// Component only known after runtime registration
+            pub struct PluginHealth {
+                pub hp: i32 = 10,
+            }
+        }
+
+        assert!(!global_registry().is_registered("PluginHealth"));
+        register_global_component::<PluginHealth>();
+        assert!(global_registry().is_registered("PluginHealth"));
+
+        let mut world = World::new();
+        let entity = world.create_entity();
+        global_registry()
+            .insert_component(&mut world, entity, "PluginHealth", serde_json::json!({ "hp": 3 }))
+            .expect("registered component should insert");
+        assert_eq!(world.get::<PluginHealth>(entity).map(|h| h.hp), Some(3));
+
+        let err = global_registry().insert_component(&mut world, entity, "Missing", serde_json::json!({}));
+        assert!(err.is_err());
+    }
 }
@@ -1,25 +1,28 @@
 //! Fluent entity builder for creating entities with components.
 
+use crate::bundle::Bundle;
 use crate::component::Component;
 use crate::entity::EntityId;
 use crate::world::World;
 
 /// A builder for creating entities with components in a fluent style.
 ///
-/// Created via [`World::spawn()`]. Components are added immediately
-/// on each `.with()` call. Call `.id()` to get the entity ID.
+/// Created via [`World::spawn()`], which adds the initial [`Bundle`].
+/// Components are added immediately on each `.with()` / `.insert()` call,
+/// which suits components that are only added conditionally. Call `.id()`
+/// to get the entity ID.
 ///
 /// # Example
 /// ```
 /// # use ecs::{World, Transform2D, Sprite, Name};
 /// # use glam::Vec2;
 /// # let mut world = World::new();
-/// # let (pos, tex) = (Vec2::ZERO, 0);
-/// let entity = world.spawn()
-///     .with(Transform2D::new(pos))
-///     .with(Sprite::new(tex))
-///     .with(Name::new("player"))
-///     .id();
+/// # let (pos, tex, named) = (Vec2::ZERO, 0, true);
+/// let mut builder = world.spawn((Transform2D::new(pos), Sprite::new(tex)));
+/// if named {
+///     builder = builder.with(Name::new("player"));
+/// }
+/// let entity = builder.id();
 /// # assert!(world.has_component::<Name>(&entity).unwrap());
 /// ```
 pub struct EntityBuilder<'w> {
@@ -41,6 +44,14 @@ impl<'w> EntityBuilder<'w> {
         self
     }
 
+    /// Add every component of a bundle to the entity being built.
+    pub fn insert<B: Bundle>(self, bundle: B) -> Self {
+        if let Err(e) = bundle.insert_into(self.world, self.entity_id) {
+            log::error!("EntityBuilder::insert failed for entity {}: {}", self.entity_id, e);
+        }
+        self
+    }
+
     /// Finish building and return the entity ID.
     pub fn id(self) -> EntityId {
         self.entity_id
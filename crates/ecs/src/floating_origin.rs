@@ -0,0 +1,243 @@
+//! Floating origin for large worlds.
+//!
+//! `Transform2D` positions are f32, which resolves about a hundredth of a
+//! unit at 100k units from the origin: far-off sprites wobble as the
+//! camera moves and physics contacts jitter. Rather than widen every
+//! position, the world is kept near the origin: once the camera strays
+//! past a threshold, [`shift_world_origin`] moves everything back by the
+//! camera's offset and adds that offset to the [`WorldOrigin`] resource,
+//! which holds where local `(0, 0)` sits in f64 absolute coordinates.
+//! Convert with [`WorldOrigin::to_absolute`] / [`WorldOrigin::to_local`]
+//! to save, network or compare positions across shifts.
+//!
+//! The shift moves root transforms (children are relative to them), world
+//! transforms, cameras, trail points and the areas of [`WorldBounds`] and
+//! [`KillZone`]. Parallax roots move by their scroll factor, so they stay
+//! put on screen. Anything else a game keeps in world coordinates — a
+//! patrol waypoint in a behavior, a cached target — should move by the
+//! [`OriginShifted`] event emitted with every shift. The engine shifts its
+//! own physics and particles alongside (`GameConfig::with_floating_origin`).
+//! Entities spawned later from absolute coordinates — a streamed chunk, a
+//! loaded save — are brought to the current origin with
+//! [`place_at_origin`].
+//!
+//! ```
+//! use ecs::{shift_world_origin, Transform2D, World, WorldOrigin};
+//! use glam::{DVec2, Vec2};
+//!
+//! let mut world = World::new();
+//! let ship = world.spawn((Transform2D::new(Vec2::new(250_010.0, 0.0)),)).id();
+//!
+//! shift_world_origin(&mut world, Vec2::new(250_000.0, 0.0));
+//! assert_eq!(world.get::<Transform2D>(ship).map(|t| t.position), Some(Vec2::new(10.0, 0.0)));
+//! let origin = world.resource::<WorldOrigin>().copied().unwrap_or_default();
+//! assert_eq!(origin.to_absolute(Vec2::new(10.0, 0.0)), DVec2::new(250_010.0, 0.0));
+//! ```
+
+use glam::{DVec2, Vec2};
+use serde::{Deserialize, Serialize};
+
+use crate::bounds::{KillZone, WorldBounds};
+use crate::entity::EntityId;
+use crate::hierarchy::{GlobalTransform2D, Parent};
+use crate::parallax::ParallaxLayer;
+use crate::query::Single;
+use crate::sprite_components::{Camera, Transform2D};
+use crate::trail::Trail2D;
+use crate::world::World;
+
+/// Resource: where local `(0, 0)` sits in absolute world coordinates.
+/// Absent (or default) until the first shift.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct WorldOrigin {
+    /// Absolute position of the local origin
+    pub offset: DVec2,
+}
+
+impl WorldOrigin {
+    /// Absolute coordinates of a local position.
+    pub fn to_absolute(&self, local: Vec2) -> DVec2 {
+        self.offset + local.as_dvec2()
+    }
+
+    /// Local position of absolute coordinates (precise near the origin).
+    pub fn to_local(&self, absolute: DVec2) -> Vec2 {
+        (absolute - self.offset).as_vec2()
+    }
+}
+
+/// Event: the world moved by `-shift` (the origin moved by `shift`).
+/// Subtract `shift` from any world position held outside the ECS.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OriginShifted {
+    /// How far the origin moved, in local units
+    pub shift: Vec2,
+}
+
+/// Move the origin to the local point `shift`: everything in the world
+/// moves by `-shift`, [`WorldOrigin`] absorbs it, and an [`OriginShifted`]
+/// event is emitted. A zero shift does nothing.
+pub fn shift_world_origin(world: &mut World, shift: Vec2) {
+    if shift == Vec2::ZERO || !shift.is_finite() {
+        return;
+    }
+
+    for entity in world.query_entities::<Single<Transform2D>>() {
+        if world.get::<Parent>(entity).is_none() {
+            let delta = root_shift(world, entity, shift);
+            if let Some(transform) = world.get_mut::<Transform2D>(entity) {
+                transform.position -= delta;
+            }
+        }
+    }
+    for entity in world.query_entities::<Single<GlobalTransform2D>>() {
+        let delta = root_shift(world, root_of(world, entity), shift);
+        if let Some(global) = world.get_mut::<GlobalTransform2D>(entity) {
+            global.position -= delta;
+        }
+    }
+    for (_, camera) in world.iter_mut::<Camera>() {
+        camera.position -= shift;
+    }
+    for (_, trail) in world.iter_mut::<Trail2D>() {
+        trail.translate(-shift);
+    }
+    for (_, bounds) in world.iter_mut::<WorldBounds>() {
+        bounds.area = bounds.area.translate(-shift);
+    }
+    for (_, zone) in world.iter_mut::<KillZone>() {
+        zone.area = zone.area.translate(-shift);
+    }
+
+    let mut origin = world.resource::<WorldOrigin>().copied().unwrap_or_default();
+    origin.offset += shift.as_dvec2();
+    world.insert_resource(origin);
+    world.emit_event(OriginShifted { shift });
+}
+
+/// Move `entities`, spawned at absolute coordinates, to where they belong
+/// relative to the current [`WorldOrigin`] — as if they had been in the
+/// world for every shift so far. Does nothing before the first shift.
+pub fn place_at_origin(world: &mut World, entities: &[EntityId]) {
+    let offset = world.resource::<WorldOrigin>().map_or(DVec2::ZERO, |origin| origin.offset);
+    if offset == DVec2::ZERO {
+        return;
+    }
+    // In f64, so far-off absolute positions land precisely near the origin
+    let moved = |position: Vec2, factor: Vec2| (position.as_dvec2() - offset * factor.as_dvec2()).as_vec2();
+
+    for &entity in entities {
+        let root = root_of(world, entity);
+        let factor = world.get::<ParallaxLayer>(root).map_or(Vec2::ONE, |layer| layer.scroll_factor);
+        if world.get::<Parent>(entity).is_none() {
+            if let Some(transform) = world.get_mut::<Transform2D>(entity) {
+                transform.position = moved(transform.position, factor);
+            }
+        }
+        if let Some(global) = world.get_mut::<GlobalTransform2D>(entity) {
+            global.position = moved(global.position, factor);
+        }
+        if let Some(camera) = world.get_mut::<Camera>(entity) {
+            camera.position = moved(camera.position, Vec2::ONE);
+        }
+        let shift = offset.as_vec2();
+        if let Some(trail) = world.get_mut::<Trail2D>(entity) {
+            trail.translate(-shift);
+        }
+        if let Some(bounds) = world.get_mut::<WorldBounds>(entity) {
+            bounds.area = bounds.area.translate(-shift);
+        }
+        if let Some(zone) = world.get_mut::<KillZone>(entity) {
+            zone.area = zone.area.translate(-shift);
+        }
+    }
+}
+
+/// How far a hierarchy rooted at `root` moves for an origin `shift`: all
+/// of it, or its parallax layer's scroll factor of it, since the camera's
+/// move already carries the rest of the layer's rendered position.
+fn root_shift(world: &World, root: EntityId, shift: Vec2) -> Vec2 {
+    world.get::<ParallaxLayer>(root).map_or(shift, |layer| shift * layer.scroll_factor)
+}
+
+fn root_of(world: &World, entity: EntityId) -> EntityId {
+    let mut current = entity;
+    while let Some(parent) = world.get::<Parent>(current) {
+        current = parent.entity();
+    }
+    current
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Rect;
+
+    #[test]
+    fn test_shift_moves_roots_but_not_children_and_tracks_the_origin() {
+        let mut world = World::new();
+        let root = world.spawn((Transform2D::new(Vec2::new(100_050.0, 20.0)),)).id();
+        let child = world.spawn((Transform2D::new(Vec2::new(5.0, 0.0)), Parent::new(root))).id();
+        let zone = world.spawn((KillZone::despawn(Rect::new(100_000.0, -50.0, 10.0, 10.0)),)).id();
+
+        shift_world_origin(&mut world, Vec2::new(100_000.0, 0.0));
+        shift_world_origin(&mut world, Vec2::new(0.0, 20.0));
+
+        let position = |entity| world.get::<Transform2D>(entity).map(|t| t.position);
+        assert_eq!(position(root), Some(Vec2::new(50.0, 0.0)));
+        assert_eq!(position(child), Some(Vec2::new(5.0, 0.0)), "children stay relative to their root");
+        assert_eq!(world.get::<KillZone>(zone).map(|z| (z.area.x, z.area.y)), Some((0.0, -70.0)));
+
+        let origin = world.resource::<WorldOrigin>().copied().unwrap_or_default();
+        assert_eq!(origin.offset, DVec2::new(100_000.0, 20.0));
+        assert_eq!(origin.to_local(DVec2::new(100_050.0, 20.0)), Vec2::new(50.0, 0.0));
+        assert_eq!(world.read_events::<OriginShifted>().len(), 2);
+    }
+
+    #[test]
+    fn test_place_at_origin_matches_a_shifted_world() {
+        let mut world = World::new();
+        let old = world.spawn((Transform2D::new(Vec2::new(100_010.0, 0.0)),)).id();
+        shift_world_origin(&mut world, Vec2::new(100_000.0, 0.0));
+
+        // Spawned later from absolute scene data
+        let new = world.spawn((Transform2D::new(Vec2::new(100_010.0, 0.0)),)).id();
+        let child = world.spawn((Transform2D::new(Vec2::new(5.0, 0.0)), Parent::new(new))).id();
+        place_at_origin(&mut world, &[new, child]);
+
+        let position = |entity| world.get::<Transform2D>(entity).map(|t| t.position);
+        assert_eq!(position(new), position(old));
+        assert_eq!(position(child), Some(Vec2::new(5.0, 0.0)));
+    }
+
+    #[test]
+    fn test_parked_pool_entities_shift_too() {
+        let mut world = World::new();
+        let mut pool = crate::EntityPool::new(&mut world, 1, (Transform2D::new(Vec2::new(5_000.0, 0.0)),));
+
+        shift_world_origin(&mut world, Vec2::new(5_000.0, 0.0));
+        let bullet = pool.acquire(&mut world);
+        assert_eq!(world.get::<Transform2D>(bullet).map(|t| t.position), Some(Vec2::ZERO));
+    }
+
+    #[test]
+    fn test_parallax_roots_shift_by_their_scroll_factor() {
+        let mut world = World::new();
+        let sky = world.spawn((Transform2D::new(Vec2::ZERO), ParallaxLayer::new(0.0))).id();
+        let hills = world.spawn((Transform2D::new(Vec2::ZERO), ParallaxLayer::new(0.5))).id();
+        let camera_before = Vec2::new(1_000.0, 0.0);
+        let rendered = |world: &World, entity, camera| {
+            let position = world.get::<Transform2D>(entity).map_or(Vec2::NAN, |t| t.position);
+            world.get::<ParallaxLayer>(entity).map(|layer| layer.rendered_position(position, camera))
+        };
+        let before = [rendered(&world, sky, camera_before), rendered(&world, hills, camera_before)];
+
+        shift_world_origin(&mut world, camera_before);
+
+        // Relative to the (now centered) camera, both layers are where they were
+        let after = [rendered(&world, sky, Vec2::ZERO), rendered(&world, hills, Vec2::ZERO)];
+        for (before, after) in before.into_iter().zip(after) {
+            assert_eq!(after.zip(before).map(|(a, b)| a - (b - camera_before)), Some(Vec2::ZERO));
+        }
+    }
+}
@@ -0,0 +1,227 @@
+//! Persistent entity identity for cross-scene references.
+//!
+//! [`EntityId`]s are runtime values: they change every time a scene is
+//! loaded. Every entity also gets a [`Guid`] component when it is created;
+//! the GUID is saved with the scene and restored on load, so it names the
+//! same entity across save/load, play-mode snapshots, and streaming chunks.
+//!
+//! Components that point at other entities store an [`EntityRef`] (which
+//! serializes as the target's GUID) and resolve it through the world's
+//! GUID index:
+//!
+//! ```
+//! use ecs::{EntityRef, Guid, World};
+//!
+//! let mut world = World::new();
+//! let door = world.create_entity();
+//! let lever = world.create_entity();
+//!
+//! // The lever remembers its door by GUID, not by runtime id
+//! let target = EntityRef::to(&world, door).unwrap();
+//! assert_eq!(target.resolve(&world), Some(door));
+//!
+//! // A loaded scene re-assigns the saved GUID to a fresh entity
+//! let saved: Guid = world.guid_of(door).unwrap();
+//! world.remove_entity(&door).unwrap();
+//! assert_eq!(target.resolve(&world), None);
+//! let reloaded = world.create_entity();
+//! world.add_component(&reloaded, saved).unwrap();
+//! assert_eq!(target.resolve(&world), Some(reloaded));
+//! # let _ = lever;
+//! ```
+
+use std::collections::hash_map::RandomState;
+use std::fmt;
+use std::hash::BuildHasher;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use common::time::{SystemTime, UNIX_EPOCH};
+use ecs_macros::ComponentMeta as DeriveComponentMeta;
+use serde::{Deserialize, Serialize};
+
+use crate::component_registry::ComponentMeta;
+use crate::entity::EntityId;
+use crate::world::World;
+
+/// Component: a globally unique, persistent entity identifier.
+///
+/// Assigned automatically by `World::create_entity`; scene loading and
+/// snapshot restore replace it with the saved value via `add_component`.
+/// Treat it as immutable — mutating it through `get_mut` bypasses the
+/// world's GUID index (replace it with `add_component` instead).
+///
+/// Serializes as a hyphenated hex string
+/// (`"6f1c2a9e-03b4-4d52-9e1a-7c0d5b8f2e41"`).
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize, DeriveComponentMeta)]
+#[serde(into = "String", try_from = "String")]
+pub struct Guid(u128);
+
+impl Guid {
+    /// Generate a new random GUID (version-4 layout).
+    pub fn new() -> Self {
+        static COUNTER: AtomicU64 = AtomicU64::new(0);
+        let count = COUNTER.fetch_add(1, Ordering::Relaxed);
+        let nanos = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_nanos());
+        // RandomState is seeded from the OS per process; hashing a counter
+        // and the clock with two fresh states yields 128 unpredictable bits.
+        let high = RandomState::new().hash_one((count, nanos));
+        let low = RandomState::new().hash_one((nanos, count, high));
+        Self::version_4((u128::from(high) << 64) | u128::from(low))
+    }
+
+    /// The GUID this one takes inside the nested-scene instance owned by
+    /// `owner`. Deterministic, so a scene embedded twice gets distinct
+    /// GUIDs per instance that still come back the same on every load.
+    pub fn nested_in(self, owner: Guid) -> Self {
+        // 128-bit FNV-1a over both values
+        const OFFSET: u128 = 0x6c62272e07bb014262b821756295c58d;
+        const PRIME: u128 = 0x0000000001000000000000000000013b;
+        let bytes = owner.0.to_le_bytes().into_iter().chain(self.0.to_le_bytes());
+        Self::version_4(bytes.fold(OFFSET, |hash, byte| (hash ^ u128::from(byte)).wrapping_mul(PRIME)))
+    }
+
+    /// `bits` with the version-4 / RFC 4122 variant markers set.
+    const fn version_4(bits: u128) -> Self {
+        Self((bits & !(0xf << 76) & !(0b11 << 62)) | (0x4 << 76) | (0b10 << 62))
+    }
+
+    /// GUID with the given raw bits (tests, fixed well-known ids).
+    pub const fn from_u128(bits: u128) -> Self {
+        Self(bits)
+    }
+
+    /// Raw 128-bit value.
+    pub const fn as_u128(self) -> u128 {
+        self.0
+    }
+}
+
+impl Default for Guid {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for Guid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let v = self.0;
+        write!(
+            f,
+            "{:08x}-{:04x}-{:04x}-{:04x}-{:012x}",
+            v >> 96,
+            (v >> 80) & 0xffff,
+            (v >> 64) & 0xffff,
+            (v >> 48) & 0xffff,
+            v & 0xffff_ffff_ffff
+        )
+    }
+}
+
+impl fmt::Debug for Guid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Guid({self})")
+    }
+}
+
+impl FromStr for Guid {
+    type Err = String;
+
+    /// Parse a GUID, with or without hyphens.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let hex: String = s.chars().filter(|c| *c != '-').collect();
+        if hex.len() != 32 {
+            return Err(format!("invalid GUID '{s}': expected 32 hex digits"));
+        }
+        u128::from_str_radix(&hex, 16)
+            .map(Self)
+            .map_err(|e| format!("invalid GUID '{s}': {e}"))
+    }
+}
+
+impl From<Guid> for String {
+    fn from(guid: Guid) -> Self {
+        guid.to_string()
+    }
+}
+
+impl TryFrom<String> for Guid {
+    type Error = String;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+/// A serializable reference to another entity, stored by [`Guid`].
+///
+/// Unlike an [`EntityId`], it survives save/load: resolve it against the
+/// world each time it is used.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct EntityRef(Guid);
+
+impl EntityRef {
+    /// Reference the entity with `guid`.
+    pub fn new(guid: Guid) -> Self {
+        Self(guid)
+    }
+
+    /// Reference `entity`, or `None` if it is dead or has no GUID.
+    pub fn to(world: &World, entity: EntityId) -> Option<Self> {
+        world.guid_of(entity).map(Self)
+    }
+
+    /// The referenced entity's GUID.
+    pub fn guid(self) -> Guid {
+        self.0
+    }
+
+    /// The live entity this refers to, if it exists in `world`.
+    pub fn resolve(self, world: &World) -> Option<EntityId> {
+        world.entity_by_guid(self.0)
+    }
+}
+
+impl From<Guid> for EntityRef {
+    fn from(guid: Guid) -> Self {
+        Self(guid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_guids_are_unique_version_4() {
+        let guids: std::collections::HashSet<Guid> = (0..1000).map(|_| Guid::new()).collect();
+        assert_eq!(guids.len(), 1000);
+        let guid = Guid::new().to_string();
+        assert_eq!(guid.len(), 36);
+        assert_eq!(&guid[14..15], "4", "version nibble");
+        assert!("89ab".contains(&guid[19..20]), "variant bits");
+    }
+
+    #[test]
+    fn test_nested_guids_are_deterministic_per_owner() {
+        let guid = Guid::from_u128(7);
+        let (a, b) = (Guid::from_u128(1), Guid::from_u128(2));
+        assert_eq!(guid.nested_in(a), guid.nested_in(a));
+        assert_ne!(guid.nested_in(a), guid.nested_in(b));
+        assert_ne!(guid.nested_in(a), guid);
+        assert_eq!(&guid.nested_in(a).to_string()[14..15], "4", "version nibble");
+    }
+
+    #[test]
+    fn test_guid_string_round_trip() {
+        let guid = Guid::from_u128(0x6f1c2a9e_03b4_4d52_9e1a_7c0d5b8f2e41);
+        assert_eq!(guid.to_string(), "6f1c2a9e-03b4-4d52-9e1a-7c0d5b8f2e41");
+        assert_eq!("6f1c2a9e03b44d529e1a7c0d5b8f2e41".parse(), Ok(guid));
+        assert!("not-a-guid".parse::<Guid>().is_err());
+
+        let ron_str = ron::to_string(&EntityRef::new(guid)).unwrap();
+        assert_eq!(ron_str, "\"6f1c2a9e-03b4-4d52-9e1a-7c0d5b8f2e41\"");
+        assert_eq!(ron::from_str::<EntityRef>(&ron_str).unwrap().guid(), guid);
+    }
+}
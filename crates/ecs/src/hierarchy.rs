@@ -93,9 +93,40 @@ impl Children {
     }
 }
 
+/// Marker component for an organizational group (folder) node
+///
+/// A group exists only to organize the hierarchy: it carries a name and an
+/// identity `Transform2D` so its children keep their placement, and nothing
+/// else. The editor draws it as a folder and creates one around the
+/// selection with Group Selection (Ctrl+G); scene files keep it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Group;
+
+/// Component embedding another scene file under this entity
+///
+/// Like a prefab, but a whole scene: the scene loader instantiates the
+/// referenced file's entities as children of this entity, so its
+/// `Transform2D` places the nested scene, and refuses reference cycles.
+/// `path` is relative to the directory of the scene file holding the
+/// reference. Saving writes the reference only, never the nested entities;
+/// the editor shows the entity as a single collapsed node.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SceneInstanceRef {
+    /// Scene file to instantiate, relative to the referencing scene's directory
+    pub path: String,
+}
+
+impl SceneInstanceRef {
+    /// Reference the scene file at `path`.
+    pub fn new(path: impl Into<String>) -> Self {
+        Self { path: path.into() }
+    }
+}
+
 /// Component storing the computed world-space transform
 ///
-/// This is automatically updated by the TransformHierarchySystem. For root entities
+/// This is automatically inserted and updated by the TransformHierarchySystem on
+/// every entity with a Transform2D, so it never needs adding by hand. For root entities
 /// (those without a Parent), this equals their local Transform2D. For child entities,
 /// this is the result of multiplying the parent's GlobalTransform2D with the child's
 /// local Transform2D.
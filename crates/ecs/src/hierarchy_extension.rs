@@ -104,6 +104,7 @@ pub trait WorldHierarchyExt {
     /// Remove an entity and all its descendants from the hierarchy.
     ///
     /// This recursively removes all children and their children, etc.
+    /// Same as [`World::despawn_recursive`].
     ///
     /// # Errors
     /// Returns an error if the entity doesn't exist.
@@ -246,18 +247,7 @@ impl WorldHierarchyExt for World {
     }
 
     fn remove_entity_hierarchy(&mut self, entity: &EntityId) -> Result<(), EcsError> {
-        // First, collect all descendants (we need to do this before we start removing)
-        let descendants = self.get_descendants(*entity);
-
-        // Remove descendants in reverse order (deepest first)
-        for descendant in descendants.into_iter().rev() {
-            self.remove_entity(&descendant)?;
-        }
-
-        // Remove the entity itself (remove_entity unlinks it from its parent)
-        self.remove_entity(entity)?;
-
-        Ok(())
+        self.despawn_recursive(*entity)
     }
 }
 
@@ -44,11 +44,14 @@ struct CachedNode {
 ///
 /// # Usage
 ///
-/// Add this system to your world and ensure entities have:
+/// The engine runs this system every frame after the game's update, so games
+/// don't add it themselves. Entities need:
 /// - `Transform2D` - local transform relative to parent (or world if no parent)
-/// - `GlobalTransform2D` - will be computed automatically
 /// - `Parent` (optional) - if present, transform is relative to parent
 ///
+/// `GlobalTransform2D` is inserted on every entity with a `Transform2D` that
+/// lacks one and kept up to date from then on; there is no need to add it.
+///
 /// # Example
 ///
 /// ```
@@ -61,12 +64,10 @@ struct CachedNode {
 /// // Create parent
 /// let parent = world.create_entity();
 /// world.add_component(&parent, Transform2D::new(Vec2::new(100.0, 0.0))).ok();
-/// world.add_component(&parent, GlobalTransform2D::default()).ok();
 ///
 /// // Create child
 /// let child = world.create_entity();
 /// world.add_component(&child, Transform2D::new(Vec2::new(50.0, 0.0))).ok();
-/// world.add_component(&child, GlobalTransform2D::default()).ok();
 /// world.set_parent(child, parent).ok();
 ///
 /// // After update, child's GlobalTransform2D.position will be (150.0, 0.0)
@@ -437,6 +438,21 @@ mod tests {
         assert!((child_global.scale.y - 2.0).abs() < 0.001);
     }
 
+    #[test]
+    fn test_global_transform_inserted_without_manual_bookkeeping() {
+        let mut world = World::new();
+        let parent = world.spawn((Transform2D::new(Vec2::new(100.0, 0.0)),)).id();
+        let child = world.spawn((Transform2D::new(Vec2::new(0.0, 25.0)),)).id();
+        world.set_parent(child, parent).unwrap();
+        let untransformed = world.create_entity();
+
+        TransformHierarchySystem::new().update(&mut world, 0.016);
+
+        assert_eq!(world.get::<GlobalTransform2D>(parent).unwrap().position, Vec2::new(100.0, 0.0));
+        assert_eq!(world.get::<GlobalTransform2D>(child).unwrap().position, Vec2::new(100.0, 25.0));
+        assert!(world.get::<GlobalTransform2D>(untransformed).is_none(), "only entities with a Transform2D");
+    }
+
     #[test]
     fn test_disabled_system_does_nothing() {
         let mut world = World::new();
@@ -1,7 +1,8 @@
 //! Simple entity-component-system for the insiculous_2d game engine.
 //!
-//! This crate provides a minimal ECS implementation with HashMap-based
-//! per-type component storage.
+//! This crate provides a minimal ECS implementation with sparse-set
+//! per-type component storage: each component type is packed contiguously,
+//! so lookups are array reads and queries walk dense arrays.
 //!
 //! # Module Visibility Strategy
 //!
@@ -18,53 +19,86 @@
 //! All public types are accessible from the crate root: `use ecs::EntityId;`
 
 // Core infrastructure - private modules, re-exported at crate root
+mod bundle;
 mod component;
 mod entity;
 mod entity_builder;
 mod query;
+mod storage;
 mod world;
 
 // Domain modules - public for documentation, also re-exported at crate root
+pub mod animation;
 pub mod audio_components;
+pub mod change_detection;
+pub mod commands;
 pub mod behavior;
+pub mod bounds;
 pub mod lifetime;
 pub mod component_registry;
 pub mod event;
+pub mod floating_origin;
 pub mod generation;
+pub mod guid;
 pub mod hierarchy;
 pub mod hierarchy_extension;
 pub mod hierarchy_system;
+pub mod parallax;
+pub mod pool;
+pub mod render_layers;
 pub mod resource;
 pub mod sprite_components;
+pub mod sprite_sort;
 pub mod sprite_system;
 pub mod state_machine;
 pub mod system;
 pub mod tilemap;
+pub mod trail;
+pub mod validation;
+pub mod world_text;
 
 pub mod prelude;
 
 // Re-export all public items at crate root for convenient access
 pub use query::*;
+pub use bundle::Bundle;
+pub use animation::{
+    AnimationClip, AnimationCurve, AnimationTarget, AnimationTrack, Animator, AnimatorSystem, Interpolation, Keyframe,
+    PlaybackMode,
+};
 pub use audio_components::*;
+pub use commands::Commands;
+pub use change_detection::{Added, Changed, ComponentTicks, Or, QueryFilter};
 pub use behavior::*;
+pub use bounds::{BoundsMode, BoundsSystem, KillZone, KillZoneAction, KillZoneEntered, WorldBounds};
 pub use lifetime::{Lifetime, LifetimeSystem};
 pub use component::*;
+pub use storage::ComponentStore;
 pub use component_registry::{global_registry, ComponentMeta};
 pub use ecs_macros::ComponentMeta as DeriveComponentMeta;
 pub use entity::*;
 pub use entity_builder::*;
 pub use event::EventBus;
+pub use floating_origin::{place_at_origin, shift_world_origin, OriginShifted, WorldOrigin};
 pub use generation::*;
+pub use guid::{EntityRef, Guid};
 pub use hierarchy::*;
 pub use hierarchy_extension::*;
 pub use hierarchy_system::*;
-pub use resource::ResourceStorage;
+pub use pool::{EntityPool, Inactive};
+pub use parallax::{effective_parallax, ParallaxLayer, MAX_PARALLAX_TILES};
+pub use render_layers::{RenderLayers, DEFAULT_LAYER, LAYER_DEPTH_SPAN};
+pub use resource::{ResourceStorage, SimulationTick};
 pub use sprite_components::*;
+pub use sprite_sort::{SortBias, SpriteSortMode, ISOMETRIC_DEPTH_PER_UNIT};
 pub use sprite_system::*;
 pub use state_machine::{HierarchicalStateMachine, StateMachine};
 pub use system::*;
 pub use tilemap::{TileInstance, Tilemap};
+pub use trail::{ColorGradient, ColorStop, Trail2D, TrailSystem};
+pub use validation::{validate_components, ComponentError, Validate};
 pub use world::*;
+pub use world_text::{TextAlignment, WorldText, WorldTextSystem};
 
 /// Initialize the ECS
 pub fn init() -> Result<World, EcsError> {
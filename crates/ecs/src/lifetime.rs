@@ -44,9 +44,9 @@ impl LifetimeSystem {
 
 impl System for LifetimeSystem {
     fn update(&mut self, world: &mut World, delta_time: f32) {
-        // query_entities returns an owned Vec, so removal during the loop
+        // query_active returns an owned Vec, so removal during the loop
         // can't invalidate the iteration.
-        let carriers: Vec<EntityId> = world.query_entities::<Single<Lifetime>>();
+        let carriers: Vec<EntityId> = world.query_active::<Single<Lifetime>>();
         for entity in carriers {
             let expired = world
                 .get_mut::<Lifetime>(entity)
@@ -0,0 +1,171 @@
+//! Parallax background layers.
+//!
+//! A [`ParallaxLayer`] on an entity (or any of its ancestors) makes its
+//! sprites follow the main camera by a fraction of its movement: a
+//! `scroll_factor` of 1 is ordinary world space, 0.5 scrolls at half speed
+//! (distant hills), 0 stays fixed on screen (the sky). Values above 1 give
+//! foreground layers that sweep past faster than the world. Tiling repeats
+//! each sprite along an axis so a single strip fills the view however far
+//! the camera travels.
+//!
+//! Only the rendered position changes — the engine's sprite extraction
+//! applies the offset every frame, so `Transform2D` keeps the authored
+//! position (where the layer sits when the camera is at the origin) and
+//! gameplay, picking and saving are unaffected.
+//!
+//! ```
+//! use ecs::{ParallaxLayer, Sprite, Transform2D, World};
+//! use glam::Vec2;
+//!
+//! let mut world = World::new();
+//! world.spawn((Transform2D::new(Vec2::ZERO), Sprite::new(3), ParallaxLayer::new(0.3).tiled_x()));
+//!
+//! let hills = ParallaxLayer::new(0.5);
+//! // The camera moved 200 units right; the hills only appear to move 100
+//! assert_eq!(hills.rendered_position(Vec2::ZERO, Vec2::new(200.0, 0.0)), Vec2::new(100.0, 0.0));
+//! ```
+
+use glam::Vec2;
+use serde::{Deserialize, Serialize};
+
+use crate::component_registry::ComponentMeta;
+use crate::entity::EntityId;
+use crate::hierarchy::Parent;
+use crate::world::World;
+use crate::DeriveComponentMeta;
+
+/// Most copies drawn per axis for a tiled layer, so a tiny sprite under a
+/// zoomed-out camera can't flood the batcher.
+pub const MAX_PARALLAX_TILES: usize = 64;
+
+/// Component: draw the entity's sprites (and its descendants') scrolled
+/// relative to the main camera (see the module docs).
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, DeriveComponentMeta)]
+pub struct ParallaxLayer {
+    /// Fraction of the camera's movement the layer follows, per axis
+    #[serde(default = "default_scroll_factor")]
+    pub scroll_factor: Vec2,
+    /// Repeat horizontally to fill the view
+    #[serde(default)]
+    pub tile_x: bool,
+    /// Repeat vertically to fill the view
+    #[serde(default)]
+    pub tile_y: bool,
+}
+
+fn default_scroll_factor() -> Vec2 {
+    Vec2::ONE
+}
+
+impl Default for ParallaxLayer {
+    fn default() -> Self {
+        Self::with_factor(Vec2::ONE)
+    }
+}
+
+impl ParallaxLayer {
+    /// A layer scrolling at `factor` of the camera's speed on both axes.
+    pub fn new(factor: f32) -> Self {
+        Self::with_factor(Vec2::splat(factor))
+    }
+
+    /// A layer with separate horizontal and vertical scroll factors (e.g.
+    /// `(0.5, 1.0)` for a side-scroller whose vertical camera moves should
+    /// not shift the backdrop out of place).
+    pub fn with_factor(scroll_factor: Vec2) -> Self {
+        Self { scroll_factor, tile_x: false, tile_y: false }
+    }
+
+    /// Repeat the layer horizontally.
+    pub fn tiled_x(mut self) -> Self {
+        self.tile_x = true;
+        self
+    }
+
+    /// Repeat the layer vertically.
+    pub fn tiled_y(mut self) -> Self {
+        self.tile_y = true;
+        self
+    }
+
+    /// Where a sprite authored at `position` is drawn with the camera at
+    /// `camera_position`.
+    pub fn rendered_position(&self, position: Vec2, camera_position: Vec2) -> Vec2 {
+        position + camera_position * (Vec2::ONE - self.scroll_factor)
+    }
+
+    /// Centers of the copies of a `size`-sized sprite drawn at `rendered`
+    /// that cover the view rectangle `view_center ± view_half`. Untiled
+    /// axes (and degenerate sizes) keep the single `rendered` coordinate;
+    /// each tiled axis is capped at [`MAX_PARALLAX_TILES`] copies.
+    pub fn tile_positions(&self, rendered: Vec2, size: Vec2, view_center: Vec2, view_half: Vec2) -> Vec<Vec2> {
+        let xs = tile_axis(self.tile_x, rendered.x, size.x.abs(), view_center.x, view_half.x);
+        let ys = tile_axis(self.tile_y, rendered.y, size.y.abs(), view_center.y, view_half.y);
+        ys.iter().flat_map(|&y| xs.iter().map(move |&x| Vec2::new(x, y))).collect()
+    }
+}
+
+/// Copy centers along one axis.
+fn tile_axis(tiled: bool, rendered: f32, size: f32, center: f32, half: f32) -> Vec<f32> {
+    if !tiled || !size.is_finite() || size <= f32::EPSILON || !half.is_finite() {
+        return vec![rendered];
+    }
+    // Copy nearest the view center, then enough on each side to reach the
+    // view edges (plus one so partly visible copies are included)
+    let nearest = rendered + ((center - rendered) / size).round() * size;
+    let reach = ((half / size).ceil() as usize + 1).min(MAX_PARALLAX_TILES / 2);
+    (0..=reach * 2).map(|i| nearest + (i as f32 - reach as f32) * size).collect()
+}
+
+/// The [`ParallaxLayer`] governing `entity`: its own, or its nearest
+/// ancestor's.
+pub fn effective_parallax(world: &World, entity: EntityId) -> Option<&ParallaxLayer> {
+    let mut current = Some(entity);
+    while let Some(candidate) = current {
+        if let Some(layer) = world.get::<ParallaxLayer>(candidate) {
+            return Some(layer);
+        }
+        current = world.get::<Parent>(candidate).map(|parent| parent.entity());
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::WorldHierarchyExt;
+
+    #[test]
+    fn test_parallax_offsets_by_scroll_factor() {
+        let camera = Vec2::new(100.0, 40.0);
+        assert_eq!(ParallaxLayer::new(1.0).rendered_position(Vec2::ONE, camera), Vec2::ONE);
+        assert_eq!(ParallaxLayer::new(0.0).rendered_position(Vec2::ZERO, camera), camera);
+        let side_scroller = ParallaxLayer::with_factor(Vec2::new(0.25, 1.0));
+        assert_eq!(side_scroller.rendered_position(Vec2::ZERO, camera), Vec2::new(75.0, 0.0));
+
+        // Children inherit their ancestor's layer
+        let mut world = World::new();
+        let backdrop = world.spawn((ParallaxLayer::new(0.5),)).id();
+        let tree = world.create_entity();
+        world.set_parent(tree, backdrop).unwrap();
+        assert_eq!(effective_parallax(&world, tree), Some(&ParallaxLayer::new(0.5)));
+        let loose = world.create_entity();
+        assert_eq!(effective_parallax(&world, loose), None);
+    }
+
+    #[test]
+    fn test_tiling_covers_the_view() {
+        let layer = ParallaxLayer::new(0.5).tiled_x();
+        let tiles = layer.tile_positions(Vec2::new(10.0, 5.0), Vec2::new(100.0, 50.0), Vec2::new(1000.0, 0.0), Vec2::new(150.0, 100.0));
+        assert!(tiles.iter().all(|tile| tile.y == 5.0));
+        let (left, right) = (tiles[0].x - 50.0, tiles[tiles.len() - 1].x + 50.0);
+        assert!(left <= 850.0 && right >= 1150.0, "{left}..{right} should cover the view");
+        assert!(tiles.iter().any(|tile| tile.x == 1010.0), "copies stay on the layer's grid");
+
+        // Untiled, or tiling a zero-sized sprite: just the one position
+        assert_eq!(ParallaxLayer::new(0.5).tile_positions(Vec2::ONE, Vec2::ONE, Vec2::ZERO, Vec2::splat(10.0)), vec![Vec2::ONE]);
+        assert_eq!(layer.tile_positions(Vec2::ONE, Vec2::ZERO, Vec2::ZERO, Vec2::splat(10.0)).len(), 1);
+        let tiny = layer.tile_positions(Vec2::ZERO, Vec2::ONE, Vec2::ZERO, Vec2::splat(1.0e6));
+        assert!(tiny.len() <= MAX_PARALLAX_TILES + 1);
+    }
+}
@@ -0,0 +1,211 @@
+//! Entity pooling for games that churn short-lived entities.
+//!
+//! Spawning and despawning hundreds of bullets a second allocates component
+//! storage, GUIDs and physics bodies every time. An [`EntityPool`]
+//! pre-spawns its entities once from a template bundle and parks the unused
+//! ones with the [`Inactive`] marker. [`acquire`](EntityPool::acquire) hands
+//! one out by removing the marker; [`release`](EntityPool::release) parks it
+//! again. Nothing is despawned, so physics keeps the entity's body and only
+//! disables it while parked.
+//!
+//! Inactive entities are left out of [`World::query_active`],
+//! [`World::iter_active`] and [`World::active_entities`], and the engine's
+//! sprite, tilemap, trail, particle, behavior, animation and physics passes
+//! skip them. The plain `query_entities` / `iter` still list them, so
+//! cleanup (state-scoped despawns, origin shifts) reaches parked entities
+//! too. `Inactive` is per entity: pool root entities, not children.
+//!
+//! ```
+//! use ecs::pool::EntityPool;
+//! use ecs::{Lifetime, Transform2D, World};
+//! use glam::Vec2;
+//!
+//! let mut world = World::new();
+//! let mut bullets = EntityPool::new(&mut world, 64, (Transform2D::default(), Lifetime::new(2.0)));
+//!
+//! let bullet = bullets.acquire(&mut world);
+//! // Reset whatever the last user changed
+//! if let Some(transform) = world.get_mut::<Transform2D>(bullet) {
+//!     transform.position = Vec2::new(100.0, 0.0);
+//! }
+//! assert!(world.is_active(bullet));
+//!
+//! bullets.release(&mut world, bullet);
+//! assert!(!world.is_active(bullet));
+//! assert_eq!(bullets.available(), 64);
+//! ```
+
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+
+use crate::bundle::Bundle;
+use crate::entity::EntityId;
+use crate::world::World;
+
+/// Marker for a parked entity: still alive, but skipped by queries,
+/// systems and rendering.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Inactive;
+
+/// Pre-spawned entities built from one template bundle, handed out and
+/// taken back without despawning.
+pub struct EntityPool<B: Bundle + Clone> {
+    /// Template every pooled entity is spawned from
+    template: B,
+    /// Parked entities, reused last-in first-out
+    free: Vec<EntityId>,
+    /// Entities handed out by `acquire` and not yet released
+    active: HashSet<EntityId>,
+}
+
+impl<B: Bundle + Clone> EntityPool<B> {
+    /// Spawn `count` parked entities from `template`.
+    pub fn new(world: &mut World, count: usize, template: B) -> Self {
+        let mut pool = Self {
+            template,
+            free: Vec::with_capacity(count),
+            active: HashSet::with_capacity(count),
+        };
+        for _ in 0..count {
+            let entity = pool.spawn(world);
+            pool.free.push(entity);
+        }
+        pool
+    }
+
+    /// Spawn one parked entity from the template
+    fn spawn(&self, world: &mut World) -> EntityId {
+        world.spawn(self.template.clone()).with(Inactive).id()
+    }
+
+    /// Activate a parked entity, spawning a new one from the template when
+    /// the pool is exhausted. Components keep the values the previous user
+    /// left, so reset what matters (position, velocity, lifetime) after
+    /// acquiring. Parked entities despawned behind the pool's back are
+    /// dropped.
+    pub fn acquire(&mut self, world: &mut World) -> EntityId {
+        let entity = loop {
+            match self.free.pop() {
+                Some(entity) if world.validate_entity(&entity).is_ok() => break entity,
+                Some(_) => continue,
+                None => {
+                    log::debug!("Entity pool exhausted, growing to {}", self.len() + 1);
+                    break self.spawn(world);
+                }
+            }
+        };
+        world.remove_component::<Inactive>(&entity).ok();
+        self.active.insert(entity);
+        entity
+    }
+
+    /// Park an acquired entity again. Returns `false` (and does nothing)
+    /// when `entity` isn't currently acquired from this pool.
+    pub fn release(&mut self, world: &mut World, entity: EntityId) -> bool {
+        if !self.active.remove(&entity) {
+            return false;
+        }
+        if world.add_component(&entity, Inactive).is_err() {
+            // Despawned while acquired: nothing left to reuse
+            return false;
+        }
+        self.free.push(entity);
+        true
+    }
+
+    /// Park every acquired entity (e.g. on level restart).
+    pub fn release_all(&mut self, world: &mut World) {
+        let active: Vec<EntityId> = self.active.iter().copied().collect();
+        for entity in active {
+            self.release(world, entity);
+        }
+    }
+
+    /// Despawn every pooled entity, acquired or parked, and empty the pool.
+    pub fn despawn_all(&mut self, world: &mut World) {
+        for entity in self.free.drain(..).chain(self.active.drain()) {
+            world.remove_entity(&entity).ok();
+        }
+    }
+
+    /// Whether `entity` is currently acquired from this pool
+    pub fn is_acquired(&self, entity: EntityId) -> bool {
+        self.active.contains(&entity)
+    }
+
+    /// Parked entities ready to acquire
+    pub fn available(&self) -> usize {
+        self.free.len()
+    }
+
+    /// Entities currently acquired
+    pub fn active_count(&self) -> usize {
+        self.active.len()
+    }
+
+    /// All pooled entities, acquired or parked
+    pub fn len(&self) -> usize {
+        self.free.len() + self.active.len()
+    }
+
+    /// Whether the pool holds no entities
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::query::Single;
+    use crate::sprite_components::{Name, Transform2D};
+
+    #[test]
+    fn test_acquire_reuses_parked_entities_and_grows_when_empty() {
+        let mut world = World::new();
+        let mut pool = EntityPool::new(&mut world, 2, (Transform2D::default(), Name::new("bullet")));
+        assert_eq!(world.entity_count(), 2);
+        assert!(world.query_active::<Single<Transform2D>>().is_empty(), "parked entities are hidden");
+        assert_eq!(world.query_entities::<Single<Transform2D>>().len(), 2, "but still listed for cleanup");
+        assert_eq!(world.iter_active::<Transform2D>().count(), 0);
+
+        let first = pool.acquire(&mut world);
+        let second = pool.acquire(&mut world);
+        assert_eq!(world.query_active::<Single<Transform2D>>().len(), 2);
+        assert_eq!(pool.available(), 0);
+
+        let third = pool.acquire(&mut world);
+        assert_eq!(world.entity_count(), 3, "grew by one");
+        assert!(world.get::<Name>(third).is_some(), "grown from the template");
+
+        assert!(pool.release(&mut world, first));
+        assert!(!pool.release(&mut world, first), "already parked");
+        assert_eq!(pool.acquire(&mut world), first, "parked entity reused before growing");
+        assert_eq!(world.entity_count(), 3);
+        assert_eq!(world.active_entities().len(), 3);
+
+        pool.release_all(&mut world);
+        assert!(world.active_entities().is_empty());
+        assert!(!pool.is_acquired(second));
+        assert_eq!(pool.len(), 3);
+
+        pool.despawn_all(&mut world);
+        assert_eq!(world.entity_count(), 0);
+        assert!(pool.is_empty());
+    }
+
+    #[test]
+    fn test_entities_despawned_elsewhere_are_dropped() {
+        let mut world = World::new();
+        let mut pool = EntityPool::new(&mut world, 1, (Transform2D::default(),));
+        let parked = world.entities()[0];
+        world.remove_entity(&parked).unwrap();
+
+        let fresh = pool.acquire(&mut world);
+        assert_ne!(fresh, parked);
+        world.remove_entity(&fresh).unwrap();
+        assert!(!pool.release(&mut world, fresh));
+        assert!(pool.is_empty());
+    }
+}
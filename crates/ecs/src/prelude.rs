@@ -4,15 +4,20 @@
 //! for ergonomic imports.
 
 pub use crate::{
+    bundle::Bundle,
+    change_detection::{Added, Changed},
+    commands::Commands,
     component::Component,
     entity::{Entity, EntityId},
     entity_builder::EntityBuilder,
     event::EventBus,
+    guid::{EntityRef, Guid},
     hierarchy::{Children, GlobalTransform2D, Parent},
     hierarchy_extension::WorldHierarchyExt,
     hierarchy_system::TransformHierarchySystem,
     init,
-    resource::ResourceStorage,
+    pool::{EntityPool, Inactive},
+    resource::{ResourceStorage, SimulationTick},
     state_machine::{HierarchicalStateMachine, StateMachine},
     system::{SimpleSystem, System},
     world::World,
@@ -0,0 +1,133 @@
+//! Named render layers for sprite z-ordering.
+//!
+//! A [`Sprite`](crate::sprite_components::Sprite) names its layer; layers
+//! draw in list order (later on top), and `Sprite::depth` orders sprites
+//! within a layer. Each layer owns a band of [`LAYER_DEPTH_SPAN`] world
+//! depth units centred on its offset from the [`DEFAULT_LAYER`], so a scene
+//! without layers renders exactly as before. The order is per scene and
+//! lives in the world as a [`RenderLayers`] resource.
+
+use serde::{Deserialize, Serialize};
+
+/// The layer sprites use unless they name another; always present.
+pub const DEFAULT_LAYER: &str = "Default";
+
+/// World depth between neighbouring layers. Depths within a layer should
+/// stay within ±half of this to keep to their layer's band.
+pub const LAYER_DEPTH_SPAN: f32 = 200.0;
+
+/// The layers a scene starts with.
+const BUILTIN_LAYERS: [&str; 4] = ["Background", DEFAULT_LAYER, "Foreground", "UI"];
+
+/// Ordered render layer names, back to front (a world resource).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RenderLayers {
+    names: Vec<String>,
+}
+
+impl Default for RenderLayers {
+    fn default() -> Self {
+        Self { names: BUILTIN_LAYERS.iter().map(|name| name.to_string()).collect() }
+    }
+}
+
+impl RenderLayers {
+    /// The built-in layers: Background, Default, Foreground, UI.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Layers from back to front (as saved in a scene). Duplicates and
+    /// empty names are dropped, and [`DEFAULT_LAYER`] is added at the front
+    /// if missing; no names at all gives the built-in layers.
+    pub fn from_names(names: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        let mut layers = Self { names: Vec::new() };
+        for name in names.into_iter().map(Into::into) {
+            if !name.is_empty() && !layers.contains(&name) {
+                layers.names.push(name);
+            }
+        }
+        if layers.names.is_empty() {
+            return Self::default();
+        }
+        if !layers.contains(DEFAULT_LAYER) {
+            layers.names.insert(0, DEFAULT_LAYER.to_string());
+        }
+        layers
+    }
+
+    /// Layer names, back to front.
+    pub fn names(&self) -> &[String] {
+        &self.names
+    }
+
+    /// Whether a layer with this name exists.
+    pub fn contains(&self, name: &str) -> bool {
+        self.index_of(name).is_some()
+    }
+
+    /// Position of a layer, back to front.
+    pub fn index_of(&self, name: &str) -> Option<usize> {
+        self.names.iter().position(|n| n == name)
+    }
+
+    /// Whether these are exactly the built-in layers in the built-in order.
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Depth offset of a layer's band; unknown layers draw in the default
+    /// layer's band.
+    pub fn layer_offset(&self, name: &str) -> f32 {
+        let default = self.index_of(DEFAULT_LAYER).unwrap_or(0) as f32;
+        let index = self.index_of(name).map_or(default, |i| i as f32);
+        (index - default) * LAYER_DEPTH_SPAN
+    }
+
+    /// The world depth a sprite on `layer` with `depth` renders and picks at.
+    pub fn sort_depth(&self, layer: &str, depth: f32) -> f32 {
+        self.layer_offset(layer) + depth
+    }
+
+    /// Move a layer to `index` (clamped), shifting the others. Returns
+    /// `false` if there is no such layer.
+    pub fn move_layer(&mut self, name: &str, index: usize) -> bool {
+        let Some(from) = self.index_of(name) else {
+            return false;
+        };
+        let layer = self.names.remove(from);
+        self.names.insert(index.min(self.names.len()), layer);
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_layer_keeps_plain_depth() {
+        let layers = RenderLayers::new();
+        assert_eq!(layers.sort_depth(DEFAULT_LAYER, 3.0), 3.0);
+        assert_eq!(layers.sort_depth("Background", 3.0), 3.0 - LAYER_DEPTH_SPAN);
+        assert_eq!(layers.sort_depth("UI", 0.0), 2.0 * LAYER_DEPTH_SPAN);
+        assert_eq!(layers.sort_depth("Missing", 1.0), 1.0, "unknown layers fall back to the default band");
+    }
+
+    #[test]
+    fn test_moving_a_layer_reorders_bands() {
+        let mut layers = RenderLayers::new();
+        assert!(layers.move_layer("Background", 10));
+        assert_eq!(layers.names().last().map(String::as_str), Some("Background"));
+        assert!(layers.sort_depth("Background", 0.0) > layers.sort_depth("UI", 0.0));
+        assert!(!layers.move_layer("Missing", 0));
+        assert!(!layers.is_default());
+    }
+
+    #[test]
+    fn test_from_names_always_has_the_default_layer() {
+        let layers = RenderLayers::from_names(["Sky", "Sky", "", "Props"]);
+        assert_eq!(layers.names(), [DEFAULT_LAYER, "Sky", "Props"]);
+        assert!(RenderLayers::from_names(Vec::<String>::new()).is_default());
+    }
+}
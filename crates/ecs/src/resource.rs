@@ -21,10 +21,23 @@
 //! world.resource_mut::<Score>().unwrap().value += 10;
 //! assert_eq!(world.resource::<Score>().unwrap().value, 10);
 //! ```
+//!
+//! Systems that share a singleton can each call
+//! `world.init_resource::<T>()` (for `T: Default`); only the first inserts.
 
 use std::any::{Any, TypeId};
 use std::collections::HashMap;
 
+/// Fixed-update tick counter: monotonically increasing, advanced by the
+/// engine once per fixed update. Systems stamp events with it (physics
+/// collisions, gameplay events) and snapshots/saves record it, so state can
+/// be correlated with networked or replayed input logs.
+///
+/// Read it with [`World::simulation_tick`](crate::World::simulation_tick);
+/// a world that no engine loop drives reads tick 0.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SimulationTick(pub u64);
+
 /// Storage for typed singleton resources.
 ///
 /// Each resource type can have at most one instance. Resources are
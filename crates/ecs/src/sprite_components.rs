@@ -50,10 +50,15 @@ pub struct Sprite {
     pub scale: Vec2,
     /// Texture region (x, y, width, height) in texture coordinates [0, 1]
     pub tex_region: [f32; 4],
-    /// Color tint
+    /// Color tint, sRGB like the texture it multiplies; RGB above 1.0
+    /// overdrives into HDR
     pub color: Vec4,
-    /// Layer depth for sorting (higher values render on top)
+    /// Depth for sorting within the sprite's layer (higher values render on top)
     pub depth: f32,
+    /// Render layer name (see [`RenderLayers`](crate::render_layers::RenderLayers));
+    /// layers order sprites before depth does
+    #[serde(default = "default_layer")]
+    pub layer: String,
     /// Whether this sprite is visible (invisible sprites are skipped during rendering)
     #[serde(default = "default_visible")]
     pub visible: bool,
@@ -68,6 +73,8 @@ pub struct Sprite {
 
 fn default_visible() -> bool { true }
 
+fn default_layer() -> String { crate::render_layers::DEFAULT_LAYER.to_string() }
+
 impl Default for Sprite {
     fn default() -> Self {
         Self {
@@ -77,6 +84,7 @@ impl Default for Sprite {
             tex_region: [0.0, 0.0, 1.0, 1.0], // Full texture
             color: Vec4::ONE, // White
             depth: 0.0,
+            layer: default_layer(),
             visible: true,
             emissive: 0.0,
             texture_handle: 0,
@@ -129,6 +137,12 @@ impl Sprite {
         self
     }
 
+    /// Set render layer
+    pub fn with_layer(mut self, layer: impl Into<String>) -> Self {
+        self.layer = layer.into();
+        self
+    }
+
     /// Set visibility
     pub fn with_visible(mut self, visible: bool) -> Self {
         self.visible = visible;
@@ -145,6 +159,30 @@ impl Sprite {
 // Note: Transform2D and Camera2D are now re-exported from common crate
 // This eliminates ~170 lines of duplicated code
 
+/// A named marker on one frame of a [`SpriteAnimation`] (e.g. `"footstep"`
+/// on frame 3). Entering that frame during playback emits an
+/// [`AnimationEventFired`] on the world event bus.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AnimationEvent {
+    /// Frame index the event is attached to
+    pub frame: usize,
+    /// Event name games match on
+    pub name: String,
+}
+
+/// Emitted by [`SpriteAnimationSystem`](crate::SpriteAnimationSystem) when an
+/// animation's playback enters a frame carrying an [`AnimationEvent`] — sync
+/// footstep sounds or hit particles to the animation without polling frames.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AnimationEventFired {
+    /// Entity whose animation fired the event
+    pub entity: crate::EntityId,
+    /// The event's name
+    pub name: String,
+    /// Frame the event is attached to
+    pub frame: usize,
+}
+
 /// Sprite animation component
 #[derive(Debug, Clone, Serialize, Deserialize, DeriveComponentMeta)]
 pub struct SpriteAnimation {
@@ -160,6 +198,9 @@ pub struct SpriteAnimation {
     pub time_accumulator: f32,
     /// Texture regions for each frame [x, y, width, height]
     pub frames: Vec<[f32; 4]>,
+    /// Named per-frame events, fired when playback enters their frame
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub events: Vec<AnimationEvent>,
 }
 
 impl Default for SpriteAnimation {
@@ -171,6 +212,7 @@ impl Default for SpriteAnimation {
             loop_animation: true,
             time_accumulator: 0.0,
             frames: vec![[0.0, 0.0, 1.0, 1.0]], // Single frame covering entire texture
+            events: Vec::new(),
         }
     }
 }
@@ -191,6 +233,12 @@ impl SpriteAnimation {
         self
     }
 
+    /// Attach a named event to `frame`
+    pub fn with_event(mut self, frame: usize, name: impl Into<String>) -> Self {
+        self.events.push(AnimationEvent { frame, name: name.into() });
+        self
+    }
+
     /// Start playing the animation
     pub fn play(&mut self) {
         self.playing = true;
@@ -209,8 +257,17 @@ impl SpriteAnimation {
 
     /// Update the animation (should be called every frame)
     pub fn update(&mut self, delta_time: f32) {
+        self.update_with_events(delta_time);
+    }
+
+    /// Update the animation, returning the events of every frame entered
+    /// along the way, in order — a long step that crosses several frames
+    /// reports them all. Looping back to frame 0 enters it again; starting
+    /// playback or stopping on the last frame does not.
+    pub fn update_with_events(&mut self, delta_time: f32) -> Vec<AnimationEvent> {
+        let mut fired = Vec::new();
         if !self.playing || self.frames.is_empty() {
-            return;
+            return fired;
         }
 
         self.time_accumulator += delta_time;
@@ -226,9 +283,13 @@ impl SpriteAnimation {
                 } else {
                     self.current_frame = self.frames.len() - 1;
                     self.playing = false;
+                    continue;
                 }
             }
+            let frame = self.current_frame;
+            fired.extend(self.events.iter().filter(|event| event.frame == frame).cloned());
         }
+        fired
     }
 
     /// Get the current frame's texture region
@@ -246,6 +307,88 @@ impl SpriteAnimation {
     }
 }
 
+/// The shape a [`SpriteMask`] clips to.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Default)]
+pub enum MaskShape {
+    /// Rectangle of the mask's size
+    #[default]
+    Rect,
+    /// Ellipse inscribed in the mask's size (a circle when square)
+    Ellipse,
+    /// Alpha channel of a texture region, stretched over the mask's size
+    Texture {
+        /// Texture handle ID (resolved by the renderer)
+        texture_handle: u32,
+        /// Texture region (x, y, width, height) in texture coordinates [0, 1]
+        tex_region: [f32; 4],
+    },
+}
+
+/// Clips the sprites of this entity and all its descendants to a world-space
+/// shape — a minimap circle, a water reflection area. The mask follows the
+/// entity's transform; a descendant's own mask replaces an ancestor's.
+#[derive(Debug, Clone, Serialize, Deserialize, DeriveComponentMeta)]
+pub struct SpriteMask {
+    /// Shape to clip to
+    pub shape: MaskShape,
+    /// Mask size in local units (scaled by the entity's transform)
+    pub size: Vec2,
+    /// Mask center offset from the entity position
+    #[serde(default)]
+    pub offset: Vec2,
+    /// Show sprites only *outside* the mask
+    #[serde(default)]
+    pub invert: bool,
+}
+
+impl Default for SpriteMask {
+    fn default() -> Self {
+        Self { shape: MaskShape::Rect, size: Vec2::splat(100.0), offset: Vec2::ZERO, invert: false }
+    }
+}
+
+impl SpriteMask {
+    /// Rectangular mask of `size`
+    pub fn rect(size: Vec2) -> Self {
+        Self { size, ..Default::default() }
+    }
+
+    /// Elliptical mask inscribed in `size`
+    pub fn ellipse(size: Vec2) -> Self {
+        Self { shape: MaskShape::Ellipse, size, ..Default::default() }
+    }
+
+    /// Texture-alpha mask: `tex_region` of the texture stretched over `size`
+    pub fn texture(texture_handle: u32, tex_region: [f32; 4], size: Vec2) -> Self {
+        Self { shape: MaskShape::Texture { texture_handle, tex_region }, size, ..Default::default() }
+    }
+
+    /// Set the mask center offset
+    pub fn with_offset(mut self, offset: Vec2) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Show sprites only outside the mask
+    pub fn inverted(mut self) -> Self {
+        self.invert = !self.invert;
+        self
+    }
+}
+
+/// The [`SpriteMask`] clipping `entity`: its own, or its nearest masked
+/// ancestor's. Returns the masking entity alongside the mask.
+pub fn effective_sprite_mask(world: &crate::World, entity: crate::EntityId) -> Option<(crate::EntityId, &SpriteMask)> {
+    let mut current = Some(entity);
+    while let Some(candidate) = current {
+        if let Some(mask) = world.get::<SpriteMask>(candidate) {
+            return Some((candidate, mask));
+        }
+        current = world.get::<crate::hierarchy::Parent>(candidate).map(|parent| parent.entity());
+    }
+    None
+}
+
 // Note: Sprite and SpriteAnimation use #[derive(ComponentMeta)]
 // Transform2D and Camera need manual impls since they're from the common crate
 
@@ -301,4 +444,27 @@ mod visibility_tests {
         set_sprites_visible(&mut world, [a], true);
         assert!(world.get::<Sprite>(a).unwrap().visible);
     }
+
+    #[test]
+    fn test_effective_sprite_mask_walks_up_to_nearest_masked_ancestor() {
+        use crate::WorldHierarchyExt;
+
+        let mut world = crate::World::new();
+        let root = world.create_entity();
+        let child = world.create_entity();
+        let grandchild = world.create_entity();
+        let loose = world.create_entity();
+        world.add_component(&root, SpriteMask::ellipse(Vec2::splat(64.0))).unwrap();
+        world.set_parent(child, root).unwrap();
+        world.set_parent(grandchild, child).unwrap();
+
+        let (owner, mask) = effective_sprite_mask(&world, grandchild).unwrap();
+        assert_eq!((owner, mask.shape), (root, MaskShape::Ellipse));
+        assert!(effective_sprite_mask(&world, loose).is_none());
+
+        world.add_component(&child, SpriteMask::rect(Vec2::ONE).inverted()).unwrap();
+        let (owner, mask) = effective_sprite_mask(&world, grandchild).unwrap();
+        assert_eq!(owner, child, "nearest mask wins");
+        assert!(mask.invert);
+    }
 }
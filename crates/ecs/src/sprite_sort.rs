@@ -0,0 +1,192 @@
+//! Sprite sort modes: plain depth, or 2.5D isometric.
+//!
+//! By default sprites draw in [`RenderLayers`] order and then by
+//! `Sprite::depth`. A scene can switch to [`SpriteSortMode::Isometric`]
+//! instead (a world resource, saved with the scene): within each layer,
+//! sprites lower on screen — smaller world `y` — draw in front of the ones
+//! above them, so characters walk behind and in front of trees and walls
+//! without depth bookkeeping. `Sprite::depth` then only breaks ties.
+//!
+//! A sprite sorts at its entity's position by default, which is its centre.
+//! A [`SortBias`] moves the sort point (typically down to the feet), and for
+//! tall objects spanning several tile rows names how many, so the object
+//! sorts with the row its base stands on rather than the row its centre
+//! overlaps. With a `row_height`, sort points snap to whole rows: everything
+//! standing on one row sorts together, ties broken by `Sprite::depth`.
+//!
+//! ```
+//! use ecs::{RenderLayers, SortBias, SpriteSortMode, DEFAULT_LAYER};
+//! use glam::Vec2;
+//!
+//! let layers = RenderLayers::new();
+//! let iso = SpriteSortMode::isometric(0.0);
+//! let front = iso.sort_depth(&layers, DEFAULT_LAYER, 0.0, Vec2::new(0.0, -50.0), None);
+//! let back = iso.sort_depth(&layers, DEFAULT_LAYER, 0.0, Vec2::new(0.0, 50.0), None);
+//! assert!(front > back);
+//!
+//! // A tree centred above the player's row but rooted below it sorts in front
+//! let tree = SortBias::new(-60.0);
+//! let tree_depth = iso.sort_depth(&layers, DEFAULT_LAYER, 0.0, Vec2::new(0.0, 0.0), Some(&tree));
+//! let player = iso.sort_depth(&layers, DEFAULT_LAYER, 0.0, Vec2::new(0.0, -40.0), None);
+//! assert!(tree_depth > player);
+//! ```
+
+use glam::Vec2;
+use serde::{Deserialize, Serialize};
+
+use crate::component_registry::ComponentMeta;
+use crate::render_layers::{RenderLayers, LAYER_DEPTH_SPAN};
+use crate::DeriveComponentMeta;
+
+/// Depth gained per world unit a sprite sits lower, in isometric mode:
+/// heights within ±99,000 units keep inside their layer's band.
+pub const ISOMETRIC_DEPTH_PER_UNIT: f32 = 0.001;
+
+/// Weight of `Sprite::depth` in isometric mode, small enough that depths of
+/// up to ±10 reorder sprites at the same height but never across a unit.
+const ISOMETRIC_TIE_BREAK: f32 = 0.000_05;
+
+/// How sprites within a render layer are ordered (a world resource; absent
+/// means [`Depth`](Self::Depth)).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub enum SpriteSortMode {
+    /// By `Sprite::depth` (higher on top)
+    #[default]
+    Depth,
+    /// By world height: lower sprites draw in front (see the module docs)
+    Isometric {
+        /// Height of a tile row; sort points snap to whole rows when
+        /// positive. 0 sorts by exact height.
+        #[serde(default)]
+        row_height: f32,
+    },
+}
+
+impl SpriteSortMode {
+    /// Isometric sorting, snapped to rows of `row_height` (0 for exact
+    /// heights).
+    pub fn isometric(row_height: f32) -> Self {
+        Self::Isometric { row_height }
+    }
+
+    /// Whether this is [`Isometric`](Self::Isometric).
+    pub fn is_isometric(&self) -> bool {
+        matches!(self, Self::Isometric { .. })
+    }
+
+    /// Whether this is the default [`Depth`](Self::Depth) mode.
+    pub fn is_depth(&self) -> bool {
+        *self == Self::Depth
+    }
+
+    /// The world depth a sprite on `layer` with `depth`, whose entity sits
+    /// at `position` with an optional `bias`, renders and picks at.
+    pub fn sort_depth(
+        &self,
+        layers: &RenderLayers,
+        layer: &str,
+        depth: f32,
+        position: Vec2,
+        bias: Option<&SortBias>,
+    ) -> f32 {
+        let Self::Isometric { row_height } = *self else {
+            return layers.sort_depth(layer, depth);
+        };
+        let sort_y = bias.map_or(position.y, |bias| bias.sort_y(position.y, row_height));
+        let sort_y = if row_height > 0.0 { (sort_y / row_height).floor() * row_height } else { sort_y };
+        let half_band = LAYER_DEPTH_SPAN * 0.5 - 1.0;
+        let in_band = (-sort_y * ISOMETRIC_DEPTH_PER_UNIT).clamp(-half_band, half_band);
+        layers.layer_offset(layer) + in_band + depth * ISOMETRIC_TIE_BREAK
+    }
+}
+
+/// Component: where an entity's sprite sorts in isometric mode (ignored
+/// otherwise). The default sorts at the entity's position.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, DeriveComponentMeta)]
+pub struct SortBias {
+    /// Added to the entity's `y` to get its sort point (negative moves it
+    /// down toward the feet)
+    #[serde(default)]
+    pub offset: f32,
+    /// Tile rows the object spans; a tall object sorts with its bottom
+    /// row when the scene sorts by rows
+    #[serde(default = "default_rows")]
+    pub rows: u32,
+}
+
+fn default_rows() -> u32 {
+    1
+}
+
+impl Default for SortBias {
+    fn default() -> Self {
+        Self { offset: 0.0, rows: 1 }
+    }
+}
+
+impl SortBias {
+    /// Sort `offset` units above (negative: below) the entity's position.
+    pub fn new(offset: f32) -> Self {
+        Self { offset, ..Self::default() }
+    }
+
+    /// An object spanning `rows` tile rows, sorted with the bottom one.
+    pub fn with_rows(mut self, rows: u32) -> Self {
+        self.rows = rows;
+        self
+    }
+
+    /// The sort point's height for an entity at height `y`. With rows, an
+    /// object spanning several is centred on them, so its base row lies
+    /// half the extra rows below the (biased) centre.
+    fn sort_y(&self, y: f32, row_height: f32) -> f32 {
+        let extra_rows = self.rows.saturating_sub(1) as f32;
+        y + self.offset - extra_rows * row_height.max(0.0) * 0.5
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::render_layers::DEFAULT_LAYER;
+
+    fn iso_depth(mode: SpriteSortMode, y: f32, depth: f32, bias: Option<&SortBias>) -> f32 {
+        mode.sort_depth(&RenderLayers::new(), DEFAULT_LAYER, depth, Vec2::new(0.0, y), bias)
+    }
+
+    #[test]
+    fn test_depth_mode_ignores_position() {
+        let layers = RenderLayers::new();
+        let mode = SpriteSortMode::default();
+        assert_eq!(mode.sort_depth(&layers, "Background", 3.0, Vec2::new(5.0, -80.0), None), layers.sort_depth("Background", 3.0));
+    }
+
+    #[test]
+    fn test_isometric_sorts_lower_sprites_in_front_within_their_layer() {
+        let mode = SpriteSortMode::isometric(0.0);
+        assert!(iso_depth(mode, -10.0, 0.0, None) > iso_depth(mode, 10.0, 0.0, None));
+        assert!(iso_depth(mode, 0.0, 5.0, None) > iso_depth(mode, 0.0, 0.0, None), "depth breaks ties");
+        assert!(iso_depth(mode, 1.0, 0.0, None) < iso_depth(mode, 0.0, -10.0, None), "but never across a unit");
+
+        // Far-away sprites stay inside their layer's band
+        let layers = RenderLayers::new();
+        let far_background = mode.sort_depth(&layers, "Background", 0.0, Vec2::new(0.0, -1.0e6), None);
+        assert!(far_background < mode.sort_depth(&layers, DEFAULT_LAYER, 0.0, Vec2::new(0.0, 1.0e6), None));
+    }
+
+    #[test]
+    fn test_rows_group_sprites_and_tall_objects_sort_with_their_base_row() {
+        let mode = SpriteSortMode::isometric(32.0);
+        // Two sprites on the same row tie; depth decides
+        assert_eq!(iso_depth(mode, 3.0, 0.0, None), iso_depth(mode, 30.0, 0.0, None));
+        assert!(iso_depth(mode, 3.0, 0.0, None) > iso_depth(mode, 40.0, 0.0, None));
+
+        // A three-row tree centred on row 1 stands on row 0: it sorts with a
+        // player there, and the player's depth puts them in front
+        let tree = SortBias::default().with_rows(3);
+        let tree_y = 48.0;
+        assert_eq!(iso_depth(mode, tree_y, 0.0, Some(&tree)), iso_depth(mode, 16.0, 0.0, None));
+        assert!(iso_depth(mode, tree_y, 0.0, Some(&tree)) < iso_depth(mode, 16.0, 1.0, None));
+        assert!(iso_depth(mode, tree_y, 0.0, None) < iso_depth(mode, 16.0, 0.0, None), "unbiased it sorts behind");
+    }
+}
@@ -2,18 +2,22 @@
 
 use crate::{
     World, System,
-    sprite_components::SpriteAnimation,
+    sprite_components::{AnimationEventFired, SpriteAnimation},
 };
 
-/// System that updates sprite animations
+/// System that updates sprite animations and emits an
+/// [`AnimationEventFired`] for each frame event playback crosses.
 pub struct SpriteAnimationSystem;
 
 impl System for SpriteAnimationSystem {
     fn update(&mut self, world: &mut World, delta_time: f32) {
         // Update all sprite animations
-        for entity_id in world.entities() {
-            if let Some(animation) = world.get_mut::<SpriteAnimation>(entity_id) {
-                animation.update(delta_time);
+        for entity_id in world.active_entities() {
+            let Some(animation) = world.get_mut::<SpriteAnimation>(entity_id) else {
+                continue;
+            };
+            for event in animation.update_with_events(delta_time) {
+                world.emit_event(AnimationEventFired { entity: entity_id, name: event.name, frame: event.frame });
             }
         }
     }
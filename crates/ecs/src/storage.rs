@@ -0,0 +1,293 @@
+//! Sparse-set component storage.
+//!
+//! Each component type lives in its own [`ComponentStore<T>`]: the
+//! components are packed contiguously in a dense `Vec<T>`, alongside their
+//! entity IDs and change ticks, and a paged sparse index maps an entity's
+//! raw ID to its dense slot. Lookup is two array reads, iteration walks the
+//! dense arrays in insertion order, and removal swaps the last component
+//! into the freed slot.
+//!
+//! Entity IDs are never recycled, so the sparse index is split into pages
+//! that are allocated on first use and freed once empty; long sessions that
+//! spawn and despawn millions of bullets don't keep an index for every ID
+//! ever handed out.
+
+use std::any::Any;
+
+use crate::change_detection::ComponentTicks;
+use crate::component::Component;
+use crate::entity::EntityId;
+
+/// Sparse index slots per page
+const PAGE_SIZE: usize = 1024;
+
+/// Sparse index value for "no component"
+const EMPTY: u32 = u32::MAX;
+
+/// One page of the sparse index: dense slots for `PAGE_SIZE` consecutive IDs
+struct Page {
+    slots: Box<[u32]>,
+    /// Occupied slots; the page is freed when this reaches zero
+    live: usize,
+}
+
+/// Dense, contiguous storage for every `T` component in a world
+pub struct ComponentStore<T: Component> {
+    /// The components, packed
+    dense: Vec<T>,
+    /// Entity owning each dense component
+    entities: Vec<EntityId>,
+    /// Added/changed ticks of each dense component
+    ticks: Vec<ComponentTicks>,
+    /// Raw entity ID → dense slot, in lazily allocated pages
+    pages: Vec<Option<Page>>,
+}
+
+impl<T: Component> Default for ComponentStore<T> {
+    fn default() -> Self {
+        Self {
+            dense: Vec::new(),
+            entities: Vec::new(),
+            ticks: Vec::new(),
+            pages: Vec::new(),
+        }
+    }
+}
+
+/// Page and offset of an entity's sparse slot
+fn sparse_position(entity_id: &EntityId) -> Option<(usize, usize)> {
+    let raw = usize::try_from(entity_id.value()).ok()?;
+    Some((raw / PAGE_SIZE, raw % PAGE_SIZE))
+}
+
+impl<T: Component> ComponentStore<T> {
+    /// Create an empty store
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Dense slot recorded for the entity's raw ID, whatever its generation
+    fn raw_slot(&self, entity_id: &EntityId) -> Option<usize> {
+        let (page, offset) = sparse_position(entity_id)?;
+        let slot = self.pages.get(page)?.as_ref()?.slots[offset];
+        (slot != EMPTY).then_some(slot as usize)
+    }
+
+    /// Dense slot of the entity's component
+    fn slot(&self, entity_id: &EntityId) -> Option<usize> {
+        self.raw_slot(entity_id)
+            .filter(|&slot| self.entities[slot] == *entity_id)
+    }
+
+    /// Point the entity's sparse slot at `slot` (`EMPTY` clears it)
+    fn set_slot(&mut self, entity_id: &EntityId, slot: u32) {
+        let Some((page, offset)) = sparse_position(entity_id) else {
+            return;
+        };
+        if page >= self.pages.len() {
+            self.pages.resize_with(page + 1, || None);
+        }
+        let entry = &mut self.pages[page];
+        let page_data = entry.get_or_insert_with(|| Page {
+            slots: vec![EMPTY; PAGE_SIZE].into_boxed_slice(),
+            live: 0,
+        });
+        match (page_data.slots[offset] == EMPTY, slot == EMPTY) {
+            (true, false) => page_data.live += 1,
+            (false, true) => page_data.live -= 1,
+            _ => {}
+        }
+        page_data.slots[offset] = slot;
+        if page_data.live == 0 {
+            *entry = None;
+        }
+    }
+
+    /// Add a component for an entity at change tick `tick`. Replacing an
+    /// existing component counts as a change, not an addition.
+    pub fn insert(&mut self, entity_id: EntityId, component: T, tick: u32) {
+        if let Some(slot) = self.raw_slot(&entity_id) {
+            if self.entities[slot] == entity_id {
+                self.ticks[slot].changed = tick;
+            } else {
+                // Same raw ID restored under another generation
+                self.entities[slot] = entity_id;
+                self.ticks[slot] = ComponentTicks::new(tick);
+            }
+            self.dense[slot] = component;
+            return;
+        }
+        let Ok(slot) = u32::try_from(self.dense.len()) else {
+            log::error!("Component store for {} is full", std::any::type_name::<T>());
+            return;
+        };
+        self.dense.push(component);
+        self.entities.push(entity_id);
+        self.ticks.push(ComponentTicks::new(tick));
+        self.set_slot(&entity_id, slot);
+    }
+
+    /// Remove and return an entity's component
+    pub fn remove(&mut self, entity_id: &EntityId) -> Option<T> {
+        let slot = self.slot(entity_id)?;
+        self.set_slot(entity_id, EMPTY);
+        let component = self.dense.swap_remove(slot);
+        self.entities.swap_remove(slot);
+        self.ticks.swap_remove(slot);
+        if let Some(moved) = self.entities.get(slot).copied() {
+            // The previous last component now lives in `slot`; a store that
+            // fit its old slot in u32 fits this smaller one too
+            self.set_slot(&moved, slot as u32);
+        }
+        Some(component)
+    }
+
+    /// Get an entity's component
+    pub fn get(&self, entity_id: &EntityId) -> Option<&T> {
+        self.slot(entity_id).map(|slot| &self.dense[slot])
+    }
+
+    /// Get an entity's component mutably, marking it changed at `tick`
+    pub fn get_mut(&mut self, entity_id: &EntityId, tick: u32) -> Option<&mut T> {
+        let slot = self.slot(entity_id)?;
+        self.ticks[slot].changed = tick;
+        Some(&mut self.dense[slot])
+    }
+
+    /// Added/changed ticks of an entity's component
+    pub fn ticks(&self, entity_id: &EntityId) -> Option<ComponentTicks> {
+        self.slot(entity_id).map(|slot| self.ticks[slot])
+    }
+
+    /// Check if an entity has a component stored
+    pub fn contains(&self, entity_id: &EntityId) -> bool {
+        self.slot(entity_id).is_some()
+    }
+
+    /// Entities with a component, in storage order
+    pub fn entities(&self) -> &[EntityId] {
+        &self.entities
+    }
+
+    /// Iterate every component with its entity, in storage order
+    pub fn iter(&self) -> impl Iterator<Item = (EntityId, &T)> + '_ {
+        self.entities.iter().copied().zip(self.dense.iter())
+    }
+
+    /// Iterate every component mutably, marking each changed at `tick`
+    pub fn iter_mut(&mut self, tick: u32) -> impl Iterator<Item = (EntityId, &mut T)> + '_ {
+        self.entities
+            .iter()
+            .copied()
+            .zip(self.dense.iter_mut())
+            .zip(self.ticks.iter_mut())
+            .map(move |(entry, ticks)| {
+                ticks.changed = tick;
+                entry
+            })
+    }
+
+    /// Get the number of components
+    pub fn len(&self) -> usize {
+        self.dense.len()
+    }
+
+    /// Check if there are no components
+    pub fn is_empty(&self) -> bool {
+        self.dense.is_empty()
+    }
+}
+
+/// Type-erased view of a [`ComponentStore`], for operations that don't know
+/// the component type (entity removal, queries by `TypeId`)
+pub(crate) trait AnyStore: Send + Sync {
+    /// The concrete store, for downcasting
+    fn store_any(&self) -> &dyn Any;
+
+    /// The concrete store, mutably, for downcasting
+    fn store_any_mut(&mut self) -> &mut dyn Any;
+
+    /// Check if an entity has a component stored
+    fn contains_entity(&self, entity_id: &EntityId) -> bool;
+
+    /// Drop an entity's component, if any
+    fn remove_entity(&mut self, entity_id: &EntityId);
+
+    /// Entities with a component, in storage order
+    fn stored_entities(&self) -> &[EntityId];
+}
+
+impl<T: Component> AnyStore for ComponentStore<T> {
+    fn store_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn store_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn contains_entity(&self, entity_id: &EntityId) -> bool {
+        self.contains(entity_id)
+    }
+
+    fn remove_entity(&mut self, entity_id: &EntityId) {
+        self.remove(entity_id);
+    }
+
+    fn stored_entities(&self) -> &[EntityId] {
+        self.entities()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entity(id: u64) -> EntityId {
+        EntityId::with_generation(id, 1)
+    }
+
+    #[test]
+    fn test_swap_remove_keeps_lookups_valid() {
+        let mut store = ComponentStore::new();
+        for id in 0..4 {
+            store.insert(entity(id), id as u32 * 10, 1);
+        }
+
+        assert_eq!(store.remove(&entity(1)), Some(10));
+        assert_eq!(store.get(&entity(3)), Some(&30), "last component moved into the hole");
+        assert_eq!(store.entities(), &[entity(0), entity(3), entity(2)]);
+        assert!(store.remove(&entity(1)).is_none());
+
+        let values: Vec<u32> = store.iter().map(|(_, value)| *value).collect();
+        assert_eq!(values, [0, 30, 20]);
+    }
+
+    #[test]
+    fn test_replacing_marks_changed_and_other_generations_miss() {
+        let mut store = ComponentStore::new();
+        store.insert(entity(5), 'a', 1);
+        store.insert(entity(5), 'b', 4);
+        assert_eq!(store.len(), 1);
+        assert_eq!(store.ticks(&entity(5)).map(|t| (t.added, t.changed)), Some((1, 4)));
+        assert!(store.get(&EntityId::with_generation(5, 2)).is_none());
+
+        for (_, value) in store.iter_mut(9) {
+            *value = 'c';
+        }
+        assert_eq!(store.ticks(&entity(5)).map(|t| t.changed), Some(9));
+        assert_eq!(store.get(&entity(5)), Some(&'c'));
+    }
+
+    #[test]
+    fn test_sparse_pages_are_freed_when_empty() {
+        let mut store = ComponentStore::new();
+        let far = entity(10 * PAGE_SIZE as u64 + 3);
+        store.insert(far, 1u8, 1);
+        assert_eq!(store.pages.iter().filter(|page| page.is_some()).count(), 1);
+
+        store.remove(&far);
+        assert!(store.pages.iter().all(Option::is_none));
+        assert!(store.is_empty());
+    }
+}
@@ -2,6 +2,8 @@
 
 use std::any::Any;
 
+use common::time::Instant;
+
 use crate::world::World;
 
 /// A trait for systems in the ECS
@@ -70,11 +72,34 @@ where
     }
 }
 
+/// How long one system's `update` took.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SystemTiming {
+    /// The system's name
+    pub name: String,
+    /// Wall-clock update time in seconds
+    pub seconds: f32,
+}
+
+impl SystemTiming {
+    /// A timing of `seconds` for `name`.
+    pub fn new(name: impl Into<String>, seconds: f32) -> Self {
+        Self { name: name.into(), seconds }
+    }
+
+    /// The update time in milliseconds.
+    pub fn millis(&self) -> f32 {
+        self.seconds * 1000.0
+    }
+}
+
 /// A registry for systems with lifecycle management
 #[derive(Default)]
 pub struct SystemRegistry {
     /// The systems
     systems: Vec<Box<dyn System>>,
+    /// Per-system update times of the last `update_all`
+    timings: Vec<SystemTiming>,
     /// Whether the systems are initialized
     initialized: bool,
     /// Whether the systems are running
@@ -86,6 +111,7 @@ impl SystemRegistry {
     pub fn new() -> Self {
         Self {
             systems: Vec::new(),
+            timings: Vec::new(),
             initialized: false,
             running: false,
         }
@@ -206,8 +232,10 @@ impl SystemRegistry {
         std::mem::swap(&mut self.systems, &mut temp_systems);
         
         // Update all systems with individual error handling
+        self.timings.clear();
         for system in &mut temp_systems {
             let system_name = system.name().to_string();
+            let started = Instant::now();
             
             // Use catch_unwind to prevent panics from corrupting the system registry
             let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
@@ -218,12 +246,23 @@ impl SystemRegistry {
                 log::error!("System '{}' panicked during update", system_name);
                 // Continue with other systems instead of crashing the whole engine
             }
+            self.timings.push(SystemTiming::new(system_name, started.elapsed().as_secs_f32()));
         }
         
         // Move systems back
         self.systems = temp_systems;
     }
 
+    /// Update times of the last `update_all`, in registration order
+    pub fn last_timings(&self) -> &[SystemTiming] {
+        &self.timings
+    }
+
+    /// Whether `start` has run (and `stop` hasn't since)
+    pub fn is_running(&self) -> bool {
+        self.running
+    }
+
     /// Get the number of systems
     pub fn len(&self) -> usize {
         self.systems.len()
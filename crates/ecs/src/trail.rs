@@ -0,0 +1,520 @@
+//! Motion trails: ribbons left behind moving entities.
+//!
+//! A [`Trail2D`] records world-space points as its entity moves (emission
+//! from motion — a stationary entity leaves nothing behind) and
+//! [`TrailSystem`] ages and trims them every frame. The engine turns each
+//! trail into a triangle strip via [`Trail2D::strip`]: the width curve and
+//! color gradient are sampled along the trail, 0 at the head (the entity)
+//! and 1 at the tail.
+//!
+//! Presets cover the common cases — [`Trail2D::sword_swipe`],
+//! [`Trail2D::projectile`], [`Trail2D::dash`] and the short
+//! [`Trail2D::motion_blur`] smear for fast sprites.
+//!
+//! ```
+//! use ecs::trail::{Trail2D, TrailSystem};
+//! use ecs::{System, Transform2D, World};
+//! use glam::Vec2;
+//!
+//! let mut world = World::new();
+//! let bullet = world.create_entity();
+//! world.add_component(&bullet, Transform2D::new(Vec2::ZERO)).unwrap();
+//! world.add_component(&bullet, Trail2D::projectile()).unwrap();
+//!
+//! let mut trails = TrailSystem::new();
+//! for step in 1..=5 {
+//!     world.get_mut::<Transform2D>(bullet).unwrap().position = Vec2::new(step as f32 * 20.0, 0.0);
+//!     trails.update(&mut world, 1.0 / 60.0);
+//! }
+//! assert!(world.get::<Trail2D>(bullet).unwrap().strip().len() >= 4);
+//! ```
+
+use std::collections::VecDeque;
+
+use glam::{Vec2, Vec4};
+use serde::{Deserialize, Serialize};
+
+use crate::animation::{AnimationCurve, Keyframe};
+use crate::component_registry::ComponentMeta;
+use crate::entity::EntityId;
+use crate::hierarchy::GlobalTransform2D;
+use crate::query::Single;
+use crate::sprite_components::Transform2D;
+use crate::system::System;
+use crate::world::World;
+use crate::DeriveComponentMeta;
+
+/// One color of a [`ColorGradient`], at position `t` in `[0, 1]`.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ColorStop {
+    /// Position along the gradient
+    pub t: f32,
+    /// RGBA color at `t`
+    pub color: Vec4,
+}
+
+/// Linear RGBA gradient over `[0, 1]`. Before the first stop it holds the
+/// first color, after the last the last; an empty gradient is white.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[serde(from = "Vec<ColorStop>", into = "Vec<ColorStop>")]
+pub struct ColorGradient {
+    stops: Vec<ColorStop>,
+}
+
+impl From<Vec<ColorStop>> for ColorGradient {
+    fn from(stops: Vec<ColorStop>) -> Self {
+        Self::new(stops)
+    }
+}
+
+impl From<ColorGradient> for Vec<ColorStop> {
+    fn from(gradient: ColorGradient) -> Self {
+        gradient.stops
+    }
+}
+
+impl ColorGradient {
+    /// Gradient through `stops` (sorted by `t`; non-finite positions dropped).
+    pub fn new(mut stops: Vec<ColorStop>) -> Self {
+        stops.retain(|stop| stop.t.is_finite());
+        stops.sort_by(|a, b| a.t.total_cmp(&b.t));
+        Self { stops }
+    }
+
+    /// Two-stop gradient from `start` (t = 0) to `end` (t = 1).
+    pub fn linear(start: Vec4, end: Vec4) -> Self {
+        Self::new(vec![ColorStop { t: 0.0, color: start }, ColorStop { t: 1.0, color: end }])
+    }
+
+    /// `color` fading to fully transparent.
+    pub fn fade_out(color: Vec4) -> Self {
+        Self::linear(color, color.truncate().extend(0.0))
+    }
+
+    /// The stops, sorted by `t`.
+    pub fn stops(&self) -> &[ColorStop] {
+        &self.stops
+    }
+
+    /// Color at `t`.
+    pub fn sample(&self, t: f32) -> Vec4 {
+        let Some(first) = self.stops.first() else {
+            return Vec4::ONE;
+        };
+        let next_index = self.stops.partition_point(|stop| stop.t <= t);
+        if next_index == 0 {
+            return first.color;
+        }
+        let stop = self.stops[next_index - 1];
+        let Some(next) = self.stops.get(next_index) else {
+            return stop.color;
+        };
+        let span = next.t - stop.t;
+        let blend = if span > 0.0 { (t - stop.t) / span } else { 1.0 };
+        stop.color.lerp(next.color, blend)
+    }
+}
+
+/// A recorded trail point in world space.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TrailPoint {
+    /// World-space position
+    pub position: Vec2,
+    /// Seconds since the point was left behind
+    pub age: f32,
+}
+
+/// One strip vertex produced by [`Trail2D::strip`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TrailStripVertex {
+    /// World-space position
+    pub position: Vec2,
+    /// RGBA color
+    pub color: Vec4,
+}
+
+/// Component: leaves a ribbon behind its entity as it moves.
+#[derive(Debug, Clone, Serialize, Deserialize, DeriveComponentMeta)]
+pub struct Trail2D {
+    /// Longest the trail gets, in world units (0 = limited only by
+    /// `point_lifetime` and `max_points`)
+    #[serde(default)]
+    pub length: f32,
+    /// Seconds a point survives after it is left behind
+    #[serde(default = "default_point_lifetime")]
+    pub point_lifetime: f32,
+    /// Distance the entity moves before a new point is recorded
+    #[serde(default = "default_min_distance")]
+    pub min_distance: f32,
+    /// Upper bound on recorded points
+    #[serde(default = "default_max_points")]
+    pub max_points: usize,
+    /// Full width across the trail, sampled from 0 (head) to 1 (tail)
+    #[serde(default = "default_width")]
+    pub width: AnimationCurve,
+    /// Color from 0 (head) to 1 (tail)
+    #[serde(default = "default_color")]
+    pub color: ColorGradient,
+    /// Bloom contribution, like `Sprite::emissive`
+    #[serde(default)]
+    pub emissive: f32,
+    /// Render depth, same convention as `Sprite::depth`
+    #[serde(default)]
+    pub depth: f32,
+    /// Whether movement records new points; when false the existing
+    /// points still age out
+    #[serde(default = "default_emitting")]
+    pub emitting: bool,
+    /// Recorded points, head (newest) first
+    #[serde(skip)]
+    points: VecDeque<TrailPoint>,
+}
+
+fn default_point_lifetime() -> f32 { 0.3 }
+fn default_min_distance() -> f32 { 4.0 }
+fn default_max_points() -> usize { 32 }
+fn default_width() -> AnimationCurve { AnimationCurve::new(vec![Keyframe::linear(0.0, 16.0), Keyframe::linear(1.0, 0.0)]) }
+fn default_color() -> ColorGradient { ColorGradient::fade_out(Vec4::ONE) }
+fn default_emitting() -> bool { true }
+
+impl Default for Trail2D {
+    fn default() -> Self {
+        Self {
+            length: 0.0,
+            point_lifetime: default_point_lifetime(),
+            min_distance: default_min_distance(),
+            max_points: default_max_points(),
+            width: default_width(),
+            color: default_color(),
+            emissive: 0.0,
+            depth: 0.0,
+            emitting: true,
+            points: VecDeque::new(),
+        }
+    }
+}
+
+impl Trail2D {
+    /// White trail, 16 units wide at the head tapering to nothing, points
+    /// living 0.3 s.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Wide, bright, very short-lived arc for melee swings.
+    pub fn sword_swipe() -> Self {
+        Self::new()
+            .with_width(AnimationCurve::new(vec![Keyframe::ease_in_out(0.0, 28.0), Keyframe::linear(1.0, 0.0)]))
+            .with_color(ColorGradient::fade_out(Vec4::new(0.9, 0.95, 1.0, 0.9)))
+            .with_lifetime(0.12)
+            .with_min_distance(2.0)
+            .with_emissive(0.6)
+    }
+
+    /// Thin glowing tail of fixed length for bullets and missiles.
+    pub fn projectile() -> Self {
+        Self::new()
+            .with_width(AnimationCurve::new(vec![Keyframe::linear(0.0, 6.0), Keyframe::linear(1.0, 1.0)]))
+            .with_color(ColorGradient::fade_out(Vec4::new(1.0, 0.8, 0.3, 1.0)))
+            .with_lifetime(0.5)
+            .with_length(120.0)
+            .with_emissive(1.0)
+    }
+
+    /// Body-wide translucent streak for dashes and blinks.
+    pub fn dash() -> Self {
+        Self::new()
+            .with_width(AnimationCurve::new(vec![Keyframe::linear(0.0, 40.0), Keyframe::linear(1.0, 24.0)]))
+            .with_color(ColorGradient::fade_out(Vec4::new(0.5, 0.8, 1.0, 0.5)))
+            .with_lifetime(0.25)
+            .with_min_distance(8.0)
+    }
+
+    /// Constant-width smear in `color` that lags a fast sprite by a few
+    /// frames — a cheap motion blur. Match `width` to the sprite's size
+    /// across its direction of travel and `depth` to just behind it.
+    pub fn motion_blur(width: f32, color: Vec4) -> Self {
+        Self::new()
+            .with_width(AnimationCurve::new(vec![Keyframe::linear(0.0, width)]))
+            .with_color(ColorGradient::linear(color * Vec4::new(1.0, 1.0, 1.0, 0.5), color.truncate().extend(0.0)))
+            .with_lifetime(0.08)
+            .with_min_distance(1.0)
+    }
+
+    /// Set the width curve (builder-style).
+    pub fn with_width(mut self, width: AnimationCurve) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Set the color gradient.
+    pub fn with_color(mut self, color: ColorGradient) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Set how long points survive, in seconds.
+    pub fn with_lifetime(mut self, seconds: f32) -> Self {
+        self.point_lifetime = seconds;
+        self
+    }
+
+    /// Cap the trail's world length (0 = uncapped).
+    pub fn with_length(mut self, length: f32) -> Self {
+        self.length = length;
+        self
+    }
+
+    /// Set the movement needed before a new point is recorded.
+    pub fn with_min_distance(mut self, distance: f32) -> Self {
+        self.min_distance = distance;
+        self
+    }
+
+    /// Set the bloom contribution.
+    pub fn with_emissive(mut self, emissive: f32) -> Self {
+        self.emissive = emissive;
+        self
+    }
+
+    /// Set the render depth.
+    pub fn with_depth(mut self, depth: f32) -> Self {
+        self.depth = depth;
+        self
+    }
+
+    /// Recorded points, head (newest) first.
+    pub fn points(&self) -> impl ExactSizeIterator<Item = &TrailPoint> {
+        self.points.iter()
+    }
+
+    /// Drop every point (e.g. after a teleport, so the trail doesn't
+    /// streak across the screen).
+    pub fn clear(&mut self) {
+        self.points.clear();
+    }
+
+    /// Move every recorded point by `offset`, keeping the trail's shape
+    /// (e.g. when the world origin shifts).
+    pub fn translate(&mut self, offset: Vec2) {
+        for point in &mut self.points {
+            point.position += offset;
+        }
+    }
+
+    /// Advance the trail one frame with its entity at `head`.
+    pub fn advance(&mut self, head: Vec2, delta_time: f32) {
+        for point in &mut self.points {
+            point.age += delta_time;
+        }
+        if self.emitting {
+            self.emit(head);
+        }
+        let lifetime = self.point_lifetime;
+        while self.points.back().is_some_and(|point| point.age > lifetime) {
+            self.points.pop_back();
+        }
+        self.points.truncate(self.max_points.max(2));
+        self.trim_to_length();
+    }
+
+    /// Record `head`. The newest point follows the entity until it is
+    /// `min_distance` away from the one before, then stays behind and a
+    /// new head starts.
+    fn emit(&mut self, head: Vec2) {
+        let fresh = TrailPoint { position: head, age: 0.0 };
+        let anchored = self
+            .points
+            .get(1)
+            .is_some_and(|previous| previous.position.distance(head) < self.min_distance);
+        match self.points.front_mut() {
+            Some(newest) if anchored || newest.position == head => *newest = fresh,
+            _ => self.points.push_front(fresh),
+        }
+    }
+
+    /// Shorten the tail so the polyline is at most `length` long.
+    fn trim_to_length(&mut self) {
+        if self.length <= 0.0 {
+            return;
+        }
+        let mut travelled = 0.0;
+        for index in 1..self.points.len() {
+            let from = self.points[index - 1].position;
+            let to = self.points[index].position;
+            let segment = from.distance(to);
+            if travelled + segment > self.length {
+                let keep = (self.length - travelled) / segment;
+                self.points[index].position = from.lerp(to, keep);
+                self.points.truncate(index + 1);
+                return;
+            }
+            travelled += segment;
+        }
+    }
+
+    /// Triangle-strip vertices, a left/right pair per point from head to
+    /// tail. Empty when fewer than two distinct points are recorded.
+    pub fn strip(&self) -> Vec<TrailStripVertex> {
+        let total: f32 = self.segment_lengths().sum();
+        if self.points.len() < 2 || total <= f32::EPSILON {
+            return Vec::new();
+        }
+
+        let mut vertices = Vec::with_capacity(self.points.len() * 2);
+        let mut travelled = 0.0;
+        let mut normal = Vec2::Y;
+        for (index, point) in self.points.iter().enumerate() {
+            if index > 0 {
+                travelled += self.points[index - 1].position.distance(point.position);
+            }
+            let before = index.checked_sub(1).map_or(point.position, |i| self.points[i].position);
+            let after = self.points.get(index + 1).map_or(point.position, |next| next.position);
+            // Keep the previous normal through zero-length segments.
+            if let Some(direction) = (before - after).try_normalize() {
+                normal = direction.perp();
+            }
+
+            let t = travelled / total;
+            let half_width = self.width.sample(t).unwrap_or(0.0).max(0.0) * 0.5;
+            let color = self.color.sample(t);
+            vertices.push(TrailStripVertex { position: point.position + normal * half_width, color });
+            vertices.push(TrailStripVertex { position: point.position - normal * half_width, color });
+        }
+        vertices
+    }
+
+    fn segment_lengths(&self) -> impl Iterator<Item = f32> + '_ {
+        self.points
+            .iter()
+            .zip(self.points.iter().skip(1))
+            .map(|(a, b)| a.position.distance(b.position))
+    }
+}
+
+/// System: advances every [`Trail2D`] with its entity's world position
+/// (`GlobalTransform2D` when present, else `Transform2D`). Run it after
+/// movement each frame; the engine's game loop does.
+#[derive(Debug, Default)]
+pub struct TrailSystem;
+
+impl TrailSystem {
+    /// Create a new trail system.
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl System for TrailSystem {
+    fn update(&mut self, world: &mut World, delta_time: f32) {
+        let trails: Vec<EntityId> = world.query_active::<Single<Trail2D>>();
+        for entity in trails {
+            let head = world
+                .get::<GlobalTransform2D>(entity)
+                .map(|global| global.position)
+                .or_else(|| world.get::<Transform2D>(entity).map(|transform| transform.position));
+            let Some(head) = head else {
+                continue;
+            };
+            if let Some(trail) = world.get_mut::<Trail2D>(entity) {
+                trail.advance(head, delta_time);
+            }
+        }
+    }
+
+    fn name(&self) -> &str {
+        "TrailSystem"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn moved(trail: &mut Trail2D, path: &[(f32, f32)], dt: f32) {
+        for &(x, y) in path {
+            trail.advance(Vec2::new(x, y), dt);
+        }
+    }
+
+    #[test]
+    fn test_gradient_samples_between_stops() {
+        let gradient = ColorGradient::linear(Vec4::new(1.0, 0.0, 0.0, 1.0), Vec4::new(0.0, 0.0, 1.0, 0.0));
+        assert_eq!(gradient.sample(-1.0), Vec4::new(1.0, 0.0, 0.0, 1.0));
+        assert_eq!(gradient.sample(0.5), Vec4::new(0.5, 0.0, 0.5, 0.5));
+        assert_eq!(gradient.sample(2.0), Vec4::new(0.0, 0.0, 1.0, 0.0));
+        assert_eq!(ColorGradient::default().sample(0.3), Vec4::ONE);
+    }
+
+    #[test]
+    fn test_points_are_emitted_from_motion_only() {
+        let mut trail = Trail2D::new().with_min_distance(5.0);
+        moved(&mut trail, &[(0.0, 0.0), (0.0, 0.0), (2.0, 0.0)], 0.01);
+        assert_eq!(trail.points().len(), 2, "the head follows until min_distance");
+        moved(&mut trail, &[(4.0, 0.0), (7.0, 0.0)], 0.01);
+        let xs: Vec<f32> = trail.points().map(|p| p.position.x).collect();
+        assert_eq!(xs, [7.0, 4.0, 0.0], "4 stays behind once the head passes 5");
+
+        trail.emitting = false;
+        moved(&mut trail, &[(40.0, 0.0)], 0.01);
+        assert_eq!(trail.points().next().map(|p| p.position.x), Some(7.0));
+    }
+
+    #[test]
+    fn test_points_age_out_and_length_is_capped() {
+        let mut trail = Trail2D::new().with_min_distance(1.0).with_lifetime(0.1).with_length(15.0);
+        moved(&mut trail, &[(0.0, 0.0), (10.0, 0.0), (20.0, 0.0)], 0.01);
+        let xs: Vec<f32> = trail.points().map(|p| p.position.x).collect();
+        assert_eq!(xs, [20.0, 10.0, 5.0], "the tail is cut at 15 units");
+
+        // Standing still: everything but the head expires.
+        moved(&mut trail, &[(20.0, 0.0)], 0.2);
+        assert_eq!(trail.points().len(), 1);
+        assert!(trail.strip().is_empty());
+    }
+
+    #[test]
+    fn test_strip_tapers_along_the_width_curve() {
+        let mut trail = Trail2D::new().with_min_distance(1.0);
+        moved(&mut trail, &[(0.0, 0.0), (10.0, 0.0), (20.0, 0.0)], 0.01);
+        let strip = trail.strip();
+        assert_eq!(strip.len(), 6);
+        // Head at x = 20 is 16 wide, the middle 8, the tail 0.
+        assert_eq!(strip[0].position, Vec2::new(20.0, 8.0));
+        assert_eq!(strip[1].position, Vec2::new(20.0, -8.0));
+        assert_eq!(strip[2].position, Vec2::new(10.0, 4.0));
+        assert_eq!(strip[4].position, strip[5].position);
+        assert_eq!(strip[0].color.w, 1.0);
+        assert_eq!(strip[5].color.w, 0.0);
+    }
+
+    #[test]
+    fn test_system_follows_global_transform() {
+        let mut world = World::new();
+        let entity = world.create_entity();
+        world.add_component(&entity, Transform2D::new(Vec2::ZERO)).unwrap();
+        world
+            .add_component(&entity, GlobalTransform2D { position: Vec2::new(100.0, 0.0), ..Default::default() })
+            .unwrap();
+        world.add_component(&entity, Trail2D::projectile()).unwrap();
+
+        TrailSystem::new().update(&mut world, 0.016);
+        let head = world.get::<Trail2D>(entity).and_then(|t| t.points().next().map(|p| p.position));
+        assert_eq!(head, Some(Vec2::new(100.0, 0.0)));
+    }
+
+    #[test]
+    fn test_trail_round_trips_without_points() {
+        let mut trail = Trail2D::sword_swipe();
+        moved(&mut trail, &[(0.0, 0.0), (30.0, 0.0)], 0.01);
+        let text = ron::to_string(&trail).unwrap();
+        let restored: Trail2D = ron::from_str(&text).unwrap();
+        assert_eq!(restored.width, trail.width);
+        assert_eq!(restored.color, trail.color);
+        assert_eq!(restored.points().len(), 0, "points are runtime state");
+
+        let minimal: Trail2D = ron::from_str("(emissive: 0.5)").unwrap();
+        assert_eq!(minimal.point_lifetime, 0.3);
+        assert!(minimal.emitting);
+    }
+}
@@ -0,0 +1,194 @@
+//! Component data validation.
+//!
+//! Components implement [`Validate`] to reject values that would otherwise
+//! surface much later as NaNs or panics (a zero-fps animation, a collider
+//! with no extent, a camera with zero zoom). Validators are registered by
+//! component name in the [`ComponentRegistry`](crate::component_registry::ComponentRegistry)
+//! — built-ins automatically, other crates with [`register_global_validator`]
+//! — and run over whole entities with [`validate_components`]. The engine
+//! calls it after loading a scene; the editor also after inspector edits and
+//! before entering play mode.
+//!
+//! ```
+//! use ecs::validation::validate_components;
+//! use ecs::{SpriteAnimation, World};
+//!
+//! let mut world = World::new();
+//! let entity = world.spawn((SpriteAnimation::new(0.0, vec![[0.0, 0.0, 1.0, 1.0]]),)).id();
+//! let errors = validate_components(&world, [entity]);
+//! assert_eq!(errors[0].component, "SpriteAnimation");
+//! ```
+
+use std::fmt;
+
+use crate::audio_components::AudioOcclusion;
+use crate::component_registry::{registry_write, ComponentMeta};
+use crate::entity::EntityId;
+use crate::parallax::ParallaxLayer;
+use crate::sprite_components::{Camera, Sprite, SpriteAnimation, SpriteMask, Transform2D};
+use crate::sprite_sort::SortBias;
+use crate::world::World;
+
+/// A component that can check its own data for invalid values.
+pub trait Validate {
+    /// `Err` with a short, human-readable reason when the data is unusable.
+    fn validate(&self) -> Result<(), String>;
+}
+
+/// An invalid component found by [`validate_components`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ComponentError {
+    /// Entity carrying the component
+    pub entity: EntityId,
+    /// Registry name of the component (e.g. `"Collider"`)
+    pub component: &'static str,
+    /// Why the data was rejected
+    pub message: String,
+}
+
+impl fmt::Display for ComponentError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} on {}: {}", self.component, self.entity, self.message)
+    }
+}
+
+/// Register `T`'s validator in the global registry so
+/// [`validate_components`] checks it. Registering again is harmless.
+pub fn register_global_validator<T: ComponentMeta + Validate>() {
+    registry_write().register_validator::<T>();
+}
+
+/// Run every registered validator over `entities`, collecting the failures
+/// (in entity order, then component name order).
+pub fn validate_components(world: &World, entities: impl IntoIterator<Item = EntityId>) -> Vec<ComponentError> {
+    let registry = crate::component_registry::global_registry();
+    entities
+        .into_iter()
+        .flat_map(|entity| registry.validate_entity(world, entity))
+        .collect()
+}
+
+/// `Ok` when `condition` holds, otherwise `Err(message)`.
+pub fn ensure(condition: bool, message: impl FnOnce() -> String) -> Result<(), String> {
+    if condition {
+        Ok(())
+    } else {
+        Err(message())
+    }
+}
+
+impl Validate for Transform2D {
+    fn validate(&self) -> Result<(), String> {
+        ensure(self.position.is_finite(), || format!("position {} is not finite", self.position))?;
+        ensure(self.rotation.is_finite(), || format!("rotation {} is not finite", self.rotation))?;
+        ensure(self.scale.is_finite(), || format!("scale {} is not finite", self.scale))
+    }
+}
+
+impl Validate for Sprite {
+    fn validate(&self) -> Result<(), String> {
+        ensure(self.offset.is_finite() && self.rotation.is_finite(), || "offset or rotation is not finite".to_string())?;
+        ensure(self.scale.is_finite(), || format!("scale {} is not finite", self.scale))?;
+        ensure(self.color.is_finite(), || format!("color {} is not finite", self.color))?;
+        ensure(self.depth.is_finite(), || format!("depth {} is not finite", self.depth))?;
+        ensure(self.emissive.is_finite() && self.emissive >= 0.0, || {
+            format!("emissive must be zero or more, got {}", self.emissive)
+        })
+    }
+}
+
+impl Validate for SpriteAnimation {
+    fn validate(&self) -> Result<(), String> {
+        ensure(self.fps.is_finite() && self.fps > 0.0, || format!("fps must be greater than 0, got {}", self.fps))
+    }
+}
+
+impl Validate for SpriteMask {
+    fn validate(&self) -> Result<(), String> {
+        ensure(self.offset.is_finite(), || format!("offset {} is not finite", self.offset))?;
+        ensure(self.size.is_finite() && self.size.min_element() >= 0.0, || {
+            format!("size must be finite and non-negative, got {}", self.size)
+        })
+    }
+}
+
+impl Validate for Camera {
+    fn validate(&self) -> Result<(), String> {
+        ensure(self.zoom.is_finite() && self.zoom > 0.0, || format!("zoom must be greater than 0, got {}", self.zoom))?;
+        ensure(self.position.is_finite() && self.rotation.is_finite(), || {
+            "position or rotation is not finite".to_string()
+        })?;
+        ensure(self.viewport_size.is_finite() && self.viewport_size.min_element() > 0.0, || {
+            format!("viewport size must be positive, got {}", self.viewport_size)
+        })
+    }
+}
+
+impl Validate for ParallaxLayer {
+    fn validate(&self) -> Result<(), String> {
+        ensure(self.scroll_factor.is_finite(), || format!("scroll factor {} is not finite", self.scroll_factor))
+    }
+}
+
+impl Validate for SortBias {
+    fn validate(&self) -> Result<(), String> {
+        ensure(self.offset.is_finite(), || format!("sort offset {} is not finite", self.offset))
+    }
+}
+
+impl Validate for AudioOcclusion {
+    fn validate(&self) -> Result<(), String> {
+        let unit = |value: f32| (0.0..=1.0).contains(&value);
+        ensure(unit(self.per_blocker) && unit(self.occluded_volume), || {
+            format!(
+                "per-blocker occlusion and occluded volume must be within 0..=1, got {} / {}",
+                self.per_blocker, self.occluded_volume
+            )
+        })?;
+        ensure(self.fade_speed.is_finite() && self.fade_speed > 0.0, || {
+            format!("fade speed must be greater than 0, got {}", self.fade_speed)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_built_in_validators_report_bad_values() {
+        let mut world = World::new();
+        let good = world.spawn((Transform2D::default(), Sprite::new(0), Camera::default())).id();
+        let camera = Camera { zoom: 0.0, ..Default::default() };
+        let transform = Transform2D::new(glam::Vec2::new(f32::NAN, 0.0));
+        let bad = world.spawn((transform, camera, SpriteAnimation::new(-1.0, Vec::new()))).id();
+
+        assert!(validate_components(&world, [good]).is_empty());
+        let errors = validate_components(&world, [good, bad]);
+        let components: Vec<_> = errors.iter().map(|error| error.component).collect();
+        assert_eq!(components, ["Camera", "SpriteAnimation", "Transform2D"]);
+        assert!(errors.iter().all(|error| error.entity == bad));
+        assert!(errors[0].to_string().contains("zoom must be greater than 0"));
+    }
+
+    #[test]
+    fn test_custom_validators_register_globally() {
+        #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, crate::DeriveComponentMeta)]
+        struct Health {
+            current: f32,
+        }
+        impl Validate for Health {
+            fn validate(&self) -> Result<(), String> {
+                ensure(self.current >= 0.0, || "health below zero".to_string())
+            }
+        }
+
+        let mut world = World::new();
+        let entity = world.spawn((Health { current: -5.0 },)).id();
+        assert!(validate_components(&world, [entity]).is_empty(), "not registered yet");
+        register_global_validator::<Health>();
+        let errors = validate_components(&world, [entity]);
+        assert_eq!(errors.len(), 1);
+        assert_eq!((errors[0].component, errors[0].message.as_str()), ("Health", "health below zero"));
+    }
+}
@@ -0,0 +1,65 @@
+//! Pool-aware iteration: the `*_active` counterparts of `entities`,
+//! `query_entities`, `iter` and `iter_mut` that leave out entities parked
+//! with [`Inactive`] (see [`pool`](crate::pool)).
+//!
+//! Systems, rendering and gameplay passes use these; the plain versions
+//! keep listing parked entities for code that must see every entity —
+//! cleanup such as state-scoped despawns, snapshots, origin shifts.
+
+use super::World;
+use crate::component::Component;
+use crate::entity::EntityId;
+use crate::pool::Inactive;
+use crate::query::QueryTypes;
+
+impl World {
+    /// Whether `entity_id` is alive and not parked with [`Inactive`].
+    pub fn is_active(&self, entity_id: EntityId) -> bool {
+        self.validate_entity(&entity_id).is_ok() && !self.components.has::<Inactive>(&entity_id)
+    }
+
+    /// Snapshot of the entities not parked with [`Inactive`]. Systems that
+    /// walk every entity use this; `entities()` still lists parked ones.
+    pub fn active_entities(&self) -> Vec<EntityId> {
+        self.entity_ids()
+            .filter(|entity| !self.components.has::<Inactive>(entity))
+            .collect()
+    }
+
+    /// [`query_entities`](World::query_entities) without parked entities.
+    ///
+    /// ```
+    /// use ecs::pool::Inactive;
+    /// use ecs::{Single, Transform2D, World};
+    ///
+    /// let mut world = World::new();
+    /// world.spawn((Transform2D::default(),));
+    /// world.spawn((Transform2D::default(), Inactive));
+    /// assert_eq!(world.query_entities::<Single<Transform2D>>().len(), 2);
+    /// assert_eq!(world.query_active::<Single<Transform2D>>().len(), 1);
+    /// ```
+    pub fn query_active<Q: QueryTypes>(&self) -> Vec<EntityId> {
+        let mut entities = self.query_entities::<Q>();
+        if self.components.store::<Inactive>().is_some() {
+            entities.retain(|entity| !self.components.has::<Inactive>(entity));
+        }
+        entities
+    }
+
+    /// [`iter`](World::iter) without parked entities.
+    pub fn iter_active<T: Component>(&self) -> impl Iterator<Item = (EntityId, &T)> + '_ {
+        let inactive = self.components.store::<Inactive>();
+        self.iter::<T>()
+            .filter(move |(entity, _)| inactive.is_none_or(|parked| !parked.contains(entity)))
+    }
+
+    /// [`iter_mut`](World::iter_mut) without parked entities.
+    pub fn iter_active_mut<T: Component>(&mut self) -> impl Iterator<Item = (EntityId, &mut T)> + '_ {
+        let tick = self.change_tick;
+        let (store, inactive) = self.components.store_mut_with::<T, Inactive>();
+        store
+            .into_iter()
+            .flat_map(move |store| store.iter_mut(tick))
+            .filter(move |(entity, _)| inactive.is_none_or(|parked| !parked.contains(entity)))
+    }
+}
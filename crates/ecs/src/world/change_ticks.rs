@@ -0,0 +1,68 @@
+//! Change detection on the world: the change tick, `clear_trackers`, the
+//! `is_added`/`is_changed` checks and `query_filtered` (see
+//! [`change_detection`](crate::change_detection)).
+
+use super::World;
+use crate::change_detection::{ComponentTicks, QueryFilter};
+use crate::component::Component;
+use crate::entity::EntityId;
+use crate::query::QueryTypes;
+
+impl World {
+    /// Query for entities matching `Q` that also pass the change filter `F`
+    /// for changes after tick `since`, e.g.
+    /// `query_filtered::<Single<Transform2D>, Changed<Transform2D>>(tick)`.
+    pub fn query_filtered<Q: QueryTypes, F: QueryFilter>(&self, since: u32) -> Vec<EntityId> {
+        self.query_entities::<Q>()
+            .into_iter()
+            .filter(|&entity| F::matches(self, entity, since))
+            .collect()
+    }
+
+    /// Current change tick.
+    pub fn change_tick(&self) -> u32 {
+        self.change_tick
+    }
+
+    /// Advance the change tick, returning the tick that just ended. A system
+    /// stores the result after its pass; later changes compare greater.
+    pub fn increment_change_tick(&mut self) -> u32 {
+        let ended = self.change_tick;
+        self.change_tick = self.change_tick.wrapping_add(1);
+        ended
+    }
+
+    /// Start a new change-detection frame: `is_added`/`is_changed` only
+    /// report changes made after this call. The game loop calls it once per
+    /// frame.
+    pub fn clear_trackers(&mut self) {
+        self.last_change_tick = self.increment_change_tick();
+    }
+
+    /// Added/changed ticks of an entity's `T`, if it has one.
+    pub fn component_ticks<T: Component>(&self, entity_id: EntityId) -> Option<ComponentTicks> {
+        self.validate_entity(&entity_id).ok()?;
+        self.components.ticks::<T>(&entity_id)
+    }
+
+    /// Whether the entity's `T` was added since the last `clear_trackers()`.
+    pub fn is_added<T: Component>(&self, entity_id: EntityId) -> bool {
+        self.is_added_since::<T>(entity_id, self.last_change_tick)
+    }
+
+    /// Whether the entity's `T` was added or changed since the last
+    /// `clear_trackers()`.
+    pub fn is_changed<T: Component>(&self, entity_id: EntityId) -> bool {
+        self.is_changed_since::<T>(entity_id, self.last_change_tick)
+    }
+
+    /// Whether the entity's `T` was added after tick `since`.
+    pub fn is_added_since<T: Component>(&self, entity_id: EntityId, since: u32) -> bool {
+        self.component_ticks::<T>(entity_id).is_some_and(|ticks| ticks.is_added(since))
+    }
+
+    /// Whether the entity's `T` was added or changed after tick `since`.
+    pub fn is_changed_since<T: Component>(&self, entity_id: EntityId, since: u32) -> bool {
+        self.component_ticks::<T>(entity_id).is_some_and(|ticks| ticks.is_changed(since))
+    }
+}
@@ -0,0 +1,38 @@
+//! Recursive despawn and the deferred command queue on [`World`].
+
+use super::World;
+use crate::commands::Commands;
+use crate::entity::EntityId;
+use crate::hierarchy_extension::WorldHierarchyExt;
+use crate::EcsError;
+
+impl World {
+    /// Remove `entity` and every descendant, deepest first, right now.
+    ///
+    /// Unlike [`remove_entity`](Self::remove_entity), which turns the
+    /// children into roots, nothing of the subtree is left behind.
+    ///
+    /// # Errors
+    /// Returns an error if `entity` is dead or unknown; nothing is removed.
+    pub fn despawn_recursive(&mut self, entity: EntityId) -> Result<(), EcsError> {
+        self.validate_entity(&entity)?;
+        for descendant in self.get_descendants(entity).into_iter().rev() {
+            self.remove_entity(&descendant).ok();
+        }
+        self.remove_entity(&entity)
+    }
+
+    /// The world's deferred command queue, applied by
+    /// [`apply_commands`](Self::apply_commands) (the game loop does so once
+    /// per frame, after `update()`).
+    pub fn commands(&mut self) -> &mut Commands {
+        &mut self.commands
+    }
+
+    /// Apply and clear the world's command queue. Commands queued while it
+    /// runs wait for the next call.
+    pub fn apply_commands(&mut self) {
+        let mut queued = std::mem::take(&mut self.commands);
+        queued.apply(self);
+    }
+}
@@ -0,0 +1,44 @@
+//! The world's [`Guid`] → [`EntityId`] index.
+//!
+//! Kept in sync by `create_entity` (fresh GUID), `add_component::<Guid>`
+//! (restored GUID replaces the entity's old one), `remove_component::<Guid>`,
+//! `remove_entity`, and `clear`.
+
+use super::World;
+use crate::entity::EntityId;
+use crate::guid::Guid;
+
+impl World {
+    /// The live entity carrying `guid`, if any.
+    pub fn entity_by_guid(&self, guid: Guid) -> Option<EntityId> {
+        let entity = *self.guids.get(&guid)?;
+        // Guard against a GUID mutated in place via `get_mut`
+        (self.get::<Guid>(entity) == Some(&guid)).then_some(entity)
+    }
+
+    /// The persistent GUID of `entity`, if it is alive and has one.
+    pub fn guid_of(&self, entity: EntityId) -> Option<Guid> {
+        self.get::<Guid>(entity).copied()
+    }
+
+    /// Point `guid` at `entity`, dropping the entity's previous GUID. A GUID
+    /// already held by another live entity moves to `entity` (the other
+    /// keeps the component but no longer resolves).
+    pub(super) fn index_guid(&mut self, entity: EntityId, guid: Guid) {
+        self.unindex_guid(&entity);
+        if let Some(previous) = self.guids.insert(guid, entity) {
+            if previous != entity && self.validate_entity(&previous).is_ok() {
+                log::warn!("GUID {guid} moved from entity {} to entity {}", previous.value(), entity.value());
+            }
+        }
+    }
+
+    /// Remove `entity`'s GUID from the index.
+    pub(super) fn unindex_guid(&mut self, entity: &EntityId) {
+        if let Some(guid) = self.components.get_typed::<Guid>(entity) {
+            if self.guids.get(guid) == Some(entity) {
+                self.guids.remove(guid);
+            }
+        }
+    }
+}
@@ -2,6 +2,8 @@
 
 use std::collections::HashMap;
 
+use crate::bundle::Bundle;
+use crate::commands::Commands;
 use crate::component::{Component, ComponentRegistry};
 use crate::entity::{Entity, EntityId};
 use crate::hierarchy::{Children, Parent};
@@ -11,6 +13,13 @@ use crate::resource::ResourceStorage;
 use crate::system::SystemRegistry;
 use crate::query::QueryTypes;
 use crate::EcsError;
+use crate::guid::Guid;
+
+mod active;
+mod change_ticks;
+mod commands;
+mod guids;
+mod resources;
 
 /// Configuration for the ECS world
 #[derive(Debug, Clone)]
@@ -44,6 +53,14 @@ pub struct World {
     resources: ResourceStorage,
     /// Typed event bus for loose-coupled system communication
     events: EventBus,
+    /// Deferred operations, applied by `apply_commands()`
+    commands: Commands,
+    /// Live entity for each [`Guid`] component
+    guids: HashMap<Guid, EntityId>,
+    /// Current change tick, stamped on component adds and mutable accesses
+    change_tick: u32,
+    /// Change tick at the last `clear_trackers()` (frame boundary)
+    last_change_tick: u32,
     /// Whether the world is initialized
     initialized: bool,
     /// Whether the world is running
@@ -67,6 +84,10 @@ impl World {
             systems: SystemRegistry::new(),
             resources: ResourceStorage::new(),
             events: EventBus::new(),
+            commands: Commands::new(),
+            guids: HashMap::new(),
+            change_tick: 1,
+            last_change_tick: 0,
             initialized: false,
             running: false,
             config,
@@ -151,27 +172,31 @@ impl World {
         self.running
     }
 
-    /// Create a new entity with generation tracking
-    /// Create an entity and return a builder for adding components.
+    /// Create an entity with the components of `bundle` and return a builder
+    /// for adding more.
     ///
     /// # Example
     /// ```
-    /// # use ecs::{World, Transform2D, Sprite};
+    /// # use ecs::{World, Transform2D, Sprite, Name};
     /// # use glam::Vec2;
     /// # let mut world = World::new();
     /// # let (pos, tex) = (Vec2::new(10.0, 20.0), 0);
-    /// let entity = world.spawn()
-    ///     .with(Transform2D::new(pos))
-    ///     .with(Sprite::new(tex))
-    ///     .id();
+    /// let entity = world.spawn((Transform2D::new(pos), Sprite::new(tex))).id();
+    /// let empty = world.spawn(()).with(Name::new("marker")).id();
     /// # assert!(world.has_component::<Transform2D>(&entity).unwrap());
     /// # assert!(world.has_component::<Sprite>(&entity).unwrap());
+    /// # assert!(world.has_component::<Name>(&empty).unwrap());
     /// ```
-    pub fn spawn(&mut self) -> crate::entity_builder::EntityBuilder<'_> {
-        crate::entity_builder::EntityBuilder::new(self)
+    pub fn spawn<B: Bundle>(&mut self, bundle: B) -> crate::entity_builder::EntityBuilder<'_> {
+        crate::entity_builder::EntityBuilder::new(self).insert(bundle)
+    }
+
+    /// Add every component of `bundle` to an existing entity.
+    pub fn insert_bundle<B: Bundle>(&mut self, entity_id: EntityId, bundle: B) -> Result<(), EcsError> {
+        bundle.insert_into(self, entity_id)
     }
 
-    /// Create a new entity and return its ID
+    /// Create a new entity with a fresh [`Guid`] and return its ID
     pub fn create_entity(&mut self) -> EntityId {
         let entity = Entity::new();
         let id = entity.id();
@@ -180,6 +205,9 @@ impl World {
         let generation = EntityGeneration::with_generation(id.generation());
         self.entity_generations.insert(id, generation);
         self.entities.insert(id, entity);
+        let guid = Guid::new();
+        self.guids.insert(guid, id);
+        self.components.add(id, guid, self.change_tick);
 
         log::trace!("Created entity {} with generation {}", id.value(), id.generation());
         id
@@ -190,7 +218,7 @@ impl World {
     /// Hierarchy links are cleaned up automatically: the entity is unlinked
     /// from its parent's `Children` list, and its own children become root
     /// entities (their `Parent` component is removed). To delete a whole
-    /// subtree instead, use `WorldHierarchyExt::remove_entity_hierarchy`.
+    /// subtree instead, use [`despawn_recursive`](Self::despawn_recursive).
     ///
     /// The dead generation entry is retained on purpose so later accesses
     /// with the stale ID report "not alive" rather than "not found".
@@ -208,6 +236,7 @@ impl World {
         }
 
         self.detach_from_hierarchy(entity_id);
+        self.unindex_guid(entity_id);
 
         // Remove components for this entity
         self.components.remove_all(entity_id);
@@ -224,7 +253,7 @@ impl World {
         // Remove the entity from its parent's Children list
         let parent_id = self.components.get_typed::<Parent>(entity_id).map(|p| p.entity());
         if let Some(parent_id) = parent_id {
-            if let Some(children) = self.components.get_typed_mut::<Children>(&parent_id) {
+            if let Some(children) = self.components.get_typed_mut::<Children>(&parent_id, self.change_tick) {
                 children.remove(entity_id);
             }
         }
@@ -262,7 +291,10 @@ impl World {
     ) -> Result<(), EcsError> {
         self.validate_entity(entity_id)?;
 
-        self.components.add(*entity_id, component);
+        if let Some(guid) = (&component as &dyn std::any::Any).downcast_ref::<Guid>() {
+            self.index_guid(*entity_id, *guid);
+        }
+        self.components.add(*entity_id, component, self.change_tick);
         Ok(())
     }
 
@@ -270,6 +302,9 @@ impl World {
     pub fn remove_component<T: Component>(&mut self, entity_id: &EntityId) -> Result<(), EcsError> {
         self.validate_entity(entity_id)?;
 
+        if std::any::TypeId::of::<T>() == std::any::TypeId::of::<Guid>() {
+            self.unindex_guid(entity_id);
+        }
         if self.components.remove::<T>(entity_id).is_none() {
             return Err(EcsError::ComponentNotFound(*entity_id));
         }
@@ -287,14 +322,15 @@ impl World {
         self.components.get_typed::<T>(&entity_id)
     }
 
-    /// Get a typed mutable reference to a component for an entity.
+    /// Get a typed mutable reference to a component for an entity, marking
+    /// it changed (see [`change_detection`](crate::change_detection)).
     ///
     /// Returns `None` if the entity is dead, stale, or lacks the component.
     pub fn get_mut<T: Component>(&mut self, entity_id: EntityId) -> Option<&mut T> {
         if self.validate_entity(&entity_id).is_err() {
             return None;
         }
-        self.components.get_typed_mut::<T>(&entity_id)
+        self.components.get_typed_mut::<T>(&entity_id, self.change_tick)
     }
 
     /// Check if an entity has a component
@@ -342,7 +378,8 @@ impl World {
         self.entities.len()
     }
 
-    /// Get a snapshot of all entity IDs as an owned Vec.
+    /// Get a snapshot of all entity IDs as an owned Vec, including entities
+    /// parked with [`Inactive`](crate::pool::Inactive) (see `active_entities()`).
     ///
     /// Useful when you need to mutate the world while iterating. For
     /// read-only iteration prefer `entity_ids()`, which does not allocate.
@@ -386,7 +423,8 @@ impl World {
 
     /// Query for entities with specific component types.
     ///
-    /// Returns a vector of entity IDs that have all required component types.
+    /// Returns a vector of entity IDs that have all required component types,
+    /// parked entities included (systems use `query_active`).
     ///
     /// # Example
     /// ```
@@ -406,8 +444,16 @@ impl World {
     /// ```
     pub fn query_entities<Q: QueryTypes>(&self) -> Vec<EntityId> {
         let required_types = Q::component_types();
-        self.entities
-            .keys()
+        // Walk the smallest store and probe the others' sparse indices
+        let Some(smallest) = required_types
+            .iter()
+            .map(|type_id| self.components.entities_with(*type_id))
+            .min_by_key(|entities| entities.len())
+        else {
+            return self.entities();
+        };
+        smallest
+            .iter()
             .filter(|entity| {
                 required_types
                     .iter()
@@ -417,6 +463,38 @@ impl World {
             .collect()
     }
 
+    /// Iterate every `T` component with its entity, straight over the
+    /// type's contiguous storage. Much faster than `query_entities` + `get`
+    /// for single-component passes over large worlds. Parked entities are
+    /// included (see `iter_active`).
+    ///
+    /// ```
+    /// # use ecs::{World, Transform2D};
+    /// # use glam::Vec2;
+    /// let mut world = World::new();
+    /// world.spawn((Transform2D::new(Vec2::new(3.0, 0.0)),));
+    /// let total_x: f32 = world.iter::<Transform2D>().map(|(_, t)| t.position.x).sum();
+    /// # assert_eq!(total_x, 3.0);
+    /// ```
+    pub fn iter<T: Component>(&self) -> impl Iterator<Item = (EntityId, &T)> + '_ {
+        self.components.store::<T>().into_iter().flat_map(|store| store.iter())
+    }
+
+    /// Iterate every `T` component mutably, marking each one changed (as
+    /// `get_mut` would).
+    pub fn iter_mut<T: Component>(&mut self) -> impl Iterator<Item = (EntityId, &mut T)> + '_ {
+        let tick = self.change_tick;
+        self.components
+            .store_mut::<T>()
+            .into_iter()
+            .flat_map(move |store| store.iter_mut(tick))
+    }
+
+    /// Number of entities with a `T` component
+    pub fn count<T: Component>(&self) -> usize {
+        self.components.store::<T>().map_or(0, |store| store.len())
+    }
+
     /// Remove all entities and components from the world.
     ///
     /// Clears entities, generations, and component storage. Does not
@@ -424,10 +502,12 @@ impl World {
     pub fn clear(&mut self) {
         self.entities.clear();
         self.entity_generations.clear();
+        self.guids.clear();
         self.components.shutdown().ok();
     }
 
     /// Create an entity with a specific ID (for snapshot restoration only).
+    /// No [`Guid`] is assigned — restore the saved one with `add_component`.
     ///
     /// Revives the ID's generation as alive: stale references carrying the
     /// same `(id, generation)` will validate again afterwards. That is
@@ -449,55 +529,6 @@ impl World {
         id
     }
 
-    // --- Resources (typed singleton state) ---
-
-    /// Insert a resource, replacing any previous value of the same type.
-    pub fn insert_resource<T: Send + Sync + 'static>(&mut self, resource: T) {
-        self.resources.insert(resource);
-    }
-
-    /// Get an immutable reference to a resource by type.
-    pub fn resource<T: Send + Sync + 'static>(&self) -> Option<&T> {
-        self.resources.get::<T>()
-    }
-
-    /// Get a mutable reference to a resource by type.
-    pub fn resource_mut<T: Send + Sync + 'static>(&mut self) -> Option<&mut T> {
-        self.resources.get_mut::<T>()
-    }
-
-    /// Remove a resource by type, returning it if it existed.
-    pub fn remove_resource<T: Send + Sync + 'static>(&mut self) -> Option<T> {
-        self.resources.remove::<T>()
-    }
-
-    /// Check if a resource of the given type exists.
-    pub fn has_resource<T: Send + Sync + 'static>(&self) -> bool {
-        self.resources.contains::<T>()
-    }
-
-    // --- Events (typed per-frame messaging) ---
-
-    /// Emit an event. Readable by any system until the next `flush_events()`.
-    pub fn emit_event<E: Send + Sync + 'static>(&mut self, event: E) {
-        self.events.emit(event);
-    }
-
-    /// Read all events of type `E` emitted since the last flush.
-    pub fn read_events<E: Send + Sync + 'static>(&self) -> &[E] {
-        self.events.read::<E>()
-    }
-
-    /// Check if there are any pending events of type `E`.
-    pub fn has_events<E: Send + Sync + 'static>(&self) -> bool {
-        self.events.has_events::<E>()
-    }
-
-    /// Clear all event queues. Call at the end of each frame.
-    pub fn flush_events(&mut self) {
-        self.events.flush();
-    }
-
     /// Get world configuration
     pub fn config(&self) -> &WorldConfig {
         &self.config
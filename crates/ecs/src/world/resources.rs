@@ -0,0 +1,69 @@
+//! Resource and event forwarding on [`World`].
+
+use super::World;
+
+impl World {
+    // --- Resources (typed singleton state) ---
+
+    /// Insert a resource, replacing any previous value of the same type.
+    pub fn insert_resource<T: Send + Sync + 'static>(&mut self, resource: T) {
+        self.resources.insert(resource);
+    }
+
+    /// Get an immutable reference to a resource by type.
+    pub fn resource<T: Send + Sync + 'static>(&self) -> Option<&T> {
+        self.resources.get::<T>()
+    }
+
+    /// Get a mutable reference to a resource by type.
+    pub fn resource_mut<T: Send + Sync + 'static>(&mut self) -> Option<&mut T> {
+        self.resources.get_mut::<T>()
+    }
+
+    /// Insert `T::default()` unless a `T` resource already exists. Lets
+    /// several systems share a singleton without agreeing on who creates it
+    /// (call from each one's `System::initialize`).
+    pub fn init_resource<T: Default + Send + Sync + 'static>(&mut self) {
+        if !self.resources.contains::<T>() {
+            self.resources.insert(T::default());
+        }
+    }
+
+    /// Remove a resource by type, returning it if it existed.
+    pub fn remove_resource<T: Send + Sync + 'static>(&mut self) -> Option<T> {
+        self.resources.remove::<T>()
+    }
+
+    /// Check if a resource of the given type exists.
+    pub fn has_resource<T: Send + Sync + 'static>(&self) -> bool {
+        self.resources.contains::<T>()
+    }
+
+    /// Current fixed-update tick ([`SimulationTick`](crate::SimulationTick)),
+    /// or 0 when no engine loop has advanced it.
+    pub fn simulation_tick(&self) -> u64 {
+        self.resource::<crate::resource::SimulationTick>().map_or(0, |tick| tick.0)
+    }
+
+    // --- Events (typed per-frame messaging) ---
+
+    /// Emit an event. Readable by any system until the next `flush_events()`.
+    pub fn emit_event<E: Send + Sync + 'static>(&mut self, event: E) {
+        self.events.emit(event);
+    }
+
+    /// Read all events of type `E` emitted since the last flush.
+    pub fn read_events<E: Send + Sync + 'static>(&self) -> &[E] {
+        self.events.read::<E>()
+    }
+
+    /// Check if there are any pending events of type `E`.
+    pub fn has_events<E: Send + Sync + 'static>(&self) -> bool {
+        self.events.has_events::<E>()
+    }
+
+    /// Clear all event queues. Call at the end of each frame.
+    pub fn flush_events(&mut self) {
+        self.events.flush();
+    }
+}
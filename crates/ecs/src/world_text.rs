@@ -0,0 +1,329 @@
+//! Text drawn in the world: NPC names, signs, floating damage numbers.
+//!
+//! A [`WorldText`] is anchored at its entity's world position (plus
+//! `offset`) and sorts against sprites by `layer` and `depth`, the same
+//! way [`Sprite`](crate::sprite_components::Sprite) does. The engine lays
+//! it out with the UI's default font at `font_size` world units and draws
+//! its glyphs in the sprite pass; rotation and scale of the entity are
+//! ignored, so text stays upright and readable.
+//!
+//! Floating combat text drifts by `velocity` and fades over
+//! `fade_duration`. [`WorldTextSystem`] advances both every frame and
+//! removes the entity of a [`WorldText::floating`] label once it has
+//! faded out.
+//!
+//! ```
+//! use ecs::world_text::{WorldText, WorldTextSystem};
+//! use ecs::{System, Transform2D, World};
+//! use glam::{Vec2, Vec4};
+//!
+//! let mut world = World::new();
+//! let hit = world.create_entity();
+//! world.add_component(&hit, Transform2D::new(Vec2::new(40.0, 0.0))).unwrap();
+//! world
+//!     .add_component(&hit, WorldText::floating("-12", Vec2::new(0.0, 30.0), 0.5).with_color(Vec4::new(1.0, 0.3, 0.2, 1.0)))
+//!     .unwrap();
+//!
+//! let mut texts = WorldTextSystem::new();
+//! texts.update(&mut world, 0.25);
+//! let text = world.get::<WorldText>(hit).unwrap();
+//! assert_eq!(text.drift(), Vec2::new(0.0, 7.5));
+//! assert_eq!(text.current_color().w, 0.5);
+//!
+//! texts.update(&mut world, 0.25);
+//! assert!(world.get::<WorldText>(hit).is_none(), "faded out and despawned");
+//! ```
+
+use glam::{Vec2, Vec4};
+use serde::{Deserialize, Serialize};
+
+use crate::component_registry::ComponentMeta;
+use crate::entity::EntityId;
+use crate::query::Single;
+use crate::system::System;
+use crate::world::World;
+use crate::DeriveComponentMeta;
+
+/// Which part of the text sits on the anchor horizontally. Vertically the
+/// text is always centered on the anchor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum TextAlignment {
+    /// The text starts at the anchor
+    Left,
+    /// The text is centered on the anchor
+    #[default]
+    Center,
+    /// The text ends at the anchor
+    Right,
+}
+
+impl TextAlignment {
+    /// Horizontal offset from the anchor to the left edge of text `width`
+    /// wide.
+    pub fn left_edge(self, width: f32) -> f32 {
+        match self {
+            TextAlignment::Left => 0.0,
+            TextAlignment::Center => -width * 0.5,
+            TextAlignment::Right => -width,
+        }
+    }
+}
+
+/// Component: a line of text rendered at the entity's world position.
+#[derive(Debug, Clone, Serialize, Deserialize, DeriveComponentMeta)]
+pub struct WorldText {
+    /// The text; `\n` starts a new line
+    pub text: String,
+    /// Glyph size in world units
+    #[serde(default = "default_font_size")]
+    pub font_size: f32,
+    /// RGBA color
+    #[serde(default = "default_color")]
+    pub color: Vec4,
+    /// Horizontal placement against the anchor
+    #[serde(default)]
+    pub alignment: TextAlignment,
+    /// Anchor offset from the entity position (e.g. above a character's
+    /// head)
+    #[serde(default)]
+    pub offset: Vec2,
+    /// Depth for sorting within the layer, same convention as
+    /// `Sprite::depth`
+    #[serde(default)]
+    pub depth: f32,
+    /// Render layer name, same convention as `Sprite::layer`
+    #[serde(default = "default_layer")]
+    pub layer: String,
+    /// Whether the text is drawn
+    #[serde(default = "default_visible")]
+    pub visible: bool,
+    /// Drift in world units per second (zero for static labels)
+    #[serde(default)]
+    pub velocity: Vec2,
+    /// Seconds over which the text fades to transparent (0 = never fades)
+    #[serde(default)]
+    pub fade_duration: f32,
+    /// Whether the entity is despawned once the text has faded out
+    #[serde(default)]
+    pub despawn_when_faded: bool,
+    /// Seconds since the text appeared
+    #[serde(skip)]
+    age: f32,
+    /// Distance drifted so far
+    #[serde(skip)]
+    drift: Vec2,
+}
+
+fn default_font_size() -> f32 { 16.0 }
+fn default_color() -> Vec4 { Vec4::ONE }
+fn default_layer() -> String { crate::render_layers::DEFAULT_LAYER.to_string() }
+fn default_visible() -> bool { true }
+
+impl Default for WorldText {
+    fn default() -> Self {
+        Self::new("")
+    }
+}
+
+impl WorldText {
+    /// White 16-unit text centered on its entity.
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            font_size: default_font_size(),
+            color: default_color(),
+            alignment: TextAlignment::Center,
+            offset: Vec2::ZERO,
+            depth: 0.0,
+            layer: default_layer(),
+            visible: true,
+            velocity: Vec2::ZERO,
+            fade_duration: 0.0,
+            despawn_when_faded: false,
+            age: 0.0,
+            drift: Vec2::ZERO,
+        }
+    }
+
+    /// Floating combat text: drifts by `velocity`, fades out over
+    /// `seconds`, then despawns its entity. Give it an entity of its own.
+    pub fn floating(text: impl Into<String>, velocity: Vec2, seconds: f32) -> Self {
+        let mut floating = Self::new(text).with_velocity(velocity).with_fade(seconds);
+        floating.despawn_when_faded = true;
+        floating
+    }
+
+    /// Set the glyph size in world units (builder-style).
+    pub fn with_font_size(mut self, font_size: f32) -> Self {
+        self.font_size = font_size;
+        self
+    }
+
+    /// Set the color.
+    pub fn with_color(mut self, color: Vec4) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Set the horizontal alignment.
+    pub fn with_alignment(mut self, alignment: TextAlignment) -> Self {
+        self.alignment = alignment;
+        self
+    }
+
+    /// Set the anchor offset from the entity position.
+    pub fn with_offset(mut self, offset: Vec2) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Set the depth within the layer.
+    pub fn with_depth(mut self, depth: f32) -> Self {
+        self.depth = depth;
+        self
+    }
+
+    /// Set the render layer.
+    pub fn with_layer(mut self, layer: impl Into<String>) -> Self {
+        self.layer = layer.into();
+        self
+    }
+
+    /// Set the drift velocity in world units per second.
+    pub fn with_velocity(mut self, velocity: Vec2) -> Self {
+        self.velocity = velocity;
+        self
+    }
+
+    /// Fade to transparent over `seconds` (0 = never fade).
+    pub fn with_fade(mut self, seconds: f32) -> Self {
+        self.fade_duration = seconds.max(0.0);
+        self
+    }
+
+    /// Seconds since the text appeared.
+    pub fn age(&self) -> f32 {
+        self.age
+    }
+
+    /// Distance drifted so far.
+    pub fn drift(&self) -> Vec2 {
+        self.drift
+    }
+
+    /// World-space anchor for an entity at `position`: the offset plus the
+    /// distance drifted.
+    pub fn anchor(&self, position: Vec2) -> Vec2 {
+        position + self.offset + self.drift
+    }
+
+    /// `color` with the fade applied.
+    pub fn current_color(&self) -> Vec4 {
+        if self.fade_duration <= 0.0 {
+            return self.color;
+        }
+        let remaining = (1.0 - self.age / self.fade_duration).clamp(0.0, 1.0);
+        self.color.truncate().extend(self.color.w * remaining)
+    }
+
+    /// Whether the text has fully faded out.
+    pub fn is_faded(&self) -> bool {
+        self.fade_duration > 0.0 && self.age >= self.fade_duration
+    }
+
+    /// Advance drift and fade by `delta_time` seconds.
+    pub fn advance(&mut self, delta_time: f32) {
+        self.age += delta_time;
+        self.drift += self.velocity * delta_time;
+    }
+}
+
+/// System: advances every [`WorldText`]'s drift and fade and despawns
+/// faded-out floating text. The engine's game loop runs it each frame
+/// before laying the text out.
+#[derive(Debug, Default)]
+pub struct WorldTextSystem;
+
+impl WorldTextSystem {
+    /// Create a new world-text system.
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl System for WorldTextSystem {
+    fn update(&mut self, world: &mut World, delta_time: f32) {
+        let texts: Vec<EntityId> = world.query_active::<Single<WorldText>>();
+        for entity in texts {
+            let expired = world
+                .get_mut::<WorldText>(entity)
+                .map(|text| {
+                    text.advance(delta_time);
+                    text.despawn_when_faded && text.is_faded()
+                })
+                .unwrap_or(false);
+            if expired {
+                world.remove_entity(&entity).ok();
+            }
+        }
+    }
+
+    fn name(&self) -> &str {
+        "WorldTextSystem"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_alignment_places_left_edge_against_anchor() {
+        assert_eq!(TextAlignment::Left.left_edge(40.0), 0.0);
+        assert_eq!(TextAlignment::Center.left_edge(40.0), -20.0);
+        assert_eq!(TextAlignment::Right.left_edge(40.0), -40.0);
+    }
+
+    #[test]
+    fn test_static_text_never_fades_or_despawns() {
+        let mut world = World::new();
+        let sign = world.create_entity();
+        world.add_component(&sign, WorldText::new("Shop").with_offset(Vec2::new(0.0, 24.0))).unwrap();
+
+        let mut system = WorldTextSystem::new();
+        for _ in 0..100 {
+            system.update(&mut world, 0.1);
+        }
+        let text = world.get::<WorldText>(sign).expect("labels stay");
+        assert_eq!(text.current_color(), Vec4::ONE);
+        assert_eq!(text.anchor(Vec2::new(10.0, 0.0)), Vec2::new(10.0, 24.0));
+    }
+
+    #[test]
+    fn test_fading_text_without_despawn_keeps_its_entity() {
+        let mut text = WorldText::new("Checkpoint").with_fade(1.0);
+        text.advance(0.5);
+        assert_eq!(text.current_color().w, 0.5);
+        text.advance(1.0);
+        assert!(text.is_faded());
+        assert_eq!(text.current_color().w, 0.0);
+        assert!(!text.despawn_when_faded);
+    }
+
+    #[test]
+    fn test_world_text_round_trips_without_runtime_state() {
+        let mut text = WorldText::floating("CRIT", Vec2::new(0.0, 40.0), 0.8).with_alignment(TextAlignment::Left);
+        text.advance(0.4);
+        let ron_text = ron::to_string(&text).unwrap();
+        let restored: WorldText = ron::from_str(&ron_text).unwrap();
+        assert_eq!(restored.alignment, TextAlignment::Left);
+        assert!(restored.despawn_when_faded);
+        assert_eq!(restored.age(), 0.0, "age is runtime state");
+        assert_eq!(restored.drift(), Vec2::ZERO);
+
+        let minimal: WorldText = ron::from_str(r#"(text: "Guard")"#).unwrap();
+        assert_eq!(minimal.font_size, 16.0);
+        assert_eq!(minimal.layer, crate::DEFAULT_LAYER);
+        assert!(minimal.visible);
+    }
+}
@@ -317,6 +317,44 @@ fn test_sprite_animation_reset() {
     assert_eq!(animation.time_accumulator, 0.0);
 }
 
+#[test]
+fn test_sprite_animation_events_fire_on_crossed_frames() {
+    let mut animation = SpriteAnimation::new(10.0, vec![[0.0, 0.0, 0.25, 1.0]; 4])
+        .with_event(0, "loop")
+        .with_event(2, "footstep");
+
+    assert!(animation.update_with_events(0.05).is_empty());
+    // One long step crosses frames 1 and 2
+    let fired = animation.update_with_events(0.21);
+    assert_eq!(fired.iter().map(|e| e.name.as_str()).collect::<Vec<_>>(), ["footstep"]);
+    // Wrapping back to frame 0 enters it again
+    let fired = animation.update_with_events(0.2);
+    assert_eq!(fired, vec![AnimationEvent { frame: 0, name: "loop".into() }]);
+
+    // Stopping on the last frame does not re-enter it
+    let mut once = SpriteAnimation::new(10.0, vec![[0.0, 0.0, 1.0, 1.0]; 2])
+        .with_loop(false)
+        .with_event(1, "end");
+    assert_eq!(once.update_with_events(0.1).len(), 1);
+    assert!(once.update_with_events(0.5).is_empty());
+}
+
+#[test]
+fn test_sprite_animation_system_emits_events() {
+    use ecs::{SpriteAnimationSystem, System, World};
+
+    let mut world = World::new();
+    let entity = world.create_entity();
+    let animation = SpriteAnimation::new(10.0, vec![[0.0, 0.0, 0.5, 1.0]; 2]).with_event(1, "hit");
+    world.add_component(&entity, animation).unwrap();
+
+    SpriteAnimationSystem.update(&mut world, 0.1);
+    assert_eq!(
+        world.read_events::<AnimationEventFired>(),
+        [AnimationEventFired { entity, name: "hit".into(), frame: 1 }]
+    );
+}
+
 #[test]
 fn test_component_trait() {
     // Test that all sprite components implement the Component trait
@@ -341,7 +379,7 @@ fn test_sprite_animation_component_meta() {
     assert_eq!(<SpriteAnimation as ComponentMeta>::type_name(), "SpriteAnimation");
 
     let fields = <SpriteAnimation as ComponentMeta>::field_names();
-    assert_eq!(fields, &["current_frame", "fps", "playing", "loop_animation", "time_accumulator", "frames"]);
+    assert_eq!(fields, &["current_frame", "fps", "playing", "loop_animation", "time_accumulator", "frames", "events"]);
 }
 
 #[test]
@@ -350,7 +388,7 @@ fn test_sprite_component_meta() {
 
     assert_eq!(<Sprite as ComponentMeta>::type_name(), "Sprite");
     let fields = <Sprite as ComponentMeta>::field_names();
-    assert_eq!(fields, &["offset", "rotation", "scale", "tex_region", "color", "depth", "visible", "emissive", "texture_handle"]);
+    assert_eq!(fields, &["offset", "rotation", "scale", "tex_region", "color", "depth", "layer", "visible", "emissive", "texture_handle"]);
 }
 
 #[test]
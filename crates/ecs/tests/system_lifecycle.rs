@@ -339,6 +339,24 @@ fn test_panic_recovery_in_systems() {
     registry.update_all(&mut world, 0.016);
 }
 
+#[test]
+fn test_update_all_times_each_system_in_order() {
+    let mut registry = SystemRegistry::new();
+    let mut world = World::new();
+    registry.add(TestSystem::new("First"));
+    registry.add_simple("Sleeper", |_, _| std::thread::sleep(std::time::Duration::from_millis(2)));
+    assert!(registry.last_timings().is_empty());
+
+    registry.initialize(&mut world).unwrap();
+    registry.start(&mut world).unwrap();
+    registry.update_all(&mut world, 0.016);
+
+    let timings = registry.last_timings();
+    let names: Vec<&str> = timings.iter().map(|timing| timing.name.as_str()).collect();
+    assert_eq!(names, ["First", "Sleeper"]);
+    assert!(timings[1].millis() >= 2.0, "{:?}", timings[1]);
+}
+
 #[test]
 fn test_world_lifecycle_integration() {
     let mut world = World::new();
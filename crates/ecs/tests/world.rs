@@ -180,6 +180,36 @@ fn test_query_entities() {
     assert!(with_both.contains(&entity_with_both));
 }
 
+#[test]
+fn test_iter_walks_components_after_despawns() {
+    use ecs::sprite_components::{Sprite, Transform2D};
+
+    let mut world = World::new();
+    let entities: Vec<EntityId> = (0..5)
+        .map(|i| world.spawn((Transform2D::new(glam::Vec2::new(i as f32, 0.0)),)).id())
+        .collect();
+    world.add_component(&entities[2], Sprite::new(0)).unwrap();
+    world.remove_entity(&entities[0]).unwrap();
+    world.remove_component::<Transform2D>(&entities[3]).unwrap();
+
+    let mut xs: Vec<f32> = world.iter::<Transform2D>().map(|(_, t)| t.position.x).collect();
+    xs.sort_by(f32::total_cmp);
+    assert_eq!(xs, [1.0, 2.0, 4.0]);
+    assert_eq!(world.count::<Transform2D>(), 3);
+    assert_eq!(world.count::<ecs::Name>(), 0);
+
+    // Lookups stay correct after swap-removal reshuffled the storage
+    assert_eq!(world.get::<Transform2D>(entities[4]).map(|t| t.position.x), Some(4.0));
+    assert_eq!(world.query_entities::<ecs::Pair<Sprite, Transform2D>>(), vec![entities[2]]);
+
+    world.clear_trackers();
+    for (_, transform) in world.iter_mut::<Transform2D>() {
+        transform.position.y = 1.0;
+    }
+    assert!(world.is_changed::<Transform2D>(entities[1]));
+    assert_eq!(world.get::<Transform2D>(entities[4]).map(|t| t.position.y), Some(1.0));
+}
+
 #[test]
 fn test_world_clear_removes_entities_and_components() {
     use ecs::sprite_components::Transform2D;
@@ -231,12 +261,12 @@ fn test_clear_then_create_entity_with_id() {
     assert_eq!(t.position, glam::Vec2::new(30.0, 40.0));
 }
 
-// === EntityBuilder (world.spawn()) tests ===
+// === Bundle spawning (world.spawn(bundle)) tests ===
 
 #[test]
 fn test_spawn_creates_entity() {
     let mut world = World::new();
-    let entity = world.spawn().id();
+    let entity = world.spawn(()).id();
 
     assert_eq!(world.entity_count(), 1);
     assert!(world.get_entity(&entity).is_ok());
@@ -247,9 +277,7 @@ fn test_spawn_with_single_component() {
     use ecs::sprite_components::Transform2D;
 
     let mut world = World::new();
-    let entity = world.spawn()
-        .with(Transform2D::new(glam::Vec2::new(10.0, 20.0)))
-        .id();
+    let entity = world.spawn((Transform2D::new(glam::Vec2::new(10.0, 20.0)),)).id();
 
     assert!(world.has_component::<Transform2D>(&entity).unwrap());
     let t = world.get::<Transform2D>(entity).unwrap();
@@ -261,10 +289,7 @@ fn test_spawn_with_multiple_components() {
     use ecs::sprite_components::{Transform2D, Sprite};
 
     let mut world = World::new();
-    let entity = world.spawn()
-        .with(Transform2D::new(glam::Vec2::new(5.0, 5.0)))
-        .with(Sprite::new(42))
-        .id();
+    let entity = world.spawn((Transform2D::new(glam::Vec2::new(5.0, 5.0)), Sprite::new(42))).id();
 
     assert!(world.has_component::<Transform2D>(&entity).unwrap());
     assert!(world.has_component::<Sprite>(&entity).unwrap());
@@ -276,12 +301,8 @@ fn test_spawn_returns_correct_entity_id() {
     use ecs::sprite_components::Transform2D;
 
     let mut world = World::new();
-    let e1 = world.spawn()
-        .with(Transform2D::new(glam::Vec2::new(1.0, 0.0)))
-        .id();
-    let e2 = world.spawn()
-        .with(Transform2D::new(glam::Vec2::new(2.0, 0.0)))
-        .id();
+    let e1 = world.spawn((Transform2D::new(glam::Vec2::new(1.0, 0.0)),)).id();
+    let e2 = world.spawn((Transform2D::new(glam::Vec2::new(2.0, 0.0)),)).id();
 
     assert_ne!(e1, e2);
     assert_eq!(world.get::<Transform2D>(e1).unwrap().position.x, 1.0);
@@ -293,12 +314,8 @@ fn test_spawn_multiple_entities_independent() {
     use ecs::sprite_components::{Transform2D, Sprite};
 
     let mut world = World::new();
-    let e1 = world.spawn()
-        .with(Transform2D::new(glam::Vec2::ZERO))
-        .id();
-    let e2 = world.spawn()
-        .with(Sprite::new(7))
-        .id();
+    let e1 = world.spawn((Transform2D::new(glam::Vec2::ZERO),)).id();
+    let e2 = world.spawn((Sprite::new(7),)).id();
 
     assert_eq!(world.entity_count(), 2);
     assert!(world.has_component::<Transform2D>(&e1).unwrap());
@@ -307,6 +324,45 @@ fn test_spawn_multiple_entities_independent() {
     assert!(world.has_component::<Sprite>(&e2).unwrap());
 }
 
+#[test]
+fn test_spawn_bundle_then_with_and_insert() {
+    use ecs::sprite_components::{Name, Sprite, Transform2D};
+
+    let mut world = World::new();
+    let entity = world
+        .spawn((Transform2D::new(glam::Vec2::new(3.0, 4.0)), Sprite::new(1)))
+        .with(Name::new("player"))
+        .insert((GlobalTransform2D::default(),))
+        .id();
+
+    assert!(world.has_component::<GlobalTransform2D>(&entity).unwrap());
+    assert_eq!(world.get::<Name>(entity).unwrap().as_str(), "player");
+    assert_eq!(world.get::<Sprite>(entity).unwrap().texture_handle, 1);
+}
+
+#[test]
+fn test_bundle_repeated_type_keeps_last_value() {
+    use ecs::sprite_components::Sprite;
+
+    let mut world = World::new();
+    let entity = world.spawn((Sprite::new(1), Sprite::new(2))).id();
+    assert_eq!(world.get::<Sprite>(entity).unwrap().texture_handle, 2);
+}
+
+#[test]
+fn test_insert_bundle_rejects_dead_entity() {
+    use ecs::sprite_components::{Sprite, Transform2D};
+
+    let mut world = World::new();
+    let entity = world.spawn(()).id();
+    world.insert_bundle(entity, (Transform2D::default(), Sprite::new(0))).unwrap();
+    assert!(world.has_component::<Sprite>(&entity).unwrap());
+
+    world.remove_entity(&entity).unwrap();
+    assert!(world.insert_bundle(entity, (Sprite::new(0),)).is_err());
+    assert!(world.insert_bundle(entity, ()).is_err());
+}
+
 // --- Stale entity ID rejection (generation validation in component ops) ---
 
 #[test]
@@ -441,3 +497,108 @@ fn test_deep_hierarchy_ancestor_descendant_queries() {
     assert_eq!(world.get_ancestors(leaf).len(), 50);
     assert_eq!(world.get_descendants(root).len(), 50);
 }
+
+#[test]
+fn test_init_resource_inserts_default_only_when_missing() {
+    #[derive(Default)]
+    struct Score(u32);
+
+    let mut world = World::new();
+    world.init_resource::<Score>();
+    if let Some(score) = world.resource_mut::<Score>() {
+        score.0 = 7;
+    }
+    // A second system initializing the same resource must not reset it.
+    world.init_resource::<Score>();
+    assert_eq!(world.resource::<Score>().map(|s| s.0), Some(7));
+}
+
+#[test]
+fn test_simulation_tick_defaults_to_zero_and_survives_clear() {
+    let mut world = World::new();
+    assert_eq!(world.simulation_tick(), 0);
+
+    world.insert_resource(SimulationTick(42));
+    world.clear();
+    assert_eq!(world.simulation_tick(), 42);
+}
+
+#[test]
+fn test_entities_get_unique_indexed_guids() {
+    let mut world = World::new();
+    let a = world.create_entity();
+    let b = world.create_entity();
+    let (guid_a, guid_b) = (world.guid_of(a).unwrap(), world.guid_of(b).unwrap());
+    assert_ne!(guid_a, guid_b);
+    assert_eq!(world.entity_by_guid(guid_a), Some(a));
+
+    world.remove_entity(&a).unwrap();
+    assert_eq!(world.entity_by_guid(guid_a), None);
+
+    // Replacing a GUID re-indexes the entity under the new value
+    let restored = Guid::from_u128(42);
+    world.add_component(&b, restored).unwrap();
+    assert_eq!(world.entity_by_guid(restored), Some(b));
+    assert_eq!(world.entity_by_guid(guid_b), None);
+
+    world.remove_component::<Guid>(&b).unwrap();
+    assert_eq!(world.entity_by_guid(restored), None);
+    assert_eq!(EntityRef::new(restored).resolve(&world), None);
+}
+
+#[test]
+fn test_snapshot_ids_restore_their_saved_guid() {
+    let mut world = World::new();
+    let entity = world.create_entity();
+    let guid = world.guid_of(entity).unwrap();
+    let reference = EntityRef::to(&world, entity).unwrap();
+
+    world.clear();
+    assert_eq!(reference.resolve(&world), None);
+    let restored = world.create_entity_with_id(entity);
+    assert_eq!(world.guid_of(restored), None, "snapshot restore supplies the GUID");
+    world.add_component(&restored, guid).unwrap();
+    assert_eq!(reference.resolve(&world), Some(entity));
+}
+
+#[test]
+fn test_despawn_recursive_removes_the_whole_subtree() {
+    let mut world = World::new();
+    let root = world.create_entity();
+    let branch = world.create_entity();
+    let leaf = world.create_entity();
+    let sibling = world.create_entity();
+    world.set_parent(branch, root).unwrap();
+    world.set_parent(leaf, branch).unwrap();
+    world.set_parent(sibling, root).unwrap();
+
+    world.despawn_recursive(branch).unwrap();
+    assert!(world.validate_entity(&leaf).is_err(), "descendants go with it");
+    assert_eq!(world.get_children(root), Some(&[sibling][..]));
+    assert!(world.despawn_recursive(branch).is_err(), "already gone");
+}
+
+#[test]
+fn test_world_commands_apply_in_order_at_flush() {
+    let mut world = World::new();
+    let parent = world.create_entity();
+    let child = world.create_entity();
+    world.set_parent(child, parent).unwrap();
+
+    world.commands().insert(parent, ecs::Name::new("doomed"));
+    world.commands().despawn_recursive(parent);
+    world.commands().despawn(parent);
+    world.commands().spawn_then((ecs::Name::new("spawned"),), |world, entity| {
+        world.commands().despawn(entity);
+    });
+    assert_eq!(world.commands().len(), 4);
+    assert_eq!(world.entity_count(), 2, "nothing happens until the flush");
+
+    world.apply_commands();
+    assert!(world.validate_entity(&child).is_err());
+    assert_eq!(world.entity_count(), 1, "only the spawned entity is left");
+    assert_eq!(world.commands().len(), 1, "commands queued while applying wait");
+
+    world.apply_commands();
+    assert_eq!(world.entity_count(), 0);
+}
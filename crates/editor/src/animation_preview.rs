@@ -0,0 +1,254 @@
+//! Sprite animation preview for the inspector.
+//!
+//! Game systems don't run in Edit mode, so the SpriteAnimation inspector
+//! plays the animation itself: [`AnimationPreview`] advances the selected
+//! entity's frame each editor frame and puts the authored frame back when
+//! the preview stops. The inspector rows (Play/Pause button, frame
+//! scrubber, per-frame regions) live here too.
+
+use ecs::sprite_components::SpriteAnimation;
+use ecs::{EntityId, World};
+use glam::Vec2;
+use ui::{Rect, TextAlign, UIContext};
+
+use crate::editable_inspector::{EditResult, EditableInspector};
+use crate::field_style::{EditableFieldStyle, FieldId};
+use crate::sprite_region::named_region_text;
+
+/// The entity being previewed and the frame state to restore.
+#[derive(Debug, Clone, Copy)]
+struct PreviewTarget {
+    entity: EntityId,
+    start_frame: usize,
+    start_accumulator: f32,
+    /// Frame the preview last showed; any other value means the frame was
+    /// set from outside (scrubber, undo) and the preview yields to it.
+    shown_frame: usize,
+}
+
+/// Edit-mode playback of one entity's [`SpriteAnimation`].
+///
+/// The preview writes `current_frame` directly (no undo entries, no dirty
+/// flag) and always loops, so one-shot animations can be watched
+/// repeatedly.
+#[derive(Debug, Clone, Default)]
+pub struct AnimationPreview {
+    target: Option<PreviewTarget>,
+}
+
+impl AnimationPreview {
+    /// Create an idle preview.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether `entity`'s animation is being previewed.
+    pub fn is_previewing(&self, entity: EntityId) -> bool {
+        self.target.is_some_and(|target| target.entity == entity)
+    }
+
+    /// The previewed entity, if any.
+    pub fn entity(&self) -> Option<EntityId> {
+        self.target.map(|target| target.entity)
+    }
+
+    /// Start previewing `entity` (stopping any other preview), or stop if it
+    /// is already being previewed.
+    pub fn toggle(&mut self, world: &mut World, entity: EntityId) {
+        if self.is_previewing(entity) {
+            self.stop(world);
+            return;
+        }
+        self.stop(world);
+        if let Some(animation) = world.get::<SpriteAnimation>(entity) {
+            self.target = Some(PreviewTarget {
+                entity,
+                start_frame: animation.current_frame,
+                start_accumulator: animation.time_accumulator,
+                shown_frame: animation.current_frame,
+            });
+        }
+    }
+
+    /// Stop previewing and restore the authored frame.
+    pub fn stop(&mut self, world: &mut World) {
+        if let Some(target) = self.target.take() {
+            if let Some(animation) = world.get_mut::<SpriteAnimation>(target.entity) {
+                if animation.current_frame == target.shown_frame {
+                    animation.current_frame = target.start_frame;
+                    animation.time_accumulator = target.start_accumulator;
+                }
+            }
+        }
+    }
+
+    /// Forget the preview without touching the world (the world it ran in
+    /// was replaced: scene load, new scene, tab switch).
+    pub fn clear(&mut self) {
+        self.target = None;
+    }
+
+    /// Advance the preview by `delta_time`. Stops (restoring the authored
+    /// frame) when the entity is no longer `selected`; ends without
+    /// restoring when the animation is gone or its frame was set from
+    /// outside.
+    pub fn tick(&mut self, world: &mut World, selected: Option<EntityId>, delta_time: f32) {
+        let Some(mut target) = self.target else {
+            return;
+        };
+        if selected != Some(target.entity) {
+            self.stop(world);
+            return;
+        }
+        let Some(animation) = world.get_mut::<SpriteAnimation>(target.entity) else {
+            self.target = None;
+            return;
+        };
+        if animation.current_frame != target.shown_frame {
+            self.target = None;
+            return;
+        }
+
+        let playing = animation.playing;
+        animation.playing = true;
+        animation.update(delta_time);
+        if !animation.playing {
+            // A one-shot animation reached its end: wrap for the preview
+            animation.current_frame = 0;
+            animation.time_accumulator = 0.0;
+        }
+        animation.playing = playing;
+
+        target.shown_frame = animation.current_frame;
+        self.target = Some(target);
+    }
+}
+
+impl EditableInspector<'_> {
+    /// Add a preview row with a Play/Pause button. Returns `true` when the
+    /// button is clicked.
+    pub fn animation_preview(&mut self, label: &str, previewing: bool) -> bool {
+        self.custom_row(|ui, id, pos, style| {
+            ui.label_styled(label, Vec2::new(pos.x, pos.y + 4.0), style.label_color, style.label_font);
+            let bounds = Rect::new(pos.x + style.label_width, pos.y + 2.0, 60.0, style.row_height - 4.0);
+            ui.button(id, if previewing { "Pause" } else { "Play" }, bounds)
+        })
+    }
+
+    /// Add a frame scrubber: a slider over `0..frame_count` plus an
+    /// "n / count" readout. Returns `Changed(frame)` while dragged.
+    pub fn frame_scrubber(&mut self, label: &str, frame: usize, frame_count: usize) -> EditResult<usize> {
+        self.custom_row(|ui, id, pos, style| edit_frame_scrubber(ui, id, label, frame, frame_count, pos, style))
+    }
+
+    /// Add one read-only row per frame showing its texture region (by atlas
+    /// name when `names` has it), with the current frame highlighted.
+    pub fn frame_regions(&mut self, frames: &[[f32; 4]], current: usize, names: &[(String, [f32; 4])]) {
+        for (index, region) in frames.iter().enumerate() {
+            self.custom_row(|ui, _id, pos, style| {
+                let color = if index == current { style.header_color } else { style.label_color };
+                let label = format!("Frame {index}");
+                ui.label_styled(&label, Vec2::new(pos.x, pos.y + 4.0), color, style.label_font);
+                let bounds = Rect::new(pos.x + style.label_width, pos.y + 2.0, style.input_width, style.row_height - 4.0);
+                ui.label_in_bounds_styled(&named_region_text(*region, names), bounds, TextAlign::Left, style.value_color, style.label_font, 0.0);
+            });
+        }
+    }
+}
+
+/// Render a frame scrubber row. A single-frame (or empty) animation shows
+/// the readout only.
+pub fn edit_frame_scrubber(
+    ui: &mut UIContext,
+    id: FieldId,
+    label: &str,
+    frame: usize,
+    frame_count: usize,
+    pos: Vec2,
+    style: &EditableFieldStyle,
+) -> EditResult<usize> {
+    ui.label_styled(label, Vec2::new(pos.x, pos.y + 4.0), style.label_color, style.label_font);
+
+    let slider_x = pos.x + style.label_width;
+    let readout = format!("{} / {}", frame.min(frame_count.saturating_sub(1)), frame_count);
+    ui.label_styled(
+        &readout,
+        Vec2::new(slider_x + style.input_width + 6.0, pos.y + 4.0),
+        style.value_color,
+        style.label_font,
+    );
+    if frame_count <= 1 {
+        return EditResult::Unchanged;
+    }
+
+    let last = (frame_count - 1) as f32;
+    let bounds = Rect::new(slider_x, pos.y + (style.row_height - style.slider_height) / 2.0, style.input_width, style.slider_height);
+    let value = ui.slider_range(id, frame as f32, 0.0, last, bounds);
+    let scrubbed = value.round().clamp(0.0, last) as usize;
+    if scrubbed == frame {
+        EditResult::Unchanged
+    } else {
+        EditResult::Changed(scrubbed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn animated_world(loop_animation: bool) -> (World, EntityId) {
+        let mut world = World::new();
+        let frames = vec![[0.0, 0.0, 0.25, 1.0], [0.25, 0.0, 0.25, 1.0], [0.5, 0.0, 0.25, 1.0]];
+        let mut animation = SpriteAnimation::new(10.0, frames).with_loop(loop_animation);
+        animation.playing = false;
+        animation.current_frame = 1;
+        let entity = world.spawn((animation,)).id();
+        (world, entity)
+    }
+
+    fn frame(world: &World, entity: EntityId) -> usize {
+        world.get::<SpriteAnimation>(entity).unwrap().current_frame
+    }
+
+    #[test]
+    fn test_preview_plays_paused_animation_and_restores_on_stop() {
+        let (mut world, entity) = animated_world(true);
+        let mut preview = AnimationPreview::new();
+        preview.toggle(&mut world, entity);
+        assert!(preview.is_previewing(entity));
+
+        preview.tick(&mut world, Some(entity), 0.1);
+        assert_eq!(frame(&world, entity), 2);
+        assert!(!world.get::<SpriteAnimation>(entity).unwrap().playing, "authored flag untouched");
+
+        preview.toggle(&mut world, entity);
+        assert!(!preview.is_previewing(entity));
+        assert_eq!(frame(&world, entity), 1, "authored frame restored");
+    }
+
+    #[test]
+    fn test_preview_wraps_one_shot_and_stops_on_deselect() {
+        let (mut world, entity) = animated_world(false);
+        let mut preview = AnimationPreview::new();
+        preview.toggle(&mut world, entity);
+        preview.tick(&mut world, Some(entity), 0.1);
+        preview.tick(&mut world, Some(entity), 0.1);
+        assert_eq!(frame(&world, entity), 0, "one-shot wraps while previewing");
+
+        preview.tick(&mut world, None, 0.1);
+        assert_eq!(preview.entity(), None);
+        assert_eq!(frame(&world, entity), 1);
+    }
+
+    #[test]
+    fn test_preview_yields_to_scrubbed_frame() {
+        let (mut world, entity) = animated_world(true);
+        let mut preview = AnimationPreview::new();
+        preview.toggle(&mut world, entity);
+        world.get_mut::<SpriteAnimation>(entity).unwrap().current_frame = 0;
+
+        preview.tick(&mut world, Some(entity), 0.1);
+        assert_eq!(preview.entity(), None);
+        assert_eq!(frame(&world, entity), 0, "scrubbed frame kept");
+    }
+}
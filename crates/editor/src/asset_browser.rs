@@ -43,6 +43,11 @@ pub struct AssetBrowserState {
     pub scanned: bool,
     /// Vertical scroll offset in pixels
     pub scroll_offset: f32,
+    /// Scan running on a background thread (see [`BackgroundTasks`](crate::BackgroundTasks))
+    pub pending_scan: Option<crate::TaskHandle<Vec<AssetEntry>>>,
+    /// Pixels-per-unit typed into the batch-import field; `None` shows the
+    /// project default
+    pub batch_pixels_per_unit: Option<f32>,
 }
 
 impl AssetBrowserState {
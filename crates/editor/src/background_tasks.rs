@@ -0,0 +1,264 @@
+//! Background task runner for long editor jobs (asset scans, imports, packing).
+//!
+//! Jobs run on their own thread and report progress through a
+//! [`TaskProgress`] reporter. The editor polls [`BackgroundTasks`] once per
+//! frame, which forwards progress and completion events to the
+//! [`StatusBar`] so work never blocks the UI or happens invisibly. The job's
+//! return value is collected separately through its [`TaskHandle`].
+
+use std::sync::mpsc::{self, Receiver, Sender, TryRecvError};
+
+use crate::status_bar::StatusBar;
+
+/// Identifier of a background task (unique per [`BackgroundTasks`] runner).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TaskId(u64);
+
+impl TaskId {
+    /// Create a task id from a raw value. Subsystems that report progress
+    /// without a background thread can use their own id space.
+    pub const fn new(raw: u64) -> Self {
+        Self(raw)
+    }
+
+    /// The raw id value.
+    pub const fn raw(self) -> u64 {
+        self.0
+    }
+}
+
+/// Event sent from a job thread to the editor.
+#[derive(Debug)]
+enum TaskEvent {
+    Progress {
+        id: TaskId,
+        fraction: f32,
+        detail: Option<String>,
+    },
+    Finished {
+        id: TaskId,
+        outcome: Result<String, String>,
+    },
+}
+
+/// Progress reporter handed to a running job.
+#[derive(Debug, Clone)]
+pub struct TaskProgress {
+    id: TaskId,
+    sender: Sender<TaskEvent>,
+}
+
+impl TaskProgress {
+    /// Report completion as a fraction in `0.0..=1.0` (clamped).
+    pub fn report(&self, fraction: f32) {
+        self.send(fraction, None);
+    }
+
+    /// Report completion together with a short detail (e.g. the current file).
+    pub fn report_with(&self, fraction: f32, detail: impl Into<String>) {
+        self.send(fraction, Some(detail.into()));
+    }
+
+    fn send(&self, fraction: f32, detail: Option<String>) {
+        // The runner may already be gone (editor shutting down) — progress
+        // is best-effort, so a closed channel is not an error.
+        let _ = self.sender.send(TaskEvent::Progress {
+            id: self.id,
+            fraction: fraction.clamp(0.0, 1.0),
+            detail,
+        });
+    }
+}
+
+/// Handle to a spawned job's result.
+#[derive(Debug)]
+pub struct TaskHandle<T> {
+    id: TaskId,
+    result: Receiver<Result<T, String>>,
+}
+
+impl<T> TaskHandle<T> {
+    /// The task's id.
+    pub fn id(&self) -> TaskId {
+        self.id
+    }
+
+    /// Take the job's result if it has finished. Returns `None` while the
+    /// job is still running; a job whose thread died without producing a
+    /// result yields an error.
+    pub fn try_take(&self) -> Option<Result<T, String>> {
+        match self.result.try_recv() {
+            Ok(result) => Some(result),
+            Err(TryRecvError::Empty) => None,
+            Err(TryRecvError::Disconnected) => Some(Err("Task ended without a result".to_string())),
+        }
+    }
+}
+
+/// Spawns background jobs and forwards their progress to the status bar.
+#[derive(Debug)]
+pub struct BackgroundTasks {
+    next_id: u64,
+    sender: Sender<TaskEvent>,
+    receiver: Receiver<TaskEvent>,
+    running: usize,
+}
+
+impl Default for BackgroundTasks {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BackgroundTasks {
+    /// Create an empty runner.
+    pub fn new() -> Self {
+        let (sender, receiver) = mpsc::channel();
+        Self {
+            next_id: 1,
+            sender,
+            receiver,
+            running: 0,
+        }
+    }
+
+    /// Number of jobs spawned that have not yet been reported finished by
+    /// [`poll`](Self::poll).
+    pub fn running_count(&self) -> usize {
+        self.running
+    }
+
+    /// Spawn `job` on a background thread, labelled `label` in the status bar.
+    ///
+    /// The job receives a [`TaskProgress`] reporter and returns its result;
+    /// an `Err` is shown as a persistent status-bar error and recorded in the
+    /// task history.
+    pub fn spawn<T, F>(&mut self, label: impl Into<String>, job: F) -> TaskHandle<T>
+    where
+        T: Send + 'static,
+        F: FnOnce(&TaskProgress) -> Result<T, String> + Send + 'static,
+    {
+        let id = TaskId(self.next_id);
+        self.next_id += 1;
+        self.running += 1;
+
+        let label = label.into();
+        let (result_tx, result_rx) = mpsc::channel();
+        let progress = TaskProgress { id, sender: self.sender.clone() };
+        let events = self.sender.clone();
+
+        // Announce the task so the status bar shows it from the first frame.
+        let _ = self.sender.send(TaskEvent::Progress { id, fraction: 0.0, detail: Some(label.clone()) });
+
+        let spawned = std::thread::Builder::new()
+            .name(format!("editor-task-{}", id.0))
+            .spawn(move || {
+                let result = job(&progress);
+                let outcome = match &result {
+                    Ok(_) => Ok(format!("{label} finished")),
+                    Err(e) => Err(format!("{label} failed: {e}")),
+                };
+                let _ = result_tx.send(result);
+                let _ = events.send(TaskEvent::Finished { id, outcome });
+            });
+
+        if let Err(e) = spawned {
+            // The closure (and its result sender) was dropped, so the handle
+            // reports a missing result; surface the spawn error here.
+            let _ = self.sender.send(TaskEvent::Finished {
+                id,
+                outcome: Err(format!("Could not start task: {e}")),
+            });
+        }
+
+        TaskHandle { id, result: result_rx }
+    }
+
+    /// Forward pending progress and completion events to `status_bar`.
+    /// Call once per frame.
+    pub fn poll(&mut self, status_bar: &mut StatusBar) {
+        while let Ok(event) = self.receiver.try_recv() {
+            match event {
+                TaskEvent::Progress { id, fraction, detail } => {
+                    if status_bar.has_task(id) {
+                        status_bar.set_task_progress(id, fraction, detail);
+                    } else {
+                        // First event of a task carries its label.
+                        status_bar.begin_task(id, detail.unwrap_or_default());
+                    }
+                }
+                TaskEvent::Finished { id, outcome } => {
+                    self.running = self.running.saturating_sub(1);
+                    status_bar.finish_task(id, outcome);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Poll until the runner has no running jobs (bounded, to keep a broken
+    /// job from hanging the test suite).
+    fn poll_until_idle(tasks: &mut BackgroundTasks, bar: &mut StatusBar) {
+        for _ in 0..500 {
+            tasks.poll(bar);
+            if tasks.running_count() == 0 {
+                return;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(2));
+        }
+        panic!("background task did not finish");
+    }
+
+    #[test]
+    fn spawned_job_result_is_returned_through_handle() {
+        let mut tasks = BackgroundTasks::new();
+        let mut bar = StatusBar::new();
+        let handle = tasks.spawn("Sum", |_| Ok(2 + 2));
+
+        poll_until_idle(&mut tasks, &mut bar);
+
+        assert_eq!(handle.try_take(), Some(Ok(4)));
+    }
+
+    #[test]
+    fn finished_job_is_recorded_in_status_history() {
+        let mut tasks = BackgroundTasks::new();
+        let mut bar = StatusBar::new();
+        let _handle = tasks.spawn("Packing atlas", |progress| {
+            progress.report(0.5);
+            Ok(())
+        });
+
+        poll_until_idle(&mut tasks, &mut bar);
+
+        assert!(bar.active_tasks().is_empty());
+        let latest = bar.history().next().expect("history entry");
+        assert!(latest.succeeded);
+        assert_eq!(latest.text, "Packing atlas finished");
+    }
+
+    #[test]
+    fn failed_job_shows_persistent_error() {
+        let mut tasks = BackgroundTasks::new();
+        let mut bar = StatusBar::new();
+        let handle = tasks.spawn::<(), _>("Import", |_| Err("bad file".to_string()));
+
+        poll_until_idle(&mut tasks, &mut bar);
+
+        assert_eq!(handle.try_take(), Some(Err("bad file".to_string())));
+        assert_eq!(bar.message(), Some("Import failed: bad file"));
+        assert!(!bar.history().next().expect("history entry").succeeded);
+    }
+
+    #[test]
+    fn task_ids_are_unique() {
+        let mut tasks = BackgroundTasks::new();
+        let a = tasks.spawn("A", |_| Ok(()));
+        let b = tasks.spawn("B", |_| Ok(()));
+        assert_ne!(a.id(), b.id());
+    }
+}
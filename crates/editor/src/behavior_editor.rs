@@ -1,12 +1,11 @@
 //! Editable inspector for the `Behavior` component.
 //!
 //! Behaviors are an enum, so the editor shows a variant cycle selector
-//! (`< PlayerPlatformer >`) followed by the selected variant's fields.
-//! Switching variants replaces the behavior with that variant's defaults.
-//!
-//! String fields (tags, target names) are read-only for now — the ui crate
-//! has no general text-input widget yet (same precedent as RigidBody's
-//! read-only body type).
+//! (`< PlayerPlatformer >`) followed by the selected variant's fields —
+//! every field is editable, tags and target names as free text. Switching
+//! variants replaces the behavior with that variant's defaults; the Add
+//! Component popup picks the variant up front (see
+//! [`behavior_variant_options`]).
 
 use ecs::behavior::Behavior;
 
@@ -29,6 +28,34 @@ mod ranges {
     pub const POSITION: RangeInclusive<f32> = -1000.0..=1000.0;
     /// Unit fractions (CameraFollow lerp speed: 1.0 snaps instantly).
     pub const FRACTION: RangeInclusive<f32> = 0.0..=1.0;
+    /// Collectible score values (whole points).
+    pub const SCORE: RangeInclusive<f32> = 0.0..=1_000_000.0;
+    /// CameraFollow dead-zone width and height in pixels.
+    pub const DEAD_ZONE: RangeInclusive<f32> = 0.0..=2000.0;
+}
+
+/// Dead zone a CameraFollow gets when its checkbox is ticked.
+const DEFAULT_DEAD_ZONE: (f32, f32) = (160.0, 96.0);
+
+/// The Add Component popup's Behavior entries: each variant's name and
+/// default value, in `VARIANT_NAMES` order.
+pub fn behavior_variant_options() -> Vec<(&'static str, Behavior)> {
+    Behavior::VARIANT_NAMES
+        .iter()
+        .enumerate()
+        .map(|(index, &name)| (name, Behavior::default_for_variant(index)))
+        .collect()
+}
+
+/// Free-text field: write a committed edit into `value`.
+fn edit_string(inspector: &mut EditableInspector<'_>, label: &str, value: &mut String) -> bool {
+    match inspector.text(label, value) {
+        EditResult::Changed(text) => {
+            *value = text;
+            true
+        }
+        EditResult::Unchanged => false,
+    }
 }
 
 /// Edit a Behavior component.
@@ -72,17 +99,23 @@ pub fn edit_behavior(
                 *jump_cooldown = v;
                 hint = Some("jump_cooldown");
             }
-            inspector.string("Tag", tag);
+            if edit_string(inspector, "Tag", tag) {
+                hint = Some("tag");
+            }
         }
         Behavior::PlayerTopDown { move_speed, tag } => {
             if let EditResult::Changed(v) = inspector.f32("Move Speed", *move_speed, ranges::SPEED) {
                 *move_speed = v;
                 hint = Some("move_speed");
             }
-            inspector.string("Tag", tag);
+            if edit_string(inspector, "Tag", tag) {
+                hint = Some("tag");
+            }
         }
         Behavior::FollowEntity { target_name, follow_distance, follow_speed } => {
-            inspector.string("Target Name", target_name);
+            if edit_string(inspector, "Target Name", target_name) {
+                hint = Some("target_name");
+            }
             if let EditResult::Changed(v) = inspector.f32("Distance", *follow_distance, ranges::DISTANCE) {
                 *follow_distance = v;
                 hint = Some("follow_distance");
@@ -93,7 +126,9 @@ pub fn edit_behavior(
             }
         }
         Behavior::FollowTagged { target_tag, follow_distance, follow_speed } => {
-            inspector.string("Target Tag", target_tag);
+            if edit_string(inspector, "Target Tag", target_tag) {
+                hint = Some("target_tag");
+            }
             if let EditResult::Changed(v) = inspector.f32("Distance", *follow_distance, ranges::DISTANCE) {
                 *follow_distance = v;
                 hint = Some("follow_distance");
@@ -130,15 +165,22 @@ pub fn edit_behavior(
             }
         }
         Behavior::Collectible { score_value, despawn_on_collect, collector_tag } => {
-            inspector.u32("Score Value", *score_value);
+            if let EditResult::Changed(v) = inspector.f32("Score Value", *score_value as f32, ranges::SCORE) {
+                *score_value = v.round() as u32;
+                hint = Some("score_value");
+            }
             if let EditResult::Changed(v) = inspector.bool("Despawn", *despawn_on_collect) {
                 *despawn_on_collect = v;
                 hint = Some("despawn_on_collect");
             }
-            inspector.string("Collector Tag", collector_tag);
+            if edit_string(inspector, "Collector Tag", collector_tag) {
+                hint = Some("collector_tag");
+            }
         }
         Behavior::ChaseTagged { target_tag, detection_range, chase_speed, lose_interest_range } => {
-            inspector.string("Target Tag", target_tag);
+            if edit_string(inspector, "Target Tag", target_tag) {
+                hint = Some("target_tag");
+            }
             if let EditResult::Changed(v) = inspector.f32("Detect Range", *detection_range, ranges::DISTANCE) {
                 *detection_range = v;
                 hint = Some("detection_range");
@@ -153,7 +195,9 @@ pub fn edit_behavior(
             }
         }
         Behavior::CameraFollow { target_tag, lerp_speed, offset, dead_zone } => {
-            inspector.string("Target Tag", target_tag);
+            if edit_string(inspector, "Target Tag", target_tag) {
+                hint = Some("target_tag");
+            }
             if let EditResult::Changed(v) = inspector.f32("Lerp Speed", *lerp_speed, ranges::FRACTION) {
                 *lerp_speed = v;
                 hint = Some("lerp_speed");
@@ -166,13 +210,20 @@ pub fn edit_behavior(
                 *offset = (v.x, v.y);
                 hint = Some("offset");
             }
-            // Read-only until the ui crate grows an Option/toggle widget
-            // (same precedent as the string fields above).
-            let dead_zone_label = match dead_zone {
-                Some((w, h)) => format!("{w:.0} x {h:.0}"),
-                None => "None".to_string(),
-            };
-            inspector.string("Dead Zone", &dead_zone_label);
+            if let EditResult::Changed(on) = inspector.bool("Dead Zone", dead_zone.is_some()) {
+                *dead_zone = on.then_some(DEFAULT_DEAD_ZONE);
+                hint = Some("dead_zone");
+            }
+            if let Some((width, height)) = *dead_zone {
+                if let EditResult::Changed(v) = inspector.vec2(
+                    "Dead Zone Size",
+                    glam::Vec2::new(width, height),
+                    ranges::DEAD_ZONE,
+                ) {
+                    *dead_zone = Some((v.x, v.y));
+                    hint = Some("dead_zone");
+                }
+            }
         }
     }
 
@@ -190,6 +241,17 @@ mod tests {
         assert!(ranges::SECONDS.contains(&0.3)); // default jump cooldown
         assert!(ranges::DISTANCE.contains(&300.0)); // default lose range
         assert!(ranges::POSITION.contains(&0.0));
+        assert!(ranges::DEAD_ZONE.contains(&DEFAULT_DEAD_ZONE.0) && ranges::DEAD_ZONE.contains(&DEFAULT_DEAD_ZONE.1));
+    }
+
+    #[test]
+    fn test_add_component_options_cover_every_variant_in_order() {
+        let options = behavior_variant_options();
+        assert_eq!(options.len(), Behavior::VARIANT_NAMES.len());
+        for (index, (name, behavior)) in options.iter().enumerate() {
+            assert_eq!(behavior.variant_index(), index);
+            assert_eq!(*name, behavior.variant_name());
+        }
     }
 
     #[test]
@@ -215,7 +277,9 @@ mod tests {
                     assert!(ranges::SPEED.contains(&speed));
                     assert!(ranges::SECONDS.contains(&wait_time));
                 }
-                Behavior::Collectible { .. } => {}
+                Behavior::Collectible { score_value, .. } => {
+                    assert!(ranges::SCORE.contains(&(score_value as f32)));
+                }
                 Behavior::ChaseTagged { detection_range, chase_speed, lose_interest_range, .. } => {
                     assert!(ranges::DISTANCE.contains(&detection_range));
                     assert!(ranges::SPEED.contains(&chase_speed));
@@ -0,0 +1,154 @@
+//! Numbered editor camera bookmarks (Ctrl+1..9 stores, 1..9 recalls).
+//!
+//! Bookmarks are part of [`EditorPreferences`](crate::EditorPreferences),
+//! so they survive editor restarts.
+
+use std::collections::BTreeMap;
+
+use glam::Vec2;
+use serde::{Deserialize, Serialize};
+use winit::keyboard::KeyCode;
+
+use crate::viewport::SceneViewport;
+use crate::EditorContext;
+
+/// Number of bookmark slots (keys 1..9).
+pub const CAMERA_BOOKMARK_SLOTS: u8 = 9;
+
+/// A saved viewport camera.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct CameraBookmark {
+    /// Camera position (x, y) in world units
+    pub position: (f32, f32),
+    /// Camera zoom level
+    pub zoom: f32,
+    /// View rotation in radians
+    #[serde(default)]
+    pub rotation: f32,
+}
+
+impl CameraBookmark {
+    /// Capture the viewport's current camera.
+    pub fn capture(viewport: &SceneViewport) -> Self {
+        let position = viewport.camera_position();
+        Self {
+            position: (position.x, position.y),
+            zoom: viewport.camera_zoom(),
+            rotation: viewport.camera_rotation(),
+        }
+    }
+
+    /// Glide the viewport to this camera (position and zoom interpolate;
+    /// rotation snaps).
+    pub fn apply(&self, viewport: &mut SceneViewport) {
+        viewport.set_target_camera_position(Vec2::new(self.position.0, self.position.1));
+        viewport.set_target_zoom(self.zoom);
+        viewport.set_camera_rotation(self.rotation);
+    }
+}
+
+/// Bookmark slots 1..=[`CAMERA_BOOKMARK_SLOTS`]; empty slots are absent.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct CameraBookmarks {
+    slots: BTreeMap<u8, CameraBookmark>,
+}
+
+impl CameraBookmarks {
+    /// Store `bookmark` in `slot`. Returns `false` (nothing stored) for a
+    /// slot outside 1..=9.
+    pub fn store(&mut self, slot: u8, bookmark: CameraBookmark) -> bool {
+        if !(1..=CAMERA_BOOKMARK_SLOTS).contains(&slot) {
+            return false;
+        }
+        self.slots.insert(slot, bookmark);
+        true
+    }
+
+    /// The bookmark in `slot`, if one was stored.
+    pub fn get(&self, slot: u8) -> Option<CameraBookmark> {
+        self.slots.get(&slot).copied()
+    }
+
+    /// Number of stored bookmarks.
+    pub fn len(&self) -> usize {
+        self.slots.len()
+    }
+
+    /// Whether no bookmark is stored.
+    pub fn is_empty(&self) -> bool {
+        self.slots.is_empty()
+    }
+
+    /// The slot a number-row key selects (`Digit1` → 1 … `Digit9` → 9).
+    pub fn slot_for_key(key: KeyCode) -> Option<u8> {
+        let slot = match key {
+            KeyCode::Digit1 => 1,
+            KeyCode::Digit2 => 2,
+            KeyCode::Digit3 => 3,
+            KeyCode::Digit4 => 4,
+            KeyCode::Digit5 => 5,
+            KeyCode::Digit6 => 6,
+            KeyCode::Digit7 => 7,
+            KeyCode::Digit8 => 8,
+            KeyCode::Digit9 => 9,
+            _ => return None,
+        };
+        Some(slot)
+    }
+}
+
+impl EditorContext {
+    /// Save the current viewport camera in `slot` (Ctrl+1..9).
+    pub fn store_camera_bookmark(&mut self, slot: u8) -> bool {
+        let bookmark = CameraBookmark::capture(&self.viewport);
+        self.camera_bookmarks.store(slot, bookmark)
+    }
+
+    /// Glide the viewport to the camera saved in `slot` (1..9). Returns
+    /// `false` when the slot is empty.
+    pub fn recall_camera_bookmark(&mut self, slot: u8) -> bool {
+        match self.camera_bookmarks.get(slot) {
+            Some(bookmark) => {
+                bookmark.apply(&mut self.viewport);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bookmark_capture_and_recall() {
+        let mut viewport = SceneViewport::new();
+        viewport.set_camera_position(Vec2::new(120.0, -40.0));
+        viewport.set_camera_zoom(2.0);
+        let mut bookmarks = CameraBookmarks::default();
+        assert!(bookmarks.store(3, CameraBookmark::capture(&viewport)));
+        assert!(!bookmarks.store(0, CameraBookmark::capture(&viewport)));
+        assert!(!bookmarks.store(10, CameraBookmark::capture(&viewport)));
+        assert_eq!(bookmarks.len(), 1);
+
+        viewport.reset_camera_immediate();
+        let bookmark = bookmarks.get(3).unwrap();
+        bookmark.apply(&mut viewport);
+        for _ in 0..200 {
+            viewport.update(0.016);
+        }
+        assert!((viewport.camera_position() - Vec2::new(120.0, -40.0)).length() < 0.5);
+        assert!((viewport.camera_zoom() - 2.0).abs() < 0.01);
+        assert_eq!(bookmarks.get(4), None);
+    }
+
+    #[test]
+    fn test_bookmark_slots_from_number_keys() {
+        assert_eq!(CameraBookmarks::slot_for_key(KeyCode::Digit1), Some(1));
+        assert_eq!(CameraBookmarks::slot_for_key(KeyCode::Digit9), Some(9));
+        assert_eq!(CameraBookmarks::slot_for_key(KeyCode::Digit0), None);
+        assert_eq!(CameraBookmarks::slot_for_key(KeyCode::KeyF), None);
+    }
+}
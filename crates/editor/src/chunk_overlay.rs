@@ -0,0 +1,118 @@
+//! Streaming chunk bounds for the scene view.
+//!
+//! Scenes set up for world streaming are split into square chunks
+//! (`engine_core::streaming`); entities load and unload with the chunk
+//! their position falls in. This overlay draws the chunk borders and labels
+//! each chunk with its coordinate, so level authors can see which chunk an
+//! entity will stream with.
+
+use glam::Vec2;
+use ui::{Color, Rect, UIContext};
+
+use crate::viewport::SceneViewport;
+
+/// Chunk border width, in screen pixels.
+const LINE_WIDTH: f32 = 1.0;
+/// Chunks narrower than this on screen aren't labelled.
+const MIN_LABELLED_CHUNK: f32 = 96.0;
+/// More borders than this per axis (zoomed far out) draws nothing.
+const MAX_LINES: i64 = 128;
+
+/// Inclusive range of chunk indices covering `min..max` on one axis.
+fn chunk_range(min: f32, max: f32, chunk_size: f32) -> (i64, i64) {
+    ((min / chunk_size).floor() as i64, (max / chunk_size).floor() as i64)
+}
+
+/// World-space chunk border segments over the visible area. Empty when
+/// `chunk_size` isn't positive or the borders would be too dense to read.
+pub fn chunk_border_segments(viewport: &SceneViewport, chunk_size: f32) -> Vec<(Vec2, Vec2)> {
+    if !chunk_size.is_finite() || chunk_size <= 0.0 {
+        return Vec::new();
+    }
+    let (min_x, min_y, max_x, max_y) = viewport.visible_world_bounds();
+    let (first_x, last_x) = chunk_range(min_x, max_x, chunk_size);
+    let (first_y, last_y) = chunk_range(min_y, max_y, chunk_size);
+    if last_x - first_x > MAX_LINES || last_y - first_y > MAX_LINES {
+        return Vec::new();
+    }
+
+    let vertical = (first_x + 1..=last_x).map(|i| {
+        let x = i as f32 * chunk_size;
+        (Vec2::new(x, min_y), Vec2::new(x, max_y))
+    });
+    let horizontal = (first_y + 1..=last_y).map(|j| {
+        let y = j as f32 * chunk_size;
+        (Vec2::new(min_x, y), Vec2::new(max_x, y))
+    });
+    vertical.chain(horizontal).collect()
+}
+
+/// Visible chunks as `(coordinate, top-left world corner)`, for labels.
+/// Empty while chunks are too small on screen to label.
+pub fn chunk_labels(viewport: &SceneViewport, chunk_size: f32) -> Vec<((i64, i64), Vec2)> {
+    if !chunk_size.is_finite() || chunk_size * viewport.camera_zoom() < MIN_LABELLED_CHUNK {
+        return Vec::new();
+    }
+    let (min_x, min_y, max_x, max_y) = viewport.visible_world_bounds();
+    let (first_x, last_x) = chunk_range(min_x, max_x, chunk_size);
+    let (first_y, last_y) = chunk_range(min_y, max_y, chunk_size);
+    (first_y..=last_y)
+        .flat_map(|j| (first_x..=last_x).map(move |i| (i, j)))
+        .map(|(i, j)| ((i, j), Vec2::new(i as f32, (j + 1) as f32) * chunk_size))
+        .collect()
+}
+
+/// Draw chunk borders and coordinate labels, clipped to the scene-view
+/// `bounds`.
+pub fn render_chunk_overlay(
+    ui: &mut UIContext,
+    viewport: &SceneViewport,
+    chunk_size: f32,
+    color: Color,
+    font_size: f32,
+    bounds: Rect,
+) {
+    let segments = chunk_border_segments(viewport, chunk_size);
+    ui.push_clip_rect(bounds);
+    for (start, end) in segments {
+        ui.line(viewport.world_to_screen(start), viewport.world_to_screen(end), color, LINE_WIDTH);
+    }
+    for ((x, y), corner) in chunk_labels(viewport, chunk_size) {
+        let screen = viewport.world_to_screen(corner) + Vec2::new(4.0, 4.0);
+        ui.label_styled(&format!("chunk ({x}, {y})"), screen, color, font_size);
+    }
+    ui.pop_clip_rect();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chunk_borders_cover_visible_area() {
+        let mut viewport = SceneViewport::new();
+        viewport.set_viewport_bounds(Rect::new(0.0, 0.0, 800.0, 600.0));
+        viewport.set_camera_position(Vec2::new(50.0, 50.0));
+
+        // Visible x: -350..450 → borders at -300..=400 every 100
+        let segments = chunk_border_segments(&viewport, 100.0);
+        let vertical = segments.iter().filter(|(a, b)| a.x == b.x).count();
+        assert_eq!(vertical, 8);
+        assert!(segments.iter().all(|(a, b)| (a.x == b.x) != (a.y == b.y)));
+
+        assert!(chunk_border_segments(&viewport, 0.0).is_empty());
+        assert!(chunk_border_segments(&viewport, 1.0).is_empty(), "too dense to draw");
+    }
+
+    #[test]
+    fn test_chunk_labels_need_room() {
+        let mut viewport = SceneViewport::new();
+        viewport.set_viewport_bounds(Rect::new(0.0, 0.0, 800.0, 600.0));
+        let labels = chunk_labels(&viewport, 400.0);
+        assert!(labels.contains(&((-1, -1), Vec2::new(-400.0, 0.0))));
+        assert_eq!(labels.len(), 3 * 2);
+
+        viewport.set_camera_zoom(0.1);
+        assert!(chunk_labels(&viewport, 400.0).is_empty());
+    }
+}
@@ -0,0 +1,306 @@
+//! Viewport handles for resizing the selected entity's collider.
+//!
+//! Each collider shape exposes handles on its outline: box edges drag the
+//! half-extents, the circle rim drags the radius, and capsules have one
+//! handle for the cap radius and one for the half-height. Dragging is
+//! absolute — the dimension becomes the mouse's distance from the collider
+//! center along the handle's body-local axis — so the handle stays under the
+//! cursor however the body is rotated. Placement matches
+//! [`collider_outline_segments`](crate::collider_outline_segments): the
+//! entity `Transform2D` positions and rotates the shape, its scale is
+//! ignored.
+
+use common::Transform2D;
+use glam::Vec2;
+use physics::components::{Collider, ColliderShape};
+use ui::{Color, Rect, UIContext};
+
+use crate::theme::EditorTheme;
+use crate::viewport::SceneViewport;
+
+/// Smallest dimension a handle drag can produce, in pixels.
+pub const MIN_COLLIDER_EXTENT: f32 = 1.0;
+
+/// Which collider dimension a handle edits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColliderHandleKind {
+    /// Box half-extent along the body's X axis
+    HalfExtentX,
+    /// Box half-extent along the body's Y axis
+    HalfExtentY,
+    /// Circle or capsule cap radius
+    Radius,
+    /// Capsule half-height (straight segment, excluding caps)
+    HalfHeight,
+}
+
+/// A handle's kind, its body-local direction from the collider center, and
+/// its world position.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ColliderHandle {
+    /// Dimension the handle edits
+    pub kind: ColliderHandleKind,
+    /// Unit body-local axis the handle sits on
+    pub axis: Vec2,
+    /// World position of the handle
+    pub position: Vec2,
+}
+
+/// Rotate a vector by an angle in radians (counter-clockwise).
+fn rotate_vec(v: Vec2, angle: f32) -> Vec2 {
+    let (sin, cos) = angle.sin_cos();
+    Vec2::new(v.x * cos - v.y * sin, v.x * sin + v.y * cos)
+}
+
+/// World position of the collider's center.
+fn collider_center(transform: &Transform2D, collider: &Collider) -> Vec2 {
+    transform.position + rotate_vec(collider.offset, transform.rotation)
+}
+
+/// Handles for `collider` on an entity at `transform`.
+pub fn collider_handles(transform: &Transform2D, collider: &Collider) -> Vec<ColliderHandle> {
+    use ColliderHandleKind::*;
+
+    let local: Vec<(ColliderHandleKind, Vec2, f32)> = match collider.shape {
+        ColliderShape::Box { half_extents } => vec![
+            (HalfExtentX, Vec2::X, half_extents.x),
+            (HalfExtentX, Vec2::NEG_X, half_extents.x),
+            (HalfExtentY, Vec2::Y, half_extents.y),
+            (HalfExtentY, Vec2::NEG_Y, half_extents.y),
+        ],
+        ColliderShape::Circle { radius } => vec![(Radius, Vec2::X, radius)],
+        ColliderShape::CapsuleY { half_height, radius } => {
+            vec![(Radius, Vec2::X, radius), (HalfHeight, Vec2::Y, half_height + radius)]
+        }
+        ColliderShape::CapsuleX { half_height, radius } => {
+            vec![(Radius, Vec2::Y, radius), (HalfHeight, Vec2::X, half_height + radius)]
+        }
+    };
+
+    let center = collider_center(transform, collider);
+    local
+        .into_iter()
+        .map(|(kind, axis, distance)| ColliderHandle {
+            kind,
+            axis,
+            position: center + rotate_vec(axis * distance, transform.rotation),
+        })
+        .collect()
+}
+
+/// Resize `collider` so the dragged handle lands at `world_pos`.
+///
+/// `snap` rounds the edited dimension to multiples of half the step, so
+/// full widths and diameters land on whole steps (pass the grid size).
+/// Returns whether the collider changed.
+pub fn drag_collider_handle(
+    collider: &mut Collider,
+    transform: &Transform2D,
+    handle: &ColliderHandle,
+    world_pos: Vec2,
+    snap: Option<f32>,
+) -> bool {
+    let local = rotate_vec(world_pos - collider_center(transform, collider), -transform.rotation);
+    let mut distance = local.dot(handle.axis).abs();
+    // Capsule half-height handles sit on the cap tip, past the radius.
+    if handle.kind == ColliderHandleKind::HalfHeight {
+        let radius = match collider.shape {
+            ColliderShape::CapsuleY { radius, .. } | ColliderShape::CapsuleX { radius, .. } => radius,
+            _ => 0.0,
+        };
+        distance -= radius;
+    }
+    if let Some(step) = snap.filter(|s| *s > 0.0) {
+        let half_step = step / 2.0;
+        distance = (distance / half_step).round() * half_step;
+    }
+    let value = distance.max(MIN_COLLIDER_EXTENT);
+
+    let target = match (&mut collider.shape, handle.kind) {
+        (ColliderShape::Box { half_extents }, ColliderHandleKind::HalfExtentX) => &mut half_extents.x,
+        (ColliderShape::Box { half_extents }, ColliderHandleKind::HalfExtentY) => &mut half_extents.y,
+        (ColliderShape::Circle { radius }, ColliderHandleKind::Radius)
+        | (ColliderShape::CapsuleY { radius, .. }, ColliderHandleKind::Radius)
+        | (ColliderShape::CapsuleX { radius, .. }, ColliderHandleKind::Radius) => radius,
+        (ColliderShape::CapsuleY { half_height, .. }, ColliderHandleKind::HalfHeight)
+        | (ColliderShape::CapsuleX { half_height, .. }, ColliderHandleKind::HalfHeight) => half_height,
+        // A handle from a different shape (the shape changed mid-drag).
+        _ => return false,
+    };
+    if *target == value {
+        return false;
+    }
+    *target = value;
+    true
+}
+
+/// Interactive collider handles for the primary selection.
+#[derive(Debug, Clone)]
+pub struct ColliderGizmo {
+    /// Handle being dragged (index into `collider_handles`)
+    active: Option<usize>,
+    /// Handle square size in screen pixels
+    handle_size: f32,
+    /// Handle color
+    color: Color,
+    /// Handle color while hovered or dragged
+    hover_color: Color,
+}
+
+impl Default for ColliderGizmo {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ColliderGizmo {
+    /// Create a collider gizmo with default colors.
+    pub fn new() -> Self {
+        Self {
+            active: None,
+            handle_size: 10.0,
+            color: Color::new(1.0, 0.85, 0.2, 1.0),
+            hover_color: Color::new(0.9, 0.9, 0.4, 1.0),
+        }
+    }
+
+    /// Apply colors from the editor theme.
+    pub fn apply_theme(&mut self, theme: &EditorTheme) {
+        self.color = theme.colors.collider_selected;
+        self.hover_color = theme.colors.gizmo_scale_handle_hover;
+    }
+
+    /// Whether a handle is being dragged.
+    pub fn is_active(&self) -> bool {
+        self.active.is_some()
+    }
+
+    /// Abort any drag in progress.
+    pub fn cancel(&mut self) {
+        self.active = None;
+    }
+
+    /// Draw the handles and resize `collider` while one is dragged. Call
+    /// before the transform gizmo so handles win overlapping clicks.
+    /// Returns whether the collider changed this frame.
+    pub fn render(
+        &mut self,
+        ui: &mut UIContext,
+        viewport: &SceneViewport,
+        transform: &Transform2D,
+        collider: &mut Collider,
+        snap: Option<f32>,
+    ) -> bool {
+        let handles = collider_handles(transform, collider);
+        let mouse_pos = ui.mouse_pos();
+        let mut dragging = None;
+
+        for (index, handle) in handles.iter().enumerate() {
+            let center = viewport.world_to_screen(handle.position);
+            let bounds = Rect::new(
+                center.x - self.handle_size / 2.0,
+                center.y - self.handle_size / 2.0,
+                self.handle_size,
+                self.handle_size,
+            );
+            let result = ui.interact(format!("collider_handle_{index}").as_str(), bounds, true);
+            if result.dragging {
+                dragging = Some(index);
+            }
+            let highlighted = bounds.contains(mouse_pos) || self.active == Some(index);
+            ui.rect(bounds, if highlighted { self.hover_color } else { self.color });
+        }
+
+        self.active = dragging;
+        match dragging.and_then(|index| handles.get(index)) {
+            Some(handle) => {
+                drag_collider_handle(collider, transform, handle, viewport.screen_to_world(mouse_pos), snap)
+            }
+            None => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f32::consts::FRAC_PI_2;
+
+    fn handle_of(transform: &Transform2D, collider: &Collider, kind: ColliderHandleKind) -> ColliderHandle {
+        collider_handles(transform, collider)
+            .into_iter()
+            .find(|h| h.kind == kind)
+            .expect("handle")
+    }
+
+    #[test]
+    fn test_box_has_a_handle_on_each_edge() {
+        let transform = Transform2D::new(Vec2::new(100.0, 0.0));
+        let handles = collider_handles(&transform, &Collider::box_collider(40.0, 20.0));
+        let positions: Vec<Vec2> = handles.iter().map(|h| h.position).collect();
+        assert_eq!(
+            positions,
+            vec![Vec2::new(120.0, 0.0), Vec2::new(80.0, 0.0), Vec2::new(100.0, 10.0), Vec2::new(100.0, -10.0)]
+        );
+    }
+
+    #[test]
+    fn test_dragging_box_edge_sets_half_extent() {
+        let transform = Transform2D::new(Vec2::ZERO);
+        let mut collider = Collider::box_collider(40.0, 20.0);
+        let handle = handle_of(&transform, &collider, ColliderHandleKind::HalfExtentX);
+
+        assert!(drag_collider_handle(&mut collider, &transform, &handle, Vec2::new(35.0, 8.0), None));
+        assert_eq!(collider.shape, ColliderShape::Box { half_extents: Vec2::new(35.0, 10.0) });
+    }
+
+    #[test]
+    fn test_drag_follows_body_rotation() {
+        // Rotated 90° CCW: the body's X axis points along world +Y.
+        let transform = Transform2D::from_parts(Vec2::ZERO, FRAC_PI_2, Vec2::ONE);
+        let mut collider = Collider::circle_collider(10.0);
+        let handle = handle_of(&transform, &collider, ColliderHandleKind::Radius);
+        assert!((handle.position - Vec2::new(0.0, 10.0)).length() < 1e-4);
+
+        drag_collider_handle(&mut collider, &transform, &handle, Vec2::new(0.0, 25.0), None);
+        match collider.shape {
+            ColliderShape::Circle { radius } => assert!((radius - 25.0).abs() < 1e-4),
+            other => panic!("unexpected shape {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_capsule_half_height_excludes_the_cap() {
+        let transform = Transform2D::new(Vec2::ZERO);
+        let mut collider = Collider::new(ColliderShape::capsule_y(120.0, 10.0));
+        let handle = handle_of(&transform, &collider, ColliderHandleKind::HalfHeight);
+        assert_eq!(handle.position, Vec2::new(0.0, 60.0));
+
+        drag_collider_handle(&mut collider, &transform, &handle, Vec2::new(0.0, 80.0), None);
+        assert_eq!(collider.shape, ColliderShape::CapsuleY { half_height: 70.0, radius: 10.0 });
+    }
+
+    #[test]
+    fn test_snapping_and_minimum_extent() {
+        let transform = Transform2D::new(Vec2::ZERO);
+        let mut collider = Collider::box_collider(40.0, 20.0);
+        let handle = handle_of(&transform, &collider, ColliderHandleKind::HalfExtentY);
+
+        // Grid 32: half-extents snap to multiples of 16.
+        drag_collider_handle(&mut collider, &transform, &handle, Vec2::new(0.0, 27.0), Some(32.0));
+        assert_eq!(collider.shape, ColliderShape::Box { half_extents: Vec2::new(20.0, 32.0) });
+
+        drag_collider_handle(&mut collider, &transform, &handle, Vec2::ZERO, None);
+        assert_eq!(collider.shape, ColliderShape::Box { half_extents: Vec2::new(20.0, MIN_COLLIDER_EXTENT) });
+    }
+
+    #[test]
+    fn test_offset_moves_the_drag_origin() {
+        let transform = Transform2D::new(Vec2::ZERO);
+        let mut collider = Collider::circle_collider(5.0).with_offset(Vec2::new(50.0, 0.0));
+        let handle = handle_of(&transform, &collider, ColliderHandleKind::Radius);
+
+        drag_collider_handle(&mut collider, &transform, &handle, Vec2::new(62.0, 0.0), None);
+        assert_eq!(collider.shape, ColliderShape::Circle { radius: 12.0 });
+    }
+}
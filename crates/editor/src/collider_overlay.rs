@@ -27,7 +27,7 @@ const OUTLINE_WIDTH_SELECTED: f32 = 2.5;
 
 /// Outline colors for the collider overlay.
 ///
-/// Normally sourced from the theme via `EditorTheme::collider_overlay_colors()`.
+/// Normally sourced from the theme via `EditorColorScheme::collider_overlay_colors()`.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct ColliderOverlayColors {
     /// Solid (non-sensor) colliders
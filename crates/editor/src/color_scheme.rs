@@ -0,0 +1,462 @@
+//! Switchable color schemes for the editor's scene-view drawing.
+//!
+//! [`EditorColorScheme`] holds every color the editor draws *over the
+//! scene*: gizmo handles, grid lines, selection and collider outlines, and
+//! the play-state viewport border. It lives on the theme
+//! (`EditorTheme::colors`) so all overlay drawing reads from one place, and
+//! it is serializable so a customized scheme persists with the editor
+//! preferences.
+//!
+//! Presets ([`ColorSchemePreset`]) cover the stock palette, a color-blind
+//! safe palette (Okabe–Ito — no red/green pairs, so the X/Y gizmo axes and
+//! the collider/selection states stay distinguishable with deuteranopia and
+//! protanopia), and a high-contrast palette.
+//!
+//! ```
+//! use editor::{ColorSchemePreset, EditorColorScheme};
+//!
+//! let scheme = ColorSchemePreset::ColorBlindSafe.scheme();
+//! assert_ne!(scheme, EditorColorScheme::default());
+//! assert_eq!(ColorSchemePreset::of(&scheme), Some(ColorSchemePreset::ColorBlindSafe));
+//! ```
+
+use serde::{Deserialize, Serialize};
+use ui::Color;
+
+use crate::EditorContext;
+
+/// Built-in color schemes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+pub enum ColorSchemePreset {
+    /// The stock palette (red/green axes)
+    #[default]
+    Standard,
+    /// Okabe–Ito palette, safe for red-green color blindness
+    ColorBlindSafe,
+    /// Saturated, opaque colors for low-vision use
+    HighContrast,
+}
+
+impl ColorSchemePreset {
+    /// Every preset, in cycling order.
+    pub const ALL: [ColorSchemePreset; 3] = [Self::Standard, Self::ColorBlindSafe, Self::HighContrast];
+
+    /// Human-readable name (menus, status bar).
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Standard => "Standard",
+            Self::ColorBlindSafe => "Color-Blind Safe",
+            Self::HighContrast => "High Contrast",
+        }
+    }
+
+    /// The preset's colors.
+    pub fn scheme(self) -> EditorColorScheme {
+        match self {
+            Self::Standard => EditorColorScheme::standard(),
+            Self::ColorBlindSafe => EditorColorScheme::color_blind_safe(),
+            Self::HighContrast => EditorColorScheme::high_contrast(),
+        }
+    }
+
+    /// The preset after this one, wrapping around.
+    pub fn next(self) -> Self {
+        let index = Self::ALL.iter().position(|preset| *preset == self).unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+
+    /// The preset `scheme` matches exactly, if any (customized schemes match
+    /// none).
+    pub fn of(scheme: &EditorColorScheme) -> Option<Self> {
+        Self::ALL.into_iter().find(|preset| preset.scheme() == *scheme)
+    }
+}
+
+/// Colors for everything the editor draws over the scene view.
+///
+/// Fields missing from a serialized scheme fall back to the standard
+/// palette.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EditorColorScheme {
+    // ── Gizmos ──────────────────────────────────────────────────
+    /// X-axis gizmo handle
+    pub gizmo_x: Color,
+    /// Y-axis gizmo handle
+    pub gizmo_y: Color,
+    /// Center/free-move gizmo handle
+    pub gizmo_center: Color,
+    /// X-axis handle while hovered/dragged
+    pub gizmo_x_hover: Color,
+    /// Y-axis handle while hovered/dragged
+    pub gizmo_y_hover: Color,
+    /// Center handle while hovered/dragged
+    pub gizmo_center_hover: Color,
+    /// Rotation ring
+    pub gizmo_ring: Color,
+    /// Current-rotation indicator line
+    pub gizmo_rotation_indicator: Color,
+    /// Scale gizmo box outline
+    pub gizmo_scale_outline: Color,
+    /// Scale gizmo (and collider gizmo) handles
+    pub gizmo_scale_handle: Color,
+    /// Scale gizmo (and collider gizmo) handles while hovered/dragged
+    pub gizmo_scale_handle_hover: Color,
+
+    // ── Grid ────────────────────────────────────────────────────
+    /// Primary grid lines
+    pub grid_primary: Color,
+    /// Secondary (subdivision) grid lines
+    pub grid_secondary: Color,
+    /// Grid X axis line
+    pub grid_axis_x: Color,
+    /// Grid Y axis line
+    pub grid_axis_y: Color,
+
+    // ── Play-state viewport borders ─────────────────────────────
+    /// Viewport border tint while editing
+    pub border_editing: Color,
+    /// Viewport border tint while playing
+    pub border_playing: Color,
+    /// Viewport border tint while paused
+    pub border_paused: Color,
+
+    // ── Collider overlay ────────────────────────────────────────
+    /// Solid (non-sensor) collider outlines
+    pub collider_outline: Color,
+    /// Sensor (trigger-only) collider outlines
+    pub collider_sensor: Color,
+    /// Collider outline on selected entities
+    pub collider_selected: Color,
+    /// Paused-edit marker on bodies physics hasn't synced yet
+    pub physics_unsynced: Color,
+    /// World-streaming chunk borders and labels
+    pub chunk_bounds: Color,
+    /// Spatial audio source ranges (Overlays menu)
+    pub audio_range: Color,
+    /// Camera entity frames (Overlays menu)
+    pub camera_frame: Color,
+    /// Measure-tool line, endpoints and readout
+    pub measure: Color,
+
+    // ── Selection overlay ───────────────────────────────────────
+    /// Outline around the primary selected entity
+    pub selection_outline_primary: Color,
+    /// Outline around additionally selected entities
+    pub selection_outline_secondary: Color,
+}
+
+impl Default for EditorColorScheme {
+    fn default() -> Self {
+        Self::standard()
+    }
+}
+
+impl EditorColorScheme {
+    /// The stock palette.
+    pub fn standard() -> Self {
+        Self {
+            gizmo_x: Color::new(0.0, 1.0, 0.0, 1.0),
+            gizmo_y: Color::new(1.0, 0.0, 0.0, 1.0),
+            gizmo_center: Color::new(0.9, 0.9, 0.2, 1.0),
+            gizmo_x_hover: Color::new(1.0, 0.4, 0.4, 1.0),
+            gizmo_y_hover: Color::new(0.4, 1.0, 0.4, 1.0),
+            gizmo_center_hover: Color::new(1.0, 1.0, 0.4, 1.0),
+            gizmo_ring: Color::new(0.3, 0.3, 0.9, 1.0),
+            gizmo_rotation_indicator: Color::new(0.9, 0.9, 0.9, 1.0),
+            gizmo_scale_outline: Color::new(0.6, 0.6, 0.6, 1.0),
+            gizmo_scale_handle: Color::new(0.7, 0.7, 0.7, 1.0),
+            gizmo_scale_handle_hover: Color::new(0.9, 0.9, 0.4, 1.0),
+
+            grid_primary: Color::new(0.3, 0.3, 0.3, 0.5),
+            grid_secondary: Color::new(0.25, 0.25, 0.25, 0.3),
+            grid_axis_x: Color::new(0.8, 0.2, 0.2, 0.8),
+            grid_axis_y: Color::new(0.2, 0.8, 0.2, 0.8),
+
+            border_editing: Color::new(0.0, 0.48, 0.83, 0.5),
+            border_playing: Color::new(0.0, 0.8, 0.27, 0.8),
+            border_paused: Color::new(1.0, 0.8, 0.0, 0.8),
+
+            collider_outline: Color::new(0.2, 1.0, 0.4, 0.9),
+            collider_sensor: Color::new(0.2, 0.85, 1.0, 0.9),
+            collider_selected: Color::new(1.0, 0.85, 0.2, 1.0),
+            physics_unsynced: Color::new(1.0, 0.45, 0.1, 1.0),
+            chunk_bounds: Color::new(0.75, 0.55, 1.0, 0.6),
+            audio_range: Color::new(0.3, 0.6, 1.0, 0.7),
+            camera_frame: Color::new(0.95, 0.95, 0.95, 0.8),
+            measure: Color::new(1.0, 0.4, 0.8, 1.0),
+
+            selection_outline_primary: Color::from_hex(0xff8c00),
+            selection_outline_secondary: Color::new(0.0, 0.85, 1.0, 0.8),
+        }
+    }
+
+    /// Okabe–Ito palette: axes are vermillion vs blue, states use orange,
+    /// sky blue, bluish green and yellow — never a red/green pair.
+    pub fn color_blind_safe() -> Self {
+        let orange = Color::from_hex(0xe69f00);
+        let sky_blue = Color::from_hex(0x56b4e9);
+        let bluish_green = Color::from_hex(0x009e73);
+        let yellow = Color::from_hex(0xf0e442);
+        let blue = Color::from_hex(0x0072b2);
+        let vermillion = Color::from_hex(0xd55e00);
+        let reddish_purple = Color::from_hex(0xcc79a7);
+        Self {
+            gizmo_x: vermillion,
+            gizmo_y: blue,
+            gizmo_center: yellow,
+            gizmo_x_hover: vermillion.lighten(0.4),
+            gizmo_y_hover: blue.lighten(0.4),
+            gizmo_center_hover: yellow.lighten(0.5),
+            gizmo_ring: sky_blue,
+            gizmo_rotation_indicator: Color::new(0.9, 0.9, 0.9, 1.0),
+            gizmo_scale_outline: Color::new(0.6, 0.6, 0.6, 1.0),
+            gizmo_scale_handle: Color::new(0.7, 0.7, 0.7, 1.0),
+            gizmo_scale_handle_hover: yellow,
+
+            grid_primary: Color::new(0.3, 0.3, 0.3, 0.5),
+            grid_secondary: Color::new(0.25, 0.25, 0.25, 0.3),
+            grid_axis_x: vermillion.with_alpha(0.8),
+            grid_axis_y: blue.with_alpha(0.8),
+
+            border_editing: sky_blue.with_alpha(0.5),
+            border_playing: bluish_green.with_alpha(0.8),
+            border_paused: yellow.with_alpha(0.8),
+
+            collider_outline: bluish_green.with_alpha(0.9),
+            collider_sensor: sky_blue.with_alpha(0.9),
+            collider_selected: yellow,
+            physics_unsynced: vermillion,
+            chunk_bounds: reddish_purple.with_alpha(0.6),
+            audio_range: blue.with_alpha(0.7),
+            camera_frame: Color::new(0.95, 0.95, 0.95, 0.8),
+            measure: yellow,
+
+            selection_outline_primary: orange,
+            selection_outline_secondary: sky_blue.with_alpha(0.8),
+        }
+    }
+
+    /// Saturated, fully opaque colors on white hovers.
+    pub fn high_contrast() -> Self {
+        Self {
+            gizmo_x: Color::MAGENTA,
+            gizmo_y: Color::CYAN,
+            gizmo_center: Color::YELLOW,
+            gizmo_x_hover: Color::WHITE,
+            gizmo_y_hover: Color::WHITE,
+            gizmo_center_hover: Color::WHITE,
+            gizmo_ring: Color::CYAN,
+            gizmo_rotation_indicator: Color::WHITE,
+            gizmo_scale_outline: Color::WHITE,
+            gizmo_scale_handle: Color::YELLOW,
+            gizmo_scale_handle_hover: Color::WHITE,
+
+            grid_primary: Color::new(0.6, 0.6, 0.6, 0.8),
+            grid_secondary: Color::new(0.4, 0.4, 0.4, 0.6),
+            grid_axis_x: Color::MAGENTA,
+            grid_axis_y: Color::CYAN,
+
+            border_editing: Color::WHITE,
+            border_playing: Color::CYAN,
+            border_paused: Color::YELLOW,
+
+            collider_outline: Color::CYAN,
+            collider_sensor: Color::MAGENTA,
+            collider_selected: Color::YELLOW,
+            physics_unsynced: Color::from_hex(0xff6600),
+            chunk_bounds: Color::WHITE,
+            audio_range: Color::CYAN,
+            camera_frame: Color::WHITE,
+            measure: Color::YELLOW,
+
+            selection_outline_primary: Color::YELLOW,
+            selection_outline_secondary: Color::WHITE,
+        }
+    }
+}
+
+impl EditorColorScheme {
+    /// Create `GridColors` from this scheme.
+    pub fn grid_colors(&self) -> crate::GridColors {
+        crate::GridColors {
+            primary: self.grid_primary.to_vec4(),
+            secondary: self.grid_secondary.to_vec4(),
+            axis_x: self.grid_axis_x.to_vec4(),
+            axis_y: self.grid_axis_y.to_vec4(),
+        }
+    }
+
+    /// Create a `GizmoPalette` from this scheme.
+    pub fn gizmo_palette(&self) -> crate::GizmoPalette {
+        crate::GizmoPalette {
+            x: self.gizmo_x,
+            y: self.gizmo_y,
+            center: self.gizmo_center,
+            x_hover: self.gizmo_x_hover,
+            y_hover: self.gizmo_y_hover,
+            center_hover: self.gizmo_center_hover,
+            ring: self.gizmo_ring,
+            rotation_indicator: self.gizmo_rotation_indicator,
+            scale_outline: self.gizmo_scale_outline,
+            scale_handle: self.gizmo_scale_handle,
+            scale_handle_hover: self.gizmo_scale_handle_hover,
+        }
+    }
+
+    /// Create `ColliderOverlayColors` from this scheme.
+    pub fn collider_overlay_colors(&self) -> crate::ColliderOverlayColors {
+        crate::ColliderOverlayColors {
+            solid: self.collider_outline,
+            sensor: self.collider_sensor,
+            selected: self.collider_selected,
+        }
+    }
+
+    /// Create `SelectionOverlayColors` from this scheme.
+    pub fn selection_overlay_colors(&self) -> crate::SelectionOverlayColors {
+        crate::SelectionOverlayColors {
+            primary: self.selection_outline_primary,
+            secondary: self.selection_outline_secondary,
+        }
+    }
+
+    /// Get the viewport border color for a given play state.
+    pub fn play_state_border(&self, state: crate::EditorPlayState) -> Color {
+        match state {
+            crate::EditorPlayState::Editing => self.border_editing,
+            crate::EditorPlayState::Playing => self.border_playing,
+            crate::EditorPlayState::Paused => self.border_paused,
+        }
+    }
+}
+
+impl EditorContext {
+    /// The active scene-view color scheme.
+    pub fn color_scheme(&self) -> &EditorColorScheme {
+        &self.theme.colors
+    }
+
+    /// Switch the scene-view color scheme, recoloring the gizmos and grid.
+    pub fn set_color_scheme(&mut self, scheme: EditorColorScheme) {
+        self.theme.colors = scheme;
+        self.gizmo.apply_theme(&self.theme);
+        self.collider_gizmo.apply_theme(&self.theme);
+        self.grid.colors = self.theme.colors.grid_colors();
+    }
+
+    /// Switch to the next preset (View > Cycle Color Scheme). A customized
+    /// scheme cycles to the first preset. Returns the new preset.
+    pub fn cycle_color_scheme(&mut self) -> ColorSchemePreset {
+        let next = ColorSchemePreset::of(&self.theme.colors)
+            .map_or(ColorSchemePreset::Standard, ColorSchemePreset::next);
+        self.set_color_scheme(next.scheme());
+        next
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_every_preset_keeps_states_distinct() {
+        for preset in ColorSchemePreset::ALL {
+            let scheme = preset.scheme();
+            let label = preset.label();
+            assert_ne!(scheme.gizmo_x, scheme.gizmo_y, "{label}: gizmo axes");
+            assert_ne!(scheme.gizmo_x_hover, scheme.gizmo_x, "{label}: x hover");
+            assert_ne!(scheme.gizmo_y_hover, scheme.gizmo_y, "{label}: y hover");
+            assert_ne!(scheme.gizmo_scale_handle_hover, scheme.gizmo_scale_handle, "{label}: scale hover");
+            assert_ne!(scheme.collider_outline, scheme.collider_sensor, "{label}: collider vs sensor");
+            assert_ne!(scheme.collider_outline, scheme.collider_selected, "{label}: collider vs selected");
+            assert_ne!(scheme.collider_sensor, scheme.collider_selected, "{label}: sensor vs selected");
+            assert_ne!(scheme.selection_outline_primary, scheme.selection_outline_secondary, "{label}: selection");
+            assert_ne!(scheme.border_editing, scheme.border_playing, "{label}: editing vs playing");
+            assert_ne!(scheme.border_playing, scheme.border_paused, "{label}: playing vs paused");
+        }
+    }
+
+    #[test]
+    fn test_standard_play_state_borders_are_distinct() {
+        let scheme = EditorColorScheme::standard();
+        let (editing, playing, paused) = (scheme.border_editing, scheme.border_playing, scheme.border_paused);
+        // Each state has a unique dominant channel
+        assert!(editing.b > editing.r && editing.b > editing.g); // blue-ish
+        assert!(playing.g > playing.r && playing.g > playing.b); // green-ish
+        assert!(paused.r > paused.b); // warm/yellow-ish
+    }
+
+    #[test]
+    fn test_color_blind_safe_axes_avoid_red_green_pair() {
+        let scheme = EditorColorScheme::color_blind_safe();
+        // Red-green deficiency collapses the red/green difference; the axes
+        // must still differ strongly in blue and in lightness.
+        let luma = |c: Color| 0.2126 * c.r + 0.7152 * c.g + 0.0722 * c.b;
+        assert!((scheme.gizmo_x.b - scheme.gizmo_y.b).abs() > 0.5);
+        assert!((luma(scheme.gizmo_x) - luma(scheme.gizmo_y)).abs() > 0.1);
+        assert!(scheme.gizmo_y.g < scheme.gizmo_y.b, "y axis is blue, not green");
+    }
+
+    #[test]
+    fn test_converters_match_fields() {
+        let scheme = EditorColorScheme::color_blind_safe();
+        let grid = scheme.grid_colors();
+        assert_eq!(grid.primary, scheme.grid_primary.to_vec4());
+        assert_eq!(grid.axis_x, scheme.grid_axis_x.to_vec4());
+        assert_eq!(grid.axis_y, scheme.grid_axis_y.to_vec4());
+
+        let palette = scheme.gizmo_palette();
+        assert_eq!((palette.x, palette.y, palette.center), (scheme.gizmo_x, scheme.gizmo_y, scheme.gizmo_center));
+        assert_eq!(palette.ring, scheme.gizmo_ring);
+        assert_eq!(palette.scale_handle_hover, scheme.gizmo_scale_handle_hover);
+
+        let colliders = scheme.collider_overlay_colors();
+        assert_eq!(colliders.solid, scheme.collider_outline);
+        assert_eq!(colliders.sensor, scheme.collider_sensor);
+        assert_eq!(colliders.selected, scheme.collider_selected);
+
+        let selection = scheme.selection_overlay_colors();
+        assert_eq!(selection.primary, scheme.selection_outline_primary);
+        assert_eq!(selection.secondary, scheme.selection_outline_secondary);
+
+        assert_eq!(scheme.play_state_border(crate::EditorPlayState::Editing), scheme.border_editing);
+        assert_eq!(scheme.play_state_border(crate::EditorPlayState::Playing), scheme.border_playing);
+        assert_eq!(scheme.play_state_border(crate::EditorPlayState::Paused), scheme.border_paused);
+    }
+
+    #[test]
+    fn test_presets_cycle_and_identify() {
+        assert_eq!(ColorSchemePreset::Standard.next(), ColorSchemePreset::ColorBlindSafe);
+        assert_eq!(ColorSchemePreset::HighContrast.next(), ColorSchemePreset::Standard);
+        for preset in ColorSchemePreset::ALL {
+            assert_eq!(ColorSchemePreset::of(&preset.scheme()), Some(preset));
+        }
+        let custom = EditorColorScheme { gizmo_x: Color::WHITE, ..Default::default() };
+        assert_eq!(ColorSchemePreset::of(&custom), None);
+    }
+
+    #[test]
+    fn test_partial_json_falls_back_to_standard() {
+        let json = r#"{ "gizmo_x": { "r": 1.0, "g": 1.0, "b": 1.0, "a": 1.0 } }"#;
+        let scheme: EditorColorScheme = serde_json::from_str(json).unwrap();
+        assert_eq!(scheme.gizmo_x, Color::WHITE);
+        assert_eq!(scheme.gizmo_y, EditorColorScheme::standard().gizmo_y);
+
+        let high_contrast = EditorColorScheme::high_contrast();
+        let round_trip: EditorColorScheme =
+            serde_json::from_str(&serde_json::to_string(&high_contrast).unwrap()).unwrap();
+        assert_eq!(round_trip, high_contrast);
+    }
+
+    #[test]
+    fn test_set_color_scheme_recolors_gizmos_and_grid() {
+        let mut editor = EditorContext::new();
+        assert_eq!(editor.cycle_color_scheme(), ColorSchemePreset::ColorBlindSafe);
+
+        let scheme = EditorColorScheme::color_blind_safe();
+        assert_eq!(editor.color_scheme(), &scheme);
+        assert_eq!(editor.grid.colors.axis_x, scheme.grid_axis_x.to_vec4());
+        assert_eq!(editor.gizmo.palette().x, scheme.gizmo_x);
+    }
+}
@@ -28,12 +28,22 @@ impl AddComponentCommand {
             captured: None,
         }
     }
+
+    /// Add `value` instead of a default instance (pasting a copied
+    /// component). `None` for components that can't be added.
+    pub fn with_value(entity: EntityId, value: StoredComponent) -> Option<Self> {
+        Some(Self {
+            entity,
+            kind: value.kind()?,
+            captured: Some(value),
+        })
+    }
 }
 
 impl EditorCommand for AddComponentCommand {
     fn execute(&mut self, world: &mut World) {
         if let Some(ref stored) = self.captured {
-            // Redo — restore the captured value.
+            // Redo (or a pasted value) — restore the captured value.
             stored.apply_to(world, self.entity);
         } else {
             // First execute — add default.
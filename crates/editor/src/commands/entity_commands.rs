@@ -1,8 +1,9 @@
-//! Commands for entity lifecycle: create, delete, and grouped (macro) actions.
+//! Commands for entity lifecycle: create (single or a whole group), delete,
+//! and grouped (macro) actions.
 
 use std::any::Any;
 
-use ecs::{EntityId, World, WorldHierarchyExt};
+use ecs::{EntityId, Guid, World, WorldHierarchyExt};
 
 use crate::stored_component::{capture_all_components, restore_components, StoredComponent};
 
@@ -68,6 +69,86 @@ impl EditorCommand for CreateEntityCommand {
     fn as_any_mut(&mut self) -> &mut dyn Any { self }
 }
 
+// ---------------------------------------------------------------------------
+// CreateEntitiesCommand
+// ---------------------------------------------------------------------------
+
+/// Command for a group of entities the caller already created together,
+/// hierarchy included (a pasted subtree).
+///
+/// Undo removes the group; redo recreates every entity under its original
+/// id and re-links each to the parent it had, which [`CreateEntityCommand`]
+/// alone can't do for children.
+pub struct CreateEntitiesCommand {
+    name: String,
+    /// Entities in creation order (parents before their children)
+    entities: Vec<EntityId>,
+    parents: Vec<Option<EntityId>>,
+    components: Vec<Vec<StoredComponent>>,
+    captured: bool,
+}
+
+impl CreateEntitiesCommand {
+    /// Create from entities already added to the world, listed parents
+    /// before children.
+    pub fn already_created(world: &World, name: impl Into<String>, entities: Vec<EntityId>) -> Self {
+        let mut command = Self {
+            name: name.into(),
+            entities,
+            parents: Vec::new(),
+            components: Vec::new(),
+            captured: true,
+        };
+        command.capture(world);
+        command
+    }
+
+    /// The entities this command creates.
+    pub fn entities(&self) -> &[EntityId] {
+        &self.entities
+    }
+
+    fn capture(&mut self, world: &World) {
+        self.parents = self.entities.iter().map(|&e| world.get_parent(e)).collect();
+        self.components = self.entities.iter().map(|&e| capture_all_components(world, e)).collect();
+    }
+}
+
+impl EditorCommand for CreateEntitiesCommand {
+    fn execute(&mut self, world: &mut World) {
+        if self.captured {
+            self.captured = false;
+            return;
+        }
+        // Redo — same ids (never recycled), then the hierarchy once every
+        // member exists
+        for (&entity, components) in self.entities.iter().zip(&self.components) {
+            world.create_entity_with_id(entity);
+            restore_components(world, entity, components);
+        }
+        for (&entity, parent) in self.entities.iter().zip(&self.parents) {
+            if let Some(parent) = parent {
+                world.set_parent(entity, *parent).ok();
+            }
+        }
+    }
+
+    fn undo(&mut self, world: &mut World) {
+        self.capture(world);
+        for entity in self.entities.iter().rev() {
+            world.remove_entity(entity).ok();
+        }
+        self.captured = false;
+    }
+
+    fn display_name(&self) -> &str {
+        &self.name
+    }
+
+    fn as_any(&self) -> &dyn Any { self }
+    fn as_any_mut(&mut self) -> &mut dyn Any { self }
+}
+
 // ---------------------------------------------------------------------------
 // DeleteEntityCommand
 // ---------------------------------------------------------------------------
@@ -75,6 +156,9 @@ impl EditorCommand for CreateEntityCommand {
 /// Command for deleting an entity.
 pub struct DeleteEntityCommand {
     entity: EntityId,
+    /// Captured separately: the generic component capture skips `Guid` so
+    /// duplicate/paste mint fresh ones, but undo must bring this one back.
+    guid: Option<Guid>,
     components: Vec<StoredComponent>,
     parent: Option<EntityId>,
     children: Vec<EntityId>,
@@ -85,6 +169,7 @@ impl DeleteEntityCommand {
     pub fn new(entity: EntityId) -> Self {
         Self {
             entity,
+            guid: None,
             components: Vec::new(),
             parent: None,
             children: Vec::new(),
@@ -95,6 +180,7 @@ impl DeleteEntityCommand {
 impl EditorCommand for DeleteEntityCommand {
     fn execute(&mut self, world: &mut World) {
         // Capture component data and hierarchy before removal.
+        self.guid = world.guid_of(self.entity);
         self.components = capture_all_components(world, self.entity);
         self.parent = world.get_parent(self.entity);
         self.children = world
@@ -120,6 +206,9 @@ impl EditorCommand for DeleteEntityCommand {
         // recycled, so the slot is guaranteed free, and selections / later
         // commands referencing it stay valid across the undo.
         world.create_entity_with_id(self.entity);
+        if let Some(guid) = self.guid {
+            world.add_component(&self.entity, guid).ok();
+        }
         restore_components(world, self.entity, &self.components);
 
         // Restore hierarchy.
@@ -175,6 +264,22 @@ impl EditorCommand for MacroCommand {
         &self.name
     }
 
+    /// Merges a same-named macro of the same length whose commands each
+    /// merge into this one's, in order — a batch inspector edit dragged
+    /// across frames over the same selection.
+    fn try_merge(&mut self, other: &dyn EditorCommand) -> bool {
+        let Some(other) = other.as_any().downcast_ref::<MacroCommand>() else {
+            return false;
+        };
+        self.name == other.name
+            && self.commands.len() == other.commands.len()
+            && self
+                .commands
+                .iter_mut()
+                .zip(&other.commands)
+                .all(|(cmd, next)| cmd.try_merge(next.as_ref()))
+    }
+
     fn as_any(&self) -> &dyn Any { self }
     fn as_any_mut(&mut self) -> &mut dyn Any { self }
 }
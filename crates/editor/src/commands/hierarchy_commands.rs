@@ -0,0 +1,354 @@
+//! Commands that restructure the entity hierarchy.
+
+use std::any::Any;
+
+use glam::Vec2;
+
+use ecs::{EntityId, GlobalTransform2D, Group, Name, Transform2D, World, WorldHierarchyExt};
+
+use super::EditorCommand;
+
+// ---------------------------------------------------------------------------
+// FlattenSubtreeCommand
+// ---------------------------------------------------------------------------
+
+/// A descendant moved by [`FlattenSubtreeCommand`], with what undo restores.
+struct FlattenedEntity {
+    entity: EntityId,
+    parent: EntityId,
+    transform: Option<Transform2D>,
+}
+
+/// Reparent every descendant of `root` directly under `root`, undoing
+/// accidental deep nesting. Each moved entity's local `Transform2D` is
+/// rewritten so its world pose is unchanged. Children keep their
+/// depth-first order under the root.
+pub struct FlattenSubtreeCommand {
+    root: EntityId,
+    moved: Vec<FlattenedEntity>,
+}
+
+impl FlattenSubtreeCommand {
+    /// Flatten the subtree under `root`. Hierarchy state is captured on
+    /// execute.
+    pub fn new(root: EntityId) -> Self {
+        Self { root, moved: Vec::new() }
+    }
+
+    /// How many entities the last execute moved (0 = already flat).
+    pub fn moved_count(&self) -> usize {
+        self.moved.len()
+    }
+}
+
+impl EditorCommand for FlattenSubtreeCommand {
+    fn execute(&mut self, world: &mut World) {
+        // Compute every new local transform before touching the hierarchy:
+        // world poses depend on the structure being rewritten.
+        let root_global = propagated_global(world, self.root);
+        let mut moved = Vec::new();
+        let mut new_locals = Vec::new();
+        for entity in world.get_descendants(self.root) {
+            let Some(parent) = world.get_parent(entity) else {
+                continue;
+            };
+            if parent == self.root {
+                continue;
+            }
+            let transform = world.get::<Transform2D>(entity).copied();
+            new_locals.push(transform.map(|t| relative_to(&root_global, &propagated_global(world, entity), t)));
+            moved.push(FlattenedEntity { entity, parent, transform });
+        }
+
+        for (flattened, local) in moved.iter().zip(new_locals) {
+            world.set_parent(flattened.entity, self.root).ok();
+            if let (Some(local), Some(transform)) = (local, world.get_mut::<Transform2D>(flattened.entity)) {
+                *transform = local;
+            }
+        }
+        self.moved = moved;
+    }
+
+    fn undo(&mut self, world: &mut World) {
+        // Depth-first order re-appends each parent's children in their
+        // original order.
+        for flattened in &self.moved {
+            world.set_parent(flattened.entity, flattened.parent).ok();
+            if let (Some(original), Some(transform)) =
+                (flattened.transform, world.get_mut::<Transform2D>(flattened.entity))
+            {
+                *transform = original;
+            }
+        }
+    }
+
+    fn display_name(&self) -> &str {
+        "Flatten Subtree"
+    }
+
+    fn as_any(&self) -> &dyn Any { self }
+    fn as_any_mut(&mut self) -> &mut dyn Any { self }
+}
+
+// ---------------------------------------------------------------------------
+// GroupEntitiesCommand
+// ---------------------------------------------------------------------------
+
+/// An entity moved into a group by [`GroupEntitiesCommand`], with what undo
+/// restores.
+struct GroupedEntity {
+    entity: EntityId,
+    parent: Option<EntityId>,
+    transform: Option<Transform2D>,
+}
+
+/// Create a [`Group`] node and move entities into it (Entity > Group
+/// Selection, Ctrl+G).
+///
+/// The group gets a `Name` and an identity `Transform2D` and sits under the
+/// grouped entities' shared parent, or at the scene root when they have
+/// different parents. Entities that change parent get their local
+/// `Transform2D` rewritten so their world pose is unchanged. An entity whose
+/// ancestor is also being grouped moves with that ancestor. The group keeps
+/// its id across undo/redo.
+pub struct GroupEntitiesCommand {
+    entities: Vec<EntityId>,
+    name: String,
+    group: Option<EntityId>,
+    moved: Vec<GroupedEntity>,
+}
+
+impl GroupEntitiesCommand {
+    /// Group `entities` under a new group named `name`. Hierarchy state is
+    /// captured on execute.
+    pub fn new(entities: Vec<EntityId>, name: impl Into<String>) -> Self {
+        Self { entities, name: name.into(), group: None, moved: Vec::new() }
+    }
+
+    /// The group node, once executed (None if there was nothing to group).
+    pub fn group(&self) -> Option<EntityId> {
+        self.group
+    }
+}
+
+impl EditorCommand for GroupEntitiesCommand {
+    fn execute(&mut self, world: &mut World) {
+        let grouped: Vec<EntityId> = self
+            .entities
+            .iter()
+            .copied()
+            .filter(|&e| world.get_entity(&e).is_ok())
+            .filter(|&e| !self.entities.iter().any(|&other| other != e && world.is_ancestor_of(other, e)))
+            .collect();
+        let Some(&first) = grouped.first() else {
+            self.moved.clear();
+            return;
+        };
+        let first_parent = world.get_parent(first);
+        let group_parent = first_parent.filter(|_| grouped.iter().all(|&e| world.get_parent(e) == first_parent));
+
+        // Redo recreates the group under its original id
+        let group = match self.group {
+            Some(id) => world.create_entity_with_id(id),
+            None => world.create_entity(),
+        };
+        world
+            .insert_bundle(group, (Name::new(self.name.clone()), Transform2D::default(), Group))
+            .ok();
+        if let Some(parent) = group_parent {
+            world.set_parent(group, parent).ok();
+        }
+        self.group = Some(group);
+
+        // New locals are computed before any entity moves (poses depend on
+        // the structure being rewritten)
+        let group_global = propagated_global(world, group);
+        let mut moved = Vec::new();
+        let mut new_locals = Vec::new();
+        for &entity in &grouped {
+            let parent = world.get_parent(entity);
+            let transform = world.get::<Transform2D>(entity).copied();
+            let rebased = parent != group_parent;
+            new_locals.push(
+                transform
+                    .filter(|_| rebased)
+                    .map(|t| relative_to(&group_global, &propagated_global(world, entity), t)),
+            );
+            moved.push(GroupedEntity { entity, parent, transform });
+        }
+        for (grouped, local) in moved.iter().zip(new_locals) {
+            world.set_parent(grouped.entity, group).ok();
+            if let (Some(local), Some(transform)) = (local, world.get_mut::<Transform2D>(grouped.entity)) {
+                *transform = local;
+            }
+        }
+        self.moved = moved;
+    }
+
+    fn undo(&mut self, world: &mut World) {
+        for grouped in &self.moved {
+            match grouped.parent {
+                Some(parent) => world.set_parent(grouped.entity, parent).ok(),
+                None => world.remove_parent(grouped.entity).ok(),
+            };
+            if let (Some(original), Some(transform)) =
+                (grouped.transform, world.get_mut::<Transform2D>(grouped.entity))
+            {
+                *transform = original;
+            }
+        }
+        if let Some(group) = self.group.filter(|_| !self.moved.is_empty()) {
+            world.remove_parent(group).ok();
+            world.remove_entity(&group).ok();
+        }
+    }
+
+    fn display_name(&self) -> &str {
+        "Group Selection"
+    }
+
+    fn as_any(&self) -> &dyn Any { self }
+    fn as_any_mut(&mut self) -> &mut dyn Any { self }
+}
+
+/// World transform of `entity` as the transform hierarchy system computes
+/// it: the `Transform2D` chain from the root ancestor down.
+fn propagated_global(world: &World, entity: EntityId) -> GlobalTransform2D {
+    let mut chain = world.get_ancestors(entity);
+    chain.reverse();
+    chain.push(entity);
+    chain.iter().fold(GlobalTransform2D::default(), |global, &e| {
+        global.mul_transform(&world.get::<Transform2D>(e).copied().unwrap_or_default())
+    })
+}
+
+/// Local transform that places a child of `parent` at `global`. Axes with a
+/// zero parent scale cannot be inverted and keep `fallback`'s scale.
+fn relative_to(parent: &GlobalTransform2D, global: &GlobalTransform2D, fallback: Transform2D) -> Transform2D {
+    let safe_div = |value: f32, by: f32, keep: f32| if by == 0.0 { keep } else { value / by };
+    let offset = global.position - parent.position;
+    let scaled = Vec2::new(
+        safe_div(offset.x, parent.scale.x, 0.0),
+        safe_div(offset.y, parent.scale.y, 0.0),
+    );
+    let (sin, cos) = (-parent.rotation).sin_cos();
+    let position = Vec2::new(scaled.x * cos - scaled.y * sin, scaled.x * sin + scaled.y * cos);
+    let scale = Vec2::new(
+        safe_div(global.scale.x, parent.scale.x, fallback.scale.x),
+        safe_div(global.scale.y, parent.scale.y, fallback.scale.y),
+    );
+    Transform2D::from_parts(position, global.rotation - parent.rotation, scale)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spawn_at(world: &mut World, x: f32, parent: Option<EntityId>) -> EntityId {
+        let entity = world
+            .spawn((Transform2D::from_parts(Vec2::new(x, 0.0), 0.0, Vec2::splat(2.0)),))
+            .id();
+        if let Some(parent) = parent {
+            world.set_parent(entity, parent).expect("parent");
+        }
+        entity
+    }
+
+    #[test]
+    fn test_flatten_moves_descendants_under_root_keeping_world_pose() {
+        let mut world = World::new();
+        let root = spawn_at(&mut world, 10.0, None);
+        let child = spawn_at(&mut world, 5.0, Some(root));
+        let grandchild = spawn_at(&mut world, 3.0, Some(child));
+        let before = propagated_global(&world, grandchild);
+
+        let mut cmd = FlattenSubtreeCommand::new(root);
+        cmd.execute(&mut world);
+
+        assert_eq!(cmd.moved_count(), 1);
+        assert_eq!(world.get_parent(grandchild), Some(root));
+        assert_eq!(world.get_children(root), Some(&[child, grandchild][..]));
+        let after = propagated_global(&world, grandchild);
+        assert!((after.position - before.position).length() < 1e-4, "{before:?} vs {after:?}");
+        assert!((after.scale - before.scale).length() < 1e-4);
+    }
+
+    #[test]
+    fn test_flatten_undo_restores_parents_and_transforms() {
+        let mut world = World::new();
+        let root = spawn_at(&mut world, 0.0, None);
+        let child = spawn_at(&mut world, 5.0, Some(root));
+        let first = spawn_at(&mut world, 1.0, Some(child));
+        let second = spawn_at(&mut world, 2.0, Some(child));
+        let original = *world.get::<Transform2D>(first).expect("transform");
+
+        let mut cmd = FlattenSubtreeCommand::new(root);
+        cmd.execute(&mut world);
+        cmd.undo(&mut world);
+
+        assert_eq!(world.get_children(child), Some(&[first, second][..]));
+        assert_eq!(world.get_children(root), Some(&[child][..]));
+        assert_eq!(world.get::<Transform2D>(first), Some(&original));
+    }
+
+    #[test]
+    fn test_group_siblings_nests_group_under_shared_parent() {
+        let mut world = World::new();
+        let root = spawn_at(&mut world, 10.0, None);
+        let a = spawn_at(&mut world, 1.0, Some(root));
+        let b = spawn_at(&mut world, 2.0, Some(root));
+        let local_a = *world.get::<Transform2D>(a).expect("transform");
+
+        let mut cmd = GroupEntitiesCommand::new(vec![a, b], "Group 1");
+        cmd.execute(&mut world);
+
+        let group = cmd.group().expect("group created");
+        assert!(world.get::<Group>(group).is_some());
+        assert_eq!(world.get::<Name>(group).map(|n| n.as_str()), Some("Group 1"));
+        assert_eq!(world.get_parent(group), Some(root));
+        assert_eq!(world.get_children(group), Some(&[a, b][..]));
+        assert_eq!(world.get::<Transform2D>(a), Some(&local_a), "identity group leaves locals alone");
+
+        cmd.undo(&mut world);
+        assert!(world.get_entity(&group).is_err());
+        assert_eq!(world.get_children(root), Some(&[a, b][..]));
+    }
+
+    #[test]
+    fn test_group_mixed_parents_goes_to_root_keeping_world_pose_and_id() {
+        let mut world = World::new();
+        let root = spawn_at(&mut world, 10.0, None);
+        let child = spawn_at(&mut world, 5.0, Some(root));
+        let grandchild = spawn_at(&mut world, 3.0, Some(child));
+        let loose = spawn_at(&mut world, -4.0, None);
+        let before = propagated_global(&world, child);
+
+        let mut cmd = GroupEntitiesCommand::new(vec![child, grandchild, loose], "Group");
+        cmd.execute(&mut world);
+        let group = cmd.group().expect("group created");
+
+        assert_eq!(world.get_parent(group), None);
+        assert_eq!(world.get_children(group), Some(&[child, loose][..]), "grandchild moves with its ancestor");
+        assert_eq!(world.get_parent(grandchild), Some(child));
+        let after = propagated_global(&world, child);
+        assert!((after.position - before.position).length() < 1e-4, "{before:?} vs {after:?}");
+
+        cmd.undo(&mut world);
+        assert_eq!(world.get_parent(child), Some(root));
+        assert_eq!(world.get_parent(loose), None);
+        cmd.execute(&mut world);
+        assert_eq!(cmd.group(), Some(group), "redo keeps the group's id");
+        assert_eq!(world.get_parent(loose), Some(group));
+    }
+
+    #[test]
+    fn test_flatten_of_flat_subtree_moves_nothing() {
+        let mut world = World::new();
+        let root = spawn_at(&mut world, 0.0, None);
+        spawn_at(&mut world, 1.0, Some(root));
+
+        let mut cmd = FlattenSubtreeCommand::new(root);
+        cmd.execute(&mut world);
+        assert_eq!(cmd.moved_count(), 0);
+    }
+}
@@ -0,0 +1,385 @@
+//! Undo/redo stacks for editor commands.
+//!
+//! Every recorded command is kept as a [`HistoryEntry`] with a sequence id
+//! and the time it was first recorded, so the History panel can list them
+//! and [`jump_to`](CommandHistory::jump_to) any point with repeated
+//! undo/redo. A [`RestorePoint`] pins the current point before a risky edit;
+//! it stays reachable until the entries it depends on are evicted by the
+//! history limit or discarded by a new command after an undo.
+
+use std::collections::VecDeque;
+use std::time::Instant;
+
+use ecs::World;
+
+use super::EditorCommand;
+
+/// A recorded command with its history metadata.
+pub struct HistoryEntry {
+    /// Sequence number, unique within one history
+    id: u64,
+    /// When the command was first recorded (merges keep the original time)
+    recorded_at: Instant,
+    command: Box<dyn EditorCommand>,
+}
+
+impl HistoryEntry {
+    /// Sequence number, unique within one history.
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    /// Display name of the command.
+    pub fn name(&self) -> &str {
+        self.command.display_name()
+    }
+
+    /// When the command was first recorded.
+    pub fn recorded_at(&self) -> Instant {
+        self.recorded_at
+    }
+}
+
+/// A pinned point in the history that can be jumped back to.
+#[derive(Debug, Clone)]
+pub struct RestorePoint {
+    /// Label shown in the History panel
+    pub label: String,
+    /// When the point was pinned
+    pub pinned_at: Instant,
+    /// Id of the last applied entry when pinned; `None` = before every
+    /// retained entry
+    after: Option<u64>,
+}
+
+/// Manages undo/redo stacks for editor commands.
+pub struct CommandHistory {
+    undo_stack: VecDeque<HistoryEntry>,
+    redo_stack: Vec<HistoryEntry>,
+    max_history: usize,
+    next_id: u64,
+    restore_points: Vec<RestorePoint>,
+}
+
+impl CommandHistory {
+    /// Create a new command history with default max history (100).
+    pub fn new() -> Self {
+        Self {
+            undo_stack: VecDeque::new(),
+            redo_stack: Vec::new(),
+            max_history: 100,
+            next_id: 0,
+            restore_points: Vec::new(),
+        }
+    }
+
+    /// Execute a command and push it onto the undo stack. Clears the redo stack.
+    pub fn execute(&mut self, mut cmd: Box<dyn EditorCommand>, world: &mut World) {
+        cmd.execute(world);
+        self.record(cmd);
+    }
+
+    /// Undo the most recent command. Returns `true` if a command was applied.
+    pub fn undo(&mut self, world: &mut World) -> bool {
+        if let Some(mut entry) = self.undo_stack.pop_back() {
+            entry.command.undo(world);
+            self.redo_stack.push(entry);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Redo the most recently undone command. Returns `true` if a command was applied.
+    pub fn redo(&mut self, world: &mut World) -> bool {
+        if let Some(mut entry) = self.redo_stack.pop() {
+            entry.command.execute(world);
+            self.undo_stack.push_back(entry);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Whether there is a command to undo.
+    pub fn can_undo(&self) -> bool {
+        !self.undo_stack.is_empty()
+    }
+
+    /// Whether there is a command to redo.
+    pub fn can_redo(&self) -> bool {
+        !self.redo_stack.is_empty()
+    }
+
+    /// Display name of the command that would be undone, if any.
+    pub fn undo_name(&self) -> Option<&str> {
+        self.undo_stack.back().map(HistoryEntry::name)
+    }
+
+    /// Display name of the command that would be redone, if any.
+    pub fn redo_name(&self) -> Option<&str> {
+        self.redo_stack.last().map(HistoryEntry::name)
+    }
+
+    /// Clear both undo and redo stacks, and every restore point.
+    pub fn clear(&mut self) {
+        self.undo_stack.clear();
+        self.redo_stack.clear();
+        self.restore_points.clear();
+    }
+
+    /// Push a pre-executed command onto the undo stack without calling execute().
+    /// Use when the action was already performed and you just need to record it for undo.
+    pub fn push_already_executed(&mut self, cmd: Box<dyn EditorCommand>) {
+        self.record(cmd);
+    }
+
+    /// Try to merge `cmd` with the last undo command. If merging fails, execute normally.
+    ///
+    /// Used for continuous edits like gizmo drags or slider scrubs to avoid
+    /// flooding the undo history with one entry per frame.
+    pub fn try_merge_or_execute(&mut self, cmd: Box<dyn EditorCommand>, world: &mut World) {
+        if self.try_merge(cmd.as_ref()) {
+            // Merged into existing command — no new push needed.
+            return;
+        }
+        self.execute(cmd, world);
+    }
+
+    /// Try to merge `cmd` with the last undo command, or push without executing if merge fails.
+    ///
+    /// Use when the change was already applied to the world manually (e.g., inspector
+    /// writeback for immediate visual feedback). The command is recorded for undo/redo
+    /// but `execute()` is not called.
+    pub fn try_merge_or_push(&mut self, cmd: Box<dyn EditorCommand>) {
+        if self.try_merge(cmd.as_ref()) {
+            return;
+        }
+        self.push_already_executed(cmd);
+    }
+
+    // ================== History Listing ==================
+
+    /// Every retained entry, oldest first: the first [`position`](Self::position)
+    /// are applied, the rest were undone and can be redone.
+    pub fn entries(&self) -> impl Iterator<Item = &HistoryEntry> + '_ {
+        self.undo_stack.iter().chain(self.redo_stack.iter().rev())
+    }
+
+    /// Number of retained entries, applied or undone.
+    pub fn len(&self) -> usize {
+        self.undo_stack.len() + self.redo_stack.len()
+    }
+
+    /// Whether no entries are retained.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Number of applied entries; `0` is the oldest reachable state.
+    pub fn position(&self) -> usize {
+        self.undo_stack.len()
+    }
+
+    /// Undo or redo until `position` entries are applied (clamped to
+    /// [`len`](Self::len)). Returns the number of commands undone or redone.
+    pub fn jump_to(&mut self, position: usize, world: &mut World) -> usize {
+        let target = position.min(self.len());
+        let mut steps = 0;
+        while self.position() > target && self.undo(world) {
+            steps += 1;
+        }
+        while self.position() < target && self.redo(world) {
+            steps += 1;
+        }
+        steps
+    }
+
+    // ================== Restore Points ==================
+
+    /// Pin the current position as a restore point. The newest applied
+    /// entry stops merging follow-up edits, so the pinned state stays
+    /// exactly reachable.
+    pub fn pin_restore_point(&mut self, label: impl Into<String>) {
+        self.restore_points.push(RestorePoint {
+            label: label.into(),
+            pinned_at: Instant::now(),
+            after: self.undo_stack.back().map(HistoryEntry::id),
+        });
+    }
+
+    /// Restore points still reachable, oldest pin first.
+    pub fn restore_points(&self) -> &[RestorePoint] {
+        &self.restore_points
+    }
+
+    /// History position restore point `index` returns to.
+    pub fn restore_point_position(&self, index: usize) -> Option<usize> {
+        match self.restore_points.get(index)?.after {
+            None => Some(0),
+            Some(id) => self.entries().position(|entry| entry.id == id).map(|found| found + 1),
+        }
+    }
+
+    /// Jump to restore point `index`. Returns `false` when there is no such point.
+    pub fn jump_to_restore_point(&mut self, index: usize, world: &mut World) -> bool {
+        match self.restore_point_position(index) {
+            Some(position) => {
+                self.jump_to(position, world);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Unpin restore point `index`.
+    pub fn remove_restore_point(&mut self, index: usize) -> Option<RestorePoint> {
+        (index < self.restore_points.len()).then(|| self.restore_points.remove(index))
+    }
+
+    // ================== Internals ==================
+
+    /// Push an already-applied command as a new entry, discarding the redo
+    /// stack and restore points that pointed into it.
+    fn record(&mut self, command: Box<dyn EditorCommand>) {
+        let discarded: Vec<u64> = self.redo_stack.drain(..).map(|entry| entry.id).collect();
+        self.restore_points
+            .retain(|point| point.after.is_none_or(|id| !discarded.contains(&id)));
+        self.undo_stack.push_back(HistoryEntry { id: self.next_id, recorded_at: Instant::now(), command });
+        self.next_id += 1;
+        self.enforce_limit();
+    }
+
+    /// Merge `cmd` into the newest applied entry unless a restore point pins it.
+    fn try_merge(&mut self, cmd: &dyn EditorCommand) -> bool {
+        let Some(last) = self.undo_stack.back_mut() else {
+            return false;
+        };
+        if self.restore_points.iter().any(|point| point.after == Some(last.id)) {
+            return false;
+        }
+        last.command.try_merge(cmd)
+    }
+
+    fn enforce_limit(&mut self) {
+        while self.undo_stack.len() > self.max_history {
+            let Some(evicted) = self.undo_stack.pop_front() else {
+                break;
+            };
+            // The state before the evicted entry is gone; the state after it
+            // is now the oldest reachable one
+            self.restore_points.retain_mut(|point| match point.after {
+                None => false,
+                Some(id) if id == evicted.id => {
+                    point.after = None;
+                    true
+                }
+                Some(_) => true,
+            });
+        }
+    }
+}
+
+impl Default for CommandHistory {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::SetTransformCommand;
+    use ecs::EntityId;
+    use glam::Vec2;
+
+    fn move_command(world: &World, entity: EntityId, x: f32) -> Box<dyn EditorCommand> {
+        let old = world.get::<common::Transform2D>(entity).copied().unwrap_or_default();
+        let new = common::Transform2D::new(Vec2::new(x, 0.0));
+        Box::new(SetTransformCommand::new(entity, old, new, "position"))
+    }
+
+    fn x_of(world: &World, entity: EntityId) -> f32 {
+        world.get::<common::Transform2D>(entity).unwrap().position.x
+    }
+
+    fn setup() -> (World, EntityId) {
+        let mut world = World::new();
+        let entity = world.spawn((common::Transform2D::new(Vec2::ZERO),)).id();
+        (world, entity)
+    }
+
+    #[test]
+    fn test_jump_to_undoes_and_redoes_several_steps() {
+        let (mut world, entity) = setup();
+        let mut history = CommandHistory::new();
+        for x in 1..=4 {
+            let cmd = move_command(&world, entity, x as f32);
+            history.execute(cmd, &mut world);
+        }
+
+        assert_eq!(history.jump_to(1, &mut world), 3);
+        assert_eq!(x_of(&world, entity), 1.0);
+        assert_eq!(history.position(), 1);
+        assert_eq!(history.len(), 4, "undone entries stay listed");
+        let ids: Vec<u64> = history.entries().map(HistoryEntry::id).collect();
+        assert_eq!(ids, [0, 1, 2, 3]);
+
+        assert_eq!(history.jump_to(3, &mut world), 2);
+        assert_eq!(x_of(&world, entity), 3.0);
+        assert_eq!(history.jump_to(99, &mut world), 1, "clamped to the newest entry");
+        assert_eq!(history.jump_to(0, &mut world), 4);
+        assert_eq!(x_of(&world, entity), 0.0);
+    }
+
+    #[test]
+    fn test_restore_point_survives_merges_and_later_edits() {
+        let (mut world, entity) = setup();
+        let mut history = CommandHistory::new();
+        let cmd = move_command(&world, entity, 1.0);
+        history.execute(cmd, &mut world);
+        history.pin_restore_point("Before risky edit");
+
+        // Would merge into the first move without the pin
+        let cmd = move_command(&world, entity, 2.0);
+        history.try_merge_or_execute(cmd, &mut world);
+        assert_eq!(history.len(), 2);
+        let cmd = move_command(&world, entity, 3.0);
+        history.try_merge_or_execute(cmd, &mut world);
+        assert_eq!(history.len(), 2, "entries after the pin merge as usual");
+
+        assert!(history.jump_to_restore_point(0, &mut world));
+        assert_eq!(x_of(&world, entity), 1.0);
+        assert_eq!(history.restore_points()[0].label, "Before risky edit");
+        assert!(!history.jump_to_restore_point(1, &mut world));
+    }
+
+    #[test]
+    fn test_restore_points_are_pruned_with_their_entries() {
+        let (mut world, entity) = setup();
+        let mut history = CommandHistory::new();
+        history.pin_restore_point("Start");
+        let cmd = move_command(&world, entity, 1.0);
+        history.execute(cmd, &mut world);
+        history.pin_restore_point("After first");
+        let cmd = move_command(&world, entity, 2.0);
+        history.execute(cmd, &mut world);
+        history.pin_restore_point("After second");
+
+        // Undo the second move, then branch: the second move is discarded
+        history.undo(&mut world);
+        let cmd = move_command(&world, entity, 5.0);
+        history.execute(cmd, &mut world);
+        let labels: Vec<&str> = history.restore_points().iter().map(|point| point.label.as_str()).collect();
+        assert_eq!(labels, ["Start", "After first"]);
+
+        // Evicting the first move drops "Start"; "After first" becomes the oldest state
+        history.max_history = 2;
+        let cmd = move_command(&world, entity, 6.0);
+        history.execute(cmd, &mut world);
+        assert_eq!(history.restore_points().len(), 1);
+        assert_eq!(history.restore_point_position(0), Some(0));
+        assert!(history.remove_restore_point(0).is_some());
+        assert!(history.restore_points().is_empty());
+    }
+}
@@ -0,0 +1,51 @@
+//! Commands that edit the scene's render layer order.
+
+use std::any::Any;
+
+use ecs::{RenderLayers, World};
+
+use super::EditorCommand;
+
+// ---------------------------------------------------------------------------
+// MoveRenderLayerCommand
+// ---------------------------------------------------------------------------
+
+/// Move a render layer to a new position in the world's [`RenderLayers`]
+/// (back to front), e.g. from the layers panel's up/down buttons. A world
+/// without the resource starts from the built-in layers.
+pub struct MoveRenderLayerCommand {
+    name: String,
+    to: usize,
+    from: Option<usize>,
+}
+
+impl MoveRenderLayerCommand {
+    /// Move layer `name` to index `to`. The original index is captured on
+    /// execute.
+    pub fn new(name: impl Into<String>, to: usize) -> Self {
+        Self { name: name.into(), to, from: None }
+    }
+}
+
+impl EditorCommand for MoveRenderLayerCommand {
+    fn execute(&mut self, world: &mut World) {
+        world.init_resource::<RenderLayers>();
+        if let Some(layers) = world.resource_mut::<RenderLayers>() {
+            self.from = layers.index_of(&self.name);
+            layers.move_layer(&self.name, self.to);
+        }
+    }
+
+    fn undo(&mut self, world: &mut World) {
+        if let (Some(from), Some(layers)) = (self.from, world.resource_mut::<RenderLayers>()) {
+            layers.move_layer(&self.name, from);
+        }
+    }
+
+    fn display_name(&self) -> &str {
+        "Move Layer"
+    }
+
+    fn as_any(&self) -> &dyn Any { self }
+    fn as_any_mut(&mut self) -> &mut dyn Any { self }
+}
@@ -1,12 +1,16 @@
-//! Commands for property edits: gizmo drags and inspector field writes.
+//! Commands for property edits: gizmo drags, inspector field writes and
+//! renames.
 
 use std::any::Any;
 
 use ecs::audio_components::AudioSource;
 use ecs::behavior::Behavior;
-use ecs::sprite_components::Sprite;
+use ecs::sprite_components::{Name, Sprite, SpriteAnimation};
 use ecs::{EntityId, World};
 use physics::components::{Collider, RigidBody};
+use physics::{AreaForce, Buoyancy, Drag};
+
+use crate::stored_component::StoredComponent;
 
 use super::EditorCommand;
 
@@ -61,6 +65,92 @@ impl EditorCommand for TransformGizmoCommand {
     fn as_any_mut(&mut self) -> &mut dyn Any { self }
 }
 
+// ---------------------------------------------------------------------------
+// SetStoredComponentCommand
+// ---------------------------------------------------------------------------
+
+/// Command that swaps a whole component value for any registry type — used
+/// when the new value was built generically (e.g. play-mode changes kept
+/// after Stop) rather than by a typed field editor.
+pub struct SetStoredComponentCommand {
+    entity: EntityId,
+    old: StoredComponent,
+    new: StoredComponent,
+}
+
+impl SetStoredComponentCommand {
+    /// `old` and `new` must be the same component type.
+    pub fn new(entity: EntityId, old: StoredComponent, new: StoredComponent) -> Self {
+        Self { entity, old, new }
+    }
+}
+
+impl EditorCommand for SetStoredComponentCommand {
+    fn execute(&mut self, world: &mut World) {
+        self.new.apply_to(world, self.entity);
+    }
+
+    fn undo(&mut self, world: &mut World) {
+        self.old.apply_to(world, self.entity);
+    }
+
+    fn display_name(&self) -> &str {
+        "Set Component"
+    }
+
+    fn as_any(&self) -> &dyn Any { self }
+    fn as_any_mut(&mut self) -> &mut dyn Any { self }
+}
+
+// ---------------------------------------------------------------------------
+// RenameEntityCommand
+// ---------------------------------------------------------------------------
+
+/// Command that renames an entity through its [`Name`] component. Clearing
+/// the name removes the component, so the hierarchy falls back to its
+/// generated label.
+pub struct RenameEntityCommand {
+    entity: EntityId,
+    old: Option<String>,
+    new: Option<String>,
+}
+
+impl RenameEntityCommand {
+    /// Rename `entity` to `name` (trimmed; blank clears the name). `None`
+    /// when that is already its name.
+    pub fn new(world: &World, entity: EntityId, name: &str) -> Option<Self> {
+        let old = world.get::<Name>(entity).map(|name| name.as_str().to_string());
+        let name = name.trim();
+        let new = (!name.is_empty()).then(|| name.to_string());
+        (old != new).then_some(Self { entity, old, new })
+    }
+
+    fn write(&self, world: &mut World, name: Option<&String>) {
+        // Missing entities are ignored like every other set command
+        match name {
+            Some(name) => world.add_component(&self.entity, Name::new(name.as_str())).ok(),
+            None => world.remove_component::<Name>(&self.entity).ok(),
+        };
+    }
+}
+
+impl EditorCommand for RenameEntityCommand {
+    fn execute(&mut self, world: &mut World) {
+        self.write(world, self.new.as_ref());
+    }
+
+    fn undo(&mut self, world: &mut World) {
+        self.write(world, self.old.as_ref());
+    }
+
+    fn display_name(&self) -> &str {
+        "Rename Entity"
+    }
+
+    fn as_any(&self) -> &dyn Any { self }
+    fn as_any_mut(&mut self) -> &mut dyn Any { self }
+}
+
 // ---------------------------------------------------------------------------
 // Set*Commands (inspector property edits)
 // ---------------------------------------------------------------------------
@@ -122,12 +212,24 @@ impl_set_component_command!(
 impl_set_component_command!(
     /// Command for an inspector property edit on a Sprite.
     SetSpriteCommand, Sprite, "Set Sprite");
+impl_set_component_command!(
+    /// Command for an inspector property edit on a SpriteAnimation.
+    SetSpriteAnimationCommand, SpriteAnimation, "Set SpriteAnimation");
 impl_set_component_command!(
     /// Command for an inspector property edit on a RigidBody.
     SetRigidBodyCommand, RigidBody, "Set RigidBody");
 impl_set_component_command!(
     /// Command for an inspector property edit on a Collider.
     SetColliderCommand, Collider, "Set Collider");
+impl_set_component_command!(
+    /// Command for an inspector property edit on an AreaForce.
+    SetAreaForceCommand, AreaForce, "Set AreaForce");
+impl_set_component_command!(
+    /// Command for an inspector property edit on a Buoyancy.
+    SetBuoyancyCommand, Buoyancy, "Set Buoyancy");
+impl_set_component_command!(
+    /// Command for an inspector property edit on a Drag.
+    SetDragCommand, Drag, "Set Drag");
 impl_set_component_command!(
     /// Command for an inspector property edit on an AudioSource.
     SetAudioSourceCommand, AudioSource, "Set AudioSource");
@@ -188,6 +188,21 @@ fn test_delete_entity_undo_restores() {
     assert_eq!(world.get::<Sprite>(restored).unwrap().texture_handle, 7);
 }
 
+#[test]
+fn test_delete_entity_undo_keeps_the_guid() {
+    let mut world = World::new();
+    let entity = setup_entity(&mut world);
+    let guid = world.guid_of(entity).unwrap();
+
+    let mut history = CommandHistory::new();
+    history.execute(Box::new(DeleteEntityCommand::new(entity)), &mut world);
+    assert_eq!(world.entity_by_guid(guid), None);
+
+    history.undo(&mut world);
+    assert_eq!(world.guid_of(entity), Some(guid));
+    assert_eq!(world.entity_by_guid(guid), Some(entity), "GUID references resolve again");
+}
+
 #[test]
 fn test_delete_entity_redo_removes_again() {
     let mut world = World::new();
@@ -392,6 +407,45 @@ fn test_macro_command_undo() {
     assert_eq!(world.get::<common::Transform2D>(e2).unwrap().position, Vec2::ZERO);
 }
 
+#[test]
+fn test_macro_commands_merge_pairwise() {
+    let mut world = World::new();
+    let e1 = setup_entity(&mut world);
+    let e2 = setup_entity(&mut world);
+    let origin = common::Transform2D::default();
+    let batch = |x: f32| -> Box<dyn EditorCommand> {
+        let new = common::Transform2D::new(Vec2::new(x, 0.0));
+        Box::new(MacroCommand::new("Set Transform (2 entities)", vec![
+            Box::new(SetTransformCommand::new(e1, origin, new, "position")),
+            Box::new(SetTransformCommand::new(e2, origin, new, "position")),
+        ]))
+    };
+
+    let mut history = CommandHistory::new();
+    history.execute(batch(1.0), &mut world);
+    history.try_merge_or_push(batch(2.0));
+
+    history.undo(&mut world);
+    assert!(!history.can_undo(), "the second batch merged into the first");
+    assert_eq!(world.get::<common::Transform2D>(e1).unwrap().position, Vec2::ZERO);
+    history.redo(&mut world);
+    assert_eq!(world.get::<common::Transform2D>(e2).unwrap().position, Vec2::new(2.0, 0.0));
+}
+
+// -- MoveRenderLayerCommand --
+
+#[test]
+fn test_move_render_layer_undo_restores_order() {
+    let mut world = World::new();
+    let mut history = CommandHistory::new();
+    history.execute(Box::new(MoveRenderLayerCommand::new("Background", 3)), &mut world);
+    let layers = world.resource::<ecs::RenderLayers>().unwrap();
+    assert_eq!(layers.index_of("Background"), Some(3));
+
+    history.undo(&mut world);
+    assert!(world.resource::<ecs::RenderLayers>().unwrap().is_default());
+}
+
 // -- Max history limit --
 
 #[test]
@@ -0,0 +1,243 @@
+//! Component clipboard: copy one component off an entity and paste its
+//! values onto others (Inspector header right-click menu), or add it to
+//! entities that lack it ("+ Add Component" right-click menu).
+//!
+//! The copied component is held as serialized JSON text rather than a live
+//! value, so it outlives the world it came from — copy a tuned Collider in
+//! one scene and paste it in another.
+
+use serde::{Deserialize, Serialize};
+
+use ecs::{EntityId, World};
+
+use crate::commands::{AddComponentCommand, EditorCommand, MacroCommand, SetStoredComponentCommand};
+use crate::stored_component::StoredComponent;
+use crate::{EditableFieldStyle, FieldId, InspectorExtras};
+
+/// Header context-menu items, in menu order.
+const COPY_ITEM: &str = "Copy Component";
+const PASTE_ITEM: &str = "Paste Component Values";
+
+/// Request raised by a component header's right-click menu; the
+/// integration layer carries it out (it owns the clipboard and history).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ComponentMenuAction {
+    /// Copy this component (registry name) from the primary selection
+    Copy(&'static str),
+    /// Paste the clipboard's values over this component on the selection
+    PasteValues(&'static str),
+}
+
+/// The clipboard's serialized form of one component (also used per
+/// component by the entity clipboard).
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct ClipboardEntry {
+    pub(crate) component: String,
+    pub(crate) value: serde_json::Value,
+}
+
+impl ClipboardEntry {
+    /// Serialize an inspectable component; `None` for hidden ones.
+    pub(crate) fn from_component(component: &StoredComponent) -> Option<Self> {
+        let value = component.to_json()?;
+        Some(Self { component: component.type_name().to_string(), value })
+    }
+
+    /// Rebuild the component.
+    pub(crate) fn into_component(self) -> Result<StoredComponent, String> {
+        StoredComponent::from_json(&self.component, self.value)
+    }
+}
+
+/// Editor-internal clipboard holding one copied component.
+#[derive(Debug, Clone, Default)]
+pub struct ComponentClipboard {
+    /// Registry name of the copied component, cached for menu labels
+    type_name: Option<String>,
+    /// Serialized [`ClipboardEntry`]
+    text: Option<String>,
+}
+
+impl ComponentClipboard {
+    /// An empty clipboard.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether nothing has been copied yet.
+    pub fn is_empty(&self) -> bool {
+        self.text.is_none()
+    }
+
+    /// Registry name of the copied component (e.g. `"Collider"`).
+    pub fn type_name(&self) -> Option<&str> {
+        self.type_name.as_deref()
+    }
+
+    /// The clipboard's serialized text.
+    pub fn text(&self) -> Option<&str> {
+        self.text.as_deref()
+    }
+
+    /// Copy `component`. Hidden components (never inspected) are refused.
+    pub fn copy(&mut self, component: &StoredComponent) -> Result<(), String> {
+        let entry = ClipboardEntry::from_component(component)
+            .ok_or_else(|| format!("{} can't be copied", component.type_name()))?;
+        let text = serde_json::to_string(&entry).map_err(|e| e.to_string())?;
+        self.type_name = Some(entry.component);
+        self.text = Some(text);
+        Ok(())
+    }
+
+    /// Copy `entity`'s component registered as `type_name`.
+    pub fn copy_from(&mut self, world: &World, entity: EntityId, type_name: &str) -> Result<(), String> {
+        let component = StoredComponent::capture_named(world, entity, type_name)
+            .ok_or_else(|| format!("Entity has no {type_name}"))?;
+        self.copy(&component)
+    }
+
+    /// Replace the clipboard with serialized text (as returned by
+    /// [`text`](Self::text)); rejected unless it holds a known component.
+    pub fn set_text(&mut self, text: &str) -> Result<(), String> {
+        let component = Self::parse(text)?;
+        self.copy(&component)
+    }
+
+    /// The copied component, rebuilt from the clipboard text.
+    pub fn component(&self) -> Result<StoredComponent, String> {
+        Self::parse(self.text.as_deref().ok_or("Clipboard is empty")?)
+    }
+
+    /// Command overwriting the copied component's values on every entity
+    /// in `entities` that has that component; entities without it are
+    /// skipped. `Err` when none has it.
+    pub fn paste_values(&self, world: &World, entities: &[EntityId]) -> Result<Box<dyn EditorCommand>, String> {
+        let component = self.component()?;
+        let name = component.type_name();
+        let commands: Vec<Box<dyn EditorCommand>> = entities
+            .iter()
+            .filter_map(|&entity| {
+                let old = StoredComponent::capture_named(world, entity, name)?;
+                Some(Box::new(SetStoredComponentCommand::new(entity, old, component.clone())) as Box<dyn EditorCommand>)
+            })
+            .collect();
+        combine(format!("Paste {name} Values"), commands).ok_or_else(|| format!("No selected entity has a {name}"))
+    }
+
+    /// Command adding the copied component to every entity in `entities`
+    /// that lacks it. `Err` for components every entity already carries
+    /// (e.g. Transform2D) or when all of them have it.
+    pub fn paste_as_new(&self, world: &World, entities: &[EntityId]) -> Result<Box<dyn EditorCommand>, String> {
+        let component = self.component()?;
+        let name = component.type_name();
+        let kind = component.kind().ok_or_else(|| format!("{name} can't be added"))?;
+        let commands: Vec<Box<dyn EditorCommand>> = entities
+            .iter()
+            .filter(|&&entity| !kind.is_present(world, entity))
+            .filter_map(|&entity| AddComponentCommand::with_value(entity, component.clone()))
+            .map(|command| Box::new(command) as Box<dyn EditorCommand>)
+            .collect();
+        combine(format!("Paste {name}"), commands).ok_or_else(|| format!("Every selected entity already has a {name}"))
+    }
+
+    fn parse(text: &str) -> Result<StoredComponent, String> {
+        let entry: ClipboardEntry = serde_json::from_str(text).map_err(|e| format!("Not a copied component: {e}"))?;
+        entry.into_component()
+    }
+}
+
+/// One command as-is, several as a [`MacroCommand`], none as `None`.
+fn combine(name: String, mut commands: Vec<Box<dyn EditorCommand>>) -> Option<Box<dyn EditorCommand>> {
+    match commands.len() {
+        0 => None,
+        1 => commands.pop(),
+        _ => Some(Box::new(MacroCommand::new(name, commands))),
+    }
+}
+
+/// Right-click menu over a component block's header row: "Copy Component",
+/// plus "Paste Component Values" when the clipboard holds the same type.
+/// The pick lands in [`InspectorExtras::component_menu`]. Drawn before the
+/// block's widgets so the open menu blocks them.
+pub(crate) fn header_menu(
+    ui: &mut ui::UIContext,
+    component_index: usize,
+    type_name: &'static str,
+    x: f32,
+    header_y: f32,
+    style: &EditableFieldStyle,
+    extras: &mut InspectorExtras<'_>,
+) {
+    let can_paste = extras.clipboard_type == Some(type_name);
+    let items: &[&str] = if can_paste { &[COPY_ITEM, PASTE_ITEM] } else { &[COPY_ITEM] };
+    let header = ui::Rect::new(x, header_y, style.label_width + style.input_width, style.row_height);
+    match ui.context_menu(FieldId::new(component_index, 98, 0), header, items) {
+        Some(0) => extras.component_menu = Some(ComponentMenuAction::Copy(type_name)),
+        Some(1) => extras.component_menu = Some(ComponentMenuAction::PasteValues(type_name)),
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::CommandHistory;
+    use physics::components::{Collider, RigidBody};
+
+    #[test]
+    fn test_paste_values_copies_between_entities_and_undoes() {
+        let mut world = World::new();
+        let source = world.create_entity();
+        let target = world.create_entity();
+        let tuned = Collider::circle_collider(12.0).with_friction(0.9);
+        world.add_component(&source, tuned).unwrap();
+        world.add_component(&target, Collider::default()).unwrap();
+
+        let mut clipboard = ComponentClipboard::new();
+        clipboard.copy_from(&world, source, "Collider").unwrap();
+        assert_eq!(clipboard.type_name(), Some("Collider"));
+
+        // The text form survives a round trip, as when switching scenes
+        let mut restored = ComponentClipboard::new();
+        restored.set_text(clipboard.text().unwrap()).unwrap();
+
+        let mut history = CommandHistory::new();
+        let paste = restored.paste_values(&world, &[target, source]).unwrap();
+        history.execute(paste, &mut world);
+        assert_eq!(world.get::<Collider>(target).unwrap().friction, 0.9);
+
+        history.undo(&mut world);
+        assert_eq!(world.get::<Collider>(target).unwrap().friction, Collider::default().friction);
+
+        // Nothing to overwrite on an entity without the component
+        let bare = world.create_entity();
+        assert!(restored.paste_values(&world, &[bare]).is_err());
+        assert!(ComponentClipboard::new().set_text("{\"component\":\"Nope\",\"value\":1}").is_err());
+    }
+
+    #[test]
+    fn test_paste_as_new_adds_only_where_missing() {
+        let mut world = World::new();
+        let source = world.create_entity();
+        let missing = world.create_entity();
+        let present = world.create_entity();
+        world.add_component(&source, RigidBody::default().with_gravity_scale(0.25)).unwrap();
+        world.add_component(&present, RigidBody::default()).unwrap();
+
+        let mut clipboard = ComponentClipboard::new();
+        clipboard.copy_from(&world, source, "RigidBody").unwrap();
+        let mut history = CommandHistory::new();
+        let paste = clipboard.paste_as_new(&world, &[missing, present]).unwrap();
+        history.execute(paste, &mut world);
+        assert_eq!(world.get::<RigidBody>(missing).unwrap().gravity_scale, 0.25);
+        assert_eq!(world.get::<RigidBody>(present).unwrap().gravity_scale, RigidBody::default().gravity_scale);
+
+        history.undo(&mut world);
+        assert!(world.get::<RigidBody>(missing).is_none());
+
+        // Builtin components can be copied but never added
+        world.add_component(&source, common::Transform2D::default()).unwrap();
+        clipboard.copy_from(&world, source, "Transform2D").unwrap();
+        assert!(clipboard.paste_as_new(&world, &[missing]).is_err());
+    }
+}
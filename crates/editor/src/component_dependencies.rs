@@ -0,0 +1,146 @@
+//! Component dependency checks built on the registry's `requires [..]`
+//! metadata: the add-component prompt state and whole-world validation.
+//!
+//! Some components only do something alongside another — a `Collider`
+//! without a `RigidBody` is never simulated, a `SpriteAnimation` without a
+//! `Sprite` has nothing to animate. The registry declares those pairs; this
+//! module turns them into an "add the required components too?" prompt and
+//! a validation pass run on scene save/load.
+
+use std::fmt;
+
+use ecs::{EntityId, World};
+
+use crate::stored_component::{missing_dependencies, ComponentKind};
+
+/// A component present on an entity without one it requires.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DependencyViolation {
+    /// The offending entity.
+    pub entity: EntityId,
+    /// The component whose requirement is unmet.
+    pub component: ComponentKind,
+    /// The missing required component.
+    pub missing: ComponentKind,
+}
+
+impl fmt::Display for DependencyViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Entity {}: {} requires {}",
+            self.entity.value(),
+            self.component.display_name(),
+            self.missing.display_name()
+        )
+    }
+}
+
+/// Every unmet component dependency in the world, ordered by entity id.
+pub fn validate_component_dependencies(world: &World) -> Vec<DependencyViolation> {
+    let mut entities = world.entities();
+    entities.sort_by_key(|e| e.value());
+
+    let mut violations = Vec::new();
+    for entity in entities {
+        for &component in ComponentKind::ALL {
+            if !component.is_present(world, entity) {
+                continue;
+            }
+            for missing in missing_dependencies(world, entity, component) {
+                violations.push(DependencyViolation { entity, component, missing });
+            }
+        }
+    }
+    violations
+}
+
+/// Pending "add required components?" prompt shown by the inspector when
+/// the chosen component is missing dependencies.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DependencyPrompt {
+    /// Entity the component is being added to.
+    pub entity: EntityId,
+    /// The component the user picked.
+    pub kind: ComponentKind,
+    /// Required components the entity lacks.
+    pub missing: Vec<ComponentKind>,
+}
+
+impl DependencyPrompt {
+    /// Prompt text, e.g. `"Collider requires RigidBody. Add it too?"`.
+    pub fn message(&self) -> String {
+        let names: Vec<&str> = self.missing.iter().map(|k| k.display_name()).collect();
+        let pronoun = if self.missing.len() == 1 { "it" } else { "them" };
+        format!("{} requires {}. Add {} too?", self.kind.display_name(), names.join(", "), pronoun)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ecs::sprite_components::{Sprite, SpriteAnimation};
+    use physics::components::{Collider, RigidBody};
+
+    #[test]
+    fn registry_declares_collider_and_animation_dependencies() {
+        assert_eq!(ComponentKind::Collider.requires(), &[ComponentKind::RigidBody]);
+        assert_eq!(ComponentKind::SpriteAnimation.requires(), &[ComponentKind::Sprite]);
+        assert!(ComponentKind::Sprite.requires().is_empty());
+    }
+
+    #[test]
+    fn missing_dependencies_lists_only_absent_requirements() {
+        let mut world = World::new();
+        let entity = world.create_entity();
+        assert_eq!(
+            missing_dependencies(&world, entity, ComponentKind::Collider),
+            vec![ComponentKind::RigidBody]
+        );
+
+        world.add_component(&entity, RigidBody::default()).ok();
+        assert!(missing_dependencies(&world, entity, ComponentKind::Collider).is_empty());
+    }
+
+    #[test]
+    fn validation_flags_collider_without_rigid_body() {
+        let mut world = World::new();
+        let valid = world.create_entity();
+        world.add_component(&valid, RigidBody::default()).ok();
+        world.add_component(&valid, Collider::default()).ok();
+        let invalid = world.create_entity();
+        world.add_component(&invalid, Collider::default()).ok();
+
+        let violations = validate_component_dependencies(&world);
+        assert_eq!(
+            violations,
+            vec![DependencyViolation {
+                entity: invalid,
+                component: ComponentKind::Collider,
+                missing: ComponentKind::RigidBody,
+            }]
+        );
+        assert!(violations[0].to_string().ends_with("Collider requires RigidBody"));
+    }
+
+    #[test]
+    fn validation_passes_complete_entities() {
+        let mut world = World::new();
+        let entity = world.create_entity();
+        world.add_component(&entity, Sprite::new(0)).ok();
+        world.add_component(&entity, SpriteAnimation::default()).ok();
+        assert!(validate_component_dependencies(&world).is_empty());
+    }
+
+    #[test]
+    fn prompt_message_names_missing_components() {
+        let mut world = World::new();
+        let entity = world.create_entity();
+        let prompt = DependencyPrompt {
+            entity,
+            kind: ComponentKind::Collider,
+            missing: vec![ComponentKind::RigidBody],
+        };
+        assert_eq!(prompt.message(), "Collider requires RigidBody. Add it too?");
+    }
+}
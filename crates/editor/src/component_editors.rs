@@ -3,6 +3,7 @@
 //! Provides pre-built inspectors for common component types:
 //! - Transform2D
 //! - Sprite
+//! - SpriteAnimation
 //! - RigidBody
 //! - Collider
 //! - AudioSource
@@ -12,16 +13,17 @@
 //! `Some` with the full new value and a `field_hint` naming the changed
 //! field (used to merge continuous slider drags into one undo entry).
 
-use ecs::sprite_components::Sprite;
+use ecs::sprite_components::{Sprite, SpriteAnimation};
 use common::Transform2D;
 use physics::components::{Collider, RigidBody, RigidBodyType, ColliderShape};
+use physics::CombineRule;
 use ecs::audio_components::AudioSource;
 
 use crate::editable_inspector::{EditResult, EditableInspector};
 
 /// Value ranges for component field editors, centralized so every editor
 /// uses consistent limits and they can be tuned in one place.
-mod ranges {
+pub(crate) mod ranges {
     use std::ops::RangeInclusive;
 
     /// Position covers most game worlds.
@@ -35,6 +37,8 @@ mod ranges {
     pub const OFFSET: RangeInclusive<f32> = -100.0..=100.0;
     /// Collider shape dimensions (half-extents, radii) in pixels.
     pub const COLLIDER_EXTENT: RangeInclusive<f32> = 0.5..=1000.0;
+    /// Animation playback speed in frames per second.
+    pub const ANIMATION_FPS: RangeInclusive<f32> = 0.1..=120.0;
     /// Depth sorting range.
     pub const DEPTH: RangeInclusive<f32> = -100.0..=100.0;
     /// Linear velocity.
@@ -51,6 +55,14 @@ mod ranges {
     pub const REFERENCE_DISTANCE: RangeInclusive<f32> = 0.0..=1000.0;
     /// Spatial audio rolloff factor.
     pub const ROLLOFF: RangeInclusive<f32> = 0.0..=5.0;
+    /// Effector zone dimensions (half-extents, radii) in pixels.
+    pub const ZONE_EXTENT: RangeInclusive<f32> = 1.0..=5000.0;
+    /// Area force in pixel units.
+    pub const AREA_FORCE: RangeInclusive<f32> = -1000.0..=1000.0;
+    /// Fluid density relative to the bodies'.
+    pub const FLUID_DENSITY: RangeInclusive<f32> = 0.0..=10.0;
+    /// Effector damping per second (capped at one step's worth when applied).
+    pub const ZONE_DAMPING: RangeInclusive<f32> = 0.0..=60.0;
 }
 
 /// A completed single-frame inspector edit on a component.
@@ -127,12 +139,60 @@ pub fn edit_sprite(
         new.depth = v;
         hint = Some("depth");
     }
+    if let EditResult::Changed(layer) = inspector.layer("Layer", &sprite.layer, extras.layers) {
+        new.layer = layer;
+        hint = Some("layer");
+    }
 
     // Texture slot: shows the resolved path, accepts asset-browser drops
     if let EditResult::Changed(handle) = inspector.texture("Texture", sprite.texture_handle, extras) {
         new.texture_handle = handle;
         hint = Some("texture_handle");
     }
+    // Sheet region: edited in the picker popup, applied by the integration layer
+    if inspector.tex_region("Region", sprite.tex_region, extras.region_names) {
+        extras.pick_region = true;
+    }
+
+    hint.map(|field_hint| ComponentEdit { new_value: new, field_hint })
+}
+
+/// Edit a SpriteAnimation component: preview playback (editor-only, works
+/// in Edit mode), a frame scrubber, fps and flags, and each frame's region.
+pub fn edit_sprite_animation(
+    inspector: &mut EditableInspector<'_>,
+    animation: &SpriteAnimation,
+    extras: &mut crate::InspectorExtras<'_>,
+) -> Option<ComponentEdit<SpriteAnimation>> {
+    let mut new = animation.clone();
+    let mut hint = None;
+
+    inspector.header("SpriteAnimation");
+
+    // Preview toggles are applied by the integration layer (it owns the world)
+    if inspector.animation_preview("Preview", extras.animation_preview) {
+        extras.toggle_animation_preview = true;
+    }
+    if let EditResult::Changed(frame) =
+        inspector.frame_scrubber("Frame", animation.current_frame, animation.frames.len())
+    {
+        new.current_frame = frame;
+        new.time_accumulator = 0.0;
+        hint = Some("current_frame");
+    }
+    if let EditResult::Changed(v) = inspector.f32("FPS", animation.fps, ranges::ANIMATION_FPS) {
+        new.fps = v;
+        hint = Some("fps");
+    }
+    if let EditResult::Changed(v) = inspector.bool("Playing", animation.playing) {
+        new.playing = v;
+        hint = Some("playing");
+    }
+    if let EditResult::Changed(v) = inspector.bool("Loop", animation.loop_animation) {
+        new.loop_animation = v;
+        hint = Some("loop_animation");
+    }
+    inspector.frame_regions(&animation.frames, animation.current_frame, extras.region_names);
 
     hint.map(|field_hint| ComponentEdit { new_value: new, field_hint })
 }
@@ -184,6 +244,13 @@ pub fn edit_rigid_body(
         new.ccd_enabled = v;
         hint = Some("ccd_enabled");
     }
+    if let EditResult::Changed(v) = inspector.bool("Can Sleep", body.can_sleep) {
+        new.can_sleep = v;
+        hint = Some("can_sleep");
+    }
+    // Live state from the last physics update (always awake while paused
+    // before the first step)
+    inspector.string("Sleeping", if body.is_sleeping() { "Yes" } else { "No" });
 
     hint.map(|field_hint| ComponentEdit { new_value: new, field_hint })
 }
@@ -192,7 +259,7 @@ pub fn edit_rigid_body(
 pub fn edit_collider(
     inspector: &mut EditableInspector<'_>,
     collider: &Collider,
-    _extras: &mut crate::InspectorExtras<'_>,
+    extras: &mut crate::InspectorExtras<'_>,
 ) -> Option<ComponentEdit<Collider>> {
     let mut new = collider.clone();
     let mut hint = None;
@@ -262,14 +329,35 @@ pub fn edit_collider(
         new.is_sensor = v;
         hint = Some("is_sensor");
     }
+    // Picking a material sets the coefficients below; hand-editing any of
+    // them detaches the collider from its material ("Custom")
+    if let EditResult::Changed(choice) = inspector.material("Material", collider.material.as_deref(), extras.materials) {
+        match choice {
+            Some(material) => new.set_material(&material),
+            None => new.material = None,
+        }
+        hint = Some("material");
+    }
     if let EditResult::Changed(v) = inspector.normalized_f32("Friction", collider.friction) {
         new.friction = v;
+        new.material = None;
         hint = Some("friction");
     }
     if let EditResult::Changed(v) = inspector.normalized_f32("Restitution", collider.restitution) {
         new.restitution = v;
+        new.material = None;
         hint = Some("restitution");
     }
+    if let Some(rule) = combine_rule_row(inspector, "Friction Mix", collider.friction_combine) {
+        new.friction_combine = rule;
+        new.material = None;
+        hint = Some("friction_combine");
+    }
+    if let Some(rule) = combine_rule_row(inspector, "Bounce Mix", collider.restitution_combine) {
+        new.restitution_combine = rule;
+        new.material = None;
+        hint = Some("restitution_combine");
+    }
 
     // Collision groups/filter (read-only)
     inspector.u32("Groups", collider.collision_groups);
@@ -278,6 +366,15 @@ pub fn edit_collider(
     hint.map(|field_hint| ComponentEdit { new_value: new, field_hint })
 }
 
+/// Cycle row over the contact mixing rules; `Some(rule)` when stepped.
+fn combine_rule_row(inspector: &mut EditableInspector<'_>, label: &str, rule: CombineRule) -> Option<CombineRule> {
+    let rules = CombineRule::ALL;
+    match inspector.cycle(label, rule.name(), rule.priority(), rules.len()) {
+        EditResult::Changed(index) => rules.get(index).copied(),
+        EditResult::Unchanged => None,
+    }
+}
+
 /// Edit an AudioSource component.
 pub fn edit_audio_source(
     inspector: &mut EditableInspector<'_>,
@@ -401,6 +498,8 @@ mod tests {
         assert!(ranges::ROTATION.contains(&0.0));
         // Collider dimensions must stay positive (rapier rejects zero extents)
         assert!(ranges::COLLIDER_EXTENT.start() > &0.0);
+        // A zero frame rate would make the frame duration infinite
+        assert!(ranges::ANIMATION_FPS.start() > &0.0);
     }
 
     #[test]
@@ -0,0 +1,109 @@
+//! Invalid component data in the edited scene, as reported by the
+//! components' [`Validate`](ecs::Validate) impls.
+//!
+//! Refreshed for the whole world on scene load and before entering play
+//! mode, and for the inspected entities as they're edited; listed in the
+//! Console panel.
+//! Play refuses to start while any remain, so a zero-size collider or a
+//! zero-fps animation is fixed in the inspector instead of surfacing as NaNs
+//! inside the physics step.
+
+use ecs::{ComponentError, EntityId, World};
+
+/// Current component validation failures.
+#[derive(Debug, Default)]
+pub struct ComponentIssues {
+    errors: Vec<ComponentError>,
+}
+
+impl ComponentIssues {
+    /// An empty issue list.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// All current failures.
+    pub fn errors(&self) -> &[ComponentError] {
+        &self.errors
+    }
+
+    /// Whether no failures are recorded.
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Number of recorded failures.
+    pub fn len(&self) -> usize {
+        self.errors.len()
+    }
+
+    /// Replace the list (e.g. with a loaded scene's `component_errors`).
+    pub fn set(&mut self, errors: Vec<ComponentError>) {
+        self.errors = errors;
+    }
+
+    /// Forget every failure (new scene).
+    pub fn clear(&mut self) {
+        self.errors.clear();
+    }
+
+    /// Re-check every entity in `world`. Returns the number of failures.
+    pub fn validate_world(&mut self, world: &World) -> usize {
+        let mut entities = world.entities();
+        entities.sort_by_key(|entity| entity.value());
+        self.errors = validate(world, entities);
+        self.errors.len()
+    }
+
+    /// Re-check `entities` only (the inspected ones, after edits), keeping
+    /// other entities' failures. Returns the failures that weren't already
+    /// recorded, so callers can report each new problem once.
+    pub fn revalidate(&mut self, world: &World, entities: &[EntityId]) -> Vec<ComponentError> {
+        let (previous, mut kept): (Vec<_>, Vec<_>) =
+            self.errors.drain(..).partition(|error| entities.contains(&error.entity));
+        let found = validate(world, entities.iter().copied());
+        let fresh = found.iter().filter(|error| !previous.contains(error)).cloned().collect();
+        kept.extend(found);
+        self.errors = kept;
+        fresh
+    }
+}
+
+/// Run the registered validators, including the physics ones.
+fn validate(world: &World, entities: impl IntoIterator<Item = EntityId>) -> Vec<ComponentError> {
+    physics::register_validators();
+    ecs::validate_components(world, entities)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ecs::SpriteAnimation;
+    use physics::Collider;
+
+    #[test]
+    fn test_revalidate_replaces_only_the_edited_entities() {
+        let mut world = World::new();
+        let flat = world.spawn((Collider::circle_collider(0.0),)).id();
+        let frozen = world.spawn((SpriteAnimation::new(0.0, Vec::new()),)).id();
+
+        let mut issues = ComponentIssues::new();
+        assert_eq!(issues.validate_world(&world), 2);
+        assert!(issues.revalidate(&world, &[flat]).is_empty(), "already recorded");
+
+        // Fixing the collider clears its entry; the animation stays reported
+        if let Some(collider) = world.get_mut::<Collider>(flat) {
+            *collider = Collider::circle_collider(8.0);
+        }
+        assert!(issues.revalidate(&world, &[flat]).is_empty());
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues.errors()[0].entity, frozen);
+        if let Some(collider) = world.get_mut::<Collider>(flat) {
+            collider.restitution = -1.0;
+        }
+        assert_eq!(issues.revalidate(&world, &[flat]).len(), 1, "a new problem is reported");
+
+        issues.clear();
+        assert!(issues.is_empty());
+    }
+}
@@ -0,0 +1,35 @@
+//! Gizmo placement and priority helpers on [`EditorContext`].
+
+use glam::Vec2;
+
+use super::EditorContext;
+
+impl EditorContext {
+    /// Get the screen position for the gizmo based on its world position.
+    ///
+    /// Use this to pass to gizmo.render().
+    pub fn gizmo_screen_position(&self) -> Vec2 {
+        self.viewport.world_to_screen(self.gizmo.position())
+    }
+
+    /// Convert a gizmo delta from screen space to world space.
+    ///
+    /// The gizmo returns deltas in screen pixels. This converts them
+    /// to world units accounting for camera zoom, view rotation, and Y-axis
+    /// inversion (screen Y increases downward, world Y increases upward).
+    pub fn gizmo_delta_to_world(&self, screen_delta: Vec2) -> Vec2 {
+        let view_delta = Vec2::new(
+            screen_delta.x / self.viewport.camera_zoom(),
+            -screen_delta.y / self.viewport.camera_zoom(), // Negate Y for world coords
+        );
+        Vec2::from_angle(-self.viewport.camera_rotation()).rotate(view_delta)
+    }
+
+    /// Check if the gizmo should take priority over picking.
+    ///
+    /// Returns true if the transform gizmo or a collider handle is
+    /// currently being interacted with, meaning picking should be skipped.
+    pub fn gizmo_has_priority(&self) -> bool {
+        self.gizmo.is_active() || self.collider_gizmo.is_active()
+    }
+}
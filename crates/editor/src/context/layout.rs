@@ -0,0 +1,126 @@
+//! Panel layout on [`EditorContext`]: dock bounds, fullscreen play,
+//! panel maximize and selection framing.
+
+use glam::Vec2;
+
+use crate::PanelId;
+
+use super::EditorContext;
+
+impl EditorContext {
+    /// Whether the fullscreen play preview is enabled.
+    pub fn is_fullscreen_play(&self) -> bool {
+        self.fullscreen_play
+    }
+
+    /// Enable or disable the fullscreen play preview.
+    pub fn set_fullscreen_play(&mut self, enabled: bool) {
+        self.fullscreen_play = enabled;
+    }
+
+    /// Toggle the fullscreen play preview.
+    pub fn toggle_fullscreen_play(&mut self) {
+        self.fullscreen_play = !self.fullscreen_play;
+    }
+
+    /// Whether editor chrome (menu bar, toolbar, panels, status bar) is
+    /// hidden this frame: fullscreen play is enabled and a play session is
+    /// active. Stopping play brings the chrome back automatically.
+    pub fn is_chrome_hidden(&self) -> bool {
+        self.fullscreen_play && self.in_play_session()
+    }
+
+    /// Maximize the panel under `pos` to the whole dock area, or restore the
+    /// docked layout if it is already maximized. Returns the toggled panel.
+    pub fn toggle_maximize_at(&mut self, pos: Vec2) -> Option<PanelId> {
+        let id = self.dock_area.panel_at(pos)?;
+        self.dock_area.toggle_maximized(id);
+        Some(id)
+    }
+
+    /// Update layout based on window size.
+    ///
+    /// This should be called each frame before rendering to ensure
+    /// panels are properly sized and viewport bounds are updated.
+    pub fn update_layout(&mut self, window_size: Vec2) {
+        if self.is_chrome_hidden() {
+            let window_bounds = common::Rect::new(0.0, 0.0, window_size.x, window_size.y);
+            self.fullscreen_bounds = Some(window_bounds);
+            self.viewport.set_viewport_bounds(window_bounds);
+            return;
+        }
+        self.fullscreen_bounds = None;
+
+        // Reserve space for menu bar (top) and status bar (bottom)
+        let menu_height = self.menu_bar.height();
+        let status_bar_height = crate::status_bar::STATUS_BAR_HEIGHT;
+        let content_bounds = common::Rect::new(
+            0.0,
+            menu_height,
+            window_size.x,
+            window_size.y - menu_height - status_bar_height,
+        );
+
+        self.dock_area.set_bounds(content_bounds);
+        self.dock_area.layout();
+
+        // Update viewport bounds from scene view panel
+        if let Some(scene_bounds) = self.scene_view_bounds() {
+            self.viewport.set_viewport_bounds(scene_bounds);
+        }
+
+        // Note: The gizmo position should be set by the caller based on entity transform
+        // after calling update_layout, using self.selection.primary() to get the selected entity
+    }
+
+    /// Update viewport interpolation. Call each frame.
+    pub fn update_viewport(&mut self, delta_time: f32) {
+        self.viewport.update(delta_time);
+    }
+
+    /// Get the scene view content bounds (where the game world is rendered).
+    pub fn scene_view_bounds(&self) -> Option<common::Rect> {
+        if let Some(bounds) = self.fullscreen_bounds {
+            return Some(bounds);
+        }
+        // Hidden behind another panel's maximize: no scene view this frame.
+        if !self.dock_area.is_panel_shown(PanelId::SCENE_VIEW) {
+            return None;
+        }
+        self.dock_area
+            .get_panel(PanelId::SCENE_VIEW)
+            .map(|p| p.content_bounds())
+    }
+
+    /// Get the inspector panel bounds.
+    pub fn inspector_bounds(&self) -> Option<common::Rect> {
+        self.dock_area
+            .get_panel(PanelId::INSPECTOR)
+            .map(|p| p.content_bounds())
+    }
+
+    /// Get the hierarchy panel bounds.
+    pub fn hierarchy_bounds(&self) -> Option<common::Rect> {
+        self.dock_area
+            .get_panel(PanelId::HIERARCHY)
+            .map(|p| p.content_bounds())
+    }
+
+    /// Frame the selection (F): glide the viewport to fit the union of the
+    /// selected entities' bounds. Returns `false` when none of `entities`
+    /// is selected.
+    pub fn frame_selection(&mut self, entities: &[crate::PickableEntity]) -> bool {
+        let bounds = entities
+            .iter()
+            .filter(|entity| self.selection.contains(entity.entity_id))
+            .map(|entity| entity.aabb())
+            .reduce(|a, b| a.union(&b));
+        match bounds {
+            Some(bounds) => {
+                self.viewport.frame_bounds(bounds);
+                true
+            }
+            None => false,
+        }
+    }
+}
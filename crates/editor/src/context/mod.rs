@@ -16,7 +16,7 @@ use crate::{
     theme::EditorTheme,
     viewport::SceneViewport,
     viewport_input::ViewportInputHandler,
-    DockArea, DockPanel, DockPosition, EditorTool, Gizmo, GizmoMode, MenuBar, PanelId, Selection,
+    ColliderGizmo, DockArea, DockPanel, DockPosition, EditorTool, Gizmo, GizmoMode, MenuBar, PanelId, Selection,
     Toolbar,
 };
 
@@ -27,6 +27,8 @@ pub struct EditorContext {
     pub selection: Selection,
     /// Transform gizmo
     pub gizmo: Gizmo,
+    /// Collider shape handles for the primary selection
+    pub collider_gizmo: ColliderGizmo,
     /// Editor toolbar
     pub toolbar: Toolbar,
     /// Menu bar
@@ -49,12 +51,26 @@ pub struct EditorContext {
     snap_to_grid: bool,
     /// Whether collider outlines are drawn in the scene view
     show_colliders: bool,
+    /// Audio range / camera frame overlay toggles and the Overlays list
+    pub overlays: crate::ViewportOverlays,
+    /// Distraction-free play preview: hide all editor chrome while a play
+    /// session runs and give the scene view the whole window.
+    fullscreen_play: bool,
+    /// Window bounds the scene view fills while chrome is hidden (set by
+    /// `update_layout`; `None` when the docked layout is in effect).
+    fullscreen_bounds: Option<common::Rect>,
     /// Current play state (Editing / Playing / Paused)
     play_state: EditorPlayState,
     /// Play / Pause / Stop controls widget
     pub play_controls: PlayControls,
+    /// Frame counter and queued single steps of the play session
+    pub frame_stepper: crate::FrameStepper,
+    /// Keyboard/mouse focus between the editor and the running game
+    pub input_focus: crate::InputRouter,
     /// Whether the add-component popup is open in the inspector.
     add_component_popup_open: bool,
+    /// Open "add required components too?" prompt in the inspector
+    pub dependency_prompt: Option<crate::DependencyPrompt>,
     /// Whether the scene has unsaved changes
     is_dirty: bool,
     /// Current scene file path (None = untitled/new scene)
@@ -63,10 +79,52 @@ pub struct EditorContext {
     pub theme: EditorTheme,
     /// Status bar at the bottom of the editor
     pub status_bar: StatusBar,
+    /// Background job runner; progress is forwarded to the status bar
+    pub background_tasks: crate::BackgroundTasks,
+    /// Play-mode changes marked to survive Stop
+    pub play_changes: crate::PlayChanges,
     /// Cross-panel drag-and-drop coordinator
     pub drag_drop: crate::DragDropState,
+    /// Copied component for Inspector copy/paste (kept across scene loads)
+    pub component_clipboard: crate::ComponentClipboard,
+    /// Cut/copied entity subtrees for Edit > Cut/Copy/Paste (kept across
+    /// scene loads and tab switches)
+    pub entity_clipboard: crate::EntityClipboard,
     /// Asset browser panel state (scan results, scroll)
     pub asset_browser: crate::AssetBrowserState,
+    /// Compare panel state (last scene comparison)
+    pub scene_compare: crate::SceneCompareState,
+    /// History panel list scroll offset
+    pub history_scroll: f32,
+    /// Sprite-sheet region picker popup (opened from the Sprite inspector)
+    pub region_picker: crate::SpriteRegionPicker,
+    /// Texture picker popup (opened from the Sprite inspector's Texture row)
+    pub texture_picker: crate::TexturePicker,
+    /// Sprite-sheet slicer popup (opened from the region picker)
+    pub sprite_slicer: crate::SpriteSheetSlicer,
+    /// Sliced atlases of textures the inspector has shown
+    pub sprite_atlases: crate::SpriteAtlases,
+    /// Edit-mode SpriteAnimation playback started from the inspector
+    pub animation_preview: crate::AnimationPreview,
+    /// Physics materials the edited scene can reference (presets plus the
+    /// scene's own); offered by the Collider "Material" dropdown
+    pub physics_materials: Vec<physics::PhysicsMaterial>,
+    /// Numbered viewport camera bookmarks (persisted in preferences)
+    pub camera_bookmarks: crate::CameraBookmarks,
+    /// World-coordinate rulers along the scene-view edges
+    pub rulers: crate::Rulers,
+    /// Measure tool state (last measured span)
+    pub measure: crate::MeasureTool,
+    /// Entities hidden in the scene view (hierarchy eye toggle)
+    pub visibility: crate::EditorVisibility,
+    /// Entities locked against viewport moves (hierarchy lock toggle)
+    pub locks: crate::EditorLock,
+    /// Custom dock panels added by the game or plugins (`add_panel`)
+    pub plugin_panels: crate::PluginPanels,
+    /// Invalid component data (Console panel; blocks entering play mode)
+    pub component_issues: crate::ComponentIssues,
+    /// Play-mode frame timings (Profiler panel)
+    pub profiler: crate::Profiler,
 }
 
 impl Default for EditorContext {
@@ -101,15 +159,62 @@ impl EditorContext {
                 .with_size(180.0)
                 .with_min_size(100.0),
         );
+        // Opened from View > World Stats
+        let mut world_stats = DockPanel::new(PanelId::WORLD_STATS, "World Stats", DockPosition::Right)
+            .with_size(240.0)
+            .with_min_size(180.0);
+        world_stats.visible = false;
+        dock_area.add_panel(world_stats);
+        // Opened from View > Layers
+        let mut layers = DockPanel::new(PanelId::LAYERS, "Layers", DockPosition::Right)
+            .with_size(220.0)
+            .with_min_size(160.0);
+        layers.visible = false;
+        dock_area.add_panel(layers);
+        // Opened from View > History
+        let mut history = DockPanel::new(PanelId::HISTORY, "History", DockPosition::Right)
+            .with_size(240.0)
+            .with_min_size(180.0);
+        history.visible = false;
+        dock_area.add_panel(history);
+        // Opened from View > Scene Settings
+        let mut scene_settings = DockPanel::new(PanelId::SCENE_SETTINGS, "Scene Settings", DockPosition::Right)
+            .with_size(280.0)
+            .with_min_size(200.0);
+        scene_settings.visible = false;
+        dock_area.add_panel(scene_settings);
+        // Opened from View > Profiler
+        let mut profiler = DockPanel::new(PanelId::PROFILER, "Profiler", DockPosition::Bottom)
+            .with_size(200.0)
+            .with_min_size(120.0);
+        profiler.visible = false;
+        dock_area.add_panel(profiler);
+        // Opened by File > Compare With Saved
+        let mut compare = DockPanel::new(PanelId::SCENE_COMPARE, "Compare", DockPosition::Bottom)
+            .with_size(180.0)
+            .with_min_size(100.0);
+        compare.visible = false;
+        dock_area.add_panel(compare);
+        // Opened from View > Console
+        let mut console = DockPanel::new(PanelId::CONSOLE, "Console", DockPosition::Bottom)
+            .with_size(160.0)
+            .with_min_size(100.0);
+        console.visible = false;
+        dock_area.add_panel(console);
+        dock_area.mark_default_layout();
 
         let theme = EditorTheme::default();
         let mut gizmo = Gizmo::new();
         gizmo.apply_theme(&theme);
+        let mut collider_gizmo = ColliderGizmo::new();
+        collider_gizmo.apply_theme(&theme);
 
         let mut editor = Self {
             selection: Selection::new(),
             gizmo,
-            toolbar: Toolbar::new().with_position(Vec2::new(220.0, 54.0)), // Inside scene view, below panel header
+            collider_gizmo,
+            // Inside scene view, below the panel header and the top ruler
+            toolbar: Toolbar::new().with_position(Vec2::new(220.0 + crate::RULER_SIZE, 54.0 + crate::RULER_SIZE)),
             menu_bar: MenuBar::editor_default(),
             dock_area,
             viewport: SceneViewport::new(),
@@ -120,15 +225,41 @@ impl EditorContext {
             hierarchy: HierarchyPanel::new(),
             snap_to_grid: false,
             show_colliders: true,
+            overlays: crate::ViewportOverlays::new(),
+            fullscreen_play: false,
+            fullscreen_bounds: None,
             play_state: EditorPlayState::default(),
             play_controls: PlayControls::new(),
+            frame_stepper: crate::FrameStepper::new(),
+            input_focus: crate::InputRouter::new(),
             add_component_popup_open: false,
+            dependency_prompt: None,
             is_dirty: false,
             scene_path: None,
             theme,
             status_bar: StatusBar::new(),
+            background_tasks: crate::BackgroundTasks::new(),
+            play_changes: crate::PlayChanges::new(),
             drag_drop: crate::DragDropState::new(),
+            component_clipboard: crate::ComponentClipboard::new(),
+            entity_clipboard: crate::EntityClipboard::new(),
             asset_browser: crate::AssetBrowserState::default(),
+            scene_compare: crate::SceneCompareState::default(),
+            history_scroll: 0.0,
+            region_picker: crate::SpriteRegionPicker::new(),
+            texture_picker: crate::TexturePicker::new(),
+            sprite_slicer: crate::SpriteSheetSlicer::new(),
+            sprite_atlases: crate::SpriteAtlases::new(),
+            physics_materials: physics::PhysicsMaterial::presets(),
+            camera_bookmarks: crate::CameraBookmarks::default(),
+            animation_preview: crate::AnimationPreview::new(),
+            rulers: crate::Rulers::new(),
+            measure: crate::MeasureTool::new(),
+            visibility: crate::EditorVisibility::new(),
+            locks: crate::EditorLock::new(),
+            plugin_panels: crate::PluginPanels::new(),
+            component_issues: crate::ComponentIssues::new(),
+            profiler: crate::Profiler::new(),
         };
         // The toolbar's default tool and the gizmo's default mode disagree
         // (Select vs Translate) — run the tool→gizmo mapping once so startup
@@ -154,183 +285,14 @@ impl EditorContext {
             EditorTool::Move => GizmoMode::Translate,
             EditorTool::Rotate => GizmoMode::Rotate,
             EditorTool::Scale => GizmoMode::Scale,
+            EditorTool::Measure => GizmoMode::None,
         };
         self.gizmo.set_mode(gizmo_mode);
-    }
-
-    // ================== Camera Methods ==================
-    // These delegate to the SceneViewport for camera control
-
-    /// Get the camera offset (position).
-    pub fn camera_offset(&self) -> Vec2 {
-        self.viewport.camera_position()
-    }
-
-    /// Set the camera offset (position).
-    pub fn set_camera_offset(&mut self, offset: Vec2) {
-        self.viewport.set_camera_position(offset);
-    }
-
-    /// Pan the camera by a delta.
-    pub fn pan_camera(&mut self, delta: Vec2) {
-        self.viewport.pan_immediate(delta);
-    }
-
-    /// Get the camera zoom level.
-    pub fn camera_zoom(&self) -> f32 {
-        self.viewport.camera_zoom()
-    }
-
-    /// Set the camera zoom level.
-    pub fn set_camera_zoom(&mut self, zoom: f32) {
-        self.viewport.set_camera_zoom(zoom);
-    }
-
-    /// Zoom the camera by a factor.
-    pub fn zoom_camera(&mut self, factor: f32) {
-        let new_zoom = self.viewport.camera_zoom() * factor;
-        self.viewport.set_camera_zoom(new_zoom);
-    }
-
-    /// Reset camera to default view.
-    pub fn reset_camera(&mut self) {
-        self.viewport.reset_camera_immediate();
-    }
-
-    /// Convert screen position to world position.
-    pub fn screen_to_world(&self, screen_pos: Vec2) -> Vec2 {
-        self.viewport.screen_to_world(screen_pos)
-    }
-
-    /// Convert world position to screen position.
-    pub fn world_to_screen(&self, world_pos: Vec2) -> Vec2 {
-        self.viewport.world_to_screen(world_pos)
-    }
-
-    // ================== Grid Methods ==================
-    // These delegate to the GridRenderer
-
-    /// Check if the grid is visible.
-    pub fn is_grid_visible(&self) -> bool {
-        self.grid.is_visible()
-    }
-
-    /// Set grid visibility.
-    pub fn set_grid_visible(&mut self, visible: bool) {
-        self.grid.set_visible(visible);
-    }
-
-    /// Toggle grid visibility.
-    pub fn toggle_grid(&mut self) {
-        self.grid.toggle_visible();
-    }
-
-    /// Get the grid size.
-    pub fn grid_size(&self) -> f32 {
-        self.grid.grid_size()
-    }
-
-    /// Set the grid size.
-    pub fn set_grid_size(&mut self, size: f32) {
-        self.grid.set_grid_size(size);
-    }
-
-    // ================== Collider Overlay Methods ==================
-
-    /// Check if collider outlines are visible in the scene view.
-    pub fn is_colliders_visible(&self) -> bool {
-        self.show_colliders
-    }
-
-    /// Set collider outline visibility.
-    pub fn set_colliders_visible(&mut self, visible: bool) {
-        self.show_colliders = visible;
-    }
-
-    /// Toggle collider outline visibility.
-    pub fn toggle_colliders(&mut self) {
-        self.show_colliders = !self.show_colliders;
-    }
-
-    /// Check if snap to grid is enabled.
-    pub fn is_snap_to_grid(&self) -> bool {
-        self.snap_to_grid
-    }
-
-    /// Set snap to grid.
-    pub fn set_snap_to_grid(&mut self, snap: bool) {
-        self.snap_to_grid = snap;
-    }
-
-    /// Toggle snap to grid.
-    pub fn toggle_snap_to_grid(&mut self) {
-        self.snap_to_grid = !self.snap_to_grid;
-    }
-
-    /// Snap a position to the grid.
-    pub fn snap_position(&self, pos: Vec2) -> Vec2 {
-        if self.snap_to_grid {
-            let grid_size = self.grid.grid_size();
-            Vec2::new(
-                (pos.x / grid_size).round() * grid_size,
-                (pos.y / grid_size).round() * grid_size,
-            )
-        } else {
-            pos
+        if tool != EditorTool::Measure {
+            self.measure.clear();
         }
     }
 
-    // ================== Play State Methods ==================
-
-    /// Get the current play state.
-    pub fn play_state(&self) -> EditorPlayState {
-        self.play_state
-    }
-
-    /// Set the play state.
-    pub fn set_play_state(&mut self, state: EditorPlayState) {
-        self.play_state = state;
-    }
-
-    /// Whether the editor is in normal editing mode.
-    pub fn is_editing(&self) -> bool {
-        self.play_state.is_editing()
-    }
-
-    /// Whether the game simulation is actively running.
-    pub fn is_playing(&self) -> bool {
-        self.play_state.is_playing()
-    }
-
-    /// Whether the game is paused.
-    pub fn is_paused(&self) -> bool {
-        self.play_state.is_paused()
-    }
-
-    /// Whether a play session is active (Playing or Paused).
-    pub fn in_play_session(&self) -> bool {
-        self.play_state.in_play_session()
-    }
-
-    /// Enter play mode (sets state to Playing).
-    pub fn enter_play_mode(&mut self) {
-        self.play_state = EditorPlayState::Playing;
-    }
-
-    /// Exit play mode (sets state to Editing).
-    pub fn exit_play_mode(&mut self) {
-        self.play_state = EditorPlayState::Editing;
-    }
-
-    /// Toggle between Editing and Playing.
-    pub fn toggle_play_mode(&mut self) {
-        self.play_state = if self.play_state.is_editing() {
-            EditorPlayState::Playing
-        } else {
-            EditorPlayState::Editing
-        };
-    }
-
     // ================== Add Component Popup ==================
 
     /// Whether the add-component popup is currently open.
@@ -347,152 +309,13 @@ impl EditorContext {
     pub fn close_add_component_popup(&mut self) {
         self.add_component_popup_open = false;
     }
-
-    // ================== Scene State Methods ==================
-
-    /// Whether the scene has unsaved changes.
-    pub fn is_dirty(&self) -> bool {
-        self.is_dirty
-    }
-
-    /// Set the dirty flag.
-    pub fn set_dirty(&mut self, dirty: bool) {
-        self.is_dirty = dirty;
-    }
-
-    /// Convenience: mark the scene as having unsaved changes.
-    pub fn mark_dirty(&mut self) {
-        self.is_dirty = true;
-    }
-
-    /// Get the current scene file path, if any.
-    pub fn scene_path(&self) -> Option<&std::path::Path> {
-        self.scene_path.as_deref()
-    }
-
-    /// Set the current scene file path.
-    pub fn set_scene_path(&mut self, path: Option<std::path::PathBuf>) {
-        self.scene_path = path;
-    }
-
-    /// Get a display name for the current scene.
-    ///
-    /// Returns the file name if a path is set, otherwise "Untitled".
-    pub fn scene_display_name(&self) -> String {
-        self.scene_path
-            .as_ref()
-            .and_then(|p| p.file_name())
-            .and_then(|n| n.to_str())
-            .map(|s| s.to_string())
-            .unwrap_or_else(|| "Untitled".to_string())
-    }
-
-    /// Get the title bar text including dirty indicator.
-    ///
-    /// Format: "filename.ron* - Insiculous Editor" (with * if dirty)
-    pub fn title_bar_text(&self) -> String {
-        let name = self.scene_display_name();
-        let dirty_indicator = if self.is_dirty { "*" } else { "" };
-        format!("{}{} - Insiculous Editor", name, dirty_indicator)
-    }
-
-    // ================== Layout Methods ==================
-
-    /// Update layout based on window size.
-    ///
-    /// This should be called each frame before rendering to ensure
-    /// panels are properly sized and viewport bounds are updated.
-    pub fn update_layout(&mut self, window_size: Vec2) {
-        // Reserve space for menu bar (top) and status bar (bottom)
-        let menu_height = self.menu_bar.height();
-        let status_bar_height = crate::status_bar::STATUS_BAR_HEIGHT;
-        let content_bounds = common::Rect::new(
-            0.0,
-            menu_height,
-            window_size.x,
-            window_size.y - menu_height - status_bar_height,
-        );
-
-        self.dock_area.set_bounds(content_bounds);
-        self.dock_area.layout();
-
-        // Update viewport bounds from scene view panel
-        if let Some(scene_bounds) = self.scene_view_bounds() {
-            self.viewport.set_viewport_bounds(scene_bounds);
-        }
-
-        // Note: The gizmo position should be set by the caller based on entity transform
-        // after calling update_layout, using self.selection.primary() to get the selected entity
-    }
-
-    /// Update viewport interpolation. Call each frame.
-    pub fn update_viewport(&mut self, delta_time: f32) {
-        self.viewport.update(delta_time);
-    }
-
-    /// Get the scene view content bounds (where the game world is rendered).
-    pub fn scene_view_bounds(&self) -> Option<common::Rect> {
-        self.dock_area
-            .get_panel(PanelId::SCENE_VIEW)
-            .map(|p| p.content_bounds())
-    }
-
-    /// Get the inspector panel bounds.
-    pub fn inspector_bounds(&self) -> Option<common::Rect> {
-        self.dock_area
-            .get_panel(PanelId::INSPECTOR)
-            .map(|p| p.content_bounds())
-    }
-
-    /// Get the hierarchy panel bounds.
-    pub fn hierarchy_bounds(&self) -> Option<common::Rect> {
-        self.dock_area
-            .get_panel(PanelId::HIERARCHY)
-            .map(|p| p.content_bounds())
-    }
-
-    // ================== Gizmo Integration ==================
-
-    /// Get the screen position for the gizmo based on its world position.
-    ///
-    /// Use this to pass to gizmo.render().
-    pub fn gizmo_screen_position(&self) -> Vec2 {
-        self.viewport.world_to_screen(self.gizmo.position())
-    }
-
-    /// Convert a gizmo delta from screen space to world space.
-    ///
-    /// The gizmo returns deltas in screen pixels. This converts them
-    /// to world units accounting for camera zoom and Y-axis inversion
-    /// (screen Y increases downward, world Y increases upward).
-    pub fn gizmo_delta_to_world(&self, screen_delta: Vec2) -> Vec2 {
-        Vec2::new(
-            screen_delta.x / self.viewport.camera_zoom(),
-            -screen_delta.y / self.viewport.camera_zoom(), // Negate Y for world coords
-        )
-    }
-
-    /// Check if the gizmo should take priority over picking.
-    ///
-    /// Returns true if the gizmo is currently being interacted with,
-    /// meaning picking should be skipped.
-    pub fn gizmo_has_priority(&self) -> bool {
-        self.gizmo.is_active()
-    }
-
-    /// Focus the viewport camera on the current selection.
-    pub fn focus_on_selection(&mut self, entity_positions: &[(ecs::EntityId, Vec2)]) {
-        let selected_positions: Vec<Vec2> = entity_positions
-            .iter()
-            .filter(|(id, _)| self.selection.contains(*id))
-            .map(|(_, pos)| *pos)
-            .collect();
-
-        if !selected_positions.is_empty() {
-            self.viewport.focus_on_bounds(&selected_positions);
-        }
-    }
 }
 
+mod gizmo;
+mod layout;
+mod play;
+mod scene;
+mod view;
+
 #[cfg(test)]
 mod tests;
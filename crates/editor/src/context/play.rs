@@ -0,0 +1,64 @@
+//! Play state on [`EditorContext`]: Editing / Playing / Paused and the
+//! input focus that follows it.
+
+use crate::play_state::EditorPlayState;
+
+use super::EditorContext;
+
+impl EditorContext {
+    /// Get the current play state.
+    pub fn play_state(&self) -> EditorPlayState {
+        self.play_state
+    }
+
+    /// Set the play state. Playing gives the game input focus; Paused and
+    /// Editing give it back to the editor.
+    pub fn set_play_state(&mut self, state: EditorPlayState) {
+        self.play_state = state;
+        let focus = if state.is_playing() { crate::InputFocus::Game } else { crate::InputFocus::Editor };
+        self.input_focus.set_focus(focus);
+        if state.is_editing() {
+            self.input_focus.clear_keys();
+        }
+    }
+
+    /// Whether the editor is in normal editing mode.
+    pub fn is_editing(&self) -> bool {
+        self.play_state.is_editing()
+    }
+
+    /// Whether the game simulation is actively running.
+    pub fn is_playing(&self) -> bool {
+        self.play_state.is_playing()
+    }
+
+    /// Whether the game is paused.
+    pub fn is_paused(&self) -> bool {
+        self.play_state.is_paused()
+    }
+
+    /// Whether a play session is active (Playing or Paused).
+    pub fn in_play_session(&self) -> bool {
+        self.play_state.in_play_session()
+    }
+
+    /// Enter play mode (sets state to Playing).
+    pub fn enter_play_mode(&mut self) {
+        self.set_play_state(EditorPlayState::Playing);
+    }
+
+    /// Exit play mode (sets state to Editing).
+    pub fn exit_play_mode(&mut self) {
+        self.set_play_state(EditorPlayState::Editing);
+    }
+
+    /// Toggle between Editing and Playing.
+    pub fn toggle_play_mode(&mut self) {
+        let state = if self.play_state.is_editing() {
+            EditorPlayState::Playing
+        } else {
+            EditorPlayState::Editing
+        };
+        self.set_play_state(state);
+    }
+}
@@ -0,0 +1,52 @@
+//! Scene file state on [`EditorContext`]: the dirty flag, the scene path
+//! and the title bar text derived from them.
+
+use super::EditorContext;
+
+impl EditorContext {
+    /// Whether the scene has unsaved changes.
+    pub fn is_dirty(&self) -> bool {
+        self.is_dirty
+    }
+
+    /// Set the dirty flag.
+    pub fn set_dirty(&mut self, dirty: bool) {
+        self.is_dirty = dirty;
+    }
+
+    /// Convenience: mark the scene as having unsaved changes.
+    pub fn mark_dirty(&mut self) {
+        self.is_dirty = true;
+    }
+
+    /// Get the current scene file path, if any.
+    pub fn scene_path(&self) -> Option<&std::path::Path> {
+        self.scene_path.as_deref()
+    }
+
+    /// Set the current scene file path.
+    pub fn set_scene_path(&mut self, path: Option<std::path::PathBuf>) {
+        self.scene_path = path;
+    }
+
+    /// Get a display name for the current scene.
+    ///
+    /// Returns the file name if a path is set, otherwise "Untitled".
+    pub fn scene_display_name(&self) -> String {
+        self.scene_path
+            .as_ref()
+            .and_then(|p| p.file_name())
+            .and_then(|n| n.to_str())
+            .map(|s| s.to_string())
+            .unwrap_or_else(|| "Untitled".to_string())
+    }
+
+    /// Get the title bar text including dirty indicator.
+    ///
+    /// Format: "filename.ron* - Insiculous Editor" (with * if dirty)
+    pub fn title_bar_text(&self) -> String {
+        let name = self.scene_display_name();
+        let dirty_indicator = if self.is_dirty { "*" } else { "" };
+        format!("{}{} - Insiculous Editor", name, dirty_indicator)
+    }
+}
@@ -31,6 +31,12 @@ fn test_editor_context_set_tool() {
     ctx.set_tool(EditorTool::Select);
     assert_eq!(ctx.current_tool(), EditorTool::Select);
     assert_eq!(ctx.gizmo.mode(), GizmoMode::None);
+
+    ctx.set_tool(EditorTool::Measure);
+    assert_eq!(ctx.gizmo.mode(), GizmoMode::None);
+    ctx.measure.drag(Vec2::ZERO, Vec2::ONE, None);
+    ctx.set_tool(EditorTool::Move);
+    assert_eq!(ctx.measure.measurement(), None, "leaving the tool drops the measurement");
 }
 
 #[test]
@@ -158,18 +164,44 @@ fn test_editor_context_play_state() {
     assert!(ctx.in_play_session());
 }
 
+#[test]
+fn test_play_state_moves_input_focus() {
+    let mut ctx = EditorContext::new();
+    assert!(!ctx.input_focus.game_has_focus());
+
+    ctx.set_play_state(EditorPlayState::Playing);
+    assert!(ctx.input_focus.game_has_focus(), "Play focuses the game");
+    assert!(ctx.input_focus.press(winit::keyboard::KeyCode::KeyA));
+
+    ctx.set_play_state(EditorPlayState::Paused);
+    assert!(!ctx.input_focus.game_has_focus());
+    ctx.set_play_state(EditorPlayState::Playing);
+    ctx.set_play_state(EditorPlayState::Editing);
+    assert!(!ctx.input_focus.release(winit::keyboard::KeyCode::KeyA), "Stop forgets held keys");
+}
+
 #[test]
 fn test_editor_context_default_panels() {
     let ctx = EditorContext::new();
 
-    // Should have 4 default panels
-    assert_eq!(ctx.dock_area.panels().len(), 4);
+    // Should have 11 default panels
+    assert_eq!(ctx.dock_area.panels().len(), 11);
 
     // Check panel positions
     assert!(ctx.dock_area.get_panel(PanelId::HIERARCHY).is_some());
     assert!(ctx.dock_area.get_panel(PanelId::INSPECTOR).is_some());
     assert!(ctx.dock_area.get_panel(PanelId::SCENE_VIEW).is_some());
     assert!(ctx.dock_area.get_panel(PanelId::ASSET_BROWSER).is_some());
+    // World Stats starts hidden until opened from the View menu
+    assert!(!ctx.dock_area.is_panel_shown(PanelId::WORLD_STATS));
+    assert!(!ctx.dock_area.is_panel_shown(PanelId::CONSOLE));
+    assert!(!ctx.dock_area.is_panel_shown(PanelId::LAYERS));
+    assert!(!ctx.dock_area.is_panel_shown(PanelId::SCENE_COMPARE));
+    assert!(!ctx.dock_area.is_panel_shown(PanelId::HISTORY));
+    assert!(!ctx.dock_area.is_panel_shown(PanelId::SCENE_SETTINGS));
+    assert!(!ctx.dock_area.is_panel_shown(PanelId::PROFILER));
+    // Layout presets build on this placement
+    assert_eq!(ctx.dock_area.default_layout().panels.len(), 11);
 }
 
 #[test]
@@ -186,6 +218,46 @@ fn test_editor_context_update_layout() {
     assert!(bounds.height > 0.0);
 }
 
+#[test]
+fn test_fullscreen_play_hides_chrome_only_during_play_session() {
+    let mut ctx = EditorContext::new();
+    ctx.set_fullscreen_play(true);
+    assert!(!ctx.is_chrome_hidden());
+
+    ctx.enter_play_mode();
+    assert!(ctx.is_chrome_hidden());
+    ctx.update_layout(Vec2::new(1280.0, 720.0));
+    let bounds = ctx.scene_view_bounds().unwrap();
+    assert_eq!((bounds.x, bounds.y, bounds.width, bounds.height), (0.0, 0.0, 1280.0, 720.0));
+
+    ctx.exit_play_mode();
+    ctx.update_layout(Vec2::new(1280.0, 720.0));
+    assert!(ctx.scene_view_bounds().unwrap().y > 0.0);
+}
+
+#[test]
+fn test_toggle_maximize_at_hovered_panel() {
+    let mut ctx = EditorContext::new();
+    ctx.update_layout(Vec2::new(1280.0, 720.0));
+    let scene = ctx.scene_view_bounds().unwrap();
+    let center = Vec2::new(scene.x + scene.width / 2.0, scene.y + scene.height / 2.0);
+
+    assert_eq!(ctx.toggle_maximize_at(center), Some(PanelId::SCENE_VIEW));
+    ctx.update_layout(Vec2::new(1280.0, 720.0));
+    assert_eq!(ctx.scene_view_bounds().unwrap().width, 1280.0);
+
+    ctx.toggle_maximize_at(center);
+    assert_eq!(ctx.dock_area.maximized_panel(), None);
+}
+
+#[test]
+fn test_maximized_inspector_hides_scene_view_bounds() {
+    let mut ctx = EditorContext::new();
+    ctx.dock_area.maximize_panel(PanelId::INSPECTOR);
+    ctx.update_layout(Vec2::new(1280.0, 720.0));
+    assert!(ctx.scene_view_bounds().is_none());
+}
+
 #[test]
 fn test_gizmo_delta_to_world() {
     let mut ctx = EditorContext::new();
@@ -320,3 +392,25 @@ fn test_collider_overlay_visible_by_default_and_toggles() {
     ctx.set_colliders_visible(false);
     assert!(!ctx.is_colliders_visible());
 }
+
+#[test]
+fn test_frame_selection_fits_selected_bounds() {
+    let mut ctx = EditorContext::new();
+    ctx.viewport.set_viewport_bounds(common::Rect::new(0.0, 0.0, 800.0, 600.0));
+    ctx.viewport.set_interpolation_speed(1.0);
+    let mut world = ecs::World::new();
+    let (a, b, other) = (world.create_entity(), world.create_entity(), world.create_entity());
+    let entities = [
+        crate::PickableEntity::new(a, Vec2::new(0.0, 0.0), Vec2::new(20.0, 20.0), 0.0),
+        crate::PickableEntity::new(b, Vec2::new(100.0, 0.0), Vec2::new(20.0, 20.0), 0.0),
+        crate::PickableEntity::new(other, Vec2::new(5000.0, 0.0), Vec2::new(20.0, 20.0), 0.0),
+    ];
+
+    assert!(!ctx.frame_selection(&entities), "nothing selected");
+    ctx.selection.select(a);
+    ctx.selection.add(b);
+    assert!(ctx.frame_selection(&entities));
+    ctx.update_viewport(0.016);
+    assert_eq!(ctx.camera_offset(), Vec2::new(50.0, 0.0));
+    assert!((ctx.camera_zoom() - 640.0 / 120.0).abs() < 1e-4);
+}
@@ -0,0 +1,128 @@
+//! Scene-view helpers on [`EditorContext`]: the camera (delegating to
+//! [`SceneViewport`](crate::viewport::SceneViewport)), the grid, the
+//! collider overlay and snapping.
+
+use glam::Vec2;
+
+use super::EditorContext;
+
+impl EditorContext {
+    /// Get the camera offset (position).
+    pub fn camera_offset(&self) -> Vec2 {
+        self.viewport.camera_position()
+    }
+
+    /// Set the camera offset (position).
+    pub fn set_camera_offset(&mut self, offset: Vec2) {
+        self.viewport.set_camera_position(offset);
+    }
+
+    /// Pan the camera by a delta.
+    pub fn pan_camera(&mut self, delta: Vec2) {
+        self.viewport.pan_immediate(delta);
+    }
+
+    /// Get the camera zoom level.
+    pub fn camera_zoom(&self) -> f32 {
+        self.viewport.camera_zoom()
+    }
+
+    /// Set the camera zoom level.
+    pub fn set_camera_zoom(&mut self, zoom: f32) {
+        self.viewport.set_camera_zoom(zoom);
+    }
+
+    /// Zoom the camera by a factor.
+    pub fn zoom_camera(&mut self, factor: f32) {
+        let new_zoom = self.viewport.camera_zoom() * factor;
+        self.viewport.set_camera_zoom(new_zoom);
+    }
+
+    /// Reset camera to default view.
+    pub fn reset_camera(&mut self) {
+        self.viewport.reset_camera_immediate();
+    }
+
+    /// Straighten a rotated view back to the world axes.
+    pub fn reset_view_rotation(&mut self) {
+        self.viewport.set_camera_rotation(0.0);
+    }
+
+    /// Convert screen position to world position.
+    pub fn screen_to_world(&self, screen_pos: Vec2) -> Vec2 {
+        self.viewport.screen_to_world(screen_pos)
+    }
+
+    /// Convert world position to screen position.
+    pub fn world_to_screen(&self, world_pos: Vec2) -> Vec2 {
+        self.viewport.world_to_screen(world_pos)
+    }
+
+    /// Check if the grid is visible.
+    pub fn is_grid_visible(&self) -> bool {
+        self.grid.is_visible()
+    }
+
+    /// Set grid visibility.
+    pub fn set_grid_visible(&mut self, visible: bool) {
+        self.grid.set_visible(visible);
+    }
+
+    /// Toggle grid visibility.
+    pub fn toggle_grid(&mut self) {
+        self.grid.toggle_visible();
+    }
+
+    /// Get the grid size.
+    pub fn grid_size(&self) -> f32 {
+        self.grid.grid_size()
+    }
+
+    /// Set the grid size.
+    pub fn set_grid_size(&mut self, size: f32) {
+        self.grid.set_grid_size(size);
+    }
+
+    /// Check if collider outlines are visible in the scene view.
+    pub fn is_colliders_visible(&self) -> bool {
+        self.show_colliders
+    }
+
+    /// Set collider outline visibility.
+    pub fn set_colliders_visible(&mut self, visible: bool) {
+        self.show_colliders = visible;
+    }
+
+    /// Toggle collider outline visibility.
+    pub fn toggle_colliders(&mut self) {
+        self.show_colliders = !self.show_colliders;
+    }
+
+    /// Check if snap to grid is enabled.
+    pub fn is_snap_to_grid(&self) -> bool {
+        self.snap_to_grid
+    }
+
+    /// Set snap to grid.
+    pub fn set_snap_to_grid(&mut self, snap: bool) {
+        self.snap_to_grid = snap;
+    }
+
+    /// Toggle snap to grid.
+    pub fn toggle_snap_to_grid(&mut self) {
+        self.snap_to_grid = !self.snap_to_grid;
+    }
+
+    /// Snap a position to the grid.
+    pub fn snap_position(&self, pos: Vec2) -> Vec2 {
+        if self.snap_to_grid {
+            let grid_size = self.grid.grid_size();
+            Vec2::new(
+                (pos.x / grid_size).round() * grid_size,
+                (pos.y / grid_size).round() * grid_size,
+            )
+        } else {
+            pos
+        }
+    }
+}
@@ -0,0 +1,191 @@
+//! Saved dock layouts: where each panel is docked, how big it is and
+//! whether it is shown.
+//!
+//! The editor writes the layout on exit and restores it on startup, and the
+//! View menu switches between the built-in [`LayoutPreset`]s. Presets are
+//! variations of the layout the editor starts with, so panels added later
+//! (plugin panels) keep whatever state they have.
+
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+use super::{DockArea, DockPosition, PanelId};
+
+/// One panel's entry in a [`DockLayout`].
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PanelLayout {
+    /// Panel this entry applies to
+    pub id: PanelId,
+    /// Where the panel is docked
+    pub position: DockPosition,
+    /// Panel size (width for Left/Right, height for Top/Bottom)
+    pub size: f32,
+    /// Whether the panel is shown
+    pub visible: bool,
+}
+
+/// Dock placement of a set of panels, saved between sessions.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DockLayout {
+    /// Per-panel placement; panels not listed are left as they are
+    pub panels: Vec<PanelLayout>,
+}
+
+impl DockLayout {
+    /// Capture the placement of every panel in a dock area.
+    pub fn capture(dock: &DockArea) -> Self {
+        let panels = dock
+            .panels()
+            .iter()
+            .map(|panel| PanelLayout {
+                id: panel.id,
+                position: panel.position,
+                size: panel.size,
+                visible: panel.visible,
+            })
+            .collect();
+        Self { panels }
+    }
+
+    /// The entry for a panel, if the layout has one.
+    pub fn panel(&self, id: PanelId) -> Option<&PanelLayout> {
+        self.panels.iter().find(|p| p.id == id)
+    }
+
+    /// Set a panel's placement, adding an entry if the layout has none.
+    pub fn set_panel(&mut self, id: PanelId, position: DockPosition, size: f32, visible: bool) {
+        let entry = PanelLayout { id, position, size, visible };
+        match self.panels.iter_mut().find(|p| p.id == id) {
+            Some(existing) => *existing = entry,
+            None => self.panels.push(entry),
+        }
+    }
+
+    /// Place the dock area's panels as this layout lists them, leave
+    /// maximize mode and re-layout.
+    ///
+    /// Entries for panels the dock area doesn't have are ignored, and sizes
+    /// are kept at or above each panel's minimum.
+    pub fn apply(&self, dock: &mut DockArea) {
+        for entry in &self.panels {
+            if let Some(panel) = dock.get_panel_mut(entry.id) {
+                panel.position = entry.position;
+                panel.size = entry.size.max(panel.min_size);
+                panel.visible = entry.visible;
+            }
+        }
+        dock.restore_layout();
+    }
+
+    /// Load a layout from a JSON file.
+    ///
+    /// Returns an empty layout (which changes nothing when applied) if the
+    /// file doesn't exist or can't be parsed.
+    pub fn load(path: &Path) -> Self {
+        match std::fs::read_to_string(path) {
+            Ok(content) => serde_json::from_str(&content).unwrap_or_default(),
+            Err(_) => Self::default(),
+        }
+    }
+
+    /// Save the layout to a JSON file.
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let json = serde_json::to_string_pretty(self)
+            .map_err(|e| format!("Failed to serialize layout: {}", e))?;
+        std::fs::write(path, json).map_err(|e| format!("Failed to write layout file: {}", e))
+    }
+}
+
+/// Built-in layouts offered in the View menu.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LayoutPreset {
+    /// The layout the editor starts with (View > Reset Layout)
+    Default,
+    /// Wide Inspector and a tall Assets strip for sprite sheets and
+    /// animation preview; auxiliary panels hidden
+    Animation,
+    /// Wider Hierarchy with Layers open beside the Inspector
+    LevelDesign,
+}
+
+impl LayoutPreset {
+    /// Every preset, in menu order.
+    pub const ALL: [LayoutPreset; 3] = [LayoutPreset::Default, LayoutPreset::Animation, LayoutPreset::LevelDesign];
+
+    /// Human-readable name (status bar).
+    pub fn label(self) -> &'static str {
+        match self {
+            LayoutPreset::Default => "Default",
+            LayoutPreset::Animation => "Animation",
+            LayoutPreset::LevelDesign => "Level Design",
+        }
+    }
+
+    /// The View menu action that switches to this preset.
+    pub fn menu_label(self) -> &'static str {
+        match self {
+            LayoutPreset::Default => "Reset Layout",
+            LayoutPreset::Animation => "Animation Layout",
+            LayoutPreset::LevelDesign => "Level Design Layout",
+        }
+    }
+
+    /// The preset for a View menu action, if it is one.
+    pub fn from_menu_label(label: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|preset| preset.menu_label() == label)
+    }
+
+    /// This preset's layout, as a variation of the editor's `default` one.
+    pub fn layout(self, default: &DockLayout) -> DockLayout {
+        let mut layout = default.clone();
+        if self == LayoutPreset::Default {
+            return layout;
+        }
+        // The focused presets start from just the core panels
+        let auxiliary = [
+            PanelId::WORLD_STATS,
+            PanelId::LAYERS,
+            PanelId::HISTORY,
+            PanelId::SCENE_SETTINGS,
+            PanelId::PROFILER,
+            PanelId::SCENE_COMPARE,
+            PanelId::CONSOLE,
+        ];
+        for entry in layout.panels.iter_mut().filter(|p| auxiliary.contains(&p.id)) {
+            entry.visible = false;
+        }
+        match self {
+            LayoutPreset::Default => {}
+            LayoutPreset::Animation => {
+                layout.set_panel(PanelId::HIERARCHY, DockPosition::Left, 180.0, true);
+                layout.set_panel(PanelId::INSPECTOR, DockPosition::Right, 340.0, true);
+                layout.set_panel(PanelId::ASSET_BROWSER, DockPosition::Bottom, 260.0, true);
+            }
+            LayoutPreset::LevelDesign => {
+                layout.set_panel(PanelId::HIERARCHY, DockPosition::Left, 260.0, true);
+                layout.set_panel(PanelId::INSPECTOR, DockPosition::Right, 260.0, true);
+                layout.set_panel(PanelId::LAYERS, DockPosition::Right, 200.0, true);
+                layout.set_panel(PanelId::ASSET_BROWSER, DockPosition::Bottom, 160.0, true);
+            }
+        }
+        layout
+    }
+}
+
+impl DockArea {
+    /// Remember the current placement as the layout presets build on.
+    /// Called once the built-in panels are docked.
+    pub fn mark_default_layout(&mut self) {
+        self.default_layout = DockLayout::capture(self);
+    }
+
+    /// The layout recorded by [`mark_default_layout`](Self::mark_default_layout).
+    pub fn default_layout(&self) -> &DockLayout {
+        &self.default_layout
+    }
+
+    /// Switch to a built-in layout preset.
+    pub fn apply_preset(&mut self, preset: LayoutPreset) {
+        preset.layout(&self.default_layout).apply(self);
+    }
+}
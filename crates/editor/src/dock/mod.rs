@@ -3,13 +3,18 @@
 //! Provides a flexible layout system with dockable panels that can be
 //! positioned at different edges of the window or floated.
 
+use serde::{Deserialize, Serialize};
 use ui::{Rect, UIContext, WidgetId};
 
 use crate::layout::{DEFAULT_PANEL_WIDTH, HEADER_HEIGHT, MIN_PANEL_SIZE, RESIZE_HANDLE_SIZE};
 use crate::theme::EditorTheme;
 
+mod layout;
+
+pub use layout::{DockLayout, LayoutPreset, PanelLayout};
+
 /// Unique identifier for a dock panel.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct PanelId(pub u32);
 
 impl PanelId {
@@ -23,6 +28,18 @@ impl PanelId {
     pub const ASSET_BROWSER: PanelId = PanelId(3);
     /// Console/output panel
     pub const CONSOLE: PanelId = PanelId(4);
+    /// World stats panel (scene graph metrics and warnings)
+    pub const WORLD_STATS: PanelId = PanelId(5);
+    /// Render layers panel (layer order and visibility)
+    pub const LAYERS: PanelId = PanelId(6);
+    /// Scene comparison panel (structural diff against the saved file)
+    pub const SCENE_COMPARE: PanelId = PanelId(7);
+    /// Undo history panel (recent operations and restore points)
+    pub const HISTORY: PanelId = PanelId(8);
+    /// Scene settings panel (per-scene physics settings)
+    pub const SCENE_SETTINGS: PanelId = PanelId(9);
+    /// Profiler panel (play-mode pass timings and draw counts)
+    pub const PROFILER: PanelId = PanelId(10);
 }
 
 impl From<PanelId> for WidgetId {
@@ -32,7 +49,7 @@ impl From<PanelId> for WidgetId {
 }
 
 /// Position where a panel can be docked.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum DockPosition {
     /// Panel is docked to the left edge
     Left,
@@ -103,6 +120,11 @@ impl DockPanel {
         self
     }
 
+    /// Get the header strip bounds (title row above the content).
+    pub fn header_bounds(&self) -> Rect {
+        Rect::new(self.bounds.x, self.bounds.y, self.bounds.width, HEADER_HEIGHT)
+    }
+
     /// Get the content bounds (excluding header).
     pub fn content_bounds(&self) -> Rect {
         Rect::new(
@@ -125,6 +147,10 @@ pub struct DockArea {
     header_height: f32,
     /// Resize handle size
     resize_handle_size: f32,
+    /// Panel temporarily filling the whole dock area (`None` = normal layout)
+    maximized: Option<PanelId>,
+    /// Placement the layout presets build on (`mark_default_layout`)
+    default_layout: DockLayout,
 }
 
 impl Default for DockArea {
@@ -141,6 +167,8 @@ impl DockArea {
             bounds: Rect::default(),
             header_height: HEADER_HEIGHT,
             resize_handle_size: RESIZE_HANDLE_SIZE,
+            maximized: None,
+            default_layout: DockLayout::default(),
         }
     }
 
@@ -169,8 +197,90 @@ impl DockArea {
         self.bounds = bounds;
     }
 
+    /// The panel currently maximized to the whole dock area, if any.
+    ///
+    /// A maximized panel that has since been hidden or removed reports
+    /// `None` — the normal layout is back in effect.
+    pub fn maximized_panel(&self) -> Option<PanelId> {
+        self.maximized
+            .filter(|id| self.get_panel(*id).is_some_and(|p| p.visible))
+    }
+
+    /// Whether the given panel should be drawn this frame (visible, and not
+    /// covered by another panel's maximize).
+    pub fn is_panel_shown(&self, id: PanelId) -> bool {
+        match self.maximized_panel() {
+            Some(maximized) => maximized == id,
+            None => self.get_panel(id).is_some_and(|p| p.visible),
+        }
+    }
+
+    /// Maximize a panel to fill the whole dock area. Other panels keep their
+    /// docked sizes and come back on [`restore_layout`](Self::restore_layout).
+    pub fn maximize_panel(&mut self, id: PanelId) {
+        if self.get_panel(id).is_some() {
+            self.maximized = Some(id);
+            self.layout();
+        }
+    }
+
+    /// Leave maximize mode and return to the docked layout.
+    pub fn restore_layout(&mut self) {
+        self.maximized = None;
+        self.layout();
+    }
+
+    /// Maximize `id`, or restore the docked layout if it is already the
+    /// maximized panel. Returns whether a panel is maximized afterwards.
+    pub fn toggle_maximized(&mut self, id: PanelId) -> bool {
+        if self.maximized_panel() == Some(id) {
+            self.restore_layout();
+        } else {
+            self.maximize_panel(id);
+        }
+        self.maximized_panel().is_some()
+    }
+
+    /// Show or hide a panel and re-layout. Returns whether the panel is
+    /// visible afterwards (`false` for unknown panels).
+    pub fn toggle_panel_visible(&mut self, id: PanelId) -> bool {
+        let Some(panel) = self.get_panel_mut(id) else {
+            return false;
+        };
+        panel.visible = !panel.visible;
+        let visible = panel.visible;
+        self.layout();
+        visible
+    }
+
+    /// Show or hide a panel and re-layout (no-op for unknown panels).
+    pub fn set_panel_visible(&mut self, id: PanelId, visible: bool) {
+        if let Some(panel) = self.get_panel_mut(id) {
+            panel.visible = visible;
+            self.layout();
+        }
+    }
+
+    /// The topmost shown panel whose bounds contain `pos` (the hover target
+    /// for panel shortcuts like maximize).
+    pub fn panel_at(&self, pos: glam::Vec2) -> Option<PanelId> {
+        self.panels
+            .iter()
+            .filter(|p| self.is_panel_shown(p.id))
+            .find(|p| p.bounds.contains(pos))
+            .map(|p| p.id)
+    }
+
     /// Update panel layouts based on current dock positions.
     pub fn layout(&mut self) {
+        if let Some(id) = self.maximized_panel() {
+            let bounds = self.bounds;
+            if let Some(panel) = self.get_panel_mut(id) {
+                panel.bounds = bounds;
+            }
+            return;
+        }
+
         let mut remaining = self.bounds;
 
         // First pass: allocate space for edge-docked panels
@@ -239,7 +349,7 @@ impl DockArea {
         let mut content_areas = Vec::new();
 
         for panel in &self.panels {
-            if !panel.visible {
+            if !self.is_panel_shown(panel.id) {
                 continue;
             }
 
@@ -286,6 +396,10 @@ impl DockArea {
 
     /// Handle resize dragging for panels.
     pub fn handle_resize(&mut self, ui: &mut UIContext) {
+        // A maximized panel owns the whole area — there is no edge to drag.
+        if self.maximized_panel().is_some() {
+            return;
+        }
         for i in 0..self.panels.len() {
             if !self.panels[i].visible || !self.panels[i].resizable {
                 continue;
@@ -388,165 +502,4 @@ fn draw_panel_chrome(ui: &mut UIContext, header_bounds: &Rect, theme: &EditorThe
 }
 
 #[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_panel_id_constants() {
-        assert_eq!(PanelId::SCENE_VIEW.0, 0);
-        assert_eq!(PanelId::INSPECTOR.0, 1);
-        assert_eq!(PanelId::HIERARCHY.0, 2);
-        assert_eq!(PanelId::ASSET_BROWSER.0, 3);
-        assert_eq!(PanelId::CONSOLE.0, 4);
-    }
-
-    #[test]
-    fn test_dock_position_default() {
-        assert_eq!(DockPosition::default(), DockPosition::Center);
-    }
-
-    #[test]
-    fn test_dock_panel_new() {
-        let panel = DockPanel::new(PanelId::INSPECTOR, "Inspector", DockPosition::Right);
-        assert_eq!(panel.id, PanelId::INSPECTOR);
-        assert_eq!(panel.title, "Inspector");
-        assert_eq!(panel.position, DockPosition::Right);
-        assert!(panel.visible);
-        assert!(panel.resizable);
-    }
-
-    #[test]
-    fn test_dock_panel_builder() {
-        let panel = DockPanel::new(PanelId::HIERARCHY, "Hierarchy", DockPosition::Left)
-            .with_size(300.0)
-            .with_min_size(150.0)
-            .with_resizable(false);
-
-        assert_eq!(panel.size, 300.0);
-        assert_eq!(panel.min_size, 150.0);
-        assert!(!panel.resizable);
-    }
-
-    #[test]
-    fn test_dock_panel_content_bounds() {
-        let mut panel = DockPanel::new(PanelId::INSPECTOR, "Test", DockPosition::Right);
-        panel.bounds = Rect::new(100.0, 50.0, 200.0, 400.0);
-
-        let content = panel.content_bounds();
-        assert_eq!(content.x, 100.0);
-        assert_eq!(content.y, 74.0); // 50 + 24 header
-        assert_eq!(content.width, 200.0);
-        assert_eq!(content.height, 376.0); // 400 - 24 header
-    }
-
-    #[test]
-    fn test_dock_area_new() {
-        let area = DockArea::new();
-        assert!(area.panels().is_empty());
-    }
-
-    #[test]
-    fn test_dock_area_add_panel() {
-        let mut area = DockArea::new();
-        area.add_panel(DockPanel::new(PanelId::INSPECTOR, "Inspector", DockPosition::Right));
-        area.add_panel(DockPanel::new(PanelId::HIERARCHY, "Hierarchy", DockPosition::Left));
-
-        assert_eq!(area.panels().len(), 2);
-    }
-
-    #[test]
-    fn test_dock_area_get_panel() {
-        let mut area = DockArea::new();
-        area.add_panel(DockPanel::new(PanelId::INSPECTOR, "Inspector", DockPosition::Right));
-
-        let panel = area.get_panel(PanelId::INSPECTOR);
-        assert!(panel.is_some());
-        assert_eq!(panel.unwrap().title, "Inspector");
-
-        let missing = area.get_panel(PanelId::HIERARCHY);
-        assert!(missing.is_none());
-    }
-
-    #[test]
-    fn test_dock_area_layout_left() {
-        let mut area = DockArea::new();
-        area.set_bounds(Rect::new(0.0, 0.0, 1000.0, 800.0));
-        area.add_panel(
-            DockPanel::new(PanelId::HIERARCHY, "Hierarchy", DockPosition::Left)
-                .with_size(200.0),
-        );
-        area.layout();
-
-        let panel = area.get_panel(PanelId::HIERARCHY).unwrap();
-        assert_eq!(panel.bounds.x, 0.0);
-        assert_eq!(panel.bounds.y, 0.0);
-        assert_eq!(panel.bounds.width, 200.0);
-        assert_eq!(panel.bounds.height, 800.0);
-    }
-
-    #[test]
-    fn test_dock_area_layout_right() {
-        let mut area = DockArea::new();
-        area.set_bounds(Rect::new(0.0, 0.0, 1000.0, 800.0));
-        area.add_panel(
-            DockPanel::new(PanelId::INSPECTOR, "Inspector", DockPosition::Right)
-                .with_size(250.0),
-        );
-        area.layout();
-
-        let panel = area.get_panel(PanelId::INSPECTOR).unwrap();
-        assert_eq!(panel.bounds.x, 750.0); // 1000 - 250
-        assert_eq!(panel.bounds.y, 0.0);
-        assert_eq!(panel.bounds.width, 250.0);
-        assert_eq!(panel.bounds.height, 800.0);
-    }
-
-    #[test]
-    fn test_dock_area_layout_center_gets_remaining() {
-        let mut area = DockArea::new();
-        area.set_bounds(Rect::new(0.0, 0.0, 1000.0, 800.0));
-        area.add_panel(
-            DockPanel::new(PanelId::HIERARCHY, "Hierarchy", DockPosition::Left)
-                .with_size(200.0),
-        );
-        area.add_panel(
-            DockPanel::new(PanelId::INSPECTOR, "Inspector", DockPosition::Right)
-                .with_size(250.0),
-        );
-        area.add_panel(DockPanel::new(
-            PanelId::SCENE_VIEW,
-            "Scene",
-            DockPosition::Center,
-        ));
-        area.layout();
-
-        let center = area.get_panel(PanelId::SCENE_VIEW).unwrap();
-        assert_eq!(center.bounds.x, 200.0);
-        assert_eq!(center.bounds.y, 0.0);
-        assert_eq!(center.bounds.width, 550.0); // 1000 - 200 - 250
-        assert_eq!(center.bounds.height, 800.0);
-    }
-
-    #[test]
-    fn test_dock_area_layout_hidden_panel() {
-        let mut area = DockArea::new();
-        area.set_bounds(Rect::new(0.0, 0.0, 1000.0, 800.0));
-        area.add_panel({
-            let mut panel = DockPanel::new(PanelId::HIERARCHY, "Hierarchy", DockPosition::Left)
-                .with_size(200.0);
-            panel.visible = false;
-            panel
-        });
-        area.add_panel(DockPanel::new(
-            PanelId::SCENE_VIEW,
-            "Scene",
-            DockPosition::Center,
-        ));
-        area.layout();
-
-        // Center should get full width since left panel is hidden
-        let center = area.get_panel(PanelId::SCENE_VIEW).unwrap();
-        assert_eq!(center.bounds.x, 0.0);
-        assert_eq!(center.bounds.width, 1000.0);
-    }
-}
+mod tests;
@@ -0,0 +1,302 @@
+use super::*;
+
+#[test]
+fn test_panel_id_constants() {
+    assert_eq!(PanelId::SCENE_VIEW.0, 0);
+    assert_eq!(PanelId::INSPECTOR.0, 1);
+    assert_eq!(PanelId::HIERARCHY.0, 2);
+    assert_eq!(PanelId::ASSET_BROWSER.0, 3);
+    assert_eq!(PanelId::CONSOLE.0, 4);
+    assert_eq!(PanelId::WORLD_STATS.0, 5);
+}
+
+#[test]
+fn test_dock_position_default() {
+    assert_eq!(DockPosition::default(), DockPosition::Center);
+}
+
+#[test]
+fn test_dock_panel_new() {
+    let panel = DockPanel::new(PanelId::INSPECTOR, "Inspector", DockPosition::Right);
+    assert_eq!(panel.id, PanelId::INSPECTOR);
+    assert_eq!(panel.title, "Inspector");
+    assert_eq!(panel.position, DockPosition::Right);
+    assert!(panel.visible);
+    assert!(panel.resizable);
+}
+
+#[test]
+fn test_dock_panel_builder() {
+    let panel = DockPanel::new(PanelId::HIERARCHY, "Hierarchy", DockPosition::Left)
+        .with_size(300.0)
+        .with_min_size(150.0)
+        .with_resizable(false);
+
+    assert_eq!(panel.size, 300.0);
+    assert_eq!(panel.min_size, 150.0);
+    assert!(!panel.resizable);
+}
+
+#[test]
+fn test_dock_panel_content_bounds() {
+    let mut panel = DockPanel::new(PanelId::INSPECTOR, "Test", DockPosition::Right);
+    panel.bounds = Rect::new(100.0, 50.0, 200.0, 400.0);
+
+    let content = panel.content_bounds();
+    assert_eq!(content.x, 100.0);
+    assert_eq!(content.y, 74.0); // 50 + 24 header
+    assert_eq!(content.width, 200.0);
+    assert_eq!(content.height, 376.0); // 400 - 24 header
+}
+
+#[test]
+fn test_dock_area_new() {
+    let area = DockArea::new();
+    assert!(area.panels().is_empty());
+}
+
+#[test]
+fn test_dock_area_add_panel() {
+    let mut area = DockArea::new();
+    area.add_panel(DockPanel::new(PanelId::INSPECTOR, "Inspector", DockPosition::Right));
+    area.add_panel(DockPanel::new(PanelId::HIERARCHY, "Hierarchy", DockPosition::Left));
+
+    assert_eq!(area.panels().len(), 2);
+}
+
+#[test]
+fn test_dock_area_get_panel() {
+    let mut area = DockArea::new();
+    area.add_panel(DockPanel::new(PanelId::INSPECTOR, "Inspector", DockPosition::Right));
+
+    let panel = area.get_panel(PanelId::INSPECTOR);
+    assert!(panel.is_some());
+    assert_eq!(panel.unwrap().title, "Inspector");
+
+    let missing = area.get_panel(PanelId::HIERARCHY);
+    assert!(missing.is_none());
+}
+
+#[test]
+fn test_dock_area_layout_left() {
+    let mut area = DockArea::new();
+    area.set_bounds(Rect::new(0.0, 0.0, 1000.0, 800.0));
+    area.add_panel(
+        DockPanel::new(PanelId::HIERARCHY, "Hierarchy", DockPosition::Left)
+            .with_size(200.0),
+    );
+    area.layout();
+
+    let panel = area.get_panel(PanelId::HIERARCHY).unwrap();
+    assert_eq!(panel.bounds.x, 0.0);
+    assert_eq!(panel.bounds.y, 0.0);
+    assert_eq!(panel.bounds.width, 200.0);
+    assert_eq!(panel.bounds.height, 800.0);
+}
+
+#[test]
+fn test_dock_area_layout_right() {
+    let mut area = DockArea::new();
+    area.set_bounds(Rect::new(0.0, 0.0, 1000.0, 800.0));
+    area.add_panel(
+        DockPanel::new(PanelId::INSPECTOR, "Inspector", DockPosition::Right)
+            .with_size(250.0),
+    );
+    area.layout();
+
+    let panel = area.get_panel(PanelId::INSPECTOR).unwrap();
+    assert_eq!(panel.bounds.x, 750.0); // 1000 - 250
+    assert_eq!(panel.bounds.y, 0.0);
+    assert_eq!(panel.bounds.width, 250.0);
+    assert_eq!(panel.bounds.height, 800.0);
+}
+
+#[test]
+fn test_dock_area_layout_center_gets_remaining() {
+    let mut area = DockArea::new();
+    area.set_bounds(Rect::new(0.0, 0.0, 1000.0, 800.0));
+    area.add_panel(
+        DockPanel::new(PanelId::HIERARCHY, "Hierarchy", DockPosition::Left)
+            .with_size(200.0),
+    );
+    area.add_panel(
+        DockPanel::new(PanelId::INSPECTOR, "Inspector", DockPosition::Right)
+            .with_size(250.0),
+    );
+    area.add_panel(DockPanel::new(
+        PanelId::SCENE_VIEW,
+        "Scene",
+        DockPosition::Center,
+    ));
+    area.layout();
+
+    let center = area.get_panel(PanelId::SCENE_VIEW).unwrap();
+    assert_eq!(center.bounds.x, 200.0);
+    assert_eq!(center.bounds.y, 0.0);
+    assert_eq!(center.bounds.width, 550.0); // 1000 - 200 - 250
+    assert_eq!(center.bounds.height, 800.0);
+}
+
+#[test]
+fn test_dock_area_layout_hidden_panel() {
+    let mut area = DockArea::new();
+    area.set_bounds(Rect::new(0.0, 0.0, 1000.0, 800.0));
+    area.add_panel({
+        let mut panel = DockPanel::new(PanelId::HIERARCHY, "Hierarchy", DockPosition::Left)
+            .with_size(200.0);
+        panel.visible = false;
+        panel
+    });
+    area.add_panel(DockPanel::new(
+        PanelId::SCENE_VIEW,
+        "Scene",
+        DockPosition::Center,
+    ));
+    area.layout();
+
+    // Center should get full width since left panel is hidden
+    let center = area.get_panel(PanelId::SCENE_VIEW).unwrap();
+    assert_eq!(center.bounds.x, 0.0);
+    assert_eq!(center.bounds.width, 1000.0);
+}
+
+fn three_panel_area() -> DockArea {
+    let mut area = DockArea::new();
+    area.set_bounds(Rect::new(0.0, 0.0, 1000.0, 800.0));
+    area.add_panel(
+        DockPanel::new(PanelId::HIERARCHY, "Hierarchy", DockPosition::Left).with_size(200.0),
+    );
+    area.add_panel(
+        DockPanel::new(PanelId::INSPECTOR, "Inspector", DockPosition::Right).with_size(250.0),
+    );
+    area.add_panel(DockPanel::new(PanelId::SCENE_VIEW, "Scene", DockPosition::Center));
+    area.layout();
+    area
+}
+
+#[test]
+fn test_maximize_panel_fills_dock_area_and_hides_others() {
+    let mut area = three_panel_area();
+    area.maximize_panel(PanelId::SCENE_VIEW);
+
+    let scene = area.get_panel(PanelId::SCENE_VIEW).unwrap();
+    assert_eq!(scene.bounds.x, 0.0);
+    assert_eq!(scene.bounds.width, 1000.0);
+    assert!(area.is_panel_shown(PanelId::SCENE_VIEW));
+    assert!(!area.is_panel_shown(PanelId::HIERARCHY));
+    assert!(!area.is_panel_shown(PanelId::INSPECTOR));
+}
+
+#[test]
+fn test_toggle_maximized_restores_docked_layout() {
+    let mut area = three_panel_area();
+    assert!(area.toggle_maximized(PanelId::INSPECTOR));
+    assert_eq!(area.maximized_panel(), Some(PanelId::INSPECTOR));
+
+    assert!(!area.toggle_maximized(PanelId::INSPECTOR));
+    let inspector = area.get_panel(PanelId::INSPECTOR).unwrap();
+    assert_eq!(inspector.bounds.x, 750.0);
+    assert_eq!(inspector.bounds.width, 250.0);
+    assert!(area.is_panel_shown(PanelId::HIERARCHY));
+}
+
+#[test]
+fn test_maximize_unknown_panel_is_ignored() {
+    let mut area = three_panel_area();
+    area.maximize_panel(PanelId::CONSOLE);
+    assert_eq!(area.maximized_panel(), None);
+}
+
+#[test]
+fn test_panel_at_finds_hovered_panel() {
+    let area = three_panel_area();
+    assert_eq!(area.panel_at(glam::Vec2::new(100.0, 400.0)), Some(PanelId::HIERARCHY));
+    assert_eq!(area.panel_at(glam::Vec2::new(500.0, 400.0)), Some(PanelId::SCENE_VIEW));
+    assert_eq!(area.panel_at(glam::Vec2::new(900.0, 400.0)), Some(PanelId::INSPECTOR));
+    assert_eq!(area.panel_at(glam::Vec2::new(2000.0, 400.0)), None);
+}
+
+#[test]
+fn test_toggle_panel_visible_hides_and_relayouts() {
+    let mut area = three_panel_area();
+    assert!(!area.toggle_panel_visible(PanelId::INSPECTOR));
+    assert!(!area.is_panel_shown(PanelId::INSPECTOR));
+    assert_eq!(area.get_panel(PanelId::SCENE_VIEW).unwrap().bounds.width, 800.0);
+
+    assert!(area.toggle_panel_visible(PanelId::INSPECTOR));
+    assert_eq!(area.get_panel(PanelId::SCENE_VIEW).unwrap().bounds.width, 550.0);
+    assert!(!area.toggle_panel_visible(PanelId::CONSOLE));
+}
+
+#[test]
+fn test_set_panel_visible_is_idempotent() {
+    let mut area = three_panel_area();
+    area.set_panel_visible(PanelId::INSPECTOR, true);
+    assert!(area.is_panel_shown(PanelId::INSPECTOR), "already visible stays visible");
+    area.set_panel_visible(PanelId::INSPECTOR, false);
+    area.set_panel_visible(PanelId::INSPECTOR, false);
+    assert!(!area.is_panel_shown(PanelId::INSPECTOR));
+    assert_eq!(area.get_panel(PanelId::SCENE_VIEW).unwrap().bounds.width, 800.0);
+}
+
+#[test]
+fn test_dock_layout_save_load_restores_sizes_and_visibility() {
+    let mut area = three_panel_area();
+    area.get_panel_mut(PanelId::HIERARCHY).unwrap().size = 320.0;
+    area.set_panel_visible(PanelId::INSPECTOR, false);
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("editor_layout.json");
+    DockLayout::capture(&area).save(&path).unwrap();
+
+    let mut restored = three_panel_area();
+    DockLayout::load(&path).apply(&mut restored);
+    assert_eq!(restored.get_panel(PanelId::HIERARCHY).unwrap().size, 320.0);
+    assert!(!restored.is_panel_shown(PanelId::INSPECTOR));
+    assert_eq!(restored.get_panel(PanelId::SCENE_VIEW).unwrap().bounds.width, 680.0);
+}
+
+#[test]
+fn test_dock_layout_apply_skips_unknown_panels_and_clamps_size() {
+    let mut area = three_panel_area();
+    area.maximize_panel(PanelId::SCENE_VIEW);
+    let mut layout = DockLayout::default();
+    layout.set_panel(PanelId::INSPECTOR, DockPosition::Right, 1.0, true);
+    layout.set_panel(PanelId(123), DockPosition::Left, 300.0, true);
+    layout.apply(&mut area);
+
+    let inspector = area.get_panel(PanelId::INSPECTOR).unwrap();
+    assert_eq!(inspector.size, inspector.min_size);
+    assert_eq!(area.maximized_panel(), None, "applying a layout leaves maximize mode");
+    assert_eq!(area.panels().len(), 3);
+}
+
+#[test]
+fn test_dock_layout_load_missing_file_changes_nothing() {
+    let mut area = three_panel_area();
+    let layout = DockLayout::load(std::path::Path::new("/nonexistent/layout.json"));
+    assert!(layout.panels.is_empty());
+    layout.apply(&mut area);
+    assert_eq!(area.get_panel(PanelId::INSPECTOR).unwrap().size, 250.0);
+}
+
+#[test]
+fn test_layout_presets_build_on_default_and_reset_restores_it() {
+    let mut area = three_panel_area();
+    let mut layers = DockPanel::new(PanelId::LAYERS, "Layers", DockPosition::Right).with_size(220.0);
+    layers.visible = false;
+    area.add_panel(layers);
+    area.mark_default_layout();
+
+    area.apply_preset(LayoutPreset::LevelDesign);
+    assert!(area.is_panel_shown(PanelId::LAYERS));
+    assert_eq!(area.get_panel(PanelId::HIERARCHY).unwrap().size, 260.0);
+
+    area.apply_preset(LayoutPreset::Animation);
+    assert!(!area.is_panel_shown(PanelId::LAYERS));
+    assert_eq!(area.get_panel(PanelId::INSPECTOR).unwrap().size, 340.0);
+
+    area.apply_preset(LayoutPreset::Default);
+    assert_eq!(DockLayout::capture(&area), *area.default_layout());
+    assert_eq!(LayoutPreset::from_menu_label("Reset Layout"), Some(LayoutPreset::Default));
+    assert_eq!(LayoutPreset::from_menu_label("Layers"), None);
+}
@@ -0,0 +1,324 @@
+//! Free-standing editable field widgets (f32, Vec2, bool, text, color,
+//! read-only values) drawn at an explicit position. [`EditableInspector`](super::EditableInspector)
+//! lays these out row by row.
+
+use std::ops::RangeInclusive;
+
+use glam::{Vec2, Vec4};
+use ui::{Color, Rect, UIContext};
+
+use crate::field_style::{EditResult, EditableFieldStyle, FieldId};
+
+/// Render an editable f32 value with a text input box.
+pub fn edit_f32(
+    ui: &mut UIContext,
+    id: FieldId,
+    label: &str,
+    value: f32,
+    range: RangeInclusive<f32>,
+    pos: Vec2,
+    style: &EditableFieldStyle,
+) -> EditResult<f32> {
+    // Draw label
+    ui.label_styled(label, glam::Vec2::new(pos.x, pos.y + 4.0), style.label_color, style.label_font);
+
+    // Text input bounds
+    let input_x = pos.x + style.label_width;
+    let input_height = style.row_height - 4.0;
+    let input_bounds = Rect::new(
+        input_x,
+        pos.y + (style.row_height - input_height) / 2.0,
+        style.input_width,
+        input_height,
+    );
+
+    let new_value = ui.float_input(id, value, *range.start(), *range.end(), input_bounds);
+
+    if (new_value - value).abs() > f32::EPSILON {
+        EditResult::Changed(new_value)
+    } else {
+        EditResult::Unchanged
+    }
+}
+
+/// Render an editable f32 value with a slider that wraps to a 0-1 range.
+/// Useful for normalized values like volume, friction, restitution.
+pub fn edit_normalized_f32(
+    ui: &mut UIContext,
+    id: FieldId,
+    label: &str,
+    value: f32,
+    pos: Vec2,
+    style: &EditableFieldStyle,
+) -> EditResult<f32> {
+    let clamped = value.clamp(0.0, 1.0);
+    edit_f32(ui, id, label, clamped, 0.0..=1.0, pos, style)
+}
+
+/// Render an editable boolean value with a checkbox.
+pub fn edit_bool(
+    ui: &mut UIContext,
+    id: FieldId,
+    label: &str,
+    value: bool,
+    pos: Vec2,
+    style: &EditableFieldStyle,
+) -> EditResult<bool> {
+    // Draw label
+    ui.label_styled(label, glam::Vec2::new(pos.x, pos.y + 4.0), style.label_color, style.label_font);
+
+    // Checkbox bounds
+    let checkbox_x = pos.x + style.label_width;
+    let checkbox_bounds = Rect::new(
+        checkbox_x,
+        pos.y + (style.row_height - style.checkbox_size) / 2.0,
+        style.checkbox_size,
+        style.checkbox_size,
+    );
+
+    // Render checkbox and check if toggled
+    let toggled = ui.checkbox(id, value, checkbox_bounds);
+
+    if toggled {
+        EditResult::Changed(!value)
+    } else {
+        EditResult::Unchanged
+    }
+}
+
+/// Render an editable Vec2 value with separate X/Y text input boxes.
+pub fn edit_vec2(
+    ui: &mut UIContext,
+    id: FieldId,
+    label: &str,
+    value: Vec2,
+    range: RangeInclusive<f32>,
+    pos: Vec2,
+    style: &EditableFieldStyle,
+) -> EditResult<Vec2> {
+    let (min, max) = (*range.start(), *range.end());
+
+    // Draw label
+    ui.label_styled(label, glam::Vec2::new(pos.x, pos.y + 4.0), style.label_color, style.label_font);
+
+    let mut new_value = value;
+    let input_width = style.vec2_input_width;
+    let input_height = style.row_height - 4.0;
+    let input_x = pos.x + style.label_width;
+    let input_y = pos.y + (style.row_height - input_height) / 2.0;
+
+    // X label
+    ui.label_styled(
+        "X",
+        glam::Vec2::new(input_x, pos.y + 4.0),
+        style.axis_x_label,
+        style.axis_font,
+    );
+
+    // X input
+    let x_bounds = Rect::new(input_x + style.axis_label_gap, input_y, input_width, input_height);
+    new_value.x = ui.float_input(
+        FieldId::new(id.component_index, id.field_index, 0),
+        value.x, min, max, x_bounds,
+    );
+
+    // Y label
+    let y_label_x = input_x + style.axis_label_gap + input_width + style.input_gap;
+    ui.label_styled(
+        "Y",
+        glam::Vec2::new(y_label_x, pos.y + 4.0),
+        style.axis_y_label,
+        style.axis_font,
+    );
+
+    // Y input
+    let y_bounds = Rect::new(y_label_x + style.axis_label_gap, input_y, input_width, input_height);
+    new_value.y = ui.float_input(
+        FieldId::new(id.component_index, id.field_index, 1),
+        value.y, min, max, y_bounds,
+    );
+
+    if new_value != value {
+        EditResult::Changed(new_value)
+    } else {
+        EditResult::Unchanged
+    }
+}
+
+/// Render an editable string (tags, target names) with a free-text input.
+/// Returns the new text once an edit commits with a different value.
+pub fn edit_text(
+    ui: &mut UIContext,
+    id: FieldId,
+    label: &str,
+    value: &str,
+    pos: Vec2,
+    style: &EditableFieldStyle,
+) -> EditResult<String> {
+    ui.label_styled(label, glam::Vec2::new(pos.x, pos.y + 4.0), style.label_color, style.label_font);
+
+    let input_height = style.row_height - 4.0;
+    let input_bounds = Rect::new(
+        pos.x + style.label_width,
+        pos.y + (style.row_height - input_height) / 2.0,
+        style.input_width,
+        input_height,
+    );
+
+    match ui.text_input(id, value, input_bounds) {
+        Some(text) => EditResult::Changed(text),
+        None => EditResult::Unchanged,
+    }
+}
+
+/// Render a read-only u32 value (for asset handles, etc.).
+pub fn display_u32(
+    ui: &mut UIContext,
+    label: &str,
+    value: u32,
+    pos: Vec2,
+    style: &EditableFieldStyle,
+) {
+    ui.label_styled(label, glam::Vec2::new(pos.x, pos.y + 4.0), style.label_color, style.label_font);
+
+    let value_text = format!("{}", value);
+    ui.label_styled(
+        &value_text,
+        glam::Vec2::new(pos.x + style.label_width, pos.y + 4.0),
+        style.value_color,
+        style.label_font,
+    );
+}
+
+/// Render a read-only string value (for tags, target names, etc.).
+pub fn display_string(
+    ui: &mut UIContext,
+    label: &str,
+    value: &str,
+    pos: Vec2,
+    style: &EditableFieldStyle,
+) {
+    ui.label_styled(label, glam::Vec2::new(pos.x, pos.y + 4.0), style.label_color, style.label_font);
+    ui.label_styled(
+        value,
+        glam::Vec2::new(pos.x + style.label_width, pos.y + 4.0),
+        style.value_color,
+        style.label_font,
+    );
+}
+
+/// Step an index forward or backward through `count` values, wrapping at
+/// the ends. Pure helper behind [`EditableInspector::cycle`].
+pub fn cycle_step(index: usize, count: usize, forward: bool) -> usize {
+    if count == 0 {
+        return 0;
+    }
+    if forward {
+        (index + 1) % count
+    } else {
+        (index + count - 1) % count
+    }
+}
+
+/// Render an editable color (Vec4) with RGBA text inputs and preview.
+pub fn edit_color(
+    ui: &mut UIContext,
+    id: FieldId,
+    label: &str,
+    value: Vec4,
+    pos: Vec2,
+    style: &EditableFieldStyle,
+) -> EditResult<Vec4> {
+    let mut new_value = value;
+    let mut changed = false;
+    let row_height = style.row_height;
+    let (x, y) = (pos.x, pos.y);
+
+    // Draw label
+    ui.label_styled(label, glam::Vec2::new(x, y + 4.0), style.label_color, style.label_font);
+
+    // Color preview
+    let preview_x = x + style.label_width;
+    let preview_bounds = Rect::new(
+        preview_x,
+        y + (row_height - style.color_preview_size) / 2.0,
+        style.color_preview_size,
+        style.color_preview_size,
+    );
+    let preview_color = Color::new(value.x, value.y, value.z, value.w);
+    ui.rect_rounded(preview_bounds, preview_color, 2.0);
+
+    // RGBA text inputs (compact)
+    let input_x = preview_x + style.color_preview_size + style.input_gap;
+    let input_width = style.color_input_width;
+    let input_height = style.color_input_height;
+    let gap = style.color_input_gap;
+
+    // Red
+    ui.label_styled("R", glam::Vec2::new(input_x, y + 2.0), style.channel_labels[0], style.channel_font);
+    let r_bounds = Rect::new(input_x + style.channel_label_gap, y + 2.0, input_width, input_height);
+    let new_r = ui.float_input(
+        FieldId::new(id.component_index, id.field_index, 0),
+        value.x, 0.0, 1.0, r_bounds,
+    );
+    if (new_r - value.x).abs() > f32::EPSILON {
+        new_value.x = new_r;
+        changed = true;
+    }
+
+    // Green
+    let g_x = input_x + style.channel_label_gap + input_width + gap;
+    ui.label_styled("G", glam::Vec2::new(g_x, y + 2.0), style.channel_labels[1], style.channel_font);
+    let g_bounds = Rect::new(g_x + style.channel_label_gap, y + 2.0, input_width, input_height);
+    let new_g = ui.float_input(
+        FieldId::new(id.component_index, id.field_index, 1),
+        value.y, 0.0, 1.0, g_bounds,
+    );
+    if (new_g - value.y).abs() > f32::EPSILON {
+        new_value.y = new_g;
+        changed = true;
+    }
+
+    // Blue
+    ui.label_styled("B", glam::Vec2::new(input_x, y + 2.0 + input_height + 4.0), style.channel_labels[2], style.channel_font);
+    let b_bounds = Rect::new(input_x + style.channel_label_gap, y + 2.0 + input_height + 4.0, input_width, input_height);
+    let new_b = ui.float_input(
+        FieldId::new(id.component_index, id.field_index, 2),
+        value.z, 0.0, 1.0, b_bounds,
+    );
+    if (new_b - value.z).abs() > f32::EPSILON {
+        new_value.z = new_b;
+        changed = true;
+    }
+
+    // Alpha
+    let a_x = input_x + style.channel_label_gap + input_width + gap;
+    ui.label_styled("A", glam::Vec2::new(a_x, y + 2.0 + input_height + 4.0), style.channel_labels[3], style.channel_font);
+    let a_bounds = Rect::new(a_x + style.channel_label_gap, y + 2.0 + input_height + 4.0, input_width, input_height);
+    let new_a = ui.float_input(
+        FieldId::new(id.component_index, id.field_index, 3),
+        value.w, 0.0, 1.0, a_bounds,
+    );
+    if (new_a - value.w).abs() > f32::EPSILON {
+        new_value.w = new_a;
+        changed = true;
+    }
+
+    if changed {
+        EditResult::Changed(new_value)
+    } else {
+        EditResult::Unchanged
+    }
+}
+
+/// Calculate the Y position after rendering a component section header.
+pub fn component_header(
+    ui: &mut UIContext,
+    type_name: &str,
+    x: f32,
+    y: f32,
+    style: &EditableFieldStyle,
+) -> f32 {
+    ui.label_styled(type_name, glam::Vec2::new(x, y), style.header_color, style.header_font);
+    y + style.row_height + 4.0
+}
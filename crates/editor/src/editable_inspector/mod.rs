@@ -3,306 +3,35 @@
 //! This module provides editable UI widgets for modifying component
 //! properties directly in the editor. Supports all common component
 //! field types used in Transform2D, Sprite, RigidBody, Collider, and AudioSource.
-//! Field identity and styling types live in [`crate::field_style`].
+//! Field identity and styling types live in [`crate::field_style`]; the
+//! free-standing field widgets in `fields.rs`.
 
+mod fields;
+
+use std::collections::HashSet;
 use std::ops::RangeInclusive;
 
 use glam::{Vec2, Vec4};
-use ui::{Color, Rect, UIContext};
+use ui::{Rect, UIContext};
 
 pub use crate::field_style::{EditResult, EditableFieldStyle, FieldId};
+pub use fields::{
+    component_header, cycle_step, display_string, display_u32, edit_bool, edit_color, edit_f32,
+    edit_normalized_f32, edit_text, edit_vec2,
+};
 
-/// Render an editable f32 value with a text input box.
-pub fn edit_f32(
-    ui: &mut UIContext,
-    id: FieldId,
-    label: &str,
-    value: f32,
-    range: RangeInclusive<f32>,
-    pos: Vec2,
-    style: &EditableFieldStyle,
-) -> EditResult<f32> {
-    // Draw label
-    ui.label_styled(label, glam::Vec2::new(pos.x, pos.y + 4.0), style.label_color, style.label_font);
-
-    // Text input bounds
-    let input_x = pos.x + style.label_width;
-    let input_height = style.row_height - 4.0;
-    let input_bounds = Rect::new(
-        input_x,
-        pos.y + (style.row_height - input_height) / 2.0,
-        style.input_width,
-        input_height,
-    );
-
-    let new_value = ui.float_input(id, value, *range.start(), *range.end(), input_bounds);
-
-    if (new_value - value).abs() > f32::EPSILON {
-        EditResult::Changed(new_value)
-    } else {
-        EditResult::Unchanged
-    }
-}
+use crate::multi_edit::FieldCell;
 
-/// Render an editable f32 value with a slider that wraps to a 0-1 range.
-/// Useful for normalized values like volume, friction, restitution.
-pub fn edit_normalized_f32(
-    ui: &mut UIContext,
-    id: FieldId,
-    label: &str,
-    value: f32,
-    pos: Vec2,
-    style: &EditableFieldStyle,
-) -> EditResult<f32> {
-    let clamped = value.clamp(0.0, 1.0);
-    edit_f32(ui, id, label, clamped, 0.0..=1.0, pos, style)
-}
-
-/// Render an editable boolean value with a checkbox.
-pub fn edit_bool(
-    ui: &mut UIContext,
-    id: FieldId,
-    label: &str,
-    value: bool,
-    pos: Vec2,
-    style: &EditableFieldStyle,
-) -> EditResult<bool> {
-    // Draw label
-    ui.label_styled(label, glam::Vec2::new(pos.x, pos.y + 4.0), style.label_color, style.label_font);
-
-    // Checkbox bounds
-    let checkbox_x = pos.x + style.label_width;
-    let checkbox_bounds = Rect::new(
-        checkbox_x,
-        pos.y + (style.row_height - style.checkbox_size) / 2.0,
-        style.checkbox_size,
-        style.checkbox_size,
-    );
-
-    // Render checkbox and check if toggled
-    let toggled = ui.checkbox(id, value, checkbox_bounds);
-
-    if toggled {
-        EditResult::Changed(!value)
-    } else {
-        EditResult::Unchanged
-    }
-}
-
-/// Render an editable Vec2 value with separate X/Y text input boxes.
-pub fn edit_vec2(
-    ui: &mut UIContext,
-    id: FieldId,
-    label: &str,
-    value: Vec2,
-    range: RangeInclusive<f32>,
-    pos: Vec2,
-    style: &EditableFieldStyle,
-) -> EditResult<Vec2> {
-    let (min, max) = (*range.start(), *range.end());
-
-    // Draw label
-    ui.label_styled(label, glam::Vec2::new(pos.x, pos.y + 4.0), style.label_color, style.label_font);
-
-    let mut new_value = value;
-    let input_width = style.vec2_input_width;
-    let input_height = style.row_height - 4.0;
-    let input_x = pos.x + style.label_width;
-    let input_y = pos.y + (style.row_height - input_height) / 2.0;
-
-    // X label
-    ui.label_styled(
-        "X",
-        glam::Vec2::new(input_x, pos.y + 4.0),
-        style.axis_x_label,
-        style.axis_font,
-    );
-
-    // X input
-    let x_bounds = Rect::new(input_x + style.axis_label_gap, input_y, input_width, input_height);
-    new_value.x = ui.float_input(
-        FieldId::new(id.component_index, id.field_index, 0),
-        value.x, min, max, x_bounds,
-    );
-
-    // Y label
-    let y_label_x = input_x + style.axis_label_gap + input_width + style.input_gap;
-    ui.label_styled(
-        "Y",
-        glam::Vec2::new(y_label_x, pos.y + 4.0),
-        style.axis_y_label,
-        style.axis_font,
-    );
-
-    // Y input
-    let y_bounds = Rect::new(y_label_x + style.axis_label_gap, input_y, input_width, input_height);
-    new_value.y = ui.float_input(
-        FieldId::new(id.component_index, id.field_index, 1),
-        value.y, min, max, y_bounds,
-    );
-
-    if new_value != value {
-        EditResult::Changed(new_value)
-    } else {
-        EditResult::Unchanged
-    }
-}
-
-/// Render a read-only u32 value (for asset handles, etc.).
-pub fn display_u32(
-    ui: &mut UIContext,
-    label: &str,
-    value: u32,
-    pos: Vec2,
-    style: &EditableFieldStyle,
-) {
-    ui.label_styled(label, glam::Vec2::new(pos.x, pos.y + 4.0), style.label_color, style.label_font);
-
-    let value_text = format!("{}", value);
-    ui.label_styled(
-        &value_text,
-        glam::Vec2::new(pos.x + style.label_width, pos.y + 4.0),
-        style.value_color,
-        style.label_font,
-    );
-}
-
-/// Render a read-only string value (for tags, target names, etc.).
-pub fn display_string(
-    ui: &mut UIContext,
-    label: &str,
-    value: &str,
-    pos: Vec2,
-    style: &EditableFieldStyle,
-) {
-    ui.label_styled(label, glam::Vec2::new(pos.x, pos.y + 4.0), style.label_color, style.label_font);
-    ui.label_styled(
-        value,
-        glam::Vec2::new(pos.x + style.label_width, pos.y + 4.0),
-        style.value_color,
-        style.label_font,
-    );
-}
-
-/// Step an index forward or backward through `count` values, wrapping at
-/// the ends. Pure helper behind [`EditableInspector::cycle`].
-pub fn cycle_step(index: usize, count: usize, forward: bool) -> usize {
-    if count == 0 {
-        return 0;
-    }
-    if forward {
-        (index + 1) % count
-    } else {
-        (index + count - 1) % count
-    }
-}
-
-/// Render an editable color (Vec4) with RGBA text inputs and preview.
-pub fn edit_color(
-    ui: &mut UIContext,
-    id: FieldId,
-    label: &str,
-    value: Vec4,
-    pos: Vec2,
-    style: &EditableFieldStyle,
-) -> EditResult<Vec4> {
-    let mut new_value = value;
-    let mut changed = false;
-    let row_height = style.row_height;
-    let (x, y) = (pos.x, pos.y);
-
-    // Draw label
-    ui.label_styled(label, glam::Vec2::new(x, y + 4.0), style.label_color, style.label_font);
-
-    // Color preview
-    let preview_x = x + style.label_width;
-    let preview_bounds = Rect::new(
-        preview_x,
-        y + (row_height - style.color_preview_size) / 2.0,
-        style.color_preview_size,
-        style.color_preview_size,
-    );
-    let preview_color = Color::new(value.x, value.y, value.z, value.w);
-    ui.rect_rounded(preview_bounds, preview_color, 2.0);
-
-    // RGBA text inputs (compact)
-    let input_x = preview_x + style.color_preview_size + style.input_gap;
-    let input_width = style.color_input_width;
-    let input_height = style.color_input_height;
-    let gap = style.color_input_gap;
-
-    // Red
-    ui.label_styled("R", glam::Vec2::new(input_x, y + 2.0), style.channel_labels[0], style.channel_font);
-    let r_bounds = Rect::new(input_x + style.channel_label_gap, y + 2.0, input_width, input_height);
-    let new_r = ui.float_input(
-        FieldId::new(id.component_index, id.field_index, 0),
-        value.x, 0.0, 1.0, r_bounds,
-    );
-    if (new_r - value.x).abs() > f32::EPSILON {
-        new_value.x = new_r;
-        changed = true;
-    }
-
-    // Green
-    let g_x = input_x + style.channel_label_gap + input_width + gap;
-    ui.label_styled("G", glam::Vec2::new(g_x, y + 2.0), style.channel_labels[1], style.channel_font);
-    let g_bounds = Rect::new(g_x + style.channel_label_gap, y + 2.0, input_width, input_height);
-    let new_g = ui.float_input(
-        FieldId::new(id.component_index, id.field_index, 1),
-        value.y, 0.0, 1.0, g_bounds,
-    );
-    if (new_g - value.y).abs() > f32::EPSILON {
-        new_value.y = new_g;
-        changed = true;
-    }
-
-    // Blue
-    ui.label_styled("B", glam::Vec2::new(input_x, y + 2.0 + input_height + 4.0), style.channel_labels[2], style.channel_font);
-    let b_bounds = Rect::new(input_x + style.channel_label_gap, y + 2.0 + input_height + 4.0, input_width, input_height);
-    let new_b = ui.float_input(
-        FieldId::new(id.component_index, id.field_index, 2),
-        value.z, 0.0, 1.0, b_bounds,
-    );
-    if (new_b - value.z).abs() > f32::EPSILON {
-        new_value.z = new_b;
-        changed = true;
-    }
-
-    // Alpha
-    let a_x = input_x + style.channel_label_gap + input_width + gap;
-    ui.label_styled("A", glam::Vec2::new(a_x, y + 2.0 + input_height + 4.0), style.channel_labels[3], style.channel_font);
-    let a_bounds = Rect::new(a_x + style.channel_label_gap, y + 2.0 + input_height + 4.0, input_width, input_height);
-    let new_a = ui.float_input(
-        FieldId::new(id.component_index, id.field_index, 3),
-        value.w, 0.0, 1.0, a_bounds,
-    );
-    if (new_a - value.w).abs() > f32::EPSILON {
-        new_value.w = new_a;
-        changed = true;
-    }
-
-    if changed {
-        EditResult::Changed(new_value)
-    } else {
-        EditResult::Unchanged
-    }
-}
-
-/// Calculate the Y position after rendering a component section header.
-pub fn component_header(
-    ui: &mut UIContext,
-    type_name: &str,
-    x: f32,
-    y: f32,
-    style: &EditableFieldStyle,
-) -> f32 {
-    ui.label_styled(type_name, glam::Vec2::new(x, y), style.header_color, style.header_font);
-    y + style.row_height + 4.0
-}
+/// Shown in place of a value that differs across a multi-selection.
+const MIXED: &str = "—";
 
 /// A builder for constructing editable component inspectors.
 ///
 /// This provides a fluent API for building inspectors for specific component types.
+///
+/// Every field row records the value it shows, per cell, so a
+/// multi-selection can tell which cells differ; cells marked mixed show
+/// "—".
 pub struct EditableInspector<'a> {
     ui: &'a mut UIContext,
     style: EditableFieldStyle,
@@ -310,6 +39,10 @@ pub struct EditableInspector<'a> {
     field_index: usize,
     current_y: f32,
     x: f32,
+    /// Cells whose value differs across the multi-selection
+    mixed: HashSet<FieldCell>,
+    /// Value shown in each cell so far
+    shown: Vec<(FieldCell, String)>,
 }
 
 impl<'a> EditableInspector<'a> {
@@ -322,6 +55,8 @@ impl<'a> EditableInspector<'a> {
             field_index: 0,
             current_y: y,
             x,
+            mixed: HashSet::new(),
+            shown: Vec::new(),
         }
     }
 
@@ -337,6 +72,17 @@ impl<'a> EditableInspector<'a> {
         self
     }
 
+    /// Show these cells as mixed ("—") — they differ across the selection.
+    pub(crate) fn with_mixed(mut self, mixed: HashSet<FieldCell>) -> Self {
+        self.mixed = mixed;
+        self
+    }
+
+    /// The value each cell showed, in row order.
+    pub(crate) fn into_shown_values(self) -> Vec<(FieldCell, String)> {
+        self.shown
+    }
+
     /// Get the current Y position.
     pub fn y(&self) -> f32 {
         self.current_y
@@ -385,29 +131,67 @@ impl<'a> EditableInspector<'a> {
         Vec2::new(self.x + self.style.indent, self.current_y)
     }
 
-    /// Add a texture slot field: shows the texture's display name and acts
-    /// as a drag-and-drop target for asset-browser textures.
+    /// Record the value shown in cell `sub` (axis/channel) of the current
+    /// row and, if that cell is mixed, mark its widget so. Returns whether
+    /// it is mixed.
+    fn cell(&mut self, label: &str, sub: usize, shown: impl std::fmt::Debug) -> bool {
+        let cell = (label.to_string(), sub);
+        let mixed = self.mixed.contains(&cell);
+        if mixed {
+            self.ui.mark_mixed(FieldId::new(self.component_index, self.field_index, sub));
+        }
+        self.shown.push((cell, format!("{shown:?}")));
+        mixed
+    }
+
+    /// `result`, or `Changed(value)` when one of the current row's first
+    /// `cells` mixed inputs was committed with the value it already held —
+    /// the edit must still reach the rest of the selection.
+    fn settle<T>(&self, result: EditResult<T>, value: T, cells: usize) -> EditResult<T> {
+        let recommitted = (0..cells)
+            .any(|sub| self.ui.mixed_committed(FieldId::new(self.component_index, self.field_index, sub)));
+        match result {
+            EditResult::Unchanged if recommitted => EditResult::Changed(value),
+            result => result,
+        }
+    }
+
+    /// Lay out one custom field row: hands the field id, position and style
+    /// to `draw`, then advances to the next row.
+    pub(crate) fn custom_row<R>(
+        &mut self,
+        draw: impl FnOnce(&mut UIContext, FieldId, Vec2, &EditableFieldStyle) -> R,
+    ) -> R {
+        let id = FieldId::new(self.component_index, self.field_index, 0);
+        let pos = self.field_pos();
+        let result = draw(&mut *self.ui, id, pos, &self.style);
+        self.field_index += 1;
+        self.current_y += self.style.row_height;
+        result
+    }
+
+    /// Add a texture slot field: shows the texture's thumbnail and display
+    /// name, acts as a drag-and-drop target for asset-browser textures, and
+    /// sets `extras.pick_texture` when its "Pick" button is clicked.
     pub fn texture(
         &mut self,
         label: &str,
         handle: u32,
         extras: &mut crate::InspectorExtras<'_>,
     ) -> EditResult<u32> {
-        let id = FieldId::new(self.component_index, self.field_index, 0);
-        let pos = self.field_pos();
-        let display = extras.texture_display.clone();
-        let result = crate::edit_texture_field(
-            self.ui,
-            id,
-            label,
-            handle,
-            extras.drag_drop,
-            display.as_deref(),
-            pos,
-            &self.style,
-        );
-        self.field_index += 1;
-        self.current_y += self.style.row_height;
+        let (display, texture_size) = if self.cell(label, 0, handle) {
+            (Some(MIXED.to_string()), Vec2::ZERO)
+        } else {
+            (extras.texture_display.clone(), extras.texture_size)
+        };
+        let (result, pick) = self.custom_row(|ui, id, pos, style| {
+            let result = crate::edit_texture_field(
+                ui, id, label, handle, extras.drag_drop, display.as_deref(), texture_size, pos, style,
+            );
+            let pick_id = FieldId::new(id.component_index, id.field_index, 1);
+            (result, crate::texture_pick_button(ui, pick_id, pos, style))
+        });
+        extras.pick_texture |= pick;
         result
     }
 
@@ -415,7 +199,9 @@ impl<'a> EditableInspector<'a> {
     pub fn f32(&mut self, label: &str, value: f32, range: RangeInclusive<f32>) -> EditResult<f32> {
         let id = FieldId::new(self.component_index, self.field_index, 0);
         let pos = self.field_pos();
+        self.cell(label, 0, value);
         let result = edit_f32(self.ui, id, label, value, range, pos, &self.style);
+        let result = self.settle(result, value, 1);
         self.field_index += 1;
         self.current_y += self.style.row_height;
         result
@@ -425,7 +211,9 @@ impl<'a> EditableInspector<'a> {
     pub fn normalized_f32(&mut self, label: &str, value: f32) -> EditResult<f32> {
         let id = FieldId::new(self.component_index, self.field_index, 0);
         let pos = self.field_pos();
+        self.cell(label, 0, value);
         let result = edit_normalized_f32(self.ui, id, label, value, pos, &self.style);
+        let result = self.settle(result, value, 1);
         self.field_index += 1;
         self.current_y += self.style.row_height;
         result
@@ -435,6 +223,7 @@ impl<'a> EditableInspector<'a> {
     pub fn bool(&mut self, label: &str, value: bool) -> EditResult<bool> {
         let id = FieldId::new(self.component_index, self.field_index, 0);
         let pos = self.field_pos();
+        self.cell(label, 0, value);
         let result = edit_bool(self.ui, id, label, value, pos, &self.style);
         self.field_index += 1;
         self.current_y += self.style.row_height;
@@ -445,7 +234,10 @@ impl<'a> EditableInspector<'a> {
     pub fn vec2(&mut self, label: &str, value: Vec2, range: RangeInclusive<f32>) -> EditResult<Vec2> {
         let id = FieldId::new(self.component_index, self.field_index, 0);
         let pos = self.field_pos();
+        self.cell(label, 0, value.x);
+        self.cell(label, 1, value.y);
         let result = edit_vec2(self.ui, id, label, value, range, pos, &self.style);
+        let result = self.settle(result, value, 2);
         self.field_index += 1;
         self.current_y += self.style.row_height;
         result
@@ -454,7 +246,11 @@ impl<'a> EditableInspector<'a> {
     /// Add a read-only u32 display.
     pub fn u32(&mut self, label: &str, value: u32) {
         let pos = self.field_pos();
-        display_u32(self.ui, label, value, pos, &self.style);
+        if self.cell(label, 0, value) {
+            display_string(self.ui, label, MIXED, pos, &self.style);
+        } else {
+            display_u32(self.ui, label, value, pos, &self.style);
+        }
         self.field_index += 1;
         self.current_y += self.style.row_height;
     }
@@ -462,11 +258,24 @@ impl<'a> EditableInspector<'a> {
     /// Add a read-only string display.
     pub fn string(&mut self, label: &str, value: &str) {
         let pos = self.field_pos();
+        let value = if self.cell(label, 0, value) { MIXED } else { value };
         display_string(self.ui, label, value, pos, &self.style);
         self.field_index += 1;
         self.current_y += self.style.row_height;
     }
 
+    /// Add an editable string field. A mixed cell shows "—"; committing
+    /// any other text applies it to the whole selection.
+    pub fn text(&mut self, label: &str, value: &str) -> EditResult<String> {
+        let id = FieldId::new(self.component_index, self.field_index, 0);
+        let pos = self.field_pos();
+        let shown = if self.cell(label, 0, value) { MIXED } else { value };
+        let result = edit_text(self.ui, id, label, shown, pos, &self.style);
+        self.field_index += 1;
+        self.current_y += self.style.row_height;
+        result
+    }
+
     /// Add a cycle selector row: `label  [<] value [>]` for choosing among
     /// `count` named values (e.g. enum variants, where a dropdown is not
     /// available).
@@ -484,6 +293,7 @@ impl<'a> EditableInspector<'a> {
         let (label_color, value_color) = (self.style.label_color, self.style.value_color);
         let (row_height, label_width) = (self.style.row_height, self.style.label_width);
         let label_font = self.style.label_font;
+        let value_name = if self.cell(label, 0, index) { MIXED } else { value_name };
         self.ui
             .label_styled(label, glam::Vec2::new(pos.x, pos.y + 4.0), label_color, label_font);
 
@@ -527,7 +337,11 @@ impl<'a> EditableInspector<'a> {
     pub fn color(&mut self, label: &str, value: Vec4) -> EditResult<Vec4> {
         let id = FieldId::new(self.component_index, self.field_index, 0);
         let pos = self.field_pos();
+        for (sub, channel) in value.to_array().into_iter().enumerate() {
+            self.cell(label, sub, channel);
+        }
         let result = edit_color(self.ui, id, label, value, pos, &self.style);
+        let result = self.settle(result, value, 4);
         self.field_index += 1;
         // Color spans two input rows (RG / BA) of color_input_height plus gaps
         self.current_y += self.style.row_height * 1.8;
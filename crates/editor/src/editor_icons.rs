@@ -0,0 +1,52 @@
+//! Editor icons, rasterized at startup into the UI atlas.
+//!
+//! Each icon is a white alpha mask drawn with `UIContext::draw_icon` and a
+//! theme tint. Registered as images, they are packed next to the glyphs,
+//! so an icon draws in the same batch as the panel and text around it.
+//! Icons become drawable the frame after [`register_editor_icons`]; until
+//! then callers draw their rect fallback.
+
+use ui::UIContext;
+
+/// The hierarchy's group (folder) icon.
+pub const FOLDER_ICON: &str = "editor.folder";
+/// Folder icon size in pixels: a tab above a body.
+pub const FOLDER_ICON_SIZE: (u32, u32) = (12, 10);
+
+/// Queue every editor icon for the UI atlas.
+pub fn register_editor_icons(ui: &mut UIContext) {
+    let (width, height) = FOLDER_ICON_SIZE;
+    ui.register_icon_image(FOLDER_ICON, width, height, folder_mask(width, height));
+}
+
+/// A 5-pixel-wide tab two rows tall above a full-width body.
+fn folder_mask(width: u32, height: u32) -> Vec<u8> {
+    mask(width, height, |x, y| y >= 2 || x < 5)
+}
+
+/// White RGBA pixels where `inside`, transparent elsewhere.
+fn mask(width: u32, height: u32, inside: impl Fn(u32, u32) -> bool) -> Vec<u8> {
+    (0..height)
+        .flat_map(|y| (0..width).map(move |x| (x, y)))
+        .flat_map(|(x, y)| if inside(x, y) { [255; 4] } else { [0; 4] })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_folder_mask_has_a_tab_above_its_body() {
+        let (width, height) = FOLDER_ICON_SIZE;
+        let pixels = folder_mask(width, height);
+        assert_eq!(pixels.len(), (width * height * 4) as usize);
+        let alpha = |x: u32, y: u32| pixels[((y * width + x) * 4 + 3) as usize];
+        assert_eq!((alpha(0, 0), alpha(4, 1), alpha(5, 1)), (255, 255, 0), "tab");
+        assert_eq!((alpha(11, 2), alpha(11, 9)), (255, 255), "body");
+
+        let mut ui = UIContext::new();
+        register_editor_icons(&mut ui);
+        assert_eq!(ui.take_icon_images()[0].name, FOLDER_ICON);
+    }
+}
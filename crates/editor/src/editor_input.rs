@@ -24,6 +24,8 @@ pub enum EditorAction {
     FocusSelection,
     /// Reset camera to origin
     ResetCamera,
+    /// Rotate the view while dragging (when viewport rotation is enabled)
+    RotateView,
 
     // ================== Selection ==================
     /// Primary select (click to select)
@@ -46,6 +48,8 @@ pub enum EditorAction {
     ToolRotate,
     /// Scale tool
     ToolScale,
+    /// Measure tool (drag to read distance/angle)
+    ToolMeasure,
 
     // ================== Edit ==================
     /// Delete selected entities
@@ -83,12 +87,18 @@ pub struct EditorInputState {
     pub add_modifier: bool,
     /// Whether toggle-selection modifier is active (Ctrl held)
     pub toggle_modifier: bool,
+    /// Whether view-rotation modifier is active (Alt held)
+    pub rotate_modifier: bool,
     /// Current mouse position (screen coords)
     pub mouse_position: glam::Vec2,
     /// Mouse movement delta
     pub mouse_delta: glam::Vec2,
     /// Mouse scroll delta
     pub scroll_delta: f32,
+    /// Trackpad two-finger scroll in pixels (zero for mouse wheels)
+    pub trackpad_scroll: glam::Vec2,
+    /// Trackpad pinch delta (positive = zoom in)
+    pub pinch_delta: f32,
     /// Primary mouse button (left) state
     pub primary_button: ButtonState,
     /// Secondary mouse button (right) state
@@ -141,6 +151,8 @@ impl EditorInputMapping {
         self.bind(EditorAction::ResetCamera, InputSource::Keyboard(KeyCode::Home));
         self.bind(EditorAction::Pan, InputSource::Keyboard(KeyCode::Space));
         self.bind(EditorAction::Pan, InputSource::Mouse(MouseButton::Middle));
+        self.bind(EditorAction::RotateView, InputSource::Keyboard(KeyCode::AltLeft));
+        self.bind(EditorAction::RotateView, InputSource::Keyboard(KeyCode::AltRight));
 
         // Selection modifiers
         self.bind(EditorAction::AddToSelection, InputSource::Keyboard(KeyCode::ShiftLeft));
@@ -153,11 +165,12 @@ impl EditorInputMapping {
         // Primary select
         self.bind(EditorAction::Select, InputSource::Mouse(MouseButton::Left));
 
-        // Tools (Q, W, E, R like most editors)
+        // Tools (Q, W, E, R like most editors; M to measure)
         self.bind(EditorAction::ToolSelect, InputSource::Keyboard(KeyCode::KeyQ));
         self.bind(EditorAction::ToolMove, InputSource::Keyboard(KeyCode::KeyW));
         self.bind(EditorAction::ToolRotate, InputSource::Keyboard(KeyCode::KeyE));
         self.bind(EditorAction::ToolScale, InputSource::Keyboard(KeyCode::KeyR));
+        self.bind(EditorAction::ToolMeasure, InputSource::Keyboard(KeyCode::KeyM));
 
         // Edit operations
         self.bind(EditorAction::Delete, InputSource::Keyboard(KeyCode::Delete));
@@ -216,14 +229,18 @@ impl EditorInputMapping {
     pub fn update_state(&self, input: &InputHandler) -> EditorInputState {
         let mouse_pos = input.mouse_position();
         let mouse_delta = input.mouse_movement_delta();
+        let trackpad_scroll = input.trackpad_scroll_delta();
 
         EditorInputState {
             pan_modifier: self.is_action_pressed(EditorAction::Pan, input),
             add_modifier: self.is_action_pressed(EditorAction::AddToSelection, input),
             toggle_modifier: self.is_action_pressed(EditorAction::ToggleSelection, input),
+            rotate_modifier: self.is_action_pressed(EditorAction::RotateView, input),
             mouse_position: glam::Vec2::new(mouse_pos.x, mouse_pos.y),
             mouse_delta: glam::Vec2::new(mouse_delta.0, mouse_delta.1),
             scroll_delta: input.mouse_wheel_delta(),
+            trackpad_scroll: glam::Vec2::new(trackpad_scroll.0, trackpad_scroll.1),
+            pinch_delta: input.pinch_delta(),
             primary_button: ButtonState {
                 pressed: input.is_mouse_button_pressed(MouseButton::Left),
                 just_pressed: input.is_mouse_button_just_pressed(MouseButton::Left),
@@ -1,10 +1,16 @@
 //! Editor preferences for persisting editor state across sessions.
 //!
-//! Stores camera position, zoom level, last opened scene, and grid settings.
+//! Stores camera position, zoom level, last opened scene, grid settings,
+//! viewport navigation settings (pan/zoom/rotate/trackpad), camera
+//! bookmarks, the scene-view color scheme, and ruler visibility.
 
 use serde::{Deserialize, Serialize};
 use std::path::Path;
 
+use crate::camera_bookmarks::CameraBookmarks;
+use crate::viewport_input::ViewportInputConfig;
+use crate::{EditorColorScheme, EditorContext};
+
 /// Persistent editor preferences saved between sessions.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EditorPreferences {
@@ -18,6 +24,26 @@ pub struct EditorPreferences {
     pub snap_to_grid: bool,
     /// Grid cell size
     pub grid_size: f32,
+    /// Viewport navigation settings (pan mode, zoom sensitivity, rotation,
+    /// trackpad gestures). Absent in older files → defaults.
+    #[serde(default)]
+    pub navigation: ViewportInputConfig,
+    /// Numbered camera bookmarks (slot → camera). Absent in older files →
+    /// none.
+    #[serde(default)]
+    pub camera_bookmarks: CameraBookmarks,
+    /// Scene-view overlay colors (gizmos, grid, outlines, play border).
+    /// Absent in older files → the standard scheme.
+    #[serde(default)]
+    pub color_scheme: EditorColorScheme,
+    /// Whether the scene-view rulers are shown. Absent in older files →
+    /// shown.
+    #[serde(default = "default_show_rulers")]
+    pub show_rulers: bool,
+}
+
+fn default_show_rulers() -> bool {
+    true
 }
 
 impl Default for EditorPreferences {
@@ -28,6 +54,10 @@ impl Default for EditorPreferences {
             last_scene_path: None,
             snap_to_grid: false,
             grid_size: 32.0,
+            navigation: ViewportInputConfig::default(),
+            camera_bookmarks: CameraBookmarks::default(),
+            color_scheme: EditorColorScheme::default(),
+            show_rulers: default_show_rulers(),
         }
     }
 }
@@ -43,6 +73,40 @@ impl EditorPreferences {
         }
     }
 
+    /// Capture the persistable parts of the current editor state.
+    pub fn capture(editor: &EditorContext) -> Self {
+        let camera = editor.camera_offset();
+        Self {
+            camera_position: (camera.x, camera.y),
+            camera_zoom: editor.camera_zoom(),
+            last_scene_path: editor
+                .scene_path()
+                .map(|p| p.to_string_lossy().into_owned()),
+            snap_to_grid: editor.is_snap_to_grid(),
+            grid_size: editor.grid_size(),
+            navigation: editor.viewport_input.config.clone(),
+            camera_bookmarks: editor.camera_bookmarks.clone(),
+            color_scheme: editor.color_scheme().clone(),
+            show_rulers: editor.rulers.is_visible(),
+        }
+    }
+
+    /// Apply the camera, grid, navigation, bookmark, color-scheme and
+    /// ruler settings to an editor.
+    ///
+    /// `last_scene_path` is left for the caller — reopening a scene needs
+    /// the world and asset manager.
+    pub fn apply(&self, editor: &mut EditorContext) {
+        editor.set_camera_offset(glam::Vec2::new(self.camera_position.0, self.camera_position.1));
+        editor.set_camera_zoom(self.camera_zoom);
+        editor.set_snap_to_grid(self.snap_to_grid);
+        editor.set_grid_size(self.grid_size);
+        editor.viewport_input.config = self.navigation.clone();
+        editor.camera_bookmarks = self.camera_bookmarks.clone();
+        editor.set_color_scheme(self.color_scheme.clone());
+        editor.rulers.set_visible(self.show_rulers);
+    }
+
     /// Save preferences to a JSON file.
     pub fn save(&self, path: &Path) -> Result<(), String> {
         let json = serde_json::to_string_pretty(self)
@@ -74,6 +138,13 @@ mod tests {
             last_scene_path: Some("scenes/test.ron".to_string()),
             snap_to_grid: true,
             grid_size: 64.0,
+            navigation: ViewportInputConfig {
+                rotation_enabled: true,
+                ..Default::default()
+            },
+            camera_bookmarks: CameraBookmarks::default(),
+            color_scheme: crate::ColorSchemePreset::ColorBlindSafe.scheme(),
+            show_rulers: false,
         };
 
         let temp_dir = std::env::temp_dir();
@@ -87,6 +158,9 @@ mod tests {
         assert_eq!(loaded.last_scene_path, Some("scenes/test.ron".to_string()));
         assert!(loaded.snap_to_grid);
         assert_eq!(loaded.grid_size, 64.0);
+        assert!(loaded.navigation.rotation_enabled);
+        assert_eq!(loaded.color_scheme, crate::ColorSchemePreset::ColorBlindSafe.scheme());
+        assert!(!loaded.show_rulers);
 
         // Cleanup
         let _ = std::fs::remove_file(&path);
@@ -97,4 +171,47 @@ mod tests {
         let prefs = EditorPreferences::load(Path::new("/nonexistent/path.json"));
         assert_eq!(prefs.camera_zoom, 1.0); // Should return defaults
     }
+
+    #[test]
+    fn test_editor_preferences_without_navigation_use_defaults() {
+        let json = r#"{"camera_position":[0.0,0.0],"camera_zoom":1.0,
+            "last_scene_path":null,"snap_to_grid":false,"grid_size":32.0}"#;
+        let prefs: EditorPreferences = serde_json::from_str(json).expect("parse");
+        assert_eq!(prefs.navigation, ViewportInputConfig::default());
+        assert!(prefs.camera_bookmarks.is_empty());
+        assert!(prefs.show_rulers);
+    }
+
+    #[test]
+    fn test_editor_preferences_apply_and_capture_roundtrip() {
+        let prefs = EditorPreferences {
+            camera_position: (40.0, -20.0),
+            camera_zoom: 2.0,
+            snap_to_grid: true,
+            grid_size: 16.0,
+            navigation: ViewportInputConfig {
+                pan_mode: crate::viewport_input::PanMode::SpaceDrag,
+                zoom_factor: 1.25,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let mut editor = EditorContext::new();
+        prefs.apply(&mut editor);
+        editor.set_camera_offset(glam::Vec2::new(5.0, 6.0));
+        assert!(editor.store_camera_bookmark(2));
+        editor.set_camera_offset(glam::Vec2::new(40.0, -20.0));
+
+        let captured = EditorPreferences::capture(&editor);
+        assert_eq!(captured.camera_position, (40.0, -20.0));
+        assert_eq!(captured.camera_zoom, 2.0);
+        assert!(captured.snap_to_grid);
+        assert_eq!(captured.grid_size, 16.0);
+        assert_eq!(captured.navigation, prefs.navigation);
+        let json = serde_json::to_string(&captured).expect("serialize");
+        let reloaded: EditorPreferences = serde_json::from_str(&json).expect("parse");
+        let bookmark = reloaded.camera_bookmarks.get(2).expect("bookmark 2 persisted");
+        assert_eq!(bookmark.position, (5.0, 6.0));
+        assert_eq!(bookmark.zoom, 2.0);
+    }
 }
@@ -0,0 +1,123 @@
+//! Editable inspectors for the physics effectors (`AreaForce`, `Buoyancy`,
+//! `Drag`). Like collider shapes, a zone's shape kind is fixed and only its
+//! dimensions are editable.
+
+use physics::{AreaForce, Buoyancy, Drag, ZoneShape};
+
+use crate::component_editors::{ranges, ComponentEdit};
+use crate::editable_inspector::{EditResult, EditableInspector};
+use crate::InspectorExtras;
+
+/// Shape rows shared by the effector editors; `Some` when resized.
+fn edit_zone_shape(inspector: &mut EditableInspector<'_>, shape: ZoneShape) -> Option<ZoneShape> {
+    match shape {
+        ZoneShape::Rect { half_extents } => {
+            inspector.header("  Shape: Rect");
+            match inspector.vec2("Half Extents", half_extents, ranges::ZONE_EXTENT) {
+                EditResult::Changed(v) => Some(ZoneShape::Rect { half_extents: v }),
+                EditResult::Unchanged => None,
+            }
+        }
+        ZoneShape::Circle { radius } => {
+            inspector.header("  Shape: Circle");
+            match inspector.f32("Radius", radius, ranges::ZONE_EXTENT) {
+                EditResult::Changed(v) => Some(ZoneShape::Circle { radius: v }),
+                EditResult::Unchanged => None,
+            }
+        }
+    }
+}
+
+/// Edit an AreaForce component.
+pub fn edit_area_force(
+    inspector: &mut EditableInspector<'_>,
+    area: &AreaForce,
+    _extras: &mut InspectorExtras<'_>,
+) -> Option<ComponentEdit<AreaForce>> {
+    let mut new = area.clone();
+    let mut hint = None;
+
+    inspector.header("AreaForce");
+    if let Some(shape) = edit_zone_shape(inspector, area.shape) {
+        new.shape = shape;
+        hint = Some("shape");
+    }
+    if let EditResult::Changed(v) = inspector.vec2("Force", area.force, ranges::AREA_FORCE) {
+        new.force = v;
+        hint = Some("force");
+    }
+
+    hint.map(|field_hint| ComponentEdit { new_value: new, field_hint })
+}
+
+/// Edit a Buoyancy component.
+pub fn edit_buoyancy(
+    inspector: &mut EditableInspector<'_>,
+    fluid: &Buoyancy,
+    _extras: &mut InspectorExtras<'_>,
+) -> Option<ComponentEdit<Buoyancy>> {
+    let mut new = fluid.clone();
+    let mut hint = None;
+
+    inspector.header("Buoyancy");
+    if let Some(shape) = edit_zone_shape(inspector, fluid.shape) {
+        new.shape = shape;
+        hint = Some("shape");
+    }
+    if let EditResult::Changed(v) = inspector.f32("Surface", fluid.surface, ranges::POSITION) {
+        new.surface = v;
+        hint = Some("surface");
+    }
+    if let EditResult::Changed(v) = inspector.f32("Density", fluid.density, ranges::FLUID_DENSITY) {
+        new.density = v;
+        hint = Some("density");
+    }
+    if let EditResult::Changed(v) = inspector.f32("Drag", fluid.drag, ranges::ZONE_DAMPING) {
+        new.drag = v;
+        hint = Some("drag");
+    }
+
+    hint.map(|field_hint| ComponentEdit { new_value: new, field_hint })
+}
+
+/// Edit a Drag component.
+pub fn edit_drag(
+    inspector: &mut EditableInspector<'_>,
+    drag: &Drag,
+    _extras: &mut InspectorExtras<'_>,
+) -> Option<ComponentEdit<Drag>> {
+    let mut new = drag.clone();
+    let mut hint = None;
+
+    inspector.header("Drag");
+    if let Some(shape) = edit_zone_shape(inspector, drag.shape) {
+        new.shape = shape;
+        hint = Some("shape");
+    }
+    if let EditResult::Changed(v) = inspector.f32("Damping", drag.damping, ranges::ZONE_DAMPING) {
+        new.damping = v;
+        hint = Some("damping");
+    }
+
+    hint.map(|field_hint| ComponentEdit { new_value: new, field_hint })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_effector_defaults_fit_the_editor_ranges() {
+        let extent = |shape: ZoneShape| match shape {
+            ZoneShape::Rect { half_extents } => half_extents.min_element(),
+            ZoneShape::Circle { radius } => radius,
+        };
+        let fluid = Buoyancy::default();
+        assert!(ranges::ZONE_EXTENT.contains(&extent(fluid.shape)));
+        assert!(ranges::POSITION.contains(&fluid.surface));
+        assert!(ranges::FLUID_DENSITY.contains(&fluid.density));
+        assert!(ranges::ZONE_DAMPING.contains(&Drag::default().damping));
+        let force = AreaForce::default().force;
+        assert!(ranges::AREA_FORCE.contains(&force.x) && ranges::AREA_FORCE.contains(&force.y));
+    }
+}
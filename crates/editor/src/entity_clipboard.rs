@@ -0,0 +1,243 @@
+//! Entity clipboard: cut, copy and paste entities together with their
+//! children (Edit menu, Ctrl+X / Ctrl+C / Ctrl+V).
+//!
+//! Copies are held as serialized JSON — each entity's inspectable
+//! components, its name and its parent within the copy — rather than live
+//! ids, so they outlive the world they came from: paste into another open
+//! scene tab, or later in the session. Pasted entities get fresh ids and
+//! GUIDs; the component values and the hierarchy are preserved.
+
+use serde::{Deserialize, Serialize};
+
+use ecs::{EntityId, Name, World, WorldHierarchyExt};
+
+use crate::commands::{CreateEntitiesCommand, DeleteEntityCommand, EditorCommand, MacroCommand};
+use crate::component_clipboard::ClipboardEntry;
+use crate::stored_component::{capture_all_components, restore_components, StoredComponent};
+
+/// One copied entity.
+#[derive(Debug, Serialize, Deserialize)]
+struct CopiedEntity {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    /// Index of the parent within the copy (`None` for a copied root)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    parent: Option<usize>,
+    components: Vec<ClipboardEntry>,
+}
+
+/// The clipboard's serialized form; parents precede their children.
+#[derive(Debug, Serialize, Deserialize)]
+struct CopiedEntities {
+    entities: Vec<CopiedEntity>,
+}
+
+/// Editor-internal clipboard holding copied entity subtrees.
+#[derive(Debug, Clone, Default)]
+pub struct EntityClipboard {
+    /// Serialized [`CopiedEntities`]
+    text: Option<String>,
+    /// Number of copied subtrees, cached for status messages
+    roots: usize,
+}
+
+impl EntityClipboard {
+    /// An empty clipboard.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether nothing has been copied yet.
+    pub fn is_empty(&self) -> bool {
+        self.text.is_none()
+    }
+
+    /// Number of copied subtrees.
+    pub fn len(&self) -> usize {
+        self.roots
+    }
+
+    /// The clipboard's serialized text.
+    pub fn text(&self) -> Option<&str> {
+        self.text.as_deref()
+    }
+
+    /// Replace the clipboard with serialized text (as returned by
+    /// [`text`](Self::text)); rejected unless it parses.
+    pub fn set_text(&mut self, text: &str) -> Result<(), String> {
+        let copied = parse(text)?;
+        self.roots = copied.entities.iter().filter(|e| e.parent.is_none()).count();
+        self.text = Some(text.to_string());
+        Ok(())
+    }
+
+    /// Copy `entities` with all their descendants. Entities whose ancestor
+    /// is also listed travel with that ancestor. Returns the number of
+    /// copied subtrees.
+    pub fn copy(&mut self, world: &World, entities: &[EntityId]) -> Result<usize, String> {
+        let roots = subtree_roots(world, entities);
+        if roots.is_empty() {
+            return Err("Nothing selected to copy".to_string());
+        }
+        let mut copied = CopiedEntities { entities: Vec::new() };
+        for &root in &roots {
+            copy_subtree(world, root, None, &mut copied.entities);
+        }
+        self.text = Some(serde_json::to_string(&copied).map_err(|e| e.to_string())?);
+        self.roots = roots.len();
+        Ok(self.roots)
+    }
+
+    /// Copy `entities` (as [`copy`](Self::copy)) and return the command
+    /// deleting them with their descendants, for the caller to execute.
+    pub fn cut(&mut self, world: &World, entities: &[EntityId]) -> Result<Box<dyn EditorCommand>, String> {
+        self.copy(world, entities)?;
+        // Deepest first, so undo restores each parent before its children
+        let mut commands: Vec<Box<dyn EditorCommand>> = Vec::new();
+        for root in subtree_roots(world, entities) {
+            for entity in world.get_descendants(root).into_iter().rev().chain([root]) {
+                commands.push(Box::new(DeleteEntityCommand::new(entity)));
+            }
+        }
+        Ok(Box::new(MacroCommand::new("Cut Entities", commands)))
+    }
+
+    /// Create the copied entities in `world`, roots under `parent` (or as
+    /// roots). Returns the new root entities and the already-executed
+    /// command to record for undo.
+    pub fn paste(
+        &self,
+        world: &mut World,
+        parent: Option<EntityId>,
+    ) -> Result<(Vec<EntityId>, CreateEntitiesCommand), String> {
+        let copied = parse(self.text.as_deref().ok_or("Clipboard is empty")?)?;
+        // Rebuild every component before touching the world
+        let mut entities = Vec::with_capacity(copied.entities.len());
+        for entity in copied.entities {
+            let components = entity
+                .components
+                .into_iter()
+                .map(ClipboardEntry::into_component)
+                .collect::<Result<Vec<StoredComponent>, String>>()?;
+            if entity.parent.is_some_and(|index| index >= entities.len()) {
+                return Err("Copied entities are out of order".to_string());
+            }
+            entities.push((entity.name, entity.parent, components));
+        }
+
+        let mut created: Vec<EntityId> = Vec::with_capacity(entities.len());
+        let mut roots = Vec::new();
+        for (name, parent_index, components) in entities {
+            let entity = world.create_entity();
+            restore_components(world, entity, &components);
+            if let Some(name) = name {
+                world.add_component(&entity, Name::new(name)).ok();
+            }
+            if let Some(new_parent) = parent_index.and_then(|index| created.get(index)).copied().or(parent) {
+                world.set_parent(entity, new_parent).ok();
+            }
+            if parent_index.is_none() {
+                roots.push(entity);
+            }
+            created.push(entity);
+        }
+        let command = CreateEntitiesCommand::already_created(world, "Paste Entities", created);
+        Ok((roots, command))
+    }
+}
+
+fn parse(text: &str) -> Result<CopiedEntities, String> {
+    serde_json::from_str(text).map_err(|e| format!("Not copied entities: {e}"))
+}
+
+/// Live entities of `entities` without a listed ancestor, in id order.
+fn subtree_roots(world: &World, entities: &[EntityId]) -> Vec<EntityId> {
+    let mut roots: Vec<EntityId> = entities
+        .iter()
+        .copied()
+        .filter(|&e| world.validate_entity(&e).is_ok())
+        .filter(|&e| !entities.iter().any(|&other| other != e && world.is_ancestor_of(other, e)))
+        .collect();
+    roots.sort_by_key(|e| e.value());
+    roots.dedup();
+    roots
+}
+
+/// Append `entity` and its descendants (pre-order) to `out`.
+fn copy_subtree(world: &World, entity: EntityId, parent: Option<usize>, out: &mut Vec<CopiedEntity>) {
+    let index = out.len();
+    out.push(CopiedEntity {
+        name: world.get::<Name>(entity).map(|name| name.as_str().to_string()),
+        parent,
+        components: capture_all_components(world, entity)
+            .iter()
+            .filter_map(ClipboardEntry::from_component)
+            .collect(),
+    });
+    let children = world.get_children(entity).map(|c| c.to_vec()).unwrap_or_default();
+    for child in children {
+        copy_subtree(world, child, Some(index), out);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::CommandHistory;
+    use physics::components::Collider;
+
+    #[test]
+    fn test_copy_paste_subtree_into_another_world() {
+        let mut source = World::new();
+        let ship = source.create_entity();
+        source.add_component(&ship, Name::new("Ship")).unwrap();
+        source.add_component(&ship, Collider::circle_collider(8.0)).unwrap();
+        let turret = source.create_entity();
+        source.add_component(&turret, Name::new("Turret")).unwrap();
+        source.set_parent(turret, ship).unwrap();
+
+        let mut clipboard = EntityClipboard::new();
+        // The child travels with its selected parent
+        assert_eq!(clipboard.copy(&source, &[turret, ship]), Ok(1));
+
+        let mut target = World::new();
+        let mut history = CommandHistory::new();
+        let (roots, command) = clipboard.paste(&mut target, None).unwrap();
+        history.push_already_executed(Box::new(command));
+        let pasted = roots[0];
+        assert_eq!(target.get::<Name>(pasted).map(|n| n.as_str()), Some("Ship"));
+        assert!(target.get::<Collider>(pasted).is_some());
+        let children = target.get_children(pasted).unwrap().to_vec();
+        assert_eq!(target.get::<Name>(children[0]).map(|n| n.as_str()), Some("Turret"));
+
+        // Redo brings the hierarchy back, not just the entities
+        history.undo(&mut target);
+        assert_eq!(target.entity_count(), 0);
+        history.redo(&mut target);
+        assert_eq!(target.get_children(pasted), Some(&children[..]));
+    }
+
+    #[test]
+    fn test_cut_removes_subtree_and_undo_restores_it() {
+        let mut world = World::new();
+        let parent = world.create_entity();
+        let child = world.create_entity();
+        world.set_parent(child, parent).unwrap();
+
+        let mut clipboard = EntityClipboard::new();
+        let mut history = CommandHistory::new();
+        let cut = clipboard.cut(&world, &[parent]).unwrap();
+        history.execute(cut, &mut world);
+        assert_eq!(world.entity_count(), 0);
+
+        history.undo(&mut world);
+        assert_eq!(world.get_parent(child), Some(parent));
+
+        // The text form survives, e.g. for a later session
+        let mut restored = EntityClipboard::new();
+        restored.set_text(clipboard.text().unwrap()).unwrap();
+        assert_eq!(restored.len(), 1);
+        assert!(restored.set_text("{}").is_err());
+        assert!(EntityClipboard::new().copy(&world, &[]).is_err());
+    }
+}
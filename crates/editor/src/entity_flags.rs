@@ -0,0 +1,254 @@
+//! Per-entity editor flags: hidden (the hierarchy eye toggle) and locked
+//! (the lock toggle).
+//!
+//! These are editor metadata, not components — the game never sees them.
+//! A flag set on an entity also covers its descendants. Hidden entities are
+//! left out of scene-view rendering and picking; locked entities can still
+//! be selected, but the gizmo and collider handles won't move them. Both
+//! sets are saved in the scene's editor settings block by entity GUID.
+//! Whole render layers can be hidden too (the layers panel eye toggle);
+//! those are saved by layer name.
+
+use std::collections::{BTreeSet, HashSet};
+
+use ecs::sprite_components::Sprite;
+use ecs::{EntityId, Guid, World, WorldHierarchyExt};
+
+/// A set of flagged entities, shared by [`EditorVisibility`] and
+/// [`EditorLock`].
+#[derive(Debug, Clone, Default)]
+struct EntityFlags {
+    entities: HashSet<EntityId>,
+}
+
+impl EntityFlags {
+    fn set(&mut self, entity: EntityId, on: bool) {
+        if on {
+            self.entities.insert(entity);
+        } else {
+            self.entities.remove(&entity);
+        }
+    }
+
+    fn toggle(&mut self, entity: EntityId) -> bool {
+        let on = !self.entities.contains(&entity);
+        self.set(entity, on);
+        on
+    }
+
+    /// Flagged itself or through an ancestor.
+    fn covers(&self, world: &World, entity: EntityId) -> bool {
+        std::iter::successors(Some(entity), |&e| world.get_parent(e)).any(|e| self.entities.contains(&e))
+    }
+
+    fn covered(&self, world: &World) -> HashSet<EntityId> {
+        if self.entities.is_empty() {
+            return HashSet::new();
+        }
+        world.entities().into_iter().filter(|&e| self.covers(world, e)).collect()
+    }
+
+    /// GUIDs of the live flagged entities, sorted so saves are stable.
+    fn guids(&self, world: &World) -> Vec<Guid> {
+        let mut guids: Vec<Guid> = self.entities.iter().filter_map(|&e| world.guid_of(e)).collect();
+        guids.sort();
+        guids
+    }
+
+    fn load(&mut self, world: &World, guids: &[Guid]) {
+        self.entities = guids.iter().filter_map(|&guid| world.entity_by_guid(guid)).collect();
+    }
+}
+
+/// Entities and render layers hidden in the scene view.
+#[derive(Debug, Clone, Default)]
+pub struct EditorVisibility {
+    hidden: EntityFlags,
+    hidden_layers: BTreeSet<String>,
+}
+
+impl EditorVisibility {
+    /// Nothing hidden.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether `entity` itself is flagged hidden.
+    pub fn is_hidden(&self, entity: EntityId) -> bool {
+        self.hidden.entities.contains(&entity)
+    }
+
+    /// Whether `entity` or one of its ancestors is hidden.
+    pub fn is_hidden_in(&self, world: &World, entity: EntityId) -> bool {
+        self.hidden.covers(world, entity)
+    }
+
+    /// Hide or show `entity`.
+    pub fn set_hidden(&mut self, entity: EntityId, hidden: bool) {
+        self.hidden.set(entity, hidden);
+    }
+
+    /// Flip `entity`'s hidden flag. Returns the new state.
+    pub fn toggle(&mut self, entity: EntityId) -> bool {
+        self.hidden.toggle(entity)
+    }
+
+    /// Whether no entity or layer is hidden.
+    pub fn is_empty(&self) -> bool {
+        self.hidden.entities.is_empty() && self.hidden_layers.is_empty()
+    }
+
+    /// Every entity the scene view should skip: the flagged ones, their
+    /// descendants, and sprites on hidden layers.
+    pub fn hidden_entities(&self, world: &World) -> HashSet<EntityId> {
+        let mut hidden = self.hidden.covered(world);
+        if !self.hidden_layers.is_empty() {
+            hidden.extend(world.entities().into_iter().filter(|&entity| {
+                world.get::<Sprite>(entity).is_some_and(|sprite| self.hidden_layers.contains(&sprite.layer))
+            }));
+        }
+        hidden
+    }
+
+    /// Whether the render layer `name` is hidden.
+    pub fn is_layer_hidden(&self, name: &str) -> bool {
+        self.hidden_layers.contains(name)
+    }
+
+    /// Flip a render layer's hidden flag. Returns the new state.
+    pub fn toggle_layer(&mut self, name: &str) -> bool {
+        let hidden = !self.hidden_layers.remove(name);
+        if hidden {
+            self.hidden_layers.insert(name.to_string());
+        }
+        hidden
+    }
+
+    /// Hidden layer names to save in the scene's editor settings, sorted.
+    pub fn hidden_layers(&self) -> Vec<String> {
+        self.hidden_layers.iter().cloned().collect()
+    }
+
+    /// Replace the hidden layers from saved names.
+    pub fn load_hidden_layers(&mut self, names: &[String]) {
+        self.hidden_layers = names.iter().cloned().collect();
+    }
+
+    /// GUIDs to save in the scene's editor settings.
+    pub fn to_guids(&self, world: &World) -> Vec<Guid> {
+        self.hidden.guids(world)
+    }
+
+    /// Replace the hidden set from saved GUIDs (unknown GUIDs are dropped).
+    pub fn load_guids(&mut self, world: &World, guids: &[Guid]) {
+        self.hidden.load(world, guids);
+    }
+}
+
+/// Entities locked against being moved in the scene view.
+#[derive(Debug, Clone, Default)]
+pub struct EditorLock {
+    locked: EntityFlags,
+}
+
+impl EditorLock {
+    /// Nothing locked.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether `entity` itself is flagged locked.
+    pub fn is_locked(&self, entity: EntityId) -> bool {
+        self.locked.entities.contains(&entity)
+    }
+
+    /// Whether `entity` or one of its ancestors is locked.
+    pub fn is_locked_in(&self, world: &World, entity: EntityId) -> bool {
+        self.locked.covers(world, entity)
+    }
+
+    /// Lock or unlock `entity`.
+    pub fn set_locked(&mut self, entity: EntityId, locked: bool) {
+        self.locked.set(entity, locked);
+    }
+
+    /// Flip `entity`'s lock flag. Returns the new state.
+    pub fn toggle(&mut self, entity: EntityId) -> bool {
+        self.locked.toggle(entity)
+    }
+
+    /// Whether no entity is flagged.
+    pub fn is_empty(&self) -> bool {
+        self.locked.entities.is_empty()
+    }
+
+    /// GUIDs to save in the scene's editor settings.
+    pub fn to_guids(&self, world: &World) -> Vec<Guid> {
+        self.locked.guids(world)
+    }
+
+    /// Replace the locked set from saved GUIDs (unknown GUIDs are dropped).
+    pub fn load_guids(&mut self, world: &World, guids: &[Guid]) {
+        self.locked.load(world, guids);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hidden_flag_covers_descendants() {
+        let mut world = World::new();
+        let parent = world.create_entity();
+        let child = world.create_entity();
+        let other = world.create_entity();
+        world.set_parent(child, parent).unwrap();
+
+        let mut visibility = EditorVisibility::new();
+        assert!(visibility.toggle(parent));
+        assert!(visibility.is_hidden(parent) && !visibility.is_hidden(child));
+        assert!(visibility.is_hidden_in(&world, child), "children of a hidden entity are hidden");
+        assert!(!visibility.is_hidden_in(&world, other));
+        assert_eq!(visibility.hidden_entities(&world), HashSet::from([parent, child]));
+
+        assert!(!visibility.toggle(parent));
+        assert!(visibility.is_empty());
+    }
+
+    #[test]
+    fn test_hidden_layer_hides_its_sprites() {
+        let mut world = World::new();
+        let background = world.create_entity();
+        let player = world.create_entity();
+        world.add_component(&background, Sprite::new(0).with_layer("Background")).unwrap();
+        world.add_component(&player, Sprite::new(0)).unwrap();
+
+        let mut visibility = EditorVisibility::new();
+        assert!(visibility.toggle_layer("Background"));
+        assert!(!visibility.is_empty());
+        assert_eq!(visibility.hidden_entities(&world), HashSet::from([background]));
+        assert_eq!(visibility.hidden_layers(), ["Background"]);
+
+        assert!(!visibility.toggle_layer("Background"));
+        assert!(visibility.is_empty() && visibility.hidden_entities(&world).is_empty());
+    }
+
+    #[test]
+    fn test_lock_flags_round_trip_through_guids() {
+        let mut world = World::new();
+        let a = world.create_entity();
+        let b = world.create_entity();
+        let mut locks = EditorLock::new();
+        locks.set_locked(a, true);
+        locks.set_locked(b, true);
+        let guids = locks.to_guids(&world);
+        assert_eq!(guids.len(), 2);
+
+        world.remove_entity(&b).unwrap();
+        let mut reloaded = EditorLock::new();
+        reloaded.load_guids(&world, &guids);
+        assert!(reloaded.is_locked(a));
+        assert!(!reloaded.is_locked(b), "GUIDs of removed entities are dropped");
+    }
+}
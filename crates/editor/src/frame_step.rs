@@ -0,0 +1,96 @@
+//! Frame stepping for debugging a paused play session.
+//!
+//! While paused, the play controls' Step buttons queue single frames; the
+//! integration layer runs one queued frame per editor frame — one game
+//! update plus exactly one physics tick, with a fixed delta — so a
+//! collision can be walked through tick by tick. The frame index counts
+//! every frame the game ran in this play session and is shown in the
+//! status bar.
+
+/// Frames the Step N button queues by default.
+pub const DEFAULT_STEP_COUNT: u32 = 10;
+
+/// Largest Step N the play controls accept.
+pub const MAX_STEP_COUNT: u32 = 1000;
+
+/// Frame counter and queued single steps of the current play session
+/// (`EditorContext::frame_stepper`).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FrameStepper {
+    /// Frames the game has run this play session
+    frame: u64,
+    /// Single steps still to run
+    pending: u32,
+}
+
+impl FrameStepper {
+    /// A stepper at frame 0 with nothing queued.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start over (on Play from editing, and on Stop).
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Queue `frames` single steps.
+    pub fn request(&mut self, frames: u32) {
+        self.pending = self.pending.saturating_add(frames);
+    }
+
+    /// Drop queued steps (on Resume, which runs freely anyway).
+    pub fn cancel(&mut self) {
+        self.pending = 0;
+    }
+
+    /// Consume one queued step; `false` when none is queued.
+    pub fn take_step(&mut self) -> bool {
+        if self.pending == 0 {
+            return false;
+        }
+        self.pending -= 1;
+        true
+    }
+
+    /// Count a frame the game ran in (stepped or playing).
+    pub fn record_frame(&mut self) {
+        self.frame += 1;
+    }
+
+    /// Frames the game has run this play session.
+    pub fn frame(&self) -> u64 {
+        self.frame
+    }
+
+    /// Single steps still queued.
+    pub fn pending(&self) -> u32 {
+        self.pending
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_queued_steps_run_one_per_take_and_count_frames() {
+        let mut stepper = FrameStepper::new();
+        assert!(!stepper.take_step(), "nothing queued");
+
+        stepper.request(1);
+        stepper.request(2);
+        let mut ran = 0;
+        while stepper.take_step() {
+            stepper.record_frame();
+            ran += 1;
+        }
+        assert_eq!((ran, stepper.frame(), stepper.pending()), (3, 3, 0));
+
+        stepper.request(DEFAULT_STEP_COUNT);
+        stepper.cancel();
+        assert!(!stepper.take_step());
+        stepper.reset();
+        assert_eq!(stepper, FrameStepper::new());
+    }
+}
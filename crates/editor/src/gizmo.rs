@@ -84,8 +84,8 @@ impl Default for GizmoInteraction {
 
 /// Complete color set for gizmo rendering.
 ///
-/// Defaults match the editor's dark theme; `EditorTheme::gizmo_palette()`
-/// produces a themed instance.
+/// Defaults match the standard color scheme;
+/// `EditorColorScheme::gizmo_palette()` produces a themed instance.
 #[derive(Debug, Clone)]
 pub struct GizmoPalette {
     /// X axis line and handle
@@ -114,19 +114,7 @@ pub struct GizmoPalette {
 
 impl Default for GizmoPalette {
     fn default() -> Self {
-        Self {
-            x: Color::new(0.9, 0.2, 0.2, 1.0),      // Red
-            y: Color::new(0.2, 0.9, 0.2, 1.0),      // Green
-            center: Color::new(0.9, 0.9, 0.2, 1.0), // Yellow
-            x_hover: Color::new(1.0, 0.4, 0.4, 1.0),
-            y_hover: Color::new(0.4, 1.0, 0.4, 1.0),
-            center_hover: Color::new(1.0, 1.0, 0.4, 1.0),
-            ring: Color::new(0.3, 0.3, 0.9, 1.0),
-            rotation_indicator: Color::new(0.9, 0.9, 0.9, 1.0),
-            scale_outline: Color::new(0.6, 0.6, 0.6, 1.0),
-            scale_handle: Color::new(0.7, 0.7, 0.7, 1.0),
-            scale_handle_hover: Color::new(0.9, 0.9, 0.4, 1.0),
-        }
+        crate::EditorColorScheme::standard().gizmo_palette()
     }
 }
 
@@ -207,7 +195,12 @@ impl Gizmo {
 
     /// Apply colors from the editor theme.
     pub fn apply_theme(&mut self, theme: &EditorTheme) {
-        self.palette = theme.gizmo_palette();
+        self.palette = theme.colors.gizmo_palette();
+    }
+
+    /// The colors the gizmo draws with.
+    pub fn palette(&self) -> &GizmoPalette {
+        &self.palette
     }
 
     /// Set the entity rotation (for rotation gizmo display).
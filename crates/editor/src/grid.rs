@@ -22,12 +22,7 @@ pub struct GridColors {
 
 impl Default for GridColors {
     fn default() -> Self {
-        Self {
-            primary: Vec4::new(0.3, 0.3, 0.3, 0.5),
-            secondary: Vec4::new(0.25, 0.25, 0.25, 0.3),
-            axis_x: Vec4::new(0.8, 0.2, 0.2, 0.8),
-            axis_y: Vec4::new(0.2, 0.8, 0.2, 0.8),
-        }
+        crate::EditorColorScheme::standard().grid_colors()
     }
 }
 
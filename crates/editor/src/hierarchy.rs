@@ -1,17 +1,24 @@
 //! Hierarchy panel for displaying entity tree structure.
 //!
 //! The HierarchyPanel displays all entities in the scene as a tree view,
-//! showing parent-child relationships and allowing entity selection.
+//! showing parent-child relationships and allowing entity selection. Each
+//! row ends in eye and lock toggles (see [`EditorVisibility`] and
+//! [`EditorLock`]). Double-clicking a row asks to rename it; the caller
+//! starts the inline edit with [`HierarchyPanel::begin_rename`]. Group
+//! nodes ([`ecs::Group`]) are drawn with a folder icon. A nested scene
+//! instance ([`ecs::SceneInstanceRef`]) is one collapsed row named after
+//! its scene file; the entities it loaded aren't listed.
 
 use std::collections::HashSet;
 
-use ecs::{EntityId, Name, Sprite, World, WorldHierarchyExt};
+use ecs::{EntityId, Group, Name, SceneInstanceRef, Sprite, World, WorldHierarchyExt};
 use glam::Vec2;
 use physics::components::RigidBody;
 
+use crate::editor_icons::{FOLDER_ICON, FOLDER_ICON_SIZE};
 use crate::layout::{LINE_HEIGHT, PADDING};
 use crate::theme::EditorTheme;
-use crate::Selection;
+use crate::{EditorLock, EditorVisibility, Selection};
 
 /// Row height for each entity in the hierarchy (matches LINE_HEIGHT).
 const ROW_HEIGHT: f32 = LINE_HEIGHT;
@@ -25,11 +32,34 @@ const INDENT_PER_DEPTH: f32 = 16.0;
 /// Width of the expand/collapse arrow.
 const ARROW_WIDTH: f32 = 16.0;
 
+/// Width of the folder icon in front of a group's name.
+const FOLDER_WIDTH: f32 = 16.0;
+
+/// Width of each eye/lock toggle at the right end of a row.
+const TOGGLE_WIDTH: f32 = 18.0;
+
 /// Hierarchy panel for displaying entity tree structure.
 #[derive(Debug, Default)]
 pub struct HierarchyPanel {
     /// Entities that are collapsed (all expanded by default).
     collapsed: HashSet<EntityId>,
+    /// Entity whose name is being edited inline
+    renaming: Option<EntityId>,
+}
+
+/// What the user did in the hierarchy this frame.
+#[derive(Debug, Default)]
+pub struct HierarchyResponse {
+    /// Rows clicked for selection
+    pub clicked: Vec<EntityId>,
+    /// Entity whose eye toggle was clicked
+    pub toggle_hidden: Option<EntityId>,
+    /// Entity whose lock toggle was clicked
+    pub toggle_locked: Option<EntityId>,
+    /// Row double-clicked to rename
+    pub rename_requested: Option<EntityId>,
+    /// Inline rename committed with a changed name
+    pub renamed: Option<(EntityId, String)>,
 }
 
 /// Shared state for one hierarchy render pass, threaded through the node recursion.
@@ -37,9 +67,11 @@ struct NodeRenderCtx<'a> {
     ui: &'a mut ui::UIContext,
     world: &'a World,
     selection: &'a Selection,
+    visibility: &'a EditorVisibility,
+    locks: &'a EditorLock,
     theme: &'a EditorTheme,
     bounds: common::Rect,
-    clicked_entities: &'a mut Vec<EntityId>,
+    response: &'a mut HierarchyResponse,
 }
 
 impl HierarchyPanel {
@@ -47,9 +79,27 @@ impl HierarchyPanel {
     pub fn new() -> Self {
         Self {
             collapsed: HashSet::new(),
+            renaming: None,
         }
     }
 
+    /// Start renaming `entity` inline, its current name selected so typing
+    /// replaces it. Enter or clicking away commits (reported in
+    /// [`HierarchyResponse::renamed`]), Escape cancels.
+    pub fn begin_rename(&mut self, ui: &mut ui::UIContext, world: &World, entity: EntityId) {
+        self.renaming = Some(entity);
+        ui.focus_text_input(Self::rename_id(entity).as_str(), &Self::entity_display_name(world, entity));
+    }
+
+    /// Entity being renamed inline, if any.
+    pub fn renaming(&self) -> Option<EntityId> {
+        self.renaming
+    }
+
+    fn rename_id(entity: EntityId) -> String {
+        format!("hierarchy_rename_{}", entity.value())
+    }
+
     /// Check if an entity is expanded (default: true).
     pub fn is_expanded(&self, entity: EntityId) -> bool {
         !self.collapsed.contains(&entity)
@@ -78,15 +128,25 @@ impl HierarchyPanel {
     ///
     /// Resolution order:
     /// 1. Name component
-    /// 2. Sprite component → "Sprite (Entity {id})"
-    /// 3. RigidBody component → "RigidBody (Entity {id})"
-    /// 4. Fallback → "Entity {id}"
+    /// 2. Group component → "Group (Entity {id})"
+    /// 3. SceneInstanceRef component → "Scene (Entity {id})"
+    /// 4. Sprite component → "Sprite (Entity {id})"
+    /// 5. RigidBody component → "RigidBody (Entity {id})"
+    /// 6. Fallback → "Entity {id}"
     pub fn entity_display_name(world: &World, entity: EntityId) -> String {
         // Check for Name component first
         if let Some(name) = world.get::<Name>(entity) {
             return name.as_str().to_string();
         }
 
+        if world.get::<Group>(entity).is_some() {
+            return format!("Group (Entity {})", entity.value());
+        }
+
+        if world.get::<SceneInstanceRef>(entity).is_some() {
+            return format!("Scene (Entity {})", entity.value());
+        }
+
         // Check for Sprite component
         if world.get::<Sprite>(entity).is_some() {
             return format!("Sprite (Entity {})", entity.value());
@@ -101,18 +161,51 @@ impl HierarchyPanel {
         format!("Entity {}", entity.value())
     }
 
+    /// Children listed under an entity's row. A nested scene instance
+    /// lists none: its entities belong to the referenced file.
+    pub fn tree_children(world: &World, entity: EntityId) -> &[EntityId] {
+        if world.get::<SceneInstanceRef>(entity).is_some() {
+            return &[];
+        }
+        world.get_children(entity).unwrap_or(&[])
+    }
+
+    /// Row label for display name `name`: followed by the scene file name
+    /// for a nested scene instance ("West Room [room.scene.ron]").
+    pub fn row_label(world: &World, entity: EntityId, name: &str) -> String {
+        let Some(scene) = world.get::<SceneInstanceRef>(entity) else {
+            return name.to_string();
+        };
+        let file = std::path::Path::new(&scene.path)
+            .file_name()
+            .map_or_else(|| scene.path.clone(), |f| f.to_string_lossy().into_owned());
+        format!("{name} [{file}]")
+    }
+
+    /// Whether an entity other than `entity` is already named `name`, for
+    /// duplicate-name warnings (names stay free-form; scenes may repeat them).
+    pub fn is_duplicate_name(world: &World, entity: EntityId, name: &str) -> bool {
+        let name = name.trim();
+        world
+            .query_entities::<ecs::Single<Name>>()
+            .into_iter()
+            .any(|other| other != entity && world.get::<Name>(other).is_some_and(|n| n.as_str() == name))
+    }
+
     /// Render the hierarchy panel.
     ///
-    /// Returns the list of entities that were clicked for selection.
+    /// Returns the rows clicked for selection and any eye/lock toggle
+    /// clicks; the caller applies them to `flags`.
     pub fn render(
         &mut self,
         ui: &mut ui::UIContext,
         world: &World,
         selection: &mut Selection,
+        flags: (&EditorVisibility, &EditorLock),
         bounds: common::Rect,
         theme: &EditorTheme,
-    ) -> Vec<EntityId> {
-        let mut clicked_entities = Vec::new();
+    ) -> HierarchyResponse {
+        let mut response = HierarchyResponse::default();
 
         // Get root entities (no parent) and sort by ID for consistent ordering
         let mut roots = world.get_root_entities();
@@ -122,9 +215,11 @@ impl HierarchyPanel {
             ui,
             world,
             selection,
+            visibility: flags.0,
+            locks: flags.1,
             theme,
             bounds,
-            clicked_entities: &mut clicked_entities,
+            response: &mut response,
         };
 
         // Render each root and its descendants with top padding
@@ -133,7 +228,7 @@ impl HierarchyPanel {
             y = self.render_node(&mut ctx, root, 0, y);
         }
 
-        clicked_entities
+        response
     }
 
     /// Render a single node and its children recursively.
@@ -153,18 +248,16 @@ impl HierarchyPanel {
             // Skip rendering but still calculate next Y
             let mut next_y = y + ROW_HEIGHT;
             if self.is_expanded(entity) {
-                if let Some(children) = ctx.world.get_children(entity) {
-                    let children_vec: Vec<EntityId> = children.to_vec();
-                    for child in children_vec {
-                        next_y = self.render_node(ctx, child, depth + 1, next_y);
-                    }
+                let children_vec: Vec<EntityId> = Self::tree_children(ctx.world, entity).to_vec();
+                for child in children_vec {
+                    next_y = self.render_node(ctx, child, depth + 1, next_y);
                 }
             }
             return next_y;
         }
 
         let x = bounds.x + BASE_PADDING + (depth as f32 * INDENT_PER_DEPTH);
-        let has_children = ctx.world.get_children(entity).is_some_and(|c| !c.is_empty());
+        let has_children = !Self::tree_children(ctx.world, entity).is_empty();
         let is_selected = ctx.selection.contains(entity);
         let is_expanded = self.is_expanded(entity);
 
@@ -191,16 +284,30 @@ impl HierarchyPanel {
             ctx.ui.label(arrow, Vec2::new(x, y + ROW_HEIGHT - 4.0));
         }
 
-        // Row interaction - use area after arrow for entities with children
+        // Eye/lock toggles at the right end, checked before the row so a
+        // toggle click doesn't also select
+        let toggles_x = bounds.x + bounds.width - 2.0 * TOGGLE_WIDTH;
+        Self::render_toggles(ctx, entity, toggles_x, y);
+
+        // Row interaction - use area after arrow for entities with children,
+        // up to the toggles. A row being renamed leaves clicks to its input.
         let row_interact_x = if has_children { x + ARROW_WIDTH } else { bounds.x };
-        let row_interact_width = bounds.x + bounds.width - row_interact_x;
+        let row_interact_width = (toggles_x - row_interact_x).max(0.0);
         let row_interact_rect = common::Rect::new(row_interact_x, y, row_interact_width, ROW_HEIGHT);
+        let renaming = self.renaming == Some(entity);
 
         let row_id = format!("hierarchy_row_{}", entity.value());
-        let row_interaction = ctx.ui.interact(row_id.as_str(), row_interact_rect, true);
+        let row_interaction = if renaming {
+            ui::InteractionResult::default()
+        } else {
+            ctx.ui.interact(row_id.as_str(), row_interact_rect, true)
+        };
 
         if row_interaction.clicked && !arrow_clicked {
-            ctx.clicked_entities.push(entity);
+            ctx.response.clicked.push(entity);
+        }
+        if row_interaction.double_clicked && !arrow_clicked {
+            ctx.response.rename_requested = Some(entity);
         }
 
         // Hover highlight (full row width for visual consistency)
@@ -208,201 +315,88 @@ impl HierarchyPanel {
             ctx.ui.rect(row_rect, ctx.theme.hover_fill);
         }
 
-        // Entity name (baseline near bottom of row)
+        // Entity name (baseline near bottom of row); dimmed while hidden
         let name = Self::entity_display_name(ctx.world, entity);
-        let name_x = x + if has_children { ARROW_WIDTH } else { 0.0 };
-        ctx.ui.label(&name, Vec2::new(name_x, y + ROW_HEIGHT - 4.0));
+        let mut name_x = x + if has_children { ARROW_WIDTH } else { 0.0 };
+        if ctx.world.get::<Group>(entity).is_some() {
+            // Folder icon from the UI atlas; two rects until it is packed
+            let (width, height) = (FOLDER_ICON_SIZE.0 as f32, FOLDER_ICON_SIZE.1 as f32);
+            let top = y + (ROW_HEIGHT - height) * 0.5;
+            let icon = common::Rect::new(name_x + 1.0, top, width, height);
+            if !ctx.ui.draw_icon(FOLDER_ICON, icon, ctx.theme.text_secondary) {
+                ctx.ui.rect(common::Rect::new(icon.x, top, 5.0, 2.0), ctx.theme.text_secondary);
+                ctx.ui.rect(common::Rect::new(icon.x, top + 2.0, width, height - 2.0), ctx.theme.text_secondary);
+            }
+            name_x += FOLDER_WIDTH;
+        }
+        let name_pos = Vec2::new(name_x, y + ROW_HEIGHT - 4.0);
+        if renaming {
+            let id = Self::rename_id(entity);
+            let input = common::Rect::new(name_x, y + 1.0, (toggles_x - name_x - 4.0).max(0.0), ROW_HEIGHT - 2.0);
+            if let Some(new_name) = ctx.ui.text_input(id.as_str(), &name, input) {
+                ctx.response.renamed = Some((entity, new_name));
+            }
+            if !ctx.ui.is_editing(id.as_str()) {
+                self.renaming = None;
+            }
+        } else if ctx.visibility.is_hidden_in(ctx.world, entity) {
+            let label = Self::row_label(ctx.world, entity, &name);
+            ctx.ui.label_styled(&label, name_pos, ctx.theme.text_muted, ctx.theme.fonts.body);
+        } else {
+            ctx.ui.label(&Self::row_label(ctx.world, entity, &name), name_pos);
+        }
 
         // Render children if expanded
         let mut next_y = y + ROW_HEIGHT;
         if is_expanded && has_children {
-            if let Some(children) = ctx.world.get_children(entity) {
-                // Clone to avoid borrow issues
-                let children_vec: Vec<EntityId> = children.to_vec();
-                for child in children_vec {
-                    next_y = self.render_node(ctx, child, depth + 1, next_y);
-                }
+            // Clone to avoid borrow issues
+            let children_vec: Vec<EntityId> = Self::tree_children(ctx.world, entity).to_vec();
+            for child in children_vec {
+                next_y = self.render_node(ctx, child, depth + 1, next_y);
             }
         }
 
         next_y
     }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use ecs::World;
-
-    fn entity(id: u64) -> EntityId {
-        EntityId::with_generation(id, 1)
-    }
-
-    // ==================== Expand/Collapse State Tests ====================
-
-    #[test]
-    fn test_default_expanded() {
-        let panel = HierarchyPanel::new();
-        let e1 = entity(1);
-
-        // Entities are expanded by default
-        assert!(panel.is_expanded(e1));
-    }
 
-    #[test]
-    fn test_toggle_collapse() {
-        let mut panel = HierarchyPanel::new();
-        let e1 = entity(1);
-
-        // Initially expanded
-        assert!(panel.is_expanded(e1));
-
-        // Toggle to collapse
-        panel.toggle_expanded(e1);
-        assert!(!panel.is_expanded(e1));
-
-        // Toggle to expand again
-        panel.toggle_expanded(e1);
-        assert!(panel.is_expanded(e1));
-    }
-
-    #[test]
-    fn test_collapse_persists() {
-        let mut panel = HierarchyPanel::new();
-        let e1 = entity(1);
-        let e2 = entity(2);
-
-        // Collapse e1
-        panel.collapse(e1);
-        assert!(!panel.is_expanded(e1));
-        assert!(panel.is_expanded(e2)); // e2 still expanded
-
-        // Expand e1
-        panel.expand(e1);
-        assert!(panel.is_expanded(e1));
-    }
-
-    #[test]
-    fn test_multiple_entities_independent_state() {
-        let mut panel = HierarchyPanel::new();
-        let e1 = entity(1);
-        let e2 = entity(2);
-        let e3 = entity(3);
-
-        panel.collapse(e1);
-        panel.collapse(e3);
-
-        assert!(!panel.is_expanded(e1));
-        assert!(panel.is_expanded(e2));
-        assert!(!panel.is_expanded(e3));
-    }
-
-    // ==================== Name Resolution Tests ====================
-
-    #[test]
-    fn test_name_from_name_component() {
-        let mut world = World::new();
-        let e = world.create_entity();
-        world.add_component(&e, Name::new("Player")).ok();
-        world.add_component(&e, Sprite::default()).ok(); // Also has sprite
-
-        // Name component takes priority
-        let name = HierarchyPanel::entity_display_name(&world, e);
-        assert_eq!(name, "Player");
-    }
-
-    #[test]
-    fn test_name_fallback_sprite() {
-        let mut world = World::new();
-        let e = world.create_entity();
-        world.add_component(&e, Sprite::default()).ok();
-
-        let name = HierarchyPanel::entity_display_name(&world, e);
-        assert!(name.starts_with("Sprite (Entity"));
-    }
-
-    #[test]
-    fn test_name_fallback_rigidbody() {
-        let mut world = World::new();
-        let e = world.create_entity();
-        world.add_component(&e, RigidBody::default()).ok();
-
-        let name = HierarchyPanel::entity_display_name(&world, e);
-        assert!(name.starts_with("RigidBody (Entity"));
-    }
-
-    #[test]
-    fn test_name_fallback_entity_id() {
-        let mut world = World::new();
-        let e = world.create_entity();
-
-        let name = HierarchyPanel::entity_display_name(&world, e);
-        assert!(name.starts_with("Entity"));
-    }
-
-    // ==================== Tree Structure Tests ====================
-
-    #[test]
-    fn test_hierarchy_panel_new() {
-        let panel = HierarchyPanel::new();
-        assert!(panel.collapsed.is_empty());
-    }
-
-    #[test]
-    fn test_root_entities_rendering_order() {
-        // This test verifies the logic without actual UI rendering
-        let mut world = World::new();
-        let root1 = world.create_entity();
-        let root2 = world.create_entity();
-        let child = world.create_entity();
-
-        world.set_parent(child, root1).unwrap();
-
-        let roots = world.get_root_entities();
-
-        // Should have 2 root entities
-        assert_eq!(roots.len(), 2);
-        assert!(roots.contains(&root1));
-        assert!(roots.contains(&root2));
-        assert!(!roots.contains(&child));
-    }
-
-    #[test]
-    fn test_collapsed_hides_children() {
-        let mut panel = HierarchyPanel::new();
-        let mut world = World::new();
-
-        let parent = world.create_entity();
-        let child = world.create_entity();
-        world.set_parent(child, parent).unwrap();
-
-        // When parent is expanded, children are visible (is_expanded returns true)
-        assert!(panel.is_expanded(parent));
-
-        // When parent is collapsed, children are hidden
-        panel.collapse(parent);
-        assert!(!panel.is_expanded(parent));
-    }
-
-    #[test]
-    fn test_deep_hierarchy_structure() {
-        let mut world = World::new();
-
-        let grandparent = world.create_entity();
-        let parent = world.create_entity();
-        let child = world.create_entity();
+    /// Draw and hit-test the eye and lock toggles of one row. A flag set on
+    /// the entity itself draws in the accent color; one inherited from an
+    /// ancestor draws muted.
+    fn render_toggles(ctx: &mut NodeRenderCtx<'_>, entity: EntityId, x: f32, y: f32) {
+        let theme = ctx.theme;
+        let eye = common::Rect::new(x, y, TOGGLE_WIDTH, ROW_HEIGHT);
+        let lock = common::Rect::new(x + TOGGLE_WIDTH, y, TOGGLE_WIDTH, ROW_HEIGHT);
+        if ctx.ui.interact(format!("hierarchy_eye_{}", entity.value()).as_str(), eye, true).clicked {
+            ctx.response.toggle_hidden = Some(entity);
+        }
+        if ctx.ui.interact(format!("hierarchy_lock_{}", entity.value()).as_str(), lock, true).clicked {
+            ctx.response.toggle_locked = Some(entity);
+        }
 
-        world.set_parent(parent, grandparent).unwrap();
-        world.set_parent(child, parent).unwrap();
+        let flag_color = |own: bool, inherited: bool| match (own, inherited) {
+            (true, _) => theme.accent_cyan,
+            (false, true) => theme.text_muted,
+            (false, false) => theme.text_muted.with_alpha(0.35),
+        };
 
-        // Verify hierarchy structure
-        let roots = world.get_root_entities();
-        assert_eq!(roots.len(), 1);
-        assert!(roots.contains(&grandparent));
+        // Eye: a dot, struck through while hidden
+        let hidden = ctx.visibility.is_hidden_in(ctx.world, entity);
+        let color = flag_color(ctx.visibility.is_hidden(entity), hidden);
+        let center = eye.center();
+        ctx.ui.circle(center, 3.0, if hidden { color } else { theme.text_secondary });
+        if hidden {
+            ctx.ui.line(center + Vec2::new(-5.0, 5.0), center + Vec2::new(5.0, -5.0), color, 1.5);
+        }
 
-        let descendants = world.get_descendants(grandparent);
-        assert_eq!(descendants.len(), 2);
-        assert!(descendants.contains(&parent));
-        assert!(descendants.contains(&child));
+        // Lock: a padlock body under a shackle
+        let locked = ctx.locks.is_locked_in(ctx.world, entity);
+        let color = flag_color(ctx.locks.is_locked(entity), locked);
+        let center = lock.center();
+        ctx.ui.rect(common::Rect::new(center.x - 4.0, center.y - 1.0, 8.0, 6.0), color);
+        ctx.ui.rect_border(common::Rect::new(center.x - 3.0, center.y - 6.0, 6.0, 6.0), color, 1.0, 2.0);
     }
 }
+
+#[cfg(test)]
+#[path = "hierarchy_tests.rs"]
+mod tests;
@@ -0,0 +1,239 @@
+use super::*;
+use ecs::World;
+
+fn entity(id: u64) -> EntityId {
+    EntityId::with_generation(id, 1)
+}
+
+// ==================== Expand/Collapse State Tests ====================
+
+#[test]
+fn test_default_expanded() {
+    let panel = HierarchyPanel::new();
+    let e1 = entity(1);
+
+    // Entities are expanded by default
+    assert!(panel.is_expanded(e1));
+}
+
+#[test]
+fn test_toggle_collapse() {
+    let mut panel = HierarchyPanel::new();
+    let e1 = entity(1);
+
+    // Initially expanded
+    assert!(panel.is_expanded(e1));
+
+    // Toggle to collapse
+    panel.toggle_expanded(e1);
+    assert!(!panel.is_expanded(e1));
+
+    // Toggle to expand again
+    panel.toggle_expanded(e1);
+    assert!(panel.is_expanded(e1));
+}
+
+#[test]
+fn test_collapse_persists() {
+    let mut panel = HierarchyPanel::new();
+    let e1 = entity(1);
+    let e2 = entity(2);
+
+    // Collapse e1
+    panel.collapse(e1);
+    assert!(!panel.is_expanded(e1));
+    assert!(panel.is_expanded(e2)); // e2 still expanded
+
+    // Expand e1
+    panel.expand(e1);
+    assert!(panel.is_expanded(e1));
+}
+
+#[test]
+fn test_multiple_entities_independent_state() {
+    let mut panel = HierarchyPanel::new();
+    let e1 = entity(1);
+    let e2 = entity(2);
+    let e3 = entity(3);
+
+    panel.collapse(e1);
+    panel.collapse(e3);
+
+    assert!(!panel.is_expanded(e1));
+    assert!(panel.is_expanded(e2));
+    assert!(!panel.is_expanded(e3));
+}
+
+// ==================== Name Resolution Tests ====================
+
+#[test]
+fn test_name_from_name_component() {
+    let mut world = World::new();
+    let e = world.create_entity();
+    world.add_component(&e, Name::new("Player")).ok();
+    world.add_component(&e, Sprite::default()).ok(); // Also has sprite
+
+    // Name component takes priority
+    let name = HierarchyPanel::entity_display_name(&world, e);
+    assert_eq!(name, "Player");
+}
+
+#[test]
+fn test_name_fallback_group() {
+    let mut world = World::new();
+    let e = world.spawn((Group,)).id();
+    assert_eq!(HierarchyPanel::entity_display_name(&world, e), format!("Group (Entity {})", e.value()));
+}
+
+#[test]
+fn test_scene_instance_is_a_collapsed_row_labelled_with_its_file() {
+    let mut world = World::new();
+    let room = world.spawn((Name::new("West Room"), SceneInstanceRef::new("rooms/room.scene.ron"))).id();
+    let chest = world.create_entity();
+    world.set_parent(chest, room).unwrap();
+
+    assert!(HierarchyPanel::tree_children(&world, room).is_empty());
+    assert_eq!(HierarchyPanel::row_label(&world, room, "West Room"), "West Room [room.scene.ron]");
+
+    let unnamed = world.spawn((SceneInstanceRef::new("a.ron"),)).id();
+    assert_eq!(HierarchyPanel::entity_display_name(&world, unnamed), format!("Scene (Entity {})", unnamed.value()));
+}
+
+#[test]
+fn test_name_fallback_sprite() {
+    let mut world = World::new();
+    let e = world.create_entity();
+    world.add_component(&e, Sprite::default()).ok();
+
+    let name = HierarchyPanel::entity_display_name(&world, e);
+    assert!(name.starts_with("Sprite (Entity"));
+}
+
+#[test]
+fn test_name_fallback_rigidbody() {
+    let mut world = World::new();
+    let e = world.create_entity();
+    world.add_component(&e, RigidBody::default()).ok();
+
+    let name = HierarchyPanel::entity_display_name(&world, e);
+    assert!(name.starts_with("RigidBody (Entity"));
+}
+
+#[test]
+fn test_name_fallback_entity_id() {
+    let mut world = World::new();
+    let e = world.create_entity();
+
+    let name = HierarchyPanel::entity_display_name(&world, e);
+    assert!(name.starts_with("Entity"));
+}
+
+#[test]
+fn test_duplicate_name_ignores_the_entity_itself() {
+    let mut world = World::new();
+    let [player, enemy] = std::array::from_fn(|_| world.create_entity());
+    world.add_component(&player, Name::new("Player")).ok();
+    world.add_component(&enemy, Name::new("Enemy")).ok();
+
+    assert!(HierarchyPanel::is_duplicate_name(&world, enemy, " Player "));
+    assert!(!HierarchyPanel::is_duplicate_name(&world, player, "Player"));
+    assert!(!HierarchyPanel::is_duplicate_name(&world, player, "Boss"));
+}
+
+#[test]
+fn test_rename_command_sets_and_clears_the_name_with_undo() {
+    use crate::commands::{EditorCommand, RenameEntityCommand};
+    let mut world = World::new();
+    let e = world.create_entity();
+    assert!(RenameEntityCommand::new(&world, e, "  ").is_none(), "already unnamed");
+
+    let mut rename = RenameEntityCommand::new(&world, e, " Player ").unwrap();
+    rename.execute(&mut world);
+    assert_eq!(HierarchyPanel::entity_display_name(&world, e), "Player");
+    assert!(RenameEntityCommand::new(&world, e, "Player").is_none(), "unchanged");
+
+    let mut clear = RenameEntityCommand::new(&world, e, "").unwrap();
+    clear.execute(&mut world);
+    assert!(world.get::<Name>(e).is_none());
+    clear.undo(&mut world);
+    rename.undo(&mut world);
+    assert!(world.get::<Name>(e).is_none());
+}
+
+#[test]
+fn test_begin_rename_focuses_the_row_input() {
+    let mut ui = ui::UIContext::new();
+    let mut world = World::new();
+    let e = world.create_entity();
+    let mut panel = HierarchyPanel::new();
+
+    panel.begin_rename(&mut ui, &world, e);
+    assert_eq!(panel.renaming(), Some(e));
+    assert!(ui.wants_keyboard(), "typing goes to the rename input");
+}
+
+// ==================== Tree Structure Tests ====================
+
+#[test]
+fn test_hierarchy_panel_new() {
+    let panel = HierarchyPanel::new();
+    assert!(panel.collapsed.is_empty());
+}
+
+#[test]
+fn test_root_entities_rendering_order() {
+    // This test verifies the logic without actual UI rendering
+    let mut world = World::new();
+    let root1 = world.create_entity();
+    let root2 = world.create_entity();
+    let child = world.create_entity();
+
+    world.set_parent(child, root1).unwrap();
+
+    let roots = world.get_root_entities();
+
+    // Should have 2 root entities
+    assert_eq!(roots.len(), 2);
+    assert!(roots.contains(&root1));
+    assert!(roots.contains(&root2));
+    assert!(!roots.contains(&child));
+}
+
+#[test]
+fn test_collapsed_hides_children() {
+    let mut panel = HierarchyPanel::new();
+    let mut world = World::new();
+
+    let parent = world.create_entity();
+    let child = world.create_entity();
+    world.set_parent(child, parent).unwrap();
+
+    // When parent is expanded, children are visible (is_expanded returns true)
+    assert!(panel.is_expanded(parent));
+
+    // When parent is collapsed, children are hidden
+    panel.collapse(parent);
+    assert!(!panel.is_expanded(parent));
+}
+
+#[test]
+fn test_deep_hierarchy_structure() {
+    let mut world = World::new();
+
+    let grandparent = world.create_entity();
+    let parent = world.create_entity();
+    let child = world.create_entity();
+
+    world.set_parent(parent, grandparent).unwrap();
+    world.set_parent(child, parent).unwrap();
+
+    // Verify hierarchy structure
+    let roots = world.get_root_entities();
+    assert_eq!(roots.len(), 1);
+    assert!(roots.contains(&grandparent));
+
+    let descendants = world.get_descendants(grandparent);
+    assert_eq!(descendants.len(), 2);
+    assert!(descendants.contains(&parent));
+    assert!(descendants.contains(&child));
+}
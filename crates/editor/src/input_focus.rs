@@ -0,0 +1,121 @@
+//! Keyboard and mouse focus between the editor and the game it runs.
+//!
+//! While a play session runs, keyboard and mouse input goes to exactly one
+//! side. Entering play gives the game focus; clicking anywhere outside the
+//! scene view hands it back to the editor (panels, inspector, menus), and
+//! clicking inside the scene view gives it to the game again. Pausing or
+//! stopping returns focus to the editor. Gamepads always reach the game —
+//! the editor doesn't use them.
+//!
+//! [`InputRouter`] also remembers which key presses the game was sent, so a
+//! key held while focus moves still reaches the game as a release and the
+//! game never sees a release for a press it didn't get.
+
+use std::collections::HashSet;
+
+use glam::Vec2;
+use winit::keyboard::KeyCode;
+
+/// Which side receives keyboard and mouse input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum InputFocus {
+    /// Editor panels and shortcuts (always, outside play)
+    #[default]
+    Editor,
+    /// The running game's update and key callbacks
+    Game,
+}
+
+/// Input focus plus the keys the game holds; see the module docs.
+#[derive(Debug, Default)]
+pub struct InputRouter {
+    focus: InputFocus,
+    /// Keys pressed while the game had focus and not released since
+    game_keys: HashSet<KeyCode>,
+}
+
+impl InputRouter {
+    /// Router with the editor focused.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Current focus.
+    pub fn focus(&self) -> InputFocus {
+        self.focus
+    }
+
+    /// Whether the game receives keyboard and mouse input.
+    pub fn game_has_focus(&self) -> bool {
+        self.focus == InputFocus::Game
+    }
+
+    /// Move focus. Returns whether it changed.
+    pub fn set_focus(&mut self, focus: InputFocus) -> bool {
+        let changed = self.focus != focus;
+        self.focus = focus;
+        changed
+    }
+
+    /// Click-to-focus: a click inside `game_view` focuses the game, any
+    /// other click the editor. Returns whether focus changed.
+    pub fn click(&mut self, position: Vec2, game_view: Option<common::Rect>) -> bool {
+        let inside = game_view.is_some_and(|view| view.contains(position));
+        self.set_focus(if inside { InputFocus::Game } else { InputFocus::Editor })
+    }
+
+    /// Route a key press: `true` when it goes to the game (which then also
+    /// gets its release).
+    pub fn press(&mut self, key: KeyCode) -> bool {
+        if !self.game_has_focus() {
+            return false;
+        }
+        self.game_keys.insert(key);
+        true
+    }
+
+    /// Route a key release: `true` when the game got the press, whatever
+    /// has focus now.
+    pub fn release(&mut self, key: KeyCode) -> bool {
+        self.game_keys.remove(&key)
+    }
+
+    /// Forget the game's held keys (its session ended).
+    pub fn clear_keys(&mut self) {
+        self.game_keys.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clicks_move_focus_between_game_view_and_editor() {
+        let view = common::Rect::new(100.0, 50.0, 400.0, 300.0);
+        let mut router = InputRouter::new();
+        assert_eq!(router.focus(), InputFocus::Editor);
+
+        assert!(router.click(Vec2::new(200.0, 100.0), Some(view)));
+        assert!(router.game_has_focus());
+        assert!(!router.click(Vec2::new(210.0, 100.0), Some(view)), "already focused");
+
+        assert!(router.click(Vec2::new(20.0, 100.0), Some(view)), "a panel click");
+        assert_eq!(router.focus(), InputFocus::Editor);
+        router.click(Vec2::new(200.0, 100.0), None);
+        assert_eq!(router.focus(), InputFocus::Editor, "no game view on screen");
+    }
+
+    #[test]
+    fn test_game_gets_releases_only_for_its_own_presses() {
+        let mut router = InputRouter::new();
+        assert!(!router.press(KeyCode::Space));
+
+        router.set_focus(InputFocus::Game);
+        assert!(router.press(KeyCode::KeyW));
+        router.set_focus(InputFocus::Editor);
+        assert!(router.release(KeyCode::KeyW), "held across the focus change");
+        assert!(!router.release(KeyCode::Space), "the editor had that press");
+        assert!(!router.release(KeyCode::KeyW), "released once");
+    }
+}
@@ -0,0 +1,72 @@
+//! Render layer row for the Sprite inspector: a dropdown of the scene's
+//! layers, back to front.
+
+use glam::Vec2;
+use ui::{Rect, UIContext};
+
+use crate::editable_inspector::{EditResult, EditableInspector};
+use crate::field_style::{EditableFieldStyle, FieldId};
+
+impl EditableInspector<'_> {
+    /// Add a layer dropdown row. `Changed(name)` when another layer is picked.
+    pub fn layer(&mut self, label: &str, current: &str, layers: &[String]) -> EditResult<String> {
+        self.custom_row(|ui, id, pos, style| edit_layer_field(ui, id, label, current, layers, pos, style))
+    }
+}
+
+/// Dropdown options for a sprite on `current`: the scene's layers, plus
+/// `current` itself when the scene lacks it (so a sprite on a removed layer
+/// shows its real layer name). Returns the options and the selected index.
+pub fn layer_options<'a>(current: &'a str, layers: &'a [String]) -> (Vec<&'a str>, usize) {
+    let mut options: Vec<&str> = layers.iter().map(String::as_str).collect();
+    let selected = match options.iter().position(|option| *option == current) {
+        Some(index) => index,
+        None => {
+            options.push(current);
+            options.len() - 1
+        }
+    };
+    (options, selected)
+}
+
+/// Render a layer row: label and a dropdown of [`layer_options`].
+pub fn edit_layer_field(
+    ui: &mut UIContext,
+    id: FieldId,
+    label: &str,
+    current: &str,
+    layers: &[String],
+    pos: Vec2,
+    style: &EditableFieldStyle,
+) -> EditResult<String> {
+    ui.label_styled(label, Vec2::new(pos.x, pos.y + 4.0), style.label_color, style.label_font);
+
+    let (options, selected) = layer_options(current, layers);
+    let bounds = Rect::new(pos.x + style.label_width, pos.y + 2.0, style.input_width, style.row_height - 4.0);
+    let picked = ui.dropdown(id, selected, &options, bounds);
+    match options.get(picked) {
+        Some(name) if picked != selected => EditResult::Changed(name.to_string()),
+        _ => EditResult::Unchanged,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_layer_options_select_current() {
+        let layers = ecs::RenderLayers::new();
+        let (options, selected) = layer_options("Foreground", layers.names());
+        assert_eq!(options.len(), layers.names().len());
+        assert_eq!(options[selected], "Foreground");
+    }
+
+    #[test]
+    fn test_unknown_layer_stays_listed() {
+        let layers = ecs::RenderLayers::new();
+        let (options, selected) = layer_options("Parallax", layers.names());
+        assert_eq!(options.len(), layers.names().len() + 1);
+        assert_eq!(options[selected], "Parallax");
+    }
+}
@@ -29,97 +29,182 @@
 //! assert!(editor.is_editing()); // starts in Editing play state
 //! ```
 
+mod animation_preview;
 mod asset_browser;
+mod background_tasks;
 mod behavior_editor;
+mod camera_bookmarks;
+mod chunk_overlay;
+mod collider_gizmo;
 mod collider_overlay;
+mod color_scheme;
 mod drag_drop;
 pub mod commands;
+mod component_clipboard;
+mod component_dependencies;
 mod component_editors;
+mod component_issues;
 mod context;
 mod dock;
 mod editable_inspector;
+mod editor_icons;
+mod effector_editors;
+mod entity_clipboard;
+mod entity_flags;
 mod field_style;
 mod editor_input;
+mod frame_step;
 mod gizmo;
 mod gizmo_math;
 mod grid;
 mod hierarchy;
+mod input_focus;
 mod inspector;
+mod layer_field;
+mod material_field;
+mod measure;
 mod menu;
+mod multi_edit;
+mod physics_sync_overlay;
 mod picking;
+mod play_changes;
 mod play_controls;
 mod play_state;
+mod plugin_panels;
+mod profiler;
+mod rulers;
+mod scene_compare;
+mod scene_graph_stats;
+mod scene_tabs;
 mod selection;
+mod selection_overlay;
+mod sprite_region;
+mod sprite_slicer;
 pub mod status_bar;
 pub mod stored_component;
 mod texture_field;
+mod texture_picker;
 pub mod theme;
 pub mod typography;
 mod toolbar;
 mod viewport;
 mod viewport_input;
+mod viewport_overlays;
 pub mod editor_preferences;
 pub mod layout;
 pub mod world_snapshot;
 
 // Re-export main types
+pub use animation_preview::{edit_frame_scrubber, AnimationPreview};
 pub use asset_browser::{fit_rect, scan_assets, AssetBrowserState, AssetEntry, AssetKind};
-pub use behavior_editor::edit_behavior;
+pub use background_tasks::{BackgroundTasks, TaskHandle, TaskId, TaskProgress};
+pub use behavior_editor::{behavior_variant_options, edit_behavior};
+pub use camera_bookmarks::{CameraBookmark, CameraBookmarks, CAMERA_BOOKMARK_SLOTS};
+pub use chunk_overlay::{chunk_border_segments, chunk_labels, render_chunk_overlay};
 pub use drag_drop::{DragDropState, DragPayload, DRAG_THRESHOLD};
-pub use texture_field::{edit_texture_field, InspectorExtras};
+pub use texture_field::{edit_texture_field, texture_pick_button, InspectorExtras};
+pub use texture_picker::{
+    texture_picker_entries, TexturePicker, TexturePickerAction, TexturePickerEntry, TEXTURE_PICKER_SIZE,
+};
+pub use color_scheme::{ColorSchemePreset, EditorColorScheme};
+pub use collider_gizmo::{
+    collider_handles, drag_collider_handle, ColliderGizmo, ColliderHandle, ColliderHandleKind,
+    MIN_COLLIDER_EXTENT,
+};
 pub use collider_overlay::{
     collider_outline_segments, render_collider_overlay, ColliderOverlayColors,
 };
 pub use commands::{CommandHistory, EditorCommand};
+pub use component_clipboard::{ComponentClipboard, ComponentMenuAction};
+pub use component_dependencies::{
+    validate_component_dependencies, DependencyPrompt, DependencyViolation,
+};
 pub use component_editors::{
     apply_component_edit, edit_audio_source, edit_collider, edit_rigid_body, edit_sprite,
-    edit_transform2d, ComponentEdit,
+    edit_sprite_animation, edit_transform2d, ComponentEdit,
 };
+pub use effector_editors::{edit_area_force, edit_buoyancy, edit_drag};
+pub use component_issues::ComponentIssues;
 pub use context::EditorContext;
+pub use editor_icons::{register_editor_icons, FOLDER_ICON, FOLDER_ICON_SIZE};
 pub use editor_preferences::EditorPreferences;
-pub use dock::{DockArea, DockPanel, DockPosition, PanelId};
+pub use dock::{DockArea, DockLayout, DockPanel, DockPosition, LayoutPreset, PanelId, PanelLayout};
 pub use editable_inspector::{
     component_header, cycle_step, display_string, display_u32, edit_bool, edit_color, edit_f32,
-    edit_normalized_f32, edit_vec2, EditableFieldStyle, EditableInspector, EditResult, FieldId,
+    edit_normalized_f32, edit_text, edit_vec2, EditableFieldStyle, EditableInspector, EditResult, FieldId,
 };
 pub use editor_input::{EditorAction, EditorInputMapping, EditorInputState};
+pub use entity_clipboard::EntityClipboard;
+pub use entity_flags::{EditorLock, EditorVisibility};
+pub use frame_step::{FrameStepper, DEFAULT_STEP_COUNT, MAX_STEP_COUNT};
 pub use gizmo::{Gizmo, GizmoMode, GizmoPalette};
-pub use hierarchy::HierarchyPanel;
+pub use hierarchy::{HierarchyPanel, HierarchyResponse};
 pub use grid::{GridColors, GridConfig, GridRenderer};
+pub use input_focus::{InputFocus, InputRouter};
 pub use inspector::{inspect_component, InspectorStyle};
+pub use layer_field::{edit_layer_field, layer_options};
+pub use material_field::{edit_material_field, material_options, CUSTOM_MATERIAL};
+pub use measure::{render_measure_overlay, MeasureTool, Measurement};
+pub use multi_edit::apply_multi_edit;
 pub use menu::{Menu, MenuBar, MenuItem};
+pub use physics_sync_overlay::{physics_sync_markers, render_physics_sync_overlay};
 pub use picking::{EntityPicker, PickResult, PickableEntity, SelectionRect, AABB};
+pub use play_changes::{apply_play_changes, diff_entity, edited_fields, FieldChange, FieldKey, PlayChanges};
 pub use play_controls::{PlayControlAction, PlayControls};
 pub use play_state::EditorPlayState;
+pub use plugin_panels::{EditorPanel, PluginPanels};
+pub use profiler::{Profiler, ProfilerRow, ProfilerSample, FRAME_BUDGET_MS, PROFILER_HISTORY};
+pub use rulers::{format_ruler_value, ruler_step, ruler_ticks, RulerTick, Rulers, RULER_SIZE};
+pub use scene_compare::{CompareRow, CompareRowKind, SceneCompareState};
+pub use scene_graph_stats::{
+    SceneGraphStats, SceneGraphWarning, CHILDREN_WARNING_THRESHOLD, DEPTH_WARNING_THRESHOLD,
+};
+pub use scene_tabs::{SceneTab, SceneTabAction, SceneTabs, SCENE_TAB_WIDTH};
 pub use selection::Selection;
-pub use status_bar::{StatusBar, StatusBarStats, STATUS_BAR_HEIGHT};
+pub use sprite_region::{
+    cell_at, cell_region, drag_region, named_region_text, region_name, region_text, edit_region_field,
+    RegionPickerAction, SpriteRegionPicker, FULL_REGION, REGION_PICKER_SIZE,
+};
+pub use sprite_slicer::{
+    step_cell_size, SliceMode, SlicerAction, SpriteAtlases, SpriteSheetSlicer, SPRITE_SLICER_SIZE,
+};
+pub use selection_overlay::{
+    render_selection_overlay, selection_outlines, SelectionOutline, SelectionOverlayColors,
+};
+pub use status_bar::{StatusBar, StatusBarStats, StatusTask, TaskRecord, STATUS_BAR_HEIGHT};
 pub use stored_component::{
-    available_components, capture_all_components, categorized_components,
-    edit_all_components, inspect_all_components, restore_components,
-    ComponentCategory, ComponentKind, StoredComponent,
+    available_components, capture_all_components, capture_inspectable_components,
+    categorized_components, edit_all_components, inspect_all_components, missing_dependencies,
+    restore_components, ComponentCategory, ComponentEditorContext, ComponentKind, StoredComponent,
 };
 pub use theme::EditorTheme;
 pub use toolbar::{EditorTool, Toolbar};
 pub use viewport::SceneViewport;
-pub use viewport_input::{ViewportInputConfig, ViewportInputHandler, ViewportInputResult};
+pub use viewport_input::{PanMode, ScrollMode, ViewportInputConfig, ViewportInputHandler, ViewportInputResult};
+pub use viewport_overlays::{
+    audio_range_circles, camera_frame_corners, render_audio_range_overlay, render_camera_frame_overlay,
+    ViewportOverlay, ViewportOverlays,
+};
 
 /// Prelude module for convenient imports.
 pub mod prelude {
     pub use crate::{
-        collider_outline_segments, render_collider_overlay, ColliderOverlayColors,
+        collider_outline_segments, render_collider_overlay, ColliderGizmo, ColliderOverlayColors,
+        ColorSchemePreset, EditorColorScheme,
         available_components, capture_all_components, categorized_components,
         inspect_all_components, restore_components, CommandHistory, ComponentCategory,
         ComponentEdit, ComponentKind, EditorCommand, StoredComponent,
         component_header, display_u32, edit_audio_source, edit_bool, edit_collider, edit_color,
         edit_f32, edit_normalized_f32, edit_rigid_body, edit_sprite, edit_transform2d, edit_vec2,
         inspect_component, DockArea, DockPanel,
-        DockPosition, EditorAction, EditorContext, EditorInputMapping, EditorInputState,
+        DockPosition, EditorAction, EditorContext, EditorInputMapping, EditorInputState, EditorLock,
+        EditorVisibility,
         EditorPlayState, EditorPreferences, EditorTool, EditableFieldStyle, EditableInspector,
         EditorTheme, EditResult, EntityPicker, FieldId, Gizmo, GizmoMode, GridRenderer,
-        HierarchyPanel, InspectorStyle, Menu, MenuBar, MenuItem, PanelId, PickResult,
+        HierarchyPanel, InspectorStyle, MeasureTool, Menu, MenuBar, MenuItem, PanelId, PickResult,
         PickableEntity, StatusBar, StatusBarStats, STATUS_BAR_HEIGHT,
-        PlayControlAction, PlayControls, SceneViewport, Selection,
-        SelectionRect, Toolbar, ViewportInputConfig,
+        PlayControlAction, PlayControls, Rulers, SceneViewport, Selection,
+        SelectionRect, Toolbar, PanMode, ViewportInputConfig,
         ViewportInputHandler, ViewportInputResult, AABB,
     };
 }
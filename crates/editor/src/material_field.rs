@@ -0,0 +1,97 @@
+//! Physics material row for the Collider inspector: a dropdown of the
+//! materials the scene can reference, plus "Custom" for hand-tuned values.
+
+use glam::Vec2;
+use physics::PhysicsMaterial;
+use ui::{Rect, UIContext};
+
+use crate::editable_inspector::{EditResult, EditableInspector};
+use crate::field_style::{EditableFieldStyle, FieldId};
+
+/// Dropdown entry for colliders not tied to a material.
+pub const CUSTOM_MATERIAL: &str = "Custom";
+
+impl EditableInspector<'_> {
+    /// Add a material dropdown row. `Changed(Some(material))` when a
+    /// material is picked, `Changed(None)` when "Custom" is.
+    pub fn material(
+        &mut self,
+        label: &str,
+        current: Option<&str>,
+        library: &[PhysicsMaterial],
+    ) -> EditResult<Option<PhysicsMaterial>> {
+        self.custom_row(|ui, id, pos, style| edit_material_field(ui, id, label, current, library, pos, style))
+    }
+}
+
+/// Dropdown options for a collider using `current`: "Custom", the library,
+/// and `current` itself when the library lacks it (an unresolved name is
+/// kept visible rather than silently shown as "Custom"). Returns the options
+/// and the selected index.
+pub fn material_options<'a>(current: Option<&'a str>, library: &'a [PhysicsMaterial]) -> (Vec<&'a str>, usize) {
+    let mut options: Vec<&str> = std::iter::once(CUSTOM_MATERIAL)
+        .chain(library.iter().map(|material| material.name.as_str()))
+        .collect();
+    let selected = match current {
+        None => 0,
+        Some(name) => match options.iter().skip(1).position(|option| *option == name) {
+            Some(index) => index + 1,
+            None => {
+                options.push(name);
+                options.len() - 1
+            }
+        },
+    };
+    (options, selected)
+}
+
+/// Render a material row: label and a dropdown of [`material_options`].
+pub fn edit_material_field(
+    ui: &mut UIContext,
+    id: FieldId,
+    label: &str,
+    current: Option<&str>,
+    library: &[PhysicsMaterial],
+    pos: Vec2,
+    style: &EditableFieldStyle,
+) -> EditResult<Option<PhysicsMaterial>> {
+    ui.label_styled(label, Vec2::new(pos.x, pos.y + 4.0), style.label_color, style.label_font);
+
+    let (options, selected) = material_options(current, library);
+    let bounds = Rect::new(pos.x + style.label_width, pos.y + 2.0, style.input_width, style.row_height - 4.0);
+    let picked = ui.dropdown(id, selected, &options, bounds);
+    if picked == selected {
+        return EditResult::Unchanged;
+    }
+    match picked.checked_sub(1).and_then(|index| library.get(index)) {
+        Some(material) => EditResult::Changed(Some(material.clone())),
+        // "Custom", or the unresolved name (keeps the collider's values)
+        None if picked == 0 => EditResult::Changed(None),
+        None => EditResult::Unchanged,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_material_options_select_current() {
+        let library = PhysicsMaterial::presets();
+        let (options, selected) = material_options(None, &library);
+        assert_eq!(options[0], CUSTOM_MATERIAL);
+        assert_eq!(selected, 0);
+        assert_eq!(options.len(), library.len() + 1);
+
+        let (options, selected) = material_options(Some("ice"), &library);
+        assert_eq!(options[selected], "ice");
+    }
+
+    #[test]
+    fn test_unknown_material_stays_listed() {
+        let library = PhysicsMaterial::presets();
+        let (options, selected) = material_options(Some("lava"), &library);
+        assert_eq!(options.len(), library.len() + 2);
+        assert_eq!(options[selected], "lava");
+    }
+}
@@ -0,0 +1,50 @@
+//! The View menu: panel toggles, scene-view display and navigation
+//! settings, and the dock layout presets.
+
+use super::{Menu, MenuItem};
+use crate::LayoutPreset;
+
+impl Menu {
+    /// The editor's View menu.
+    pub(super) fn view() -> Self {
+        let mut items = vec![
+            MenuItem::action("Scene View"),
+            MenuItem::action("Inspector"),
+            MenuItem::action("Hierarchy"),
+            MenuItem::action("Asset Browser"),
+            MenuItem::action("Console"),
+            MenuItem::action("World Stats"),
+            MenuItem::action("Layers"),
+            MenuItem::action("History"),
+            MenuItem::action("Scene Settings"),
+            MenuItem::action("Profiler"),
+            MenuItem::separator(),
+            MenuItem::action_with_shortcut("Toggle Grid", "G"),
+            MenuItem::action_with_shortcut("Toggle Colliders", "C"),
+            MenuItem::action("Toggle Rulers"),
+            MenuItem::action("Cycle Color Scheme"),
+            MenuItem::separator(),
+            MenuItem::action("Cycle Pan Mode"),
+            MenuItem::action("Toggle Zoom to Cursor"),
+            MenuItem::action("Toggle View Rotation"),
+            MenuItem::action("Toggle Trackpad Gestures"),
+            MenuItem::action("Toggle Right-Drag Pan"),
+            MenuItem::action("Toggle Alt-Drag Pan"),
+            MenuItem::action("Cycle Scroll Mode"),
+            MenuItem::action("Toggle Smooth Zoom"),
+            MenuItem::action("Reset View Rotation"),
+            MenuItem::separator(),
+            MenuItem::action_with_shortcut("Maximize Scene View", "Shift+Space"),
+            MenuItem::action_with_shortcut("Fullscreen Play Preview", "F11"),
+            MenuItem::separator(),
+        ];
+        items.extend(Self::layout_items());
+        Self::new("View").with_items(items)
+    }
+
+    /// One item per dock layout preset; the editor applies the preset whose
+    /// `menu_label` was picked.
+    fn layout_items() -> impl Iterator<Item = MenuItem> {
+        LayoutPreset::ALL.into_iter().map(|preset| MenuItem::action(preset.menu_label()))
+    }
+}
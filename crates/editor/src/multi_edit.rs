@@ -0,0 +1,327 @@
+//! Batch property editing for a multi-selection.
+//!
+//! The inspector draws a component's fields from the primary selection,
+//! but only for components every selected entity has. Each field cell
+//! (a label and, for Vec2/color rows, the axis or channel) whose value
+//! differs across the selection shows "—" instead of the primary's value;
+//! which cells differ is found by running the component's editor over every
+//! entity's value into a scratch UI and comparing what each would show.
+//!
+//! An edit made on the primary is turned into a patch of the serde JSON
+//! leaves it changed — so typing a new X leaves every entity's Y alone —
+//! and the patch is applied to each selected entity. The whole batch is
+//! one undo entry; slider drags merge into it like single-entity edits.
+//! The other entities' values go through a serde round-trip, so their
+//! runtime-only (`#[serde(skip)]`) state such as physics handles is reset;
+//! the physics sync re-links those from its own entity maps.
+
+use std::collections::{HashMap, HashSet};
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+
+use ecs::{Component, EntityId, World};
+use ui::UIContext;
+
+use crate::commands::{CommandHistory, EditorCommand, MacroCommand};
+use crate::component_editors::{apply_component_edit, ComponentEdit};
+use crate::{DragDropState, EditableInspector, InspectorExtras};
+
+/// One inspector field cell: row label plus axis/channel index.
+pub(crate) type FieldCell = (String, usize);
+
+/// The primary's value of `T`, if every entity in `entities` (primary
+/// first) has a `T` — only shared components are shown for a selection.
+pub(crate) fn shared_value<T: Component + Clone>(world: &World, entities: &[EntityId]) -> Option<T> {
+    let (&primary, rest) = entities.split_first()?;
+    if !rest.iter().all(|&entity| world.get::<T>(entity).is_some()) {
+        return None;
+    }
+    world.get::<T>(primary).cloned()
+}
+
+/// Cells of `T`'s editor whose shown value differs between the primary
+/// (first of `entities`) and any other selected entity. Empty for a single
+/// selection.
+pub(crate) fn mixed_fields<T: Component>(
+    world: &World,
+    entities: &[EntityId],
+    materials: &[physics::PhysicsMaterial],
+    edit_fn: impl Fn(&mut EditableInspector<'_>, &T, &mut InspectorExtras<'_>) -> Option<ComponentEdit<T>>,
+) -> HashSet<FieldCell> {
+    if entities.len() < 2 {
+        return HashSet::new();
+    }
+    let mut shown = entities
+        .iter()
+        .filter_map(|&entity| world.get::<T>(entity))
+        .map(|value| shown_values(value, &edit_fn, materials));
+    let Some(primary) = shown.next() else {
+        return HashSet::new();
+    };
+    let others: Vec<HashMap<FieldCell, String>> = shown.map(|values| values.into_iter().collect()).collect();
+    primary
+        .into_iter()
+        .filter(|(cell, value)| others.iter().any(|other| other.get(cell) != Some(value)))
+        .map(|(cell, _)| cell)
+        .collect()
+}
+
+/// What `edit_fn` shows for `value`, cell by cell, drawn into a scratch UI
+/// that never reaches the screen (and has no input, so nothing is edited).
+fn shown_values<T>(
+    value: &T,
+    edit_fn: &impl Fn(&mut EditableInspector<'_>, &T, &mut InspectorExtras<'_>) -> Option<ComponentEdit<T>>,
+    materials: &[physics::PhysicsMaterial],
+) -> Vec<(FieldCell, String)> {
+    let mut ui = UIContext::new();
+    let mut drag_drop = DragDropState::new();
+    let mut extras = InspectorExtras {
+        drag_drop: &mut drag_drop,
+        texture_display: None,
+        texture_size: glam::Vec2::ZERO,
+        pick_texture: false,
+        region_names: &[],
+        pick_region: false,
+        materials,
+        layers: &[],
+        animation_preview: false,
+        toggle_animation_preview: false,
+        clipboard_type: None,
+        component_menu: None,
+    };
+    let mut inspector = EditableInspector::new(&mut ui, 0.0, 0.0);
+    edit_fn(&mut inspector, value, &mut extras);
+    inspector.into_shown_values()
+}
+
+/// Apply an inspector edit made on the primary (first of `entities`) to
+/// every selected entity. A single selection goes through
+/// [`apply_component_edit`] unchanged; a multi-selection writes each entity
+/// and records one [`MacroCommand`] of per-entity `make_cmd` commands.
+pub fn apply_multi_edit<T: Component + Clone + Serialize + DeserializeOwned>(
+    world: &mut World,
+    entities: &[EntityId],
+    old: &T,
+    edit: Option<ComponentEdit<T>>,
+    history: &mut CommandHistory,
+    make_cmd: impl Fn(EntityId, T, T, &'static str) -> Box<dyn EditorCommand>,
+) {
+    let (Some((&primary, rest)), Some(edit)) = (entities.split_first(), edit) else {
+        return;
+    };
+    if rest.is_empty() {
+        apply_component_edit(world, primary, old, Some(edit), history, make_cmd);
+        return;
+    }
+    let Some(patch) = FieldPatch::between(old, &edit.new_value, edit.field_hint) else {
+        return;
+    };
+
+    let mut commands = Vec::new();
+    for &entity in entities {
+        let Some(current) = world.get::<T>(entity).cloned() else {
+            continue;
+        };
+        let new_value = if entity == primary {
+            edit.new_value.clone()
+        } else {
+            match patch.apply(&current) {
+                Some(value) => value,
+                None => continue,
+            }
+        };
+        if let Some(component) = world.get_mut::<T>(entity) {
+            *component = new_value.clone();
+        }
+        commands.push(make_cmd(entity, current, new_value, edit.field_hint));
+    }
+    let Some(first) = commands.first() else {
+        return;
+    };
+    let name = format!("{} ({} entities)", first.display_name(), commands.len());
+    history.try_merge_or_push(Box::new(MacroCommand::new(name, commands)));
+}
+
+/// One step into a JSON value.
+#[derive(Debug, Clone, PartialEq)]
+enum PathStep {
+    Key(String),
+    Index(usize),
+}
+
+/// The JSON leaves an edit changed: each path with its new value, or
+/// `None` where the field disappeared (e.g. an `Option` skipped when empty).
+#[derive(Debug, Clone, PartialEq)]
+struct FieldPatch {
+    leaves: Vec<(Vec<PathStep>, Option<Value>)>,
+}
+
+impl FieldPatch {
+    /// The leaves that differ between `old` and `new`. When nothing differs
+    /// (a mixed field committed with the primary's own value) the patch
+    /// sets the whole `field_hint` field instead, if it is a top-level one.
+    fn between<T: Serialize>(old: &T, new: &T, field_hint: &str) -> Option<Self> {
+        let (old, new) = (serde_json::to_value(old).ok()?, serde_json::to_value(new).ok()?);
+        let mut leaves = Vec::new();
+        changed_leaves(&old, &new, &mut Vec::new(), &mut leaves);
+        if leaves.is_empty() {
+            let value = new.get(field_hint)?.clone();
+            leaves.push((vec![PathStep::Key(field_hint.to_string())], Some(value)));
+        }
+        Some(Self { leaves })
+    }
+
+    /// `target` with every leaf that has somewhere to go set. `None` if the
+    /// result no longer deserializes as a `T`.
+    fn apply<T: Serialize + DeserializeOwned>(&self, target: &T) -> Option<T> {
+        let mut value = serde_json::to_value(target).ok()?;
+        for (path, leaf) in &self.leaves {
+            set_leaf(&mut value, path, leaf.clone());
+        }
+        serde_json::from_value(value).ok()
+    }
+}
+
+/// Collect the leaves of `new` that differ from `old` into `out`. Objects
+/// and same-length arrays are compared member by member; a switched enum
+/// variant (a one-key object whose key changed) is replaced whole.
+fn changed_leaves(old: &Value, new: &Value, path: &mut Vec<PathStep>, out: &mut Vec<(Vec<PathStep>, Option<Value>)>) {
+    match (old, new) {
+        (Value::Object(a), Value::Object(b)) if !(a.len() == 1 && b.len() == 1 && a.keys().ne(b.keys())) => {
+            for (key, new_field) in b {
+                path.push(PathStep::Key(key.clone()));
+                match a.get(key) {
+                    Some(old_field) => changed_leaves(old_field, new_field, path, out),
+                    None => out.push((path.clone(), Some(new_field.clone()))),
+                }
+                path.pop();
+            }
+            for key in a.keys().filter(|key| !b.contains_key(*key)) {
+                path.push(PathStep::Key(key.clone()));
+                out.push((path.clone(), None));
+                path.pop();
+            }
+        }
+        (Value::Array(a), Value::Array(b)) if a.len() == b.len() => {
+            for (index, (old_item, new_item)) in a.iter().zip(b).enumerate() {
+                path.push(PathStep::Index(index));
+                changed_leaves(old_item, new_item, path, out);
+                path.pop();
+            }
+        }
+        _ if old != new => out.push((path.clone(), Some(new.clone()))),
+        _ => {}
+    }
+}
+
+/// Set (or remove, for `None`) the leaf at `path` in `value`. A path whose
+/// parent doesn't exist in this value — another collider shape, a shorter
+/// frame list — is skipped.
+fn set_leaf(value: &mut Value, path: &[PathStep], leaf: Option<Value>) {
+    let Some((last, parents)) = path.split_last() else {
+        if let Some(leaf) = leaf {
+            *value = leaf;
+        }
+        return;
+    };
+    let mut parent = value;
+    for step in parents {
+        let child = match step {
+            PathStep::Key(key) => parent.get_mut(key.as_str()),
+            PathStep::Index(index) => parent.get_mut(*index),
+        };
+        match child {
+            Some(child) => parent = child,
+            None => return,
+        }
+    }
+    match (last, parent, leaf) {
+        (PathStep::Key(key), Value::Object(fields), Some(leaf)) => {
+            fields.insert(key.clone(), leaf);
+        }
+        (PathStep::Key(key), Value::Object(fields), None) => {
+            fields.remove(key);
+        }
+        (PathStep::Index(index), Value::Array(items), Some(leaf)) => {
+            if let Some(item) = items.get_mut(*index) {
+                *item = leaf;
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Transform2D;
+    use ecs::sprite_components::Sprite;
+    use glam::Vec2;
+
+    use crate::commands::SetTransformCommand;
+
+    #[test]
+    fn test_patch_changes_only_the_edited_axis() {
+        let old = Transform2D::new(Vec2::new(1.0, 2.0));
+        let new = Transform2D::new(Vec2::new(5.0, 2.0));
+        let patch = FieldPatch::between(&old, &new, "position").unwrap();
+        assert_eq!(patch.leaves.len(), 1);
+
+        let other = Transform2D::new(Vec2::new(-3.0, 9.0));
+        assert_eq!(patch.apply(&other).unwrap().position, Vec2::new(5.0, 9.0));
+    }
+
+    #[test]
+    fn test_patch_without_a_change_sets_the_hinted_field() {
+        let sprite = Sprite { depth: 4.0, ..Sprite::new(0) };
+        let patch = FieldPatch::between(&sprite, &sprite, "depth").unwrap();
+        let other = Sprite { depth: -1.0, ..Sprite::new(3) };
+        let patched = patch.apply(&other).unwrap();
+        assert_eq!(patched.depth, 4.0);
+        assert_eq!(patched.texture_handle, 3, "other fields are untouched");
+    }
+
+    #[test]
+    fn test_multi_edit_writes_every_entity_as_one_undo_entry() {
+        let mut world = World::new();
+        let entities: Vec<EntityId> = [1.0, 2.0, 3.0]
+            .into_iter()
+            .map(|y| {
+                let entity = world.create_entity();
+                world.add_component(&entity, Transform2D::new(Vec2::new(0.0, y))).unwrap();
+                entity
+            })
+            .collect();
+        let mut history = CommandHistory::new();
+        let old = *world.get::<Transform2D>(entities[0]).unwrap();
+        let edit = ComponentEdit { new_value: Transform2D::new(Vec2::new(7.0, 1.0)), field_hint: "position" };
+        apply_multi_edit(&mut world, &entities, &old, Some(edit), &mut history, |e, o, n, h| {
+            Box::new(SetTransformCommand::new(e, o, n, h))
+        });
+
+        let positions = |world: &World| -> Vec<Vec2> {
+            entities.iter().map(|&e| world.get::<Transform2D>(e).unwrap().position).collect()
+        };
+        assert_eq!(positions(&world), [Vec2::new(7.0, 1.0), Vec2::new(7.0, 2.0), Vec2::new(7.0, 3.0)]);
+
+        history.undo(&mut world);
+        assert_eq!(positions(&world), [Vec2::new(0.0, 1.0), Vec2::new(0.0, 2.0), Vec2::new(0.0, 3.0)]);
+        assert!(!history.can_undo(), "the batch is a single entry");
+    }
+
+    #[test]
+    fn test_mixed_fields_flags_differing_cells_only() {
+        let mut world = World::new();
+        let a = world.create_entity();
+        let b = world.create_entity();
+        world.add_component(&a, Sprite { depth: 1.0, ..Sprite::new(0) }).unwrap();
+        world.add_component(&b, Sprite { depth: 2.0, ..Sprite::new(0) }).unwrap();
+
+        let mixed = mixed_fields(&world, &[a, b], &[], crate::edit_sprite);
+        assert_eq!(mixed, HashSet::from([("Depth".to_string(), 0)]));
+        assert!(mixed_fields(&world, &[a], &[], crate::edit_sprite).is_empty());
+        assert_eq!(shared_value::<Sprite>(&world, &[a, b]).map(|s| s.depth), Some(1.0));
+    }
+}
@@ -0,0 +1,91 @@
+//! Paused-edit indicator for the scene view.
+//!
+//! While a play session is paused the physics simulation doesn't run, so
+//! transform, velocity, and collider edits made in the editor haven't
+//! reached the rapier bodies yet — `PhysicsSystem` pushes them on the first
+//! update after resume. This overlay marks every body with such pending
+//! edits (`physics::pending_physics_edits`) with a diamond at its position,
+//! so it's clear which entities the resume will move.
+
+use common::Transform2D;
+use ecs::{EntityId, World};
+use glam::Vec2;
+use ui::{Color, Rect, UIContext};
+
+use crate::viewport::SceneViewport;
+
+/// Half the diamond's diagonal, in screen pixels.
+const MARKER_RADIUS: f32 = 7.0;
+/// Diamond outline width, in screen pixels.
+const MARKER_WIDTH: f32 = 2.0;
+
+/// Screen positions of bodies whose edits physics hasn't seen yet.
+pub fn physics_sync_markers(world: &World, viewport: &SceneViewport) -> Vec<(EntityId, Vec2)> {
+    physics::pending_physics_edits(world)
+        .into_iter()
+        .filter_map(|entity| {
+            let transform = world.get::<Transform2D>(entity)?;
+            Some((entity, viewport.world_to_screen(transform.position)))
+        })
+        .collect()
+}
+
+/// Draw a diamond on every body with pending physics edits, clipped to the
+/// scene-view `bounds`.
+pub fn render_physics_sync_overlay(
+    ui: &mut UIContext,
+    world: &World,
+    viewport: &SceneViewport,
+    color: Color,
+    bounds: Rect,
+) {
+    let markers = physics_sync_markers(world, viewport);
+    if markers.is_empty() {
+        return;
+    }
+    ui.push_clip_rect(bounds);
+    for (_, center) in markers {
+        let points = [
+            center + Vec2::new(0.0, -MARKER_RADIUS),
+            center + Vec2::new(MARKER_RADIUS, 0.0),
+            center + Vec2::new(0.0, MARKER_RADIUS),
+            center + Vec2::new(-MARKER_RADIUS, 0.0),
+        ];
+        for i in 0..4 {
+            ui.line(points[i], points[(i + 1) % 4], color, MARKER_WIDTH);
+        }
+    }
+    ui.pop_clip_rect();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ecs::System;
+    use physics::{Collider, PhysicsConfig, PhysicsSystem, RigidBody};
+
+    #[test]
+    fn test_only_bodies_edited_since_the_physics_update_are_marked() {
+        let mut world = World::new();
+        let mut physics = PhysicsSystem::with_config(PhysicsConfig::new(Vec2::ZERO));
+        let moved = world
+            .spawn((
+                Transform2D::new(Vec2::ZERO),
+                RigidBody::new_dynamic(),
+                Collider::box_collider(10.0, 10.0),
+            ))
+            .id();
+        world
+            .spawn((Transform2D::new(Vec2::new(50.0, 0.0)), RigidBody::new_static()))
+            .id();
+        physics.update(&mut world, 1.0 / 60.0);
+        let viewport = SceneViewport::new();
+        assert!(physics_sync_markers(&world, &viewport).is_empty());
+
+        if let Some(transform) = world.get_mut::<Transform2D>(moved) {
+            transform.position = Vec2::new(30.0, 20.0);
+        }
+        let markers = physics_sync_markers(&world, &viewport);
+        assert_eq!(markers, vec![(moved, viewport.world_to_screen(Vec2::new(30.0, 20.0)))]);
+    }
+}
@@ -0,0 +1,132 @@
+//! World-space bounding boxes for picking, rectangle selection and
+//! selection framing (`union` of the selected entities' boxes).
+
+use glam::Vec2;
+
+/// An axis-aligned bounding box in world coordinates.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AABB {
+    /// Minimum corner (bottom-left in world coords)
+    pub min: Vec2,
+    /// Maximum corner (top-right in world coords)
+    pub max: Vec2,
+}
+
+impl AABB {
+    /// Create a new AABB from min and max corners.
+    pub fn new(min: Vec2, max: Vec2) -> Self {
+        Self { min, max }
+    }
+
+    /// Create an AABB from center and half-extents.
+    pub fn from_center_half_extents(center: Vec2, half_extents: Vec2) -> Self {
+        Self {
+            min: center - half_extents,
+            max: center + half_extents,
+        }
+    }
+
+    /// Create an AABB from position and size.
+    pub fn from_position_size(position: Vec2, size: Vec2) -> Self {
+        let half = size * 0.5;
+        Self::from_center_half_extents(position, half)
+    }
+
+    /// Get the center of the AABB.
+    pub fn center(&self) -> Vec2 {
+        (self.min + self.max) * 0.5
+    }
+
+    /// Get the size (width, height) of the AABB.
+    pub fn size(&self) -> Vec2 {
+        self.max - self.min
+    }
+
+    /// Check if a point is inside the AABB.
+    pub fn contains_point(&self, point: Vec2) -> bool {
+        point.x >= self.min.x
+            && point.x <= self.max.x
+            && point.y >= self.min.y
+            && point.y <= self.max.y
+    }
+
+    /// Check if this AABB intersects another AABB.
+    pub fn intersects(&self, other: &AABB) -> bool {
+        self.min.x <= other.max.x
+            && self.max.x >= other.min.x
+            && self.min.y <= other.max.y
+            && self.max.y >= other.min.y
+    }
+
+    /// The smallest AABB containing both boxes.
+    pub fn union(&self, other: &AABB) -> Self {
+        Self {
+            min: self.min.min(other.min),
+            max: self.max.max(other.max),
+        }
+    }
+
+    /// Expand the AABB by a margin on all sides.
+    pub fn expand(&self, margin: f32) -> Self {
+        Self {
+            min: self.min - Vec2::splat(margin),
+            max: self.max + Vec2::splat(margin),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_aabb_contains_point() {
+        let aabb = AABB::new(Vec2::new(-10.0, -10.0), Vec2::new(10.0, 10.0));
+
+        assert!(aabb.contains_point(Vec2::ZERO));
+        assert!(aabb.contains_point(Vec2::new(5.0, 5.0)));
+        assert!(aabb.contains_point(Vec2::new(-10.0, -10.0))); // On edge
+        assert!(!aabb.contains_point(Vec2::new(15.0, 0.0)));
+        assert!(!aabb.contains_point(Vec2::new(0.0, 15.0)));
+    }
+
+    #[test]
+    fn test_aabb_intersects() {
+        let aabb1 = AABB::new(Vec2::new(0.0, 0.0), Vec2::new(10.0, 10.0));
+        let aabb2 = AABB::new(Vec2::new(5.0, 5.0), Vec2::new(15.0, 15.0));
+        let aabb3 = AABB::new(Vec2::new(20.0, 20.0), Vec2::new(30.0, 30.0));
+
+        assert!(aabb1.intersects(&aabb2)); // Overlapping
+        assert!(!aabb1.intersects(&aabb3)); // Not overlapping
+    }
+
+    #[test]
+    fn test_aabb_from_position_size() {
+        let aabb = AABB::from_position_size(Vec2::new(10.0, 20.0), Vec2::new(6.0, 4.0));
+
+        assert_eq!(aabb.center(), Vec2::new(10.0, 20.0));
+        assert_eq!(aabb.size(), Vec2::new(6.0, 4.0));
+        assert_eq!(aabb.min, Vec2::new(7.0, 18.0));
+        assert_eq!(aabb.max, Vec2::new(13.0, 22.0));
+    }
+
+    #[test]
+    fn test_aabb_expand() {
+        let aabb = AABB::new(Vec2::new(0.0, 0.0), Vec2::new(10.0, 10.0));
+        let expanded = aabb.expand(5.0);
+
+        assert_eq!(expanded.min, Vec2::new(-5.0, -5.0));
+        assert_eq!(expanded.max, Vec2::new(15.0, 15.0));
+    }
+
+    #[test]
+    fn test_aabb_union_covers_both_boxes() {
+        let a = AABB::new(Vec2::new(0.0, 0.0), Vec2::new(10.0, 10.0));
+        let b = AABB::new(Vec2::new(-5.0, 4.0), Vec2::new(3.0, 20.0));
+        let union = a.union(&b);
+
+        assert_eq!(union.min, Vec2::new(-5.0, 0.0));
+        assert_eq!(union.max, Vec2::new(10.0, 20.0));
+        assert_eq!(union, b.union(&a));
+    }
+}
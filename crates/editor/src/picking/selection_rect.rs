@@ -0,0 +1,186 @@
+//! Rubber-band rectangle selection state and its screen-space overlay
+//! sprites.
+
+use glam::Vec2;
+use renderer::sprite::Sprite;
+use renderer::texture::TextureHandle;
+
+/// Selection rectangle state for rectangle selection.
+#[derive(Debug, Clone, Default)]
+pub struct SelectionRect {
+    /// Start position in screen coordinates
+    pub start: Vec2,
+    /// Current end position in screen coordinates
+    pub end: Vec2,
+    /// Whether the selection rect is active
+    pub active: bool,
+}
+
+impl SelectionRect {
+    /// Create a new inactive selection rect.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start a selection at the given screen position.
+    pub fn begin(&mut self, pos: Vec2) {
+        self.start = pos;
+        self.end = pos;
+        self.active = true;
+    }
+
+    /// Update the selection end position.
+    pub fn update(&mut self, pos: Vec2) {
+        if self.active {
+            self.end = pos;
+        }
+    }
+
+    /// End the selection and return the final rect.
+    pub fn end(&mut self) -> Option<(Vec2, Vec2)> {
+        if self.active {
+            self.active = false;
+            Some((self.start, self.end))
+        } else {
+            None
+        }
+    }
+
+    /// Cancel the selection.
+    pub fn cancel(&mut self) {
+        self.active = false;
+    }
+
+    /// Get the normalized rect (min, max in screen coords).
+    pub fn normalized(&self) -> (Vec2, Vec2) {
+        (
+            Vec2::new(self.start.x.min(self.end.x), self.start.y.min(self.end.y)),
+            Vec2::new(self.start.x.max(self.end.x), self.start.y.max(self.end.y)),
+        )
+    }
+
+    /// Get the rect size.
+    pub fn size(&self) -> Vec2 {
+        let (min, max) = self.normalized();
+        max - min
+    }
+
+    /// Check if the rect is large enough to be considered a drag (not a click).
+    pub fn is_drag(&self, threshold: f32) -> bool {
+        self.size().length() > threshold
+    }
+
+    /// Generate a sprite for rendering the selection rect.
+    pub fn to_sprite(&self, white_texture: TextureHandle, window_size: Vec2) -> Sprite {
+        let (min, max) = self.normalized();
+        let size = max - min;
+        let center = (min + max) * 0.5;
+
+        // Convert screen coords to world coords for rendering
+        // Screen: origin top-left, Y down
+        // World: origin center, Y up
+        let world_center = Vec2::new(
+            center.x - window_size.x * 0.5,
+            window_size.y * 0.5 - center.y,
+        );
+
+        Sprite::new(white_texture)
+            .with_position(world_center)
+            .with_scale(size)
+            .with_color(glam::Vec4::new(0.3, 0.5, 1.0, 0.2)) // Light blue, transparent
+            .with_depth(100.0) // On top of everything
+    }
+
+    /// Generate a border sprite for the selection rect.
+    pub fn to_border_sprite(&self, white_texture: TextureHandle, window_size: Vec2) -> Vec<Sprite> {
+        let (min, max) = self.normalized();
+        let border_thickness = 1.0;
+        let color = glam::Vec4::new(0.3, 0.5, 1.0, 0.8);
+        let depth = 100.0;
+
+        let mut sprites = Vec::new();
+
+        // Convert to world coordinates
+        let to_world = |screen: Vec2| -> Vec2 {
+            Vec2::new(
+                screen.x - window_size.x * 0.5,
+                window_size.y * 0.5 - screen.y,
+            )
+        };
+
+        let width = max.x - min.x;
+        let height = max.y - min.y;
+
+        // Top edge
+        let top_center = to_world(Vec2::new(min.x + width * 0.5, min.y));
+        sprites.push(
+            Sprite::new(white_texture)
+                .with_position(top_center)
+                .with_scale(Vec2::new(width, border_thickness))
+                .with_color(color)
+                .with_depth(depth),
+        );
+
+        // Bottom edge
+        let bottom_center = to_world(Vec2::new(min.x + width * 0.5, max.y));
+        sprites.push(
+            Sprite::new(white_texture)
+                .with_position(bottom_center)
+                .with_scale(Vec2::new(width, border_thickness))
+                .with_color(color)
+                .with_depth(depth),
+        );
+
+        // Left edge
+        let left_center = to_world(Vec2::new(min.x, min.y + height * 0.5));
+        sprites.push(
+            Sprite::new(white_texture)
+                .with_position(left_center)
+                .with_scale(Vec2::new(border_thickness, height))
+                .with_color(color)
+                .with_depth(depth),
+        );
+
+        // Right edge
+        let right_center = to_world(Vec2::new(max.x, min.y + height * 0.5));
+        sprites.push(
+            Sprite::new(white_texture)
+                .with_position(right_center)
+                .with_scale(Vec2::new(border_thickness, height))
+                .with_color(color)
+                .with_depth(depth),
+        );
+
+        sprites
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_selection_rect_normalized() {
+        let mut rect = SelectionRect::new();
+        rect.begin(Vec2::new(100.0, 200.0));
+        rect.update(Vec2::new(50.0, 150.0)); // End is before start
+
+        let (min, max) = rect.normalized();
+        assert_eq!(min, Vec2::new(50.0, 150.0));
+        assert_eq!(max, Vec2::new(100.0, 200.0));
+    }
+
+    #[test]
+    fn test_selection_rect_is_drag() {
+        let mut rect = SelectionRect::new();
+        rect.begin(Vec2::new(100.0, 100.0));
+
+        // Small movement - not a drag
+        rect.update(Vec2::new(102.0, 102.0));
+        assert!(!rect.is_drag(5.0));
+
+        // Large movement - is a drag
+        rect.update(Vec2::new(150.0, 150.0));
+        assert!(rect.is_drag(5.0));
+    }
+}
@@ -0,0 +1,103 @@
+use super::*;
+
+#[test]
+fn test_pick_single_entity() {
+    let mut picker = EntityPicker::new();
+    let mut viewport = SceneViewport::new();
+    viewport.set_viewport_bounds(common::Rect::new(0.0, 0.0, 800.0, 600.0));
+
+    let entities = vec![PickableEntity::new(
+        EntityId::with_generation(1, 1),
+        Vec2::new(0.0, 0.0),
+        Vec2::new(50.0, 50.0),
+        0.0,
+    )];
+
+    // Click at viewport center (world origin)
+    let result = picker.pick_at_screen_pos(&viewport, Vec2::new(400.0, 300.0), &entities);
+
+    assert_eq!(result.len(), 1);
+    assert_eq!(result.topmost(), Some(EntityId::with_generation(1, 1)));
+}
+
+#[test]
+fn test_pick_miss() {
+    let mut picker = EntityPicker::new();
+    let mut viewport = SceneViewport::new();
+    viewport.set_viewport_bounds(common::Rect::new(0.0, 0.0, 800.0, 600.0));
+
+    let entities = vec![PickableEntity::new(
+        EntityId::with_generation(1, 1),
+        Vec2::new(100.0, 100.0), // Entity at (100, 100)
+        Vec2::new(10.0, 10.0),
+        0.0,
+    )];
+
+    // Click at viewport center (world origin) - should miss
+    let result = picker.pick_at_screen_pos(&viewport, Vec2::new(400.0, 300.0), &entities);
+
+    assert!(result.is_empty());
+}
+
+#[test]
+fn test_pick_depth_sorting() {
+    let mut picker = EntityPicker::new();
+    let mut viewport = SceneViewport::new();
+    viewport.set_viewport_bounds(common::Rect::new(0.0, 0.0, 800.0, 600.0));
+
+    let entities = vec![
+        PickableEntity::new(EntityId::with_generation(1, 1), Vec2::ZERO, Vec2::new(50.0, 50.0), 0.0),
+        PickableEntity::new(EntityId::with_generation(2, 1), Vec2::ZERO, Vec2::new(50.0, 50.0), 10.0), // Higher depth
+        PickableEntity::new(EntityId::with_generation(3, 1), Vec2::ZERO, Vec2::new(50.0, 50.0), 5.0),
+    ];
+
+    let result = picker.pick_at_screen_pos(&viewport, Vec2::new(400.0, 300.0), &entities);
+
+    assert_eq!(result.len(), 3);
+    // Should be sorted by depth, highest first
+    assert_eq!(result.hits[0], EntityId::with_generation(2, 1)); // depth 10
+    assert_eq!(result.hits[1], EntityId::with_generation(3, 1)); // depth 5
+    assert_eq!(result.hits[2], EntityId::with_generation(1, 1)); // depth 0
+}
+
+#[test]
+fn test_pick_in_rect() {
+    let picker = EntityPicker::new();
+    let mut viewport = SceneViewport::new();
+    viewport.set_viewport_bounds(common::Rect::new(0.0, 0.0, 800.0, 600.0));
+
+    let entities = vec![
+        PickableEntity::new(
+            EntityId::with_generation(1, 1),
+            Vec2::new(-50.0, 50.0),
+            Vec2::new(10.0, 10.0),
+            0.0,
+        ),
+        PickableEntity::new(
+            EntityId::with_generation(2, 1),
+            Vec2::new(50.0, -50.0),
+            Vec2::new(10.0, 10.0),
+            0.0,
+        ),
+        PickableEntity::new(
+            EntityId::with_generation(3, 1),
+            Vec2::new(200.0, 200.0), // Outside rect
+            Vec2::new(10.0, 10.0),
+            0.0,
+        ),
+    ];
+
+    // Select rectangle around entities 1 and 2 (but not 3)
+    // Screen coords: top-left to bottom-right
+    let result = picker.pick_in_screen_rect(
+        &viewport,
+        Vec2::new(300.0, 200.0), // Screen top-left
+        Vec2::new(500.0, 400.0), // Screen bottom-right
+        &entities,
+    );
+
+    assert_eq!(result.len(), 2);
+    assert!(result.hits.contains(&EntityId::with_generation(1, 1)));
+    assert!(result.hits.contains(&EntityId::with_generation(2, 1)));
+    assert!(!result.hits.contains(&EntityId::with_generation(3, 1)));
+}
@@ -0,0 +1,459 @@
+//! Play-mode tweaks that survive Stop ("keep changes after stop").
+//!
+//! Stopping play restores the world from the [`WorldSnapshot`] taken at
+//! Play, discarding everything that happened during the session. Designers
+//! can instead mark individual fields (from the play-mode inspector) or whole
+//! selected entities to keep: just before the restore, the play world is
+//! diffed against the snapshot, and the chosen changes are re-applied to the
+//! restored edit world as a single undoable command.
+//!
+//! Values edited in the inspector during the session are marked as such
+//! ([`PlayChanges::mark_edited`]). Unless kept, they revert at Stop like
+//! everything else, but are then offered back
+//! ([`PlayChanges::offered_edits`]): the inspector asks whether to keep the
+//! edited values or revert all (the default), so tuning done during a
+//! playtest isn't lost by accident.
+//!
+//! Diffs are field-level over each inspectable component's serde JSON
+//! (top-level fields; non-struct components such as `Behavior` compare
+//! whole). Entities created or deleted during play, and components added or
+//! removed during play, are not carried over.
+
+use serde_json::Value;
+
+use ecs::{EntityId, World};
+
+use crate::commands::{EditorCommand, MacroCommand, SetStoredComponentCommand};
+use crate::stored_component::{capture_inspectable_components, StoredComponent};
+use crate::world_snapshot::WorldSnapshot;
+
+/// Identifies one keepable field: `entity`'s `component.field`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FieldKey {
+    /// Entity the field belongs to.
+    pub entity: EntityId,
+    /// Registry name of the component (e.g. `"Transform2D"`).
+    pub component: &'static str,
+    /// Top-level field name; `None` for non-struct components kept whole.
+    pub field: Option<String>,
+}
+
+/// A field whose play-world value differs from the edit snapshot.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldChange {
+    /// Which field changed.
+    pub key: FieldKey,
+    /// The play-world value.
+    pub value: Value,
+}
+
+impl FieldChange {
+    /// Display label, e.g. `"Transform2D.position"`.
+    pub fn label(&self) -> String {
+        match &self.key.field {
+            Some(field) => format!("{}.{}", self.key.component, field),
+            None => self.key.component.to_string(),
+        }
+    }
+}
+
+/// Diff `entity`'s inspectable components in the play `world` against the
+/// edit-state `snapshot`, one [`FieldChange`] per differing top-level field.
+pub fn diff_entity(snapshot: &WorldSnapshot, world: &World, entity: EntityId) -> Vec<FieldChange> {
+    diff_against(world, entity, |name| snapshot.component_json(entity, name))
+}
+
+/// Fields of `entity` that differ from `before` (its inspectable components
+/// captured earlier, e.g. just before the inspector ran this frame).
+pub fn edited_fields(before: &[StoredComponent], world: &World, entity: EntityId) -> Vec<FieldKey> {
+    diff_against(world, entity, |name| {
+        before.iter().find(|c| c.type_name() == name).and_then(StoredComponent::to_json)
+    })
+    .into_iter()
+    .map(|change| change.key)
+    .collect()
+}
+
+/// Diff `entity`'s inspectable components against the JSON `baseline`
+/// returns for each component name. Components without a baseline are
+/// skipped.
+fn diff_against(
+    world: &World,
+    entity: EntityId,
+    baseline: impl Fn(&str) -> Option<Value>,
+) -> Vec<FieldChange> {
+    let mut changes = Vec::new();
+    for component in capture_inspectable_components(world, entity) {
+        let name = component.type_name();
+        let (Some(play), Some(edit)) = (component.to_json(), baseline(name)) else {
+            continue;
+        };
+        if play == edit {
+            continue;
+        }
+        match (&play, &edit) {
+            (Value::Object(play_fields), Value::Object(edit_fields)) => {
+                for (field, value) in play_fields {
+                    if edit_fields.get(field) != Some(value) {
+                        changes.push(FieldChange {
+                            key: FieldKey { entity, component: name, field: Some(field.clone()) },
+                            value: value.clone(),
+                        });
+                    }
+                }
+            }
+            _ => changes.push(FieldChange {
+                key: FieldKey { entity, component: name, field: None },
+                value: play,
+            }),
+        }
+    }
+    changes
+}
+
+/// The set of play-mode changes chosen to survive Stop, plus the live diff
+/// of the primary selection shown in the play-mode inspector.
+#[derive(Debug, Default)]
+pub struct PlayChanges {
+    /// Individually kept fields.
+    kept_fields: Vec<FieldKey>,
+    /// Entities whose every change is kept.
+    kept_entities: Vec<EntityId>,
+    /// Fields edited in the inspector this session.
+    edited_fields: Vec<FieldKey>,
+    /// Diff of the primary selection, refreshed each frame during play.
+    selection_changes: Vec<FieldChange>,
+    /// Edited values reverted by the last Stop, offered to keep.
+    offered_edits: Vec<FieldChange>,
+}
+
+impl PlayChanges {
+    /// Create an empty set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Toggle whether a field is kept. Returns `true` if it is now kept.
+    pub fn toggle_field(&mut self, key: FieldKey) -> bool {
+        if let Some(index) = self.kept_fields.iter().position(|k| *k == key) {
+            self.kept_fields.remove(index);
+            false
+        } else {
+            self.kept_fields.push(key);
+            true
+        }
+    }
+
+    /// Whether a field is kept, individually or through its entity.
+    pub fn is_field_kept(&self, key: &FieldKey) -> bool {
+        self.kept_entities.contains(&key.entity) || self.kept_fields.contains(key)
+    }
+
+    /// Keep every change on `entities` (the "keep changes for selected
+    /// entities" command). Returns how many entities were newly added.
+    pub fn keep_entities(&mut self, entities: impl IntoIterator<Item = EntityId>) -> usize {
+        let mut added = 0;
+        for entity in entities {
+            if !self.kept_entities.contains(&entity) {
+                self.kept_entities.push(entity);
+                added += 1;
+            }
+        }
+        added
+    }
+
+    /// Whether every change on `entity` is kept.
+    pub fn is_entity_kept(&self, entity: EntityId) -> bool {
+        self.kept_entities.contains(&entity)
+    }
+
+    /// Mark fields as edited in the inspector during the session. Edited
+    /// values that aren't kept are offered back after Stop.
+    pub fn mark_edited(&mut self, keys: impl IntoIterator<Item = FieldKey>) {
+        for key in keys {
+            if !self.edited_fields.contains(&key) {
+                self.edited_fields.push(key);
+            }
+        }
+    }
+
+    /// Whether a field was edited in the inspector during the session.
+    pub fn is_field_edited(&self, key: &FieldKey) -> bool {
+        self.edited_fields.contains(key)
+    }
+
+    /// Number of fields edited in the inspector during the session.
+    pub fn edited_count(&self) -> usize {
+        self.edited_fields.len()
+    }
+
+    /// Whether nothing is marked to keep.
+    pub fn is_empty(&self) -> bool {
+        self.kept_fields.is_empty() && self.kept_entities.is_empty()
+    }
+
+    /// Forget everything marked to keep or edited, and any offered edits
+    /// (called when a play session starts and when the scene changes).
+    pub fn clear(&mut self) {
+        self.kept_fields.clear();
+        self.kept_entities.clear();
+        self.edited_fields.clear();
+        self.selection_changes.clear();
+        self.offered_edits.clear();
+    }
+
+    /// Recompute the diff shown for the primary selection.
+    pub fn refresh_selection(&mut self, snapshot: &WorldSnapshot, world: &World, entity: Option<EntityId>) {
+        self.selection_changes = entity
+            .map(|entity| diff_entity(snapshot, world, entity))
+            .unwrap_or_default();
+    }
+
+    /// Changed fields of the primary selection (from the last refresh).
+    pub fn selection_changes(&self) -> &[FieldChange] {
+        &self.selection_changes
+    }
+
+    /// Collect the kept changes with their current play-world values and
+    /// reset the set. Call at Stop, BEFORE the snapshot is restored.
+    ///
+    /// Edited fields that aren't kept (and still differ from the snapshot)
+    /// become the [`offered_edits`](Self::offered_edits).
+    pub fn take_kept_changes(&mut self, snapshot: &WorldSnapshot, world: &World) -> Vec<FieldChange> {
+        let mut entities: Vec<EntityId> = self.kept_entities.clone();
+        for key in self.kept_fields.iter().chain(&self.edited_fields) {
+            if !entities.contains(&key.entity) {
+                entities.push(key.entity);
+            }
+        }
+
+        let (kept, unkept): (Vec<FieldChange>, Vec<FieldChange>) = entities
+            .into_iter()
+            .flat_map(|entity| diff_entity(snapshot, world, entity))
+            .partition(|change| self.is_field_kept(&change.key));
+        let offered = unkept.into_iter().filter(|change| self.is_field_edited(&change.key)).collect();
+        self.clear();
+        self.offered_edits = offered;
+        kept
+    }
+
+    /// Values edited during the last session that Stop reverted, with their
+    /// play-world values. Empty once kept or discarded.
+    pub fn offered_edits(&self) -> &[FieldChange] {
+        &self.offered_edits
+    }
+
+    /// Take the offered edits to re-apply them ("Keep Edited Values"; pass
+    /// to [`apply_play_changes`]).
+    pub fn take_offered_edits(&mut self) -> Vec<FieldChange> {
+        std::mem::take(&mut self.offered_edits)
+    }
+
+    /// Drop the offered edits ("Revert All", the default).
+    pub fn discard_offered_edits(&mut self) {
+        self.offered_edits.clear();
+    }
+}
+
+/// Re-apply kept play-mode changes onto the restored edit `world`.
+///
+/// Changes are grouped per component, merged into the edit-state value and
+/// written back. Returns the already-executed undo command (one entry for
+/// the whole Stop) and the number of components updated, or `None` if
+/// nothing applied. Values that no longer deserialize are skipped with a
+/// warning.
+pub fn apply_play_changes(world: &mut World, changes: &[FieldChange]) -> Option<(Box<dyn EditorCommand>, usize)> {
+    let mut groups: Vec<(EntityId, &'static str, Vec<&FieldChange>)> = Vec::new();
+    for change in changes {
+        match groups
+            .iter_mut()
+            .find(|(entity, component, _)| *entity == change.key.entity && *component == change.key.component)
+        {
+            Some((_, _, group)) => group.push(change),
+            None => groups.push((change.key.entity, change.key.component, vec![change])),
+        }
+    }
+
+    let mut commands: Vec<Box<dyn EditorCommand>> = Vec::new();
+    for (entity, component, group) in groups {
+        let Some(old) = capture_inspectable_components(world, entity)
+            .into_iter()
+            .find(|c| c.type_name() == component)
+        else {
+            continue;
+        };
+        let Some(mut value) = old.to_json() else {
+            continue;
+        };
+        for change in group {
+            match (&change.key.field, &mut value) {
+                (Some(field), Value::Object(fields)) => {
+                    fields.insert(field.clone(), change.value.clone());
+                }
+                _ => value = change.value.clone(),
+            }
+        }
+        match StoredComponent::from_json(component, value) {
+            Ok(new) => {
+                new.apply_to(world, entity);
+                commands.push(Box::new(SetStoredComponentCommand::new(entity, old, new)));
+            }
+            Err(e) => log::warn!("Could not keep play-mode change: {}", e),
+        }
+    }
+
+    if commands.is_empty() {
+        return None;
+    }
+    let count = commands.len();
+    Some((Box::new(MacroCommand::new("Keep Play Changes", commands)), count))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ecs::sprite_components::Sprite;
+    use glam::Vec2;
+
+    /// Edit world with one sprite entity, its snapshot, and the entity id.
+    fn edit_world() -> (World, WorldSnapshot, EntityId) {
+        let mut world = World::new();
+        let entity = world.create_entity();
+        world.add_component(&entity, common::Transform2D::new(Vec2::ZERO)).ok();
+        world.add_component(&entity, Sprite::new(1)).ok();
+        let snapshot = WorldSnapshot::capture(&world);
+        (world, snapshot, entity)
+    }
+
+    fn move_entity(world: &mut World, entity: EntityId, to: Vec2) {
+        if let Some(t) = world.get_mut::<common::Transform2D>(entity) {
+            t.position = to;
+        }
+    }
+
+    #[test]
+    fn unchanged_entity_has_no_diff() {
+        let (world, snapshot, entity) = edit_world();
+        assert!(diff_entity(&snapshot, &world, entity).is_empty());
+    }
+
+    #[test]
+    fn diff_reports_only_changed_fields() {
+        let (mut world, snapshot, entity) = edit_world();
+        move_entity(&mut world, entity, Vec2::new(5.0, 6.0));
+
+        let changes = diff_entity(&snapshot, &world, entity);
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].label(), "Transform2D.position");
+    }
+
+    #[test]
+    fn kept_field_survives_restore_and_undo_reverts_it() {
+        let (mut world, snapshot, entity) = edit_world();
+        move_entity(&mut world, entity, Vec2::new(5.0, 6.0));
+
+        let mut play_changes = PlayChanges::new();
+        let key = diff_entity(&snapshot, &world, entity)[0].key.clone();
+        assert!(play_changes.toggle_field(key));
+
+        let kept = play_changes.take_kept_changes(&snapshot, &world);
+        assert!(play_changes.is_empty(), "taking the changes resets the set");
+        snapshot.restore(&mut world);
+
+        let (mut command, count) = apply_play_changes(&mut world, &kept).expect("change applied");
+        assert_eq!(count, 1);
+        let position = world.get::<common::Transform2D>(entity).map(|t| t.position);
+        assert_eq!(position, Some(Vec2::new(5.0, 6.0)));
+
+        command.undo(&mut world);
+        let position = world.get::<common::Transform2D>(entity).map(|t| t.position);
+        assert_eq!(position, Some(Vec2::ZERO));
+    }
+
+    #[test]
+    fn unkept_changes_are_discarded() {
+        let (mut world, snapshot, entity) = edit_world();
+        move_entity(&mut world, entity, Vec2::new(5.0, 6.0));
+
+        let mut play_changes = PlayChanges::new();
+        assert!(play_changes.take_kept_changes(&snapshot, &world).is_empty());
+        assert!(apply_play_changes(&mut world, &[]).is_none());
+    }
+
+    #[test]
+    fn kept_entity_keeps_every_changed_field() {
+        let (mut world, snapshot, entity) = edit_world();
+        move_entity(&mut world, entity, Vec2::new(1.0, 1.0));
+        if let Some(sprite) = world.get_mut::<Sprite>(entity) {
+            sprite.depth = 4.0;
+        }
+
+        let mut play_changes = PlayChanges::new();
+        assert_eq!(play_changes.keep_entities([entity, entity]), 1);
+        let kept = play_changes.take_kept_changes(&snapshot, &world);
+        assert_eq!(kept.len(), 2);
+
+        snapshot.restore(&mut world);
+        let (_, count) = apply_play_changes(&mut world, &kept).expect("changes applied");
+        assert_eq!(count, 2, "one command per changed component");
+        assert_eq!(world.get::<Sprite>(entity).map(|s| s.depth), Some(4.0));
+    }
+
+    #[test]
+    fn edited_values_revert_at_stop_and_are_offered_back() {
+        let (mut world, snapshot, entity) = edit_world();
+        let before = capture_inspectable_components(&world, entity);
+        move_entity(&mut world, entity, Vec2::new(0.0, 3.0));
+        let edited = edited_fields(&before, &world, entity);
+        assert_eq!(edited.len(), 1, "only the inspector-edited field");
+
+        let mut play_changes = PlayChanges::new();
+        play_changes.mark_edited(edited.clone());
+        play_changes.mark_edited(edited);
+        assert_eq!(play_changes.edited_count(), 1);
+        // The simulation moves other things too; those aren't offered
+        if let Some(sprite) = world.get_mut::<Sprite>(entity) {
+            sprite.depth = 2.0;
+        }
+
+        assert!(play_changes.take_kept_changes(&snapshot, &world).is_empty(), "revert all by default");
+        snapshot.restore(&mut world);
+        assert_eq!(play_changes.offered_edits().len(), 1);
+        assert_eq!(play_changes.offered_edits()[0].label(), "Transform2D.position");
+
+        let offered = play_changes.take_offered_edits();
+        assert!(play_changes.offered_edits().is_empty());
+        apply_play_changes(&mut world, &offered).expect("kept on request");
+        let position = world.get::<common::Transform2D>(entity).map(|t| t.position);
+        assert_eq!(position, Some(Vec2::new(0.0, 3.0)));
+        assert_eq!(world.get::<Sprite>(entity).map(|s| s.depth), Some(0.0));
+    }
+
+    #[test]
+    fn kept_edits_are_not_offered_again() {
+        let (mut world, snapshot, entity) = edit_world();
+        let before = capture_inspectable_components(&world, entity);
+        move_entity(&mut world, entity, Vec2::new(0.0, 3.0));
+
+        let mut play_changes = PlayChanges::new();
+        play_changes.mark_edited(edited_fields(&before, &world, entity));
+        play_changes.keep_entities([entity]);
+        assert_eq!(play_changes.take_kept_changes(&snapshot, &world).len(), 1);
+        assert!(play_changes.offered_edits().is_empty());
+
+        play_changes.mark_edited(edited_fields(&before, &world, entity));
+        play_changes.take_kept_changes(&snapshot, &world);
+        play_changes.discard_offered_edits();
+        assert!(play_changes.offered_edits().is_empty());
+    }
+
+    #[test]
+    fn toggling_a_kept_field_unkeeps_it() {
+        let (_, _, entity) = edit_world();
+        let mut play_changes = PlayChanges::new();
+        let key = FieldKey { entity, component: "Transform2D", field: Some("position".to_string()) };
+        assert!(play_changes.toggle_field(key.clone()));
+        assert!(!play_changes.toggle_field(key.clone()));
+        assert!(!play_changes.is_field_kept(&key));
+    }
+}
@@ -1,11 +1,13 @@
 //! Play / Pause / Stop controls for the editor toolbar.
 //!
 //! Renders context-sensitive buttons next to the tool toolbar and returns
-//! the action the user clicked, if any.
+//! the action the user clicked, if any. While paused, Step buttons run
+//! single frames (see [`FrameStepper`](crate::FrameStepper)).
 
 use glam::Vec2;
 use ui::{Rect, UIContext};
 
+use crate::frame_step::{DEFAULT_STEP_COUNT, MAX_STEP_COUNT};
 use crate::play_state::EditorPlayState;
 use crate::theme::EditorTheme;
 
@@ -18,6 +20,8 @@ pub enum PlayControlAction {
     Pause,
     /// Stop the game and restore the pre-play snapshot.
     Stop,
+    /// Run this many single frames while paused.
+    Step(u32),
 }
 
 /// Play control widget rendered to the right of the tool toolbar.
@@ -29,6 +33,8 @@ pub struct PlayControls {
     pub button_size: f32,
     /// Spacing between buttons.
     pub spacing: f32,
+    /// Frames the "+N" step button runs.
+    pub step_count: u32,
 }
 
 impl Default for PlayControls {
@@ -44,6 +50,7 @@ impl PlayControls {
             position: Vec2::ZERO,
             button_size: 40.0,
             spacing: 4.0,
+            step_count: DEFAULT_STEP_COUNT,
         }
     }
 
@@ -52,9 +59,9 @@ impl PlayControls {
     /// Button layout varies by state:
     /// - **Editing:** `[Play]`
     /// - **Playing:** `[Pause] [Stop]`
-    /// - **Paused:**  `[Resume] [Stop]`
+    /// - **Paused:**  `[Resume] [Stop] [Step] [+N] [N]`
     pub fn render(
-        &self,
+        &mut self,
         ui: &mut UIContext,
         state: EditorPlayState,
         theme: &EditorTheme,
@@ -106,11 +113,40 @@ impl PlayControls {
                 if ui.button("play_ctrl_stop2", "Stop", stop_btn) {
                     action = Some(PlayControlAction::Stop);
                 }
+
+                let step_x = stop_x + self.button_size + self.spacing * 3.0;
+                if let Some(frames) = self.render_step_controls(ui, Vec2::new(step_x, y)) {
+                    action = Some(PlayControlAction::Step(frames));
+                }
             }
         }
 
         action
     }
+
+    /// `[Step] [+N] [N]`: one frame, `step_count` frames, and the count
+    /// itself. Returns the frames to run when a Step button is clicked.
+    fn render_step_controls(&mut self, ui: &mut UIContext, at: Vec2) -> Option<u32> {
+        let step_btn = Rect::new(at.x, at.y, self.button_size, self.button_size);
+        let step = ui.button("play_ctrl_step", "Step", step_btn);
+
+        let many_x = at.x + self.button_size + self.spacing;
+        let many_btn = Rect::new(many_x, at.y, self.button_size, self.button_size);
+        let many = ui.button("play_ctrl_step_n", &format!("+{}", self.step_count), many_btn);
+
+        let count_x = many_x + self.button_size + self.spacing;
+        let count_bounds = Rect::new(count_x, at.y + (self.button_size - 22.0) / 2.0, 44.0, 22.0);
+        let count = ui.float_input("play_ctrl_step_count", self.step_count as f32, 1.0, MAX_STEP_COUNT as f32, count_bounds);
+        self.step_count = (count.round() as u32).clamp(1, MAX_STEP_COUNT);
+
+        if step {
+            Some(1)
+        } else if many {
+            Some(self.step_count)
+        } else {
+            None
+        }
+    }
 }
 
 #[cfg(test)]
@@ -122,6 +158,7 @@ mod tests {
         let controls = PlayControls::new();
         assert_eq!(controls.button_size, 40.0);
         assert_eq!(controls.spacing, 4.0);
+        assert_eq!(controls.step_count, DEFAULT_STEP_COUNT);
     }
 
     #[test]
@@ -129,5 +166,6 @@ mod tests {
         assert_eq!(PlayControlAction::Play, PlayControlAction::Play);
         assert_ne!(PlayControlAction::Play, PlayControlAction::Pause);
         assert_ne!(PlayControlAction::Pause, PlayControlAction::Stop);
+        assert_ne!(PlayControlAction::Step(1), PlayControlAction::Step(10));
     }
 }
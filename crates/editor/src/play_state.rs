@@ -41,7 +41,7 @@ impl EditorPlayState {
 
     /// Human-readable label for the current state.
     ///
-    /// Viewport border tinting lives on the theme: `EditorTheme::play_state_border`.
+    /// Viewport border tinting lives on the theme: `EditorColorScheme::play_state_border`.
     pub fn label(self) -> &'static str {
         match self {
             EditorPlayState::Editing => "Editing",
@@ -0,0 +1,220 @@
+//! Dock panels contributed by games and plugins.
+//!
+//! A game implements [`EditorPanel`] for a custom tool (a wave-spawner
+//! tuner, a dialogue preview) and registers it with
+//! [`EditorContext::add_panel`], or from a plugin's `build` through the
+//! engine's plugin builder (see `editor_integration::EditorPluginExt`). The
+//! editor docks each one and calls its `render` for the panel's content
+//! area, like the built-in panels.
+
+use std::fmt;
+
+use common::Rect;
+use ecs::World;
+use ui::UIContext;
+
+use crate::context::EditorContext;
+use crate::dock::{DockArea, DockPanel, DockPosition, PanelId};
+use crate::selection::Selection;
+use crate::theme::EditorTheme;
+
+/// A custom editor dock panel.
+pub trait EditorPanel: Send + Sync + 'static {
+    /// Stable identifier; registering another panel with the same id
+    /// replaces this one. Defaults to the title.
+    fn id(&self) -> &str {
+        self.title()
+    }
+
+    /// Title shown in the panel header.
+    fn title(&self) -> &str;
+
+    /// Dock edge the panel starts on.
+    fn position(&self) -> DockPosition {
+        DockPosition::Right
+    }
+
+    /// Draw the panel content into `bounds` (already clipped). The panel
+    /// may read or change the editor selection (e.g. "select all enemies").
+    fn render(&mut self, ui: &mut UIContext, world: &mut World, selection: &mut Selection, bounds: Rect, theme: &EditorTheme);
+}
+
+/// Custom panels with the dock ids assigned to them.
+#[derive(Default)]
+pub struct PluginPanels {
+    panels: Vec<(PanelId, Box<dyn EditorPanel>)>,
+}
+
+impl PluginPanels {
+    /// First dock id handed to custom panels (built-ins stay below it).
+    pub const FIRST_ID: u32 = 100;
+
+    /// Create an empty set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a panel, returning its dock id. A panel whose [`id`](EditorPanel::id)
+    /// is already registered replaces the old one and keeps its dock id.
+    pub fn add(&mut self, panel: impl EditorPanel) -> PanelId {
+        self.add_boxed(Box::new(panel))
+    }
+
+    /// [`add`](Self::add) for an already boxed panel.
+    pub fn add_boxed(&mut self, panel: Box<dyn EditorPanel>) -> PanelId {
+        if let Some((id, existing)) = self.panels.iter_mut().find(|(_, existing)| existing.id() == panel.id()) {
+            *existing = panel;
+            return *id;
+        }
+        let id = PanelId(Self::FIRST_ID + self.panels.len() as u32);
+        self.panels.push((id, panel));
+        id
+    }
+
+    /// Move every panel of `other` into this set.
+    pub fn append(&mut self, other: PluginPanels) {
+        for (_, panel) in other.panels {
+            self.add_boxed(panel);
+        }
+    }
+
+    /// Dock id of the panel registered as `id`.
+    pub fn panel_id(&self, id: &str) -> Option<PanelId> {
+        self.panels.iter().find(|(_, panel)| panel.id() == id).map(|(panel_id, _)| *panel_id)
+    }
+
+    /// Number of panels.
+    pub fn len(&self) -> usize {
+        self.panels.len()
+    }
+
+    /// Whether no panels were added.
+    pub fn is_empty(&self) -> bool {
+        self.panels.is_empty()
+    }
+
+    /// Dock every panel that isn't docked yet.
+    pub fn install(&self, dock_area: &mut DockArea) {
+        for (id, panel) in &self.panels {
+            if dock_area.get_panel(*id).is_none() {
+                dock_area.add_panel(DockPanel::new(*id, panel.title(), panel.position()));
+            }
+        }
+    }
+
+    /// Render the custom panel with `id`. Returns false when `id` is not a
+    /// custom panel (a built-in renders it instead).
+    pub fn render(
+        &mut self,
+        id: PanelId,
+        ui: &mut UIContext,
+        world: &mut World,
+        selection: &mut Selection,
+        bounds: Rect,
+        theme: &EditorTheme,
+    ) -> bool {
+        let Some((_, panel)) = self.panels.iter_mut().find(|(panel_id, _)| *panel_id == id) else {
+            return false;
+        };
+        panel.render(ui, world, selection, bounds, theme);
+        true
+    }
+}
+
+impl fmt::Debug for PluginPanels {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.panels.iter().map(|(id, panel)| (id, panel.id()))).finish()
+    }
+}
+
+impl EditorContext {
+    /// Register a custom panel and dock it, returning its dock id.
+    ///
+    /// ```
+    /// # use editor::{EditorContext, EditorPanel, EditorTheme, Selection};
+    /// struct WaveTuner { wave: u32 }
+    ///
+    /// impl EditorPanel for WaveTuner {
+    ///     fn title(&self) -> &str { "Waves" }
+    ///     fn render(&mut self, ui: &mut ui::UIContext, _world: &mut ecs::World, _selection: &mut Selection,
+    ///               bounds: common::Rect, theme: &EditorTheme) {
+    ///         ui.label(&format!("Wave {}", self.wave), glam::Vec2::new(bounds.x + 8.0, bounds.y + 8.0));
+    ///     }
+    /// }
+    ///
+    /// let mut editor = EditorContext::new();
+    /// let id = editor.add_panel(WaveTuner { wave: 1 });
+    /// assert!(editor.dock_area.get_panel(id).is_some());
+    /// ```
+    pub fn add_panel(&mut self, panel: impl EditorPanel) -> PanelId {
+        let id = self.plugin_panels.add(panel);
+        self.plugin_panels.install(&mut self.dock_area);
+        id
+    }
+
+    /// Register and dock every panel of `panels` (what plugins registered
+    /// on the engine builder).
+    pub fn install_panels(&mut self, panels: PluginPanels) {
+        self.plugin_panels.append(panels);
+        self.plugin_panels.install(&mut self.dock_area);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Counter {
+        renders: u32,
+    }
+
+    impl EditorPanel for Counter {
+        fn title(&self) -> &str {
+            "Counter"
+        }
+        fn position(&self) -> DockPosition {
+            DockPosition::Bottom
+        }
+        fn render(&mut self, _ui: &mut UIContext, world: &mut World, selection: &mut Selection, _bounds: Rect, _theme: &EditorTheme) {
+            self.renders += 1;
+            selection.select(world.create_entity());
+        }
+    }
+
+    #[test]
+    fn test_plugin_panels_dock_and_render_by_id() {
+        let mut panels = PluginPanels::new();
+        let id = panels.add(Counter { renders: 0 });
+        assert_eq!(id, PanelId(PluginPanels::FIRST_ID));
+
+        let mut dock = DockArea::new();
+        panels.install(&mut dock);
+        panels.install(&mut dock);
+        assert_eq!(dock.panels().len(), 1, "installing twice docks once");
+        let docked = dock.get_panel(id).map(|p| (p.title.as_str(), p.position));
+        assert_eq!(docked, Some(("Counter", DockPosition::Bottom)));
+
+        let mut ui = UIContext::new();
+        let mut world = World::new();
+        let mut selection = Selection::new();
+        let theme = EditorTheme::default();
+        let bounds = Rect::new(0.0, 0.0, 100.0, 100.0);
+        assert!(panels.render(id, &mut ui, &mut world, &mut selection, bounds, &theme));
+        assert!(!panels.render(PanelId::INSPECTOR, &mut ui, &mut world, &mut selection, bounds, &theme));
+        assert_eq!(world.entity_count(), 1);
+        assert_eq!(selection.len(), 1, "panels can change the selection");
+    }
+
+    #[test]
+    fn test_context_panels_replace_by_id() {
+        let mut editor = EditorContext::new();
+        let id = editor.add_panel(Counter { renders: 0 });
+        let mut from_plugin = PluginPanels::new();
+        from_plugin.add(Counter { renders: 5 });
+        editor.install_panels(from_plugin);
+
+        assert_eq!(editor.plugin_panels.len(), 1, "same id replaces");
+        assert_eq!(editor.plugin_panels.panel_id("Counter"), Some(id));
+        assert_eq!(editor.dock_area.panels().iter().filter(|p| p.id == id).count(), 1);
+    }
+}
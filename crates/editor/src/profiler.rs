@@ -0,0 +1,213 @@
+//! Profiler panel data: a rolling history of play-mode frames — how long
+//! each update pass took (the game, plugin systems, physics, particles) and
+//! how much was drawn — so regressions show up without exporting a build.
+//!
+//! The integration layer records one [`ProfilerSample`] per played frame;
+//! the panel draws the history as stacked bars plus a per-pass table.
+
+use std::collections::VecDeque;
+
+use ecs::SystemTiming;
+
+/// Frames kept by a new [`Profiler`] (4 seconds at 60 fps).
+pub const PROFILER_HISTORY: usize = 240;
+
+/// Frame time budget at 60 fps, in milliseconds (the graph's reference line).
+pub const FRAME_BUDGET_MS: f32 = 1000.0 / 60.0;
+
+/// One profiled frame.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ProfilerSample {
+    /// Update passes in frame order
+    pub timings: Vec<SystemTiming>,
+    /// Sprites drawn
+    pub sprites: usize,
+    /// GPU draw calls
+    pub draw_calls: usize,
+    /// Live entities at the end of the frame
+    pub entities: usize,
+}
+
+impl ProfilerSample {
+    /// Sum of the pass times in milliseconds.
+    pub fn total_ms(&self) -> f32 {
+        self.timings.iter().map(SystemTiming::millis).sum()
+    }
+}
+
+/// Latest, average and peak time of one pass over the history.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProfilerRow {
+    /// Pass name
+    pub name: String,
+    /// Time in the latest frame (ms); 0 when it didn't run then
+    pub latest_ms: f32,
+    /// Average over the frames it ran in (ms)
+    pub average_ms: f32,
+    /// Slowest frame (ms)
+    pub peak_ms: f32,
+}
+
+/// Rolling history of profiled frames, oldest first.
+#[derive(Debug, Clone)]
+pub struct Profiler {
+    samples: VecDeque<ProfilerSample>,
+    capacity: usize,
+}
+
+impl Default for Profiler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Profiler {
+    /// A profiler keeping [`PROFILER_HISTORY`] frames.
+    pub fn new() -> Self {
+        Self::with_capacity(PROFILER_HISTORY)
+    }
+
+    /// A profiler keeping the last `capacity` frames (at least one).
+    pub fn with_capacity(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        Self { samples: VecDeque::with_capacity(capacity), capacity }
+    }
+
+    /// Frames kept.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Add a frame, dropping the oldest once the history is full.
+    pub fn record(&mut self, sample: ProfilerSample) {
+        if self.samples.len() == self.capacity {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(sample);
+    }
+
+    /// Forget every frame (a new play session starts).
+    pub fn clear(&mut self) {
+        self.samples.clear();
+    }
+
+    /// Recorded frames, oldest first.
+    pub fn samples(&self) -> impl Iterator<Item = &ProfilerSample> {
+        self.samples.iter()
+    }
+
+    /// The newest frame.
+    pub fn latest(&self) -> Option<&ProfilerSample> {
+        self.samples.back()
+    }
+
+    /// Number of recorded frames.
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    /// Whether no frame has been recorded.
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    /// Slowest recorded frame total (ms).
+    pub fn peak_total_ms(&self) -> f32 {
+        self.samples.iter().map(ProfilerSample::total_ms).fold(0.0, f32::max)
+    }
+
+    /// Per-pass statistics, in first-seen order (passes that stopped
+    /// running stay listed until they leave the history).
+    pub fn rows(&self) -> Vec<ProfilerRow> {
+        let mut rows: Vec<(ProfilerRow, usize)> = Vec::new();
+        for sample in &self.samples {
+            for timing in &sample.timings {
+                let ms = timing.millis();
+                match rows.iter_mut().find(|(row, _)| row.name == timing.name) {
+                    Some((row, count)) => {
+                        row.average_ms += ms;
+                        row.peak_ms = row.peak_ms.max(ms);
+                        *count += 1;
+                    }
+                    None => rows.push((
+                        ProfilerRow { name: timing.name.clone(), latest_ms: 0.0, average_ms: ms, peak_ms: ms },
+                        1,
+                    )),
+                }
+            }
+        }
+        let latest = self.latest();
+        rows.into_iter()
+            .map(|(mut row, count)| {
+                row.average_ms /= count as f32;
+                row.latest_ms = latest
+                    .and_then(|sample| sample.timings.iter().find(|timing| timing.name == row.name))
+                    .map_or(0.0, SystemTiming::millis);
+                row
+            })
+            .collect()
+    }
+
+    /// Top of the graph's time axis (ms): the frame budget, or the peak
+    /// frame rounded up to a whole budget multiple when it's over.
+    pub fn graph_scale_ms(&self) -> f32 {
+        let budgets = (self.peak_total_ms() / FRAME_BUDGET_MS).ceil().max(1.0);
+        budgets * FRAME_BUDGET_MS
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(update_ms: f32, physics_ms: f32) -> ProfilerSample {
+        ProfilerSample {
+            timings: vec![
+                SystemTiming::new("update", update_ms / 1000.0),
+                SystemTiming::new("physics", physics_ms / 1000.0),
+            ],
+            sprites: 10,
+            draw_calls: 2,
+            entities: 12,
+        }
+    }
+
+    #[test]
+    fn test_history_keeps_the_newest_frames() {
+        let mut profiler = Profiler::with_capacity(3);
+        for ms in 1..=5 {
+            profiler.record(sample(ms as f32, 0.0));
+        }
+        assert_eq!(profiler.len(), 3);
+        let totals: Vec<f32> = profiler.samples().map(|s| s.total_ms().round()).collect();
+        assert_eq!(totals, [3.0, 4.0, 5.0]);
+
+        profiler.clear();
+        assert!(profiler.is_empty() && profiler.latest().is_none());
+    }
+
+    #[test]
+    fn test_rows_report_latest_average_and_peak_per_pass() {
+        let mut profiler = Profiler::new();
+        profiler.record(sample(2.0, 6.0));
+        profiler.record(sample(4.0, 1.0));
+
+        let rows = profiler.rows();
+        let names: Vec<&str> = rows.iter().map(|row| row.name.as_str()).collect();
+        assert_eq!(names, ["update", "physics"]);
+        let physics = &rows[1];
+        assert!((physics.latest_ms - 1.0).abs() < 1e-4);
+        assert!((physics.average_ms - 3.5).abs() < 1e-4);
+        assert!((physics.peak_ms - 6.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_graph_scale_grows_in_frame_budgets() {
+        let mut profiler = Profiler::new();
+        assert_eq!(profiler.graph_scale_ms(), FRAME_BUDGET_MS);
+        profiler.record(sample(10.0, 2.0));
+        assert_eq!(profiler.graph_scale_ms(), FRAME_BUDGET_MS);
+        profiler.record(sample(30.0, 5.0));
+        assert!((profiler.graph_scale_ms() - 3.0 * FRAME_BUDGET_MS).abs() < 1e-4);
+    }
+}
@@ -0,0 +1,198 @@
+//! Scene-view rulers.
+//!
+//! A strip along the top and left edges of the scene view shows world X
+//! and Y under the current camera, with labelled major ticks at a "nice"
+//! step (1, 2 or 5 × 10ⁿ world units) that keeps labels readable at any
+//! zoom, and a marker at the mouse position. While the view is rotated the
+//! world axes no longer run along the edges, so the rulers hide.
+
+use glam::Vec2;
+use ui::{Rect, UIContext};
+
+use crate::theme::EditorTheme;
+use crate::viewport::SceneViewport;
+
+/// Ruler thickness, in screen pixels.
+pub const RULER_SIZE: f32 = 16.0;
+/// Minimum screen distance between labelled ticks.
+const MIN_LABEL_SPACING: f32 = 72.0;
+/// Unlabelled ticks per labelled step.
+const SUBDIVISIONS: i64 = 5;
+/// More ticks than this per ruler (degenerate zoom) draws none.
+const MAX_TICKS: i64 = 2_000;
+
+/// One ruler tick.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RulerTick {
+    /// World coordinate of the tick
+    pub value: f32,
+    /// Labelled tick (a multiple of the ruler step)
+    pub major: bool,
+}
+
+/// Smallest 1/2/5 × 10ⁿ world step whose ticks are at least
+/// `MIN_LABEL_SPACING` pixels apart at `zoom`.
+pub fn ruler_step(zoom: f32) -> f32 {
+    let min_world = MIN_LABEL_SPACING / zoom.max(f32::EPSILON);
+    let magnitude = 10f32.powf(min_world.log10().floor());
+    [1.0, 2.0, 5.0]
+        .into_iter()
+        .map(|m| m * magnitude)
+        .find(|step| *step >= min_world)
+        .unwrap_or(10.0 * magnitude)
+}
+
+/// Ticks covering `min..=max`: a major tick every `step`, minor ticks in
+/// between. Empty for an unusable range or step.
+pub fn ruler_ticks(min: f32, max: f32, step: f32) -> Vec<RulerTick> {
+    if !(min.is_finite() && max.is_finite() && step.is_finite() && step > 0.0) {
+        return Vec::new();
+    }
+    let minor = step / SUBDIVISIONS as f32;
+    let first = (min / minor).ceil() as i64;
+    let last = (max / minor).floor() as i64;
+    if last - first > MAX_TICKS {
+        return Vec::new();
+    }
+    (first..=last)
+        .map(|i| RulerTick { value: i as f32 * minor, major: i.rem_euclid(SUBDIVISIONS) == 0 })
+        .collect()
+}
+
+/// Label for a major tick: as many decimals as `step` needs, no `-0`.
+pub fn format_ruler_value(value: f32, step: f32) -> String {
+    let decimals = if step >= 1.0 { 0 } else { (-step.log10() - 1e-3).ceil() as usize };
+    let value = if value.abs() < step * 1e-3 { 0.0 } else { value };
+    format!("{value:.decimals$}")
+}
+
+/// The scene-view rulers and their visibility (View > Toggle Rulers).
+#[derive(Debug, Clone)]
+pub struct Rulers {
+    visible: bool,
+}
+
+impl Default for Rulers {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Rulers {
+    /// Visible rulers.
+    pub fn new() -> Self {
+        Self { visible: true }
+    }
+
+    /// Whether the rulers are drawn.
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    /// Show or hide the rulers.
+    pub fn set_visible(&mut self, visible: bool) {
+        self.visible = visible;
+    }
+
+    /// Toggle visibility.
+    pub fn toggle(&mut self) {
+        self.visible = !self.visible;
+    }
+
+    /// Draw the rulers along the top and left of the scene-view `bounds`,
+    /// marking `cursor` (screen position) when it is inside them.
+    pub fn render(
+        &self,
+        ui: &mut UIContext,
+        viewport: &SceneViewport,
+        bounds: Rect,
+        theme: &EditorTheme,
+        cursor: Option<Vec2>,
+    ) {
+        if !self.visible || viewport.is_rotated() {
+            return;
+        }
+        let step = ruler_step(viewport.camera_zoom());
+        let top = Rect::new(bounds.x, bounds.y, bounds.width, RULER_SIZE);
+        let left = Rect::new(bounds.x, bounds.y + RULER_SIZE, RULER_SIZE, bounds.height - RULER_SIZE);
+        let font_size = theme.fonts.small;
+
+        ui.push_clip_rect(bounds);
+        ui.rect(top, theme.bg_header);
+        ui.rect(left, theme.bg_header);
+
+        let x_min = viewport.screen_to_world(Vec2::new(left.right(), top.y)).x;
+        let x_max = viewport.screen_to_world(Vec2::new(top.right(), top.y)).x;
+        for tick in ruler_ticks(x_min, x_max, step) {
+            let x = viewport.world_to_screen(Vec2::new(tick.value, 0.0)).x;
+            let length = if tick.major { RULER_SIZE } else { RULER_SIZE * 0.3 };
+            ui.line(Vec2::new(x, top.bottom() - length), Vec2::new(x, top.bottom()), theme.text_muted, 1.0);
+            if tick.major {
+                let label = format_ruler_value(tick.value, step);
+                ui.label_styled(&label, Vec2::new(x + 3.0, top.y + font_size), theme.text_secondary, font_size);
+            }
+        }
+
+        // Screen Y grows downward, world Y upward: the bottom edge is the minimum.
+        let y_min = viewport.screen_to_world(Vec2::new(left.x, left.bottom())).y;
+        let y_max = viewport.screen_to_world(Vec2::new(left.x, left.y)).y;
+        for tick in ruler_ticks(y_min, y_max, step) {
+            let y = viewport.world_to_screen(Vec2::new(0.0, tick.value)).y;
+            let length = if tick.major { RULER_SIZE } else { RULER_SIZE * 0.3 };
+            ui.line(Vec2::new(left.right() - length, y), Vec2::new(left.right(), y), theme.text_muted, 1.0);
+            if tick.major {
+                // Too narrow for the text: it sits just above the tick,
+                // running into the scene.
+                let label = format_ruler_value(tick.value, step);
+                ui.label_styled(&label, Vec2::new(left.x + 2.0, y - 3.0), theme.text_secondary, font_size);
+            }
+        }
+
+        ui.line(Vec2::new(left.right(), top.bottom()), Vec2::new(top.right(), top.bottom()), theme.border_subtle, 1.0);
+        ui.line(Vec2::new(left.right(), top.bottom()), Vec2::new(left.right(), left.bottom()), theme.border_subtle, 1.0);
+        ui.rect(Rect::new(bounds.x, bounds.y, RULER_SIZE, RULER_SIZE), theme.bg_header);
+
+        if let Some(cursor) = cursor.filter(|c| bounds.contains(*c)) {
+            ui.line(Vec2::new(cursor.x, top.y), Vec2::new(cursor.x, top.bottom()), theme.accent_cyan, 1.0);
+            ui.line(Vec2::new(left.x, cursor.y), Vec2::new(left.right(), cursor.y), theme.accent_cyan, 1.0);
+        }
+        ui.pop_clip_rect();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ruler_step_is_nice_and_wide_enough() {
+        assert_eq!(ruler_step(1.0), 100.0);
+        assert_eq!(ruler_step(2.0), 50.0);
+        assert_eq!(ruler_step(0.1), 1000.0);
+        assert_eq!(ruler_step(10.0), 10.0);
+        assert_eq!(ruler_step(100.0), 1.0);
+        assert_eq!(ruler_step(30.0), 5.0);
+        for zoom in [0.05, 0.3, 1.0, 7.0, 40.0] {
+            assert!(ruler_step(zoom) * zoom >= MIN_LABEL_SPACING - 1e-3);
+        }
+    }
+
+    #[test]
+    fn test_ruler_ticks_cover_range_with_majors_on_steps() {
+        let ticks = ruler_ticks(-25.0, 105.0, 50.0);
+        assert_eq!(ticks.first().map(|t| t.value), Some(-20.0));
+        assert_eq!(ticks.last().map(|t| t.value), Some(100.0));
+        let majors: Vec<f32> = ticks.iter().filter(|t| t.major).map(|t| t.value).collect();
+        assert_eq!(majors, [0.0, 50.0, 100.0]);
+        assert!(ruler_ticks(0.0, 1.0, 0.0).is_empty());
+        assert!(ruler_ticks(0.0, 1e9, 1.0).is_empty(), "too dense");
+    }
+
+    #[test]
+    fn test_format_ruler_value_uses_step_precision() {
+        assert_eq!(format_ruler_value(200.0, 100.0), "200");
+        assert_eq!(format_ruler_value(-1.5, 0.5), "-1.5");
+        assert_eq!(format_ruler_value(0.25, 0.05), "0.25");
+        assert_eq!(format_ruler_value(-0.000_01, 0.1), "0.0");
+    }
+}
@@ -0,0 +1,73 @@
+//! Compare panel data: a structural scene diff as display rows.
+//!
+//! Pure data — the diff itself comes from `engine_core::scene_tools` in
+//! `editor_integration` (File > Compare With Saved), which fills this in.
+
+use ecs::Guid;
+
+/// What a [`CompareRow`] reports (drives its color).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompareRowKind {
+    /// A scene-level setting changed
+    Setting,
+    /// An entity or component was added
+    Added,
+    /// An entity or component was removed
+    Removed,
+    /// An entity moved to another parent
+    Moved,
+    /// A field changed
+    Changed,
+}
+
+/// One line of the comparison.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompareRow {
+    pub kind: CompareRowKind,
+    /// Entity the row is about, if it has a GUID (clicking selects it)
+    pub entity: Option<Guid>,
+    /// Description, e.g. `player: Sprite.depth 1.0 -> 5.0`
+    pub text: String,
+}
+
+/// Compare panel state (a field on `EditorContext`).
+#[derive(Debug, Default)]
+pub struct SceneCompareState {
+    /// What was compared, e.g. `level1.ron (saved) → current`
+    pub title: String,
+    /// Differences, in diff order
+    pub rows: Vec<CompareRow>,
+    /// Vertical scroll offset in pixels
+    pub scroll_offset: f32,
+}
+
+impl SceneCompareState {
+    /// Replace the comparison shown, scrolled to the top.
+    pub fn show(&mut self, title: impl Into<String>, rows: Vec<CompareRow>) {
+        self.title = title.into();
+        self.rows = rows;
+        self.scroll_offset = 0.0;
+    }
+
+    /// Drop the comparison (e.g. when the scene it was made for closes).
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_show_replaces_rows_and_resets_scroll() {
+        let mut state = SceneCompareState { scroll_offset: 40.0, ..Default::default() };
+        let row = CompareRow { kind: CompareRowKind::Added, entity: None, text: "added door".into() };
+        state.show("level (saved) → current", vec![row.clone()]);
+        assert_eq!(state.rows, [row]);
+        assert_eq!(state.scroll_offset, 0.0);
+
+        state.clear();
+        assert!(state.rows.is_empty() && state.title.is_empty());
+    }
+}
@@ -0,0 +1,174 @@
+//! Scene graph statistics for the World Stats panel.
+//!
+//! Very deep or very wide hierarchies slow transform propagation and the
+//! hierarchy panel, and a `GlobalTransform2D` without a `Transform2D` is
+//! never recomputed (it renders wherever it was last left). This module
+//! measures all three in one pass and turns outliers into warnings.
+
+use ecs::{EntityId, GlobalTransform2D, Transform2D, World, WorldHierarchyExt};
+
+/// Hierarchy depth (root = 0) above which an entity is flagged as deeply
+/// nested.
+pub const DEPTH_WARNING_THRESHOLD: usize = 12;
+
+/// Child count above which a parent is flagged as too wide.
+pub const CHILDREN_WARNING_THRESHOLD: usize = 256;
+
+/// Shape of the world's entity hierarchy.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SceneGraphStats {
+    /// Live entities
+    pub entity_count: usize,
+    /// Entities without a parent
+    pub root_count: usize,
+    /// Deepest nesting level (roots are depth 0)
+    pub max_depth: usize,
+    /// An entity at `max_depth`
+    pub deepest: Option<EntityId>,
+    /// Largest child count of any entity
+    pub max_children: usize,
+    /// An entity with `max_children` children
+    pub widest: Option<EntityId>,
+    /// Entities with a `GlobalTransform2D` but no `Transform2D`
+    pub orphaned_global_transforms: Vec<EntityId>,
+}
+
+/// A scene graph outlier worth the user's attention.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SceneGraphWarning {
+    /// `entity` is nested `depth` levels deep.
+    DeepHierarchy { entity: EntityId, depth: usize },
+    /// `entity` has `children` direct children.
+    WideHierarchy { entity: EntityId, children: usize },
+    /// Entities whose `GlobalTransform2D` is never recomputed.
+    OrphanedGlobalTransforms(usize),
+}
+
+impl SceneGraphWarning {
+    /// One-line description for the panel.
+    pub fn message(&self) -> String {
+        match self {
+            Self::DeepHierarchy { entity, depth } => {
+                format!("Entity {} is nested {} levels deep", entity.value(), depth)
+            }
+            Self::WideHierarchy { entity, children } => {
+                format!("Entity {} has {} children", entity.value(), children)
+            }
+            Self::OrphanedGlobalTransforms(count) => {
+                format!("{} GlobalTransform2D without Transform2D", count)
+            }
+        }
+    }
+}
+
+impl SceneGraphStats {
+    /// Measure the world's hierarchy. Iterative, so pathological chains
+    /// cannot overflow the stack.
+    pub fn collect(world: &World) -> Self {
+        let mut stats = Self { entity_count: world.entity_count(), ..Self::default() };
+
+        let mut roots = world.get_root_entities();
+        roots.sort_by_key(|e| e.value());
+        stats.root_count = roots.len();
+        let mut stack: Vec<(EntityId, usize)> = roots.into_iter().map(|e| (e, 0)).collect();
+        while let Some((entity, depth)) = stack.pop() {
+            if depth > stats.max_depth || stats.deepest.is_none() {
+                stats.max_depth = depth;
+                stats.deepest = Some(entity);
+            }
+            let children = world.get_children(entity).unwrap_or(&[]);
+            if children.len() > stats.max_children {
+                stats.max_children = children.len();
+                stats.widest = Some(entity);
+            }
+            stack.extend(children.iter().map(|&child| (child, depth + 1)));
+        }
+
+        let mut orphaned: Vec<EntityId> = world
+            .entity_ids()
+            .filter(|&e| world.get::<GlobalTransform2D>(e).is_some() && world.get::<Transform2D>(e).is_none())
+            .collect();
+        orphaned.sort_by_key(|e| e.value());
+        stats.orphaned_global_transforms = orphaned;
+        stats
+    }
+
+    /// Outliers beyond the warning thresholds.
+    pub fn warnings(&self) -> Vec<SceneGraphWarning> {
+        let mut warnings = Vec::new();
+        if let Some(entity) = self.deepest.filter(|_| self.max_depth > DEPTH_WARNING_THRESHOLD) {
+            warnings.push(SceneGraphWarning::DeepHierarchy { entity, depth: self.max_depth });
+        }
+        if let Some(entity) = self.widest.filter(|_| self.max_children > CHILDREN_WARNING_THRESHOLD) {
+            warnings.push(SceneGraphWarning::WideHierarchy { entity, children: self.max_children });
+        }
+        if !self.orphaned_global_transforms.is_empty() {
+            warnings.push(SceneGraphWarning::OrphanedGlobalTransforms(self.orphaned_global_transforms.len()));
+        }
+        warnings
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chain(world: &mut World, length: usize) -> Vec<EntityId> {
+        let mut entities: Vec<EntityId> = Vec::new();
+        for _ in 0..length {
+            let entity = world.spawn((Transform2D::default(),)).id();
+            if let Some(&parent) = entities.last() {
+                world.set_parent(entity, parent).expect("parent");
+            }
+            entities.push(entity);
+        }
+        entities
+    }
+
+    #[test]
+    fn test_stats_measure_depth_and_width() {
+        let mut world = World::new();
+        let nodes = chain(&mut world, 4);
+        let extra = world.spawn((Transform2D::default(),)).id();
+        world.set_parent(extra, nodes[0]).expect("parent");
+
+        let stats = SceneGraphStats::collect(&world);
+        assert_eq!(stats.entity_count, 5);
+        assert_eq!(stats.root_count, 1);
+        assert_eq!(stats.max_depth, 3);
+        assert_eq!(stats.deepest, Some(nodes[3]));
+        assert_eq!(stats.max_children, 2);
+        assert_eq!(stats.widest, Some(nodes[0]));
+        assert!(stats.warnings().is_empty());
+    }
+
+    #[test]
+    fn test_deep_chain_is_warned_about() {
+        let mut world = World::new();
+        let nodes = chain(&mut world, DEPTH_WARNING_THRESHOLD + 2);
+
+        let warnings = SceneGraphStats::collect(&world).warnings();
+        assert_eq!(
+            warnings,
+            vec![SceneGraphWarning::DeepHierarchy { entity: nodes[nodes.len() - 1], depth: DEPTH_WARNING_THRESHOLD + 1 }]
+        );
+    }
+
+    #[test]
+    fn test_global_transform_without_local_is_reported() {
+        let mut world = World::new();
+        let orphan = world.spawn((GlobalTransform2D::default(),)).id();
+        world.spawn((Transform2D::default(), GlobalTransform2D::default())).id();
+
+        let stats = SceneGraphStats::collect(&world);
+        assert_eq!(stats.orphaned_global_transforms, vec![orphan]);
+        assert_eq!(stats.warnings(), vec![SceneGraphWarning::OrphanedGlobalTransforms(1)]);
+    }
+
+    #[test]
+    fn test_empty_world_has_no_deepest_entity() {
+        let stats = SceneGraphStats::collect(&World::new());
+        assert_eq!(stats.deepest, None);
+        assert!(stats.warnings().is_empty());
+    }
+}
@@ -0,0 +1,351 @@
+//! Multi-scene tabs: the list of open scenes and the tab bar drawn in the
+//! scene view header.
+//!
+//! Only the active tab's scene is live — its world, selection, camera and
+//! dirty flag sit in the usual places (the game's `World`, `EditorContext`).
+//! Every other tab parks that state as an opaque `T` chosen by the host
+//! (`editor_integration` stores the world, selection, camera and undo
+//! history), so switching tabs is "park the live state, unpark the target".
+
+use std::path::{Path, PathBuf};
+
+use ui::{Rect, UIContext, WidgetId};
+
+use crate::theme::EditorTheme;
+
+/// Width of one scene tab in the tab bar.
+pub const SCENE_TAB_WIDTH: f32 = 140.0;
+
+/// Width of the close button inside a tab, and of the "+" new-tab button.
+const TAB_BUTTON_WIDTH: f32 = 18.0;
+
+/// Gap between tabs.
+const TAB_GAP: f32 = 2.0;
+
+/// Tab bar request returned by [`SceneTabs::render`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SceneTabAction {
+    /// Make the tab at this index the active one
+    Activate(usize),
+    /// Close the tab at this index
+    Close(usize),
+    /// Open a new untitled tab
+    New,
+}
+
+/// One open scene.
+#[derive(Debug)]
+pub struct SceneTab<T> {
+    /// Scene file path (`None` = untitled)
+    pub path: Option<PathBuf>,
+    /// Whether the scene has unsaved changes
+    pub dirty: bool,
+    /// Parked editing state; `None` while this tab is the active one
+    parked: Option<T>,
+}
+
+impl<T> SceneTab<T> {
+    fn new(path: Option<PathBuf>) -> Self {
+        Self { path, dirty: false, parked: None }
+    }
+
+    /// Tab label: file name (or "Untitled") with a `*` when dirty.
+    pub fn label(&self) -> String {
+        let name = self
+            .path
+            .as_ref()
+            .and_then(|p| p.file_name())
+            .and_then(|n| n.to_str())
+            .unwrap_or("Untitled");
+        if self.dirty {
+            format!("{}*", name)
+        } else {
+            name.to_string()
+        }
+    }
+}
+
+/// The open scenes, in tab order, with exactly one active.
+#[derive(Debug)]
+pub struct SceneTabs<T> {
+    tabs: Vec<SceneTab<T>>,
+    active: usize,
+    /// Dirty tab whose close button was clicked once; a second click discards it
+    pending_close: Option<usize>,
+}
+
+impl<T> Default for SceneTabs<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> SceneTabs<T> {
+    /// A single active, untitled tab.
+    pub fn new() -> Self {
+        Self {
+            tabs: vec![SceneTab::new(None)],
+            active: 0,
+            pending_close: None,
+        }
+    }
+
+    /// Number of open tabs (always at least one).
+    pub fn len(&self) -> usize {
+        self.tabs.len()
+    }
+
+    /// Always `false`: the last tab cannot be closed.
+    pub fn is_empty(&self) -> bool {
+        self.tabs.is_empty()
+    }
+
+    /// Index of the active tab.
+    pub fn active_index(&self) -> usize {
+        self.active
+    }
+
+    /// The active tab.
+    pub fn active(&self) -> &SceneTab<T> {
+        &self.tabs[self.active]
+    }
+
+    /// The tab at `index`.
+    pub fn get(&self, index: usize) -> Option<&SceneTab<T>> {
+        self.tabs.get(index)
+    }
+
+    /// Index of the tab editing `path`, if one is open.
+    pub fn find_path(&self, path: &Path) -> Option<usize> {
+        self.tabs.iter().position(|tab| tab.path.as_deref() == Some(path))
+    }
+
+    /// Mirror the live scene's path and dirty flag onto the active tab.
+    /// Call once per frame before [`render`](Self::render).
+    pub fn sync_active(&mut self, path: Option<&Path>, dirty: bool) {
+        let tab = &mut self.tabs[self.active];
+        tab.path = path.map(Path::to_path_buf);
+        tab.dirty = dirty;
+    }
+
+    /// Park `current` in the active tab and open a new untitled tab after
+    /// it, which becomes active. Returns the new tab's index.
+    pub fn open(&mut self, current: T) -> usize {
+        self.tabs[self.active].parked = Some(current);
+        let index = self.active + 1;
+        self.tabs.insert(index, SceneTab::new(None));
+        self.shift_pending_close(index);
+        self.active = index;
+        index
+    }
+
+    /// Park `current` in the active tab and activate `index`, returning the
+    /// state to unpark. `Err(current)` when `index` is already active or out
+    /// of range (nothing changes).
+    pub fn switch_to(&mut self, index: usize, current: T) -> Result<T, T> {
+        if index == self.active {
+            return Err(current);
+        }
+        let Some(parked) = self.tabs.get_mut(index).and_then(|tab| tab.parked.take()) else {
+            return Err(current);
+        };
+        self.tabs[self.active].parked = Some(current);
+        self.active = index;
+        Ok(parked)
+    }
+
+    /// The tab to activate when the active tab closes: the next one, or the
+    /// previous one when it is the last. `None` for a single tab.
+    pub fn neighbor_of_active(&self) -> Option<usize> {
+        if self.tabs.len() < 2 {
+            None
+        } else if self.active + 1 < self.tabs.len() {
+            Some(self.active + 1)
+        } else {
+            Some(self.active - 1)
+        }
+    }
+
+    /// Close an inactive tab, dropping its parked state. The active tab
+    /// must be switched away from first. Returns `false` (nothing closed)
+    /// for the active tab or an invalid index.
+    pub fn close(&mut self, index: usize) -> bool {
+        if index == self.active || index >= self.tabs.len() {
+            return false;
+        }
+        self.tabs.remove(index);
+        if index < self.active {
+            self.active -= 1;
+        }
+        self.pending_close = None;
+        true
+    }
+
+    /// Whether a close click on `index` should go through: clean tabs close
+    /// at once; a dirty tab needs a second click (the first arms it and
+    /// returns `false`).
+    pub fn confirm_close(&mut self, index: usize) -> bool {
+        let dirty = self.tabs.get(index).is_some_and(|tab| tab.dirty);
+        if !dirty || self.pending_close == Some(index) {
+            self.pending_close = None;
+            return true;
+        }
+        self.pending_close = Some(index);
+        false
+    }
+
+    /// Keep an armed close pointing at the same tab after an insert.
+    fn shift_pending_close(&mut self, inserted: usize) {
+        if let Some(pending) = self.pending_close.as_mut() {
+            if *pending >= inserted {
+                *pending += 1;
+            }
+        }
+    }
+
+    /// Draw the tab bar inside `bounds` (one row, left to right) and return
+    /// the clicked action, if any. Close buttons appear once more than one
+    /// tab is open; `enabled = false` (play session) draws the tabs inert.
+    pub fn render(
+        &self,
+        ui: &mut UIContext,
+        bounds: Rect,
+        theme: &EditorTheme,
+        enabled: bool,
+    ) -> Option<SceneTabAction> {
+        let mut action = None;
+        let closable = self.tabs.len() > 1;
+        let font_size = theme.fonts.small;
+        let mut x = bounds.x;
+
+        for (index, tab) in self.tabs.iter().enumerate() {
+            let tab_bounds = Rect::new(x, bounds.y, SCENE_TAB_WIDTH, bounds.height);
+            x += SCENE_TAB_WIDTH + TAB_GAP;
+            if tab_bounds.x + tab_bounds.width > bounds.x + bounds.width {
+                break;
+            }
+
+            let close_bounds = Rect::new(
+                tab_bounds.x + tab_bounds.width - TAB_BUTTON_WIDTH,
+                tab_bounds.y,
+                TAB_BUTTON_WIDTH,
+                tab_bounds.height,
+            );
+            // Close button first so it wins the click over the tab body
+            if closable {
+                let id = WidgetId::from_str_index("scene_tab_close", index);
+                if ui.interact(id, close_bounds, enabled).clicked {
+                    action = Some(SceneTabAction::Close(index));
+                }
+            }
+            let id = WidgetId::from_str_index("scene_tab", index);
+            let result = ui.interact(id, tab_bounds, enabled && index != self.active);
+            if result.clicked && action.is_none() {
+                action = Some(SceneTabAction::Activate(index));
+            }
+
+            let active = index == self.active;
+            let background = if active { theme.bg_primary } else { theme.bg_input };
+            ui.rect(tab_bounds, background);
+            if active {
+                let underline = Rect::new(tab_bounds.x, tab_bounds.y + tab_bounds.height - 2.0, tab_bounds.width, 2.0);
+                ui.rect(underline, theme.accent_blue);
+            }
+
+            let text_color = if active { theme.text_primary } else { theme.text_secondary };
+            let label_bounds = Rect::new(
+                tab_bounds.x,
+                tab_bounds.y,
+                tab_bounds.width - if closable { TAB_BUTTON_WIDTH } else { 0.0 },
+                tab_bounds.height,
+            );
+            ui.push_clip_rect(label_bounds);
+            ui.label_in_bounds_styled(&tab.label(), label_bounds, ui::TextAlign::Left, text_color, font_size, 6.0);
+            ui.pop_clip_rect();
+
+            if closable {
+                // Armed (dirty, clicked once): highlight the close button
+                let close_color = if self.pending_close == Some(index) {
+                    theme.accent_cyan
+                } else {
+                    theme.text_muted
+                };
+                ui.label_in_bounds_styled("x", close_bounds, ui::TextAlign::Center, close_color, font_size, 0.0);
+            }
+        }
+
+        let new_bounds = Rect::new(x, bounds.y, TAB_BUTTON_WIDTH, bounds.height);
+        if new_bounds.x + new_bounds.width <= bounds.x + bounds.width {
+            if ui.interact("scene_tab_new", new_bounds, enabled).clicked {
+                action = Some(SceneTabAction::New);
+            }
+            ui.label_in_bounds_styled("+", new_bounds, ui::TextAlign::Center, theme.text_secondary, theme.fonts.body, 0.0);
+        }
+
+        action
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_starts_with_one_untitled_active_tab() {
+        let tabs: SceneTabs<u32> = SceneTabs::new();
+        assert_eq!(tabs.len(), 1);
+        assert_eq!(tabs.active_index(), 0);
+        assert_eq!(tabs.active().label(), "Untitled");
+        assert_eq!(tabs.neighbor_of_active(), None);
+    }
+
+    #[test]
+    fn test_open_and_switch_park_and_unpark_state() {
+        let mut tabs = SceneTabs::new();
+        tabs.sync_active(Some(Path::new("scenes/level1.ron")), true);
+        assert_eq!(tabs.open(1), 1);
+        assert_eq!(tabs.active().label(), "Untitled");
+
+        // Back to the first tab: its parked state comes back, ours is parked
+        assert_eq!(tabs.switch_to(0, 2), Ok(1));
+        assert_eq!(tabs.active().label(), "level1.ron*");
+        assert_eq!(tabs.switch_to(1, 3), Ok(2));
+        // Already active or out of range: the live state is handed back
+        assert_eq!(tabs.switch_to(1, 4), Err(4));
+        assert_eq!(tabs.switch_to(9, 5), Err(5));
+    }
+
+    #[test]
+    fn test_close_only_inactive_tabs_and_keep_active_index() {
+        let mut tabs = SceneTabs::new();
+        tabs.open(10);
+        tabs.open(11);
+        assert_eq!(tabs.active_index(), 2);
+
+        assert!(!tabs.close(2), "the active tab must be switched away first");
+        assert!(tabs.close(0));
+        assert_eq!(tabs.len(), 2);
+        assert_eq!(tabs.active_index(), 1, "active tab index follows the removal");
+        assert_eq!(tabs.neighbor_of_active(), Some(0));
+        assert_eq!(tabs.switch_to(0, 12), Ok(11));
+    }
+
+    #[test]
+    fn test_dirty_tab_needs_a_second_close_click() {
+        let mut tabs = SceneTabs::new();
+        tabs.sync_active(None, true);
+        tabs.open(0);
+        assert!(tabs.confirm_close(1), "clean tabs close at once");
+        assert!(!tabs.confirm_close(0), "first click arms a dirty tab");
+        assert!(tabs.confirm_close(0));
+    }
+
+    #[test]
+    fn test_find_path() {
+        let mut tabs = SceneTabs::new();
+        tabs.sync_active(Some(Path::new("a.ron")), false);
+        tabs.open(0);
+        assert_eq!(tabs.find_path(Path::new("a.ron")), Some(0));
+        assert_eq!(tabs.find_path(Path::new("b.ron")), None);
+    }
+}
@@ -0,0 +1,161 @@
+//! Selection outline overlay for the scene view.
+//!
+//! Draws an outline around every selected entity's pickable AABB so the
+//! selection is visible without relying on the gizmo (which only follows
+//! the primary selection). The primary selection gets its own color and a
+//! thicker line. AABB corners go through `SceneViewport::world_to_screen`,
+//! so the outline stays glued to the sprite under view rotation.
+
+use ecs::EntityId;
+use glam::Vec2;
+use ui::{Color, Rect, UIContext};
+
+use crate::picking::PickableEntity;
+use crate::selection::Selection;
+use crate::viewport::SceneViewport;
+
+/// Gap between the sprite bounds and the outline, in screen pixels.
+const OUTLINE_PADDING: f32 = 3.0;
+/// Outline width for the primary selection, in screen pixels.
+const OUTLINE_WIDTH_PRIMARY: f32 = 2.0;
+/// Outline width for additional selections, in screen pixels.
+const OUTLINE_WIDTH_SECONDARY: f32 = 1.5;
+
+/// Outline colors for the selection overlay.
+///
+/// Normally sourced from the theme via `EditorColorScheme::selection_overlay_colors()`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SelectionOverlayColors {
+    /// The primary (most recently selected) entity
+    pub primary: Color,
+    /// Every other selected entity
+    pub secondary: Color,
+}
+
+/// One selected entity's outline in screen space.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SelectionOutline {
+    /// The outlined entity
+    pub entity: EntityId,
+    /// Whether this is the primary selection
+    pub is_primary: bool,
+    /// Screen-space corners, in order around the outline
+    pub corners: [Vec2; 4],
+}
+
+/// Screen-space outlines for every selected entity that has a pickable AABB.
+///
+/// The primary selection comes last so it draws on top of overlapping
+/// secondary outlines.
+pub fn selection_outlines(
+    selection: &Selection,
+    pickables: &[PickableEntity],
+    viewport: &SceneViewport,
+) -> Vec<SelectionOutline> {
+    let primary = selection.primary();
+    // Padding is constant on screen regardless of zoom.
+    let margin = OUTLINE_PADDING / viewport.camera_zoom().max(f32::EPSILON);
+
+    let mut outlines: Vec<SelectionOutline> = pickables
+        .iter()
+        .filter(|p| selection.contains(p.entity_id))
+        .map(|p| {
+            let aabb = p.aabb().expand(margin);
+            let corners = [
+                Vec2::new(aabb.min.x, aabb.min.y),
+                Vec2::new(aabb.max.x, aabb.min.y),
+                Vec2::new(aabb.max.x, aabb.max.y),
+                Vec2::new(aabb.min.x, aabb.max.y),
+            ]
+            .map(|c| viewport.world_to_screen(c));
+            SelectionOutline { entity: p.entity_id, is_primary: primary == Some(p.entity_id), corners }
+        })
+        .collect();
+    outlines.sort_by_key(|o| o.is_primary);
+    outlines
+}
+
+/// Draw selection outlines for every selected pickable entity, clipped to
+/// the scene-view `bounds`.
+pub fn render_selection_overlay(
+    ui: &mut UIContext,
+    viewport: &SceneViewport,
+    selection: &Selection,
+    pickables: &[PickableEntity],
+    colors: &SelectionOverlayColors,
+    bounds: Rect,
+) {
+    if selection.is_empty() {
+        return;
+    }
+    ui.push_clip_rect(bounds);
+    for outline in selection_outlines(selection, pickables, viewport) {
+        let (color, width) = if outline.is_primary {
+            (colors.primary, OUTLINE_WIDTH_PRIMARY)
+        } else {
+            (colors.secondary, OUTLINE_WIDTH_SECONDARY)
+        };
+        for i in 0..4 {
+            ui.line(outline.corners[i], outline.corners[(i + 1) % 4], color, width);
+        }
+    }
+    ui.pop_clip_rect();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ecs::World;
+
+    fn pickable(entity: EntityId, position: Vec2) -> PickableEntity {
+        PickableEntity::new(entity, position, Vec2::new(20.0, 10.0), 0.0)
+    }
+
+    #[test]
+    fn only_selected_entities_are_outlined() {
+        let mut world = World::new();
+        let a = world.create_entity();
+        let b = world.create_entity();
+        let pickables = [pickable(a, Vec2::ZERO), pickable(b, Vec2::new(50.0, 0.0))];
+        let mut selection = Selection::new();
+        selection.select(b);
+
+        let outlines = selection_outlines(&selection, &pickables, &SceneViewport::new());
+        assert_eq!(outlines.len(), 1);
+        assert_eq!(outlines[0].entity, b);
+        assert!(outlines[0].is_primary);
+    }
+
+    #[test]
+    fn primary_selection_is_drawn_last() {
+        let mut world = World::new();
+        let a = world.create_entity();
+        let b = world.create_entity();
+        let pickables = [pickable(a, Vec2::ZERO), pickable(b, Vec2::new(50.0, 0.0))];
+        let mut selection = Selection::new();
+        selection.select(a);
+        selection.add(b);
+
+        let outlines = selection_outlines(&selection, &pickables, &SceneViewport::new());
+        assert_eq!(outlines.len(), 2);
+        assert!(!outlines[0].is_primary);
+        assert_eq!(outlines[1].entity, selection.primary().expect("primary"));
+    }
+
+    #[test]
+    fn outline_encloses_sprite_with_screen_padding() {
+        let mut world = World::new();
+        let a = world.create_entity();
+        let pickables = [pickable(a, Vec2::ZERO)];
+        let mut selection = Selection::new();
+        selection.select(a);
+        let viewport = SceneViewport::new();
+
+        let outline = selection_outlines(&selection, &pickables, &viewport)[0];
+        let sprite_corner = viewport.world_to_screen(Vec2::new(-10.0, -5.0));
+        let outline_corner = outline.corners[0];
+        let gap = (outline_corner - sprite_corner).abs();
+        assert!((gap.x - OUTLINE_PADDING).abs() < 1e-3, "gap {gap:?}");
+        assert!((gap.y - OUTLINE_PADDING).abs() < 1e-3, "gap {gap:?}");
+    }
+}
@@ -0,0 +1,467 @@
+//! Sprite-sheet region picker: edits `Sprite.tex_region` by dragging a
+//! sub-rectangle over the texture or clicking a cell of a grid overlay.
+//!
+//! The inspector's Region row shows the normalized `[x, y, w, h]` and a
+//! "Pick..." button; the integration layer opens [`SpriteRegionPicker`] as
+//! a popup and writes the applied region back through the undo history.
+//! When the texture has a sliced atlas (see [`crate::SpriteSheetSlicer`]),
+//! its named regions are outlined and clickable, and the inspector shows
+//! region names instead of raw values.
+
+use glam::Vec2;
+use ui::{Color, Rect, UIContext};
+
+use crate::editable_inspector::EditableInspector;
+use crate::field_style::{EditableFieldStyle, FieldId};
+use crate::theme::EditorTheme;
+
+/// Size of the picker popup.
+pub const REGION_PICKER_SIZE: Vec2 = Vec2::new(420.0, 500.0);
+
+/// Largest grid the column/row steppers allow.
+const MAX_GRID_CELLS: u32 = 64;
+
+/// Mouse travel (screen px) below which a press-release picks a grid cell
+/// instead of a dragged rectangle.
+const CLICK_SLOP: f32 = 4.0;
+
+/// Popup padding and row heights.
+const PAD: f32 = 8.0;
+const ROW_HEIGHT: f32 = 22.0;
+
+/// The full texture (`Sprite::default().tex_region`).
+pub const FULL_REGION: [f32; 4] = [0.0, 0.0, 1.0, 1.0];
+
+/// Region of cell `(column, row)` of a `columns` x `rows` sheet grid.
+pub fn cell_region(column: u32, row: u32, columns: u32, rows: u32) -> [f32; 4] {
+    let (columns, rows) = (columns.max(1) as f32, rows.max(1) as f32);
+    [column as f32 / columns, row as f32 / rows, 1.0 / columns, 1.0 / rows]
+}
+
+/// Grid cell containing normalized texture point `uv` (clamped to the sheet).
+pub fn cell_at(uv: Vec2, columns: u32, rows: u32) -> (u32, u32) {
+    let (columns, rows) = (columns.max(1), rows.max(1));
+    let column = (uv.x.clamp(0.0, 1.0) * columns as f32) as u32;
+    let row = (uv.y.clamp(0.0, 1.0) * rows as f32) as u32;
+    (column.min(columns - 1), row.min(rows - 1))
+}
+
+/// Region spanned by a drag between two normalized texture points, clamped
+/// to the texture and snapped outward to whole texels when the texture's
+/// pixel size is known (`texture_size` of zero = no snapping).
+pub fn drag_region(a: Vec2, b: Vec2, texture_size: Vec2) -> [f32; 4] {
+    let mut min = a.min(b).clamp(Vec2::ZERO, Vec2::ONE);
+    let mut max = a.max(b).clamp(Vec2::ZERO, Vec2::ONE);
+    if texture_size.x > 0.0 && texture_size.y > 0.0 {
+        min = (min * texture_size).floor() / texture_size;
+        max = (max * texture_size).ceil() / texture_size;
+    }
+    [min.x, min.y, max.x - min.x, max.y - min.y]
+}
+
+/// Screen rect of `region` drawn over a texture shown in `view`.
+fn region_rect(region: [f32; 4], view: Rect) -> Rect {
+    Rect::new(
+        view.x + region[0] * view.width,
+        view.y + region[1] * view.height,
+        region[2] * view.width,
+        region[3] * view.height,
+    )
+}
+
+/// Name of the atlas region matching `region` (within a small tolerance).
+pub fn region_name(region: [f32; 4], names: &[(String, [f32; 4])]) -> Option<&str> {
+    names
+        .iter()
+        .find(|(_, named)| named.iter().zip(region).all(|(a, b)| (a - b).abs() < 1e-4))
+        .map(|(name, _)| name.as_str())
+}
+
+/// Display text for a region: its atlas name when it has one, otherwise
+/// [`region_text`].
+pub fn named_region_text(region: [f32; 4], names: &[(String, [f32; 4])]) -> String {
+    region_name(region, names).map_or_else(|| region_text(region), str::to_string)
+}
+
+/// Human-readable region: normalized values, plus texels when known.
+fn describe_region(region: [f32; 4], texture_size: Vec2) -> String {
+    let [x, y, w, h] = region;
+    if texture_size.x > 0.0 && texture_size.y > 0.0 {
+        format!(
+            "{:.3}, {:.3}, {:.3}, {:.3}  ({}x{} px at {}, {})",
+            x, y, w, h,
+            (w * texture_size.x).round(), (h * texture_size.y).round(),
+            (x * texture_size.x).round(), (y * texture_size.y).round(),
+        )
+    } else {
+        format!("{:.3}, {:.3}, {:.3}, {:.3}", x, y, w, h)
+    }
+}
+
+/// Result of a picker frame.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RegionPickerAction {
+    /// Write `region` into the target entity's sprite
+    Apply { entity: ecs::EntityId, region: [f32; 4] },
+    /// Close without changes
+    Cancel,
+    /// Close and open the sprite-sheet slicer on `texture`
+    Slice { texture: u32 },
+}
+
+/// The sprite being edited by an open picker.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct PickerTarget {
+    entity: ecs::EntityId,
+    texture: u32,
+    /// Texture size in pixels (zero when unknown)
+    texture_size: Vec2,
+}
+
+/// Popup state for picking a sprite's texture region.
+#[derive(Debug, Clone)]
+pub struct SpriteRegionPicker {
+    target: Option<PickerTarget>,
+    /// Region being edited (applied on "Apply")
+    region: [f32; 4],
+    columns: u32,
+    rows: u32,
+    /// Normalized texture point and screen position where the drag started
+    drag_anchor: Option<(Vec2, Vec2)>,
+    /// The texture's atlas regions as `(name, region)`; clicks select these
+    /// before falling back to grid cells
+    named_regions: Vec<(String, [f32; 4])>,
+}
+
+impl Default for SpriteRegionPicker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SpriteRegionPicker {
+    /// A closed picker with a 4x4 grid.
+    pub fn new() -> Self {
+        Self {
+            target: None,
+            region: FULL_REGION,
+            columns: 4,
+            rows: 4,
+            drag_anchor: None,
+            named_regions: Vec::new(),
+        }
+    }
+
+    /// Open for `entity`'s sprite showing `texture` (pixel size, or zero when
+    /// unknown), starting from its current `region`. The grid size is kept
+    /// between openings, since sheets in one project tend to share a layout.
+    pub fn open(&mut self, entity: ecs::EntityId, texture: u32, texture_size: Vec2, region: [f32; 4]) {
+        self.target = Some(PickerTarget { entity, texture, texture_size });
+        self.region = region;
+        self.drag_anchor = None;
+        self.named_regions.clear();
+    }
+
+    /// Offer the texture's atlas regions (call after [`Self::open`]).
+    pub fn set_named_regions(&mut self, regions: Vec<(String, [f32; 4])>) {
+        self.named_regions = regions;
+    }
+
+    /// Close without applying.
+    pub fn close(&mut self) {
+        self.target = None;
+        self.drag_anchor = None;
+    }
+
+    /// Whether the picker is open.
+    pub fn is_open(&self) -> bool {
+        self.target.is_some()
+    }
+
+    /// Entity whose sprite is being edited, if open.
+    pub fn entity(&self) -> Option<ecs::EntityId> {
+        self.target.map(|target| target.entity)
+    }
+
+    /// Atlas region name of the current region, if it matches one.
+    pub fn region_name(&self) -> Option<&str> {
+        region_name(self.region, &self.named_regions)
+    }
+
+    /// Select the named region at normalized texture point `uv`, or the
+    /// grid cell there when no named region covers it.
+    pub fn select_at(&mut self, uv: Vec2) {
+        let named = self.named_regions.iter().find(|(_, [x, y, w, h])| {
+            uv.x >= *x && uv.x < x + w && uv.y >= *y && uv.y < y + h
+        });
+        match named {
+            Some((_, region)) => self.region = *region,
+            None => {
+                let (column, row) = cell_at(uv, self.columns, self.rows);
+                self.select_cell(column, row);
+            }
+        }
+    }
+
+    /// Region currently shown (not yet applied).
+    pub fn region(&self) -> [f32; 4] {
+        self.region
+    }
+
+    /// Grid size as `(columns, rows)`.
+    pub fn grid(&self) -> (u32, u32) {
+        (self.columns, self.rows)
+    }
+
+    /// Set the grid size (clamped to 1..=64 per axis).
+    pub fn set_grid(&mut self, columns: u32, rows: u32) {
+        self.columns = columns.clamp(1, MAX_GRID_CELLS);
+        self.rows = rows.clamp(1, MAX_GRID_CELLS);
+    }
+
+    /// Select grid cell `(column, row)`.
+    pub fn select_cell(&mut self, column: u32, row: u32) {
+        self.region = cell_region(column, row, self.columns, self.rows);
+    }
+
+    /// Draw the picker inside `bounds` (see [`REGION_PICKER_SIZE`]) as an
+    /// input-blocking overlay. Returns the user's decision, if any; the
+    /// picker closes itself on either action.
+    pub fn render(&mut self, ui: &mut UIContext, bounds: Rect, theme: &EditorTheme) -> Option<RegionPickerAction> {
+        let target = self.target?;
+        ui.begin_overlay(bounds);
+        ui.panel_styled(bounds, theme.bg_primary, theme.border_panel, 1.0);
+        ui.label_styled("Sprite Region", Vec2::new(bounds.x + PAD, bounds.y + PAD), theme.text_primary, theme.fonts.body);
+
+        self.render_grid_steppers(ui, bounds, theme);
+        let view = self.texture_view(bounds, target.texture_size);
+        self.handle_view_input(ui, view, target.texture_size);
+        self.draw_view(ui, view, target.texture, theme);
+
+        let footer_y = bounds.y + bounds.height - PAD - ROW_HEIGHT;
+        let description = describe_region(self.region, target.texture_size);
+        let description = match self.region_name() {
+            Some(name) => format!("{name}  ({description})"),
+            None => description,
+        };
+        ui.label_styled(
+            &description,
+            Vec2::new(bounds.x + PAD, footer_y - ROW_HEIGHT + 4.0),
+            theme.text_secondary,
+            theme.fonts.small,
+        );
+        let button = |index: f32| Rect::new(bounds.x + PAD + index * 76.0, footer_y, 70.0, ROW_HEIGHT);
+        if ui.button("region_picker_full", "Full", button(0.0)) {
+            self.region = FULL_REGION;
+        }
+        let apply = ui.button("region_picker_apply", "Apply", button(1.0));
+        let cancel = ui.button("region_picker_cancel", "Cancel", button(2.0));
+        let slice = ui.button("region_picker_slice", "Slice...", button(3.0));
+        ui.end_overlay();
+
+        if apply {
+            self.close();
+            Some(RegionPickerAction::Apply { entity: target.entity, region: self.region })
+        } else if cancel {
+            self.close();
+            Some(RegionPickerAction::Cancel)
+        } else if slice {
+            self.close();
+            Some(RegionPickerAction::Slice { texture: target.texture })
+        } else {
+            None
+        }
+    }
+
+    /// "Grid  Cols n [-][+]  Rows n [-][+]" row under the title.
+    fn render_grid_steppers(&mut self, ui: &mut UIContext, bounds: Rect, theme: &EditorTheme) {
+        let y = bounds.y + PAD + ROW_HEIGHT + 2.0;
+        let mut x = bounds.x + PAD;
+        let (mut columns, mut rows) = (self.columns, self.rows);
+        for (index, (axis, value)) in [("Cols", &mut columns), ("Rows", &mut rows)].into_iter().enumerate() {
+            ui.label_styled(&format!("{} {}", axis, value), Vec2::new(x, y + 4.0), theme.text_secondary, theme.fonts.small);
+            x += 56.0;
+            if ui.button(("region_picker_less", index), "-", Rect::new(x, y, ROW_HEIGHT, ROW_HEIGHT)) {
+                *value = value.saturating_sub(1);
+            }
+            x += ROW_HEIGHT + 2.0;
+            if ui.button(("region_picker_more", index), "+", Rect::new(x, y, ROW_HEIGHT, ROW_HEIGHT)) {
+                *value += 1;
+            }
+            x += ROW_HEIGHT + 16.0;
+        }
+        self.set_grid(columns, rows);
+    }
+
+    /// Largest rect with the texture's aspect ratio that fits between the
+    /// steppers and the footer, centered horizontally.
+    fn texture_view(&self, bounds: Rect, texture_size: Vec2) -> Rect {
+        let top = bounds.y + PAD + 2.0 * (ROW_HEIGHT + 2.0) + PAD;
+        let bottom = bounds.y + bounds.height - PAD - 2.0 * ROW_HEIGHT - PAD;
+        let area = Vec2::new(bounds.width - 2.0 * PAD, (bottom - top).max(0.0));
+        let aspect = if texture_size.x > 0.0 && texture_size.y > 0.0 { texture_size.x / texture_size.y } else { 1.0 };
+        let size = if area.x / area.y.max(1.0) > aspect {
+            Vec2::new(area.y * aspect, area.y)
+        } else {
+            Vec2::new(area.x, area.x / aspect)
+        };
+        Rect::new(bounds.x + (bounds.width - size.x) / 2.0, top, size.x, size.y)
+    }
+
+    /// Drag a rectangle over the texture, or click to pick a named region
+    /// or grid cell.
+    fn handle_view_input(&mut self, ui: &mut UIContext, view: Rect, texture_size: Vec2) {
+        let result = ui.interact("region_picker_view", view, true);
+        let mouse = ui.mouse_pos();
+        let uv = (mouse - Vec2::new(view.x, view.y)) / Vec2::new(view.width, view.height).max(Vec2::ONE);
+        if result.dragging {
+            let (anchor_uv, anchor_screen) = *self.drag_anchor.get_or_insert((uv, mouse));
+            if anchor_screen.distance(mouse) >= CLICK_SLOP {
+                self.region = drag_region(anchor_uv, uv, texture_size);
+            }
+        } else if let Some((anchor_uv, anchor_screen)) = self.drag_anchor.take() {
+            if anchor_screen.distance(mouse) < CLICK_SLOP {
+                self.select_at(anchor_uv);
+            }
+        }
+    }
+
+    /// Texture, grid lines, named region outlines, and the region outline.
+    fn draw_view(&self, ui: &mut UIContext, view: Rect, texture: u32, theme: &EditorTheme) {
+        ui.rect(view, theme.bg_input);
+        ui.image(view, texture, Color::WHITE);
+
+        let grid_color = theme.border_subtle;
+        for column in 1..self.columns {
+            let x = view.x + view.width * column as f32 / self.columns as f32;
+            ui.line(Vec2::new(x, view.y), Vec2::new(x, view.y + view.height), grid_color, 1.0);
+        }
+        for row in 1..self.rows {
+            let y = view.y + view.height * row as f32 / self.rows as f32;
+            ui.line(Vec2::new(view.x, y), Vec2::new(view.x + view.width, y), grid_color, 1.0);
+        }
+        for (_, region) in &self.named_regions {
+            ui.rect_border(region_rect(*region, view), theme.warn_yellow, 1.0, 0.0);
+        }
+
+        let selected = region_rect(self.region, view);
+        ui.rect(selected, theme.selection_fill);
+        ui.rect_border(selected, theme.accent_cyan, 2.0, 0.0);
+    }
+}
+
+impl EditableInspector<'_> {
+    /// Add a texture-region row: the region's atlas name from `names`, or
+    /// the normalized `[x, y, w, h]`, plus a "Pick..." button. Returns
+    /// `true` when the button is clicked.
+    pub fn tex_region(&mut self, label: &str, region: [f32; 4], names: &[(String, [f32; 4])]) -> bool {
+        self.custom_row(|ui, id, pos, style| edit_region_field(ui, id, label, region, names, pos, style))
+    }
+}
+
+/// Display text for a texture region: `"Full texture"` or `x, y, w, h`.
+pub fn region_text(region: [f32; 4]) -> String {
+    if region == FULL_REGION {
+        "Full texture".to_string()
+    } else {
+        format!("{:.2}, {:.2}, {:.2}, {:.2}", region[0], region[1], region[2], region[3])
+    }
+}
+
+/// Render a texture-region row: label, the region's name or values, and
+/// a "Pick..." button that opens the sprite-sheet picker.
+pub fn edit_region_field(
+    ui: &mut UIContext,
+    id: FieldId,
+    label: &str,
+    region: [f32; 4],
+    names: &[(String, [f32; 4])],
+    pos: Vec2,
+    style: &EditableFieldStyle,
+) -> bool {
+    ui.label_styled(label, Vec2::new(pos.x, pos.y + 4.0), style.label_color, style.label_font);
+
+    let value_bounds = Rect::new(pos.x + style.label_width, pos.y + 2.0, style.input_width, style.row_height - 4.0);
+    ui.label_in_bounds_styled(&named_region_text(region, names), value_bounds, ui::TextAlign::Left, style.value_color, style.label_font, 0.0);
+
+    let button_bounds = Rect::new(value_bounds.x + value_bounds.width + 4.0, value_bounds.y, 40.0, value_bounds.height);
+    ui.button(id, "Pick", button_bounds)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cell_region_and_cell_at_round_trip() {
+        assert_eq!(cell_region(1, 2, 4, 4), [0.25, 0.5, 0.25, 0.25]);
+        assert_eq!(cell_at(Vec2::new(0.3, 0.6), 4, 4), (1, 2));
+        // Edges and out-of-range points clamp into the sheet
+        assert_eq!(cell_at(Vec2::new(1.0, 1.0), 4, 2), (3, 1));
+        assert_eq!(cell_at(Vec2::new(-0.5, 2.0), 4, 2), (0, 1));
+        assert_eq!(cell_region(0, 0, 0, 0), FULL_REGION, "a zero grid acts as 1x1");
+        assert_eq!(region_text(FULL_REGION), "Full texture");
+        assert_eq!(region_text(cell_region(1, 0, 4, 1)), "0.25, 0.00, 0.25, 1.00");
+    }
+
+    #[test]
+    fn test_drag_region_normalizes_and_snaps_to_texels() {
+        // Dragging up-left gives the same rect as down-right
+        let region = drag_region(Vec2::new(0.5, 0.5), Vec2::new(0.25, 0.0), Vec2::ZERO);
+        assert_eq!(region, [0.25, 0.0, 0.25, 0.5]);
+
+        // 64 px texture: 0.1 * 64 = 6.4 → floor 6; 0.3 * 64 = 19.2 → ceil 20
+        let [x, _, w, _] = drag_region(Vec2::new(0.1, 0.0), Vec2::new(0.3, 1.0), Vec2::splat(64.0));
+        assert_eq!(x, 6.0 / 64.0);
+        assert_eq!(w, 14.0 / 64.0);
+
+        let clamped = drag_region(Vec2::new(-1.0, -1.0), Vec2::new(2.0, 2.0), Vec2::ZERO);
+        assert_eq!(clamped, FULL_REGION);
+    }
+
+    #[test]
+    fn test_open_select_and_close() {
+        let mut picker = SpriteRegionPicker::new();
+        assert!(!picker.is_open());
+
+        let entity = ecs::EntityId::new();
+        picker.open(entity, 3, Vec2::new(128.0, 64.0), [0.0, 0.0, 0.5, 0.5]);
+        assert_eq!(picker.entity(), Some(entity));
+        assert_eq!(picker.region(), [0.0, 0.0, 0.5, 0.5]);
+
+        picker.set_grid(8, 0);
+        assert_eq!(picker.grid(), (8, 1));
+        picker.select_cell(7, 0);
+        assert_eq!(picker.region(), [0.875, 0.0, 0.125, 1.0]);
+
+        picker.close();
+        assert!(!picker.is_open());
+        assert_eq!(picker.grid(), (8, 1), "grid size survives closing");
+    }
+
+    #[test]
+    fn test_clicks_prefer_named_regions_over_grid_cells() {
+        let mut picker = SpriteRegionPicker::new();
+        picker.open(ecs::EntityId::new(), 3, Vec2::new(64.0, 64.0), FULL_REGION);
+        picker.set_grid(2, 2);
+        let names = vec![("coin".to_string(), [0.125, 0.125, 0.25, 0.25])];
+        picker.set_named_regions(names.clone());
+
+        picker.select_at(Vec2::new(0.2, 0.2));
+        assert_eq!(picker.region_name(), Some("coin"));
+        picker.select_at(Vec2::new(0.75, 0.75));
+        assert_eq!(picker.region(), [0.5, 0.5, 0.5, 0.5], "outside named regions: grid cell");
+        assert_eq!(picker.region_name(), None);
+
+        assert_eq!(named_region_text([0.125, 0.125, 0.25, 0.25], &names), "coin");
+        assert_eq!(named_region_text(FULL_REGION, &names), "Full texture");
+    }
+
+    #[test]
+    fn test_render_closed_picker_is_a_no_op() {
+        let mut picker = SpriteRegionPicker::new();
+        let mut ui = UIContext::new();
+        let bounds = Rect::new(0.0, 0.0, REGION_PICKER_SIZE.x, REGION_PICKER_SIZE.y);
+        assert_eq!(picker.render(&mut ui, bounds, &EditorTheme::default()), None);
+    }
+}
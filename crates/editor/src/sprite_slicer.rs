@@ -0,0 +1,431 @@
+//! Sprite-sheet slicer: cuts a texture into named regions, either by cell
+//! size or by the bounding boxes of opaque islands, and saves them as the
+//! texture's atlas (`hero.png` → `hero.atlas.ron`).
+//!
+//! Opened from the region picker's "Slice..." button. The editor crate
+//! can't read files through the engine's `AssetManager`, so the
+//! integration layer hands over the texture's path and pixels on open,
+//! writes the atlas on [`SlicerAction::Save`], and fills [`SpriteAtlases`]
+//! so the inspector and the region picker can show regions by name.
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use glam::Vec2;
+use renderer::sprite_sheet::{detect_islands, slice_grid};
+use renderer::{PixelRect, SpriteAtlas};
+use ui::{Color, Rect, UIContext};
+
+use crate::theme::EditorTheme;
+
+/// Size of the slicer popup.
+pub const SPRITE_SLICER_SIZE: Vec2 = Vec2::new(460.0, 520.0);
+
+/// Cell size the slicer starts from for a new sheet (rounded down to a
+/// size that divides the texture).
+const DEFAULT_CELL: u32 = 32;
+
+/// Alpha threshold step for the island stepper.
+const ALPHA_STEP: u8 = 32;
+
+/// Popup padding and row heights.
+const PAD: f32 = 8.0;
+const ROW_HEIGHT: f32 = 22.0;
+
+/// How the sheet is cut.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SliceMode {
+    /// Fixed-size cells, row by row
+    Grid,
+    /// One region per island of opaque pixels
+    Islands,
+}
+
+/// Result of a slicer frame.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SlicerAction {
+    /// Write this atlas next to its texture
+    Save(SpriteAtlas),
+    /// Close without saving
+    Cancel,
+}
+
+/// The texture being sliced.
+#[derive(Clone)]
+struct SlicerTarget {
+    texture: u32,
+    /// Texture path as loaded (relative to the asset base)
+    path: String,
+    width: u32,
+    height: u32,
+    /// RGBA8 pixels, when the file could be decoded (island mode needs them)
+    pixels: Option<Vec<u8>>,
+}
+
+impl std::fmt::Debug for SlicerTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SlicerTarget")
+            .field("texture", &self.texture)
+            .field("path", &self.path)
+            .field("size", &(self.width, self.height))
+            .field("has_pixels", &self.pixels.is_some())
+            .finish()
+    }
+}
+
+/// Largest divisor of `size` that is at most `limit` (at least 1).
+fn divisor_at_most(size: u32, limit: u32) -> u32 {
+    (1..=limit.min(size)).rev().find(|d| size.is_multiple_of(*d)).unwrap_or(1)
+}
+
+/// Next cell size that tiles `size` exactly, growing or shrinking from
+/// `current`; stays put at either end.
+pub fn step_cell_size(size: u32, current: u32, grow: bool) -> u32 {
+    if grow {
+        (current + 1..=size).find(|d| size.is_multiple_of(*d)).unwrap_or(current)
+    } else {
+        (1..current).rev().find(|d| size.is_multiple_of(*d)).unwrap_or(current)
+    }
+}
+
+/// Popup state for slicing a sprite sheet.
+#[derive(Debug, Clone)]
+pub struct SpriteSheetSlicer {
+    target: Option<SlicerTarget>,
+    mode: SliceMode,
+    /// Cell width and height in pixels
+    cell: (u32, u32),
+    /// Pixels with alpha above this belong to an island
+    alpha_threshold: u8,
+    /// Regions for the current settings
+    slices: Vec<PixelRect>,
+}
+
+impl Default for SpriteSheetSlicer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SpriteSheetSlicer {
+    /// A closed slicer in grid mode.
+    pub fn new() -> Self {
+        Self {
+            target: None,
+            mode: SliceMode::Grid,
+            cell: (DEFAULT_CELL, DEFAULT_CELL),
+            alpha_threshold: 0,
+            slices: Vec::new(),
+        }
+    }
+
+    /// Open on `texture`, loaded from `path` and `width` x `height` pixels.
+    /// `pixels` (RGBA8) enables island detection. The previous cell size is
+    /// kept when it still tiles the new sheet.
+    pub fn open(&mut self, texture: u32, path: impl Into<String>, width: u32, height: u32, pixels: Option<Vec<u8>>) {
+        let (cell_width, cell_height) = self.cell;
+        if !width.is_multiple_of(cell_width.max(1)) || !height.is_multiple_of(cell_height.max(1)) {
+            self.cell = (divisor_at_most(width, DEFAULT_CELL), divisor_at_most(height, DEFAULT_CELL));
+        }
+        self.target = Some(SlicerTarget { texture, path: path.into(), width, height, pixels });
+        self.reslice();
+    }
+
+    /// Close without saving.
+    pub fn close(&mut self) {
+        self.target = None;
+        self.slices.clear();
+    }
+
+    /// Whether the slicer is open.
+    pub fn is_open(&self) -> bool {
+        self.target.is_some()
+    }
+
+    /// Texture being sliced, if open.
+    pub fn texture(&self) -> Option<u32> {
+        self.target.as_ref().map(|target| target.texture)
+    }
+
+    /// Current slicing mode.
+    pub fn mode(&self) -> SliceMode {
+        self.mode
+    }
+
+    /// Switch slicing mode.
+    pub fn set_mode(&mut self, mode: SliceMode) {
+        self.mode = mode;
+        self.reslice();
+    }
+
+    /// Cell size as `(width, height)` in pixels.
+    pub fn cell_size(&self) -> (u32, u32) {
+        self.cell
+    }
+
+    /// Set the cell size (at least 1 px per axis).
+    pub fn set_cell_size(&mut self, width: u32, height: u32) {
+        self.cell = (width.max(1), height.max(1));
+        self.reslice();
+    }
+
+    /// Alpha above which a pixel belongs to an island.
+    pub fn alpha_threshold(&self) -> u8 {
+        self.alpha_threshold
+    }
+
+    /// Set the island alpha threshold.
+    pub fn set_alpha_threshold(&mut self, threshold: u8) {
+        self.alpha_threshold = threshold;
+        self.reslice();
+    }
+
+    /// Whether island detection is possible (the texture's pixels are known).
+    pub fn has_pixels(&self) -> bool {
+        self.target.as_ref().is_some_and(|target| target.pixels.is_some())
+    }
+
+    /// Regions the current settings produce.
+    pub fn slices(&self) -> &[PixelRect] {
+        &self.slices
+    }
+
+    /// The atlas saving would write: the slices named after the texture's
+    /// file stem (`hero_0`, `hero_1`, ...).
+    pub fn atlas(&self) -> Option<SpriteAtlas> {
+        let target = self.target.as_ref()?;
+        let prefix = Path::new(&target.path).file_stem().and_then(|stem| stem.to_str()).unwrap_or("region");
+        Some(SpriteAtlas::from_rects(&target.path, target.width, target.height, prefix, self.slices.iter().copied()))
+    }
+
+    /// Recompute `slices` for the current target and settings.
+    fn reslice(&mut self) {
+        let Some(target) = &self.target else {
+            self.slices.clear();
+            return;
+        };
+        self.slices = match (self.mode, &target.pixels) {
+            (SliceMode::Grid, _) => slice_grid(target.width, target.height, self.cell.0, self.cell.1),
+            (SliceMode::Islands, Some(pixels)) => {
+                detect_islands(pixels, target.width, target.height, self.alpha_threshold)
+            }
+            (SliceMode::Islands, None) => Vec::new(),
+        };
+    }
+
+    /// Draw the slicer inside `bounds` (see [`SPRITE_SLICER_SIZE`]) as an
+    /// input-blocking overlay. Returns the user's decision, if any; the
+    /// slicer closes itself on either action.
+    pub fn render(&mut self, ui: &mut UIContext, bounds: Rect, theme: &EditorTheme) -> Option<SlicerAction> {
+        let target = self.target.clone()?;
+        ui.begin_overlay(bounds);
+        ui.panel_styled(bounds, theme.bg_primary, theme.border_panel, 1.0);
+        ui.label_styled("Slice Sprite Sheet", Vec2::new(bounds.x + PAD, bounds.y + PAD), theme.text_primary, theme.fonts.body);
+        let info = format!("{} ({}x{} px)", target.path, target.width, target.height);
+        ui.label_styled(&info, Vec2::new(bounds.x + 150.0, bounds.y + PAD + 3.0), theme.text_muted, theme.fonts.small);
+
+        self.render_mode_row(ui, bounds);
+        self.render_settings_row(ui, bounds, &target, theme);
+        let view = texture_view(bounds, target.width, target.height);
+        self.draw_view(ui, view, &target, theme);
+
+        let footer_y = bounds.y + bounds.height - PAD - ROW_HEIGHT;
+        let summary = match self.atlas() {
+            Some(atlas) if !atlas.is_empty() => format!("{} regions: {} .. {}", atlas.len(), atlas.regions[0].name, atlas.regions[atlas.len() - 1].name),
+            _ => "No regions".to_string(),
+        };
+        ui.label_styled(&summary, Vec2::new(bounds.x + PAD, footer_y - ROW_HEIGHT + 4.0), theme.text_secondary, theme.fonts.small);
+        let button = |index: f32| Rect::new(bounds.x + PAD + index * 96.0, footer_y, 90.0, ROW_HEIGHT);
+        let save = ui.button_styled("sprite_slicer_save", "Save Atlas", button(0.0), !self.slices.is_empty());
+        let cancel = ui.button("sprite_slicer_cancel", "Cancel", button(1.0));
+        ui.end_overlay();
+
+        if save {
+            let atlas = self.atlas();
+            self.close();
+            atlas.map(SlicerAction::Save)
+        } else if cancel {
+            self.close();
+            Some(SlicerAction::Cancel)
+        } else {
+            None
+        }
+    }
+
+    /// "[Grid] [Islands]" — the active mode's button is disabled.
+    fn render_mode_row(&mut self, ui: &mut UIContext, bounds: Rect) {
+        let y = bounds.y + PAD + ROW_HEIGHT + 2.0;
+        for (index, (mode, label)) in [(SliceMode::Grid, "Grid"), (SliceMode::Islands, "Islands")].into_iter().enumerate() {
+            let rect = Rect::new(bounds.x + PAD + index as f32 * 76.0, y, 70.0, ROW_HEIGHT);
+            if ui.button_styled(("sprite_slicer_mode", index), label, rect, self.mode != mode) {
+                self.set_mode(mode);
+            }
+        }
+    }
+
+    /// Grid: "W n px [-][+]  H n px [-][+]"; islands: "Alpha > n [-][+]".
+    fn render_settings_row(&mut self, ui: &mut UIContext, bounds: Rect, target: &SlicerTarget, theme: &EditorTheme) {
+        let y = bounds.y + PAD + 2.0 * (ROW_HEIGHT + 2.0);
+        let mut x = bounds.x + PAD;
+        let stepper = |ui: &mut UIContext, x: f32, index: usize| {
+            let less = ui.button(("sprite_slicer_less", index), "-", Rect::new(x, y, ROW_HEIGHT, ROW_HEIGHT));
+            let more = ui.button(("sprite_slicer_more", index), "+", Rect::new(x + ROW_HEIGHT + 2.0, y, ROW_HEIGHT, ROW_HEIGHT));
+            (less, more)
+        };
+        match self.mode {
+            SliceMode::Grid => {
+                let (mut width, mut height) = self.cell;
+                for (index, (axis, value, size)) in
+                    [("W", &mut width, target.width), ("H", &mut height, target.height)].into_iter().enumerate()
+                {
+                    ui.label_styled(&format!("{axis} {value} px"), Vec2::new(x, y + 4.0), theme.text_secondary, theme.fonts.small);
+                    x += 64.0;
+                    match stepper(ui, x, index) {
+                        (true, _) => *value = step_cell_size(size, *value, false),
+                        (_, true) => *value = step_cell_size(size, *value, true),
+                        _ => {}
+                    }
+                    x += 2.0 * ROW_HEIGHT + 18.0;
+                }
+                if (width, height) != self.cell {
+                    self.set_cell_size(width, height);
+                }
+            }
+            SliceMode::Islands if self.has_pixels() => {
+                let threshold = self.alpha_threshold;
+                ui.label_styled(&format!("Alpha > {threshold}"), Vec2::new(x, y + 4.0), theme.text_secondary, theme.fonts.small);
+                x += 80.0;
+                match stepper(ui, x, 0) {
+                    (true, _) => self.set_alpha_threshold(threshold.saturating_sub(ALPHA_STEP)),
+                    (_, true) => self.set_alpha_threshold(threshold.saturating_add(ALPHA_STEP).min(u8::MAX - 1)),
+                    _ => {}
+                }
+            }
+            SliceMode::Islands => {
+                ui.label_styled("Pixel data unavailable for this texture", Vec2::new(x, y + 4.0), theme.error_red, theme.fonts.small);
+            }
+        }
+    }
+
+    /// Texture with every slice outlined.
+    fn draw_view(&self, ui: &mut UIContext, view: Rect, target: &SlicerTarget, theme: &EditorTheme) {
+        ui.rect(view, theme.bg_input);
+        ui.image(view, target.texture, Color::WHITE);
+        let scale = Vec2::new(view.width / target.width.max(1) as f32, view.height / target.height.max(1) as f32);
+        for slice in &self.slices {
+            let rect = Rect::new(
+                view.x + slice.x as f32 * scale.x,
+                view.y + slice.y as f32 * scale.y,
+                slice.width as f32 * scale.x,
+                slice.height as f32 * scale.y,
+            );
+            ui.rect_border(rect, theme.accent_cyan, 1.0, 0.0);
+        }
+    }
+}
+
+/// Largest rect with the texture's aspect ratio between the settings row
+/// and the footer, centered horizontally.
+fn texture_view(bounds: Rect, width: u32, height: u32) -> Rect {
+    let top = bounds.y + PAD + 3.0 * (ROW_HEIGHT + 2.0) + PAD;
+    let bottom = bounds.y + bounds.height - PAD - 2.0 * ROW_HEIGHT - PAD;
+    let area = Vec2::new(bounds.width - 2.0 * PAD, (bottom - top).max(0.0));
+    let aspect = if width > 0 && height > 0 { width as f32 / height as f32 } else { 1.0 };
+    let size = if area.x / area.y.max(1.0) > aspect {
+        Vec2::new(area.y * aspect, area.y)
+    } else {
+        Vec2::new(area.x, area.x / aspect)
+    };
+    Rect::new(bounds.x + (bounds.width - size.x) / 2.0, top, size.x, size.y)
+}
+
+/// Atlases of the textures the inspector has shown, by texture handle.
+/// `None` records "no atlas file" so missing files are read once.
+#[derive(Debug, Default)]
+pub struct SpriteAtlases {
+    atlases: HashMap<u32, Option<SpriteAtlas>>,
+}
+
+impl SpriteAtlases {
+    /// An empty cache.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether `texture` has been looked up (with or without an atlas).
+    pub fn is_loaded(&self, texture: u32) -> bool {
+        self.atlases.contains_key(&texture)
+    }
+
+    /// Record `texture`'s atlas, or that it has none.
+    pub fn insert(&mut self, texture: u32, atlas: Option<SpriteAtlas>) {
+        self.atlases.insert(texture, atlas);
+    }
+
+    /// `texture`'s atlas, if it has one.
+    pub fn get(&self, texture: u32) -> Option<&SpriteAtlas> {
+        self.atlases.get(&texture)?.as_ref()
+    }
+
+    /// `texture`'s regions as `(name, normalized region)`; empty without an atlas.
+    pub fn named_regions(&self, texture: u32) -> Vec<(String, [f32; 4])> {
+        self.get(texture).map(SpriteAtlas::uv_regions).unwrap_or_default()
+    }
+
+    /// Forget every looked-up texture (e.g. after assets reload).
+    pub fn clear(&mut self) {
+        self.atlases.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cell_steps_only_land_on_sizes_that_tile_the_sheet() {
+        assert_eq!(step_cell_size(96, 32, true), 48);
+        assert_eq!(step_cell_size(96, 32, false), 24);
+        assert_eq!(step_cell_size(96, 96, true), 96, "stays at the full size");
+        assert_eq!(step_cell_size(96, 1, false), 1);
+        assert_eq!(divisor_at_most(100, 32), 25);
+    }
+
+    #[test]
+    fn test_open_slices_by_grid_and_islands() {
+        let mut slicer = SpriteSheetSlicer::new();
+        // 4x2 sheet: two opaque pixels far apart
+        let mut pixels = vec![0u8; 4 * 2 * 4];
+        pixels[3] = 255;
+        pixels[(7 * 4) + 3] = 255;
+        slicer.open(5, "sprites/bat.png", 4, 2, Some(pixels));
+        assert_eq!(slicer.cell_size(), (4, 2), "largest cell at most 32 that tiles the sheet");
+        slicer.set_cell_size(2, 2);
+        assert_eq!(slicer.slices().len(), 2);
+
+        slicer.set_mode(SliceMode::Islands);
+        assert_eq!(slicer.slices(), [PixelRect::new(0, 0, 1, 1), PixelRect::new(3, 1, 1, 1)]);
+        let atlas = slicer.atlas().unwrap();
+        assert_eq!(atlas.texture, "sprites/bat.png");
+        assert_eq!(atlas.regions[1].name, "bat_1");
+
+        slicer.close();
+        assert!(!slicer.is_open());
+        slicer.open(6, "tiles.png", 8, 8, None);
+        assert!(slicer.slices().is_empty(), "islands need pixels");
+        assert_eq!(slicer.cell_size(), (2, 2), "cell size kept when it still tiles");
+    }
+
+    #[test]
+    fn test_atlas_cache_remembers_missing_atlases() {
+        let mut atlases = SpriteAtlases::new();
+        assert!(!atlases.is_loaded(3));
+        atlases.insert(3, None);
+        assert!(atlases.is_loaded(3));
+        assert!(atlases.named_regions(3).is_empty());
+
+        let atlas = SpriteAtlas::from_rects("a.png", 2, 1, "a", slice_grid(2, 1, 1, 1));
+        atlases.insert(4, Some(atlas));
+        assert_eq!(atlases.named_regions(4)[1], ("a_1".to_string(), [0.5, 0.0, 0.5, 1.0]));
+    }
+}
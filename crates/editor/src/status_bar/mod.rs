@@ -0,0 +1,401 @@
+//! Editor status bar displayed at the bottom of the window.
+//!
+//! Shows contextual status messages or background task progress (left),
+//! runtime stats (center), and the world position under the mouse plus
+//! version info (right). Status messages
+//! auto-clear after a timeout. Clicking the left section opens a popup with
+//! the history of recent task results.
+
+use std::collections::VecDeque;
+
+use glam::Vec2;
+use renderer::RenderStats;
+use ui::{Rect, UIContext};
+
+use crate::background_tasks::TaskId;
+use crate::theme::EditorTheme;
+
+/// Height of the status bar in pixels.
+pub const STATUS_BAR_HEIGHT: f32 = 22.0;
+
+/// Duration in seconds before a status message auto-clears.
+const MESSAGE_TIMEOUT: f32 = 3.0;
+
+/// Maximum number of task results kept for the history popup.
+pub const TASK_HISTORY_CAPACITY: usize = 20;
+
+/// Width of the inline progress bar for the active task.
+const PROGRESS_BAR_WIDTH: f32 = 120.0;
+
+/// Height of the inline progress bar.
+const PROGRESS_BAR_HEIGHT: f32 = 8.0;
+
+/// Width of the task history popup.
+const HISTORY_POPUP_WIDTH: f32 = 360.0;
+
+/// Height of one row in the task history popup.
+const HISTORY_ROW_HEIGHT: f32 = 18.0;
+
+/// Runtime statistics for the status bar center section.
+#[derive(Debug, Clone, Default)]
+pub struct StatusBarStats {
+    /// Number of entities in the world.
+    pub entity_count: usize,
+    /// Smoothed frames-per-second.
+    pub fps: f32,
+    /// Render statistics of the previous frame.
+    pub render: RenderStats,
+    /// Frames the game has run this play session (`None` while editing).
+    pub play_frame: Option<u64>,
+}
+
+impl StatusBarStats {
+    /// Center-section text, e.g.
+    /// `"Objects: 12 | Sprites: 40 | Draw calls: 9 | Textures: 1.5 MiB | FPS: 60"`,
+    /// followed by `" | Frame: 128"` during a play session.
+    pub fn display_text(&self) -> String {
+        let text = format!(
+            "Objects: {} | Sprites: {} | Draw calls: {} | Textures: {:.1} MiB | FPS: {:.0}",
+            self.entity_count,
+            self.render.sprites,
+            self.render.draw_calls,
+            self.render.texture_memory_mib(),
+            self.fps,
+        );
+        match self.play_frame {
+            Some(frame) => format!("{text} | Frame: {frame}"),
+            None => text,
+        }
+    }
+}
+
+/// A task currently reporting progress to the status bar.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StatusTask {
+    /// Task id (from [`BackgroundTasks`](crate::BackgroundTasks) or the
+    /// reporting subsystem).
+    pub id: TaskId,
+    /// Label shown next to the progress bar (e.g. "Packing atlas").
+    pub label: String,
+    /// Completion in `0.0..=1.0`.
+    pub fraction: f32,
+    /// Optional detail (e.g. the file being processed).
+    pub detail: Option<String>,
+}
+
+impl StatusTask {
+    /// Status text, e.g. `"Packing atlas… 40%"` or
+    /// `"Importing… 25% (hero.png)"`.
+    pub fn display_text(&self) -> String {
+        let percent = (self.fraction * 100.0).round();
+        match &self.detail {
+            Some(detail) => format!("{}… {percent:.0}% ({detail})", self.label),
+            None => format!("{}… {percent:.0}%", self.label),
+        }
+    }
+}
+
+/// A finished task result kept in the history popup.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TaskRecord {
+    /// Result text (e.g. "Scan assets finished").
+    pub text: String,
+    /// Whether the task succeeded.
+    pub succeeded: bool,
+}
+
+/// The editor status bar widget.
+#[derive(Debug, Clone)]
+pub struct StatusBar {
+    /// Current status message (left section).
+    message: Option<String>,
+    /// Time remaining before the message auto-clears (seconds).
+    message_timer: f32,
+    /// Whether the current message should persist (errors).
+    message_persistent: bool,
+    /// Version string (right section).
+    version: String,
+    /// Runtime stats updated each frame.
+    stats: StatusBarStats,
+    /// Tasks currently reporting progress, in start order.
+    tasks: Vec<StatusTask>,
+    /// Recent task results, newest first.
+    history: VecDeque<TaskRecord>,
+    /// Whether the history popup is open.
+    history_open: bool,
+    /// World position under the mouse while it is over the scene view.
+    cursor_world: Option<Vec2>,
+}
+
+impl Default for StatusBar {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl StatusBar {
+    /// Create a new status bar.
+    pub fn new() -> Self {
+        Self {
+            message: None,
+            message_timer: 0.0,
+            message_persistent: false,
+            version: String::from("v0.1.0"),
+            stats: StatusBarStats::default(),
+            tasks: Vec::new(),
+            history: VecDeque::new(),
+            history_open: false,
+            cursor_world: None,
+        }
+    }
+
+    /// Set the version string displayed on the right.
+    pub fn set_version(&mut self, version: impl Into<String>) {
+        self.version = version.into();
+    }
+
+    /// Show a temporary status message (auto-clears after 3 seconds).
+    pub fn show_message(&mut self, message: impl Into<String>) {
+        self.message = Some(message.into());
+        self.message_timer = MESSAGE_TIMEOUT;
+        self.message_persistent = false;
+    }
+
+    /// Show a persistent status message (stays until explicitly cleared).
+    pub fn show_error(&mut self, message: impl Into<String>) {
+        self.message = Some(message.into());
+        self.message_timer = 0.0;
+        self.message_persistent = true;
+    }
+
+    /// Clear the current status message.
+    pub fn clear_message(&mut self) {
+        self.message = None;
+        self.message_timer = 0.0;
+        self.message_persistent = false;
+    }
+
+    /// Get the current message, if any.
+    pub fn message(&self) -> Option<&str> {
+        self.message.as_deref()
+    }
+
+    // ================== Task Progress ==================
+
+    /// Start showing progress for a task. Restarting an existing id resets
+    /// its progress.
+    pub fn begin_task(&mut self, id: TaskId, label: impl Into<String>) {
+        let task = StatusTask { id, label: label.into(), fraction: 0.0, detail: None };
+        match self.tasks.iter_mut().find(|t| t.id == id) {
+            Some(existing) => *existing = task,
+            None => self.tasks.push(task),
+        }
+    }
+
+    /// Update a task's progress (`fraction` is clamped to `0.0..=1.0`).
+    /// Unknown ids are ignored.
+    pub fn set_task_progress(&mut self, id: TaskId, fraction: f32, detail: Option<String>) {
+        if let Some(task) = self.tasks.iter_mut().find(|t| t.id == id) {
+            task.fraction = fraction.clamp(0.0, 1.0);
+            task.detail = detail;
+        }
+    }
+
+    /// Finish a task: remove its progress bar, show the outcome (errors
+    /// persist) and record it in the history.
+    pub fn finish_task(&mut self, id: TaskId, outcome: Result<String, String>) {
+        self.tasks.retain(|t| t.id != id);
+        match outcome {
+            Ok(text) => {
+                self.show_message(text.clone());
+                self.record_result(text, true);
+            }
+            Err(text) => {
+                self.show_error(text.clone());
+                self.record_result(text, false);
+            }
+        }
+    }
+
+    /// Whether a task with `id` is currently reporting progress.
+    pub fn has_task(&self, id: TaskId) -> bool {
+        self.tasks.iter().any(|t| t.id == id)
+    }
+
+    /// Tasks currently reporting progress, in start order.
+    pub fn active_tasks(&self) -> &[StatusTask] {
+        &self.tasks
+    }
+
+    /// Record a job result in the history popup without touching the
+    /// current message (for synchronous jobs such as scene saves that
+    /// already reported through [`show_message`](Self::show_message)).
+    pub fn record_result(&mut self, text: impl Into<String>, succeeded: bool) {
+        self.history.push_front(TaskRecord { text: text.into(), succeeded });
+        self.history.truncate(TASK_HISTORY_CAPACITY);
+    }
+
+    /// Recent task results, newest first.
+    pub fn history(&self) -> impl Iterator<Item = &TaskRecord> {
+        self.history.iter()
+    }
+
+    /// Whether the task history popup is open.
+    pub fn is_history_open(&self) -> bool {
+        self.history_open
+    }
+
+    /// Open or close the task history popup.
+    pub fn toggle_history(&mut self) {
+        self.history_open = !self.history_open;
+    }
+
+    /// Update runtime stats.
+    pub fn update_stats(&mut self, entity_count: usize, fps: f32) {
+        self.stats.entity_count = entity_count;
+        self.stats.fps = fps;
+    }
+
+    /// Show the play session's frame index (`None` hides it).
+    pub fn set_play_frame(&mut self, frame: Option<u64>) {
+        self.stats.play_frame = frame;
+    }
+
+    /// Update the render statistics shown in the center section.
+    pub fn update_render_stats(&mut self, stats: RenderStats) {
+        self.stats.render = stats;
+    }
+
+    /// Set the world position under the mouse (`None` when the mouse is
+    /// outside the scene view).
+    pub fn set_cursor_world(&mut self, position: Option<Vec2>) {
+        self.cursor_world = position;
+    }
+
+    /// Cursor readout text, e.g. `"X: 120.5  Y: -40.0"`, if the mouse is
+    /// over the scene view.
+    pub fn cursor_text(&self) -> Option<String> {
+        self.cursor_world.map(|p| format!("X: {:.1}  Y: {:.1}", p.x, p.y))
+    }
+
+    /// Tick the message timer. Call once per frame with delta time.
+    pub fn update(&mut self, delta_time: f32) {
+        if !self.message_persistent && self.message.is_some() {
+            self.message_timer -= delta_time;
+            if self.message_timer <= 0.0 {
+                self.message = None;
+                self.message_timer = 0.0;
+            }
+        }
+    }
+
+    /// Render the status bar. Returns the bar bounds for layout purposes.
+    ///
+    /// The status bar is positioned at the bottom of the given `window_size`.
+    pub fn render(
+        &mut self,
+        ui: &mut UIContext,
+        window_size: Vec2,
+        theme: &EditorTheme,
+    ) -> Rect {
+        let bar = Rect::new(0.0, window_size.y - STATUS_BAR_HEIGHT, window_size.x, STATUS_BAR_HEIGHT);
+
+        // Background
+        ui.rect(bar, theme.status_bar_bg);
+
+        // Top separator in the panel-border blue so the bar reads as chrome
+        ui.line(
+            Vec2::new(bar.x, bar.y),
+            Vec2::new(bar.x + bar.width, bar.y),
+            theme.border_panel,
+            1.0,
+        );
+
+        let padding = 8.0;
+
+        // Left section: clickable — toggles the task history popup
+        let left = Rect::new(bar.x, bar.y, bar.width / 3.0, bar.height);
+        if ui.interact("status_bar_history", left, true).clicked {
+            self.toggle_history();
+        }
+
+        if let (None, Some(task)) = (&self.message, self.tasks.last()) {
+            // Active task: inline progress bar followed by "Label… 40%"
+            let track = Rect::new(
+                bar.x + padding,
+                bar.y + (bar.height - PROGRESS_BAR_HEIGHT) / 2.0,
+                PROGRESS_BAR_WIDTH,
+                PROGRESS_BAR_HEIGHT,
+            );
+            ui.progress_bar_styled(task.fraction, track, theme.bg_input, theme.accent_blue);
+            let mut text = task.display_text();
+            if self.tasks.len() > 1 {
+                text.push_str(&format!(" (+{} more)", self.tasks.len() - 1));
+            }
+            let text_bounds = Rect::new(track.x + track.width, bar.y, left.width - track.width - padding, bar.height);
+            ui.label_in_bounds_styled(&text, text_bounds, ui::TextAlign::Left, theme.text_secondary, theme.fonts.small, padding);
+        } else {
+            // Left section: status message
+            let status_text = self.message.as_deref().unwrap_or("Ready");
+            let status_color = if self.message_persistent {
+                theme.error_red
+            } else {
+                theme.text_secondary
+            };
+            ui.label_in_bounds_styled(status_text, bar, ui::TextAlign::Left, status_color, theme.fonts.small, padding);
+        }
+
+        // Center section: runtime stats
+        let stats_text = self.stats.display_text();
+        ui.label_in_bounds_styled(&stats_text, bar, ui::TextAlign::Center, theme.text_muted, theme.fonts.small, padding);
+
+        // Right section: cursor world position, then version
+        ui.label_in_bounds_styled(&self.version, bar, ui::TextAlign::Right, theme.accent_cyan, theme.fonts.small, padding);
+        if let Some(cursor) = self.cursor_text() {
+            let version_width = ui.measure_text_styled(&self.version, theme.fonts.small).x;
+            let cursor_bounds = Rect::new(bar.x, bar.y, bar.width - version_width - padding * 2.0, bar.height);
+            ui.label_in_bounds_styled(&cursor, cursor_bounds, ui::TextAlign::Right, theme.text_secondary, theme.fonts.small, padding);
+        }
+
+        if self.history_open {
+            self.render_history_popup(ui, bar, left, theme);
+        }
+
+        bar
+    }
+
+    /// Render the task history popup above the left section. A click
+    /// anywhere outside the popup and the left section closes it.
+    fn render_history_popup(&mut self, ui: &mut UIContext, bar: Rect, left: Rect, theme: &EditorTheme) {
+        let rows = self.history.len().max(1);
+        let height = rows as f32 * HISTORY_ROW_HEIGHT + 8.0;
+        let popup = Rect::new(bar.x, bar.y - height, HISTORY_POPUP_WIDTH.min(bar.width), height);
+
+        if ui.mouse_just_pressed() {
+            let mouse = ui.mouse_pos();
+            if !ui.hit_test(mouse, popup) && !ui.hit_test(mouse, left) {
+                self.history_open = false;
+                return;
+            }
+        }
+
+        ui.begin_overlay(popup);
+        ui.rect(popup, theme.bg_header);
+        ui.rect_border(popup, theme.border_panel, 1.0, 0.0);
+
+        let padding = 8.0;
+        if self.history.is_empty() {
+            let row = Rect::new(popup.x, popup.y + 4.0, popup.width, HISTORY_ROW_HEIGHT);
+            ui.label_in_bounds_styled("No recent tasks", row, ui::TextAlign::Left, theme.text_muted, theme.fonts.small, padding);
+        }
+        for (i, record) in self.history.iter().enumerate() {
+            let row = Rect::new(popup.x, popup.y + 4.0 + i as f32 * HISTORY_ROW_HEIGHT, popup.width, HISTORY_ROW_HEIGHT);
+            let color = if record.succeeded { theme.text_secondary } else { theme.error_red };
+            ui.label_in_bounds_styled(&record.text, row, ui::TextAlign::Left, color, theme.fonts.small, padding);
+        }
+        ui.end_overlay();
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,191 @@
+use super::*;
+
+#[test]
+fn test_status_bar_default() {
+    let bar = StatusBar::new();
+    assert!(bar.message().is_none());
+    assert_eq!(bar.stats.entity_count, 0);
+    assert_eq!(bar.stats.fps, 0.0);
+}
+
+#[test]
+fn test_show_message() {
+    let mut bar = StatusBar::new();
+    bar.show_message("Entity created");
+    assert_eq!(bar.message(), Some("Entity created"));
+}
+
+#[test]
+fn test_message_auto_clears() {
+    let mut bar = StatusBar::new();
+    bar.show_message("Saved");
+
+    // Tick partially — message still visible
+    bar.update(1.0);
+    assert!(bar.message().is_some());
+
+    // Tick past timeout — message cleared
+    bar.update(3.0);
+    assert!(bar.message().is_none());
+}
+
+#[test]
+fn test_persistent_error_message() {
+    let mut bar = StatusBar::new();
+    bar.show_error("Failed to save");
+
+    // Even after a long time, error persists
+    bar.update(100.0);
+    assert_eq!(bar.message(), Some("Failed to save"));
+
+    // Explicit clear removes it
+    bar.clear_message();
+    assert!(bar.message().is_none());
+}
+
+#[test]
+fn test_update_stats() {
+    let mut bar = StatusBar::new();
+    bar.update_stats(42, 60.0);
+    assert_eq!(bar.stats.entity_count, 42);
+    assert_eq!(bar.stats.fps, 60.0);
+}
+
+#[test]
+fn test_render_stats_in_display_text() {
+    let mut bar = StatusBar::new();
+    bar.update_stats(3, 59.6);
+    bar.update_render_stats(renderer::RenderStats {
+        sprites: 120,
+        draw_calls: 7,
+        texture_memory: 3 * 1024 * 1024 / 2,
+        ..Default::default()
+    });
+    assert_eq!(
+        bar.stats.display_text(),
+        "Objects: 3 | Sprites: 120 | Draw calls: 7 | Textures: 1.5 MiB | FPS: 60"
+    );
+}
+
+#[test]
+fn test_play_frame_in_display_text() {
+    let mut bar = StatusBar::new();
+    bar.update_stats(1, 30.0);
+    bar.set_play_frame(Some(128));
+    assert!(bar.stats.display_text().ends_with("FPS: 30 | Frame: 128"));
+    bar.set_play_frame(None);
+    assert!(bar.stats.display_text().ends_with("FPS: 30"));
+}
+
+#[test]
+fn test_set_version() {
+    let mut bar = StatusBar::new();
+    bar.set_version("v2.0.1 - Stable");
+    assert_eq!(bar.version, "v2.0.1 - Stable");
+}
+
+#[test]
+fn test_show_message_resets_timer() {
+    let mut bar = StatusBar::new();
+    bar.show_message("First");
+    bar.update(2.0); // 1 second left
+
+    bar.show_message("Second");
+    // Timer should be reset to full duration
+    bar.update(2.0); // Should still be visible
+    assert_eq!(bar.message(), Some("Second"));
+
+    bar.update(2.0); // Now it should be gone
+    assert!(bar.message().is_none());
+}
+
+#[test]
+fn test_clear_message_stops_timer() {
+    let mut bar = StatusBar::new();
+    bar.show_message("Temp");
+    bar.clear_message();
+    assert!(bar.message().is_none());
+    assert!(!bar.message_persistent);
+}
+
+#[test]
+fn task_progress_is_tracked_until_finished() {
+    let mut bar = StatusBar::new();
+    let id = TaskId::new(7);
+    bar.begin_task(id, "Packing atlas");
+    bar.set_task_progress(id, 0.4, None);
+
+    let task = &bar.active_tasks()[0];
+    assert_eq!(task.display_text(), "Packing atlas… 40%");
+
+    bar.finish_task(id, Ok("Packing atlas finished".to_string()));
+    assert!(bar.active_tasks().is_empty());
+    assert_eq!(bar.message(), Some("Packing atlas finished"));
+}
+
+#[test]
+fn task_progress_is_clamped_and_shows_detail() {
+    let mut bar = StatusBar::new();
+    let id = TaskId::new(1);
+    bar.begin_task(id, "Importing");
+    bar.set_task_progress(id, 1.5, Some("hero.png".to_string()));
+    assert_eq!(bar.active_tasks()[0].display_text(), "Importing… 100% (hero.png)");
+}
+
+#[test]
+fn progress_for_unknown_task_is_ignored() {
+    let mut bar = StatusBar::new();
+    bar.set_task_progress(TaskId::new(3), 0.5, None);
+    assert!(bar.active_tasks().is_empty());
+}
+
+#[test]
+fn failed_task_shows_persistent_error_and_is_recorded() {
+    let mut bar = StatusBar::new();
+    let id = TaskId::new(2);
+    bar.begin_task(id, "Scan");
+    bar.finish_task(id, Err("Scan failed".to_string()));
+
+    bar.update(100.0);
+    assert_eq!(bar.message(), Some("Scan failed"));
+    let record = bar.history().next().expect("history entry");
+    assert!(!record.succeeded);
+}
+
+#[test]
+fn history_is_newest_first_and_bounded() {
+    let mut bar = StatusBar::new();
+    for i in 0..TASK_HISTORY_CAPACITY + 5 {
+        bar.record_result(format!("job {i}"), true);
+    }
+    assert_eq!(bar.history().count(), TASK_HISTORY_CAPACITY);
+    let newest = format!("job {}", TASK_HISTORY_CAPACITY + 4);
+    assert_eq!(bar.history().next().map(|r| r.text.as_str()), Some(newest.as_str()));
+}
+
+#[test]
+fn record_result_does_not_change_message() {
+    let mut bar = StatusBar::new();
+    bar.record_result("Scene saved", true);
+    assert!(bar.message().is_none());
+}
+
+#[test]
+fn toggle_history_opens_and_closes_popup() {
+    let mut bar = StatusBar::new();
+    assert!(!bar.is_history_open());
+    bar.toggle_history();
+    assert!(bar.is_history_open());
+    bar.toggle_history();
+    assert!(!bar.is_history_open());
+}
+
+#[test]
+fn test_cursor_readout_formats_world_position() {
+    let mut bar = StatusBar::new();
+    assert_eq!(bar.cursor_text(), None);
+    bar.set_cursor_world(Some(Vec2::new(120.46, -40.0)));
+    assert_eq!(bar.cursor_text().as_deref(), Some("X: 120.5  Y: -40.0"));
+    bar.set_cursor_world(None);
+    assert_eq!(bar.cursor_text(), None);
+}
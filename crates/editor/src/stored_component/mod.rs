@@ -8,21 +8,25 @@
 
 use ecs::audio_components::{AudioListener, AudioSource};
 use ecs::behavior::{Behavior, BehaviorState, EntityTag};
-use ecs::hierarchy::GlobalTransform2D;
+use ecs::hierarchy::{GlobalTransform2D, Group, SceneInstanceRef};
 use ecs::sprite_components::{Name, Sprite, SpriteAnimation};
 use ecs::tilemap::Tilemap;
 use ecs::{EntityId, World};
 use physics::components::{Collider, RigidBody};
+use physics::{AreaForce, Buoyancy, Drag};
 use ui::UIContext;
 
 use crate::behavior_editor::edit_behavior;
 use crate::commands::{
-    CommandHistory, RemoveComponentCommand, SetAudioSourceCommand, SetBehaviorCommand,
-    SetColliderCommand, SetRigidBodyCommand, SetSpriteCommand, SetTransformCommand,
+    CommandHistory, EditorCommand, MacroCommand, RemoveComponentCommand, SetAreaForceCommand, SetAudioSourceCommand,
+    SetBehaviorCommand, SetBuoyancyCommand, SetColliderCommand, SetDragCommand, SetRigidBodyCommand,
+    SetSpriteAnimationCommand, SetSpriteCommand, SetTransformCommand,
 };
 use crate::component_editors::{
-    edit_audio_source, edit_collider, edit_rigid_body, edit_sprite, edit_transform2d,
+    edit_audio_source, edit_collider, edit_rigid_body, edit_sprite, edit_sprite_animation,
+    edit_transform2d,
 };
+use crate::effector_editors::{edit_area_force, edit_buoyancy, edit_drag};
 use crate::inspector::{inspect_component, InspectorStyle};
 use crate::{EditableFieldStyle, EditableInspector};
 
@@ -30,21 +34,26 @@ use crate::{EditableFieldStyle, EditableInspector};
 /// [`edit_all_components`] — dispatched on the edit spec written in the
 /// registry: `{ edit <fn> => <SetCommand> }` renders field editors with
 /// undo-recorded writeback, `{ readonly }` renders the registry header with
-/// a remove button plus the serde-based read-only display.
+/// a remove button plus the serde-based read-only display. Blocks show only
+/// components every selected entity has; edits apply to all of them. Every
+/// header also carries the copy/paste right-click menu.
 macro_rules! registry_edit_block {
     // Editable, NOT removable (builtin): the editor fn renders its own header.
     (@fixed $name:ident, $ty:ty, (edit $edit_fn:ident => $cmd:ident),
-     $ui:ident, $world:ident, $entity:ident, $history:ident, $x:ident, $y:ident,
+     $ui:ident, $world:ident, $entities:ident, $history:ident, $x:ident, $y:ident,
      $inspect_style:ident, $field_style:ident, $gap:ident, $idx:ident, $removals:ident,
      $extras:ident) => {
-        if let Some(value) = $world.get::<$ty>($entity).cloned() {
+        if let Some(value) = crate::multi_edit::shared_value::<$ty>($world, $entities) {
             $y += $gap;
+            crate::component_clipboard::header_menu($ui, $idx, stringify!($name), $x, $y, $field_style, $extras);
+            let mixed = crate::multi_edit::mixed_fields::<$ty>($world, $entities, $extras.materials, $edit_fn);
             let mut inspector = EditableInspector::new($ui, $x, $y)
                 .with_component_index($idx)
-                .with_style($field_style.clone());
+                .with_style($field_style.clone())
+                .with_mixed(mixed);
             let edit = $edit_fn(&mut inspector, &value, &mut *$extras);
             $y = inspector.y();
-            crate::component_editors::apply_component_edit($world, $entity, &value, edit, $history, |e, old, new, hint| {
+            crate::multi_edit::apply_multi_edit($world, $entities, &value, edit, $history, |e, old, new, hint| {
                 Box::new($cmd::new(e, old, new, hint))
             });
             $idx += 1;
@@ -52,34 +61,38 @@ macro_rules! registry_edit_block {
     };
     // Editable + removable: overlay the [X] at the header the editor fn drew.
     (@removable $name:ident, $ty:ty, (edit $edit_fn:ident => $cmd:ident),
-     $ui:ident, $world:ident, $entity:ident, $history:ident, $x:ident, $y:ident,
+     $ui:ident, $world:ident, $entities:ident, $history:ident, $x:ident, $y:ident,
      $inspect_style:ident, $field_style:ident, $gap:ident, $idx:ident, $removals:ident,
      $extras:ident) => {
-        if let Some(value) = $world.get::<$ty>($entity).cloned() {
+        if let Some(value) = crate::multi_edit::shared_value::<$ty>($world, $entities) {
             $y += $gap;
+            crate::component_clipboard::header_menu($ui, $idx, stringify!($name), $x, $y, $field_style, $extras);
             let header_y = $y;
+            let mixed = crate::multi_edit::mixed_fields::<$ty>($world, $entities, $extras.materials, $edit_fn);
             let mut inspector = EditableInspector::new($ui, $x, $y)
                 .with_component_index($idx)
-                .with_style($field_style.clone());
+                .with_style($field_style.clone())
+                .with_mixed(mixed);
             let edit = $edit_fn(&mut inspector, &value, &mut *$extras);
             $y = inspector.y();
             if crate::component_editors::remove_button($ui, $idx, $x, header_y, $field_style) {
                 $removals.push(ComponentKind::$name);
             }
-            crate::component_editors::apply_component_edit($world, $entity, &value, edit, $history, |e, old, new, hint| {
+            crate::multi_edit::apply_multi_edit($world, $entities, &value, edit, $history, |e, old, new, hint| {
                 Box::new($cmd::new(e, old, new, hint))
             });
             $idx += 1;
         }
     };
     // Read-only + removable: registry header with [X] + serde inspection
-    // (components without a field editor yet).
+    // (components without a field editor yet) of the primary's value.
     (@removable $name:ident, $ty:ty, (readonly),
-     $ui:ident, $world:ident, $entity:ident, $history:ident, $x:ident, $y:ident,
+     $ui:ident, $world:ident, $entities:ident, $history:ident, $x:ident, $y:ident,
      $inspect_style:ident, $field_style:ident, $gap:ident, $idx:ident, $removals:ident,
      $extras:ident) => {
-        if $world.get::<$ty>($entity).is_some() {
+        if let Some(value) = crate::multi_edit::shared_value::<$ty>($world, $entities) {
             $y += $gap;
+            crate::component_clipboard::header_menu($ui, $idx, stringify!($name), $x, $y, $field_style, $extras);
             let mut inspector = EditableInspector::new($ui, $x, $y)
                 .with_component_index($idx)
                 .with_style($field_style.clone());
@@ -87,14 +100,27 @@ macro_rules! registry_edit_block {
                 $removals.push(ComponentKind::$name);
             }
             $y = inspector.y();
-            if let Some(value) = $world.get::<$ty>($entity) {
-                $y = inspect_component($ui, "", value, $x + 16.0, $y, $inspect_style);
-            }
+            $y = inspect_component($ui, "", &value, $x + 16.0, $y, $inspect_style);
             $idx += 1;
         }
     };
 }
 
+/// Where and how [`edit_all_components`] lays out the component blocks,
+/// and the history their edits and removals are recorded into.
+pub struct ComponentEditorContext<'a> {
+    pub ui: &'a mut UIContext,
+    pub history: &'a mut CommandHistory,
+    /// Left edge of the blocks
+    pub x: f32,
+    /// Top of the first block
+    pub y: f32,
+    pub inspect_style: &'a InspectorStyle,
+    pub field_style: &'a EditableFieldStyle,
+    /// Space above each block
+    pub section_gap: f32,
+}
+
 /// Category grouping for the add-component popup.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ComponentCategory {
@@ -140,7 +166,7 @@ macro_rules! editor_component_registry {
     (
         hidden:    [ $( $h:ident => $h_ty:ty ),+ $(,)? ],
         builtin:   [ $( $b:ident => $b_ty:ty { $($b_edit:tt)+ } ),+ $(,)? ],
-        removable: [ $( $r:ident => $r_ty:ty : $cat:ident { $($r_edit:tt)+ } ),+ $(,)? ] $(,)?
+        removable: [ $( $r:ident => $r_ty:ty : $cat:ident $( requires [ $($dep:ident),+ ] )? { $($r_edit:tt)+ } ),+ $(,)? ] $(,)?
     ) => {
         /// A captured component value for undo/redo storage.
         ///
@@ -162,6 +188,58 @@ macro_rules! editor_component_registry {
                     $( Self::$r(c) => { world.add_component(&entity, Clone::clone(c)).ok(); } )+
                 }
             }
+
+            /// Registry name of the stored component type (e.g. `"Sprite"`).
+            pub fn type_name(&self) -> &'static str {
+                match self {
+                    $( Self::$h(_) => stringify!($h), )+
+                    $( Self::$b(_) => stringify!($b), )+
+                    $( Self::$r(_) => stringify!($r), )+
+                }
+            }
+
+            /// Serialize an inspectable (builtin/removable) component to JSON
+            /// for field-level diffing. Hidden components return `None`.
+            pub fn to_json(&self) -> Option<serde_json::Value> {
+                match self {
+                    $( Self::$b(c) => serde_json::to_value(c).ok(), )+
+                    $( Self::$r(c) => serde_json::to_value(c).ok(), )+
+                    _ => None,
+                }
+            }
+
+            /// Rebuild an inspectable component from its registry name and a
+            /// JSON value (the inverse of [`to_json`](Self::to_json)).
+            pub fn from_json(type_name: &str, value: serde_json::Value) -> Result<Self, String> {
+                match type_name {
+                    $( stringify!($b) => serde_json::from_value::<$b_ty>(value)
+                        .map(Self::$b)
+                        .map_err(|e| format!("{}: {}", type_name, e)), )+
+                    $( stringify!($r) => serde_json::from_value::<$r_ty>(value)
+                        .map(Self::$r)
+                        .map_err(|e| format!("{}: {}", type_name, e)), )+
+                    _ => Err(format!("{} is not an inspectable component", type_name)),
+                }
+            }
+
+            /// Capture the inspectable component registered as `type_name`
+            /// from an entity, if present.
+            pub fn capture_named(world: &World, entity: EntityId, type_name: &str) -> Option<Self> {
+                match type_name {
+                    $( stringify!($b) => world.get::<$b_ty>(entity).map(|c| Self::$b(Clone::clone(c))), )+
+                    $( stringify!($r) => world.get::<$r_ty>(entity).map(|c| Self::$r(Clone::clone(c))), )+
+                    _ => None,
+                }
+            }
+
+            /// The addable/removable kind of this component (`None` for
+            /// hidden and builtin components every entity carries).
+            pub fn kind(&self) -> Option<ComponentKind> {
+                match self {
+                    $( Self::$r(_) => Some(ComponentKind::$r), )+
+                    _ => None,
+                }
+            }
         }
 
         /// Capture all known component types from an entity into a `Vec<StoredComponent>`.
@@ -183,6 +261,19 @@ macro_rules! editor_component_registry {
             components
         }
 
+        /// Capture the inspectable (builtin + removable) components of an
+        /// entity, in registry order — the set play-mode diffs compare.
+        pub fn capture_inspectable_components(world: &World, entity: EntityId) -> Vec<StoredComponent> {
+            let mut components = Vec::new();
+            $( if let Some(c) = world.get::<$b_ty>(entity) {
+                components.push(StoredComponent::$b(Clone::clone(c)));
+            } )+
+            $( if let Some(c) = world.get::<$r_ty>(entity) {
+                components.push(StoredComponent::$r(Clone::clone(c)));
+            } )+
+            components
+        }
+
         /// The component kinds that can be added to / removed from entities.
         ///
         /// This is THE editor-wide `ComponentKind` — commands, the inspector,
@@ -206,6 +297,12 @@ macro_rules! editor_component_registry {
                 match self { $( Self::$r => ComponentCategory::$cat, )+ }
             }
 
+            /// Components this one needs to have any effect (registry
+            /// `requires [..]`), e.g. a Collider does nothing without a RigidBody.
+            pub fn requires(self) -> &'static [ComponentKind] {
+                match self { $( Self::$r => &[ $( $( ComponentKind::$dep, )+ )? ], )+ }
+            }
+
             /// Add a default instance of this component to an entity.
             pub fn add_default(self, world: &mut World, entity: EntityId) {
                 match self {
@@ -236,40 +333,45 @@ macro_rules! editor_component_registry {
             }
         }
 
-        /// Render the editable inspector for every present component
-        /// (builtin + removable, in registry order): field editors with
-        /// undo-recorded writeback via [`apply_component_edit`], remove [X]
-        /// buttons (removals executed as commands), and a serde read-only
-        /// display for components marked `readonly` in the registry.
+        /// Render the editable inspector for every component present on all
+        /// of `entities` (the primary selection first; builtin + removable,
+        /// in registry order): field editors with undo-recorded writeback
+        /// via [`apply_multi_edit`](crate::apply_multi_edit) (fields that differ across the entities
+        /// show "—"), remove [X] buttons (removals executed as commands),
+        /// and a serde read-only display for components marked `readonly`
+        /// in the registry.
         ///
         /// Returns `(next_y, component_count)` — the count feeds the
         /// add-component popup's widget-id offsets.
-        #[allow(clippy::too_many_arguments)]
         pub fn edit_all_components(
-            ui: &mut UIContext,
+            layout: ComponentEditorContext<'_>,
             world: &mut World,
-            entity: EntityId,
-            history: &mut CommandHistory,
-            x: f32,
-            mut y: f32,
-            inspect_style: &InspectorStyle,
-            field_style: &EditableFieldStyle,
-            section_gap: f32,
+            entities: &[EntityId],
             extras: &mut crate::InspectorExtras<'_>,
         ) -> (f32, usize) {
+            let ComponentEditorContext { ui, history, x, mut y, inspect_style, field_style, section_gap } = layout;
             let mut component_index: usize = 0;
             let mut removals: Vec<ComponentKind> = Vec::new();
 
             $( registry_edit_block!(@fixed $b, $b_ty, ($($b_edit)+),
-                ui, world, entity, history, x, y,
+                ui, world, entities, history, x, y,
                 inspect_style, field_style, section_gap, component_index, removals, extras); )+
             $( registry_edit_block!(@removable $r, $r_ty, ($($r_edit)+),
-                ui, world, entity, history, x, y,
+                ui, world, entities, history, x, y,
                 inspect_style, field_style, section_gap, component_index, removals, extras); )+
 
             for kind in &removals {
-                let cmd = RemoveComponentCommand::new(entity, *kind);
-                history.execute(Box::new(cmd), world);
+                let cmd: Box<dyn EditorCommand> = match entities {
+                    [entity] => Box::new(RemoveComponentCommand::new(*entity, *kind)),
+                    _ => Box::new(MacroCommand::new(
+                        format!("Remove {}", kind.display_name()),
+                        entities
+                            .iter()
+                            .map(|&entity| Box::new(RemoveComponentCommand::new(entity, *kind)) as Box<dyn EditorCommand>)
+                            .collect(),
+                    )),
+                };
+                history.execute(cmd, world);
                 log::info!("Removed component: {}", kind.display_name());
             }
 
@@ -305,6 +407,8 @@ editor_component_registry! {
         GlobalTransform2D => GlobalTransform2D,
         Name              => Name,
         BehaviorState     => BehaviorState,
+        Group             => Group,
+        SceneInstanceRef  => SceneInstanceRef,
     ],
     builtin: [
         Transform2D => common::Transform2D { edit edit_transform2d => SetTransformCommand },
@@ -312,10 +416,13 @@ editor_component_registry! {
     removable: [
         Camera          => common::Camera : Core { readonly },
         Sprite          => Sprite : Rendering { edit edit_sprite => SetSpriteCommand },
-        SpriteAnimation => SpriteAnimation : Rendering { readonly },
+        SpriteAnimation => SpriteAnimation : Rendering requires [Sprite] { edit edit_sprite_animation => SetSpriteAnimationCommand },
         Tilemap         => Tilemap : Rendering { readonly },
         RigidBody       => RigidBody : Physics { edit edit_rigid_body => SetRigidBodyCommand },
-        Collider        => Collider : Physics { edit edit_collider => SetColliderCommand },
+        Collider        => Collider : Physics requires [RigidBody] { edit edit_collider => SetColliderCommand },
+        AreaForce       => AreaForce : Physics { edit edit_area_force => SetAreaForceCommand },
+        Buoyancy        => Buoyancy : Physics { edit edit_buoyancy => SetBuoyancyCommand },
+        Drag            => Drag : Physics { edit edit_drag => SetDragCommand },
         AudioSource     => AudioSource : Audio { edit edit_audio_source => SetAudioSourceCommand },
         AudioListener   => AudioListener : Audio { readonly },
         Behavior        => Behavior : Gameplay { edit edit_behavior => SetBehaviorCommand },
@@ -340,6 +447,16 @@ pub fn available_components(world: &World, entity: EntityId) -> Vec<ComponentKin
         .collect()
 }
 
+/// Required components of `kind` that the entity is missing — what the
+/// add-component flow offers to add alongside it.
+pub fn missing_dependencies(world: &World, entity: EntityId, kind: ComponentKind) -> Vec<ComponentKind> {
+    kind.requires()
+        .iter()
+        .copied()
+        .filter(|dep| !dep.is_present(world, entity))
+        .collect()
+}
+
 /// Returns all component kinds grouped by category, in display order.
 /// Categories with no components are omitted.
 pub fn categorized_components() -> Vec<(ComponentCategory, Vec<ComponentKind>)> {
@@ -358,219 +475,4 @@ pub fn categorized_components() -> Vec<(ComponentCategory, Vec<ComponentKind>)>
 }
 
 #[cfg(test)]
-mod tests {
-    use super::*;
-    use glam::Vec2;
-
-    #[test]
-    fn test_edit_all_components_covers_present_components_and_advances_y() {
-        let mut world = World::new();
-        let entity = world.create_entity();
-        world
-            .add_component(&entity, common::Transform2D::new(Vec2::new(1.0, 2.0)))
-            .unwrap();
-        world.add_component(&entity, Sprite::new(0)).unwrap();
-        world.add_component(&entity, EntityTag::new("player")).unwrap();
-
-        let mut ui = UIContext::new();
-        let mut history = CommandHistory::new();
-        let inspect_style = InspectorStyle::default();
-        let field_style = EditableFieldStyle::default();
-
-        let start_y = 40.0;
-        let mut drag_drop = crate::DragDropState::new();
-        let mut extras = crate::InspectorExtras { drag_drop: &mut drag_drop, texture_display: None };
-        let (y, count) = edit_all_components(
-            &mut ui, &mut world, entity, &mut history,
-            10.0, start_y, &inspect_style, &field_style, 10.0, &mut extras,
-        );
-
-        assert_eq!(count, 3, "one block per present registry component");
-        assert!(y > start_y, "rendering must advance the layout cursor");
-        assert!(
-            !history.can_undo(),
-            "rendering without input must not record any edit"
-        );
-        // Registry order is builtin-then-removable, so absent components
-        // (RigidBody etc.) contribute nothing.
-        let bare = world.create_entity();
-        let (_, none_count) = edit_all_components(
-            &mut ui, &mut world, bare, &mut history,
-            10.0, start_y, &inspect_style, &field_style, 10.0, &mut extras,
-        );
-        assert_eq!(none_count, 0, "an entity with no components renders no blocks");
-    }
-
-    #[test]
-    fn test_capture_empty_entity() {
-        let mut world = World::new();
-        let entity = world.create_entity();
-        let captured = capture_all_components(&world, entity);
-        assert!(captured.is_empty());
-    }
-
-    #[test]
-    fn test_capture_and_restore_round_trip() {
-        let mut world = World::new();
-        let entity = world.create_entity();
-        let pos = Vec2::new(42.0, 99.0);
-        world.add_component(&entity, common::Transform2D::new(pos)).ok();
-        world.add_component(&entity, GlobalTransform2D::default()).ok();
-        world.add_component(&entity, Name::new("TestEntity")).ok();
-        world.add_component(&entity, Sprite::new(5)).ok();
-        world.add_component(&entity, RigidBody::default()).ok();
-
-        let captured = capture_all_components(&world, entity);
-        assert_eq!(captured.len(), 5);
-
-        // Create a fresh entity and restore onto it
-        let new_entity = world.create_entity();
-        restore_components(&mut world, new_entity, &captured);
-
-        let t = world.get::<common::Transform2D>(new_entity).unwrap();
-        assert_eq!(t.position, pos);
-        assert!(world.get::<Name>(new_entity).is_some());
-        assert!(world.get::<Sprite>(new_entity).is_some());
-        assert!(world.get::<RigidBody>(new_entity).is_some());
-        assert!(world.get::<GlobalTransform2D>(new_entity).is_some());
-    }
-
-    #[test]
-    fn test_capture_includes_all_component_types() {
-        let mut world = World::new();
-        let entity = world.create_entity();
-        world.add_component(&entity, common::Transform2D::default()).ok();
-        world.add_component(&entity, GlobalTransform2D::default()).ok();
-        world.add_component(&entity, Name::new("All")).ok();
-        world.add_component(&entity, common::Camera::default()).ok();
-        world.add_component(&entity, Sprite::default()).ok();
-        world.add_component(&entity, SpriteAnimation::default()).ok();
-        world.add_component(&entity, RigidBody::default()).ok();
-        world.add_component(&entity, Collider::default()).ok();
-        world.add_component(&entity, AudioSource::default()).ok();
-        world.add_component(&entity, AudioListener::default()).ok();
-        world.add_component(&entity, Behavior::default()).ok();
-        world.add_component(&entity, BehaviorState::default()).ok();
-        world.add_component(&entity, EntityTag::default()).ok();
-
-        let captured = capture_all_components(&world, entity);
-        assert_eq!(captured.len(), 13);
-    }
-
-    #[test]
-    fn test_gameplay_components_registered_under_gameplay_category() {
-        assert_eq!(ComponentKind::Behavior.category(), ComponentCategory::Gameplay);
-        assert_eq!(ComponentKind::EntityTag.category(), ComponentCategory::Gameplay);
-
-        let categories = categorized_components();
-        let (_, gameplay_kinds) = categories
-            .iter()
-            .find(|(c, _)| *c == ComponentCategory::Gameplay)
-            .expect("Gameplay category present");
-        assert!(gameplay_kinds.contains(&ComponentKind::Behavior));
-        assert!(gameplay_kinds.contains(&ComponentKind::EntityTag));
-    }
-
-    // ==================== ComponentKind dispatch ====================
-
-    #[test]
-    fn test_add_default_creates_each_component_kind() {
-        let mut world = World::new();
-        let entity = world.create_entity();
-
-        for &kind in ComponentKind::ALL {
-            kind.add_default(&mut world, entity);
-            assert!(
-                kind.is_present(&world, entity),
-                "add_default did not add {:?}",
-                kind
-            );
-        }
-    }
-
-    #[test]
-    fn test_remove_deletes_each_component_kind() {
-        let mut world = World::new();
-        let entity = world.create_entity();
-
-        for &kind in ComponentKind::ALL {
-            kind.add_default(&mut world, entity);
-            kind.remove(&mut world, entity);
-            assert!(
-                !kind.is_present(&world, entity),
-                "remove did not delete {:?}",
-                kind
-            );
-        }
-    }
-
-    #[test]
-    fn test_remove_absent_component_is_safe() {
-        let mut world = World::new();
-        let entity = world.create_entity();
-        // Should not panic
-        ComponentKind::Sprite.remove(&mut world, entity);
-        assert!(!ComponentKind::Sprite.is_present(&world, entity));
-    }
-
-    #[test]
-    fn test_capture_returns_value_when_present() {
-        let mut world = World::new();
-        let entity = world.create_entity();
-        world.add_component(&entity, Sprite::new(7)).ok();
-
-        let stored = ComponentKind::Sprite.capture(&world, entity);
-        assert!(matches!(stored, Some(StoredComponent::Sprite(s)) if s.texture_handle == 7));
-        assert!(ComponentKind::Camera.capture(&world, entity).is_none());
-    }
-
-    #[test]
-    fn test_display_names_match_variant_names() {
-        assert_eq!(ComponentKind::Camera.display_name(), "Camera");
-        assert_eq!(ComponentKind::SpriteAnimation.display_name(), "SpriteAnimation");
-        for &kind in ComponentKind::ALL {
-            assert!(!kind.display_name().is_empty());
-        }
-    }
-
-    #[test]
-    fn test_available_components_filters_present() {
-        let mut world = World::new();
-        let entity = world.create_entity();
-        world.add_component(&entity, Sprite::default()).ok();
-        world.add_component(&entity, RigidBody::default()).ok();
-
-        let available = available_components(&world, entity);
-        assert!(!available.contains(&ComponentKind::Sprite));
-        assert!(!available.contains(&ComponentKind::RigidBody));
-        assert!(available.contains(&ComponentKind::Camera));
-        assert!(available.contains(&ComponentKind::Collider));
-        assert!(available.contains(&ComponentKind::AudioSource));
-    }
-
-    #[test]
-    fn test_categorized_components_covers_all_kinds() {
-        let categories = categorized_components();
-        let all: Vec<ComponentKind> = categories
-            .iter()
-            .flat_map(|(_, kinds)| kinds.iter().copied())
-            .collect();
-        assert_eq!(all.len(), ComponentKind::ALL.len());
-        for &kind in ComponentKind::ALL {
-            assert!(all.contains(&kind), "{:?} missing from categories", kind);
-        }
-    }
-
-    #[test]
-    fn test_every_kind_has_consistent_category() {
-        for &kind in ComponentKind::ALL {
-            let category = kind.category();
-            let categories = categorized_components();
-            let (_, kinds) = categories
-                .iter()
-                .find(|(c, _)| *c == category)
-                .expect("category present");
-            assert!(kinds.contains(&kind));
-        }
-    }
-}
+mod tests;
@@ -0,0 +1,240 @@
+use super::*;
+use glam::Vec2;
+
+fn layout<'a>(
+    ui: &'a mut UIContext,
+    history: &'a mut CommandHistory,
+    inspect_style: &'a InspectorStyle,
+    field_style: &'a EditableFieldStyle,
+) -> ComponentEditorContext<'a> {
+    ComponentEditorContext { ui, history, x: 10.0, y: 40.0, inspect_style, field_style, section_gap: 10.0 }
+}
+
+#[test]
+fn test_edit_all_components_covers_present_components_and_advances_y() {
+    let mut world = World::new();
+    let entity = world.create_entity();
+    world
+        .add_component(&entity, common::Transform2D::new(Vec2::new(1.0, 2.0)))
+        .unwrap();
+    world.add_component(&entity, Sprite::new(0)).unwrap();
+    world.add_component(&entity, EntityTag::new("player")).unwrap();
+
+    let mut ui = UIContext::new();
+    let mut history = CommandHistory::new();
+    let inspect_style = InspectorStyle::default();
+    let field_style = EditableFieldStyle::default();
+
+    let start_y = 40.0;
+    let mut drag_drop = crate::DragDropState::new();
+    let mut extras = crate::InspectorExtras {
+        drag_drop: &mut drag_drop,
+        texture_display: None,
+        texture_size: glam::Vec2::ZERO,
+        pick_texture: false,
+        region_names: &[],
+        pick_region: false,
+        materials: &[],
+        layers: &[],
+        animation_preview: false,
+        toggle_animation_preview: false,
+        clipboard_type: None,
+        component_menu: None,
+    };
+    let cx = layout(&mut ui, &mut history, &inspect_style, &field_style);
+    let (y, count) = edit_all_components(cx, &mut world, &[entity], &mut extras);
+
+    assert_eq!(count, 3, "one block per present registry component");
+    assert!(y > start_y, "rendering must advance the layout cursor");
+    assert!(
+        !history.can_undo(),
+        "rendering without input must not record any edit"
+    );
+    // Registry order is builtin-then-removable, so absent components
+    // (RigidBody etc.) contribute nothing.
+    let bare = world.create_entity();
+    let cx = layout(&mut ui, &mut history, &inspect_style, &field_style);
+    let (_, none_count) = edit_all_components(cx, &mut world, &[bare], &mut extras);
+    assert_eq!(none_count, 0, "an entity with no components renders no blocks");
+
+    // A multi-selection shows only the components every entity has.
+    world.add_component(&bare, common::Transform2D::default()).unwrap();
+    world.add_component(&bare, Sprite::new(1)).unwrap();
+    let cx = layout(&mut ui, &mut history, &inspect_style, &field_style);
+    let (_, shared_count) = edit_all_components(cx, &mut world, &[entity, bare], &mut extras);
+    assert_eq!(shared_count, 2, "Transform2D and Sprite, not the EntityTag");
+    assert!(!history.can_undo());
+}
+
+#[test]
+fn test_capture_empty_entity() {
+    let mut world = World::new();
+    let entity = world.create_entity();
+    let captured = capture_all_components(&world, entity);
+    assert!(captured.is_empty());
+}
+
+#[test]
+fn test_capture_and_restore_round_trip() {
+    let mut world = World::new();
+    let entity = world.create_entity();
+    let pos = Vec2::new(42.0, 99.0);
+    world.add_component(&entity, common::Transform2D::new(pos)).ok();
+    world.add_component(&entity, GlobalTransform2D::default()).ok();
+    world.add_component(&entity, Name::new("TestEntity")).ok();
+    world.add_component(&entity, Sprite::new(5)).ok();
+    world.add_component(&entity, RigidBody::default()).ok();
+
+    let captured = capture_all_components(&world, entity);
+    assert_eq!(captured.len(), 5);
+
+    // Create a fresh entity and restore onto it
+    let new_entity = world.create_entity();
+    restore_components(&mut world, new_entity, &captured);
+
+    let t = world.get::<common::Transform2D>(new_entity).unwrap();
+    assert_eq!(t.position, pos);
+    assert!(world.get::<Name>(new_entity).is_some());
+    assert!(world.get::<Sprite>(new_entity).is_some());
+    assert!(world.get::<RigidBody>(new_entity).is_some());
+    assert!(world.get::<GlobalTransform2D>(new_entity).is_some());
+}
+
+#[test]
+fn test_capture_includes_all_component_types() {
+    let mut world = World::new();
+    let entity = world.create_entity();
+    world.add_component(&entity, common::Transform2D::default()).ok();
+    world.add_component(&entity, GlobalTransform2D::default()).ok();
+    world.add_component(&entity, Name::new("All")).ok();
+    world.add_component(&entity, common::Camera::default()).ok();
+    world.add_component(&entity, Sprite::default()).ok();
+    world.add_component(&entity, SpriteAnimation::default()).ok();
+    world.add_component(&entity, RigidBody::default()).ok();
+    world.add_component(&entity, Collider::default()).ok();
+    world.add_component(&entity, AudioSource::default()).ok();
+    world.add_component(&entity, AudioListener::default()).ok();
+    world.add_component(&entity, Behavior::default()).ok();
+    world.add_component(&entity, BehaviorState::default()).ok();
+    world.add_component(&entity, EntityTag::default()).ok();
+
+    let captured = capture_all_components(&world, entity);
+    assert_eq!(captured.len(), 13);
+}
+
+#[test]
+fn test_gameplay_components_registered_under_gameplay_category() {
+    assert_eq!(ComponentKind::Behavior.category(), ComponentCategory::Gameplay);
+    assert_eq!(ComponentKind::EntityTag.category(), ComponentCategory::Gameplay);
+
+    let categories = categorized_components();
+    let (_, gameplay_kinds) = categories
+        .iter()
+        .find(|(c, _)| *c == ComponentCategory::Gameplay)
+        .expect("Gameplay category present");
+    assert!(gameplay_kinds.contains(&ComponentKind::Behavior));
+    assert!(gameplay_kinds.contains(&ComponentKind::EntityTag));
+}
+
+// ==================== ComponentKind dispatch ====================
+
+#[test]
+fn test_add_default_creates_each_component_kind() {
+    let mut world = World::new();
+    let entity = world.create_entity();
+
+    for &kind in ComponentKind::ALL {
+        kind.add_default(&mut world, entity);
+        assert!(
+            kind.is_present(&world, entity),
+            "add_default did not add {:?}",
+            kind
+        );
+    }
+}
+
+#[test]
+fn test_remove_deletes_each_component_kind() {
+    let mut world = World::new();
+    let entity = world.create_entity();
+
+    for &kind in ComponentKind::ALL {
+        kind.add_default(&mut world, entity);
+        kind.remove(&mut world, entity);
+        assert!(
+            !kind.is_present(&world, entity),
+            "remove did not delete {:?}",
+            kind
+        );
+    }
+}
+
+#[test]
+fn test_remove_absent_component_is_safe() {
+    let mut world = World::new();
+    let entity = world.create_entity();
+    // Should not panic
+    ComponentKind::Sprite.remove(&mut world, entity);
+    assert!(!ComponentKind::Sprite.is_present(&world, entity));
+}
+
+#[test]
+fn test_capture_returns_value_when_present() {
+    let mut world = World::new();
+    let entity = world.create_entity();
+    world.add_component(&entity, Sprite::new(7)).ok();
+
+    let stored = ComponentKind::Sprite.capture(&world, entity);
+    assert!(matches!(stored, Some(StoredComponent::Sprite(s)) if s.texture_handle == 7));
+    assert!(ComponentKind::Camera.capture(&world, entity).is_none());
+}
+
+#[test]
+fn test_display_names_match_variant_names() {
+    assert_eq!(ComponentKind::Camera.display_name(), "Camera");
+    assert_eq!(ComponentKind::SpriteAnimation.display_name(), "SpriteAnimation");
+    for &kind in ComponentKind::ALL {
+        assert!(!kind.display_name().is_empty());
+    }
+}
+
+#[test]
+fn test_available_components_filters_present() {
+    let mut world = World::new();
+    let entity = world.create_entity();
+    world.add_component(&entity, Sprite::default()).ok();
+    world.add_component(&entity, RigidBody::default()).ok();
+
+    let available = available_components(&world, entity);
+    assert!(!available.contains(&ComponentKind::Sprite));
+    assert!(!available.contains(&ComponentKind::RigidBody));
+    assert!(available.contains(&ComponentKind::Camera));
+    assert!(available.contains(&ComponentKind::Collider));
+    assert!(available.contains(&ComponentKind::AudioSource));
+}
+
+#[test]
+fn test_categorized_components_covers_all_kinds() {
+    let categories = categorized_components();
+    let all: Vec<ComponentKind> = categories
+        .iter()
+        .flat_map(|(_, kinds)| kinds.iter().copied())
+        .collect();
+    assert_eq!(all.len(), ComponentKind::ALL.len());
+    for &kind in ComponentKind::ALL {
+        assert!(all.contains(&kind), "{:?} missing from categories", kind);
+    }
+}
+
+#[test]
+fn test_every_kind_has_consistent_category() {
+    for &kind in ComponentKind::ALL {
+        let category = kind.category();
+        let categories = categorized_components();
+        let (_, kinds) = categories
+            .iter()
+            .find(|(c, _)| *c == category)
+            .expect("category present");
+        assert!(kinds.contains(&kind));
+    }
+}
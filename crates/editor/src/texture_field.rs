@@ -1,5 +1,6 @@
-//! Inspector texture field: shows the sprite's texture by name and accepts
-//! drag-and-drop assignment from the asset browser.
+//! Inspector texture field: shows the sprite's texture by name with a
+//! thumbnail, accepts drag-and-drop assignment from the asset browser, and
+//! opens the [`TexturePicker`](crate::TexturePicker) from its "Pick" button.
 
 use glam::Vec2;
 use ui::UIContext;
@@ -18,11 +19,41 @@ pub struct InspectorExtras<'a> {
     /// Display path for the selected entity's sprite texture, if resolvable
     /// (e.g. `"player.png"` or `"#white"`).
     pub texture_display: Option<String>,
+    /// Pixel size of the selected sprite's texture for the slot thumbnail
+    /// (zero when unknown: no thumbnail).
+    pub texture_size: Vec2,
+    /// Set when the sprite's Texture "Pick" button is clicked; the
+    /// integration layer opens the texture picker in response.
+    pub pick_texture: bool,
+    /// Atlas regions of the selected sprite's texture as `(name, region)`;
+    /// Region and SpriteAnimation frame rows show matching regions by name.
+    pub region_names: &'a [(String, [f32; 4])],
+    /// Set when the sprite's Region "Pick" button is clicked; the
+    /// integration layer opens the sprite-sheet picker in response.
+    pub pick_region: bool,
+    /// Physics materials the Collider "Material" dropdown offers (presets
+    /// plus the scene's own).
+    pub materials: &'a [physics::PhysicsMaterial],
+    /// Render layer names the Sprite "Layer" dropdown offers, back to front.
+    pub layers: &'a [String],
+    /// Whether the selected entity's animation is being previewed (drives
+    /// the SpriteAnimation Play/Pause label).
+    pub animation_preview: bool,
+    /// Set when the SpriteAnimation Play/Pause button is clicked; the
+    /// integration layer toggles the preview in response.
+    pub toggle_animation_preview: bool,
+    /// Registry name of the component on the clipboard, if any (offers
+    /// "Paste Component Values" on matching component headers).
+    pub clipboard_type: Option<&'a str>,
+    /// Set when a component header's right-click menu item is picked; the
+    /// integration layer copies or pastes in response.
+    pub component_menu: Option<crate::ComponentMenuAction>,
 }
 
-/// Render a texture slot: label + a boxed value showing the texture's path
-/// (falling back to the raw handle), acting as a drop target for
-/// [`DragPayload::Texture`]. Returns `Changed(handle)` when a drop lands.
+/// Render a texture slot: label + a boxed value showing a thumbnail (when
+/// `texture_size` is known) and the texture's path (falling back to the raw
+/// handle), acting as a drop target for [`DragPayload::Texture`]. Returns
+/// `Changed(handle)` when a drop lands.
 #[allow(clippy::too_many_arguments)]
 pub fn edit_texture_field(
     ui: &mut UIContext,
@@ -31,6 +62,7 @@ pub fn edit_texture_field(
     handle: u32,
     drag_drop: &mut DragDropState,
     display: Option<&str>,
+    texture_size: Vec2,
     pos: Vec2,
     style: &EditableFieldStyle,
 ) -> EditResult<u32> {
@@ -56,11 +88,21 @@ pub fn edit_texture_field(
         ui.rect_border(slot_bounds, style.drop_highlight, 2.0, 2.0);
     }
 
+    let mut text_bounds = slot_bounds;
+    if texture_size.x > 0.0 && texture_size.y > 0.0 {
+        let edge = slot_bounds.height - 4.0;
+        let thumb_slot = common::Rect::new(slot_bounds.x + 2.0, slot_bounds.y + 2.0, edge, edge);
+        let thumb = crate::fit_rect(texture_size.x as u32, texture_size.y as u32, thumb_slot);
+        ui.image(ui::Rect::new(thumb.x, thumb.y, thumb.width, thumb.height), handle, ui::Color::WHITE);
+        text_bounds.x += edge + 2.0;
+        text_bounds.width -= edge + 2.0;
+    }
+
     let fallback = if handle == 0 { "#white".to_string() } else { format!("handle {handle}") };
     let text = display.unwrap_or(&fallback);
     ui.label_in_bounds_styled(
         text,
-        slot_bounds,
+        text_bounds,
         ui::TextAlign::Left,
         style.value_color,
         style.label_font,
@@ -83,6 +125,18 @@ pub fn edit_texture_field(
     EditResult::Unchanged
 }
 
+/// The "Pick" button right of a texture slot drawn at `pos`. Returns `true`
+/// when clicked.
+pub fn texture_pick_button(ui: &mut UIContext, id: FieldId, pos: Vec2, style: &EditableFieldStyle) -> bool {
+    let bounds = ui::Rect::new(
+        pos.x + style.label_width + style.input_width + 44.0,
+        pos.y + 2.0,
+        40.0,
+        style.row_height - 4.0,
+    );
+    ui.button(id, "Pick", bounds)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -110,7 +164,7 @@ mod tests {
 
         let result = edit_texture_field(
             &mut ui, FieldId::new(0, 0, 0), "Texture", 1,
-            &mut drag_drop, Some("old.png"), pos, &style,
+            &mut drag_drop, Some("old.png"), Vec2::ZERO, pos, &style,
         );
         assert_eq!(result, EditResult::Changed(7));
     }
@@ -126,7 +180,7 @@ mod tests {
 
         let result = edit_texture_field(
             &mut ui, FieldId::new(0, 0, 0), "Texture", 1,
-            &mut drag_drop, None, Vec2::new(10.0, 10.0), &style,
+            &mut drag_drop, None, Vec2::ZERO, Vec2::new(10.0, 10.0), &style,
         );
         assert_eq!(result, EditResult::Unchanged);
     }
@@ -143,7 +197,7 @@ mod tests {
 
         let result = edit_texture_field(
             &mut ui, FieldId::new(0, 0, 0), "Texture", 1,
-            &mut drag_drop, None, Vec2::new(10.0, 10.0), &style,
+            &mut drag_drop, None, Vec2::ZERO, Vec2::new(10.0, 10.0), &style,
         );
         assert_eq!(result, EditResult::Unchanged, "no-op drop must not dirty the scene");
     }
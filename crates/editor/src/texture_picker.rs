@@ -0,0 +1,260 @@
+//! Texture picker: a popup listing every texture a sprite can use — the
+//! ones already loaded plus the images in the asset folder — opened from
+//! the Sprite inspector's texture "Pick" button.
+//!
+//! The editor crate cannot see the engine's `AssetManager`, so the
+//! integration layer gathers the entries ([`texture_picker_entries`]) and
+//! loads a folder image when it is picked.
+
+use glam::Vec2;
+use ui::{Color, Rect, UIContext};
+
+use crate::asset_browser::{fit_rect, AssetEntry, AssetKind};
+use crate::theme::EditorTheme;
+
+/// Size of the picker popup.
+pub const TEXTURE_PICKER_SIZE: Vec2 = Vec2::new(360.0, 440.0);
+
+/// Popup padding and row heights.
+const PAD: f32 = 8.0;
+const ROW_HEIGHT: f32 = 22.0;
+/// List row height; the thumbnail is this tall minus padding.
+const ENTRY_HEIGHT: f32 = 36.0;
+
+/// One texture the picker offers.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TexturePickerEntry {
+    /// Display path (`"#white"` for the built-in white texture)
+    pub path: String,
+    /// Loaded texture, or `None` for a folder image not loaded yet
+    pub handle: Option<u32>,
+    /// Pixel size when loaded (zero when unknown)
+    pub size: Vec2,
+}
+
+/// Merge loaded textures `(handle, path, size)` with the asset folder's
+/// images: the white texture first, then by path, each path once (a loaded
+/// texture wins, so picking it never loads a duplicate).
+pub fn texture_picker_entries(
+    loaded: impl IntoIterator<Item = (u32, String, Vec2)>,
+    folder: &[AssetEntry],
+) -> Vec<TexturePickerEntry> {
+    let mut entries: Vec<TexturePickerEntry> = loaded
+        .into_iter()
+        .map(|(handle, path, size)| TexturePickerEntry { path, handle: Some(handle), size })
+        .collect();
+    for asset in folder.iter().filter(|asset| asset.kind == AssetKind::Image) {
+        if !entries.iter().any(|entry| entry.path == asset.relative_path) {
+            entries.push(TexturePickerEntry {
+                path: asset.relative_path.clone(),
+                handle: asset.texture_handle,
+                size: Vec2::ZERO,
+            });
+        }
+    }
+    if !entries.iter().any(|entry| entry.handle == Some(0)) {
+        entries.push(TexturePickerEntry { path: "#white".to_string(), handle: Some(0), size: Vec2::ONE });
+    }
+    entries.sort_by(|a, b| (a.handle != Some(0)).cmp(&(b.handle != Some(0))).then_with(|| a.path.cmp(&b.path)));
+    entries
+}
+
+/// Result of a picker frame.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TexturePickerAction {
+    /// Assign `entry`'s texture to every sprite in `entities`
+    Pick { entities: Vec<ecs::EntityId>, entry: TexturePickerEntry },
+    /// Close without changes
+    Cancel,
+}
+
+/// Popup state for picking a sprite's texture.
+#[derive(Debug, Clone, Default)]
+pub struct TexturePicker {
+    /// Sprites the pick applies to; empty while closed
+    entities: Vec<ecs::EntityId>,
+    /// Texture the primary sprite uses now (highlighted)
+    current: u32,
+    entries: Vec<TexturePickerEntry>,
+    /// Case-insensitive path filter
+    filter: String,
+    scroll_offset: f32,
+}
+
+impl TexturePicker {
+    /// A closed picker.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Open for the sprites of `entities` (primary first, which uses the
+    /// `current` texture), offering `entries`. The filter is kept between
+    /// openings.
+    pub fn open(&mut self, entities: Vec<ecs::EntityId>, current: u32, entries: Vec<TexturePickerEntry>) {
+        self.entities = entities;
+        self.current = current;
+        self.entries = entries;
+        self.scroll_offset = 0.0;
+    }
+
+    /// Close without picking.
+    pub fn close(&mut self) {
+        self.entities.clear();
+    }
+
+    /// Whether the picker is open.
+    pub fn is_open(&self) -> bool {
+        !self.entities.is_empty()
+    }
+
+    /// Sprites the pick applies to (empty while closed).
+    pub fn entities(&self) -> &[ecs::EntityId] {
+        &self.entities
+    }
+
+    /// Set the path filter.
+    pub fn set_filter(&mut self, filter: impl Into<String>) {
+        self.filter = filter.into();
+        self.scroll_offset = 0.0;
+    }
+
+    /// Entries whose path contains the filter (case-insensitive).
+    pub fn visible_entries(&self) -> Vec<&TexturePickerEntry> {
+        let filter = self.filter.to_lowercase();
+        self.entries.iter().filter(|entry| entry.path.to_lowercase().contains(&filter)).collect()
+    }
+
+    /// Draw the picker inside `bounds` (see [`TEXTURE_PICKER_SIZE`]) as an
+    /// input-blocking overlay. Returns the user's decision, if any; the
+    /// picker closes itself on either action.
+    pub fn render(&mut self, ui: &mut UIContext, bounds: Rect, theme: &EditorTheme) -> Option<TexturePickerAction> {
+        if !self.is_open() {
+            return None;
+        }
+        ui.begin_overlay(bounds);
+        ui.panel_styled(bounds, theme.bg_primary, theme.border_panel, 1.0);
+        ui.label_styled("Texture", Vec2::new(bounds.x + PAD, bounds.y + PAD), theme.text_primary, theme.fonts.body);
+
+        let filter_y = bounds.y + PAD + ROW_HEIGHT + 2.0;
+        ui.label_styled("Filter", Vec2::new(bounds.x + PAD, filter_y + 4.0), theme.text_secondary, theme.fonts.small);
+        let filter_bounds = Rect::new(bounds.x + PAD + 44.0, filter_y, bounds.width - 2.0 * PAD - 44.0, ROW_HEIGHT);
+        if let Some(filter) = ui.text_input("texture_picker_filter", &self.filter, filter_bounds) {
+            self.set_filter(filter);
+        }
+
+        let footer_y = bounds.y + bounds.height - PAD - ROW_HEIGHT;
+        let list_top = filter_y + ROW_HEIGHT + PAD;
+        let list = Rect::new(bounds.x + PAD, list_top, bounds.width - 2.0 * PAD, (footer_y - PAD - list_top).max(0.0));
+        let picked = self.render_list(ui, list, theme);
+
+        let cancel = ui.button("texture_picker_cancel", "Cancel", Rect::new(bounds.x + PAD, footer_y, 70.0, ROW_HEIGHT));
+        ui.end_overlay();
+
+        if let Some(entry) = picked {
+            let entities = std::mem::take(&mut self.entities);
+            Some(TexturePickerAction::Pick { entities, entry })
+        } else if cancel {
+            self.close();
+            Some(TexturePickerAction::Cancel)
+        } else {
+            None
+        }
+    }
+
+    /// Scrolling list of thumbnail + path rows; returns the clicked entry.
+    fn render_list(&mut self, ui: &mut UIContext, list: Rect, theme: &EditorTheme) -> Option<TexturePickerEntry> {
+        ui.rect(list, theme.bg_input);
+        let visible: Vec<TexturePickerEntry> = self.visible_entries().into_iter().cloned().collect();
+        let max_scroll = (visible.len() as f32 * ENTRY_HEIGHT - list.height).max(0.0);
+        if list.contains(ui.mouse_pos()) {
+            self.scroll_offset = (self.scroll_offset - ui.scroll_delta() * 30.0).clamp(0.0, max_scroll);
+        }
+        if visible.is_empty() {
+            ui.label_styled("(no textures)", Vec2::new(list.x + PAD, list.y + PAD), theme.text_muted, theme.fonts.small);
+            return None;
+        }
+
+        let mut picked = None;
+        ui.push_clip_rect(list);
+        for (index, entry) in visible.into_iter().enumerate() {
+            let row = Rect::new(list.x, list.y + index as f32 * ENTRY_HEIGHT - self.scroll_offset, list.width, ENTRY_HEIGHT);
+            if row.y + row.height < list.y || row.y > list.y + list.height {
+                continue;
+            }
+            let result = ui.interact(ui::WidgetId::from_str_index("texture_picker_entry", index), row, true);
+            if entry.handle == Some(self.current) {
+                ui.rect(row, theme.selection_fill);
+            } else if result.state == ui::WidgetState::Hovered {
+                ui.rect(row, theme.hover_fill);
+            }
+
+            let slot = common::Rect::new(row.x + 4.0, row.y + 4.0, ENTRY_HEIGHT - 8.0, ENTRY_HEIGHT - 8.0);
+            match entry.handle {
+                Some(handle) => {
+                    let size = if entry.size.x > 0.0 && entry.size.y > 0.0 { entry.size } else { Vec2::ONE };
+                    let image = fit_rect(size.x as u32, size.y as u32, slot);
+                    ui.image(Rect::new(image.x, image.y, image.width, image.height), handle, Color::WHITE);
+                }
+                None => ui.rect_border(Rect::new(slot.x, slot.y, slot.width, slot.height), theme.border_subtle, 1.0, 2.0),
+            }
+            let text_bounds = Rect::new(row.x + ENTRY_HEIGHT + 2.0, row.y, row.width - ENTRY_HEIGHT - 2.0, row.height);
+            let color = if entry.handle.is_some() { theme.text_primary } else { theme.text_secondary };
+            ui.label_in_bounds_styled(&entry.path, text_bounds, ui::TextAlign::Left, color, theme.fonts.small, 0.0);
+
+            if result.clicked {
+                picked = Some(entry);
+            }
+        }
+        ui.pop_clip_rect();
+        picked
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn folder_image(path: &str, handle: Option<u32>) -> AssetEntry {
+        AssetEntry {
+            name: path.rsplit('/').next().unwrap_or(path).to_string(),
+            relative_path: path.to_string(),
+            kind: AssetKind::Image,
+            texture_handle: handle,
+            load_failed: false,
+        }
+    }
+
+    #[test]
+    fn test_entries_merge_loaded_and_folder_textures_once_each() {
+        let loaded = vec![(4, "player.png".to_string(), Vec2::new(32.0, 32.0))];
+        let scene = AssetEntry { kind: AssetKind::Scene, ..folder_image("level.scene.ron", None) };
+        let folder = vec![folder_image("player.png", None), folder_image("enemies/bat.png", None), scene];
+
+        let entries = texture_picker_entries(loaded, &folder);
+        let paths: Vec<&str> = entries.iter().map(|e| e.path.as_str()).collect();
+        assert_eq!(paths, ["#white", "enemies/bat.png", "player.png"], "white first, scenes skipped");
+        assert_eq!(entries[2].handle, Some(4), "the loaded texture wins over the folder entry");
+        assert_eq!(entries[1].handle, None, "folder images load when picked");
+    }
+
+    #[test]
+    fn test_filter_open_and_close() {
+        let mut picker = TexturePicker::new();
+        assert!(!picker.is_open());
+        let entries = texture_picker_entries([], &[folder_image("Tiles/Grass.png", None), folder_image("ship.png", Some(2))]);
+
+        let entity = ecs::EntityId::new();
+        picker.open(vec![entity], 0, entries);
+        assert_eq!(picker.entities(), [entity]);
+        assert_eq!(picker.visible_entries().len(), 3);
+        picker.set_filter("grass");
+        let visible: Vec<&str> = picker.visible_entries().iter().map(|e| e.path.as_str()).collect();
+        assert_eq!(visible, ["Tiles/Grass.png"]);
+
+        picker.close();
+        assert!(!picker.is_open());
+        let mut ui = UIContext::new();
+        let bounds = Rect::new(0.0, 0.0, TEXTURE_PICKER_SIZE.x, TEXTURE_PICKER_SIZE.y);
+        assert_eq!(picker.render(&mut ui, bounds, &EditorTheme::default()), None);
+    }
+}
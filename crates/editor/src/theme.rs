@@ -16,7 +16,7 @@
 //!
 //! // ...and use the converter methods for subsystem style bundles.
 //! let inspector_style = theme.inspector_style();
-//! let grid_colors = theme.grid_colors();
+//! let grid_colors = theme.colors.grid_colors();
 //! # let _ = (header_color, panel_bg, inspector_style, grid_colors);
 //! ```
 
@@ -26,8 +26,8 @@ use ui::Color;
 /// Centralized design-system theme for the entire editor.
 ///
 /// Every color used in panels, toolbars, inspector, hierarchy, status bar,
-/// gizmos, and grid should reference a field on this struct — never a
-/// hardcoded literal.
+/// and scene-view overlays (via [`colors`](Self::colors)) should reference a
+/// field on this struct — never a hardcoded literal.
 #[derive(Debug, Clone)]
 pub struct EditorTheme {
     // ── Backgrounds ─────────────────────────────────────────────
@@ -60,30 +60,6 @@ pub struct EditorTheme {
     /// Disabled text, placeholders (`#888888`)
     pub text_muted: Color,
 
-    // ── Gizmos ──────────────────────────────────────────────────
-    /// X-axis gizmo color (green, horizontal) (`#00ff00`)
-    pub gizmo_x: Color,
-    /// Y-axis gizmo color (red, vertical) (`#ff0000`)
-    pub gizmo_y: Color,
-    /// Center/free-move gizmo handle
-    pub gizmo_center: Color,
-    /// X-axis handle while hovered/dragged
-    pub gizmo_x_hover: Color,
-    /// Y-axis handle while hovered/dragged
-    pub gizmo_y_hover: Color,
-    /// Center handle while hovered/dragged
-    pub gizmo_center_hover: Color,
-    /// Rotation ring
-    pub gizmo_ring: Color,
-    /// Current-rotation indicator line
-    pub gizmo_rotation_indicator: Color,
-    /// Scale gizmo box outline
-    pub gizmo_scale_outline: Color,
-    /// Scale gizmo corner handles
-    pub gizmo_scale_handle: Color,
-    /// Scale gizmo corner handles while hovered/dragged
-    pub gizmo_scale_handle_hover: Color,
-
     // ── Selection / rows ────────────────────────────────────────
     /// Selected row background (hierarchy, lists)
     pub selection_fill: Color,
@@ -132,16 +108,6 @@ pub struct EditorTheme {
     /// Thin separator lines between toolbar sections
     pub separator: Color,
 
-    // ── Grid (Vec4 for renderer sprite pipeline) ────────────────
-    /// Primary grid line color
-    pub grid_primary: Vec4,
-    /// Secondary (subdivision) grid line color
-    pub grid_secondary: Vec4,
-    /// Grid X-axis color (red)
-    pub grid_axis_x: Vec4,
-    /// Grid Y-axis color (green)
-    pub grid_axis_y: Vec4,
-
     // ── Status bar ──────────────────────────────────────────────
     /// Status bar background (slightly darker than panels)
     pub status_bar_bg: Color,
@@ -154,21 +120,14 @@ pub struct EditorTheme {
     /// Inspector section header color
     pub inspector_header: Color,
 
-    // ── Play-state viewport borders ─────────────────────────────
-    /// Viewport border tint while editing
-    pub border_editing: Color,
-    /// Viewport border tint while playing
-    pub border_playing: Color,
-    /// Viewport border tint while paused
-    pub border_paused: Color,
-
-    // ── Collider overlay ────────────────────────────────────────
-    /// Solid (non-sensor) collider outlines in the scene view
-    pub collider_outline: Color,
-    /// Sensor (trigger-only) collider outlines
-    pub collider_sensor: Color,
-    /// Collider outline on selected entities
-    pub collider_selected: Color,
+    // ── Profiler ────────────────────────────────────────────────
+    /// Series colors of the Profiler graph, cycled per pass
+    pub profiler_series: [Color; 6],
+
+    // ── Scene-view overlays ─────────────────────────────────────
+    /// Gizmo, grid, selection/collider outline and play-state border
+    /// colors — a switchable, serializable scheme (incl. color-blind safe)
+    pub colors: crate::EditorColorScheme,
 
     // ── Typography ──────────────────────────────────────────────
     /// Font-size tokens — all editor text sizes come from here
@@ -200,19 +159,6 @@ impl Default for EditorTheme {
             text_secondary: Color::from_hex(0xcccccc),
             text_muted: Color::from_hex(0x888888),
 
-            // Gizmos
-            gizmo_x: Color::new(0.0, 1.0, 0.0, 1.0),
-            gizmo_y: Color::new(1.0, 0.0, 0.0, 1.0),
-            gizmo_center: Color::new(0.9, 0.9, 0.2, 1.0),
-            gizmo_x_hover: Color::new(1.0, 0.4, 0.4, 1.0),
-            gizmo_y_hover: Color::new(0.4, 1.0, 0.4, 1.0),
-            gizmo_center_hover: Color::new(1.0, 1.0, 0.4, 1.0),
-            gizmo_ring: Color::new(0.3, 0.3, 0.9, 1.0),
-            gizmo_rotation_indicator: Color::new(0.9, 0.9, 0.9, 1.0),
-            gizmo_scale_outline: Color::new(0.6, 0.6, 0.6, 1.0),
-            gizmo_scale_handle: Color::new(0.7, 0.7, 0.7, 1.0),
-            gizmo_scale_handle_hover: Color::new(0.9, 0.9, 0.4, 1.0),
-
             // Selection / rows
             selection_fill: Color::new(0.3, 0.5, 0.8, 0.5),
             hover_fill: Color::new(0.5, 0.5, 0.5, 0.2),
@@ -249,12 +195,6 @@ impl Default for EditorTheme {
             // Separator
             separator: Color::new(0.4, 0.4, 0.4, 0.6),
 
-            // Grid (Vec4 for sprite renderer)
-            grid_primary: Vec4::new(0.3, 0.3, 0.3, 0.5),
-            grid_secondary: Vec4::new(0.25, 0.25, 0.25, 0.3),
-            grid_axis_x: Vec4::new(0.8, 0.2, 0.2, 0.8),
-            grid_axis_y: Vec4::new(0.2, 0.8, 0.2, 0.8),
-
             // Status bar
             status_bar_bg: Color::new(0.10, 0.10, 0.10, 1.0),
 
@@ -263,15 +203,18 @@ impl Default for EditorTheme {
             inspector_value: Color::WHITE,
             inspector_header: Color::from_hex(0x00d9ff),
 
-            // Play-state viewport borders
-            border_editing: Color::new(0.0, 0.48, 0.83, 0.5),
-            border_playing: Color::new(0.0, 0.8, 0.27, 0.8),
-            border_paused: Color::new(1.0, 0.8, 0.0, 0.8),
+            // Profiler (Okabe–Ito hues, distinguishable with color blindness)
+            profiler_series: [
+                Color::from_hex(0x56b4e9),
+                Color::from_hex(0xe69f00),
+                Color::from_hex(0x009e73),
+                Color::from_hex(0xcc79a7),
+                Color::from_hex(0xf0e442),
+                Color::from_hex(0xd55e00),
+            ],
 
-            // Collider overlay
-            collider_outline: Color::new(0.2, 1.0, 0.4, 0.9),
-            collider_sensor: Color::new(0.2, 0.85, 1.0, 0.9),
-            collider_selected: Color::new(1.0, 0.85, 0.2, 1.0),
+            // Scene-view overlays
+            colors: crate::EditorColorScheme::default(),
         }
     }
 }
@@ -287,16 +230,6 @@ impl EditorTheme {
         Vec4::new(color.r, color.g, color.b, color.a)
     }
 
-    /// Create `GridColors` from this theme.
-    pub fn grid_colors(&self) -> crate::GridColors {
-        crate::GridColors {
-            primary: self.grid_primary,
-            secondary: self.grid_secondary,
-            axis_x: self.grid_axis_x,
-            axis_y: self.grid_axis_y,
-        }
-    }
-
     /// Create `InspectorStyle` from this theme.
     pub fn inspector_style(&self) -> crate::InspectorStyle {
         crate::InspectorStyle {
@@ -362,41 +295,6 @@ impl EditorTheme {
 
         theme
     }
-
-    /// Create a `GizmoPalette` from this theme.
-    pub fn gizmo_palette(&self) -> crate::gizmo::GizmoPalette {
-        crate::gizmo::GizmoPalette {
-            x: self.gizmo_x,
-            y: self.gizmo_y,
-            center: self.gizmo_center,
-            x_hover: self.gizmo_x_hover,
-            y_hover: self.gizmo_y_hover,
-            center_hover: self.gizmo_center_hover,
-            ring: self.gizmo_ring,
-            rotation_indicator: self.gizmo_rotation_indicator,
-            scale_outline: self.gizmo_scale_outline,
-            scale_handle: self.gizmo_scale_handle,
-            scale_handle_hover: self.gizmo_scale_handle_hover,
-        }
-    }
-
-    /// Create `ColliderOverlayColors` from this theme.
-    pub fn collider_overlay_colors(&self) -> crate::ColliderOverlayColors {
-        crate::ColliderOverlayColors {
-            solid: self.collider_outline,
-            sensor: self.collider_sensor,
-            selected: self.collider_selected,
-        }
-    }
-
-    /// Get the viewport border color for a given play state.
-    pub fn play_state_border(&self, state: crate::EditorPlayState) -> Color {
-        match state {
-            crate::EditorPlayState::Editing => self.border_editing,
-            crate::EditorPlayState::Playing => self.border_playing,
-            crate::EditorPlayState::Paused => self.border_paused,
-        }
-    }
 }
 
 #[cfg(test)]
@@ -424,28 +322,6 @@ mod tests {
         assert_ne!(theme.accent_blue.b, theme.accent_cyan.b);
     }
 
-    #[test]
-    fn test_play_state_borders_are_distinct() {
-        let theme = EditorTheme::default();
-        let editing = theme.border_editing;
-        let playing = theme.border_playing;
-        let paused = theme.border_paused;
-        // Each state has a unique dominant channel
-        assert!(editing.b > editing.r && editing.b > editing.g); // blue-ish
-        assert!(playing.g > playing.r && playing.g > playing.b); // green-ish
-        assert!(paused.r > paused.b); // warm/yellow-ish
-    }
-
-    #[test]
-    fn test_grid_colors_conversion() {
-        let theme = EditorTheme::default();
-        let grid = theme.grid_colors();
-        assert_eq!(grid.primary, theme.grid_primary);
-        assert_eq!(grid.secondary, theme.grid_secondary);
-        assert_eq!(grid.axis_x, theme.grid_axis_x);
-        assert_eq!(grid.axis_y, theme.grid_axis_y);
-    }
-
     #[test]
     fn test_inspector_style_conversion() {
         let theme = EditorTheme::default();
@@ -479,23 +355,6 @@ mod tests {
         );
     }
 
-    #[test]
-    fn test_play_state_border_method() {
-        let theme = EditorTheme::default();
-        assert_eq!(
-            theme.play_state_border(crate::EditorPlayState::Editing),
-            theme.border_editing
-        );
-        assert_eq!(
-            theme.play_state_border(crate::EditorPlayState::Playing),
-            theme.border_playing
-        );
-        assert_eq!(
-            theme.play_state_border(crate::EditorPlayState::Paused),
-            theme.border_paused
-        );
-    }
-
     #[test]
     fn test_color_to_vec4() {
         let color = Color::new(0.1, 0.2, 0.3, 0.4);
@@ -506,19 +365,6 @@ mod tests {
         assert!((v.w - 0.4).abs() < f32::EPSILON);
     }
 
-    #[test]
-    fn test_gizmo_palette_matches_tokens() {
-        let theme = EditorTheme::default();
-        let palette = theme.gizmo_palette();
-        assert_eq!(palette.x, theme.gizmo_x);
-        assert_eq!(palette.y, theme.gizmo_y);
-        assert_eq!(palette.center, theme.gizmo_center);
-        assert_eq!(palette.x_hover, theme.gizmo_x_hover);
-        assert_eq!(palette.y_hover, theme.gizmo_y_hover);
-        assert_eq!(palette.ring, theme.gizmo_ring);
-        assert_eq!(palette.scale_handle_hover, theme.gizmo_scale_handle_hover);
-    }
-
     #[test]
     fn test_editable_field_style_includes_axis_and_channel_labels() {
         let theme = EditorTheme::default();
@@ -529,27 +375,11 @@ mod tests {
     }
 
     #[test]
-    fn test_hover_colors_differ_from_base() {
+    fn test_selection_and_hover_fills_differ() {
         let theme = EditorTheme::default();
-        assert_ne!(theme.gizmo_x_hover, theme.gizmo_x);
-        assert_ne!(theme.gizmo_y_hover, theme.gizmo_y);
-        assert_ne!(theme.gizmo_scale_handle_hover, theme.gizmo_scale_handle);
         assert_ne!(theme.selection_fill, theme.hover_fill);
     }
 
-    #[test]
-    fn test_collider_overlay_colors_match_tokens_and_are_distinct() {
-        let theme = EditorTheme::default();
-        let colors = theme.collider_overlay_colors();
-        assert_eq!(colors.solid, theme.collider_outline);
-        assert_eq!(colors.sensor, theme.collider_sensor);
-        assert_eq!(colors.selected, theme.collider_selected);
-        // Each state must be visually distinguishable
-        assert_ne!(theme.collider_outline, theme.collider_sensor);
-        assert_ne!(theme.collider_outline, theme.collider_selected);
-        assert_ne!(theme.collider_sensor, theme.collider_selected);
-    }
-
     #[test]
     fn test_dark_is_default() {
         let dark = EditorTheme::dark();
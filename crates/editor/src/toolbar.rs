@@ -1,7 +1,7 @@
 //! Editor toolbar with tool selection.
 //!
 //! The toolbar provides buttons for switching between editor tools
-//! (Select, Move, Rotate, Scale) and displays the current tool state.
+//! (Select, Move, Rotate, Scale, Measure) and displays the current tool state.
 
 use glam::Vec2;
 use ui::{Rect, UIContext};
@@ -18,6 +18,8 @@ pub enum EditorTool {
     Rotate,
     /// Scale entities uniformly or non-uniformly
     Scale,
+    /// Drag to measure distance and angle in world units
+    Measure,
 }
 
 impl EditorTool {
@@ -28,6 +30,7 @@ impl EditorTool {
             EditorTool::Move => "Move",
             EditorTool::Rotate => "Rotate",
             EditorTool::Scale => "Scale",
+            EditorTool::Measure => "Measure",
         }
     }
 
@@ -38,6 +41,7 @@ impl EditorTool {
             EditorTool::Move => "W",
             EditorTool::Rotate => "E",
             EditorTool::Scale => "R",
+            EditorTool::Measure => "M",
         }
     }
 
@@ -48,6 +52,7 @@ impl EditorTool {
             EditorTool::Move,
             EditorTool::Rotate,
             EditorTool::Scale,
+            EditorTool::Measure,
         ]
     }
 }
@@ -79,9 +84,9 @@ impl Toolbar {
         Self {
             current_tool: EditorTool::Select,
             position: Vec2::new(10.0, 10.0),
-            // Wide enough for the longest label ("Rotate"/"Select") at the
-            // body font size — 40px caused the labels to overflow each other
-            button_size: 56.0,
+            // Wide enough for the longest label ("Measure") at the body font
+            // size — 40px caused the labels to overflow each other
+            button_size: 64.0,
             spacing: 6.0,
         }
     }
@@ -182,6 +187,7 @@ mod tests {
         assert_eq!(EditorTool::Move.name(), "Move");
         assert_eq!(EditorTool::Rotate.name(), "Rotate");
         assert_eq!(EditorTool::Scale.name(), "Scale");
+        assert_eq!(EditorTool::Measure.name(), "Measure");
     }
 
     #[test]
@@ -190,16 +196,18 @@ mod tests {
         assert_eq!(EditorTool::Move.shortcut(), "W");
         assert_eq!(EditorTool::Rotate.shortcut(), "E");
         assert_eq!(EditorTool::Scale.shortcut(), "R");
+        assert_eq!(EditorTool::Measure.shortcut(), "M");
     }
 
     #[test]
     fn test_editor_tool_all() {
         let tools = EditorTool::all();
-        assert_eq!(tools.len(), 4);
+        assert_eq!(tools.len(), 5);
         assert!(tools.contains(&EditorTool::Select));
         assert!(tools.contains(&EditorTool::Move));
         assert!(tools.contains(&EditorTool::Rotate));
         assert!(tools.contains(&EditorTool::Scale));
+        assert!(tools.contains(&EditorTool::Measure));
     }
 
     #[test]
@@ -226,8 +234,8 @@ mod tests {
         let toolbar = Toolbar::new();
         let bounds = toolbar.bounds();
 
-        // 4 tools * (56 + 6) - 6 = 242
-        assert_eq!(bounds.width, 242.0);
-        assert_eq!(bounds.height, 56.0);
+        // 5 tools * (64 + 6) - 6 = 344
+        assert_eq!(bounds.width, 344.0);
+        assert_eq!(bounds.height, 64.0);
     }
 }
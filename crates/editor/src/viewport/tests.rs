@@ -0,0 +1,268 @@
+use super::*;
+
+#[test]
+fn test_viewport_new() {
+    let viewport = SceneViewport::new();
+    assert_eq!(viewport.camera_position(), Vec2::ZERO);
+    assert_eq!(viewport.camera_zoom(), 1.0);
+}
+
+#[test]
+fn test_viewport_set_bounds() {
+    let mut viewport = SceneViewport::new();
+    viewport.set_viewport_bounds(Rect::new(100.0, 50.0, 800.0, 600.0));
+
+    assert_eq!(viewport.viewport_bounds().x, 100.0);
+    assert_eq!(viewport.viewport_bounds().y, 50.0);
+    assert_eq!(viewport.viewport_size(), Vec2::new(800.0, 600.0));
+    assert_eq!(viewport.viewport_center(), Vec2::new(500.0, 350.0));
+}
+
+#[test]
+fn test_viewport_screen_to_world_no_offset() {
+    let mut viewport = SceneViewport::new();
+    viewport.set_viewport_bounds(Rect::new(0.0, 0.0, 800.0, 600.0));
+
+    // Center of viewport should map to camera position (origin)
+    let world = viewport.screen_to_world(Vec2::new(400.0, 300.0));
+    assert!((world.x).abs() < 0.001);
+    assert!((world.y).abs() < 0.001);
+}
+
+#[test]
+fn test_viewport_world_to_screen_no_offset() {
+    let mut viewport = SceneViewport::new();
+    viewport.set_viewport_bounds(Rect::new(0.0, 0.0, 800.0, 600.0));
+
+    // World origin should map to viewport center
+    let screen = viewport.world_to_screen(Vec2::ZERO);
+    assert!((screen.x - 400.0).abs() < 0.001);
+    assert!((screen.y - 300.0).abs() < 0.001);
+}
+
+#[test]
+fn test_viewport_coordinate_roundtrip() {
+    let mut viewport = SceneViewport::new();
+    viewport.set_viewport_bounds(Rect::new(100.0, 50.0, 800.0, 600.0));
+    viewport.set_camera_position(Vec2::new(100.0, -50.0));
+    viewport.set_camera_zoom(2.0);
+
+    let original_screen = Vec2::new(350.0, 200.0);
+    let world = viewport.screen_to_world(original_screen);
+    let back_to_screen = viewport.world_to_screen(world);
+
+    assert!((back_to_screen.x - original_screen.x).abs() < 0.001);
+    assert!((back_to_screen.y - original_screen.y).abs() < 0.001);
+}
+
+#[test]
+fn test_viewport_visible_world_bounds() {
+    let mut viewport = SceneViewport::new();
+    viewport.set_viewport_bounds(Rect::new(0.0, 0.0, 800.0, 600.0));
+
+    let (min_x, min_y, max_x, max_y) = viewport.visible_world_bounds();
+    assert_eq!(min_x, -400.0);
+    assert_eq!(min_y, -300.0);
+    assert_eq!(max_x, 400.0);
+    assert_eq!(max_y, 300.0);
+}
+
+#[test]
+fn test_viewport_visible_world_bounds_with_zoom() {
+    let mut viewport = SceneViewport::new();
+    viewport.set_viewport_bounds(Rect::new(0.0, 0.0, 800.0, 600.0));
+    viewport.set_camera_zoom(2.0);
+
+    let (min_x, min_y, max_x, max_y) = viewport.visible_world_bounds();
+    // At 2x zoom, visible area is halved
+    assert_eq!(min_x, -200.0);
+    assert_eq!(min_y, -150.0);
+    assert_eq!(max_x, 200.0);
+    assert_eq!(max_y, 150.0);
+}
+
+#[test]
+fn test_viewport_pan() {
+    let mut viewport = SceneViewport::new();
+    viewport.pan_immediate(Vec2::new(50.0, -25.0));
+
+    assert_eq!(viewport.camera_position(), Vec2::new(50.0, -25.0));
+}
+
+#[test]
+fn test_viewport_zoom_clamp() {
+    let mut viewport = SceneViewport::new();
+
+    viewport.set_camera_zoom(0.01);
+    assert_eq!(viewport.camera_zoom(), 0.1); // Clamped to min
+
+    viewport.set_camera_zoom(100.0);
+    assert_eq!(viewport.camera_zoom(), 10.0); // Clamped to max
+}
+
+#[test]
+fn test_viewport_reset_camera() {
+    let mut viewport = SceneViewport::new();
+    viewport.set_camera_position(Vec2::new(100.0, 200.0));
+    viewport.set_camera_zoom(3.0);
+
+    viewport.reset_camera_immediate();
+
+    assert_eq!(viewport.camera_position(), Vec2::ZERO);
+    assert_eq!(viewport.camera_zoom(), 1.0);
+}
+
+#[test]
+fn test_viewport_contains_screen_point() {
+    let mut viewport = SceneViewport::new();
+    viewport.set_viewport_bounds(Rect::new(100.0, 50.0, 400.0, 300.0));
+
+    assert!(viewport.contains_screen_point(Vec2::new(200.0, 150.0)));
+    assert!(!viewport.contains_screen_point(Vec2::new(50.0, 150.0)));
+    assert!(!viewport.contains_screen_point(Vec2::new(200.0, 400.0)));
+}
+
+/// Assert the viewport overlay mapping and the GPU render-camera mapping
+/// agree for a set of world points. This equivalence is the contract that
+/// keeps gizmo/picking/grid aligned with the rendered sprites.
+fn assert_overlay_matches_render_camera(viewport: &SceneViewport, window_size: Vec2) {
+    let camera = viewport.to_window_render_camera(window_size);
+    for world in [
+        Vec2::ZERO,
+        Vec2::new(100.0, 50.0),
+        Vec2::new(-3.5, 77.25),
+        Vec2::new(-250.0, -125.0),
+    ] {
+        let overlay = viewport.world_to_screen(world);
+        let gpu = camera.world_to_screen(world);
+        assert!(
+            (overlay - gpu).length() < 0.01,
+            "mismatch at {world}: overlay {overlay} vs gpu {gpu}"
+        );
+    }
+}
+
+#[test]
+fn test_window_render_camera_matches_overlay_default_view() {
+    // Regression: with a NONZERO panel origin (dock chrome on all sides),
+    // the old to_render_camera produced a panel_center-to-window_center
+    // offset between sprites and the editor overlay.
+    let mut viewport = SceneViewport::new();
+    viewport.set_viewport_bounds(Rect::new(300.0, 100.0, 800.0, 600.0));
+    assert_overlay_matches_render_camera(&viewport, Vec2::new(1600.0, 900.0));
+}
+
+#[test]
+fn test_window_render_camera_matches_overlay_pan_zoom() {
+    let mut viewport = SceneViewport::new();
+    viewport.set_viewport_bounds(Rect::new(300.0, 100.0, 800.0, 600.0));
+    viewport.set_camera_position(Vec2::new(120.0, -40.0));
+    viewport.set_camera_zoom(2.0);
+    assert_overlay_matches_render_camera(&viewport, Vec2::new(1600.0, 900.0));
+}
+
+#[test]
+fn test_window_render_camera_matches_overlay_rotated() {
+    let mut viewport = SceneViewport::new();
+    viewport.set_viewport_bounds(Rect::new(300.0, 100.0, 800.0, 600.0));
+    viewport.set_camera_position(Vec2::new(120.0, -40.0));
+    viewport.set_camera_zoom(1.5);
+    viewport.set_camera_rotation(0.6);
+    assert_overlay_matches_render_camera(&viewport, Vec2::new(1600.0, 900.0));
+}
+
+#[test]
+fn test_rotated_screen_world_roundtrip() {
+    let mut viewport = SceneViewport::new();
+    viewport.set_viewport_bounds(Rect::new(0.0, 0.0, 800.0, 600.0));
+    viewport.set_camera_rotation(std::f32::consts::FRAC_PI_2);
+
+    // A quarter turn CCW puts world +X straight up on screen
+    let screen = viewport.world_to_screen(Vec2::new(100.0, 0.0));
+    assert!((screen - Vec2::new(400.0, 200.0)).length() < 0.01);
+    assert!((viewport.screen_to_world(screen) - Vec2::new(100.0, 0.0)).length() < 0.01);
+}
+
+#[test]
+fn test_camera_rotation_wraps_and_resets() {
+    let mut viewport = SceneViewport::new();
+    viewport.rotate(std::f32::consts::TAU + 0.5);
+    assert!((viewport.camera_rotation() - 0.5).abs() < 1e-4);
+    assert!(viewport.is_rotated());
+
+    viewport.reset_camera();
+    assert!(!viewport.is_rotated());
+}
+
+#[test]
+fn test_window_render_camera_screen_roundtrip() {
+    // viewport.screen_to_world must be the inverse of the GPU camera's
+    // world_to_screen, so clicks land on the sprite under the cursor.
+    let mut viewport = SceneViewport::new();
+    viewport.set_viewport_bounds(Rect::new(300.0, 100.0, 800.0, 600.0));
+    viewport.set_camera_position(Vec2::new(-60.0, 25.0));
+    viewport.set_camera_zoom(1.5);
+    let camera = viewport.to_window_render_camera(Vec2::new(1600.0, 900.0));
+
+    for screen in [
+        Vec2::new(700.0, 400.0),
+        Vec2::new(310.0, 110.0),
+        Vec2::new(1050.0, 650.0),
+    ] {
+        let world = viewport.screen_to_world(screen);
+        let back = camera.world_to_screen(world);
+        assert!(
+            (back - screen).length() < 0.01,
+            "roundtrip mismatch at {screen}: got {back}"
+        );
+    }
+}
+
+#[test]
+fn test_viewport_focus_on() {
+    let mut viewport = SceneViewport::new();
+    viewport.focus_on(Vec2::new(500.0, 300.0));
+
+    // Target should be set (actual position updates on update())
+    viewport.update(0.016);
+
+    // After interpolation, should be moving toward target
+    let pos = viewport.camera_position();
+    assert!(pos.x > 0.0); // Moving toward 500
+    assert!(pos.y > 0.0); // Moving toward 300
+}
+
+#[test]
+fn test_viewport_focus_on_bounds() {
+    let mut viewport = SceneViewport::new();
+    viewport.set_viewport_bounds(Rect::new(0.0, 0.0, 800.0, 600.0));
+
+    let positions = vec![
+        Vec2::new(-100.0, -50.0),
+        Vec2::new(100.0, 50.0),
+    ];
+    viewport.focus_on_bounds(&positions);
+
+    // Target should be center of bounds
+    viewport.update(0.016);
+    // Camera should be moving toward (0, 0) - center of bounds
+}
+
+#[test]
+fn test_viewport_frame_bounds_fits_box() {
+    let mut viewport = SceneViewport::new();
+    viewport.set_viewport_bounds(Rect::new(0.0, 0.0, 800.0, 600.0));
+    viewport.set_interpolation_speed(1.0);
+
+    viewport.frame_bounds(AABB::new(Vec2::new(100.0, 100.0), Vec2::new(300.0, 200.0)));
+    viewport.update(0.016);
+    assert_eq!(viewport.camera_position(), Vec2::new(200.0, 150.0));
+    // 200x100 box in 800x600 at 80% fill: width limits (640 / 200)
+    assert!((viewport.camera_zoom() - 3.2).abs() < 1e-4);
+
+    // A point keeps the zoom
+    viewport.frame_bounds(AABB::new(Vec2::new(-50.0, 0.0), Vec2::new(-50.0, 0.0)));
+    viewport.update(0.016);
+    assert_eq!(viewport.camera_position(), Vec2::new(-50.0, 0.0));
+    assert!((viewport.camera_zoom() - 3.2).abs() < 1e-4);
+}
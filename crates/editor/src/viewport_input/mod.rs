@@ -0,0 +1,469 @@
+//! Input handling for the scene viewport.
+//!
+//! Handles pan, zoom, and navigation controls for the scene viewport camera.
+//! Uses the editor input mapping system for configurable key bindings.
+
+use glam::Vec2;
+use serde::{Deserialize, Serialize};
+
+use crate::editor_input::{EditorAction, EditorInputMapping, EditorInputState};
+use crate::viewport::SceneViewport;
+
+/// Which inputs start a viewport pan drag.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum PanMode {
+    /// Middle-mouse drag only
+    MiddleMouse,
+    /// Space + left-drag only
+    SpaceDrag,
+    /// Either middle-mouse drag or Space + left-drag
+    #[default]
+    Both,
+}
+
+impl PanMode {
+    /// Every mode, in settings-cycle order.
+    pub const ALL: [PanMode; 3] = [PanMode::Both, PanMode::MiddleMouse, PanMode::SpaceDrag];
+
+    /// Whether middle-mouse drag pans in this mode.
+    pub fn allows_middle_mouse(self) -> bool {
+        matches!(self, PanMode::MiddleMouse | PanMode::Both)
+    }
+
+    /// Whether Space + left-drag pans in this mode.
+    pub fn allows_space_drag(self) -> bool {
+        matches!(self, PanMode::SpaceDrag | PanMode::Both)
+    }
+
+    /// Human-readable label for settings UI.
+    pub fn label(self) -> &'static str {
+        match self {
+            PanMode::MiddleMouse => "Middle Mouse",
+            PanMode::SpaceDrag => "Space + Drag",
+            PanMode::Both => "Middle Mouse or Space + Drag",
+        }
+    }
+}
+
+/// What the mouse wheel does in the scene view.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ScrollMode {
+    /// The wheel zooms
+    #[default]
+    Zoom,
+    /// The wheel pans (Shift: sideways); Ctrl + wheel zooms
+    PanCtrlZoom,
+}
+
+impl ScrollMode {
+    /// Every mode, in settings-cycle order.
+    pub const ALL: [ScrollMode; 2] = [ScrollMode::Zoom, ScrollMode::PanCtrlZoom];
+
+    /// Human-readable label for settings UI.
+    pub fn label(self) -> &'static str {
+        match self {
+            ScrollMode::Zoom => "Wheel Zooms",
+            ScrollMode::PanCtrlZoom => "Wheel Pans, Ctrl + Wheel Zooms",
+        }
+    }
+}
+
+/// Configuration for viewport input handling.
+///
+/// Persisted as the `navigation` section of
+/// [`EditorPreferences`](crate::EditorPreferences); missing fields fall back
+/// to the defaults so older preference files keep loading.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ViewportInputConfig {
+    /// Zoom factor per scroll notch (zoom sensitivity)
+    pub zoom_factor: f32,
+    /// Whether to invert scroll direction for zoom
+    pub invert_zoom: bool,
+    /// Minimum zoom level
+    pub min_zoom: f32,
+    /// Maximum zoom level
+    pub max_zoom: f32,
+    /// Pan sensitivity multiplier
+    pub pan_sensitivity: f32,
+    /// Drag threshold in pixels (movement needed to start drag vs click)
+    pub drag_threshold: f32,
+    /// Which inputs pan the view
+    pub pan_mode: PanMode,
+    /// Whether right-mouse drag also pans
+    pub right_drag_pan: bool,
+    /// Whether Alt + left-drag pans (Alt-navigation without orbiting);
+    /// view rotation takes Alt + drag instead while it is enabled
+    pub alt_drag_pan: bool,
+    /// What the mouse wheel does
+    pub scroll_mode: ScrollMode,
+    /// Screen pixels panned per wheel line in [`ScrollMode::PanCtrlZoom`]
+    pub scroll_pan_speed: f32,
+    /// Animate zooms (holding the zoom point in place throughout) rather
+    /// than jumping
+    pub smooth_zoom: bool,
+    /// Zoom around the cursor (`true`) or the viewport center (`false`)
+    pub zoom_to_cursor: bool,
+    /// Whether Alt + left-drag rotates the view
+    pub rotation_enabled: bool,
+    /// View rotation per pixel of horizontal drag, in radians
+    pub rotation_sensitivity: f32,
+    /// Trackpad gestures: two-finger scroll pans, pinch zooms. When off,
+    /// trackpad scroll zooms like a mouse wheel.
+    pub trackpad_gestures: bool,
+}
+
+impl ViewportInputConfig {
+    /// Advance to the next [`PanMode`] and return it.
+    pub fn cycle_pan_mode(&mut self) -> PanMode {
+        let index = PanMode::ALL.iter().position(|m| *m == self.pan_mode).unwrap_or(0);
+        self.pan_mode = PanMode::ALL[(index + 1) % PanMode::ALL.len()];
+        self.pan_mode
+    }
+
+    /// Advance to the next [`ScrollMode`] and return it.
+    pub fn cycle_scroll_mode(&mut self) -> ScrollMode {
+        let index = ScrollMode::ALL.iter().position(|m| *m == self.scroll_mode).unwrap_or(0);
+        self.scroll_mode = ScrollMode::ALL[(index + 1) % ScrollMode::ALL.len()];
+        self.scroll_mode
+    }
+
+    /// Zoom multiplier for a scroll delta in lines: one line is one
+    /// `zoom_factor` step, fractional (trackpad) deltas scale smoothly.
+    pub fn scroll_zoom_factor(&self, scroll_delta: f32) -> f32 {
+        let direction = if self.invert_zoom { -1.0 } else { 1.0 };
+        self.zoom_factor.powf(scroll_delta * direction)
+    }
+}
+
+impl Default for ViewportInputConfig {
+    fn default() -> Self {
+        Self {
+            zoom_factor: 1.1,
+            invert_zoom: false,
+            min_zoom: 0.1,
+            max_zoom: 10.0,
+            pan_sensitivity: 1.0,
+            drag_threshold: 5.0,
+            pan_mode: PanMode::Both,
+            right_drag_pan: true,
+            alt_drag_pan: false,
+            scroll_mode: ScrollMode::Zoom,
+            scroll_pan_speed: 40.0,
+            smooth_zoom: true,
+            zoom_to_cursor: true,
+            rotation_enabled: false,
+            rotation_sensitivity: 0.01,
+            trackpad_gestures: true,
+        }
+    }
+}
+
+/// Input state for viewport interaction.
+#[derive(Debug, Clone, Default)]
+struct ViewportInputInternalState {
+    /// Whether panning is currently active
+    panning: bool,
+    /// Whether an Alt-drag view rotation is active
+    rotating: bool,
+    /// Last mouse position during pan
+    last_pan_position: Vec2,
+    /// Selection rectangle start position (screen coords)
+    selection_start: Option<Vec2>,
+    /// Whether selection rectangle is active (dragged past threshold)
+    selection_active: bool,
+}
+
+/// Result of viewport input handling.
+#[derive(Debug, Clone, Default)]
+pub struct ViewportInputResult {
+    /// Whether the viewport consumed this input (should not pass to other systems)
+    pub consumed: bool,
+    /// Whether a click occurred (for entity picking)
+    pub clicked: bool,
+    /// Click position in screen coordinates
+    pub click_position: Vec2,
+    /// Whether add-to-selection modifier is held (Shift)
+    pub shift_held: bool,
+    /// Whether toggle-selection modifier is held (Ctrl)
+    pub ctrl_held: bool,
+    /// Whether a selection rectangle drag is active
+    pub selection_drag_active: bool,
+    /// Selection rectangle start position (screen coords)
+    pub selection_start: Vec2,
+    /// Selection rectangle end position (screen coords)
+    pub selection_end: Vec2,
+    /// Whether focus on selection was requested
+    pub focus_requested: bool,
+    /// Whether camera reset was requested
+    pub reset_requested: bool,
+}
+
+/// Handles input for viewport navigation (pan, zoom, focus).
+#[derive(Debug, Clone)]
+pub struct ViewportInputHandler {
+    /// Configuration
+    pub config: ViewportInputConfig,
+    /// Current input state
+    state: ViewportInputInternalState,
+}
+
+impl Default for ViewportInputHandler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ViewportInputHandler {
+    /// Create a new viewport input handler.
+    pub fn new() -> Self {
+        Self {
+            config: ViewportInputConfig::default(),
+            state: ViewportInputInternalState::default(),
+        }
+    }
+
+    /// Create with custom configuration.
+    pub fn with_config(config: ViewportInputConfig) -> Self {
+        Self {
+            config,
+            state: ViewportInputInternalState::default(),
+        }
+    }
+
+    /// Handle input and update viewport camera.
+    ///
+    /// Returns information about the input for other systems (picking, etc).
+    ///
+    /// # Arguments
+    /// * `viewport` - The scene viewport to update
+    /// * `input_state` - Current input state from EditorInputMapping
+    /// * `input_mapping` - Editor input mapping for checking actions
+    /// * `input_handler` - Raw input handler for action checks
+    /// * `viewport_contains_mouse` - Whether mouse is over the viewport
+    pub fn handle_input(
+        &mut self,
+        viewport: &mut SceneViewport,
+        input_state: &EditorInputState,
+        input_mapping: &EditorInputMapping,
+        input_handler: &input::InputHandler,
+        viewport_contains_mouse: bool,
+    ) -> ViewportInputResult {
+        // Track modifier keys from input state
+        let mut result = ViewportInputResult {
+            shift_held: input_state.add_modifier,
+            ctrl_held: input_state.toggle_modifier,
+            ..Default::default()
+        };
+
+        // Only handle input if mouse is over viewport
+        if !viewport_contains_mouse {
+            // End any active interactions
+            if self.state.panning {
+                self.state.panning = false;
+            }
+            self.state.rotating = false;
+            if self.state.selection_active {
+                self.state.selection_active = false;
+                self.state.selection_start = None;
+            }
+            return result;
+        }
+
+        let mouse_pos = input_state.mouse_position;
+
+        // Handle keyboard shortcuts (actions)
+        if input_mapping.is_action_just_pressed(EditorAction::FocusSelection, input_handler) {
+            result.focus_requested = true;
+            result.consumed = true;
+        }
+
+        if input_mapping.is_action_just_pressed(EditorAction::ResetCamera, input_handler) {
+            viewport.reset_camera();
+            result.reset_requested = true;
+            result.consumed = true;
+        }
+
+        // Handle pan input
+        // Pan is active when: middle mouse button held, OR pan modifier (Space) + primary button
+        // (each path gated by the configured pan mode)
+        let space_pan_held = self.config.pan_mode.allows_space_drag() && input_state.pan_modifier;
+        let pan_via_middle =
+            self.config.pan_mode.allows_middle_mouse() && input_state.middle_button.pressed;
+        let pan_via_space = space_pan_held && input_state.primary_button.pressed;
+        let pan_via_right = self.config.right_drag_pan && input_state.secondary_button.pressed;
+        let alt_pan_held =
+            self.config.alt_drag_pan && !self.config.rotation_enabled && input_state.rotate_modifier;
+        let pan_via_alt = alt_pan_held && input_state.primary_button.pressed;
+        let pan_active = pan_via_middle || pan_via_space || pan_via_right || pan_via_alt;
+
+        if pan_active {
+            if !self.state.panning {
+                // Start panning
+                self.state.panning = true;
+                self.state.last_pan_position = mouse_pos;
+            } else {
+                // Continue panning - move the camera so the world point
+                // grabbed last frame stays under the cursor (rotation-aware)
+                let world_delta = viewport.screen_to_world(self.state.last_pan_position)
+                    - viewport.screen_to_world(mouse_pos);
+                viewport.pan_immediate(world_delta * self.config.pan_sensitivity);
+                self.state.last_pan_position = mouse_pos;
+            }
+            result.consumed = true;
+        } else {
+            self.state.panning = false;
+        }
+
+        // Handle view rotation (Alt + primary drag, when enabled)
+        let rotate_active = self.config.rotation_enabled
+            && input_state.rotate_modifier
+            && input_state.primary_button.pressed
+            && !self.state.panning;
+        if rotate_active {
+            self.state.rotating = true;
+            viewport.rotate(input_state.mouse_delta.x * self.config.rotation_sensitivity);
+            result.consumed = true;
+        } else if !input_state.primary_button.pressed {
+            self.state.rotating = false;
+        }
+
+        let zoom_anchor = if self.config.zoom_to_cursor {
+            mouse_pos
+        } else {
+            viewport.viewport_center()
+        };
+
+        // Trackpad gestures: two-finger scroll pans, pinch zooms. The pixel
+        // scroll also arrives as a wheel delta, which is skipped below.
+        let trackpad_scrolled = input_state.trackpad_scroll != Vec2::ZERO;
+        if self.config.trackpad_gestures {
+            if trackpad_scrolled {
+                let world_delta = viewport.screen_to_world(mouse_pos)
+                    - viewport.screen_to_world(mouse_pos + input_state.trackpad_scroll);
+                viewport.pan_immediate(world_delta * self.config.pan_sensitivity);
+                result.consumed = true;
+            }
+            if input_state.pinch_delta.abs() > f32::EPSILON {
+                // Pinches arrive every frame of the gesture; follow them directly
+                viewport.zoom_at_immediate((1.0 + input_state.pinch_delta).max(0.1), zoom_anchor);
+                result.consumed = true;
+            }
+        }
+
+        // Handle the scroll wheel: zoom, or pan with Ctrl + wheel zooming
+        let wheel_used = !(self.config.trackpad_gestures && trackpad_scrolled)
+            && input_state.scroll_delta.abs() > 0.001;
+        let wheel_pans =
+            self.config.scroll_mode == ScrollMode::PanCtrlZoom && !input_state.toggle_modifier;
+        if wheel_used && wheel_pans {
+            let lines = input_state.scroll_delta * self.config.scroll_pan_speed;
+            // Wheel up moves the view up; with Shift, wheel down moves it right
+            let screen_delta = if input_state.add_modifier {
+                Vec2::new(lines, 0.0)
+            } else {
+                Vec2::new(0.0, lines)
+            };
+            let world_delta =
+                viewport.screen_to_world(mouse_pos) - viewport.screen_to_world(mouse_pos + screen_delta);
+            viewport.pan_immediate(world_delta * self.config.pan_sensitivity);
+            result.consumed = true;
+        } else if wheel_used {
+            let factor = self.config.scroll_zoom_factor(input_state.scroll_delta);
+            if self.config.smooth_zoom {
+                viewport.zoom_at(factor, zoom_anchor);
+            } else {
+                viewport.zoom_at_immediate(factor, zoom_anchor);
+            }
+            result.consumed = true;
+        }
+
+        // Handle selection rectangle (primary button drag without pan/rotate modifier)
+        let can_select = input_state.primary_button.pressed
+            && !space_pan_held
+            && !alt_pan_held
+            && !self.state.panning
+            && !self.state.rotating;
+
+        if can_select {
+            if self.state.selection_start.is_none() {
+                // Start selection drag
+                self.state.selection_start = Some(mouse_pos);
+                self.state.selection_active = false; // Not yet active until dragged past threshold
+            } else if let Some(start) = self.state.selection_start {
+                // Check if we've dragged enough to start selection rect
+                let drag_dist = (mouse_pos - start).length();
+                if drag_dist > self.config.drag_threshold {
+                    self.state.selection_active = true;
+                }
+            }
+
+            if self.state.selection_active {
+                result.selection_drag_active = true;
+                result.selection_start = self.state.selection_start.unwrap_or(mouse_pos);
+                result.selection_end = mouse_pos;
+            }
+        } else {
+            // Primary button released or pan started
+            if let Some(start) = self.state.selection_start {
+                if self.state.selection_active {
+                    // Complete selection rectangle
+                    result.selection_drag_active = false;
+                    result.selection_start = start;
+                    result.selection_end = mouse_pos;
+                    // The caller will handle the actual selection
+                } else {
+                    // Was a click, not a drag
+                    result.clicked = true;
+                    result.click_position = start;
+                }
+            }
+            self.state.selection_start = None;
+            self.state.selection_active = false;
+        }
+
+        result
+    }
+
+    /// Simplified input handling that creates the input state internally.
+    pub fn handle_input_simple(
+        &mut self,
+        viewport: &mut SceneViewport,
+        input_mapping: &EditorInputMapping,
+        input_handler: &input::InputHandler,
+    ) -> ViewportInputResult {
+        let input_state = input_mapping.update_state(input_handler);
+        let viewport_contains_mouse = viewport.contains_screen_point(input_state.mouse_position);
+
+        self.handle_input(
+            viewport,
+            &input_state,
+            input_mapping,
+            input_handler,
+            viewport_contains_mouse,
+        )
+    }
+
+    /// Check if panning is currently active.
+    pub fn is_panning(&self) -> bool {
+        self.state.panning
+    }
+
+    /// Check if an Alt-drag view rotation is active.
+    pub fn is_rotating(&self) -> bool {
+        self.state.rotating
+    }
+
+    /// Check if selection rectangle is active.
+    pub fn is_selecting(&self) -> bool {
+        self.state.selection_active
+    }
+
+    /// Reset all input state.
+    pub fn reset(&mut self) {
+        self.state = ViewportInputInternalState::default();
+    }
+}
+
+#[cfg(test)]
+mod tests;
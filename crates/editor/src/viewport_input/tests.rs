@@ -0,0 +1,341 @@
+use super::*;
+use input::prelude::MouseButton;
+use crate::editor_input::ButtonState;
+
+/// Calculate zoom factor for a scroll delta (mirrors the logic in `handle_input`).
+fn calculate_zoom_factor(scroll_delta: f32, base_factor: f32, invert: bool) -> f32 {
+    let factor = if scroll_delta > 0.0 {
+        base_factor
+    } else {
+        1.0 / base_factor
+    };
+
+    if invert {
+        1.0 / factor
+    } else {
+        factor
+    }
+}
+
+/// Convert screen delta to world delta for panning (mirrors the logic in `handle_input`).
+fn screen_to_world_delta(screen_delta: Vec2, camera_zoom: f32) -> Vec2 {
+    Vec2::new(
+        -screen_delta.x / camera_zoom,
+        screen_delta.y / camera_zoom, // Flip Y
+    )
+}
+
+#[test]
+fn test_viewport_input_handler_new() {
+    let handler = ViewportInputHandler::new();
+    assert!(!handler.is_panning());
+    assert!(!handler.is_selecting());
+}
+
+#[test]
+fn test_zoom_factor_calculation() {
+    let factor = calculate_zoom_factor(1.0, 1.1, false);
+    assert!((factor - 1.1).abs() < 0.001);
+
+    let factor = calculate_zoom_factor(-1.0, 1.1, false);
+    assert!((factor - 1.0 / 1.1).abs() < 0.001);
+}
+
+#[test]
+fn test_zoom_factor_inverted() {
+    let factor = calculate_zoom_factor(1.0, 1.1, true);
+    assert!((factor - 1.0 / 1.1).abs() < 0.001);
+}
+
+#[test]
+fn test_screen_to_world_delta() {
+    let screen_delta = Vec2::new(100.0, 50.0);
+    let world_delta = screen_to_world_delta(screen_delta, 1.0);
+
+    // X should be negated, Y should be flipped
+    assert_eq!(world_delta.x, -100.0);
+    assert_eq!(world_delta.y, 50.0);
+}
+
+#[test]
+fn test_screen_to_world_delta_with_zoom() {
+    let screen_delta = Vec2::new(100.0, 50.0);
+    let world_delta = screen_to_world_delta(screen_delta, 2.0);
+
+    // At 2x zoom, world deltas are halved
+    assert_eq!(world_delta.x, -50.0);
+    assert_eq!(world_delta.y, 25.0);
+}
+
+#[test]
+fn test_viewport_input_config_default() {
+    let config = ViewportInputConfig::default();
+    assert!((config.zoom_factor - 1.1).abs() < 0.001);
+    assert!(!config.invert_zoom);
+    assert_eq!(config.min_zoom, 0.1);
+    assert_eq!(config.max_zoom, 10.0);
+}
+
+fn test_viewport() -> SceneViewport {
+    let mut viewport = SceneViewport::new();
+    viewport.set_viewport_bounds(common::Rect::new(0.0, 0.0, 800.0, 600.0));
+    viewport.set_interpolation_speed(1.0);
+    viewport
+}
+
+fn run_frame(
+    handler: &mut ViewportInputHandler,
+    viewport: &mut SceneViewport,
+    state: &EditorInputState,
+) -> ViewportInputResult {
+    let result = handler.handle_input(
+        viewport,
+        state,
+        &EditorInputMapping::new(),
+        &input::InputHandler::new(),
+        true,
+    );
+    viewport.update(0.016);
+    result
+}
+
+#[test]
+fn test_cycle_pan_mode_wraps() {
+    let mut config = ViewportInputConfig::default();
+    assert_eq!(config.cycle_pan_mode(), PanMode::MiddleMouse);
+    assert_eq!(config.cycle_pan_mode(), PanMode::SpaceDrag);
+    assert_eq!(config.cycle_pan_mode(), PanMode::Both);
+}
+
+#[test]
+fn test_scroll_zoom_factor_scales_with_delta() {
+    let config = ViewportInputConfig::default();
+    assert!((config.scroll_zoom_factor(1.0) - 1.1).abs() < 1e-4);
+    assert!((config.scroll_zoom_factor(2.0) - 1.21).abs() < 1e-4);
+    assert!((config.scroll_zoom_factor(-1.0) - 1.0 / 1.1).abs() < 1e-4);
+}
+
+#[test]
+fn test_middle_mouse_only_pan_mode_lets_space_click_select() {
+    let mut handler = ViewportInputHandler::with_config(ViewportInputConfig {
+        pan_mode: PanMode::MiddleMouse,
+        ..Default::default()
+    });
+    let mut viewport = test_viewport();
+    let mut state = EditorInputState {
+        pan_modifier: true,
+        mouse_position: Vec2::new(100.0, 100.0),
+        ..Default::default()
+    };
+    state.primary_button.pressed = true;
+    run_frame(&mut handler, &mut viewport, &state);
+    state.mouse_position = Vec2::new(200.0, 150.0);
+    let result = run_frame(&mut handler, &mut viewport, &state);
+
+    assert!(!handler.is_panning());
+    assert!(result.selection_drag_active);
+    assert_eq!(viewport.camera_position(), Vec2::ZERO);
+}
+
+#[test]
+fn test_alt_drag_rotates_view_only_when_enabled() {
+    let mut state = EditorInputState {
+        rotate_modifier: true,
+        mouse_delta: Vec2::new(50.0, 0.0),
+        ..Default::default()
+    };
+    state.primary_button.pressed = true;
+
+    let mut viewport = test_viewport();
+    run_frame(&mut ViewportInputHandler::new(), &mut viewport, &state);
+    assert!(!viewport.is_rotated());
+
+    let mut handler = ViewportInputHandler::with_config(ViewportInputConfig {
+        rotation_enabled: true,
+        ..Default::default()
+    });
+    let result = run_frame(&mut handler, &mut viewport, &state);
+    assert!((viewport.camera_rotation() - 0.5).abs() < 1e-4);
+    assert!(handler.is_rotating());
+    assert!(!result.selection_drag_active);
+}
+
+#[test]
+fn test_trackpad_scroll_pans_instead_of_zooming() {
+    let mut handler = ViewportInputHandler::new();
+    let mut viewport = test_viewport();
+    let state = EditorInputState {
+        mouse_position: Vec2::new(400.0, 300.0),
+        trackpad_scroll: Vec2::new(40.0, 0.0),
+        scroll_delta: 0.0,
+        ..Default::default()
+    };
+    run_frame(&mut handler, &mut viewport, &state);
+
+    assert_eq!(viewport.camera_zoom(), 1.0);
+    assert!((viewport.camera_position() - Vec2::new(-40.0, 0.0)).length() < 0.01);
+}
+
+#[test]
+fn test_pinch_zooms_in() {
+    let mut handler = ViewportInputHandler::new();
+    let mut viewport = test_viewport();
+    let state = EditorInputState {
+        mouse_position: Vec2::new(400.0, 300.0),
+        pinch_delta: 0.5,
+        ..Default::default()
+    };
+    run_frame(&mut handler, &mut viewport, &state);
+    assert!((viewport.camera_zoom() - 1.5).abs() < 1e-4);
+}
+
+#[test]
+fn test_zoom_to_center_ignores_cursor_position() {
+    let mut handler = ViewportInputHandler::with_config(ViewportInputConfig {
+        zoom_to_cursor: false,
+        ..Default::default()
+    });
+    let mut viewport = test_viewport();
+    let state = EditorInputState {
+        mouse_position: Vec2::new(700.0, 100.0),
+        scroll_delta: 1.0,
+        ..Default::default()
+    };
+    run_frame(&mut handler, &mut viewport, &state);
+
+    assert!(viewport.camera_zoom() > 1.0);
+    assert!(viewport.camera_position().length() < 0.01);
+}
+
+/// Play `script` through `handle_input_simple`, one frame per script frame,
+/// returning every frame's result.
+fn run_script(
+    handler: &mut ViewportInputHandler,
+    viewport: &mut SceneViewport,
+    script: input::InputScript,
+) -> Vec<ViewportInputResult> {
+    let mapping = EditorInputMapping::new();
+    let mut input = input::InputHandler::new();
+    input.play_script(script);
+    let mut results = Vec::new();
+    while input.is_playing_script() {
+        input.process_queued_events();
+        results.push(handler.handle_input_simple(viewport, &mapping, &input));
+        viewport.update(0.016);
+        input.end_frame();
+    }
+    results
+}
+
+#[test]
+fn test_scripted_click_reports_click_position() {
+    let mut viewport = test_viewport();
+    let script = input::InputScript::new().click(MouseButton::Left, 120.0, 80.0);
+    let results = run_script(&mut ViewportInputHandler::new(), &mut viewport, script);
+
+    let last = results.last().unwrap();
+    assert!(last.clicked);
+    assert_eq!(last.click_position, Vec2::new(120.0, 80.0));
+}
+
+#[test]
+fn test_scripted_drag_completes_selection_rectangle() {
+    let mut viewport = test_viewport();
+    let script = input::InputScript::new().drag(MouseButton::Left, (100.0, 100.0), (300.0, 200.0), 4);
+    let results = run_script(&mut ViewportInputHandler::new(), &mut viewport, script);
+
+    assert!(results[results.len() - 2].selection_drag_active);
+    let released = results.last().unwrap();
+    assert!(!released.selection_drag_active && !released.clicked);
+    assert_eq!((released.selection_start, released.selection_end), (Vec2::new(100.0, 100.0), Vec2::new(300.0, 200.0)));
+}
+
+#[test]
+fn test_scripted_middle_drag_pans_camera() {
+    let mut viewport = test_viewport();
+    let script = input::InputScript::new().drag(MouseButton::Middle, (400.0, 300.0), (500.0, 300.0), 5);
+    let results = run_script(&mut ViewportInputHandler::new(), &mut viewport, script);
+
+    assert!(results.iter().all(|result| !result.clicked && !result.selection_drag_active));
+    // The grabbed world point follows the cursor 100px right
+    assert!((viewport.camera_position() - Vec2::new(-100.0, 0.0)).length() < 0.01);
+}
+
+#[test]
+fn test_scripted_right_drag_pans_unless_disabled() {
+    let script = || input::InputScript::new().drag(MouseButton::Right, (400.0, 300.0), (400.0, 350.0), 5);
+    let mut viewport = test_viewport();
+    let results = run_script(&mut ViewportInputHandler::new(), &mut viewport, script());
+    assert!(results.iter().all(|result| !result.clicked));
+    assert!((viewport.camera_position() - Vec2::new(0.0, 50.0)).length() < 0.01);
+
+    let mut viewport = test_viewport();
+    let mut handler = ViewportInputHandler::with_config(ViewportInputConfig {
+        right_drag_pan: false,
+        ..Default::default()
+    });
+    run_script(&mut handler, &mut viewport, script());
+    assert_eq!(viewport.camera_position(), Vec2::ZERO);
+}
+
+#[test]
+fn test_alt_drag_pans_only_while_rotation_is_off() {
+    let mut handler = ViewportInputHandler::with_config(ViewportInputConfig {
+        alt_drag_pan: true,
+        ..Default::default()
+    });
+    let mut viewport = test_viewport();
+    let mut state = EditorInputState {
+        rotate_modifier: true,
+        mouse_position: Vec2::new(400.0, 300.0),
+        primary_button: ButtonState { pressed: true, ..Default::default() },
+        ..Default::default()
+    };
+    run_frame(&mut handler, &mut viewport, &state);
+    state.mouse_position.x += 30.0;
+    let result = run_frame(&mut handler, &mut viewport, &state);
+    assert!(!result.selection_drag_active);
+    assert!((viewport.camera_position() - Vec2::new(-30.0, 0.0)).length() < 0.01);
+
+    handler.config.rotation_enabled = true;
+    run_frame(&mut handler, &mut viewport, &state);
+    assert!(handler.is_rotating());
+}
+
+#[test]
+fn test_pan_scroll_mode_pans_and_ctrl_zooms() {
+    let mut handler = ViewportInputHandler::with_config(ViewportInputConfig {
+        scroll_mode: ScrollMode::PanCtrlZoom,
+        ..Default::default()
+    });
+    let mut viewport = test_viewport();
+    let mut state = EditorInputState {
+        mouse_position: Vec2::new(200.0, 200.0),
+        scroll_delta: 1.0,
+        ..Default::default()
+    };
+    run_frame(&mut handler, &mut viewport, &state);
+    assert_eq!(viewport.camera_zoom(), 1.0);
+    assert!((viewport.camera_position() - Vec2::new(0.0, 40.0)).length() < 0.01, "wheel up scrolls up");
+
+    state.toggle_modifier = true;
+    run_frame(&mut handler, &mut viewport, &state);
+    assert!(viewport.camera_zoom() > 1.0);
+}
+
+#[test]
+fn test_smooth_zoom_holds_the_cursor_point_every_frame() {
+    let mut handler = ViewportInputHandler::new();
+    let mut viewport = test_viewport();
+    viewport.set_interpolation_speed(0.15);
+    let cursor = Vec2::new(650.0, 120.0);
+    let grabbed = viewport.screen_to_world(cursor);
+    let state = EditorInputState { mouse_position: cursor, scroll_delta: 3.0, ..Default::default() };
+    run_frame(&mut handler, &mut viewport, &state);
+
+    for _ in 0..60 {
+        assert!((viewport.screen_to_world(cursor) - grabbed).length() < 0.01);
+        viewport.update(0.016);
+    }
+    assert!((viewport.camera_zoom() - 1.1f32.powi(3)).abs() < 1e-3);
+}
@@ -0,0 +1,282 @@
+//! Scene-view overlay toggles and the overlays they switch on.
+//!
+//! The Overlays dropdown in the scene view's top-right corner toggles each
+//! [`ViewportOverlay`] independently. Grid and collider outlines keep their
+//! existing state (`GridRenderer`, the collider toggle); audio ranges and
+//! camera frames are drawn here:
+//!
+//! - audio ranges: a circle of `max_distance` around every spatial
+//!   `AudioSource` — outside it the source is silent
+//! - camera frames: the world area each `Camera` entity shows, from its
+//!   `viewport_size`, `zoom` and `rotation` at the entity's position (the
+//!   main camera's frame is drawn thicker)
+//!
+//! Like the collider overlay, positions come from the entity `Transform2D`.
+
+use common::{Camera, Transform2D};
+use ecs::audio_components::AudioSource;
+use ecs::World;
+use glam::Vec2;
+use ui::{Color, Rect, UIContext};
+
+use crate::viewport::SceneViewport;
+use crate::EditorContext;
+
+/// Number of segments used to approximate an audio range circle.
+const CIRCLE_SEGMENTS: usize = 48;
+/// Outline width of audio ranges and camera frames, in screen pixels.
+const LINE_WIDTH: f32 = 1.0;
+/// Outline width of the main camera's frame, in screen pixels.
+const MAIN_CAMERA_LINE_WIDTH: f32 = 2.0;
+/// Size of the Overlays button.
+const BUTTON_SIZE: Vec2 = Vec2::new(90.0, 22.0);
+/// Width of the open Overlays list.
+const MENU_WIDTH: f32 = 170.0;
+/// Row height of the open Overlays list.
+const MENU_ROW_HEIGHT: f32 = 24.0;
+/// Checkbox size in the Overlays list.
+const CHECKBOX_SIZE: f32 = 14.0;
+
+/// An overlay the scene view can draw over the scene.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ViewportOverlay {
+    /// Grid lines
+    Grid,
+    /// Physics collider outlines
+    Colliders,
+    /// Spatial audio source ranges
+    AudioRanges,
+    /// Camera viewport rectangles
+    CameraFrames,
+}
+
+impl ViewportOverlay {
+    /// Every overlay, in menu order.
+    pub const ALL: [ViewportOverlay; 4] = [Self::Grid, Self::Colliders, Self::AudioRanges, Self::CameraFrames];
+
+    /// Human-readable name (Overlays menu).
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Grid => "Grid",
+            Self::Colliders => "Colliders",
+            Self::AudioRanges => "Audio Ranges",
+            Self::CameraFrames => "Camera Frames",
+        }
+    }
+}
+
+/// Scene-view overlay state not owned elsewhere: the audio range and camera
+/// frame toggles and whether the Overlays list is open.
+#[derive(Debug, Clone, Default)]
+pub struct ViewportOverlays {
+    audio_ranges: bool,
+    camera_frames: bool,
+    menu_open: bool,
+}
+
+impl ViewportOverlays {
+    /// Both overlays off, menu closed.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether the Overlays list is open.
+    pub fn is_menu_open(&self) -> bool {
+        self.menu_open
+    }
+}
+
+impl EditorContext {
+    /// Whether an overlay is drawn in the scene view.
+    pub fn is_overlay_visible(&self, overlay: ViewportOverlay) -> bool {
+        match overlay {
+            ViewportOverlay::Grid => self.is_grid_visible(),
+            ViewportOverlay::Colliders => self.is_colliders_visible(),
+            ViewportOverlay::AudioRanges => self.overlays.audio_ranges,
+            ViewportOverlay::CameraFrames => self.overlays.camera_frames,
+        }
+    }
+
+    /// Turn an overlay on or off.
+    pub fn toggle_overlay(&mut self, overlay: ViewportOverlay) {
+        match overlay {
+            ViewportOverlay::Grid => self.toggle_grid(),
+            ViewportOverlay::Colliders => self.toggle_colliders(),
+            ViewportOverlay::AudioRanges => self.overlays.audio_ranges = !self.overlays.audio_ranges,
+            ViewportOverlay::CameraFrames => self.overlays.camera_frames = !self.overlays.camera_frames,
+        }
+    }
+
+    /// Bounds of the Overlays button in the scene view's top-right corner.
+    pub fn overlay_button_bounds(scene_bounds: Rect) -> Rect {
+        let padding = 8.0;
+        Rect::new(
+            scene_bounds.x + scene_bounds.width - BUTTON_SIZE.x - padding,
+            scene_bounds.y + padding,
+            BUTTON_SIZE.x,
+            BUTTON_SIZE.y,
+        )
+    }
+
+    /// Draw the Overlays button and, while open, its list of checkboxes.
+    /// The list stays open while overlays are toggled; a click outside it
+    /// or on the button closes it. Both swallow mouse input, so clicks on
+    /// them never reach viewport picking.
+    pub fn render_overlay_menu(&mut self, ui: &mut UIContext, scene_bounds: Rect) {
+        let button = Self::overlay_button_bounds(scene_bounds);
+        // The button floats over the scene: keep its clicks out of picking
+        ui.begin_overlay(button);
+        if ui.button("viewport_overlays", "Overlays", button) {
+            self.overlays.menu_open = !self.overlays.menu_open;
+        }
+        ui.end_overlay();
+        if !self.overlays.menu_open {
+            return;
+        }
+
+        let height = ViewportOverlay::ALL.len() as f32 * MENU_ROW_HEIGHT + 8.0;
+        let list = Rect::new(button.x + button.width - MENU_WIDTH, button.y + button.height + 2.0, MENU_WIDTH, height);
+        if ui.mouse_just_pressed() {
+            let mouse = ui.mouse_pos();
+            if !ui.hit_test(mouse, list) && !ui.hit_test(mouse, button) {
+                self.overlays.menu_open = false;
+                return;
+            }
+        }
+
+        ui.begin_overlay(list);
+        ui.rect(list, self.theme.bg_header);
+        ui.rect_border(list, self.theme.border_panel, 1.0, 0.0);
+        for (i, overlay) in ViewportOverlay::ALL.into_iter().enumerate() {
+            let row_y = list.y + 4.0 + i as f32 * MENU_ROW_HEIGHT;
+            let checkbox = Rect::new(list.x + 8.0, row_y + (MENU_ROW_HEIGHT - CHECKBOX_SIZE) * 0.5, CHECKBOX_SIZE, CHECKBOX_SIZE);
+            if ui.checkbox(("viewport_overlay", i), self.is_overlay_visible(overlay), checkbox) {
+                self.toggle_overlay(overlay);
+            }
+            let label = Rect::new(checkbox.x + CHECKBOX_SIZE, row_y, list.width - CHECKBOX_SIZE - 8.0, MENU_ROW_HEIGHT);
+            ui.label_in_bounds_styled(
+                overlay.label(),
+                label,
+                ui::TextAlign::Left,
+                self.theme.text_secondary,
+                self.theme.fonts.small,
+                8.0,
+            );
+        }
+        ui.end_overlay();
+    }
+}
+
+/// World-space `(center, radius)` of every spatial audio source's audible
+/// range. Non-spatial sources play everywhere and have no range.
+pub fn audio_range_circles(world: &World) -> Vec<(Vec2, f32)> {
+    world
+        .entities()
+        .into_iter()
+        .filter_map(|entity| {
+            let source = world.get::<AudioSource>(entity)?;
+            let transform = world.get::<Transform2D>(entity)?;
+            (source.spatial && source.max_distance > 0.0).then_some((transform.position, source.max_distance))
+        })
+        .collect()
+}
+
+/// World-space corners of the area `camera` shows from `position`
+/// (counter-clockwise from bottom-left). Matches the camera's view
+/// transform: the frame shrinks as zoom grows and turns against the
+/// camera's rotation.
+pub fn camera_frame_corners(position: Vec2, camera: &Camera) -> [Vec2; 4] {
+    let zoom = if camera.zoom > 0.0 { camera.zoom } else { 1.0 };
+    let half = camera.viewport_size * 0.5 / zoom;
+    let (sin, cos) = (-camera.rotation).sin_cos();
+    let corner = |local: Vec2| position + Vec2::new(local.x * cos - local.y * sin, local.x * sin + local.y * cos);
+    [
+        corner(Vec2::new(-half.x, -half.y)),
+        corner(Vec2::new(half.x, -half.y)),
+        corner(Vec2::new(half.x, half.y)),
+        corner(Vec2::new(-half.x, half.y)),
+    ]
+}
+
+/// Draw the audio range of every spatial audio source, clipped to the
+/// scene-view `bounds`.
+pub fn render_audio_range_overlay(ui: &mut UIContext, world: &World, viewport: &SceneViewport, color: Color, bounds: Rect) {
+    ui.push_clip_rect(bounds);
+    for (center, radius) in audio_range_circles(world) {
+        let point = |i: usize| {
+            let angle = i as f32 / CIRCLE_SEGMENTS as f32 * std::f32::consts::TAU;
+            viewport.world_to_screen(center + Vec2::from_angle(angle) * radius)
+        };
+        for i in 0..CIRCLE_SEGMENTS {
+            ui.line(point(i), point(i + 1), color, LINE_WIDTH);
+        }
+    }
+    ui.pop_clip_rect();
+}
+
+/// Draw the frame of every `Camera` entity, clipped to the scene-view
+/// `bounds`.
+pub fn render_camera_frame_overlay(ui: &mut UIContext, world: &World, viewport: &SceneViewport, color: Color, bounds: Rect) {
+    ui.push_clip_rect(bounds);
+    for entity in world.entities() {
+        let Some(camera) = world.get::<Camera>(entity) else { continue };
+        let Some(transform) = world.get::<Transform2D>(entity) else { continue };
+        let width = if camera.is_main_camera { MAIN_CAMERA_LINE_WIDTH } else { LINE_WIDTH };
+        let corners = camera_frame_corners(transform.position, camera);
+        for i in 0..corners.len() {
+            let (start, end) = (corners[i], corners[(i + 1) % corners.len()]);
+            ui.line(viewport.world_to_screen(start), viewport.world_to_screen(end), color, width);
+        }
+    }
+    ui.pop_clip_rect();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_vec2_near(actual: Vec2, expected: Vec2) {
+        assert!((actual - expected).length() < 1e-3, "expected {expected:?}, got {actual:?}");
+    }
+
+    #[test]
+    fn test_only_spatial_audio_sources_have_ranges() {
+        let mut world = World::new();
+        let spatial = world.create_entity();
+        world.add_component(&spatial, Transform2D::new(Vec2::new(10.0, 20.0))).unwrap();
+        world.add_component(&spatial, AudioSource::new(1).with_spatial_settings(300.0, 50.0, 1.0)).unwrap();
+        let ambient = world.create_entity();
+        world.add_component(&ambient, Transform2D::new(Vec2::ZERO)).unwrap();
+        world.add_component(&ambient, AudioSource::new(2)).unwrap();
+
+        assert_eq!(audio_range_circles(&world), vec![(Vec2::new(10.0, 20.0), 300.0)]);
+    }
+
+    #[test]
+    fn test_camera_frame_follows_zoom_and_rotation() {
+        let camera = Camera::new(Vec2::ZERO, Vec2::new(800.0, 600.0)).with_zoom(2.0);
+        let corners = camera_frame_corners(Vec2::new(100.0, 0.0), &camera);
+        assert_vec2_near(corners[0], Vec2::new(-100.0, -150.0));
+        assert_vec2_near(corners[2], Vec2::new(300.0, 150.0));
+
+        // A camera turned a quarter turn shows the world turned back
+        let turned = camera.with_rotation(std::f32::consts::FRAC_PI_2);
+        let corners = camera_frame_corners(Vec2::ZERO, &turned);
+        assert_vec2_near(corners[0], Vec2::new(-150.0, 200.0));
+    }
+
+    #[test]
+    fn test_overlay_toggles_route_to_their_owners() {
+        let mut editor = EditorContext::new();
+        let grid = editor.is_grid_visible();
+        editor.toggle_overlay(ViewportOverlay::Grid);
+        assert_eq!(editor.is_grid_visible(), !grid);
+        editor.toggle_overlay(ViewportOverlay::Colliders);
+        assert!(!editor.is_colliders_visible());
+
+        assert!(!editor.is_overlay_visible(ViewportOverlay::AudioRanges));
+        editor.toggle_overlay(ViewportOverlay::AudioRanges);
+        assert!(editor.is_overlay_visible(ViewportOverlay::AudioRanges));
+        assert!(!editor.is_overlay_visible(ViewportOverlay::CameraFrames));
+    }
+}
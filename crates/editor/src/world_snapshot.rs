@@ -7,12 +7,17 @@
 //! **Known limitation:** Custom component types not in the known list are
 //! lost on restore. Acceptable for Phase 1C.
 
-use ecs::{EntityId, World};
+use ecs::{EntityId, Guid, SimulationTick, World};
 use ecs::behavior::{Behavior, BehaviorState, EntityTag};
-use ecs::hierarchy::{Children, GlobalTransform2D, Parent};
-use ecs::sprite_components::{Name, Sprite, SpriteAnimation};
+use ecs::hierarchy::{Children, GlobalTransform2D, Group, Parent, SceneInstanceRef};
+use ecs::animation::Animator;
+use ecs::sprite_components::{Name, Sprite, SpriteAnimation, SpriteMask};
+use ecs::trail::Trail2D;
+use ecs::world_text::WorldText;
+use ecs::sprite_sort::SortBias;
 use ecs::audio_components::{AudioListener, AudioSource};
 use physics::components::{Collider, RigidBody};
+use physics::{AreaForce, Buoyancy, Drag};
 
 /// Snapshot of a single entity's components.
 struct EntitySnapshot {
@@ -22,12 +27,21 @@ struct EntitySnapshot {
     global_transform: Option<GlobalTransform2D>,
     camera: Option<common::Camera>,
     name: Option<Name>,
+    guid: Option<Guid>,
     // Rendering
     sprite: Option<Sprite>,
     sprite_animation: Option<SpriteAnimation>,
+    sprite_mask: Option<SpriteMask>,
+    animator: Option<Animator>,
+    trail: Option<Trail2D>,
+    world_text: Option<WorldText>,
+    sort_bias: Option<SortBias>,
     // Physics
     rigid_body: Option<RigidBody>,
     collider: Option<Collider>,
+    area_force: Option<AreaForce>,
+    buoyancy: Option<Buoyancy>,
+    drag: Option<Drag>,
     // Audio
     audio_source: Option<AudioSource>,
     audio_listener: Option<AudioListener>,
@@ -38,6 +52,8 @@ struct EntitySnapshot {
     // Hierarchy
     parent: Option<Parent>,
     children: Option<Children>,
+    group: Option<Group>,
+    scene_instance_ref: Option<SceneInstanceRef>,
 }
 
 impl EntitySnapshot {
@@ -49,10 +65,19 @@ impl EntitySnapshot {
             global_transform: world.get::<GlobalTransform2D>(id).cloned(),
             camera: world.get::<common::Camera>(id).cloned(),
             name: world.get::<Name>(id).cloned(),
+            guid: world.guid_of(id),
             sprite: world.get::<Sprite>(id).cloned(),
             sprite_animation: world.get::<SpriteAnimation>(id).cloned(),
+            sprite_mask: world.get::<SpriteMask>(id).cloned(),
+            animator: world.get::<Animator>(id).cloned(),
+            trail: world.get::<Trail2D>(id).cloned(),
+            world_text: world.get::<WorldText>(id).cloned(),
+            sort_bias: world.get::<SortBias>(id).copied(),
             rigid_body: world.get::<RigidBody>(id).cloned(),
             collider: world.get::<Collider>(id).cloned(),
+            area_force: world.get::<AreaForce>(id).cloned(),
+            buoyancy: world.get::<Buoyancy>(id).cloned(),
+            drag: world.get::<Drag>(id).cloned(),
             audio_source: world.get::<AudioSource>(id).cloned(),
             audio_listener: world.get::<AudioListener>(id).cloned(),
             behavior: world.get::<Behavior>(id).cloned(),
@@ -60,6 +85,32 @@ impl EntitySnapshot {
             entity_tag: world.get::<EntityTag>(id).cloned(),
             parent: world.get::<Parent>(id).cloned(),
             children: world.get::<Children>(id).cloned(),
+            group: world.get::<Group>(id).cloned(),
+            scene_instance_ref: world.get::<SceneInstanceRef>(id).cloned(),
+        }
+    }
+
+    /// JSON value of an inspectable component by registry name (the
+    /// `StoredComponent` variant name), if it was captured.
+    fn component_json(&self, type_name: &str) -> Option<serde_json::Value> {
+        fn json<T: serde::Serialize>(c: &Option<T>) -> Option<serde_json::Value> {
+            c.as_ref().and_then(|c| serde_json::to_value(c).ok())
+        }
+        match type_name {
+            "Transform2D" => json(&self.transform),
+            "Camera" => json(&self.camera),
+            "Sprite" => json(&self.sprite),
+            "SpriteAnimation" => json(&self.sprite_animation),
+            "RigidBody" => json(&self.rigid_body),
+            "Collider" => json(&self.collider),
+            "AreaForce" => json(&self.area_force),
+            "Buoyancy" => json(&self.buoyancy),
+            "Drag" => json(&self.drag),
+            "AudioSource" => json(&self.audio_source),
+            "AudioListener" => json(&self.audio_listener),
+            "Behavior" => json(&self.behavior),
+            "EntityTag" => json(&self.entity_tag),
+            _ => None,
         }
     }
 
@@ -70,10 +121,19 @@ impl EntitySnapshot {
         if let Some(c) = self.global_transform { world.add_component(&id, c).ok(); }
         if let Some(c) = self.camera { world.add_component(&id, c).ok(); }
         if let Some(c) = self.name { world.add_component(&id, c).ok(); }
+        if let Some(c) = self.guid { world.add_component(&id, c).ok(); }
         if let Some(c) = self.sprite { world.add_component(&id, c).ok(); }
         if let Some(c) = self.sprite_animation { world.add_component(&id, c).ok(); }
+        if let Some(c) = self.sprite_mask { world.add_component(&id, c).ok(); }
+        if let Some(c) = self.animator { world.add_component(&id, c).ok(); }
+        if let Some(c) = self.trail { world.add_component(&id, c).ok(); }
+        if let Some(c) = self.world_text { world.add_component(&id, c).ok(); }
+        if let Some(c) = self.sort_bias { world.add_component(&id, c).ok(); }
         if let Some(c) = self.rigid_body { world.add_component(&id, c).ok(); }
         if let Some(c) = self.collider { world.add_component(&id, c).ok(); }
+        if let Some(c) = self.area_force { world.add_component(&id, c).ok(); }
+        if let Some(c) = self.buoyancy { world.add_component(&id, c).ok(); }
+        if let Some(c) = self.drag { world.add_component(&id, c).ok(); }
         if let Some(c) = self.audio_source { world.add_component(&id, c).ok(); }
         if let Some(c) = self.audio_listener { world.add_component(&id, c).ok(); }
         if let Some(c) = self.behavior { world.add_component(&id, c).ok(); }
@@ -81,6 +141,8 @@ impl EntitySnapshot {
         if let Some(c) = self.entity_tag { world.add_component(&id, c).ok(); }
         if let Some(c) = self.parent { world.add_component(&id, c).ok(); }
         if let Some(c) = self.children { world.add_component(&id, c).ok(); }
+        if let Some(c) = self.group { world.add_component(&id, c).ok(); }
+        if let Some(c) = self.scene_instance_ref { world.add_component(&id, c).ok(); }
     }
 }
 
@@ -90,6 +152,8 @@ impl EntitySnapshot {
 /// consumed by `WorldSnapshot::restore()` when stopping play mode.
 pub struct WorldSnapshot {
     snapshots: Vec<EntitySnapshot>,
+    /// Simulation tick the state belongs to (restored with it)
+    tick: u64,
 }
 
 impl WorldSnapshot {
@@ -99,14 +163,17 @@ impl WorldSnapshot {
             .into_iter()
             .map(|id| EntitySnapshot::capture(world, id))
             .collect();
-        Self { snapshots }
+        Self { snapshots, tick: world.simulation_tick() }
     }
 
     /// Restore the captured state, replacing the current world contents.
     ///
-    /// Clears all entities and components, then recreates them from the snapshot.
+    /// Clears all entities and components, then recreates them from the
+    /// snapshot. The simulation tick rewinds to the captured one, so events
+    /// after the restore line up with the restored state.
     pub fn restore(self, world: &mut World) {
         world.clear();
+        world.insert_resource(SimulationTick(self.tick));
 
         for snapshot in self.snapshots {
             world.create_entity_with_id(snapshot.id);
@@ -114,6 +181,22 @@ impl WorldSnapshot {
         }
     }
 
+    /// JSON value of `entity`'s inspectable component `type_name` as it was
+    /// when the snapshot was taken — the edit-state baseline that play-mode
+    /// changes are diffed against. `None` if the entity or component was not
+    /// captured.
+    pub fn component_json(&self, entity: EntityId, type_name: &str) -> Option<serde_json::Value> {
+        self.snapshots
+            .iter()
+            .find(|s| s.id == entity)
+            .and_then(|s| s.component_json(type_name))
+    }
+
+    /// Simulation tick at capture time.
+    pub fn tick(&self) -> u64 {
+        self.tick
+    }
+
     /// Number of entities in the snapshot.
     pub fn entity_count(&self) -> usize {
         self.snapshots.len()
@@ -277,4 +360,16 @@ mod tests {
         let tag = world.get::<EntityTag>(entity).unwrap();
         assert!(tag.matches("hero"));
     }
+
+    #[test]
+    fn test_snapshot_restore_rewinds_simulation_tick() {
+        let mut world = World::new();
+        world.insert_resource(SimulationTick(120));
+        let snapshot = WorldSnapshot::capture(&world);
+        assert_eq!(snapshot.tick(), 120);
+
+        world.insert_resource(SimulationTick(300));
+        snapshot.restore(&mut world);
+        assert_eq!(world.simulation_tick(), 120);
+    }
 }
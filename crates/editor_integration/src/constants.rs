@@ -8,6 +8,17 @@ use glam::Vec2;
 /// Default scene file path used until a file picker exists (Phase 2+).
 pub(crate) const DEFAULT_SCENE_PATH: &str = "scenes/scene.ron";
 
+/// Previous versions kept when saving a scene (`<file>.bak1` is newest).
+pub(crate) const SCENE_BACKUP_COUNT: usize = 3;
+
+/// Editor preferences file (camera, grid, viewport navigation), loaded at
+/// startup and written on exit.
+pub(crate) const EDITOR_PREFERENCES_PATH: &str = "editor_preferences.json";
+
+/// Dock layout file (panel positions, sizes and visibility), loaded at
+/// startup and written on exit.
+pub(crate) const EDITOR_LAYOUT_PATH: &str = "editor_layout.json";
+
 /// Minimum window width for the editor to be usable.
 pub(crate) const MIN_EDITOR_WINDOW_WIDTH: u32 = 1024;
 
@@ -9,6 +9,7 @@ use engine_core::Game;
 
 use crate::constants::DEFAULT_SCENE_PATH;
 use crate::entity_ops;
+use crate::panel_renderer::flatten_selected_subtree;
 
 use super::EditorGame;
 
@@ -43,6 +44,10 @@ impl<G: Game> EditorGame<G> {
             "Duplicate" if !self.editor.is_playing() => {
                 self.duplicate_selected_entities(ctx);
             }
+            "Cut" if !self.editor.is_playing() => self.cut_selected_entities(ctx),
+            "Copy" => self.copy_selected_entities(ctx),
+            "Paste" if !self.editor.is_playing() => self.paste_entities(ctx),
+            "Keep Play Changes for Selection" => self.keep_play_changes_for_selection(),
             "Undo" if !self.editor.is_playing() => {
                 if let Some(name) = self.command_history.undo_name() {
                     self.editor.status_bar.show_message(format!("Undo: {}", name));
@@ -66,23 +71,119 @@ impl<G: Game> EditorGame<G> {
                 let path = PathBuf::from(DEFAULT_SCENE_PATH);
                 self.load_scene_with_feedback(ctx.world, ctx.assets, &path);
             }
+            "New Tab" if !self.editor.in_play_session() => self.open_scene_tab(ctx.world),
+            "Close Tab" if !self.editor.in_play_session() => {
+                let active = self.scene_tabs.active_index();
+                self.close_scene_tab(active, ctx.world);
+            }
             "Save" => {
                 if let Err(e) = self.save_scene(ctx.world, ctx.assets) {
-                    self.editor.status_bar.show_error(format!("Save failed: {}", e));
-                    log::error!("Failed to save: {}", e);
+                    self.report_save_error(&e);
                 }
             }
             "Save As..." => {
                 let path = PathBuf::from(DEFAULT_SCENE_PATH);
                 if let Err(e) = self.save_scene_as(ctx.world, ctx.assets, path) {
-                    self.editor.status_bar.show_error(format!("Save failed: {}", e));
-                    log::error!("Failed to save: {}", e);
+                    self.report_save_error(&e);
                 }
             }
-            "Exit" => std::process::exit(0),
+            "Validate Scene" => self.validate_current_scene(ctx.world, ctx.assets),
+            "Compare With Saved" => self.compare_with_saved(ctx.world, ctx.assets),
+            "Export Streaming Chunks" => self.export_streaming_chunks(ctx.world, ctx.assets),
+            "Exit" => {
+                // Don't cut a background save short
+                self.finish_pending_save();
+                std::process::exit(0)
+            }
             "Toggle Grid" => self.editor.toggle_grid(),
             "Toggle Colliders" => self.editor.toggle_colliders(),
-            "Scene View" | "Inspector" | "Hierarchy" | "Asset Browser" | "Console" => {
+            "Toggle Rulers" => self.editor.rulers.toggle(),
+            "Cycle Color Scheme" => {
+                let preset = self.editor.cycle_color_scheme();
+                self.editor.status_bar.show_message(format!("Color scheme: {}", preset.label()));
+            }
+            "Cycle Pan Mode" => {
+                let mode = self.editor.viewport_input.config.cycle_pan_mode();
+                self.editor.status_bar.show_message(format!("Pan: {}", mode.label()));
+            }
+            "Toggle Zoom to Cursor" => {
+                let config = &mut self.editor.viewport_input.config;
+                config.zoom_to_cursor = !config.zoom_to_cursor;
+                let target = if config.zoom_to_cursor { "cursor" } else { "view center" };
+                self.editor.status_bar.show_message(format!("Zoom to {}", target));
+            }
+            "Toggle View Rotation" => {
+                let config = &mut self.editor.viewport_input.config;
+                config.rotation_enabled = !config.rotation_enabled;
+                let state = if config.rotation_enabled { "on (Alt+Drag)" } else { "off" };
+                self.editor.status_bar.show_message(format!("View rotation {}", state));
+            }
+            "Toggle Trackpad Gestures" => {
+                let config = &mut self.editor.viewport_input.config;
+                config.trackpad_gestures = !config.trackpad_gestures;
+                let state = if config.trackpad_gestures { "on" } else { "off" };
+                self.editor.status_bar.show_message(format!("Trackpad gestures {}", state));
+            }
+            "Toggle Right-Drag Pan" => {
+                let config = &mut self.editor.viewport_input.config;
+                config.right_drag_pan = !config.right_drag_pan;
+                let state = if config.right_drag_pan { "on" } else { "off" };
+                self.editor.status_bar.show_message(format!("Right-drag pan {}", state));
+            }
+            "Toggle Alt-Drag Pan" => {
+                let config = &mut self.editor.viewport_input.config;
+                config.alt_drag_pan = !config.alt_drag_pan;
+                let state = match (config.alt_drag_pan, config.rotation_enabled) {
+                    (false, _) => "off",
+                    (true, false) => "on",
+                    (true, true) => "on (while view rotation is off)",
+                };
+                self.editor.status_bar.show_message(format!("Alt-drag pan {}", state));
+            }
+            "Cycle Scroll Mode" => {
+                let mode = self.editor.viewport_input.config.cycle_scroll_mode();
+                self.editor.status_bar.show_message(format!("Scroll: {}", mode.label()));
+            }
+            "Toggle Smooth Zoom" => {
+                let config = &mut self.editor.viewport_input.config;
+                config.smooth_zoom = !config.smooth_zoom;
+                let state = if config.smooth_zoom { "on" } else { "off" };
+                self.editor.status_bar.show_message(format!("Smooth zoom {}", state));
+            }
+            "Reset View Rotation" => self.editor.reset_view_rotation(),
+            "Maximize Scene View" => {
+                self.editor.dock_area.toggle_maximized(editor::PanelId::SCENE_VIEW);
+            }
+            "Fullscreen Play Preview" => self.toggle_fullscreen_play(),
+            "Animation Layout" | "Level Design Layout" | "Reset Layout" => {
+                if let Some(preset) = editor::LayoutPreset::from_menu_label(&action) {
+                    self.editor.dock_area.apply_preset(preset);
+                    self.editor.status_bar.show_message(format!("Layout: {}", preset.label()));
+                }
+            }
+            "World Stats" => {
+                self.editor.dock_area.toggle_panel_visible(editor::PanelId::WORLD_STATS);
+            }
+            "Layers" => {
+                self.editor.dock_area.toggle_panel_visible(editor::PanelId::LAYERS);
+            }
+            "History" => {
+                self.editor.dock_area.toggle_panel_visible(editor::PanelId::HISTORY);
+            }
+            "Scene Settings" => {
+                self.editor.dock_area.toggle_panel_visible(editor::PanelId::SCENE_SETTINGS);
+            }
+            "Profiler" => {
+                self.editor.dock_area.toggle_panel_visible(editor::PanelId::PROFILER);
+            }
+            "Console" => {
+                self.editor.dock_area.toggle_panel_visible(editor::PanelId::CONSOLE);
+            }
+            "Group Selection" if !self.editor.is_playing() => self.group_selected_entities(ctx),
+            "Flatten Subtree" if !self.editor.is_playing() => {
+                flatten_selected_subtree(&mut self.editor, ctx.world, &mut self.command_history);
+            }
+            "Scene View" | "Inspector" | "Hierarchy" | "Asset Browser" => {
                 log::info!("Toggle panel: {}", action);
             }
             _ => log::info!("Unhandled action: {}", action),
@@ -127,4 +228,62 @@ impl<G: Game> EditorGame<G> {
             }
         }
     }
+
+    /// Copy the selected entities (with their children) to the entity
+    /// clipboard.
+    pub(super) fn copy_selected_entities(&mut self, ctx: &mut GameContext) {
+        let selected: Vec<ecs::EntityId> = self.editor.selection.selected().collect();
+        match self.editor.entity_clipboard.copy(ctx.world, &selected) {
+            Ok(count) => self.editor.status_bar.show_message(format!("Copied {} entities", count)),
+            Err(e) => self.editor.status_bar.show_message(e),
+        }
+    }
+
+    /// Copy the selected entities, then delete them as one undoable action.
+    pub(super) fn cut_selected_entities(&mut self, ctx: &mut GameContext) {
+        let selected: Vec<ecs::EntityId> = self.editor.selection.selected().collect();
+        match self.editor.entity_clipboard.cut(ctx.world, &selected) {
+            Ok(cmd) => {
+                self.command_history.execute(cmd, ctx.world);
+                self.editor.selection.clear();
+                self.editor.mark_dirty();
+            }
+            Err(e) => self.editor.status_bar.show_message(e),
+        }
+    }
+
+    /// Paste the entity clipboard into the current scene as new roots and
+    /// select them.
+    pub(super) fn paste_entities(&mut self, ctx: &mut GameContext) {
+        match self.editor.entity_clipboard.paste(ctx.world, None) {
+            Ok((roots, cmd)) => {
+                self.command_history.push_already_executed(Box::new(cmd));
+                self.editor.selection.select_multiple(roots);
+                self.editor.mark_dirty();
+            }
+            Err(e) => self.editor.status_bar.show_message(e),
+        }
+    }
+
+    /// Move the selected entities into a new group node as one undoable
+    /// action, then select the group.
+    pub(super) fn group_selected_entities(&mut self, ctx: &mut GameContext) {
+        let mut selected: Vec<ecs::EntityId> = self.editor.selection.selected().collect();
+        if selected.is_empty() {
+            self.editor.status_bar.show_message("Select the entities to group");
+            return;
+        }
+        // Hierarchy order (the selection set is unordered)
+        selected.sort_by_key(|e| e.value());
+        self.entity_counter += 1;
+        let name = format!("Group {}", self.entity_counter);
+        let mut cmd = editor::commands::GroupEntitiesCommand::new(selected, name);
+        cmd.execute(ctx.world);
+        let Some(group) = cmd.group() else {
+            return;
+        };
+        self.command_history.push_already_executed(Box::new(cmd));
+        self.editor.selection.select(group);
+        self.editor.mark_dirty();
+    }
 }
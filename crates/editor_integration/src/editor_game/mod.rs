@@ -7,9 +7,15 @@
 //!
 //! The wrapper is split by feature:
 //! - [`menu_actions`] — menu bar rendering and action dispatch
+//! - [`profiler`] — play-mode frame samples for the Profiler panel
 //! - [`scene_io`] — scene save/load/new
+//! - [`scene_tabs`] — multi-scene tabs (park/unpark per-tab worlds)
 //! - [`shortcuts`] — keyboard shortcuts and play state transitions
-//! - [`viewport_interaction`] — viewport picking and gizmo dragging
+//! - [`viewport_interaction`] — click-to-focus, viewport picking and gizmo
+//!   dragging
+
+use std::collections::HashMap;
+use std::process::ExitCode;
 
 use glam::Vec2;
 use winit::keyboard::KeyCode;
@@ -18,15 +24,20 @@ use ecs::System;
 use editor::EditorContext;
 use editor::world_snapshot::WorldSnapshot;
 use engine_core::contexts::{GameContext, RenderContext};
-use engine_core::scene_data::PhysicsSettings;
-use engine_core::Game;
+use engine_core::scene_data::{PhysicsMaterialData, PhysicsSettings, StreamingSettings};
+use engine_core::{EngineBuilder, Game};
 use engine_core::GameConfig;
+use engine_core::HeadlessScript;
 
-use crate::constants::{MIN_EDITOR_WINDOW_HEIGHT, MIN_EDITOR_WINDOW_WIDTH};
+use crate::constants::{
+    EDITOR_LAYOUT_PATH, EDITOR_PREFERENCES_PATH, MIN_EDITOR_WINDOW_HEIGHT, MIN_EDITOR_WINDOW_WIDTH,
+};
 use crate::panel_renderer;
 
 mod menu_actions;
+mod profiler;
 mod scene_io;
+mod scene_tabs;
 mod shortcuts;
 mod viewport_interaction;
 
@@ -34,23 +45,46 @@ mod viewport_interaction;
 struct EditorGame<G: Game> {
     inner: G,
     editor: EditorContext,
-    transform_system: ecs::TransformHierarchySystem,
+    /// The world was replaced wholesale (Stop, tab switch): reset the
+    /// engine's transform propagation (`ctx.transforms`) before its next run.
+    reset_transforms: bool,
     font_loaded: bool,
     /// Snapshot of the world state captured when entering play mode.
     world_snapshot: Option<WorldSnapshot>,
     /// Auto-incrementing counter for unique entity names.
     entity_counter: u32,
-    /// Undo/redo command history for editor actions.
+    /// Undo/redo command history for editor actions. During a play session
+    /// this is a throwaway history for edits to the play world.
     command_history: editor::CommandHistory,
+    /// The edit-mode history, set aside while a play session runs and put
+    /// back on Stop (its commands target the restored edit world).
+    edit_history: Option<editor::CommandHistory>,
     /// Initial transform captured when gizmo drag starts.
     gizmo_drag_start: Option<common::Transform2D>,
     /// Initial collider captured when gizmo drag starts (scale tool resizes
     /// colliders alongside the transform — physics ignores Transform2D.scale).
     gizmo_drag_start_collider: Option<physics::components::Collider>,
-    /// Physics settings for scene serialization.
+    /// Collider captured when a collider handle drag starts.
+    collider_drag_start: Option<physics::components::Collider>,
+    /// Physics settings for scene serialization (Scene Settings panel).
     physics_settings: Option<PhysicsSettings>,
+    /// A play session started: apply `physics_settings` to the engine's
+    /// physics before its first step.
+    apply_physics_settings: bool,
+    /// The scene's own physics materials, written back on save.
+    scene_materials: HashMap<String, PhysicsMaterialData>,
+    /// World-streaming chunk layout, written back on save and drawn as
+    /// chunk bounds in the scene view.
+    streaming_settings: Option<StreamingSettings>,
     /// Editing pan/zoom saved while a play session runs (restored on Stop).
     editing_camera: Option<(Vec2, f32)>,
+    /// Open scenes; background tabs park their world and editing state here.
+    scene_tabs: editor::SceneTabs<scene_tabs::ParkedScene>,
+    /// Scene save running on a worker thread, polled each frame.
+    pending_save: Option<engine_core::SaveHandle>,
+    /// Seconds the inner game's `update` took last frame, if it ran;
+    /// recorded into the Profiler next frame with the engine's timings.
+    game_update_time: Option<f32>,
 }
 
 impl<G: Game> EditorGame<G> {
@@ -58,15 +92,23 @@ impl<G: Game> EditorGame<G> {
         Self {
             inner: game,
             editor: EditorContext::new(),
-            transform_system: ecs::TransformHierarchySystem::new(),
+            reset_transforms: false,
             font_loaded: false,
             world_snapshot: None,
             entity_counter: 0,
             command_history: editor::CommandHistory::new(),
+            edit_history: None,
             gizmo_drag_start: None,
             gizmo_drag_start_collider: None,
+            collider_drag_start: None,
             physics_settings: None,
+            apply_physics_settings: false,
+            scene_materials: HashMap::new(),
+            streaming_settings: None,
             editing_camera: None,
+            scene_tabs: editor::SceneTabs::new(),
+            pending_save: None,
+            game_update_time: None,
         }
     }
 
@@ -99,6 +141,8 @@ impl<G: Game> EditorGame<G> {
         let theme = &self.editor.theme;
         if let Some(action) = self.editor.play_controls.render(ctx.ui, play_state, theme) {
             if self.handle_play_action(action, ctx.world) {
+                // Play's state transitions don't survive the snapshot restore
+                ctx.states.clear();
                 self.inner.on_play_stopped(ctx);
             }
         }
@@ -107,33 +151,140 @@ impl<G: Game> EditorGame<G> {
     /// Render the dock panel frames and their content. Returns the panel
     /// content areas for later viewport/gizmo hit testing.
     fn render_panels(&mut self, ctx: &mut GameContext) -> Vec<(editor::PanelId, common::Rect)> {
+        // The inspector lists the selection's play-mode changes (the ones
+        // that can be kept across Stop) — diff against the Play snapshot.
+        if let Some(snapshot) = &self.world_snapshot {
+            let primary = self.editor.selection.primary();
+            self.editor.play_changes.refresh_selection(snapshot, ctx.world, primary);
+        }
+
         let theme = &self.editor.theme;
         let content_areas = self.editor.dock_area.render(ctx.ui, theme);
         self.editor.dock_area.handle_resize(ctx.ui);
+        self.render_scene_tabs(ctx);
 
         for (panel_id, bounds) in content_areas.clone() {
             ctx.ui.push_clip_rect(ui::Rect::new(bounds.x, bounds.y, bounds.width, bounds.height));
-            panel_renderer::render_panel_content(
-                &mut self.editor, ctx, panel_id, bounds, &mut self.command_history,
+            let editor = &mut self.editor;
+            let custom = editor.plugin_panels.render(
+                panel_id, ctx.ui, ctx.world, &mut editor.selection, bounds, &editor.theme,
             );
+            if !custom {
+                if panel_id == editor::PanelId::SCENE_SETTINGS {
+                    panel_renderer::render_scene_settings(&mut self.editor, ctx, bounds, &mut self.physics_settings);
+                } else {
+                    panel_renderer::render_panel_content(
+                        &mut self.editor, ctx, panel_id, bounds, &mut self.command_history,
+                    );
+                }
+            }
             ctx.ui.pop_clip_rect();
         }
+        self.render_chunk_bounds(ctx, &content_areas);
+        self.render_viewport_guides(ctx, &content_areas);
 
         content_areas
     }
 
+    /// Outline the streaming chunks of a streamed scene over the scene view
+    /// (not while Playing — the game's own view is shown then).
+    fn render_chunk_bounds(&self, ctx: &mut GameContext, content_areas: &[(editor::PanelId, common::Rect)]) {
+        let Some(streaming) = self.streaming_settings else {
+            return;
+        };
+        if self.editor.is_playing() {
+            return;
+        }
+        let Some(&(_, bounds)) = content_areas.iter().find(|(id, _)| *id == editor::PanelId::SCENE_VIEW) else {
+            return;
+        };
+        let theme = &self.editor.theme;
+        editor::render_chunk_overlay(
+            ctx.ui,
+            &self.editor.viewport,
+            streaming.chunk_size,
+            theme.colors.chunk_bounds,
+            theme.fonts.small,
+            bounds,
+        );
+    }
+
+    /// Rulers and the measure-tool span over the scene view (not while
+    /// Playing), and the status-bar readout of the world position under the
+    /// mouse.
+    fn render_viewport_guides(&mut self, ctx: &mut GameContext, content_areas: &[(editor::PanelId, common::Rect)]) {
+        let scene = content_areas.iter().find(|(id, _)| *id == editor::PanelId::SCENE_VIEW);
+        let Some(&(_, bounds)) = scene else {
+            self.editor.status_bar.set_cursor_world(None);
+            return;
+        };
+        let mouse = ctx.ui.mouse_pos();
+        let hovered = bounds.contains(mouse);
+        let cursor_world = hovered.then(|| self.editor.screen_to_world(mouse));
+        self.editor.status_bar.set_cursor_world(cursor_world);
+        if self.editor.is_playing() {
+            return;
+        }
+
+        let theme = &self.editor.theme;
+        if self.editor.current_tool() == editor::EditorTool::Measure {
+            if let Some(measurement) = self.editor.measure.measurement() {
+                editor::render_measure_overlay(
+                    ctx.ui,
+                    &self.editor.viewport,
+                    measurement,
+                    theme.colors.measure,
+                    theme.fonts.small,
+                    bounds,
+                );
+            }
+        }
+        self.editor.rulers.render(ctx.ui, &self.editor.viewport, bounds, theme, hovered.then_some(mouse));
+    }
+
+    /// Hand the entities hidden with the hierarchy eye toggle to the sprite
+    /// extractors. A running game draws everything.
+    fn sync_hidden_entities(&mut self, ctx: &mut GameContext) {
+        if self.editor.is_playing() || self.editor.visibility.is_empty() {
+            ctx.extractors.set_hidden([]);
+        } else {
+            ctx.extractors.set_hidden(self.editor.visibility.hidden_entities(ctx.world));
+        }
+    }
+
     /// Delegate the frame to the inner game — only while Playing, clipped to
-    /// the scene view.
+    /// the scene view. While the editor has input focus the game sees no
+    /// keyboard or mouse input (gamepads still reach it).
     fn update_inner_game(&mut self, ctx: &mut GameContext) {
-        if !self.editor.is_playing() {
+        // Paused, a queued frame step runs one update with a fixed delta
+        // and steps physics exactly once itself (the engine's step skips
+        // while paused)
+        let stepping = self.editor.is_paused() && self.editor.frame_stepper.take_step();
+        if !self.editor.is_playing() && !stepping {
             return;
         }
+        if stepping {
+            let timestep = ctx.physics.system().map_or(ctx.time.fixed_timestep(), |system| system.fixed_timestep());
+            ctx.time = ctx.time.single_step(timestep);
+            ctx.delta_time = timestep;
+        }
         if let Some(scene_bounds) = self.editor.scene_view_bounds() {
             ctx.ui.push_clip_rect(ui::Rect::new(
                 scene_bounds.x, scene_bounds.y, scene_bounds.width, scene_bounds.height,
             ));
         }
+        let suspended = (!self.editor.input_focus.game_has_focus()).then(|| ctx.input.suspend_keyboard_and_mouse());
+        let started = common::time::Instant::now();
         self.inner.update(ctx);
+        self.game_update_time = Some(started.elapsed().as_secs_f32());
+        if stepping {
+            ctx.world.apply_commands();
+            ctx.physics.step_once(ctx.world);
+        }
+        self.editor.frame_stepper.record_frame();
+        if let Some(suspended) = suspended {
+            ctx.input.resume_keyboard_and_mouse(suspended);
+        }
         if self.editor.scene_view_bounds().is_some() {
             ctx.ui.pop_clip_rect();
         }
@@ -141,10 +292,16 @@ impl<G: Game> EditorGame<G> {
 
     /// Update status bar stats and render it.
     fn render_status_bar(&mut self, ctx: &mut GameContext, window_size: Vec2) {
-        let fps = if ctx.delta_time > 0.0 { 1.0 / ctx.delta_time } else { 0.0 };
+        // Real frame time: the FPS readout and message fade ignore the time scale.
+        let frame_time = ctx.time.unscaled_delta();
+        let fps = if frame_time > 0.0 { 1.0 / frame_time } else { 0.0 };
         let smoothed_fps = fps.min(999.0); // Cap for display
         self.editor.status_bar.update_stats(ctx.world.entity_count(), smoothed_fps);
-        self.editor.status_bar.update(ctx.delta_time);
+        self.editor.status_bar.update_render_stats(ctx.render_stats);
+        let play_frame = self.editor.in_play_session().then(|| self.editor.frame_stepper.frame());
+        self.editor.status_bar.set_play_frame(play_frame);
+        self.editor.background_tasks.poll(&mut self.editor.status_bar);
+        self.editor.status_bar.update(frame_time);
 
         let theme = &self.editor.theme;
         self.editor.status_bar.render(ctx.ui, window_size, theme);
@@ -156,6 +313,11 @@ impl<G: Game> Game for EditorGame<G> {
         // Editor look for generic ui widgets (buttons, sliders, inputs):
         // derive the ui theme from the editor palette once at startup.
         ctx.ui.set_theme(self.editor.theme.ui_theme());
+        // Icons join the glyphs in the UI atlas, so panels draw from one texture
+        editor::register_editor_icons(ctx.ui);
+        // render() replaces the camera with the viewport's, so the camera
+        // seen at extraction is stale; leave culling to the batcher
+        ctx.extractors.set_culling(false);
 
         // Load font from common search paths
         let font_paths = [
@@ -181,6 +343,18 @@ impl<G: Game> Game for EditorGame<G> {
             log::warn!("To enable font rendering, add a .ttf file to examples/assets/fonts/font.ttf");
         }
 
+        // Dock the panels plugins registered on the engine builder
+        if let Some(panels) = ctx.plugins.take_extension::<editor::PluginPanels>() {
+            self.editor.install_panels(panels);
+        }
+
+        // Restore camera, grid and viewport navigation settings
+        editor::EditorPreferences::load(std::path::Path::new(EDITOR_PREFERENCES_PATH))
+            .apply(&mut self.editor);
+        // Restore the dock layout (plugin panels are docked by now)
+        editor::DockLayout::load(std::path::Path::new(EDITOR_LAYOUT_PATH))
+            .apply(&mut self.editor.dock_area);
+
         // Delegate to inner game
         self.inner.init(ctx);
     }
@@ -188,12 +362,25 @@ impl<G: Game> Game for EditorGame<G> {
     fn update(&mut self, ctx: &mut GameContext) {
         let window_size = ctx.window_size;
 
-        // 1. Run transform hierarchy system
-        self.transform_system.update(ctx.world, ctx.delta_time);
-
-        // 1b. While Playing, the game's main camera drives the viewport
+        // 1. While Playing, the game's main camera drives the viewport
         self.sync_viewport_from_main_camera(ctx.world);
 
+        // 1b. Report a background scene save that has finished
+        self.poll_pending_save();
+
+        // 1c. Profile last frame if the game ran in it (its engine timings
+        // only arrive now)
+        self.record_profile(ctx);
+
+        // 1d. Engine-owned physics only simulates while Playing, configured
+        // by the scene's settings from the start of each session
+        if std::mem::take(&mut self.apply_physics_settings) {
+            if let Some(settings) = &self.physics_settings {
+                ctx.physics.apply_scene_settings(settings);
+            }
+        }
+        ctx.physics.set_paused(!self.editor.is_playing());
+
         // 2. Editor layout
         self.editor.update_layout(window_size);
 
@@ -207,31 +394,60 @@ impl<G: Game> Game for EditorGame<G> {
         );
         panel_renderer::render_drag_ghost(&mut self.editor, ctx);
 
-        // 3. Menu bar + action dispatch
-        self.handle_menu_bar(ctx, window_size);
-
-        // 4. Toolbar + play controls
-        self.render_toolbar_and_play_controls(ctx);
-
-        // 5. Dock panels + content
-        let content_areas = self.render_panels(ctx);
-
-        // 6. Viewport input (pan, zoom, click, rectangle selection)
+        // 2c. Texture picker, sprite region picker and slicer popups — same
+        // reasoning: their blocking rects must exist before the panels
+        // underneath interact.
+        panel_renderer::render_texture_picker(&mut self.editor, ctx, &mut self.command_history);
+        panel_renderer::render_region_picker(&mut self.editor, ctx, &mut self.command_history);
+        panel_renderer::render_sprite_slicer(&mut self.editor, ctx);
+
+        // 3–5. Editor chrome: menu bar, toolbar + play controls, dock
+        // panels. The fullscreen play preview hides all of it.
+        let content_areas = if self.editor.is_chrome_hidden() {
+            Vec::new()
+        } else {
+            self.handle_menu_bar(ctx, window_size);
+            self.render_toolbar_and_play_controls(ctx);
+            self.render_panels(ctx)
+        };
+
+        // 6. Click-to-focus while Playing, viewport input (pan, zoom, click,
+        //    rectangle selection), then glide the camera toward its target
+        //    (zoom, frame, bookmarks)
+        self.update_input_focus(ctx);
         self.handle_viewport_picking(ctx);
+        self.editor.update_viewport(ctx.delta_time);
 
-        // 7. Gizmo interaction for the selected entity
+        // 7. Selection outlines, then collider handles and gizmo interaction
+        //    for the selected entity (collider handles win overlapping clicks)
+        self.render_selection_outlines(ctx, &content_areas);
+        self.handle_collider_gizmo(ctx, &content_areas);
         self.handle_gizmo(ctx, &content_areas);
 
-        // 8. Tool keyboard shortcuts (skip during play)
+        // 8. Tool keyboard shortcuts and the inspector's animation preview
+        //    (skip during play)
         if !self.editor.is_playing() {
             self.handle_tool_shortcuts(ctx);
+            let selected = self.editor.selection.primary();
+            self.editor.animation_preview.tick(ctx.world, selected, ctx.delta_time);
         }
 
-        // 9. Delegate to inner game (only when Playing)
+        // 9. Delegate to inner game (while Playing, or one queued frame step
+        //    while Paused); entities hidden in the hierarchy stay out of
+        //    the scene view while editing
+        self.sync_hidden_entities(ctx);
         self.update_inner_game(ctx);
 
         // 10. Status bar
-        self.render_status_bar(ctx, window_size);
+        if !self.editor.is_chrome_hidden() {
+            self.render_status_bar(ctx, window_size);
+        }
+
+        // 11. The engine propagates transforms after this; a restored or
+        //     swapped world must not reuse the old world's baselines
+        if std::mem::take(&mut self.reset_transforms) {
+            ctx.transforms.reset();
+        }
     }
 
     fn render(&mut self, ctx: &mut RenderContext) {
@@ -250,7 +466,10 @@ impl<G: Game> Game for EditorGame<G> {
     }
 
     fn on_key_released(&mut self, key: KeyCode, ctx: &mut GameContext) {
-        self.inner.on_key_released(key, ctx);
+        // Mid-play the game gets releases for exactly the presses it got
+        if self.editor.input_focus.release(key) || !self.editor.is_playing() {
+            self.inner.on_key_released(key, ctx);
+        }
     }
 
     fn on_resize(&mut self, width: u32, height: u32) {
@@ -258,6 +477,20 @@ impl<G: Game> Game for EditorGame<G> {
     }
 
     fn on_exit(&mut self) {
+        self.finish_pending_save();
+        let mut prefs = editor::EditorPreferences::capture(&self.editor);
+        // Mid-play the viewport shows the game camera; keep the editing view
+        if let Some((position, zoom)) = self.editing_camera {
+            prefs.camera_position = (position.x, position.y);
+            prefs.camera_zoom = zoom;
+        }
+        if let Err(e) = prefs.save(std::path::Path::new(EDITOR_PREFERENCES_PATH)) {
+            log::warn!("Failed to save editor preferences: {}", e);
+        }
+        let layout = editor::DockLayout::capture(&self.editor.dock_area);
+        if let Err(e) = layout.save(std::path::Path::new(EDITOR_LAYOUT_PATH)) {
+            log::warn!("Failed to save editor layout: {}", e);
+        }
         self.inner.on_exit();
     }
 }
@@ -271,17 +504,34 @@ impl<G: Game> Game for EditorGame<G> {
 /// # Minimum window size
 /// The editor needs at least 1024x720 to be usable. If the provided config
 /// specifies a smaller size, it will be enlarged.
-pub fn run_game_with_editor<G: Game>(game: G, mut config: GameConfig) -> Result<(), Box<dyn std::error::Error>> {
-    // Enforce minimum window size for editor usability
-    if config.width < MIN_EDITOR_WINDOW_WIDTH {
-        config.width = MIN_EDITOR_WINDOW_WIDTH;
-    }
-    if config.height < MIN_EDITOR_WINDOW_HEIGHT {
-        config.height = MIN_EDITOR_WINDOW_HEIGHT;
-    }
+pub fn run_game_with_editor<G: Game>(game: G, config: GameConfig) -> Result<(), Box<dyn std::error::Error>> {
+    engine_core::run_game(EditorGame::new(game), editor_window_config(config))
+}
+
+/// [`run_game_with_editor`] with the plugins added to `builder`; their
+/// editor panels (see [`crate::EditorPluginExt`]) are docked at startup.
+pub fn run_builder_with_editor<G: Game>(
+    mut builder: EngineBuilder,
+    game: G,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let config = builder.config_mut();
+    config.width = config.width.max(MIN_EDITOR_WINDOW_WIDTH);
+    config.height = config.height.max(MIN_EDITOR_WINDOW_HEIGHT);
+    builder.run(EditorGame::new(game))
+}
+
+/// [`run_game_with_editor`] for examples: `--headless` auto-plays the
+/// wrapped game inside the editor with `script` (see
+/// [`engine_core::run_example`]).
+pub fn run_example_with_editor<G: Game>(game: G, config: GameConfig, script: HeadlessScript) -> ExitCode {
+    engine_core::run_example(EditorGame::new(game), editor_window_config(config), script)
+}
 
-    let editor_game = EditorGame::new(game);
-    engine_core::run_game(editor_game, config)
+/// Enforce the minimum window size for editor usability.
+fn editor_window_config(mut config: GameConfig) -> GameConfig {
+    config.width = config.width.max(MIN_EDITOR_WINDOW_WIDTH);
+    config.height = config.height.max(MIN_EDITOR_WINDOW_HEIGHT);
+    config
 }
 
 #[cfg(test)]
@@ -0,0 +1,45 @@
+//! Profiler sampling: while Playing, each frame feeds the previous one to
+//! `editor::Profiler` — the engine's pass timings (`ctx.profile`) with
+//! `Game::update` split into the game's own share and the editor UI's.
+
+use ecs::SystemTiming;
+use editor::ProfilerSample;
+use engine_core::contexts::GameContext;
+use engine_core::profiling::{PARTICLES_TIMING, PHYSICS_TIMING};
+use engine_core::{FrameProfile, Game};
+
+use super::EditorGame;
+
+/// Profiler name of the inner game's `update`.
+pub(super) const GAME_TIMING: &str = "Game";
+/// Profiler name of the editor's own share of the frame update.
+pub(super) const EDITOR_TIMING: &str = "Editor UI";
+
+/// One Profiler frame from the engine's `profile`, of which the inner game
+/// took `game_update` seconds.
+pub(super) fn profiler_sample(profile: &FrameProfile, game_update: f32, render_stats: renderer::RenderStats) -> ProfilerSample {
+    let mut timings = vec![
+        SystemTiming::new(GAME_TIMING, game_update),
+        SystemTiming::new(EDITOR_TIMING, (profile.update - game_update).max(0.0)),
+    ];
+    timings.extend(profile.systems.iter().cloned());
+    timings.push(SystemTiming::new(PHYSICS_TIMING, profile.physics));
+    timings.push(SystemTiming::new(PARTICLES_TIMING, profile.particles));
+    ProfilerSample {
+        timings,
+        sprites: render_stats.sprites,
+        draw_calls: render_stats.draw_calls,
+        entities: profile.entities,
+    }
+}
+
+impl<G: Game> EditorGame<G> {
+    /// Record the previous frame if the game ran in it (paused and editing
+    /// frames leave the history as it was).
+    pub(super) fn record_profile(&mut self, ctx: &GameContext) {
+        if let Some(game_update) = self.game_update_time.take() {
+            let sample = profiler_sample(ctx.profile, game_update, ctx.render_stats);
+            self.editor.profiler.record(sample);
+        }
+    }
+}
@@ -1,11 +1,13 @@
-//! Scene save/load/new operations for the editor.
+//! Scene save/load/new/validate operations for the editor.
 
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 
 use ecs::World;
+use engine_core::scene_data::{EditorSettings, PhysicsMaterialData, StreamingSettings};
 use engine_core::Game;
 
-use crate::constants::DEFAULT_SCENE_PATH;
+use crate::constants::{DEFAULT_SCENE_PATH, SCENE_BACKUP_COUNT};
 
 use super::EditorGame;
 
@@ -29,6 +31,71 @@ impl<G: Game> EditorGame<G> {
         assets: &engine_core::assets::AssetManager,
         path: PathBuf,
     ) -> Result<(), String> {
+        let scene_data = self.scene_data_for(world, assets, &path);
+
+        // Ensure parent directory exists
+        if let Some(parent) = path.parent() {
+            if !parent.exists() {
+                std::fs::create_dir_all(parent)
+                    .map_err(|e| format!("Failed to create directory: {}", e))?;
+            }
+        }
+
+        // One save at a time: two writers would race on the temp file
+        self.finish_pending_save();
+        let saver = engine_core::SceneSaver::new().with_backups(SCENE_BACKUP_COUNT);
+        self.pending_save = Some(saver.save_async(scene_data, path.clone()));
+
+        // The scene is captured, so edits from here on count as unsaved;
+        // a failed write marks it dirty again when it's reported
+        self.editor.set_scene_path(Some(path));
+        self.editor.set_dirty(false);
+        self.editor.status_bar.show_message("Saving scene...");
+        self.report_dependency_violations(world, "saved");
+        Ok(())
+    }
+
+    /// Report the background save if it has finished.
+    pub(super) fn poll_pending_save(&mut self) {
+        let Some(result) = self.pending_save.as_ref().and_then(engine_core::SaveHandle::poll) else {
+            return;
+        };
+        if let Some(handle) = self.pending_save.take() {
+            self.report_save(handle.path().to_path_buf(), result);
+        }
+    }
+
+    /// Block until the background save (if any) finishes, then report it.
+    pub(super) fn finish_pending_save(&mut self) {
+        if let Some(handle) = self.pending_save.take() {
+            let path = handle.path().to_path_buf();
+            self.report_save(path, handle.wait());
+        }
+    }
+
+    fn report_save(&mut self, path: PathBuf, result: Result<(), String>) {
+        match result {
+            Ok(()) => {
+                self.editor.status_bar.show_message("Scene saved");
+                self.editor.status_bar.record_result(format!("Scene saved to {}", path.display()), true);
+                log::info!("Scene saved to: {:?}", path);
+            }
+            Err(e) => {
+                if self.editor.scene_path() == Some(path.as_path()) {
+                    self.editor.set_dirty(true);
+                }
+                self.report_save_error(&e);
+            }
+        }
+    }
+
+    /// The world as it would be saved to `path` (the file stem names the scene).
+    fn scene_data_for(
+        &self,
+        world: &World,
+        assets: &engine_core::assets::AssetManager,
+        path: &Path,
+    ) -> engine_core::scene_data::SceneData {
         let scene_name = path.file_stem()
             .and_then(|s| s.to_str())
             .unwrap_or("Untitled")
@@ -42,25 +109,204 @@ impl<G: Game> EditorGame<G> {
                 })
         };
 
-        let scene_data = engine_core::scene_serializer::world_to_scene_data(
+        let mut scene_data = engine_core::scene_serializer::world_to_scene_data(
             world, &scene_name, self.physics_settings.clone(), &texture_path_fn,
         );
+        scene_data.materials = self.scene_materials.clone();
+        scene_data.streaming = self.streaming_settings;
+        scene_data.editor = self.editor_settings(world);
+        // Authored scenes start from tick 0; the editor's own tick is noise.
+        scene_data.simulation_tick = None;
+        scene_data
+    }
 
-        // Ensure parent directory exists
-        if let Some(parent) = path.parent() {
-            if !parent.exists() {
-                std::fs::create_dir_all(parent)
-                    .map_err(|e| format!("Failed to create directory: {}", e))?;
+    /// The scene's editor settings block: the camera plus the entities
+    /// hidden or locked in the hierarchy and the hidden render layers.
+    /// Omitted while nothing is flagged, so untouched scenes save as before.
+    pub(super) fn editor_settings(&self, world: &World) -> Option<EditorSettings> {
+        let hidden = self.editor.visibility.to_guids(world);
+        let locked = self.editor.locks.to_guids(world);
+        let hidden_layers = self.editor.visibility.hidden_layers();
+        if hidden.is_empty() && locked.is_empty() && hidden_layers.is_empty() {
+            return None;
+        }
+        let camera = self.editor.camera_offset();
+        Some(EditorSettings {
+            camera_position: (camera.x, camera.y),
+            camera_zoom: self.editor.camera_zoom(),
+            hidden,
+            locked,
+            hidden_layers,
+        })
+    }
+
+    /// Adopt a scene's material table (kept for saving) and offer its
+    /// materials in the Collider inspector's Material dropdown.
+    pub(super) fn set_scene_materials(&mut self, materials: HashMap<String, PhysicsMaterialData>) {
+        self.editor.physics_materials = engine_core::scene_materials::material_library(&materials);
+        self.scene_materials = materials;
+    }
+
+    /// File > Validate Scene: run the offline `scene_tools` checks on the
+    /// scene as it would be saved. Each issue goes to the log; the status
+    /// bar shows the summary and the first issue.
+    pub(super) fn validate_current_scene(
+        &mut self,
+        world: &World,
+        assets: &engine_core::assets::AssetManager,
+    ) {
+        let path = self.editor.scene_path()
+            .map(|p| p.to_path_buf())
+            .unwrap_or_else(|| PathBuf::from(DEFAULT_SCENE_PATH));
+        let scene_data = self.scene_data_for(world, assets, &path);
+        let report = engine_core::scene_tools::SceneValidator::new()
+            .with_asset_root(assets.asset_root().clone())
+            .validate(&scene_data);
+
+        for issue in &report.issues {
+            log::warn!(
+                "Validate Scene: {:?} [{}] {}",
+                issue.kind,
+                issue.location.as_deref().unwrap_or("scene"),
+                issue.message,
+            );
+        }
+        let summary = report.summary();
+        match report.issues.first() {
+            None => self.editor.status_bar.show_message(format!("{}: no issues", report.scene)),
+            Some(first) if !report.is_ok() => {
+                self.editor.status_bar.show_error(format!("{} — {}", summary, first.message));
             }
+            Some(first) => self.editor.status_bar.show_message(format!("{} — {}", summary, first.message)),
         }
+        self.editor.status_bar.record_result(summary, report.is_ok());
+        self.report_dependency_violations(world, "validated");
+        self.editor.component_issues.validate_world(world);
+        self.report_component_issues("Scene validated");
+    }
+
+    /// File > Compare With Saved: structurally diff the scene file on disk
+    /// against the scene as it would be saved now, and list the changes in
+    /// the Compare panel.
+    pub(super) fn compare_with_saved(
+        &mut self,
+        world: &World,
+        assets: &engine_core::assets::AssetManager,
+    ) {
+        // Compare against the file as it will be once any save lands
+        self.finish_pending_save();
+        let Some(path) = self.editor.scene_path().map(|p| p.to_path_buf()) else {
+            self.editor.status_bar.show_message("Compare: the scene has not been saved yet");
+            return;
+        };
+        let diff = engine_core::scene_loader::SceneLoader::load_from_file(&path)
+            .map_err(|e| e.to_string())
+            .and_then(|saved| {
+                let current = self.scene_data_for(world, assets, &path);
+                engine_core::scene_tools::diff_scenes(&saved, &current).map_err(|e| e.to_string())
+            });
+        let diff = match diff {
+            Ok(diff) => diff,
+            Err(e) => {
+                self.editor.status_bar.show_error(format!("Compare failed: {}", e));
+                log::error!("Failed to compare {:?} with the saved scene: {}", path, e);
+                return;
+            }
+        };
 
-        engine_core::scene_serializer::save_scene_to_file(&scene_data, &path)?;
+        let rows: Vec<editor::CompareRow> = diff.changes.iter().map(compare_row).collect();
+        let summary = match rows.len() {
+            0 => "no differences".to_string(),
+            count => format!("{} difference(s)", count),
+        };
+        let name = path.file_name().map_or_else(|| path.display().to_string(), |n| n.to_string_lossy().into_owned());
+        self.editor.scene_compare.show(format!("{} (saved) → current: {}", name, summary), rows);
+        self.editor.dock_area.set_panel_visible(editor::PanelId::SCENE_COMPARE, true);
+        self.editor.status_bar.show_message(format!("Compare: {}", summary));
+    }
 
-        self.editor.set_scene_path(Some(path.clone()));
-        self.editor.set_dirty(false);
-        self.editor.status_bar.show_message("Scene saved");
-        log::info!("Scene saved to: {:?}", path);
-        Ok(())
+    /// File > Export Streaming Chunks: split the scene as it would be saved
+    /// into a `<scene>_chunks/` directory next to it (base scene plus one
+    /// file per chunk, for `WorldStreamer::open_dir`). A scene without
+    /// streaming settings gets the defaults, which then save with it and
+    /// show as chunk bounds in the scene view.
+    pub(super) fn export_streaming_chunks(
+        &mut self,
+        world: &World,
+        assets: &engine_core::assets::AssetManager,
+    ) {
+        let settings = match self.streaming_settings {
+            Some(settings) => settings,
+            None => {
+                self.editor.set_dirty(true);
+                *self.streaming_settings.insert(StreamingSettings::default())
+            }
+        };
+        let path = self.editor.scene_path()
+            .map(|p| p.to_path_buf())
+            .unwrap_or_else(|| PathBuf::from(DEFAULT_SCENE_PATH));
+        let scene_data = self.scene_data_for(world, assets, &path);
+        let dir = path.with_file_name(format!("{}_chunks", scene_data.name));
+
+        let chunked = engine_core::streaming::partition_scene(scene_data, settings);
+        let summary = format!(
+            "{} chunk(s), {} always-loaded entities",
+            chunked.chunks.len(),
+            chunked.base.entities.len(),
+        );
+        match chunked.write_to_dir(&dir) {
+            Ok(_) => {
+                let message = format!("Exported {} to {}", summary, dir.display());
+                self.editor.status_bar.show_message(message.clone());
+                self.editor.status_bar.record_result(message, true);
+            }
+            Err(e) => {
+                let message = format!("Chunk export failed: {}", e);
+                self.editor.status_bar.show_error(message.clone());
+                self.editor.status_bar.record_result(message, false);
+                log::error!("Failed to export streaming chunks: {}", e);
+            }
+        }
+    }
+
+    /// Surface a failed save on the status bar and in its task history.
+    pub(super) fn report_save_error(&mut self, error: &str) {
+        let message = format!("Save failed: {}", error);
+        self.editor.status_bar.show_error(message.clone());
+        self.editor.status_bar.record_result(message, false);
+        log::error!("Failed to save: {}", error);
+    }
+
+    /// Validate component dependencies (e.g. Collider requires RigidBody)
+    /// and flag violations on the status bar. `action` completes "Scene …
+    /// with N issue(s)".
+    fn report_dependency_violations(&mut self, world: &World, action: &str) {
+        let violations = editor::validate_component_dependencies(world);
+        let Some(first) = violations.first() else {
+            return;
+        };
+        for violation in &violations {
+            log::warn!("Component dependency: {}", violation);
+        }
+        self.editor.status_bar.show_error(format!(
+            "Scene {} with {} component dependency issue(s): {}",
+            action,
+            violations.len(),
+            first,
+        ));
+    }
+
+    /// Flag invalid component data (zero-size colliders, zero fps, …) on
+    /// the status bar and open the Console panel that lists it. `context`
+    /// prefixes the message ("Scene loaded", "Play blocked").
+    pub(super) fn report_component_issues(&mut self, context: &str) {
+        let issues = &self.editor.component_issues;
+        let Some(first) = issues.errors().first() else {
+            return;
+        };
+        let message = format!("{}: {} invalid component value(s): {}", context, issues.len(), first);
+        self.editor.status_bar.show_error(message);
+        self.editor.dock_area.set_panel_visible(editor::PanelId::CONSOLE, true);
     }
 
     /// Load a scene from disk, replacing the current world.
@@ -70,6 +316,8 @@ impl<G: Game> EditorGame<G> {
         assets: &mut engine_core::assets::AssetManager,
         path: &Path,
     ) -> Result<(), String> {
+        // The file may be the one still being written
+        self.finish_pending_save();
         if self.editor.is_dirty() {
             log::warn!("Current scene has unsaved changes. Save first to avoid losing work.");
         }
@@ -85,26 +333,44 @@ impl<G: Game> EditorGame<G> {
 
         // Store physics settings from loaded scene
         self.physics_settings = scene_instance.physics.clone();
+        self.set_scene_materials(scene_instance.materials.clone());
+        self.streaming_settings = scene_instance.streaming;
+        self.editor.component_issues.set(scene_instance.component_errors.clone());
+        let settings = scene_instance.editor.unwrap_or_default();
+        self.editor.visibility.load_guids(world, &settings.hidden);
+        self.editor.visibility.load_hidden_layers(&settings.hidden_layers);
+        self.editor.locks.load_guids(world, &settings.locked);
 
         log::info!("Scene loaded from: {:?} ({} entities)", path, scene_instance.entity_count);
 
         self.editor.set_scene_path(Some(path.to_path_buf()));
         self.editor.set_dirty(false);
         self.command_history = editor::CommandHistory::new();
+        self.edit_history = None;
         self.editor.selection.clear();
+        self.editor.animation_preview.clear();
+        self.editor.play_changes.clear();
         self.gizmo_drag_start = None;
+        self.collider_drag_start = None;
+        self.editor.collider_gizmo.cancel();
         self.editor.status_bar.show_message("Scene loaded");
+        self.report_dependency_violations(world, "loaded");
+        self.report_component_issues("Scene loaded");
 
         Ok(())
     }
 
-    /// Load a scene and surface any failure on the status bar.
+    /// Load a scene and surface any failure on the status bar. A scene
+    /// already open in another tab is switched to instead of loaded twice.
     pub(super) fn load_scene_with_feedback(
         &mut self,
         world: &mut World,
         assets: &mut engine_core::assets::AssetManager,
         path: &Path,
     ) {
+        if self.focus_open_scene_tab(path, world) {
+            return;
+        }
         if let Err(e) = self.load_scene(world, assets, path) {
             self.editor.status_bar.show_error(format!("Load failed: {}", e));
             log::error!("Failed to load scene: {}", e);
@@ -125,10 +391,40 @@ impl<G: Game> EditorGame<G> {
         self.editor.set_scene_path(None);
         self.editor.set_dirty(false);
         self.command_history = editor::CommandHistory::new();
+        self.edit_history = None;
         self.editor.selection.clear();
+        self.editor.animation_preview.clear();
+        self.editor.play_changes.clear();
         self.entity_counter = 0;
         self.physics_settings = None;
+        self.set_scene_materials(HashMap::new());
+        self.streaming_settings = None;
+        world.insert_resource(ecs::RenderLayers::new());
+        self.editor.visibility = editor::EditorVisibility::new();
+        self.editor.locks = editor::EditorLock::new();
+        self.editor.component_issues.clear();
         self.gizmo_drag_start = None;
+        self.collider_drag_start = None;
+        self.editor.collider_gizmo.cancel();
         log::info!("New scene created");
     }
 }
+
+/// A Compare panel row for one scene change.
+fn compare_row(change: &engine_core::scene_tools::SceneChange) -> editor::CompareRow {
+    use editor::CompareRowKind;
+    use engine_core::scene_tools::{EntityKey, SceneChange};
+
+    let kind = match change {
+        SceneChange::SettingChanged { .. } => CompareRowKind::Setting,
+        SceneChange::EntityAdded { .. } | SceneChange::ComponentAdded { .. } => CompareRowKind::Added,
+        SceneChange::EntityRemoved { .. } | SceneChange::ComponentRemoved { .. } => CompareRowKind::Removed,
+        SceneChange::EntityMoved { .. } => CompareRowKind::Moved,
+        SceneChange::FieldChanged { .. } => CompareRowKind::Changed,
+    };
+    let entity = match change.entity() {
+        Some(EntityKey::Guid(guid)) => Some(*guid),
+        _ => None,
+    };
+    editor::CompareRow { kind, entity, text: change.describe() }
+}
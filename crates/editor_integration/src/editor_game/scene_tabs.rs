@@ -0,0 +1,318 @@
+//! Multi-scene tabs: park/unpark the live editing state when switching the
+//! scene tab shown in the viewport.
+//!
+//! Each tab owns a whole `World`. The active tab's world is the engine's
+//! live world (`ctx.world`); switching swaps it with the target tab's parked
+//! world, together with the selection, camera, undo history, hidden/locked
+//! flags and scene settings, so nothing unsaved is lost in the background
+//! tabs.
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use glam::Vec2;
+
+use ecs::World;
+use editor::{SceneTabAction, Selection};
+use engine_core::contexts::GameContext;
+use engine_core::scene_data::{PhysicsMaterialData, PhysicsSettings, StreamingSettings};
+use engine_core::Game;
+
+use super::EditorGame;
+
+/// Editing state of a background scene tab.
+pub(super) struct ParkedScene {
+    world: World,
+    selection: Selection,
+    /// Viewport position, zoom, and rotation
+    camera: (Vec2, f32, f32),
+    command_history: editor::CommandHistory,
+    physics_settings: Option<PhysicsSettings>,
+    scene_materials: HashMap<String, PhysicsMaterialData>,
+    streaming_settings: Option<StreamingSettings>,
+    entity_counter: u32,
+    visibility: editor::EditorVisibility,
+    locks: editor::EditorLock,
+}
+
+impl<G: Game> EditorGame<G> {
+    /// Draw the tab bar in the scene view header (right of the "Scene"
+    /// title) and act on clicks. Tabs are inert during a play session.
+    pub(super) fn render_scene_tabs(&mut self, ctx: &mut GameContext) {
+        if !self.editor.dock_area.is_panel_shown(editor::PanelId::SCENE_VIEW) {
+            return;
+        }
+        let Some(panel) = self.editor.dock_area.get_panel(editor::PanelId::SCENE_VIEW) else {
+            return;
+        };
+        let header = panel.header_bounds();
+        let theme = &self.editor.theme;
+        let title_width = ctx.ui.measure_text_styled(&panel.title, theme.fonts.body).x + 2.0 * editor::layout::PADDING;
+        let bar = common::Rect::new(header.x + title_width, header.y, (header.width - title_width).max(0.0), header.height - 2.0);
+
+        self.scene_tabs.sync_active(self.editor.scene_path(), self.editor.is_dirty());
+        let enabled = !self.editor.in_play_session();
+        if let Some(action) = self.scene_tabs.render(ctx.ui, bar, theme, enabled) {
+            self.handle_scene_tab_action(action, ctx.world);
+        }
+    }
+
+    /// Apply a tab bar click or tab shortcut.
+    pub(super) fn handle_scene_tab_action(&mut self, action: SceneTabAction, world: &mut World) {
+        if self.editor.in_play_session() {
+            self.editor.status_bar.show_message("Stop the game to switch scenes");
+            return;
+        }
+        match action {
+            SceneTabAction::New => self.open_scene_tab(world),
+            SceneTabAction::Activate(index) => self.switch_scene_tab(index, world),
+            SceneTabAction::Close(index) => self.close_scene_tab(index, world),
+        }
+    }
+
+    /// Park the current scene and start a new untitled one in a new tab.
+    pub(super) fn open_scene_tab(&mut self, world: &mut World) {
+        self.scene_tabs.sync_active(self.editor.scene_path(), self.editor.is_dirty());
+        let parked = self.park_active_scene(world);
+        self.scene_tabs.open(parked);
+        self.set_scene_materials(HashMap::new());
+        self.editor.component_issues.clear();
+        self.editor.set_scene_path(None);
+        self.editor.set_dirty(false);
+        self.editor.reset_camera();
+        self.editor.reset_view_rotation();
+        self.reset_transforms = true;
+        log::info!("New scene tab ({} open)", self.scene_tabs.len());
+    }
+
+    /// Make tab `index` the edited scene, parking the current one.
+    pub(super) fn switch_scene_tab(&mut self, index: usize, world: &mut World) {
+        if index == self.scene_tabs.active_index() || self.scene_tabs.get(index).is_none() {
+            return;
+        }
+        self.scene_tabs.sync_active(self.editor.scene_path(), self.editor.is_dirty());
+        let current = self.park_active_scene(world);
+        match self.scene_tabs.switch_to(index, current) {
+            Ok(target) => self.unpark_scene(target, world),
+            // Nothing to switch to: put the live state straight back
+            Err(current) => self.unpark_scene(current, world),
+        }
+    }
+
+    /// Activate the next tab (wrapping), for Ctrl+Tab.
+    pub(super) fn next_scene_tab(&mut self, world: &mut World) {
+        let next = (self.scene_tabs.active_index() + 1) % self.scene_tabs.len();
+        self.switch_scene_tab(next, world);
+    }
+
+    /// Close tab `index`. A dirty tab needs a second click; closing the
+    /// active tab first switches to its neighbor. The last tab stays open.
+    pub(super) fn close_scene_tab(&mut self, index: usize, world: &mut World) {
+        self.scene_tabs.sync_active(self.editor.scene_path(), self.editor.is_dirty());
+        let Some(tab) = self.scene_tabs.get(index) else {
+            return;
+        };
+        if self.scene_tabs.len() < 2 {
+            return;
+        }
+        let label = tab.label();
+        if !self.scene_tabs.confirm_close(index) {
+            self.editor
+                .status_bar
+                .show_message(format!("{} has unsaved changes: close again to discard them", label));
+            return;
+        }
+        if index == self.scene_tabs.active_index() {
+            let Some(neighbor) = self.scene_tabs.neighbor_of_active() else {
+                return;
+            };
+            self.switch_scene_tab(neighbor, world);
+        }
+        if self.scene_tabs.close(index) {
+            self.editor.status_bar.show_message(format!("Closed {}", label));
+        }
+    }
+
+    /// Whether `path` is open in a tab other than the active one; switches
+    /// to that tab when it is (so a scene is never edited in two tabs).
+    pub(super) fn focus_open_scene_tab(&mut self, path: &Path, world: &mut World) -> bool {
+        match self.scene_tabs.find_path(path) {
+            Some(index) if index != self.scene_tabs.active_index() => {
+                self.switch_scene_tab(index, world);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Move the live scene state out, leaving an empty world and fresh
+    /// per-scene editor state behind.
+    fn park_active_scene(&mut self, world: &mut World) -> ParkedScene {
+        self.cancel_scene_interactions();
+        // The parked world keeps its authored animation frames
+        self.editor.animation_preview.stop(world);
+        let viewport = &self.editor.viewport;
+        let camera = (viewport.camera_position(), viewport.camera_zoom(), viewport.camera_rotation());
+        ParkedScene {
+            world: std::mem::take(world),
+            selection: std::mem::take(&mut self.editor.selection),
+            camera,
+            command_history: std::mem::take(&mut self.command_history),
+            physics_settings: self.physics_settings.take(),
+            scene_materials: std::mem::take(&mut self.scene_materials),
+            streaming_settings: self.streaming_settings.take(),
+            entity_counter: std::mem::take(&mut self.entity_counter),
+            visibility: std::mem::take(&mut self.editor.visibility),
+            locks: std::mem::take(&mut self.editor.locks),
+        }
+    }
+
+    /// Install a parked scene as the live one; path and dirty flag come from
+    /// the (now active) tab.
+    fn unpark_scene(&mut self, parked: ParkedScene, world: &mut World) {
+        *world = parked.world;
+        self.editor.selection = parked.selection;
+        let (position, zoom, rotation) = parked.camera;
+        self.editor.viewport.set_camera_position(position);
+        self.editor.viewport.set_camera_zoom(zoom);
+        self.editor.viewport.set_camera_rotation(rotation);
+        self.command_history = parked.command_history;
+        self.physics_settings = parked.physics_settings;
+        self.set_scene_materials(parked.scene_materials);
+        self.streaming_settings = parked.streaming_settings;
+        self.entity_counter = parked.entity_counter;
+        self.editor.visibility = parked.visibility;
+        self.editor.locks = parked.locks;
+        self.editor.component_issues.validate_world(world);
+
+        let tab = self.scene_tabs.active();
+        self.editor.set_scene_path(tab.path.clone());
+        self.editor.set_dirty(tab.dirty);
+        // The world was wholesale-replaced: drop stale propagation baselines
+        self.reset_transforms = true;
+    }
+
+    /// Drop in-flight drags, popups and offered play edits that point into
+    /// the outgoing world.
+    fn cancel_scene_interactions(&mut self) {
+        self.gizmo_drag_start = None;
+        self.gizmo_drag_start_collider = None;
+        self.collider_drag_start = None;
+        self.editor.collider_gizmo.cancel();
+        self.editor.close_add_component_popup();
+        self.editor.dependency_prompt = None;
+        self.editor.play_changes.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct DummyGame;
+    impl Game for DummyGame {
+        fn update(&mut self, _ctx: &mut GameContext) {}
+    }
+
+    #[test]
+    fn test_switching_tabs_keeps_each_scene_state() {
+        let mut editor = EditorGame::new(DummyGame);
+        let mut world = World::new();
+        let first = world.create_entity();
+        editor.editor.selection.select(first);
+        editor.editor.set_scene_path(Some("scenes/a.ron".into()));
+        editor.editor.mark_dirty();
+        editor.editor.set_camera_offset(Vec2::new(50.0, 0.0));
+
+        editor.open_scene_tab(&mut world);
+        assert_eq!(world.entity_count(), 0, "new tab starts with an empty world");
+        assert!(editor.editor.selection.is_empty());
+        assert!(!editor.editor.is_dirty());
+        assert!(editor.editor.scene_path().is_none());
+        world.create_entity();
+        world.create_entity();
+
+        editor.switch_scene_tab(0, &mut world);
+        assert_eq!(world.entity_count(), 1);
+        assert_eq!(editor.editor.selection.primary(), Some(first));
+        assert!(editor.editor.is_dirty(), "unsaved changes survive the switch");
+        assert_eq!(editor.editor.scene_path(), Some(Path::new("scenes/a.ron")));
+        assert_eq!(editor.editor.camera_offset(), Vec2::new(50.0, 0.0));
+
+        editor.next_scene_tab(&mut world);
+        assert_eq!(world.entity_count(), 2);
+    }
+
+    #[test]
+    fn test_closing_dirty_active_tab_needs_confirmation() {
+        let mut editor = EditorGame::new(DummyGame);
+        let mut world = World::new();
+        world.create_entity();
+        editor.open_scene_tab(&mut world);
+        editor.editor.mark_dirty();
+
+        editor.close_scene_tab(1, &mut world);
+        assert_eq!(editor.scene_tabs.len(), 2, "first click only arms the close");
+        editor.close_scene_tab(1, &mut world);
+        assert_eq!(editor.scene_tabs.len(), 1);
+        assert_eq!(world.entity_count(), 1, "the remaining tab's world is live");
+        assert!(!editor.editor.is_dirty());
+
+        editor.close_scene_tab(0, &mut world);
+        assert_eq!(editor.scene_tabs.len(), 1, "the last tab stays open");
+    }
+
+    #[test]
+    fn test_scene_settings_follow_their_tab() {
+        let mut editor = EditorGame::new(DummyGame);
+        let mut world = World::new();
+        let presets = physics::PhysicsMaterial::PRESET_NAMES.len();
+        let sticky = PhysicsMaterialData {
+            friction: 1.0,
+            restitution: 0.0,
+            friction_combine: Default::default(),
+            restitution_combine: Default::default(),
+        };
+        editor.set_scene_materials(HashMap::from([("sticky".to_string(), sticky)]));
+        editor.streaming_settings = Some(StreamingSettings::default());
+        assert_eq!(editor.editor.physics_materials.len(), presets + 1);
+
+        editor.open_scene_tab(&mut world);
+        assert!(editor.scene_materials.is_empty());
+        assert_eq!(editor.streaming_settings, None);
+        assert_eq!(editor.editor.physics_materials.len(), presets);
+
+        editor.switch_scene_tab(0, &mut world);
+        assert!(editor.scene_materials.contains_key("sticky"));
+        assert_eq!(editor.streaming_settings, Some(StreamingSettings::default()));
+        assert_eq!(editor.editor.physics_materials.len(), presets + 1);
+    }
+
+    #[test]
+    fn test_hidden_and_locked_flags_follow_their_tab() {
+        let mut editor = EditorGame::new(DummyGame);
+        let mut world = World::new();
+        let entity = world.create_entity();
+        editor.editor.visibility.set_hidden(entity, true);
+        editor.editor.locks.set_locked(entity, true);
+        let settings = editor.editor_settings(&world).expect("flags are saved with the scene");
+        assert_eq!(settings.hidden, world.guid_of(entity).into_iter().collect::<Vec<_>>());
+
+        editor.open_scene_tab(&mut world);
+        assert!(editor.editor.visibility.is_empty() && editor.editor.locks.is_empty());
+        assert!(editor.editor_settings(&world).is_none(), "nothing flagged → no editor block");
+
+        editor.switch_scene_tab(0, &mut world);
+        assert!(editor.editor.visibility.is_hidden(entity));
+        assert!(editor.editor.locks.is_locked(entity));
+    }
+
+    #[test]
+    fn test_tabs_are_locked_during_play() {
+        let mut editor = EditorGame::new(DummyGame);
+        let mut world = World::new();
+        editor.handle_play_action(editor::PlayControlAction::Play, &mut world);
+        editor.handle_scene_tab_action(SceneTabAction::New, &mut world);
+        assert_eq!(editor.scene_tabs.len(), 1);
+    }
+}
@@ -14,7 +14,7 @@ use crate::constants::DEFAULT_SCENE_PATH;
 use super::EditorGame;
 
 impl<G: Game> EditorGame<G> {
-    /// Q/W/E/R tool selection shortcuts.
+    /// Q/W/E/R/M tool selection shortcuts.
     pub(super) fn handle_tool_shortcuts(&mut self, ctx: &GameContext) {
         // A focused text input owns the keyboard — typing must not switch tools
         if ctx.ui.wants_keyboard() {
@@ -22,6 +22,10 @@ impl<G: Game> EditorGame<G> {
         }
 
         let kb = ctx.input.keyboard();
+        // Ctrl+<letter> is a command shortcut (Ctrl+W closes a tab), not a tool
+        if kb.is_key_pressed(KeyCode::ControlLeft) || kb.is_key_pressed(KeyCode::ControlRight) {
+            return;
+        }
 
         if kb.is_key_just_pressed(KeyCode::KeyQ) {
             self.editor.set_tool(EditorTool::Select);
@@ -31,10 +35,12 @@ impl<G: Game> EditorGame<G> {
             self.editor.set_tool(EditorTool::Rotate);
         } else if kb.is_key_just_pressed(KeyCode::KeyR) {
             self.editor.set_tool(EditorTool::Scale);
+        } else if kb.is_key_just_pressed(KeyCode::KeyM) {
+            self.editor.set_tool(EditorTool::Measure);
         }
     }
 
-    /// Handle a play control action (Play, Pause, Stop).
+    /// Handle a play control action (Play, Pause, Stop, Step).
     ///
     /// Returns `true` if a Stop was performed (world restored from snapshot),
     /// so the caller can notify the inner game via `on_play_stopped`.
@@ -42,10 +48,31 @@ impl<G: Game> EditorGame<G> {
         match action {
             PlayControlAction::Play => {
                 if self.editor.is_editing() {
+                    // Invalid component data would only surface as NaNs
+                    // inside the physics step — fix it first
+                    if self.editor.component_issues.validate_world(world) > 0 {
+                        self.report_component_issues("Play blocked");
+                        return false;
+                    }
                     // Cancel any in-progress gizmo drag
                     self.gizmo_drag_start = None;
+                    self.collider_drag_start = None;
+                    self.editor.collider_gizmo.cancel();
+                    // A sync tick left over from the last session would
+                    // mark every restored body as edited.
+                    world.remove_resource::<physics::PhysicsSyncTick>();
+                    // Scene Settings may have changed since the last session
+                    self.apply_physics_settings = true;
+                    // The snapshot (and the game) start from the authored frame
+                    self.editor.animation_preview.stop(world);
                     // Starting a new play session — capture snapshot
                     self.world_snapshot = Some(WorldSnapshot::capture(world));
+                    // Edits to the play world get their own history, so
+                    // undo after Stop never replays them on the edit world
+                    self.edit_history = Some(std::mem::take(&mut self.command_history));
+                    self.editor.play_changes.clear();
+                    self.editor.profiler.clear();
+                    self.editor.frame_stepper.reset();
                     // Save the editing pan/zoom; play renders at zoom 1.0
                     // (parity with the game's own camera, which has no zoom
                     // source), position driven by the main-camera entity.
@@ -58,9 +85,17 @@ impl<G: Game> EditorGame<G> {
                     self.editor.close_add_component_popup();
                     log::info!("Play: snapshot captured, entering play mode");
                 } else if self.editor.is_paused() {
-                    // Resuming from pause
+                    // Resuming from pause: the game's next physics update
+                    // pushes edits made while paused into the live bodies.
                     self.editor.set_play_state(EditorPlayState::Playing);
                     self.editor.close_add_component_popup();
+                    self.editor.frame_stepper.cancel();
+                    let pending = physics::pending_physics_edits(world).len();
+                    if pending > 0 {
+                        self.editor
+                            .status_bar
+                            .show_message(format!("Resumed: syncing {pending} edited bodies to physics"));
+                    }
                     log::info!("Play: resumed from pause");
                 }
                 false
@@ -72,22 +107,46 @@ impl<G: Game> EditorGame<G> {
                 }
                 false
             }
+            PlayControlAction::Step(frames) => {
+                // Stepping from a running game pauses it first
+                if self.editor.is_playing() {
+                    self.editor.set_play_state(EditorPlayState::Paused);
+                }
+                if self.editor.is_paused() {
+                    self.editor.frame_stepper.request(frames);
+                }
+                false
+            }
             PlayControlAction::Stop => {
                 if self.editor.in_play_session() {
+                    // Back to the edit-mode history; play-time edits are
+                    // dropped with the play world
+                    self.command_history = self.edit_history.take().unwrap_or_default();
                     // Restore world from snapshot
                     if let Some(snapshot) = self.world_snapshot.take() {
+                        // Collect kept tweaks from the play world before it
+                        // is discarded.
+                        let kept = self.editor.play_changes.take_kept_changes(&snapshot, world);
                         snapshot.restore(world);
-                        // The world was wholesale-replaced: drop the transform
-                        // system's propagation baselines so no stale cache
-                        // entry survives the restore.
-                        self.transform_system.reset();
+                        // The world was wholesale-replaced: the engine's
+                        // transform propagation drops its baselines so no
+                        // stale cache entry survives the restore.
+                        self.reset_transforms = true;
                         log::info!("Stop: world restored from snapshot");
+                        self.apply_kept_play_changes(world, &kept);
+                        let offered = self.editor.play_changes.offered_edits().len();
+                        if offered > 0 {
+                            self.editor.status_bar.show_message(format!(
+                                "Reverted {offered} value(s) edited during play (Keep Edited Values in the Inspector)"
+                            ));
+                        }
                     }
                     // Restore the pan/zoom the user had while editing
                     if let Some((position, zoom)) = self.editing_camera.take() {
                         self.editor.viewport.set_camera_position(position);
                         self.editor.viewport.set_camera_zoom(zoom);
                     }
+                    self.editor.frame_stepper.reset();
                     self.editor.set_play_state(EditorPlayState::Editing);
                     true
                 } else {
@@ -97,13 +156,97 @@ impl<G: Game> EditorGame<G> {
         }
     }
 
-    /// Top-level key handler: play shortcuts always work; editor shortcuts
-    /// apply while Editing/Paused; everything else forwards to the game.
+    /// Re-apply play-mode changes kept across Stop onto the restored edit
+    /// world as one undo entry.
+    fn apply_kept_play_changes(&mut self, world: &mut ecs::World, kept: &[editor::FieldChange]) {
+        if let Some((cmd, count)) = editor::apply_play_changes(world, kept) {
+            self.command_history.push_already_executed(cmd);
+            self.editor.mark_dirty();
+            self.editor.status_bar.show_message(format!("Kept play-mode changes on {} component(s)", count));
+        }
+    }
+
+    /// Mark every play-mode change on the selected entities to survive Stop.
+    pub(super) fn keep_play_changes_for_selection(&mut self) {
+        if !self.editor.in_play_session() {
+            self.editor.status_bar.show_message("Keep play changes: not in play mode");
+            return;
+        }
+        let selected: Vec<ecs::EntityId> = self.editor.selection.selected().collect();
+        if selected.is_empty() {
+            self.editor.status_bar.show_message("Keep play changes: nothing selected");
+            return;
+        }
+        self.editor.play_changes.keep_entities(selected.iter().copied());
+        self.editor
+            .status_bar
+            .show_message(format!("Changes on {} entit(ies) will be kept at Stop", selected.len()));
+    }
+
+    /// Toggle the fullscreen play preview and say so on the status bar (the
+    /// message shows once the chrome is back).
+    pub(super) fn toggle_fullscreen_play(&mut self) {
+        self.editor.toggle_fullscreen_play();
+        let state = if self.editor.is_fullscreen_play() { "on" } else { "off" };
+        self.editor.status_bar.show_message(format!("Fullscreen play preview {}", state));
+    }
+
+    /// Frame the selection in the viewport (F).
+    pub(super) fn frame_selection(&mut self, ctx: &GameContext) {
+        let entities = super::viewport_interaction::selection_frame_entities(
+            ctx.world,
+            &self.editor.selection,
+            ctx.assets.texture_sizes(),
+        );
+        if !self.editor.frame_selection(&entities) {
+            self.editor.status_bar.show_message("Nothing selected to frame");
+        }
+    }
+
+    /// Store (`ctrl`) or recall camera bookmark `slot`.
+    pub(super) fn handle_camera_bookmark_key(&mut self, slot: u8, ctrl: bool) {
+        if ctrl {
+            self.editor.store_camera_bookmark(slot);
+            self.editor.status_bar.show_message(format!("Camera bookmark {slot} saved"));
+        } else if !self.editor.recall_camera_bookmark(slot) {
+            self.editor
+                .status_bar
+                .show_message(format!("Camera bookmark {slot} is empty (Ctrl+{slot} to save)"));
+        }
+    }
+
+    /// Ctrl+P (Play/Pause) and Ctrl+Shift+P (Stop), which work whatever has
+    /// input focus. Returns whether `key` was one of them.
+    fn handle_play_shortcut(&mut self, key: KeyCode, ctrl: bool, shift: bool, ctx: &mut GameContext) -> bool {
+        if key != KeyCode::KeyP || !ctrl {
+            return false;
+        }
+        if shift {
+            // Ctrl+Shift+P → Stop
+            if self.handle_play_action(PlayControlAction::Stop, ctx.world) {
+                // Play's state transitions don't survive the snapshot restore
+                ctx.states.clear();
+                self.inner.on_play_stopped(ctx);
+            }
+        } else if self.editor.is_playing() {
+            // Ctrl+P → Play/Pause toggle
+            self.handle_play_action(PlayControlAction::Pause, ctx.world);
+        } else {
+            self.handle_play_action(PlayControlAction::Play, ctx.world);
+        }
+        true
+    }
+
+    /// Top-level key handler. Play shortcuts and F11 always work; every
+    /// other key goes to the game while it has input focus. With the editor
+    /// focused, editor shortcuts apply while Editing/Paused and mid-play
+    /// keys stay with the editor.
     pub(super) fn handle_editor_key(&mut self, key: KeyCode, ctx: &mut GameContext) {
+        let game_focused = self.editor.input_focus.game_has_focus();
         // A focused text input (inspector value box) owns the keyboard:
         // Delete/Backspace edit the buffer, they must not delete the entity.
         // Enter/Tab/Escape are handled by the widget itself, which clears focus.
-        if ctx.ui.wants_keyboard() {
+        if !game_focused && ctx.ui.wants_keyboard() {
             return;
         }
 
@@ -112,27 +255,38 @@ impl<G: Game> EditorGame<G> {
         let shift = ctx.input.keyboard().is_key_pressed(KeyCode::ShiftLeft)
             || ctx.input.keyboard().is_key_pressed(KeyCode::ShiftRight);
 
-        // Play state shortcuts (always intercepted)
-        if key == KeyCode::KeyP && ctrl && shift {
-            // Ctrl+Shift+P → Stop
-            if self.handle_play_action(PlayControlAction::Stop, ctx.world) {
-                self.inner.on_play_stopped(ctx);
-            }
+        if self.handle_play_shortcut(key, ctrl, shift, ctx) {
             return;
         }
-        if key == KeyCode::KeyP && ctrl {
-            // Ctrl+P → Play/Pause toggle
-            if self.editor.is_playing() {
-                self.handle_play_action(PlayControlAction::Pause, ctx.world);
-            } else {
-                self.handle_play_action(PlayControlAction::Play, ctx.world);
+        if key == KeyCode::F11 {
+            // F11 → toggle the distraction-free fullscreen play preview
+            self.toggle_fullscreen_play();
+            return;
+        }
+
+        if game_focused {
+            if self.editor.input_focus.press(key) {
+                self.inner.on_key_pressed(key, ctx);
             }
             return;
         }
 
-        // During play mode, forward keys to inner game (skip editor shortcuts)
+        if key == KeyCode::Space && shift && !ctrl {
+            // Shift+Space → maximize/restore the hovered panel (mid-play
+            // too, so the game view can be maximized)
+            self.editor.toggle_maximize_at(ctx.ui.mouse_pos());
+            return;
+        }
+
+        // Mid-play with the editor focused: the game doesn't get the key and
+        // editing shortcuts stay off
         if self.editor.is_playing() {
-            self.inner.on_key_pressed(key, ctx);
+            return;
+        }
+
+        // Camera bookmarks: Ctrl+1..9 stores, 1..9 recalls
+        if let Some(slot) = editor::CameraBookmarks::slot_for_key(key) {
+            self.handle_camera_bookmark_key(slot, ctrl);
             return;
         }
 
@@ -153,21 +307,23 @@ impl<G: Game> EditorGame<G> {
                     self.editor.mark_dirty();
                 }
             }
+            KeyCode::KeyG if ctrl => self.group_selected_entities(ctx),
             KeyCode::KeyG => self.editor.toggle_grid(),
             KeyCode::KeyC if !ctrl => self.editor.toggle_colliders(),
+            KeyCode::KeyC if ctrl => self.copy_selected_entities(ctx),
+            KeyCode::KeyX if ctrl => self.cut_selected_entities(ctx),
+            KeyCode::KeyV if ctrl => self.paste_entities(ctx),
             KeyCode::KeyS if ctrl && shift => {
                 // Ctrl+Shift+S → Save As
                 let path = PathBuf::from(DEFAULT_SCENE_PATH);
                 if let Err(e) = self.save_scene_as(ctx.world, ctx.assets, path) {
-                    self.editor.status_bar.show_error(format!("Save failed: {}", e));
-                    log::error!("Failed to save: {}", e);
+                    self.report_save_error(&e);
                 }
             }
             KeyCode::KeyS if ctrl => {
                 // Ctrl+S → Save
                 if let Err(e) = self.save_scene(ctx.world, ctx.assets) {
-                    self.editor.status_bar.show_error(format!("Save failed: {}", e));
-                    log::error!("Failed to save: {}", e);
+                    self.report_save_error(&e);
                 }
             }
             KeyCode::KeyN if ctrl => {
@@ -179,6 +335,17 @@ impl<G: Game> EditorGame<G> {
                 let path = PathBuf::from(DEFAULT_SCENE_PATH);
                 self.load_scene_with_feedback(ctx.world, ctx.assets, &path);
             }
+            // Scene tabs: switching is blocked mid-session (Paused included)
+            KeyCode::KeyT if ctrl && !self.editor.in_play_session() => {
+                self.open_scene_tab(ctx.world);
+            }
+            KeyCode::KeyW if ctrl && !self.editor.in_play_session() => {
+                let active = self.scene_tabs.active_index();
+                self.close_scene_tab(active, ctx.world);
+            }
+            KeyCode::Tab if ctrl && !self.editor.in_play_session() => {
+                self.next_scene_tab(ctx.world);
+            }
             KeyCode::KeyD if ctrl => {
                 self.duplicate_selected_entities(ctx);
             }
@@ -188,6 +355,7 @@ impl<G: Game> EditorGame<G> {
             KeyCode::Equal => self.editor.zoom_camera(1.1),
             KeyCode::Minus => self.editor.zoom_camera(0.9),
             KeyCode::Digit0 => self.editor.reset_camera(),
+            KeyCode::KeyF if !ctrl => self.frame_selection(ctx),
             KeyCode::F5 => {
                 // F5 → Start/Resume play (only from Editing or Paused)
                 self.handle_play_action(PlayControlAction::Play, ctx.world);
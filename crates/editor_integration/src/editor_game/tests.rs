@@ -42,7 +42,7 @@ fn test_command_history_initialized() {
 #[test]
 fn test_editor_game_default_panels() {
     let editor = EditorGame::new(DummyGame);
-    assert_eq!(editor.editor.dock_area.panels().len(), 4);
+    assert_eq!(editor.editor.dock_area.panels().len(), 8);
 }
 
 #[test]
@@ -78,6 +78,26 @@ fn test_play_action_captures_snapshot() {
     assert!(editor.world_snapshot.is_some());
 }
 
+#[test]
+fn play_is_blocked_by_invalid_component_data() {
+    let mut editor = EditorGame::new(DummyGame);
+    let mut world = ecs::World::new();
+    let ground = world.spawn((physics::Collider::circle_collider(0.0),)).id();
+
+    editor.handle_play_action(PlayControlAction::Play, &mut world);
+    assert!(editor.editor.is_editing(), "a zero-radius collider must be fixed first");
+    assert!(editor.world_snapshot.is_none());
+    assert_eq!(editor.editor.component_issues.len(), 1);
+    assert!(editor.editor.dock_area.get_panel(editor::PanelId::CONSOLE).is_some_and(|panel| panel.visible));
+
+    if let Some(collider) = world.get_mut::<physics::Collider>(ground) {
+        *collider = physics::Collider::circle_collider(4.0);
+    }
+    editor.handle_play_action(PlayControlAction::Play, &mut world);
+    assert!(editor.editor.is_playing());
+    assert!(editor.editor.component_issues.is_empty());
+}
+
 #[test]
 fn test_play_pause_resume_stop_cycle() {
     let mut editor = EditorGame::new(DummyGame);
@@ -103,6 +123,28 @@ fn test_play_pause_resume_stop_cycle() {
     assert!(editor.world_snapshot.is_none());
 }
 
+#[test]
+fn test_step_pauses_a_running_game_and_queues_frames() {
+    let mut editor = EditorGame::new(DummyGame);
+    let mut world = ecs::World::new();
+
+    // Ignored outside a play session
+    editor.handle_play_action(PlayControlAction::Step(1), &mut world);
+    assert_eq!(editor.editor.frame_stepper.pending(), 0);
+
+    editor.handle_play_action(PlayControlAction::Play, &mut world);
+    editor.handle_play_action(PlayControlAction::Step(3), &mut world);
+    assert!(editor.editor.is_paused());
+    assert_eq!(editor.editor.frame_stepper.pending(), 3);
+
+    // Resume drops the queue; Stop resets the frame counter
+    editor.handle_play_action(PlayControlAction::Play, &mut world);
+    assert_eq!(editor.editor.frame_stepper.pending(), 0);
+    editor.editor.frame_stepper.record_frame();
+    editor.handle_play_action(PlayControlAction::Stop, &mut world);
+    assert_eq!(editor.editor.frame_stepper.frame(), 0);
+}
+
 #[test]
 fn test_stop_restores_world_state() {
     let mut editor = EditorGame::new(DummyGame);
@@ -126,33 +168,107 @@ fn test_stop_restores_world_state() {
 }
 
 #[test]
-fn test_stop_resets_transform_propagation_cache() {
-    use ecs::System;
+fn stop_keeps_changes_on_entities_marked_to_keep() {
+    let mut editor = EditorGame::new(DummyGame);
+    let mut world = ecs::World::new();
+    let kept = world.create_entity();
+    let discarded = world.create_entity();
+    for entity in [kept, discarded] {
+        world.add_component(&entity, common::Transform2D::new(Vec2::ZERO)).ok();
+    }
+
+    editor.handle_play_action(PlayControlAction::Play, &mut world);
+    for entity in [kept, discarded] {
+        if let Some(t) = world.get_mut::<common::Transform2D>(entity) {
+            t.position = Vec2::new(7.0, 8.0);
+        }
+    }
+    editor.editor.selection.select(kept);
+    editor.keep_play_changes_for_selection();
+    editor.handle_play_action(PlayControlAction::Stop, &mut world);
+
+    let position = |e| world.get::<common::Transform2D>(e).map(|t| t.position);
+    assert_eq!(position(kept), Some(Vec2::new(7.0, 8.0)));
+    assert_eq!(position(discarded), Some(Vec2::ZERO));
+    assert_eq!(editor.command_history.undo_name(), Some("Keep Play Changes"));
+    assert!(editor.editor.is_dirty());
+}
+
+#[test]
+fn test_stop_reverts_values_edited_during_play_and_offers_them() {
+    let mut editor = EditorGame::new(DummyGame);
+    let mut world = ecs::World::new();
+    let player = world.create_entity();
+    world.add_component(&player, common::Transform2D::new(Vec2::ZERO)).ok();
+
+    editor.handle_play_action(PlayControlAction::Play, &mut world);
+    // What the inspector does around an edit while playing
+    let before = editor::capture_inspectable_components(&world, player);
+    if let Some(t) = world.get_mut::<common::Transform2D>(player) {
+        t.position = Vec2::new(0.0, 12.0);
+    }
+    editor.editor.play_changes.mark_edited(editor::edited_fields(&before, &world, player));
+    editor.handle_play_action(PlayControlAction::Stop, &mut world);
+
+    let position = |world: &ecs::World| world.get::<common::Transform2D>(player).map(|t| t.position);
+    assert_eq!(position(&world), Some(Vec2::ZERO), "revert all is the default");
+    assert!(!editor.editor.is_dirty());
+    assert_eq!(editor.editor.play_changes.offered_edits().len(), 1);
+
+    // Keep Edited Values
+    let offered = editor.editor.play_changes.take_offered_edits();
+    let (cmd, _) = editor::apply_play_changes(&mut world, &offered).expect("offered edit applies");
+    editor.command_history.push_already_executed(cmd);
+    assert_eq!(position(&world), Some(Vec2::new(0.0, 12.0)));
+
+    // The next session starts with nothing offered
+    editor.handle_play_action(PlayControlAction::Play, &mut world);
+    assert!(editor.editor.play_changes.offered_edits().is_empty());
+}
+
+#[test]
+fn test_play_session_edits_stay_out_of_the_edit_history() {
+    use editor::commands::TransformGizmoCommand;
+    let mut editor = EditorGame::new(DummyGame);
+    let mut world = ecs::World::new();
+    let player = world.create_entity();
+    let at = |x, y| common::Transform2D::new(Vec2::new(x, y));
+    world.add_component(&player, at(0.0, 0.0)).ok();
+    let edit = TransformGizmoCommand::new(player, at(0.0, 0.0), at(5.0, 0.0));
+    editor.command_history.execute(Box::new(edit), &mut world);
+
+    editor.handle_play_action(PlayControlAction::Play, &mut world);
+    assert!(!editor.command_history.can_undo(), "play edits start a fresh history");
+    let play_edit = TransformGizmoCommand::new(player, at(5.0, 0.0), at(9.0, 9.0));
+    editor.command_history.execute(Box::new(play_edit), &mut world);
+    editor.handle_play_action(PlayControlAction::Stop, &mut world);
 
+    // Only the edit-mode entry is left, and it undoes against the edit world
+    assert_eq!(editor.command_history.len(), 1);
+    assert!(editor.command_history.undo(&mut world));
+    assert_eq!(world.get::<common::Transform2D>(player).map(|t| t.position), Some(Vec2::ZERO));
+}
+
+#[test]
+fn test_stop_resets_transform_propagation_cache() {
     let mut editor = EditorGame::new(DummyGame);
     let mut world = ecs::World::new();
     let entity = world.create_entity();
     world.add_component(&entity, common::Transform2D::new(glam::Vec2::new(10.0, 20.0))).ok();
 
-    // Propagate once so the transform system has a cached baseline.
-    editor.transform_system.update(&mut world, 0.016);
-    assert_eq!(editor.transform_system.tracked_entity_count(), 1);
-
     // Play, mutate during play, Stop (restores the snapshot).
     editor.handle_play_action(PlayControlAction::Play, &mut world);
+    assert!(!editor.reset_transforms, "Play keeps the world it started from");
     if let Some(t) = world.get_mut::<common::Transform2D>(entity) {
         t.position = glam::Vec2::new(999.0, 999.0);
     }
     editor.handle_play_action(PlayControlAction::Stop, &mut world);
 
-    // The restore wholesale-replaced the world — the propagation baseline
-    // must have been dropped so the next update recomputes from scratch.
-    assert_eq!(
-        editor.transform_system.tracked_entity_count(),
-        0,
-        "Stop must reset the transform system's cache"
-    );
-    editor.transform_system.update(&mut world, 0.016);
+    // The restore wholesale-replaced the world — the engine's propagation
+    // baseline is dropped at the end of this frame's update.
+    assert!(editor.reset_transforms, "Stop must reset the transform system's cache");
+    let mut transforms = ecs::TransformHierarchySystem::new();
+    ecs::System::update(&mut transforms, &mut world, 0.016);
     let global = world.get::<ecs::GlobalTransform2D>(entity).unwrap();
     assert_eq!(global.position, glam::Vec2::new(10.0, 20.0));
 }
@@ -171,16 +287,30 @@ fn test_build_pickable_entities_with_both_components() {
     sprite.depth = 5.0;
     world.add_component(&entity, sprite).ok();
 
-    let pickables = build_pickable_entities(&world);
+    let pickables = build_pickable_entities(&world, &engine_core::TextureSizes::new());
     assert_eq!(pickables.len(), 1);
     assert_eq!(pickables[0].entity_id, entity);
     assert_eq!(pickables[0].position, Vec2::new(100.0, 200.0));
     // Size matches the render path: sprite.scale * transform.scale *
-    // RENDER_UNIT = (0.5, 0.5) * (2, 2) * 80 = (80, 80) pixels
+    // white-texture size = (0.5, 0.5) * (2, 2) * 80 = (80, 80) pixels
     assert_eq!(pickables[0].size, Vec2::new(80.0, 80.0));
     assert_eq!(pickables[0].depth, 5.0);
 }
 
+#[test]
+fn imported_texture_pick_size_follows_pixels_per_unit() {
+    let mut world = ecs::World::new();
+    let entity = world.create_entity();
+    world.add_component(&entity, GlobalTransform2D::default()).ok();
+    world.add_component(&entity, ecs::sprite_components::Sprite::new(7)).ok();
+    let mut sizes = engine_core::TextureSizes::new();
+    // 64x32 texture imported at 2 pixels per unit
+    sizes.insert(7, 64, 32, 2.0);
+
+    let pickables = build_pickable_entities(&world, &sizes);
+    assert_eq!(pickables[0].size, Vec2::new(32.0, 16.0));
+}
+
 #[test]
 fn test_pick_hits_sprite_at_rendered_size_with_offset_panel() {
     // Regression for two shipped bugs at once:
@@ -198,7 +328,7 @@ fn test_pick_hits_sprite_at_rendered_size_with_offset_panel() {
     let mut viewport = editor::SceneViewport::new();
     viewport.set_viewport_bounds(common::Rect::new(300.0, 100.0, 800.0, 600.0));
 
-    let pickables = build_pickable_entities(&world);
+    let pickables = build_pickable_entities(&world, &engine_core::TextureSizes::new());
     let mut picker = editor::EntityPicker::new();
 
     // Click 30px off-center — inside the rendered 80x80 sprite, but a miss
@@ -220,7 +350,7 @@ fn test_build_pickable_entities_skips_without_sprite() {
     // Only GlobalTransform2D, no Sprite
     world.add_component(&entity, GlobalTransform2D::default()).ok();
 
-    let pickables = build_pickable_entities(&world);
+    let pickables = build_pickable_entities(&world, &engine_core::TextureSizes::new());
     assert!(pickables.is_empty());
 }
 
@@ -231,7 +361,7 @@ fn test_build_pickable_entities_skips_without_global_transform() {
     // Only Sprite, no GlobalTransform2D
     world.add_component(&entity, ecs::sprite_components::Sprite::new(0)).ok();
 
-    let pickables = build_pickable_entities(&world);
+    let pickables = build_pickable_entities(&world, &engine_core::TextureSizes::new());
     assert!(pickables.is_empty());
 }
 
@@ -263,7 +393,7 @@ fn test_build_pickable_entities_multiple() {
     let e3 = world.create_entity();
     world.add_component(&e3, GlobalTransform2D::default()).ok();
 
-    let pickables = build_pickable_entities(&world);
+    let pickables = build_pickable_entities(&world, &engine_core::TextureSizes::new());
     assert_eq!(pickables.len(), 2);
 
     let ids: Vec<_> = pickables.iter().map(|p| p.entity_id).collect();
@@ -458,6 +588,21 @@ fn test_physics_settings_preserved_on_new() {
     assert!(editor.physics_settings.is_none());
 }
 
+#[test]
+fn test_play_schedules_the_scene_physics_settings() {
+    let mut editor = EditorGame::new(DummyGame);
+    let mut world = ecs::World::new();
+
+    editor.handle_play_action(PlayControlAction::Play, &mut world);
+    assert!(editor.apply_physics_settings, "applied before the session's first step");
+
+    // Resuming from pause keeps the live simulation
+    editor.apply_physics_settings = false;
+    editor.handle_play_action(PlayControlAction::Pause, &mut world);
+    editor.handle_play_action(PlayControlAction::Play, &mut world);
+    assert!(!editor.apply_physics_settings);
+}
+
 #[test]
 fn test_scene_display_in_status() {
     let editor = EditorGame::new(DummyGame);
@@ -498,7 +643,8 @@ fn test_render_overrides_camera_from_viewport() {
     let world = World::new();
     let mut sprites = renderer::sprite::SpriteBatcher::new();
     let mut camera = common::Camera::default();
-    let glyph_textures = std::collections::HashMap::new();
+    let glyph_textures = engine_core::contexts::UiAtlasRegions::default();
+    let texture_sizes = engine_core::TextureSizes::new();
     let window_size = Vec2::new(1600.0, 900.0);
     let mut ctx = engine_core::contexts::RenderContext {
         world: &world,
@@ -507,6 +653,8 @@ fn test_render_overrides_camera_from_viewport() {
         window_size,
         ui_commands: &[],
         glyph_textures: &glyph_textures,
+        texture_sizes: &texture_sizes,
+        render_stats: renderer::RenderStats::default(),
     };
 
     engine_core::Game::render(&mut editor_game, &mut ctx);
@@ -625,3 +773,64 @@ fn test_gizmo_scale_undo_restores_transform_and_collider_together() {
         other => panic!("unexpected shape {other:?}"),
     }
 }
+
+#[test]
+fn test_stop_brings_back_chrome_hidden_by_fullscreen_play() {
+    let mut editor = EditorGame::new(DummyGame);
+    let mut world = ecs::World::new();
+    editor.toggle_fullscreen_play();
+
+    editor.handle_play_action(PlayControlAction::Play, &mut world);
+    assert!(editor.editor.is_chrome_hidden());
+
+    editor.handle_play_action(PlayControlAction::Stop, &mut world);
+    assert!(!editor.editor.is_chrome_hidden());
+    assert!(editor.editor.is_fullscreen_play());
+}
+
+#[test]
+fn test_profiler_sample_splits_the_update_between_game_and_editor() {
+    use engine_core::FrameProfile;
+
+    let profile = FrameProfile {
+        update: 0.005,
+        systems: vec![ecs::SystemTiming::new("ai", 0.001)],
+        physics: 0.002,
+        particles: 0.0,
+        entities: 1500,
+    };
+    let stats = renderer::RenderStats { sprites: 120, draw_calls: 7, ..Default::default() };
+    let sample = super::profiler::profiler_sample(&profile, 0.003, stats);
+
+    let names: Vec<&str> = sample.timings.iter().map(|timing| timing.name.as_str()).collect();
+    assert_eq!(names, [super::profiler::GAME_TIMING, super::profiler::EDITOR_TIMING, "ai", "Physics", "Particles"]);
+    assert!((sample.timings[1].seconds - 0.002).abs() < 1e-6, "update minus the game's share");
+    assert!((sample.total_ms() - 8.0).abs() < 1e-3);
+    assert_eq!((sample.sprites, sample.draw_calls, sample.entities), (120, 7, 1500));
+}
+
+#[test]
+fn test_play_starts_a_fresh_profile() {
+    let mut editor = EditorGame::new(DummyGame);
+    let mut world = ecs::World::new();
+    editor.editor.profiler.record(editor::ProfilerSample::default());
+
+    editor.handle_play_action(PlayControlAction::Play, &mut world);
+    assert!(editor.editor.profiler.is_empty());
+}
+
+#[test]
+fn test_play_gives_the_game_input_focus_until_pause_or_stop() {
+    let mut editor = EditorGame::new(DummyGame);
+    let mut world = ecs::World::new();
+    assert!(!editor.editor.input_focus.game_has_focus());
+
+    editor.handle_play_action(PlayControlAction::Play, &mut world);
+    assert!(editor.editor.input_focus.game_has_focus());
+    editor.handle_play_action(PlayControlAction::Pause, &mut world);
+    assert!(!editor.editor.input_focus.game_has_focus(), "paused: the editor has it");
+    editor.handle_play_action(PlayControlAction::Play, &mut world);
+    assert!(editor.editor.input_focus.game_has_focus(), "resumed");
+    editor.handle_play_action(PlayControlAction::Stop, &mut world);
+    assert!(!editor.editor.input_focus.game_has_focus());
+}
@@ -1,9 +1,13 @@
-//! Viewport picking (click + rectangle selection) and gizmo dragging.
+//! Click-to-focus while playing, viewport picking (click + rectangle
+//! selection), measure-tool drags, selection outlines, collider handle
+//! dragging, and gizmo dragging.
+//! Entities hidden in the hierarchy can't be picked; locked ones get no
+//! gizmo or collider handles.
 
 use glam::Vec2;
 
 use ecs::{GlobalTransform2D, Pair, World};
-use editor::{PanelId, PickableEntity};
+use editor::{EditorTool, PanelId, PickableEntity, ViewportInputResult};
 use engine_core::contexts::GameContext;
 use engine_core::Game;
 
@@ -13,6 +17,20 @@ use crate::entity_ops;
 use super::EditorGame;
 
 impl<G: Game> EditorGame<G> {
+    /// Click-to-focus while Playing: a click in the scene view gives the
+    /// game keyboard and mouse input, a click anywhere else the editor.
+    pub(super) fn update_input_focus(&mut self, ctx: &GameContext) {
+        if !self.editor.is_playing() || !ctx.ui.mouse_just_pressed() {
+            return;
+        }
+        let position = ctx.ui.mouse_pos();
+        // A menu dropdown open over the scene view takes the click
+        let game_view = self.editor.scene_view_bounds().filter(|_| !ctx.ui.is_input_blocked_at(position));
+        if self.editor.input_focus.click(position, game_view) && !self.editor.input_focus.game_has_focus() {
+            self.editor.status_bar.show_message("Input goes to the editor (click the scene view to return it to the game)");
+        }
+    }
+
     /// Handle viewport input: pan/zoom plus click and rectangle selection.
     pub(super) fn handle_viewport_picking(&mut self, ctx: &mut GameContext) {
         if self.editor.is_playing() {
@@ -51,9 +69,14 @@ impl<G: Game> EditorGame<G> {
             return;
         }
 
+        if self.editor.current_tool() == EditorTool::Measure {
+            self.handle_measure_drag(&input_result);
+            return;
+        }
+
         if input_result.clicked {
             self.editor.close_add_component_popup();
-            let pickables = build_pickable_entities(ctx.world);
+            let pickables = self.scene_pickables(ctx);
             let pick_result = self.editor.picker.pick_at_screen_pos(
                 &self.editor.viewport,
                 input_result.click_position,
@@ -78,7 +101,7 @@ impl<G: Game> EditorGame<G> {
             && input_result.selection_start != Vec2::ZERO
             && !input_result.clicked
         {
-            let pickables = build_pickable_entities(ctx.world);
+            let pickables = self.scene_pickables(ctx);
             let pick_result = self.editor.picker.pick_in_screen_rect(
                 &self.editor.viewport,
                 input_result.selection_start,
@@ -95,6 +118,38 @@ impl<G: Game> EditorGame<G> {
         }
     }
 
+    /// Pickable entities in the scene view, minus those hidden with the
+    /// hierarchy eye toggle.
+    fn scene_pickables(&self, ctx: &GameContext) -> Vec<PickableEntity> {
+        let mut pickables = build_pickable_entities(ctx.world, ctx.assets.texture_sizes());
+        if !self.editor.visibility.is_empty() {
+            let hidden = self.editor.visibility.hidden_entities(ctx.world);
+            pickables.retain(|pickable| !hidden.contains(&pickable.entity_id));
+        }
+        pickables
+    }
+
+    /// Measure tool: the primary-button drag measures instead of selecting
+    /// (snapped to the grid when snapping is on); a plain click clears the
+    /// last measurement.
+    fn handle_measure_drag(&mut self, input_result: &ViewportInputResult) {
+        if input_result.clicked {
+            self.editor.measure.clear();
+            return;
+        }
+        let released = !input_result.selection_drag_active && input_result.selection_start != Vec2::ZERO;
+        if !input_result.selection_drag_active && !released {
+            return;
+        }
+        let start = self.editor.screen_to_world(input_result.selection_start);
+        let end = self.editor.screen_to_world(input_result.selection_end);
+        let snap = self.editor.is_snap_to_grid().then(|| self.editor.grid_size());
+        self.editor.measure.drag(start, end, snap);
+        if released {
+            self.editor.measure.finish();
+        }
+    }
+
     /// Handle a texture dropped from the asset browser onto the scene view:
     /// dropping onto an existing sprite reskins it (assign); dropping onto
     /// empty space spawns a new sprite entity at that world position. Both
@@ -106,7 +161,7 @@ impl<G: Game> EditorGame<G> {
         path: &str,
         drop_pos: Vec2,
     ) {
-        let pickables = build_pickable_entities(ctx.world);
+        let pickables = self.scene_pickables(ctx);
         let hit = self
             .editor
             .picker
@@ -142,15 +197,95 @@ impl<G: Game> EditorGame<G> {
         }
     }
 
+    /// Outline every selected entity in the scene view (primary selection
+    /// in its own color). Drawn before the gizmo so the gizmo stays on top.
+    pub(super) fn render_selection_outlines(&mut self, ctx: &mut GameContext, content_areas: &[(PanelId, common::Rect)]) {
+        if self.editor.selection.is_empty() {
+            return;
+        }
+        let Some((_, bounds)) = content_areas.iter().find(|(id, _)| *id == PanelId::SCENE_VIEW) else {
+            return;
+        };
+        let pickables = self.scene_pickables(ctx);
+        editor::render_selection_overlay(
+            ctx.ui,
+            &self.editor.viewport,
+            &self.editor.selection,
+            &pickables,
+            &self.editor.theme.colors.selection_overlay_colors(),
+            *bounds,
+        );
+    }
+
+    /// Render collider shape handles for the primary selection while the
+    /// collider overlay is visible, resizing the `Collider` live and
+    /// recording a single undo entry per drag. Locked entities get none.
+    pub(super) fn handle_collider_gizmo(&mut self, ctx: &mut GameContext, content_areas: &[(PanelId, common::Rect)]) {
+        if self.editor.is_playing() || !self.editor.is_colliders_visible() {
+            self.editor.collider_gizmo.cancel();
+            self.collider_drag_start = None;
+            return;
+        }
+        let Some(entity_id) = self.editor.selection.primary() else {
+            return;
+        };
+        if self.editor.locks.is_locked_in(ctx.world, entity_id) {
+            return;
+        }
+        let Some((_, bounds)) = content_areas.iter().find(|(id, _)| *id == PanelId::SCENE_VIEW) else {
+            return;
+        };
+        // The transform gizmo owns the drag until it is released.
+        if self.gizmo_drag_start.is_some() {
+            return;
+        }
+        let Some(transform) = ctx.world.get::<ecs::sprite_components::Transform2D>(entity_id).copied() else {
+            return;
+        };
+        let Some(mut collider) = ctx.world.get::<physics::components::Collider>(entity_id).cloned() else {
+            return;
+        };
+
+        let snap = self.editor.is_snap_to_grid().then(|| self.editor.grid_size());
+        let before = collider.clone();
+        ctx.ui.push_clip_rect(*bounds);
+        let changed = self.editor.collider_gizmo.render(ctx.ui, &self.editor.viewport, &transform, &mut collider, snap);
+        ctx.ui.pop_clip_rect();
+
+        if self.editor.collider_gizmo.is_active() && self.collider_drag_start.is_none() {
+            self.collider_drag_start = Some(before);
+        }
+        if changed {
+            if let Some(live) = ctx.world.get_mut::<physics::components::Collider>(entity_id) {
+                *live = collider.clone();
+            }
+        }
+
+        // Handle released — record ONE undo entry for the whole drag
+        if !self.editor.collider_gizmo.is_active() {
+            if let Some(initial) = self.collider_drag_start.take() {
+                if initial != collider {
+                    let cmd = editor::commands::SetColliderCommand::new(entity_id, initial, collider, "gizmo_collider");
+                    self.command_history.push_already_executed(Box::new(cmd));
+                    self.editor.mark_dirty();
+                }
+            }
+        }
+    }
+
     /// Render the gizmo for the primary selection and apply drag deltas,
-    /// recording a single undo entry per drag.
+    /// recording a single undo entry per drag. Locked entities (selectable,
+    /// but not movable) get no gizmo.
     pub(super) fn handle_gizmo(&mut self, ctx: &mut GameContext, content_areas: &[(PanelId, common::Rect)]) {
-        if self.editor.is_playing() {
+        if self.editor.is_playing() || self.editor.collider_gizmo.is_active() {
             return;
         }
         let Some(entity_id) = self.editor.selection.primary() else {
             return;
         };
+        if self.editor.locks.is_locked_in(ctx.world, entity_id) {
+            return;
+        }
         if !content_areas.iter().any(|(id, _)| *id == PanelId::SCENE_VIEW) {
             return;
         }
@@ -276,21 +411,54 @@ pub(super) fn scale_collider(collider: &mut physics::components::Collider, facto
 ///
 /// Queries for entities that have both `GlobalTransform2D` and `Sprite` components,
 /// which are required for viewport picking (position + visual size).
-pub(super) fn build_pickable_entities(world: &World) -> Vec<PickableEntity> {
-    let entities = world.query_entities::<Pair<GlobalTransform2D, ecs::sprite_components::Sprite>>();
+/// Framing bounds for the selected entities: sprite bounds where there is a
+/// sprite, otherwise a point at the entity's world position.
+pub(super) fn selection_frame_entities(
+    world: &World,
+    selection: &editor::Selection,
+    texture_sizes: &engine_core::TextureSizes,
+) -> Vec<PickableEntity> {
+    let mut entities: Vec<PickableEntity> = build_pickable_entities(world, texture_sizes)
+        .into_iter()
+        .filter(|entity| selection.contains(entity.entity_id))
+        .collect();
+    for entity_id in selection.selected() {
+        if entities.iter().any(|entity| entity.entity_id == entity_id) {
+            continue;
+        }
+        if let Some(global_t) = world.get::<GlobalTransform2D>(entity_id) {
+            entities.push(PickableEntity::new(entity_id, global_t.position, Vec2::ZERO, 0.0));
+        }
+    }
+    entities
+}
+
+pub(super) fn build_pickable_entities(world: &World, texture_sizes: &engine_core::TextureSizes) -> Vec<PickableEntity> {
+    let entities = world.query_active::<Pair<GlobalTransform2D, ecs::sprite_components::Sprite>>();
+    let layers = world.resource::<ecs::RenderLayers>().cloned().unwrap_or_default();
+    let sort_mode = world.resource::<ecs::SpriteSortMode>().copied().unwrap_or_default();
     entities
         .into_iter()
         .filter_map(|entity_id| {
             let global_t = world.get::<GlobalTransform2D>(entity_id)?;
             let sprite = world.get::<ecs::sprite_components::Sprite>(entity_id)?;
             // Visual size must match the render path (engine_core game.rs):
-            // sprites draw at scale * sprite.scale * RENDER_UNIT pixels.
-            let size = sprite.scale * global_t.scale * engine_core::RENDER_UNIT;
+            // sprites draw at scale * sprite.scale * the texture's natural
+            // (pixels-per-unit) size.
+            let natural_size = texture_sizes.sprite_size(sprite.texture_handle, sprite.tex_region);
+            let size = sprite.scale * global_t.scale * natural_size;
             Some(PickableEntity::new(
                 entity_id,
                 global_t.position,
                 size,
-                sprite.depth,
+                // Same layered depth the renderer sorts by
+                sort_mode.sort_depth(
+                    &layers,
+                    &sprite.layer,
+                    sprite.depth,
+                    global_t.position,
+                    world.get::<ecs::SortBias>(entity_id),
+                ),
             ))
         })
         .collect()
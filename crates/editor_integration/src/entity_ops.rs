@@ -4,43 +4,49 @@
 //! dependency — fully testable headlessly.
 
 use ecs::sprite_components::{Name, Sprite};
-use ecs::hierarchy::GlobalTransform2D;
-use ecs::{EntityId, World, WorldHierarchyExt};
-use editor::{capture_all_components, restore_components, Selection};
+use ecs::{Bundle, EntityId, World, WorldHierarchyExt};
+use editor::commands::AddComponentCommand;
+use editor::{capture_all_components, restore_components, ComponentKind, Selection};
 use glam::Vec2;
 use physics::components::{Collider, RigidBody, RigidBodyType};
 
 use crate::constants::DUPLICATE_OFFSET;
 
 // Component add/remove and the add-component popup are driven by
-// `editor::ComponentKind` — the registry in editor/src/stored_component.rs
-// is the single source of truth for editor-visible component types.
+// `editor::ComponentKind` — the registry in editor/src/stored_component/
+// is the single source of truth for editor-visible component types and
+// their dependencies.
 
-/// Create a base entity with Transform2D, GlobalTransform2D, and Name, then select it.
+/// Spawn an entity with Transform2D, Name, and the components of `extra`,
+/// then select it. `TransformHierarchySystem` adds its GlobalTransform2D.
 fn create_base_entity(
     world: &mut World,
     selection: &mut Selection,
     position: Vec2,
     label: &str,
     counter: &mut u32,
+    extra: impl Bundle,
 ) -> EntityId {
     *counter += 1;
-    let entity = world.create_entity();
-    world.add_component(&entity, common::Transform2D::new(position)).ok();
-    world.add_component(&entity, GlobalTransform2D::default()).ok();
-    world.add_component(&entity, Name::new(format!("{} {}", label, counter))).ok();
+    let entity = world
+        .spawn((
+            common::Transform2D::new(position),
+            Name::new(format!("{} {}", label, counter)),
+        ))
+        .insert(extra)
+        .id();
     selection.select(entity);
     entity
 }
 
-/// Create an empty entity with Transform2D, GlobalTransform2D, and Name.
+/// Create an empty entity with Transform2D and Name.
 pub fn create_empty_entity(
     world: &mut World,
     selection: &mut Selection,
     position: Vec2,
     counter: &mut u32,
 ) -> EntityId {
-    create_base_entity(world, selection, position, "Entity", counter)
+    create_base_entity(world, selection, position, "Entity", counter, ())
 }
 
 /// Create a sprite entity (empty + Sprite).
@@ -50,9 +56,7 @@ pub fn create_sprite_entity(
     position: Vec2,
     counter: &mut u32,
 ) -> EntityId {
-    let entity = create_base_entity(world, selection, position, "Sprite", counter);
-    world.add_component(&entity, Sprite::new(0)).ok();
-    entity
+    create_base_entity(world, selection, position, "Sprite", counter, (Sprite::new(0),))
 }
 
 /// Create a camera entity (empty + Camera).
@@ -62,9 +66,7 @@ pub fn create_camera_entity(
     position: Vec2,
     counter: &mut u32,
 ) -> EntityId {
-    let entity = create_base_entity(world, selection, position, "Camera", counter);
-    world.add_component(&entity, common::Camera::default()).ok();
-    entity
+    create_base_entity(world, selection, position, "Camera", counter, (common::Camera::default(),))
 }
 
 /// Create a physics body entity (empty + Sprite + RigidBody + Collider).
@@ -80,11 +82,8 @@ pub fn create_physics_body(
         RigidBodyType::Dynamic => "DynamicBody",
         RigidBodyType::Kinematic => "KinematicBody",
     };
-    let entity = create_base_entity(world, selection, position, type_label, counter);
-    world.add_component(&entity, Sprite::new(0)).ok();
-    world.add_component(&entity, RigidBody::default().with_body_type(body_type)).ok();
-    world.add_component(&entity, Collider::default()).ok();
-    entity
+    let body = (Sprite::new(0), RigidBody::default().with_body_type(body_type), Collider::default());
+    create_base_entity(world, selection, position, type_label, counter, body)
 }
 
 /// Dispatch a menu action string to the appropriate create function.
@@ -136,6 +135,23 @@ pub fn assign_sprite_texture(
     true
 }
 
+/// Rename an entity as one undo entry; a blank name clears it. Returns
+/// false (and records nothing) when the name is unchanged.
+pub fn rename_entity(
+    world: &mut World,
+    entity: EntityId,
+    name: &str,
+    history: &mut editor::CommandHistory,
+) -> bool {
+    match editor::commands::RenameEntityCommand::new(world, entity, name) {
+        Some(command) => {
+            history.execute(Box::new(command), world);
+            true
+        }
+        None => false,
+    }
+}
+
 /// Create a sprite entity showing `texture_handle` at `position`, named from
 /// the asset's file stem, and record its creation for undo. Used when a
 /// texture is dropped on empty viewport space.
@@ -148,14 +164,44 @@ pub fn create_sprite_entity_with_texture(
     counter: &mut u32,
     history: &mut editor::CommandHistory,
 ) -> EntityId {
-    let entity = create_base_entity(world, selection, position, name_stem, counter);
-    world.add_component(&entity, Sprite::new(texture_handle)).ok();
+    let sprite = (Sprite::new(texture_handle),);
+    let entity = create_base_entity(world, selection, position, name_stem, counter, sprite);
     history.push_already_executed(Box::new(
         editor::commands::CreateEntityCommand::already_created(world, entity),
     ));
     entity
 }
 
+/// Add a default `kind` component to an entity as one undo entry. With
+/// `include_dependencies`, the required components it is missing (registry
+/// `requires [..]`) are added first in the same entry. Returns the kinds
+/// added, in order.
+pub fn add_component_to_entity(
+    world: &mut World,
+    entity: EntityId,
+    kind: ComponentKind,
+    include_dependencies: bool,
+    history: &mut editor::CommandHistory,
+) -> Vec<ComponentKind> {
+    let mut kinds = if include_dependencies {
+        editor::missing_dependencies(world, entity, kind)
+    } else {
+        Vec::new()
+    };
+    kinds.push(kind);
+
+    if let [only] = kinds.as_slice() {
+        history.execute(Box::new(AddComponentCommand::new(entity, *only)), world);
+    } else {
+        let commands: Vec<Box<dyn editor::EditorCommand>> = kinds
+            .iter()
+            .map(|&k| Box::new(AddComponentCommand::new(entity, k)) as Box<dyn editor::EditorCommand>)
+            .collect();
+        history.execute(Box::new(editor::commands::MacroCommand::new("Add Components", commands)), world);
+    }
+    kinds
+}
+
 /// Delete all selected entities, reparenting their children.
 ///
 /// For each deleted entity:
@@ -19,10 +19,22 @@ mod tests {
         let entity = create_empty_entity(&mut world, &mut sel, Vec2::ZERO, &mut counter);
 
         assert!(world.get::<common::Transform2D>(entity).is_some());
-        assert!(world.get::<GlobalTransform2D>(entity).is_some());
         assert!(world.get::<Name>(entity).is_some());
     }
 
+    #[test]
+    fn test_created_entity_gets_global_transform_from_hierarchy_system() {
+        use ecs::{GlobalTransform2D, System, TransformHierarchySystem};
+
+        let (mut world, mut sel) = new_world_and_selection();
+        let mut counter = 0;
+        let entity = create_empty_entity(&mut world, &mut sel, Vec2::new(30.0, 40.0), &mut counter);
+
+        TransformHierarchySystem::new().update(&mut world, 0.016);
+        let global = world.get::<GlobalTransform2D>(entity).expect("inserted by the system");
+        assert_eq!(global.position, Vec2::new(30.0, 40.0));
+    }
+
     #[test]
     fn test_create_empty_at_correct_position() {
         let (mut world, mut sel) = new_world_and_selection();
@@ -331,6 +343,42 @@ mod tests {
     // in editor/src/commands.rs).
 }
 
+#[cfg(test)]
+mod add_component_tests {
+    use super::*;
+    use editor::CommandHistory;
+
+    #[test]
+    fn add_with_dependencies_adds_required_components_in_one_undo_entry() {
+        let mut world = World::new();
+        let entity = world.create_entity();
+        let mut history = CommandHistory::new();
+
+        let added = add_component_to_entity(&mut world, entity, ComponentKind::Collider, true, &mut history);
+
+        assert_eq!(added, vec![ComponentKind::RigidBody, ComponentKind::Collider]);
+        assert!(world.get::<RigidBody>(entity).is_some());
+        assert!(world.get::<Collider>(entity).is_some());
+
+        history.undo(&mut world);
+        assert!(world.get::<RigidBody>(entity).is_none());
+        assert!(world.get::<Collider>(entity).is_none());
+    }
+
+    #[test]
+    fn add_without_dependencies_adds_only_the_chosen_component() {
+        let mut world = World::new();
+        let entity = world.create_entity();
+        let mut history = CommandHistory::new();
+
+        let added = add_component_to_entity(&mut world, entity, ComponentKind::Collider, false, &mut history);
+
+        assert_eq!(added, vec![ComponentKind::Collider]);
+        assert!(world.get::<RigidBody>(entity).is_none());
+        assert_eq!(editor::validate_component_dependencies(&world).len(), 1);
+    }
+}
+
 #[cfg(test)]
 mod asset_assignment_tests {
     use super::*;
@@ -387,4 +435,22 @@ mod asset_assignment_tests {
         assert!(history.undo(&mut world));
         assert!(world.get::<Sprite>(entity).is_none(), "undo deletes the spawned entity");
     }
+
+    #[test]
+    fn test_rename_entity_records_one_undo_entry() {
+        let mut world = World::new();
+        let mut selection = Selection::new();
+        let mut counter = 0;
+        let entity = create_empty_entity(&mut world, &mut selection, Vec2::ZERO, &mut counter);
+        let original = world.get::<Name>(entity).unwrap().as_str().to_string();
+        let mut history = CommandHistory::new();
+
+        assert!(!rename_entity(&mut world, entity, &original, &mut history), "unchanged");
+        assert!(rename_entity(&mut world, entity, "Player", &mut history));
+        assert_eq!(world.get::<Name>(entity).unwrap().as_str(), "Player");
+        assert_eq!(history.undo_name(), Some("Rename Entity"));
+
+        assert!(history.undo(&mut world));
+        assert_eq!(world.get::<Name>(entity).unwrap().as_str(), original);
+    }
 }
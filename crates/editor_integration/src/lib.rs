@@ -23,5 +23,7 @@ mod constants;
 mod editor_game;
 mod entity_ops;
 mod panel_renderer;
+mod plugins;
 
-pub use editor_game::run_game_with_editor;
+pub use editor_game::{run_builder_with_editor, run_example_with_editor, run_game_with_editor};
+pub use plugins::EditorPluginExt;
@@ -5,7 +5,7 @@
 //! `editor::asset_browser`; this file owns the AssetManager interaction
 //! (lazy thumbnail loads) and the panel drawing.
 
-use std::path::Path;
+use std::path::PathBuf;
 
 use glam::Vec2;
 
@@ -49,11 +49,28 @@ pub(super) fn render_asset_browser(
     command_history: &mut CommandHistory,
 ) {
     // ── Scan (first open or Rescan click) ───────────────────────────
+    // The fs walk runs on a background task so large asset trees don't
+    // stall the frame; progress and the result land in the status bar.
     let rescan_bounds = ui::Rect::new(bounds.x + PADDING, bounds.y + 2.0, 70.0, 20.0);
     let rescan_clicked = ctx.ui.button("asset_rescan", "Rescan", rescan_bounds);
-    if !editor.asset_browser.scanned || rescan_clicked {
-        let entries = scan_assets(Path::new(ctx.assets.base_path()));
-        editor.asset_browser.apply_scan(entries);
+    let scan_pending = editor.asset_browser.pending_scan.is_some();
+    if (!editor.asset_browser.scanned || rescan_clicked) && !scan_pending {
+        let base = PathBuf::from(ctx.assets.base_path());
+        let handle = editor.background_tasks.spawn("Scan assets", move |progress| {
+            let entries = scan_assets(&base);
+            progress.report(1.0);
+            Ok(entries)
+        });
+        editor.asset_browser.pending_scan = Some(handle);
+    }
+    let finished = editor.asset_browser.pending_scan.as_ref().and_then(|handle| handle.try_take());
+    if let Some(result) = finished {
+        editor.asset_browser.pending_scan = None;
+        match result {
+            Ok(entries) => editor.asset_browser.apply_scan(entries),
+            // The failure is already in the status bar; don't rescan every frame.
+            Err(_) => editor.asset_browser.scanned = true,
+        }
     }
 
     let count_label = format!("{} assets", editor.asset_browser.entries.len());
@@ -64,6 +81,8 @@ pub(super) fn render_asset_browser(
         editor.theme.fonts.small,
     );
 
+    render_batch_import(editor, ctx, bounds);
+
     // ── Lazy thumbnail loading (bounded per frame) ──────────────────
     let mut loads = 0;
     for entry in editor.asset_browser.entries.iter_mut() {
@@ -201,6 +220,53 @@ pub(super) fn render_asset_browser(
     }
 }
 
+/// Header controls for batch import: a pixels-per-unit field and a button
+/// that applies it to every image in the browser, then persists the
+/// project's import settings.
+fn render_batch_import(editor: &mut EditorContext, ctx: &mut GameContext, bounds: common::Rect) {
+    let apply_bounds = ui::Rect::new(bounds.x + bounds.width - PADDING - 84.0, bounds.y + 2.0, 84.0, 20.0);
+    let input_bounds = ui::Rect::new(apply_bounds.x - 58.0, bounds.y + 2.0, 54.0, 20.0);
+    ctx.ui.label_styled(
+        "PPU",
+        Vec2::new(input_bounds.x - 28.0, bounds.y + 16.0),
+        editor.theme.text_muted,
+        editor.theme.fonts.small,
+    );
+
+    let project_default = ctx.assets.import_settings().default_pixels_per_unit;
+    let current = editor.asset_browser.batch_pixels_per_unit.unwrap_or(project_default);
+    let edited = ctx.ui.float_input("asset_batch_ppu", current, 0.01, 4096.0, input_bounds);
+    if edited != current {
+        editor.asset_browser.batch_pixels_per_unit = Some(edited);
+    }
+
+    if editor.is_playing() || !ctx.ui.button("asset_apply_ppu", "Apply PPU", apply_bounds) {
+        return;
+    }
+    let paths: Vec<&str> = editor
+        .asset_browser
+        .entries
+        .iter()
+        .filter(|e| e.kind == AssetKind::Image)
+        .map(|e| e.relative_path.as_str())
+        .collect();
+    if paths.is_empty() {
+        editor.status_bar.show_message("No images to import");
+        return;
+    }
+
+    let mut settings = ctx.assets.import_settings().clone();
+    let changed = settings.set_pixels_per_unit_batch(paths.iter().copied(), edited);
+    let total = paths.len();
+    ctx.assets.set_import_settings(settings);
+    match ctx.assets.save_import_settings() {
+        Ok(()) => editor
+            .status_bar
+            .show_message(format!("Imported {total} images at {edited:.2} pixels per unit ({changed} changed)")),
+        Err(e) => editor.status_bar.show_error(format!("Failed to save import settings: {e}")),
+    }
+}
+
 /// Draw the drag ghost (a translucent thumbnail following the cursor) while
 /// a texture drag is in flight. The overlay's blocking rect also makes
 /// widgets and viewport picking under the cursor inert for the frame.
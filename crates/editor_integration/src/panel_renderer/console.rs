@@ -0,0 +1,64 @@
+//! Console panel: editor diagnostics that don't fit the status bar —
+//! invalid component data (which blocks Play until fixed) and the
+//! translation keys UI text asked for but no locale provides.
+
+use glam::Vec2;
+
+use editor::EditorContext;
+use engine_core::contexts::GameContext;
+
+/// Panel content padding.
+const PADDING: f32 = 8.0;
+/// Height of the title line.
+const HEADER_HEIGHT: f32 = 20.0;
+/// Height of one message row.
+const ROW_HEIGHT: f32 = 18.0;
+/// Width of the Clear button.
+const CLEAR_WIDTH: f32 = 56.0;
+
+/// Render the Console panel content.
+pub(super) fn render_console(editor: &mut EditorContext, ctx: &mut GameContext, bounds: common::Rect) {
+    let theme = &editor.theme;
+    let x = bounds.x + PADDING;
+    let issues = editor.component_issues.errors();
+    let missing = ui::i18n::missing_keys();
+    if issues.is_empty() && missing.is_empty() {
+        ctx.ui.label_styled("No messages", Vec2::new(x, bounds.y + PADDING), theme.text_muted, theme.fonts.small);
+        return;
+    }
+
+    let title = format!("Invalid components ({}) · Missing translations ({})", issues.len(), missing.len());
+    ctx.ui.label_styled(&title, Vec2::new(x, bounds.y + PADDING), theme.text_secondary, theme.fonts.small);
+    // Component issues clear themselves once fixed; only translations need a button
+    if !missing.is_empty() {
+        let clear_rect = common::Rect::new(bounds.x + bounds.width - PADDING - CLEAR_WIDTH, bounds.y + 4.0, CLEAR_WIDTH, 18.0);
+        if ctx.ui.button("console_clear", "Clear", clear_rect) {
+            ui::i18n::with_localizer_mut(|localizer| localizer.clear_missing());
+        }
+    }
+
+    let rows = issues
+        .iter()
+        .map(|issue| {
+            let entity = ctx
+                .world
+                .get::<ecs::Name>(issue.entity)
+                .map_or_else(|| issue.entity.to_string(), |name| name.0.clone());
+            (format!("{entity} · {}: {}", issue.component, issue.message), theme.error_red)
+        })
+        .chain(missing.iter().map(|(locale, key)| (format!("[{locale}] no translation for `{key}`"), theme.warn_yellow)))
+        .collect::<Vec<_>>();
+
+    let list_top = bounds.y + PADDING + HEADER_HEIGHT;
+    let capacity = ((bounds.y + bounds.height - list_top) / ROW_HEIGHT).max(0.0) as usize;
+    let shown = if rows.len() > capacity { capacity.saturating_sub(1) } else { rows.len() };
+    for (index, (text, color)) in rows.iter().take(shown).enumerate() {
+        let y = list_top + index as f32 * ROW_HEIGHT;
+        ctx.ui.label_styled(text, Vec2::new(x, y), *color, theme.fonts.small);
+    }
+    if shown < rows.len() && capacity > 0 {
+        let y = list_top + shown as f32 * ROW_HEIGHT;
+        let more = format!("… and {} more", rows.len() - shown);
+        ctx.ui.label_styled(&more, Vec2::new(x, y), theme.text_muted, theme.fonts.small);
+    }
+}
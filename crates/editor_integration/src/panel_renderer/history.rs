@@ -0,0 +1,141 @@
+//! History panel: pinned restore points, then every retained undo entry
+//! oldest first with how long ago it was recorded. Clicking a row undoes or
+//! redoes as many steps as it takes to get back to that point.
+
+use std::time::{Duration, Instant};
+
+use glam::Vec2;
+
+use editor::{CommandHistory, EditorContext};
+use engine_core::contexts::GameContext;
+
+/// Panel content padding.
+const PADDING: f32 = 8.0;
+/// Height of the pin button row.
+const HEADER_HEIGHT: f32 = 28.0;
+/// Height of one history or restore point row.
+const ROW_HEIGHT: f32 = 18.0;
+/// Width of the pin button.
+const PIN_WIDTH: f32 = 130.0;
+/// Width of a restore point's unpin button.
+const UNPIN_WIDTH: f32 = 18.0;
+
+/// A click in the panel, applied after drawing.
+enum HistoryAction {
+    Jump(usize),
+    JumpToRestorePoint(usize),
+    Unpin(usize),
+    Pin,
+}
+
+/// Render the History panel content.
+pub(super) fn render_history(
+    editor: &mut EditorContext,
+    ctx: &mut GameContext,
+    bounds: common::Rect,
+    command_history: &mut CommandHistory,
+) {
+    let x = bounds.x + PADDING;
+    let width = bounds.width - PADDING * 2.0;
+    // The play world is discarded on Stop, so history is edit-only
+    let can_jump = !editor.is_playing();
+    let now = Instant::now();
+    let mut action = None;
+
+    let pin = common::Rect::new(x, bounds.y + PADDING, PIN_WIDTH, HEADER_HEIGHT - 6.0);
+    if ctx.ui.button_styled("history_pin", "Pin Restore Point", pin, can_jump) {
+        action = Some(HistoryAction::Pin);
+    }
+
+    // Restore points stay put above the scrolling list
+    let mut list_top = bounds.y + PADDING + HEADER_HEIGHT;
+    for (index, point) in command_history.restore_points().iter().enumerate() {
+        let rect = common::Rect::new(x, list_top, width - UNPIN_WIDTH, ROW_HEIGHT);
+        if ctx.ui.interact(format!("history_point_{index}").as_str(), rect, can_jump).clicked {
+            action = Some(HistoryAction::JumpToRestorePoint(index));
+        }
+        let unpin = common::Rect::new(x + width - UNPIN_WIDTH, list_top, UNPIN_WIDTH, ROW_HEIGHT);
+        if ctx.ui.button(format!("history_unpin_{index}").as_str(), "x", unpin) {
+            action = Some(HistoryAction::Unpin(index));
+        }
+        let text = format!("* {} ({})", point.label, format_age(now.duration_since(point.pinned_at)));
+        ctx.ui.label_styled(&text, Vec2::new(x, list_top + 2.0), editor.theme.warn_yellow, editor.theme.fonts.small);
+        list_top += ROW_HEIGHT;
+    }
+
+    // Row 0 is the oldest reachable state, row N the state after entry N
+    let rows = command_history.len() + 1;
+    let visible_height = (bounds.y + bounds.height - list_top).max(0.0);
+    let max_scroll = (rows as f32 * ROW_HEIGHT - visible_height).max(0.0);
+    if bounds.contains(ctx.ui.mouse_pos()) {
+        let delta = ctx.ui.scroll_delta();
+        if delta != 0.0 {
+            editor.history_scroll = (editor.history_scroll - delta * 30.0).clamp(0.0, max_scroll);
+        }
+    }
+    let scroll = editor.history_scroll.min(max_scroll);
+
+    let position = command_history.position();
+    let names = std::iter::once(("Initial State", None))
+        .chain(command_history.entries().map(|entry| (entry.name(), Some(entry.recorded_at()))));
+    for (row, (name, recorded_at)) in names.enumerate() {
+        let y = list_top + row as f32 * ROW_HEIGHT - scroll;
+        if y + ROW_HEIGHT < list_top || y > bounds.y + bounds.height {
+            continue;
+        }
+        let rect = common::Rect::new(x, y, width, ROW_HEIGHT);
+        if ctx.ui.interact(format!("history_row_{row}").as_str(), rect, can_jump && row != position).clicked {
+            action = Some(HistoryAction::Jump(row));
+        }
+        let color = if row == position {
+            editor.theme.accent_cyan
+        } else if row < position {
+            editor.theme.text_secondary
+        } else {
+            // Undone, still redoable
+            editor.theme.text_muted
+        };
+        let text = match recorded_at {
+            Some(at) => format!("{name} ({})", format_age(now.duration_since(at))),
+            None => name.to_string(),
+        };
+        ctx.ui.label_styled(&text, Vec2::new(x, y + 2.0), color, editor.theme.fonts.small);
+    }
+
+    let steps = match action {
+        Some(HistoryAction::Jump(position)) => command_history.jump_to(position, ctx.world),
+        Some(HistoryAction::JumpToRestorePoint(index)) => {
+            let label = command_history.restore_points().get(index).map(|point| point.label.clone());
+            let before = command_history.position();
+            command_history.jump_to_restore_point(index, ctx.world);
+            if let Some(label) = label {
+                editor.status_bar.show_message(format!("Restored: {label}"));
+            }
+            before.abs_diff(command_history.position())
+        }
+        Some(HistoryAction::Unpin(index)) => {
+            command_history.remove_restore_point(index);
+            0
+        }
+        Some(HistoryAction::Pin) => {
+            let label = format!("Restore Point {}", command_history.restore_points().len() + 1);
+            editor.status_bar.show_message(format!("Pinned {label}"));
+            command_history.pin_restore_point(label);
+            0
+        }
+        None => 0,
+    };
+    if steps > 0 {
+        editor.mark_dirty();
+    }
+}
+
+/// Short "how long ago" text for a history row.
+pub(super) fn format_age(age: Duration) -> String {
+    match age.as_secs() {
+        0..=4 => "just now".to_string(),
+        secs @ 5..=59 => format!("{secs}s ago"),
+        secs @ 60..=3599 => format!("{}m ago", secs / 60),
+        secs => format!("{}h ago", secs / 3600),
+    }
+}
@@ -1,19 +1,43 @@
-//! Inspector panel: editable component fields with undo-recorded writeback,
-//! read-only view during play, remove buttons, and the add-component popup.
+//! Inspector panel: the entity's name, editable component fields with undo-recorded writeback
+//! (batch-edited across a multi-selection, also during play), remove buttons, the add-component popup
+//! (Behavior is added as a picked variant), the texture picker, the sprite-sheet region picker (with atlas region names),
+//! the SpriteAnimation preview toggle, component copy/paste, the play-mode changes list (keep tweaks
+//! across Stop, inspector edits marked) and, after Stop, the keep-or-revert prompt for values edited
+//! during play.
 
 use glam::Vec2;
 
+use ecs::sprite_components::{Name, Sprite};
+use ecs::RenderLayers;
+use editor::commands::{AddComponentCommand, SetSpriteCommand};
 use editor::{
-    available_components, categorized_components, edit_all_components,
-    inspect_all_components, CommandHistory, ComponentKind, EditorContext,
-    FieldId, InspectorStyle,
+    available_components, behavior_variant_options, capture_inspectable_components, categorized_components,
+    edit_all_components, edited_fields, missing_dependencies, CommandHistory, ComponentEdit, ComponentEditorContext, ComponentKind,
+    ComponentMenuAction, DependencyPrompt, EditorContext, FieldId, HierarchyPanel, RegionPickerAction,
+    StoredComponent,
 };
+use renderer::TextureHandle;
 use engine_core::contexts::GameContext;
 
+use crate::entity_ops;
+
+use super::sprite_slicer::{atlas_regions, open_sprite_slicer};
+use super::texture_picker::open_texture_picker;
+
+/// Width of the "add required components?" prompt.
+const DEPENDENCY_PROMPT_WIDTH: f32 = 240.0;
+
+/// Width of the inspector's Name input.
+const NAME_INPUT_WIDTH: f32 = 160.0;
+
+/// Row height of the play-mode changes list.
+const PLAY_CHANGE_ROW_HEIGHT: f32 = 22.0;
+
 /// Inspector — component inspection for the selected entity.
 ///
-/// During Editing/Paused: renders editable fields with live writeback.
-/// During Playing: renders read-only view via `inspect_component()`.
+/// Renders editable fields with live writeback; with several entities
+/// selected, the components they share, edited on all. During a play
+/// session the edited fields are marked, so Stop can offer to keep them.
 pub(super) fn render_inspector(
     editor: &mut EditorContext,
     ctx: &mut GameContext,
@@ -22,6 +46,9 @@ pub(super) fn render_inspector(
     command_history: &mut CommandHistory,
 ) {
     let line_height = 20.0;
+    if !editor.in_play_session() && !editor.play_changes.offered_edits().is_empty() {
+        y = render_offered_edits(editor, ctx, content_x, y, command_history);
+    }
 
     let entity_id = match editor.selection.primary() {
         Some(id) => id,
@@ -31,42 +58,152 @@ pub(super) fn render_inspector(
         }
     };
 
-    ctx.ui.label(
-        &format!("Entity: {}", entity_id.value()),
-        Vec2::new(content_x, y),
-    );
+    // Primary first: its values are the ones shown and edited
+    let entities: Vec<ecs::EntityId> = std::iter::once(entity_id)
+        .chain(editor.selection.selected().filter(|&e| e != entity_id))
+        .collect();
+    let title = match entities.len() {
+        1 => format!("Entity: {}", entity_id.value()),
+        count => format!("{} entities (shared components)", count),
+    };
+    ctx.ui.label(&title, Vec2::new(content_x, y));
     y += line_height;
+    if entities.len() == 1 && !editor.is_playing() {
+        y = render_name_field(editor, ctx, entity_id, content_x, y, command_history);
+    }
+
+    if !editor.in_play_session() {
+        render_inspector_editable(editor, ctx, &entities, content_x, y, command_history);
+        return;
+    }
 
-    if editor.is_playing() {
-        render_inspector_readonly(ctx, entity_id, content_x, y, &editor.theme.inspector_style());
-    } else {
-        render_inspector_editable(editor, ctx, entity_id, content_x, y, command_history);
+    let before: Vec<_> = entities.iter().map(|&e| capture_inspectable_components(ctx.world, e)).collect();
+    let y = render_inspector_editable(editor, ctx, &entities, content_x, y, command_history);
+    for (&entity, before) in entities.iter().zip(&before) {
+        editor.play_changes.mark_edited(edited_fields(before, ctx.world, entity));
     }
+    render_play_changes(editor, ctx, entity_id, content_x, y + line_height);
 }
 
-/// Read-only inspector using the editor's component registry (used during Playing).
-fn render_inspector_readonly(
+/// "Values edited during play were reverted" prompt shown after Stop:
+/// Keep Edited Values re-applies them as one undo entry, Revert All (the
+/// default) drops them. Returns the Y below the prompt.
+fn render_offered_edits(
+    editor: &mut EditorContext,
     ctx: &mut GameContext,
-    entity_id: ecs::EntityId,
     content_x: f32,
     y: f32,
-    style: &InspectorStyle,
-) {
-    let line_height = 20.0;
-    inspect_all_components(
-        ctx.ui, ctx.world, entity_id, content_x, y, style, line_height * 0.5,
-    );
+    command_history: &mut CommandHistory,
+) -> f32 {
+    let count = editor.play_changes.offered_edits().len();
+    let message = format!("{} value(s) edited during play were reverted", count);
+    ctx.ui.label_styled(&message, Vec2::new(content_x, y + 4.0), editor.theme.warn_yellow, editor.theme.fonts.small);
+    let button_y = y + PLAY_CHANGE_ROW_HEIGHT;
+    let keep_bounds = ui::Rect::new(content_x, button_y, 130.0, 20.0);
+    let revert_bounds = ui::Rect::new(content_x + 136.0, button_y, 80.0, 20.0);
+    if ctx.ui.button("offered_edits_keep", "Keep Edited Values", keep_bounds) {
+        let offered = editor.play_changes.take_offered_edits();
+        if let Some((cmd, count)) = editor::apply_play_changes(ctx.world, &offered) {
+            command_history.push_already_executed(cmd);
+            editor.mark_dirty();
+            editor.status_bar.show_message(format!("Kept values edited during play on {} component(s)", count));
+        }
+    }
+    if ctx.ui.button("offered_edits_revert", "Revert All", revert_bounds) {
+        editor.play_changes.discard_offered_edits();
+    }
+    button_y + PLAY_CHANGE_ROW_HEIGHT + 8.0
 }
 
-/// Editable inspector with live writeback (used during Editing/Paused).
-fn render_inspector_editable(
+/// Editable `Name` row, with a warning while another entity shares the
+/// name. Returns the Y below it.
+fn render_name_field(
     editor: &mut EditorContext,
     ctx: &mut GameContext,
     entity_id: ecs::EntityId,
     content_x: f32,
     mut y: f32,
     command_history: &mut CommandHistory,
+) -> f32 {
+    let style = editor.theme.editable_field_style();
+    let name = ctx.world.get::<Name>(entity_id).map(|name| name.as_str().to_string()).unwrap_or_default();
+    ctx.ui.label_styled("Name", Vec2::new(content_x, y + 4.0), style.label_color, style.label_font);
+    let input_height = style.row_height - 4.0;
+    let input = ui::Rect::new(content_x + style.label_width, y + 2.0, NAME_INPUT_WIDTH, input_height);
+    if let Some(new_name) = ctx.ui.text_input(("inspector_name", entity_id.value() as usize), &name, input) {
+        super::rename_entity(editor, ctx, entity_id, &new_name, command_history);
+    }
+    y += style.row_height;
+
+    if !name.is_empty() && HierarchyPanel::is_duplicate_name(ctx.world, entity_id, &name) {
+        let theme = &editor.theme;
+        ctx.ui.label_styled("Another entity has this name", Vec2::new(content_x, y + 4.0), theme.warn_yellow, theme.fonts.small);
+        y += style.row_height;
+    }
+    y
+}
+
+/// Play-mode changes of the selected entity, each with a Keep toggle that
+/// carries the field over to the edit world at Stop.
+fn render_play_changes(
+    editor: &mut EditorContext,
+    ctx: &mut GameContext,
+    entity_id: ecs::EntityId,
+    content_x: f32,
+    mut y: f32,
 ) {
+    let theme = &editor.theme;
+    ctx.ui.label_styled("Play Changes", Vec2::new(content_x, y), theme.inspector_header, theme.fonts.small);
+
+    let keep_all_bounds = ui::Rect::new(content_x + 110.0, y - 4.0, 70.0, 20.0);
+    let entity_kept = editor.play_changes.is_entity_kept(entity_id);
+    let keep_all_label = if entity_kept { "All Kept" } else { "Keep All" };
+    if ctx.ui.button("play_changes_keep_all", keep_all_label, keep_all_bounds) && !entity_kept {
+        editor.play_changes.keep_entities([entity_id]);
+    }
+    y += PLAY_CHANGE_ROW_HEIGHT;
+
+    let changes = editor.play_changes.selection_changes().to_vec();
+    if changes.is_empty() {
+        ctx.ui.label_styled("(no changes)", Vec2::new(content_x + 8.0, y), editor.theme.text_muted, editor.theme.fonts.small);
+        return;
+    }
+
+    for (i, change) in changes.into_iter().enumerate() {
+        let kept = editor.play_changes.is_field_kept(&change.key);
+        let color = if kept { editor.theme.accent_cyan } else { editor.theme.text_secondary };
+        // Inspector edits are marked; they are offered back after Stop
+        let label = if editor.play_changes.is_field_edited(&change.key) {
+            format!("* {}", change.label())
+        } else {
+            change.label()
+        };
+        ctx.ui.label_styled(&label, Vec2::new(content_x + 8.0, y), color, editor.theme.fonts.small);
+
+        let toggle_bounds = ui::Rect::new(content_x + 190.0, y - 4.0, 50.0, 20.0);
+        let toggle_label = if kept { "Kept" } else { "Keep" };
+        if ctx.ui.button(("play_change_keep", i), toggle_label, toggle_bounds) && !entity_kept {
+            editor.play_changes.toggle_field(change.key);
+        }
+        y += PLAY_CHANGE_ROW_HEIGHT;
+    }
+}
+
+/// Editable inspector with live writeback for
+/// `entities`, the primary selection first. Returns the Y below the
+/// add-component button (and popup, when open); a multi-selection has no
+/// add-component button.
+fn render_inspector_editable(
+    editor: &mut EditorContext,
+    ctx: &mut GameContext,
+    entities: &[ecs::EntityId],
+    content_x: f32,
+    mut y: f32,
+    command_history: &mut CommandHistory,
+) -> f32 {
+    let Some(&entity_id) = entities.first() else {
+        return y;
+    };
     let line_height = 20.0;
     let inspect_style = editor.theme.inspector_style();
     let field_style = editor.theme.editable_field_style();
@@ -76,40 +213,95 @@ fn render_inspector_editable(
     // registry-generated inspector so the Texture slot can accept drops.
     let texture_display = ctx
         .world
-        .get::<ecs::sprite_components::Sprite>(entity_id)
+        .get::<Sprite>(entity_id)
         .and_then(|s| ctx.assets.texture_path(s.texture_handle).map(str::to_string));
+    let texture_size = ctx
+        .world
+        .get::<Sprite>(entity_id)
+        .and_then(|s| ctx.assets.get_texture(TextureHandle { id: s.texture_handle }))
+        .map_or(Vec2::ZERO, |texture| Vec2::new(texture.width as f32, texture.height as f32));
+    let region_names = match ctx.world.get::<Sprite>(entity_id) {
+        Some(sprite) => atlas_regions(editor, ctx.assets, sprite.texture_handle),
+        None => Vec::new(),
+    };
+    let layers = ctx
+        .world
+        .resource::<RenderLayers>()
+        .map_or_else(|| RenderLayers::new().names().to_vec(), |layers| layers.names().to_vec());
     let mut extras = editor::InspectorExtras {
         drag_drop: &mut editor.drag_drop,
         texture_display,
+        texture_size,
+        pick_texture: false,
+        region_names: &region_names,
+        pick_region: false,
+        materials: &editor.physics_materials,
+        layers: &layers,
+        animation_preview: editor.animation_preview.is_previewing(entity_id),
+        toggle_animation_preview: false,
+        clipboard_type: editor.component_clipboard.type_name(),
+        component_menu: None,
     };
 
     // Every per-component block (field editors, undo-recorded writeback,
     // remove buttons, read-only fallbacks) is generated from the editor's
     // component registry — adding a component to the registry is all it
     // takes to appear here.
-    let (next_y, component_index) = edit_all_components(
-        ctx.ui,
-        ctx.world,
-        entity_id,
-        command_history,
-        content_x,
+    let layout = ComponentEditorContext {
+        ui: ctx.ui,
+        history: command_history,
+        x: content_x,
         y,
-        &inspect_style,
-        &field_style,
-        line_height * 0.5,
-        &mut extras,
-    );
+        inspect_style: &inspect_style,
+        field_style: &field_style,
+        section_gap: line_height * 0.5,
+    };
+    let (next_y, component_index) = edit_all_components(layout, ctx.world, entities, &mut extras);
     y = next_y;
+    let (pick_region, toggle_animation_preview) = (extras.pick_region, extras.toggle_animation_preview);
+    let pick_texture = extras.pick_texture;
+    let component_menu = extras.component_menu;
+    if pick_region {
+        open_region_picker(editor, ctx, entity_id);
+    }
+    if pick_texture {
+        open_texture_picker(editor, ctx, entities);
+    }
+    if toggle_animation_preview {
+        editor.animation_preview.toggle(ctx.world, entity_id);
+    }
+    if let Some(action) = component_menu {
+        apply_component_menu(editor, ctx, entities, action, command_history);
+    }
+    // Catch bad values (zero fps, zero-size collider) as they're typed
+    if let Some(first) = editor.component_issues.revalidate(ctx.world, entities).first() {
+        editor.status_bar.show_error(format!("Invalid value: {first}"));
+    }
+    if entities.len() > 1 {
+        return y;
+    }
 
     // --- [+ Add Component] button ---
     y += line_height;
     let btn_bounds = ui::Rect::new(content_x, y, 160.0, 24.0);
     let add_btn_id = FieldId::new(component_index + 50, 0, 0);
+    // Right-click offers the copied component, values and all
+    let paste_label = editor.component_clipboard.type_name().map(|name| format!("Paste {name} As New"));
+    if let Some(label) = paste_label {
+        let menu_id = FieldId::new(component_index + 50, 1, 0);
+        if ctx.ui.context_menu(menu_id, btn_bounds, &[label.as_str()]) == Some(0) {
+            let pasted = editor.component_clipboard.paste_as_new(ctx.world, entities);
+            finish_paste(editor, ctx, pasted, command_history);
+        }
+    }
     if ctx.ui.button(add_btn_id, "+ Add Component", btn_bounds) {
         editor.toggle_add_component_popup();
     }
     y += 28.0;
 
+    // --- Required-components prompt ---
+    y = render_dependency_prompt(editor, ctx, entity_id, content_x, y, command_history);
+
     // --- Add Component Popup ---
     if editor.is_add_component_popup_open() {
         let available = available_components(ctx.world, entity_id);
@@ -141,32 +333,237 @@ fn render_inspector_editable(
                 popup_y += 18.0;
 
                 for kind in visible {
+                    if kind == ComponentKind::Behavior {
+                        // Pick the variant up front rather than switching
+                        // from the default one afterwards
+                        ctx.ui.label_styled(
+                            kind.display_name(),
+                            Vec2::new(content_x + 16.0, popup_y),
+                            editor.theme.text_muted,
+                            editor.theme.fonts.small,
+                        );
+                        popup_y += 18.0;
+                        for (name, behavior) in behavior_variant_options() {
+                            let btn_bounds = ui::Rect::new(content_x + 24.0, popup_y, 140.0, 22.0);
+                            let btn_id = FieldId::new(component_index + 60 + popup_btn_idx, 0, 0);
+                            if ctx.ui.button(btn_id, name, btn_bounds) {
+                                let stored = StoredComponent::Behavior(behavior);
+                                if let Some(command) = AddComponentCommand::with_value(entity_id, stored) {
+                                    command_history.execute(Box::new(command), ctx.world);
+                                    log::info!("Added component: Behavior ({name})");
+                                }
+                                editor.close_add_component_popup();
+                            }
+                            popup_y += 24.0;
+                            popup_btn_idx += 1;
+                        }
+                        continue;
+                    }
                     let btn_bounds = ui::Rect::new(content_x + 16.0, popup_y, 148.0, 22.0);
                     let btn_id = FieldId::new(component_index + 60 + popup_btn_idx, 0, 0);
                     if ctx.ui.button(btn_id, kind.display_name(), btn_bounds) {
-                        let cmd = editor::commands::AddComponentCommand::new(entity_id, kind);
-                        command_history.execute(Box::new(cmd), ctx.world);
+                        let missing = missing_dependencies(ctx.world, entity_id, kind);
+                        if missing.is_empty() {
+                            entity_ops::add_component_to_entity(ctx.world, entity_id, kind, false, command_history);
+                            log::info!("Added component: {}", kind.display_name());
+                        } else {
+                            // Ask before silently adding a component that
+                            // would do nothing on its own.
+                            editor.dependency_prompt = Some(DependencyPrompt { entity: entity_id, kind, missing });
+                        }
                         editor.close_add_component_popup();
-                        log::info!("Added component: {}", kind.display_name());
                     }
                     popup_y += 24.0;
                     popup_btn_idx += 1;
                 }
             }
+            y += popup_height;
+        }
+    }
+
+    y
+}
+
+/// "X requires Y. Add it too?" prompt raised by the add-component popup.
+/// Returns the Y below the prompt (unchanged when no prompt is open for
+/// this entity).
+fn render_dependency_prompt(
+    editor: &mut EditorContext,
+    ctx: &mut GameContext,
+    entity_id: ecs::EntityId,
+    content_x: f32,
+    y: f32,
+    command_history: &mut CommandHistory,
+) -> f32 {
+    let Some(prompt) = editor.dependency_prompt.clone() else {
+        return y;
+    };
+    if prompt.entity != entity_id {
+        // Selection moved on — the prompt no longer applies.
+        editor.dependency_prompt = None;
+        return y;
+    }
+
+    let panel = ui::Rect::new(content_x, y, DEPENDENCY_PROMPT_WIDTH, 56.0);
+    ctx.ui.panel(panel);
+    ctx.ui.label_styled(
+        &prompt.message(),
+        Vec2::new(content_x + 8.0, y + 14.0),
+        editor.theme.warn_yellow,
+        editor.theme.fonts.small,
+    );
+
+    let button_y = y + 28.0;
+    let add_all = ctx.ui.button(
+        "dependency_prompt_add_all",
+        "Add All",
+        ui::Rect::new(content_x + 8.0, button_y, 70.0, 22.0),
+    );
+    let add_only = ctx.ui.button(
+        "dependency_prompt_add_only",
+        "Add Only",
+        ui::Rect::new(content_x + 84.0, button_y, 70.0, 22.0),
+    );
+    let cancel = ctx.ui.button(
+        "dependency_prompt_cancel",
+        "Cancel",
+        ui::Rect::new(content_x + 160.0, button_y, 70.0, 22.0),
+    );
+
+    if add_all || add_only {
+        let added = entity_ops::add_component_to_entity(ctx.world, entity_id, prompt.kind, add_all, command_history);
+        let names: Vec<&str> = added.iter().map(|k| k.display_name()).collect();
+        editor.status_bar.show_message(format!("Added {}", names.join(", ")));
+        editor.dependency_prompt = None;
+    } else if cancel {
+        editor.dependency_prompt = None;
+    }
+    y + 60.0
+}
+
+/// Open the sprite-sheet region picker on the entity's sprite texture,
+/// offering the texture's atlas regions by name.
+fn open_region_picker(editor: &mut EditorContext, ctx: &GameContext, entity_id: ecs::EntityId) {
+    let Some(sprite) = ctx.world.get::<Sprite>(entity_id) else {
+        return;
+    };
+    let texture_size = ctx
+        .assets
+        .get_texture(TextureHandle { id: sprite.texture_handle })
+        .map(|texture| Vec2::new(texture.width as f32, texture.height as f32))
+        .unwrap_or(Vec2::ZERO);
+    editor.region_picker.open(entity_id, sprite.texture_handle, texture_size, sprite.tex_region);
+    let names = atlas_regions(editor, ctx.assets, sprite.texture_handle);
+    editor.region_picker.set_named_regions(names);
+}
+
+/// Sprite-sheet region picker popup, centered in the window. Runs before
+/// the panels so its blocking rect makes the widgets underneath inert;
+/// "Apply" writes `tex_region` as one undoable sprite edit; "Slice..."
+/// hands the texture to the sprite-sheet slicer.
+pub(crate) fn render_region_picker(
+    editor: &mut EditorContext,
+    ctx: &mut GameContext,
+    command_history: &mut CommandHistory,
+) {
+    let Some(entity_id) = editor.region_picker.entity() else {
+        return;
+    };
+    if editor.in_play_session() || ctx.world.get::<Sprite>(entity_id).is_none() {
+        editor.region_picker.close();
+        return;
+    }
+
+    let size = editor::REGION_PICKER_SIZE;
+    let bounds = ui::Rect::new(
+        (ctx.window_size.x - size.x) / 2.0,
+        (ctx.window_size.y - size.y) / 2.0,
+        size.x,
+        size.y,
+    );
+    let (entity, region) = match editor.region_picker.render(ctx.ui, bounds, &editor.theme) {
+        Some(RegionPickerAction::Apply { entity, region }) => (entity, region),
+        Some(RegionPickerAction::Slice { texture }) => {
+            open_sprite_slicer(editor, ctx, texture);
+            return;
+        }
+        Some(RegionPickerAction::Cancel) | None => return,
+    };
+    let Some(old) = ctx.world.get::<Sprite>(entity).cloned() else {
+        return;
+    };
+    if old.tex_region == region {
+        return;
+    }
+    let edit = ComponentEdit {
+        new_value: Sprite { tex_region: region, ..old.clone() },
+        field_hint: "tex_region",
+    };
+    editor::apply_component_edit(ctx.world, entity, &old, Some(edit), command_history, |e, o, n, h| {
+        Box::new(SetSpriteCommand::new(e, o, n, h))
+    });
+    editor.mark_dirty();
+    editor.status_bar.show_message("Sprite region updated");
+}
+
+/// Carry out a component header's Copy / Paste Component Values pick.
+fn apply_component_menu(
+    editor: &mut EditorContext,
+    ctx: &mut GameContext,
+    entities: &[ecs::EntityId],
+    action: ComponentMenuAction,
+    command_history: &mut CommandHistory,
+) {
+    match action {
+        ComponentMenuAction::Copy(name) => {
+            let Some(&entity) = entities.first() else {
+                return;
+            };
+            match editor.component_clipboard.copy_from(ctx.world, entity, name) {
+                Ok(()) => editor.status_bar.show_message(format!("Copied {name}")),
+                Err(e) => editor.status_bar.show_error(format!("Copy failed: {e}")),
+            }
+        }
+        ComponentMenuAction::PasteValues(_) => {
+            let pasted = editor.component_clipboard.paste_values(ctx.world, entities);
+            finish_paste(editor, ctx, pasted, command_history);
         }
     }
+}
 
-    let _ = component_index;
+/// Execute a clipboard paste command, or report why there was none.
+fn finish_paste(
+    editor: &mut EditorContext,
+    ctx: &mut GameContext,
+    pasted: Result<Box<dyn editor::EditorCommand>, String>,
+    command_history: &mut CommandHistory,
+) {
+    match pasted {
+        Ok(command) => {
+            command_history.execute(command, ctx.world);
+            editor.mark_dirty();
+            let name = editor.component_clipboard.type_name().unwrap_or("component");
+            let message = format!("Pasted {name}");
+            editor.status_bar.show_message(message);
+        }
+        Err(e) => editor.status_bar.show_error(format!("Paste failed: {e}")),
+    }
 }
 
 /// Calculate the height needed for the categorized popup.
 fn categorized_popup_height(available: &[ComponentKind]) -> f32 {
     let mut height = 8.0; // padding
     for (_, kinds) in categorized_components() {
-        let visible_count = kinds.iter().filter(|k| available.contains(k)).count();
-        if visible_count > 0 {
+        let visible: Vec<&ComponentKind> = kinds.iter().filter(|k| available.contains(k)).collect();
+        if !visible.is_empty() {
             height += 18.0; // category label
-            height += visible_count as f32 * 24.0; // buttons
+            for kind in visible {
+                height += if *kind == ComponentKind::Behavior {
+                    18.0 + behavior_variant_options().len() as f32 * 24.0 // label + a button per variant
+                } else {
+                    24.0 // button
+                };
+            }
         }
     }
     height
@@ -0,0 +1,71 @@
+//! Layers panel: the scene's render layers front to back, with up/down
+//! buttons to reorder them (undoable, saved with the scene) and an eye
+//! toggle to hide a layer's sprites in the scene view (editor-only, saved
+//! in the scene's editor settings).
+
+use glam::Vec2;
+
+use ecs::RenderLayers;
+use editor::commands::MoveRenderLayerCommand;
+use editor::{CommandHistory, EditorContext};
+use engine_core::contexts::GameContext;
+
+/// Panel content padding.
+const PADDING: f32 = 8.0;
+/// Height of one layer row.
+const ROW_HEIGHT: f32 = 24.0;
+/// Width of the eye toggle and each reorder button.
+const BUTTON_WIDTH: f32 = 22.0;
+
+/// Render the Layers panel content.
+pub(super) fn render_layers(
+    editor: &mut EditorContext,
+    ctx: &mut GameContext,
+    bounds: common::Rect,
+    command_history: &mut CommandHistory,
+) {
+    let names = ctx
+        .world
+        .resource::<RenderLayers>()
+        .map_or_else(|| RenderLayers::new().names().to_vec(), |layers| layers.names().to_vec());
+    let x = bounds.x + PADDING;
+    let mut y = bounds.y + PADDING + 12.0;
+    let buttons_x = bounds.x + bounds.width - PADDING - 2.0 * BUTTON_WIDTH;
+    // The play world is discarded on Stop, so reordering is edit-only
+    let can_reorder = !editor.is_playing();
+    let last = names.len().saturating_sub(1);
+
+    // Front-most first, like the draw order reads top-down
+    for (index, name) in names.iter().enumerate().rev() {
+        let hidden = editor.visibility.is_layer_hidden(name);
+        let eye = common::Rect::new(x, y, BUTTON_WIDTH, ROW_HEIGHT);
+        if ctx.ui.interact(format!("layers_eye_{name}").as_str(), eye, true).clicked {
+            editor.visibility.toggle_layer(name);
+            editor.mark_dirty();
+        }
+        let center = eye.center();
+        let color = if hidden { editor.theme.accent_cyan } else { editor.theme.text_secondary };
+        ctx.ui.circle(center, 3.0, color);
+        if hidden {
+            ctx.ui.line(center + Vec2::new(-5.0, 5.0), center + Vec2::new(5.0, -5.0), color, 1.5);
+        }
+
+        let text_color = if hidden { editor.theme.text_muted } else { editor.theme.text_secondary };
+        ctx.ui.label_styled(name, Vec2::new(x + BUTTON_WIDTH + 4.0, y + 5.0), text_color, editor.theme.fonts.small);
+
+        let up = ui::Rect::new(buttons_x, y + 1.0, BUTTON_WIDTH, ROW_HEIGHT - 2.0);
+        let down = ui::Rect::new(buttons_x + BUTTON_WIDTH, y + 1.0, BUTTON_WIDTH, ROW_HEIGHT - 2.0);
+        let target = if ctx.ui.button_styled(format!("layers_up_{name}").as_str(), "^", up, can_reorder && index < last) {
+            Some(index + 1)
+        } else if ctx.ui.button_styled(format!("layers_down_{name}").as_str(), "v", down, can_reorder && index > 0) {
+            Some(index - 1)
+        } else {
+            None
+        };
+        if let Some(to) = target {
+            command_history.execute(Box::new(MoveRenderLayerCommand::new(name.clone(), to)), ctx.world);
+            editor.mark_dirty();
+        }
+        y += ROW_HEIGHT;
+    }
+}
@@ -1,11 +1,13 @@
 //! Panel content rendering for editor dock panels.
 //!
 //! Extracted from editor_demo.rs — renders the content inside each dock panel
-//! (scene view, hierarchy tree, inspector, asset browser).
+//! (scene view, hierarchy tree, inspector, asset browser, world stats,
+//! layers, compare, history, profiler, console). Scene Settings edits state the
+//! editor game owns and is rendered by it directly.
 
 use glam::Vec2;
 
-use editor::{CommandHistory, EditorContext, HierarchyPanel, PanelId};
+use editor::{CommandHistory, EditorContext, HierarchyPanel, PanelId, ViewportOverlay};
 use engine_core::contexts::GameContext;
 
 /// Render the content of a specific dock panel.
@@ -22,19 +24,34 @@ pub fn render_panel_content(
 
     match panel_id {
         PanelId::SCENE_VIEW => render_scene_view(editor, ctx, bounds),
-        PanelId::HIERARCHY => render_hierarchy(editor, ctx, bounds),
+        PanelId::HIERARCHY => render_hierarchy(editor, ctx, bounds, command_history),
         PanelId::INSPECTOR => render_inspector(editor, ctx, content_x, y, command_history),
         PanelId::ASSET_BROWSER => {
             asset_browser::render_asset_browser(editor, ctx, bounds, command_history)
         }
+        PanelId::WORLD_STATS => world_stats::render_world_stats(editor, ctx, bounds, command_history),
+        PanelId::LAYERS => layers::render_layers(editor, ctx, bounds, command_history),
+        PanelId::SCENE_COMPARE => scene_compare::render_scene_compare(editor, ctx, bounds),
+        PanelId::HISTORY => history::render_history(editor, ctx, bounds, command_history),
+        PanelId::PROFILER => profiler::render_profiler(editor, ctx, bounds),
+        PanelId::CONSOLE => console::render_console(editor, ctx, bounds),
         _ => render_default(ctx, content_x, y),
     }
 }
 
 pub(crate) use asset_browser::render_drag_ghost;
+pub(crate) use inspector::render_region_picker;
+pub(crate) use scene_settings::render_scene_settings;
+pub(crate) use sprite_slicer::render_sprite_slicer;
+pub(crate) use texture_picker::render_texture_picker;
+pub(crate) use world_stats::flatten_selected_subtree;
+
+/// Scene view — grid info, viewport origin crosshair, Overlays dropdown and
+/// the overlays it enables, view-rotation reset, and play-state border.
+fn render_scene_view(editor: &mut EditorContext, ctx: &mut GameContext, bounds: common::Rect) {
+    render_view_rotation_reset(editor, ctx, bounds);
+    editor.render_overlay_menu(ctx.ui, bounds);
 
-/// Scene view — grid info, viewport origin crosshair, and play-state border.
-fn render_scene_view(editor: &EditorContext, ctx: &mut GameContext, bounds: common::Rect) {
     let theme = &editor.theme;
     let padding = 8.0;
     let content_x = bounds.x + padding;
@@ -74,14 +91,26 @@ fn render_scene_view(editor: &EditorContext, ctx: &mut GameContext, bounds: comm
             ctx.world,
             &editor.viewport,
             &editor.selection,
-            &editor.theme.collider_overlay_colors(),
+            &editor.theme.colors.collider_overlay_colors(),
             bounds,
         );
     }
 
-    // Play-state border tint
-    let border_color = theme.play_state_border(editor.play_state());
-    let w = if editor.in_play_session() { 3.0 } else { 1.0 };
+    if editor.is_overlay_visible(ViewportOverlay::AudioRanges) {
+        editor::render_audio_range_overlay(ctx.ui, ctx.world, &editor.viewport, theme.colors.audio_range, bounds);
+    }
+    if editor.is_overlay_visible(ViewportOverlay::CameraFrames) {
+        editor::render_camera_frame_overlay(ctx.ui, ctx.world, &editor.viewport, theme.colors.camera_frame, bounds);
+    }
+
+    // Paused: mark bodies whose edits physics will push on resume.
+    if editor.is_paused() {
+        editor::render_physics_sync_overlay(ctx.ui, ctx.world, &editor.viewport, theme.colors.physics_unsynced, bounds);
+    }
+
+    // Play-state border tint, thick while the game has input focus
+    let border_color = theme.colors.play_state_border(editor.play_state());
+    let w = if editor.input_focus.game_has_focus() { 3.0 } else { 1.0 };
 
     // Top
     ctx.ui.line(
@@ -109,20 +138,72 @@ fn render_scene_view(editor: &EditorContext, ctx: &mut GameContext, bounds: comm
     );
 }
 
-/// Hierarchy — tree view with click-to-select and Ctrl toggle.
-fn render_hierarchy(editor: &mut EditorContext, ctx: &mut GameContext, bounds: common::Rect) {
-    let clicked = editor.hierarchy.render(
+/// While the view is rotated, show the angle and a button that straightens
+/// it back to the world axes (top-right of the scene view, below the
+/// Overlays button).
+fn render_view_rotation_reset(editor: &mut EditorContext, ctx: &mut GameContext, bounds: common::Rect) {
+    if !editor.viewport.is_rotated() {
+        return;
+    }
+    let overlays = EditorContext::overlay_button_bounds(bounds);
+    let button = common::Rect::new(
+        overlays.x + overlays.width - VIEW_ROTATION_BUTTON_WIDTH,
+        overlays.y + overlays.height + 6.0,
+        VIEW_ROTATION_BUTTON_WIDTH,
+        22.0,
+    );
+    ctx.ui.label_styled(
+        &format!("{:.0}°", editor.viewport.camera_rotation().to_degrees()),
+        Vec2::new(button.x - 44.0, button.y + 16.0),
+        editor.theme.text_muted,
+        editor.theme.fonts.small,
+    );
+    if ctx.ui.button("reset_view_rotation", "Reset Rotation", button) {
+        editor.reset_view_rotation();
+    }
+}
+
+/// Width of the scene view's "Reset Rotation" button.
+const VIEW_ROTATION_BUTTON_WIDTH: f32 = 120.0;
+
+/// Hierarchy — tree view with click-to-select, Ctrl toggle and
+/// double-click rename (not during play).
+fn render_hierarchy(
+    editor: &mut EditorContext,
+    ctx: &mut GameContext,
+    bounds: common::Rect,
+    command_history: &mut CommandHistory,
+) {
+    let response = editor.hierarchy.render(
         ctx.ui,
         ctx.world,
         &mut editor.selection,
+        (&editor.visibility, &editor.locks),
         bounds,
         &editor.theme,
     );
 
-    if !clicked.is_empty() {
+    // Eye/lock flags are saved with the scene
+    if let Some(entity_id) = response.toggle_hidden {
+        editor.visibility.toggle(entity_id);
+        editor.mark_dirty();
+    }
+    if let Some(entity_id) = response.toggle_locked {
+        editor.locks.toggle(entity_id);
+        editor.mark_dirty();
+    }
+
+    if let Some(entity_id) = response.rename_requested.filter(|_| !editor.is_playing()) {
+        editor.hierarchy.begin_rename(ctx.ui, ctx.world, entity_id);
+    }
+    if let Some((entity_id, name)) = response.renamed {
+        rename_entity(editor, ctx, entity_id, &name, command_history);
+    }
+
+    if !response.clicked.is_empty() {
         editor.close_add_component_popup();
     }
-    for entity_id in clicked {
+    for entity_id in response.clicked {
         if ctx.input.keyboard().is_key_pressed(winit::keyboard::KeyCode::ControlLeft) {
             editor.selection.toggle(entity_id);
         } else {
@@ -136,6 +217,25 @@ fn render_hierarchy(editor: &mut EditorContext, ctx: &mut GameContext, bounds: c
     }
 }
 
+/// Rename an entity (one undo entry), warning in the status bar when another
+/// entity already has the name — names stay free-form, but scripts and
+/// scene diffs that look entities up by name would pick either.
+pub(super) fn rename_entity(
+    editor: &mut EditorContext,
+    ctx: &mut GameContext,
+    entity_id: ecs::EntityId,
+    name: &str,
+    command_history: &mut CommandHistory,
+) {
+    if !crate::entity_ops::rename_entity(ctx.world, entity_id, name, command_history) {
+        return;
+    }
+    editor.mark_dirty();
+    let name = name.trim();
+    if !name.is_empty() && HierarchyPanel::is_duplicate_name(ctx.world, entity_id, name) {
+        editor.status_bar.show_message(format!("Another entity is already named \"{name}\""));
+    }
+}
 
 /// Fallback for unknown panels.
 fn render_default(ctx: &mut GameContext, content_x: f32, y: f32) {
@@ -143,7 +243,16 @@ fn render_default(ctx: &mut GameContext, content_x: f32, y: f32) {
 }
 
 mod asset_browser;
+mod console;
+mod history;
 mod inspector;
+mod layers;
+mod profiler;
+mod scene_compare;
+mod scene_settings;
+mod sprite_slicer;
+mod texture_picker;
+mod world_stats;
 use inspector::render_inspector;
 
 #[cfg(test)]
@@ -0,0 +1,121 @@
+//! Profiler panel: the play-mode frame history of `editor::Profiler` —
+//! per-pass stacked bars against the 60 fps budget, and a table of each
+//! pass's latest/average/peak time — plus sprite and draw-call counts.
+//!
+//! The samples are recorded by the editor game (`editor_game/profiler.rs`);
+//! this file only draws them.
+
+use glam::Vec2;
+
+use editor::{EditorContext, ProfilerRow, FRAME_BUDGET_MS};
+use engine_core::contexts::GameContext;
+
+/// Panel content padding.
+const PADDING: f32 = 8.0;
+/// Vertical distance between text rows.
+const ROW_HEIGHT: f32 = 18.0;
+/// Share of the content width taken by the graph; the table gets the rest.
+const GRAPH_SHARE: f32 = 0.55;
+/// Gap between the graph and the table.
+const GAP: f32 = 12.0;
+/// Side of a pass's color swatch in the table.
+const SWATCH: f32 = 10.0;
+/// Width of each number column in the table.
+const NUMBER_COLUMN: f32 = 56.0;
+
+/// Render the Profiler panel content.
+pub(super) fn render_profiler(editor: &EditorContext, ctx: &mut GameContext, bounds: common::Rect) {
+    let theme = &editor.theme;
+    let profiler = &editor.profiler;
+    let x = bounds.x + PADDING;
+    let y = bounds.y + PADDING + 4.0;
+
+    let Some(latest) = profiler.latest() else {
+        let hint = if editor.in_play_session() { "No frames yet" } else { "Press Play to profile frames" };
+        ctx.ui.label_styled(hint, Vec2::new(x, y), theme.text_muted, theme.fonts.small);
+        return;
+    };
+    let summary = format!(
+        "Frame {:.2} ms (peak {:.2})   Entities {}   Sprites {}   Draw calls {}",
+        latest.total_ms(),
+        profiler.peak_total_ms(),
+        latest.entities,
+        latest.sprites,
+        latest.draw_calls,
+    );
+    ctx.ui.label_styled(&summary, Vec2::new(x, y), theme.text_secondary, theme.fonts.small);
+
+    let top = y + ROW_HEIGHT + 4.0;
+    let width = (bounds.width - PADDING * 2.0).max(0.0);
+    let height = (bounds.y + bounds.height - PADDING - top).max(0.0);
+    let graph_width = width * GRAPH_SHARE;
+    let rows = profiler.rows();
+    render_graph(editor, ctx, &rows, common::Rect::new(x, top, graph_width, height));
+    let table_x = x + graph_width + GAP;
+    render_table(editor, ctx, &rows, common::Rect::new(table_x, top, (width - graph_width - GAP).max(0.0), height));
+}
+
+/// Stacked per-pass bars, newest frame at the right edge, with the frame
+/// budget marked.
+fn render_graph(editor: &EditorContext, ctx: &mut GameContext, rows: &[ProfilerRow], graph: common::Rect) {
+    let theme = &editor.theme;
+    let profiler = &editor.profiler;
+    if graph.width <= 0.0 || graph.height <= 0.0 {
+        return;
+    }
+    ctx.ui.rect(graph, theme.bg_input);
+
+    let scale = profiler.graph_scale_ms();
+    let bottom = graph.y + graph.height;
+    let bar_width = graph.width / profiler.capacity() as f32;
+    let mut bar_x = graph.x + graph.width - profiler.len() as f32 * bar_width;
+    for sample in profiler.samples() {
+        let mut stack = bottom;
+        for timing in &sample.timings {
+            let bar_height = timing.millis() / scale * graph.height;
+            if bar_height < 0.5 {
+                continue;
+            }
+            stack -= bar_height;
+            let series = rows.iter().position(|row| row.name == timing.name).unwrap_or(0);
+            let color = theme.profiler_series[series % theme.profiler_series.len()];
+            ctx.ui.rect(common::Rect::new(bar_x, stack, bar_width.max(1.0), bar_height), color);
+        }
+        bar_x += bar_width;
+    }
+
+    let budget_y = bottom - FRAME_BUDGET_MS / scale * graph.height;
+    ctx.ui.line(Vec2::new(graph.x, budget_y), Vec2::new(graph.x + graph.width, budget_y), theme.warn_yellow, 1.0);
+    let budget = format!("{FRAME_BUDGET_MS:.1} ms");
+    ctx.ui.label_styled(&budget, Vec2::new(graph.x + 4.0, budget_y + 2.0), theme.warn_yellow, theme.fonts.small);
+    if scale > FRAME_BUDGET_MS {
+        let top = format!("{scale:.1} ms");
+        ctx.ui.label_styled(&top, Vec2::new(graph.x + 4.0, graph.y + 2.0), theme.text_muted, theme.fonts.small);
+    }
+}
+
+/// One row per pass: color swatch, name, latest/average/peak milliseconds.
+fn render_table(editor: &EditorContext, ctx: &mut GameContext, rows: &[ProfilerRow], table: common::Rect) {
+    let theme = &editor.theme;
+    let number_x = |column: f32| table.x + table.width - NUMBER_COLUMN * (3.0 - column);
+    let header = [("Now", 0.0), ("Avg", 1.0), ("Peak", 2.0)];
+    ctx.ui.label_styled("Pass", Vec2::new(table.x, table.y), theme.text_muted, theme.fonts.small);
+    for (label, column) in header {
+        ctx.ui.label_styled(label, Vec2::new(number_x(column), table.y), theme.text_muted, theme.fonts.small);
+    }
+
+    let mut y = table.y + ROW_HEIGHT;
+    for (index, row) in rows.iter().enumerate() {
+        if y + ROW_HEIGHT > table.y + table.height {
+            break;
+        }
+        let color = theme.profiler_series[index % theme.profiler_series.len()];
+        ctx.ui.rect(common::Rect::new(table.x, y + 3.0, SWATCH, SWATCH), color);
+        ctx.ui.label_styled(&row.name, Vec2::new(table.x + SWATCH + 6.0, y), theme.text_secondary, theme.fonts.small);
+        for (value, column) in [(row.latest_ms, 0.0), (row.average_ms, 1.0), (row.peak_ms, 2.0)] {
+            let text = format!("{value:.2}");
+            ctx.ui.label_styled(&text, Vec2::new(number_x(column), y), theme.text_primary, theme.fonts.small);
+        }
+        y += ROW_HEIGHT;
+    }
+}
@@ -0,0 +1,64 @@
+//! Compare panel: the last File > Compare With Saved result, one row per
+//! structural change. Clicking a row about an entity selects it.
+
+use glam::Vec2;
+
+use editor::{CompareRowKind, EditorContext};
+use engine_core::contexts::GameContext;
+
+/// Panel content padding.
+const PADDING: f32 = 8.0;
+/// Height of the title line.
+const HEADER_HEIGHT: f32 = 20.0;
+/// Height of one change row.
+const ROW_HEIGHT: f32 = 18.0;
+
+/// Render the Compare panel content.
+pub(super) fn render_scene_compare(editor: &mut EditorContext, ctx: &mut GameContext, bounds: common::Rect) {
+    let x = bounds.x + PADDING;
+    let title = if editor.scene_compare.title.is_empty() {
+        "Use File > Compare With Saved to compare this scene with its file"
+    } else {
+        editor.scene_compare.title.as_str()
+    };
+    ctx.ui.label_styled(title, Vec2::new(x, bounds.y + PADDING), editor.theme.text_secondary, editor.theme.fonts.small);
+
+    let list_top = bounds.y + PADDING + HEADER_HEIGHT;
+    let visible_height = (bounds.y + bounds.height - list_top).max(0.0);
+    let content_height = editor.scene_compare.rows.len() as f32 * ROW_HEIGHT;
+    let max_scroll = (content_height - visible_height).max(0.0);
+    if bounds.contains(ctx.ui.mouse_pos()) {
+        let delta = ctx.ui.scroll_delta();
+        if delta != 0.0 {
+            editor.scene_compare.scroll_offset =
+                (editor.scene_compare.scroll_offset - delta * 30.0).clamp(0.0, max_scroll);
+        }
+    }
+    let scroll = editor.scene_compare.scroll_offset;
+
+    let mut clicked = None;
+    for (index, row) in editor.scene_compare.rows.iter().enumerate() {
+        let y = list_top + index as f32 * ROW_HEIGHT - scroll;
+        if y + ROW_HEIGHT < list_top || y > bounds.y + bounds.height {
+            continue;
+        }
+        let color = match row.kind {
+            CompareRowKind::Setting => editor.theme.text_secondary,
+            CompareRowKind::Added => editor.theme.play_green,
+            CompareRowKind::Removed => editor.theme.error_red,
+            CompareRowKind::Moved => editor.theme.accent_cyan,
+            CompareRowKind::Changed => editor.theme.warn_yellow,
+        };
+        let rect = common::Rect::new(x, y, bounds.width - PADDING * 2.0, ROW_HEIGHT);
+        if let Some(guid) = row.entity {
+            if ctx.ui.interact(format!("compare_row_{index}").as_str(), rect, true).clicked {
+                clicked = Some(guid);
+            }
+        }
+        ctx.ui.label_styled(&row.text, Vec2::new(x, y + 2.0), color, editor.theme.fonts.small);
+    }
+
+    if let Some(entity) = clicked.and_then(|guid| ctx.world.entity_by_guid(guid)) {
+        editor.selection.select(entity);
+    }
+}
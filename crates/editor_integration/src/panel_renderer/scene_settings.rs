@@ -0,0 +1,134 @@
+//! Scene Settings panel: the scene's physics settings (gravity, scale,
+//! solver iterations, fixed rate), saved with the scene as
+//! `SceneData.physics` and applied to the engine's physics when play
+//! starts.
+//!
+//! A scene without settings shows the defaults and gets its own on the
+//! first edit. The timestep is edited as a rate in Hz: field inputs show two
+//! decimals, too coarse for a timestep in seconds.
+//!
+//! Below them, the sprite sort mode (`ecs::SpriteSortMode`, a world
+//! resource saved as `SceneData.sort_mode`) switches between depth and
+//! isometric sorting, taking effect immediately.
+
+use glam::Vec2;
+
+use editor::{EditableFieldStyle, EditableInspector, EditorContext};
+use engine_core::contexts::GameContext;
+use ecs::SpriteSortMode;
+use engine_core::scene_data::PhysicsSettings;
+
+/// Panel content padding.
+const PADDING: f32 = 8.0;
+/// Field-ID component index, clear of the inspector's components.
+const FIELD_COMPONENT_INDEX: usize = 500;
+
+/// Render the Scene Settings panel content. Edits (not while playing)
+/// replace `settings` and mark the scene dirty.
+pub(crate) fn render_scene_settings(
+    editor: &mut EditorContext,
+    ctx: &mut GameContext,
+    bounds: common::Rect,
+    settings: &mut Option<PhysicsSettings>,
+) {
+    let x = bounds.x + PADDING;
+    let current = settings.clone().unwrap_or_default();
+    let style = editor.theme.editable_field_style();
+    let (edited, mut y) = edit_physics_settings(ctx.ui, x, bounds.y + PADDING, style.clone(), &current);
+    let editable = !editor.is_playing();
+    if let Some(edited) = edited.filter(|_| editable) {
+        *settings = Some(edited);
+        editor.mark_dirty();
+    }
+
+    y += PADDING;
+    let note = match (settings.is_some(), editable) {
+        (_, false) => "Applied when play started",
+        (true, true) => "Saved with the scene; applied on Play",
+        (false, true) => "Game defaults (not saved until edited)",
+    };
+    ctx.ui.label_styled(note, Vec2::new(x, y + 4.0), editor.theme.text_muted, editor.theme.fonts.small);
+    y += 24.0;
+
+    let button = ui::Rect::new(x, y, (bounds.width - PADDING * 2.0).max(0.0), 22.0);
+    if settings.is_some() && ctx.ui.button_styled("scene_settings_reset", "Use Game Defaults", button, editable) {
+        *settings = None;
+        editor.mark_dirty();
+    }
+    y += 22.0 + PADDING;
+
+    let mode = ctx.world.resource::<SpriteSortMode>().copied().unwrap_or_default();
+    let (edited, _) = edit_sort_mode(ctx.ui, x, y, style, mode);
+    if let Some(edited) = edited.filter(|_| editable) {
+        ctx.world.insert_resource(edited);
+        editor.mark_dirty();
+    }
+}
+
+/// Draw the sprite sort fields at `(x, y)`: the isometric toggle, plus the
+/// row height while it's on. Returns the edited mode (if a field changed)
+/// and the y below the last row.
+pub(super) fn edit_sort_mode(
+    ui: &mut ui::UIContext,
+    x: f32,
+    y: f32,
+    style: EditableFieldStyle,
+    mode: SpriteSortMode,
+) -> (Option<SpriteSortMode>, f32) {
+    let mut inspector = EditableInspector::new(ui, x, y)
+        .with_component_index(FIELD_COMPONENT_INDEX + 1)
+        .with_style(style);
+    inspector.header("Rendering");
+    let mut edited = None;
+
+    if let Some(&isometric) = inspector.bool("Isometric Sort", mode.is_isometric()).new_value() {
+        edited = Some(if isometric { SpriteSortMode::isometric(0.0) } else { SpriteSortMode::Depth });
+    }
+    if let SpriteSortMode::Isometric { row_height } = mode {
+        if let Some(&value) = inspector.f32("Row Height", row_height, 0.0..=10_000.0).new_value() {
+            edited = Some(SpriteSortMode::isometric(value.max(0.0)));
+        }
+    }
+
+    (edited, inspector.y())
+}
+
+/// Draw the settings fields at `(x, y)`. Returns the edited settings (if a
+/// field changed) and the y below the last row.
+pub(super) fn edit_physics_settings(
+    ui: &mut ui::UIContext,
+    x: f32,
+    y: f32,
+    style: EditableFieldStyle,
+    settings: &PhysicsSettings,
+) -> (Option<PhysicsSettings>, f32) {
+    let mut inspector = EditableInspector::new(ui, x, y)
+        .with_component_index(FIELD_COMPONENT_INDEX)
+        .with_style(style);
+    inspector.header("Physics");
+    let mut edited = settings.clone();
+    let mut changed = false;
+
+    let gravity = Vec2::new(settings.gravity.0, settings.gravity.1);
+    if let Some(value) = inspector.vec2("Gravity", gravity, -100_000.0..=100_000.0).new_value() {
+        edited.gravity = (value.x, value.y);
+        changed = true;
+    }
+    if let Some(&value) = inspector.f32("Pixels/Meter", settings.pixels_per_meter, 1.0..=10_000.0).new_value() {
+        edited.pixels_per_meter = value;
+        changed = true;
+    }
+    let iterations = settings
+        .solver_iterations
+        .unwrap_or(physics::PhysicsConfig::default().solver_iterations) as f32;
+    if let Some(&value) = inspector.f32("Solver Iterations", iterations, 1.0..=64.0).new_value() {
+        edited.solver_iterations = Some(value.round().max(1.0) as usize);
+        changed = true;
+    }
+    if let Some(&value) = inspector.f32("Fixed Rate (Hz)", 1.0 / settings.timestep, 1.0..=480.0).new_value() {
+        edited.timestep = 1.0 / value.max(1.0);
+        changed = true;
+    }
+
+    (changed.then_some(edited), inspector.y())
+}
@@ -0,0 +1,64 @@
+//! Sprite-sheet slicer popup and the atlas lookups behind named regions:
+//! the editor crate can't read files, so atlases are loaded (once per
+//! texture) and saved here through the `AssetManager`.
+
+use editor::{EditorContext, SlicerAction};
+use engine_core::assets::AssetManager;
+use engine_core::contexts::GameContext;
+use renderer::TextureHandle;
+
+/// `texture`'s atlas regions as `(name, region)`, reading its atlas file the
+/// first time the texture is asked about. Empty when it has no atlas.
+pub(super) fn atlas_regions(
+    editor: &mut EditorContext,
+    assets: &AssetManager,
+    texture: u32,
+) -> Vec<(String, [f32; 4])> {
+    if !editor.sprite_atlases.is_loaded(texture) {
+        editor.sprite_atlases.insert(texture, assets.load_sprite_atlas(texture).ok());
+    }
+    editor.sprite_atlases.named_regions(texture)
+}
+
+/// Open the slicer on `texture`, decoding its file for island detection.
+pub(super) fn open_sprite_slicer(editor: &mut EditorContext, ctx: &GameContext, texture: u32) {
+    let path = ctx.assets.texture_path(texture).filter(|path| !path.starts_with('#'));
+    let size = ctx.assets.get_texture(TextureHandle { id: texture }).map(|texture| (texture.width, texture.height));
+    let (Some(path), Some((width, height))) = (path, size) else {
+        editor.status_bar.show_error("Only textures loaded from files can be sliced");
+        return;
+    };
+    let pixels = ctx.assets.texture_pixels(texture).ok().map(|(pixels, _, _)| pixels);
+    editor.sprite_slicer.open(texture, path, width, height, pixels);
+}
+
+/// Sprite-sheet slicer popup, centered in the window (rendered before the
+/// panels, like the region picker). "Save Atlas" writes the atlas next to
+/// the texture and makes its names available to the inspector.
+pub(crate) fn render_sprite_slicer(editor: &mut EditorContext, ctx: &mut GameContext) {
+    let Some(texture) = editor.sprite_slicer.texture() else {
+        return;
+    };
+    if editor.in_play_session() {
+        editor.sprite_slicer.close();
+        return;
+    }
+
+    let size = editor::SPRITE_SLICER_SIZE;
+    let bounds = ui::Rect::new(
+        (ctx.window_size.x - size.x) / 2.0,
+        (ctx.window_size.y - size.y) / 2.0,
+        size.x,
+        size.y,
+    );
+    let Some(SlicerAction::Save(atlas)) = editor.sprite_slicer.render(ctx.ui, bounds, &editor.theme) else {
+        return;
+    };
+    match ctx.assets.save_sprite_atlas(&atlas) {
+        Ok(path) => {
+            editor.status_bar.show_message(format!("Saved {} regions to {}", atlas.len(), path.display()));
+            editor.sprite_atlases.insert(texture, Some(atlas));
+        }
+        Err(e) => editor.status_bar.show_error(format!("Failed to save atlas: {e}")),
+    }
+}
@@ -369,3 +369,43 @@ fn test_remove_component_via_command_and_undo() {
     assert!(world.get::<ecs::sprite_components::Sprite>(entity).is_some());
     assert_eq!(world.get::<ecs::sprite_components::Sprite>(entity).unwrap().texture_handle, 42);
 }
+
+#[test]
+fn test_history_ages_read_as_relative_times() {
+    use super::history::format_age;
+    use std::time::Duration;
+
+    assert_eq!(format_age(Duration::from_millis(1500)), "just now");
+    assert_eq!(format_age(Duration::from_secs(42)), "42s ago");
+    assert_eq!(format_age(Duration::from_secs(150)), "2m ago");
+    assert_eq!(format_age(Duration::from_secs(7300)), "2h ago");
+}
+
+#[test]
+fn test_scene_settings_fields_report_no_edit_without_input() {
+    use super::scene_settings::edit_physics_settings;
+
+    let mut ui = ui::UIContext::new();
+    let style = editor::EditorTheme::default().editable_field_style();
+    let row_height = style.row_height;
+    let settings = engine_core::scene_data::PhysicsSettings::default();
+    let (edited, bottom) = edit_physics_settings(&mut ui, 0.0, 0.0, style, &settings);
+
+    assert!(edited.is_none());
+    assert!(bottom >= 4.0 * row_height, "gravity, scale, iterations and rate rows");
+}
+
+#[test]
+fn test_sort_mode_fields_show_row_height_only_when_isometric() {
+    use super::scene_settings::edit_sort_mode;
+
+    let mut ui = ui::UIContext::new();
+    let style = editor::EditorTheme::default().editable_field_style();
+    let row_height = style.row_height;
+    let (edited, depth_bottom) = edit_sort_mode(&mut ui, 0.0, 0.0, style.clone(), ecs::SpriteSortMode::Depth);
+    assert!(edited.is_none());
+
+    let (edited, iso_bottom) = edit_sort_mode(&mut ui, 0.0, 0.0, style, ecs::SpriteSortMode::isometric(32.0));
+    assert!(edited.is_none());
+    assert!(iso_bottom >= depth_bottom + row_height, "row height row");
+}
@@ -0,0 +1,96 @@
+//! Texture picker popup wiring: gathers the loaded textures and the asset
+//! folder's images for `editor::TexturePicker`, and assigns the pick to
+//! the selected sprites (loading a folder image first) as one undo entry.
+
+use glam::Vec2;
+
+use ecs::sprite_components::Sprite;
+use editor::commands::{MacroCommand, SetSpriteCommand};
+use editor::{texture_picker_entries, CommandHistory, EditorCommand, EditorContext, TexturePickerAction};
+use engine_core::contexts::GameContext;
+
+/// Open the texture picker on the sprites of `entities` (primary first),
+/// offering every loaded texture with a path plus the asset browser's
+/// images.
+pub(super) fn open_texture_picker(editor: &mut EditorContext, ctx: &GameContext, entities: &[ecs::EntityId]) {
+    let Some(current) = entities.first().and_then(|&entity| ctx.world.get::<Sprite>(entity)) else {
+        return;
+    };
+    let current = current.texture_handle;
+    let loaded = ctx.assets.texture_handles().into_iter().filter_map(|handle| {
+        let path = ctx.assets.texture_path(handle.id)?.to_string();
+        let size = ctx
+            .assets
+            .get_texture(handle)
+            .map_or(Vec2::ZERO, |texture| Vec2::new(texture.width as f32, texture.height as f32));
+        Some((handle.id, path, size))
+    });
+    let entries = texture_picker_entries(loaded, &editor.asset_browser.entries);
+    editor.texture_picker.open(entities.to_vec(), current, entries);
+}
+
+/// Texture picker popup, centered in the window. Runs before the panels so
+/// its blocking rect makes the widgets underneath inert; a pick loads the
+/// image if needed and sets every target sprite's texture.
+pub(crate) fn render_texture_picker(
+    editor: &mut EditorContext,
+    ctx: &mut GameContext,
+    command_history: &mut CommandHistory,
+) {
+    if !editor.texture_picker.is_open() {
+        return;
+    }
+    if editor.in_play_session() {
+        editor.texture_picker.close();
+        return;
+    }
+
+    let size = editor::TEXTURE_PICKER_SIZE;
+    let bounds = ui::Rect::new(
+        (ctx.window_size.x - size.x) / 2.0,
+        (ctx.window_size.y - size.y) / 2.0,
+        size.x,
+        size.y,
+    );
+    let (entities, entry) = match editor.texture_picker.render(ctx.ui, bounds, &editor.theme) {
+        Some(TexturePickerAction::Pick { entities, entry }) => (entities, entry),
+        Some(TexturePickerAction::Cancel) | None => return,
+    };
+
+    let handle = match entry.handle {
+        Some(handle) => handle,
+        None => match ctx.assets.load_texture(&entry.path) {
+            Ok(handle) => {
+                // Share the load with the asset browser's thumbnail (the
+                // texture manager does not dedupe by path)
+                if let Some(asset) = editor.asset_browser.entries.iter_mut().find(|a| a.relative_path == entry.path) {
+                    asset.texture_handle = Some(handle.id);
+                }
+                handle.id
+            }
+            Err(e) => {
+                editor.status_bar.show_error(format!("Failed to load '{}': {e}", entry.path));
+                return;
+            }
+        },
+    };
+
+    let mut commands: Vec<Box<dyn EditorCommand>> = Vec::new();
+    for entity in entities {
+        let Some(old) = ctx.world.get::<Sprite>(entity).cloned() else {
+            continue;
+        };
+        if old.texture_handle != handle {
+            let new = Sprite { texture_handle: handle, ..old.clone() };
+            commands.push(Box::new(SetSpriteCommand::new(entity, old, new, "texture_handle")));
+        }
+    }
+    if commands.is_empty() {
+        return;
+    }
+    let count = commands.len();
+    command_history.execute(Box::new(MacroCommand::new("Set Texture", commands)), ctx.world);
+    editor.mark_dirty();
+    let target = if count == 1 { "sprite".to_string() } else { format!("{count} sprites") };
+    editor.status_bar.show_message(format!("Assigned {} to {target}", entry.path));
+}
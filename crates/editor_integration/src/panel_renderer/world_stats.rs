@@ -0,0 +1,95 @@
+//! World Stats panel: scene graph metrics, hierarchy warnings, and the
+//! "Flatten Subtree" fix for accidental deep nesting.
+//!
+//! The metrics come from `editor::SceneGraphStats`; this file draws them
+//! and runs the flatten command against the current selection.
+
+use glam::Vec2;
+
+use ecs::World;
+use editor::commands::FlattenSubtreeCommand;
+use editor::{CommandHistory, EditorCommand, EditorContext, SceneGraphStats, SceneGraphWarning};
+use engine_core::contexts::GameContext;
+
+/// Panel content padding.
+const PADDING: f32 = 8.0;
+/// Vertical distance between text rows.
+const ROW_HEIGHT: f32 = 18.0;
+
+/// Render the World Stats panel content.
+pub(super) fn render_world_stats(
+    editor: &mut EditorContext,
+    ctx: &mut GameContext,
+    bounds: common::Rect,
+    command_history: &mut CommandHistory,
+) {
+    let stats = SceneGraphStats::collect(ctx.world);
+    let x = bounds.x + PADDING;
+    let mut y = bounds.y + PADDING + 12.0;
+
+    let rows = [
+        format!("Entities: {}", stats.entity_count),
+        format!("Roots: {}", stats.root_count),
+        format!("Max depth: {}", stats.max_depth),
+        format!("Max children: {}", stats.max_children),
+        format!("Orphaned globals: {}", stats.orphaned_global_transforms.len()),
+    ];
+    for row in &rows {
+        ctx.ui.label_styled(row, Vec2::new(x, y), editor.theme.text_secondary, editor.theme.fonts.small);
+        y += ROW_HEIGHT;
+    }
+
+    y += ROW_HEIGHT / 2.0;
+    let warnings = stats.warnings();
+    if warnings.is_empty() {
+        ctx.ui.label_styled("No hierarchy warnings", Vec2::new(x, y), editor.theme.text_muted, editor.theme.fonts.small);
+        y += ROW_HEIGHT;
+    }
+    for warning in &warnings {
+        let color = match warning {
+            SceneGraphWarning::OrphanedGlobalTransforms(_) => editor.theme.error_red,
+            _ => editor.theme.warn_yellow,
+        };
+        ctx.ui.label_styled(&warning.message(), Vec2::new(x, y), color, editor.theme.fonts.small);
+        y += ROW_HEIGHT;
+    }
+
+    // Jump to the deepest entity so its chain can be inspected (and flattened
+    // from whichever ancestor the nesting went wrong at).
+    let button_width = (bounds.width - PADDING * 2.0).max(0.0);
+    if let Some(deepest) = stats.deepest.filter(|_| stats.max_depth > 0) {
+        let select_bounds = ui::Rect::new(x, y, button_width, 22.0);
+        if ctx.ui.button("world_stats_select_deepest", "Select Deepest", select_bounds) {
+            editor.selection.select(deepest);
+        }
+        y += 28.0;
+    }
+
+    let flatten_bounds = ui::Rect::new(x, y, button_width, 22.0);
+    if ctx.ui.button("world_stats_flatten", "Flatten Subtree", flatten_bounds) && !editor.is_playing() {
+        flatten_selected_subtree(editor, ctx.world, command_history);
+    }
+}
+
+/// Reparent every descendant of the primary selection directly under it,
+/// recording one undo entry. Reports the outcome in the status bar.
+pub(crate) fn flatten_selected_subtree(
+    editor: &mut EditorContext,
+    world: &mut World,
+    command_history: &mut CommandHistory,
+) {
+    let Some(root) = editor.selection.primary() else {
+        editor.status_bar.show_message("Select the root of the subtree to flatten");
+        return;
+    };
+    let mut cmd = FlattenSubtreeCommand::new(root);
+    cmd.execute(world);
+    let moved = cmd.moved_count();
+    if moved == 0 {
+        editor.status_bar.show_message("Subtree is already flat");
+        return;
+    }
+    command_history.push_already_executed(Box::new(cmd));
+    editor.mark_dirty();
+    editor.status_bar.show_message(format!("Flattened {moved} entities under entity {}", root.value()));
+}
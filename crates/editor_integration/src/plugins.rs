@@ -0,0 +1,23 @@
+//! Editor hooks for engine plugins.
+//!
+//! Plugins add dock panels with [`EditorPluginExt::add_editor_panel`] in
+//! their `build`; the panels travel as a plugin extension and are docked
+//! when the editor starts. Outside the editor the extension is ignored, so
+//! the same plugin works in shipped builds.
+
+use editor::{EditorPanel, PluginPanels};
+use engine_core::EngineBuilder;
+
+/// Editor registration methods on [`EngineBuilder`].
+pub trait EditorPluginExt {
+    /// Dock `panel` in the editor (ignored when running without it).
+    fn add_editor_panel(&mut self, panel: impl EditorPanel) -> &mut Self;
+}
+
+impl EditorPluginExt for EngineBuilder {
+    fn add_editor_panel(&mut self, panel: impl EditorPanel) -> &mut Self {
+        self.with_extension(|panels: &mut PluginPanels| {
+            panels.add(panel);
+        })
+    }
+}
@@ -29,11 +29,11 @@
 
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
-use std::time::{SystemTime, UNIX_EPOCH};
 
 use glam::Vec2;
 use serde::{Deserialize, Serialize};
 use ui::UIContext;
+use common::time::{SystemTime, UNIX_EPOCH};
 use common::{Color, Rect};
 
 /// An achievement definition.
@@ -0,0 +1,197 @@
+//! Custom asset loaders: turn file bytes into game-defined asset types.
+//!
+//! Textures are built in; everything else (dialogue tables, level data,
+//! audio banks) is loaded through an [`AssetLoader`] registered on the
+//! [`AssetManager`](crate::assets::AssetManager) — usually by a plugin via
+//! `EngineBuilder::add_asset_loader`. Loaders are keyed by file extension
+//! and asset type, so two loaders may share an extension if they produce
+//! different types.
+//!
+//! ```
+//! use std::path::Path;
+//! use engine_core::asset_loaders::{AssetLoader, AssetLoaders};
+//!
+//! struct Dialogue(Vec<String>);
+//!
+//! struct DialogueLoader;
+//! impl AssetLoader for DialogueLoader {
+//!     type Asset = Dialogue;
+//!     fn extensions(&self) -> &[&str] {
+//!         &["dlg"]
+//!     }
+//!     fn load(&self, bytes: &[u8], _path: &Path) -> Result<Dialogue, String> {
+//!         let text = std::str::from_utf8(bytes).map_err(|e| e.to_string())?;
+//!         Ok(Dialogue(text.lines().map(str::to_string).collect()))
+//!     }
+//! }
+//!
+//! let mut loaders = AssetLoaders::new();
+//! loaders.register(DialogueLoader);
+//! let intro: Dialogue = loaders.load_bytes(b"Hi!\nBye.", Path::new("intro.dlg")).unwrap();
+//! assert_eq!(intro.0, ["Hi!", "Bye."]);
+//! ```
+
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Arc;
+
+use crate::assets::AssetError;
+
+/// Loads one asset type from file bytes.
+pub trait AssetLoader: Send + Sync + 'static {
+    /// The asset type this loader produces.
+    type Asset: Send + Sync + 'static;
+
+    /// File extensions handled, without the dot (`"dlg"`, `"level.ron"`
+    /// is matched as `"ron"`). Matching is case-insensitive.
+    fn extensions(&self) -> &[&str];
+
+    /// Decode an asset. `path` is for error messages and relative lookups.
+    fn load(&self, bytes: &[u8], path: &Path) -> Result<Self::Asset, String>;
+}
+
+/// Object-safe view of an [`AssetLoader`] so loaders of different asset
+/// types share one registry.
+trait ErasedLoader: Send + Sync {
+    fn load_erased(&self, bytes: &[u8], path: &Path) -> Result<Box<dyn Any>, String>;
+}
+
+/// Registry of [`AssetLoader`]s keyed by (extension, asset type).
+#[derive(Default)]
+pub struct AssetLoaders {
+    loaders: HashMap<(String, TypeId), Box<dyn ErasedLoader>>,
+}
+
+impl AssetLoaders {
+    /// Create an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register `loader` for each of its extensions, replacing any loader
+    /// previously registered for the same extension and asset type.
+    pub fn register<L: AssetLoader>(&mut self, loader: L) {
+        let extensions: Vec<String> = loader.extensions().iter().map(|e| e.to_ascii_lowercase()).collect();
+        let loader = Arc::new(loader);
+        for extension in extensions {
+            self.loaders
+                .insert((extension, TypeId::of::<L::Asset>()), Box::new(SharedLoader(loader.clone())));
+        }
+    }
+
+    /// Whether a loader produces `T` from files with `path`'s extension.
+    pub fn can_load<T: 'static>(&self, path: &Path) -> bool {
+        self.loaders.contains_key(&(extension_of(path), TypeId::of::<T>()))
+    }
+
+    /// Number of (extension, asset type) registrations.
+    pub fn len(&self) -> usize {
+        self.loaders.len()
+    }
+
+    /// Whether no loaders are registered.
+    pub fn is_empty(&self) -> bool {
+        self.loaders.is_empty()
+    }
+
+    /// Decode `bytes` as a `T` with the loader registered for `path`'s
+    /// extension.
+    pub fn load_bytes<T: 'static>(&self, bytes: &[u8], path: &Path) -> Result<T, AssetError> {
+        let display = path.display().to_string();
+        let loader = self
+            .loaders
+            .get(&(extension_of(path), TypeId::of::<T>()))
+            .ok_or_else(|| AssetError::NoLoader(display.clone()))?;
+        let asset = loader
+            .load_erased(bytes, path)
+            .map_err(|message| AssetError::Load { path: display.clone(), message })?;
+        asset
+            .downcast::<T>()
+            .map(|asset| *asset)
+            .map_err(|_| AssetError::NoLoader(display))
+    }
+
+    /// Read `path` from disk and decode it as a `T`.
+    pub fn load_file<T: 'static>(&self, path: &Path) -> Result<T, AssetError> {
+        if !self.can_load::<T>(path) {
+            return Err(AssetError::NoLoader(path.display().to_string()));
+        }
+        let bytes = std::fs::read(path).map_err(|e| AssetError::Load {
+            path: path.display().to_string(),
+            message: e.to_string(),
+        })?;
+        self.load_bytes(&bytes, path)
+    }
+}
+
+/// One loader registered under several extensions.
+struct SharedLoader<L>(Arc<L>);
+
+impl<L: AssetLoader> ErasedLoader for SharedLoader<L> {
+    fn load_erased(&self, bytes: &[u8], path: &Path) -> Result<Box<dyn Any>, String> {
+        self.0.load(bytes, path).map(|asset| Box::new(asset) as Box<dyn Any>)
+    }
+}
+
+/// Lower-cased final extension of `path` (empty when it has none).
+fn extension_of(path: &Path) -> String {
+    path.extension()
+        .map(|e| e.to_string_lossy().to_ascii_lowercase())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Words;
+    impl AssetLoader for Words {
+        type Asset = Vec<String>;
+        fn extensions(&self) -> &[&str] {
+            &["txt", "WORDS"]
+        }
+        fn load(&self, bytes: &[u8], _path: &Path) -> Result<Vec<String>, String> {
+            let text = std::str::from_utf8(bytes).map_err(|e| e.to_string())?;
+            Ok(text.split_whitespace().map(str::to_string).collect())
+        }
+    }
+
+    struct Length;
+    impl AssetLoader for Length {
+        type Asset = usize;
+        fn extensions(&self) -> &[&str] {
+            &["txt"]
+        }
+        fn load(&self, bytes: &[u8], _path: &Path) -> Result<usize, String> {
+            Ok(bytes.len())
+        }
+    }
+
+    #[test]
+    fn test_loaders_are_keyed_by_extension_and_type() {
+        let mut loaders = AssetLoaders::new();
+        loaders.register(Words);
+        loaders.register(Length);
+        assert_eq!(loaders.len(), 3);
+
+        let words: Vec<String> = loaders.load_bytes(b"a b", Path::new("x.TXT")).unwrap();
+        assert_eq!(words, ["a", "b"]);
+        assert_eq!(loaders.load_bytes::<usize>(b"a b", Path::new("x.txt")).unwrap(), 3);
+        assert!(loaders.can_load::<Vec<String>>(Path::new("list.words")));
+        assert!(!loaders.can_load::<usize>(Path::new("list.words")));
+    }
+
+    #[test]
+    fn test_load_errors_name_the_path() {
+        let mut loaders = AssetLoaders::new();
+        loaders.register(Words);
+
+        let missing = loaders.load_bytes::<Vec<String>>(b"", Path::new("level.bin"));
+        assert!(matches!(missing, Err(AssetError::NoLoader(p)) if p == "level.bin"));
+        let bad_utf8 = loaders.load_bytes::<Vec<String>>(&[0xff], Path::new("bad.txt"));
+        assert!(matches!(bad_utf8, Err(AssetError::Load { path, .. }) if path == "bad.txt"));
+        let absent = loaders.load_file::<Vec<String>>(Path::new("/nonexistent/dir/a.txt"));
+        assert!(matches!(absent, Err(AssetError::Load { .. })));
+    }
+}
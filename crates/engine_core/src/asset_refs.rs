@@ -0,0 +1,215 @@
+//! Texture reference tracking for [`AssetManager`](crate::assets::AssetManager).
+//!
+//! Handles are plain ids, so the asset manager counts references itself:
+//! every frame the engine collects the texture ids components still point at
+//! ([`referenced_textures`] plus live particles) and the ids the frame's UI
+//! drew ([`ui_textures`]), and feeds them to [`TextureRefs::sweep`]. A
+//! managed texture (one loaded from a file or a `#solid` scene reference)
+//! that nothing references — no component, no UI image and no
+//! [`retain`](TextureRefs::retain) — starts accumulating unused time. With a
+//! grace period configured (`GameConfig::with_unused_texture_grace`) it is
+//! unloaded once that time runs out; `assets.unload_unused()` frees every
+//! such texture immediately.
+//!
+//! Games that keep a handle in their own state (a bullet texture loaded in
+//! `init()` and spawned later) must `retain_texture` it, or it may be freed
+//! while no bullet is alive.
+
+use std::collections::{HashMap, HashSet};
+
+use ecs::sprite_components::{MaskShape, Sprite, SpriteMask};
+use ecs::{Tilemap, World};
+use ui::DrawCommand;
+
+use crate::particles::ParticleEmitter;
+
+/// Texture ids the world's components reference: sprites, texture masks,
+/// tilemap tilesets and particle emitters.
+pub fn referenced_textures(world: &World) -> HashSet<u32> {
+    let mut referenced = HashSet::new();
+    for entity in world.entities() {
+        if let Some(sprite) = world.get::<Sprite>(entity) {
+            referenced.insert(sprite.texture_handle);
+        }
+        if let Some(MaskShape::Texture { texture_handle, .. }) = world.get::<SpriteMask>(entity).map(|mask| &mask.shape) {
+            referenced.insert(*texture_handle);
+        }
+        if let Some(tilemap) = world.get::<Tilemap>(entity) {
+            referenced.insert(tilemap.tileset);
+        }
+        if let Some(config) = world.get::<ParticleEmitter>(entity).and_then(|emitter| emitter.config.as_ref()) {
+            referenced.insert(config.texture);
+        }
+    }
+    referenced
+}
+
+/// Texture ids a frame's UI draw list samples (`ui.image*` calls).
+pub fn ui_textures(commands: &[DrawCommand]) -> impl Iterator<Item = u32> + '_ {
+    commands.iter().filter_map(|command| match command {
+        DrawCommand::Image { texture_id, .. } => Some(*texture_id),
+        _ => None,
+    })
+}
+
+/// GPU memory held by loaded textures, from
+/// [`AssetManager::memory_usage`](crate::assets::AssetManager::memory_usage).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AssetMemoryUsage {
+    /// Loaded textures, including generated ones
+    pub textures: usize,
+    /// Bytes held by all loaded textures
+    pub texture_bytes: u64,
+    /// Managed textures nothing referenced at the last sweep
+    pub unused_textures: usize,
+    /// Bytes `unload_unused()` would free
+    pub unused_bytes: u64,
+}
+
+/// Explicit retain counts and unused time per managed texture.
+#[derive(Debug, Default)]
+pub struct TextureRefs {
+    /// Retains held by game code, keyed by texture id
+    retained: HashMap<u32, usize>,
+    /// Managed textures unreferenced at the last sweep → seconds unused
+    unused: HashMap<u32, f32>,
+    /// Reused per sweep
+    scratch: HashSet<u32>,
+}
+
+impl TextureRefs {
+    /// Empty tracker.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Hold `id` loaded even with no component referencing it.
+    pub fn retain(&mut self, id: u32) {
+        *self.retained.entry(id).or_insert(0) += 1;
+        self.unused.remove(&id);
+    }
+
+    /// Drop one retain. Returns `false` when `id` wasn't retained.
+    pub fn release(&mut self, id: u32) -> bool {
+        match self.retained.get_mut(&id) {
+            Some(count) if *count > 1 => *count -= 1,
+            Some(_) => {
+                self.retained.remove(&id);
+            }
+            None => return false,
+        }
+        true
+    }
+
+    /// Retains currently held on `id`.
+    pub fn retain_count(&self, id: u32) -> usize {
+        self.retained.get(&id).copied().unwrap_or(0)
+    }
+
+    /// Forget everything about `id` (it was unloaded).
+    pub fn forget(&mut self, id: u32) {
+        self.retained.remove(&id);
+        self.unused.remove(&id);
+    }
+
+    /// Record one frame: `managed` textures not in `referenced` and not
+    /// retained gain `dt` seconds of unused time; the rest reset.
+    pub fn sweep(
+        &mut self,
+        managed: impl IntoIterator<Item = u32>,
+        referenced: impl IntoIterator<Item = u32>,
+        dt: f32,
+    ) {
+        self.scratch.clear();
+        self.scratch.extend(referenced);
+        let mut unused = HashMap::with_capacity(self.unused.len());
+        for id in managed {
+            if !self.scratch.contains(&id) && !self.retained.contains_key(&id) {
+                unused.insert(id, self.unused.get(&id).copied().unwrap_or(0.0) + dt);
+            }
+        }
+        self.unused = unused;
+    }
+
+    /// Textures unused at the last sweep, sorted by id.
+    pub fn unused(&self) -> Vec<u32> {
+        let mut ids: Vec<u32> = self.unused.keys().copied().collect();
+        ids.sort_unstable();
+        ids
+    }
+
+    /// Textures unused for at least `grace` seconds, sorted by id.
+    pub fn expired(&self, grace: f32) -> Vec<u32> {
+        let mut ids: Vec<u32> = self.unused.iter().filter(|(_, time)| **time >= grace).map(|(id, _)| *id).collect();
+        ids.sort_unstable();
+        ids
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unreferenced_textures_expire_after_grace() {
+        let mut refs = TextureRefs::new();
+        refs.sweep([1, 2, 3], [1], 0.5);
+        assert_eq!(refs.unused(), [2, 3]);
+        assert!(refs.expired(1.0).is_empty());
+
+        // Referencing a texture again resets its unused time
+        refs.sweep([1, 2, 3], [1, 3], 0.5);
+        refs.sweep([1, 2, 3], [1], 0.5);
+        assert_eq!(refs.expired(1.0), [2]);
+
+        refs.forget(2);
+        refs.sweep([1, 3], [1], 0.5);
+        assert_eq!(refs.expired(1.0), [3]);
+    }
+
+    #[test]
+    fn test_retained_textures_are_never_unused() {
+        let mut refs = TextureRefs::new();
+        refs.retain(7);
+        refs.retain(7);
+        refs.sweep([7], [], 10.0);
+        assert!(refs.unused().is_empty());
+
+        assert!(refs.release(7));
+        assert_eq!(refs.retain_count(7), 1);
+        assert!(refs.release(7));
+        assert!(!refs.release(7), "no retains left");
+        refs.sweep([7], [], 10.0);
+        assert_eq!(refs.expired(5.0), [7]);
+    }
+
+    #[test]
+    fn test_referenced_textures_cover_texture_components() {
+        let mut world = World::new();
+        world.spawn((Sprite::new(4),));
+        world.spawn((SpriteMask::texture(5, [0.0, 0.0, 1.0, 1.0], glam::Vec2::ONE),));
+        world.spawn((Tilemap { tileset: 6, ..Tilemap::new(2, 2, 16.0) },));
+        let config = crate::particles::ParticleConfig { texture: 8, ..Default::default() };
+        let emitter = ParticleEmitter { config: Some(config), ..Default::default() };
+        world.spawn((emitter,));
+
+        let mut ids: Vec<u32> = referenced_textures(&world).into_iter().collect();
+        ids.sort_unstable();
+        assert_eq!(ids, [4, 5, 6, 8]);
+    }
+
+    #[test]
+    fn test_ui_only_texture_counts_as_referenced() {
+        let mut ui = ui::UIContext::new();
+        ui.begin_frame(&input::InputHandler::new(), glam::Vec2::new(800.0, 600.0));
+        ui.image(ui::Rect::new(0.0, 0.0, 32.0, 32.0), 9, ui::Color::WHITE);
+        ui.end_frame();
+
+        let world = World::new();
+        let mut referenced = referenced_textures(&world);
+        referenced.extend(ui_textures(ui.draw_list().commands()));
+        let mut refs = TextureRefs::new();
+        refs.sweep([9, 10], referenced, 1.0);
+        assert_eq!(refs.unused(), [10], "a texture only the UI draws stays in use");
+    }
+}
@@ -0,0 +1,271 @@
+//! Asset roots and virtual asset paths.
+//!
+//! Relative asset paths used to be joined onto a base directory string,
+//! which breaks as soon as the game is launched from another working
+//! directory (and there is no meaningful working directory on the web or on
+//! mobile). An [`AssetRoot`] anchors them instead:
+//!
+//! - `assets://textures/player.png` and plain `textures/player.png` resolve
+//!   against the root directory; `\` and `/` both separate segments.
+//! - Mount points send a virtual prefix elsewhere
+//!   (`assets://dlc/boss.png` → `<dlc dir>/boss.png`); the longest matching
+//!   prefix wins.
+//! - Absolute filesystem paths pass through unchanged.
+//!
+//! [`AssetRoot::packaged`] (through the [`asset_root!`](crate::asset_root!)
+//! macro) finds the shipped `assets/` folder next to the executable, or the
+//! game crate's `assets/` under `cargo run`.
+//!
+//! ```
+//! use std::path::Path;
+//! use engine_core::asset_root::AssetRoot;
+//!
+//! let root = AssetRoot::new("/games/demo/assets").with_mount("dlc", "/games/demo/dlc");
+//! assert_eq!(root.resolve("assets://textures/player.png"), Path::new("/games/demo/assets/textures/player.png"));
+//! assert_eq!(root.resolve("textures/player.png"), Path::new("/games/demo/assets/textures/player.png"));
+//! assert_eq!(root.resolve("assets://dlc/boss.png"), Path::new("/games/demo/dlc/boss.png"));
+//! ```
+
+use std::path::{Component, Path, PathBuf};
+
+use crate::assets::AssetManager;
+
+/// Scheme marking a virtual asset path.
+pub const ASSET_SCHEME: &str = "assets://";
+
+/// Whether `path` is written as a virtual path (`assets://...`).
+pub fn is_virtual(path: &str) -> bool {
+    path.starts_with(ASSET_SCHEME)
+}
+
+/// Normalize a virtual or relative asset path to `a/b/c` form: scheme
+/// stripped, either separator accepted, `.` and empty segments dropped and
+/// `..` applied. `None` when `..` climbs above the root.
+pub fn normalize_virtual(path: &str) -> Option<String> {
+    let path = path.strip_prefix(ASSET_SCHEME).unwrap_or(path);
+    let mut segments = Vec::new();
+    for segment in path.split(['/', '\\']) {
+        match segment {
+            "" | "." => {}
+            ".." => {
+                segments.pop()?;
+            }
+            segment => segments.push(segment),
+        }
+    }
+    Some(segments.join("/"))
+}
+
+/// A virtual prefix served from another directory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Mount {
+    /// Normalized prefix, e.g. `dlc` or `mods/extra`
+    prefix: String,
+    directory: PathBuf,
+}
+
+/// Where asset paths resolve: a root directory plus mount points.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AssetRoot {
+    directory: PathBuf,
+    mounts: Vec<Mount>,
+}
+
+impl Default for AssetRoot {
+    /// `assets/` under the working directory (the historical default).
+    fn default() -> Self {
+        Self::new("assets")
+    }
+}
+
+impl AssetRoot {
+    /// A root serving every virtual path from `directory`.
+    pub fn new(directory: impl Into<PathBuf>) -> Self {
+        Self { directory: directory.into(), mounts: Vec::new() }
+    }
+
+    /// The root of a packaged build: `assets/` beside the executable when
+    /// shipped that way, otherwise `assets/` in `manifest_dir` (the game
+    /// crate's directory under `cargo run`). Usually called through
+    /// [`asset_root!`](crate::asset_root!).
+    #[cfg(not(any(target_arch = "wasm32", target_os = "android", target_os = "ios")))]
+    pub fn packaged(manifest_dir: &str) -> Self {
+        Self::new(crate::assets::game_root_from(manifest_dir).join("assets"))
+    }
+
+    /// The root of a packaged build. Web and mobile builds have no
+    /// executable directory to look in; assets are served relative to the
+    /// page or app bundle.
+    #[cfg(any(target_arch = "wasm32", target_os = "android", target_os = "ios"))]
+    pub fn packaged(_manifest_dir: &str) -> Self {
+        Self::new("assets")
+    }
+
+    /// Directory unmounted virtual paths resolve against.
+    pub fn directory(&self) -> &Path {
+        &self.directory
+    }
+
+    /// Move the root (mount points stay).
+    pub fn set_directory(&mut self, directory: impl Into<PathBuf>) {
+        self.directory = directory.into();
+    }
+
+    /// Serve virtual paths under `prefix` (`"dlc"`, `"assets://mods/extra"`)
+    /// from `directory`, replacing an existing mount of the same prefix. An
+    /// empty prefix moves the root instead.
+    pub fn mount(&mut self, prefix: &str, directory: impl Into<PathBuf>) {
+        let directory = directory.into();
+        let prefix = normalize_virtual(prefix).unwrap_or_default();
+        if prefix.is_empty() {
+            self.directory = directory;
+            return;
+        }
+        match self.mounts.iter_mut().find(|mount| mount.prefix == prefix) {
+            Some(mount) => mount.directory = directory,
+            None => self.mounts.push(Mount { prefix, directory }),
+        }
+    }
+
+    /// Builder form of [`mount`](Self::mount).
+    pub fn with_mount(mut self, prefix: &str, directory: impl Into<PathBuf>) -> Self {
+        self.mount(prefix, directory);
+        self
+    }
+
+    /// Remove the mount for `prefix`. Returns whether one existed.
+    pub fn unmount(&mut self, prefix: &str) -> bool {
+        let prefix = normalize_virtual(prefix).unwrap_or_default();
+        let before = self.mounts.len();
+        self.mounts.retain(|mount| mount.prefix != prefix);
+        self.mounts.len() != before
+    }
+
+    /// Mount points as `(prefix, directory)`, in the order they were added.
+    pub fn mounts(&self) -> impl Iterator<Item = (&str, &Path)> {
+        self.mounts.iter().map(|mount| (mount.prefix.as_str(), mount.directory.as_path()))
+    }
+
+    /// Filesystem path for an asset path. Virtual and relative paths go
+    /// through the mount points and root; absolute paths are returned as-is.
+    /// Relative paths that climb above the root (`../shared/x.png`) are
+    /// joined onto the root unchanged, as before virtual paths existed.
+    pub fn resolve(&self, path: impl AsRef<Path>) -> PathBuf {
+        let path = path.as_ref();
+        let Some(text) = path.to_str() else {
+            return if path.is_relative() { self.directory.join(path) } else { path.to_path_buf() };
+        };
+        if !is_virtual(text) && path.is_absolute() {
+            return path.to_path_buf();
+        }
+        let Some(normalized) = normalize_virtual(text) else {
+            return self.directory.join(text.strip_prefix(ASSET_SCHEME).unwrap_or(text));
+        };
+        let (directory, rest) = self.mount_for(&normalized);
+        rest.split('/').filter(|segment| !segment.is_empty()).fold(directory.to_path_buf(), |file, segment| {
+            file.join(segment)
+        })
+    }
+
+    /// Virtual path (`assets://...`) for a file under the root or a mount
+    /// directory; the most specific directory wins. `None` for files
+    /// outside all of them.
+    pub fn to_virtual(&self, file: &Path) -> Option<String> {
+        let roots = self
+            .mounts
+            .iter()
+            .map(|mount| (mount.prefix.as_str(), mount.directory.as_path()))
+            .chain(std::iter::once(("", self.directory.as_path())));
+        let (prefix, rest) = roots
+            .filter_map(|(prefix, directory)| Some((prefix, directory, file.strip_prefix(directory).ok()?)))
+            .max_by_key(|(_, directory, _)| directory.components().count())
+            .map(|(prefix, _, rest)| (prefix, rest))?;
+
+        let mut segments: Vec<&str> = (!prefix.is_empty()).then_some(prefix).into_iter().collect();
+        for component in rest.components() {
+            match component {
+                Component::Normal(segment) => segments.push(segment.to_str()?),
+                Component::CurDir => {}
+                _ => return None,
+            }
+        }
+        Some(format!("{ASSET_SCHEME}{}", segments.join("/")))
+    }
+
+    /// Directory serving `path` (normalized) and the rest of the path
+    /// below it.
+    fn mount_for<'a>(&self, path: &'a str) -> (&Path, &'a str) {
+        self.mounts
+            .iter()
+            .filter_map(|mount| {
+                let rest = path.strip_prefix(mount.prefix.as_str())?;
+                (rest.is_empty() || rest.starts_with('/')).then_some((mount, rest))
+            })
+            .max_by_key(|(mount, _)| mount.prefix.len())
+            .map_or((self.directory.as_path(), path), |(mount, rest)| (mount.directory.as_path(), rest))
+    }
+}
+
+impl AssetManager {
+    /// Where asset paths resolve (root directory and mount points).
+    pub fn asset_root(&self) -> &AssetRoot {
+        &self.root
+    }
+
+    /// Serve virtual paths under `prefix` from `directory` (see
+    /// [`AssetRoot::mount`]). Textures already loaded keep their files.
+    pub fn mount(&mut self, prefix: &str, directory: impl Into<PathBuf>) {
+        self.root.mount(prefix, directory);
+    }
+
+    /// Filesystem path an asset path loads from.
+    pub fn resolve_path(&self, path: impl AsRef<Path>) -> PathBuf {
+        self.root.resolve(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_accepts_either_separator_and_rejects_escapes() {
+        assert_eq!(normalize_virtual("assets://textures\\ui/./button.png").as_deref(), Some("textures/ui/button.png"));
+        assert_eq!(normalize_virtual("a/b/../c.png").as_deref(), Some("a/c.png"));
+        assert_eq!(normalize_virtual("assets://../secret.txt"), None);
+        assert!(is_virtual("assets://x.png"));
+        assert!(!is_virtual("x.png"));
+    }
+
+    #[test]
+    fn test_resolve_uses_longest_mount_and_passes_absolute_paths() {
+        let root = AssetRoot::new("game/assets")
+            .with_mount("mods", "mods_dir")
+            .with_mount("assets://mods/extra", "extra_dir");
+        let expect = |parts: &[&str]| parts.iter().collect::<PathBuf>();
+
+        assert_eq!(root.resolve("assets://hero.png"), expect(&["game", "assets", "hero.png"]));
+        assert_eq!(root.resolve("mods/a.png"), expect(&["mods_dir", "a.png"]));
+        assert_eq!(root.resolve("assets://mods/extra/b.png"), expect(&["extra_dir", "b.png"]));
+        assert_eq!(root.resolve("modsfoo/c.png"), expect(&["game", "assets", "modsfoo", "c.png"]), "whole segments only");
+        assert_eq!(root.resolve("../shared/d.png"), Path::new("game/assets").join("../shared/d.png"));
+
+        let absolute = std::env::temp_dir().join("e.png");
+        assert_eq!(root.resolve(&absolute), absolute);
+    }
+
+    #[test]
+    fn test_to_virtual_inverts_resolve_and_mounts_can_be_replaced() {
+        let mut root = AssetRoot::new("game/assets").with_mount("dlc", "game/assets/dlc_pack");
+        assert_eq!(root.to_virtual(&root.resolve("dlc/boss.png")).as_deref(), Some("assets://dlc/boss.png"));
+        assert_eq!(root.to_virtual(&root.resolve("ui/icon.png")).as_deref(), Some("assets://ui/icon.png"));
+        assert_eq!(root.to_virtual(Path::new("elsewhere/x.png")), None);
+
+        root.mount("dlc", "patch");
+        assert_eq!(root.mounts().collect::<Vec<_>>(), [("dlc", Path::new("patch"))]);
+        assert!(root.unmount("assets://dlc"));
+        assert!(!root.unmount("dlc"));
+        root.mount("", "moved");
+        assert_eq!(root.directory(), Path::new("moved"));
+    }
+}
@@ -29,12 +29,17 @@ use std::path::Path;
 use std::sync::Arc;
 
 use renderer::{
-    TextureManager, TextureHandle, TextureResource, TextureLoadConfig, TextureError,
+    AtlasUpload, TextureManager, TextureHandle, TextureResource, TextureLoadConfig, TextureError,
 };
 
 // Re-export wgpu types from renderer
 use renderer::wgpu::{Device, Queue};
 
+use crate::asset_loaders::{AssetLoader, AssetLoaders};
+use crate::asset_refs::{AssetMemoryUsage, TextureRefs};
+use crate::asset_root::{AssetRoot, ASSET_SCHEME};
+use crate::texture_import::{TextureImportSettings, TextureSizes};
+
 /// Asset loading errors
 #[derive(Debug, thiserror::Error)]
 pub enum AssetError {
@@ -46,22 +51,44 @@ pub enum AssetError {
 
     #[error("Asset manager not initialized")]
     NotInitialized,
+
+    #[error("No asset loader for {0}")]
+    NoLoader(String),
+
+    #[error("Failed to load asset {path}: {message}")]
+    Load { path: String, message: String },
 }
 
 /// Configuration for the asset manager
 #[derive(Debug, Clone)]
 pub struct AssetConfig {
-    /// Base path for asset loading (relative to the executable)
+    /// Root directory that relative and `assets://` paths resolve against
+    /// (relative to the working directory unless absolute; see
+    /// [`AssetRoot::packaged`] for a launch-independent root)
     pub base_path: String,
+    /// Mount points as `(virtual prefix, directory)`, e.g.
+    /// `("dlc", "/opt/game/dlc")` serves `assets://dlc/...` from there
+    pub mounts: Vec<(String, String)>,
     /// Whether to log asset loading operations
     pub log_loading: bool,
+    /// Sampling and mipmap settings for textures loaded without an explicit
+    /// config (`load_texture`, scene textures). `GameConfig::pixel_art`
+    /// switches these to nearest filtering.
+    pub texture_defaults: TextureLoadConfig,
+    /// Seconds a file or `#solid` texture may go unreferenced (no component,
+    /// no `retain_texture`) before it's unloaded automatically. `None` keeps
+    /// textures until `unload_unused()` or `unload_texture()`.
+    pub unused_texture_grace: Option<f32>,
 }
 
 impl Default for AssetConfig {
     fn default() -> Self {
         Self {
             base_path: "assets".to_string(),
+            mounts: Vec::new(),
             log_loading: true,
+            texture_defaults: TextureLoadConfig::default(),
+            unused_texture_grace: None,
         }
     }
 }
@@ -75,6 +102,16 @@ pub struct AssetManager {
     config: AssetConfig,
     /// Maps texture handle IDs back to their original path strings for serialization.
     handle_to_path: HashMap<u32, String>,
+    /// Pixels-per-unit import settings (from `<base>/import_settings.ron`).
+    import_settings: TextureImportSettings,
+    /// Natural world size of each loaded file texture.
+    texture_sizes: TextureSizes,
+    /// Custom (non-texture) asset loaders, usually registered by plugins.
+    loaders: AssetLoaders,
+    /// Retain counts and unused time of managed textures.
+    texture_refs: TextureRefs,
+    /// Resolves asset paths (`config.base_path` plus mount points).
+    pub(crate) root: AssetRoot,
 }
 
 impl AssetManager {
@@ -83,10 +120,18 @@ impl AssetManager {
         let mut handle_to_path = HashMap::new();
         // Handle 0 is always the white texture
         handle_to_path.insert(0, "#white".to_string());
+        let config = AssetConfig::default();
+        let import_settings = TextureImportSettings::load_or_default(Path::new(&config.base_path));
+        let root = asset_root(&config);
         Self {
             texture_manager: TextureManager::new(device, queue),
-            config: AssetConfig::default(),
+            config,
             handle_to_path,
+            import_settings,
+            texture_sizes: TextureSizes::new(),
+            loaders: AssetLoaders::new(),
+            texture_refs: TextureRefs::new(),
+            root,
         }
     }
 
@@ -95,17 +140,25 @@ impl AssetManager {
         let mut handle_to_path = HashMap::new();
         // Handle 0 is always the white texture
         handle_to_path.insert(0, "#white".to_string());
+        let import_settings = TextureImportSettings::load_or_default(Path::new(&config.base_path));
+        let root = asset_root(&config);
         Self {
             texture_manager: TextureManager::new(device, queue),
             config,
             handle_to_path,
+            import_settings,
+            texture_sizes: TextureSizes::new(),
+            loaders: AssetLoaders::new(),
+            texture_refs: TextureRefs::new(),
+            root,
         }
     }
 
     /// Load a texture from a file path
     ///
-    /// The path can be absolute or relative. If relative, it will be resolved
-    /// against the asset manager's base path.
+    /// The path can be virtual (`assets://sprites/player.png`), relative
+    /// (same thing) or absolute; see [`crate::asset_root`] for how virtual
+    /// and relative paths resolve against the base path and mount points.
     ///
     /// # Example
     /// ```
@@ -121,24 +174,34 @@ impl AssetManager {
         let path = path.as_ref();
         let original_path_string = path.to_string_lossy().to_string();
 
-        // Resolve path against base path if relative
-        let full_path = if path.is_relative() {
-            Path::new(&self.config.base_path).join(path)
-        } else {
-            path.to_path_buf()
-        };
+        let full_path = self.root.resolve(path);
 
         if self.config.log_loading {
             log::info!("Loading texture: {:?}", full_path);
         }
 
-        let handle = self.texture_manager.load_texture(&full_path, TextureLoadConfig::default())?;
+        let handle = self.texture_manager.load_texture(&full_path, self.config.texture_defaults.clone())?;
+        self.record_texture_size(handle, &original_path_string);
         self.handle_to_path.insert(handle.id, original_path_string);
 
         Ok(handle)
     }
 
-    /// Load a texture with custom configuration
+    /// Load a texture with its own filtering, address mode and mipmap
+    /// settings, overriding [`texture_defaults`](Self::texture_defaults).
+    ///
+    /// ```no_run
+    /// # use engine_core::prelude::*;
+    /// # use renderer::{wgpu::AddressMode, SamplerConfig, TextureLoadConfig};
+    /// # fn load(assets: &mut AssetManager) -> Result<(), AssetError> {
+    /// let tiles = assets.load_texture_with_config(
+    ///     "tiles.png",
+    ///     TextureLoadConfig::pixel_art().with_sampler(SamplerConfig::nearest().with_address_mode(AddressMode::Repeat)),
+    /// )?;
+    /// let backdrop = assets.load_texture_with_config("sky.png", TextureLoadConfig::default().with_mipmaps(true))?;
+    /// # Ok(())
+    /// # }
+    /// ```
     pub fn load_texture_with_config<P: AsRef<Path>>(
         &mut self,
         path: P,
@@ -146,17 +209,14 @@ impl AssetManager {
     ) -> Result<TextureHandle, AssetError> {
         let path = path.as_ref();
         let original_path_string = path.to_string_lossy().to_string();
-        let full_path = if path.is_relative() {
-            Path::new(&self.config.base_path).join(path)
-        } else {
-            path.to_path_buf()
-        };
+        let full_path = self.root.resolve(path);
 
         if self.config.log_loading {
             log::info!("Loading texture with config: {:?}", full_path);
         }
 
         let handle = self.texture_manager.load_texture(&full_path, config)?;
+        self.record_texture_size(handle, &original_path_string);
         self.handle_to_path.insert(handle.id, original_path_string);
 
         Ok(handle)
@@ -166,7 +226,9 @@ impl AssetManager {
     ///
     /// Useful for loading textures from embedded assets or network resources.
     pub fn load_texture_from_bytes(&mut self, bytes: &[u8]) -> Result<TextureHandle, AssetError> {
-        let handle = self.texture_manager.load_texture_from_bytes(bytes, TextureLoadConfig::default())?;
+        let handle = self.texture_manager.load_texture_from_bytes(bytes, self.config.texture_defaults.clone())?;
+        // No path to key an override on: the project default applies.
+        self.record_texture_size(handle, "");
         Ok(handle)
     }
 
@@ -216,16 +278,7 @@ impl AssetManager {
             return Ok(TextureHandle { id: 0 });
         }
 
-        // Convert grayscale to RGBA where RGBA all = grayscale value
-        // This allows the shader to multiply by text color while preserving shape
-        let mut rgba = Vec::with_capacity((width * height * 4) as usize);
-        for &gray in grayscale {
-            rgba.push(gray); // R = grayscale
-            rgba.push(gray); // G = grayscale
-            rgba.push(gray); // B = grayscale
-            rgba.push(gray); // A = grayscale
-        }
-
+        let rgba = grayscale_to_rgba(grayscale);
         let handle = self.texture_manager.load_texture_from_rgba(
             width,
             height,
@@ -235,6 +288,39 @@ impl AssetManager {
         Ok(handle)
     }
 
+    /// Create a blank (fully transparent) `size`×`size` page for the UI
+    /// atlas, to be filled with [`write_glyph_region`](Self::write_glyph_region)
+    /// or [`write_atlas_upload`](Self::write_atlas_upload).
+    pub fn create_glyph_atlas_page(&mut self, size: u32) -> Result<TextureHandle, AssetError> {
+        let blank = vec![0u8; (size * size * 4) as usize];
+        let handle = self.texture_manager.load_texture_from_rgba(size, size, &blank, TextureLoadConfig::default())?;
+        Ok(handle)
+    }
+
+    /// Write a grayscale glyph bitmap into a region of an atlas page, as the
+    /// same alpha mask [`create_glyph_texture`](Self::create_glyph_texture) builds.
+    pub fn write_glyph_region(
+        &mut self,
+        page: TextureHandle,
+        x: u32,
+        y: u32,
+        width: u32,
+        height: u32,
+        grayscale: &[u8],
+    ) -> Result<(), AssetError> {
+        let rgba = grayscale_to_rgba(grayscale);
+        self.texture_manager.write_texture_region(page, x, y, width, height, &rgba)?;
+        Ok(())
+    }
+
+    /// Write pixels packed into a [`TextureAtlasBuilder`](renderer::TextureAtlasBuilder)
+    /// into the atlas page texture mirroring it.
+    pub fn write_atlas_upload(&mut self, page: TextureHandle, upload: &AtlasUpload) -> Result<(), AssetError> {
+        self.texture_manager
+            .write_texture_region(page, upload.x, upload.y, upload.width, upload.height, &upload.data)?;
+        Ok(())
+    }
+
     /// Get a texture resource by handle
     pub fn get_texture(&self, handle: TextureHandle) -> Option<&TextureResource> {
         self.texture_manager.get_texture(handle)
@@ -247,9 +333,71 @@ impl AssetManager {
 
     /// Unload a texture, freeing GPU resources
     pub fn unload_texture(&mut self, handle: TextureHandle) -> bool {
+        if handle == TextureHandle::WHITE {
+            return false;
+        }
+        self.texture_sizes.remove(handle.id);
+        self.texture_refs.forget(handle.id);
+        self.handle_to_path.remove(&handle.id);
         self.texture_manager.remove_texture(handle).is_some()
     }
 
+    /// Keep `handle` loaded while no component references it — for handles
+    /// the game stores itself and spawns from later. Counted: each retain
+    /// needs its own [`release_texture`](Self::release_texture).
+    pub fn retain_texture(&mut self, handle: TextureHandle) {
+        self.texture_refs.retain(handle.id);
+    }
+
+    /// Drop one [`retain_texture`](Self::retain_texture). Returns `false`
+    /// when the texture wasn't retained.
+    pub fn release_texture(&mut self, handle: TextureHandle) -> bool {
+        self.texture_refs.release(handle.id)
+    }
+
+    /// Retains currently held on `handle`.
+    pub fn texture_retain_count(&self, handle: TextureHandle) -> usize {
+        self.texture_refs.retain_count(handle.id)
+    }
+
+    /// Record which textures are in use this frame (see
+    /// [`asset_refs::referenced_textures`](crate::asset_refs::referenced_textures))
+    /// and, with [`AssetConfig::unused_texture_grace`] set, unload managed
+    /// textures unused for longer than the grace period. The engine calls
+    /// this once per frame. Returns the number of textures unloaded.
+    pub fn track_texture_usage(&mut self, referenced: impl IntoIterator<Item = u32>, dt: f32) -> usize {
+        let managed = self.managed_textures();
+        self.texture_refs.sweep(managed, referenced, dt);
+        match self.config.unused_texture_grace {
+            Some(grace) => self.unload_all(self.texture_refs.expired(grace)),
+            None => 0,
+        }
+    }
+
+    /// Unload every file or `#solid` texture nothing referenced at the last
+    /// usage sweep and no one retains (e.g. after a level change). Returns
+    /// the number of textures unloaded.
+    pub fn unload_unused(&mut self) -> usize {
+        self.unload_all(self.texture_refs.unused())
+    }
+
+    /// Loaded texture count and GPU bytes, and how much of that
+    /// [`unload_unused`](Self::unload_unused) would free.
+    pub fn memory_usage(&self) -> AssetMemoryUsage {
+        let textures = self.texture_manager.textures();
+        let unused = self.texture_refs.unused();
+        AssetMemoryUsage {
+            textures: textures.len(),
+            texture_bytes: textures.values().map(TextureResource::memory_bytes).sum(),
+            unused_textures: unused.len(),
+            unused_bytes: unused
+                .iter()
+                .filter_map(|id| textures.get(&TextureHandle::new(*id)))
+                .map(TextureResource::memory_bytes)
+                .sum(),
+        }
+    }
+
     /// Get the number of loaded textures
     pub fn texture_count(&self) -> usize {
         self.texture_manager.texture_count()
@@ -267,9 +415,24 @@ impl AssetManager {
         self.texture_manager.textures()
     }
 
-    /// Set the base path for asset loading
+    /// Set the base path for asset loading (mount points stay), reloading
+    /// the import settings stored there.
     pub fn set_base_path(&mut self, path: impl Into<String>) {
         self.config.base_path = path.into();
+        self.root.set_directory(&self.config.base_path);
+        let settings = TextureImportSettings::load_or_default(Path::new(&self.config.base_path));
+        self.set_import_settings(settings);
+    }
+
+    /// Load settings applied to textures loaded without an explicit config.
+    pub fn texture_defaults(&self) -> &TextureLoadConfig {
+        &self.config.texture_defaults
+    }
+
+    /// Change the settings for textures loaded from now on; textures
+    /// already loaded keep theirs.
+    pub fn set_texture_defaults(&mut self, config: TextureLoadConfig) {
+        self.config.texture_defaults = config;
     }
 
     /// Get the current base path
@@ -284,6 +447,98 @@ impl AssetManager {
     pub fn texture_path(&self, handle: u32) -> Option<&str> {
         self.handle_to_path.get(&handle).map(|s| s.as_str())
     }
+
+    /// Pixels-per-unit import settings in effect.
+    pub fn import_settings(&self) -> &TextureImportSettings {
+        &self.import_settings
+    }
+
+    /// Replace the import settings and resize every loaded texture to match.
+    /// Call [`save_import_settings`](Self::save_import_settings) to persist.
+    pub fn set_import_settings(&mut self, settings: TextureImportSettings) {
+        self.import_settings = settings;
+        for handle in self.texture_manager.texture_handles() {
+            let path = match self.handle_to_path.get(&handle.id) {
+                // Generated textures keep the fixed generated size.
+                Some(path) if path.starts_with('#') => continue,
+                Some(path) => path.clone(),
+                None if self.texture_sizes.contains(handle.id) => String::new(),
+                None => continue,
+            };
+            self.record_texture_size(handle, &path);
+        }
+    }
+
+    /// Write the import settings to `<base>/import_settings.ron`.
+    pub fn save_import_settings(&self) -> std::io::Result<()> {
+        self.import_settings.save(Path::new(&self.config.base_path))
+    }
+
+    /// Natural (scale-1) world sizes of loaded textures, used by the render
+    /// path and editor picking to size sprites.
+    pub fn texture_sizes(&self) -> &TextureSizes {
+        &self.texture_sizes
+    }
+
+    /// Register a custom asset loader (see [`crate::asset_loaders`]).
+    pub fn register_loader<L: AssetLoader>(&mut self, loader: L) {
+        self.loaders.register(loader);
+    }
+
+    /// Replace the custom loaders (the plugins' loaders at startup).
+    pub(crate) fn set_loaders(&mut self, loaders: AssetLoaders) {
+        self.loaders = loaders;
+    }
+
+    /// Load a custom asset with the loader registered for the file's
+    /// extension and `T`. Virtual and relative paths resolve through the
+    /// [`asset_root`](Self::asset_root).
+    pub fn load_asset<T: 'static, P: AsRef<Path>>(&self, path: P) -> Result<T, AssetError> {
+        self.loaders.load_file(&self.root.resolve(path))
+    }
+
+    /// Textures eligible for automatic unloading: loaded from a file or a
+    /// `#solid` reference. Generated textures (glyphs, the white texture)
+    /// live until unloaded explicitly.
+    fn managed_textures(&self) -> Vec<u32> {
+        self.handle_to_path
+            .keys()
+            .copied()
+            .filter(|id| *id != TextureHandle::WHITE.id && self.texture_manager.has_texture(TextureHandle::new(*id)))
+            .collect()
+    }
+
+    fn unload_all(&mut self, ids: Vec<u32>) -> usize {
+        let unloaded = ids.into_iter().filter(|id| self.unload_texture(TextureHandle::new(*id))).count();
+        if unloaded > 0 && self.config.log_loading {
+            log::info!("Unloaded {unloaded} unused texture(s)");
+        }
+        unloaded
+    }
+
+    /// Record a file texture's natural size from its pixel size and the
+    /// pixels-per-unit for `path` (overrides are keyed without the
+    /// `assets://` scheme).
+    fn record_texture_size(&mut self, handle: TextureHandle, path: &str) {
+        let pixels_per_unit = self.import_settings.pixels_per_unit(path.strip_prefix(ASSET_SCHEME).unwrap_or(path));
+        if let Some(texture) = self.texture_manager.get_texture(handle) {
+            self.texture_sizes.insert(handle.id, texture.width, texture.height, pixels_per_unit);
+        }
+    }
+}
+
+/// Asset root for a configuration: its base path and mount points.
+fn asset_root(config: &AssetConfig) -> AssetRoot {
+    config
+        .mounts
+        .iter()
+        .fold(AssetRoot::new(&config.base_path), |root, (prefix, directory)| root.with_mount(prefix, directory))
+}
+
+/// Expand a grayscale glyph bitmap to RGBA with every channel set to the
+/// gray value: an alpha mask the shader multiplies by the text color.
+pub(crate) fn grayscale_to_rgba(grayscale: &[u8]) -> Vec<u8> {
+    grayscale.iter().flat_map(|&gray| [gray; 4]).collect()
 }
 
 #[cfg(test)]
@@ -0,0 +1,127 @@
+//! Audio occlusion: colliders between the listener and a sound muffle it.
+//!
+//! Each frame, for every entity with an [`AudioSource`] and an enabled
+//! [`AudioOcclusion`], a ray from the active [`AudioListener`] to the source
+//! counts the solid colliders on the occlusion's layers in between. The
+//! occlusion's `amount` eases toward `blockers * per_blocker`, and the
+//! source's sound is muffled with [`AudioManager::set_muffle`]: its volume
+//! (times the source's distance attenuation when `spatial`) and a low-pass
+//! cutoff that darkens with occlusion.
+//!
+//! The engine runs this after its physics step when `ctx.physics` is
+//! enabled; games that own a `PhysicsSystem` call
+//! [`update_audio_occlusion`] themselves.
+
+use audio::AudioManager;
+use ecs::audio_components::{AudioListener, AudioOcclusion, AudioSource};
+use ecs::{EntityId, World};
+use glam::Vec2;
+use physics::PhysicsSystem;
+
+use crate::extraction::ExtractTransform;
+
+/// Update every occluded source's `amount` from `physics` and apply the
+/// resulting muffling through `audio`. Does nothing without an active
+/// listener that has a transform.
+pub fn update_audio_occlusion(world: &mut World, physics: &PhysicsSystem, audio: &mut AudioManager, dt: f32) {
+    let Some((listener, listener_volume, ear)) = active_listener(world) else {
+        return;
+    };
+
+    for source_entity in world.active_entities() {
+        let Some(position) = ExtractTransform::of(world, source_entity).map(|transform| transform.position) else {
+            continue;
+        };
+        let Some(source) = world.get::<AudioSource>(source_entity) else {
+            continue;
+        };
+        let (sound_id, attenuation) = (source.sound_id, source.calculate_attenuation(ear.distance(position)));
+        let Some(occlusion) = world.get_mut::<AudioOcclusion>(source_entity) else {
+            continue;
+        };
+        if !occlusion.enabled {
+            continue;
+        }
+
+        let blockers = count_blockers(physics, ear, position, occlusion.layers, [listener, source_entity]);
+        let target = occlusion.target(blockers);
+        occlusion.step(target, dt);
+        let muffle = (occlusion.volume() * attenuation * listener_volume, occlusion.low_pass());
+
+        if let Some(handle) = audio.handle(sound_id) {
+            if audio.muffle(handle) != muffle {
+                audio.set_muffle(handle, muffle.0, muffle.1);
+            }
+        }
+    }
+}
+
+/// The first active listener (lowest entity id, so the choice is stable;
+/// parked entities don't count): its entity, volume and world position.
+fn active_listener(world: &World) -> Option<(EntityId, f32, Vec2)> {
+    let mut entities = world.active_entities();
+    entities.sort_by_key(|entity| entity.value());
+    entities.into_iter().find_map(|entity| {
+        let listener = world.get::<AudioListener>(entity).filter(|listener| listener.active)?;
+        let transform = ExtractTransform::of(world, entity)?;
+        Some((entity, listener.volume, transform.position))
+    })
+}
+
+/// Solid colliders on `layers` between `from` and `to`, not counting the
+/// listener's or source's own colliders.
+fn count_blockers(physics: &PhysicsSystem, from: Vec2, to: Vec2, layers: u32, ignore: [EntityId; 2]) -> usize {
+    let offset = to - from;
+    physics
+        .raycast_all(from, offset, offset.length(), layers)
+        .iter()
+        .filter(|(entity, _)| !ignore.contains(entity))
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game_physics::GamePhysics;
+    use physics::{Collider, PhysicsConfig, RigidBody};
+
+    fn spawn_wall(world: &mut World, position: Vec2, collider: Collider) {
+        let wall = world.create_entity();
+        world.add_component(&wall, common::Transform2D::new(position)).unwrap();
+        world.add_component(&wall, RigidBody::new_static()).unwrap();
+        world.add_component(&wall, collider).unwrap();
+    }
+
+    #[test]
+    fn test_walls_between_listener_and_source_occlude() {
+        let mut world = World::new();
+        world.spawn((common::Transform2D::new(Vec2::ZERO), AudioListener::new()));
+        let behind_wall = world
+            .spawn((common::Transform2D::new(Vec2::new(300.0, 0.0)), AudioSource::new(1), AudioOcclusion::new()))
+            .id();
+        let behind_glass = world
+            .spawn((
+                common::Transform2D::new(Vec2::new(0.0, 300.0)),
+                AudioSource::new(2),
+                AudioOcclusion::new().with_layers(0b01),
+            ))
+            .id();
+        spawn_wall(&mut world, Vec2::new(150.0, 0.0), Collider::box_collider(20.0, 100.0));
+        let glass = Collider::box_collider(100.0, 20.0).with_collision_groups(0b10, u32::MAX);
+        spawn_wall(&mut world, Vec2::new(0.0, 150.0), glass);
+
+        let mut physics = GamePhysics::new();
+        physics.enable(PhysicsConfig::top_down());
+        physics.step(&mut world, 1.0 / 60.0);
+        let system = physics.system().unwrap();
+        let mut audio = AudioManager::disabled();
+        update_audio_occlusion(&mut world, system, &mut audio, 0.1);
+        update_audio_occlusion(&mut world, system, &mut audio, 0.1);
+
+        let wall_occlusion = world.get::<AudioOcclusion>(behind_wall).unwrap();
+        assert!((wall_occlusion.amount - 0.5).abs() < 1e-5, "one wall, eased in");
+        assert!(wall_occlusion.low_pass().is_some());
+        let glass_occlusion = world.get::<AudioOcclusion>(behind_glass).unwrap();
+        assert_eq!(glass_occlusion.amount, 0.0, "glass isn't on the blocking layers");
+    }
+}
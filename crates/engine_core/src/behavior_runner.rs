@@ -25,6 +25,8 @@ pub struct EntityCollected {
     pub score_value: u32,
     /// The collector entity's tag
     pub collector_tag: String,
+    /// Simulation tick (`World::simulation_tick`) of the collection
+    pub tick: u64,
 }
 
 /// Commands collected while iterating behaviors, applied after the loop to
@@ -125,8 +127,8 @@ impl BehaviorRunner {
         // (avoids borrow conflicts with the world).
         let mut commands = BehaviorCommands::default();
 
-        // Process all entities with behaviors directly - avoid cloning
-        for entity in world.entities() {
+        // Process all active entities with behaviors directly - avoid cloning
+        for entity in world.active_entities() {
             // Get behavior component by reference to avoid cloning
             let Some(behavior) = world.get::<Behavior>(entity) else { continue };
 
@@ -475,6 +477,7 @@ impl BehaviorRunner {
                 entity,
                 score_value,
                 collector_tag: collector_tag.to_string(),
+                tick: world.simulation_tick(),
             });
             if despawn_on_collect {
                 commands.to_despawn.push(entity);
@@ -559,7 +562,7 @@ impl BehaviorRunner {
     fn find_nearest_tagged_position(world: &World, exclude: EntityId, tag: &str) -> Option<Vec2> {
         let exclude_pos = Self::get_position(world, exclude)?;
 
-        world.entities().into_iter()
+        world.active_entities().into_iter()
             .filter(|e| *e != exclude)
             .filter(|e| world.get::<EntityTag>(*e).map(|t| t.matches(tag)).unwrap_or(false))
             .filter_map(|e| Self::get_position(world, e))
@@ -574,7 +577,7 @@ impl BehaviorRunner {
     fn check_tagged_overlap(world: &World, entity: EntityId, tag: &str, radius: f32) -> bool {
         let Some(entity_pos) = Self::get_position(world, entity) else { return false };
 
-        world.entities().into_iter()
+        world.active_entities().into_iter()
             .filter(|e| *e != entity)
             .filter(|e| world.get::<EntityTag>(*e).map(|t| t.matches(tag)).unwrap_or(false))
             .filter_map(|e| Self::get_position(world, e))
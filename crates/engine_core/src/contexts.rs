@@ -8,21 +8,30 @@ use ecs::World;
 use input::{InputHandler, InputSettings};
 use audio::AudioManager;
 use ui::UIContext;
-use renderer::{line_pipeline::LineVertex, sprite::SpriteBatcher, Camera, texture::TextureHandle};
+use renderer::{debug_draw::DebugDraw, line_pipeline::LineVertex, sprite::SpriteBatcher, Camera, texture::TextureHandle, RenderStats};
 use std::collections::HashMap;
 use crate::assets::AssetManager;
 use crate::chaos_mode::ChaosMode;
+use crate::extraction::SpriteExtractors;
+use crate::game_states::GameStates;
 use crate::achievements::AchievementManager;
 use crate::particles::ParticleManager;
+use crate::plugin::Plugins;
+use crate::profiling::FrameProfile;
+use crate::texture_import::TextureSizes;
+use crate::timing::Time;
+use crate::validation::DebugValidator;
 
-/// Key for caching glyph textures.
+/// Key for caching glyph atlas regions.
 ///
-/// Note: Color is NOT included in the cache key because glyph textures are
+/// Note: Color is NOT included in the cache key because glyph bitmaps are
 /// grayscale alpha masks. The color is applied at render time by multiplying
-/// the sprite color with the texture, allowing the same glyph texture to be
+/// the sprite color with the texture, allowing the same atlas region to be
 /// reused for any color.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct GlyphCacheKey {
+    /// Font the glyph was rasterized from
+    font_id: u32,
     /// Character being rendered
     character: char,
     /// Width of the glyph bitmap
@@ -32,8 +41,9 @@ pub struct GlyphCacheKey {
 }
 
 impl GlyphCacheKey {
-    pub(crate) fn new(character: char, width: u32, height: u32) -> Self {
+    pub(crate) fn new(font_id: u32, character: char, width: u32, height: u32) -> Self {
         Self {
+            font_id,
             character,
             width,
             height,
@@ -41,6 +51,40 @@ impl GlyphCacheKey {
     }
 }
 
+/// Where a cached glyph lives in the UI glyph atlas.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GlyphRegion {
+    /// Atlas page texture holding the glyph
+    pub texture: TextureHandle,
+    /// Glyph rectangle on the page as `[u, v, width, height]` in UV space
+    /// (the sprite `tex_region` layout)
+    pub uv: [f32; 4],
+}
+
+/// Regions of the UI atlas that draw commands sample: cached glyphs, plus
+/// a solid white point on the first page that rects, borders, circles and
+/// lines use so they batch with the text and icons on that page.
+#[derive(Debug, Clone, Default)]
+pub struct UiAtlasRegions {
+    /// Cached glyphs
+    pub glyphs: HashMap<GlyphCacheKey, GlyphRegion>,
+    /// White region for untextured shapes; `None` until the atlas has a
+    /// page (shapes then sample [`TextureHandle::WHITE`])
+    pub white: Option<GlyphRegion>,
+}
+
+impl UiAtlasRegions {
+    /// The atlas region of a glyph.
+    pub fn get(&self, key: &GlyphCacheKey) -> Option<&GlyphRegion> {
+        self.glyphs.get(key)
+    }
+
+    /// Where untextured UI shapes sample white.
+    pub fn white_region(&self) -> GlyphRegion {
+        self.white.unwrap_or(GlyphRegion { texture: TextureHandle::WHITE, uv: [0.0, 0.0, 1.0, 1.0] })
+    }
+}
+
 /// Context passed to game methods, providing access to engine systems.
 pub struct GameContext<'a> {
     /// Input handler for keyboard, mouse, and gamepad
@@ -59,8 +103,19 @@ pub struct GameContext<'a> {
     pub audio: &'a mut AudioManager,
     /// UI context for immediate-mode UI
     pub ui: &'a mut UIContext,
-    /// Delta time since last frame in seconds
+    /// Delta time since last frame in seconds, scaled by the time scale
+    /// (0.0 while `ctx.time` is paused). Step physics and gameplay with it;
+    /// UI animation uses `ctx.time.unscaled_delta()`.
     pub delta_time: f32,
+    /// Fixed-update clock: `ctx.time.tick()` is a monotonically increasing
+    /// tick counter (one per fixed update, `GameConfig::fixed_timestep`),
+    /// also stamped on collision/gameplay events and recorded in saves.
+    /// The engine advances it before each `update()`. Speed controls are
+    /// read-write: `ctx.time.set_scale(0.5)` for slow motion, `pause()` /
+    /// `resume()` to freeze the simulation while rendering and UI keep
+    /// running. Changes persist to the world's `TimeScale` resource and
+    /// apply from the next frame (the fixed tick and particles included).
+    pub time: Time,
     /// Current window size
     pub window_size: Vec2,
     /// Project-wide gameplay intensity theme. Seeded from `GameConfig` and
@@ -68,12 +123,6 @@ pub struct GameContext<'a> {
     /// and the engine persists the change, so `ctx.chaos_mode` is always the
     /// current selection on later frames (no stale startup value).
     pub chaos_mode: ChaosMode,
-    /// Engine time multiplier, **read-write** like `chaos_mode` (the engine
-    /// persists writes across frames). Currently scales engine-side particle
-    /// stepping only — set it to `0.0` while paused so bursts freeze with the
-    /// game (`PauseMenu::time_scale()` provides the right value each frame).
-    /// It does NOT scale `ctx.delta_time`; games gate their own logic.
-    pub time_scale: f32,
     /// Set to `true` to quit the game (title-screen Exit items, the pause
     /// menu's Exit Game). The engine performs the same clean shutdown as
     /// closing the window: `Game::on_exit`, input-settings save, scene
@@ -92,6 +141,52 @@ pub struct GameContext<'a> {
     /// Typical use: step a [`GridMesh`](crate::grid::GridMesh) and append
     /// its `build_line_vertices()` output here, or push debug-draw segments.
     pub lines: &'a mut Vec<LineVertex>,
+    /// World-space debug shapes (`line`, `circle`, `arc`, `polyline`,
+    /// `polygon`, `aabb`) drawn over everything without depth testing.
+    /// Cleared each frame before `update()`. On in debug builds
+    /// (`GameConfig::with_debug_draw` overrides); `set_enabled` toggles it at
+    /// runtime, and calls are no-ops while off.
+    pub debug_draw: &'a mut DebugDraw,
+    /// Render statistics from the previous frame: sprite/batch counts (game
+    /// + UI batchers), sprites culled and dropped by the hard limit, whether
+    /// the soft limit (`GameConfig::with_sprite_limits`) was exceeded, and
+    /// the GPU draw calls, texture binds and texture memory.
+    pub render_stats: RenderStats,
+    /// CPU timings of the previous frame's update passes: `Game::update`,
+    /// each plugin system, the engine physics step and particles.
+    pub profile: &'a FrameProfile,
+    /// Debug validation layer (`GameConfig::debug_validation`). The engine
+    /// checks transforms, sprite textures, and colliders after `update()`;
+    /// games add panic-free asserts with `ctx.validation.check(cond, msg)`
+    /// and pass their physics world to `check_physics`. Current problems are
+    /// in `ctx.validation.issues()`; new ones are logged as warnings.
+    pub validation: &'a mut DebugValidator,
+    /// Sprite extraction registry run before every `render()`. Register
+    /// custom renderable components in `init()` with
+    /// `ctx.extractors.register_component::<C>(name, f)`; opt out of a
+    /// built-in with `ctx.extractors.set_enabled(SPRITE_EXTRACTOR, false)`.
+    pub extractors: &'a mut SpriteExtractors,
+    /// Plugins added through [`EngineBuilder`](crate::plugin::EngineBuilder):
+    /// their names and the typed extension data they registered
+    /// (`ctx.plugins.extension::<T>()`). Their systems run after `update()`.
+    pub plugins: &'a mut Plugins,
+    /// Game-flow state stack (main menu, loading, playing, paused):
+    /// register states with hooks and guards in `init()`, then
+    /// `ctx.states.push("paused")` / `pop()` / `switch(..)` / `reset(..)`.
+    /// Transitions apply after `init()` and after each `update()`.
+    pub states: &'a mut GameStates,
+    /// Hierarchy propagation the engine runs after `update()` to keep
+    /// `GlobalTransform2D` current. It caches last frame's transforms; call
+    /// `ctx.transforms.reset()` after replacing the world wholesale
+    /// (restoring a `WorldSnapshot`, swapping scenes) so nothing stale is
+    /// reused.
+    pub transforms: &'a mut ecs::TransformHierarchySystem,
+    /// Engine-owned physics: opt in with `ctx.physics.enable(config)` in
+    /// `init()`, then `raycast`, `overlap_circle`/`overlap_point`,
+    /// `apply_impulse`/`set_velocity` and last step's `collisions()` without
+    /// holding a `PhysicsSystem`. The engine steps it after `update()`.
+    #[cfg(feature = "physics")]
+    pub physics: &'a mut crate::game_physics::GamePhysics,
 }
 
 /// Render context passed to the render method.
@@ -106,6 +201,11 @@ pub struct RenderContext<'a> {
     pub window_size: Vec2,
     /// UI draw commands to render
     pub ui_commands: &'a [ui::DrawCommand],
-    /// Cached glyph textures for text rendering
-    pub glyph_textures: &'a HashMap<GlyphCacheKey, TextureHandle>,
+    /// UI atlas regions (glyphs and the white point) for text and UI shapes
+    pub glyph_textures: &'a UiAtlasRegions,
+    /// Natural sprite sizes of loaded textures (pixels-per-unit import)
+    pub texture_sizes: &'a TextureSizes,
+    /// Render statistics from the previous frame (see
+    /// [`GameContext::render_stats`])
+    pub render_stats: RenderStats,
 }
\ No newline at end of file
@@ -0,0 +1,595 @@
+//! Sprite extraction registry: how renderable components become sprites.
+//!
+//! Every frame, before `Game::render`, the engine runs each enabled
+//! extractor against the world and appends its sprites to the game batcher.
+//! The built-ins cover tilemaps, ECS `Sprite`s, and particles. Games add
+//! their own renderable components with
+//! [`SpriteExtractors::register_component`] (or a whole-world
+//! [`SpriteExtractors::register`]) and opt out of a built-in with
+//! [`SpriteExtractors::set_enabled`] — so `render()` rarely needs
+//! overriding. Extracted sprites go through the same camera culling and
+//! depth ordering as anything `render()` adds; the built-in sprite
+//! extractor also skips sprites outside [`ExtractContext::view`] before
+//! building them at all (turn that off with
+//! [`SpriteExtractors::set_culling`] when `render()` moves the camera).
+//! Entities in the
+//! [`SpriteExtractors::set_hidden`] set (the editor's per-entity eye toggle)
+//! are skipped by the built-ins and by component extractors.
+//!
+//! ```
+//! use engine_core::extraction::{SpriteExtractors, SPRITE_EXTRACTOR};
+//! use glam::Vec2;
+//!
+//! /// A game-specific renderable: a flat-colored square.
+//! struct Marker { size: f32 }
+//!
+//! let mut extractors = SpriteExtractors::new();
+//! extractors.register_component::<Marker>("marker", |marker, transform, _ctx, sprites| {
+//!     let sprite = renderer::Sprite::new(renderer::TextureHandle::WHITE)
+//!         .with_position(transform.position)
+//!         .with_scale(Vec2::splat(marker.size / engine_core::RENDER_UNIT));
+//!     sprites.add_sprite(&sprite);
+//! });
+//! // Draw ECS sprites some other way (e.g. in a custom render())
+//! assert!(extractors.set_enabled(SPRITE_EXTRACTOR, false));
+//! assert!(extractors.is_enabled("marker"));
+//! ```
+
+use std::collections::HashSet;
+
+use ecs::hierarchy::GlobalTransform2D;
+use ecs::sprite_components::{effective_sprite_mask, MaskShape, Sprite as EcsSprite, SpriteAnimation, Transform2D};
+use ecs::parallax::{effective_parallax, ParallaxLayer};
+use ecs::{Component, EntityId, RenderLayers, SortBias, SpriteSortMode, World};
+use glam::Vec2;
+use renderer::sprite::{CameraView, SpriteBatcher, SpriteMask};
+use renderer::Camera;
+use renderer::texture::TextureHandle;
+
+use crate::particles::ParticleManager;
+use crate::render_manager::main_camera_position;
+use crate::texture_import::TextureSizes;
+
+/// Name of the built-in extractor for `Tilemap` entities.
+pub const TILEMAP_EXTRACTOR: &str = "tilemap";
+/// Name of the built-in extractor for ECS `Sprite` entities.
+pub const SPRITE_EXTRACTOR: &str = "sprite";
+/// Name of the built-in extractor for live particles.
+pub const PARTICLE_EXTRACTOR: &str = "particles";
+
+/// Read-only engine state an extractor draws from.
+pub struct ExtractContext<'a> {
+    /// The ECS world
+    pub world: &'a World,
+    /// Natural sprite sizes of loaded textures (pixels-per-unit import)
+    pub texture_sizes: &'a TextureSizes,
+    /// Live particles
+    pub particles: &'a ParticleManager,
+    /// Entities not to draw (see [`SpriteExtractors::set_hidden`])
+    pub hidden: &'a HashSet<EntityId>,
+    /// The render camera as `update()` left it (main camera already synced)
+    pub camera: &'a Camera,
+    /// What the camera sees as `update()` left it; `None` when culling is
+    /// off. Extractors may skip sprites outside it, reporting them with
+    /// `SpriteBatcher::record_culled`.
+    pub view: Option<CameraView>,
+}
+
+impl ExtractContext<'_> {
+    /// Whether `entity` is in the hidden set or parked with
+    /// [`ecs::Inactive`]. Whole-world extractors should skip such entities.
+    pub fn is_hidden(&self, entity: EntityId) -> bool {
+        self.hidden.contains(&entity) || !self.world.is_active(entity)
+    }
+}
+
+/// World-space placement of an entity being extracted: its
+/// `GlobalTransform2D` when it is part of a hierarchy, else its local
+/// `Transform2D`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ExtractTransform {
+    /// World-space position
+    pub position: Vec2,
+    /// World-space rotation in radians
+    pub rotation: f32,
+    /// World-space scale
+    pub scale: Vec2,
+}
+
+impl ExtractTransform {
+    /// The entity's world transform, or `None` if it has no transform.
+    pub fn of(world: &World, entity: EntityId) -> Option<Self> {
+        if let Some(global) = world.get::<GlobalTransform2D>(entity) {
+            return Some(Self { position: global.position, rotation: global.rotation, scale: global.scale });
+        }
+        world.get::<Transform2D>(entity)
+            .map(|local| Self { position: local.position, rotation: local.rotation, scale: local.scale })
+    }
+}
+
+/// The world-space mask clipping `entity`'s sprites: the `SpriteMask` on the
+/// entity or its nearest masked ancestor, placed by that entity's transform.
+/// Custom extractors call this to honor masks too.
+pub fn world_sprite_mask(world: &World, entity: EntityId) -> Option<SpriteMask> {
+    let (owner, mask) = effective_sprite_mask(world, entity)?;
+    let transform = ExtractTransform::of(world, owner)?;
+    let center = transform.position + Vec2::from_angle(transform.rotation).rotate(mask.offset * transform.scale);
+    let size = mask.size * transform.scale;
+    let world_mask = match mask.shape {
+        MaskShape::Rect => SpriteMask::rect(center, size),
+        MaskShape::Ellipse => SpriteMask::ellipse(center, size),
+        MaskShape::Texture { texture_handle, tex_region } => {
+            SpriteMask::texture(TextureHandle { id: texture_handle }, tex_region, center, size)
+        }
+    }
+    .with_rotation(transform.rotation);
+    Some(if mask.invert { world_mask.inverted() } else { world_mask })
+}
+
+/// An extraction function: appends sprites for one kind of renderable.
+type ExtractFn = Box<dyn Fn(&ExtractContext, &mut SpriteBatcher)>;
+
+/// A registered extractor.
+struct Extractor {
+    name: String,
+    enabled: bool,
+    extract: ExtractFn,
+}
+
+/// Ordered set of named sprite extractors, run by the engine every frame
+/// (exposed as `ctx.extractors`). Extractors run in registration order.
+pub struct SpriteExtractors {
+    extractors: Vec<Extractor>,
+    hidden: HashSet<EntityId>,
+    culling: bool,
+}
+
+impl Default for SpriteExtractors {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SpriteExtractors {
+    /// The built-in extractors: tilemaps, then ECS sprites (so equal-depth
+    /// sprites draw over tiles), then particles.
+    pub fn new() -> Self {
+        let mut extractors = Self { extractors: Vec::new(), hidden: HashSet::new(), culling: true };
+        extractors.register(TILEMAP_EXTRACTOR, |ctx, sprites| {
+            crate::tilemap_render::append_tilemap_sprites(ctx.world, ctx.hidden, sprites);
+        });
+        extractors.register(SPRITE_EXTRACTOR, extract_ecs_sprites);
+        extractors.register(PARTICLE_EXTRACTOR, extract_particles);
+        extractors
+    }
+
+    /// Register a whole-world extractor under `name`. Registering an
+    /// existing name replaces that extractor in place (keeping its order)
+    /// and re-enables it.
+    pub fn register(
+        &mut self,
+        name: impl Into<String>,
+        extract: impl Fn(&ExtractContext, &mut SpriteBatcher) + 'static,
+    ) {
+        let name = name.into();
+        let extract: ExtractFn = Box::new(extract);
+        match self.extractors.iter_mut().find(|e| e.name == name) {
+            Some(existing) => {
+                existing.extract = extract;
+                existing.enabled = true;
+            }
+            None => self.extractors.push(Extractor { name, enabled: true, extract }),
+        }
+    }
+
+    /// Register an extractor for a renderable component: `extract` runs for
+    /// every entity that has a `C` and a transform.
+    pub fn register_component<C: Component>(
+        &mut self,
+        name: impl Into<String>,
+        extract: impl Fn(&C, ExtractTransform, &ExtractContext, &mut SpriteBatcher) + 'static,
+    ) {
+        self.register(name, move |ctx, sprites| {
+            for entity in ctx.world.entities() {
+                if ctx.is_hidden(entity) {
+                    continue;
+                }
+                let Some(component) = ctx.world.get::<C>(entity) else { continue };
+                let Some(transform) = ExtractTransform::of(ctx.world, entity) else { continue };
+                extract(component, transform, ctx, sprites);
+            }
+        });
+    }
+
+    /// Turn an extractor on or off (e.g. opt out of the built-in
+    /// [`SPRITE_EXTRACTOR`] to draw sprites yourself). Returns `false` if no
+    /// extractor has that name.
+    pub fn set_enabled(&mut self, name: &str, enabled: bool) -> bool {
+        match self.extractors.iter_mut().find(|e| e.name == name) {
+            Some(extractor) => {
+                extractor.enabled = enabled;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Whether an extractor with this name is registered and enabled.
+    pub fn is_enabled(&self, name: &str) -> bool {
+        self.extractors.iter().any(|e| e.name == name && e.enabled)
+    }
+
+    /// Remove an extractor. Returns `false` if no extractor has that name.
+    pub fn remove(&mut self, name: &str) -> bool {
+        let before = self.extractors.len();
+        self.extractors.retain(|e| e.name != name);
+        self.extractors.len() != before
+    }
+
+    /// Registered extractor names in run order.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.extractors.iter().map(|e| e.name.as_str())
+    }
+
+    /// Replace the set of entities no extractor draws (the editor hides
+    /// entities in the scene view this way without touching their
+    /// components). The engine passes it to extractors as
+    /// [`ExtractContext::hidden`].
+    pub fn set_hidden(&mut self, entities: impl IntoIterator<Item = EntityId>) {
+        self.hidden.clear();
+        self.hidden.extend(entities);
+    }
+
+    /// Entities currently skipped by extraction.
+    pub fn hidden(&self) -> &HashSet<EntityId> {
+        &self.hidden
+    }
+
+    /// Turn extraction-time camera culling on or off (on by default). The
+    /// engine passes the camera view to extractors only while it is on;
+    /// games that move the camera inside `render()` turn it off, leaving
+    /// the batcher's cull against the final camera.
+    pub fn set_culling(&mut self, culling: bool) {
+        self.culling = culling;
+    }
+
+    /// Whether extraction-time camera culling is on.
+    pub fn is_culling(&self) -> bool {
+        self.culling
+    }
+
+    /// Run every enabled extractor, in order.
+    pub fn extract(&self, ctx: &ExtractContext, sprites: &mut SpriteBatcher) {
+        for extractor in self.extractors.iter().filter(|e| e.enabled) {
+            (extractor.extract)(ctx, sprites);
+        }
+    }
+}
+
+/// The built-in ECS sprite extractor: one sprite per visible `Sprite` with
+/// a transform, sized from the texture's pixels-per-unit import settings.
+/// A `SpriteAnimation` on the entity supplies the texture region (its
+/// current frame) in place of `Sprite::tex_region`. The sprite's render
+/// layer (the world's [`RenderLayers`], or the built-ins) offsets its depth;
+/// in [`SpriteSortMode::Isometric`] its height orders it within the layer.
+/// Sprites under a [`ParallaxLayer`] are scrolled (and tiled) relative to
+/// the main camera. Sprites outside [`ExtractContext::view`] are counted as
+/// culled and skipped.
+fn extract_ecs_sprites(ctx: &ExtractContext, sprites: &mut SpriteBatcher) {
+    let layers = ctx.world.resource::<RenderLayers>().cloned().unwrap_or_default();
+    let sort_mode = ctx.world.resource::<SpriteSortMode>().copied().unwrap_or_default();
+    // Parallax scrolls relative to the main camera entity (stable in the
+    // editor, where the render camera is the viewport's)
+    let parallax_anchor = main_camera_position(ctx.world).unwrap_or(ctx.camera.position);
+    for entity in ctx.world.entities() {
+        let Some(ecs_sprite) = ctx.world.get::<EcsSprite>(entity) else { continue };
+        if !ecs_sprite.visible || ctx.is_hidden(entity) {
+            continue;
+        }
+        let Some(transform) = ExtractTransform::of(ctx.world, entity) else { continue };
+
+        let texture = TextureHandle { id: ecs_sprite.texture_handle };
+        let [u, v, w, h] = ctx
+            .world
+            .get::<SpriteAnimation>(entity)
+            .map_or(ecs_sprite.tex_region, SpriteAnimation::current_frame_region);
+        let natural_size = ctx.texture_sizes.sprite_size(ecs_sprite.texture_handle, [u, v, w, h]);
+        let scale = transform.scale * ecs_sprite.scale * natural_size;
+        let bias = ctx.world.get::<SortBias>(entity);
+        let depth = sort_mode.sort_depth(&layers, &ecs_sprite.layer, ecs_sprite.depth, transform.position, bias);
+        let mut sprite = renderer::Sprite::new(texture)
+            .with_tex_region(u, v, w, h)
+            .with_rotation(transform.rotation)
+            .with_scale(scale)
+            .with_color(ecs_sprite.color)
+            .with_depth(depth)
+            .with_emissive(ecs_sprite.emissive);
+        let mut mask = None;
+        for position in sprite_positions(ctx, entity, transform.position, scale, parallax_anchor) {
+            if ctx.view.is_some_and(|view| !view.may_see(position, scale)) {
+                sprites.record_culled(1);
+                continue;
+            }
+            sprite.position = position;
+            sprite.mask = *mask.get_or_insert_with(|| world_sprite_mask(ctx.world, entity));
+            sprites.add_sprite(&sprite);
+        }
+    }
+}
+
+/// Where to draw `entity`'s sprite: its world position, or — under a
+/// [`ParallaxLayer`] — the scrolled position, repeated across the view on
+/// tiled axes.
+fn sprite_positions(ctx: &ExtractContext, entity: EntityId, position: Vec2, scale: Vec2, anchor: Vec2) -> Vec<Vec2> {
+    let Some(layer) = effective_parallax(ctx.world, entity) else {
+        return vec![position];
+    };
+    let rendered = layer.rendered_position(position, anchor);
+    match CameraView::of(ctx.camera) {
+        Some(view) if layer.tile_x || layer.tile_y => {
+            layer.tile_positions(rendered, scale, view.center, view.half_extents)
+        }
+        _ => vec![rendered],
+    }
+}
+
+/// The built-in particle extractor: one sprite per live particle.
+fn extract_particles(ctx: &ExtractContext, sprites: &mut SpriteBatcher) {
+    for p in ctx.particles.iter_alive() {
+        let color = ParticleManager::current_color(p);
+        let scale = ParticleManager::current_scale(p);
+        let sprite = renderer::Sprite::new(TextureHandle { id: p.texture })
+            .with_position(p.position)
+            .with_rotation(p.rotation)
+            .with_scale(Vec2::splat(scale))
+            .with_color(color)
+            .with_emissive(p.emissive)
+            // Just behind UI (positive depth) so particles glow on top of gameplay.
+            .with_depth(0.5);
+        sprites.add_sprite(&sprite);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Marker;
+
+    static NOTHING_HIDDEN: std::sync::LazyLock<HashSet<EntityId>> = std::sync::LazyLock::new(HashSet::new);
+    static ORIGIN_CAMERA: std::sync::LazyLock<Camera> =
+        std::sync::LazyLock::new(|| Camera::new(Vec2::ZERO, Vec2::new(800.0, 600.0)));
+
+    fn extract_ctx<'a>(world: &'a World, sizes: &'a TextureSizes, particles: &'a ParticleManager) -> ExtractContext<'a> {
+        ExtractContext { world, texture_sizes: sizes, particles, hidden: &NOTHING_HIDDEN, camera: &ORIGIN_CAMERA, view: None }
+    }
+
+    fn sprite_count(sprites: &SpriteBatcher) -> usize {
+        sprites.batches().values().map(|batch| batch.instances.len()).sum()
+    }
+
+    fn world_with_sprite_and_marker() -> World {
+        let mut world = World::new();
+        let sprite = world.create_entity();
+        world.add_component(&sprite, Transform2D::new(Vec2::ZERO)).unwrap();
+        world.add_component(&sprite, EcsSprite::new(0)).unwrap();
+        let marker = world.create_entity();
+        world.add_component(&marker, Transform2D::new(Vec2::new(5.0, 0.0))).unwrap();
+        world.add_component(&marker, Marker).unwrap();
+        world
+    }
+
+    #[test]
+    fn builtins_run_in_order() {
+        let extractors = SpriteExtractors::new();
+        let names: Vec<&str> = extractors.names().collect();
+        assert_eq!(names, [TILEMAP_EXTRACTOR, SPRITE_EXTRACTOR, PARTICLE_EXTRACTOR]);
+    }
+
+    #[test]
+    fn custom_component_extractor_runs_alongside_sprites() {
+        let world = world_with_sprite_and_marker();
+        let (sizes, particles) = (TextureSizes::new(), ParticleManager::default());
+        let mut extractors = SpriteExtractors::new();
+        extractors.register_component::<Marker>("marker", |_, transform, _, sprites| {
+            sprites.add_sprite(&renderer::Sprite::new(TextureHandle { id: 3 }).with_position(transform.position));
+        });
+
+        let mut sprites = SpriteBatcher::new();
+        extractors.extract(&extract_ctx(&world, &sizes, &particles), &mut sprites);
+        assert_eq!(sprite_count(&sprites), 2);
+        assert_eq!(sprites.batch(TextureHandle { id: 3 }).unwrap().instances[0].position, [5.0, 0.0]);
+    }
+
+    #[test]
+    fn sprites_use_their_region_or_current_animation_frame() {
+        let mut world = world_with_sprite_and_marker();
+        let (sizes, particles) = (TextureSizes::new(), ParticleManager::default());
+        let extractors = SpriteExtractors::new();
+        let region = [0.5, 0.0, 0.5, 1.0];
+        let sheet = world.create_entity();
+        world.add_component(&sheet, Transform2D::new(Vec2::ZERO)).unwrap();
+        world.add_component(&sheet, EcsSprite::new(7).with_tex_region(0.5, 0.0, 0.5, 1.0)).unwrap();
+
+        let mut sprites = SpriteBatcher::new();
+        extractors.extract(&extract_ctx(&world, &sizes, &particles), &mut sprites);
+        assert_eq!(sprites.batch(TextureHandle { id: 7 }).unwrap().instances[0].tex_region, region);
+
+        let mut animation = SpriteAnimation::new(8.0, vec![[0.0, 0.0, 0.25, 1.0], [0.25, 0.0, 0.25, 1.0]]);
+        animation.current_frame = 1;
+        world.add_component(&sheet, animation).unwrap();
+        let mut sprites = SpriteBatcher::new();
+        extractors.extract(&extract_ctx(&world, &sizes, &particles), &mut sprites);
+        assert_eq!(sprites.batch(TextureHandle { id: 7 }).unwrap().instances[0].tex_region, [0.25, 0.0, 0.25, 1.0]);
+    }
+
+    #[test]
+    fn sprite_layers_offset_depth_in_the_world_layer_order() {
+        let mut world = World::new();
+        let (sizes, particles) = (TextureSizes::new(), ParticleManager::default());
+        let sky = world.create_entity();
+        world.add_component(&sky, Transform2D::new(Vec2::ZERO)).unwrap();
+        world.add_component(&sky, EcsSprite::new(1).with_layer("Background").with_depth(5.0)).unwrap();
+        let depth_of = |world: &World| {
+            let mut sprites = SpriteBatcher::new();
+            SpriteExtractors::new().extract(&extract_ctx(world, &sizes, &particles), &mut sprites);
+            sprites.batch(TextureHandle { id: 1 }).unwrap().instances[0].depth
+        };
+        assert_eq!(depth_of(&world), 5.0 - ecs::LAYER_DEPTH_SPAN, "built-in layers without a resource");
+
+        let mut layers = RenderLayers::new();
+        layers.move_layer("Background", 3);
+        world.insert_resource(layers);
+        assert_eq!(depth_of(&world), 5.0 + 3.0 * ecs::LAYER_DEPTH_SPAN, "Background moved to the front");
+    }
+
+    #[test]
+    fn isometric_mode_draws_lower_sprites_in_front() {
+        let mut world = World::new();
+        let (sizes, particles) = (TextureSizes::new(), ParticleManager::default());
+        world.spawn((Transform2D::new(Vec2::new(0.0, -20.0)), EcsSprite::new(1).with_depth(1.0)));
+        let tree = world.spawn((Transform2D::new(Vec2::new(0.0, 40.0)), EcsSprite::new(2))).id();
+        let depths = |world: &World| {
+            let mut sprites = SpriteBatcher::new();
+            SpriteExtractors::new().extract(&extract_ctx(world, &sizes, &particles), &mut sprites);
+            let depth = |id| sprites.batch(TextureHandle { id }).unwrap().instances[0].depth;
+            (depth(1), depth(2))
+        };
+        let (player_depth, tree_depth) = depths(&world);
+        assert!(player_depth > tree_depth, "depth mode: the player's depth wins");
+
+        world.insert_resource(SpriteSortMode::isometric(0.0));
+        let (player_depth, tree_depth) = depths(&world);
+        assert!(player_depth > tree_depth, "the player stands lower");
+
+        // Rooted below the player, the tree now covers them
+        world.add_component(&tree, SortBias::new(-80.0)).unwrap();
+        let (player_depth, tree_depth) = depths(&world);
+        assert!(tree_depth > player_depth);
+    }
+
+    #[test]
+    fn sprites_inherit_the_nearest_ancestor_mask() {
+        use ecs::sprite_components::SpriteMask as EcsSpriteMask;
+        use ecs::WorldHierarchyExt;
+
+        let mut world = World::new();
+        let (sizes, particles) = (TextureSizes::new(), ParticleManager::default());
+        let minimap = world.create_entity();
+        world.add_component(&minimap, Transform2D::new(Vec2::new(100.0, 50.0)).with_scale(Vec2::splat(2.0))).unwrap();
+        world.add_component(&minimap, EcsSpriteMask::ellipse(Vec2::splat(32.0)).with_offset(Vec2::new(4.0, 0.0))).unwrap();
+        let icon = world.create_entity();
+        world.add_component(&icon, Transform2D::new(Vec2::ZERO)).unwrap();
+        world.add_component(&icon, EcsSprite::new(5)).unwrap();
+        world.set_parent(icon, minimap).unwrap();
+
+        let mask = world_sprite_mask(&world, icon).unwrap();
+        assert_eq!(mask, SpriteMask::ellipse(Vec2::new(108.0, 50.0), Vec2::splat(64.0)));
+
+        let mut sprites = SpriteBatcher::new();
+        SpriteExtractors::new().extract(&extract_ctx(&world, &sizes, &particles), &mut sprites);
+        let instance = sprites.batch(TextureHandle { id: 5 }).unwrap().instances[0];
+        assert_eq!(instance.mask_bounds, [108.0, 50.0, 32.0, 32.0]);
+        assert_eq!(instance.mask[0], 2.0, "ellipse");
+    }
+
+    #[test]
+    fn disabled_extractors_are_skipped() {
+        let world = world_with_sprite_and_marker();
+        let (sizes, particles) = (TextureSizes::new(), ParticleManager::default());
+        let mut extractors = SpriteExtractors::new();
+        assert!(extractors.set_enabled(SPRITE_EXTRACTOR, false));
+        assert!(!extractors.set_enabled("unknown", false));
+
+        let mut sprites = SpriteBatcher::new();
+        extractors.extract(&extract_ctx(&world, &sizes, &particles), &mut sprites);
+        assert_eq!(sprite_count(&sprites), 0);
+    }
+
+    #[test]
+    fn hidden_entities_are_not_extracted() {
+        let world = world_with_sprite_and_marker();
+        let (sizes, particles) = (TextureSizes::new(), ParticleManager::default());
+        let mut extractors = SpriteExtractors::new();
+        extractors.register_component::<Marker>("marker", |_, transform, _, sprites| {
+            sprites.add_sprite(&renderer::Sprite::new(TextureHandle { id: 3 }).with_position(transform.position));
+        });
+        extractors.set_hidden(world.entities());
+
+        let ctx = ExtractContext { hidden: extractors.hidden(), ..extract_ctx(&world, &sizes, &particles) };
+        let mut sprites = SpriteBatcher::new();
+        extractors.extract(&ctx, &mut sprites);
+        assert_eq!(sprite_count(&sprites), 0);
+    }
+
+    #[test]
+    fn sprites_outside_the_view_are_culled_at_extraction() {
+        let mut world = world_with_sprite_and_marker();
+        let far = world.create_entity();
+        world.add_component(&far, Transform2D::new(Vec2::new(5000.0, 0.0))).unwrap();
+        world.add_component(&far, EcsSprite::new(0)).unwrap();
+        let (sizes, particles) = (TextureSizes::new(), ParticleManager::default());
+        let view = CameraView::of(&common::Camera::new(Vec2::ZERO, Vec2::new(800.0, 600.0)));
+
+        let mut sprites = SpriteBatcher::new();
+        let ctx = ExtractContext { view, ..extract_ctx(&world, &sizes, &particles) };
+        extract_ecs_sprites(&ctx, &mut sprites);
+        assert_eq!(sprite_count(&sprites), 1);
+        assert_eq!(sprites.stats().sprites_culled, 1);
+    }
+
+    #[test]
+    fn parallax_sprites_scroll_with_the_main_camera_and_tile() {
+        let mut world = World::new();
+        let camera = world.create_entity();
+        world.add_component(&camera, Transform2D::new(Vec2::new(200.0, 0.0))).unwrap();
+        world.add_component(&camera, common::Camera { is_main_camera: true, ..Default::default() }).unwrap();
+        let hills = world.create_entity();
+        world.add_component(&hills, Transform2D::new(Vec2::ZERO)).unwrap();
+        world.add_component(&hills, EcsSprite::new(1)).unwrap();
+        world.add_component(&hills, ParallaxLayer::new(0.5)).unwrap();
+        let clouds = world.create_entity();
+        world.add_component(&clouds, Transform2D::new(Vec2::ZERO)).unwrap();
+        world.add_component(&clouds, EcsSprite::new(2).with_scale(Vec2::splat(1.25))).unwrap();
+        world.add_component(&clouds, ParallaxLayer::new(0.0).tiled_x()).unwrap();
+        let (sizes, particles) = (TextureSizes::new(), ParticleManager::default());
+
+        let mut sprites = SpriteBatcher::new();
+        extract_ecs_sprites(&extract_ctx(&world, &sizes, &particles), &mut sprites);
+        let hills_instance = sprites.batch(TextureHandle { id: 1 }).unwrap().instances[0];
+        assert_eq!(hills_instance.position, [100.0, 0.0]);
+        // An 800-wide view needs several 100-wide copies
+        let clouds_batch = sprites.batch(TextureHandle { id: 2 }).unwrap();
+        assert!(clouds_batch.instances.len() >= 9);
+        assert!(clouds_batch.instances.iter().all(|instance| instance.position[1] == 0.0));
+    }
+
+    #[test]
+    fn re_registering_replaces_in_place_and_remove_drops() {
+        let mut extractors = SpriteExtractors::new();
+        extractors.set_enabled(TILEMAP_EXTRACTOR, false);
+        extractors.register(TILEMAP_EXTRACTOR, |_, _| {});
+        assert!(extractors.is_enabled(TILEMAP_EXTRACTOR));
+        assert_eq!(extractors.names().next(), Some(TILEMAP_EXTRACTOR));
+
+        assert!(extractors.remove(PARTICLE_EXTRACTOR));
+        assert!(!extractors.remove(PARTICLE_EXTRACTOR));
+        assert_eq!(extractors.names().count(), 2);
+    }
+
+    #[test]
+    fn hierarchy_entities_extract_at_their_global_transform() {
+        let mut world = World::new();
+        let entity = world.create_entity();
+        world.add_component(&entity, Transform2D::new(Vec2::new(1.0, 1.0))).unwrap();
+        world.add_component(&entity, GlobalTransform2D {
+            position: Vec2::new(10.0, 20.0),
+            rotation: 0.5,
+            scale: Vec2::ONE,
+        }).unwrap();
+
+        let transform = ExtractTransform::of(&world, entity).unwrap();
+        assert_eq!(transform.position, Vec2::new(10.0, 20.0));
+        assert_eq!(transform.rotation, 0.5);
+    }
+}
@@ -36,23 +36,34 @@ use winit::{
 
 use audio::AudioManager;
 use input::InputHandler;
-use renderer::{sprite::SpriteBatcher, texture::TextureHandle};
-
+use renderer::{sprite::SpriteBatcher, RenderStats};
+
+mod floating_origin;
+mod frame;
+mod headless;
+#[cfg(feature = "physics")]
+mod physics;
+mod profile;
 mod render;
+#[cfg(target_arch = "wasm32")]
+mod web;
+
+pub use headless::{run_example, run_headless};
+pub(crate) use headless::{run_builder_example, run_builder_headless};
 
 use crate::{GameLoopManager, UIManager};
 use crate::game_config::GameConfig;
 use crate::ui_integration::render_ui_commands;
-use ui::DrawCommand;
 use crate::contexts::{GameContext, RenderContext};
-use crate::assets::{AssetConfig, AssetManager};
+use crate::assets::AssetManager;
 use crate::achievements::AchievementManager;
+use crate::extraction::SpriteExtractors;
 use crate::glyph_texture_cache::GlyphTextureCache;
+use crate::plugin::{EngineBuilder, Plugins};
 use crate::render_manager::RenderManager;
 use crate::window_manager::{WindowConfig, WindowManager};
 use crate::Scene;
 
-use ecs::sprite_components::{Sprite as EcsSprite, Transform2D};
 
 /// The main game trait. Implement this to create your game.
 ///
@@ -67,42 +78,11 @@ pub trait Game: Sized + 'static {
     fn update(&mut self, ctx: &mut GameContext);
 
     /// Called every frame to render sprites. Add sprites to `ctx.sprites`.
-    /// Default implementation extracts sprites from ECS entities with Transform2D and Sprite components,
-    /// then renders UI draw commands on top.
+    /// Entities are already extracted by then — tilemaps, ECS sprites,
+    /// particles, and anything registered with `ctx.extractors` (see
+    /// [`crate::extraction`]) — so override this only for drawing that no
+    /// component describes. The default renders UI draw commands on top.
     fn render(&mut self, ctx: &mut RenderContext) {
-        // Tilemaps first, so equal-depth entity sprites draw over tiles
-        crate::tilemap_render::append_tilemap_sprites(ctx.world, ctx.sprites);
-
-        // Default: extract sprites from ECS
-        for entity_id in ctx.world.entities() {
-            let sprite = ctx.world.get::<EcsSprite>(entity_id);
-
-            if let Some(ecs_sprite) = sprite {
-                if !ecs_sprite.visible { continue; }
-                // Use GlobalTransform2D if available (for hierarchical entities),
-                // otherwise fall back to local Transform2D
-                let (position, rotation, scale) = if let Some(global) = ctx.world.get::<ecs::hierarchy::GlobalTransform2D>(entity_id) {
-                    (global.position, global.rotation, global.scale)
-                } else if let Some(transform) = ctx.world.get::<Transform2D>(entity_id) {
-                    (transform.position, transform.rotation, transform.scale)
-                } else {
-                    continue; // No transform, skip this entity
-                };
-
-                // Use the texture handle from the ECS sprite component
-                let texture = TextureHandle { id: ecs_sprite.texture_handle };
-                let renderer_sprite = renderer::Sprite::new(texture)
-                    .with_position(position)
-                    .with_rotation(rotation)
-                    .with_scale(scale * ecs_sprite.scale * crate::RENDER_UNIT)
-                    .with_color(ecs_sprite.color)
-                    .with_depth(ecs_sprite.depth)
-                    .with_emissive(ecs_sprite.emissive);
-
-                ctx.sprites.add_sprite(&renderer_sprite);
-            }
-        }
-
         // Render UI draw commands on top
         render_ui_commands(ctx.sprites, ctx.ui_commands, &*ctx.camera, ctx.glyph_textures);
     }
@@ -147,9 +127,16 @@ pub trait Game: Sized + 'static {
 /// }
 /// ```
 pub fn run_game<G: Game>(game: G, config: GameConfig) -> Result<(), Box<dyn std::error::Error>> {
+    EngineBuilder::new(config).run(game)
+}
+
+/// [`run_game`] with everything registered on `builder` (see [`crate::plugin`]).
+pub(crate) fn run_builder<G: Game>(builder: EngineBuilder, game: G) -> Result<(), Box<dyn std::error::Error>> {
     let event_loop = EventLoop::new()?;
-    let mut runner = GameRunner::new(game, config);
-    event_loop.run_app(&mut runner)?;
+    #[cfg(not(target_arch = "wasm32"))]
+    event_loop.run_app(&mut GameRunner::new(game, builder))?;
+    #[cfg(target_arch = "wasm32")]
+    web::spawn_app(event_loop, GameRunner::new(game, builder));
     Ok(())
 }
 
@@ -181,6 +168,8 @@ struct GameRunner<G: Game> {
     input: InputHandler,
     /// Gamepad hardware backend (gilrs); disabled no-op when unavailable.
     gamepad_backend: crate::gamepad_backend::GamepadBackend,
+    /// Right-stick mouse cursor, when `GameConfig::virtual_cursor` is set.
+    virtual_cursor: Option<input::VirtualCursor>,
     /// Player-aware input bindings (universal per-player mapping layer),
     /// loaded from `GameConfig::input_settings_path` when set.
     player_input: input::InputSettings,
@@ -188,11 +177,12 @@ struct GameRunner<G: Game> {
     ui_manager: UIManager,
     /// Game loop timing and frame management
     game_loop_manager: GameLoopManager,
-    /// Cached glyph textures for text rendering
+    /// UI atlas: cached glyphs, icon images and the white point
     glyph_textures: GlyphTextureCache,
-    /// Engine time multiplier mirrored onto `GameContext.time_scale`
-    /// (read-write, persisted like chaos_mode). Scales particle stepping.
-    time_scale: f32,
+    /// Sprite extractors run before `Game::render`, exposed as `ctx.extractors`
+    sprite_extractors: SpriteExtractors,
+    /// Plugin-registered systems and extensions, exposed as `ctx.plugins`
+    plugins: Plugins,
     /// Set when the game writes `GameContext.exit_requested` — triggers the
     /// clean shutdown path at the end of the frame.
     exit_requested: bool,
@@ -203,20 +193,50 @@ struct GameRunner<G: Game> {
     /// CPU-pooled particle system. Lives across frames so emitters can
     /// accumulate over time and spawn bursts can persist for their lifetime.
     particles: crate::particles::ParticleManager,
+    /// Optional engine-owned physics (`ctx.physics`), stepped after `update()`.
+    #[cfg(feature = "physics")]
+    physics: crate::game_physics::GamePhysics,
     /// Line vertex buffer that the game fills each frame and the engine
     /// uploads to the renderer. Cleared before every `update()`.
     lines: Vec<renderer::line_pipeline::LineVertex>,
+    /// Debug shapes the game draws each frame, exposed as `ctx.debug_draw`.
+    /// Cleared before every `update()`.
+    debug_draw: renderer::DebugDraw,
+    /// Keeps `GlobalTransform2D` in step with `Transform2D` and the
+    /// hierarchy, inserting it where it is missing.
+    transforms: ecs::TransformHierarchySystem,
+    /// Steps `Trail2D` components and builds their strip vertices.
+    trails: crate::trail_render::TrailRenderer,
+    /// Steps `WorldText` components and lays them out for the sprite pass.
+    world_text: crate::world_text_render::WorldTextRenderer,
     /// Persistent sprite batchers, cleared (capacity retained) each frame —
     /// no per-frame HashMap/Vec churn (GPP-15). Game and UI sprites batch
     /// separately so UI never shares a batch with (and paints over) sprites.
     game_batcher: SpriteBatcher,
     ui_batcher: SpriteBatcher,
+    /// Last rendered frame's statistics (batchers + GPU), mirrored onto
+    /// `GameContext` and `RenderContext`.
+    render_stats: RenderStats,
+    /// Last frame's update-pass timings, mirrored onto `GameContext`.
+    profile: crate::profiling::FrameProfile,
+    /// Fixed-update accumulator driving the world's `SimulationTick`
+    fixed_clock: crate::timing::FixedClock,
+    /// This frame's simulation time, mirrored onto `GameContext`.
+    time: crate::timing::Time,
+    /// Debug validation layer, exposed as `ctx.validation`
+    validator: crate::validation::DebugValidator,
+    /// Game-flow state stack, exposed as `ctx.states`
+    states: crate::game_states::GameStates,
     /// Whether the game's init() has been called
     initialized: bool,
+    /// Pending renderer init and audio unlock in the browser
+    #[cfg(target_arch = "wasm32")]
+    web: web::WebState,
 }
 
 impl<G: Game> GameRunner<G> {
-    fn new(game: G, config: GameConfig) -> Self {
+    fn new(game: G, builder: EngineBuilder) -> Self {
+        let (config, plugins, sprite_extractors) = builder.into_parts();
         // Create window manager from game config
         let window_config = WindowConfig::new(&config.title)
             .with_size(config.width, config.height)
@@ -224,7 +244,12 @@ impl<G: Game> GameRunner<G> {
 
         // Audio init failure is non-fatal: falls back to a disabled manager
         // whose playback calls are no-ops, so init()/update() always run.
+        // Browsers only allow output after a user gesture, so web builds
+        // start disabled and connect on the first input.
+        #[cfg(not(target_arch = "wasm32"))]
         let audio_manager = AudioManager::new_or_disabled();
+        #[cfg(target_arch = "wasm32")]
+        let audio_manager = AudioManager::disabled();
 
         let achievements = match &config.achievement_save_path {
             Some(path) => AchievementManager::with_save_path(path),
@@ -238,6 +263,12 @@ impl<G: Game> GameRunner<G> {
             Some(path) => crate::input_settings_io::load_or_create(std::path::Path::new(path)),
             None => input::InputSettings::default_two_player(),
         };
+        let virtual_cursor = config.virtual_cursor.map(input::VirtualCursor::new);
+        let sprite_limits = config.sprite_limits();
+        let fixed_clock = crate::timing::FixedClock::new(config.fixed_timestep);
+        let validator = crate::validation::DebugValidator::new(config.debug_validation_enabled());
+        let mut debug_draw = renderer::DebugDraw::new();
+        debug_draw.set_enabled(config.debug_draw_enabled());
 
         Self {
             game,
@@ -248,23 +279,40 @@ impl<G: Game> GameRunner<G> {
             audio_manager,
             input: InputHandler::new(),
             gamepad_backend: crate::gamepad_backend::GamepadBackend::new_or_disabled(),
+            virtual_cursor,
             player_input,
             ui_manager: UIManager::new(),
             game_loop_manager,
             glyph_textures: GlyphTextureCache::new(),
-            time_scale: 1.0,
+            sprite_extractors,
+            plugins,
             exit_requested: false,
             scene: Scene::new("main"),
             achievements,
             particles: crate::particles::ParticleManager::default(),
+            #[cfg(feature = "physics")]
+            physics: crate::game_physics::GamePhysics::new(),
             lines: Vec::new(),
-            game_batcher: SpriteBatcher::new(),
+            debug_draw,
+            transforms: ecs::TransformHierarchySystem::new(),
+            trails: Default::default(),
+            world_text: Default::default(),
+            game_batcher: SpriteBatcher::with_limits(sprite_limits),
             ui_batcher: SpriteBatcher::new(),
+            render_stats: RenderStats::default(),
+            profile: Default::default(),
+            fixed_clock,
+            time: crate::timing::Time::default(),
+            validator,
+            states: crate::game_states::GameStates::new(),
             initialized: false,
+            #[cfg(target_arch = "wasm32")]
+            web: web::WebState::default(),
         }
     }
 
     /// Initialize the render manager with the current window.
+    #[cfg(not(target_arch = "wasm32"))]
     fn init_renderer(&mut self) -> Result<(), renderer::RendererError> {
         let window = self.window_manager.window_clone().ok_or_else(|| {
             renderer::RendererError::WindowCreationError("No window".to_string())
@@ -274,8 +322,19 @@ impl<G: Game> GameRunner<G> {
         self.render_manager.init(
             window,
             self.config.clear_color,
-            renderer::RendererConfig { vsync: self.config.vsync },
+            renderer::RendererConfig {
+                vsync: self.config.vsync,
+                color: self.config.color,
+                camera_relative: self.config.floating_origin.is_some(),
+            },
         )?;
+        self.renderer_ready();
+        Ok(())
+    }
+
+    /// Size the viewport and create the asset manager once the renderer
+    /// exists.
+    fn renderer_ready(&mut self) {
         self.render_manager.set_viewport_size(
             self.config.width as f32,
             self.config.height as f32,
@@ -283,21 +342,15 @@ impl<G: Game> GameRunner<G> {
 
         // Create asset manager with renderer's device and queue
         if let (Some(device), Some(queue)) = (self.render_manager.device(), self.render_manager.queue()) {
-            let asset_manager = match &self.config.asset_base_path {
-                Some(base_path) => {
-                    let asset_config = AssetConfig {
-                        base_path: base_path.clone(),
-                        ..AssetConfig::default()
-                    };
-                    AssetManager::with_config(device, queue, asset_config)
-                }
-                None => AssetManager::new(device, queue),
-            };
-            self.asset_manager = Some(asset_manager);
+            self.install_asset_manager(AssetManager::with_config(device, queue, self.config.asset_config()));
             log::info!("Asset manager initialized");
         }
+    }
 
-        Ok(())
+    /// Adopt the asset manager, handing it the plugins' asset loaders.
+    fn install_asset_manager(&mut self, mut asset_manager: AssetManager) {
+        asset_manager.set_loaders(self.plugins.take_asset_loaders());
+        self.asset_manager = Some(asset_manager);
     }
 
     /// Helper to get window size from window manager.
@@ -305,122 +358,6 @@ impl<G: Game> GameRunner<G> {
         let (w, h) = self.window_manager.size();
         Vec2::new(w as f32, h as f32)
     }
-
-
-    fn update_and_render(&mut self) {
-        // Update game loop timing
-        let delta_time = self.game_loop_manager.update();
-        let window_size = self.window_size();
-
-        // Check if managers are available (audio is optional)
-        let has_managers = self.asset_manager.is_some();
-        if !has_managers {
-            return;
-        }
-
-        // Flush events from previous frame before processing new input
-        self.scene.world.flush_events();
-
-        // Drain gamepad hardware events into the same queue as window events,
-        // then process everything FIRST so UI and game logic see fresh state
-        // with identical frame semantics across devices.
-        self.gamepad_backend.pump(&mut self.input);
-        self.input.process_queued_events();
-
-        // Update all subsystems
-        self.update_audio();
-        self.update_ui_begin(window_size, delta_time);
-        self.initialize_and_update(delta_time, window_size);
-        let ui_commands = self.update_ui_end();
-        self.update_input_end();
-
-        // Render frame if ready
-        if self.render_manager.is_initialized() {
-            self.render_frame(window_size, &ui_commands);
-        }
-    }
-
-    /// Update audio manager to clean up finished sounds
-    fn update_audio(&mut self) {
-        self.audio_manager.update();
-    }
-
-    /// Begin UI frame and process input
-    fn update_ui_begin(&mut self, window_size: Vec2, delta_time: f32) {
-        self.ui_manager.begin_frame(&self.input, window_size, delta_time);
-    }
-
-    /// Initialize game on first frame, then update game logic.
-    fn initialize_and_update(&mut self, delta_time: f32, window_size: Vec2) {
-        let Some(asset_manager) = self.asset_manager.as_mut() else {
-            log::warn!("initialize_and_update called before asset manager exists; skipping frame");
-            return;
-        };
-
-        // Clear the line buffer at the start of the frame so games push fresh
-        // vertices each update (typical case: grid.build_line_vertices()).
-        self.lines.clear();
-
-        let mut ctx = GameContext {
-            input: &self.input,
-            players: &mut self.player_input,
-            world: &mut self.scene.world,
-            assets: asset_manager,
-            audio: &mut self.audio_manager,
-            ui: self.ui_manager.ui_context(),
-            delta_time,
-            window_size,
-            chaos_mode: self.config.chaos_mode,
-            time_scale: self.time_scale,
-            exit_requested: false,
-            achievements: &mut self.achievements,
-            particles: &mut self.particles,
-            lines: &mut self.lines,
-        };
-
-        if !self.initialized {
-            self.game.init(&mut ctx);
-            self.initialized = true;
-        }
-
-        self.game.update(&mut ctx);
-
-        // Persist any chaos-mode or time-scale change the game wrote to the
-        // context, so both reflect the current runtime selection next frame.
-        self.config.chaos_mode = ctx.chaos_mode;
-        self.time_scale = ctx.time_scale;
-        self.exit_requested |= ctx.exit_requested;
-
-        // Step the particle system after the game's update — emitter
-        // accumulators see the latest transforms, and pool stepping
-        // happens once per frame. Scaled by time_scale so a paused game
-        // (time_scale 0.0) freezes its particles with the rest of the world.
-        crate::particles::ParticleSystem::update(
-            &mut self.scene.world,
-            &mut self.particles,
-            delta_time * self.time_scale,
-        );
-
-        // Forward the line vertices the game pushed during update to the
-        // renderer. Empty buffer == no lines drawn this frame.
-        self.render_manager.set_lines(&self.lines);
-
-        // Draw achievement toasts on top of whatever the game drew.
-        self.achievements
-            .draw_toasts(self.ui_manager.ui_context(), window_size);
-        self.achievements.tick(delta_time);
-    }
-
-    /// End UI frame and return draw commands
-    fn update_ui_end(&mut self) -> Vec<DrawCommand> {
-        self.ui_manager.end_frame()
-    }
-
-    /// Clear input state for next frame
-    fn update_input_end(&mut self) {
-        self.input.end_frame();
-    }
-
 }
 
 impl<G: Game> GameRunner<G> {
@@ -428,7 +365,14 @@ impl<G: Game> GameRunner<G> {
     /// scene down, and exit the event loop. Shared by the window close
     /// button and game-requested exits (`GameContext::exit_requested`).
     fn shutdown(&mut self, event_loop: &ActiveEventLoop) {
+        self.finish();
+        event_loop.exit();
+    }
+
+    /// Clean shutdown shared by the windowed and headless loops.
+    fn finish(&mut self) {
         self.game.on_exit();
+        self.plugins.shutdown_systems(&mut self.scene.world);
         // Persist input bindings (incl. runtime pad re-assignments)
         if let Some(path) = &self.config.input_settings_path {
             if let Err(e) = crate::input_settings_io::save(
@@ -440,7 +384,6 @@ impl<G: Game> GameRunner<G> {
         }
         let _ = self.scene.stop();
         let _ = self.scene.shutdown();
-        event_loop.exit();
     }
 }
 
@@ -458,8 +401,13 @@ impl<G: Game> ApplicationHandler<()> for GameRunner<G> {
             return;
         }
 
-        // Initialize renderer
-        if let Err(e) = self.init_renderer() {
+        // Initialize renderer (in the browser this only starts it; frames
+        // are skipped until it is ready)
+        #[cfg(not(target_arch = "wasm32"))]
+        let renderer = self.init_renderer();
+        #[cfg(target_arch = "wasm32")]
+        let renderer = self.start_renderer();
+        if let Err(e) = renderer {
             log::error!("Failed to initialize renderer: {}", e);
             event_loop.exit();
             return;
@@ -489,6 +437,8 @@ impl<G: Game> ApplicationHandler<()> for GameRunner<G> {
 
         // Forward to input handler
         self.input.handle_window_event(&event);
+        #[cfg(target_arch = "wasm32")]
+        self.unlock_audio(&event);
 
         match event {
             WindowEvent::CloseRequested => {
@@ -519,13 +469,23 @@ impl<G: Game> ApplicationHandler<()> for GameRunner<G> {
                             audio: &mut self.audio_manager,
                             ui: self.ui_manager.ui_context(),
                             delta_time: 0.0,
+                            time: self.time,
                             window_size,
                             chaos_mode: self.config.chaos_mode,
-                            time_scale: self.time_scale,
                             exit_requested: false,
                             achievements: &mut self.achievements,
                             particles: &mut self.particles,
                             lines: &mut self.lines,
+                            debug_draw: &mut self.debug_draw,
+                            render_stats: self.render_stats,
+                            profile: &self.profile,
+                            validation: &mut self.validator,
+                            extractors: &mut self.sprite_extractors,
+                            plugins: &mut self.plugins,
+                            states: &mut self.states,
+                            transforms: &mut self.transforms,
+                            #[cfg(feature = "physics")]
+                            physics: &mut self.physics,
                         };
 
                         match event.state {
@@ -540,18 +500,22 @@ impl<G: Game> ApplicationHandler<()> for GameRunner<G> {
                         // Persist chaos-mode/time-scale/exit changes made in
                         // key handlers too.
                         self.config.chaos_mode = ctx.chaos_mode;
-                        self.time_scale = ctx.time_scale;
+                        ctx.world.insert_resource(ctx.time.time_scale());
                         self.exit_requested |= ctx.exit_requested;
                     }
                 }
             }
             WindowEvent::RedrawRequested => {
-                // Rendering is done in about_to_wait
+                // Natively, rendering is done in about_to_wait; the browser
+                // paces frames with requestAnimationFrame instead
+                #[cfg(target_arch = "wasm32")]
+                self.animation_frame(event_loop);
             }
             _ => {}
         }
     }
 
+    #[cfg(not(target_arch = "wasm32"))]
     fn about_to_wait(&mut self, event_loop: &ActiveEventLoop) {
         self.update_and_render();
         if self.exit_requested {
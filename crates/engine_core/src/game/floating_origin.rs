@@ -0,0 +1,32 @@
+//! Floating-origin rebasing for the game loop (`GameConfig::floating_origin`).
+//!
+//! Runs at the start of a frame, before `update()`, so the game sees the
+//! `ecs::OriginShifted` event the same frame and physics queries agree with
+//! the shifted transforms. The reference point is the camera as last
+//! rendered; the shift is rounded to whole units so pixel-aligned content
+//! stays aligned.
+
+use super::{Game, GameRunner};
+
+impl<G: Game> GameRunner<G> {
+    /// Shift the world back to the origin once the camera strays past the
+    /// configured threshold: ECS, engine physics, particles and the render
+    /// camera move together. Games owning their own `PhysicsSystem` call
+    /// its `shift_origin` from the `OriginShifted` event; a `WorldStreamer`
+    /// reads the world's `WorldOrigin` itself.
+    pub(super) fn rebase_world_origin(&mut self) {
+        let Some(threshold) = self.config.floating_origin else { return };
+        let shift = self.render_manager.camera().position.round();
+        if shift.abs().max_element() < threshold {
+            return;
+        }
+
+        ecs::shift_world_origin(&mut self.scene.world, shift);
+        #[cfg(feature = "physics")]
+        if let Some(system) = self.physics.system_mut() {
+            system.shift_origin(shift);
+        }
+        self.particles.translate(-shift);
+        self.render_manager.camera_mut().position -= shift;
+    }
+}
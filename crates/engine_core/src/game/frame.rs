@@ -0,0 +1,189 @@
+//! Frame orchestration of the game loop, split out of `game.rs`: input,
+//! audio and UI bookkeeping around `Game::update`, the engine passes that
+//! follow it (see [`profile`](super::profile)), and the line, trail and
+//! world-text uploads ahead of [`render`](super::render).
+
+use glam::Vec2;
+
+use common::time::Instant;
+use ui::DrawCommand;
+
+use crate::contexts::GameContext;
+
+use super::{Game, GameRunner};
+
+impl<G: Game> GameRunner<G> {
+    pub(super) fn update_and_render(&mut self) {
+        let delta_time = self.game_loop_manager.update();
+        self.run_frame(delta_time);
+    }
+
+    /// One frame of the loop with an explicit delta (the windowed loop's
+    /// measured one, or a headless script's fixed one).
+    pub(super) fn run_frame(&mut self, delta_time: f32) {
+        let window_size = self.window_size();
+
+        // Check if managers are available (audio is optional)
+        let has_managers = self.asset_manager.is_some();
+        if !has_managers {
+            return;
+        }
+
+        // Flush events from previous frame before processing new input, and
+        // start a new change-detection frame (`world.is_changed::<T>()`).
+        self.scene.world.flush_events();
+        self.scene.world.clear_trackers();
+        // Advance the fixed-update tick before anything can emit events
+        // stamped with it.
+        self.time = crate::timing::Time::advance(&mut self.fixed_clock, &mut self.scene.world, delta_time);
+        // Large worlds: pull everything back toward the origin before the
+        // game runs, so it sees `OriginShifted` this frame
+        self.rebase_world_origin();
+
+        // Drain gamepad hardware events into the same queue as window events,
+        // then process everything FIRST so UI and game logic see fresh state
+        // with identical frame semantics across devices.
+        self.gamepad_backend.pump(&mut self.input);
+        self.input.advance_time(delta_time);
+        self.input.process_queued_events();
+        if let Some(cursor) = &mut self.virtual_cursor {
+            cursor.update(&mut self.input, delta_time, (window_size.x, window_size.y));
+        }
+
+        // Update all subsystems
+        self.update_audio();
+        self.update_ui_begin(window_size, delta_time);
+        self.initialize_and_update(delta_time, window_size);
+        let ui_commands = self.update_ui_end();
+        self.update_input_end();
+        self.track_texture_usage(&ui_commands, delta_time);
+
+        // Builds the sprite batches even headless; the GPU submit inside
+        // is skipped until the renderer is initialized.
+        self.render_frame(window_size, &ui_commands);
+    }
+
+    /// Update audio manager to clean up finished sounds
+    fn update_audio(&mut self) {
+        self.audio_manager.update();
+    }
+
+    /// Begin UI frame and process input
+    fn update_ui_begin(&mut self, window_size: Vec2, delta_time: f32) {
+        self.ui_manager.begin_frame(&self.input, window_size, delta_time);
+        // Icon images registered last frame join the UI atlas before this
+        // frame's UI draws them
+        if let Some(asset_manager) = &mut self.asset_manager {
+            self.glyph_textures.pack_icons(self.ui_manager.ui_context(), asset_manager);
+        }
+    }
+
+    /// Initialize game on first frame, then update game logic.
+    fn initialize_and_update(&mut self, delta_time: f32, window_size: Vec2) {
+        let Some(asset_manager) = self.asset_manager.as_mut() else {
+            log::warn!("initialize_and_update called before asset manager exists; skipping frame");
+            return;
+        };
+
+        // Clear the line buffer at the start of the frame so games push fresh
+        // vertices each update (typical case: grid.build_line_vertices()).
+        self.lines.clear();
+        self.debug_draw.clear();
+        self.validator.begin_frame();
+        if !self.initialized {
+            self.plugins.start_systems(&mut self.scene.world);
+        }
+
+        let mut ctx = GameContext {
+            input: &self.input,
+            players: &mut self.player_input,
+            world: &mut self.scene.world,
+            assets: asset_manager,
+            audio: &mut self.audio_manager,
+            ui: self.ui_manager.ui_context(),
+            delta_time: self.time.delta(),
+            time: self.time,
+            window_size,
+            chaos_mode: self.config.chaos_mode,
+            exit_requested: false,
+            achievements: &mut self.achievements,
+            particles: &mut self.particles,
+            lines: &mut self.lines,
+            debug_draw: &mut self.debug_draw,
+            render_stats: self.render_stats,
+            profile: &self.profile,
+            validation: &mut self.validator,
+            extractors: &mut self.sprite_extractors,
+            plugins: &mut self.plugins,
+            states: &mut self.states,
+            transforms: &mut self.transforms,
+            #[cfg(feature = "physics")]
+            physics: &mut self.physics,
+        };
+
+        if !self.initialized {
+            self.game.init(&mut ctx);
+            ctx.states.apply(ctx.world);
+            self.initialized = true;
+        }
+
+        let started = Instant::now();
+        self.game.update(&mut ctx);
+        let update_time = started.elapsed().as_secs_f32();
+        // State transitions requested this frame (hooks, scoped despawns)
+        ctx.states.apply(ctx.world);
+
+        // Persist any chaos-mode or time-scale change the game wrote to the
+        // context, so both reflect the current runtime selection next frame.
+        self.config.chaos_mode = ctx.chaos_mode;
+        ctx.world.insert_resource(ctx.time.time_scale());
+        self.exit_requested |= ctx.exit_requested;
+        self.run_engine_passes(update_time, delta_time);
+
+        // Forward the line vertices the game pushed during update to the
+        // renderer. Empty buffer == no lines drawn this frame.
+        self.render_manager.set_lines(&self.lines);
+        self.render_manager.set_debug_lines(self.debug_draw.vertices());
+        // Trails advance after movement (scaled delta) and upload likewise.
+        self.render_manager.set_trails(self.trails.update(&mut self.scene.world, self.time.delta()));
+        // World text drifts and fades likewise, then is laid out with the
+        // UI's default font for the glyph atlas.
+        self.world_text.update(
+            &mut self.scene.world,
+            self.ui_manager.ui_context().font_manager_mut(),
+            self.sprite_extractors.hidden(),
+            self.time.delta(),
+        );
+
+        // Draw achievement toasts on top of whatever the game drew.
+        self.achievements
+            .draw_toasts(self.ui_manager.ui_context(), window_size);
+        self.achievements.tick(delta_time);
+    }
+
+    /// End UI frame and return draw commands
+    fn update_ui_end(&mut self) -> Vec<DrawCommand> {
+        if let Some(cursor) = self.virtual_cursor.as_ref().filter(|cursor| cursor.is_active()) {
+            let position = cursor.position();
+            self.ui_manager.ui_context().pointer(Vec2::new(position.x, position.y));
+        }
+        self.ui_manager.end_frame()
+    }
+
+    /// Texture reference sweep: feeds `unload_unused()` and, with a grace
+    /// period configured, unloads textures nothing has used for a while.
+    /// Runs after the UI frame ends so `ui.image*` textures count.
+    fn track_texture_usage(&mut self, ui_commands: &[DrawCommand], delta_time: f32) {
+        if let Some(asset_manager) = self.asset_manager.as_mut() {
+            let mut referenced = crate::asset_refs::referenced_textures(&self.scene.world);
+            referenced.extend(self.particles.iter_alive().map(|particle| particle.texture));
+            referenced.extend(crate::asset_refs::ui_textures(ui_commands));
+            asset_manager.track_texture_usage(referenced, delta_time);
+        }
+    }
+
+    /// Clear input state for next frame
+    fn update_input_end(&mut self) {
+        self.input.end_frame();
+    }
+}
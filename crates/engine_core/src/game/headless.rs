@@ -0,0 +1,110 @@
+//! Headless game loop: runs a [`GameRunner`] against a windowless GPU
+//! device with scripted input, for the example suite's auto-play mode.
+//!
+//! Everything but the window runs — `init`, `update`, `render` into the
+//! sprite batchers, UI, particles, validation — with the script's fixed
+//! delta instead of measured frame time. Only the final GPU submit is
+//! skipped, since there is no surface. Audio and gamepads are disabled so
+//! runs are silent and reproducible.
+
+use std::process::ExitCode;
+
+use audio::AudioManager;
+
+use crate::assets::AssetManager;
+use crate::game_config::GameConfig;
+use crate::gamepad_backend::GamepadBackend;
+use crate::headless::{headless_requested, HeadlessError, HeadlessReport, HeadlessScript};
+use crate::plugin::EngineBuilder;
+
+use super::{run_builder, Game, GameRunner};
+
+/// Run `game` for the frames in `script` without a window, injecting its
+/// input and evaluating its checks. Stops early when the game requests
+/// exit; `Game::on_exit` runs either way.
+pub fn run_headless<G: Game>(
+    game: G,
+    config: GameConfig,
+    script: HeadlessScript,
+) -> Result<HeadlessReport, HeadlessError> {
+    EngineBuilder::new(config).run_headless(game, script)
+}
+
+/// [`run_headless`] with everything registered on `builder`.
+pub(crate) fn run_builder_headless<G: Game>(
+    builder: EngineBuilder,
+    game: G,
+    script: HeadlessScript,
+) -> Result<HeadlessReport, HeadlessError> {
+    let (device, queue) = pollster::block_on(renderer::request_headless_device())?;
+
+    let mut runner = GameRunner::new(game, builder);
+    runner.audio_manager = AudioManager::disabled();
+    runner.gamepad_backend = GamepadBackend::disabled();
+    let asset_config = runner.config.asset_config();
+    runner.install_asset_manager(AssetManager::with_config(device, queue, asset_config));
+    // No resize event will size the camera, so match the configured window.
+    runner.render_manager.camera_mut().viewport_size = runner.window_size();
+
+    let mut report = HeadlessReport::default();
+    for frame in 0..script.frames() {
+        for event in script.events_at(frame) {
+            runner.input.queue_event(event.clone());
+        }
+        runner.run_frame(script.delta_time());
+        report.frames_run = frame + 1;
+
+        if let Some(name) = script.failed_check(frame, &runner.scene.world) {
+            let name = name.to_string();
+            runner.finish();
+            return Err(HeadlessError::CheckFailed { frame: Some(frame), name });
+        }
+        if runner.exit_requested {
+            break;
+        }
+    }
+
+    let failed = script.failed_final_check(&runner.scene.world).map(str::to_string);
+    report.final_tick = runner.time.tick();
+    report.entity_count = runner.scene.world.entity_count();
+    report.render_stats = runner.render_stats;
+    report.validation_issues = runner.validator.issues().to_vec();
+    report.exit_requested = runner.exit_requested;
+    runner.finish();
+
+    match failed {
+        Some(name) => Err(HeadlessError::CheckFailed { frame: None, name }),
+        None => Ok(report),
+    }
+}
+
+/// Entry point for examples: a normal windowed run, or [`run_headless`]
+/// with `script` when `--headless` / `INSICULOUS_HEADLESS=1` is given.
+/// The exit code reports the outcome (see [`HeadlessError::exit_code`]).
+pub fn run_example<G: Game>(game: G, config: GameConfig, script: HeadlessScript) -> ExitCode {
+    EngineBuilder::new(config).run_example(game, script)
+}
+
+/// [`run_example`] with everything registered on `builder`.
+pub(crate) fn run_builder_example<G: Game>(builder: EngineBuilder, game: G, script: HeadlessScript) -> ExitCode {
+    if !headless_requested() {
+        return match run_builder(builder, game) {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(e) => {
+                log::error!("Game error: {}", e);
+                ExitCode::FAILURE
+            }
+        };
+    }
+
+    match run_builder_headless(builder, game, script) {
+        Ok(report) => {
+            println!("{report}");
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("Headless run failed: {e}");
+            ExitCode::from(e.exit_code())
+        }
+    }
+}
@@ -0,0 +1,29 @@
+//! Engine-owned physics (`ctx.physics`) in the frame: the step, the audio
+//! occlusion queries that run against it, and its debug validation.
+
+use common::time::Instant;
+
+use super::{Game, GameRunner};
+
+impl<G: Game> GameRunner<G> {
+    /// Step once the game has issued its impulses and spawns (scaled delta,
+    /// like particles), then let walls between the listener and
+    /// `AudioOcclusion` sources muffle them. Returns the step's CPU time.
+    pub(super) fn step_physics(&mut self, delta_time: f32) -> f32 {
+        let started = Instant::now();
+        self.physics.step(&mut self.scene.world, self.time.delta());
+        let elapsed = started.elapsed().as_secs_f32();
+        if let Some(system) = self.physics.system() {
+            crate::audio_occlusion::update_audio_occlusion(&mut self.scene.world, system, &mut self.audio_manager, delta_time);
+        }
+        elapsed
+    }
+
+    /// Check bodies and colliders against the physics world (no-op when
+    /// validation is disabled).
+    pub(super) fn validate_physics(&mut self) {
+        if let Some(system) = self.physics.system() {
+            self.validator.check_physics(&self.scene.world, system.physics_world());
+        }
+    }
+}
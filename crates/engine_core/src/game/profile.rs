@@ -0,0 +1,47 @@
+//! The engine passes that follow `Game::update` each frame, timed into the
+//! [`FrameProfile`] games read back as `ctx.profile`.
+
+use common::time::Instant;
+
+use crate::profiling::FrameProfile;
+
+use super::{Game, GameRunner};
+
+impl<G: Game> GameRunner<G> {
+    /// Plugin systems, deferred commands, physics, transform propagation,
+    /// validation and particles, in that order. `update_time` is how long
+    /// `Game::update` took this frame.
+    pub(super) fn run_engine_passes(&mut self, update_time: f32, delta_time: f32) {
+        let mut profile = FrameProfile { update: update_time, ..FrameProfile::default() };
+        self.plugins.update_systems(&mut self.scene.world, self.time.delta());
+        profile.systems = self.plugins.system_timings().to_vec();
+        // Deferred spawns/despawns (`world.commands()`) land before physics
+        // and rendering see the world.
+        self.scene.world.apply_commands();
+        #[cfg(feature = "physics")]
+        {
+            profile.physics = self.step_physics(delta_time);
+        }
+
+        // World transforms follow the frame's movement before anything reads
+        // them (validation, particles, trails, extraction, picking).
+        ecs::System::update(&mut self.transforms, &mut self.scene.world, delta_time);
+
+        // Validate once the game's writes for the frame are in (no-op when disabled).
+        if let Some(asset_manager) = &self.asset_manager {
+            self.validator.check_world(&self.scene.world, |handle| asset_manager.has_texture(handle));
+        }
+        #[cfg(feature = "physics")]
+        self.validate_physics();
+
+        // Step the particle system after the game's update — emitter
+        // accumulators see the latest transforms, and pool stepping
+        // happens once per frame. Scaled delta, so a paused game freezes
+        // its particles with the rest of the world.
+        let started = Instant::now();
+        crate::particles::ParticleSystem::update(&mut self.scene.world, &mut self.particles, self.time.delta());
+        profile.particles = started.elapsed().as_secs_f32();
+        profile.entities = self.scene.world.entity_count();
+        self.profile = profile;
+    }
+}
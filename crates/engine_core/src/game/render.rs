@@ -1,50 +1,32 @@
 //! Frame rendering tail of the game loop, split out of `game.rs`.
 //!
-//! Owns sprite-batch assembly and submission: game sprites, particles,
-//! UI sprites, batch ordering, and the final render call.
+//! Owns sprite-batch assembly and submission: extracted sprites (see
+//! [`crate::extraction`]), world text, `Game::render` output, UI sprites,
+//! batch ordering, and the final render call.
 
 use glam::Vec2;
 
-use renderer::{
-    sprite::{SpriteBatch, SpriteBatcher},
-    texture::TextureHandle,
-};
+use renderer::sprite::{CameraView, SpriteBatch};
 use ui::DrawCommand;
 
 use crate::contexts::RenderContext;
+use crate::extraction::ExtractContext;
+use crate::texture_import::TextureSizes;
 use crate::ui_integration::render_ui_commands;
+use crate::world_text_render::render_world_text;
 
 use super::{Game, GameRunner};
 
-/// Append the manager's alive particles to a [`SpriteBatcher`].
-///
-/// Called from the engine after `Game::render` so particles always render,
-/// regardless of whether the game overrides the default render impl.
-fn append_particle_sprites(
-    batcher: &mut SpriteBatcher,
-    particles: &crate::particles::ParticleManager,
-) {
-    for p in particles.iter_alive() {
-        let color = crate::particles::ParticleManager::current_color(p);
-        let scale = crate::particles::ParticleManager::current_scale(p);
-        let sprite = renderer::Sprite::new(TextureHandle { id: p.texture })
-            .with_position(p.position)
-            .with_rotation(p.rotation)
-            .with_scale(Vec2::splat(scale))
-            .with_color(color)
-            .with_emissive(p.emissive)
-            // Just behind UI (positive depth) so particles glow on top of gameplay.
-            .with_depth(0.5);
-        batcher.add_sprite(&sprite);
-    }
-}
+/// Sizes used when no asset manager exists yet (every sprite is then a
+/// generated-texture square).
+static NO_TEXTURE_SIZES: TextureSizes = TextureSizes::new();
 
 impl<G: Game> GameRunner<G> {
     /// Render complete frame with sprites and UI
     pub(super) fn render_frame(&mut self, window_size: Vec2, ui_commands: &[DrawCommand]) {
-        // Prepare glyph textures for text rendering
+        // Pack any new UI and world-text glyphs into the UI atlas
         if let Some(asset_manager) = &mut self.asset_manager {
-            self.glyph_textures.prepare(ui_commands, asset_manager);
+            self.glyph_textures.prepare(&[ui_commands, self.world_text.commands()], asset_manager);
         }
 
         // Phase 1: Game sprites — render into their own batcher so they never
@@ -58,6 +40,24 @@ impl<G: Game> GameRunner<G> {
         self.render_manager.sync_main_camera(&self.scene.world);
         {
             let empty_commands: &[DrawCommand] = &[];
+            let texture_sizes = self
+                .asset_manager
+                .as_ref()
+                .map_or(&NO_TEXTURE_SIZES, |assets| assets.texture_sizes());
+            // Registered extractors (tilemaps, ECS sprites, particles, and
+            // game components) run whether or not the game overrides render().
+            let extract_ctx = ExtractContext {
+                world: &self.scene.world,
+                texture_sizes,
+                particles: &self.particles,
+                hidden: self.sprite_extractors.hidden(),
+                camera: self.render_manager.camera(),
+                view: CameraView::of(self.render_manager.camera()).filter(|_| self.sprite_extractors.is_culling()),
+            };
+            self.sprite_extractors.extract(&extract_ctx, &mut self.game_batcher);
+            // World text sorts and culls with the sprites
+            render_world_text(&mut self.game_batcher, self.world_text.commands(), self.glyph_textures.textures());
+
             let mut ctx = RenderContext {
                 world: &self.scene.world,
                 sprites: &mut self.game_batcher,
@@ -65,14 +65,15 @@ impl<G: Game> GameRunner<G> {
                 window_size,
                 ui_commands: empty_commands,
                 glyph_textures: self.glyph_textures.textures(),
+                texture_sizes,
+                render_stats: self.render_stats,
             };
             self.game.render(&mut ctx);
         }
 
-        // Append particle sprites into the game batcher. Particles render
-        // after gameplay sprites so they appear on top of static objects
-        // but below UI.
-        append_particle_sprites(&mut self.game_batcher, &self.particles);
+        // Frustum-cull against the camera as the game left it, so off-screen
+        // sprites never reach sorting or the instance upload.
+        self.game_batcher.cull_to_camera(self.render_manager.camera());
 
         // Phase 2: UI sprites — separate batcher. Conversion is
         // camera-relative so UI stays at fixed screen pixels even when the
@@ -91,6 +92,8 @@ impl<G: Game> GameRunner<G> {
         // now-empty batches for textures with no sprites this frame; skip them.
         self.game_batcher.sort_all_batches();
         self.ui_batcher.sort_all_batches();
+        self.render_stats = self.game_batcher.stats();
+        self.render_stats.merge(self.ui_batcher.stats());
         let mut batch_refs: Vec<&SpriteBatch> =
             self.game_batcher.batches().values().filter(|b| !b.instances.is_empty()).collect();
         Self::sort_batch_refs(&mut batch_refs);
@@ -99,10 +102,11 @@ impl<G: Game> GameRunner<G> {
         Self::sort_batch_refs(&mut batch_refs[game_batch_count..]);
 
         // Get textures from asset manager (need to reborrow after RenderContext)
-        if let Some(asset_manager) = &self.asset_manager {
+        if let Some(asset_manager) = self.asset_manager.as_ref().filter(|_| self.render_manager.is_initialized()) {
             let textures = asset_manager.textures();
-            if let Err(e) = self.render_manager.render(&batch_refs, textures) {
-                log::error!("Render error: {}", e);
+            match self.render_manager.render(&batch_refs, textures) {
+                Ok(gpu_stats) => self.render_stats.merge(gpu_stats),
+                Err(e) => log::error!("Render error: {}", e),
             }
         }
     }
@@ -119,6 +123,7 @@ impl<G: Game> GameRunner<G> {
                     a_max.total_cmp(&b_max)
                 })
                 .then_with(|| a.texture_handle.id.cmp(&b.texture_handle.id))
+                .then_with(|| a.mask_texture.id.cmp(&b.mask_texture.id))
         });
     }
 }
@@ -0,0 +1,114 @@
+//! Browser (wasm32) side of the windowed loop.
+//!
+//! The browser owns the event loop and nothing may block it, so three
+//! things differ from native:
+//!
+//! - The renderer is created asynchronously: [`GameRunner::start_renderer`]
+//!   spawns the adapter/device requests onto the page's task queue and
+//!   frames are skipped until the result lands (the game's `init` runs on
+//!   the first frame after that, as it does natively).
+//! - Frames run from `RedrawRequested`, which winit drives with
+//!   `requestAnimationFrame`, instead of `about_to_wait`;
+//!   `GameConfig::target_fps` skips callbacks that arrive early.
+//! - Audio output can only start after a user gesture, so the runner
+//!   starts with a disabled [`AudioManager`](audio::AudioManager) and
+//!   connects it on the first key, click or touch.
+//!
+//! There is no filesystem: load assets with `load_texture_from_bytes` /
+//! `load_sound_from_bytes` (e.g. from `include_bytes!`).
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use renderer::{Renderer, RendererError};
+use winit::event::WindowEvent;
+use winit::event_loop::{ActiveEventLoop, EventLoop};
+use winit::platform::web::EventLoopExtWebSys;
+
+use super::{Game, GameRunner};
+
+/// Result of the in-flight renderer init, filled in by the spawned task.
+type PendingRenderer = Rc<RefCell<Option<Result<Renderer, RendererError>>>>;
+
+/// Browser-only runner state.
+#[derive(Default)]
+pub(super) struct WebState {
+    pending_renderer: PendingRenderer,
+    /// Whether a user gesture has been seen (audio was tried)
+    audio_unlocked: bool,
+}
+
+/// Hand the runner to the browser's event loop. Returns immediately; the
+/// page keeps the loop alive.
+pub(super) fn spawn_app<G: Game>(event_loop: EventLoop<()>, runner: GameRunner<G>) {
+    event_loop.spawn_app(runner);
+}
+
+impl<G: Game> GameRunner<G> {
+    /// Start creating the renderer for the window's canvas; it is installed
+    /// by a later [`animation_frame`](Self::animation_frame).
+    pub(super) fn start_renderer(&mut self) -> Result<(), RendererError> {
+        let window = self
+            .window_manager
+            .window_clone()
+            .ok_or_else(|| RendererError::WindowCreationError("No window".to_string()))?;
+        let config = renderer::RendererConfig {
+            vsync: self.config.vsync,
+            color: self.config.color,
+            camera_relative: self.config.floating_origin.is_some(),
+        };
+        let pending = Rc::clone(&self.web.pending_renderer);
+        wasm_bindgen_futures::spawn_local(async move {
+            let result = renderer::init_with_config(window, config).await;
+            *pending.borrow_mut() = Some(result);
+        });
+        self.window_manager.request_redraw();
+        Ok(())
+    }
+
+    /// One `requestAnimationFrame` callback: finish renderer init if it
+    /// has landed, run a frame when one is due, and ask for the next.
+    pub(super) fn animation_frame(&mut self, event_loop: &ActiveEventLoop) {
+        let finished = self.web.pending_renderer.borrow_mut().take();
+        match finished {
+            Some(Ok(renderer)) => {
+                self.render_manager.install(renderer, self.config.clear_color);
+                self.renderer_ready();
+                // The canvas may have been laid out since the surface was made
+                let (width, height) = self.window_manager.size();
+                self.render_manager.resize(width, height);
+            }
+            Some(Err(e)) => {
+                log::error!("Failed to initialize renderer: {}", e);
+                event_loop.exit();
+                return;
+            }
+            None => {}
+        }
+
+        if self.game_loop_manager.frame_due() {
+            self.update_and_render();
+            if self.exit_requested {
+                self.shutdown(event_loop);
+                return;
+            }
+        }
+        self.window_manager.request_redraw();
+    }
+
+    /// Connect audio on the first user gesture; browsers keep audio
+    /// output suspended until then.
+    pub(super) fn unlock_audio(&mut self, event: &WindowEvent) {
+        let gesture = matches!(
+            event,
+            WindowEvent::KeyboardInput { .. } | WindowEvent::MouseInput { .. } | WindowEvent::Touch(_)
+        );
+        if !gesture || self.web.audio_unlocked {
+            return;
+        }
+        self.web.audio_unlocked = true;
+        if let Err(e) = self.audio_manager.enable() {
+            log::warn!("Audio unavailable: {}", e);
+        }
+    }
+}
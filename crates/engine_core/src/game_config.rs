@@ -4,12 +4,22 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::asset_root::AssetRoot;
+use crate::assets::AssetConfig;
 use crate::chaos_mode::ChaosMode;
 
 fn default_vsync() -> bool {
     true
 }
 
+fn default_fixed_timestep() -> f32 {
+    crate::timing::DEFAULT_FIXED_TIMESTEP
+}
+
+fn default_sprite_soft_limit() -> usize {
+    renderer::SpriteLimits::DEFAULT_SOFT
+}
+
 /// Configuration for the game window and engine.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GameConfig {
@@ -30,6 +40,10 @@ pub struct GameConfig {
     /// mode the platform offers.
     #[serde(default = "default_vsync")]
     pub vsync: bool,
+    /// Output exposure, tonemap and display gamma. The default shows
+    /// colors exactly as authored.
+    #[serde(default)]
+    pub color: renderer::ColorConfig,
     /// Project-wide gameplay intensity theme. Each game decides what a given
     /// variant means; the engine just carries the selection.
     #[serde(default)]
@@ -44,12 +58,54 @@ pub struct GameConfig {
     /// default is `assets` relative to the current working directory.
     #[serde(default)]
     pub asset_base_path: Option<String>,
+    /// Asset mount points as `(virtual prefix, directory)`: `assets://<prefix>/...`
+    /// loads from the directory instead of the base path.
+    #[serde(default)]
+    pub asset_mounts: Vec<(String, String)>,
     /// Optional path to persist player input bindings (JSON). When set, the
     /// engine loads bindings from this file at startup (writing the defaults
     /// if it doesn't exist — the file is hand-editable) and saves on exit.
     /// When `None`, the default two-player bindings are used in memory only.
     #[serde(default)]
     pub input_settings_path: Option<String>,
+    /// Game sprites per frame above which the engine warns and flags
+    /// `RenderStats::soft_limit_exceeded` (the batcher keeps growing).
+    #[serde(default = "default_sprite_soft_limit")]
+    pub sprite_soft_limit: usize,
+    /// Game sprites per frame beyond which further sprites are dropped and
+    /// counted in `RenderStats::sprites_dropped`. `None` = unbounded.
+    #[serde(default)]
+    pub sprite_hard_limit: Option<usize>,
+    /// Seconds per fixed update — the rate at which `ctx.time.tick()`
+    /// advances (default 1/60).
+    #[serde(default = "default_fixed_timestep")]
+    pub fixed_timestep: f32,
+    /// Run the per-frame debug validation checks (`ctx.validation`).
+    /// `None` = on in debug builds, off in release.
+    #[serde(default)]
+    pub debug_validation: Option<bool>,
+    /// Record and render `ctx.debug_draw` shapes.
+    /// `None` = on in debug builds, off in release.
+    #[serde(default)]
+    pub debug_draw: Option<bool>,
+    /// Seconds a loaded texture may go unreferenced before it's unloaded
+    /// automatically (`AssetConfig::unused_texture_grace`). `None` = never.
+    #[serde(default)]
+    pub unused_texture_grace: Option<f32>,
+    /// Pixel art mode: textures load with nearest filtering and no mipmaps
+    /// unless loaded with an explicit `TextureLoadConfig`.
+    #[serde(default)]
+    pub pixel_art: bool,
+    /// Drive the mouse with a gamepad's right stick, for controller-only
+    /// navigation of UI menus (see `input::VirtualCursor`). `None` = off.
+    #[serde(default)]
+    pub virtual_cursor: Option<input::VirtualCursorConfig>,
+    /// Floating origin for large worlds: once the camera is this many world
+    /// units from the origin, the world shifts back around it (see
+    /// `ecs::shift_world_origin`) and rendering goes camera-relative.
+    /// `None` = off.
+    #[serde(default)]
+    pub floating_origin: Option<f32>,
 }
 
 impl Default for GameConfig {
@@ -62,10 +118,21 @@ impl Default for GameConfig {
             clear_color: [0.1, 0.1, 0.15, 1.0],
             resizable: true,
             vsync: true,
+            color: renderer::ColorConfig::default(),
             chaos_mode: ChaosMode::Normal,
             achievement_save_path: None,
             asset_base_path: None,
+            asset_mounts: Vec::new(),
             input_settings_path: None,
+            sprite_soft_limit: default_sprite_soft_limit(),
+            sprite_hard_limit: None,
+            fixed_timestep: default_fixed_timestep(),
+            debug_validation: None,
+            debug_draw: None,
+            unused_texture_grace: None,
+            pixel_art: false,
+            virtual_cursor: None,
+            floating_origin: None,
         }
     }
 }
@@ -104,6 +171,12 @@ impl GameConfig {
         self
     }
 
+    /// Set the output exposure, tonemap and gamma
+    pub fn with_color(mut self, color: renderer::ColorConfig) -> Self {
+        self.color = color;
+        self
+    }
+
     /// Set the starting chaos mode. Games that let the player pick at runtime
     /// typically leave this at the default and mutate their own field.
     pub fn with_chaos_mode(mut self, mode: ChaosMode) -> Self {
@@ -126,6 +199,61 @@ impl GameConfig {
         self
     }
 
+    /// Use `root`'s directory and mount points for asset loading, e.g.
+    /// `asset_root!()` so packaged builds and `cargo run` both find assets.
+    pub fn with_asset_root(mut self, root: &AssetRoot) -> Self {
+        self.asset_base_path = Some(root.directory().to_string_lossy().into_owned());
+        self.asset_mounts = root
+            .mounts()
+            .map(|(prefix, directory)| (prefix.to_string(), directory.to_string_lossy().into_owned()))
+            .collect();
+        self
+    }
+
+    /// Serve `assets://<prefix>/...` from `directory`.
+    pub fn with_asset_mount(mut self, prefix: impl Into<String>, directory: impl Into<String>) -> Self {
+        self.asset_mounts.push((prefix.into(), directory.into()));
+        self
+    }
+
+    /// Default every texture to nearest filtering without mipmaps, keeping
+    /// pixel art crisp at any scale.
+    pub fn with_pixel_art(mut self, enabled: bool) -> Self {
+        self.pixel_art = enabled;
+        self
+    }
+
+    /// Let a gamepad's right stick move the mouse cursor and a pad button
+    /// click, so menus built with the UI crate work without a mouse.
+    pub fn with_virtual_cursor(mut self, config: input::VirtualCursorConfig) -> Self {
+        self.virtual_cursor = Some(config);
+        self
+    }
+
+    /// Keep the world near the origin: shift everything back whenever the
+    /// camera strays `threshold` world units from it. The absolute offset
+    /// lives in the `ecs::WorldOrigin` resource.
+    pub fn with_floating_origin(mut self, threshold: f32) -> Self {
+        self.floating_origin = Some(threshold);
+        self
+    }
+
+    /// Asset manager configuration: `asset_base_path` when set, otherwise
+    /// the `AssetConfig` defaults, plus the mount points, with
+    /// nearest-filtered texture defaults in pixel art mode.
+    pub fn asset_config(&self) -> AssetConfig {
+        let mut config = AssetConfig::default();
+        if let Some(base_path) = &self.asset_base_path {
+            config.base_path = base_path.clone();
+        }
+        config.mounts = self.asset_mounts.clone();
+        if self.pixel_art {
+            config.texture_defaults = renderer::TextureLoadConfig::pixel_art();
+        }
+        config.unused_texture_grace = self.unused_texture_grace;
+        config
+    }
+
     /// Persist player input bindings to this JSON path (loaded at startup,
     /// defaults written if missing, saved on exit). Parent directories are
     /// created on first save.
@@ -133,6 +261,62 @@ impl GameConfig {
         self.input_settings_path = Some(path.into());
         self
     }
+
+    /// Set the per-frame game sprite budget: warn above `soft`, drop sprites
+    /// beyond `hard` (`None` = never drop). UI sprites are not limited.
+    pub fn with_sprite_limits(mut self, soft: usize, hard: Option<usize>) -> Self {
+        self.sprite_soft_limit = soft;
+        self.sprite_hard_limit = hard;
+        self
+    }
+
+    /// Set the fixed update rate in seconds per tick (e.g. `1.0 / 30.0`).
+    /// Match the physics timestep so collision events line up with ticks.
+    pub fn with_fixed_timestep(mut self, seconds: f32) -> Self {
+        self.fixed_timestep = seconds;
+        self
+    }
+
+    /// Force the debug validation layer on or off, overriding the
+    /// debug-build default.
+    pub fn with_debug_validation(mut self, enabled: bool) -> Self {
+        self.debug_validation = Some(enabled);
+        self
+    }
+
+    /// Whether the debug validation layer runs.
+    pub fn debug_validation_enabled(&self) -> bool {
+        self.debug_validation.unwrap_or(cfg!(debug_assertions))
+    }
+
+    /// Force `ctx.debug_draw` on or off, overriding the debug-build
+    /// default — e.g. to show AI paths in a release playtest build.
+    pub fn with_debug_draw(mut self, enabled: bool) -> Self {
+        self.debug_draw = Some(enabled);
+        self
+    }
+
+    /// Unload file textures no component references (and the game hasn't
+    /// `retain_texture`d) after `seconds` unused. Games that keep handles in
+    /// their own state must retain them first.
+    pub fn with_unused_texture_grace(mut self, seconds: f32) -> Self {
+        self.unused_texture_grace = Some(seconds);
+        self
+    }
+
+    /// Whether `ctx.debug_draw` starts enabled.
+    pub fn debug_draw_enabled(&self) -> bool {
+        self.debug_draw.unwrap_or(cfg!(debug_assertions))
+    }
+
+    /// The configured game sprite limits as the renderer type.
+    pub fn sprite_limits(&self) -> renderer::SpriteLimits {
+        let limits = renderer::SpriteLimits::soft(self.sprite_soft_limit);
+        match self.sprite_hard_limit {
+            Some(hard) => limits.with_hard(hard),
+            None => limits,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -157,12 +341,68 @@ mod tests {
         assert!(!config.vsync);
     }
 
+    #[test]
+    fn test_game_config_color_defaults_to_authored_colors() {
+        assert_eq!(GameConfig::default().color, renderer::ColorConfig::default());
+        let color = renderer::ColorConfig { exposure: 1.5, gamma: 1.1, tonemap: renderer::Tonemap::Reinhard };
+        assert_eq!(GameConfig::new("Test").with_color(color).color, color);
+    }
+
     #[test]
     fn test_game_config_with_chaos_mode() {
         let config = GameConfig::new("Test").with_chaos_mode(ChaosMode::Insiculous);
         assert_eq!(config.chaos_mode, ChaosMode::Insiculous);
     }
 
+    #[test]
+    fn test_game_config_sprite_limits_default_to_soft_only() {
+        let limits = GameConfig::default().sprite_limits();
+        assert_eq!(limits.soft, renderer::SpriteLimits::DEFAULT_SOFT);
+        assert_eq!(limits.hard, None);
+    }
+
+    #[test]
+    fn test_game_config_with_sprite_limits() {
+        let limits = GameConfig::new("Test").with_sprite_limits(500, Some(800)).sprite_limits();
+        assert_eq!(limits.soft, 500);
+        assert_eq!(limits.hard, Some(800));
+    }
+
+    #[test]
+    fn test_game_config_debug_validation_defaults_to_debug_builds() {
+        assert_eq!(GameConfig::default().debug_validation_enabled(), cfg!(debug_assertions));
+        assert!(!GameConfig::new("Test").with_debug_validation(false).debug_validation_enabled());
+        assert!(GameConfig::new("Test").with_debug_validation(true).debug_validation_enabled());
+    }
+
+    #[test]
+    fn test_game_config_pixel_art_defaults_textures_to_nearest() {
+        let smooth = GameConfig::default().asset_config().texture_defaults;
+        assert_eq!(smooth.sampler_config.mag_filter, renderer::wgpu::FilterMode::Linear);
+
+        let config = GameConfig::new("Test").with_pixel_art(true).with_asset_base_path("art");
+        let assets = config.asset_config();
+        assert_eq!(assets.base_path, "art");
+        assert_eq!(assets.texture_defaults.sampler_config.mag_filter, renderer::wgpu::FilterMode::Nearest);
+        assert_eq!(assets.texture_defaults.sampler_config.min_filter, renderer::wgpu::FilterMode::Nearest);
+        assert!(!assets.texture_defaults.generate_mipmaps);
+    }
+
+    #[test]
+    fn test_game_config_asset_root_carries_directory_and_mounts() {
+        let root = AssetRoot::new("game/assets").with_mount("dlc", "game/dlc");
+        let assets = GameConfig::new("Test").with_asset_root(&root).with_asset_mount("mods", "mods").asset_config();
+        assert_eq!(assets.base_path, "game/assets");
+        assert_eq!(assets.mounts, [("dlc".to_string(), "game/dlc".to_string()), ("mods".to_string(), "mods".to_string())]);
+    }
+
+    #[test]
+    fn test_game_config_virtual_cursor_is_opt_in() {
+        assert_eq!(GameConfig::default().virtual_cursor, None);
+        let config = GameConfig::new("Test").with_virtual_cursor(input::VirtualCursorConfig::default());
+        assert_eq!(config.virtual_cursor.map(|cursor| cursor.click_button), Some(input::GamepadButton::A));
+    }
+
     #[test]
     fn test_game_config_builder() {
         let config = GameConfig::new("Test Game")
@@ -3,7 +3,9 @@
 //! This module implements the Single Responsibility Principle by extracting
 //! game loop timing and lifecycle management from the overloaded GameRunner.
 
-use std::time::{Duration, Instant};
+use std::time::Duration;
+
+use common::time::Instant;
 
 /// Upper bound on a single frame's delta time, in seconds.
 ///
@@ -48,7 +50,10 @@ impl GameLoopManager {
     /// Sleep out the remainder of the current frame's budget.
     ///
     /// Call once per frame after update/render work. No-op when uncapped or
-    /// when the frame already used its full budget.
+    /// when the frame already used its full budget. Browsers can't block
+    /// the thread; the web loop skips animation frames with
+    /// [`frame_due`](Self::frame_due) instead.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn throttle(&self) {
         if let Some(min_frame_time) = self.min_frame_time {
             let elapsed = self.last_frame_time.elapsed();
@@ -58,6 +63,13 @@ impl GameLoopManager {
         }
     }
 
+    /// Whether the frame budget since the last [`update`](Self::update) has
+    /// run out (always, when uncapped): the web loop's frame cap, where
+    /// `requestAnimationFrame` fires at the display rate.
+    pub fn frame_due(&self) -> bool {
+        self.min_frame_time.is_none_or(|min_frame_time| self.last_frame_time.elapsed() >= min_frame_time)
+    }
+
     /// Update the game loop timing and return delta time
     ///
     /// The returned delta is clamped to [`MAX_DELTA_TIME`] so long stalls
@@ -183,6 +195,20 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_frame_due_waits_out_the_budget() {
+        let mut manager = GameLoopManager::new();
+        manager.set_target_fps(50); // 20ms frame budget
+        manager.update();
+        assert!(!manager.frame_due());
+        sleep(Duration::from_millis(25));
+        assert!(manager.frame_due());
+
+        manager.set_target_fps(0);
+        manager.update();
+        assert!(manager.frame_due(), "uncapped frames are always due");
+    }
+
     #[test]
     fn test_game_loop_manager_reset() {
         let mut manager = GameLoopManager::new();
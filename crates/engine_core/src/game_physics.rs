@@ -0,0 +1,202 @@
+//! Engine-owned physics for games written against the [`Game`](crate::Game)
+//! trait: `ctx.physics`.
+//!
+//! Games opt in once (`ctx.physics.enable(PhysicsConfig::platformer())` in
+//! `init`); the engine then steps the simulation after every `update()`
+//! with the scaled delta (so pausing `ctx.time` freezes it), and gameplay
+//! code queries it from the context instead of holding a `PhysicsSystem`:
+//!
+//! ```
+//! use engine_core::contexts::GameContext;
+//! use glam::Vec2;
+//!
+//! fn kick_what_is_ahead(ctx: &mut GameContext, eye: Vec2) {
+//!     if let Some((entity, _, _)) = ctx.physics.raycast(eye, Vec2::X, 300.0) {
+//!         ctx.physics.apply_impulse(entity, Vec2::new(0.0, 200.0));
+//!     }
+//!     for collision in ctx.physics.collisions() {
+//!         log::debug!("{:?}", collision.event);
+//!     }
+//! }
+//! ```
+//!
+//! Games that already own a `PhysicsSystem` keep working unchanged — the
+//! engine's stays disabled unless enabled.
+
+use glam::Vec2;
+
+use ecs::{EntityId, World};
+use physics::{CollisionData, PhysicsConfig, PhysicsSystem};
+
+/// The engine's optional physics simulation (see the module docs).
+#[derive(Default)]
+pub struct GamePhysics {
+    system: Option<PhysicsSystem>,
+    /// Collision events from the engine's last step
+    collisions: Vec<CollisionData>,
+    paused: bool,
+}
+
+impl GamePhysics {
+    /// Disabled physics; nothing is simulated until [`enable`](Self::enable).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start simulating with `config`, replacing any running simulation.
+    pub fn enable(&mut self, config: PhysicsConfig) {
+        self.install(PhysicsSystem::with_config(config));
+    }
+
+    /// Simulate with an already configured system (e.g. from a scene's
+    /// `PhysicsSettings::physics_system`), replacing any running one.
+    pub fn install(&mut self, system: PhysicsSystem) {
+        self.system = Some(system);
+        self.collisions.clear();
+    }
+
+    /// Stop simulating and drop all physics state.
+    pub fn disable(&mut self) {
+        self.system = None;
+        self.collisions.clear();
+    }
+
+    /// Whether a simulation is running.
+    pub fn is_enabled(&self) -> bool {
+        self.system.is_some()
+    }
+
+    /// Skip the engine's stepping while `paused` (queries keep working).
+    /// The editor pauses it outside play mode.
+    pub fn set_paused(&mut self, paused: bool) {
+        self.paused = paused;
+    }
+
+    /// Whether stepping is paused.
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// The underlying system, for everything the helpers don't cover.
+    pub fn system(&self) -> Option<&PhysicsSystem> {
+        self.system.as_ref()
+    }
+
+    /// Mutable access to the underlying system.
+    pub fn system_mut(&mut self) -> Option<&mut PhysicsSystem> {
+        self.system.as_mut()
+    }
+
+    /// First hit along a ray as `(entity, hit_point, distance)`, in pixels
+    /// (see [`PhysicsSystem::raycast`]). `None` while disabled.
+    pub fn raycast(&self, origin: Vec2, direction: Vec2, max_distance: f32) -> Option<(EntityId, Vec2, f32)> {
+        self.system.as_ref()?.raycast(origin, direction, max_distance)
+    }
+
+    /// Entities whose colliders overlap a circle of `radius` pixels at `center`.
+    pub fn overlap_circle(&self, center: Vec2, radius: f32) -> Vec<EntityId> {
+        self.system.as_ref().map_or_else(Vec::new, |system| system.overlap_circle(center, radius))
+    }
+
+    /// Entities whose colliders contain `point`.
+    pub fn overlap_point(&self, point: Vec2) -> Vec<EntityId> {
+        self.system.as_ref().map_or_else(Vec::new, |system| system.overlap_point(point))
+    }
+
+    /// Mass-aware momentum change on a live body. Bodies spawned this frame
+    /// aren't simulated yet and ignore it — start those with
+    /// [`set_velocity`](Self::set_velocity).
+    pub fn apply_impulse(&mut self, entity: EntityId, impulse: Vec2) {
+        if let Some(system) = &mut self.system {
+            system.physics_world_mut().apply_impulse(entity, impulse);
+        }
+    }
+
+    /// Set a body's velocity (safe on bodies spawned this frame).
+    pub fn set_velocity(&mut self, entity: EntityId, linear: Vec2, angular: f32) {
+        if let Some(system) = &mut self.system {
+            system.set_velocity(entity, linear, angular);
+        }
+    }
+
+    /// Collision events from the engine's last step.
+    pub fn collisions(&self) -> &[CollisionData] {
+        &self.collisions
+    }
+
+    /// Run exactly one fixed step even while paused (the editor's frame
+    /// stepping); see [`PhysicsSystem::step_once`].
+    pub fn step_once(&mut self, world: &mut World) {
+        let Some(system) = self.system.as_mut() else {
+            return;
+        };
+        system.step_once(world);
+        self.collisions = system.take_collision_events();
+    }
+
+    /// Advance the simulation (the engine calls this after `update()`).
+    pub(crate) fn step(&mut self, world: &mut World, delta_time: f32) {
+        if self.paused {
+            return;
+        }
+        let Some(system) = self.system.as_mut() else {
+            return;
+        };
+        ecs::System::update(system, world, delta_time);
+        self.collisions = system.take_collision_events();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use physics::{Collider, RigidBody};
+
+    #[test]
+    fn test_queries_follow_the_engine_step() {
+        let mut world = World::new();
+        let mut physics = GamePhysics::new();
+        let wall = world.create_entity();
+        world.add_component(&wall, common::Transform2D::new(Vec2::new(200.0, 0.0))).unwrap();
+        world.add_component(&wall, RigidBody::new_static()).unwrap();
+        world.add_component(&wall, Collider::box_collider(100.0, 100.0)).unwrap();
+
+        physics.step(&mut world, 1.0 / 60.0);
+        assert!(physics.raycast(Vec2::ZERO, Vec2::X, 500.0).is_none(), "disabled physics sees nothing");
+
+        physics.enable(PhysicsConfig::top_down());
+        physics.step(&mut world, 0.1);
+        let (hit, _, _) = physics.raycast(Vec2::ZERO, Vec2::X, 500.0).expect("ray should hit the wall");
+        assert_eq!(hit, wall);
+        assert_eq!(physics.overlap_point(Vec2::new(200.0, 0.0)), vec![wall]);
+        assert_eq!(physics.overlap_circle(Vec2::new(100.0, 0.0), 60.0), vec![wall]);
+    }
+
+    #[test]
+    fn test_paused_physics_does_not_move_bodies() {
+        let mut world = World::new();
+        let ball = world.create_entity();
+        world.add_component(&ball, common::Transform2D::new(Vec2::ZERO)).unwrap();
+        world.add_component(&ball, RigidBody::default()).unwrap();
+        world.add_component(&ball, Collider::circle_collider(5.0)).unwrap();
+
+        let mut physics = GamePhysics::new();
+        physics.enable(PhysicsConfig::platformer());
+        physics.set_paused(true);
+        for _ in 0..10 {
+            physics.step(&mut world, 1.0 / 60.0);
+        }
+        assert_eq!(world.get::<common::Transform2D>(ball).unwrap().position, Vec2::ZERO);
+
+        // Frame stepping runs one tick regardless
+        physics.step_once(&mut world);
+        assert_ne!(world.get::<common::Transform2D>(ball).unwrap().position, Vec2::ZERO);
+
+        physics.set_paused(false);
+        physics.set_velocity(ball, Vec2::new(120.0, 0.0), 0.0);
+        for _ in 0..10 {
+            physics.step(&mut world, 1.0 / 60.0);
+        }
+        assert!(world.get::<common::Transform2D>(ball).unwrap().position.x > 0.0);
+    }
+}
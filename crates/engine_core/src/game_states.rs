@@ -0,0 +1,428 @@
+//! Game-flow state stack (main menu → loading → playing → paused).
+//!
+//! [`GameStates`] (`ctx.states`) holds a stack of named states: `switch`
+//! replaces the top one, `push` suspends it under a new one (a pause menu
+//! over gameplay), `pop` resumes it, and `reset` unwinds everything back to
+//! a single state (Quit to Menu). A state may name a parent, so `"playing"`
+//! and `"game_over"` can share an `"in_game"` parent that stays entered —
+//! and runs its hooks once — while play switches between them.
+//!
+//! Registered [`GameState`]s carry `on_enter`/`on_exit` hooks and guards
+//! that can refuse a transition (no leaving `"loading"` before the assets
+//! are in). Entities tagged [`StateScoped`] are despawned, with their
+//! children, when their state exits. Unregistered names work as plain
+//! states with no hooks or parent.
+//!
+//! Requests are queued and applied by the engine after `init()` and after
+//! every `update()`, when the hooks can have the world to themselves.
+//!
+//! ```
+//! use ecs::World;
+//! use engine_core::game_states::{GameState, GameStates, StateScoped};
+//!
+//! let mut world = World::new();
+//! let mut states = GameStates::new();
+//! states.register(GameState::new("playing").with_parent("in_game"));
+//! states.register(GameState::new("paused").with_parent("in_game"));
+//!
+//! states.switch("playing");
+//! states.push("paused");
+//! states.apply(&mut world);
+//! assert_eq!(states.stack(), ["playing", "paused"]);
+//! assert!(states.is_current("in_game") && !states.is_current("playing"));
+//!
+//! let menu = world.spawn((StateScoped("paused"),)).id();
+//! states.pop();
+//! states.apply(&mut world);
+//! assert!(!world.entities().contains(&menu));
+//! ```
+
+use std::collections::HashMap;
+use std::fmt;
+
+use ecs::{EntityId, Single, World};
+
+/// Hook run when a state is entered or exited.
+type StateHook = Box<dyn FnMut(&mut World)>;
+/// Guard deciding whether a state may be entered or exited right now.
+type StateGuard = Box<dyn Fn(&World) -> bool>;
+
+/// Component: despawn this entity (and its children) when the named state
+/// exits — menus, level geometry, anything owned by one phase of the game.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StateScoped(pub &'static str);
+
+/// A registered state: its parent, hooks, and guards.
+pub struct GameState {
+    name: &'static str,
+    parent: Option<&'static str>,
+    on_enter: Option<StateHook>,
+    on_exit: Option<StateHook>,
+    enter_guard: Option<StateGuard>,
+    exit_guard: Option<StateGuard>,
+}
+
+impl GameState {
+    /// A state with no parent, hooks, or guards.
+    pub fn new(name: &'static str) -> Self {
+        Self { name, parent: None, on_enter: None, on_exit: None, enter_guard: None, exit_guard: None }
+    }
+
+    /// Nest this state under `parent`, which is entered first and exited
+    /// last, and stays entered across transitions between its children.
+    pub fn with_parent(mut self, parent: &'static str) -> Self {
+        self.parent = Some(parent);
+        self
+    }
+
+    /// Run `hook` when the state is entered (spawn its entities here).
+    pub fn on_enter(mut self, hook: impl FnMut(&mut World) + 'static) -> Self {
+        self.on_enter = Some(Box::new(hook));
+        self
+    }
+
+    /// Run `hook` when the state is exited, before its [`StateScoped`]
+    /// entities are despawned.
+    pub fn on_exit(mut self, hook: impl FnMut(&mut World) + 'static) -> Self {
+        self.on_exit = Some(Box::new(hook));
+        self
+    }
+
+    /// Refuse transitions that would enter this state while `guard` is false.
+    pub fn enter_guard(mut self, guard: impl Fn(&World) -> bool + 'static) -> Self {
+        self.enter_guard = Some(Box::new(guard));
+        self
+    }
+
+    /// Refuse transitions that would exit this state while `guard` is false.
+    pub fn exit_guard(mut self, guard: impl Fn(&World) -> bool + 'static) -> Self {
+        self.exit_guard = Some(Box::new(guard));
+        self
+    }
+
+    /// The state's name.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// The state's parent, if nested.
+    pub fn parent(&self) -> Option<&'static str> {
+        self.parent
+    }
+}
+
+impl fmt::Debug for GameState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GameState").field("name", &self.name).field("parent", &self.parent).finish_non_exhaustive()
+    }
+}
+
+/// A queued change to the state stack.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StateTransition {
+    /// Suspend the current state under a new one
+    Push(&'static str),
+    /// Leave the current state, resuming the one below
+    Pop,
+    /// Replace the current state
+    Switch(&'static str),
+    /// Leave every state, then enter this one
+    Reset(&'static str),
+}
+
+/// The game-flow state stack (see the module docs).
+#[derive(Debug, Default)]
+pub struct GameStates {
+    states: HashMap<&'static str, GameState>,
+    stack: Vec<&'static str>,
+    pending: Vec<StateTransition>,
+    changed: bool,
+}
+
+impl GameStates {
+    /// An empty stack with no registered states.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register (or replace) a state's parent, hooks, and guards.
+    pub fn register(&mut self, state: GameState) -> &mut Self {
+        self.states.insert(state.name, state);
+        self
+    }
+
+    /// The top of the stack, if any state is active.
+    pub fn current(&self) -> Option<&'static str> {
+        self.stack.last().copied()
+    }
+
+    /// Active states, bottom (suspended) to top (current).
+    pub fn stack(&self) -> &[&'static str] {
+        &self.stack
+    }
+
+    /// Whether `name` is the current state or one of its ancestors — gate
+    /// per-state gameplay on this (a pushed pause state stops `"playing"`).
+    pub fn is_current(&self, name: &str) -> bool {
+        self.current().is_some_and(|top| self.lineage(top).contains(&name))
+    }
+
+    /// Whether `name` is anywhere on the stack, suspended or not, or an
+    /// ancestor of a state that is.
+    pub fn is_active(&self, name: &str) -> bool {
+        self.active_set(&self.stack).contains(&name)
+    }
+
+    /// Whether the last [`apply`](Self::apply) changed the stack.
+    pub fn just_changed(&self) -> bool {
+        self.changed
+    }
+
+    /// Transitions waiting for the next [`apply`](Self::apply).
+    pub fn pending(&self) -> &[StateTransition] {
+        &self.pending
+    }
+
+    /// Queue entering `name` on top of the current state.
+    pub fn push(&mut self, name: &'static str) {
+        self.pending.push(StateTransition::Push(name));
+    }
+
+    /// Queue leaving the current state.
+    pub fn pop(&mut self) {
+        self.pending.push(StateTransition::Pop);
+    }
+
+    /// Queue replacing the current state with `name`.
+    pub fn switch(&mut self, name: &'static str) {
+        self.pending.push(StateTransition::Switch(name));
+    }
+
+    /// Queue leaving every state and entering `name`.
+    pub fn reset(&mut self, name: &'static str) {
+        self.pending.push(StateTransition::Reset(name));
+    }
+
+    /// Drop the stack and any queued transitions without running hooks or
+    /// despawning anything — for when the world was replaced wholesale
+    /// (the editor's Stop restores its pre-play snapshot).
+    pub fn clear(&mut self) {
+        self.stack.clear();
+        self.pending.clear();
+        self.changed = false;
+    }
+
+    /// Apply the queued transitions in order: check guards, run exit hooks
+    /// and despawn scoped entities (innermost state first), then run enter
+    /// hooks (outermost first). A refused or invalid transition is logged
+    /// and skipped. Returns whether the stack changed.
+    pub fn apply(&mut self, world: &mut World) -> bool {
+        self.changed = false;
+        for transition in std::mem::take(&mut self.pending) {
+            self.changed |= self.apply_one(transition, world);
+        }
+        self.changed
+    }
+
+    fn apply_one(&mut self, transition: StateTransition, world: &mut World) -> bool {
+        let mut next = self.stack.clone();
+        match transition {
+            StateTransition::Push(name) if self.stack.contains(&name) => {
+                log::warn!("Game state: can't push '{name}', it is already on the stack");
+                return false;
+            }
+            StateTransition::Push(name) => next.push(name),
+            StateTransition::Pop if next.pop().is_none() => return false,
+            StateTransition::Pop => {}
+            StateTransition::Switch(name) => {
+                next.pop();
+                next.push(name);
+            }
+            StateTransition::Reset(name) => next = vec![name],
+        }
+
+        let before = self.active_set(&self.stack);
+        let after = self.active_set(&next);
+        let exits: Vec<_> = before.iter().rev().copied().filter(|name| !after.contains(name)).collect();
+        let enters: Vec<_> = after.iter().copied().filter(|name| !before.contains(name)).collect();
+
+        let refused_exit = exits.iter().find(|name| self.guard_refuses(name, world, |s| &s.exit_guard));
+        let refused_enter = enters.iter().find(|name| self.guard_refuses(name, world, |s| &s.enter_guard));
+        if let Some(name) = refused_exit.or(refused_enter) {
+            log::info!("Game state: {transition:?} refused by the '{name}' guard");
+            return false;
+        }
+
+        for name in exits {
+            if let Some(hook) = self.states.get_mut(name).and_then(|state| state.on_exit.as_mut()) {
+                hook(world);
+            }
+            despawn_scoped(world, name);
+        }
+        for name in enters {
+            if let Some(hook) = self.states.get_mut(name).and_then(|state| state.on_enter.as_mut()) {
+                hook(world);
+            }
+        }
+        log::debug!("Game state: {:?} -> {:?}", self.stack, next);
+        self.stack = next;
+        true
+    }
+
+    fn guard_refuses(
+        &self,
+        name: &str,
+        world: &World,
+        guard: impl Fn(&GameState) -> &Option<StateGuard>,
+    ) -> bool {
+        self.states.get(name).and_then(|state| guard(state).as_ref()).is_some_and(|guard| !guard(world))
+    }
+
+    /// `name` and its ancestors, outermost first. Stops at a parent cycle.
+    fn lineage(&self, name: &'static str) -> Vec<&'static str> {
+        let mut chain = vec![name];
+        let mut current = name;
+        while let Some(parent) = self.states.get(current).and_then(|state| state.parent) {
+            if chain.contains(&parent) {
+                log::warn!("Game state: parent cycle through '{parent}'");
+                break;
+            }
+            chain.push(parent);
+            current = parent;
+        }
+        chain.reverse();
+        chain
+    }
+
+    /// Every state entered for `stack`, outermost first, without repeats.
+    fn active_set(&self, stack: &[&'static str]) -> Vec<&'static str> {
+        let mut active = Vec::new();
+        for &entry in stack {
+            for name in self.lineage(entry) {
+                if !active.contains(&name) {
+                    active.push(name);
+                }
+            }
+        }
+        active
+    }
+}
+
+/// Despawn every entity scoped to `state`, with its children — parked pool
+/// entities included, so they don't outlive their state.
+fn despawn_scoped(world: &mut World, state: &str) {
+    let scoped: Vec<EntityId> = world
+        .query_entities::<Single<StateScoped>>()
+        .into_iter()
+        .filter(|&entity| world.get::<StateScoped>(entity).is_some_and(|scope| scope.0 == state))
+        .collect();
+    for entity in scoped {
+        // Already gone when an ancestor was scoped to the same state
+        world.despawn_recursive(entity).ok();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    /// Records hook calls as `"+name"` / `"-name"`.
+    fn logged(log: &Rc<RefCell<Vec<String>>>, name: &'static str) -> GameState {
+        let (enter, exit) = (log.clone(), log.clone());
+        GameState::new(name)
+            .on_enter(move |_| enter.borrow_mut().push(format!("+{name}")))
+            .on_exit(move |_| exit.borrow_mut().push(format!("-{name}")))
+    }
+
+    #[test]
+    fn hierarchical_transitions_run_hooks_once_per_state() {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let mut states = GameStates::new();
+        states.register(logged(&log, "menu"));
+        states.register(logged(&log, "in_game"));
+        states.register(logged(&log, "playing").with_parent("in_game"));
+        states.register(logged(&log, "paused").with_parent("in_game"));
+        states.register(logged(&log, "game_over").with_parent("in_game"));
+        let mut world = World::new();
+
+        states.reset("menu");
+        states.switch("playing");
+        assert!(states.apply(&mut world));
+        assert_eq!(*log.borrow(), ["+menu", "-menu", "+in_game", "+playing"]);
+
+        // Pausing suspends play without exiting it; the shared parent stays
+        log.borrow_mut().clear();
+        states.push("paused");
+        states.apply(&mut world);
+        assert_eq!(*log.borrow(), ["+paused"]);
+        assert!(states.is_active("playing") && !states.is_current("playing"));
+        assert!(states.is_current("in_game"));
+
+        states.pop();
+        states.switch("game_over");
+        states.apply(&mut world);
+        assert_eq!(*log.borrow(), ["+paused", "-paused", "-playing", "+game_over"]);
+
+        // Quit to menu unwinds the whole stack, innermost first
+        log.borrow_mut().clear();
+        states.push("paused");
+        states.reset("menu");
+        states.apply(&mut world);
+        assert_eq!(*log.borrow(), ["+paused", "-paused", "-game_over", "-in_game", "+menu"]);
+        assert_eq!(states.stack(), ["menu"]);
+    }
+
+    #[test]
+    fn guards_refuse_transitions_and_scoped_entities_despawn() {
+        let mut states = GameStates::new();
+        states.register(GameState::new("loading").exit_guard(|world| world.resource::<u32>().is_some()));
+        let mut world = World::new();
+        states.switch("loading");
+        states.apply(&mut world);
+
+        let screen = world.spawn((StateScoped("loading"),)).id();
+        let spinner = world.create_entity();
+        ecs::WorldHierarchyExt::set_parent(&mut world, spinner, screen).ok();
+        let keeper = world.spawn((StateScoped("playing"),)).id();
+
+        // Assets not loaded yet: the guard holds the loading screen
+        states.switch("playing");
+        assert!(!states.apply(&mut world));
+        assert_eq!(states.current(), Some("loading"));
+        assert!(world.entities().contains(&screen));
+
+        world.insert_resource(1u32);
+        states.switch("playing");
+        assert!(states.apply(&mut world) && states.just_changed());
+        assert_eq!(states.current(), Some("playing"));
+        assert!(!world.entities().contains(&screen) && !world.entities().contains(&spinner));
+        assert!(world.entities().contains(&keeper));
+
+        // Pushing a state that is already on the stack is refused; popping
+        // the last state leaves the stack empty
+        states.push("playing");
+        states.pop();
+        states.pop();
+        states.apply(&mut world);
+        assert_eq!(states.current(), None);
+        assert!(!world.entities().contains(&keeper));
+    }
+
+    #[test]
+    fn scoped_pool_entities_despawn_whether_acquired_or_parked() {
+        let mut states = GameStates::new();
+        let mut world = World::new();
+        states.switch("level");
+        states.apply(&mut world);
+
+        let mut bullets = ecs::EntityPool::new(&mut world, 2, (StateScoped("level"),));
+        let fired = bullets.acquire(&mut world);
+        assert_eq!(world.active_entities(), vec![fired]);
+
+        states.switch("menu");
+        states.apply(&mut world);
+        assert_eq!(world.entity_count(), 0, "the parked bullet went with its state too");
+    }
+}
@@ -1,25 +1,56 @@
-//! Glyph texture cache for UI text rendering.
+//! UI atlas: glyphs, icons and a white point on shared atlas pages.
 //!
-//! Scans UI draw commands for text glyphs and creates one GPU texture per
-//! unique glyph bitmap, caching handles across frames so each glyph is only
-//! uploaded once.
+//! Scans UI draw commands for text glyphs and packs each unique glyph
+//! bitmap into atlas page textures, caching regions across frames so each
+//! glyph is only uploaded once. Icons registered with
+//! [`UIContext::register_icon_image`] are packed onto the same pages, and
+//! the first page holds a small white block that rects and other
+//! untextured UI shapes sample. A panel's shapes, text and icons then draw
+//! from one texture binding — one batch instead of one per texture.
+//!
+//! Each page is a [`TextureAtlasBuilder`] (packing and CPU-side pixels)
+//! mirrored into a GPU texture by writing its uploads after every pack.
+
+use renderer::{texture::TextureHandle, TextureAtlasBuilder};
+use ui::{DrawCommand, GlyphDrawData, IconImage, Rect, UIContext};
 
-use std::collections::HashMap;
+use crate::assets::{grayscale_to_rgba, AssetManager};
+use crate::contexts::{GlyphCacheKey, GlyphRegion, UiAtlasRegions};
 
-use renderer::texture::TextureHandle;
-use ui::{DrawCommand, GlyphDrawData};
+/// Width and height of one atlas page in pixels.
+const PAGE_SIZE: u32 = 1024;
+/// Pages allocated before the atlas is rebuilt from the current frame's
+/// glyphs (bounds GPU memory when many sizes/fonts churn through).
+const MAX_PAGES: usize = 4;
+/// Transparent border around each region so linear filtering never
+/// samples a neighbour.
+const GUTTER: u32 = 1;
+/// Side of the white block; shapes sample its centre, which bilinear
+/// filtering keeps pure white.
+const WHITE_SIZE: u32 = 4;
+/// Atlas name of the white block (glyph and icon names never collide:
+/// glyphs are keyed `glyph:…`, icons `icon:…`).
+const WHITE_NAME: &str = "white";
 
-use crate::assets::AssetManager;
-use crate::contexts::GlyphCacheKey;
+/// One atlas page: a texture and the packed pixels it mirrors.
+struct AtlasPage {
+    texture: TextureHandle,
+    atlas: TextureAtlasBuilder,
+}
 
-/// Caches one texture per unique glyph so text rendering reuses GPU
-/// textures across frames.
+/// The UI atlas: caches one region per unique glyph and registered icon
+/// so UI rendering reuses GPU texture space across frames.
 ///
-/// Cache keys are color-agnostic: glyph textures are grayscale alpha masks
+/// Cache keys are color-agnostic: glyph bitmaps are grayscale alpha masks
 /// and the color is applied at render time (see [`GlyphCacheKey`]).
 #[derive(Default)]
 pub struct GlyphTextureCache {
-    textures: HashMap<GlyphCacheKey, TextureHandle>,
+    pages: Vec<AtlasPage>,
+    regions: UiAtlasRegions,
+    /// Every icon image packed so far, re-packed after a rebuild
+    icons: Vec<IconImage>,
+    /// A rebuild moved the icons; the UI gets their new regions next frame
+    icons_moved: bool,
 }
 
 impl GlyphTextureCache {
@@ -28,70 +59,206 @@ impl GlyphTextureCache {
         Self::default()
     }
 
-    /// The cached glyph textures, keyed for lookup during UI rendering.
-    pub fn textures(&self) -> &HashMap<GlyphCacheKey, TextureHandle> {
-        &self.textures
+    /// The atlas regions, for lookup during UI rendering.
+    pub fn textures(&self) -> &UiAtlasRegions {
+        &self.regions
+    }
+
+    /// Pack the icon images `ui` queued since last frame and register
+    /// their atlas regions with it. Called at the start of each UI frame.
+    pub fn pack_icons(&mut self, ui: &mut UIContext, assets: &mut AssetManager) {
+        let images = ui.take_icon_images();
+        if images.is_empty() && !self.icons_moved {
+            return;
+        }
+        self.ensure_white(assets);
+        for image in images {
+            if self.insert(&icon_name(&image.name), image.width, image.height, &image.rgba, assets).is_none() {
+                log::warn!("Icon '{}' ({}x{}) does not fit in the UI atlas", image.name, image.width, image.height);
+                continue;
+            }
+            self.icons.retain(|icon| icon.name != image.name);
+            self.icons.push(image);
+        }
+        self.register_icons(ui);
+        self.icons_moved = false;
+        self.flush_uploads(assets);
     }
 
-    /// Create textures for any glyphs in `commands` that are not cached yet.
+    /// Pack any glyphs in `command_lists` (the UI's commands, the world
+    /// text's) that are not cached yet into the atlas.
     ///
     /// Called once per frame before rendering. Glyphs already in the cache
-    /// (including duplicates within the same command list) are skipped.
-    pub fn prepare(&mut self, commands: &[DrawCommand], assets: &mut AssetManager) {
-        let missing = self.uncached_glyphs(commands);
+    /// (including duplicates within and across the lists) are skipped.
+    /// When every page is full, the atlas is cleared and refilled with the
+    /// white block, the icons and just this frame's glyphs; the UI learns
+    /// the icons' new regions at the next [`pack_icons`](Self::pack_icons).
+    pub fn prepare(&mut self, command_lists: &[&[DrawCommand]], assets: &mut AssetManager) {
+        self.ensure_white(assets);
+        if !self.pack_missing(command_lists, assets) {
+            log::debug!("UI atlas full ({} pages); rebuilding from this frame's text", self.pages.len());
+            self.rebuild(assets);
+            if !self.pack_missing(command_lists, assets) {
+                log::warn!("This frame's text does not fit in the UI atlas; some glyphs will not draw");
+            }
+        }
+        self.flush_uploads(assets);
+    }
+
+    /// Clear every page and re-pack the white block and the icons.
+    fn rebuild(&mut self, assets: &mut AssetManager) {
+        self.regions = UiAtlasRegions::default();
+        for page in &mut self.pages {
+            page.atlas.clear();
+        }
+        self.ensure_white(assets);
+        let icons = std::mem::take(&mut self.icons);
+        for icon in &icons {
+            if self.insert(&icon_name(&icon.name), icon.width, icon.height, &icon.rgba, assets).is_none() {
+                log::warn!("Icon '{}' no longer fits in the UI atlas", icon.name);
+            }
+        }
+        self.icons = icons;
+        self.icons_moved = !self.icons.is_empty();
+    }
+
+    /// Pack the white block if the atlas doesn't have it (a fresh or
+    /// rebuilt atlas puts it first, on page 0).
+    fn ensure_white(&mut self, assets: &mut AssetManager) {
+        if self.regions.white.is_some() {
+            return;
+        }
+        let white = [255u8; (WHITE_SIZE * WHITE_SIZE * 4) as usize];
+        if let Some(region) = self.insert(WHITE_NAME, WHITE_SIZE, WHITE_SIZE, &white, assets) {
+            self.regions.white = Some(white_point(region));
+        }
+    }
+
+    /// Tell `ui` where every packed icon lives.
+    fn register_icons(&self, ui: &mut UIContext) {
+        for icon in &self.icons {
+            let Some(region) = self.region(&icon_name(&icon.name)) else { continue };
+            let [u, v, width, height] = region.uv;
+            ui.register_icon_region(icon.name.clone(), region.texture.id, Rect::new(u, v, width, height));
+        }
+    }
+
+    /// Region of a packed atlas name.
+    fn region(&self, name: &str) -> Option<GlyphRegion> {
+        self.pages.iter().find_map(|page| {
+            page.atlas.region(name).map(|uv| GlyphRegion { texture: page.texture, uv })
+        })
+    }
+
+    /// Pack this frame's uncached glyphs. Returns `false` if the atlas ran
+    /// out of space.
+    fn pack_missing(&mut self, command_lists: &[&[DrawCommand]], assets: &mut AssetManager) -> bool {
+        let missing = self.uncached_glyphs(command_lists);
         for (key, glyph) in missing {
             // Re-check: the same glyph can appear more than once per frame,
-            // and the first occurrence has already created the texture.
-            if self.textures.contains_key(&key) {
+            // and the first occurrence has already been packed.
+            if self.regions.glyphs.contains_key(&key) {
                 continue;
             }
+            if glyph.width + 2 * GUTTER > PAGE_SIZE || glyph.height + 2 * GUTTER > PAGE_SIZE {
+                log::warn!("Glyph '{}' ({}x{}) is larger than an atlas page", glyph.character, glyph.width, glyph.height);
+                continue;
+            }
+            let name = format!("glyph:{}:{}:{}x{}", glyph.font_id, glyph.character, glyph.width, glyph.height);
+            let rgba = grayscale_to_rgba(&glyph.bitmap);
+            let Some(region) = self.insert(&name, glyph.width, glyph.height, &rgba, assets) else {
+                return false;
+            };
+            self.regions.glyphs.insert(key, region);
+        }
+        true
+    }
 
-            // Create glyph texture (grayscale alpha mask)
-            match assets.create_glyph_texture(glyph.width, glyph.height, &glyph.bitmap) {
-                Ok(handle) => {
-                    self.textures.insert(key, handle);
-                }
-                Err(e) => {
-                    log::warn!("Failed to create glyph texture for '{}': {}", glyph.character, e);
+    /// Pack an RGBA image on the first page with room, opening a new page
+    /// when the existing ones are full. `None` when no page has room.
+    fn insert(&mut self, name: &str, width: u32, height: u32, rgba: &[u8], assets: &mut AssetManager) -> Option<GlyphRegion> {
+        let packed = self.pages.iter_mut().find_map(|page| {
+            page.atlas.insert(name, width, height, rgba).ok().map(|uv| GlyphRegion { texture: page.texture, uv })
+        });
+        if packed.is_some() || self.pages.len() >= MAX_PAGES {
+            return packed;
+        }
+        let texture = match assets.create_glyph_atlas_page(PAGE_SIZE) {
+            Ok(texture) => texture,
+            Err(e) => {
+                log::warn!("Failed to create UI atlas page: {}", e);
+                return None;
+            }
+        };
+        let mut atlas = TextureAtlasBuilder::new(PAGE_SIZE, PAGE_SIZE).with_padding(GUTTER);
+        let uv = atlas.insert(name, width, height, rgba).ok()?;
+        self.pages.push(AtlasPage { texture, atlas });
+        Some(GlyphRegion { texture, uv })
+    }
+
+    /// Write everything packed since the last flush to the page textures
+    /// (failures are logged; those regions draw blank).
+    fn flush_uploads(&mut self, assets: &mut AssetManager) {
+        for page in &mut self.pages {
+            for upload in page.atlas.take_uploads() {
+                if let Err(e) = assets.write_atlas_upload(page.texture, &upload) {
+                    log::warn!("Failed to upload {}x{} UI atlas region: {}", upload.width, upload.height, e);
                 }
             }
         }
     }
 
-    /// Collect glyphs from `commands` that have no cached texture yet,
+    /// Collect glyphs from `command_lists` that have no cached region yet,
     /// in command order. Duplicates are not removed here; `prepare` skips
-    /// them once the first occurrence has been created.
+    /// them once the first occurrence has been packed.
     fn uncached_glyphs<'a>(
         &self,
-        commands: &'a [DrawCommand],
+        command_lists: &[&'a [DrawCommand]],
     ) -> Vec<(GlyphCacheKey, &'a GlyphDrawData)> {
-        Self::renderable_glyphs(commands)
-            .filter(|(key, _)| !self.textures.contains_key(key))
+        Self::renderable_glyphs(command_lists)
+            .filter(|(key, _)| !self.regions.glyphs.contains_key(key))
             .collect()
     }
 
-    /// Iterate all glyphs in Text commands that need a texture to render
-    /// (skips empty glyphs such as spaces).
-    fn renderable_glyphs(
-        commands: &[DrawCommand],
-    ) -> impl Iterator<Item = (GlyphCacheKey, &GlyphDrawData)> {
-        commands
+    /// Iterate all glyphs in Text commands that need atlas space to render
+    /// (skips empty glyphs such as spaces and malformed bitmaps).
+    fn renderable_glyphs<'a, 'b>(
+        command_lists: &'b [&'a [DrawCommand]],
+    ) -> impl Iterator<Item = (GlyphCacheKey, &'a GlyphDrawData)> + 'b {
+        command_lists
             .iter()
+            .flat_map(|commands| commands.iter())
             .filter_map(|cmd| match cmd {
                 DrawCommand::Text { data, .. } => Some(data.glyphs.iter()),
                 _ => None,
             })
             .flatten()
-            .filter(|glyph| glyph.width > 0 && glyph.height > 0 && !glyph.bitmap.is_empty())
+            .filter(|glyph| {
+                glyph.width > 0
+                    && glyph.height > 0
+                    && glyph.bitmap.len() == (glyph.width * glyph.height) as usize
+            })
             .map(|glyph| {
                 (
-                    GlyphCacheKey::new(glyph.character, glyph.width, glyph.height),
+                    GlyphCacheKey::new(glyph.font_id, glyph.character, glyph.width, glyph.height),
                     glyph,
                 )
             })
     }
 }
 
+/// Atlas name of an icon.
+fn icon_name(name: &str) -> String {
+    format!("icon:{name}")
+}
+
+/// The centre of the white block as a zero-size UV region: every texel a
+/// shape samples is then pure white, with no gutter bleeding in.
+fn white_point(block: GlyphRegion) -> GlyphRegion {
+    let [u, v, width, height] = block.uv;
+    GlyphRegion { texture: block.texture, uv: [u + width * 0.5, v + height * 0.5, 0.0, 0.0] }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -101,6 +268,10 @@ mod tests {
     use ui::TextDrawData;
 
     fn glyph(character: char, width: u32, height: u32, bitmap: &[u8]) -> GlyphDrawData {
+        glyph_in_font(1, character, width, height, bitmap)
+    }
+
+    fn glyph_in_font(font_id: u32, character: char, width: u32, height: u32, bitmap: &[u8]) -> GlyphDrawData {
         GlyphDrawData {
             bitmap: Arc::from(bitmap),
             width,
@@ -108,6 +279,7 @@ mod tests {
             x: 0.0,
             y: 0.0,
             character,
+            font_id,
         }
     }
 
@@ -129,7 +301,8 @@ mod tests {
     #[test]
     fn fresh_cache_starts_empty() {
         let cache = GlyphTextureCache::new();
-        assert!(cache.textures().is_empty());
+        assert!(cache.textures().glyphs.is_empty());
+        assert!(cache.textures().white.is_none(), "no page until the first pack");
     }
 
     #[test]
@@ -149,7 +322,7 @@ mod tests {
             ]),
         ];
 
-        let missing = cache.uncached_glyphs(&commands);
+        let missing = cache.uncached_glyphs(&[&commands]);
         assert_eq!(missing.len(), 1);
         assert_eq!(missing[0].1.character, 'a');
     }
@@ -162,15 +335,14 @@ mod tests {
             glyph('b', 8, 8, &[255; 64]),
         ])];
 
-        // Simulate a previously created texture for 'a'.
-        cache
-            .textures
-            .insert(GlyphCacheKey::new('a', 4, 4), TextureHandle { id: 7 });
+        // Simulate a previously packed region for 'a'.
+        let region = GlyphRegion { texture: TextureHandle { id: 7 }, uv: [1.0 / 1024.0, 1.0 / 1024.0, 4.0 / 1024.0, 4.0 / 1024.0] };
+        cache.regions.glyphs.insert(GlyphCacheKey::new(1, 'a', 4, 4), region);
 
-        let missing = cache.uncached_glyphs(&commands);
+        let missing = cache.uncached_glyphs(&[&commands]);
         assert_eq!(missing.len(), 1, "only the uncached glyph should be missing");
         assert_eq!(missing[0].1.character, 'b');
-        assert_eq!(cache.textures().len(), 1);
+        assert_eq!(cache.textures().glyphs.len(), 1);
     }
 
     #[test]
@@ -181,7 +353,26 @@ mod tests {
             glyph('a', 8, 8, &[255; 64]),
         ])];
 
-        let missing = cache.uncached_glyphs(&commands);
+        let missing = cache.uncached_glyphs(&[&commands]);
         assert_eq!(missing.len(), 2, "each rasterized size is a distinct cache entry");
     }
+
+    #[test]
+    fn same_glyph_in_different_fonts_needs_separate_regions() {
+        let cache = GlyphTextureCache::new();
+        let commands = vec![text_command(vec![
+            glyph_in_font(1, 'a', 4, 4, &[255; 16]),
+            glyph_in_font(2, 'a', 4, 4, &[255; 16]),
+        ])];
+
+        assert_eq!(cache.uncached_glyphs(&[&commands]).len(), 2);
+    }
+
+    #[test]
+    fn white_point_is_the_centre_of_the_block() {
+        let block = GlyphRegion { texture: TextureHandle { id: 3 }, uv: [0.25, 0.5, 0.125, 0.25] };
+        let white = white_point(block);
+        assert_eq!(white.texture, block.texture);
+        assert_eq!(white.uv, [0.3125, 0.625, 0.0, 0.0]);
+    }
 }
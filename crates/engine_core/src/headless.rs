@@ -0,0 +1,333 @@
+//! Headless auto-play scripts for the example suite.
+//!
+//! A [`HeadlessScript`] describes a reproducible run: a fixed number of
+//! frames at a fixed delta, input events injected on given frames, and
+//! world checks that must hold. [`run_headless`](crate::run_headless)
+//! executes it without a window; [`run_example`](crate::run_example) picks
+//! between that and a normal windowed run based on the `--headless` flag
+//! (or `INSICULOUS_HEADLESS=1`), so every example doubles as a test.
+//!
+//! ```
+//! use engine_core::prelude::*;
+//!
+//! let script = HeadlessScript::new(120)
+//!     .hold(KeyCode::KeyD, 10, 60)
+//!     .tap(KeyCode::Space, 30)
+//!     .expect_final("player exists", |world| world.entity_count() > 0);
+//! assert_eq!(script.frames(), 120);
+//! ```
+
+use std::fmt;
+
+use common::Rect;
+use ecs::sprite_components::{Name, Transform2D};
+use ecs::{Component, EntityId, Pair, World};
+use glam::Vec2;
+use input::{InputEvent, KeyCode, MouseButton};
+use renderer::{RenderStats, RendererError};
+
+use crate::timing::DEFAULT_FIXED_TIMESTEP;
+use crate::validation::ValidationIssue;
+
+/// Command-line flag that switches [`run_example`](crate::run_example) to
+/// headless auto-play.
+pub const HEADLESS_FLAG: &str = "--headless";
+
+/// Environment variable that does the same as [`HEADLESS_FLAG`] when set to
+/// `1` or `true`.
+pub const HEADLESS_ENV: &str = "INSICULOUS_HEADLESS";
+
+/// Whether this process was asked to run headless.
+pub fn headless_requested() -> bool {
+    let env = std::env::var(HEADLESS_ENV).ok();
+    headless_requested_by(std::env::args(), env.as_deref())
+}
+
+/// [`headless_requested`] over explicit arguments and environment value.
+pub fn headless_requested_by(mut args: impl Iterator<Item = String>, env: Option<&str>) -> bool {
+    args.any(|arg| arg == HEADLESS_FLAG) || matches!(env, Some("1") | Some("true"))
+}
+
+/// The entity with the [`Name`] `name` (scene-file entities carry their
+/// `name` this way), for use in script checks.
+pub fn find_named(world: &World, name: &str) -> Option<EntityId> {
+    world.entity_ids().find(|&entity| has_name(world, entity, name))
+}
+
+/// How many entities carry the [`Name`] `name` (spawned copies share one).
+pub fn count_named(world: &World, name: &str) -> usize {
+    world.entity_ids().filter(|&entity| has_name(world, entity, name)).count()
+}
+
+/// World position of the entity named `name`, when it exists and has a
+/// [`Transform2D`].
+pub fn named_position(world: &World, name: &str) -> Option<Vec2> {
+    find_named(world, name)
+        .and_then(|entity| world.get::<Transform2D>(entity))
+        .map(|transform| transform.position)
+}
+
+/// Positions of every entity with both a [`Transform2D`] and a `C`, for
+/// "everything stayed in bounds" checks.
+pub fn positions_with<C: Component>(world: &World) -> impl Iterator<Item = (EntityId, Vec2)> + '_ {
+    world
+        .query_entities::<Pair<Transform2D, C>>()
+        .into_iter()
+        .filter_map(|entity| world.get::<Transform2D>(entity).map(|transform| (entity, transform.position)))
+}
+
+fn has_name(world: &World, entity: EntityId, name: &str) -> bool {
+    world.get::<Name>(entity).is_some_and(|n| n.as_str() == name)
+}
+
+/// A world check evaluated after a frame.
+struct HeadlessCheck {
+    /// Frame the check runs after (`None` = after the final frame)
+    frame: Option<u32>,
+    name: String,
+    check: Box<dyn Fn(&World) -> bool>,
+}
+
+/// Scripted input and assertions for a headless run.
+pub struct HeadlessScript {
+    frames: u32,
+    delta_time: f32,
+    /// (frame, event), kept in insertion order per frame
+    events: Vec<(u32, InputEvent)>,
+    checks: Vec<HeadlessCheck>,
+}
+
+impl HeadlessScript {
+    /// Run `frames` frames at [`DEFAULT_FIXED_TIMESTEP`] with no input.
+    pub fn new(frames: u32) -> Self {
+        Self { frames, delta_time: DEFAULT_FIXED_TIMESTEP, events: Vec::new(), checks: Vec::new() }
+    }
+
+    /// Use a different per-frame delta (seconds).
+    pub fn with_delta_time(mut self, delta_time: f32) -> Self {
+        self.delta_time = delta_time;
+        self
+    }
+
+    /// Inject `event` before `frame` runs.
+    pub fn event(mut self, frame: u32, event: InputEvent) -> Self {
+        self.events.push((frame, event));
+        self
+    }
+
+    /// Hold `key` down from frame `from` until it is released before `to`.
+    pub fn hold(self, key: KeyCode, from: u32, to: u32) -> Self {
+        self.event(from, InputEvent::KeyPressed(key)).event(to, InputEvent::KeyReleased(key))
+    }
+
+    /// Press `key` for exactly one frame.
+    pub fn tap(self, key: KeyCode, frame: u32) -> Self {
+        self.hold(key, frame, frame + 1)
+    }
+
+    /// Move the mouse to screen position `(x, y)` and left-click for one frame.
+    pub fn click(self, frame: u32, x: f32, y: f32) -> Self {
+        self.event(frame, InputEvent::MouseMoved(x, y))
+            .event(frame, InputEvent::MouseButtonPressed(MouseButton::Left))
+            .event(frame + 1, InputEvent::MouseButtonReleased(MouseButton::Left))
+    }
+
+    /// [`click`](Self::click) the center of screen-space `bounds` (a button).
+    pub fn click_center(self, frame: u32, bounds: Rect) -> Self {
+        let center = bounds.center();
+        self.click(frame, center.x, center.y)
+    }
+
+    /// Fail the run unless `check` holds after `frame`.
+    pub fn expect(mut self, frame: u32, name: impl Into<String>, check: impl Fn(&World) -> bool + 'static) -> Self {
+        self.checks.push(HeadlessCheck { frame: Some(frame), name: name.into(), check: Box::new(check) });
+        self
+    }
+
+    /// Fail the run unless an entity with each of `names` exists after
+    /// frame 0, i.e. the scene or `init` spawned them.
+    pub fn expect_spawned(self, names: &'static [&'static str]) -> Self {
+        let check = move |world: &World| names.iter().all(|name| find_named(world, name).is_some());
+        self.expect(0, format!("spawned {}", names.join(", ")), check)
+    }
+
+    /// Fail the run unless `check` holds after the last frame (including a
+    /// run the game ended early with `ctx.exit_requested`).
+    pub fn expect_final(mut self, name: impl Into<String>, check: impl Fn(&World) -> bool + 'static) -> Self {
+        self.checks.push(HeadlessCheck { frame: None, name: name.into(), check: Box::new(check) });
+        self
+    }
+
+    /// Number of frames to run.
+    pub fn frames(&self) -> u32 {
+        self.frames
+    }
+
+    /// Delta time fed to every frame.
+    pub fn delta_time(&self) -> f32 {
+        self.delta_time
+    }
+
+    /// Events to inject before `frame`, in the order they were added.
+    pub fn events_at(&self, frame: u32) -> impl Iterator<Item = &InputEvent> {
+        self.events.iter().filter(move |(f, _)| *f == frame).map(|(_, event)| event)
+    }
+
+    /// Name of the first check scheduled after `frame` that fails.
+    pub fn failed_check(&self, frame: u32, world: &World) -> Option<&str> {
+        self.first_failure(|check| check.frame == Some(frame), world)
+    }
+
+    /// Name of the first end-of-run check that fails.
+    pub fn failed_final_check(&self, world: &World) -> Option<&str> {
+        self.first_failure(|check| check.frame.is_none(), world)
+    }
+
+    fn first_failure(&self, scheduled: impl Fn(&HeadlessCheck) -> bool, world: &World) -> Option<&str> {
+        self.checks
+            .iter()
+            .filter(|check| scheduled(check))
+            .find(|check| !(check.check)(world))
+            .map(|check| check.name.as_str())
+    }
+}
+
+/// What a successful headless run did.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct HeadlessReport {
+    /// Frames actually run (fewer than scripted if the game exited early)
+    pub frames_run: u32,
+    /// Final simulation tick
+    pub final_tick: u64,
+    /// Live entities after the last frame
+    pub entity_count: usize,
+    /// Sprite statistics of the last frame
+    pub render_stats: RenderStats,
+    /// Debug-validation issues present after the last frame
+    pub validation_issues: Vec<ValidationIssue>,
+    /// Whether the game set `ctx.exit_requested`
+    pub exit_requested: bool,
+}
+
+impl fmt::Display for HeadlessReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "headless run: {} frames, tick {}, {} entities, {} sprites, {} validation issues",
+            self.frames_run,
+            self.final_tick,
+            self.entity_count,
+            self.render_stats.sprites,
+            self.validation_issues.len()
+        )?;
+        if self.exit_requested {
+            f.write_str(" (game exited)")?;
+        }
+        Ok(())
+    }
+}
+
+/// Why a headless run failed.
+#[derive(Debug, thiserror::Error)]
+pub enum HeadlessError {
+    /// No GPU device (hardware or software fallback) could be created.
+    #[error("no headless GPU device: {0}")]
+    Gpu(#[from] RendererError),
+
+    /// A scripted check did not hold. `frame` is `None` for end-of-run checks.
+    #[error("check '{name}' failed after frame {}", frame_label(.frame))]
+    CheckFailed { frame: Option<u32>, name: String },
+}
+
+/// `frame` for error messages; end-of-run checks read "(final)".
+fn frame_label(frame: &Option<u32>) -> String {
+    frame.map_or_else(|| "(final)".to_string(), |f| f.to_string())
+}
+
+impl HeadlessError {
+    /// Process exit code: 1 for a failed check, 2 when no GPU is available
+    /// (so CI can tell a broken example from a broken runner).
+    pub fn exit_code(&self) -> u8 {
+        match self {
+            Self::CheckFailed { .. } => 1,
+            Self::Gpu(_) => 2,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(list: &[&str]) -> impl Iterator<Item = String> {
+        list.iter().map(|s| s.to_string()).collect::<Vec<_>>().into_iter()
+    }
+
+    #[test]
+    fn headless_is_requested_by_flag_or_env() {
+        assert!(headless_requested_by(args(&["example", "--headless"]), None));
+        assert!(headless_requested_by(args(&["example"]), Some("1")));
+        assert!(!headless_requested_by(args(&["example"]), Some("0")));
+        assert!(!headless_requested_by(args(&["example"]), None));
+    }
+
+    #[test]
+    fn script_schedules_events_per_frame_in_order() {
+        let script = HeadlessScript::new(10).tap(KeyCode::Space, 2).click(2, 5.0, 6.0);
+        let at_two: Vec<&InputEvent> = script.events_at(2).collect();
+        assert_eq!(at_two.len(), 3);
+        assert!(matches!(at_two[0], InputEvent::KeyPressed(KeyCode::Space)));
+        assert!(matches!(at_two[1], InputEvent::MouseMoved(x, y) if *x == 5.0 && *y == 6.0));
+        assert_eq!(script.events_at(3).count(), 2, "key and button releases");
+        assert_eq!(script.events_at(4).count(), 0);
+    }
+
+    #[test]
+    fn checks_run_on_their_frame_and_report_the_first_failure() {
+        let mut world = World::new();
+        let script = HeadlessScript::new(5)
+            .expect(1, "has entities", |w| w.entity_count() > 0)
+            .expect_final("empty at end", |w| w.entity_count() == 0);
+
+        assert_eq!(script.failed_check(0, &world), None);
+        assert_eq!(script.failed_check(1, &world), Some("has entities"));
+        assert_eq!(script.failed_final_check(&world), None);
+
+        let player = world.spawn((Name::new("player"),)).id();
+        assert_eq!(script.failed_check(1, &world), None);
+        assert_eq!(script.failed_final_check(&world), Some("empty at end"));
+        assert_eq!(find_named(&world, "player"), Some(player));
+        assert_eq!(find_named(&world, "enemy"), None);
+    }
+
+    #[test]
+    fn named_helpers_count_and_locate_entities() {
+        let mut world = World::new();
+        let script = HeadlessScript::new(1).expect_spawned(&["player", "crate"]);
+        world.spawn((Name::new("player"), Transform2D::new(Vec2::new(3.0, 4.0))));
+        assert_eq!(script.failed_check(0, &world), Some("spawned player, crate"));
+
+        world.spawn((Name::new("crate"),));
+        world.spawn((Name::new("crate"), Transform2D::default()));
+        assert_eq!(script.failed_check(0, &world), None);
+        assert_eq!(count_named(&world, "crate"), 2);
+        assert_eq!(named_position(&world, "player"), Some(Vec2::new(3.0, 4.0)));
+        assert_eq!(named_position(&world, "ghost"), None);
+        assert_eq!(positions_with::<Name>(&world).count(), 2, "the unplaced crate is skipped");
+    }
+
+    #[test]
+    fn click_center_targets_the_middle_of_a_rect() {
+        let script = HeadlessScript::new(3).click_center(1, Rect::new(10.0, 20.0, 40.0, 10.0));
+        let first = script.events_at(1).next();
+        assert!(matches!(first, Some(InputEvent::MouseMoved(x, y)) if *x == 30.0 && *y == 25.0));
+    }
+
+    #[test]
+    fn failed_checks_and_missing_gpus_use_distinct_exit_codes() {
+        let check = HeadlessError::CheckFailed { frame: Some(3), name: "x".into() };
+        let gpu = HeadlessError::Gpu(RendererError::AdapterCreationError("none".into()));
+        assert_eq!((check.exit_code(), gpu.exit_code()), (1, 2));
+        assert_eq!(check.to_string(), "check 'x' failed after frame 3");
+    }
+}
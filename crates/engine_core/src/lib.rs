@@ -29,14 +29,30 @@ mod timing;
 mod scene;
 pub mod scene_manager;
 pub mod lifecycle;
+pub mod asset_refs;
 pub mod assets;
+pub mod asset_root;
+pub mod asset_loaders;
 pub mod chaos_theme;
 pub mod behavior_data;
 pub mod scene_data;
+pub mod physics_settings;
 pub mod scene_loader;
 pub mod scene_serializer;
+pub mod scene_saver;
 mod texture_ref;
+pub mod texture_import;
+pub mod sprite_atlases;
+pub mod scene_migration;
+#[cfg(feature = "physics")]
+pub mod scene_materials;
+#[cfg(feature = "physics")]
+pub mod scene_effectors;
+pub mod scene_tools;
+pub mod streaming;
 mod tilemap_render;
+mod trail_render;
+mod world_text_render;
 pub mod render_manager;
 pub mod window_manager;
 pub mod game_loop_manager;
@@ -48,33 +64,50 @@ pub mod contexts;
 pub mod ui_integration;
 pub mod chaos_mode;
 pub mod achievements;
+pub mod rng;
 pub mod menu_input;
 pub mod menu_panel;
 pub mod pause;
 pub mod spawn_helpers;
 #[cfg(feature = "physics")]
+pub mod game_physics;
+#[cfg(feature = "physics")]
+pub mod audio_occlusion;
+pub mod game_states;
+#[cfg(feature = "physics")]
 pub mod pickups;
 pub mod particles;
 pub mod grid;
 pub mod debug;
+pub mod extraction;
+pub mod plugin;
+pub mod profiling;
+pub mod validation;
+pub mod headless;
 
 pub mod prelude;
 
 // Re-export the public API surface explicitly (no globs) so the crate's
 // top-level names are visible at a glance.
 pub use behavior_runner::{BehaviorRunner, EntityCollected};
-pub use game::{run_game, Game};
-pub use timing::Timer;
+pub use game::{run_example, run_game, run_headless, Game};
+pub use timing::{FixedClock, Time, TimeScale, Timer, DEFAULT_FIXED_TIMESTEP};
 pub use scene::Scene;
 pub use scene_manager::SceneManager;
 pub use lifecycle::{Lifecycle, LifecycleManager, LifecycleState};
+pub use asset_refs::AssetMemoryUsage;
 pub use assets::{AssetConfig, AssetError, AssetManager};
+pub use asset_loaders::{AssetLoader, AssetLoaders};
+pub use plugin::{EngineBuilder, Plugin, Plugins};
+pub use profiling::FrameProfile;
 pub use scene_data::{
-    BehaviorData, ColliderShapeData, ComponentData, EditorSettings, EntityData, PhysicsSettings,
-    PrefabData, RigidBodyTypeData, SceneData, SceneLoadError,
+    BehaviorData, ColliderShapeData, CombineRuleData, ComponentData, EditorSettings, EntityData,
+    PhysicsMaterialData, PhysicsSettings, PrefabData, RigidBodyTypeData, SceneData, SceneLoadError,
 };
 pub use chaos_theme::ChaosTheme;
+pub use rng::Rng;
 pub use scene_loader::{SceneInstance, SceneLoader};
+pub use streaming::{ChunkCoord, ChunkedScene, StreamingSettings, WorldStreamer};
 pub use texture_ref::TextureResolver;
 
 /// The game's root directory for asset/save anchoring (exe dir when shipped
@@ -87,12 +120,23 @@ macro_rules! game_root {
         $crate::assets::game_root_from(env!("CARGO_MANIFEST_DIR"))
     };
 }
+
+/// The game's packaged asset root (`assets/` under [`game_root!`]); see
+/// [`asset_root::AssetRoot::packaged`]. A macro for the same reason.
+#[macro_export]
+macro_rules! asset_root {
+    () => {
+        $crate::asset_root::AssetRoot::packaged(env!("CARGO_MANIFEST_DIR"))
+    };
+}
 pub use scene_serializer::{save_scene_to_file, serialize_to_ron, world_to_scene_data};
+pub use scene_saver::{SaveHandle, SceneSaver};
 pub use render_manager::{main_camera_position, RenderManager};
 pub use window_manager::{WindowConfig, WindowManager};
 pub use game_loop_manager::{GameLoopManager, MAX_DELTA_TIME};
 pub use ui_manager::UIManager;
 pub use game_config::GameConfig;
+pub use game_states::{GameState, GameStates, StateScoped, StateTransition};
 pub use menu_panel::{MenuPanel, MenuStyle};
 pub use pause::{PauseAction, PauseMenu};
 pub use input_settings_io::{load_or_create as load_input_settings, save as save_input_settings, InputSettingsError};
@@ -102,10 +146,16 @@ pub use achievements::{
 };
 pub use menu_input::MenuInput;
 pub use spawn_helpers::spawn_background;
+pub use texture_import::{TextureImportSettings, TextureSizes, DEFAULT_PIXELS_PER_UNIT};
+pub use validation::{validate_components, DebugValidator, ValidationIssue};
+pub use extraction::{ExtractContext, ExtractTransform, SpriteExtractors};
+pub use headless::{count_named, find_named, named_position, positions_with, HeadlessError, HeadlessReport, HeadlessScript};
 
-/// Pixels per world unit: the renderer draws a sprite at
-/// `Transform2D.scale × RENDER_UNIT` pixels. Physics colliders are sized in
-/// absolute pixels and IGNORE `Transform2D.scale`, so games size both from
+/// World size of a generated solid-color texture (`#white`, `#solid:`) at
+/// scale 1: such sprites draw at `Transform2D.scale × RENDER_UNIT` pixels.
+/// Imported textures are instead sized by pixels-per-unit (see
+/// [`texture_import`]). Physics colliders are sized in absolute pixels and
+/// IGNORE `Transform2D.scale`, so games size color blocks and colliders from
 /// the same pixel constants divided/multiplied by this.
 pub const RENDER_UNIT: f32 = 80.0;
 
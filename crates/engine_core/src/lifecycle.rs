@@ -258,7 +258,7 @@ impl LifecycleManager {
 
     /// Wait until the system reaches a specific state (with timeout)
     pub fn wait_for_state(&self, target: LifecycleState, timeout: std::time::Duration) -> Result<(), String> {
-        let start = std::time::Instant::now();
+        let start = common::time::Instant::now();
         
         while self.current_state() != target {
             if start.elapsed() > timeout {
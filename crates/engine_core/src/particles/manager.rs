@@ -135,6 +135,13 @@ impl ParticleManager {
         }
     }
 
+    /// Move every alive particle by `offset` (a floating-origin shift).
+    pub fn translate(&mut self, offset: Vec2) {
+        for p in self.pool.iter_mut().filter(|p| p.alive) {
+            p.position += offset;
+        }
+    }
+
     /// Iterate alive particles in pool order.
     pub fn iter_alive(&self) -> impl Iterator<Item = &Particle> {
         self.pool.iter().filter(|p| p.alive)
@@ -8,9 +8,10 @@
 //! Each frame [`ParticleSystem::update`] advances every alive particle and
 //! drives any emitter components.
 //!
-//! Particles render via the existing sprite pipeline — they are appended to
-//! the `SpriteBatcher` in `Game::render()` and pick up bloom for free when
-//! their `emissive` value is nonzero.
+//! Particles render via the existing sprite pipeline — the built-in
+//! `particles` sprite extractor (see [`crate::extraction`]) appends them to
+//! the game batcher, and they pick up bloom for free when their `emissive`
+//! value is nonzero.
 
 mod emitter;
 mod manager;
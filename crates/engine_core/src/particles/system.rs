@@ -31,7 +31,7 @@ impl ParticleSystem {
         // reusable per-manager scratch buffer.
         let mut emit_queue: Vec<EmitCommand> = Vec::new();
 
-        for entity in world.entities() {
+        for entity in world.active_entities() {
             let position = match world.get::<Transform2D>(entity) {
                 Some(t) => t.position,
                 None => continue,
@@ -23,7 +23,7 @@
 //!     PauseAction::Resumed => { /* skip this frame; unfreeze next */ }
 //!     PauseAction::Idle => {}
 //! }
-//! // ctx.time_scale = pause.time_scale();   // freezes engine particles
+//! // pause.sync_time(&mut ctx.time);   // freezes ctx.delta_time, ticks, particles
 //! if pause.is_active() { /* skip gameplay; draw the overlay in the UI pass */ }
 //! ```
 
@@ -33,6 +33,7 @@ use ui::UIContext;
 
 use crate::menu_input::MenuInput;
 use crate::menu_panel::{MenuPanel, MenuStyle};
+use crate::timing::Time;
 
 /// What the pause menu decided this frame.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -73,12 +74,22 @@ impl PauseMenu {
         self.active
     }
 
-    /// `0.0` while paused, `1.0` otherwise — assign to `ctx.time_scale`
-    /// every frame so engine-side particles freeze with the game.
+    /// `0.0` while paused, `1.0` otherwise.
     pub fn time_scale(&self) -> f32 {
         if self.active { 0.0 } else { 1.0 }
     }
 
+    /// Pause or resume `time` to match the menu. Call every frame with
+    /// `ctx.time` so the simulation (scaled delta, fixed ticks, engine
+    /// particles) freezes from the frame after the menu opens.
+    pub fn sync_time(&self, time: &mut Time) {
+        if self.active {
+            time.pause();
+        } else {
+            time.resume();
+        }
+    }
+
     /// Advance the pause state machine one frame.
     ///
     /// Not paused: a Menu edge (Escape / any pad's Start, either player)
@@ -262,18 +273,23 @@ mod tests {
     }
 
     #[test]
-    fn time_scale_is_zero_only_while_paused() {
+    fn time_scale_and_synced_time_freeze_only_while_paused() {
         let (mut pause, settings, mut input) = setup();
         assert_eq!(pause.time_scale(), 1.0);
 
         frame(&mut input, &[InputEvent::KeyPressed(KeyCode::Escape)]);
         pause.update(&settings, &input);
         assert_eq!(pause.time_scale(), 0.0);
+        let mut time = Time::default();
+        pause.sync_time(&mut time);
+        assert!(time.is_paused());
 
         frame(&mut input, &[InputEvent::KeyReleased(KeyCode::Escape)]);
         pause.update(&settings, &input);
         frame(&mut input, &[InputEvent::KeyPressed(KeyCode::Escape)]);
         pause.update(&settings, &input);
         assert_eq!(pause.time_scale(), 1.0);
+        pause.sync_time(&mut time);
+        assert!(!time.is_paused());
     }
 }
@@ -0,0 +1,192 @@
+//! Per-scene physics settings.
+//!
+//! Part of the scene schema (`SceneData::physics`). Kept in its own module
+//! for file-size reasons; `scene_data.rs` re-exports it, so
+//! `scene_data::PhysicsSettings` remains the canonical import path.
+//!
+//! Gravity, scale and timestep are always written. Solver iterations,
+//! substeps, CCD substeps and interpolation are optional overrides: a scene
+//! that leaves them out keeps whatever the game's base
+//! [`PhysicsConfig`](physics::PhysicsConfig) says, so fast-moving scenes can
+//! trade cost for accuracy without a rebuild.
+//!
+//! The editor edits these in its Scene Settings panel and applies them to
+//! the engine's physics (`ctx.physics`) when play starts; games loading a
+//! scene themselves do the same with
+//! [`GamePhysics::apply_scene_settings`](crate::game_physics::GamePhysics::apply_scene_settings)
+//! or [`SceneInstance::physics_system`](crate::scene_loader::SceneInstance::physics_system).
+
+use serde::{Deserialize, Serialize};
+
+/// Physics world settings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PhysicsSettings {
+    /// Gravity vector (default: (0.0, -980.0) for platformer)
+    #[serde(default = "default_gravity")]
+    pub gravity: (f32, f32),
+    /// Pixels per meter scale (default: 100.0)
+    #[serde(default = "default_pixels_per_meter")]
+    pub pixels_per_meter: f32,
+    /// Physics timestep in seconds (default: 1/60)
+    #[serde(default = "default_timestep")]
+    pub timestep: f32,
+    /// Constraint solver iterations (default: the base config's)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub solver_iterations: Option<usize>,
+    /// Simulation substeps per fixed step (default: the base config's)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub substeps: Option<usize>,
+    /// Maximum CCD substeps per step (default: the base config's)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ccd_substeps: Option<usize>,
+    /// Interpolate transforms between fixed steps (default: the base config's)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub interpolate: Option<bool>,
+}
+
+fn default_gravity() -> (f32, f32) {
+    (0.0, -980.0)
+}
+
+fn default_pixels_per_meter() -> f32 {
+    100.0
+}
+
+fn default_timestep() -> f32 {
+    1.0 / 60.0
+}
+
+impl Default for PhysicsSettings {
+    fn default() -> Self {
+        Self {
+            gravity: default_gravity(),
+            pixels_per_meter: default_pixels_per_meter(),
+            timestep: default_timestep(),
+            solver_iterations: None,
+            substeps: None,
+            ccd_substeps: None,
+            interpolate: None,
+        }
+    }
+}
+
+#[cfg(feature = "physics")]
+impl PhysicsSettings {
+    /// `base` with this scene's gravity and scale, plus any solver, substep
+    /// and interpolation overrides the scene sets.
+    pub fn apply_to(&self, base: physics::PhysicsConfig) -> physics::PhysicsConfig {
+        let mut config = base
+            .with_gravity(glam::Vec2::new(self.gravity.0, self.gravity.1))
+            .with_scale(self.pixels_per_meter);
+        if let Some(solver_iterations) = self.solver_iterations {
+            config.solver_iterations = solver_iterations;
+        }
+        if let Some(substeps) = self.substeps {
+            config.substeps = substeps;
+        }
+        if let Some(ccd_substeps) = self.ccd_substeps {
+            config.ccd_substeps = ccd_substeps;
+        }
+        if let Some(interpolate) = self.interpolate {
+            config.interpolate = interpolate;
+        }
+        config
+    }
+
+    /// A physics system for these settings on top of `base`, stepping at
+    /// the scene's timestep (ignored unless finite and positive).
+    pub fn physics_system(&self, base: physics::PhysicsConfig) -> physics::PhysicsSystem {
+        let system = physics::PhysicsSystem::with_config(self.apply_to(base));
+        match self.valid_timestep() {
+            Some(timestep) => system.with_fixed_timestep(timestep),
+            None => system,
+        }
+    }
+
+    /// The scene's timestep, unless it is not finite and positive.
+    fn valid_timestep(&self) -> Option<f32> {
+        if self.timestep.is_finite() && self.timestep > 0.0 {
+            Some(self.timestep)
+        } else {
+            log::warn!("Invalid physics timestep ({}); keeping the default", self.timestep);
+            None
+        }
+    }
+}
+
+#[cfg(feature = "physics")]
+impl crate::game_physics::GamePhysics {
+    /// Reconfigure the running simulation for a scene: its current config
+    /// with `settings` applied, stepping at the scene's timestep. Bodies
+    /// re-register from the ECS on the next step; step hooks are kept.
+    /// Does nothing while physics is disabled.
+    pub fn apply_scene_settings(&mut self, settings: &PhysicsSettings) {
+        let Some(system) = self.system_mut() else {
+            return;
+        };
+        system.set_config(settings.apply_to(system.physics_world().config().clone()));
+        if let Some(timestep) = settings.valid_timestep() {
+            system.set_fixed_timestep(timestep);
+        }
+    }
+}
+
+#[cfg(feature = "physics")]
+impl crate::scene_loader::SceneInstance {
+    /// The physics system this scene asks for: `base` with the scene's
+    /// physics settings applied, or plain `base` when it has none.
+    pub fn physics_system(&self, base: physics::PhysicsConfig) -> physics::PhysicsSystem {
+        match &self.physics {
+            Some(settings) => settings.physics_system(base),
+            None => physics::PhysicsSystem::with_config(base),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "physics"))]
+mod tests {
+    use super::*;
+    use physics::PhysicsConfig;
+
+    #[test]
+    fn test_settings_without_overrides_keep_the_base_config() {
+        let settings: PhysicsSettings =
+            ron::from_str("(gravity: (0.0, -500.0), pixels_per_meter: 50.0)").unwrap();
+        assert_eq!(settings.substeps, None);
+
+        let base = PhysicsConfig::default().with_substeps(3, 2).with_interpolation(true);
+        let config = settings.apply_to(base);
+        assert_eq!(config.gravity, glam::Vec2::new(0.0, -500.0));
+        assert_eq!(config.pixels_per_meter, 50.0);
+        assert_eq!((config.substeps, config.ccd_substeps, config.interpolate), (3, 2, true));
+    }
+
+    #[test]
+    fn test_scene_overrides_replace_substeps_and_interpolation() {
+        let settings: PhysicsSettings = ron::from_str(
+            "(solver_iterations: Some(6), substeps: Some(4), ccd_substeps: Some(8), interpolate: Some(true))",
+        )
+        .unwrap();
+        let config = settings.apply_to(PhysicsConfig::default());
+        assert_eq!(config.solver_iterations, 6);
+        assert_eq!((config.substeps, config.ccd_substeps, config.interpolate), (4, 8, true));
+
+        let written = ron::to_string(&PhysicsSettings::default()).unwrap();
+        assert!(!written.contains("substeps"), "unset overrides are not written: {written}");
+    }
+
+    #[test]
+    fn test_scene_settings_reconfigure_the_engine_physics() {
+        let mut game_physics = crate::game_physics::GamePhysics::new();
+        let settings = PhysicsSettings { gravity: (0.0, -200.0), solver_iterations: Some(3), ..Default::default() };
+        game_physics.apply_scene_settings(&settings);
+        assert!(!game_physics.is_enabled(), "disabled physics stays disabled");
+
+        game_physics.enable(PhysicsConfig::default().with_interpolation(true));
+        game_physics.apply_scene_settings(&settings);
+        let config = game_physics.system().unwrap().physics_world().config().clone();
+        assert_eq!(config.gravity, glam::Vec2::new(0.0, -200.0));
+        assert_eq!(config.solver_iterations, 3);
+        assert!(config.interpolate, "the game's own config is the base");
+    }
+}
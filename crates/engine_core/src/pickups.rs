@@ -187,14 +187,14 @@ mod tests {
 
     fn started_event(a: EntityId, b: EntityId) -> CollisionData {
         CollisionData {
-            event: CollisionEvent { entity_a: a, entity_b: b, started: true, stopped: false },
+            event: CollisionEvent { entity_a: a, entity_b: b, started: true, stopped: false, tick: 0 },
             contacts: vec![],
         }
     }
 
     fn stopped_event(a: EntityId, b: EntityId) -> CollisionData {
         CollisionData {
-            event: CollisionEvent { entity_a: a, entity_b: b, started: false, stopped: true },
+            event: CollisionEvent { entity_a: a, entity_b: b, started: false, stopped: true, tick: 0 },
             contacts: vec![],
         }
     }
@@ -359,21 +359,21 @@ mod tests {
         let mut physics = PhysicsSystem::with_config(PhysicsConfig::top_down());
 
         let paddle = world
-            .spawn()
-            .with(Transform2D::new(Vec2::new(0.0, 0.0)))
-            .with(RigidBody::new_kinematic())
-            .with(Collider::box_collider(110.0, 16.0).with_friction(0.0))
+            .spawn((
+                Transform2D::new(Vec2::new(0.0, 0.0)),
+                RigidBody::new_kinematic(),
+                Collider::box_collider(110.0, 16.0).with_friction(0.0),
+            ))
             .id();
 
         let pickup_entity = world
-            .spawn()
-            .with(Transform2D::new(Vec2::new(0.0, 60.0)))
-            .with(
+            .spawn((
+                Transform2D::new(Vec2::new(0.0, 60.0)),
                 RigidBody::new_dynamic()
                     .with_gravity_scale(0.0)
                     .with_rotation_locked(true),
-            )
-            .with(Collider::box_collider(18.0, 18.0).as_sensor())
+                Collider::box_collider(18.0, 18.0).as_sensor(),
+            ))
             .id();
         physics.set_velocity(pickup_entity, Vec2::new(0.0, -180.0), 0.0);
 
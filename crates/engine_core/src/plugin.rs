@@ -0,0 +1,254 @@
+//! Plugins: extend the engine without modifying engine crates.
+//!
+//! A [`Plugin`] receives the [`EngineBuilder`] before the game starts and
+//! registers what it adds — ECS systems (run every frame after
+//! `Game::update`), scene-loadable components, custom asset loaders, sprite
+//! extractors, and typed extension data for other layers (the editor reads
+//! its plugin panels from an extension). Plugins can add other plugins.
+//!
+//! ```no_run
+//! use engine_core::prelude::*;
+//!
+//! /// Burns down fuse timers every frame.
+//! struct FusePlugin;
+//!
+//! impl Plugin for FusePlugin {
+//!     fn build(&self, app: &mut EngineBuilder) {
+//!         app.add_system(ecs::SimpleSystem::new("fuses", |_world: &mut World, _dt: f32| {
+//!             // burn fuses
+//!         }));
+//!     }
+//! }
+//!
+//! struct MyGame;
+//! impl Game for MyGame {
+//!     fn update(&mut self, ctx: &mut GameContext) {
+//!         assert!(ctx.plugins.contains("FusePlugin"));
+//!     }
+//! }
+//!
+//! fn main() {
+//!     EngineBuilder::new(GameConfig::default())
+//!         .with_plugin(FusePlugin)
+//!         .run(MyGame)
+//!         .unwrap();
+//! }
+//! ```
+
+use std::process::ExitCode;
+
+use ecs::component_registry::{register_global_component, ComponentMeta};
+use ecs::validation::{register_global_validator, Validate};
+use ecs::{ResourceStorage, System, SystemRegistry, World};
+
+use crate::asset_loaders::{AssetLoader, AssetLoaders};
+use crate::extraction::SpriteExtractors;
+use crate::game::Game;
+use crate::game_config::GameConfig;
+use crate::headless::{HeadlessError, HeadlessReport, HeadlessScript};
+
+/// A bundle of engine extensions, registered in [`Plugin::build`].
+pub trait Plugin: 'static {
+    /// Register this plugin's systems, components, loaders and extensions.
+    fn build(&self, app: &mut EngineBuilder);
+
+    /// Unique name; a plugin whose name is already registered is skipped.
+    /// Defaults to the type name without its module path.
+    fn name(&self) -> &str {
+        let full = std::any::type_name::<Self>();
+        full.rsplit("::").next().unwrap_or(full)
+    }
+}
+
+/// What the plugins registered, kept by the running game. Exposed as
+/// `ctx.plugins` for the names and typed extension data.
+#[derive(Default)]
+pub struct Plugins {
+    names: Vec<String>,
+    systems: SystemRegistry,
+    asset_loaders: AssetLoaders,
+    extensions: ResourceStorage,
+}
+
+impl Plugins {
+    /// Names of the added plugins, in build order.
+    pub fn names(&self) -> &[String] {
+        &self.names
+    }
+
+    /// Whether a plugin with `name` was added.
+    pub fn contains(&self, name: &str) -> bool {
+        self.names.iter().any(|n| n == name)
+    }
+
+    /// Number of plugin-registered systems.
+    pub fn system_count(&self) -> usize {
+        self.systems.len()
+    }
+
+    /// Shared extension data of type `T`, if a plugin inserted it.
+    pub fn extension<T: Send + Sync + 'static>(&self) -> Option<&T> {
+        self.extensions.get::<T>()
+    }
+
+    /// Mutable extension data of type `T`.
+    pub fn extension_mut<T: Send + Sync + 'static>(&mut self) -> Option<&mut T> {
+        self.extensions.get_mut::<T>()
+    }
+
+    /// Remove and return extension data (for consumers that take ownership
+    /// once, like the editor's plugin panels).
+    pub fn take_extension<T: Send + Sync + 'static>(&mut self) -> Option<T> {
+        self.extensions.remove::<T>()
+    }
+
+    /// Update times of the plugin systems in the last frame.
+    pub(crate) fn system_timings(&self) -> &[ecs::SystemTiming] {
+        self.systems.last_timings()
+    }
+
+    /// Hand the registered loaders to the asset manager (once it exists).
+    pub(crate) fn take_asset_loaders(&mut self) -> AssetLoaders {
+        std::mem::take(&mut self.asset_loaders)
+    }
+
+    /// Initialize and start the plugin systems. Failures are logged; the
+    /// systems then stay stopped and never update.
+    pub(crate) fn start_systems(&mut self, world: &mut World) {
+        if self.systems.is_empty() {
+            return;
+        }
+        if let Err(e) = self.systems.initialize(world).and_then(|()| self.systems.start(world)) {
+            log::error!("Plugin systems failed to start: {e}");
+        }
+    }
+
+    /// Run one frame of the plugin systems.
+    pub(crate) fn update_systems(&mut self, world: &mut World, delta_time: f32) {
+        if self.systems.is_running() {
+            self.systems.update_all(world, delta_time);
+        }
+    }
+
+    /// Stop and shut down the plugin systems (best-effort).
+    pub(crate) fn shutdown_systems(&mut self, world: &mut World) {
+        if let Err(e) = self.systems.shutdown(world) {
+            log::error!("Plugin systems failed to shut down: {e}");
+        }
+    }
+}
+
+/// Configures an engine run: the [`GameConfig`] plus everything the
+/// [`Plugin`]s register. Finish with [`run`](Self::run) (windowed),
+/// [`run_headless`](Self::run_headless) or [`run_example`](Self::run_example).
+pub struct EngineBuilder {
+    config: GameConfig,
+    plugins: Plugins,
+    extractors: SpriteExtractors,
+}
+
+impl EngineBuilder {
+    /// Start from `config` with no plugins.
+    pub fn new(config: GameConfig) -> Self {
+        Self {
+            config,
+            plugins: Plugins::default(),
+            extractors: SpriteExtractors::new(),
+        }
+    }
+
+    /// Add `plugin` (builder-style, for `main`).
+    pub fn with_plugin(mut self, plugin: impl Plugin) -> Self {
+        self.add_plugin(plugin);
+        self
+    }
+
+    /// Add `plugin`, running its [`Plugin::build`] now. A plugin whose name
+    /// was already added is skipped with a warning.
+    pub fn add_plugin(&mut self, plugin: impl Plugin) -> &mut Self {
+        let name = plugin.name().to_string();
+        if self.plugins.contains(&name) {
+            log::warn!("Plugin '{name}' already added; skipping");
+            return self;
+        }
+        log::info!("Adding plugin '{name}'");
+        self.plugins.names.push(name);
+        plugin.build(self);
+        self
+    }
+
+    /// Run `system` every frame after `Game::update`, with the scaled delta.
+    /// Its lifecycle hooks run before `Game::init` and at shutdown.
+    pub fn add_system(&mut self, system: impl System) -> &mut Self {
+        self.plugins.systems.add(system);
+        self
+    }
+
+    /// Make `T` loadable from scenes by type name (a
+    /// `ComponentData::Dynamic` entry whose `type` is `T::type_name()`).
+    pub fn register_component<T>(&mut self) -> &mut Self
+    where
+        T: ComponentMeta + for<'de> serde::Deserialize<'de> + Send + Sync + 'static,
+    {
+        register_global_component::<T>();
+        self
+    }
+
+    /// Check `T` with its [`Validate`] impl whenever component data is
+    /// validated (scene loads, editor edits, entering play mode).
+    pub fn register_validator<T: ComponentMeta + Validate>(&mut self) -> &mut Self {
+        register_global_validator::<T>();
+        self
+    }
+
+    /// Register a custom asset loader (`ctx.assets.load_asset::<T>(path)`).
+    pub fn add_asset_loader(&mut self, loader: impl AssetLoader) -> &mut Self {
+        self.plugins.asset_loaders.register(loader);
+        self
+    }
+
+    /// The sprite extractors the game will start with (`ctx.extractors`).
+    pub fn extractors_mut(&mut self) -> &mut SpriteExtractors {
+        &mut self.extractors
+    }
+
+    /// Edit typed extension data shared with other layers (created with
+    /// `Default` on first use; read back through `ctx.plugins`).
+    pub fn with_extension<T: Default + Send + Sync + 'static>(&mut self, edit: impl FnOnce(&mut T)) -> &mut Self {
+        let mut extension = self.plugins.extensions.remove::<T>().unwrap_or_default();
+        edit(&mut extension);
+        self.plugins.extensions.insert(extension);
+        self
+    }
+
+    /// The game configuration, for plugins that adjust it.
+    pub fn config_mut(&mut self) -> &mut GameConfig {
+        &mut self.config
+    }
+
+    /// What has been registered so far.
+    pub fn plugins(&self) -> &Plugins {
+        &self.plugins
+    }
+
+    /// Split into the parts the game runner owns.
+    pub(crate) fn into_parts(self) -> (GameConfig, Plugins, SpriteExtractors) {
+        (self.config, self.plugins, self.extractors)
+    }
+
+    /// Run `game` in a window (see [`crate::run_game`]).
+    pub fn run<G: Game>(self, game: G) -> Result<(), Box<dyn std::error::Error>> {
+        crate::game::run_builder(self, game)
+    }
+
+    /// Run `game` without a window (see [`crate::run_headless`]).
+    pub fn run_headless<G: Game>(self, game: G, script: HeadlessScript) -> Result<HeadlessReport, HeadlessError> {
+        crate::game::run_builder_headless(self, game, script)
+    }
+
+    /// Windowed, or headless with `script` on `--headless` (see
+    /// [`crate::run_example`]).
+    pub fn run_example<G: Game>(self, game: G, script: HeadlessScript) -> ExitCode {
+        crate::game::run_builder_example(self, game, script)
+    }
+}
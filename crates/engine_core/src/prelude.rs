@@ -5,17 +5,28 @@
 
 pub use crate::{
     // Simple game API (recommended)
-    game::{Game, run_game},
-    contexts::{GameContext, RenderContext, GlyphCacheKey},
+    game::{Game, run_game, run_example, run_headless},
+    // Plugins (systems, components, asset loaders from outside the engine)
+    plugin::{EngineBuilder, Plugin, Plugins},
+    asset_loaders::AssetLoader,
+    // Headless auto-play (example suite)
+    headless::{count_named, find_named, named_position, positions_with, HeadlessError, HeadlessReport, HeadlessScript},
+    contexts::{GameContext, RenderContext, GlyphCacheKey, GlyphRegion, UiAtlasRegions},
     game_config::GameConfig,
+    // Game-flow state stack (`ctx.states`)
+    game_states::{GameState, GameStates, StateScoped},
     chaos_mode::ChaosMode,
     chaos_theme::ChaosTheme,
     achievements::{Achievement, AchievementManager, AchievementError},
     // Asset management
     assets::{AssetManager, AssetConfig, AssetError},
+    asset_root::AssetRoot,
+    asset_refs::AssetMemoryUsage,
     // Scene serialization
     scene_data::{SceneData, PhysicsSettings, PrefabData, EntityData, ComponentData, BehaviorData, SceneLoadError},
     scene_loader::{SceneLoader, SceneInstance},
+    // World streaming (chunked loading of large scenes)
+    streaming::{partition_scene, ChunkCoord, ChunkedScene, StreamingSettings, WorldStreamer},
     // Behavior system
     behavior_runner::{BehaviorRunner, EntityCollected},
     // Particle system (CPU pool; spawn bursts or attach a ParticleEmitter)
@@ -30,8 +41,14 @@ pub use crate::{
     RENDER_UNIT,
     // Debug-draw helpers (collider outlines, etc.)
     debug,
+    // Per-frame invariant checks (`ctx.validation`) and component data checks
+    validation::{validate_components, DebugValidator, ValidationIssue},
+    // Renderable-component extraction (`ctx.extractors`)
+    extraction::{world_sprite_mask, ExtractContext, ExtractTransform, SpriteExtractors, PARTICLE_EXTRACTOR, SPRITE_EXTRACTOR, TILEMAP_EXTRACTOR},
     init,
-    timing::Timer,
+    timing::{Time, TimeScale, Timer},
+    // Deterministic gameplay randomness (a world resource)
+    rng::Rng,
     scene::Scene,
     EngineError,
 };
@@ -44,12 +61,23 @@ pub use common::{hash_f32, hash_u32};
 pub use glam::{Vec2, Vec4};
 
 // Re-export ECS types
-pub use ecs::{EntityId, World};
-pub use ecs::sprite_components::{set_sprites_visible, Name, Sprite};
-pub use ecs::audio_components::{AudioSource, AudioListener, PlaySoundEffect};
+pub use ecs::{EntityId, EntityRef, Guid, World};
+pub use ecs::sprite_components::{set_sprites_visible, MaskShape, Name, Sprite, SpriteMask};
+pub use ecs::audio_components::{AudioSource, AudioListener, AudioOcclusion, PlaySoundEffect};
 pub use ecs::hierarchy_system::TransformHierarchySystem;
 pub use ecs::lifetime::{Lifetime, LifetimeSystem};
+pub use ecs::pool::{EntityPool, Inactive};
+pub use ecs::bounds::{BoundsMode, BoundsSystem, KillZone, KillZoneAction, KillZoneEntered, WorldBounds};
+pub use ecs::parallax::ParallaxLayer;
+pub use ecs::trail::{ColorGradient, ColorStop, Trail2D};
+pub use ecs::floating_origin::{OriginShifted, WorldOrigin};
+pub use ecs::world_text::{TextAlignment, WorldText};
+pub use ecs::animation::{
+    AnimationClip, AnimationCurve, AnimationTarget, AnimationTrack, Animator, AnimatorSystem, Interpolation, Keyframe,
+    PlaybackMode,
+};
 pub use ecs::WorldHierarchyExt;
+pub use ecs::{ComponentError, Validate};
 pub use ecs::System;
 pub use ecs::behavior::{Behavior, EntityTag};
 pub use ecs::{StateMachine, HierarchicalStateMachine, EventBus, ResourceStorage};
@@ -61,8 +89,9 @@ pub use input::prelude::{
 };
 
 // Re-export renderer types
-pub use renderer::{TextureHandle, TextureLoadConfig};
+pub use renderer::{ColorConfig, SpriteAtlas, TextureHandle, TextureLoadConfig, Tonemap};
 pub use renderer::line_pipeline::LineVertex;
+pub use renderer::DebugDraw;
 
 // Re-export audio types
 pub use audio::{AudioManager, SoundHandle, SoundSettings};
@@ -75,10 +104,16 @@ pub use common::Color as UIColor;
 // Re-export physics types when the physics feature is enabled
 #[cfg(feature = "physics")]
 pub use physics::{
-    Collider, ColliderShape, CollisionData, CollisionEvent, ContactPoint,
-    PhysicsConfig, PhysicsSystem, RigidBody, RigidBodyType,
+    AreaForce, BodySleep, BodyWake, Buoyancy, Collider, ColliderShape, CollisionData, CollisionEvent, ContactPoint,
+    Drag, GravityFalloff, GravityZone, PhysicsConfig, PhysicsSystem, RigidBody, RigidBodyType, ZoneShape,
 };
 
+// Engine-owned physics (`ctx.physics`)
+#[cfg(feature = "physics")]
+pub use crate::game_physics::GamePhysics;
+#[cfg(feature = "physics")]
+pub use crate::audio_occlusion::update_audio_occlusion;
+
 // Generic pickup/collectible tracking (built on physics collision events)
 #[cfg(feature = "physics")]
 pub use crate::pickups::{EffectTimer, Pickup, Pickups};
@@ -0,0 +1,69 @@
+//! Per-frame CPU timings of the engine's update passes, exposed as
+//! `ctx.profile` (the previous frame's) for profilers like the editor's.
+//!
+//! Times are wall-clock seconds measured around each pass; rendering isn't
+//! included (its sprite and draw-call counts are in `ctx.render_stats`).
+//! The live entity count rides along so stress runs can plot cost against
+//! world size.
+
+use ecs::SystemTiming;
+
+/// Timing name of `Game::update` in [`FrameProfile::timings`].
+pub const UPDATE_TIMING: &str = "Game::update";
+/// Timing name of the engine physics step.
+pub const PHYSICS_TIMING: &str = "Physics";
+/// Timing name of the particle simulation.
+pub const PARTICLES_TIMING: &str = "Particles";
+
+/// How long each update pass of one frame took.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FrameProfile {
+    /// `Game::update` in seconds (in the editor this includes the editor UI)
+    pub update: f32,
+    /// Plugin systems (`EngineBuilder::add_system`), in registration order
+    pub systems: Vec<SystemTiming>,
+    /// Engine-owned physics step (`ctx.physics`) in seconds; 0 while it is
+    /// disabled or paused
+    pub physics: f32,
+    /// Particle simulation in seconds
+    pub particles: f32,
+    /// Live entities once the frame's updates are done
+    pub entities: usize,
+}
+
+impl FrameProfile {
+    /// Every pass as a named timing, in frame order: update, plugin
+    /// systems, physics, particles.
+    pub fn timings(&self) -> Vec<SystemTiming> {
+        let mut timings = Vec::with_capacity(self.systems.len() + 3);
+        timings.push(SystemTiming::new(UPDATE_TIMING, self.update));
+        timings.extend(self.systems.iter().cloned());
+        timings.push(SystemTiming::new(PHYSICS_TIMING, self.physics));
+        timings.push(SystemTiming::new(PARTICLES_TIMING, self.particles));
+        timings
+    }
+
+    /// Sum of all passes in seconds.
+    pub fn total(&self) -> f32 {
+        self.update + self.systems.iter().map(|timing| timing.seconds).sum::<f32>() + self.physics + self.particles
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_timings_list_every_pass_in_frame_order() {
+        let profile = FrameProfile {
+            update: 0.004,
+            systems: vec![SystemTiming::new("ai", 0.001)],
+            physics: 0.002,
+            particles: 0.0005,
+            entities: 3,
+        };
+        let names: Vec<String> = profile.timings().into_iter().map(|timing| timing.name).collect();
+        assert_eq!(names, [UPDATE_TIMING, "ai", PHYSICS_TIMING, PARTICLES_TIMING]);
+        assert!((profile.total() - 0.0075).abs() < 1e-6);
+    }
+}
@@ -9,16 +9,19 @@ use std::sync::Arc;
 use ecs::sprite_components::Transform2D;
 use ecs::World;
 use glam::Vec2;
+#[cfg(not(target_arch = "wasm32"))]
 use winit::window::Window;
 
 use renderer::{
     bloom::BloomConfig,
+    color_config::ColorConfig,
     line_pipeline::LineVertex,
     sprite::{SpriteBatch, SpriteBatcher, SpritePipeline},
     sprite_data::TextureResource,
     texture::TextureHandle,
+    trail_pipeline::TrailVertex,
     wgpu::{Device, Queue},
-    Camera, Renderer, RendererError,
+    Camera, RenderStats, Renderer, RendererError,
 };
 
 /// Manages the renderer lifecycle and sprite rendering pipeline.
@@ -55,6 +58,10 @@ impl RenderManager {
 
     /// Initialize the renderer with a window.
     ///
+    /// Blocks on adapter and device creation, so it is native-only; the web
+    /// runner awaits [`renderer::init_with_config`] itself and hands the
+    /// result to [`install`](Self::install).
+    ///
     /// # Arguments
     /// * `window` - The window to render to
     /// * `clear_color` - RGBA clear color for the background
@@ -62,6 +69,7 @@ impl RenderManager {
     /// # Returns
     /// * `Ok(())` on successful initialization
     /// * `Err(RendererError)` if initialization fails
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn init(
         &mut self,
         window: Arc<Window>,
@@ -69,8 +77,14 @@ impl RenderManager {
         renderer_config: renderer::RendererConfig,
     ) -> Result<(), RendererError> {
         // Use pollster for async execution
-        let mut renderer = pollster::block_on(renderer::init_with_config(window, renderer_config))?;
+        let renderer = pollster::block_on(renderer::init_with_config(window, renderer_config))?;
+        self.install(renderer, clear_color);
+        Ok(())
+    }
 
+    /// Take over an initialized renderer and build the sprite pipeline on
+    /// its device.
+    pub fn install(&mut self, mut renderer: Renderer, clear_color: [f32; 4]) {
         renderer.set_clear_color(
             clear_color[0] as f64,
             clear_color[1] as f64,
@@ -78,14 +92,13 @@ impl RenderManager {
             clear_color[3] as f64,
         );
 
-        // Create sprite pipeline with max 1000 sprites per batch
+        // Initial instance-buffer capacity; the buffer grows on demand.
         let sprite_pipeline = SpritePipeline::new(renderer.device_ref(), 1000);
 
         self.renderer = Some(renderer);
         self.sprite_pipeline = Some(sprite_pipeline);
 
         log::info!("RenderManager initialized");
-        Ok(())
     }
 
     /// Check if the renderer is initialized.
@@ -115,7 +128,8 @@ impl RenderManager {
     }
 
     /// Handle a render error, attempting surface recreation on surface loss.
-    fn handle_render_error(renderer: &mut Renderer, error: RendererError) -> Result<(), RendererError> {
+    /// A recovered frame reports empty stats — nothing was drawn.
+    fn handle_render_error(renderer: &mut Renderer, error: RendererError) -> Result<RenderStats, RendererError> {
         match error {
             RendererError::SurfaceError(_) => {
                 if let Err(e) = renderer.recreate_surface() {
@@ -123,7 +137,7 @@ impl RenderManager {
                     return Err(e);
                 }
                 log::debug!("Surface recreated after loss");
-                Ok(())
+                Ok(RenderStats::default())
             }
             e => {
                 log::error!("Render error: {}", e);
@@ -139,13 +153,14 @@ impl RenderManager {
     /// * `textures` - Texture resources for rendering
     ///
     /// # Returns
-    /// * `Ok(())` on successful render
+    /// * `Ok(RenderStats)` with the frame's GPU statistics (draw calls,
+    ///   texture binds, texture memory) on successful render
     /// * `Err(RendererError)` if rendering fails
     pub fn render(
         &mut self,
         batches: &[&SpriteBatch],
         textures: &HashMap<TextureHandle, TextureResource>,
-    ) -> Result<(), RendererError> {
+    ) -> Result<RenderStats, RendererError> {
         let renderer = self.renderer.as_mut().ok_or_else(|| {
             RendererError::WindowCreationError("Renderer not initialized".to_string())
         })?;
@@ -154,14 +169,15 @@ impl RenderManager {
         })?;
 
         match renderer.render_with_sprites(pipeline, &self.camera, textures, batches) {
-            Ok(_) => Ok(()),
+            Ok(stats) => Ok(stats),
             Err(e) => Self::handle_render_error(renderer, e),
         }
     }
 
     /// Render a frame using a SpriteBatcher.
     ///
-    /// This is a convenience method that extracts batches from the batcher.
+    /// This is a convenience method that extracts batches from the batcher;
+    /// the returned stats include the batcher's sprite counts.
     /// Batches are submitted in deterministic order (min depth, then texture
     /// handle) — HashMap iteration order would make cross-batch draw order
     /// vary between runs.
@@ -169,7 +185,7 @@ impl RenderManager {
         &mut self,
         batcher: &SpriteBatcher,
         textures: &HashMap<TextureHandle, TextureResource>,
-    ) -> Result<(), RendererError> {
+    ) -> Result<RenderStats, RendererError> {
         let mut batch_refs: Vec<&SpriteBatch> = batcher.batches().values().collect();
         batch_refs.sort_by(|a, b| {
             let min_depth = |batch: &SpriteBatch| {
@@ -183,8 +199,11 @@ impl RenderManager {
             min_depth(a)
                 .total_cmp(&min_depth(b))
                 .then_with(|| a.texture_handle.id.cmp(&b.texture_handle.id))
+                .then_with(|| a.mask_texture.id.cmp(&b.mask_texture.id))
         });
-        self.render(&batch_refs, textures)
+        let mut stats = batcher.stats();
+        stats.merge(self.render(&batch_refs, textures)?);
+        Ok(stats)
     }
 
 
@@ -256,6 +275,17 @@ impl RenderManager {
         self.renderer.as_mut().map(|r| r.bloom_config_mut())
     }
 
+    /// Read-only view of the output color settings.
+    pub fn color_config(&self) -> Option<&ColorConfig> {
+        self.renderer.as_ref().map(|r| r.color_config())
+    }
+
+    /// Mutable access to the output color settings — exposure, gamma and
+    /// the tonemap (`Reinhard` for bloom-heavy scenes).
+    pub fn color_config_mut(&mut self) -> Option<&mut ColorConfig> {
+        self.renderer.as_mut().map(|r| r.color_config_mut())
+    }
+
     /// Upload line vertices for the next frame. Pairs of vertices form line
     /// segments. Empty slice (or no call) draws no lines this frame.
     pub fn set_lines(&mut self, vertices: &[LineVertex]) {
@@ -263,16 +293,32 @@ impl RenderManager {
             renderer.set_lines(vertices);
         }
     }
+
+    /// Upload debug-draw vertices for the next frame, drawn over everything.
+    /// Empty slice (or no call) draws no debug shapes this frame.
+    pub fn set_debug_lines(&mut self, vertices: &[LineVertex]) {
+        if let Some(renderer) = &mut self.renderer {
+            renderer.set_debug_lines(vertices);
+        }
+    }
+
+    /// Upload stitched trail-strip vertices for the next frame. Empty slice
+    /// (or no call) draws no trails this frame.
+    pub fn set_trails(&mut self, vertices: &[TrailVertex]) {
+        if let Some(renderer) = &mut self.renderer {
+            renderer.set_trails(vertices);
+        }
+    }
 }
 
-/// Position of the first entity with `Camera { is_main_camera: true }` and a
-/// `Transform2D` — the world entity that drives the render camera, if any.
+/// Position of the first active entity with `Camera { is_main_camera: true }`
+/// and a `Transform2D` — the world entity that drives the render camera, if any.
 ///
 /// Public so the editor integration can mirror the game's camera onto the
 /// editor viewport while a play session runs.
 pub fn main_camera_position(world: &World) -> Option<Vec2> {
     world
-        .entities()
+        .active_entities()
         .into_iter()
         .find(|e| {
             world
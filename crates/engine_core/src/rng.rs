@@ -0,0 +1,300 @@
+//! Deterministic gameplay randomness.
+//!
+//! [`Rng`] is a seedable generator kept as a world resource, so all of a
+//! world's gameplay rolls come from one stream: the same seed and the same
+//! inputs give the same game, which is what replays and tests need. Runtime
+//! saves record its state (the scene's `rng` field) and loading restores
+//! it, so a loaded save continues the stream where it left off.
+//!
+//! ```
+//! use engine_core::Rng;
+//! use ecs::World;
+//!
+//! let mut world = World::new();
+//! world.insert_resource(Rng::new(7));
+//! if let Some(rng) = world.resource_mut::<Rng>() {
+//!     let loot = rng.weighted_choice(&[("coin", 8.0), ("gem", 1.0)]);
+//!     assert!(loot.is_some());
+//! }
+//! ```
+//!
+//! Cosmetic randomness (particle jitter, audio variation) keeps its own
+//! generators so it never shifts the gameplay stream.
+
+use std::f32::consts::{SQRT_2, TAU};
+
+use glam::Vec2;
+use serde::{Deserialize, Serialize};
+
+/// Seed used by [`Rng::default`].
+pub const DEFAULT_SEED: u64 = 0x1D5C_0001;
+
+/// Candidates tried around each point before Poisson-disk sampling retires it.
+const POISSON_ATTEMPTS: usize = 30;
+
+/// Largest background grid Poisson-disk sampling will allocate.
+const MAX_POISSON_CELLS: usize = 1 << 22;
+
+/// Seedable gameplay random number generator (a world resource).
+///
+/// SplitMix64: every state is valid and the whole state is one `u64`, so it
+/// serializes exactly. Not for cryptography.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Rng {
+    seed: u64,
+    state: u64,
+}
+
+impl Default for Rng {
+    fn default() -> Self {
+        Self::new(DEFAULT_SEED)
+    }
+}
+
+impl Rng {
+    /// A generator starting at `seed`.
+    pub fn new(seed: u64) -> Self {
+        Self { seed, state: seed }
+    }
+
+    /// Seed from the clock, for runs that should differ. Record
+    /// [`seed`](Self::seed) to reproduce one.
+    pub fn from_time() -> Self {
+        let nanos = common::time::SystemTime::now()
+            .duration_since(common::time::UNIX_EPOCH)
+            .map_or(DEFAULT_SEED, |elapsed| elapsed.as_nanos() as u64);
+        Self::new(nanos)
+    }
+
+    /// The seed this generator started from.
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Restart the stream from `seed`.
+    pub fn reseed(&mut self, seed: u64) {
+        *self = Self::new(seed);
+    }
+
+    /// Next raw 64-bit value.
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform value in `0.0..1.0`.
+    pub fn next_f32(&mut self) -> f32 {
+        // Top 24 bits give an exact f32 below 1.0
+        (self.next_u64() >> 40) as f32 / (1u64 << 24) as f32
+    }
+
+    /// Uniform value in `min..max`; `min` when the range is empty.
+    pub fn range_f32(&mut self, min: f32, max: f32) -> f32 {
+        if max > min {
+            min + self.next_f32() * (max - min)
+        } else {
+            min
+        }
+    }
+
+    /// Uniform integer in `min..max` (max exclusive); `min` when the range
+    /// is empty.
+    pub fn range_i32(&mut self, min: i32, max: i32) -> i32 {
+        if max <= min {
+            return min;
+        }
+        let span = (max as i64 - min as i64) as u64;
+        (min as i64 + self.below(span) as i64) as i32
+    }
+
+    /// `true` with probability `probability` (never at 0.0 or below,
+    /// always at 1.0 or above).
+    pub fn chance(&mut self, probability: f32) -> bool {
+        self.next_f32() < probability
+    }
+
+    /// A uniformly chosen element, or `None` for an empty slice.
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> Option<&'a T> {
+        if items.is_empty() {
+            return None;
+        }
+        items.get(self.below(items.len() as u64) as usize)
+    }
+
+    /// Index chosen with probability proportional to its weight. Zero,
+    /// negative and non-finite weights are never chosen; `None` when no
+    /// weight is positive.
+    pub fn weighted_index(&mut self, weights: &[f32]) -> Option<usize> {
+        let usable = |weight: &f32| weight.is_finite() && *weight > 0.0;
+        let total: f32 = weights.iter().copied().filter(usable).sum();
+        if total <= 0.0 {
+            return None;
+        }
+        let mut roll = self.next_f32() * total;
+        let mut last = None;
+        for (index, weight) in weights.iter().enumerate().filter(|(_, weight)| usable(weight)) {
+            if roll < *weight {
+                return Some(index);
+            }
+            roll -= weight;
+            last = Some(index);
+        }
+        // Float rounding can leave the roll just past the final weight
+        last
+    }
+
+    /// An item chosen by its weight (see [`weighted_index`](Self::weighted_index)).
+    pub fn weighted_choice<'a, T>(&mut self, items: &'a [(T, f32)]) -> Option<&'a T> {
+        let weights: Vec<f32> = items.iter().map(|(_, weight)| *weight).collect();
+        self.weighted_index(&weights).and_then(|index| items.get(index)).map(|(item, _)| item)
+    }
+
+    /// Points spread over `bounds` with no two closer than `min_distance`
+    /// and no room left for another (Bridson's Poisson-disk sampling) —
+    /// natural-looking placement for props, spawns and foliage. Empty for
+    /// an empty area, a non-positive distance, or a distance so small the
+    /// area would need millions of samples.
+    pub fn poisson_disk(&mut self, bounds: common::Rect, min_distance: f32) -> Vec<Vec2> {
+        if !(min_distance > 0.0 && min_distance.is_finite() && bounds.width > 0.0 && bounds.height > 0.0) {
+            return Vec::new();
+        }
+        let cell = min_distance / SQRT_2;
+        let columns = (bounds.width / cell).ceil() as usize;
+        let rows = (bounds.height / cell).ceil() as usize;
+        if columns.saturating_mul(rows) > MAX_POISSON_CELLS {
+            log::warn!("Poisson-disk sampling skipped: spacing {min_distance} is too small for the area");
+            return Vec::new();
+        }
+
+        let origin = Vec2::new(bounds.x, bounds.y);
+        let cell_of = |point: Vec2| {
+            let local = (point - origin) / cell;
+            ((local.x as usize).min(columns - 1), (local.y as usize).min(rows - 1))
+        };
+        // Each cell holds at most one point (its diagonal is `min_distance`)
+        let mut grid: Vec<Option<usize>> = vec![None; columns * rows];
+        let mut points = Vec::new();
+        let mut active = Vec::new();
+
+        let first = origin + Vec2::new(self.next_f32() * bounds.width, self.next_f32() * bounds.height);
+        let (x, y) = cell_of(first);
+        grid[y * columns + x] = Some(0);
+        points.push(first);
+        active.push(0);
+
+        while !active.is_empty() {
+            let slot = self.below(active.len() as u64) as usize;
+            let center = points[active[slot]];
+            let mut placed = false;
+            for _ in 0..POISSON_ATTEMPTS {
+                let angle = self.range_f32(0.0, TAU);
+                let distance = self.range_f32(min_distance, 2.0 * min_distance);
+                let candidate = center + Vec2::new(angle.cos(), angle.sin()) * distance;
+                let inside = candidate.x >= bounds.x
+                    && candidate.y >= bounds.y
+                    && candidate.x < bounds.x + bounds.width
+                    && candidate.y < bounds.y + bounds.height;
+                if !inside {
+                    continue;
+                }
+                let (cx, cy) = cell_of(candidate);
+                let crowded = (cy.saturating_sub(2)..(cy + 3).min(rows)).any(|ny| {
+                    (cx.saturating_sub(2)..(cx + 3).min(columns)).any(|nx| {
+                        grid[ny * columns + nx]
+                            .is_some_and(|index| points[index].distance(candidate) < min_distance)
+                    })
+                });
+                if !crowded {
+                    grid[cy * columns + cx] = Some(points.len());
+                    active.push(points.len());
+                    points.push(candidate);
+                    placed = true;
+                    break;
+                }
+            }
+            if !placed {
+                active.swap_remove(slot);
+            }
+        }
+        points
+    }
+
+    /// Uniform value in `0..bound` (`bound > 0`), without modulo bias.
+    fn below(&mut self, bound: u64) -> u64 {
+        // Lemire's multiply-shift; reject the short first zone
+        let threshold = bound.wrapping_neg() % bound;
+        loop {
+            let product = self.next_u64() as u128 * bound as u128;
+            if (product as u64) >= threshold {
+                return (product >> 64) as u64;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_same_seed_same_stream() {
+        let mut a = Rng::new(42);
+        let mut b = Rng::new(42);
+        let rolls: Vec<u64> = (0..8).map(|_| a.next_u64()).collect();
+        assert_eq!(rolls, (0..8).map(|_| b.next_u64()).collect::<Vec<_>>());
+        assert_ne!(Rng::new(43).next_u64(), rolls[0]);
+
+        // A serialized generator resumes mid-stream
+        let saved = ron::to_string(&a).expect("serialize");
+        let mut restored: Rng = ron::from_str(&saved).expect("deserialize");
+        assert_eq!(restored.next_u64(), a.next_u64());
+        assert_eq!(restored.seed(), 42);
+    }
+
+    #[test]
+    fn test_ranges_stay_in_bounds() {
+        let mut rng = Rng::default();
+        for _ in 0..1000 {
+            let value = rng.range_f32(-2.0, 3.0);
+            assert!((-2.0..3.0).contains(&value));
+            let int = rng.range_i32(i32::MIN, i32::MAX);
+            assert!(int < i32::MAX);
+            assert!((5..8).contains(&rng.range_i32(5, 8)));
+        }
+        assert_eq!(rng.range_i32(4, 4), 4);
+        assert_eq!(rng.range_f32(1.0, 0.0), 1.0);
+        assert!(!rng.chance(0.0));
+        assert!(rng.chance(1.0));
+    }
+
+    #[test]
+    fn test_weighted_index_follows_weights() {
+        let mut rng = Rng::new(9);
+        let mut counts = [0usize; 4];
+        for _ in 0..10_000 {
+            let index = rng.weighted_index(&[1.0, 0.0, 3.0, f32::NAN]).expect("positive weights");
+            counts[index] += 1;
+        }
+        assert_eq!(counts[1] + counts[3], 0, "zero and NaN weights are never chosen");
+        assert!((2.5..3.5).contains(&(counts[2] as f32 / counts[0] as f32)));
+        assert_eq!(rng.weighted_index(&[0.0, -1.0]), None);
+        assert_eq!(rng.weighted_choice::<&str>(&[]), None);
+    }
+
+    #[test]
+    fn test_poisson_disk_keeps_spacing_and_fills_area() {
+        let bounds = common::Rect::new(10.0, -20.0, 200.0, 100.0);
+        let points = Rng::new(3).poisson_disk(bounds, 10.0);
+        assert!(points.iter().all(|p| bounds.contains(*p)));
+        for (i, a) in points.iter().enumerate() {
+            assert!(points[i + 1..].iter().all(|b| a.distance(*b) >= 10.0));
+        }
+        // Maximal packings at this spacing hold well over 100 points
+        assert!(points.len() > 100, "only {} points", points.len());
+        assert_eq!(points, Rng::new(3).poisson_disk(bounds, 10.0));
+        assert!(Rng::new(3).poisson_disk(bounds, 0.0).is_empty());
+    }
+}
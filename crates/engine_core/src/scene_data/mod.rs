@@ -0,0 +1,426 @@
+//! Scene data structures for RON serialization
+//!
+//! This module defines the data structures used to serialize and deserialize
+//! scene files in RON (Rusty Object Notation) format.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+// BehaviorData (+ its Behavior conversions) lives in `behavior_data.rs` for
+// file-size reasons; re-exported here so the scene schema stays one import.
+pub use crate::behavior_data::BehaviorData;
+pub use crate::physics_settings::PhysicsSettings;
+pub use crate::streaming::StreamingSettings;
+
+mod physics_data;
+
+pub use physics_data::{
+    ColliderShapeData, CombineRuleData, PhysicsMaterialData, RigidBodyTypeData, ZoneShapeData,
+};
+
+/// Editor-specific settings persisted with the scene
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct EditorSettings {
+    /// Camera position when scene was last saved
+    #[serde(default)]
+    pub camera_position: (f32, f32),
+    /// Camera zoom level when scene was last saved
+    #[serde(default = "default_zoom")]
+    pub camera_zoom: f32,
+    /// Entities hidden in the editor's scene view (eye toggle)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub hidden: Vec<ecs::Guid>,
+    /// Entities locked against viewport moves (lock toggle)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub locked: Vec<ecs::Guid>,
+    /// Render layers hidden in the editor's scene view (layers panel)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub hidden_layers: Vec<String>,
+}
+
+/// Root structure for a scene file
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SceneData {
+    /// Scene file format version. Files without one are version 0 and are
+    /// upgraded on load (see [`scene_migration`](crate::scene_migration)).
+    #[serde(default)]
+    pub format_version: u32,
+    /// Scene name
+    pub name: String,
+    /// Physics settings for this scene
+    #[serde(default)]
+    pub physics: Option<PhysicsSettings>,
+    /// Editor settings (camera position, zoom) - optional for backward compatibility
+    #[serde(default)]
+    pub editor: Option<EditorSettings>,
+    /// Prefab definitions (reusable entity templates)
+    #[serde(default)]
+    pub prefabs: HashMap<String, PrefabData>,
+    /// Scene-defined physics materials, referenced by name from colliders
+    /// (alongside the built-in presets; a scene material shadows a preset
+    /// of the same name)
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub materials: HashMap<String, PhysicsMaterialData>,
+    /// Chunk layout for world streaming (see [`streaming`](crate::streaming))
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub streaming: Option<StreamingSettings>,
+    /// Sprite render layers, back to front (see
+    /// [`ecs::RenderLayers`]); empty means the built-in layers
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub layers: Vec<String>,
+    /// How sprites are ordered within a layer (see
+    /// [`ecs::SpriteSortMode`]); omitted when sorting by depth
+    #[serde(default, skip_serializing_if = "ecs::SpriteSortMode::is_depth")]
+    pub sort_mode: ecs::SpriteSortMode,
+    /// Entity instances
+    #[serde(default)]
+    pub entities: Vec<EntityData>,
+    /// Simulation tick the saved state belongs to (`World::simulation_tick`).
+    /// Runtime saves record it so state can be matched to input logs;
+    /// authored scenes omit it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub simulation_tick: Option<u64>,
+    /// Gameplay random generator state (the world's [`Rng`](crate::rng::Rng)
+    /// resource), so a loaded save continues the same random stream.
+    /// Omitted when the world has none.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rng: Option<crate::rng::Rng>,
+}
+
+impl Default for SceneData {
+    fn default() -> Self {
+        Self {
+            format_version: crate::scene_migration::SCENE_FORMAT_VERSION,
+            name: "Untitled".to_string(),
+            physics: None,
+            editor: None,
+            prefabs: HashMap::new(),
+            materials: HashMap::new(),
+            streaming: None,
+            layers: Vec::new(),
+            sort_mode: ecs::SpriteSortMode::Depth,
+            entities: Vec::new(),
+            simulation_tick: None,
+            rng: None,
+        }
+    }
+}
+
+/// Prefab definition - a reusable entity template
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PrefabData {
+    /// Components that make up this prefab
+    pub components: Vec<ComponentData>,
+}
+
+/// Entity instance in a scene
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct EntityData {
+    /// Optional name for this entity (for lookup)
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Persistent identity (`ecs::Guid`) kept across save/load so
+    /// `EntityRef`s can find this entity. Absent → a fresh GUID on load.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub guid: Option<ecs::Guid>,
+    /// Optional prefab to instantiate
+    #[serde(default)]
+    pub prefab: Option<String>,
+    /// Optional parent entity name (for hierarchy)
+    ///
+    /// The parent must be defined earlier in the entities list so it exists
+    /// when this entity is created. Use the parent entity's `name` field.
+    #[serde(default)]
+    pub parent: Option<String>,
+    /// Component overrides (applied on top of prefab)
+    #[serde(default)]
+    pub overrides: Vec<ComponentData>,
+    /// Inline components (if no prefab is used)
+    #[serde(default)]
+    pub components: Vec<ComponentData>,
+    /// Child entities (alternative to using parent field - creates hierarchy inline)
+    #[serde(default)]
+    pub children: Vec<EntityData>,
+}
+
+/// Component data variants for serialization
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum ComponentData {
+    /// Transform component
+    Transform2D {
+        #[serde(default)]
+        position: (f32, f32),
+        #[serde(default)]
+        rotation: f32,
+        #[serde(default = "default_scale")]
+        scale: (f32, f32),
+    },
+    /// Sprite component
+    Sprite {
+        /// Texture reference: "#white", "#solid:RRGGBB", or file path
+        #[serde(default = "default_texture")]
+        texture: String,
+        #[serde(default)]
+        offset: (f32, f32),
+        #[serde(default)]
+        rotation: f32,
+        #[serde(default = "default_scale")]
+        scale: (f32, f32),
+        #[serde(default = "default_color")]
+        color: (f32, f32, f32, f32),
+        #[serde(default)]
+        depth: f32,
+        /// Render layer name; the default layer is not written
+        #[serde(default = "default_layer", skip_serializing_if = "is_default_layer")]
+        layer: String,
+        /// Emissive strength for bloom (0.0 = no glow)
+        #[serde(default)]
+        emissive: f32,
+        /// Normalized texture sub-rectangle (x, y, width, height); the
+        /// default shows the whole texture
+        #[serde(default = "default_tex_region")]
+        tex_region: (f32, f32, f32, f32),
+    },
+    /// Camera component
+    Camera2D {
+        #[serde(default)]
+        position: (f32, f32),
+        #[serde(default)]
+        rotation: f32,
+        #[serde(default = "default_zoom")]
+        zoom: f32,
+        #[serde(default = "default_viewport")]
+        viewport_size: (f32, f32),
+        #[serde(default)]
+        is_main_camera: bool,
+    },
+    /// Sprite animation component
+    SpriteAnimation {
+        #[serde(default = "default_fps")]
+        fps: f32,
+        #[serde(default)]
+        frames: Vec<(f32, f32, f32, f32)>,
+        #[serde(default = "default_true")]
+        playing: bool,
+        #[serde(default = "default_true")]
+        loop_animation: bool,
+        /// Named per-frame events as `(frame, name)`
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        events: Vec<(usize, String)>,
+    },
+    /// Rigid body component
+    RigidBody {
+        #[serde(default)]
+        body_type: RigidBodyTypeData,
+        #[serde(default)]
+        velocity: (f32, f32),
+        #[serde(default)]
+        angular_velocity: f32,
+        #[serde(default = "default_gravity_scale")]
+        gravity_scale: f32,
+        #[serde(default)]
+        linear_damping: f32,
+        #[serde(default)]
+        angular_damping: f32,
+        #[serde(default = "default_true")]
+        can_rotate: bool,
+        #[serde(default)]
+        ccd_enabled: bool,
+        #[serde(default = "default_true")]
+        can_sleep: bool,
+    },
+    /// Collider component
+    Collider {
+        #[serde(default)]
+        shape: ColliderShapeData,
+        #[serde(default)]
+        offset: (f32, f32),
+        #[serde(default)]
+        is_sensor: bool,
+        #[serde(default = "default_friction")]
+        friction: f32,
+        #[serde(default)]
+        restitution: f32,
+        /// Physics material name; when it resolves (scene `materials`, then
+        /// the built-in presets) its values replace the inline ones on load
+        #[serde(default)]
+        material: Option<String>,
+        #[serde(default)]
+        friction_combine: CombineRuleData,
+        #[serde(default)]
+        restitution_combine: CombineRuleData,
+    },
+    /// Constant-force zone (`physics::AreaForce`)
+    AreaForce {
+        #[serde(default)]
+        shape: ZoneShapeData,
+        /// Force in pixel units, in the entity's rotated frame
+        #[serde(default)]
+        force: (f32, f32),
+    },
+    /// Fluid volume (`physics::Buoyancy`)
+    Buoyancy {
+        #[serde(default)]
+        shape: ZoneShapeData,
+        /// Surface height above the entity's position
+        #[serde(default)]
+        surface: f32,
+        #[serde(default = "default_fluid_density")]
+        density: f32,
+        #[serde(default = "default_fluid_drag")]
+        drag: f32,
+    },
+    /// Drag zone (`physics::Drag`)
+    Drag {
+        #[serde(default)]
+        shape: ZoneShapeData,
+        #[serde(default)]
+        damping: f32,
+    },
+    /// Tilemap component - grid of tile indices drawn from a tileset
+    Tilemap {
+        /// Tileset texture reference: "#white", "#solid:RRGGBB", or file path
+        #[serde(default = "default_texture")]
+        tileset: String,
+        #[serde(default)]
+        width: u32,
+        #[serde(default)]
+        height: u32,
+        #[serde(default = "default_tile_size")]
+        tile_size: f32,
+        /// Row-major tile values, 0 = empty
+        #[serde(default)]
+        tiles: Vec<u32>,
+        /// Fraction of the tileset per tile, e.g. (0.25, 0.25) for 4x4
+        #[serde(default = "default_scale")]
+        tile_uv_size: (f32, f32),
+        #[serde(default = "default_tilemap_depth")]
+        depth: f32,
+    },
+    /// Behavior component - defines how an entity responds to input/events
+    Behavior(BehaviorData),
+    /// Tag component for entity identification (targeted by behaviors)
+    EntityTag {
+        #[serde(default = "default_player_tag")]
+        tag: String,
+    },
+    /// Marks an organizational group (folder) node; carries no data
+    Group,
+    /// Embeds another scene file; its entities load as children of this
+    /// entity (see `ecs::SceneInstanceRef`)
+    SceneInstanceRef {
+        /// Scene file, relative to the directory of the referencing scene
+        path: String,
+    },
+    /// Dynamic component loaded via component registry
+    ///
+    /// This variant allows loading components by type name without hardcoded
+    /// handling. The component must be registered in the global ComponentRegistry
+    /// (built-ins, or `EngineBuilder::register_component` from a plugin).
+    Dynamic {
+        /// Component type name (must match registry)
+        #[serde(rename = "type")]
+        component_type: String,
+        /// Component data as JSON
+        #[serde(flatten)]
+        data: serde_json::Value,
+    },
+}
+
+// Default value functions
+fn default_scale() -> (f32, f32) {
+    (1.0, 1.0)
+}
+
+fn default_texture() -> String {
+    "#white".to_string()
+}
+
+fn default_layer() -> String {
+    ecs::DEFAULT_LAYER.to_string()
+}
+
+fn is_default_layer(layer: &str) -> bool {
+    layer == ecs::DEFAULT_LAYER
+}
+
+fn default_color() -> (f32, f32, f32, f32) {
+    (1.0, 1.0, 1.0, 1.0)
+}
+
+fn default_tex_region() -> (f32, f32, f32, f32) {
+    (0.0, 0.0, 1.0, 1.0)
+}
+
+fn default_zoom() -> f32 {
+    1.0
+}
+
+fn default_viewport() -> (f32, f32) {
+    (800.0, 600.0)
+}
+
+fn default_fps() -> f32 {
+    10.0
+}
+
+fn default_tile_size() -> f32 {
+    32.0
+}
+
+fn default_tilemap_depth() -> f32 {
+    -1.0
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_gravity_scale() -> f32 {
+    1.0
+}
+
+fn default_friction() -> f32 {
+    0.5
+}
+
+fn default_fluid_density() -> f32 {
+    2.0
+}
+
+fn default_fluid_drag() -> f32 {
+    1.0
+}
+
+fn default_player_tag() -> String {
+    "player".to_string()
+}
+
+/// Error type for scene loading
+#[derive(Debug, thiserror::Error)]
+pub enum SceneLoadError {
+    #[error("Failed to read scene file: {0}")]
+    IoError(#[from] std::io::Error),
+
+    #[error("Failed to parse RON: {0}")]
+    RonError(#[from] ron::error::SpannedError),
+
+    #[error("Prefab not found: {0}")]
+    PrefabNotFound(String),
+
+    #[error("Invalid texture reference: {0}")]
+    InvalidTextureRef(String),
+
+    #[error("Failed to load texture: {0}")]
+    TextureLoadError(String),
+
+    #[error("Component error: {0}")]
+    ComponentError(String),
+
+    #[error("Scene reference cycle: {0}")]
+    SceneReferenceCycle(String),
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,70 @@
+//! Physics schema of scene files: body types, collider and effector zone
+//! shapes, and the physics material table colliders name.
+
+use serde::{Deserialize, Serialize};
+
+use super::default_friction;
+
+/// Rigid body type for serialization
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum RigidBodyTypeData {
+    #[default]
+    Dynamic,
+    Static,
+    Kinematic,
+}
+
+/// Coefficient mixing rule for serialization
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum CombineRuleData {
+    #[default]
+    Average,
+    Min,
+    Multiply,
+    Max,
+}
+
+/// Physics material definition for serialization
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PhysicsMaterialData {
+    #[serde(default = "default_friction")]
+    pub friction: f32,
+    #[serde(default)]
+    pub restitution: f32,
+    #[serde(default)]
+    pub friction_combine: CombineRuleData,
+    #[serde(default)]
+    pub restitution_combine: CombineRuleData,
+}
+
+/// Collider shape for serialization
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum ColliderShapeData {
+    Box { half_extents: (f32, f32) },
+    Circle { radius: f32 },
+    CapsuleY { half_height: f32, radius: f32 },
+    CapsuleX { half_height: f32, radius: f32 },
+}
+
+impl Default for ColliderShapeData {
+    fn default() -> Self {
+        Self::Box {
+            half_extents: (16.0, 16.0),
+        }
+    }
+}
+
+/// Effector zone shape for serialization
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum ZoneShapeData {
+    Rect { half_extents: (f32, f32) },
+    Circle { radius: f32 },
+}
+
+impl Default for ZoneShapeData {
+    fn default() -> Self {
+        Self::Rect {
+            half_extents: (64.0, 64.0),
+        }
+    }
+}
@@ -0,0 +1,90 @@
+//! Physics effectors in scenes: converts `AreaForce` / `Buoyancy` / `Drag`
+//! components to and from their [`ComponentData`] entries.
+
+use glam::Vec2;
+
+use ecs::{EntityId, World};
+use physics::{AreaForce, Buoyancy, Drag, ZoneShape};
+
+use crate::scene_data::{ComponentData, ZoneShapeData};
+
+impl From<ZoneShapeData> for ZoneShape {
+    fn from(shape: ZoneShapeData) -> Self {
+        match shape {
+            ZoneShapeData::Rect { half_extents } => ZoneShape::Rect { half_extents: Vec2::new(half_extents.0, half_extents.1) },
+            ZoneShapeData::Circle { radius } => ZoneShape::Circle { radius },
+        }
+    }
+}
+
+impl From<ZoneShape> for ZoneShapeData {
+    fn from(shape: ZoneShape) -> Self {
+        match shape {
+            ZoneShape::Rect { half_extents } => ZoneShapeData::Rect { half_extents: (half_extents.x, half_extents.y) },
+            ZoneShape::Circle { radius } => ZoneShapeData::Circle { radius },
+        }
+    }
+}
+
+/// Add the effector a scene component describes to `entity`. Other
+/// components are left alone.
+pub fn add_effector(world: &mut World, entity: EntityId, component: &ComponentData) -> Result<(), String> {
+    let added = match *component {
+        ComponentData::AreaForce { shape, force } => {
+            world.add_component(&entity, AreaForce::new(shape.into(), Vec2::new(force.0, force.1)))
+        }
+        ComponentData::Buoyancy { shape, surface, density, drag } => world.add_component(
+            &entity,
+            Buoyancy::new(shape.into()).with_surface(surface).with_density(density).with_drag(drag),
+        ),
+        ComponentData::Drag { shape, damping } => world.add_component(&entity, Drag::new(shape.into(), damping)),
+        _ => return Ok(()),
+    };
+    added.map_err(|e| e.to_string())
+}
+
+/// Scene entries for the effectors on `entity`.
+pub fn effector_data(world: &World, entity: EntityId) -> Vec<ComponentData> {
+    let mut components = Vec::new();
+    if let Some(area) = world.get::<AreaForce>(entity) {
+        components.push(ComponentData::AreaForce { shape: area.shape.into(), force: (area.force.x, area.force.y) });
+    }
+    if let Some(fluid) = world.get::<Buoyancy>(entity) {
+        components.push(ComponentData::Buoyancy {
+            shape: fluid.shape.into(),
+            surface: fluid.surface,
+            density: fluid.density,
+            drag: fluid.drag,
+        });
+    }
+    if let Some(drag) = world.get::<Drag>(entity) {
+        components.push(ComponentData::Drag { shape: drag.shape.into(), damping: drag.damping });
+    }
+    components
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_effectors_round_trip_through_scene_data() {
+        let mut source = World::new();
+        let entity = source.create_entity();
+        let lake = Buoyancy::new(ZoneShape::circle(90.0)).with_surface(12.0).with_density(1.5).with_drag(0.5);
+        source.add_component(&entity, AreaForce::new(ZoneShape::rect(40.0, 20.0), Vec2::new(3.0, -4.0))).unwrap();
+        source.add_component(&entity, lake.clone()).unwrap();
+        source.add_component(&entity, Drag::new(ZoneShape::rect(10.0, 10.0), 6.0)).unwrap();
+
+        let data = effector_data(&source, entity);
+        assert_eq!(data.len(), 3);
+        let mut loaded = World::new();
+        let copy = loaded.create_entity();
+        for component in &data {
+            add_effector(&mut loaded, copy, component).unwrap();
+        }
+        assert_eq!(loaded.get::<AreaForce>(copy), source.get::<AreaForce>(entity));
+        assert_eq!(loaded.get::<Buoyancy>(copy), Some(&lake));
+        assert_eq!(loaded.get::<Drag>(copy), source.get::<Drag>(entity));
+    }
+}
@@ -1,22 +1,29 @@
 //! Scene loader for RON scene files
 //!
 //! This module provides functionality to load scenes from RON files,
-//! resolve prefabs, and instantiate entities in the ECS world.
+//! resolve prefabs, instantiate entities in the ECS world, and expand
+//! nested scene references (see `nested`).
 
 use std::collections::HashMap;
 use std::path::Path;
 
 use glam::Vec2;
 
-use ecs::sprite_components::{Camera, Name, Sprite, SpriteAnimation, Transform2D};
+use ecs::sprite_components::{AnimationEvent, Camera, Name, Sprite, SpriteAnimation, Transform2D};
 use ecs::{EntityId, World, WorldHierarchyExt};
 
+use crate::scene_migration::{migrate_legacy_sprite_scale, needs_sprite_scale_migration};
 use crate::texture_ref::TextureResolver;
 use crate::scene_data::{
-    ColliderShapeData, ComponentData, EntityData, PhysicsSettings, PrefabData,
-    RigidBodyTypeData, SceneData, SceneLoadError,
+    ComponentData, EditorSettings, EntityData, PhysicsMaterialData, PhysicsSettings, PrefabData, SceneData,
+    SceneLoadError, StreamingSettings,
 };
 
+mod nested;
+mod physics_components;
+
+use nested::SceneStack;
+
 /// Result of loading a scene
 #[derive(Debug)]
 pub struct SceneInstance {
@@ -33,6 +40,18 @@ pub struct SceneInstance {
     /// The scene's prefab table, retained for runtime spawning via
     /// [`spawn_prefab`](Self::spawn_prefab).
     pub prefabs: HashMap<String, PrefabData>,
+    /// Format version of the source file; prefabs spawned from a legacy
+    /// scene get the same sprite-scale upgrade as its entities.
+    pub format_version: u32,
+    /// The scene's physics material table; named colliders (including those
+    /// of runtime-spawned prefabs) resolve against it.
+    pub materials: HashMap<String, PhysicsMaterialData>,
+    /// Chunk layout, when the scene is set up for world streaming
+    pub streaming: Option<StreamingSettings>,
+    /// Editor settings block (camera, hidden/locked entities), if saved
+    pub editor: Option<EditorSettings>,
+    /// Invalid component data found after loading (also logged as warnings)
+    pub component_errors: Vec<ecs::ComponentError>,
 }
 
 impl SceneInstance {
@@ -74,6 +93,11 @@ impl SceneInstance {
                 return Err(e);
             }
         }
+        if needs_sprite_scale_migration(self.format_version) {
+            migrate_legacy_sprite_scale(world, &[entity_id], assets.texture_sizes());
+        }
+        #[cfg(feature = "physics")]
+        crate::scene_materials::apply_scene_materials(world, &[entity_id], &self.materials);
         Ok(entity_id)
     }
 }
@@ -98,11 +122,74 @@ impl SceneLoader {
     /// This creates all entities defined in the scene, resolving prefabs
     /// and applying overrides as specified. Parent-child relationships are
     /// established based on the `parent` field or inline `children`.
+    /// Scenes referenced by `SceneInstanceRef` components are instantiated
+    /// too, with paths relative to the working directory (use
+    /// [`load_and_instantiate`](Self::load_and_instantiate) to resolve them
+    /// against the scene file's directory).
     pub fn instantiate(
         data: &SceneData,
         world: &mut World,
         assets: &mut impl TextureResolver,
     ) -> Result<SceneInstance, SceneLoadError> {
+        Self::instantiate_from(data, world, assets, &mut SceneStack::default())
+    }
+
+    /// [`instantiate`](Self::instantiate) with nested scene references
+    /// resolved against the files on `scenes`.
+    fn instantiate_from(
+        data: &SceneData,
+        world: &mut World,
+        assets: &mut impl TextureResolver,
+        scenes: &mut SceneStack,
+    ) -> Result<SceneInstance, SceneLoadError> {
+        let (named_entities, entities) = Self::create_scene_entities(data, world, assets, scenes)?;
+
+        // The scene's layer order replaces the previous scene's.
+        world.insert_resource(ecs::RenderLayers::from_names(&data.layers));
+        world.insert_resource(data.sort_mode);
+
+        // Runtime saves resume from the tick they were written at.
+        if let Some(tick) = data.simulation_tick {
+            world.insert_resource(ecs::SimulationTick(tick));
+        }
+        if let Some(rng) = &data.rng {
+            world.insert_resource(rng.clone());
+        }
+
+        // Report bad component data now, not as NaNs mid-simulation
+        let component_errors = crate::validation::validate_components(world, entities.iter().copied());
+        for error in &component_errors {
+            log::warn!("Scene '{}': {}", data.name, error);
+        }
+
+        let entity_count = entities.len();
+
+        Ok(SceneInstance {
+            name: data.name.clone(),
+            physics: data.physics.clone(),
+            named_entities,
+            entities,
+            entity_count,
+            prefabs: data.prefabs.clone(),
+            format_version: data.format_version,
+            materials: data.materials.clone(),
+            streaming: data.streaming,
+            editor: data.editor.clone(),
+            component_errors,
+        })
+    }
+
+    /// Create a scene's entities (prefabs resolved, hierarchy linked, legacy
+    /// sprites upgraded, materials applied), then its nested scenes. World
+    /// resources are left alone, so nested scenes use this directly.
+    /// Returns the scene's named entities and every entity created, nested
+    /// ones included.
+    fn create_scene_entities(
+        data: &SceneData,
+        world: &mut World,
+        assets: &mut impl TextureResolver,
+        scenes: &mut SceneStack,
+    ) -> Result<(HashMap<String, EntityId>, Vec<EntityId>), SceneLoadError> {
         let mut named_entities = HashMap::new();
         let mut entities = Vec::new();
 
@@ -133,15 +220,6 @@ impl SceneLoader {
                                 entity_name, parent_name, e
                             );
                         }
-
-                        // Add GlobalTransform2D component if the entity has Transform2D
-                        if world.get::<Transform2D>(entity_id).is_some() {
-                            Self::add_component_logged(
-                                world,
-                                entity_id,
-                                ecs::hierarchy::GlobalTransform2D::default(),
-                            );
-                        }
                     } else {
                         log::warn!(
                             "Scene load: entity '{}' references parent '{}' but one of them was not found by name",
@@ -152,16 +230,21 @@ impl SceneLoader {
             }
         }
 
-        let entity_count = entities.len();
+        if needs_sprite_scale_migration(data.format_version) {
+            let migrated = migrate_legacy_sprite_scale(world, &entities, assets.texture_sizes());
+            if migrated > 0 {
+                log::info!("Scene '{}': upgraded {} sprite scale(s) to pixels-per-unit sizing", data.name, migrated);
+            }
+        }
 
-        Ok(SceneInstance {
-            name: data.name.clone(),
-            physics: data.physics.clone(),
-            named_entities,
-            entities,
-            entity_count,
-            prefabs: data.prefabs.clone(),
-        })
+        #[cfg(feature = "physics")]
+        crate::scene_materials::apply_scene_materials(world, &entities, &data.materials);
+
+        // Nested scenes last: they were upgraded and resolved by their own
+        // file's rules above.
+        Self::instantiate_scene_refs(world, assets, scenes, &mut entities)?;
+
+        Ok((named_entities, entities))
     }
 
     /// Add a component during scene instantiation, logging failures (e.g.
@@ -211,11 +294,6 @@ impl SceneLoader {
                     entity_id, parent, e
                 );
             }
-
-            // Add GlobalTransform2D component for hierarchical entities
-            if world.get::<Transform2D>(entity_id).is_some() {
-                Self::add_component_logged(world, entity_id, ecs::hierarchy::GlobalTransform2D::default());
-            }
         }
 
         // Create inline children recursively
@@ -234,14 +312,15 @@ impl SceneLoader {
         Ok(entity_id)
     }
 
-    /// Load a scene from file and instantiate it
+    /// Load a scene from file and instantiate it. Nested scene references
+    /// resolve against the file's directory.
     pub fn load_and_instantiate(
         path: impl AsRef<Path>,
         world: &mut World,
         assets: &mut impl TextureResolver,
     ) -> Result<SceneInstance, SceneLoadError> {
-        let data = Self::load_from_file(path)?;
-        Self::instantiate(&data, world, assets)
+        let data = Self::load_from_file(path.as_ref())?;
+        Self::instantiate_from(&data, world, assets, &mut SceneStack::rooted_at(path.as_ref()))
     }
 
     /// Create a single entity from EntityData
@@ -252,6 +331,9 @@ impl SceneLoader {
         assets: &mut impl TextureResolver,
     ) -> Result<EntityId, SceneLoadError> {
         let entity_id = world.create_entity();
+        if let Some(guid) = entity_data.guid {
+            Self::add_component_logged(world, entity_id, guid);
+        }
 
         // Get base components from prefab (if any)
         let base_components = if let Some(prefab_name) = &entity_data.prefab {
@@ -276,7 +358,7 @@ impl SceneLoader {
     }
 
     /// Merge prefab components with overrides and inline components
-    fn merge_components(
+    pub(crate) fn merge_components(
         base: &[ComponentData],
         overrides: &[ComponentData],
         inline: &[ComponentData],
@@ -314,8 +396,13 @@ impl SceneLoader {
             ComponentData::SpriteAnimation { .. } => "SpriteAnimation",
             ComponentData::RigidBody { .. } => "RigidBody",
             ComponentData::Collider { .. } => "Collider",
+            ComponentData::AreaForce { .. } => "AreaForce",
+            ComponentData::Buoyancy { .. } => "Buoyancy",
+            ComponentData::Drag { .. } => "Drag",
             ComponentData::Behavior(_) => "Behavior",
             ComponentData::EntityTag { .. } => "EntityTag",
+            ComponentData::Group => "Group",
+            ComponentData::SceneInstanceRef { .. } => "SceneInstanceRef",
             ComponentData::Dynamic { component_type, .. } => component_type.as_str(),
         }
     }
@@ -348,7 +435,9 @@ impl SceneLoader {
                 scale,
                 color,
                 depth,
+                layer,
                 emissive,
+                tex_region,
             } => {
                 let texture_handle = assets.resolve_texture(texture)?;
                 let sprite = Sprite {
@@ -358,9 +447,10 @@ impl SceneLoader {
                     scale: Vec2::new(scale.0, scale.1),
                     color: glam::Vec4::new(color.0, color.1, color.2, color.3),
                     depth: *depth,
+                    layer: layer.clone(),
                     visible: true,
                     emissive: *emissive,
-                    tex_region: [0.0, 0.0, 1.0, 1.0],
+                    tex_region: [tex_region.0, tex_region.1, tex_region.2, tex_region.3],
                 };
                 Self::add_component_logged(world, entity_id, sprite);
             }
@@ -411,6 +501,7 @@ impl SceneLoader {
                 frames,
                 playing,
                 loop_animation,
+                events,
             } => {
                 let animation = SpriteAnimation {
                     fps: *fps,
@@ -422,102 +513,19 @@ impl SceneLoader {
                     loop_animation: *loop_animation,
                     current_frame: 0,
                     time_accumulator: 0.0,
+                    events: events
+                        .iter()
+                        .map(|(frame, name)| AnimationEvent { frame: *frame, name: name.clone() })
+                        .collect(),
                 };
                 Self::add_component_logged(world, entity_id, animation);
             }
 
-            ComponentData::RigidBody {
-                body_type,
-                velocity,
-                angular_velocity,
-                gravity_scale,
-                linear_damping,
-                angular_damping,
-                can_rotate,
-                ccd_enabled,
-            } => {
-                #[cfg(feature = "physics")]
-                {
-                    use physics::components::RigidBody;
-
-                    let mut rigid_body = match body_type {
-                        RigidBodyTypeData::Dynamic => RigidBody::new_dynamic(),
-                        RigidBodyTypeData::Static => RigidBody::new_static(),
-                        RigidBodyTypeData::Kinematic => RigidBody::new_kinematic(),
-                    };
-
-                    rigid_body.velocity = Vec2::new(velocity.0, velocity.1);
-                    rigid_body.angular_velocity = *angular_velocity;
-                    rigid_body.gravity_scale = *gravity_scale;
-                    rigid_body.linear_damping = *linear_damping;
-                    rigid_body.angular_damping = *angular_damping;
-                    rigid_body.can_rotate = *can_rotate;
-                    rigid_body.ccd_enabled = *ccd_enabled;
-
-                    Self::add_component_logged(world, entity_id, rigid_body);
-                }
-
-                #[cfg(not(feature = "physics"))]
-                {
-                    log::warn!(
-                        "RigidBody component in scene but physics feature is disabled"
-                    );
-                    // Suppress unused variable warnings
-                    let _ = (body_type, velocity, angular_velocity, gravity_scale,
-                             linear_damping, angular_damping, can_rotate, ccd_enabled);
-                }
-            }
-
-            ComponentData::Collider {
-                shape,
-                offset,
-                is_sensor,
-                friction,
-                restitution,
-            } => {
-                #[cfg(feature = "physics")]
-                {
-                    use physics::components::{Collider, ColliderShape};
-
-                    let collider_shape = match shape {
-                        ColliderShapeData::Box { half_extents } => ColliderShape::Box {
-                            half_extents: Vec2::new(half_extents.0, half_extents.1),
-                        },
-                        ColliderShapeData::Circle { radius } => {
-                            ColliderShape::Circle { radius: *radius }
-                        }
-                        ColliderShapeData::CapsuleY { half_height, radius } => {
-                            ColliderShape::CapsuleY {
-                                half_height: *half_height,
-                                radius: *radius,
-                            }
-                        }
-                        ColliderShapeData::CapsuleX { half_height, radius } => {
-                            ColliderShape::CapsuleX {
-                                half_height: *half_height,
-                                radius: *radius,
-                            }
-                        }
-                    };
-
-                    let mut collider = Collider::new(collider_shape);
-                    collider.offset = Vec2::new(offset.0, offset.1);
-                    collider.is_sensor = *is_sensor;
-                    collider.friction = *friction;
-                    collider.restitution = *restitution;
-
-                    Self::add_component_logged(world, entity_id, collider);
-                }
-
-                #[cfg(not(feature = "physics"))]
-                {
-                    log::warn!(
-                        "Collider component in scene but physics feature is disabled"
-                    );
-                    // Suppress unused variable warnings
-                    let _ = (shape, offset, is_sensor, friction, restitution);
-                }
-            }
+            ComponentData::RigidBody { .. }
+            | ComponentData::Collider { .. }
+            | ComponentData::AreaForce { .. }
+            | ComponentData::Buoyancy { .. }
+            | ComponentData::Drag { .. } => Self::add_physics_component(entity_id, component, world),
 
             ComponentData::Behavior(behavior_data) => {
                 let behavior: ecs::behavior::Behavior = behavior_data.into();
@@ -528,8 +536,16 @@ impl SceneLoader {
                 Self::add_component_logged(world, entity_id, ecs::behavior::EntityTag::new(tag.clone()));
             }
 
+            ComponentData::Group => {
+                Self::add_component_logged(world, entity_id, ecs::Group);
+            }
+
+            ComponentData::SceneInstanceRef { path } => {
+                Self::add_component_logged(world, entity_id, ecs::SceneInstanceRef::new(path.clone()));
+            }
+
             ComponentData::Dynamic { component_type, data } => {
-                // Use the component registry to create the component
+                // Built-ins and anything registered at runtime (plugins)
                 let registry = ecs::component_registry::global_registry();
 
                 if !registry.is_registered(component_type) {
@@ -539,24 +555,14 @@ impl SceneLoader {
                     )));
                 }
 
-                // Create the component via factory
-                match registry.create_component(component_type, data.clone()) {
-                    Ok(_boxed_component) => {
-                        // TODO: World needs type-erased component addition to fully support this.
-                        // For now, we validate the component can be created but log a warning.
-                        log::warn!(
-                            "Dynamic component '{}' created but World lacks type-erased storage. \
-                             Use explicit ComponentData variants for now.",
-                            component_type
-                        );
-                    }
-                    Err(e) => {
-                        return Err(SceneLoadError::ComponentError(format!(
+                registry
+                    .insert_component(world, entity_id, component_type, data.clone())
+                    .map_err(|e| {
+                        SceneLoadError::ComponentError(format!(
                             "Failed to create component '{}': {}",
                             component_type, e
-                        )));
-                    }
-                }
+                        ))
+                    })?;
             }
         }
 
@@ -568,38 +574,4 @@ impl SceneLoader {
 // Parse-level tests (public API) live in `tests/scene_loader_parse.rs`;
 // only tests needing private methods stay inline.
 #[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_entity_tag_component_type_name() {
-        let tag = ComponentData::EntityTag { tag: "enemy".to_string() };
-        assert_eq!(SceneLoader::component_type_name(&tag), "EntityTag");
-    }
-
-    #[test]
-    fn test_merge_components() {
-        let base = vec![ComponentData::Transform2D {
-            position: (0.0, 0.0),
-            rotation: 0.0,
-            scale: (1.0, 1.0),
-        }];
-
-        let overrides = vec![ComponentData::Transform2D {
-            position: (100.0, 200.0),
-            rotation: 0.0,
-            scale: (1.0, 1.0),
-        }];
-
-        let inline = vec![];
-
-        let merged = SceneLoader::merge_components(&base, &overrides, &inline);
-        assert_eq!(merged.len(), 1);
-
-        if let ComponentData::Transform2D { position, .. } = &merged[0] {
-            assert_eq!(*position, (100.0, 200.0));
-        } else {
-            panic!("Expected Transform2D");
-        }
-    }
-}
+mod tests;
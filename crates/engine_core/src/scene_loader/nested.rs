@@ -0,0 +1,136 @@
+//! Nested scene instancing.
+//!
+//! An entity with an `ecs::SceneInstanceRef` embeds another scene file: once
+//! the referencing scene's entities exist, the referenced file is loaded and
+//! its entities are created as children of that entity (its top-level
+//! entities become the entity's children, so its `Transform2D` places the
+//! whole nested scene). Nested scenes may reference further scenes; a
+//! reference back to a file already being loaded is a
+//! [`SceneLoadError::SceneReferenceCycle`].
+//!
+//! Only entities come across: a nested scene's layers, physics settings,
+//! prefab table and editor block are ignored, and its entity names aren't
+//! added to the outer scene's `named_entities` (several instances of one
+//! scene would collide). For the same reason each nested entity's saved
+//! GUID is re-keyed with [`Guid::nested_in`] the referencing entity's GUID,
+//! so every instance resolves on its own and stays stable across loads.
+
+use std::path::{Path, PathBuf};
+
+use ecs::{EntityId, Guid, Parent, SceneInstanceRef, World, WorldHierarchyExt};
+
+use super::SceneLoader;
+use crate::scene_data::SceneLoadError;
+use crate::texture_ref::TextureResolver;
+
+/// Scene files currently being loaded, outermost first. References resolve
+/// against the innermost file's directory (the working directory when the
+/// outermost scene didn't come from a file).
+#[derive(Debug, Default)]
+pub(super) struct SceneStack {
+    files: Vec<PathBuf>,
+}
+
+impl SceneStack {
+    /// A stack holding the scene file at `path`.
+    pub(super) fn rooted_at(path: &Path) -> Self {
+        Self { files: vec![canonical(path)] }
+    }
+
+    /// Path of the scene file `reference` names.
+    fn resolve(&self, reference: &str) -> PathBuf {
+        match self.files.last().and_then(|file| file.parent()) {
+            Some(directory) => canonical(&directory.join(reference)),
+            None => canonical(Path::new(reference)),
+        }
+    }
+
+    /// "a.ron -> b.ron -> a.ron" for a reference to `path` from the top of
+    /// the stack.
+    fn chain_to(&self, path: &Path) -> String {
+        self.files
+            .iter()
+            .map(PathBuf::as_path)
+            .chain(std::iter::once(path))
+            .map(file_label)
+            .collect::<Vec<_>>()
+            .join(" -> ")
+    }
+}
+
+/// A scene file's name, or its whole path when it has none.
+fn file_label(path: &Path) -> String {
+    path.file_name()
+        .map_or_else(|| path.display().to_string(), |name| name.to_string_lossy().into_owned())
+}
+
+/// `path` made absolute with symlinks resolved, so one file always compares
+/// equal to itself; unchanged when it can't be (e.g. it doesn't exist —
+/// loading reports that).
+fn canonical(path: &Path) -> PathBuf {
+    std::fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf())
+}
+
+impl SceneLoader {
+    /// Instantiate the scene referenced by every `SceneInstanceRef` among
+    /// `entities`, parenting each nested scene's top-level entities under
+    /// the referencing entity. The nested entities are appended to
+    /// `entities`.
+    pub(super) fn instantiate_scene_refs(
+        world: &mut World,
+        assets: &mut impl TextureResolver,
+        scenes: &mut SceneStack,
+        entities: &mut Vec<EntityId>,
+    ) -> Result<(), SceneLoadError> {
+        let references: Vec<(EntityId, String)> = entities
+            .iter()
+            .filter_map(|&entity| world.get::<SceneInstanceRef>(entity).map(|r| (entity, r.path.clone())))
+            .collect();
+
+        for (owner, reference) in references {
+            if reference.is_empty() {
+                log::warn!("Scene load: entity {:?} has a scene reference without a path", owner);
+                continue;
+            }
+            let path = scenes.resolve(&reference);
+            if scenes.files.contains(&path) {
+                return Err(SceneLoadError::SceneReferenceCycle(scenes.chain_to(&path)));
+            }
+            let data = Self::load_from_file(&path).inspect_err(|e| {
+                log::warn!("Scene load: referenced scene {} failed to load: {}", path.display(), e);
+            })?;
+
+            scenes.files.push(path);
+            let created = Self::create_scene_entities(&data, world, assets, scenes);
+            scenes.files.pop();
+            let (_, nested) = created?;
+            Self::rekey_nested_guids(world, owner, &nested);
+
+            for &entity in &nested {
+                if world.get::<Parent>(entity).is_some() {
+                    continue;
+                }
+                if let Err(e) = world.set_parent(entity, owner) {
+                    log::warn!("Scene load: failed to parent nested entity {:?} under {:?}: {}", entity, owner, e);
+                }
+            }
+            entities.extend(nested);
+        }
+        Ok(())
+    }
+
+    /// Give `nested` (the entities of one scene instance) GUIDs scoped to
+    /// `owner`, before another instance of the same file can claim the
+    /// GUIDs it saved.
+    fn rekey_nested_guids(world: &mut World, owner: EntityId, nested: &[EntityId]) {
+        let Some(owner_guid) = world.guid_of(owner) else {
+            return;
+        };
+        for &entity in nested {
+            if let Some(guid) = world.guid_of(entity) {
+                Self::add_component_logged(world, entity, guid.nested_in(owner_guid));
+            }
+        }
+    }
+}
+
@@ -0,0 +1,121 @@
+//! Physics components of scene entities: rigid bodies, colliders and
+//! effector zones. Collider material names are only recorded here; they
+//! resolve against the scene's material table once every entity exists
+//! (see `scene_materials`).
+
+use ecs::{EntityId, World};
+
+use crate::scene_data::ComponentData;
+
+use super::SceneLoader;
+
+impl SceneLoader {
+    /// Add a `RigidBody`, `Collider` or effector (`AreaForce`, `Buoyancy`,
+    /// `Drag`). Without the `physics` feature they are skipped with a
+    /// warning.
+    pub(super) fn add_physics_component(entity_id: EntityId, component: &ComponentData, world: &mut World) {
+        #[cfg(not(feature = "physics"))]
+        {
+            let _ = (entity_id, world);
+            log::warn!(
+                "{} component in scene but physics feature is disabled",
+                Self::component_type_name(component)
+            );
+        }
+
+        #[cfg(feature = "physics")]
+        match component {
+            ComponentData::RigidBody {
+                body_type,
+                velocity,
+                angular_velocity,
+                gravity_scale,
+                linear_damping,
+                angular_damping,
+                can_rotate,
+                ccd_enabled,
+                can_sleep,
+            } => {
+                use crate::scene_data::RigidBodyTypeData;
+                use physics::components::RigidBody;
+
+                let mut rigid_body = match body_type {
+                    RigidBodyTypeData::Dynamic => RigidBody::new_dynamic(),
+                    RigidBodyTypeData::Static => RigidBody::new_static(),
+                    RigidBodyTypeData::Kinematic => RigidBody::new_kinematic(),
+                };
+
+                rigid_body.velocity = glam::Vec2::new(velocity.0, velocity.1);
+                rigid_body.angular_velocity = *angular_velocity;
+                rigid_body.gravity_scale = *gravity_scale;
+                rigid_body.linear_damping = *linear_damping;
+                rigid_body.angular_damping = *angular_damping;
+                rigid_body.can_rotate = *can_rotate;
+                rigid_body.ccd_enabled = *ccd_enabled;
+                rigid_body.can_sleep = *can_sleep;
+
+                Self::add_component_logged(world, entity_id, rigid_body);
+            }
+
+            ComponentData::Collider {
+                shape,
+                offset,
+                is_sensor,
+                friction,
+                restitution,
+                material,
+                friction_combine,
+                restitution_combine,
+            } => {
+                use crate::scene_data::ColliderShapeData;
+                use glam::Vec2;
+                use physics::components::{Collider, ColliderShape};
+
+                let collider_shape = match shape {
+                    ColliderShapeData::Box { half_extents } => ColliderShape::Box {
+                        half_extents: Vec2::new(half_extents.0, half_extents.1),
+                    },
+                    ColliderShapeData::Circle { radius } => {
+                        ColliderShape::Circle { radius: *radius }
+                    }
+                    ColliderShapeData::CapsuleY { half_height, radius } => {
+                        ColliderShape::CapsuleY {
+                            half_height: *half_height,
+                            radius: *radius,
+                        }
+                    }
+                    ColliderShapeData::CapsuleX { half_height, radius } => {
+                        ColliderShape::CapsuleX {
+                            half_height: *half_height,
+                            radius: *radius,
+                        }
+                    }
+                };
+
+                let mut collider = Collider::new(collider_shape);
+                collider.offset = Vec2::new(offset.0, offset.1);
+                collider.is_sensor = *is_sensor;
+                collider.friction = *friction;
+                collider.restitution = *restitution;
+                // Named materials are re-resolved once the whole entity
+                // set exists (see `scene_materials`)
+                collider.material = material.clone();
+                collider.friction_combine = (*friction_combine).into();
+                collider.restitution_combine = (*restitution_combine).into();
+
+                Self::add_component_logged(world, entity_id, collider);
+            }
+
+            _ => {
+                if let Err(e) = crate::scene_effectors::add_effector(world, entity_id, component) {
+                    log::warn!(
+                        "Scene load: failed to add {} to entity {:?}: {}",
+                        Self::component_type_name(component),
+                        entity_id,
+                        e
+                    );
+                }
+            }
+        }
+    }
+}
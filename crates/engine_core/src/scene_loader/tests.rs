@@ -0,0 +1,38 @@
+use super::*;
+
+#[test]
+fn test_entity_tag_component_type_name() {
+    let tag = ComponentData::EntityTag { tag: "enemy".to_string() };
+    assert_eq!(SceneLoader::component_type_name(&tag), "EntityTag");
+}
+
+#[test]
+fn test_group_component_type_name() {
+    assert_eq!(SceneLoader::component_type_name(&ComponentData::Group), "Group");
+}
+
+#[test]
+fn test_merge_components() {
+    let base = vec![ComponentData::Transform2D {
+        position: (0.0, 0.0),
+        rotation: 0.0,
+        scale: (1.0, 1.0),
+    }];
+
+    let overrides = vec![ComponentData::Transform2D {
+        position: (100.0, 200.0),
+        rotation: 0.0,
+        scale: (1.0, 1.0),
+    }];
+
+    let inline = vec![];
+
+    let merged = SceneLoader::merge_components(&base, &overrides, &inline);
+    assert_eq!(merged.len(), 1);
+
+    if let ComponentData::Transform2D { position, .. } = &merged[0] {
+        assert_eq!(*position, (100.0, 200.0));
+    } else {
+        panic!("Expected Transform2D");
+    }
+}
@@ -0,0 +1,152 @@
+//! Physics material resolution for scenes.
+//!
+//! Colliders name their material; on load the name is looked up in the
+//! scene's `materials` table, then in the built-in
+//! [`PhysicsMaterial`] presets, and the material's values replace the
+//! collider's inline ones. Unknown names keep the inline values.
+
+use std::collections::HashMap;
+
+use ecs::{EntityId, World};
+use physics::{CombineRule, Collider, PhysicsMaterial};
+
+use crate::scene_data::{CombineRuleData, PhysicsMaterialData};
+
+impl From<CombineRuleData> for CombineRule {
+    fn from(rule: CombineRuleData) -> Self {
+        match rule {
+            CombineRuleData::Average => CombineRule::Average,
+            CombineRuleData::Min => CombineRule::Min,
+            CombineRuleData::Multiply => CombineRule::Multiply,
+            CombineRuleData::Max => CombineRule::Max,
+        }
+    }
+}
+
+impl From<CombineRule> for CombineRuleData {
+    fn from(rule: CombineRule) -> Self {
+        match rule {
+            CombineRule::Average => CombineRuleData::Average,
+            CombineRule::Min => CombineRuleData::Min,
+            CombineRule::Multiply => CombineRuleData::Multiply,
+            CombineRule::Max => CombineRuleData::Max,
+        }
+    }
+}
+
+impl PhysicsMaterialData {
+    /// The runtime material under `name`.
+    pub fn to_material(&self, name: &str) -> PhysicsMaterial {
+        PhysicsMaterial::new(name, self.friction, self.restitution)
+            .with_friction_combine(self.friction_combine.into())
+            .with_restitution_combine(self.restitution_combine.into())
+    }
+}
+
+impl From<&PhysicsMaterial> for PhysicsMaterialData {
+    fn from(material: &PhysicsMaterial) -> Self {
+        Self {
+            friction: material.friction,
+            restitution: material.restitution,
+            friction_combine: material.friction_combine.into(),
+            restitution_combine: material.restitution_combine.into(),
+        }
+    }
+}
+
+/// Look up a material by name: scene materials first, then presets.
+pub fn resolve_material(name: &str, scene_materials: &HashMap<String, PhysicsMaterialData>) -> Option<PhysicsMaterial> {
+    match scene_materials.get(name) {
+        Some(data) => Some(data.to_material(name)),
+        None => PhysicsMaterial::preset(name),
+    }
+}
+
+/// Every material a collider in the scene can reference: the presets in
+/// their fixed order, then scene materials sorted by name. A scene
+/// material replaces the preset it shadows in place.
+pub fn material_library(scene_materials: &HashMap<String, PhysicsMaterialData>) -> Vec<PhysicsMaterial> {
+    let mut library: Vec<PhysicsMaterial> = PhysicsMaterial::presets()
+        .into_iter()
+        .map(|preset| resolve_material(&preset.name, scene_materials).unwrap_or(preset))
+        .collect();
+    let mut extra: Vec<&String> = scene_materials
+        .keys()
+        .filter(|name| PhysicsMaterial::preset(name).is_none())
+        .collect();
+    extra.sort();
+    library.extend(extra.into_iter().map(|name| scene_materials[name].to_material(name)));
+    library
+}
+
+/// Re-apply named materials to the colliders of `entities`. Returns how
+/// many colliders were updated; unknown names are logged and left alone.
+pub fn apply_scene_materials(
+    world: &mut World,
+    entities: &[EntityId],
+    scene_materials: &HashMap<String, PhysicsMaterialData>,
+) -> usize {
+    let mut applied = 0;
+    for &entity in entities {
+        let Some(name) = world.get::<Collider>(entity).and_then(|c| c.material.clone()) else {
+            continue;
+        };
+        match resolve_material(&name, scene_materials) {
+            Some(material) => {
+                if let Some(collider) = world.get_mut::<Collider>(entity) {
+                    collider.set_material(&material);
+                    applied += 1;
+                }
+            }
+            None => log::warn!("Scene load: unknown physics material '{}' on entity {}", name, entity),
+        }
+    }
+    applied
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sticky() -> HashMap<String, PhysicsMaterialData> {
+        let data = PhysicsMaterialData {
+            friction: 1.0,
+            restitution: 0.0,
+            friction_combine: CombineRuleData::Max,
+            restitution_combine: CombineRuleData::Min,
+        };
+        HashMap::from([("sticky".to_string(), data.clone()), ("ice".to_string(), data)])
+    }
+
+    #[test]
+    fn test_scene_materials_shadow_presets() {
+        let materials = sticky();
+        let ice = resolve_material("ice", &materials).unwrap();
+        assert_eq!(ice.friction, 1.0, "scene definition wins");
+        assert_eq!(resolve_material("rubber", &materials), PhysicsMaterial::preset("rubber"));
+        assert_eq!(resolve_material("nope", &materials), None);
+
+        let names: Vec<String> = material_library(&materials).into_iter().map(|m| m.name).collect();
+        assert_eq!(names.len(), PhysicsMaterial::PRESET_NAMES.len() + 1);
+        assert_eq!(names[1], "ice", "shadowed preset keeps its slot");
+        assert_eq!(names.last().map(String::as_str), Some("sticky"));
+    }
+
+    #[test]
+    fn test_apply_scene_materials_updates_named_colliders() {
+        let mut world = World::new();
+        let mut named = Collider::box_collider(10.0, 10.0);
+        named.material = Some("sticky".to_string());
+        let mut unknown = Collider::box_collider(10.0, 10.0).with_friction(0.2);
+        unknown.material = Some("lava".to_string());
+        let a = world.spawn((named,)).id();
+        let b = world.spawn((unknown,)).id();
+        let c = world.spawn((Collider::default(),)).id();
+
+        assert_eq!(apply_scene_materials(&mut world, &[a, b, c], &sticky()), 1);
+        let collider = world.get::<Collider>(a).unwrap();
+        assert_eq!(collider.friction, 1.0);
+        assert_eq!(collider.restitution_combine, CombineRule::Min);
+        assert_eq!(world.get::<Collider>(b).unwrap().friction, 0.2);
+    }
+}
@@ -0,0 +1,105 @@
+//! Scene format versioning and upgrades of older scene files.
+//!
+//! Version 0 (no `format_version` field) drew every sprite as a square of
+//! [`RENDER_UNIT`] world units times `sprite.scale`, regardless of texture
+//! size. Version 1 draws imported textures at their natural
+//! pixels-per-unit size (see [`texture_import`](crate::texture_import)), so
+//! loading a version-0 scene rescales its sprites to keep the size they
+//! were authored at.
+
+use ecs::sprite_components::{Sprite, SpriteAnimation};
+use ecs::{EntityId, World};
+use glam::Vec2;
+
+use crate::texture_import::TextureSizes;
+use crate::RENDER_UNIT;
+
+/// Scene format written by the serializer.
+pub const SCENE_FORMAT_VERSION: u32 = 1;
+
+/// Whether a scene of `format_version` predates pixels-per-unit sizing.
+pub fn needs_sprite_scale_migration(format_version: u32) -> bool {
+    format_version < 1
+}
+
+/// Rescale the sprites of `entities` from legacy (RENDER_UNIT square)
+/// sizing to natural texture sizing, so they draw at the same size as
+/// before. Sprites on generated textures are unchanged. Returns how many
+/// sprites were rescaled.
+pub fn migrate_legacy_sprite_scale(world: &mut World, entities: &[EntityId], sizes: &TextureSizes) -> usize {
+    let mut migrated = 0;
+    for &entity in entities {
+        // Animated sprites are sized by the frame they show, not the region
+        // stored on the sprite.
+        let frame = world
+            .get::<SpriteAnimation>(entity)
+            .and_then(|animation| animation.frames.get(animation.current_frame).copied());
+        let Some(sprite) = world.get_mut::<Sprite>(entity) else {
+            continue;
+        };
+        if !sizes.contains(sprite.texture_handle) {
+            continue;
+        }
+        let natural_size = sizes.sprite_size(sprite.texture_handle, frame.unwrap_or(sprite.tex_region));
+        if natural_size.x <= 0.0 || natural_size.y <= 0.0 {
+            continue;
+        }
+        sprite.scale *= Vec2::splat(RENDER_UNIT) / natural_size;
+        migrated += 1;
+    }
+    migrated
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn world_with_sprite(sprite: Sprite) -> (World, EntityId) {
+        let mut world = World::new();
+        let entity = world.create_entity();
+        world.add_component(&entity, sprite).expect("add sprite");
+        (world, entity)
+    }
+
+    #[test]
+    fn legacy_imported_sprite_keeps_its_drawn_size() {
+        let mut sizes = TextureSizes::new();
+        sizes.insert(5, 32, 16, 1.0);
+        let (mut world, entity) = world_with_sprite(Sprite::new(5).with_scale(Vec2::new(2.0, 1.0)));
+
+        assert_eq!(migrate_legacy_sprite_scale(&mut world, &[entity], &sizes), 1);
+
+        let sprite = world.get::<Sprite>(entity).expect("sprite");
+        // Legacy drawn size: (2, 1) * 80 = (160, 80)
+        assert_eq!(sprite.scale * sizes.sprite_size(5, sprite.tex_region), Vec2::new(160.0, 80.0));
+    }
+
+    #[test]
+    fn generated_texture_sprites_are_not_rescaled() {
+        let (mut world, entity) = world_with_sprite(Sprite::new(0).with_scale(Vec2::new(3.0, 3.0)));
+
+        assert_eq!(migrate_legacy_sprite_scale(&mut world, &[entity], &TextureSizes::new()), 0);
+        assert_eq!(world.get::<Sprite>(entity).expect("sprite").scale, Vec2::new(3.0, 3.0));
+    }
+
+    #[test]
+    fn animated_sprite_is_sized_by_its_current_frame() {
+        let mut sizes = TextureSizes::new();
+        sizes.insert(2, 64, 16, 1.0);
+        let (mut world, entity) = world_with_sprite(Sprite::new(2));
+        world
+            .add_component(&entity, SpriteAnimation::new(8.0, vec![[0.0, 0.0, 0.25, 1.0]]))
+            .expect("add animation");
+
+        migrate_legacy_sprite_scale(&mut world, &[entity], &sizes);
+
+        // Frame is 16x16 pixels, so scale 1 becomes 80 / 16 = 5
+        assert_eq!(world.get::<Sprite>(entity).expect("sprite").scale, Vec2::splat(5.0));
+    }
+
+    #[test]
+    fn only_unversioned_scenes_need_migration() {
+        assert!(needs_sprite_scale_migration(0));
+        assert!(!needs_sprite_scale_migration(SCENE_FORMAT_VERSION));
+    }
+}
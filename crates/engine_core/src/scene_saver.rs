@@ -0,0 +1,187 @@
+//! Safe scene writes: atomic replace, rotating backups and background saves.
+//!
+//! [`SceneSaver::save_to_file`] writes the RON to a temporary file next to
+//! the target and renames it over the target, so a crash or full disk
+//! mid-write never leaves a truncated scene behind. With
+//! [`with_backups`](SceneSaver::with_backups) the previous file is first
+//! copied to `<file>.bak1`, shifting older copies up to `<file>.bakN`.
+//! [`save_async`](SceneSaver::save_async) does the serialization and I/O on
+//! a worker thread so large scenes don't stall the frame.
+
+use std::fs::File;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver, TryRecvError};
+
+use crate::scene_data::SceneData;
+use crate::scene_serializer::serialize_to_ron;
+
+/// Writes scenes to disk atomically, optionally keeping backups.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SceneSaver {
+    backups: usize,
+}
+
+impl SceneSaver {
+    /// A saver that keeps no backups.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Keep up to `count` previous versions as `<file>.bak1` (newest) to
+    /// `<file>.bak<count>`. `0` disables backups.
+    pub fn with_backups(mut self, count: usize) -> Self {
+        self.backups = count;
+        self
+    }
+
+    /// Number of backups kept.
+    pub fn backups(&self) -> usize {
+        self.backups
+    }
+
+    /// Serialize `scene` and write it to `path` atomically, rotating backups.
+    pub fn save_to_file(&self, scene: &SceneData, path: &Path) -> Result<(), String> {
+        let ron_string = serialize_to_ron(scene)?;
+        self.write_atomic(path, ron_string.as_bytes())
+    }
+
+    /// Save on a worker thread; poll the returned handle for the outcome.
+    pub fn save_async(&self, scene: SceneData, path: PathBuf) -> SaveHandle {
+        let (sender, receiver) = mpsc::channel();
+        let saver = *self;
+        let target = path.clone();
+        std::thread::spawn(move || {
+            // The handle may have been dropped; the file is written either way
+            let _ = sender.send(saver.save_to_file(&scene, &target));
+        });
+        SaveHandle { path, receiver }
+    }
+
+    /// Write `contents` to `path` through a temporary file and a rename,
+    /// rotating backups of the file being replaced first.
+    pub fn write_atomic(&self, path: &Path, contents: &[u8]) -> Result<(), String> {
+        let temp = temp_path(path);
+        let written = File::create(&temp)
+            .and_then(|mut file| {
+                file.write_all(contents)?;
+                file.sync_all()
+            })
+            .map_err(|e| format!("Failed to write scene file: {}", e));
+        if let Err(e) = written {
+            let _ = std::fs::remove_file(&temp);
+            return Err(e);
+        }
+        if let Err(e) = self.rotate_backups(path) {
+            let _ = std::fs::remove_file(&temp);
+            return Err(e);
+        }
+        std::fs::rename(&temp, path).map_err(|e| {
+            let _ = std::fs::remove_file(&temp);
+            format!("Failed to replace scene file: {}", e)
+        })
+    }
+
+    /// Shift `.bak1..` up one slot (dropping the oldest) and copy the
+    /// current file into `.bak1`. Nothing to do for a new file.
+    fn rotate_backups(&self, path: &Path) -> Result<(), String> {
+        if self.backups == 0 || !path.is_file() {
+            return Ok(());
+        }
+        let backup_error = |e: std::io::Error| format!("Failed to back up scene file: {}", e);
+        for slot in (1..self.backups).rev() {
+            let from = backup_path(path, slot);
+            if from.exists() {
+                std::fs::rename(&from, backup_path(path, slot + 1)).map_err(backup_error)?;
+            }
+        }
+        std::fs::copy(path, backup_path(path, 1)).map_err(backup_error)?;
+        Ok(())
+    }
+}
+
+/// Path of backup `slot` (1 = newest) for `path`, e.g. `level.ron.bak1`.
+pub fn backup_path(path: &Path, slot: usize) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(format!(".bak{}", slot));
+    path.with_file_name(name)
+}
+
+/// Hidden sibling the new contents are written to before the rename (same
+/// directory, so the rename never crosses filesystems).
+fn temp_path(path: &Path) -> PathBuf {
+    let mut name = std::ffi::OsString::from(".");
+    name.push(path.file_name().unwrap_or_default());
+    name.push(".tmp");
+    path.with_file_name(name)
+}
+
+/// A background save started by [`SceneSaver::save_async`].
+#[derive(Debug)]
+pub struct SaveHandle {
+    path: PathBuf,
+    receiver: Receiver<Result<(), String>>,
+}
+
+impl SaveHandle {
+    /// The file being written.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// The outcome once the save has finished, `None` while it runs.
+    pub fn poll(&self) -> Option<Result<(), String>> {
+        match self.receiver.try_recv() {
+            Ok(result) => Some(result),
+            Err(TryRecvError::Empty) => None,
+            Err(TryRecvError::Disconnected) => Some(Err("Scene save thread stopped unexpectedly".to_string())),
+        }
+    }
+
+    /// Block until the save finishes.
+    pub fn wait(self) -> Result<(), String> {
+        self.receiver
+            .recv()
+            .unwrap_or_else(|_| Err("Scene save thread stopped unexpectedly".to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scene(name: &str) -> SceneData {
+        ron::from_str(&format!("SceneData(name: {:?}, entities: [EntityData()])", name)).unwrap()
+    }
+
+    #[test]
+    fn test_save_rotates_backups_and_leaves_no_temp_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("level.ron");
+        let saver = SceneSaver::new().with_backups(2);
+        for name in ["First", "Second", "Third", "Fourth"] {
+            saver.save_to_file(&scene(name), &path).unwrap();
+        }
+
+        let read = |path: &Path| std::fs::read_to_string(path).unwrap();
+        assert!(read(&path).contains("Fourth"));
+        assert!(read(&backup_path(&path, 1)).contains("Third"));
+        assert!(read(&backup_path(&path, 2)).contains("Second"));
+        assert!(!backup_path(&path, 3).exists(), "only two backups are kept");
+        assert!(!temp_path(&path).exists());
+    }
+
+    #[test]
+    fn test_async_save_writes_the_scene() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("async.ron");
+        let handle = SceneSaver::new().save_async(scene("Async"), path.clone());
+        assert_eq!(handle.path(), path);
+        handle.wait().unwrap();
+        assert!(std::fs::read_to_string(&path).unwrap().contains("Async"));
+
+        // Write failures come back through the handle
+        let missing = dir.path().join("missing").join("scene.ron");
+        assert!(SceneSaver::new().save_async(scene("Lost"), missing).wait().is_err());
+    }
+}
@@ -0,0 +1,77 @@
+//! How a saved scene records its structure: `Group` markers, and nested
+//! scene instances (`SceneInstanceRef`), which save only their reference.
+
+use ecs::{EntityId, World, WorldHierarchyExt};
+
+use crate::scene_data::ComponentData;
+
+/// Children saved under `entity`. A nested scene instance saves none: its
+/// children came from the referenced file and load from there again.
+pub(super) fn saved_children(world: &World, entity: EntityId) -> &[EntityId] {
+    match world.get::<ecs::SceneInstanceRef>(entity) {
+        Some(_) => &[],
+        None => world.get_children(entity).unwrap_or(&[]),
+    }
+}
+
+/// The `Group` and `SceneInstanceRef` components on `entity`, as scene data.
+pub(super) fn instance_data(world: &World, entity: EntityId) -> Vec<ComponentData> {
+    let mut components = Vec::new();
+    if world.get::<ecs::Group>(entity).is_some() {
+        components.push(ComponentData::Group);
+    }
+    if let Some(r) = world.get::<ecs::SceneInstanceRef>(entity) {
+        components.push(ComponentData::SceneInstanceRef { path: r.path.clone() });
+    }
+    components
+}
+
+#[cfg(test)]
+mod tests {
+    use ecs::sprite_components::{Name, Transform2D};
+    use ecs::{World, WorldHierarchyExt};
+    use glam::Vec2;
+
+    use crate::scene_data::{ComponentData, SceneData};
+    use crate::scene_serializer::tests::test_texture_path;
+    use crate::scene_serializer::{serialize_to_ron, world_to_scene_data};
+
+    #[test]
+    fn test_group_marker_round_trips() {
+        let mut world = World::new();
+        let group = world.spawn((Name::new("Props"), Transform2D::default(), ecs::Group)).id();
+        let prop = world.spawn((Transform2D::new(Vec2::new(5.0, 0.0)),)).id();
+        world.set_parent(prop, group).ok();
+
+        let scene = world_to_scene_data(&world, "GroupTest", None, &test_texture_path);
+        let ron_string = serialize_to_ron(&scene).expect("Serialize should succeed");
+        let parsed: SceneData = ron::from_str(&ron_string).expect("Parse should succeed");
+
+        let group_data = &parsed.entities[0];
+        assert_eq!(group_data.name.as_deref(), Some("Props"));
+        assert!(group_data.components.iter().any(|c| matches!(c, ComponentData::Group)));
+        assert_eq!(group_data.children.len(), 1);
+        assert!(!group_data.children[0].components.iter().any(|c| matches!(c, ComponentData::Group)));
+    }
+
+    #[test]
+    fn test_scene_instance_saves_only_its_reference() {
+        let mut world = World::new();
+        let room = world
+            .spawn((Name::new("West Room"), Transform2D::default(), ecs::SceneInstanceRef::new("rooms/room.scene.ron")))
+            .id();
+        let chest = world.spawn((Name::new("Chest"), Transform2D::default())).id();
+        world.set_parent(chest, room).ok();
+
+        let scene = world_to_scene_data(&world, "NestedTest", None, &test_texture_path);
+        let ron_string = serialize_to_ron(&scene).expect("Serialize should succeed");
+        let parsed: SceneData = ron::from_str(&ron_string).expect("Parse should succeed");
+
+        assert_eq!(parsed.entities.len(), 1);
+        let room_data = &parsed.entities[0];
+        assert!(room_data.components.iter().any(
+            |c| matches!(c, ComponentData::SceneInstanceRef { path } if path == "rooms/room.scene.ron")
+        ));
+        assert!(room_data.children.is_empty(), "nested entities come from the referenced file");
+    }
+}
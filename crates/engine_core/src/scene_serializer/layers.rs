@@ -0,0 +1,52 @@
+//! The world's render-layer order and sprite sort mode, saved on the scene
+//! itself (both are world resources, not components).
+
+use ecs::World;
+
+/// The `RenderLayers` order, empty when it is the built-in one.
+pub(super) fn saved_layers(world: &World) -> Vec<String> {
+    world
+        .resource::<ecs::RenderLayers>()
+        .filter(|layers| !layers.is_default())
+        .map(|layers| layers.names().to_vec())
+        .unwrap_or_default()
+}
+
+/// The `SpriteSortMode` resource, depth sorting when there is none.
+pub(super) fn saved_sort_mode(world: &World) -> ecs::SpriteSortMode {
+    world.resource::<ecs::SpriteSortMode>().copied().unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use ecs::World;
+
+    use crate::scene_data::SceneData;
+    use crate::scene_serializer::tests::test_texture_path;
+    use crate::scene_serializer::world_to_scene_data;
+
+    #[test]
+    fn test_custom_layer_order_is_saved() {
+        let mut world = World::new();
+        world.insert_resource(ecs::RenderLayers::new());
+        assert!(world_to_scene_data(&world, "Layers", None, &test_texture_path).layers.is_empty());
+
+        let mut layers = ecs::RenderLayers::new();
+        layers.move_layer("UI", 0);
+        world.insert_resource(layers);
+        let scene = world_to_scene_data(&world, "Layers", None, &test_texture_path);
+        assert_eq!(scene.layers, ["UI", "Background", "Default", "Foreground"]);
+    }
+
+    #[test]
+    fn test_isometric_sort_mode_round_trips() {
+        let mut world = World::new();
+        assert!(world_to_scene_data(&world, "Iso", None, &test_texture_path).sort_mode.is_depth());
+
+        world.insert_resource(ecs::SpriteSortMode::isometric(32.0));
+        let scene = world_to_scene_data(&world, "Iso", None, &test_texture_path);
+        let ron = ron::ser::to_string(&scene).unwrap();
+        let loaded: SceneData = ron::from_str(&ron).unwrap();
+        assert_eq!(loaded.sort_mode, ecs::SpriteSortMode::isometric(32.0));
+    }
+}
@@ -0,0 +1,248 @@
+//! Scene serializer — converts a live World into SceneData for RON serialization.
+//!
+//! This is the inverse of `scene_loader.rs`. It walks the ECS world, extracts
+//! known component types from each entity, and produces a `SceneData` structure
+//! that can be written to a `.scene.ron` file.
+
+use std::path::Path;
+
+use ecs::sprite_components::{Camera, Name, Sprite, SpriteAnimation, Transform2D};
+use ecs::{EntityId, World, WorldHierarchyExt};
+
+use crate::scene_data::*;
+
+mod instances;
+mod layers;
+
+/// Convert a World into SceneData suitable for RON serialization.
+///
+/// The `texture_path_fn` closure maps texture handle IDs back to their
+/// original path strings (e.g., `"#white"`, `"player.png"`). Callers with
+/// access to an `AssetManager` can use `assets.texture_path(handle)`;
+/// tests can provide a simple default.
+pub fn world_to_scene_data(
+    world: &World,
+    scene_name: &str,
+    physics_settings: Option<PhysicsSettings>,
+    texture_path_fn: &dyn Fn(u32) -> String,
+) -> SceneData {
+    let roots = world.get_root_entities();
+
+    let entities: Vec<EntityData> = roots
+        .iter()
+        .map(|&root| entity_to_entity_data(world, root, texture_path_fn))
+        .collect();
+
+    SceneData {
+        format_version: crate::scene_migration::SCENE_FORMAT_VERSION,
+        name: scene_name.to_string(),
+        physics: physics_settings,
+        editor: None,
+        prefabs: std::collections::HashMap::new(),
+        materials: std::collections::HashMap::new(),
+        streaming: None,
+        layers: layers::saved_layers(world),
+        sort_mode: layers::saved_sort_mode(world),
+        entities,
+        simulation_tick: world
+            .has_resource::<ecs::SimulationTick>()
+            .then(|| world.simulation_tick()),
+        rng: world.resource::<crate::rng::Rng>().cloned(),
+    }
+}
+
+/// Convert a single entity to EntityData, recursively including children.
+/// A nested scene instance writes only its reference: its children came
+/// from the referenced file and load from there again.
+fn entity_to_entity_data(
+    world: &World,
+    entity: EntityId,
+    texture_path_fn: &dyn Fn(u32) -> String,
+) -> EntityData {
+    let name = world.get::<Name>(entity).map(|n| n.as_str().to_string());
+    let components = extract_components(world, entity, texture_path_fn);
+
+    let children: Vec<EntityData> = instances::saved_children(world, entity)
+        .iter()
+        .map(|&child| entity_to_entity_data(world, child, texture_path_fn))
+        .collect();
+
+    EntityData {
+        name,
+        guid: world.guid_of(entity),
+        prefab: None,
+        parent: None,
+        overrides: Vec::new(),
+        components,
+        children,
+    }
+}
+
+/// Extract all serializable components from an entity as ComponentData variants.
+///
+/// Skips computed/internal components (GlobalTransform2D, Parent, Children)
+/// and audio components (not yet in ComponentData enum). The `Name` component
+/// is handled separately as `EntityData.name`.
+fn extract_components(
+    world: &World,
+    entity: EntityId,
+    texture_path_fn: &dyn Fn(u32) -> String,
+) -> Vec<ComponentData> {
+    let mut components = Vec::new();
+
+    // Transform2D
+    if let Some(t) = world.get::<Transform2D>(entity) {
+        components.push(ComponentData::Transform2D {
+            position: (t.position.x, t.position.y),
+            rotation: t.rotation,
+            scale: (t.scale.x, t.scale.y),
+        });
+    }
+
+    // Sprite
+    if let Some(s) = world.get::<Sprite>(entity) {
+        components.push(ComponentData::Sprite {
+            texture: texture_path_fn(s.texture_handle),
+            offset: (s.offset.x, s.offset.y),
+            rotation: s.rotation,
+            scale: (s.scale.x, s.scale.y),
+            color: (s.color.x, s.color.y, s.color.z, s.color.w),
+            depth: s.depth,
+            layer: s.layer.clone(),
+            emissive: s.emissive,
+            tex_region: (s.tex_region[0], s.tex_region[1], s.tex_region[2], s.tex_region[3]),
+        });
+    }
+
+    // Camera
+    if let Some(c) = world.get::<Camera>(entity) {
+        components.push(ComponentData::Camera2D {
+            position: (c.position.x, c.position.y),
+            rotation: c.rotation,
+            zoom: c.zoom,
+            viewport_size: (c.viewport_size.x, c.viewport_size.y),
+            is_main_camera: c.is_main_camera,
+        });
+    }
+
+    // Tilemap
+    if let Some(tm) = world.get::<ecs::Tilemap>(entity) {
+        components.push(ComponentData::Tilemap {
+            tileset: texture_path_fn(tm.tileset),
+            width: tm.width,
+            height: tm.height,
+            tile_size: tm.tile_size,
+            tiles: tm.tiles.clone(),
+            tile_uv_size: (tm.tile_uv_size.x, tm.tile_uv_size.y),
+            depth: tm.depth,
+        });
+    }
+
+    // SpriteAnimation
+    if let Some(a) = world.get::<SpriteAnimation>(entity) {
+        let frames: Vec<(f32, f32, f32, f32)> = a
+            .frames
+            .iter()
+            .map(|f| (f[0], f[1], f[2], f[3]))
+            .collect();
+        components.push(ComponentData::SpriteAnimation {
+            fps: a.fps,
+            frames,
+            playing: a.playing,
+            loop_animation: a.loop_animation,
+            events: a.events.iter().map(|e| (e.frame, e.name.clone())).collect(),
+        });
+    }
+
+    // RigidBody (behind physics feature)
+    #[cfg(feature = "physics")]
+    if let Some(rb) = world.get::<physics::components::RigidBody>(entity) {
+        let body_type = match rb.body_type {
+            physics::components::RigidBodyType::Dynamic => RigidBodyTypeData::Dynamic,
+            physics::components::RigidBodyType::Static => RigidBodyTypeData::Static,
+            physics::components::RigidBodyType::Kinematic => RigidBodyTypeData::Kinematic,
+        };
+        components.push(ComponentData::RigidBody {
+            body_type,
+            velocity: (rb.velocity.x, rb.velocity.y),
+            angular_velocity: rb.angular_velocity,
+            gravity_scale: rb.gravity_scale,
+            linear_damping: rb.linear_damping,
+            angular_damping: rb.angular_damping,
+            can_rotate: rb.can_rotate,
+            ccd_enabled: rb.ccd_enabled,
+            can_sleep: rb.can_sleep,
+        });
+    }
+
+    // Collider (behind physics feature)
+    #[cfg(feature = "physics")]
+    if let Some(col) = world.get::<physics::components::Collider>(entity) {
+        let shape = match &col.shape {
+            physics::components::ColliderShape::Box { half_extents } => {
+                ColliderShapeData::Box {
+                    half_extents: (half_extents.x, half_extents.y),
+                }
+            }
+            physics::components::ColliderShape::Circle { radius } => {
+                ColliderShapeData::Circle { radius: *radius }
+            }
+            physics::components::ColliderShape::CapsuleY { half_height, radius } => {
+                ColliderShapeData::CapsuleY {
+                    half_height: *half_height,
+                    radius: *radius,
+                }
+            }
+            physics::components::ColliderShape::CapsuleX { half_height, radius } => {
+                ColliderShapeData::CapsuleX {
+                    half_height: *half_height,
+                    radius: *radius,
+                }
+            }
+        };
+        components.push(ComponentData::Collider {
+            shape,
+            offset: (col.offset.x, col.offset.y),
+            is_sensor: col.is_sensor,
+            friction: col.friction,
+            restitution: col.restitution,
+            material: col.material.clone(),
+            friction_combine: col.friction_combine.into(),
+            restitution_combine: col.restitution_combine.into(),
+        });
+    }
+
+    // Effectors (behind physics feature) — conversions live in scene_effectors.rs
+    #[cfg(feature = "physics")]
+    components.extend(crate::scene_effectors::effector_data(world, entity));
+
+    // Behavior — conversion lives on `From<&Behavior> for BehaviorData` in scene_data.rs
+    if let Some(b) = world.get::<ecs::behavior::Behavior>(entity) {
+        components.push(ComponentData::Behavior(BehaviorData::from(b)));
+    }
+
+    // EntityTag
+    if let Some(t) = world.get::<ecs::behavior::EntityTag>(entity) {
+        components.push(ComponentData::EntityTag { tag: t.0.clone() });
+    }
+
+    // Group and SceneInstanceRef — conversions live in instances.rs
+    components.extend(instances::instance_data(world, entity));
+
+    components
+}
+
+/// Serialize SceneData to a pretty-printed RON string.
+pub fn serialize_to_ron(scene: &SceneData) -> Result<String, String> {
+    ron::ser::to_string_pretty(scene, ron::ser::PrettyConfig::default())
+        .map_err(|e| format!("RON serialization error: {}", e))
+}
+
+/// Write SceneData to a file as RON, atomically (see [`SceneSaver`](crate::SceneSaver)
+/// for backups and background saves).
+pub fn save_scene_to_file(scene: &SceneData, path: &Path) -> Result<(), String> {
+    crate::scene_saver::SceneSaver::new().save_to_file(scene, path)
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,482 @@
+//! Structural scene diff: entities added, removed, or moved to another
+//! parent, and component fields changed — keyed by entity GUID, so a
+//! reordered or reformatted file doesn't read as a wall of changes the way
+//! a text diff does. [`merge_scenes`](super::merge_scenes) merges on the
+//! same flattened view.
+//!
+//! Entities without a GUID (hand-written scenes) are keyed by name, or by
+//! their position in the file when unnamed or the name is taken. Values
+//! are compared as JSON; prefab instance `overrides` show up as
+//! `"<Component> (override)"`, and scene-level settings one level deep
+//! (`physics.gravity`, `prefabs.Enemy`, `layers`).
+
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use crate::scene_data::{ComponentData, EntityData, SceneData};
+
+use super::SceneToolsError;
+
+/// Stable identity of an entity across versions of a scene.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EntityKey {
+    /// The entity's persistent GUID (every editor-saved entity has one)
+    Guid(ecs::Guid),
+    /// A GUID-less entity's name
+    Name(String),
+    /// A GUID-less entity's position, e.g. `entities[2].children[0]`
+    Path(String),
+}
+
+impl fmt::Display for EntityKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EntityKey::Guid(guid) => write!(f, "{}", guid),
+            EntityKey::Name(name) | EntityKey::Path(name) => f.write_str(name),
+        }
+    }
+}
+
+/// One structural difference between two scenes.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "change", rename_all = "snake_case")]
+pub enum SceneChange {
+    /// A scene-level setting changed (`None` = absent)
+    SettingChanged { setting: String, old: Option<Value>, new: Option<Value> },
+    EntityAdded { entity: EntityKey, name: Option<String> },
+    EntityRemoved { entity: EntityKey, name: Option<String> },
+    /// The entity has a different parent (`None` = the scene root)
+    EntityMoved { entity: EntityKey, from: Option<EntityKey>, to: Option<EntityKey> },
+    ComponentAdded { entity: EntityKey, component: String },
+    ComponentRemoved { entity: EntityKey, component: String },
+    /// A field changed; `component` is `None` for the entity's own `name`
+    /// and `prefab`
+    FieldChanged {
+        entity: EntityKey,
+        component: Option<String>,
+        field: String,
+        old: Option<Value>,
+        new: Option<Value>,
+    },
+}
+
+impl SceneChange {
+    /// The entity the change is on (`None` for scene settings).
+    pub fn entity(&self) -> Option<&EntityKey> {
+        match self {
+            SceneChange::SettingChanged { .. } => None,
+            SceneChange::EntityAdded { entity, .. }
+            | SceneChange::EntityRemoved { entity, .. }
+            | SceneChange::EntityMoved { entity, .. }
+            | SceneChange::ComponentAdded { entity, .. }
+            | SceneChange::ComponentRemoved { entity, .. }
+            | SceneChange::FieldChanged { entity, .. } => Some(entity),
+        }
+    }
+
+    /// One-line description, e.g. `Player: Sprite.color [1,1,1,1] -> [1,0,0,1]`.
+    pub fn describe(&self) -> String {
+        match self {
+            SceneChange::SettingChanged { setting, old, new } => {
+                format!("{}: {} -> {}", setting, show(old.as_ref()), show(new.as_ref()))
+            }
+            SceneChange::EntityAdded { entity, name } => format!("added {}", label(entity, name.as_deref())),
+            SceneChange::EntityRemoved { entity, name } => format!("removed {}", label(entity, name.as_deref())),
+            SceneChange::EntityMoved { entity, from, to } => {
+                let parent = |key: &Option<EntityKey>| key.as_ref().map_or("root".to_string(), EntityKey::to_string);
+                format!("{}: moved from {} to {}", entity, parent(from), parent(to))
+            }
+            SceneChange::ComponentAdded { entity, component } => format!("{}: added {}", entity, component),
+            SceneChange::ComponentRemoved { entity, component } => format!("{}: removed {}", entity, component),
+            SceneChange::FieldChanged { entity, component, field, old, new } => {
+                let path = component.as_ref().map_or(field.clone(), |component| format!("{}.{}", component, field));
+                format!("{}: {} {} -> {}", entity, path, show(old.as_ref()), show(new.as_ref()))
+            }
+        }
+    }
+}
+
+/// `Player (6f1c…)`-style label for added/removed entities.
+fn label(entity: &EntityKey, name: Option<&str>) -> String {
+    match (name, entity) {
+        (Some(name), EntityKey::Guid(_)) => format!("{} ({})", name, entity),
+        _ => entity.to_string(),
+    }
+}
+
+fn show(value: Option<&Value>) -> String {
+    value.map_or("none".to_string(), Value::to_string)
+}
+
+/// Every structural difference between two versions of a scene.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct SceneDiff {
+    pub changes: Vec<SceneChange>,
+}
+
+impl SceneDiff {
+    /// Whether the scenes are structurally identical.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Pretty-printed JSON list of changes.
+    pub fn to_json(&self) -> Result<String, SceneToolsError> {
+        serde_json::to_string_pretty(self).map_err(json_error)
+    }
+}
+
+/// What changed from `old` to `new`: scene settings first, then entities
+/// in `new`'s order, then removed entities.
+pub fn diff_scenes(old: &SceneData, new: &SceneData) -> Result<SceneDiff, SceneToolsError> {
+    let (old_settings, new_settings) = (settings(old)?, settings(new)?);
+    let mut changes: Vec<SceneChange> = union_keys(&[old_settings.as_slice(), new_settings.as_slice()])
+        .into_iter()
+        .filter_map(|setting| {
+            let (old, new) = (lookup(&old_settings, &setting), lookup(&new_settings, &setting));
+            (old != new).then(|| SceneChange::SettingChanged { setting, old: old.cloned(), new: new.cloned() })
+        })
+        .collect();
+
+    let (old_entities, new_entities) = (flatten(old)?, flatten(new)?);
+    let previous: HashMap<&EntityKey, &FlatEntity> = old_entities.iter().map(|e| (&e.key, e)).collect();
+    for entity in &new_entities {
+        match previous.get(&entity.key) {
+            Some(before) => entity_changes(before, entity, &mut changes),
+            None => changes.push(SceneChange::EntityAdded { entity: entity.key.clone(), name: entity.name.clone() }),
+        }
+    }
+    let remaining: HashSet<&EntityKey> = new_entities.iter().map(|e| &e.key).collect();
+    for entity in old_entities.iter().filter(|e| !remaining.contains(&e.key)) {
+        changes.push(SceneChange::EntityRemoved { entity: entity.key.clone(), name: entity.name.clone() });
+    }
+    Ok(SceneDiff { changes })
+}
+
+/// Changes between two versions of one entity.
+fn entity_changes(old: &FlatEntity, new: &FlatEntity, changes: &mut Vec<SceneChange>) {
+    let entity = &new.key;
+    if old.parent != new.parent {
+        changes.push(SceneChange::EntityMoved { entity: entity.clone(), from: old.parent.clone(), to: new.parent.clone() });
+    }
+    for (field, old_value, new_value) in [("name", &old.name, &new.name), ("prefab", &old.prefab, &new.prefab)] {
+        if old_value != new_value {
+            changes.push(SceneChange::FieldChanged {
+                entity: entity.clone(),
+                component: None,
+                field: field.to_string(),
+                old: old_value.clone().map(Value::String),
+                new: new_value.clone().map(Value::String),
+            });
+        }
+    }
+
+    for slot in &new.components {
+        let Some(before) = old.slot(&slot.name) else {
+            changes.push(SceneChange::ComponentAdded { entity: entity.clone(), component: slot.name.clone() });
+            continue;
+        };
+        let (old_fields, new_fields) = (fields(&before.value), fields(&slot.value));
+        for field in union_keys(&[old_fields.as_slice(), new_fields.as_slice()]) {
+            let (old_value, new_value) = (lookup(&old_fields, &field), lookup(&new_fields, &field));
+            if old_value != new_value {
+                changes.push(SceneChange::FieldChanged {
+                    entity: entity.clone(),
+                    component: Some(slot.name.clone()),
+                    field,
+                    old: old_value.cloned(),
+                    new: new_value.cloned(),
+                });
+            }
+        }
+    }
+    for slot in old.components.iter().filter(|slot| new.slot(&slot.name).is_none()) {
+        changes.push(SceneChange::ComponentRemoved { entity: entity.clone(), component: slot.name.clone() });
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Flattened scene view (shared with merge)
+// ---------------------------------------------------------------------------
+
+/// Key/value pairs in order; `Null` values are left out (absent).
+pub(super) type Entries = Vec<(String, Value)>;
+
+/// One component of a [`FlatEntity`].
+#[derive(Debug, Clone, PartialEq)]
+pub(super) struct Slot {
+    /// Component type (the registry name for `Dynamic` components),
+    /// `(override)`-suffixed for prefab overrides, `#n`-suffixed for repeats
+    pub(super) name: String,
+    /// `ComponentData` variant the value belongs to
+    variant: String,
+    /// Whether this comes from the entity's `overrides` list
+    is_override: bool,
+    /// The variant's fields as JSON
+    pub(super) value: Value,
+}
+
+impl Slot {
+    fn new(component: &ComponentData, is_override: bool, taken: &[Slot]) -> Result<Self, SceneToolsError> {
+        let Value::Object(tagged) = serde_json::to_value(component).map_err(json_error)? else {
+            return Err(SceneToolsError::Serialize("component did not serialize as a tagged value".into()));
+        };
+        let Some((variant, value)) = tagged.into_iter().next() else {
+            return Err(SceneToolsError::Serialize("component serialized empty".into()));
+        };
+        let type_name = match value.get("type").and_then(Value::as_str) {
+            Some(registered) if variant == "Dynamic" => registered.to_string(),
+            _ => variant.clone(),
+        };
+        let base = if is_override { format!("{} (override)", type_name) } else { type_name };
+        let mut name = base.clone();
+        let mut repeat = 2;
+        while taken.iter().any(|slot| slot.name == name) {
+            name = format!("{} #{}", base, repeat);
+            repeat += 1;
+        }
+        Ok(Self { name, variant, is_override, value })
+    }
+
+    /// This slot holding `value` instead.
+    pub(super) fn with_value(&self, value: Value) -> Self {
+        Self { value, ..self.clone() }
+    }
+
+    fn to_component(&self) -> Result<ComponentData, SceneToolsError> {
+        let tagged: Map<String, Value> = std::iter::once((self.variant.clone(), self.value.clone())).collect();
+        serde_json::from_value(Value::Object(tagged)).map_err(json_error)
+    }
+}
+
+/// An entity with its hierarchy position reduced to a parent key.
+#[derive(Debug, Clone, PartialEq)]
+pub(super) struct FlatEntity {
+    pub(super) key: EntityKey,
+    pub(super) parent: Option<EntityKey>,
+    pub(super) name: Option<String>,
+    pub(super) guid: Option<ecs::Guid>,
+    pub(super) prefab: Option<String>,
+    pub(super) components: Vec<Slot>,
+}
+
+impl FlatEntity {
+    pub(super) fn slot(&self, name: &str) -> Option<&Slot> {
+        self.components.iter().find(|slot| slot.name == name)
+    }
+
+    /// Back to scene data, without children or a `parent` name (nesting
+    /// is rebuilt by the caller).
+    pub(super) fn to_entity_data(&self) -> Result<EntityData, SceneToolsError> {
+        let mut data = EntityData {
+            name: self.name.clone(),
+            guid: self.guid,
+            prefab: self.prefab.clone(),
+            ..Default::default()
+        };
+        for slot in &self.components {
+            let component = slot.to_component()?;
+            if slot.is_override {
+                data.overrides.push(component);
+            } else {
+                data.components.push(component);
+            }
+        }
+        Ok(data)
+    }
+}
+
+/// Every entity in the scene, depth-first in file order.
+pub(super) fn flatten(scene: &SceneData) -> Result<Vec<FlatEntity>, SceneToolsError> {
+    let mut flattener = Flattener::default();
+    for (index, entity) in scene.entities.iter().enumerate() {
+        flattener.visit(entity, None, format!("entities[{}]", index))?;
+    }
+    Ok(flattener.entities)
+}
+
+#[derive(Default)]
+struct Flattener {
+    entities: Vec<FlatEntity>,
+    used: HashSet<EntityKey>,
+    /// Latest entity defined under each name (what a `parent` name means)
+    by_name: HashMap<String, EntityKey>,
+}
+
+impl Flattener {
+    fn visit(&mut self, entity: &EntityData, inline_parent: Option<&EntityKey>, path: String) -> Result<(), SceneToolsError> {
+        let key = [entity.guid.map(EntityKey::Guid), entity.name.clone().map(EntityKey::Name)]
+            .into_iter()
+            .flatten()
+            .find(|key| !self.used.contains(key))
+            .unwrap_or_else(|| EntityKey::Path(path.clone()));
+        let parent = match inline_parent {
+            Some(parent) => Some(parent.clone()),
+            None => entity.parent.as_ref().and_then(|name| self.by_name.get(name).cloned()),
+        };
+
+        let mut components = Vec::new();
+        for (list, is_override) in [(&entity.components, false), (&entity.overrides, true)] {
+            for component in list {
+                let slot = Slot::new(component, is_override, &components)?;
+                components.push(slot);
+            }
+        }
+
+        if let Some(name) = &entity.name {
+            self.by_name.insert(name.clone(), key.clone());
+        }
+        self.used.insert(key.clone());
+        self.entities.push(FlatEntity {
+            key: key.clone(),
+            parent,
+            name: entity.name.clone(),
+            guid: entity.guid,
+            prefab: entity.prefab.clone(),
+            components,
+        });
+        for (index, child) in entity.children.iter().enumerate() {
+            self.visit(child, Some(&key), format!("{}.children[{}]", path, index))?;
+        }
+        Ok(())
+    }
+}
+
+/// Scene-level values, one level deep (`physics.gravity`, `prefabs.Enemy`,
+/// `layers`) — everything but the entities.
+pub(super) fn settings(scene: &SceneData) -> Result<Entries, SceneToolsError> {
+    let Value::Object(top) = serde_json::to_value(scene).map_err(json_error)? else {
+        return Err(SceneToolsError::Serialize("scene did not serialize as an object".into()));
+    };
+    let mut settings = Entries::new();
+    for (key, value) in top.into_iter().filter(|(key, _)| key != "entities") {
+        match value {
+            Value::Object(fields) => settings.extend(
+                fields
+                    .into_iter()
+                    .filter(|(_, value)| !value.is_null())
+                    .map(|(field, value)| (format!("{}.{}", key, field), value)),
+            ),
+            Value::Null => {}
+            value => settings.push((key, value)),
+        }
+    }
+    Ok(settings)
+}
+
+/// A component value's fields (or the whole value as `value` when it
+/// isn't a struct).
+pub(super) fn fields(value: &Value) -> Entries {
+    match value {
+        Value::Object(fields) => {
+            fields.iter().filter(|(_, value)| !value.is_null()).map(|(k, v)| (k.clone(), v.clone())).collect()
+        }
+        Value::Null => Entries::new(),
+        value => vec![("value".to_string(), value.clone())],
+    }
+}
+
+/// The value under `key`, if present.
+pub(super) fn lookup<'a>(entries: &'a [(String, Value)], key: &str) -> Option<&'a Value> {
+    entries.iter().find(|(k, _)| k == key).map(|(_, value)| value)
+}
+
+/// Keys of all `lists`, each once, in first-seen order.
+pub(super) fn union_keys(lists: &[&[(String, Value)]]) -> Vec<String> {
+    let mut keys: Vec<String> = Vec::new();
+    for (key, _) in lists.iter().flat_map(|list| list.iter()) {
+        if !keys.contains(key) {
+            keys.push(key.clone());
+        }
+    }
+    keys
+}
+
+pub(super) fn json_error(e: serde_json::Error) -> SceneToolsError {
+    SceneToolsError::Serialize(e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scene_loader::SceneLoader;
+
+    fn parse(ron: &str) -> SceneData {
+        SceneLoader::parse(ron).unwrap()
+    }
+
+    const BASE: &str = r##"SceneData(
+        name: "Level",
+        entities: [
+            EntityData(name: Some("world"), guid: Some("00000000-0000-0000-0000-000000000001"), children: [
+                EntityData(
+                    name: Some("player"),
+                    guid: Some("00000000-0000-0000-0000-000000000002"),
+                    components: [Transform2D(position: (0.0, 0.0)), Sprite(texture: "#white")],
+                ),
+            ]),
+            EntityData(name: Some("crate"), guid: Some("00000000-0000-0000-0000-000000000003")),
+        ],
+    )"##;
+
+    fn guid(value: u128) -> EntityKey {
+        EntityKey::Guid(ecs::Guid::from_u128(value))
+    }
+
+    #[test]
+    fn test_identical_scenes_have_no_changes() {
+        let diff = diff_scenes(&parse(BASE), &parse(BASE)).unwrap();
+        assert!(diff.is_empty(), "{:?}", diff.changes);
+    }
+
+    #[test]
+    fn test_diff_reports_structural_changes_by_guid() {
+        let old = parse(BASE);
+        let mut new = old.clone();
+        // Reparent the player to the root, change its position, drop the crate
+        let mut world = new.entities.remove(0);
+        let mut player = world.children.remove(0);
+        player.components[0] = ComponentData::Transform2D { position: (5.0, 0.0), rotation: 0.0, scale: (1.0, 1.0) };
+        new.entities = vec![player, world];
+        new.layers = vec!["Sky".to_string()];
+
+        let diff = diff_scenes(&old, &new).unwrap();
+        assert!(diff.changes.contains(&SceneChange::SettingChanged {
+            setting: "layers".into(),
+            old: None,
+            new: Some(serde_json::json!(["Sky"])),
+        }));
+        assert!(diff.changes.contains(&SceneChange::EntityMoved { entity: guid(2), from: Some(guid(1)), to: None }));
+        assert!(diff.changes.contains(&SceneChange::FieldChanged {
+            entity: guid(2),
+            component: Some("Transform2D".into()),
+            field: "position".into(),
+            old: Some(serde_json::json!([0.0, 0.0])),
+            new: Some(serde_json::json!([5.0, 0.0])),
+        }));
+        assert_eq!(diff.changes.last(), Some(&SceneChange::EntityRemoved { entity: guid(3), name: Some("crate".into()) }));
+        assert_eq!(diff.changes.len(), 4, "{:?}", diff.changes);
+    }
+
+    #[test]
+    fn test_guidless_entities_key_by_name_then_position() {
+        let scene = parse(
+            r#"SceneData(name: "Hand", entities: [
+                EntityData(name: Some("a")),
+                EntityData(name: Some("a")),
+                EntityData(parent: Some("a")),
+            ])"#,
+        );
+        let keys: Vec<EntityKey> = flatten(&scene).unwrap().into_iter().map(|e| e.key).collect();
+        assert_eq!(
+            keys,
+            [EntityKey::Name("a".into()), EntityKey::Path("entities[1]".into()), EntityKey::Path("entities[2]".into())]
+        );
+        let parent = flatten(&scene).unwrap()[2].parent.clone();
+        assert_eq!(parent, Some(EntityKey::Path("entities[1]".into())), "a parent name means the latest entity so named");
+    }
+}
@@ -0,0 +1,392 @@
+//! Three-way scene merge on the structural view of
+//! [`diff_scenes`](super::diff_scenes): each entity (by GUID), component
+//! field, and scene setting merges on its own, so two people editing
+//! different entities — or different fields of one — never conflict.
+//!
+//! A conflict (both sides changed the same value differently, or one side
+//! deleted an entity the other changed) keeps **ours**, like git's default
+//! side, and is reported for review. The merged scene is written with
+//! children nested inline (as the editor saves), whatever form the inputs
+//! used.
+
+use std::collections::HashMap;
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use crate::scene_data::{EntityData, SceneData};
+
+use super::diff::{fields, flatten, json_error, lookup, settings, union_keys, Entries, EntityKey, FlatEntity};
+use super::SceneToolsError;
+
+/// A value both sides changed (resolved to `ours`).
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct MergeConflict {
+    /// Entity the conflict is on (`None` for scene settings)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entity: Option<EntityKey>,
+    /// What conflicted: a setting (`physics.gravity`), a component field
+    /// (`Sprite.color`), `name`/`prefab`/`parent`, or `entity` — with
+    /// existence as the values — when one side deleted what the other
+    /// changed. A `parent` with only `ours` set means the merged parent is
+    /// gone or would form a cycle, so the entity moved to the root.
+    pub field: String,
+    pub base: Option<Value>,
+    pub ours: Option<Value>,
+    pub theirs: Option<Value>,
+}
+
+impl MergeConflict {
+    /// One-line description for logs and the editor.
+    pub fn describe(&self) -> String {
+        let show = |value: &Option<Value>| value.as_ref().map_or("none".to_string(), Value::to_string);
+        let target = match &self.entity {
+            Some(entity) => format!("{}: {}", entity, self.field),
+            None => self.field.clone(),
+        };
+        format!(
+            "{} (base {}, ours {}, theirs {}; kept ours)",
+            target,
+            show(&self.base),
+            show(&self.ours),
+            show(&self.theirs)
+        )
+    }
+}
+
+/// Result of [`merge_scenes`].
+#[derive(Debug, Clone)]
+pub struct SceneMerge {
+    /// The merged scene (conflicts resolved to `ours`)
+    pub scene: SceneData,
+    pub conflicts: Vec<MergeConflict>,
+}
+
+impl SceneMerge {
+    /// Whether the merge needed no conflict resolution.
+    pub fn is_clean(&self) -> bool {
+        self.conflicts.is_empty()
+    }
+}
+
+/// Merge `ours` and `theirs`, two edits of `base`. Entities keep `ours`'
+/// order, with entities only `theirs` added after them.
+pub fn merge_scenes(base: &SceneData, ours: &SceneData, theirs: &SceneData) -> Result<SceneMerge, SceneToolsError> {
+    let mut merger = Merger::default();
+
+    let (base_settings, our_settings, their_settings) = (settings(base)?, settings(ours)?, settings(theirs)?);
+    let merged_settings = merger.entries(None, "", &base_settings, &our_settings, &their_settings);
+
+    let (base_entities, our_entities, their_entities) = (flatten(base)?, flatten(ours)?, flatten(theirs)?);
+    let index = |entities: &[FlatEntity]| -> HashMap<EntityKey, usize> {
+        entities.iter().enumerate().map(|(i, entity)| (entity.key.clone(), i)).collect()
+    };
+    let (base_index, our_index, their_index) = (index(&base_entities), index(&our_entities), index(&their_entities));
+    let keys = our_entities
+        .iter()
+        .chain(their_entities.iter().filter(|entity| !our_index.contains_key(&entity.key)))
+        .map(|entity| &entity.key);
+    let mut merged = Vec::new();
+    for key in keys {
+        let side = |entities: &[FlatEntity], index: &HashMap<EntityKey, usize>| index.get(key).map(|&i| entities[i].clone());
+        let entity = merger.entity(
+            side(&base_entities, &base_index),
+            side(&our_entities, &our_index),
+            side(&their_entities, &their_index),
+        );
+        merged.extend(entity);
+    }
+    let entities = merger.nest(&merged)?;
+
+    let mut top = Map::new();
+    for (setting, value) in merged_settings {
+        match setting.split_once('.') {
+            Some((key, field)) => {
+                if let Value::Object(fields) = top.entry(key).or_insert_with(|| Value::Object(Map::new())) {
+                    fields.insert(field.to_string(), value);
+                }
+            }
+            None => {
+                top.insert(setting, value);
+            }
+        }
+    }
+    top.insert("entities".to_string(), serde_json::to_value(&entities).map_err(json_error)?);
+    let scene = serde_json::from_value(Value::Object(top)).map_err(json_error)?;
+    Ok(SceneMerge { scene, conflicts: merger.conflicts })
+}
+
+/// Whichever side changed the value, or `ours` (and `true`) when both
+/// changed it differently.
+fn pick<'a, T: PartialEq>(base: &'a T, ours: &'a T, theirs: &'a T) -> (&'a T, bool) {
+    if ours == theirs || theirs == base {
+        (ours, false)
+    } else if ours == base {
+        (theirs, false)
+    } else {
+        (ours, true)
+    }
+}
+
+fn key_value(key: &EntityKey) -> Value {
+    Value::String(key.to_string())
+}
+
+#[derive(Default)]
+struct Merger {
+    conflicts: Vec<MergeConflict>,
+}
+
+impl Merger {
+    fn conflict(&mut self, entity: Option<&EntityKey>, field: &str, values: [Option<Value>; 3]) {
+        let [base, ours, theirs] = values;
+        self.conflicts.push(MergeConflict { entity: entity.cloned(), field: field.to_string(), base, ours, theirs });
+    }
+
+    fn value(
+        &mut self,
+        entity: Option<&EntityKey>,
+        field: &str,
+        base: Option<&Value>,
+        ours: Option<&Value>,
+        theirs: Option<&Value>,
+    ) -> Option<Value> {
+        let (picked, conflict) = pick(&base, &ours, &theirs);
+        if conflict {
+            self.conflict(entity, field, [base.cloned(), ours.cloned(), theirs.cloned()]);
+        }
+        picked.cloned()
+    }
+
+    /// Merge key by key; conflicts are named `<prefix>.<key>`.
+    fn entries(
+        &mut self,
+        entity: Option<&EntityKey>,
+        prefix: &str,
+        base: &[(String, Value)],
+        ours: &[(String, Value)],
+        theirs: &[(String, Value)],
+    ) -> Entries {
+        union_keys(&[ours, theirs, base])
+            .into_iter()
+            .filter_map(|key| {
+                let field = if prefix.is_empty() { key.clone() } else { format!("{}.{}", prefix, key) };
+                let value = self.value(entity, &field, lookup(base, &key), lookup(ours, &key), lookup(theirs, &key));
+                value.map(|value| (key, value))
+            })
+            .collect()
+    }
+
+    /// One entity across the three sides (`None` = absent or deleted).
+    fn entity(&mut self, base: Option<FlatEntity>, ours: Option<FlatEntity>, theirs: Option<FlatEntity>) -> Option<FlatEntity> {
+        match (ours, theirs) {
+            (Some(ours), Some(theirs)) => Some(self.both(base.as_ref(), ours, theirs)),
+            (None, None) => None,
+            (ours, theirs) => {
+                let ours_kept = ours.is_some();
+                let survivor = ours.or(theirs)?;
+                // Added on one side, or deleted on the other: the deletion
+                // stands unless the surviving side changed the entity
+                match base {
+                    None => Some(survivor),
+                    Some(base) if base == survivor => None,
+                    Some(_) => {
+                        let exists = |present: bool| Some(Value::Bool(present));
+                        self.conflict(Some(&survivor.key), "entity", [exists(true), exists(ours_kept), exists(!ours_kept)]);
+                        ours_kept.then_some(survivor)
+                    }
+                }
+            }
+        }
+    }
+
+    /// An entity both sides kept (or both added).
+    fn both(&mut self, base: Option<&FlatEntity>, ours: FlatEntity, theirs: FlatEntity) -> FlatEntity {
+        let key = ours.key.clone();
+
+        let sides = [base.map(|base| &base.parent), Some(&ours.parent), Some(&theirs.parent)];
+        let (parent, conflict) = pick(&sides[0], &sides[1], &sides[2]);
+        let parent = parent.and_then(|parent| parent.clone());
+        if conflict {
+            let values = sides.map(|side| side.and_then(|parent| parent.as_ref()).map(key_value));
+            self.conflict(Some(&key), "parent", values);
+        }
+
+        let name = self.text(&key, "name", [base.map(|base| &base.name), Some(&ours.name), Some(&theirs.name)]);
+        let prefab = self.text(&key, "prefab", [base.map(|base| &base.prefab), Some(&ours.prefab), Some(&theirs.prefab)]);
+
+        let mut slot_names = Vec::new();
+        for slot in ours.components.iter().chain(&theirs.components).chain(base.iter().flat_map(|base| &base.components)) {
+            if !slot_names.contains(&slot.name) {
+                slot_names.push(slot.name.clone());
+            }
+        }
+        let mut components = Vec::new();
+        for name in slot_names {
+            let base_slot = base.and_then(|base| base.slot(&name));
+            let (our_slot, their_slot) = (ours.slot(&name), theirs.slot(&name));
+            let Some(template) = our_slot.or(their_slot).or(base_slot) else {
+                continue;
+            };
+            let values = [base_slot, our_slot, their_slot].map(|slot| slot.map(|slot| &slot.value));
+            if let Some(value) = self.component(&key, &name, values) {
+                components.push(template.with_value(value));
+            }
+        }
+
+        FlatEntity { key, parent, name, guid: ours.guid, prefab, components }
+    }
+
+    /// An entity's own `name`/`prefab`.
+    fn text(&mut self, entity: &EntityKey, field: &str, values: [Option<&Option<String>>; 3]) -> Option<String> {
+        let [base, ours, theirs] = values.map(|value| value.and_then(|text| text.clone()).map(Value::String));
+        let value = self.value(Some(entity), field, base.as_ref(), ours.as_ref(), theirs.as_ref());
+        value.and_then(|value| value.as_str().map(str::to_string))
+    }
+
+    /// One component: field by field while both sides have it as a struct,
+    /// otherwise as a whole (adding/removing it).
+    fn component(&mut self, entity: &EntityKey, name: &str, values: [Option<&Value>; 3]) -> Option<Value> {
+        let [base, ours, theirs] = values;
+        match (base, ours, theirs) {
+            (None | Some(Value::Object(_)), Some(Value::Object(_)), Some(Value::Object(_))) => {
+                let (base, ours, theirs) = (fields_of(base), fields_of(ours), fields_of(theirs));
+                let merged = self.entries(Some(entity), name, &base, &ours, &theirs);
+                Some(Value::Object(merged.into_iter().collect()))
+            }
+            _ => self.value(Some(entity), name, base, ours, theirs),
+        }
+    }
+
+    /// Rebuild the hierarchy from parent keys. Entities whose parent is
+    /// gone or would form a cycle go to the root (a `parent` conflict).
+    fn nest(&mut self, entities: &[FlatEntity]) -> Result<Vec<EntityData>, SceneToolsError> {
+        let index: HashMap<&EntityKey, usize> = entities.iter().enumerate().map(|(i, entity)| (&entity.key, i)).collect();
+        let mut parents: Vec<Option<usize>> =
+            entities.iter().map(|entity| entity.parent.as_ref().and_then(|parent| index.get(parent).copied())).collect();
+        for (i, entity) in entities.iter().enumerate() {
+            let mut ancestor = parents[i];
+            let mut steps = 0;
+            while let Some(current) = ancestor.filter(|_| steps <= entities.len()) {
+                if current == i {
+                    parents[i] = None;
+                    break;
+                }
+                ancestor = parents[current];
+                steps += 1;
+            }
+            if let (Some(parent), None) = (&entity.parent, parents[i]) {
+                self.conflict(Some(&entity.key), "parent", [None, Some(key_value(parent)), None]);
+            }
+        }
+
+        let mut children: Vec<Vec<usize>> = vec![Vec::new(); entities.len()];
+        let mut roots = Vec::new();
+        for (i, parent) in parents.iter().enumerate() {
+            match parent {
+                Some(parent) => children[*parent].push(i),
+                None => roots.push(i),
+            }
+        }
+        roots.into_iter().map(|root| build(entities, &children, root)).collect()
+    }
+}
+
+fn fields_of(value: Option<&Value>) -> Entries {
+    value.map(fields).unwrap_or_default()
+}
+
+fn build(entities: &[FlatEntity], children: &[Vec<usize>], i: usize) -> Result<EntityData, SceneToolsError> {
+    let mut data = entities[i].to_entity_data()?;
+    data.children = children[i].iter().map(|&child| build(entities, children, child)).collect::<Result<_, _>>()?;
+    Ok(data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scene_data::ComponentData;
+    use crate::scene_loader::SceneLoader;
+
+    const BASE: &str = r##"SceneData(
+        name: "Level",
+        entities: [
+            EntityData(name: Some("world"), guid: Some("00000000-0000-0000-0000-000000000001"), children: [
+                EntityData(
+                    name: Some("player"),
+                    guid: Some("00000000-0000-0000-0000-000000000002"),
+                    components: [Transform2D(position: (0.0, 0.0)), Sprite(texture: "#white", depth: 1.0)],
+                ),
+            ]),
+            EntityData(name: Some("crate"), guid: Some("00000000-0000-0000-0000-000000000003")),
+        ],
+    )"##;
+
+    fn base() -> SceneData {
+        SceneLoader::parse(BASE).unwrap()
+    }
+
+    fn player(scene: &mut SceneData) -> &mut EntityData {
+        &mut scene.entities[0].children[0]
+    }
+
+    fn sprite_depth(scene: &SceneData) -> f32 {
+        match scene.entities[0].children[0].components[1] {
+            ComponentData::Sprite { depth, .. } => depth,
+            _ => panic!("player sprite moved"),
+        }
+    }
+
+    fn set_sprite_depth(scene: &mut SceneData, value: f32) {
+        if let ComponentData::Sprite { depth, .. } = &mut player(scene).components[1] {
+            *depth = value;
+        }
+    }
+
+    #[test]
+    fn test_edits_to_different_things_merge_cleanly() {
+        let base = base();
+        let mut ours = base.clone();
+        set_sprite_depth(&mut ours, 5.0);
+        let mut theirs = base.clone();
+        player(&mut theirs).name = Some("hero".into());
+        theirs.entities.push(EntityData { name: Some("door".into()), ..Default::default() });
+        theirs.entities.remove(1);
+
+        let merge = merge_scenes(&base, &ours, &theirs).unwrap();
+        assert!(merge.is_clean(), "{:?}", merge.conflicts);
+        assert_eq!(sprite_depth(&merge.scene), 5.0);
+        assert_eq!(merge.scene.entities[0].children[0].name.as_deref(), Some("hero"));
+        let roots: Vec<_> = merge.scene.entities.iter().map(|e| e.name.as_deref()).collect();
+        assert_eq!(roots, [Some("world"), Some("door")], "theirs deleted the crate and added a door");
+    }
+
+    #[test]
+    fn test_same_field_changed_twice_keeps_ours() {
+        let base = base();
+        let mut ours = base.clone();
+        set_sprite_depth(&mut ours, 5.0);
+        let mut theirs = base.clone();
+        set_sprite_depth(&mut theirs, 9.0);
+
+        let merge = merge_scenes(&base, &ours, &theirs).unwrap();
+        assert_eq!(sprite_depth(&merge.scene), 5.0);
+        assert_eq!(merge.conflicts.len(), 1);
+        assert_eq!(merge.conflicts[0].field, "Sprite.depth");
+        assert_eq!(merge.conflicts[0].theirs, Some(serde_json::json!(9.0)));
+    }
+
+    #[test]
+    fn test_deleting_a_changed_entity_conflicts() {
+        let base = base();
+        let mut ours = base.clone();
+        ours.entities[0].children.clear();
+        let mut theirs = base.clone();
+        set_sprite_depth(&mut theirs, 9.0);
+
+        let merge = merge_scenes(&base, &ours, &theirs).unwrap();
+        assert!(merge.scene.entities[0].children.is_empty(), "ours' deletion is kept");
+        assert_eq!(merge.conflicts.len(), 1);
+        assert_eq!(merge.conflicts[0].field, "entity");
+    }
+}
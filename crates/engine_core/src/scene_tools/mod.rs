@@ -0,0 +1,463 @@
+//! Offline scene tooling: validate scene files and batch-export them, with
+//! machine-readable (JSON) reports for content pipelines.
+//!
+//! Everything here works on [`SceneData`] — no window, GPU, or `World` — so
+//! it runs headlessly (the `scene_tools` binary, CI) as well as behind the
+//! editor's File > Validate Scene. Checks:
+//! - prefab references to prefabs the scene doesn't define, and `parent`
+//!   names that aren't defined earlier in the file (the loader rejects both)
+//! - texture references that don't resolve: malformed `#solid:` colors,
+//!   unknown `#` built-ins, and (with an assets root) missing image files
+//! - more than one `Camera2D` marked `is_main_camera` (a warning: the
+//!   renderer follows whichever it finds first)
+//! - colliders with zero, negative, or non-finite sizes
+//!
+//! ```no_run
+//! use engine_core::scene_tools::SceneValidator;
+//!
+//! let validator = SceneValidator::new().with_assets_root("assets");
+//! let report = validator.validate_file("assets/scenes/level1.ron");
+//! if !report.is_ok() {
+//!     eprintln!("{}", report.to_json().unwrap_or_default());
+//! }
+//! ```
+//!
+//! Scenes can also be compared and merged structurally — by entity GUID and
+//! component field rather than by line — with [`diff_scenes`] and the
+//! three-way [`merge_scenes`]; the editor's File > Compare With Saved shows
+//! a [`SceneDiff`] of the open scene against its file.
+
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+
+use crate::asset_root::AssetRoot;
+use crate::scene_data::{ColliderShapeData, ComponentData, EntityData, SceneData};
+use crate::scene_loader::SceneLoader;
+use crate::texture_ref::parse_hex_color;
+
+mod diff;
+mod merge;
+
+pub use diff::{diff_scenes, EntityKey, SceneChange, SceneDiff};
+pub use merge::{merge_scenes, MergeConflict, SceneMerge};
+
+/// Errors from exporting, diffing, or merging scenes (validation problems
+/// are [`SceneIssue`]s).
+#[derive(Debug, thiserror::Error)]
+pub enum SceneToolsError {
+    #[error("Scene tools IO error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Scene serialization error: {0}")]
+    Serialize(String),
+}
+
+/// How bad a [`SceneIssue`] is. Errors fail validation; warnings don't.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum IssueSeverity {
+    Error,
+    Warning,
+}
+
+/// What a [`SceneIssue`] is about.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SceneIssueKind {
+    /// The file could not be read or parsed
+    ParseError,
+    /// An entity names a prefab the scene doesn't define
+    DanglingPrefab,
+    /// An entity's `parent` isn't an entity defined earlier in the file
+    DanglingParent,
+    /// A texture file doesn't exist under the assets root
+    MissingTexture,
+    /// A `#` texture reference that can't be resolved (bad color, unknown built-in)
+    InvalidTextureRef,
+    /// More than one camera is marked as the main camera
+    MultipleMainCameras,
+    /// A collider with a zero, negative, or non-finite size
+    InvalidCollider,
+    /// The scene passed validation but could not be exported
+    ExportFailed,
+}
+
+/// One problem found in a scene.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SceneIssue {
+    pub severity: IssueSeverity,
+    pub kind: SceneIssueKind,
+    /// Where the problem is: an entity name, `entities[2].children[0]` for
+    /// unnamed entities, `prefab:Enemy` for prefab definitions, or `None`
+    /// for scene-wide issues
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+    pub message: String,
+}
+
+impl SceneIssue {
+    fn error(kind: SceneIssueKind, location: Option<&str>, message: impl Into<String>) -> Self {
+        Self {
+            severity: IssueSeverity::Error,
+            kind,
+            location: location.map(str::to_string),
+            message: message.into(),
+        }
+    }
+
+    fn warning(kind: SceneIssueKind, location: Option<&str>, message: impl Into<String>) -> Self {
+        Self { severity: IssueSeverity::Warning, ..Self::error(kind, location, message) }
+    }
+}
+
+/// Validation result for one scene.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SceneReport {
+    /// Scene file, when validated from disk
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<PathBuf>,
+    /// Scene name (empty when the file didn't parse)
+    pub scene: String,
+    /// Entities in the scene, children included
+    pub entity_count: usize,
+    pub issues: Vec<SceneIssue>,
+}
+
+impl SceneReport {
+    /// Whether the scene has no errors (warnings are allowed).
+    pub fn is_ok(&self) -> bool {
+        self.error_count() == 0
+    }
+
+    /// Number of error-severity issues.
+    pub fn error_count(&self) -> usize {
+        self.issues.iter().filter(|issue| issue.severity == IssueSeverity::Error).count()
+    }
+
+    /// Number of warning-severity issues.
+    pub fn warning_count(&self) -> usize {
+        self.issues.len() - self.error_count()
+    }
+
+    /// Pretty-printed JSON report.
+    pub fn to_json(&self) -> Result<String, SceneToolsError> {
+        serde_json::to_string_pretty(self).map_err(|e| SceneToolsError::Serialize(e.to_string()))
+    }
+
+    /// One-line summary, e.g. `level1: 2 errors, 0 warnings`.
+    pub fn summary(&self) -> String {
+        format!("{}: {} errors, {} warnings", self.display_name(), self.error_count(), self.warning_count())
+    }
+
+    fn display_name(&self) -> String {
+        match &self.path {
+            Some(path) => path.display().to_string(),
+            None => self.scene.clone(),
+        }
+    }
+}
+
+/// Checks [`SceneData`] for content problems the loader would trip over
+/// (or silently mis-handle) at runtime.
+#[derive(Debug, Clone, Default)]
+pub struct SceneValidator {
+    /// Where texture file paths resolve (the asset manager's root and
+    /// mounts). Without it file textures aren't checked.
+    assets_root: Option<AssetRoot>,
+}
+
+impl SceneValidator {
+    /// A validator that checks everything except texture files on disk.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Also check that texture files exist under `root`.
+    pub fn with_assets_root(mut self, root: impl Into<PathBuf>) -> Self {
+        self.assets_root = Some(AssetRoot::new(root));
+        self
+    }
+
+    /// Also check that texture files exist, resolving them (including
+    /// `assets://` paths and mount points) through `root`.
+    pub fn with_asset_root(mut self, root: AssetRoot) -> Self {
+        self.assets_root = Some(root);
+        self
+    }
+
+    /// Validate an in-memory scene.
+    pub fn validate(&self, scene: &SceneData) -> SceneReport {
+        let mut walk = SceneWalk {
+            scene,
+            validator: self,
+            defined: HashSet::new(),
+            main_cameras: Vec::new(),
+            entity_count: 0,
+            issues: Vec::new(),
+        };
+
+        // Prefab definitions are checked once, not once per instance
+        let mut prefab_names: Vec<&String> = scene.prefabs.keys().collect();
+        prefab_names.sort();
+        for name in prefab_names {
+            let location = format!("prefab:{}", name);
+            walk.check_components(&scene.prefabs[name].components, &location);
+        }
+        for (index, entity) in scene.entities.iter().enumerate() {
+            walk.visit_entity(entity, None, &format!("entities[{}]", index));
+        }
+
+        if walk.main_cameras.len() > 1 {
+            let message = format!("{} main cameras: {}", walk.main_cameras.len(), walk.main_cameras.join(", "));
+            walk.issues.push(SceneIssue::warning(SceneIssueKind::MultipleMainCameras, None, message));
+        }
+
+        SceneReport {
+            path: None,
+            scene: scene.name.clone(),
+            entity_count: walk.entity_count,
+            issues: walk.issues,
+        }
+    }
+
+    /// Load and validate a scene file; read and parse failures become a
+    /// [`SceneIssueKind::ParseError`] issue.
+    pub fn validate_file(&self, path: impl AsRef<Path>) -> SceneReport {
+        let path = path.as_ref();
+        let mut report = match SceneLoader::load_from_file(path) {
+            Ok(scene) => self.validate(&scene),
+            Err(e) => SceneReport {
+                path: None,
+                scene: String::new(),
+                entity_count: 0,
+                issues: vec![SceneIssue::error(SceneIssueKind::ParseError, None, e.to_string())],
+            },
+        };
+        report.path = Some(path.to_path_buf());
+        report
+    }
+
+    /// Validate every `.ron` scene in `dir` (not recursive), in file-name order.
+    pub fn validate_dir(&self, dir: impl AsRef<Path>) -> Result<Vec<SceneReport>, SceneToolsError> {
+        Ok(scene_files(dir.as_ref())?.iter().map(|path| self.validate_file(path)).collect())
+    }
+
+    /// Problem with a texture reference, if any.
+    fn check_texture(&self, texture: &str) -> Option<(SceneIssueKind, String)> {
+        if texture == "#white" {
+            return None;
+        }
+        if let Some(hex) = texture.strip_prefix("#solid:") {
+            return parse_hex_color(hex).err().map(|e| (SceneIssueKind::InvalidTextureRef, e.to_string()));
+        }
+        if texture.starts_with('#') {
+            return Some((SceneIssueKind::InvalidTextureRef, format!("Unknown built-in texture '{}'", texture)));
+        }
+        let root = self.assets_root.as_ref()?;
+        let file = root.resolve(texture);
+        if file.is_file() {
+            None
+        } else {
+            Some((SceneIssueKind::MissingTexture, format!("Texture file not found: {}", file.display())))
+        }
+    }
+}
+
+/// Traversal state of one [`SceneValidator::validate`] run.
+struct SceneWalk<'a> {
+    scene: &'a SceneData,
+    validator: &'a SceneValidator,
+    /// Entity names defined so far (a `parent` must come earlier)
+    defined: HashSet<&'a str>,
+    /// Locations of entities with a main camera
+    main_cameras: Vec<String>,
+    entity_count: usize,
+    issues: Vec<SceneIssue>,
+}
+
+impl<'a> SceneWalk<'a> {
+    fn visit_entity(&mut self, entity: &'a EntityData, inline_parent: Option<&str>, path: &str) {
+        self.entity_count += 1;
+        let location = entity.name.clone().unwrap_or_else(|| path.to_string());
+
+        if let Some(parent) = &entity.parent {
+            if inline_parent.is_none() && !self.defined.contains(parent.as_str()) {
+                let message = format!("Parent '{}' is not defined before this entity", parent);
+                self.issues.push(SceneIssue::error(SceneIssueKind::DanglingParent, Some(&location), message));
+            }
+        }
+
+        let scene = self.scene;
+        let base = match &entity.prefab {
+            Some(prefab) => match scene.prefabs.get(prefab) {
+                Some(data) => data.components.as_slice(),
+                None => {
+                    let message = format!("Prefab '{}' is not defined in this scene", prefab);
+                    self.issues.push(SceneIssue::error(SceneIssueKind::DanglingPrefab, Some(&location), message));
+                    &[]
+                }
+            },
+            None => &[],
+        };
+        // Own components are checked here; prefab components were checked
+        // with the prefab definition
+        self.check_components(&entity.overrides, &location);
+        self.check_components(&entity.components, &location);
+
+        let effective = SceneLoader::merge_components(base, &entity.overrides, &entity.components);
+        let is_main_camera = effective
+            .iter()
+            .any(|component| matches!(component, ComponentData::Camera2D { is_main_camera: true, .. }));
+        if is_main_camera {
+            self.main_cameras.push(location.clone());
+        }
+
+        if let Some(name) = &entity.name {
+            self.defined.insert(name.as_str());
+        }
+        for (index, child) in entity.children.iter().enumerate() {
+            self.visit_entity(child, Some(&location), &format!("{}.children[{}]", path, index));
+        }
+    }
+
+    /// Texture and collider checks on one component list.
+    fn check_components(&mut self, components: &[ComponentData], location: &str) {
+        for component in components {
+            let texture = match component {
+                ComponentData::Sprite { texture, .. } => Some(texture),
+                ComponentData::Tilemap { tileset, .. } => Some(tileset),
+                _ => None,
+            };
+            if let Some((kind, message)) = texture.and_then(|texture| self.validator.check_texture(texture)) {
+                self.issues.push(SceneIssue::error(kind, Some(location), message));
+            }
+            if let ComponentData::Collider { shape, .. } = component {
+                if let Some(message) = collider_problem(shape) {
+                    self.issues.push(SceneIssue::error(SceneIssueKind::InvalidCollider, Some(location), message));
+                }
+            }
+        }
+    }
+}
+
+/// Why a collider shape is unusable, if it is. Capsules may have a zero
+/// half-height (a circle); every other dimension must be positive.
+fn collider_problem(shape: &ColliderShapeData) -> Option<String> {
+    let positive = |value: f32| value.is_finite() && value > 0.0;
+    match *shape {
+        ColliderShapeData::Box { half_extents: (x, y) } if !positive(x) || !positive(y) => {
+            Some(format!("Box half extents must be positive, got ({}, {})", x, y))
+        }
+        ColliderShapeData::Circle { radius } if !positive(radius) => {
+            Some(format!("Circle radius must be positive, got {}", radius))
+        }
+        ColliderShapeData::CapsuleX { half_height, radius } | ColliderShapeData::CapsuleY { half_height, radius }
+            if !positive(radius) || !half_height.is_finite() || half_height < 0.0 =>
+        {
+            Some(format!("Capsule needs radius > 0 and half height >= 0, got radius {} half height {}", radius, half_height))
+        }
+        _ => None,
+    }
+}
+
+/// `.ron` files directly inside `dir`, sorted.
+pub fn scene_files(dir: &Path) -> Result<Vec<PathBuf>, SceneToolsError> {
+    let mut files: Vec<PathBuf> = std::fs::read_dir(dir)?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.is_file() && path.extension().and_then(|ext| ext.to_str()) == Some("ron"))
+        .collect();
+    files.sort();
+    Ok(files)
+}
+
+/// Output format of [`export_scene`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    /// Pretty RON in the current schema (normalizes hand-edited files)
+    Ron,
+    /// Pretty JSON, for tools outside the Rust ecosystem
+    Json,
+}
+
+impl ExportFormat {
+    /// File extension without the dot.
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::Ron => "ron",
+            Self::Json => "json",
+        }
+    }
+
+    /// Parse `"ron"` or `"json"` (case-insensitive).
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "ron" => Some(Self::Ron),
+            "json" => Some(Self::Json),
+            _ => None,
+        }
+    }
+}
+
+/// Serialize a scene in `format`.
+pub fn export_scene(scene: &SceneData, format: ExportFormat) -> Result<String, SceneToolsError> {
+    match format {
+        ExportFormat::Ron => {
+            crate::scene_serializer::serialize_to_ron(scene).map_err(SceneToolsError::Serialize)
+        }
+        ExportFormat::Json => {
+            serde_json::to_string_pretty(scene).map_err(|e| SceneToolsError::Serialize(e.to_string()))
+        }
+    }
+}
+
+/// Result of exporting one scene file.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ExportOutcome {
+    /// Written file; `None` when the scene failed validation or writing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output: Option<PathBuf>,
+    /// Validation report of the source scene (an export failure is added
+    /// to it as an error)
+    pub report: SceneReport,
+}
+
+/// Validate each scene file and export the ones without errors into
+/// `out_dir` (created if missing) as `<file stem>.<format extension>`.
+/// Scenes with errors are skipped, so a pipeline never ships a scene the
+/// loader would reject.
+pub fn batch_export(
+    files: &[PathBuf],
+    out_dir: &Path,
+    format: ExportFormat,
+    validator: &SceneValidator,
+) -> Result<Vec<ExportOutcome>, SceneToolsError> {
+    std::fs::create_dir_all(out_dir)?;
+    let outcomes = files
+        .iter()
+        .map(|file| {
+            let mut report = validator.validate_file(file);
+            if !report.is_ok() {
+                return ExportOutcome { output: None, report };
+            }
+            let stem = file.file_stem().map(|stem| stem.to_string_lossy().into_owned()).unwrap_or_default();
+            let output = out_dir.join(format!("{}.{}", stem, format.extension()));
+            let written = SceneLoader::load_from_file(file)
+                .map_err(|e| SceneToolsError::Serialize(e.to_string()))
+                .and_then(|scene| export_scene(&scene, format))
+                .and_then(|text| std::fs::write(&output, text).map_err(SceneToolsError::from));
+            match written {
+                Ok(()) => ExportOutcome { output: Some(output), report },
+                Err(e) => {
+                    report.issues.push(SceneIssue::error(SceneIssueKind::ExportFailed, None, e.to_string()));
+                    ExportOutcome { output: None, report }
+                }
+            }
+        })
+        .collect();
+    Ok(outcomes)
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,156 @@
+use super::*;
+
+fn parse(ron: &str) -> SceneData {
+    SceneLoader::parse(ron).unwrap()
+}
+
+fn kinds(report: &SceneReport) -> Vec<SceneIssueKind> {
+    report.issues.iter().map(|issue| issue.kind).collect()
+}
+
+#[test]
+fn test_clean_scene_has_no_issues() {
+    let scene = parse(
+        r##"SceneData(
+            name: "Clean",
+            prefabs: { "Ball": PrefabData(components: [Sprite(texture: "#solid:FF8800")]) },
+            entities: [
+                EntityData(name: Some("root"), components: [Camera2D(is_main_camera: true)]),
+                EntityData(prefab: Some("Ball"), parent: Some("root")),
+            ],
+        )"##,
+    );
+    let report = SceneValidator::new().validate(&scene);
+    assert!(report.issues.is_empty(), "{:?}", report.issues);
+    assert!(report.is_ok());
+    assert_eq!(report.entity_count, 2);
+}
+
+#[test]
+fn test_dangling_prefab_and_parent() {
+    let scene = parse(
+        r##"SceneData(
+            name: "Dangling",
+            entities: [
+                EntityData(name: Some("orphan"), prefab: Some("Missing"), parent: Some("later")),
+                EntityData(name: Some("later")),
+            ],
+        )"##,
+    );
+    let report = SceneValidator::new().validate(&scene);
+    assert_eq!(kinds(&report), vec![SceneIssueKind::DanglingParent, SceneIssueKind::DanglingPrefab]);
+    assert_eq!(report.issues[0].location.as_deref(), Some("orphan"));
+    assert_eq!(report.error_count(), 2);
+}
+
+#[test]
+fn test_texture_refs_and_missing_files() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("present.png"), b"").unwrap();
+    let scene = parse(
+        r##"SceneData(
+            name: "Textures",
+            entities: [
+                EntityData(components: [Sprite(texture: "present.png")]),
+                EntityData(components: [Sprite(texture: "absent.png")]),
+                EntityData(components: [Sprite(texture: "#solid:XYZ")]),
+                EntityData(children: [EntityData(components: [Tilemap(tileset: "#checker")])]),
+            ],
+        )"##,
+    );
+
+    // Without an assets root only `#` references can be checked
+    let report = SceneValidator::new().validate(&scene);
+    assert_eq!(kinds(&report), vec![SceneIssueKind::InvalidTextureRef; 2]);
+    assert_eq!(report.issues[1].location.as_deref(), Some("entities[3].children[0]"));
+
+    let report = SceneValidator::new().with_assets_root(dir.path()).validate(&scene);
+    assert_eq!(
+        kinds(&report),
+        vec![SceneIssueKind::MissingTexture, SceneIssueKind::InvalidTextureRef, SceneIssueKind::InvalidTextureRef]
+    );
+    assert_eq!(report.issues[0].location.as_deref(), Some("entities[1]"));
+}
+
+#[test]
+fn test_multiple_main_cameras_is_a_warning() {
+    let scene = parse(
+        r##"SceneData(
+            name: "Cameras",
+            prefabs: { "Cam": PrefabData(components: [Camera2D(is_main_camera: true)]) },
+            entities: [
+                EntityData(name: Some("a"), components: [Camera2D(is_main_camera: true)]),
+                EntityData(name: Some("b"), prefab: Some("Cam")),
+                EntityData(name: Some("c"), prefab: Some("Cam"), overrides: [Camera2D(is_main_camera: false)]),
+            ],
+        )"##,
+    );
+    let report = SceneValidator::new().validate(&scene);
+    assert_eq!(kinds(&report), vec![SceneIssueKind::MultipleMainCameras]);
+    assert_eq!(report.issues[0].severity, IssueSeverity::Warning);
+    assert!(report.issues[0].message.contains("a, b"));
+    assert!(report.is_ok(), "warnings don't fail validation");
+}
+
+#[test]
+fn test_invalid_collider_sizes() {
+    assert!(collider_problem(&ColliderShapeData::Box { half_extents: (8.0, 0.0) }).is_some());
+    assert!(collider_problem(&ColliderShapeData::Circle { radius: f32::NAN }).is_some());
+    assert!(collider_problem(&ColliderShapeData::CapsuleY { half_height: -1.0, radius: 4.0 }).is_some());
+    assert!(collider_problem(&ColliderShapeData::CapsuleX { half_height: 0.0, radius: 4.0 }).is_none());
+    assert!(collider_problem(&ColliderShapeData::default()).is_none());
+
+    let scene = parse(
+        r##"SceneData(
+            name: "Colliders",
+            prefabs: { "Wall": PrefabData(components: [Collider(shape: Circle(radius: 0.0))]) },
+            entities: [EntityData(prefab: Some("Wall")), EntityData(prefab: Some("Wall"))],
+        )"##,
+    );
+    let report = SceneValidator::new().validate(&scene);
+    assert_eq!(kinds(&report), vec![SceneIssueKind::InvalidCollider], "reported once, on the prefab");
+    assert_eq!(report.issues[0].location.as_deref(), Some("prefab:Wall"));
+}
+
+#[test]
+fn test_unparseable_file_and_json_report() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("broken.ron");
+    std::fs::write(&path, "SceneData(name: ").unwrap();
+
+    let report = SceneValidator::new().validate_file(&path);
+    assert_eq!(kinds(&report), vec![SceneIssueKind::ParseError]);
+    assert_eq!(report.path.as_deref(), Some(path.as_path()));
+
+    let json: serde_json::Value = serde_json::from_str(&report.to_json().unwrap()).unwrap();
+    assert_eq!(json["issues"][0]["kind"], "parse_error");
+    assert_eq!(json["issues"][0]["severity"], "error");
+}
+
+#[test]
+fn test_batch_export_skips_scenes_with_errors() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("good.ron"), r#"SceneData(name: "Good", entities: [EntityData()])"#).unwrap();
+    std::fs::write(
+        dir.path().join("bad.ron"),
+        r#"SceneData(name: "Bad", entities: [EntityData(prefab: Some("Nope"))])"#,
+    )
+    .unwrap();
+    std::fs::write(dir.path().join("notes.txt"), "not a scene").unwrap();
+
+    let files = scene_files(dir.path()).unwrap();
+    assert_eq!(files.len(), 2, "only .ron files");
+    let out = dir.path().join("out");
+    let outcomes = batch_export(&files, &out, ExportFormat::Json, &SceneValidator::new()).unwrap();
+
+    // Sorted: bad.ron, good.ron
+    assert_eq!(outcomes[0].output, None);
+    assert!(!outcomes[0].report.is_ok());
+    let written = outcomes[1].output.as_ref().unwrap();
+    assert_eq!(written, &out.join("good.json"));
+    let exported: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(written).unwrap()).unwrap();
+    assert_eq!(exported["name"], "Good");
+
+    assert_eq!(ExportFormat::from_name("RON"), Some(ExportFormat::Ron));
+    assert_eq!(ExportFormat::from_name("yaml"), None);
+}
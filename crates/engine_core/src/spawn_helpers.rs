@@ -18,10 +18,11 @@ use crate::RENDER_UNIT;
 pub fn spawn_background(world: &mut World, tex: u32, color: Vec4, window_size: Vec2) -> EntityId {
     let size = window_size * 1.2;
     world
-        .spawn()
-        .with(Name::new("Background"))
-        .with(Transform2D::from_parts(Vec2::ZERO, 0.0, size / RENDER_UNIT))
-        .with(Sprite::new(tex).with_color(color).with_depth(-100.0))
+        .spawn((
+            Name::new("Background"),
+            Transform2D::from_parts(Vec2::ZERO, 0.0, size / RENDER_UNIT),
+            Sprite::new(tex).with_color(color).with_depth(-100.0),
+        ))
         .id()
 }
 
@@ -0,0 +1,92 @@
+//! Sprite-sheet atlases stored next to their textures.
+//!
+//! A [`SpriteAtlas`] names regions of one texture and lives beside it as
+//! `<texture>.atlas.ron` (`hero.png` → `hero.atlas.ron`, see
+//! [`renderer::sprite_sheet::atlas_path`]). The editor's slicer writes them;
+//! games look regions up by name:
+//!
+//! ```no_run
+//! # use engine_core::prelude::*;
+//! # fn setup(assets: &mut AssetManager) -> Result<(), AssetError> {
+//! let hero = assets.load_texture("hero.png")?;
+//! let atlas = assets.load_sprite_atlas(hero.id)?;
+//! let [x, y, w, h] = atlas.uv("hero_0").unwrap_or([0.0, 0.0, 1.0, 1.0]);
+//! let idle = Sprite::new(hero.id).with_tex_region(x, y, w, h);
+//! let walk: Vec<[f32; 4]> = ["hero_1", "hero_2", "hero_3"].iter().filter_map(|name| atlas.uv(name)).collect();
+//! # let _ = (idle, walk);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::path::{Path, PathBuf};
+
+use renderer::sprite_sheet::{atlas_path, load_rgba};
+use renderer::SpriteAtlas;
+
+use crate::assets::{AssetError, AssetManager};
+
+/// Read an atlas file.
+pub fn read_sprite_atlas(path: &Path) -> Result<SpriteAtlas, AssetError> {
+    let load_error = |message: String| AssetError::Load { path: path.display().to_string(), message };
+    let content = std::fs::read_to_string(path).map_err(|e| load_error(e.to_string()))?;
+    ron::from_str(&content).map_err(|e| load_error(e.to_string()))
+}
+
+/// Write an atlas file.
+pub fn write_sprite_atlas(path: &Path, atlas: &SpriteAtlas) -> std::io::Result<()> {
+    let content =
+        ron::ser::to_string_pretty(atlas, ron::ser::PrettyConfig::default()).map_err(std::io::Error::other)?;
+    std::fs::write(path, content)
+}
+
+impl AssetManager {
+    /// File a texture was loaded from, resolved against the base path.
+    /// `None` for generated textures (`#white`, solid colors) and unknown
+    /// handles.
+    pub fn texture_file(&self, handle: u32) -> Option<PathBuf> {
+        let path = self.texture_path(handle).filter(|path| !path.starts_with('#'))?;
+        Some(self.resolve_path(path))
+    }
+
+    /// Load the atlas saved next to a loaded texture.
+    pub fn load_sprite_atlas(&self, handle: u32) -> Result<SpriteAtlas, AssetError> {
+        let file = self
+            .texture_file(handle)
+            .ok_or_else(|| AssetError::NotFound(format!("texture file for handle {handle}")))?;
+        read_sprite_atlas(&atlas_path(file))
+    }
+
+    /// Save an atlas next to its texture (`atlas.texture` is resolved
+    /// through the asset root). Returns the file written.
+    pub fn save_sprite_atlas(&self, atlas: &SpriteAtlas) -> std::io::Result<PathBuf> {
+        let file = atlas_path(self.resolve_path(&atlas.texture));
+        write_sprite_atlas(&file, atlas)?;
+        Ok(file)
+    }
+
+    /// Decode a loaded texture's file to RGBA8 pixels as
+    /// `(pixels, width, height)`, e.g. for alpha-island slicing.
+    pub fn texture_pixels(&self, handle: u32) -> Result<(Vec<u8>, u32, u32), AssetError> {
+        let file = self
+            .texture_file(handle)
+            .ok_or_else(|| AssetError::NotFound(format!("texture file for handle {handle}")))?;
+        Ok(load_rgba(file)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use renderer::sprite_sheet::slice_grid;
+
+    #[test]
+    fn test_atlas_files_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let atlas = SpriteAtlas::from_rects("hero.png", 64, 32, "hero", slice_grid(64, 32, 16, 16));
+        let path = atlas_path(dir.path().join("hero.png"));
+        write_sprite_atlas(&path, &atlas).unwrap();
+
+        assert_eq!(read_sprite_atlas(&path).unwrap(), atlas);
+        assert!(matches!(read_sprite_atlas(&dir.path().join("missing.atlas.ron")), Err(AssetError::Load { .. })));
+    }
+}
@@ -0,0 +1,161 @@
+//! World streaming — chunked loading of large scenes.
+//!
+//! A streamed scene is split into square spatial chunks
+//! ([`partition_scene`]): each root entity goes to the chunk containing its
+//! `Transform2D` position, with its children and `parent`-linked entities
+//! following it. Entities with no position, and cameras, stay in the
+//! always-loaded base scene alongside the prefab and material tables.
+//!
+//! At runtime a [`WorldStreamer`] keeps the chunks around a focus point
+//! (usually the main camera) instantiated. Chunk data is read and parsed on
+//! a background thread; the main thread only instantiates finished chunks,
+//! so a streamed-in chunk costs one `SceneLoader::instantiate` of its own
+//! entities. Chunks that fall out of range are despawned, which also drops
+//! their rigid bodies and colliders — `PhysicsSystem` garbage-collects the
+//! physics state of removed entities — so only active chunks simulate.
+//!
+//! Entities belong to the chunk they were loaded from for their whole life:
+//! a dynamic body that walks into a neighbouring chunk is still despawned
+//! with its origin chunk.
+
+mod partition;
+mod streamer;
+
+use glam::{DVec2, Vec2};
+use serde::{Deserialize, Serialize};
+
+pub use partition::{partition_scene, ChunkDirectory, ChunkMap, ChunkSource, ChunkedScene};
+pub use streamer::{StreamingUpdate, WorldStreamer};
+
+/// Smallest chunk size accepted; smaller values are clamped.
+pub const MIN_CHUNK_SIZE: f32 = 16.0;
+
+/// Chunk layout and streaming distances, stored in the scene file.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct StreamingSettings {
+    /// Chunk edge length in world units
+    #[serde(default = "default_chunk_size")]
+    pub chunk_size: f32,
+    /// Chunks within this many chunks of the focus chunk are loaded
+    #[serde(default = "default_load_radius")]
+    pub load_radius: u32,
+    /// Loaded chunks further than this are unloaded. Keeping it above
+    /// `load_radius` stops chunks thrashing when the focus sits on a border.
+    #[serde(default = "default_unload_radius")]
+    pub unload_radius: u32,
+}
+
+fn default_chunk_size() -> f32 {
+    1024.0
+}
+
+fn default_load_radius() -> u32 {
+    1
+}
+
+fn default_unload_radius() -> u32 {
+    2
+}
+
+impl Default for StreamingSettings {
+    fn default() -> Self {
+        Self {
+            chunk_size: default_chunk_size(),
+            load_radius: default_load_radius(),
+            unload_radius: default_unload_radius(),
+        }
+    }
+}
+
+impl StreamingSettings {
+    /// Settings with `chunk_size` clamped to [`MIN_CHUNK_SIZE`] and
+    /// `unload_radius` raised to at least `load_radius`.
+    pub fn sanitized(self) -> Self {
+        Self {
+            chunk_size: if self.chunk_size.is_finite() {
+                self.chunk_size.max(MIN_CHUNK_SIZE)
+            } else {
+                default_chunk_size()
+            },
+            load_radius: self.load_radius,
+            unload_radius: self.unload_radius.max(self.load_radius),
+        }
+    }
+}
+
+/// Grid coordinate of a chunk. Chunk `(x, y)` covers world positions
+/// `[x * size, (x + 1) * size) × [y * size, (y + 1) * size)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct ChunkCoord {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl ChunkCoord {
+    /// Create a chunk coordinate.
+    pub fn new(x: i32, y: i32) -> Self {
+        Self { x, y }
+    }
+
+    /// The chunk containing `position`.
+    pub fn containing(position: Vec2, chunk_size: f32) -> Self {
+        let cell = (position / chunk_size).floor();
+        Self::new(cell.x as i32, cell.y as i32)
+    }
+
+    /// The chunk containing the absolute position `position` (see
+    /// `ecs::WorldOrigin`), exact far from the origin.
+    pub fn containing_absolute(position: DVec2, chunk_size: f32) -> Self {
+        let cell = (position / f64::from(chunk_size)).floor();
+        Self::new(cell.x as i32, cell.y as i32)
+    }
+
+    /// World-space `(min, max)` corners of this chunk.
+    pub fn bounds(self, chunk_size: f32) -> (Vec2, Vec2) {
+        let min = Vec2::new(self.x as f32, self.y as f32) * chunk_size;
+        (min, min + Vec2::splat(chunk_size))
+    }
+
+    /// Chebyshev distance in chunks (8-connected neighbours are 1 apart).
+    pub fn distance(self, other: ChunkCoord) -> u32 {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+
+    /// Every chunk within `radius` of this one, row by row.
+    pub fn neighbourhood(self, radius: u32) -> impl Iterator<Item = ChunkCoord> {
+        let r = radius.min(i32::MAX as u32) as i32;
+        (-r..=r).flat_map(move |dy| {
+            (-r..=r).map(move |dx| ChunkCoord::new(self.x.saturating_add(dx), self.y.saturating_add(dy)))
+        })
+    }
+}
+
+impl std::fmt::Display for ChunkCoord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chunk_coord_floors_negative_positions() {
+        assert_eq!(ChunkCoord::containing(Vec2::new(10.0, 10.0), 100.0), ChunkCoord::new(0, 0));
+        assert_eq!(ChunkCoord::containing(Vec2::new(-0.5, 250.0), 100.0), ChunkCoord::new(-1, 2));
+        let (min, max) = ChunkCoord::new(-1, 2).bounds(100.0);
+        assert_eq!((min, max), (Vec2::new(-100.0, 200.0), Vec2::new(0.0, 300.0)));
+        assert_eq!(ChunkCoord::new(0, 0).distance(ChunkCoord::new(-2, 1)), 2);
+        assert_eq!(ChunkCoord::new(3, 3).neighbourhood(1).count(), 9);
+    }
+
+    #[test]
+    fn test_settings_sanitized() {
+        let settings = StreamingSettings { chunk_size: 0.0, load_radius: 3, unload_radius: 1 }.sanitized();
+        assert_eq!(settings.chunk_size, MIN_CHUNK_SIZE);
+        assert_eq!(settings.unload_radius, 3);
+        let parsed: StreamingSettings = ron::from_str("(chunk_size: 512.0)").unwrap();
+        assert_eq!(parsed, StreamingSettings { chunk_size: 512.0, ..StreamingSettings::default() });
+    }
+}
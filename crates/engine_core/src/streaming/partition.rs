@@ -0,0 +1,167 @@
+//! Splitting a scene into chunks, and where chunk data is read from.
+
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+
+use glam::Vec2;
+
+use crate::scene_data::{ComponentData, EntityData, PrefabData, SceneData, SceneLoadError};
+use crate::scene_loader::SceneLoader;
+use crate::scene_serializer::save_scene_to_file;
+
+use super::{ChunkCoord, StreamingSettings};
+
+/// Chunk contents keyed by coordinate; chunks without entities are absent.
+pub type ChunkMap = BTreeMap<ChunkCoord, Vec<EntityData>>;
+
+/// A scene split for streaming.
+#[derive(Debug, Clone)]
+pub struct ChunkedScene {
+    /// Always-loaded part: prefabs, materials, physics and streaming
+    /// settings, plus every entity that isn't tied to a chunk
+    pub base: SceneData,
+    /// Per-chunk root entities (with their children)
+    pub chunks: ChunkMap,
+}
+
+/// Where a [`WorldStreamer`](super::WorldStreamer) reads chunk contents
+/// from. Called on the streaming thread.
+pub trait ChunkSource: Send + 'static {
+    /// The entities of chunk `coord`; a chunk with no data is empty, not an
+    /// error.
+    fn load_chunk(&self, coord: ChunkCoord) -> Result<Vec<EntityData>, SceneLoadError>;
+}
+
+impl ChunkSource for ChunkMap {
+    fn load_chunk(&self, coord: ChunkCoord) -> Result<Vec<EntityData>, SceneLoadError> {
+        Ok(self.get(&coord).cloned().unwrap_or_default())
+    }
+}
+
+/// Chunks stored as scene files in a directory, as written by
+/// [`ChunkedScene::write_to_dir`]: `base.scene.ron` plus one
+/// `chunk_<x>_<y>.scene.ron` per non-empty chunk.
+#[derive(Debug, Clone)]
+pub struct ChunkDirectory {
+    dir: PathBuf,
+}
+
+impl ChunkDirectory {
+    /// File name of the always-loaded base scene.
+    pub const BASE_FILE: &'static str = "base.scene.ron";
+
+    /// Read chunks from `dir`.
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// The directory chunks are read from.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Path of the base scene file.
+    pub fn base_path(&self) -> PathBuf {
+        self.dir.join(Self::BASE_FILE)
+    }
+
+    /// Path of chunk `coord`'s scene file.
+    pub fn chunk_path(&self, coord: ChunkCoord) -> PathBuf {
+        self.dir.join(format!("chunk_{}_{}.scene.ron", coord.x, coord.y))
+    }
+}
+
+impl ChunkSource for ChunkDirectory {
+    fn load_chunk(&self, coord: ChunkCoord) -> Result<Vec<EntityData>, SceneLoadError> {
+        let path = self.chunk_path(coord);
+        if !path.exists() {
+            return Ok(Vec::new());
+        }
+        Ok(SceneLoader::load_from_file(path)?.entities)
+    }
+}
+
+impl ChunkedScene {
+    /// Write the base scene and one scene file per chunk into `dir` (created
+    /// if missing). Every file is a complete scene, so `scene_tools` and the
+    /// editor can open chunks individually.
+    pub fn write_to_dir(&self, dir: impl AsRef<Path>) -> Result<ChunkDirectory, String> {
+        let directory = ChunkDirectory::new(dir.as_ref());
+        std::fs::create_dir_all(directory.dir())
+            .map_err(|e| format!("Failed to create chunk directory: {}", e))?;
+        save_scene_to_file(&self.base, &directory.base_path())?;
+        for (coord, entities) in &self.chunks {
+            let chunk = SceneData {
+                format_version: self.base.format_version,
+                name: format!("{} chunk {}", self.base.name, coord),
+                entities: entities.clone(),
+                ..SceneData::default()
+            };
+            save_scene_to_file(&chunk, &directory.chunk_path(*coord))?;
+        }
+        Ok(directory)
+    }
+}
+
+/// Split `data` into its always-loaded base and per-chunk entities.
+///
+/// Root entities are placed by their resolved `Transform2D` position
+/// (prefab, then overrides, then inline components). An entity with a
+/// `parent` goes wherever its parent went, so hierarchies are never split.
+/// Cameras and entities without a transform stay in the base scene. The
+/// base records `settings` so the split can be reproduced.
+pub fn partition_scene(mut data: SceneData, settings: StreamingSettings) -> ChunkedScene {
+    let settings = settings.sanitized();
+    let entities = std::mem::take(&mut data.entities);
+    let mut base_entities = Vec::new();
+    let mut chunks = ChunkMap::new();
+
+    // Where each entity went, by name, so `parent` links can follow
+    let mut placed: HashMap<&str, Option<ChunkCoord>> = HashMap::new();
+    let assignments: Vec<Option<ChunkCoord>> = entities
+        .iter()
+        .map(|entity| {
+            let chunk = match &entity.parent {
+                Some(parent) => placed.get(parent.as_str()).copied().flatten(),
+                None => chunk_of(entity, &data.prefabs, settings.chunk_size),
+            };
+            if let Some(name) = &entity.name {
+                placed.insert(name.as_str(), chunk);
+            }
+            chunk
+        })
+        .collect();
+    drop(placed);
+
+    for (entity, chunk) in entities.into_iter().zip(assignments) {
+        match chunk {
+            Some(coord) => chunks.entry(coord).or_default().push(entity),
+            None => base_entities.push(entity),
+        }
+    }
+
+    data.entities = base_entities;
+    data.streaming = Some(settings);
+    ChunkedScene { base: data, chunks }
+}
+
+/// The chunk a root entity belongs to, or `None` to keep it loaded.
+fn chunk_of(entity: &EntityData, prefabs: &HashMap<String, PrefabData>, chunk_size: f32) -> Option<ChunkCoord> {
+    let prefab_components = entity
+        .prefab
+        .as_ref()
+        .and_then(|name| prefabs.get(name))
+        .map(|prefab| prefab.components.as_slice())
+        .unwrap_or_default();
+    let components = SceneLoader::merge_components(prefab_components, &entity.overrides, &entity.components);
+
+    if components.iter().any(|c| matches!(c, ComponentData::Camera2D { .. })) {
+        return None;
+    }
+    components.iter().find_map(|component| match component {
+        ComponentData::Transform2D { position, .. } => {
+            Some(ChunkCoord::containing(Vec2::new(position.0, position.1), chunk_size))
+        }
+        _ => None,
+    })
+}
@@ -0,0 +1,283 @@
+//! Runtime chunk loading around a focus point.
+
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+use std::sync::mpsc::{self, Receiver, Sender};
+
+use ecs::{EntityId, World, WorldOrigin};
+use glam::Vec2;
+
+use crate::scene_data::{EntityData, SceneData, SceneLoadError};
+use crate::scene_loader::{SceneInstance, SceneLoader};
+use crate::texture_ref::TextureResolver;
+
+use super::{ChunkCoord, ChunkDirectory, ChunkSource, ChunkedScene, StreamingSettings};
+
+/// A chunk read by the streaming thread.
+type ChunkLoad = (ChunkCoord, Result<Vec<EntityData>, SceneLoadError>);
+
+/// Chunks that changed during one [`WorldStreamer::update`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StreamingUpdate {
+    /// Chunks instantiated this update
+    pub loaded: Vec<ChunkCoord>,
+    /// Chunks despawned this update
+    pub unloaded: Vec<ChunkCoord>,
+}
+
+/// Keeps the chunks around a focus point loaded.
+///
+/// Chunk data comes from a [`ChunkSource`] read on a background thread;
+/// [`update`](Self::update) requests chunks entering the load radius,
+/// instantiates the ones that have arrived, and despawns chunks beyond the
+/// unload radius. The streamer owns the entities it spawned; the
+/// always-loaded base scene is instantiated once by
+/// [`start`](Self::start) and left to the caller.
+///
+/// Chunks are laid out in absolute scene coordinates. After a floating
+/// origin shift (`GameConfig::with_floating_origin`) the local focus is
+/// converted through the world's `WorldOrigin`, and arriving chunks are
+/// placed relative to it (`ecs::place_at_origin`).
+pub struct WorldStreamer {
+    settings: StreamingSettings,
+    /// The base scene without entities: the prefab and material tables
+    /// chunks instantiate against
+    template: SceneData,
+    loaded: HashMap<ChunkCoord, Vec<EntityId>>,
+    pending: HashSet<ChunkCoord>,
+    requests: Sender<ChunkCoord>,
+    results: Receiver<ChunkLoad>,
+}
+
+impl WorldStreamer {
+    /// Instantiate `base` (the always-loaded part of a chunked scene) and
+    /// start streaming its chunks from `source`. Settings come from
+    /// `base.streaming`, falling back to the defaults.
+    pub fn start(
+        base: &SceneData,
+        source: impl ChunkSource,
+        world: &mut World,
+        assets: &mut impl TextureResolver,
+    ) -> Result<(SceneInstance, WorldStreamer), SceneLoadError> {
+        let (request_tx, request_rx) = mpsc::channel::<ChunkCoord>();
+        let (result_tx, result_rx) = mpsc::channel::<ChunkLoad>();
+        std::thread::Builder::new()
+            .name("chunk-streamer".to_string())
+            .spawn(move || {
+                // Ends when the streamer (the request sender) is dropped
+                while let Ok(coord) = request_rx.recv() {
+                    if result_tx.send((coord, source.load_chunk(coord))).is_err() {
+                        break;
+                    }
+                }
+            })?;
+        let instance = SceneLoader::instantiate(base, world, assets)?;
+        ecs::place_at_origin(world, &instance.entities);
+
+        let template = SceneData {
+            format_version: base.format_version,
+            name: base.name.clone(),
+            physics: None,
+            editor: None,
+            prefabs: base.prefabs.clone(),
+            materials: base.materials.clone(),
+            streaming: None,
+            layers: base.layers.clone(),
+            sort_mode: base.sort_mode,
+            entities: Vec::new(),
+            simulation_tick: None,
+            rng: None,
+        };
+        let streamer = WorldStreamer {
+            settings: base.streaming.unwrap_or_default().sanitized(),
+            template,
+            loaded: HashMap::new(),
+            pending: HashSet::new(),
+            requests: request_tx,
+            results: result_rx,
+        };
+        Ok((instance, streamer))
+    }
+
+    /// Load the base scene of a chunk directory (see
+    /// [`ChunkedScene::write_to_dir`]) and stream its chunk files.
+    pub fn open_dir(
+        dir: impl AsRef<Path>,
+        world: &mut World,
+        assets: &mut impl TextureResolver,
+    ) -> Result<(SceneInstance, WorldStreamer), SceneLoadError> {
+        let directory = ChunkDirectory::new(dir.as_ref());
+        let base = SceneLoader::load_from_file(directory.base_path())?;
+        Self::start(&base, directory, world, assets)
+    }
+
+    /// The (sanitized) streaming settings in use.
+    pub fn settings(&self) -> StreamingSettings {
+        self.settings
+    }
+
+    /// Whether chunk `coord` is instantiated.
+    pub fn is_loaded(&self, coord: ChunkCoord) -> bool {
+        self.loaded.contains_key(&coord)
+    }
+
+    /// Instantiated chunks, in no particular order.
+    pub fn loaded_chunks(&self) -> impl Iterator<Item = ChunkCoord> + '_ {
+        self.loaded.keys().copied()
+    }
+
+    /// Entities spawned for chunk `coord` (empty when not loaded).
+    pub fn chunk_entities(&self, coord: ChunkCoord) -> &[EntityId] {
+        self.loaded.get(&coord).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    /// Number of chunks requested but not yet instantiated.
+    pub fn pending_count(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// The chunk containing the absolute scene position `position`.
+    pub fn chunk_at(&self, position: Vec2) -> ChunkCoord {
+        ChunkCoord::containing(position, self.settings.chunk_size)
+    }
+
+    /// The chunk containing the local position `focus`, seen through the
+    /// world's `WorldOrigin`.
+    fn focus_chunk(&self, world: &World, focus: Vec2) -> ChunkCoord {
+        let origin = world.resource::<WorldOrigin>().copied().unwrap_or_default();
+        ChunkCoord::containing_absolute(origin.to_absolute(focus), self.settings.chunk_size)
+    }
+
+    /// Stream around `focus` without blocking: request chunks entering the
+    /// load radius, instantiate the chunks that have arrived, and despawn
+    /// chunks beyond the unload radius. Call once per frame with the
+    /// camera (or player) position.
+    pub fn update(&mut self, world: &mut World, assets: &mut impl TextureResolver, focus: Vec2) -> StreamingUpdate {
+        let center = self.focus_chunk(world, focus);
+        self.request_around(center);
+        let mut update = StreamingUpdate::default();
+        while let Ok((coord, result)) = self.results.try_recv() {
+            self.receive(world, assets, center, coord, result, &mut update);
+        }
+        self.unload_beyond(world, center, &mut update);
+        update
+    }
+
+    /// Like [`update`](Self::update), but waits for every requested chunk to
+    /// arrive — for the first frame of a level, where pop-in would show.
+    pub fn load_around(&mut self, world: &mut World, assets: &mut impl TextureResolver, focus: Vec2) -> StreamingUpdate {
+        let center = self.focus_chunk(world, focus);
+        self.request_around(center);
+        let mut update = StreamingUpdate::default();
+        while !self.pending.is_empty() {
+            let Ok((coord, result)) = self.results.recv() else {
+                log::error!("World streaming: loader thread stopped; {} chunk(s) never arrived", self.pending.len());
+                self.pending.clear();
+                break;
+            };
+            self.receive(world, assets, center, coord, result, &mut update);
+        }
+        self.unload_beyond(world, center, &mut update);
+        update
+    }
+
+    /// Despawn every streamed chunk (the base scene is untouched).
+    pub fn unload_all(&mut self, world: &mut World) -> Vec<ChunkCoord> {
+        self.pending.clear();
+        let mut unloaded = Vec::with_capacity(self.loaded.len());
+        for (coord, entities) in self.loaded.drain() {
+            despawn(world, &entities);
+            unloaded.push(coord);
+        }
+        unloaded
+    }
+
+    fn request_around(&mut self, center: ChunkCoord) {
+        for coord in center.neighbourhood(self.settings.load_radius) {
+            if self.loaded.contains_key(&coord) || self.pending.contains(&coord) {
+                continue;
+            }
+            if self.requests.send(coord).is_ok() {
+                self.pending.insert(coord);
+            } else {
+                log::error!("World streaming: loader thread stopped; chunk {} not requested", coord);
+            }
+        }
+    }
+
+    /// Instantiate an arrived chunk, unless the focus has already moved out
+    /// of range (then it's dropped and re-requested if the focus returns).
+    fn receive(
+        &mut self,
+        world: &mut World,
+        assets: &mut impl TextureResolver,
+        center: ChunkCoord,
+        coord: ChunkCoord,
+        result: Result<Vec<EntityData>, SceneLoadError>,
+        update: &mut StreamingUpdate,
+    ) {
+        if !self.pending.remove(&coord) || coord.distance(center) > self.settings.unload_radius {
+            return;
+        }
+        let entities = match result {
+            Ok(entities) => entities,
+            Err(e) => {
+                // Kept as an empty chunk so a broken file isn't re-read every frame
+                log::error!("World streaming: failed to read chunk {}: {}", coord, e);
+                Vec::new()
+            }
+        };
+
+        self.template.entities = entities;
+        let instance = SceneLoader::instantiate(&self.template, world, assets);
+        self.template.entities.clear();
+        let spawned = match instance {
+            Ok(instance) => {
+                ecs::place_at_origin(world, &instance.entities);
+                instance.entities
+            }
+            Err(e) => {
+                log::error!("World streaming: failed to instantiate chunk {}: {}", coord, e);
+                Vec::new()
+            }
+        };
+        log::debug!("World streaming: loaded chunk {} ({} entities)", coord, spawned.len());
+        self.loaded.insert(coord, spawned);
+        update.loaded.push(coord);
+    }
+
+    fn unload_beyond(&mut self, world: &mut World, center: ChunkCoord, update: &mut StreamingUpdate) {
+        let radius = self.settings.unload_radius;
+        let far: Vec<ChunkCoord> = self
+            .loaded
+            .keys()
+            .copied()
+            .filter(|coord| coord.distance(center) > radius)
+            .collect();
+        for coord in far {
+            if let Some(entities) = self.loaded.remove(&coord) {
+                despawn(world, &entities);
+                log::debug!("World streaming: unloaded chunk {}", coord);
+                update.unloaded.push(coord);
+            }
+        }
+    }
+}
+
+impl ChunkedScene {
+    /// Instantiate the base scene and stream the chunks from memory.
+    pub fn start(
+        self,
+        world: &mut World,
+        assets: &mut impl TextureResolver,
+    ) -> Result<(SceneInstance, WorldStreamer), SceneLoadError> {
+        WorldStreamer::start(&self.base, self.chunks, world, assets)
+    }
+}
+
+/// Remove a chunk's entities. Ones gameplay already destroyed are skipped.
+fn despawn(world: &mut World, entities: &[EntityId]) {
+    for entity in entities.iter().rev() {
+        world.remove_entity(entity).ok();
+    }
+}
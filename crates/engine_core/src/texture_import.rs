@@ -0,0 +1,220 @@
+//! Texture import settings: pixels-per-unit sizing for sprites.
+//!
+//! A sprite's drawn size at scale 1 is its texture's *natural size*:
+//! `texture pixels / pixels_per_unit` world units (for a sprite-sheet frame,
+//! the frame's share of that). The project sets a default pixels-per-unit
+//! and individual textures may override it; both persist in
+//! `<asset base>/import_settings.ron`.
+//!
+//! Generated textures (`#white`, `#solid:RRGGBB`) have no meaningful pixel
+//! size and keep the historical square of [`RENDER_UNIT`](crate::RENDER_UNIT)
+//! world units, so color-block sprites sized as `pixels / RENDER_UNIT` are
+//! unaffected.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use glam::Vec2;
+use serde::{Deserialize, Serialize};
+
+use crate::RENDER_UNIT;
+
+/// File name of the import settings, relative to the asset base path.
+pub const IMPORT_SETTINGS_FILE: &str = "import_settings.ron";
+
+/// Project default: one texture pixel per world unit (native size).
+pub const DEFAULT_PIXELS_PER_UNIT: f32 = 1.0;
+
+/// Smallest accepted pixels-per-unit (guards divide-by-zero).
+const MIN_PIXELS_PER_UNIT: f32 = 0.001;
+
+fn default_pixels_per_unit() -> f32 {
+    DEFAULT_PIXELS_PER_UNIT
+}
+
+/// Project default and per-texture pixels-per-unit.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TextureImportSettings {
+    /// Pixels-per-unit for textures without an override.
+    #[serde(default = "default_pixels_per_unit")]
+    pub default_pixels_per_unit: f32,
+    /// Overrides keyed by the texture path as passed to `load_texture`.
+    #[serde(default)]
+    pub textures: BTreeMap<String, f32>,
+}
+
+impl Default for TextureImportSettings {
+    fn default() -> Self {
+        Self { default_pixels_per_unit: DEFAULT_PIXELS_PER_UNIT, textures: BTreeMap::new() }
+    }
+}
+
+impl TextureImportSettings {
+    /// Effective pixels-per-unit for `path`.
+    pub fn pixels_per_unit(&self, path: &str) -> f32 {
+        self.textures.get(path).copied().unwrap_or(self.default_pixels_per_unit)
+    }
+
+    /// Override pixels-per-unit for one texture (clamped to a positive value).
+    pub fn set_pixels_per_unit(&mut self, path: impl Into<String>, pixels_per_unit: f32) {
+        self.textures.insert(path.into(), pixels_per_unit.max(MIN_PIXELS_PER_UNIT));
+    }
+
+    /// Batch import: apply one pixels-per-unit to every path. Returns how
+    /// many textures changed.
+    pub fn set_pixels_per_unit_batch<'a>(
+        &mut self,
+        paths: impl IntoIterator<Item = &'a str>,
+        pixels_per_unit: f32,
+    ) -> usize {
+        let pixels_per_unit = pixels_per_unit.max(MIN_PIXELS_PER_UNIT);
+        let mut changed = 0;
+        for path in paths {
+            if self.pixels_per_unit(path) != pixels_per_unit {
+                changed += 1;
+            }
+            self.textures.insert(path.to_string(), pixels_per_unit);
+        }
+        changed
+    }
+
+    /// Remove a texture's override so it follows the project default.
+    pub fn clear_override(&mut self, path: &str) -> bool {
+        self.textures.remove(path).is_some()
+    }
+
+    /// Load `<base>/import_settings.ron`. A missing file yields defaults; a
+    /// corrupt one logs a warning and yields defaults (never fails a load).
+    pub fn load_or_default(base: &Path) -> Self {
+        let path = base.join(IMPORT_SETTINGS_FILE);
+        let Ok(content) = std::fs::read_to_string(&path) else {
+            return Self::default();
+        };
+        match ron::from_str(&content) {
+            Ok(settings) => settings,
+            Err(e) => {
+                log::warn!("Ignoring unreadable import settings {:?}: {}", path, e);
+                Self::default()
+            }
+        }
+    }
+
+    /// Write `<base>/import_settings.ron`.
+    pub fn save(&self, base: &Path) -> std::io::Result<()> {
+        let content = ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())
+            .map_err(std::io::Error::other)?;
+        std::fs::write(base.join(IMPORT_SETTINGS_FILE), content)
+    }
+}
+
+/// Natural (scale-1) world size of each imported texture, by handle id.
+///
+/// Handles without an entry — the white texture, generated solid colors —
+/// are a square of [`RENDER_UNIT`](crate::RENDER_UNIT).
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TextureSizes {
+    sizes: BTreeMap<u32, Vec2>,
+}
+
+impl TextureSizes {
+    /// An empty table (every handle uses the generated-texture size).
+    pub const fn new() -> Self {
+        Self { sizes: BTreeMap::new() }
+    }
+
+    /// Record an imported texture's pixel size and pixels-per-unit.
+    pub fn insert(&mut self, handle: u32, width: u32, height: u32, pixels_per_unit: f32) {
+        let size = Vec2::new(width as f32, height as f32) / pixels_per_unit.max(MIN_PIXELS_PER_UNIT);
+        self.sizes.insert(handle, size);
+    }
+
+    /// Forget a texture (e.g. after unloading it).
+    pub fn remove(&mut self, handle: u32) {
+        self.sizes.remove(&handle);
+    }
+
+    /// Whether `handle` has a recorded (imported) size.
+    pub fn contains(&self, handle: u32) -> bool {
+        self.sizes.contains_key(&handle)
+    }
+
+    /// Natural world size of the whole texture at scale 1.
+    pub fn texture_size(&self, handle: u32) -> Vec2 {
+        self.sizes.get(&handle).copied().unwrap_or(Vec2::splat(RENDER_UNIT))
+    }
+
+    /// Natural world size of a sprite showing `tex_region` (normalized
+    /// `[x, y, w, h]`) of `handle` at scale 1. Generated textures ignore the
+    /// region — they are uniform, so any region looks the same.
+    pub fn sprite_size(&self, handle: u32, tex_region: [f32; 4]) -> Vec2 {
+        match self.sizes.get(&handle) {
+            Some(size) => *size * Vec2::new(tex_region[2], tex_region[3]),
+            None => Vec2::splat(RENDER_UNIT),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn overrides_take_precedence_over_project_default() {
+        let mut settings = TextureImportSettings { default_pixels_per_unit: 2.0, ..Default::default() };
+        settings.set_pixels_per_unit("hero.png", 16.0);
+        assert_eq!(settings.pixels_per_unit("hero.png"), 16.0);
+        assert_eq!(settings.pixels_per_unit("tree.png"), 2.0);
+
+        assert!(settings.clear_override("hero.png"));
+        assert_eq!(settings.pixels_per_unit("hero.png"), 2.0);
+    }
+
+    #[test]
+    fn batch_import_counts_only_changed_textures() {
+        let mut settings = TextureImportSettings::default();
+        settings.set_pixels_per_unit("a.png", 4.0);
+        let changed = settings.set_pixels_per_unit_batch(["a.png", "b.png", "c.png"], 4.0);
+        assert_eq!(changed, 2);
+        assert_eq!(settings.pixels_per_unit("c.png"), 4.0);
+    }
+
+    #[test]
+    fn non_positive_pixels_per_unit_is_clamped() {
+        let mut settings = TextureImportSettings::default();
+        settings.set_pixels_per_unit("a.png", 0.0);
+        assert!(settings.pixels_per_unit("a.png") > 0.0);
+    }
+
+    #[test]
+    fn import_settings_round_trip_through_file() {
+        let dir = std::env::temp_dir().join(format!("insiculous_import_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).expect("temp dir");
+        let mut settings = TextureImportSettings { default_pixels_per_unit: 32.0, ..Default::default() };
+        settings.set_pixels_per_unit("images/hero.png", 16.0);
+        settings.save(&dir).expect("save");
+
+        assert_eq!(TextureImportSettings::load_or_default(&dir), settings);
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn missing_settings_file_yields_defaults() {
+        let dir = std::env::temp_dir().join("insiculous_import_missing_dir");
+        assert_eq!(TextureImportSettings::load_or_default(&dir), TextureImportSettings::default());
+    }
+
+    #[test]
+    fn natural_size_is_pixels_over_pixels_per_unit() {
+        let mut sizes = TextureSizes::new();
+        sizes.insert(3, 64, 32, 16.0);
+        assert_eq!(sizes.texture_size(3), Vec2::new(4.0, 2.0));
+        // Half-width sprite-sheet frame
+        assert_eq!(sizes.sprite_size(3, [0.0, 0.0, 0.5, 1.0]), Vec2::new(2.0, 2.0));
+    }
+
+    #[test]
+    fn generated_textures_keep_the_render_unit_square() {
+        let sizes = TextureSizes::new();
+        assert_eq!(sizes.sprite_size(0, [0.0, 0.0, 0.5, 0.5]), Vec2::splat(RENDER_UNIT));
+    }
+}
@@ -9,6 +9,9 @@ use renderer::TextureHandle;
 
 use crate::assets::AssetManager;
 use crate::scene_data::SceneLoadError;
+use crate::texture_import::TextureSizes;
+
+static NO_TEXTURE_SIZES: TextureSizes = TextureSizes::new();
 
 /// Resolves scene texture references (`#white`, `#solid:RRGGBB`, file paths)
 /// to texture handles.
@@ -18,12 +21,22 @@ use crate::scene_data::SceneLoadError;
 pub trait TextureResolver {
     /// Resolve a texture reference string to a handle.
     fn resolve_texture(&mut self, texture_ref: &str) -> Result<TextureHandle, SceneLoadError>;
+
+    /// Natural sizes of resolved textures, used to upgrade legacy scenes.
+    /// The default reports none (every handle sized like a generated texture).
+    fn texture_sizes(&self) -> &TextureSizes {
+        &NO_TEXTURE_SIZES
+    }
 }
 
 impl TextureResolver for AssetManager {
     fn resolve_texture(&mut self, texture_ref: &str) -> Result<TextureHandle, SceneLoadError> {
         resolve_texture(texture_ref, self)
     }
+
+    fn texture_sizes(&self) -> &TextureSizes {
+        AssetManager::texture_sizes(self)
+    }
 }
 
 /// Resolve a texture reference to a TextureHandle.
@@ -9,17 +9,23 @@
 //! `Tilemap.tile_size` and `Transform2D.position` share the same pixel
 //! space. Transform rotation/scale are ignored (see `ecs::Tilemap` docs).
 
+use std::collections::HashSet;
+
 use ecs::sprite_components::Transform2D;
-use ecs::{Tilemap, World};
+use ecs::{EntityId, Tilemap, World};
 use glam::Vec2;
 use renderer::sprite::SpriteBatcher;
 use renderer::texture::TextureHandle;
 
 /// Append one sprite per non-zero tile of every tilemap entity to the
-/// game batcher. Called by the default `Game::render` before the entity
-/// sprite loop so equal-depth sprites draw over tiles.
-pub(crate) fn append_tilemap_sprites(world: &World, sprites: &mut SpriteBatcher) {
-    for entity in world.entities() {
+/// game batcher. Run as the built-in `tilemap` extractor, ahead of the
+/// ECS sprite extractor so equal-depth sprites draw over tiles. `hidden`
+/// and inactive maps are skipped.
+pub(crate) fn append_tilemap_sprites(world: &World, hidden: &HashSet<EntityId>, sprites: &mut SpriteBatcher) {
+    for entity in world.active_entities() {
+        if hidden.contains(&entity) {
+            continue;
+        }
         let Some(tilemap) = world.get::<Tilemap>(entity) else { continue };
         let Some(transform) = world.get::<Transform2D>(entity) else { continue };
 
@@ -62,11 +68,11 @@ mod tests {
         let world = world_with_map(Vec2::new(100.0, 200.0));
         let mut batcher = SpriteBatcher::new();
 
-        append_tilemap_sprites(&world, &mut batcher);
+        append_tilemap_sprites(&world, &HashSet::new(), &mut batcher);
 
         let batches = batcher.batches();
         assert_eq!(batches.len(), 1, "whole map should share one batch");
-        let batch = batches.get(&TextureHandle { id: 7 }).unwrap();
+        let batch = batcher.batch(TextureHandle { id: 7 }).unwrap();
         assert_eq!(batch.instances.len(), 2);
 
         // Tile (0,0): at the anchor, tileset cell 0.
@@ -90,7 +96,7 @@ mod tests {
         world.add_component(&entity, Tilemap::new(2, 2, 32.0)).unwrap();
 
         let mut batcher = SpriteBatcher::new();
-        append_tilemap_sprites(&world, &mut batcher);
+        append_tilemap_sprites(&world, &HashSet::new(), &mut batcher);
         assert!(batcher.batches().is_empty());
     }
 }
@@ -1,8 +1,11 @@
 //! Timing functionality for the engine.
 //!
-//! This module provides utilities for tracking time and managing frame rates.
+//! This module provides utilities for tracking time and managing frame rates,
+//! plus the fixed-update clock behind `ctx.time`.
 
-use std::time::{Duration, Instant};
+use std::time::Duration;
+
+use common::time::Instant;
 
 /// A timer for tracking elapsed time
 pub struct Timer {
@@ -67,3 +70,296 @@ impl Default for Timer {
         Self::new()
     }
 }
+
+/// Default fixed update rate: 60 ticks per second.
+pub const DEFAULT_FIXED_TIMESTEP: f32 = 1.0 / 60.0;
+
+/// Maximum fixed updates run in one frame. Leftover time beyond the cap is
+/// dropped so a slow frame cannot snowball into ever more catch-up steps.
+pub const MAX_FIXED_STEPS_PER_FRAME: u32 = 8;
+
+/// Fixed-timestep accumulator that turns variable frame deltas into a whole
+/// number of fixed updates.
+#[derive(Debug, Clone)]
+pub struct FixedClock {
+    fixed_timestep: f32,
+    accumulator: f32,
+}
+
+impl FixedClock {
+    /// Create a clock stepping every `fixed_timestep` seconds (non-positive
+    /// values fall back to [`DEFAULT_FIXED_TIMESTEP`]).
+    pub fn new(fixed_timestep: f32) -> Self {
+        let fixed_timestep = if fixed_timestep > 0.0 { fixed_timestep } else { DEFAULT_FIXED_TIMESTEP };
+        Self { fixed_timestep, accumulator: 0.0 }
+    }
+
+    /// Seconds per fixed update.
+    pub fn fixed_timestep(&self) -> f32 {
+        self.fixed_timestep
+    }
+
+    /// Accumulate `delta_time` and return how many fixed updates are due.
+    pub fn advance(&mut self, delta_time: f32) -> u32 {
+        self.accumulator += delta_time.max(0.0);
+        let mut steps = 0;
+        while self.accumulator >= self.fixed_timestep && steps < MAX_FIXED_STEPS_PER_FRAME {
+            self.accumulator -= self.fixed_timestep;
+            steps += 1;
+        }
+        if self.accumulator >= self.fixed_timestep {
+            log::warn!("Fixed update fell behind: dropping {:.3}s of accumulated time", self.accumulator);
+            self.accumulator = 0.0;
+        }
+        steps
+    }
+}
+
+impl Default for FixedClock {
+    fn default() -> Self {
+        Self::new(DEFAULT_FIXED_TIMESTEP)
+    }
+}
+
+/// Game-speed control, stored as a world resource and edited through
+/// `ctx.time` (`set_scale`, `pause`, `resume`).
+///
+/// The engine multiplies each frame's delta by [`effective`](Self::effective)
+/// before advancing the fixed-update clock and handing `ctx.delta_time` to
+/// the game, so physics and other delta-driven logic slow down or freeze with
+/// it. Rendering, UI, and `Game::update` itself keep running; UI animation
+/// reads `ctx.time.unscaled_delta()`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TimeScale {
+    scale: f32,
+    paused: bool,
+}
+
+impl Default for TimeScale {
+    fn default() -> Self {
+        Self { scale: 1.0, paused: false }
+    }
+}
+
+impl TimeScale {
+    /// Unpaused time running at `scale` (see [`set_scale`](Self::set_scale)).
+    pub fn new(scale: f32) -> Self {
+        let mut time_scale = Self::default();
+        time_scale.set_scale(scale);
+        time_scale
+    }
+
+    /// Speed multiplier (1.0 = normal, 0.5 = slow motion), ignoring pause.
+    pub fn scale(&self) -> f32 {
+        self.scale
+    }
+
+    /// Set the speed multiplier. Negative values clamp to 0.0; non-finite
+    /// values are ignored.
+    pub fn set_scale(&mut self, scale: f32) {
+        if scale.is_finite() {
+            self.scale = scale.max(0.0);
+        } else {
+            log::warn!("Ignoring non-finite time scale {scale}");
+        }
+    }
+
+    /// Freeze simulated time, keeping the scale for [`resume`](Self::resume).
+    pub fn pause(&mut self) {
+        self.paused = true;
+    }
+
+    /// Unfreeze simulated time at the previous scale.
+    pub fn resume(&mut self) {
+        self.paused = false;
+    }
+
+    /// Whether simulated time is frozen.
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Multiplier actually applied: 0.0 while paused, otherwise the scale.
+    pub fn effective(&self) -> f32 {
+        if self.paused { 0.0 } else { self.scale }
+    }
+}
+
+/// Simulation time as seen by game code (`ctx.time`).
+///
+/// The tick counter is the world's [`SimulationTick`](ecs::SimulationTick)
+/// resource: it increases by one per fixed update, is stamped on collision
+/// and gameplay events, and is recorded by scene saves and world snapshots.
+/// The speed controls edit a copy of the world's [`TimeScale`] resource; the
+/// engine writes it back after `update()`, so changes apply from the next
+/// frame.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Time {
+    tick: u64,
+    steps_this_frame: u32,
+    fixed_timestep: f32,
+    delta: f32,
+    unscaled_delta: f32,
+    time_scale: TimeScale,
+}
+
+impl Time {
+    /// Scale `delta_time` by `world`'s [`TimeScale`], advance its tick by the
+    /// fixed updates `clock` then has due, and describe the result.
+    pub fn advance(clock: &mut FixedClock, world: &mut ecs::World, delta_time: f32) -> Self {
+        world.init_resource::<TimeScale>();
+        let time_scale = world.resource::<TimeScale>().copied().unwrap_or_default();
+        let delta = delta_time * time_scale.effective();
+        let steps = clock.advance(delta);
+        world.init_resource::<ecs::SimulationTick>();
+        let tick = match world.resource_mut::<ecs::SimulationTick>() {
+            Some(tick) => {
+                tick.0 += u64::from(steps);
+                tick.0
+            }
+            None => 0,
+        };
+        Self {
+            tick,
+            steps_this_frame: steps,
+            fixed_timestep: clock.fixed_timestep(),
+            delta,
+            unscaled_delta: delta_time,
+            time_scale,
+        }
+    }
+
+    /// Current fixed-update tick (monotonic; 0 before the first fixed update).
+    pub fn tick(&self) -> u64 {
+        self.tick
+    }
+
+    /// Fixed updates that elapsed this frame (0 on fast frames, more than 1
+    /// when catching up).
+    pub fn steps_this_frame(&self) -> u32 {
+        self.steps_this_frame
+    }
+
+    /// Seconds per fixed update.
+    pub fn fixed_timestep(&self) -> f32 {
+        self.fixed_timestep
+    }
+
+    /// This frame's delta in seconds after scaling (0.0 while paused) — the
+    /// value passed as `ctx.delta_time`.
+    pub fn delta(&self) -> f32 {
+        self.delta
+    }
+
+    /// This frame's real delta in seconds, for UI animation that must keep
+    /// moving during slow motion and pause.
+    pub fn unscaled_delta(&self) -> f32 {
+        self.unscaled_delta
+    }
+
+    /// This frame as exactly one fixed update of `timestep` seconds, for
+    /// the editor's frame stepping. The real frame delta is kept.
+    pub fn single_step(&self, timestep: f32) -> Self {
+        Self { steps_this_frame: 1, fixed_timestep: timestep, delta: timestep, ..*self }
+    }
+
+    /// The speed settings (written back to the world's resource).
+    pub fn time_scale(&self) -> TimeScale {
+        self.time_scale
+    }
+
+    /// Speed multiplier (1.0 = normal), ignoring pause.
+    pub fn scale(&self) -> f32 {
+        self.time_scale.scale()
+    }
+
+    /// Set the speed multiplier from the next frame (0.5 = slow motion).
+    pub fn set_scale(&mut self, scale: f32) {
+        self.time_scale.set_scale(scale);
+    }
+
+    /// Freeze simulated time from the next frame.
+    pub fn pause(&mut self) {
+        self.time_scale.pause();
+    }
+
+    /// Unfreeze simulated time from the next frame.
+    pub fn resume(&mut self) {
+        self.time_scale.resume();
+    }
+
+    /// Whether simulated time is (or will next frame be) frozen.
+    pub fn is_paused(&self) -> bool {
+        self.time_scale.is_paused()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fixed_clock_carries_remainders_between_frames() {
+        let mut clock = FixedClock::new(0.5);
+        assert_eq!(clock.advance(0.3), 0);
+        assert_eq!(clock.advance(0.3), 1);
+        assert_eq!(clock.advance(1.0), 2);
+    }
+
+    #[test]
+    fn fixed_clock_caps_catch_up_steps() {
+        let mut clock = FixedClock::new(0.1);
+        assert_eq!(clock.advance(10.0), MAX_FIXED_STEPS_PER_FRAME);
+        assert_eq!(clock.advance(0.05), 0, "dropped time is not replayed");
+    }
+
+    #[test]
+    fn time_advances_the_world_tick_from_its_current_value() {
+        let mut world = ecs::World::new();
+        world.insert_resource(ecs::SimulationTick(100));
+        let mut clock = FixedClock::new(0.25);
+
+        let time = Time::advance(&mut clock, &mut world, 0.5);
+        assert_eq!(time.tick(), 102);
+        assert_eq!(time.steps_this_frame(), 2);
+        assert_eq!(world.simulation_tick(), 102);
+    }
+
+    #[test]
+    fn time_scale_slows_ticks_and_delta_but_not_unscaled_delta() {
+        let mut world = ecs::World::new();
+        world.insert_resource(TimeScale::new(0.5));
+        let mut clock = FixedClock::new(0.25);
+
+        let time = Time::advance(&mut clock, &mut world, 1.0);
+        assert_eq!(time.delta(), 0.5);
+        assert_eq!(time.unscaled_delta(), 1.0);
+        assert_eq!(time.steps_this_frame(), 2);
+    }
+
+    #[test]
+    fn paused_time_freezes_the_tick_and_resume_keeps_the_scale() {
+        let mut world = ecs::World::new();
+        let mut clock = FixedClock::new(0.25);
+        let mut time_scale = TimeScale::new(2.0);
+        time_scale.pause();
+        world.insert_resource(time_scale);
+
+        let time = Time::advance(&mut clock, &mut world, 1.0);
+        assert_eq!((time.tick(), time.delta(), time.unscaled_delta()), (0, 0.0, 1.0));
+
+        time_scale.resume();
+        assert_eq!(time_scale.effective(), 2.0);
+        time_scale.set_scale(-3.0);
+        assert_eq!(time_scale.scale(), 0.0);
+    }
+
+    #[test]
+    fn single_step_is_one_fixed_update_with_the_real_delta_kept() {
+        let mut world = ecs::World::new();
+        let mut clock = FixedClock::new(0.25);
+        let time = Time::advance(&mut clock, &mut world, 0.1).single_step(1.0 / 60.0);
+        assert_eq!((time.steps_this_frame(), time.delta(), time.unscaled_delta()), (1, 1.0 / 60.0, 0.1));
+        assert_eq!(time.fixed_timestep(), 1.0 / 60.0);
+    }
+}
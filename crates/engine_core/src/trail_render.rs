@@ -0,0 +1,71 @@
+//! `Trail2D` → trail-strip geometry.
+//!
+//! The game loop advances every trail with `ecs::TrailSystem` after the
+//! game's update, then stitches each trail's strip (see
+//! `Trail2D::strip`) into one vertex list for the renderer's trail pass.
+//! Trails are world-space geometry, so they need no camera handling here.
+
+use ecs::{System, Trail2D, TrailSystem, World};
+use renderer::trail_pipeline::{append_strip, TrailVertex};
+
+/// Steps trails and owns the per-frame vertex buffers (cleared, capacity
+/// retained, every frame).
+#[derive(Default)]
+pub(crate) struct TrailRenderer {
+    system: TrailSystem,
+    vertices: Vec<TrailVertex>,
+    strip: Vec<TrailVertex>,
+}
+
+impl TrailRenderer {
+    /// Advance every trail by `delta_time` and rebuild the strip vertices.
+    pub(crate) fn update(&mut self, world: &mut World, delta_time: f32) -> &[TrailVertex] {
+        self.system.update(world, delta_time);
+        self.vertices.clear();
+        for entity in world.active_entities() {
+            let Some(trail) = world.get::<Trail2D>(entity) else { continue };
+            self.strip.clear();
+            self.strip.extend(
+                trail
+                    .strip()
+                    .into_iter()
+                    .map(|vertex| TrailVertex::new(vertex.position, trail.depth, vertex.color, trail.emissive)),
+            );
+            append_strip(&mut self.vertices, &self.strip);
+        }
+        &self.vertices
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ecs::sprite_components::Transform2D;
+    use glam::Vec2;
+
+    #[test]
+    fn test_moving_trails_become_one_stitched_strip() {
+        let mut world = World::new();
+        for y in [0.0, 100.0] {
+            let entity = world.create_entity();
+            world.add_component(&entity, Transform2D::new(Vec2::new(0.0, y))).unwrap();
+            world.add_component(&entity, Trail2D::new().with_depth(0.25)).unwrap();
+        }
+        let idle = world.create_entity();
+        world.add_component(&idle, Transform2D::new(Vec2::ZERO)).unwrap();
+        world.add_component(&idle, Trail2D::new()).unwrap();
+
+        let mut trails = TrailRenderer::default();
+        assert!(trails.update(&mut world, 0.016).is_empty(), "nothing has moved yet");
+
+        for entity in world.entities() {
+            if entity != idle {
+                world.get_mut::<Transform2D>(entity).unwrap().position.x = 50.0;
+            }
+        }
+        let vertices = trails.update(&mut world, 0.016);
+        // Two 2-point strips (4 vertices each) joined by 2 degenerates.
+        assert_eq!(vertices.len(), 10);
+        assert!(vertices.iter().all(|v| v.depth == 0.25));
+    }
+}
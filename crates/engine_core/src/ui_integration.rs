@@ -11,8 +11,7 @@ use renderer::{
     texture::TextureHandle,
 };
 use ui::{DrawCommand, Rect};
-use std::collections::HashMap;
-use crate::contexts::GlyphCacheKey;
+use crate::contexts::{GlyphCacheKey, UiAtlasRegions};
 
 /// Where UI sprites land in world space so they render at fixed SCREEN
 /// pixels through `camera`. UI must not move when the game camera moves
@@ -75,17 +74,22 @@ impl UiCameraSpace {
 /// - Text placeholders for debugging
 /// - Circle rendering (as squares until circle shader is available)
 /// - Line rendering as rotated rectangles
-/// 
+///
+/// Untextured shapes sample the atlas's white point, so a panel's rects,
+/// its text and its atlas icons share one texture binding.
+///
 /// Coordinate transformation: UI uses screen coordinates (0,0 = top-left) while
 /// the renderer uses world coordinates (0,0 = center of screen).
 pub fn render_ui_commands(
     sprites: &mut SpriteBatcher,
     commands: &[DrawCommand],
     camera: &Camera,
-    glyph_textures: &HashMap<GlyphCacheKey, TextureHandle>,
+    glyph_textures: &UiAtlasRegions,
 ) {
     let cam = UiCameraSpace::new(camera);
-    let white_texture = TextureHandle { id: 0 };
+    let white = glyph_textures.white_region();
+    let [white_u, white_v, white_w, white_h] = white.uv;
+    let solid = || Sprite::new(white.texture).with_tex_region(white_u, white_v, white_w, white_h);
     let mut clip_stack: Vec<Rect> = Vec::new();
 
     for cmd in commands {
@@ -124,7 +128,7 @@ pub fn render_ui_commands(
                 // Convert screen coordinates (0,0 = top-left) to world coordinates (0,0 = center)
                 let center = cam.rect_center(bounds);
 
-                let mut sprite = Sprite::new(white_texture)
+                let mut sprite = solid()
                     .with_position(center)
                     .with_scale(cam.size(Vec2::new(bounds.width, bounds.height)))
                     .with_color(glam::Vec4::new(color.r, color.g, color.b, color.a))
@@ -146,7 +150,7 @@ pub fn render_ui_commands(
                 );
                 let center = cam.rect_center(&grown);
 
-                let sprite = Sprite::new(white_texture)
+                let sprite = solid()
                     .with_position(center)
                     .with_scale(cam.size(Vec2::new(grown.width, grown.height)))
                     .with_color(glam::Vec4::new(color.r, color.g, color.b, color.a))
@@ -163,7 +167,7 @@ pub fn render_ui_commands(
                     let text_bounds = Rect::new(data.position.x, data.position.y, data.width, data.height);
                     let center = cam.rect_center(&text_bounds);
 
-                    let sprite = Sprite::new(white_texture)
+                    let sprite = solid()
                         .with_position(center)
                         .with_scale(cam.size(Vec2::new(data.width.max(data.font_size * 4.0), data.height.max(data.font_size))))
                         .with_color(glam::Vec4::new(data.color.r, data.color.g, data.color.b, data.color.a * 0.3))
@@ -192,30 +196,34 @@ pub fn render_ui_commands(
                         );
                         let glyph_center = cam.rect_center(&glyph_bounds);
 
-                        // Look up glyph texture in cache (color-agnostic)
+                        // Look up the glyph's atlas region (color-agnostic)
                         let glyph_key = GlyphCacheKey::new(
+                            glyph.font_id,
                             glyph.character,
                             glyph.width,
                             glyph.height,
                         );
 
-                        let texture = match glyph_textures.get(&glyph_key) {
-                            Some(&tex) => tex,
+                        let glyph_sprite = match glyph_textures.get(&glyph_key) {
+                            Some(region) => {
+                                let [u, v, w, h] = region.uv;
+                                Sprite::new(region.texture).with_tex_region(u, v, w, h)
+                            }
                             None => {
                                 log::warn!(
-                                    "Missing glyph texture for '{}' ({}x{}). Using white fallback. \
+                                    "Missing glyph atlas region for '{}' ({}x{}). Using white fallback. \
                                      This may indicate the glyph wasn't pre-cached.",
                                     glyph.character, glyph.width, glyph.height
                                 );
-                                white_texture
+                                solid()
                             }
                         };
 
-                        // Render glyph with text color - texture is grayscale alpha mask
+                        // Render glyph with text color - the atlas holds grayscale alpha masks
                         let render_width = glyph.width as f32;
                         let render_height = glyph.height as f32;
 
-                        let sprite = Sprite::new(texture)
+                        let sprite = glyph_sprite
                             .with_position(glyph_center)
                             .with_scale(cam.size(Vec2::new(render_width, render_height)))
                             .with_color(glam::Vec4::new(data.color.r, data.color.g, data.color.b, data.color.a))
@@ -233,7 +241,7 @@ pub fn render_ui_commands(
                 let placeholder_bounds = Rect::new(position.x, position.y, estimated_width, *font_size);
                 let center = cam.rect_center(&placeholder_bounds);
 
-                let sprite = Sprite::new(white_texture)
+                let sprite = solid()
                     .with_position(center)
                     .with_scale(cam.size(Vec2::new(estimated_width, *font_size)))
                     .with_color(glam::Vec4::new(color.r, color.g, color.b, color.a * 0.3))
@@ -245,7 +253,7 @@ pub fn render_ui_commands(
                 // Real circle via the sprite pipeline's SDF mask
                 let world_center = cam.point(center.x, center.y);
 
-                let sprite = Sprite::new(white_texture)
+                let sprite = solid()
                     .with_position(world_center)
                     .with_scale(cam.size(Vec2::new(*radius * 2.0, *radius * 2.0)))
                     .with_color(glam::Vec4::new(color.r, color.g, color.b, color.a))
@@ -266,7 +274,7 @@ pub fn render_ui_commands(
                     (start.y + end.y) / 2.0,
                 );
 
-                let sprite = Sprite::new(white_texture)
+                let sprite = solid()
                     .with_position(midpoint)
                     .with_rotation(-angle) // Negate for coordinate system
                     .with_scale(cam.size(Vec2::new(length, *width)))
@@ -275,7 +283,7 @@ pub fn render_ui_commands(
 
                 sprites.add_sprite(&sprite);
             }
-            DrawCommand::Image { bounds, texture_id, tint, corner_radius, depth } => {
+            DrawCommand::Image { bounds, texture_id, uv_rect, tint, corner_radius, depth } => {
                 // Same path as Rect, but sampling a real texture (the glyph
                 // pipeline established this pattern)
                 let center = cam.rect_center(bounds);
@@ -283,6 +291,7 @@ pub fn render_ui_commands(
                 let mut sprite = Sprite::new(TextureHandle { id: *texture_id })
                     .with_position(center)
                     .with_scale(cam.size(Vec2::new(bounds.width, bounds.height)))
+                    .with_tex_region(uv_rect.x, uv_rect.y, uv_rect.width, uv_rect.height)
                     .with_color(glam::Vec4::new(tint.r, tint.g, tint.b, tint.a))
                     .with_depth(*depth);
                 if *corner_radius > 0.0 {
@@ -346,7 +355,7 @@ mod tests {
     }
 
     fn white_instances(batcher: &SpriteBatcher) -> &[renderer::sprite_data::SpriteInstance] {
-        &batcher.batches()[&TextureHandle { id: 0 }].instances
+        &batcher.batch(TextureHandle::WHITE).unwrap().instances
     }
 
     #[test]
@@ -370,7 +379,7 @@ mod tests {
                 corner_radius: 0.0,
                 depth: 1.0,
             };
-            render_ui_commands(&mut batcher, &[cmd], &camera, &HashMap::new());
+            render_ui_commands(&mut batcher, &[cmd], &camera, &UiAtlasRegions::default());
 
             let instance = &white_instances(&batcher)[0];
             let world_pos = Vec2::new(instance.position[0], instance.position[1]);
@@ -396,7 +405,7 @@ mod tests {
             corner_radius: 6.0,
             depth: 1.0,
         };
-        render_ui_commands(&mut batcher, &[cmd], &test_camera(), &HashMap::new());
+        render_ui_commands(&mut batcher, &[cmd], &test_camera(), &UiAtlasRegions::default());
 
         let instances = white_instances(&batcher);
         assert_eq!(instances.len(), 1);
@@ -414,7 +423,7 @@ mod tests {
             corner_radius: 0.0,
             depth: 0.0,
         };
-        render_ui_commands(&mut batcher, &[cmd], &test_camera(), &HashMap::new());
+        render_ui_commands(&mut batcher, &[cmd], &test_camera(), &UiAtlasRegions::default());
         assert_eq!(white_instances(&batcher)[0].shape, [0.0; 4], "radius 0 keeps the legacy quad path");
     }
 
@@ -428,7 +437,7 @@ mod tests {
             corner_radius: 4.0,
             depth: 1.0,
         };
-        render_ui_commands(&mut batcher, &[cmd], &test_camera(), &HashMap::new());
+        render_ui_commands(&mut batcher, &[cmd], &test_camera(), &UiAtlasRegions::default());
 
         let instances = white_instances(&batcher);
         assert_eq!(instances.len(), 1, "border must be ONE SDF sprite, not 4 thin rects");
@@ -438,6 +447,26 @@ mod tests {
         assert_eq!(instances[0].scale, [102.0, 42.0]);
     }
 
+    #[test]
+    fn test_image_samples_its_uv_rect_from_its_texture() {
+        let mut batcher = SpriteBatcher::new();
+        let cmd = DrawCommand::Image {
+            bounds: Rect::new(0.0, 0.0, 64.0, 64.0),
+            texture_id: 9,
+            uv_rect: Rect::new(0.25, 0.5, 0.25, 0.5),
+            tint: Color::WHITE,
+            corner_radius: 0.0,
+            depth: 1.0,
+        };
+        render_ui_commands(&mut batcher, &[cmd], &test_camera(), &UiAtlasRegions::default());
+
+        assert!(batcher.batch(TextureHandle::WHITE).is_none_or(|batch| batch.instances.is_empty()));
+        let instances = &batcher.batch(TextureHandle { id: 9 }).expect("image texture batch").instances;
+        assert_eq!(instances.len(), 1);
+        assert_eq!(instances[0].tex_region, [0.25, 0.5, 0.25, 0.5]);
+        assert_eq!(instances[0].scale, [64.0, 64.0]);
+    }
+
     #[test]
     fn test_circle_emits_circle_kind_at_diameter() {
         let mut batcher = SpriteBatcher::new();
@@ -447,7 +476,7 @@ mod tests {
             color: Color::WHITE,
             depth: 0.5,
         };
-        render_ui_commands(&mut batcher, &[cmd], &test_camera(), &HashMap::new());
+        render_ui_commands(&mut batcher, &[cmd], &test_camera(), &UiAtlasRegions::default());
 
         let instances = white_instances(&batcher);
         assert_eq!(instances.len(), 1);
@@ -456,4 +485,48 @@ mod tests {
         // Screen center of an 800x600 window = world origin
         assert_eq!(instances[0].position, [0.0, 0.0]);
     }
+
+    #[test]
+    fn test_shapes_and_glyphs_share_the_atlas_page() {
+        let page = TextureHandle { id: 5 };
+        let mut atlas = UiAtlasRegions {
+            white: Some(crate::contexts::GlyphRegion { texture: page, uv: [0.5, 0.5, 0.0, 0.0] }),
+            ..UiAtlasRegions::default()
+        };
+        atlas.glyphs.insert(
+            GlyphCacheKey::new(1, 'a', 4, 4),
+            crate::contexts::GlyphRegion { texture: page, uv: [0.0, 0.0, 0.1, 0.1] },
+        );
+        let glyph = ui::GlyphDrawData {
+            bitmap: std::sync::Arc::from([255u8; 16]),
+            width: 4,
+            height: 4,
+            x: 0.0,
+            y: -4.0,
+            character: 'a',
+            font_id: 1,
+        };
+        let commands = [
+            DrawCommand::Rect { bounds: Rect::new(0.0, 0.0, 40.0, 20.0), color: Color::WHITE, corner_radius: 0.0, depth: 0.0 },
+            DrawCommand::Text {
+                data: ui::TextDrawData {
+                    text: "a".to_string(),
+                    position: Vec2::new(4.0, 14.0),
+                    color: Color::BLACK,
+                    font_size: 12.0,
+                    width: 4.0,
+                    height: 4.0,
+                    glyphs: vec![glyph],
+                },
+                depth: 1.0,
+            },
+        ];
+
+        let mut batcher = SpriteBatcher::new();
+        render_ui_commands(&mut batcher, &commands, &test_camera(), &atlas);
+        assert!(batcher.batch(TextureHandle::WHITE).is_none());
+        let instances = &batcher.batch(page).expect("one atlas batch").instances;
+        assert_eq!(instances.len(), 2);
+        assert_eq!(instances[0].tex_region, [0.5, 0.5, 0.0, 0.0], "the rect samples the white point");
+    }
 }
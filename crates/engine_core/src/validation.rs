@@ -0,0 +1,275 @@
+//! Debug validation layer: per-frame invariant checks that report instead
+//! of panicking.
+//!
+//! Broken state rarely crashes a 2D game — a NaN transform just makes a
+//! sprite vanish, a stale texture handle draws white, a zero-size collider
+//! never collides. The [`DebugValidator`] checks for these every frame and
+//! reports each problem through `log::warn!` the frame it appears (not
+//! every frame it persists), keeping the current set in
+//! [`DebugValidator::issues`] for overlays and tests.
+//!
+//! Enabled in debug builds by default; `GameConfig::with_debug_validation`
+//! overrides that either way. When disabled every check returns before
+//! touching the world. Games add their own panic-free asserts with
+//! [`DebugValidator::check`], and call [`DebugValidator::check_physics`]
+//! with the physics world they own.
+
+use std::collections::HashSet;
+use std::fmt;
+
+use ecs::sprite_components::Sprite;
+use ecs::{ComponentError, EntityId, GlobalTransform2D, World};
+use common::Transform2D;
+use glam::Vec2;
+use renderer::texture::TextureHandle;
+
+#[cfg(feature = "physics")]
+use physics::{Collider, PhysicsWorld, RigidBody};
+
+/// A broken invariant found by the [`DebugValidator`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ValidationIssue {
+    /// `Transform2D` or `GlobalTransform2D` holds a NaN or infinite value.
+    NonFiniteTransform { entity: EntityId },
+    /// A sprite references a texture handle the asset manager doesn't have.
+    MissingTexture { entity: EntityId, handle: u32 },
+    /// A collider with a zero, negative, or non-finite dimension.
+    ZeroExtentCollider { entity: EntityId },
+    /// Physics state for an entity that is dead or has neither a
+    /// `RigidBody` nor a `Collider`.
+    OrphanedPhysicsHandle { entity: EntityId },
+    /// A game-defined assertion that failed ([`DebugValidator::check`]).
+    Custom(String),
+}
+
+impl fmt::Display for ValidationIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NonFiniteTransform { entity } => write!(f, "entity {entity} has a non-finite transform"),
+            Self::MissingTexture { entity, handle } => {
+                write!(f, "entity {entity} uses missing texture handle {handle}")
+            }
+            Self::ZeroExtentCollider { entity } => write!(f, "entity {entity} has a zero-extent collider"),
+            Self::OrphanedPhysicsHandle { entity } => {
+                write!(f, "physics state for entity {entity} has no live body/collider component")
+            }
+            Self::Custom(message) => f.write_str(message),
+        }
+    }
+}
+
+/// Runs the validation checks and tracks which issues are current.
+#[derive(Debug, Default)]
+pub struct DebugValidator {
+    enabled: bool,
+    /// Issues found since `begin_frame`
+    issues: Vec<ValidationIssue>,
+    /// Last frame's issues, to report each one only when it first appears
+    previous: HashSet<ValidationIssue>,
+}
+
+impl DebugValidator {
+    /// Create a validator; a disabled one skips every check.
+    pub fn new(enabled: bool) -> Self {
+        Self { enabled, ..Self::default() }
+    }
+
+    /// Whether checks run.
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Turn checks on or off at runtime. Disabling clears current issues.
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+        if !enabled {
+            self.issues.clear();
+            self.previous.clear();
+        }
+    }
+
+    /// Issues found this frame.
+    pub fn issues(&self) -> &[ValidationIssue] {
+        &self.issues
+    }
+
+    /// Start a new frame. The engine calls this before `Game::update`.
+    pub fn begin_frame(&mut self) {
+        if !self.enabled {
+            return;
+        }
+        self.previous = self.issues.drain(..).collect();
+    }
+
+    /// Panic-free assert: record `message` as an issue when `condition` is
+    /// false. Returns `condition`.
+    pub fn check(&mut self, condition: bool, message: impl Into<String>) -> bool {
+        if self.enabled && !condition {
+            self.report(ValidationIssue::Custom(message.into()));
+        }
+        condition
+    }
+
+    /// Check transforms, sprite textures, and collider shapes.
+    /// `has_texture` answers whether a texture handle is loaded; the
+    /// built-in white texture always counts as loaded.
+    pub fn check_world(&mut self, world: &World, has_texture: impl Fn(TextureHandle) -> bool) {
+        if !self.enabled {
+            return;
+        }
+        let mut entities: Vec<EntityId> = world.entity_ids().collect();
+        entities.sort_by_key(|e| e.value());
+
+        for entity in entities {
+            let broken_local = world.get::<Transform2D>(entity)
+                .is_some_and(|t| !finite_pose(t.position, t.rotation, t.scale));
+            let broken_global = world.get::<GlobalTransform2D>(entity)
+                .is_some_and(|t| !finite_pose(t.position, t.rotation, t.scale));
+            if broken_local || broken_global {
+                self.report(ValidationIssue::NonFiniteTransform { entity });
+            }
+
+            if let Some(sprite) = world.get::<Sprite>(entity) {
+                let handle = TextureHandle::new(sprite.texture_handle);
+                if handle != TextureHandle::WHITE && !has_texture(handle) {
+                    self.report(ValidationIssue::MissingTexture { entity, handle: handle.id });
+                }
+            }
+
+            #[cfg(feature = "physics")]
+            if world.get::<Collider>(entity).is_some_and(|c| !c.shape.has_extent()) {
+                self.report(ValidationIssue::ZeroExtentCollider { entity });
+            }
+        }
+    }
+
+    /// Check that every entity `physics` tracks is alive and still has a
+    /// `RigidBody` or `Collider`. The engine doesn't own the physics world,
+    /// so games call this after their physics update.
+    #[cfg(feature = "physics")]
+    pub fn check_physics(&mut self, world: &World, physics: &PhysicsWorld) {
+        if !self.enabled {
+            return;
+        }
+        let mut tracked = physics.tracked_entities();
+        tracked.sort_by_key(|e| e.value());
+        for entity in tracked {
+            let alive = world.validate_entity(&entity).is_ok();
+            let has_physics = world.get::<RigidBody>(entity).is_some() || world.get::<Collider>(entity).is_some();
+            if !(alive && has_physics) {
+                self.report(ValidationIssue::OrphanedPhysicsHandle { entity });
+            }
+        }
+    }
+
+    /// Record an issue, logging it if it wasn't present last frame.
+    fn report(&mut self, issue: ValidationIssue) {
+        if self.issues.contains(&issue) {
+            return;
+        }
+        if !self.previous.contains(&issue) {
+            log::warn!("Validation: {issue}");
+        }
+        self.issues.push(issue);
+    }
+}
+
+/// Whether every component of a pose is finite.
+fn finite_pose(position: Vec2, rotation: f32, scale: Vec2) -> bool {
+    position.is_finite() && rotation.is_finite() && scale.is_finite()
+}
+
+/// Run the component validators ([`ecs::Validate`]) over `entities`: the
+/// ECS built-ins, game-registered ones, and with the `physics` feature
+/// `RigidBody` and `Collider`. Unlike the per-frame [`DebugValidator`] this
+/// runs at load and edit time — after scene loads and, in the editor, after
+/// inspector edits and before play — so bad data is caught before the
+/// simulation sees it.
+pub fn validate_components(world: &World, entities: impl IntoIterator<Item = EntityId>) -> Vec<ComponentError> {
+    #[cfg(feature = "physics")]
+    physics::register_validators();
+    ecs::validate_components(world, entities)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "physics")]
+    use physics::ColliderShape;
+
+    fn all_textures(_: TextureHandle) -> bool {
+        true
+    }
+
+    #[test]
+    fn disabled_validator_reports_nothing() {
+        let mut world = World::new();
+        world.spawn((Transform2D::new(Vec2::NAN),)).id();
+
+        let mut validator = DebugValidator::new(false);
+        validator.check_world(&world, all_textures);
+        assert!(!validator.check(false, "ignored"));
+        assert!(validator.issues().is_empty());
+    }
+
+    #[test]
+    fn non_finite_transform_is_reported() {
+        let mut world = World::new();
+        world.spawn((Transform2D::new(Vec2::ZERO),)).id();
+        let broken = world.spawn((Transform2D::from_parts(Vec2::ZERO, f32::INFINITY, Vec2::ONE),)).id();
+
+        let mut validator = DebugValidator::new(true);
+        validator.check_world(&world, all_textures);
+        assert_eq!(validator.issues(), &[ValidationIssue::NonFiniteTransform { entity: broken }]);
+    }
+
+    #[test]
+    fn missing_texture_is_reported_but_white_is_always_valid() {
+        let mut world = World::new();
+        world.spawn((Sprite::new(TextureHandle::WHITE.id),)).id();
+        world.spawn((Sprite::new(3),)).id();
+        let missing = world.spawn((Sprite::new(7),)).id();
+
+        let mut validator = DebugValidator::new(true);
+        validator.check_world(&world, |handle| handle.id == 3);
+        assert_eq!(validator.issues(), &[ValidationIssue::MissingTexture { entity: missing, handle: 7 }]);
+    }
+
+    #[test]
+    fn issues_reset_each_frame_and_custom_checks_record_messages() {
+        let mut validator = DebugValidator::new(true);
+        assert!(!validator.check(false, "score went negative"));
+        assert!(validator.check(true, "never recorded"));
+        assert_eq!(validator.issues(), &[ValidationIssue::Custom("score went negative".into())]);
+
+        validator.begin_frame();
+        assert!(validator.issues().is_empty());
+    }
+
+    #[cfg(feature = "physics")]
+    #[test]
+    fn zero_extent_collider_is_reported() {
+        let mut world = World::new();
+        world.spawn((Collider::box_collider(10.0, 10.0),)).id();
+        let flat = world.spawn((Collider::box_collider(10.0, 0.0),)).id();
+        world.spawn((Collider::new(ColliderShape::CapsuleY { half_height: 0.0, radius: 4.0 }),)).id();
+
+        let mut validator = DebugValidator::new(true);
+        validator.check_world(&world, all_textures);
+        assert_eq!(validator.issues(), &[ValidationIssue::ZeroExtentCollider { entity: flat }]);
+    }
+
+    #[cfg(feature = "physics")]
+    #[test]
+    fn physics_state_for_removed_entity_is_orphaned() {
+        let mut world = World::new();
+        let entity = world.spawn((Collider::circle_collider(5.0),)).id();
+        let mut physics = PhysicsWorld::default();
+        physics.add_collider(entity, &mut Collider::circle_collider(5.0), None);
+        world.remove_entity(&entity).expect("remove");
+
+        let mut validator = DebugValidator::new(true);
+        validator.check_physics(&world, &physics);
+        assert_eq!(validator.issues(), &[ValidationIssue::OrphanedPhysicsHandle { entity }]);
+    }
+}
@@ -107,6 +107,12 @@ impl WindowManager {
                 self.config.height,
             ))
             .with_resizable(self.config.resizable);
+        // On the web the window is a canvas; append it to the page body
+        #[cfg(target_arch = "wasm32")]
+        let window_attributes = {
+            use winit::platform::web::WindowAttributesExtWebSys;
+            window_attributes.with_append(true)
+        };
 
         match event_loop.create_window(window_attributes) {
             Ok(window) => {
@@ -0,0 +1,175 @@
+//! `WorldText` → glyph sprites in the game's sprite pass.
+//!
+//! The game loop advances every text with `ecs::WorldTextSystem` after the
+//! game's update, then lays each visible one out with the UI's default
+//! font. The laid-out text is kept as `DrawCommand::Text` so the glyph atlas
+//! packs it alongside the UI's text; unlike UI text, the command's position
+//! is the world-space baseline origin and its depth is the layer-sorted
+//! world depth. At render time each glyph becomes a sprite in the game
+//! batcher, where it sorts and culls like any other sprite.
+
+use std::collections::HashSet;
+
+use ecs::{EntityId, RenderLayers, System, World, WorldText, WorldTextSystem};
+use glam::{Vec2, Vec4};
+use renderer::sprite::{Sprite, SpriteBatcher};
+use ui::{Color, DrawCommand, FontManager, GlyphDrawData, TextDrawData};
+
+use crate::contexts::{GlyphCacheKey, UiAtlasRegions};
+use crate::extraction::ExtractTransform;
+
+/// Ascent as a fraction of font size when the font reports no metrics.
+const ASCENT_FACTOR: f32 = 0.8;
+
+/// Steps world text and owns this frame's laid-out text (cleared, capacity
+/// retained, every frame).
+#[derive(Default)]
+pub(crate) struct WorldTextRenderer {
+    system: WorldTextSystem,
+    commands: Vec<DrawCommand>,
+}
+
+impl WorldTextRenderer {
+    /// Advance every text by `delta_time` and lay out the visible ones.
+    /// Entities in `hidden` are skipped, like their sprites. Without a
+    /// default font nothing is laid out.
+    pub(crate) fn update(
+        &mut self,
+        world: &mut World,
+        fonts: &mut FontManager,
+        hidden: &HashSet<EntityId>,
+        delta_time: f32,
+    ) {
+        self.system.update(world, delta_time);
+        self.commands.clear();
+        let Some(font) = fonts.default_font() else { return };
+        let layers = world.resource::<RenderLayers>().cloned().unwrap_or_default();
+        for entity in world.active_entities() {
+            let Some(text) = world.get::<WorldText>(entity) else { continue };
+            if !text.visible || text.text.is_empty() || text.font_size <= 0.0 || hidden.contains(&entity) {
+                continue;
+            }
+            let Some(transform) = ExtractTransform::of(world, entity) else { continue };
+            let layout = match fonts.layout_text(font, &text.text, text.font_size) {
+                Ok(layout) => layout,
+                Err(e) => {
+                    log::warn!("World text layout failed: {}", e);
+                    continue;
+                }
+            };
+            let ascent = fonts
+                .metrics(font, text.font_size)
+                .map_or(text.font_size * ASCENT_FACTOR, |metrics| metrics.ascent);
+            // Center the block vertically on the anchor (Y-up: the first
+            // baseline sits `ascent` below the top edge)
+            let anchor = text.anchor(transform.position);
+            let origin = Vec2::new(
+                anchor.x + text.alignment.left_edge(layout.width),
+                anchor.y + layout.height * 0.5 - ascent,
+            );
+            let color = text.current_color();
+            self.commands.push(DrawCommand::Text {
+                data: TextDrawData {
+                    text: text.text.clone(),
+                    position: origin,
+                    color: Color::new(color.x, color.y, color.z, color.w),
+                    font_size: text.font_size,
+                    width: layout.width,
+                    height: layout.height,
+                    glyphs: layout.glyphs.iter().map(GlyphDrawData::from).collect(),
+                },
+                depth: layers.sort_depth(&text.layer, text.depth),
+            });
+        }
+    }
+
+    /// This frame's laid-out text, for the glyph atlas and
+    /// [`render_world_text`].
+    pub(crate) fn commands(&self) -> &[DrawCommand] {
+        &self.commands
+    }
+}
+
+/// Add one sprite per glyph of the laid-out world text to `sprites`.
+/// Glyphs the atlas has no region for (it was full) are skipped.
+pub(crate) fn render_world_text(
+    sprites: &mut SpriteBatcher,
+    commands: &[DrawCommand],
+    glyph_textures: &UiAtlasRegions,
+) {
+    for command in commands {
+        let DrawCommand::Text { data, depth } = command else { continue };
+        let color = Vec4::new(data.color.r, data.color.g, data.color.b, data.color.a);
+        for glyph in data.glyphs.iter().filter(|glyph| glyph.width > 0 && glyph.height > 0) {
+            let key = GlyphCacheKey::new(glyph.font_id, glyph.character, glyph.width, glyph.height);
+            let Some(region) = glyph_textures.get(&key) else { continue };
+            let size = Vec2::new(glyph.width as f32, glyph.height as f32);
+            // Glyph offsets are Y-down from the baseline; the world is Y-up
+            let center = data.position + Vec2::new(glyph.x + size.x * 0.5, -(glyph.y + size.y * 0.5));
+            let [u, v, w, h] = region.uv;
+            let sprite = Sprite::new(region.texture)
+                .with_tex_region(u, v, w, h)
+                .with_position(center)
+                .with_scale(size)
+                .with_color(color)
+                .with_depth(*depth);
+            sprites.add_sprite(&sprite);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ecs::sprite_components::Transform2D;
+    use renderer::texture::TextureHandle;
+    use std::sync::Arc;
+
+    fn glyph(character: char, x: f32, y: f32) -> GlyphDrawData {
+        GlyphDrawData { bitmap: Arc::from([255u8; 40]), width: 4, height: 10, x, y, character, font_id: 1 }
+    }
+
+    #[test]
+    fn test_glyphs_land_at_world_positions_with_text_depth() {
+        let command = DrawCommand::Text {
+            data: TextDrawData {
+                text: "hi".to_string(),
+                position: Vec2::new(100.0, 50.0),
+                color: Color::new(1.0, 0.0, 0.0, 0.5),
+                font_size: 12.0,
+                width: 10.0,
+                height: 12.0,
+                glyphs: vec![glyph('h', 0.0, -10.0), glyph('i', 6.0, -10.0), glyph('?', 12.0, -10.0)],
+            },
+            depth: 3.0,
+        };
+        let atlas = TextureHandle { id: 42 };
+        let mut regions = UiAtlasRegions::default();
+        for character in ['h', 'i'] {
+            regions.glyphs.insert(GlyphCacheKey::new(1, character, 4, 10), crate::contexts::GlyphRegion { texture: atlas, uv: [0.0, 0.0, 0.1, 0.1] });
+        }
+
+        let mut sprites = SpriteBatcher::new();
+        render_world_text(&mut sprites, &[command], &regions);
+        let instances = &sprites.batch(atlas).unwrap().instances;
+        assert_eq!(instances.len(), 2, "the glyph without an atlas region is skipped");
+        // 'h' sits right of the origin, its box rising 10 units above the baseline
+        assert_eq!(instances[0].position, [102.0, 55.0]);
+        assert_eq!(instances[1].position, [108.0, 55.0]);
+        assert!(instances.iter().all(|instance| instance.depth == 3.0));
+        assert_eq!(instances[0].color, [1.0, 0.0, 0.0, 0.5]);
+    }
+
+    #[test]
+    fn test_update_without_font_lays_out_nothing_but_still_animates() {
+        let mut world = World::new();
+        let entity = world.create_entity();
+        world.add_component(&entity, Transform2D::new(Vec2::ZERO)).unwrap();
+        world.add_component(&entity, WorldText::floating("-5", Vec2::new(0.0, 10.0), 1.0)).unwrap();
+
+        let mut texts = WorldTextRenderer::default();
+        texts.update(&mut world, &mut FontManager::new(), &HashSet::new(), 0.5);
+        assert!(texts.commands().is_empty());
+        assert_eq!(world.get::<WorldText>(entity).unwrap().drift(), Vec2::new(0.0, 5.0));
+    }
+}
@@ -5,7 +5,8 @@
 
 use engine_core::behavior_runner::BehaviorRunner;
 use ecs::{World, EntityId};
-use ecs::behavior::{Behavior, BehaviorState};
+use ecs::behavior::{Behavior, BehaviorPhase, BehaviorState, EntityTag};
+use ecs::pool::Inactive;
 use ecs::sprite_components::Transform2D;
 use input::InputHandler;
 use glam::Vec2;
@@ -145,3 +146,46 @@ fn test_platformer_jump_fires_from_gamepad_action_and_from_space() {
         );
     }
 }
+
+#[test]
+fn test_parked_tagged_target_is_neither_chased_nor_collecting() {
+    let mut world = World::new();
+    let mut behavior_runner = BehaviorRunner::new();
+    let input = InputHandler::new();
+
+    // A pooled "player" parked right next to a chaser and a collectible
+    let parked = world.create_entity();
+    world.add_component(&parked, Transform2D::new(Vec2::new(10.0, 0.0))).unwrap();
+    world.add_component(&parked, EntityTag::new("player")).unwrap();
+    world.add_component(&parked, Inactive).unwrap();
+
+    let chaser = world.create_entity();
+    world.add_component(&chaser, Transform2D::new(Vec2::new(50.0, 0.0))).unwrap();
+    world.add_component(&chaser, Behavior::ChaseTagged {
+        target_tag: "player".to_string(),
+        detection_range: 200.0,
+        chase_speed: 80.0,
+        lose_interest_range: 300.0,
+    }).unwrap();
+
+    let coin = world.create_entity();
+    world.add_component(&coin, Transform2D::new(Vec2::new(0.0, 0.0))).unwrap();
+    world.add_component(&coin, Behavior::Collectible {
+        score_value: 10,
+        despawn_on_collect: true,
+        collector_tag: "player".to_string(),
+    }).unwrap();
+
+    behavior_runner.update(&mut world, &input, 0.016, None);
+    let chasing = |world: &World| {
+        world.get::<BehaviorState>(chaser).map(|s| s.phase.is(&BehaviorPhase::Chasing)).unwrap_or(false)
+    };
+    assert!(!chasing(&world), "chaser should ignore a parked target");
+    assert!(world.get::<Transform2D>(coin).is_some(), "a parked collector should not collect");
+
+    // Unparked, the same target is chased and collects
+    world.remove_component::<Inactive>(&parked).unwrap();
+    behavior_runner.update(&mut world, &input, 0.016, None);
+    assert!(chasing(&world));
+    assert!(world.get::<Transform2D>(coin).is_none());
+}
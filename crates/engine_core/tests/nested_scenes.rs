@@ -0,0 +1,116 @@
+//! Nested scene instancing: `SceneInstanceRef` entities loading another
+//! scene file beneath them, headless via a stub `TextureResolver`.
+
+use std::path::{Path, PathBuf};
+
+use ecs::sprite_components::{Name, Transform2D};
+use ecs::{SceneInstanceRef, World, WorldHierarchyExt};
+use engine_core::prelude::*;
+use engine_core::TextureResolver;
+use renderer::TextureHandle;
+
+/// GPU-free resolver: every reference resolves to the built-in white texture.
+struct StubResolver;
+
+impl TextureResolver for StubResolver {
+    fn resolve_texture(&mut self, _texture_ref: &str) -> Result<TextureHandle, SceneLoadError> {
+        Ok(TextureHandle::WHITE)
+    }
+}
+
+fn write(dir: &Path, file: &str, content: &str) -> PathBuf {
+    let path = dir.join(file);
+    std::fs::write(&path, content).unwrap();
+    path
+}
+
+#[test]
+fn test_nested_scene_loads_under_the_referencing_entity() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::create_dir(dir.path().join("rooms")).unwrap();
+    write(
+        &dir.path().join("rooms"),
+        "room.scene.ron",
+        r#"(name: "Room", entities: [
+            (name: Some("Chest"), components: [Transform2D(position: (5.0, 0.0))]),
+        ])"#,
+    );
+    let level = write(
+        dir.path(),
+        "level.scene.ron",
+        r#"(name: "Level", entities: [
+            (name: Some("West Room"), components: [
+                Transform2D(position: (100.0, 0.0)),
+                SceneInstanceRef(path: "rooms/room.scene.ron"),
+            ]),
+        ])"#,
+    );
+
+    let mut world = World::new();
+    let instance = SceneLoader::load_and_instantiate(&level, &mut world, &mut StubResolver).unwrap();
+    let room = instance.get_entity("West Room").unwrap();
+    assert_eq!(instance.entity_count, 2);
+    assert!(instance.get_entity("Chest").is_none(), "nested names stay out of the outer scene");
+    assert_eq!(world.get::<SceneInstanceRef>(room).map(|r| r.path.as_str()), Some("rooms/room.scene.ron"));
+
+    let children = world.get_children(room).unwrap().to_vec();
+    assert_eq!(children.len(), 1);
+    assert_eq!(world.get::<Name>(children[0]).map(|n| n.as_str()), Some("Chest"));
+    assert_eq!(world.get::<Transform2D>(children[0]).unwrap().position.x, 5.0, "local to the room");
+}
+
+#[test]
+fn test_scene_embedded_twice_gets_distinct_guids_per_instance() {
+    let dir = tempfile::tempdir().unwrap();
+    write(
+        dir.path(),
+        "room.scene.ron",
+        r#"(name: "Room", entities: [
+            (name: Some("Chest"), guid: Some("6f1c2a9e-03b4-4d52-9e1a-7c0d5b8f2e41"), components: []),
+        ])"#,
+    );
+    let level = write(
+        dir.path(),
+        "level.scene.ron",
+        r#"(name: "Level", entities: [
+            (name: Some("West"), guid: Some("00000000-0000-4000-8000-000000000001"),
+                components: [SceneInstanceRef(path: "room.scene.ron")]),
+            (name: Some("East"), guid: Some("00000000-0000-4000-8000-000000000002"),
+                components: [SceneInstanceRef(path: "room.scene.ron")]),
+        ])"#,
+    );
+
+    let load = || {
+        let mut world = World::new();
+        let instance = SceneLoader::load_and_instantiate(&level, &mut world, &mut StubResolver).unwrap();
+        let chests: Vec<_> = ["West", "East"]
+            .iter()
+            .map(|room| world.get_children(instance.get_entity(room).unwrap()).unwrap()[0])
+            .collect();
+        let guids: Vec<Guid> = chests.iter().map(|&chest| world.guid_of(chest).unwrap()).collect();
+        for (&chest, &guid) in chests.iter().zip(&guids) {
+            assert_eq!(world.entity_by_guid(guid), Some(chest), "each instance resolves to itself");
+        }
+        guids
+    };
+
+    let guids = load();
+    assert_ne!(guids[0], guids[1]);
+    assert_eq!(load(), guids, "instance GUIDs are stable across loads");
+}
+
+#[test]
+fn test_scene_reference_cycle_is_refused() {
+    let dir = tempfile::tempdir().unwrap();
+    write(dir.path(), "a.scene.ron", r#"(name: "A", entities: [(components: [SceneInstanceRef(path: "b.scene.ron")])])"#);
+    write(dir.path(), "b.scene.ron", r#"(name: "B", entities: [(components: [SceneInstanceRef(path: "a.scene.ron")])])"#);
+
+    let mut world = World::new();
+    let result = SceneLoader::load_and_instantiate(dir.path().join("a.scene.ron"), &mut world, &mut StubResolver);
+    match result {
+        Err(SceneLoadError::SceneReferenceCycle(chain)) => {
+            assert_eq!(chain, "a.scene.ron -> b.scene.ron -> a.scene.ron");
+        }
+        other => panic!("expected a cycle error, got {other:?}"),
+    }
+}
@@ -0,0 +1,95 @@
+//! Plugin registration through `EngineBuilder` — everything a plugin adds
+//! is checked without starting a window or GPU.
+
+use std::path::Path;
+
+use ecs::component_registry::ComponentMeta;
+use ecs::{DeriveComponentMeta, SimpleSystem, World};
+use engine_core::prelude::*;
+use engine_core::TextureResolver;
+use renderer::TextureHandle;
+use serde::Deserialize;
+
+struct StubResolver;
+
+impl TextureResolver for StubResolver {
+    fn resolve_texture(&mut self, _texture_ref: &str) -> Result<TextureHandle, SceneLoadError> {
+        Ok(TextureHandle::WHITE)
+    }
+}
+
+/// Component only the plugin knows about.
+#[derive(Debug, Deserialize, DeriveComponentMeta)]
+struct Poison {
+    damage: u32,
+}
+
+/// Extension data another layer (e.g. the editor) would read.
+#[derive(Default)]
+struct PanelTitles(Vec<String>);
+
+struct WordLoader;
+
+impl AssetLoader for WordLoader {
+    type Asset = Vec<String>;
+    fn extensions(&self) -> &[&str] {
+        &["words"]
+    }
+    fn load(&self, bytes: &[u8], _path: &Path) -> Result<Vec<String>, String> {
+        Ok(String::from_utf8_lossy(bytes).split_whitespace().map(str::to_string).collect())
+    }
+}
+
+struct PoisonPlugin;
+
+impl Plugin for PoisonPlugin {
+    fn build(&self, app: &mut EngineBuilder) {
+        app.add_plugin(CorePlugin)
+            .register_component::<Poison>()
+            .add_system(SimpleSystem::new("poison", |_world: &mut World, _dt| {}))
+            .add_asset_loader(WordLoader)
+            .with_extension(|titles: &mut PanelTitles| titles.0.push("Poison Debug".into()));
+    }
+}
+
+struct CorePlugin;
+
+impl Plugin for CorePlugin {
+    fn build(&self, app: &mut EngineBuilder) {
+        app.add_system(SimpleSystem::new("core", |_world: &mut World, _dt| {}));
+    }
+}
+
+#[test]
+fn test_plugins_register_once_in_build_order() {
+    let builder = EngineBuilder::new(GameConfig::default())
+        .with_plugin(PoisonPlugin)
+        .with_plugin(CorePlugin);
+
+    let plugins = builder.plugins();
+    assert_eq!(plugins.names(), ["PoisonPlugin", "CorePlugin"]);
+    assert_eq!(plugins.system_count(), 2, "CorePlugin's second add is skipped");
+    let titles = plugins.extension::<PanelTitles>().map(|t| t.0.clone());
+    assert_eq!(titles, Some(vec!["Poison Debug".to_string()]));
+}
+
+#[test]
+fn test_plugin_component_loads_from_scene() {
+    let _builder = EngineBuilder::new(GameConfig::default()).with_plugin(PoisonPlugin);
+    assert_eq!(Poison::type_name(), "Poison");
+
+    let mut scene = SceneLoader::parse(r#"SceneData(name: "Swamp")"#).unwrap();
+    scene.entities.push(EntityData {
+        name: Some("slime".into()),
+        components: vec![ComponentData::Dynamic {
+            component_type: "Poison".into(),
+            data: serde_json::json!({ "damage": 4 }),
+        }],
+        ..Default::default()
+    });
+
+    let mut world = World::new();
+    let instance = SceneLoader::instantiate(&scene, &mut world, &mut StubResolver).unwrap();
+    let slime = instance.get_entity("slime").unwrap();
+    assert_eq!(world.get::<Poison>(slime).map(|p| p.damage), Some(4));
+}
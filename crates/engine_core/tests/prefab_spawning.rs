@@ -35,7 +35,9 @@ fn ball_scene() -> SceneData {
                 scale: (16.0, 16.0),
                 color: (1.0, 1.0, 1.0, 1.0),
                 depth: 0.0,
+                layer: ecs::DEFAULT_LAYER.to_string(),
                 emissive: 0.0,
+                tex_region: (0.0, 0.0, 1.0, 1.0),
             },
             ComponentData::EntityTag { tag: "ball".to_string() },
         ],
@@ -45,12 +47,20 @@ fn ball_scene() -> SceneData {
     prefabs.insert("Ball".to_string(), ball_prefab);
 
     SceneData {
+        format_version: engine_core::scene_migration::SCENE_FORMAT_VERSION,
+        simulation_tick: None,
+        rng: None,
         name: "prefab test".to_string(),
         physics: None,
         editor: None,
         prefabs,
+        materials: HashMap::new(),
+        streaming: None,
+        layers: Vec::new(),
+        sort_mode: ecs::SpriteSortMode::Depth,
         entities: vec![EntityData {
             name: Some("first_ball".to_string()),
+            guid: None,
             prefab: Some("Ball".to_string()),
             overrides: vec![],
             components: vec![],
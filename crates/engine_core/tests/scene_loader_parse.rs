@@ -100,6 +100,30 @@ fn test_parse_sprite_emissive_explicit() {
     }
 }
 
+#[test]
+fn test_parse_sprite_tex_region_defaults_to_full_texture() {
+    let scene_ron = r##"
+        SceneData(
+            name: "Sheet",
+            entities: [
+                EntityData(components: [Sprite(texture: "#white")]),
+                EntityData(components: [Sprite(texture: "#white", tex_region: (0.25, 0.0, 0.25, 0.5))]),
+            ],
+        )
+    "##;
+
+    let scene = SceneLoader::parse(scene_ron).unwrap();
+    let regions: Vec<_> = scene
+        .entities
+        .iter()
+        .map(|entity| match &entity.components[0] {
+            ComponentData::Sprite { tex_region, .. } => *tex_region,
+            other => panic!("Expected Sprite, got {:?}", other),
+        })
+        .collect();
+    assert_eq!(regions, vec![(0.0, 0.0, 1.0, 1.0), (0.25, 0.0, 0.25, 0.5)]);
+}
+
 #[test]
 fn test_parse_entity_tag_component() {
     let scene_ron = r#"
@@ -123,6 +147,45 @@ fn test_parse_entity_tag_component() {
     }
 }
 
+#[test]
+fn test_saved_guid_is_restored_on_instantiate() {
+    use ecs::{EntityRef, Guid, World};
+    use engine_core::scene_data::SceneLoadError;
+    use engine_core::TextureResolver;
+    use renderer::texture::TextureHandle;
+
+    struct StubResolver;
+    impl TextureResolver for StubResolver {
+        fn resolve_texture(&mut self, _texture_ref: &str) -> Result<TextureHandle, SceneLoadError> {
+            Ok(TextureHandle::WHITE)
+        }
+    }
+
+    let scene_ron = r#"
+        SceneData(
+            name: "Guid Test",
+            entities: [
+                EntityData(name: Some("door"), guid: Some("6f1c2a9e-03b4-4d52-9e1a-7c0d5b8f2e41")),
+                EntityData(name: Some("lever")),
+            ],
+        )
+    "#;
+
+    let scene = SceneLoader::parse(scene_ron).unwrap();
+    let saved: Guid = "6f1c2a9e-03b4-4d52-9e1a-7c0d5b8f2e41".parse().unwrap();
+    assert_eq!(scene.entities[0].guid, Some(saved));
+    assert_eq!(scene.entities[1].guid, None);
+
+    let mut world = World::new();
+    let instance = SceneLoader::instantiate(&scene, &mut world, &mut StubResolver).unwrap();
+    let door = instance.get_entity("door").unwrap();
+    let lever = instance.get_entity("lever").unwrap();
+    assert_eq!(world.guid_of(door), Some(saved));
+    assert_eq!(EntityRef::new(saved).resolve(&world), Some(door));
+    // Entities saved without a GUID still get a fresh one
+    assert!(world.guid_of(lever).is_some_and(|guid| guid != saved));
+}
+
 #[test]
 fn test_tilemap_parses_and_instantiates_with_resolved_tileset() {
     use ecs::{Tilemap, World};
@@ -173,6 +236,85 @@ fn test_tilemap_parses_and_instantiates_with_resolved_tileset() {
     assert_eq!(tilemap.sprite_instances().count(), 3);
 }
 
+#[cfg(feature = "physics")]
+#[test]
+fn test_collider_material_resolves_on_instantiate() {
+    use ecs::World;
+    use engine_core::scene_data::SceneLoadError;
+    use engine_core::TextureResolver;
+    use physics::{Collider, CombineRule};
+    use renderer::texture::TextureHandle;
+
+    struct StubResolver;
+    impl TextureResolver for StubResolver {
+        fn resolve_texture(&mut self, _texture_ref: &str) -> Result<TextureHandle, SceneLoadError> {
+            Ok(TextureHandle::WHITE)
+        }
+    }
+
+    let scene_ron = r##"
+        SceneData(
+            name: "Materials",
+            materials: {
+                "sticky": PhysicsMaterialData(friction: 1.0, friction_combine: Max),
+            },
+            entities: [
+                EntityData(name: Some("floor"), components: [Collider(material: Some("sticky"))]),
+                EntityData(name: Some("puck"), components: [Collider(friction: 0.9, material: Some("ice"))]),
+            ],
+        )
+    "##;
+
+    let scene = SceneLoader::parse(scene_ron).unwrap();
+    let mut world = World::new();
+    let instance = SceneLoader::instantiate(&scene, &mut world, &mut StubResolver).unwrap();
+    assert!(instance.materials.contains_key("sticky"));
+
+    let floor = world.get::<Collider>(instance.get_entity("floor").unwrap()).unwrap();
+    assert_eq!(floor.friction, 1.0);
+    assert_eq!(floor.friction_combine, CombineRule::Max);
+    let puck = world.get::<Collider>(instance.get_entity("puck").unwrap()).unwrap();
+    assert_eq!(puck.friction, 0.02, "preset values replace the inline friction");
+    assert_eq!(puck.material.as_deref(), Some("ice"));
+}
+
+#[cfg(feature = "physics")]
+#[test]
+fn test_invalid_component_data_is_reported_on_instantiate() {
+    use ecs::World;
+    use engine_core::scene_data::SceneLoadError;
+    use engine_core::TextureResolver;
+    use renderer::texture::TextureHandle;
+
+    struct StubResolver;
+    impl TextureResolver for StubResolver {
+        fn resolve_texture(&mut self, _texture_ref: &str) -> Result<TextureHandle, SceneLoadError> {
+            Ok(TextureHandle::WHITE)
+        }
+    }
+
+    let scene_ron = r##"
+        SceneData(
+            name: "Bad Data",
+            entities: [
+                EntityData(name: Some("flat"), components: [Collider(shape: Circle(radius: 0.0))]),
+                EntityData(name: Some("frozen"), components: [SpriteAnimation(fps: 0.0)]),
+                EntityData(name: Some("fine"), components: [Collider(), SpriteAnimation()]),
+            ],
+        )
+    "##;
+
+    let scene = SceneLoader::parse(scene_ron).unwrap();
+    let mut world = World::new();
+    let instance = SceneLoader::instantiate(&scene, &mut world, &mut StubResolver).unwrap();
+
+    let mut found: Vec<_> = instance.component_errors.iter().map(|error| error.component).collect();
+    found.sort_unstable();
+    assert_eq!(found, ["Collider", "SpriteAnimation"]);
+    let fine = instance.get_entity("fine").unwrap();
+    assert!(instance.component_errors.iter().all(|error| error.entity != fine));
+}
+
 #[test]
 fn test_bundled_example_scenes_parse() {
     // The example scene files checked into the repo must always parse —
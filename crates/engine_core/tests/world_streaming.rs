@@ -0,0 +1,157 @@
+//! World streaming: partitioning a scene into chunks and streaming them in
+//! and out around a focus point, headless via a stub `TextureResolver`.
+
+use ecs::sprite_components::{Name, Transform2D};
+use ecs::World;
+use engine_core::prelude::*;
+use engine_core::streaming::ChunkSource;
+use engine_core::TextureResolver;
+use glam::Vec2;
+use renderer::TextureHandle;
+
+/// GPU-free resolver: every reference resolves to the built-in white texture.
+struct StubResolver;
+
+impl TextureResolver for StubResolver {
+    fn resolve_texture(&mut self, _texture_ref: &str) -> Result<TextureHandle, SceneLoadError> {
+        Ok(TextureHandle::WHITE)
+    }
+}
+
+const SETTINGS: StreamingSettings = StreamingSettings { chunk_size: 100.0, load_radius: 1, unload_radius: 2 };
+
+fn placed(name: &str, x: f32, y: f32) -> EntityData {
+    EntityData {
+        name: Some(name.to_string()),
+        components: vec![ComponentData::Transform2D { position: (x, y), rotation: 0.0, scale: (1.0, 1.0) }],
+        ..Default::default()
+    }
+}
+
+/// A 10-chunk-wide strip with one crate per chunk, a child parented to the
+/// first crate, an untransformed "rules" entity, and a camera.
+fn strip_scene() -> SceneData {
+    let mut entities: Vec<EntityData> = (0..10)
+        .map(|i| placed(&format!("crate_{i}"), i as f32 * 100.0 + 50.0, 50.0))
+        .collect();
+    entities.push(EntityData {
+        parent: Some("crate_0".to_string()),
+        ..placed("lid", 900.0, 900.0)
+    });
+    entities.push(EntityData { name: Some("rules".to_string()), ..Default::default() });
+    entities.push(EntityData {
+        components: vec![
+            ComponentData::Transform2D { position: (450.0, 50.0), rotation: 0.0, scale: (1.0, 1.0) },
+            ComponentData::Camera2D {
+                position: (0.0, 0.0),
+                rotation: 0.0,
+                zoom: 1.0,
+                viewport_size: (800.0, 600.0),
+                is_main_camera: true,
+            },
+        ],
+        ..placed("camera", 0.0, 0.0)
+    });
+    SceneData { name: "strip".to_string(), entities, ..Default::default() }
+}
+
+fn names(world: &World) -> Vec<String> {
+    let mut names: Vec<String> = world
+        .entities()
+        .into_iter()
+        .filter_map(|entity| world.get::<Name>(entity).map(|name| name.as_str().to_string()))
+        .collect();
+    names.sort();
+    names
+}
+
+#[test]
+fn test_partition_places_entities_by_position() {
+    let chunked = partition_scene(strip_scene(), SETTINGS);
+    assert_eq!(chunked.chunks.len(), 10);
+    assert_eq!(chunked.base.streaming, Some(SETTINGS));
+
+    let base: Vec<_> = chunked.base.entities.iter().filter_map(|e| e.name.as_deref()).collect();
+    assert_eq!(base, vec!["rules", "camera"]);
+
+    let first = &chunked.chunks[&ChunkCoord::new(0, 0)];
+    let first: Vec<_> = first.iter().filter_map(|e| e.name.as_deref()).collect();
+    assert_eq!(first, vec!["crate_0", "lid"], "children follow their parent");
+}
+
+#[test]
+fn test_streamer_loads_around_focus_and_unloads_with_hysteresis() {
+    let mut world = World::new();
+    let (instance, mut streamer) = partition_scene(strip_scene(), SETTINGS).start(&mut world, &mut StubResolver).unwrap();
+    assert_eq!(instance.entity_count, 2);
+
+    let update = streamer.load_around(&mut world, &mut StubResolver, Vec2::new(50.0, 50.0));
+    // Chunks (-1..=1, -1..=1) are requested; only (0,0) and (1,0) hold entities
+    assert_eq!(update.loaded.len(), 9);
+    assert_eq!(names(&world), vec!["camera", "crate_0", "crate_1", "lid", "rules"]);
+    assert!(instance.get_entity("rules").is_some());
+
+    // Two chunks right: chunk 0 is 2 away (kept), chunks 2 and 3 load
+    streamer.load_around(&mut world, &mut StubResolver, Vec2::new(250.0, 50.0));
+    assert!(streamer.is_loaded(ChunkCoord::new(0, 0)));
+    assert_eq!(names(&world).len(), 7);
+
+    // Far right: everything before chunk 5 is dropped
+    let update = streamer.load_around(&mut world, &mut StubResolver, Vec2::new(750.0, 50.0));
+    assert!(update.unloaded.contains(&ChunkCoord::new(0, 0)));
+    assert_eq!(names(&world), vec!["camera", "crate_6", "crate_7", "crate_8", "rules"]);
+
+    streamer.unload_all(&mut world);
+    assert_eq!(names(&world), vec!["camera", "rules"]);
+}
+
+#[test]
+fn test_streamer_reloads_chunk_after_unload() {
+    let mut world = World::new();
+    let (_, mut streamer) = partition_scene(strip_scene(), SETTINGS).start(&mut world, &mut StubResolver).unwrap();
+    streamer.load_around(&mut world, &mut StubResolver, Vec2::new(50.0, 50.0));
+    streamer.load_around(&mut world, &mut StubResolver, Vec2::new(950.0, 50.0));
+    assert!(!streamer.is_loaded(ChunkCoord::new(0, 0)));
+
+    streamer.load_around(&mut world, &mut StubResolver, Vec2::new(50.0, 50.0));
+    let crate_0 = streamer.chunk_entities(ChunkCoord::new(0, 0))[0];
+    assert_eq!(world.get::<Transform2D>(crate_0).map(|t| t.position), Some(Vec2::new(50.0, 50.0)));
+}
+
+#[test]
+fn test_streaming_follows_the_floating_origin() {
+    let mut world = World::new();
+    let (_, mut streamer) = partition_scene(strip_scene(), SETTINGS).start(&mut world, &mut StubResolver).unwrap();
+    streamer.load_around(&mut world, &mut StubResolver, Vec2::new(450.0, 50.0));
+
+    // The origin moves to x = 400: a local focus of 350 is absolute 750
+    ecs::shift_world_origin(&mut world, Vec2::new(400.0, 0.0));
+    streamer.load_around(&mut world, &mut StubResolver, Vec2::new(350.0, 50.0));
+    // Chunk 5 (two away) is kept; chunks 3 and 4 unload
+    assert_eq!(names(&world), vec!["camera", "crate_5", "crate_6", "crate_7", "crate_8", "rules"]);
+
+    // Chunk 5 was shifted with the world; chunk 8 arrived after the shift
+    // and is placed relative to the new origin all the same
+    let position = |coord| {
+        let entity = streamer.chunk_entities(coord)[0];
+        world.get::<Transform2D>(entity).map(|t| t.position)
+    };
+    assert_eq!(position(ChunkCoord::new(5, 0)), Some(Vec2::new(150.0, 50.0)));
+    assert_eq!(position(ChunkCoord::new(8, 0)), Some(Vec2::new(450.0, 50.0)));
+}
+
+#[test]
+fn test_chunk_directory_round_trip() {
+    let dir = tempfile::tempdir().unwrap();
+    let chunked = partition_scene(strip_scene(), SETTINGS);
+    let directory = chunked.write_to_dir(dir.path()).unwrap();
+    assert!(directory.base_path().exists());
+    assert_eq!(directory.load_chunk(ChunkCoord::new(3, 0)).unwrap().len(), 1);
+    assert!(directory.load_chunk(ChunkCoord::new(3, 5)).unwrap().is_empty(), "missing chunk is empty");
+
+    let mut world = World::new();
+    let (_, mut streamer) = WorldStreamer::open_dir(dir.path(), &mut world, &mut StubResolver).unwrap();
+    assert_eq!(streamer.settings(), SETTINGS);
+    streamer.load_around(&mut world, &mut StubResolver, Vec2::new(450.0, 50.0));
+    assert_eq!(names(&world), vec!["camera", "crate_3", "crate_4", "crate_5", "rules"]);
+}
@@ -30,13 +30,24 @@
 //! ```
 //!
 //! For simple use cases, `update()` combines steps 2 and 4 into one call.
+//!
+//! # Headless Input
+//!
+//! Tests drive the handler without a window: `inject_event()` applies an
+//! event immediately, and `play_script()` feeds an [`InputScript`] one frame
+//! per `process_queued_events()` call. `start_recording()` captures live
+//! input as a script for later playback.
 
-use crate::gamepad::GamepadManager;
+use crate::gamepad::{AxisDirection, GamepadManager};
 use crate::input_mapping::{InputSource, AXIS_ACTIVATION_THRESHOLD};
 use crate::keyboard::{convert_physical_key, KeyboardState};
 use crate::mouse::MouseState;
+use crate::press_history::PressHistory;
+use crate::script::InputScript;
+use crate::touch::{TouchPoint, TouchState};
 use std::collections::VecDeque;
 use winit::event::{ElementState, WindowEvent};
+use winit::keyboard::KeyCode;
 
 /// Approximate pixels per scroll "line", used to normalize trackpad/pixel
 /// scroll deltas to the same scale as mouse wheel line deltas.
@@ -57,12 +68,14 @@ const SCROLL_PIXELS_PER_LINE: f32 = 16.0;
 /// If abstraction becomes necessary (e.g., for non-winit platforms), the conversion can
 /// be added at the boundary in [`InputHandler::handle_window_event`] without changing
 /// the public API.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 pub enum InputEvent {
     /// Keyboard key pressed
     KeyPressed(winit::keyboard::KeyCode),
     /// Keyboard key released
     KeyReleased(winit::keyboard::KeyCode),
+    /// Text produced by a key press (follows the keyboard layout)
+    TextTyped(String),
     /// Mouse button pressed
     MouseButtonPressed(winit::event::MouseButton),
     /// Mouse button released
@@ -71,6 +84,11 @@ pub enum InputEvent {
     MouseMoved(f32, f32),
     /// Mouse wheel scrolled
     MouseWheelScrolled(f32),
+    /// Trackpad two-finger scroll in pixels `(x, y)`. Also feeds the wheel
+    /// delta (÷ 16 per line) so wheel-only consumers keep working.
+    TrackpadScrolled(f32, f32),
+    /// Trackpad pinch gesture; positive = fingers spreading (zoom in)
+    PinchGesture(f32),
     /// Gamepad button pressed
     GamepadButtonPressed(u32, crate::gamepad::GamepadButton),
     /// Gamepad button released
@@ -96,6 +114,14 @@ pub struct InputHandler {
     gamepads: GamepadManager,
     /// Event queue for buffering input events
     event_queue: VecDeque<InputEvent>,
+    /// Remaining frames of the script being played back
+    playback: Option<std::vec::IntoIter<Vec<InputEvent>>>,
+    /// Script being recorded, one frame per `process_queued_events()`
+    recording: Option<InputScript>,
+    /// Input clock and per-source press times for buffered queries
+    presses: PressHistory,
+    /// Fingers down and the mouse emulation they drive
+    touch: TouchState,
 }
 
 impl InputHandler {
@@ -156,6 +182,43 @@ impl InputHandler {
         }
     }
 
+    // ================== Buffered Presses ==================
+
+    /// Advance the input clock by `dt` seconds. Call once per frame, before
+    /// `process_queued_events()`, so presses are stamped with the frame's time.
+    pub fn advance_time(&mut self, dt: f32) {
+        self.presses.advance(dt);
+    }
+
+    /// Seconds of input time elapsed (the sum of `advance_time` steps)
+    pub fn time(&self) -> f64 {
+        self.presses.time()
+    }
+
+    /// Check if a key was pressed no more than `seconds` ago and that press
+    /// hasn't been consumed. A press this frame always counts.
+    pub fn was_pressed_within(&self, key: KeyCode, seconds: f32) -> bool {
+        self.was_source_pressed_within(&InputSource::Keyboard(key), seconds)
+    }
+
+    /// Check if an input source was pressed no more than `seconds` ago and
+    /// that press hasn't been consumed
+    pub fn was_source_pressed_within(&self, source: &InputSource, seconds: f32) -> bool {
+        self.presses.pressed_within(source, seconds)
+    }
+
+    /// Seconds since the source's last unconsumed press, if any
+    pub fn time_since_pressed(&self, source: &InputSource) -> Option<f32> {
+        self.presses.time_since(source)
+    }
+
+    /// Consume the source's last press so buffered queries stop seeing it.
+    /// Returns whether there was a press to consume. Per-frame state
+    /// (`is_source_just_pressed`) is unaffected.
+    pub fn consume_press(&mut self, source: &InputSource) -> bool {
+        self.presses.consume(source)
+    }
+
     // ================== Event Queue ==================
 
     /// Queue an input event for later processing
@@ -175,13 +238,79 @@ impl InputHandler {
     ///
     /// At the end of the frame, call `end_frame()` to reset per-frame state.
     pub fn process_queued_events(&mut self) {
+        if let Some(frames) = &mut self.playback {
+            match frames.next() {
+                Some(frame) => self.event_queue.extend(frame),
+                None => self.playback = None,
+            }
+        }
+        let mut frame = Vec::with_capacity(self.event_queue.len());
         while let Some(event) = self.event_queue.pop_front() {
+            if self.recording.is_some() {
+                frame.push(event.clone());
+            }
             self.process_event(event);
         }
+        if let Some(recording) = &mut self.recording {
+            recording.push_frame(frame);
+        }
+    }
+
+    /// Apply an event immediately, without waiting for
+    /// `process_queued_events()`. For tests and tools that simulate input.
+    pub fn inject_event(&mut self, event: InputEvent) {
+        self.process_event(event);
+    }
+
+    // ================== Scripted Input ==================
+
+    /// Play `script` back: each `process_queued_events()` call delivers its
+    /// next frame (after any events queued directly). Window events are
+    /// ignored until the script finishes or `stop_script()` is called.
+    pub fn play_script(&mut self, script: InputScript) {
+        self.playback = Some(script.into_frames());
+    }
+
+    /// Whether a script still has frames left to deliver.
+    pub fn is_playing_script(&self) -> bool {
+        self.playback.as_ref().is_some_and(|frames| frames.len() > 0)
+    }
+
+    /// Stop script playback, returning to live window input.
+    pub fn stop_script(&mut self) {
+        self.playback = None;
+    }
+
+    /// Start recording processed events (one script frame per
+    /// `process_queued_events()` call), discarding any previous recording.
+    pub fn start_recording(&mut self) {
+        self.recording = Some(InputScript::new());
+    }
+
+    /// Whether input is being recorded.
+    pub fn is_recording(&self) -> bool {
+        self.recording.is_some()
+    }
+
+    /// Stop recording and return the captured script, if recording.
+    pub fn stop_recording(&mut self) -> Option<InputScript> {
+        self.recording.take()
     }
 
-    /// Process a single input event
+    /// Process a single input event, timestamping any source it presses
     fn process_event(&mut self, event: InputEvent) {
+        let watched = pressable_sources(&event);
+        let held = watched.map(|source| source.is_some_and(|s| self.is_source_pressed(&s)));
+        self.apply_event(event);
+        for (source, was_held) in watched.into_iter().zip(held) {
+            if let Some(source) = source.filter(|s| !was_held && self.is_source_pressed(s)) {
+                self.presses.record(source);
+            }
+        }
+    }
+
+    /// Update device state for a single input event
+    fn apply_event(&mut self, event: InputEvent) {
         match event {
             InputEvent::KeyPressed(key) => {
                 self.keyboard.handle_key_press(key);
@@ -189,6 +318,9 @@ impl InputHandler {
             InputEvent::KeyReleased(key) => {
                 self.keyboard.handle_key_release(key);
             }
+            InputEvent::TextTyped(text) => {
+                self.keyboard.handle_text(&text);
+            }
             InputEvent::MouseButtonPressed(button) => {
                 self.mouse.handle_button_press(button);
             }
@@ -201,6 +333,13 @@ impl InputHandler {
             InputEvent::MouseWheelScrolled(delta) => {
                 self.mouse.update_wheel_delta(delta);
             }
+            InputEvent::TrackpadScrolled(x, y) => {
+                self.mouse.update_wheel_delta(y / SCROLL_PIXELS_PER_LINE);
+                self.mouse.update_pixel_scroll_delta(x, y);
+            }
+            InputEvent::PinchGesture(delta) => {
+                self.mouse.update_pinch_delta(delta);
+            }
             InputEvent::GamepadButtonPressed(id, button) => {
                 self.gamepads.get_or_register(id).handle_button_press(button);
             }
@@ -221,6 +360,9 @@ impl InputHandler {
 
     /// Handle a window event by queuing it for later processing
     pub fn handle_window_event(&mut self, event: &WindowEvent) {
+        if self.is_playing_script() {
+            return;
+        }
         match event {
             WindowEvent::KeyboardInput { event, .. } => {
                 if let Some(key_code) = convert_physical_key(event.physical_key) {
@@ -230,6 +372,9 @@ impl InputHandler {
                     };
                     self.queue_event(input_event);
                 }
+                if let (ElementState::Pressed, Some(text)) = (event.state, &event.text) {
+                    self.queue_event(InputEvent::TextTyped(text.to_string()));
+                }
             }
             WindowEvent::MouseInput { state, button, .. } => {
                 let input_event = match state {
@@ -243,14 +388,26 @@ impl InputHandler {
             }
             WindowEvent::MouseWheel { delta, .. } => {
                 // Normalize both variants to "lines" so scroll speed is
-                // consistent across mice (LineDelta) and trackpads (PixelDelta)
-                let scroll_delta = match delta {
-                    winit::event::MouseScrollDelta::LineDelta(_, y) => *y,
+                // consistent across mice (LineDelta) and trackpads (PixelDelta);
+                // pixel deltas are also kept raw for trackpad-aware consumers
+                let input_event = match delta {
+                    winit::event::MouseScrollDelta::LineDelta(_, y) => {
+                        InputEvent::MouseWheelScrolled(*y)
+                    }
                     winit::event::MouseScrollDelta::PixelDelta(position) => {
-                        position.y as f32 / SCROLL_PIXELS_PER_LINE
+                        InputEvent::TrackpadScrolled(position.x as f32, position.y as f32)
                     }
                 };
-                self.queue_event(InputEvent::MouseWheelScrolled(scroll_delta));
+                self.queue_event(input_event);
+            }
+            WindowEvent::PinchGesture { delta, .. } => {
+                self.queue_event(InputEvent::PinchGesture(*delta as f32));
+            }
+            WindowEvent::Touch(touch) => {
+                let (x, y) = (touch.location.x as f32, touch.location.y as f32);
+                for input_event in self.touch.handle(touch.id, touch.phase, x, y) {
+                    self.queue_event(input_event);
+                }
             }
             _ => {
                 // Other events can be ignored for now
@@ -381,4 +538,42 @@ impl InputHandler {
     pub fn mouse_wheel_delta(&self) -> f32 {
         self.mouse.wheel_delta()
     }
+
+    /// Get trackpad two-finger scroll (pixels) accumulated this frame
+    pub fn trackpad_scroll_delta(&self) -> (f32, f32) {
+        self.mouse.pixel_scroll_delta()
+    }
+
+    /// Get trackpad pinch delta accumulated this frame (positive = zoom in)
+    pub fn pinch_delta(&self) -> f32 {
+        self.mouse.pinch_delta()
+    }
+
+    /// Fingers currently on a touch screen (see [`TouchState`])
+    pub fn touches(&self) -> &[TouchPoint] {
+        self.touch.touches()
+    }
+
+    pub(crate) fn touch_mut(&mut self) -> &mut TouchState {
+        &mut self.touch
+    }
+
+    pub(crate) fn presses_mut(&mut self) -> &mut PressHistory {
+        &mut self.presses
+    }
+}
+
+/// Sources an event can move from released to pressed: the key or button it
+/// presses, or both directions of an updated axis.
+fn pressable_sources(event: &InputEvent) -> [Option<InputSource>; 2] {
+    match *event {
+        InputEvent::KeyPressed(key) => [Some(InputSource::Keyboard(key)), None],
+        InputEvent::MouseButtonPressed(button) => [Some(InputSource::Mouse(button)), None],
+        InputEvent::GamepadButtonPressed(id, button) => [Some(InputSource::Gamepad(id, button)), None],
+        InputEvent::GamepadAxisUpdated(id, axis, _) => [
+            Some(InputSource::GamepadAxis(id, axis, AxisDirection::Positive)),
+            Some(InputSource::GamepadAxis(id, axis, AxisDirection::Negative)),
+        ],
+        _ => [None, None],
+    }
 }
@@ -43,6 +43,35 @@
 //! - [`InputMapping::just_deactivated`] — the action is inactive this frame
 //!   and was active last frame (releasing one source while another is still
 //!   held does **not** trigger)
+//!
+//! # Buffered Actions
+//!
+//! An action with a buffer window (`set_buffer_window(Jump, 0.1)`) reports
+//! [`InputMapping::is_buffered`] for that many seconds after any bound
+//! source is pressed, so a jump pressed just before landing still fires.
+//! [`InputMapping::consume_buffered`] takes the press so it fires once:
+//!
+//! ```
+//! use input::{InputEvent, InputHandler, InputMapping, InputSource};
+//! use winit::keyboard::KeyCode;
+//!
+//! # #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+//! # enum MyAction { Jump }
+//! let mut actions = InputMapping::new();
+//! actions.bind(MyAction::Jump, InputSource::Keyboard(KeyCode::Space));
+//! actions.set_buffer_window(MyAction::Jump, 0.1);
+//!
+//! let mut input = InputHandler::new();
+//! input.inject_event(InputEvent::KeyPressed(KeyCode::Space));
+//! input.end_frame();
+//! input.advance_time(1.0 / 60.0); // airborne for a few frames...
+//!
+//! let grounded = true;
+//! if grounded && actions.consume_buffered(MyAction::Jump, &mut input) {
+//!     // jump
+//! }
+//! assert!(!actions.is_buffered(MyAction::Jump, &input));
+//! ```
 
 use crate::gamepad::{AxisDirection, GamepadAxis, GamepadButton};
 use crate::input_handler::InputHandler;
@@ -104,6 +133,8 @@ pub enum GameAction {
 pub struct InputMapping<A: Copy + Eq + Hash> {
     /// Action → bound input sources (single source of truth)
     bindings: HashMap<A, Vec<InputSource>>,
+    /// Action → seconds a press stays buffered (absent = 0)
+    buffer_windows: HashMap<A, f32>,
 }
 
 impl<A: Copy + Eq + Hash> Default for InputMapping<A> {
@@ -117,6 +148,7 @@ impl<A: Copy + Eq + Hash> InputMapping<A> {
     pub fn new() -> Self {
         Self {
             bindings: HashMap::new(),
+            buffer_windows: HashMap::new(),
         }
     }
 
@@ -186,6 +218,17 @@ impl<A: Copy + Eq + Hash> InputMapping<A> {
         self.bindings.clear();
     }
 
+    /// Keep presses of `action` buffered for `seconds` (clamped to ≥ 0).
+    /// Zero, the default, buffers a press for the frame it happens in only.
+    pub fn set_buffer_window(&mut self, action: A, seconds: f32) {
+        self.buffer_windows.insert(action, seconds.max(0.0));
+    }
+
+    /// Seconds presses of `action` stay buffered
+    pub fn buffer_window(&self, action: A) -> f32 {
+        self.buffer_windows.get(&action).copied().unwrap_or(0.0)
+    }
+
     // ================== Action State Evaluation ==================
 
     /// Check if an action is currently active (any bound source is pressed)
@@ -211,6 +254,27 @@ impl<A: Copy + Eq + Hash> InputMapping<A> {
         !self.is_active(action, input) && self.was_active(action, input)
     }
 
+    /// Check if any bound source was pressed within the action's buffer
+    /// window and that press hasn't been consumed
+    pub fn is_buffered(&self, action: A, input: &InputHandler) -> bool {
+        let window = self.buffer_window(action);
+        self.bindings(action)
+            .iter()
+            .any(|source| input.was_source_pressed_within(source, window))
+    }
+
+    /// If the action is buffered, consume the presses of all its bound
+    /// sources and return `true`; otherwise return `false`.
+    pub fn consume_buffered(&self, action: A, input: &mut InputHandler) -> bool {
+        if !self.is_buffered(action, input) {
+            return false;
+        }
+        for source in self.bindings(action) {
+            input.consume_press(source);
+        }
+        true
+    }
+
     /// Whether the action was active on the previous frame.
     fn was_active(&self, action: A, input: &InputHandler) -> bool {
         self.bindings(action)
@@ -7,6 +7,8 @@ use winit::keyboard::{KeyCode, PhysicalKey};
 #[derive(Debug, Default, Clone)]
 pub struct KeyboardState {
     keys: ButtonTracker<KeyCode>,
+    /// Text typed this frame (layout-aware, from the window's key events)
+    typed_text: String,
 }
 
 impl KeyboardState {
@@ -25,6 +27,17 @@ impl KeyboardState {
         self.keys.release(key);
     }
 
+    /// Record text produced by a key press this frame
+    pub fn handle_text(&mut self, text: &str) {
+        self.typed_text.extend(text.chars().filter(|c| !c.is_control()));
+    }
+
+    /// Text typed this frame, for text fields. Unlike key codes this follows
+    /// the keyboard layout and Shift; control characters are dropped.
+    pub fn typed_text(&self) -> &str {
+        &self.typed_text
+    }
+
     /// Check if a key is currently pressed
     pub fn is_key_pressed(&self, key: KeyCode) -> bool {
         self.keys.is_pressed(key)
@@ -43,6 +56,7 @@ impl KeyboardState {
     /// Clear the just pressed and just released sets for the next frame
     pub fn clear_frame_state(&mut self) {
         self.keys.clear_frame_state();
+        self.typed_text.clear();
     }
 }
 
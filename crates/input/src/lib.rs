@@ -1,8 +1,11 @@
 //! Input abstraction for the insiculous_2d game engine.
 //!
-//! This crate provides abstractions for keyboard, mouse, and gamepad input,
+//! This crate provides abstractions for keyboard, mouse, touch and gamepad input,
 //! plus a generic action-mapping layer ([`InputMapping`]) that games use with
-//! their own action types.
+//! their own action types. [`InputScript`] drives the handler headlessly
+//! for automated tests, and [`VirtualCursor`] drives the mouse from a
+//! gamepad stick. Presses are timestamped on an input clock, so
+//! games can buffer them across frames (`was_pressed_within`).
 
 mod button_tracker;
 mod gamepad;
@@ -11,6 +14,11 @@ mod input_mapping;
 mod keyboard;
 mod mouse;
 mod player;
+mod press_history;
+mod script;
+mod suspended;
+mod touch;
+mod virtual_cursor;
 
 pub mod prelude;
 
@@ -22,3 +30,8 @@ pub use input_mapping::*;
 pub use keyboard::*;
 pub use mouse::*;
 pub use player::*;
+pub use press_history::PressHistory;
+pub use script::InputScript;
+pub use suspended::SuspendedInput;
+pub use touch::{TouchPoint, TouchState};
+pub use virtual_cursor::{VirtualCursor, VirtualCursorConfig};
@@ -27,6 +27,10 @@ pub struct MouseState {
     buttons: ButtonTracker<MouseButton>,
     /// Mouse wheel delta accumulated over the current frame
     wheel_delta: f32,
+    /// Trackpad pixel scroll accumulated over the current frame
+    pixel_scroll_delta: (f32, f32),
+    /// Trackpad pinch delta accumulated over the current frame
+    pinch_delta: f32,
 }
 
 impl MouseState {
@@ -66,6 +70,17 @@ impl MouseState {
         self.wheel_delta += delta;
     }
 
+    /// Accumulate a trackpad pixel scroll delta for this frame
+    pub fn update_pixel_scroll_delta(&mut self, x: f32, y: f32) {
+        self.pixel_scroll_delta.0 += x;
+        self.pixel_scroll_delta.1 += y;
+    }
+
+    /// Accumulate a trackpad pinch delta for this frame
+    pub fn update_pinch_delta(&mut self, delta: f32) {
+        self.pinch_delta += delta;
+    }
+
     /// Get the current mouse position
     pub fn position(&self) -> MousePosition {
         self.position
@@ -83,6 +98,16 @@ impl MouseState {
         self.wheel_delta
     }
 
+    /// Get the trackpad pixel scroll accumulated this frame
+    pub fn pixel_scroll_delta(&self) -> (f32, f32) {
+        self.pixel_scroll_delta
+    }
+
+    /// Get the trackpad pinch delta accumulated this frame
+    pub fn pinch_delta(&self) -> f32 {
+        self.pinch_delta
+    }
+
     /// Check if a button is currently pressed
     pub fn is_button_pressed(&self, button: MouseButton) -> bool {
         self.buttons.is_pressed(button)
@@ -98,10 +123,12 @@ impl MouseState {
         self.buttons.is_just_released(button)
     }
 
-    /// Clear per-frame state (just pressed/released, movement and wheel deltas)
+    /// Clear per-frame state (just pressed/released, movement, wheel and gesture deltas)
     pub fn clear_frame_state(&mut self) {
         self.buttons.clear_frame_state();
         self.frame_delta = (0.0, 0.0);
         self.wheel_delta = 0.0;
+        self.pixel_scroll_delta = (0.0, 0.0);
+        self.pinch_delta = 0.0;
     }
 }
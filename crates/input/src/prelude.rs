@@ -10,6 +10,9 @@ pub use crate::{
     keyboard::KeyboardState,
     mouse::{MousePosition, MouseState},
     player::{InputSettings, PlayerBindings, PlayerId, PlayerSource},
+    script::InputScript,
+    touch::{TouchPoint, TouchState},
+    virtual_cursor::{VirtualCursor, VirtualCursorConfig},
     InputEvent, InputHandler,
 };
 pub use winit::event::MouseButton;
@@ -0,0 +1,104 @@
+//! Press timestamps for buffered input.
+//!
+//! Edge queries (`is_just_pressed`) only last one frame, so a jump pressed
+//! a few frames before landing is lost. [`PressHistory`] keeps an input clock
+//! (advanced by the frame's `dt`, so windows are in seconds rather than
+//! frames) and the time each source last went from released to pressed.
+//! [`InputHandler::was_pressed_within`](crate::InputHandler::was_pressed_within)
+//! and [`InputMapping::is_buffered`](crate::InputMapping::is_buffered) read it;
+//! consuming a press stops it from firing twice.
+
+use crate::input_mapping::InputSource;
+use std::collections::HashMap;
+
+/// Input clock plus the last press time of every source.
+#[derive(Debug, Clone, Default)]
+pub struct PressHistory {
+    /// Seconds of input time elapsed
+    time: f64,
+    /// Source → input time of its last (unconsumed) press
+    pressed_at: HashMap<InputSource, f64>,
+}
+
+impl PressHistory {
+    /// Empty history at time zero
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Advance the clock by `dt` seconds. Negative steps are ignored.
+    pub fn advance(&mut self, dt: f32) {
+        self.time += f64::from(dt.max(0.0));
+    }
+
+    /// Seconds of input time elapsed
+    pub fn time(&self) -> f64 {
+        self.time
+    }
+
+    /// Record that `source` was pressed now
+    pub fn record(&mut self, source: InputSource) {
+        self.pressed_at.insert(source, self.time);
+    }
+
+    /// Seconds since `source`'s last unconsumed press, if any
+    pub fn time_since(&self, source: &InputSource) -> Option<f32> {
+        self.pressed_at
+            .get(source)
+            .map(|pressed| (self.time - pressed) as f32)
+    }
+
+    /// Whether `source` was pressed no more than `seconds` ago. A press this
+    /// frame counts with any non-negative window.
+    pub fn pressed_within(&self, source: &InputSource, seconds: f32) -> bool {
+        self.time_since(source).is_some_and(|elapsed| elapsed <= seconds)
+    }
+
+    /// Forget `source`'s last press. Returns whether there was one.
+    pub fn consume(&mut self, source: &InputSource) -> bool {
+        self.pressed_at.remove(source).is_some()
+    }
+
+    /// Take out the presses of the sources matching `filter`, to be put
+    /// back with [`restore`](Self::restore)
+    pub(crate) fn take_where(&mut self, filter: impl Fn(&InputSource) -> bool) -> HashMap<InputSource, f64> {
+        let taken: HashMap<InputSource, f64> =
+            self.pressed_at.iter().filter(|(source, _)| filter(source)).map(|(s, t)| (*s, *t)).collect();
+        self.pressed_at.retain(|source, _| !taken.contains_key(source));
+        taken
+    }
+
+    /// Put back presses taken by [`take_where`](Self::take_where)
+    pub(crate) fn restore(&mut self, presses: HashMap<InputSource, f64>) {
+        self.pressed_at.extend(presses);
+    }
+
+    /// Forget every recorded press (the clock keeps running)
+    pub fn clear(&mut self) {
+        self.pressed_at.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use winit::keyboard::KeyCode;
+
+    #[test]
+    fn test_press_ages_with_the_clock_until_consumed() {
+        let space = InputSource::Keyboard(KeyCode::Space);
+        let mut history = PressHistory::new();
+        history.advance(1.0);
+        history.record(space);
+        assert!(history.pressed_within(&space, 0.0), "same-frame press");
+
+        history.advance(0.25);
+        assert_eq!(history.time_since(&space), Some(0.25));
+        assert!(history.pressed_within(&space, 0.3));
+        assert!(!history.pressed_within(&space, 0.2));
+
+        assert!(history.consume(&space));
+        assert!(!history.pressed_within(&space, 10.0));
+        assert!(!history.consume(&space));
+    }
+}
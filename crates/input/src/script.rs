@@ -0,0 +1,127 @@
+//! Scripted input: frame-by-frame event sequences for headless tests.
+//!
+//! An [`InputScript`] is a list of frames, each holding the [`InputEvent`]s
+//! delivered that frame. Build one with the helpers (`click`, `drag`,
+//! `tap_key`, ...) or record a live session with
+//! [`InputHandler::start_recording`](crate::InputHandler::start_recording),
+//! then hand it to
+//! [`InputHandler::play_script`](crate::InputHandler::play_script): each
+//! `process_queued_events()` call delivers the next frame, and live window
+//! events are ignored until the script runs out.
+//!
+//! ```
+//! use input::{InputHandler, InputScript};
+//! use winit::event::MouseButton;
+//!
+//! let mut input = InputHandler::new();
+//! input.play_script(InputScript::new().drag(MouseButton::Left, (10.0, 10.0), (90.0, 50.0), 4));
+//!
+//! input.process_queued_events(); // frame 1: move to the start and press
+//! assert!(input.is_mouse_button_just_pressed(MouseButton::Left));
+//! input.end_frame();
+//! while input.is_playing_script() {
+//!     input.process_queued_events();
+//!     input.end_frame();
+//! }
+//! assert_eq!((input.mouse_position().x, input.mouse_position().y), (90.0, 50.0));
+//! assert!(!input.is_mouse_button_pressed(MouseButton::Left));
+//! ```
+
+use serde::{Deserialize, Serialize};
+use winit::event::MouseButton;
+use winit::keyboard::KeyCode;
+
+use crate::input_handler::InputEvent;
+
+/// A frame-by-frame sequence of input events.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct InputScript {
+    frames: Vec<Vec<InputEvent>>,
+}
+
+impl InputScript {
+    /// Create an empty script.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append one frame delivering `events`.
+    pub fn frame(mut self, events: impl IntoIterator<Item = InputEvent>) -> Self {
+        self.frames.push(events.into_iter().collect());
+        self
+    }
+
+    /// Append `frames` frames with no input.
+    pub fn wait(mut self, frames: usize) -> Self {
+        self.frames.extend(std::iter::repeat_with(Vec::new).take(frames));
+        self
+    }
+
+    /// Press `key` (held until a later `release_key`).
+    pub fn press_key(self, key: KeyCode) -> Self {
+        self.frame([InputEvent::KeyPressed(key)])
+    }
+
+    /// Release `key`.
+    pub fn release_key(self, key: KeyCode) -> Self {
+        self.frame([InputEvent::KeyReleased(key)])
+    }
+
+    /// Press `key` one frame and release it the next.
+    pub fn tap_key(self, key: KeyCode) -> Self {
+        self.press_key(key).release_key(key)
+    }
+
+    /// Move the mouse to `(x, y)` (screen pixels).
+    pub fn move_mouse(self, x: f32, y: f32) -> Self {
+        self.frame([InputEvent::MouseMoved(x, y)])
+    }
+
+    /// Move to `(x, y)` and press `button` in one frame, release it the next.
+    pub fn click(self, button: MouseButton, x: f32, y: f32) -> Self {
+        self.frame([InputEvent::MouseMoved(x, y), InputEvent::MouseButtonPressed(button)])
+            .frame([InputEvent::MouseButtonReleased(button)])
+    }
+
+    /// Press `button` at `from`, move to `to` in `steps` evenly spaced
+    /// frames (at least one), then release: `steps + 2` frames in all.
+    pub fn drag(mut self, button: MouseButton, from: (f32, f32), to: (f32, f32), steps: usize) -> Self {
+        self = self.frame([InputEvent::MouseMoved(from.0, from.1), InputEvent::MouseButtonPressed(button)]);
+        let steps = steps.max(1);
+        for step in 1..=steps {
+            let t = step as f32 / steps as f32;
+            self = self.move_mouse(from.0 + (to.0 - from.0) * t, from.1 + (to.1 - from.1) * t);
+        }
+        self.frame([InputEvent::MouseButtonReleased(button)])
+    }
+
+    /// Scroll the mouse wheel by `lines`.
+    pub fn scroll(self, lines: f32) -> Self {
+        self.frame([InputEvent::MouseWheelScrolled(lines)])
+    }
+
+    /// The frames, in playback order.
+    pub fn frames(&self) -> &[Vec<InputEvent>] {
+        &self.frames
+    }
+
+    /// Number of frames.
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// Whether the script has no frames.
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    /// Append a recorded frame.
+    pub(crate) fn push_frame(&mut self, events: Vec<InputEvent>) {
+        self.frames.push(events);
+    }
+
+    /// Consume the script into its frames.
+    pub(crate) fn into_frames(self) -> std::vec::IntoIter<Vec<InputEvent>> {
+        self.frames.into_iter()
+    }
+}
@@ -0,0 +1,70 @@
+//! Setting keyboard and mouse state aside while another consumer owns them.
+//!
+//! The editor runs the game inside one window: while its panels have input
+//! focus, [`InputHandler::suspend_keyboard_and_mouse`] hides keys, buttons
+//! and the pointer from the game's update and
+//! [`InputHandler::resume_keyboard_and_mouse`] puts them back, buffered
+//! presses (`was_pressed_within`, action buffers) included. Gamepads are
+//! left alone — nothing else in the window uses them.
+//!
+//! ```
+//! use input::{InputEvent, InputHandler};
+//! use winit::keyboard::KeyCode;
+//!
+//! let mut input = InputHandler::new();
+//! input.inject_event(InputEvent::KeyPressed(KeyCode::Space));
+//!
+//! let suspended = input.suspend_keyboard_and_mouse();
+//! assert!(!input.is_key_pressed(KeyCode::Space));
+//! input.resume_keyboard_and_mouse(suspended);
+//! assert!(input.is_key_just_pressed(KeyCode::Space));
+//! ```
+
+use std::collections::HashMap;
+
+use crate::input_handler::InputHandler;
+use crate::input_mapping::InputSource;
+use crate::keyboard::KeyboardState;
+use crate::mouse::MouseState;
+use crate::touch::TouchState;
+
+/// Keyboard, mouse and touch state taken out of an [`InputHandler`]; hand
+/// it back with [`InputHandler::resume_keyboard_and_mouse`].
+#[derive(Debug, Default)]
+#[must_use = "the handler reads no keyboard or mouse input until this is resumed"]
+pub struct SuspendedInput {
+    keyboard: KeyboardState,
+    mouse: MouseState,
+    touch: TouchState,
+    /// Buffered key and mouse button presses with their input times
+    presses: HashMap<InputSource, f64>,
+}
+
+impl InputHandler {
+    /// Take the keyboard, mouse and touch state out: until resumed, every
+    /// key and button reads released (no edges), the pointer sits at the
+    /// origin and nothing moved or scrolled; buffered key and button
+    /// presses are hidden too. Gamepads are unaffected.
+    ///
+    /// Don't process events meanwhile; resuming discards them.
+    pub fn suspend_keyboard_and_mouse(&mut self) -> SuspendedInput {
+        SuspendedInput {
+            keyboard: std::mem::take(self.keyboard_mut()),
+            mouse: std::mem::take(self.mouse_mut()),
+            touch: std::mem::take(self.touch_mut()),
+            presses: self
+                .presses_mut()
+                .take_where(|source| matches!(source, InputSource::Keyboard(_) | InputSource::Mouse(_))),
+        }
+    }
+
+    /// Put back state taken by
+    /// [`suspend_keyboard_and_mouse`](Self::suspend_keyboard_and_mouse),
+    /// this frame's edges and deltas included.
+    pub fn resume_keyboard_and_mouse(&mut self, suspended: SuspendedInput) {
+        *self.keyboard_mut() = suspended.keyboard;
+        *self.mouse_mut() = suspended.mouse;
+        *self.touch_mut() = suspended.touch;
+        self.presses_mut().restore(suspended.presses);
+    }
+}
@@ -0,0 +1,113 @@
+//! Touch input, mapped onto the mouse.
+//!
+//! Touch screens (phones, tablets, browsers on either) report fingers, not a
+//! cursor. So that mouse-driven games and UI work unchanged, the first
+//! finger down acts as the left mouse button and drags the cursor. A second
+//! finger turns the touch into a gesture: the left button is released and
+//! the change in distance between the two fingers is reported as a pinch,
+//! like a trackpad's. Every active finger stays queryable through
+//! [`InputHandler::touches`](crate::InputHandler::touches).
+
+use winit::event::{MouseButton, TouchPhase};
+
+use crate::input_handler::InputEvent;
+
+/// One finger on the screen.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TouchPoint {
+    /// Platform finger id, stable while the finger is down
+    pub id: u64,
+    /// Position in window pixels
+    pub x: f32,
+    pub y: f32,
+}
+
+/// Active fingers and the mouse emulation driven by them.
+#[derive(Debug, Default, Clone)]
+pub struct TouchState {
+    /// Fingers in the order they touched down
+    touches: Vec<TouchPoint>,
+    /// Finger acting as the mouse; `None` once a gesture starts, until every
+    /// finger has lifted
+    primary: Option<u64>,
+    /// Distance between the first two fingers at the last pinch update
+    pinch_distance: Option<f32>,
+}
+
+impl TouchState {
+    /// Create a touch state with no fingers down.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Active fingers, in the order they touched down.
+    pub fn touches(&self) -> &[TouchPoint] {
+        &self.touches
+    }
+
+    /// Track one touch update, returning the mouse events it maps to.
+    pub fn handle(&mut self, id: u64, phase: TouchPhase, x: f32, y: f32) -> Vec<InputEvent> {
+        match phase {
+            TouchPhase::Started => self.start(TouchPoint { id, x, y }),
+            TouchPhase::Moved => self.update(TouchPoint { id, x, y }),
+            TouchPhase::Ended | TouchPhase::Cancelled => self.end(TouchPoint { id, x, y }),
+        }
+    }
+
+    fn start(&mut self, point: TouchPoint) -> Vec<InputEvent> {
+        self.touches.retain(|touch| touch.id != point.id);
+        self.touches.push(point);
+        match self.touches.len() {
+            1 => {
+                self.primary = Some(point.id);
+                vec![InputEvent::MouseMoved(point.x, point.y), InputEvent::MouseButtonPressed(MouseButton::Left)]
+            }
+            2 => {
+                self.pinch_distance = self.finger_distance();
+                match self.primary.take() {
+                    Some(_) => vec![InputEvent::MouseButtonReleased(MouseButton::Left)],
+                    None => Vec::new(),
+                }
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    fn update(&mut self, point: TouchPoint) -> Vec<InputEvent> {
+        let Some(touch) = self.touches.iter_mut().find(|touch| touch.id == point.id) else {
+            return Vec::new();
+        };
+        *touch = point;
+        if self.primary == Some(point.id) {
+            return vec![InputEvent::MouseMoved(point.x, point.y)];
+        }
+
+        let (Some(previous), Some(distance)) = (self.pinch_distance, self.finger_distance()) else {
+            return Vec::new();
+        };
+        self.pinch_distance = Some(distance);
+        if previous > 0.0 && distance != previous {
+            vec![InputEvent::PinchGesture((distance - previous) / previous)]
+        } else {
+            Vec::new()
+        }
+    }
+
+    fn end(&mut self, point: TouchPoint) -> Vec<InputEvent> {
+        self.touches.retain(|touch| touch.id != point.id);
+        self.pinch_distance = self.finger_distance();
+        if self.primary != Some(point.id) {
+            return Vec::new();
+        }
+        self.primary = None;
+        vec![InputEvent::MouseMoved(point.x, point.y), InputEvent::MouseButtonReleased(MouseButton::Left)]
+    }
+
+    /// Distance between the first two fingers, when two are down.
+    fn finger_distance(&self) -> Option<f32> {
+        match self.touches.as_slice() {
+            [a, b, ..] => Some((a.x - b.x).hypot(a.y - b.y)),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,171 @@
+//! Gamepad-driven virtual cursor.
+//!
+//! A [`VirtualCursor`] moves a pointer with a gamepad's right stick and
+//! turns a pad button into left clicks, injecting ordinary mouse events into
+//! the [`InputHandler`]. Anything that reads the mouse (the UI interaction
+//! manager, the editor's gizmos) works from the couch without knowing a pad
+//! is involved.
+//!
+//! Cursor speed ramps from `base_speed` to `max_speed` over
+//! `acceleration_time` seconds of continuous deflection, so short flicks
+//! stay precise and long pushes cross the screen quickly. Moving the real
+//! mouse hands control back to it.
+
+use serde::{Deserialize, Serialize};
+use winit::event::MouseButton;
+
+use crate::gamepad::{GamepadAxis, GamepadButton, GamepadState};
+use crate::input_handler::{InputEvent, InputHandler};
+use crate::mouse::MousePosition;
+
+/// Tuning for a [`VirtualCursor`].
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct VirtualCursorConfig {
+    /// Pad driving the cursor; `None` = whichever pad moves it
+    pub pad: Option<u32>,
+    /// Stick deflection ignored around the center (0..1)
+    pub dead_zone: f32,
+    /// Pixels per second at full deflection when the stick starts moving
+    pub base_speed: f32,
+    /// Pixels per second at full deflection once fully accelerated
+    pub max_speed: f32,
+    /// Seconds of continuous deflection to ramp from base to max speed
+    pub acceleration_time: f32,
+    /// Pad button that left-clicks
+    pub click_button: GamepadButton,
+}
+
+impl Default for VirtualCursorConfig {
+    fn default() -> Self {
+        Self {
+            pad: None,
+            dead_zone: 0.2,
+            base_speed: 400.0,
+            max_speed: 1600.0,
+            acceleration_time: 0.6,
+            click_button: GamepadButton::A,
+        }
+    }
+}
+
+/// A pointer driven by a gamepad's right stick (see the module docs).
+#[derive(Debug, Clone, Default)]
+pub struct VirtualCursor {
+    config: VirtualCursorConfig,
+    position: MousePosition,
+    /// `position` has been set (from the mouse, or centered on first use)
+    placed: bool,
+    /// Seconds the stick has been held past the dead zone
+    held: f32,
+    /// The stick moved the cursor since the real mouse last moved
+    active: bool,
+    /// The click button is holding the left mouse button down
+    clicking: bool,
+}
+
+impl VirtualCursor {
+    /// Create a cursor with the given tuning.
+    pub fn new(config: VirtualCursorConfig) -> Self {
+        Self { config, ..Self::default() }
+    }
+
+    /// The cursor's tuning.
+    pub fn config(&self) -> &VirtualCursorConfig {
+        &self.config
+    }
+
+    /// Mutable access to the cursor's tuning.
+    pub fn config_mut(&mut self) -> &mut VirtualCursorConfig {
+        &mut self.config
+    }
+
+    /// Cursor position in window pixels.
+    pub fn position(&self) -> MousePosition {
+        self.position
+    }
+
+    /// Whether the stick is in control: it moved the cursor since the real
+    /// mouse last moved. Only then does the click button click, and only
+    /// then does the cursor need drawing.
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
+    /// Move the cursor by the stick and apply clicks, injecting the mouse
+    /// events so this frame's readers see them. Call after
+    /// `process_queued_events()`; `bounds` is the window size the cursor is
+    /// kept inside.
+    pub fn update(&mut self, input: &mut InputHandler, delta_time: f32, bounds: (f32, f32)) {
+        let (dx, dy) = input.mouse_movement_delta();
+        if dx != 0.0 || dy != 0.0 {
+            self.position = input.mouse_position();
+            self.placed = true;
+            self.active = false;
+        }
+
+        let stick = self.stick(input);
+        match stick {
+            Some((x, y)) => {
+                if !self.placed {
+                    self.position = MousePosition { x: bounds.0 / 2.0, y: bounds.1 / 2.0 };
+                    self.placed = true;
+                }
+                let length = x.hypot(y);
+                let speed = self.speed(length);
+                self.held += delta_time;
+                // Stick Y is positive up; screen Y grows down
+                let step = speed * delta_time / length;
+                self.position.x = (self.position.x + x * step).clamp(0.0, bounds.0.max(0.0));
+                self.position.y = (self.position.y - y * step).clamp(0.0, bounds.1.max(0.0));
+                self.active = true;
+                input.inject_event(InputEvent::MouseMoved(self.position.x, self.position.y));
+            }
+            None => self.held = 0.0,
+        }
+
+        let pressed = self.click_pressed(input);
+        if pressed && !self.clicking && self.active {
+            self.clicking = true;
+            input.inject_event(InputEvent::MouseButtonPressed(MouseButton::Left));
+        } else if !pressed && self.clicking {
+            self.clicking = false;
+            input.inject_event(InputEvent::MouseButtonReleased(MouseButton::Left));
+        }
+    }
+
+    /// Right-stick deflection past the dead zone from the configured pad,
+    /// or the most deflected one.
+    fn stick(&self, input: &InputHandler) -> Option<(f32, f32)> {
+        let deflection = |pad: &GamepadState| {
+            (pad.axis_value(GamepadAxis::RightStickX), pad.axis_value(GamepadAxis::RightStickY))
+        };
+        let (x, y) = match self.config.pad {
+            Some(id) => input.gamepads().get_gamepad(id).map(deflection)?,
+            None => input
+                .gamepads()
+                .iter()
+                .map(|(_, pad)| deflection(pad))
+                .max_by(|a, b| a.0.hypot(a.1).total_cmp(&b.0.hypot(b.1)))?,
+        };
+        (x.hypot(y) > self.config.dead_zone).then_some((x, y))
+    }
+
+    /// Pixels per second for a stick deflection (past the dead zone),
+    /// squared for fine control near the center and scaled by acceleration.
+    fn speed(&self, deflection: f32) -> f32 {
+        let VirtualCursorConfig { dead_zone, base_speed, max_speed, acceleration_time, .. } = self.config;
+        let amount = ((deflection.min(1.0) - dead_zone) / (1.0 - dead_zone).max(f32::EPSILON)).clamp(0.0, 1.0);
+        let ramp = if acceleration_time > 0.0 { (self.held / acceleration_time).min(1.0) } else { 1.0 };
+        (base_speed + (max_speed - base_speed) * ramp) * amount * amount
+    }
+
+    /// Whether the click button is held on the configured pad (any pad
+    /// when none is configured).
+    fn click_pressed(&self, input: &InputHandler) -> bool {
+        let button = self.config.click_button;
+        match self.config.pad {
+            Some(id) => input.gamepads().get_gamepad(id).is_some_and(|pad| pad.is_button_pressed(button)),
+            None => input.gamepads().iter().any(|(_, pad)| pad.is_button_pressed(button)),
+        }
+    }
+}
@@ -131,4 +131,33 @@ fn test_gamepad_event_queuing() {
     assert!(gamepad.is_button_just_pressed(GamepadButton::A));
     assert!(!gamepad.is_button_pressed(GamepadButton::B));
     assert_eq!(gamepad.axis_value(GamepadAxis::LeftStickX), 0.5);
-}
\ No newline at end of file
+}
+#[test]
+fn test_trackpad_events_feed_gesture_and_wheel_deltas() {
+    let mut input_handler = InputHandler::new();
+    input_handler.queue_event(InputEvent::TrackpadScrolled(8.0, 32.0));
+    input_handler.queue_event(InputEvent::PinchGesture(0.25));
+    input_handler.process_queued_events();
+
+    assert_eq!(input_handler.trackpad_scroll_delta(), (8.0, 32.0));
+    assert_eq!(input_handler.pinch_delta(), 0.25);
+    // Pixel scroll still reaches wheel-only consumers, normalized to lines
+    assert_eq!(input_handler.mouse_wheel_delta(), 2.0);
+
+    input_handler.end_frame();
+    assert_eq!(input_handler.trackpad_scroll_delta(), (0.0, 0.0));
+    assert_eq!(input_handler.pinch_delta(), 0.0);
+}
+
+#[test]
+fn test_typed_text_lasts_one_frame() {
+    let mut input_handler = InputHandler::new();
+    input_handler.queue_event(InputEvent::TextTyped("é".to_string()));
+    input_handler.queue_event(InputEvent::TextTyped("\r".to_string()));
+    input_handler.queue_event(InputEvent::TextTyped("b".to_string()));
+    input_handler.process_queued_events();
+    assert_eq!(input_handler.keyboard().typed_text(), "éb", "control characters are dropped");
+
+    input_handler.end_frame();
+    assert_eq!(input_handler.keyboard().typed_text(), "");
+}
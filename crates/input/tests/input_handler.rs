@@ -138,3 +138,62 @@ fn test_input_handler_update() {
     assert!(input_handler.keyboard().is_key_pressed(KeyCode::KeyA));
     assert!(input_handler.mouse().is_button_pressed(MouseButton::Left));
 }
+
+/// Run one frame of `dt` seconds with `events` delivered at its start.
+fn frame(input: &mut InputHandler, dt: f32, events: &[InputEvent]) {
+    input.advance_time(dt);
+    for event in events {
+        input.queue_event(event.clone());
+    }
+    input.process_queued_events();
+}
+
+#[test]
+fn test_was_pressed_within_outlives_just_pressed() {
+    let mut input = InputHandler::new();
+    frame(&mut input, 0.016, &[InputEvent::KeyPressed(KeyCode::Space)]);
+    input.end_frame();
+    frame(&mut input, 0.05, &[InputEvent::KeyReleased(KeyCode::Space)]);
+
+    assert!(!input.is_key_just_pressed(KeyCode::Space));
+    assert!(input.was_pressed_within(KeyCode::Space, 0.1));
+    assert!(!input.was_pressed_within(KeyCode::Space, 0.01));
+    input.end_frame();
+
+    // The window is in seconds, not frames
+    frame(&mut input, 0.2, &[]);
+    assert!(!input.was_pressed_within(KeyCode::Space, 0.1));
+}
+
+#[test]
+fn test_held_key_repeat_keeps_original_press_time() {
+    let mut input = InputHandler::new();
+    frame(&mut input, 0.1, &[InputEvent::KeyPressed(KeyCode::KeyJ)]);
+    input.end_frame();
+    // OS key-repeat while held is not a new press
+    frame(&mut input, 0.5, &[InputEvent::KeyPressed(KeyCode::KeyJ)]);
+
+    let j = InputSource::Keyboard(KeyCode::KeyJ);
+    assert_eq!(input.time_since_pressed(&j), Some(0.5));
+}
+
+#[test]
+fn test_consumed_press_is_no_longer_buffered() {
+    let mut input = InputHandler::new();
+    let pad_a = InputSource::Gamepad(0, GamepadButton::A);
+    let stick_left = InputSource::GamepadAxis(0, GamepadAxis::LeftStickX, AxisDirection::Negative);
+    frame(
+        &mut input,
+        0.016,
+        &[
+            InputEvent::GamepadButtonPressed(0, GamepadButton::A),
+            InputEvent::GamepadAxisUpdated(0, GamepadAxis::LeftStickX, -0.9),
+        ],
+    );
+
+    assert!(input.was_source_pressed_within(&stick_left, 0.0));
+    assert!(input.consume_press(&pad_a));
+    assert!(!input.was_source_pressed_within(&pad_a, 1.0));
+    assert!(input.is_source_just_pressed(&pad_a), "per-frame state is untouched");
+    assert!(!input.consume_press(&pad_a));
+}
@@ -299,3 +299,35 @@ fn test_default_bindings_include_pad_zero_movement() {
     assert!(actions.is_active(GameAction::MoveUp, &input));
     assert!(!actions.is_active(GameAction::MoveDown, &input));
 }
+
+#[test]
+fn test_suspended_keyboard_and_mouse_hide_from_actions_but_gamepads_do_not() {
+    let mut input = InputHandler::new();
+    let mut actions = InputMapping::with_default_bindings();
+    actions.set_buffer_window(GameAction::MoveUp, 0.2);
+    input.queue_event(InputEvent::KeyPressed(KeyCode::KeyW));
+    input.queue_event(InputEvent::MouseMoved(120.0, 80.0));
+    input.queue_event(InputEvent::MouseButtonPressed(MouseButton::Left));
+    input.queue_event(InputEvent::GamepadButtonPressed(0, GamepadButton::A));
+    input.process_queued_events();
+
+    let suspended = input.suspend_keyboard_and_mouse();
+    assert!(!actions.is_active(GameAction::MoveUp, &input));
+    assert!(!input.is_mouse_button_pressed(MouseButton::Left));
+    assert_eq!(input.mouse_position().x, 0.0);
+    assert!(input.is_source_just_pressed(&InputSource::Gamepad(0, GamepadButton::A)));
+    // Buffered queries don't see keys typed into the editor either
+    let w = InputSource::Keyboard(KeyCode::KeyW);
+    assert!(!input.was_source_pressed_within(&w, 1.0));
+    assert_eq!(input.time_since_pressed(&w), None);
+    assert!(!input.was_source_pressed_within(&InputSource::Mouse(MouseButton::Left), 1.0));
+    assert!(!actions.is_buffered(GameAction::MoveUp, &input));
+    assert!(input.was_source_pressed_within(&InputSource::Gamepad(0, GamepadButton::A), 1.0));
+
+    input.resume_keyboard_and_mouse(suspended);
+    assert!(input.was_source_pressed_within(&w, 0.1));
+    assert!(actions.is_buffered(GameAction::MoveUp, &input));
+    assert!(actions.just_activated(GameAction::MoveUp, &input));
+    assert!(input.is_mouse_button_just_pressed(MouseButton::Left));
+    assert_eq!(input.mouse_position().x, 120.0);
+}
@@ -179,3 +179,29 @@ fn test_default_gamepad_bindings() {
         .bindings(GameAction::Menu)
         .contains(&InputSource::Gamepad(0, GamepadButton::Start)));
 }
+
+#[test]
+fn test_buffered_action_fires_once_within_window() {
+    let mut mapping = InputMapping::new();
+    mapping.bind(TestAction::Jump, InputSource::Keyboard(KeyCode::Space));
+    mapping.bind(TestAction::Jump, InputSource::Gamepad(0, GamepadButton::A));
+    mapping.set_buffer_window(TestAction::Jump, 0.1);
+    assert_eq!(mapping.buffer_window(TestAction::Shoot), 0.0);
+
+    // Jump pressed in mid-air, a few frames before landing
+    let mut input = InputHandler::new();
+    input.advance_time(0.016);
+    input.inject_event(InputEvent::KeyPressed(KeyCode::Space));
+    input.end_frame();
+    input.advance_time(0.05);
+
+    assert!(!mapping.just_activated(TestAction::Jump, &input));
+    assert!(mapping.is_buffered(TestAction::Jump, &input));
+    assert!(mapping.consume_buffered(TestAction::Jump, &mut input));
+    assert!(!mapping.consume_buffered(TestAction::Jump, &mut input), "consumed");
+
+    // A press older than the window has expired
+    input.inject_event(InputEvent::GamepadButtonPressed(0, GamepadButton::A));
+    input.advance_time(0.2);
+    assert!(!mapping.is_buffered(TestAction::Jump, &input));
+}
@@ -0,0 +1,94 @@
+use input::prelude::*;
+
+/// Run one frame: process input, read it with `read`, clear frame state.
+fn frame<T>(input: &mut InputHandler, read: impl FnOnce(&InputHandler) -> T) -> T {
+    input.process_queued_events();
+    let value = read(input);
+    input.end_frame();
+    value
+}
+
+#[test]
+fn test_inject_event_applies_immediately() {
+    let mut input = InputHandler::new();
+    input.inject_event(InputEvent::KeyPressed(KeyCode::KeyA));
+    input.inject_event(InputEvent::MouseMoved(12.0, 34.0));
+
+    assert!(input.is_key_just_pressed(KeyCode::KeyA));
+    assert_eq!(input.mouse_position().x, 12.0);
+    input.end_frame();
+    assert!(input.is_key_pressed(KeyCode::KeyA));
+    assert!(!input.is_key_just_pressed(KeyCode::KeyA));
+}
+
+#[test]
+fn test_script_delivers_one_frame_per_process_call() {
+    let mut input = InputHandler::new();
+    input.play_script(InputScript::new().tap_key(KeyCode::Space).wait(1).click(MouseButton::Left, 5.0, 6.0));
+    assert!(input.is_playing_script());
+
+    assert!(frame(&mut input, |i| i.is_key_just_pressed(KeyCode::Space)));
+    assert!(frame(&mut input, |i| i.is_key_just_released(KeyCode::Space)));
+    assert!(frame(&mut input, |i| !i.is_key_pressed(KeyCode::Space)), "wait frame");
+    assert!(frame(&mut input, |i| i.is_mouse_button_just_pressed(MouseButton::Left)));
+    assert!(frame(&mut input, |i| i.mouse().is_button_just_released(MouseButton::Left)));
+    assert!(!input.is_playing_script());
+}
+
+#[test]
+fn test_drag_moves_in_even_steps_while_held() {
+    let script = InputScript::new().drag(MouseButton::Middle, (0.0, 0.0), (100.0, 40.0), 4);
+    assert_eq!(script.len(), 6);
+
+    let mut input = InputHandler::new();
+    input.play_script(script);
+    frame(&mut input, |_| ());
+    let positions: Vec<(f32, f32, bool)> = (0..4)
+        .map(|_| frame(&mut input, |i| {
+            (i.mouse_position().x, i.mouse_position().y, i.is_mouse_button_pressed(MouseButton::Middle))
+        }))
+        .collect();
+    assert_eq!(positions, vec![(25.0, 10.0, true), (50.0, 20.0, true), (75.0, 30.0, true), (100.0, 40.0, true)]);
+    assert!(!frame(&mut input, |i| i.is_mouse_button_pressed(MouseButton::Middle)));
+}
+
+#[test]
+fn test_window_events_ignored_during_playback() {
+    let mut input = InputHandler::new();
+    input.play_script(InputScript::new().wait(1));
+    input.handle_window_event(&winit::event::WindowEvent::PinchGesture {
+        device_id: winit::event::DeviceId::dummy(),
+        delta: 0.5,
+        phase: winit::event::TouchPhase::Moved,
+    });
+    assert_eq!(frame(&mut input, |i| i.pinch_delta()), 0.0);
+
+    input.stop_script();
+    input.queue_event(InputEvent::PinchGesture(0.5));
+    assert_eq!(frame(&mut input, |i| i.pinch_delta()), 0.5);
+}
+
+#[test]
+fn test_recording_plays_back_identically() {
+    let mut input = InputHandler::new();
+    input.start_recording();
+    input.queue_event(InputEvent::KeyPressed(KeyCode::KeyW));
+    frame(&mut input, |_| ());
+    frame(&mut input, |_| ());
+    input.queue_event(InputEvent::KeyReleased(KeyCode::KeyW));
+    frame(&mut input, |_| ());
+    let recording = input.stop_recording().unwrap();
+    assert!(!input.is_recording());
+
+    let expected = InputScript::new().press_key(KeyCode::KeyW).wait(1).release_key(KeyCode::KeyW);
+    assert_eq!(recording, expected);
+
+    // Scripts serialize, so recordings can be saved as test fixtures
+    let json = serde_json::to_string(&recording).unwrap();
+    let loaded: InputScript = serde_json::from_str(&json).unwrap();
+    let mut replay = InputHandler::new();
+    replay.play_script(loaded);
+    assert!(frame(&mut replay, |i| i.is_key_just_pressed(KeyCode::KeyW)));
+    assert!(frame(&mut replay, |i| i.is_key_pressed(KeyCode::KeyW)));
+    assert!(frame(&mut replay, |i| i.is_key_just_released(KeyCode::KeyW)));
+}
@@ -0,0 +1,51 @@
+use input::prelude::*;
+use winit::event::TouchPhase;
+
+/// Feed a touch update through the handler, as a window event would.
+fn touch(input: &mut InputHandler, state: &mut TouchState, id: u64, phase: TouchPhase, x: f32, y: f32) {
+    for event in state.handle(id, phase, x, y) {
+        input.queue_event(event);
+    }
+    input.process_queued_events();
+}
+
+#[test]
+fn test_first_finger_drives_the_left_button() {
+    let mut input = InputHandler::new();
+    let mut state = TouchState::new();
+
+    touch(&mut input, &mut state, 7, TouchPhase::Started, 10.0, 20.0);
+    assert!(input.is_mouse_button_just_pressed(MouseButton::Left));
+    assert_eq!(input.mouse_position(), MousePosition { x: 10.0, y: 20.0 });
+    assert_eq!(state.touches(), [TouchPoint { id: 7, x: 10.0, y: 20.0 }]);
+
+    input.end_frame();
+    touch(&mut input, &mut state, 7, TouchPhase::Moved, 30.0, 20.0);
+    assert_eq!(input.mouse_movement_delta(), (20.0, 0.0));
+    assert!(input.is_mouse_button_pressed(MouseButton::Left));
+
+    touch(&mut input, &mut state, 7, TouchPhase::Ended, 30.0, 20.0);
+    assert!(!input.is_mouse_button_pressed(MouseButton::Left));
+    assert!(state.touches().is_empty());
+}
+
+#[test]
+fn test_second_finger_turns_the_touch_into_a_pinch() {
+    let mut input = InputHandler::new();
+    let mut state = TouchState::new();
+
+    touch(&mut input, &mut state, 1, TouchPhase::Started, 0.0, 0.0);
+    touch(&mut input, &mut state, 2, TouchPhase::Started, 100.0, 0.0);
+    assert!(!input.is_mouse_button_pressed(MouseButton::Left), "a gesture is not a drag");
+
+    input.end_frame();
+    touch(&mut input, &mut state, 2, TouchPhase::Moved, 150.0, 0.0);
+    assert_eq!(input.pinch_delta(), 0.5, "fingers moved 50% further apart");
+    assert_eq!(input.mouse_position(), MousePosition { x: 0.0, y: 0.0 }, "the cursor stays put");
+
+    // Lifting one finger doesn't hand the mouse to the other
+    touch(&mut input, &mut state, 1, TouchPhase::Ended, 0.0, 0.0);
+    touch(&mut input, &mut state, 2, TouchPhase::Moved, 90.0, 0.0);
+    assert!(!input.is_mouse_button_pressed(MouseButton::Left));
+    assert_eq!(state.touches().len(), 1);
+}
@@ -0,0 +1,87 @@
+use input::prelude::*;
+
+const BOUNDS: (f32, f32) = (800.0, 600.0);
+
+/// Queue pad events and process them, as the engine does at frame start.
+fn pad_frame(input: &mut InputHandler, events: &[InputEvent]) {
+    input.end_frame();
+    for event in events {
+        input.queue_event(event.clone());
+    }
+    input.process_queued_events();
+}
+
+#[test]
+fn test_stick_moves_the_mouse_and_accelerates() {
+    let mut input = InputHandler::new();
+    let mut cursor = VirtualCursor::new(VirtualCursorConfig::default());
+
+    pad_frame(&mut input, &[InputEvent::GamepadAxisUpdated(0, GamepadAxis::RightStickX, 1.0)]);
+    cursor.update(&mut input, 0.1, BOUNDS);
+    assert!(cursor.is_active());
+    // Starts centered, first step at base speed (400 px/s)
+    assert_eq!(input.mouse_position(), MousePosition { x: 440.0, y: 300.0 });
+
+    pad_frame(&mut input, &[]);
+    cursor.update(&mut input, 0.1, BOUNDS);
+    let first_step = 40.0;
+    let second_step = input.mouse_position().x - 440.0;
+    assert!(second_step > first_step, "held stick speeds up: {second_step}");
+
+    // Stick up moves the cursor up the screen; the cursor stays in bounds
+    pad_frame(&mut input, &[
+        InputEvent::GamepadAxisUpdated(0, GamepadAxis::RightStickX, 0.0),
+        InputEvent::GamepadAxisUpdated(0, GamepadAxis::RightStickY, 1.0),
+    ]);
+    cursor.update(&mut input, 1.0, BOUNDS);
+    assert_eq!(input.mouse_position().y, 0.0);
+}
+
+#[test]
+fn test_dead_zone_and_real_mouse_hand_control_back() {
+    let mut input = InputHandler::new();
+    let mut cursor = VirtualCursor::new(VirtualCursorConfig::default());
+
+    pad_frame(&mut input, &[InputEvent::GamepadAxisUpdated(0, GamepadAxis::RightStickX, 0.1)]);
+    cursor.update(&mut input, 0.1, BOUNDS);
+    assert!(!cursor.is_active(), "inside the dead zone");
+
+    pad_frame(&mut input, &[InputEvent::GamepadButtonPressed(0, GamepadButton::A)]);
+    cursor.update(&mut input, 0.1, BOUNDS);
+    assert!(!input.is_mouse_button_pressed(MouseButton::Left), "A keeps its meaning until the stick moves the cursor");
+
+    pad_frame(&mut input, &[
+        InputEvent::GamepadButtonReleased(0, GamepadButton::A),
+        InputEvent::GamepadAxisUpdated(0, GamepadAxis::RightStickX, 1.0),
+    ]);
+    cursor.update(&mut input, 0.1, BOUNDS);
+    pad_frame(&mut input, &[
+        InputEvent::GamepadAxisUpdated(0, GamepadAxis::RightStickX, 0.0),
+        InputEvent::MouseMoved(10.0, 10.0),
+        InputEvent::MouseMoved(12.0, 10.0),
+    ]);
+    cursor.update(&mut input, 0.1, BOUNDS);
+    assert!(!cursor.is_active());
+    assert_eq!(cursor.position(), MousePosition { x: 12.0, y: 10.0 });
+}
+
+#[test]
+fn test_click_button_presses_and_releases_left() {
+    let mut input = InputHandler::new();
+    let mut cursor = VirtualCursor::new(VirtualCursorConfig { pad: Some(1), ..Default::default() });
+
+    pad_frame(&mut input, &[InputEvent::GamepadAxisUpdated(0, GamepadAxis::RightStickX, 1.0)]);
+    cursor.update(&mut input, 0.1, BOUNDS);
+    assert!(!cursor.is_active(), "other pads are ignored");
+
+    pad_frame(&mut input, &[
+        InputEvent::GamepadAxisUpdated(1, GamepadAxis::RightStickX, 1.0),
+        InputEvent::GamepadButtonPressed(1, GamepadButton::A),
+    ]);
+    cursor.update(&mut input, 0.1, BOUNDS);
+    assert!(input.is_mouse_button_just_pressed(MouseButton::Left));
+
+    pad_frame(&mut input, &[InputEvent::GamepadButtonReleased(1, GamepadButton::A)]);
+    cursor.update(&mut input, 0.1, BOUNDS);
+    assert!(!input.is_mouse_button_pressed(MouseButton::Left));
+}
@@ -0,0 +1,76 @@
+//! Minimal authoritative server + client in one process.
+//!
+//! The server owns a ball that the client steers by sending input
+//! messages; the client only ever sees interpolated replicas.
+//!
+//! Run with: `cargo run -p net --example authoritative_server`
+
+use std::thread;
+use std::time::Duration;
+
+use common::Transform2D;
+use ecs::World;
+use glam::Vec2;
+use net::{NetClient, NetResult, NetServer, ServerConfig, ServerEvent};
+
+const DT: f32 = 1.0 / 60.0;
+const SPEED: f32 = 120.0;
+
+fn main() -> NetResult<()> {
+    env_logger::init();
+
+    // --- Server: authoritative world with one replicated ball ---
+    let mut server_world = World::new();
+    let mut server = NetServer::bind("127.0.0.1:0", ServerConfig::default())?;
+    let ball = server_world.create_entity();
+    server_world.add_component(&ball, Transform2D::new(Vec2::ZERO)).ok();
+    let ball_id = server.replicate(&mut server_world, ball, None);
+    let mut steer = Vec2::ZERO;
+
+    // --- Client: starts empty, replicas arrive with the first snapshot ---
+    let mut client_world = World::new();
+    let mut client = NetClient::connect(server.local_addr()?)?;
+
+    for frame in 0..240 {
+        // Client input: move right for two seconds, then up.
+        let input = if frame < 120 { [1.0, 0.0] } else { [0.0, 1.0] };
+        if frame % 10 == 0 {
+            client.send(serde_json::json!({ "steer": input }))?;
+        }
+
+        // Server applies inputs and simulates.
+        for event in server.take_events() {
+            match event {
+                ServerEvent::ClientConnected(id) => println!("server: client {} joined", id.0),
+                ServerEvent::ClientDisconnected(id) => println!("server: client {} left", id.0),
+                ServerEvent::Message(_, value) => {
+                    if let Ok(direction) = serde_json::from_value::<[f32; 2]>(value["steer"].clone()) {
+                        steer = Vec2::from(direction);
+                    }
+                }
+            }
+        }
+        if let Some(transform) = server_world.get_mut::<Transform2D>(ball) {
+            transform.position += steer * SPEED * DT;
+        }
+        server.update(DT, &server_world)?;
+
+        // Client pumps the network; replicas are interpolated.
+        client.update(DT, &mut client_world)?;
+        if frame % 30 == 0 {
+            let server_pos = server_world.get::<Transform2D>(ball).map(|t| t.position);
+            let client_pos = client
+                .replicas()
+                .entity(ball_id)
+                .and_then(|e| client_world.get::<Transform2D>(e))
+                .map(|t| t.position);
+            println!("frame {frame:3}: server {server_pos:?}  client {client_pos:?}");
+        }
+
+        thread::sleep(Duration::from_secs_f32(DT));
+    }
+
+    client.disconnect()?;
+    server.update(DT, &server_world)?;
+    Ok(())
+}
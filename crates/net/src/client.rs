@@ -0,0 +1,173 @@
+//! Client: connects to a [`NetServer`](crate::NetServer), sends game
+//! messages and keeps interpolated replicas of the server's entities.
+
+use std::net::{SocketAddr, ToSocketAddrs};
+
+use ecs::World;
+
+use crate::connection::Connection;
+use crate::error::NetResult;
+use crate::identity::ClientId;
+use crate::interpolation::SnapshotBuffer;
+use crate::protocol::Message;
+use crate::server::send_packet;
+use crate::snapshot::ReplicaMap;
+use crate::socket::{resolve, PacketSocket};
+
+/// Seconds of server silence before the client gives up.
+const CLIENT_TIMEOUT: f32 = 5.0;
+
+/// Connection state of a [`NetClient`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ClientState {
+    /// Handshake in progress.
+    Connecting,
+    /// Accepted by the server with this id.
+    Connected(ClientId),
+    /// Disconnected, with the reason.
+    Disconnected(String),
+}
+
+/// A client connection to an authoritative server.
+///
+/// Call [`update`](Self::update) once per frame with the client's world:
+/// it pumps the socket, advances the interpolation clock and writes the
+/// interpolated server state onto local replica entities.
+pub struct NetClient {
+    socket: PacketSocket,
+    connection: Connection,
+    state: ClientState,
+    snapshots: SnapshotBuffer,
+    replicas: ReplicaMap,
+    messages: Vec<serde_json::Value>,
+}
+
+impl NetClient {
+    /// Start connecting to `server`, e.g. `"127.0.0.1:7777"`.
+    pub fn connect(server: impl ToSocketAddrs) -> NetResult<Self> {
+        let server = resolve(server)?;
+        let local: SocketAddr = if server.is_ipv4() {
+            SocketAddr::from(([0, 0, 0, 0], 0))
+        } else {
+            SocketAddr::from(([0u16; 8], 0))
+        };
+        let socket = PacketSocket::bind(local)?;
+        let mut connection = Connection::new(server);
+        let packet = connection.channel.send(Message::Connect);
+        send_packet(&socket, &mut connection, &packet)?;
+
+        Ok(Self {
+            socket,
+            connection,
+            state: ClientState::Connecting,
+            snapshots: SnapshotBuffer::default(),
+            replicas: ReplicaMap::new(),
+            messages: Vec::new(),
+        })
+    }
+
+    /// Current connection state.
+    pub fn state(&self) -> &ClientState {
+        &self.state
+    }
+
+    /// This client's id, once connected.
+    pub fn client_id(&self) -> Option<ClientId> {
+        match self.state {
+            ClientState::Connected(id) => Some(id),
+            _ => None,
+        }
+    }
+
+    /// Local replicas of the server's entities.
+    pub fn replicas(&self) -> &ReplicaMap {
+        &self.replicas
+    }
+
+    /// Tick of the newest snapshot received.
+    pub fn latest_tick(&self) -> Option<u32> {
+        self.snapshots.latest_tick()
+    }
+
+    /// Send a game message (e.g. input) reliably to the server. Messages
+    /// sent while connecting are delivered after the handshake.
+    pub fn send(&mut self, value: serde_json::Value) -> NetResult<()> {
+        if matches!(self.state, ClientState::Disconnected(_)) {
+            return Ok(());
+        }
+        let packet = self.connection.channel.send(Message::User(value));
+        send_packet(&self.socket, &mut self.connection, &packet)
+    }
+
+    /// Drain game messages received from the server.
+    pub fn take_messages(&mut self) -> Vec<serde_json::Value> {
+        std::mem::take(&mut self.messages)
+    }
+
+    /// Tell the server we are leaving (best effort) and stop.
+    pub fn disconnect(&mut self) -> NetResult<()> {
+        if matches!(self.state, ClientState::Disconnected(_)) {
+            return Ok(());
+        }
+        self.state = ClientState::Disconnected("disconnected".to_string());
+        // Sent on the reliable channel so it is ordered after pending
+        // inputs; it is not resent once updates stop.
+        let packet = self.connection.channel.send(Message::Disconnect);
+        send_packet(&self.socket, &mut self.connection, &packet)
+    }
+
+    /// Pump the network and apply interpolated server state to `world`.
+    pub fn update(&mut self, dt: f32, world: &mut World) -> NetResult<()> {
+        if matches!(self.state, ClientState::Disconnected(_)) {
+            return Ok(());
+        }
+
+        while let Some((addr, packet)) = self.socket.receive()? {
+            if addr != self.connection.addr {
+                continue;
+            }
+            let (replies, messages) = self.connection.handle(packet);
+            for reply in &replies {
+                send_packet(&self.socket, &mut self.connection, reply)?;
+            }
+            for message in messages {
+                self.handle_message(message);
+            }
+        }
+
+        for packet in self.connection.tick(dt) {
+            send_packet(&self.socket, &mut self.connection, &packet)?;
+        }
+        if self.connection.since_received > CLIENT_TIMEOUT {
+            self.state = ClientState::Disconnected("timed out".to_string());
+            return Ok(());
+        }
+
+        if self.client_id().is_some() {
+            self.snapshots.advance(dt);
+            if self.snapshots.latest_tick().is_some() {
+                self.replicas.apply(world, &self.snapshots.sample());
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_message(&mut self, message: Message) {
+        match message {
+            Message::Accept { client_id } => {
+                log::info!("Connected as client {}", client_id.0);
+                self.state = ClientState::Connected(client_id);
+            }
+            Message::Reject { reason } => {
+                log::warn!("Connection rejected: {}", reason);
+                self.state = ClientState::Disconnected(reason);
+            }
+            Message::Disconnect => {
+                self.state = ClientState::Disconnected("server closed the connection".to_string());
+            }
+            Message::Snapshot(snapshot) => self.snapshots.push(snapshot),
+            Message::User(value) => self.messages.push(value),
+            Message::Connect | Message::Heartbeat => {}
+        }
+    }
+}
@@ -0,0 +1,212 @@
+//! Per-peer connection state: the reliable channel and liveness timers.
+//!
+//! Reliability is deliberately simple: every reliable message gets an id,
+//! is resent every [`RESEND_INTERVAL`] until its `Ack` arrives, and the
+//! receiver acks every copy (acks can be lost too), buffers out-of-order
+//! arrivals and delivers strictly in id order. Arrivals more than
+//! [`RECEIVE_WINDOW`] ids ahead are dropped unacked, so a hostile or
+//! confused peer can't grow the reorder buffer without bound; the sender
+//! resends them once the gap closes.
+
+use std::collections::BTreeMap;
+use std::net::SocketAddr;
+
+use crate::protocol::{Message, Packet};
+
+/// Seconds between resends of an unacknowledged reliable message.
+pub(crate) const RESEND_INTERVAL: f32 = 0.2;
+/// Seconds of silence before a heartbeat is sent.
+pub(crate) const HEARTBEAT_INTERVAL: f32 = 1.0;
+/// How far past the next expected id a reliable message may arrive and
+/// still be buffered.
+pub(crate) const RECEIVE_WINDOW: u32 = 1024;
+
+/// A reliable message waiting for its ack.
+#[derive(Debug)]
+struct PendingMessage {
+    id: u32,
+    message: Message,
+    since_sent: f32,
+}
+
+/// Ordered, acknowledged delivery on top of unreliable datagrams.
+#[derive(Debug, Default)]
+pub(crate) struct ReliableChannel {
+    next_send_id: u32,
+    unacked: Vec<PendingMessage>,
+    next_receive_id: u32,
+    out_of_order: BTreeMap<u32, Message>,
+}
+
+impl ReliableChannel {
+    /// Queue a message and return the packet for its first send.
+    pub(crate) fn send(&mut self, message: Message) -> Packet {
+        let id = self.next_send_id;
+        self.next_send_id = self.next_send_id.wrapping_add(1);
+        self.unacked.push(PendingMessage { id, message: message.clone(), since_sent: 0.0 });
+        Packet::Reliable { id, message }
+    }
+
+    /// Stop resending `id`.
+    pub(crate) fn acknowledge(&mut self, id: u32) {
+        self.unacked.retain(|p| p.id != id);
+    }
+
+    /// Whether `id` is more than [`RECEIVE_WINDOW`] ahead of the next
+    /// expected id (and so must not be buffered or acked yet).
+    pub(crate) fn is_beyond_window(&self, id: u32) -> bool {
+        (RECEIVE_WINDOW..=u32::MAX / 2).contains(&id.wrapping_sub(self.next_receive_id))
+    }
+
+    /// Accept reliable message `id`, returning every message now
+    /// deliverable in order (empty for duplicates, gaps and ids beyond the
+    /// receive window).
+    pub(crate) fn receive(&mut self, id: u32, message: Message) -> Vec<Message> {
+        // Ids behind the cursor (wrapping) were already delivered.
+        if id.wrapping_sub(self.next_receive_id) > u32::MAX / 2 || self.is_beyond_window(id) {
+            return Vec::new();
+        }
+        self.out_of_order.entry(id).or_insert(message);
+
+        let mut delivered = Vec::new();
+        while let Some(message) = self.out_of_order.remove(&self.next_receive_id) {
+            delivered.push(message);
+            self.next_receive_id = self.next_receive_id.wrapping_add(1);
+        }
+        delivered
+    }
+
+    /// Advance timers and return packets due for a resend.
+    pub(crate) fn resends(&mut self, dt: f32) -> Vec<Packet> {
+        let mut packets = Vec::new();
+        for pending in &mut self.unacked {
+            pending.since_sent += dt;
+            if pending.since_sent >= RESEND_INTERVAL {
+                pending.since_sent = 0.0;
+                packets.push(Packet::Reliable { id: pending.id, message: pending.message.clone() });
+            }
+        }
+        packets
+    }
+
+    /// Number of messages still awaiting an ack.
+    pub(crate) fn unacked_count(&self) -> usize {
+        self.unacked.len()
+    }
+}
+
+/// State of one remote peer.
+#[derive(Debug)]
+pub(crate) struct Connection {
+    /// The peer's address.
+    pub(crate) addr: SocketAddr,
+    /// Reliable message channel.
+    pub(crate) channel: ReliableChannel,
+    /// Seconds since anything was received from the peer.
+    pub(crate) since_received: f32,
+    /// Seconds since anything was sent to the peer.
+    pub(crate) since_sent: f32,
+}
+
+impl Connection {
+    pub(crate) fn new(addr: SocketAddr) -> Self {
+        Self { addr, channel: ReliableChannel::default(), since_received: 0.0, since_sent: 0.0 }
+    }
+
+    /// Handle an incoming packet. Returns the packets to send back (acks)
+    /// and the messages delivered to the application, in order.
+    pub(crate) fn handle(&mut self, packet: Packet) -> (Vec<Packet>, Vec<Message>) {
+        self.since_received = 0.0;
+        match packet {
+            // Left unacked so the sender keeps resending it.
+            Packet::Reliable { id, .. } if self.channel.is_beyond_window(id) => (Vec::new(), Vec::new()),
+            Packet::Reliable { id, message } => {
+                (vec![Packet::Ack { id }], self.channel.receive(id, message))
+            }
+            Packet::Ack { id } => {
+                self.channel.acknowledge(id);
+                (Vec::new(), Vec::new())
+            }
+            Packet::Unreliable(message) => (Vec::new(), vec![message]),
+        }
+    }
+
+    /// Advance timers; returns resends plus a heartbeat when the link has
+    /// been quiet for [`HEARTBEAT_INTERVAL`].
+    pub(crate) fn tick(&mut self, dt: f32) -> Vec<Packet> {
+        self.since_received += dt;
+        self.since_sent += dt;
+        let mut packets = self.channel.resends(dt);
+        if packets.is_empty() && self.since_sent >= HEARTBEAT_INTERVAL {
+            packets.push(Packet::Unreliable(Message::Heartbeat));
+        }
+        packets
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn user(n: i64) -> Message {
+        Message::User(serde_json::json!(n))
+    }
+
+    #[test]
+    fn test_out_of_order_messages_are_delivered_in_order() {
+        let mut channel = ReliableChannel::default();
+        assert!(channel.receive(1, user(1)).is_empty(), "gap before id 1");
+        assert_eq!(channel.receive(0, user(0)), vec![user(0), user(1)]);
+    }
+
+    #[test]
+    fn test_duplicate_messages_are_delivered_once() {
+        let mut channel = ReliableChannel::default();
+        assert_eq!(channel.receive(0, user(0)).len(), 1);
+        assert!(channel.receive(0, user(0)).is_empty());
+    }
+
+    #[test]
+    fn test_messages_beyond_the_receive_window_are_dropped_unacked() {
+        let mut connection = Connection::new(SocketAddr::from(([127, 0, 0, 1], 9000)));
+        let far = Packet::Reliable { id: RECEIVE_WINDOW, message: user(1) };
+        assert_eq!(connection.handle(far), (Vec::new(), Vec::new()));
+        assert!(connection.channel.out_of_order.is_empty(), "nothing buffered");
+
+        let edge = Packet::Reliable { id: RECEIVE_WINDOW - 1, message: user(1) };
+        let (replies, delivered) = connection.handle(edge);
+        assert_eq!((replies.len(), delivered.len()), (1, 0), "last id in the window is buffered and acked");
+        assert!(connection.channel.receive(u32::MAX, user(2)).is_empty(), "behind the cursor");
+    }
+
+    #[test]
+    fn test_unacked_messages_are_resent_until_acknowledged() {
+        let mut channel = ReliableChannel::default();
+        let Packet::Reliable { id, .. } = channel.send(user(5)) else {
+            panic!("reliable send produces a reliable packet");
+        };
+        assert!(channel.resends(RESEND_INTERVAL * 0.5).is_empty());
+        assert_eq!(channel.resends(RESEND_INTERVAL).len(), 1);
+
+        channel.acknowledge(id);
+        assert_eq!(channel.unacked_count(), 0);
+        assert!(channel.resends(RESEND_INTERVAL).is_empty());
+    }
+
+    #[test]
+    fn test_reliable_packets_are_acked_even_when_duplicated() {
+        let addr = SocketAddr::from(([127, 0, 0, 1], 9000));
+        let mut connection = Connection::new(addr);
+        let packet = Packet::Reliable { id: 0, message: user(0) };
+        let (replies, delivered) = connection.handle(packet.clone());
+        assert_eq!((replies.len(), delivered.len()), (1, 1));
+        let (replies, delivered) = connection.handle(packet);
+        assert_eq!((replies.len(), delivered.len()), (1, 0));
+    }
+
+    #[test]
+    fn test_quiet_connection_sends_heartbeat() {
+        let mut connection = Connection::new(SocketAddr::from(([127, 0, 0, 1], 9000)));
+        assert_eq!(connection.tick(HEARTBEAT_INTERVAL), vec![Packet::Unreliable(Message::Heartbeat)]);
+    }
+}
@@ -0,0 +1,27 @@
+//! Error types for the networking layer.
+
+use std::io;
+use thiserror::Error;
+
+/// Errors that can occur while sending or receiving over the network.
+#[derive(Debug, Error)]
+pub enum NetError {
+    /// Socket I/O failed (bind, send, address resolution).
+    #[error("I/O error: {0}")]
+    IoError(#[from] io::Error),
+
+    /// A packet could not be encoded.
+    #[error("Failed to encode packet: {0}")]
+    EncodeError(String),
+
+    /// An encoded packet exceeds the maximum datagram size.
+    #[error("Packet too large: {0} bytes")]
+    PacketTooLarge(usize),
+
+    /// The address did not resolve to any socket address.
+    #[error("Address did not resolve: {0}")]
+    AddressError(String),
+}
+
+/// Result type for networking operations.
+pub type NetResult<T> = Result<T, NetError>;
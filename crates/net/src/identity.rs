@@ -0,0 +1,46 @@
+//! Network identity: the component that marks an entity for replication.
+
+use serde::{Deserialize, Serialize};
+
+/// Identifier of a connected client, assigned by the server on accept.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct ClientId(pub u32);
+
+/// Stable cross-peer identifier of a replicated entity.
+///
+/// Local `EntityId`s differ between server and clients; a `NetworkId` is
+/// what snapshots refer to, and each client maps it to its own replica.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct NetworkId(pub u32);
+
+/// Marks an entity as replicated.
+///
+/// On the server, every entity with a `NetworkIdentity` and a
+/// `Transform2D` is included in each snapshot (plus its `RigidBody`
+/// velocity, if it has one). On clients, replicas are spawned and updated
+/// with the same identity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NetworkIdentity {
+    /// Cross-peer id of the entity.
+    pub id: NetworkId,
+    /// Client that controls this entity (e.g. its player), if any.
+    pub owner: Option<ClientId>,
+}
+
+impl NetworkIdentity {
+    /// Identity with no owning client (server-controlled).
+    pub fn new(id: NetworkId) -> Self {
+        Self { id, owner: None }
+    }
+
+    /// Set the owning client.
+    pub fn with_owner(mut self, owner: ClientId) -> Self {
+        self.owner = Some(owner);
+        self
+    }
+
+    /// Whether `client` controls this entity.
+    pub fn is_owned_by(&self, client: ClientId) -> bool {
+        self.owner == Some(client)
+    }
+}
@@ -0,0 +1,199 @@
+//! Client-side snapshot interpolation.
+//!
+//! Clients render slightly in the past — [`INTERPOLATION_DELAY`] behind the
+//! newest snapshot — so there is almost always a snapshot on each side of
+//! the render time to blend between. When snapshots stop arriving, entities
+//! with a replicated velocity are extrapolated for at most
+//! [`MAX_EXTRAPOLATION`] seconds, then hold still.
+
+use std::collections::VecDeque;
+
+use crate::snapshot::{EntityState, Snapshot};
+
+/// How far behind the newest snapshot clients render, in seconds. Two
+/// snapshot intervals at the default 20 Hz tick rate.
+pub const INTERPOLATION_DELAY: f64 = 0.1;
+/// Longest extrapolation past the newest snapshot, in seconds.
+pub const MAX_EXTRAPOLATION: f64 = 0.1;
+/// Render clock drift from its target that triggers a hard resync.
+const RESYNC_THRESHOLD: f64 = 0.25;
+/// Snapshots kept for interpolation.
+const BUFFER_CAPACITY: usize = 32;
+
+/// Buffer of received snapshots plus the client's render clock.
+#[derive(Debug)]
+pub struct SnapshotBuffer {
+    snapshots: VecDeque<Snapshot>,
+    render_time: f64,
+    delay: f64,
+}
+
+impl Default for SnapshotBuffer {
+    fn default() -> Self {
+        Self::new(INTERPOLATION_DELAY)
+    }
+}
+
+impl SnapshotBuffer {
+    /// Create an empty buffer rendering `delay` seconds behind the server.
+    pub fn new(delay: f64) -> Self {
+        Self { snapshots: VecDeque::new(), render_time: 0.0, delay }
+    }
+
+    /// Add a received snapshot. Snapshots older than the newest one
+    /// (reordered datagrams) are dropped.
+    pub fn push(&mut self, snapshot: Snapshot) {
+        if self.snapshots.back().is_some_and(|newest| snapshot.tick <= newest.tick) {
+            return;
+        }
+        if self.snapshots.is_empty() {
+            self.render_time = snapshot.time - self.delay;
+        }
+        self.snapshots.push_back(snapshot);
+        while self.snapshots.len() > BUFFER_CAPACITY {
+            self.snapshots.pop_front();
+        }
+    }
+
+    /// Advance the render clock by `dt`, resyncing it if it drifted too far
+    /// from `newest - delay` (e.g. after a stall).
+    pub fn advance(&mut self, dt: f32) {
+        self.render_time += f64::from(dt);
+        if let Some(newest) = self.snapshots.back() {
+            let target = newest.time - self.delay;
+            if (self.render_time - target).abs() > RESYNC_THRESHOLD {
+                self.render_time = target;
+            }
+        }
+        // Keep one snapshot at or before the render time for blending.
+        while self.snapshots.len() > 2 && self.snapshots[1].time <= self.render_time {
+            self.snapshots.pop_front();
+        }
+    }
+
+    /// Server time currently being rendered.
+    pub fn render_time(&self) -> f64 {
+        self.render_time
+    }
+
+    /// Tick of the newest received snapshot.
+    pub fn latest_tick(&self) -> Option<u32> {
+        self.snapshots.back().map(|s| s.tick)
+    }
+
+    /// Interpolated entity states at the render time. Entities missing
+    /// from the newer snapshot are omitted (despawned).
+    pub fn sample(&self) -> Vec<EntityState> {
+        let Some(newest) = self.snapshots.back() else {
+            return Vec::new();
+        };
+        if self.render_time >= newest.time {
+            let ahead = (self.render_time - newest.time).min(MAX_EXTRAPOLATION) as f32;
+            return newest.entities.iter().map(|s| extrapolate(s, ahead)).collect();
+        }
+
+        let Some(to_index) = self.snapshots.iter().position(|s| s.time > self.render_time) else {
+            return newest.entities.clone();
+        };
+        let to = &self.snapshots[to_index];
+        let Some(from) = to_index.checked_sub(1).map(|i| &self.snapshots[i]) else {
+            return to.entities.clone();
+        };
+
+        let span = to.time - from.time;
+        let t = if span > 0.0 { ((self.render_time - from.time) / span) as f32 } else { 1.0 };
+        to.entities
+            .iter()
+            .map(|target| match from.entities.iter().find(|s| s.id == target.id) {
+                Some(start) => lerp_state(start, target, t),
+                None => *target,
+            })
+            .collect()
+    }
+}
+
+/// Blend two states of the same entity; rotation takes the short way round.
+fn lerp_state(from: &EntityState, to: &EntityState, t: f32) -> EntityState {
+    let turn = (to.rotation - from.rotation + std::f32::consts::PI).rem_euclid(std::f32::consts::TAU)
+        - std::f32::consts::PI;
+    EntityState {
+        id: to.id,
+        position: from.position.lerp(to.position, t),
+        rotation: from.rotation + turn * t,
+        velocity: to.velocity,
+    }
+}
+
+/// Project a state `ahead` seconds along its velocity.
+fn extrapolate(state: &EntityState, ahead: f32) -> EntityState {
+    match state.velocity {
+        Some(velocity) => EntityState { position: state.position + velocity * ahead, ..*state },
+        None => *state,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::identity::NetworkId;
+    use glam::Vec2;
+
+    fn snapshot(tick: u32, x: f32) -> Snapshot {
+        Snapshot {
+            tick,
+            time: f64::from(tick) * 0.05,
+            entities: vec![EntityState {
+                id: NetworkId(1),
+                position: Vec2::new(x, 0.0),
+                rotation: 0.0,
+                velocity: Some(Vec2::new(20.0, 0.0)),
+            }],
+        }
+    }
+
+    #[test]
+    fn test_render_time_between_snapshots_blends_positions() {
+        let mut buffer = SnapshotBuffer::new(0.05);
+        buffer.push(snapshot(0, 0.0));
+        buffer.push(snapshot(1, 10.0));
+        buffer.push(snapshot(2, 20.0));
+        // Render clock starts at the first snapshot minus the delay.
+        buffer.advance(0.075);
+
+        let states = buffer.sample();
+        assert!((states[0].position.x - 5.0).abs() < 1e-3, "got {:?}", states[0].position);
+    }
+
+    #[test]
+    fn test_reordered_snapshots_are_dropped() {
+        let mut buffer = SnapshotBuffer::default();
+        buffer.push(snapshot(2, 20.0));
+        buffer.push(snapshot(1, 10.0));
+        assert_eq!(buffer.latest_tick(), Some(2));
+        assert_eq!(buffer.snapshots.len(), 1);
+    }
+
+    #[test]
+    fn test_extrapolation_is_capped_when_snapshots_stop() {
+        let mut buffer = SnapshotBuffer::new(0.0);
+        buffer.push(snapshot(0, 0.0));
+        buffer.advance(0.2);
+
+        let states = buffer.sample();
+        let max_x = 20.0 * MAX_EXTRAPOLATION as f32;
+        assert!((states[0].position.x - max_x).abs() < 1e-3, "got {:?}", states[0].position);
+    }
+
+    #[test]
+    fn test_rotation_interpolates_across_the_wrap() {
+        let from = EntityState { id: NetworkId(1), position: Vec2::ZERO, rotation: 3.0, velocity: None };
+        let to = EntityState { rotation: -3.0, ..from };
+        let mid = lerp_state(&from, &to, 0.5);
+        assert!(mid.rotation > 3.0, "took the long way: {}", mid.rotation);
+    }
+
+    #[test]
+    fn test_empty_buffer_samples_nothing() {
+        assert!(SnapshotBuffer::default().sample().is_empty());
+    }
+}
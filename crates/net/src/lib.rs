@@ -0,0 +1,51 @@
+//! Networking for the insiculous_2d game engine.
+//!
+//! A minimal authoritative client/server layer over std UDP:
+//! - **Transport** — non-blocking UDP with a reliable, ordered channel for
+//!   control and game messages (resend-until-acked) and unreliable
+//!   snapshots
+//! - **Replication** — entities marked with [`NetworkIdentity`] have their
+//!   `Transform2D` and `RigidBody` velocity broadcast in periodic
+//!   [`Snapshot`]s
+//! - **Interpolation** — clients render slightly in the past and blend
+//!   between snapshots ([`SnapshotBuffer`]), extrapolating briefly on loss
+//!
+//! # Example
+//! ```
+//! use net::{NetClient, NetServer, NetResult, ServerConfig};
+//!
+//! # fn main() -> NetResult<()> {
+//! let mut server_world = ecs::World::new();
+//! let mut server = NetServer::bind("127.0.0.1:0", ServerConfig::default())?;
+//! let player = server_world.create_entity();
+//! server_world.add_component(&player, common::Transform2D::default()).ok();
+//! server.replicate(&mut server_world, player, None);
+//!
+//! let mut client_world = ecs::World::new();
+//! let mut client = NetClient::connect(server.local_addr()?)?;
+//!
+//! // Each frame: the server simulates and pumps, the client pumps and
+//! // receives interpolated replicas of `player`.
+//! server.update(1.0 / 60.0, &server_world)?;
+//! client.update(1.0 / 60.0, &mut client_world)?;
+//! # Ok(())
+//! # }
+//! ```
+
+mod client;
+mod connection;
+mod error;
+mod identity;
+mod interpolation;
+mod protocol;
+mod server;
+mod snapshot;
+mod socket;
+
+pub use client::{ClientState, NetClient};
+pub use error::{NetError, NetResult};
+pub use identity::{ClientId, NetworkId, NetworkIdentity};
+pub use interpolation::{SnapshotBuffer, INTERPOLATION_DELAY, MAX_EXTRAPOLATION};
+pub use protocol::{Message, MAX_PACKET_SIZE};
+pub use server::{NetServer, ServerConfig, ServerEvent};
+pub use snapshot::{capture_snapshot, EntityState, ReplicaMap, Snapshot};
@@ -0,0 +1,97 @@
+//! Wire protocol: the messages peers exchange and the packets that carry
+//! them.
+//!
+//! Packets are JSON-encoded UDP datagrams (serde_json is already a
+//! workspace dependency; a compact binary codec is future work). Control
+//! and user messages travel on the reliable channel; snapshots and
+//! heartbeats are unreliable — a lost snapshot is superseded by the next.
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{NetError, NetResult};
+use crate::identity::ClientId;
+use crate::snapshot::Snapshot;
+
+/// Largest datagram the transport sends or accepts (UDP payload limit).
+/// Snapshots are not fragmented, so this caps the replicated entity count.
+pub const MAX_PACKET_SIZE: usize = 65_507;
+
+/// A message between client and server.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Message {
+    /// Client → server: request a connection.
+    Connect,
+    /// Server → client: connection accepted with the assigned id.
+    Accept {
+        /// The client's id on this server.
+        client_id: ClientId,
+    },
+    /// Server → client: connection refused (e.g. server full).
+    Reject {
+        /// Human-readable reason.
+        reason: String,
+    },
+    /// Either direction: the sender is leaving.
+    Disconnect,
+    /// Keeps an idle connection from timing out.
+    Heartbeat,
+    /// Server → client: replicated world state.
+    Snapshot(Snapshot),
+    /// Game-defined payload (inputs, chat, RPCs).
+    User(serde_json::Value),
+}
+
+/// One datagram on the wire.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) enum Packet {
+    /// Reliable message `id`; resent until acknowledged, delivered in order.
+    Reliable {
+        /// Per-connection reliable message id.
+        id: u32,
+        /// The message.
+        message: Message,
+    },
+    /// Acknowledges reliable message `id`.
+    Ack {
+        /// The acknowledged id.
+        id: u32,
+    },
+    /// Fire-and-forget message.
+    Unreliable(Message),
+}
+
+impl Packet {
+    /// Encode for sending.
+    pub(crate) fn encode(&self) -> NetResult<Vec<u8>> {
+        let bytes = serde_json::to_vec(self).map_err(|e| NetError::EncodeError(e.to_string()))?;
+        if bytes.len() > MAX_PACKET_SIZE {
+            return Err(NetError::PacketTooLarge(bytes.len()));
+        }
+        Ok(bytes)
+    }
+
+    /// Decode a received datagram; `None` if it is not a valid packet.
+    pub(crate) fn decode(bytes: &[u8]) -> Option<Self> {
+        serde_json::from_slice(bytes).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_packets_round_trip_through_encoding() {
+        let packet = Packet::Reliable {
+            id: 7,
+            message: Message::User(serde_json::json!({ "move": [1.0, 0.0] })),
+        };
+        let bytes = packet.encode().expect("encodes");
+        assert_eq!(Packet::decode(&bytes), Some(packet));
+    }
+
+    #[test]
+    fn test_garbage_datagrams_are_ignored() {
+        assert_eq!(Packet::decode(b"not a packet"), None);
+    }
+}
@@ -0,0 +1,296 @@
+//! Authoritative server: accepts clients, relays game messages and
+//! broadcasts world snapshots at a fixed rate.
+
+use std::net::{SocketAddr, ToSocketAddrs};
+
+use ecs::{EntityId, World};
+
+use crate::connection::Connection;
+use crate::error::NetResult;
+use crate::identity::{ClientId, NetworkId, NetworkIdentity};
+use crate::protocol::{Message, Packet};
+use crate::snapshot::capture_snapshot;
+use crate::socket::PacketSocket;
+
+/// Server tuning.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ServerConfig {
+    /// Snapshots broadcast per second.
+    pub snapshot_rate: f32,
+    /// Connections beyond this are rejected.
+    pub max_clients: usize,
+    /// Seconds of silence before a client is dropped.
+    pub timeout: f32,
+}
+
+impl Default for ServerConfig {
+    fn default() -> Self {
+        Self { snapshot_rate: 20.0, max_clients: 16, timeout: 5.0 }
+    }
+}
+
+/// Something that happened on the server since the last
+/// [`NetServer::take_events`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum ServerEvent {
+    /// A client connected.
+    ClientConnected(ClientId),
+    /// A client disconnected or timed out.
+    ClientDisconnected(ClientId),
+    /// A client sent a game message.
+    Message(ClientId, serde_json::Value),
+}
+
+/// A connected client.
+struct ClientSlot {
+    id: ClientId,
+    connection: Connection,
+    /// Whether the client finished the handshake.
+    accepted: bool,
+}
+
+/// The authoritative server.
+///
+/// Call [`update`](Self::update) once per frame with the authoritative
+/// world; it pumps the socket and broadcasts a snapshot of every
+/// [`NetworkIdentity`] entity whenever one is due.
+pub struct NetServer {
+    socket: PacketSocket,
+    config: ServerConfig,
+    clients: Vec<ClientSlot>,
+    events: Vec<ServerEvent>,
+    next_client_id: u32,
+    next_network_id: u32,
+    tick: u32,
+    time: f64,
+    since_snapshot: f32,
+    /// Whether the last snapshot was skipped as too large (warn once per
+    /// streak, not at every snapshot interval).
+    snapshot_oversized: bool,
+}
+
+impl NetServer {
+    /// Bind the server socket, e.g. `"0.0.0.0:7777"`.
+    pub fn bind(addr: impl ToSocketAddrs, config: ServerConfig) -> NetResult<Self> {
+        Ok(Self {
+            socket: PacketSocket::bind(addr)?,
+            config,
+            clients: Vec::new(),
+            events: Vec::new(),
+            next_client_id: 1,
+            next_network_id: 1,
+            tick: 0,
+            time: 0.0,
+            since_snapshot: 0.0,
+            snapshot_oversized: false,
+        })
+    }
+
+    /// The bound address (useful after binding port 0).
+    pub fn local_addr(&self) -> NetResult<SocketAddr> {
+        self.socket.local_addr()
+    }
+
+    /// Mark `entity` for replication, returning its new [`NetworkId`].
+    pub fn replicate(&mut self, world: &mut World, entity: EntityId, owner: Option<ClientId>) -> NetworkId {
+        let id = NetworkId(self.next_network_id);
+        self.next_network_id += 1;
+        let identity = NetworkIdentity { id, owner };
+        world.add_component(&entity, identity).ok();
+        id
+    }
+
+    /// Ids of clients that completed the handshake.
+    pub fn clients(&self) -> Vec<ClientId> {
+        self.clients.iter().filter(|c| c.accepted).map(|c| c.id).collect()
+    }
+
+    /// Current server tick (snapshots sent so far).
+    pub fn tick(&self) -> u32 {
+        self.tick
+    }
+
+    /// Drain events since the last call.
+    pub fn take_events(&mut self) -> Vec<ServerEvent> {
+        std::mem::take(&mut self.events)
+    }
+
+    /// Send a game message reliably to one client. A client the send
+    /// fails for is dropped.
+    pub fn send(&mut self, client: ClientId, value: serde_json::Value) -> NetResult<()> {
+        let Some(slot) = self.clients.iter_mut().find(|c| c.id == client && c.accepted) else {
+            return Ok(());
+        };
+        let packet = slot.connection.channel.send(Message::User(value));
+        let result = send_packet(&self.socket, &mut slot.connection, &packet);
+        if let Err(e) = &result {
+            log::warn!("Send to client {} failed, dropping it: {}", client.0, e);
+            self.drop_client(client);
+        }
+        result
+    }
+
+    /// Send a game message reliably to every client. A failed send drops
+    /// only that client.
+    pub fn broadcast(&mut self, value: serde_json::Value) -> NetResult<()> {
+        for client in self.clients() {
+            // Logged (and the client dropped) by `send`
+            self.send(client, value.clone()).ok();
+        }
+        Ok(())
+    }
+
+    /// Pump the network: receive, resend, time out idle clients and
+    /// broadcast a snapshot of `world` when due. Only socket receive
+    /// failures are returned; a failed send drops just that client.
+    pub fn update(&mut self, dt: f32, world: &World) -> NetResult<()> {
+        while let Some((addr, packet)) = self.socket.receive()? {
+            self.handle_packet(addr, packet);
+        }
+
+        let timeout = self.config.timeout;
+        let mut dropped = Vec::new();
+        for slot in &mut self.clients {
+            let resends = slot.connection.tick(dt);
+            let delivered = resends.iter().all(|packet| deliver(&self.socket, slot, packet));
+            if !delivered {
+                dropped.push(slot.id);
+            } else if slot.connection.since_received > timeout {
+                log::info!("Client {} timed out", slot.id.0);
+                dropped.push(slot.id);
+            }
+        }
+        for id in dropped {
+            self.drop_client(id);
+        }
+
+        self.time += f64::from(dt);
+        self.since_snapshot += dt;
+        let interval = 1.0 / self.config.snapshot_rate.max(1.0);
+        if self.since_snapshot >= interval {
+            self.since_snapshot -= interval;
+            self.broadcast_snapshot(world);
+        }
+        Ok(())
+    }
+
+    /// Send a snapshot of `world` to every accepted client. One that
+    /// doesn't fit in a datagram is skipped (with a warning) rather than
+    /// failing every update until the replicated set shrinks.
+    fn broadcast_snapshot(&mut self, world: &World) {
+        let snapshot = capture_snapshot(world, self.tick, self.time);
+        self.tick += 1;
+        let packet = Packet::Unreliable(Message::Snapshot(snapshot));
+        let bytes = match packet.encode() {
+            Ok(bytes) => bytes,
+            Err(e) => {
+                if !std::mem::replace(&mut self.snapshot_oversized, true) {
+                    log::warn!("Skipping snapshots until they fit again: {}", e);
+                }
+                return;
+            }
+        };
+        self.snapshot_oversized = false;
+
+        let mut dropped = Vec::new();
+        for slot in self.clients.iter_mut().filter(|c| c.accepted) {
+            slot.connection.since_sent = 0.0;
+            if let Err(e) = self.socket.send_bytes(slot.connection.addr, &bytes) {
+                log::warn!("Snapshot to client {} failed, dropping it: {}", slot.id.0, e);
+                dropped.push(slot.id);
+            }
+        }
+        for id in dropped {
+            self.drop_client(id);
+        }
+    }
+
+    fn handle_packet(&mut self, addr: SocketAddr, packet: Packet) {
+        let index = match self.clients.iter().position(|c| c.connection.addr == addr) {
+            Some(index) => index,
+            // Only a reliable Connect may open a connection.
+            None if matches!(packet, Packet::Reliable { message: Message::Connect, .. }) => {
+                let id = ClientId(self.next_client_id);
+                self.next_client_id += 1;
+                self.clients.push(ClientSlot { id, connection: Connection::new(addr), accepted: false });
+                self.clients.len() - 1
+            }
+            None => return,
+        };
+
+        let slot = &mut self.clients[index];
+        let (replies, messages) = slot.connection.handle(packet);
+        let client = slot.id;
+        if !replies.iter().all(|reply| deliver(&self.socket, slot, reply)) {
+            self.drop_client(client);
+            return;
+        }
+
+        for message in messages {
+            match message {
+                Message::Connect => self.accept(client),
+                Message::Disconnect => {
+                    log::info!("Client {} disconnected", client.0);
+                    self.drop_client(client);
+                    break;
+                }
+                Message::User(value) => self.events.push(ServerEvent::Message(client, value)),
+                _ => {}
+            }
+        }
+    }
+
+    fn accept(&mut self, client: ClientId) {
+        let accepted_count = self.clients.iter().filter(|c| c.accepted).count();
+        let full = accepted_count >= self.config.max_clients;
+        let Some(slot) = self.clients.iter_mut().find(|c| c.id == client) else {
+            return;
+        };
+        if slot.accepted {
+            return;
+        }
+
+        if full {
+            let packet = Packet::Unreliable(Message::Reject { reason: "server full".to_string() });
+            // Best effort: the client is refused either way
+            deliver(&self.socket, slot, &packet);
+            self.clients.retain(|c| c.id != client);
+            return;
+        }
+
+        let packet = slot.connection.channel.send(Message::Accept { client_id: client });
+        if !deliver(&self.socket, slot, &packet) {
+            self.clients.retain(|c| c.id != client);
+            return;
+        }
+        slot.accepted = true;
+        log::info!("Client {} connected from {}", client.0, slot.connection.addr);
+        self.events.push(ServerEvent::ClientConnected(client));
+    }
+
+    fn drop_client(&mut self, client: ClientId) {
+        if let Some(index) = self.clients.iter().position(|c| c.id == client) {
+            if self.clients.remove(index).accepted {
+                self.events.push(ServerEvent::ClientDisconnected(client));
+            }
+        }
+    }
+}
+
+/// Send `packet` to `slot`'s client, logging a failure. Returns whether it
+/// was sent; the caller drops the client when it wasn't.
+fn deliver(socket: &PacketSocket, slot: &mut ClientSlot, packet: &Packet) -> bool {
+    match send_packet(socket, &mut slot.connection, packet) {
+        Ok(()) => true,
+        Err(e) => {
+            log::warn!("Send to client {} failed, dropping it: {}", slot.id.0, e);
+            false
+        }
+    }
+}
+
+/// Send `packet` on `connection`, resetting its heartbeat timer.
+pub(crate) fn send_packet(socket: &PacketSocket, connection: &mut Connection, packet: &Packet) -> NetResult<()> {
+    connection.since_sent = 0.0;
+    socket.send(connection.addr, packet)
+}
@@ -0,0 +1,178 @@
+//! Snapshot replication: capturing replicated state on the server and
+//! applying it to client-side replicas.
+
+use std::collections::HashMap;
+
+use glam::Vec2;
+use serde::{Deserialize, Serialize};
+
+use common::Transform2D;
+use ecs::{EntityId, World};
+use physics::components::RigidBody;
+
+use crate::identity::{NetworkId, NetworkIdentity};
+
+/// Replicated state of one entity.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct EntityState {
+    /// Which entity.
+    pub id: NetworkId,
+    /// `Transform2D::position`.
+    pub position: Vec2,
+    /// `Transform2D::rotation` in radians.
+    pub rotation: f32,
+    /// `RigidBody::velocity`, if the server entity has a body.
+    pub velocity: Option<Vec2>,
+}
+
+/// Replicated world state at one server tick.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Snapshot {
+    /// Server tick the state was captured on (monotonic).
+    pub tick: u32,
+    /// Server time in seconds at capture.
+    pub time: f64,
+    /// Every replicated entity, ordered by `NetworkId`.
+    pub entities: Vec<EntityState>,
+}
+
+/// Capture every entity with a [`NetworkIdentity`] and a `Transform2D`.
+pub fn capture_snapshot(world: &World, tick: u32, time: f64) -> Snapshot {
+    let mut entities: Vec<EntityState> = world
+        .entity_ids()
+        .filter_map(|entity| {
+            let identity = world.get::<NetworkIdentity>(entity)?;
+            let transform = world.get::<Transform2D>(entity)?;
+            Some(EntityState {
+                id: identity.id,
+                position: transform.position,
+                rotation: transform.rotation,
+                velocity: world.get::<RigidBody>(entity).map(|body| body.velocity),
+            })
+        })
+        .collect();
+    entities.sort_by_key(|state| state.id);
+    Snapshot { tick, time, entities }
+}
+
+/// Client-side map from [`NetworkId`] to the local replica entity.
+#[derive(Debug, Default)]
+pub struct ReplicaMap {
+    replicas: HashMap<NetworkId, EntityId>,
+}
+
+impl ReplicaMap {
+    /// Create an empty map.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The local entity replicating `id`, if spawned.
+    pub fn entity(&self, id: NetworkId) -> Option<EntityId> {
+        self.replicas.get(&id).copied()
+    }
+
+    /// Number of live replicas.
+    pub fn len(&self) -> usize {
+        self.replicas.len()
+    }
+
+    /// Whether no replicas exist.
+    pub fn is_empty(&self) -> bool {
+        self.replicas.is_empty()
+    }
+
+    /// Write `states` onto their replicas, spawning replicas for new ids
+    /// and removing replicas whose id is no longer replicated.
+    ///
+    /// Replicas are spawned with a `NetworkIdentity` and `Transform2D`
+    /// only; games attach visuals (sprites) themselves. A replica's
+    /// `RigidBody` velocity is updated if the game gave it one.
+    pub fn apply(&mut self, world: &mut World, states: &[EntityState]) {
+        for state in states {
+            let entity = match self.replicas.get(&state.id) {
+                Some(&entity) => entity,
+                None => {
+                    let entity = world.create_entity();
+                    world.add_component(&entity, NetworkIdentity::new(state.id)).ok();
+                    world.add_component(&entity, Transform2D::new(state.position)).ok();
+                    self.replicas.insert(state.id, entity);
+                    entity
+                }
+            };
+            if let Some(transform) = world.get_mut::<Transform2D>(entity) {
+                transform.position = state.position;
+                transform.rotation = state.rotation;
+            }
+            if let (Some(velocity), Some(body)) = (state.velocity, world.get_mut::<RigidBody>(entity)) {
+                body.velocity = velocity;
+            }
+        }
+
+        let stale: Vec<NetworkId> = self
+            .replicas
+            .keys()
+            .filter(|id| !states.iter().any(|s| s.id == **id))
+            .copied()
+            .collect();
+        for id in stale {
+            if let Some(entity) = self.replicas.remove(&id) {
+                world.remove_entity(&entity).ok();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn replicated_entity(world: &mut World, id: u32, position: Vec2) -> EntityId {
+        let entity = world.create_entity();
+        world.add_component(&entity, NetworkIdentity::new(NetworkId(id))).ok();
+        world.add_component(&entity, Transform2D::new(position)).ok();
+        entity
+    }
+
+    #[test]
+    fn test_snapshot_includes_only_entities_with_network_identity() {
+        let mut world = World::new();
+        replicated_entity(&mut world, 1, Vec2::new(1.0, 2.0));
+        let unmarked = world.create_entity();
+        world.add_component(&unmarked, Transform2D::new(Vec2::ONE)).ok();
+
+        let snapshot = capture_snapshot(&world, 3, 0.15);
+        assert_eq!(snapshot.tick, 3);
+        assert_eq!(snapshot.entities.len(), 1);
+        assert_eq!(snapshot.entities[0].position, Vec2::new(1.0, 2.0));
+        assert_eq!(snapshot.entities[0].velocity, None);
+    }
+
+    #[test]
+    fn test_snapshot_carries_rigid_body_velocity() {
+        let mut world = World::new();
+        let entity = replicated_entity(&mut world, 1, Vec2::ZERO);
+        let mut body = RigidBody::default();
+        body.velocity = Vec2::new(3.0, 0.0);
+        world.add_component(&entity, body).ok();
+
+        let snapshot = capture_snapshot(&world, 0, 0.0);
+        assert_eq!(snapshot.entities[0].velocity, Some(Vec2::new(3.0, 0.0)));
+    }
+
+    #[test]
+    fn test_replicas_are_spawned_updated_and_despawned() {
+        let mut server = World::new();
+        replicated_entity(&mut server, 7, Vec2::new(4.0, 5.0));
+        let mut client = World::new();
+        let mut replicas = ReplicaMap::new();
+
+        replicas.apply(&mut client, &capture_snapshot(&server, 0, 0.0).entities);
+        let replica = replicas.entity(NetworkId(7)).expect("replica spawned");
+        assert_eq!(client.get::<Transform2D>(replica).map(|t| t.position), Some(Vec2::new(4.0, 5.0)));
+
+        replicas.apply(&mut client, &[]);
+        assert!(replicas.is_empty());
+        assert_eq!(client.entity_count(), 0);
+    }
+}
@@ -0,0 +1,64 @@
+//! Non-blocking UDP socket that speaks [`Packet`]s.
+
+use std::io;
+use std::net::{SocketAddr, ToSocketAddrs, UdpSocket};
+
+use crate::error::{NetError, NetResult};
+use crate::protocol::{Packet, MAX_PACKET_SIZE};
+
+/// Non-blocking UDP socket with a reusable receive buffer.
+pub(crate) struct PacketSocket {
+    socket: UdpSocket,
+    buffer: Vec<u8>,
+}
+
+impl PacketSocket {
+    /// Bind to `addr` in non-blocking mode.
+    pub(crate) fn bind(addr: impl ToSocketAddrs) -> NetResult<Self> {
+        let socket = UdpSocket::bind(addr)?;
+        socket.set_nonblocking(true)?;
+        Ok(Self { socket, buffer: vec![0; MAX_PACKET_SIZE] })
+    }
+
+    /// The bound local address.
+    pub(crate) fn local_addr(&self) -> NetResult<SocketAddr> {
+        Ok(self.socket.local_addr()?)
+    }
+
+    /// Send one packet to `addr`.
+    pub(crate) fn send(&self, addr: SocketAddr, packet: &Packet) -> NetResult<()> {
+        self.send_bytes(addr, &packet.encode()?)
+    }
+
+    /// Send an already-encoded packet to `addr` (one encode for a
+    /// broadcast).
+    pub(crate) fn send_bytes(&self, addr: SocketAddr, bytes: &[u8]) -> NetResult<()> {
+        self.socket.send_to(bytes, addr)?;
+        Ok(())
+    }
+
+    /// Receive the next valid packet, or `None` when none are waiting.
+    /// Undecodable datagrams are logged and skipped.
+    pub(crate) fn receive(&mut self) -> NetResult<Option<(SocketAddr, Packet)>> {
+        loop {
+            match self.socket.recv_from(&mut self.buffer) {
+                Ok((len, addr)) => match Packet::decode(&self.buffer[..len]) {
+                    Some(packet) => return Ok(Some((addr, packet))),
+                    None => log::debug!("Dropped malformed datagram from {}", addr),
+                },
+                Err(e) if e.kind() == io::ErrorKind::WouldBlock => return Ok(None),
+                // Windows reports ICMP port-unreachable from an earlier send
+                // as a receive error; the peer will simply time out.
+                Err(e) if e.kind() == io::ErrorKind::ConnectionReset => continue,
+                Err(e) => return Err(NetError::IoError(e)),
+            }
+        }
+    }
+}
+
+/// Resolve `addr` to its first socket address.
+pub(crate) fn resolve(addr: impl ToSocketAddrs) -> NetResult<SocketAddr> {
+    addr.to_socket_addrs()?
+        .next()
+        .ok_or_else(|| NetError::AddressError("no addresses".to_string()))
+}
@@ -0,0 +1,135 @@
+//! Server and client talking over real loopback UDP sockets.
+
+use std::thread;
+use std::time::Duration;
+
+use common::Transform2D;
+use ecs::World;
+use glam::Vec2;
+use net::{ClientState, NetClient, NetServer, ServerConfig, ServerEvent};
+
+const DT: f32 = 1.0 / 60.0;
+
+/// Step both peers until `done` holds or ~3 seconds pass.
+fn pump_until(
+    server: &mut NetServer,
+    server_world: &World,
+    client: &mut NetClient,
+    client_world: &mut World,
+    mut done: impl FnMut(&NetServer, &NetClient) -> bool,
+) -> bool {
+    for _ in 0..180 {
+        server.update(DT, server_world).expect("server update");
+        client.update(DT, client_world).expect("client update");
+        if done(server, client) {
+            return true;
+        }
+        thread::sleep(Duration::from_millis(2));
+    }
+    false
+}
+
+#[test]
+fn test_client_connects_and_receives_replicated_entities() {
+    let mut server_world = World::new();
+    let mut server = NetServer::bind("127.0.0.1:0", ServerConfig::default()).expect("bind");
+    let entity = server_world.create_entity();
+    server_world.add_component(&entity, Transform2D::new(Vec2::new(10.0, 20.0))).ok();
+    let network_id = server.replicate(&mut server_world, entity, None);
+
+    let mut client_world = World::new();
+    let mut client = NetClient::connect(server.local_addr().expect("addr")).expect("connect");
+
+    let replicated = pump_until(&mut server, &server_world, &mut client, &mut client_world, |_, client| {
+        client.replicas().entity(network_id).is_some()
+    });
+    assert!(replicated, "replica never arrived");
+    assert!(matches!(client.state(), ClientState::Connected(_)));
+
+    let replica = client.replicas().entity(network_id).expect("replica");
+    let position = client_world.get::<Transform2D>(replica).map(|t| t.position);
+    assert_eq!(position, Some(Vec2::new(10.0, 20.0)));
+    assert!(server
+        .take_events()
+        .iter()
+        .any(|e| matches!(e, ServerEvent::ClientConnected(_))));
+}
+
+#[test]
+fn test_client_messages_reach_the_server_in_order() {
+    let server_world = World::new();
+    let mut server = NetServer::bind("127.0.0.1:0", ServerConfig::default()).expect("bind");
+    let mut client_world = World::new();
+    let mut client = NetClient::connect(server.local_addr().expect("addr")).expect("connect");
+    for n in 0..3 {
+        client.send(serde_json::json!(n)).expect("send");
+    }
+
+    assert!(pump_until(&mut server, &server_world, &mut client, &mut client_world, |server, _| {
+        server.clients().len() == 1
+    }));
+
+    let mut received = Vec::new();
+    for _ in 0..60 {
+        server.update(DT, &server_world).expect("server update");
+        for event in server.take_events() {
+            if let ServerEvent::Message(_, value) = event {
+                received.push(value);
+            }
+        }
+        if received.len() == 3 {
+            break;
+        }
+        thread::sleep(Duration::from_millis(2));
+    }
+    assert_eq!(received, vec![serde_json::json!(0), serde_json::json!(1), serde_json::json!(2)]);
+}
+
+#[test]
+fn test_disconnecting_client_is_reported_to_the_server() {
+    let server_world = World::new();
+    let mut server = NetServer::bind("127.0.0.1:0", ServerConfig::default()).expect("bind");
+    let mut client_world = World::new();
+    let mut client = NetClient::connect(server.local_addr().expect("addr")).expect("connect");
+    assert!(pump_until(&mut server, &server_world, &mut client, &mut client_world, |_, client| {
+        client.client_id().is_some()
+    }));
+
+    client.disconnect().expect("disconnect");
+    let mut disconnected = false;
+    for _ in 0..60 {
+        server.update(DT, &server_world).expect("server update");
+        if server.take_events().iter().any(|e| matches!(e, ServerEvent::ClientDisconnected(_))) {
+            disconnected = true;
+            break;
+        }
+        thread::sleep(Duration::from_millis(2));
+    }
+    assert!(disconnected);
+    assert!(server.clients().is_empty());
+}
+
+#[test]
+fn test_oversized_snapshots_are_skipped_without_failing_update() {
+    let mut server_world = World::new();
+    let mut server = NetServer::bind("127.0.0.1:0", ServerConfig::default()).expect("bind");
+    // Far more than fits in one datagram
+    for i in 0..2000 {
+        let entity = server_world.create_entity();
+        server_world.add_component(&entity, Transform2D::new(Vec2::new(i as f32, 0.0))).ok();
+        server.replicate(&mut server_world, entity, None);
+    }
+
+    let mut client_world = World::new();
+    let mut client = NetClient::connect(server.local_addr().expect("addr")).expect("connect");
+    // `pump_until` expects every update to succeed
+    assert!(pump_until(&mut server, &server_world, &mut client, &mut client_world, |server, client| {
+        server.clients().len() == 1 && client.client_id().is_some()
+    }));
+    for _ in 0..30 {
+        server.update(DT, &server_world).expect("server update");
+        client.update(DT, &mut client_world).expect("client update");
+    }
+    assert!(server.tick() > 0, "snapshots were never attempted");
+    assert_eq!(server.clients().len(), 1);
+}
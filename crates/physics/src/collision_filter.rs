@@ -0,0 +1,148 @@
+//! Marker-based collision event subscription.
+//!
+//! By default every touching pair produces events, and games filter the
+//! stream themselves. With
+//! [`PhysicsConfig::filter_collision_events`](crate::PhysicsConfig::filter_collision_events)
+//! set, only pairs where one side carries a [`CollisionEvents`] component
+//! that accepts the other produce events at all — less volume and no
+//! per-handler filtering boilerplate:
+//!
+//! ```
+//! use physics::CollisionEvents;
+//! # struct Enemy;
+//! # struct Coin;
+//!
+//! // Report every collision of the player...
+//! let player = CollisionEvents::enabled();
+//! // ...but only the bullet's hits on enemies, and the magnet's on coins or enemies
+//! let bullet = CollisionEvents::with::<Enemy>();
+//! let magnet = CollisionEvents::with::<Coin>().or_with::<Enemy>();
+//! # let _ = (player, bullet, magnet);
+//! ```
+
+use std::any::TypeId;
+use std::collections::{HashMap, HashSet};
+
+use ecs::{EntityId, Single, World};
+
+/// "Collides with entities that have component `T`".
+#[derive(Debug, Clone, Copy)]
+struct TargetFilter {
+    component: TypeId,
+    /// Entities carrying the component
+    holders: fn(&World) -> Vec<EntityId>,
+}
+
+/// Subscribes an entity to collision events when the physics config filters
+/// them (see the [module docs](self)).
+#[derive(Debug, Clone, Default)]
+pub struct CollisionEvents {
+    /// Empty = every collision
+    targets: Vec<TargetFilter>,
+}
+
+impl CollisionEvents {
+    /// Report every collision this entity takes part in.
+    pub fn enabled() -> Self {
+        Self::default()
+    }
+
+    /// Report only collisions with entities that have component `T`.
+    pub fn with<T: ecs::Component>() -> Self {
+        Self::default().or_with::<T>()
+    }
+
+    /// Also report collisions with entities that have component `T`.
+    pub fn or_with<T: ecs::Component>(mut self) -> Self {
+        self.targets.push(TargetFilter {
+            component: TypeId::of::<T>(),
+            holders: |world| world.query_entities::<Single<T>>(),
+        });
+        self
+    }
+
+    /// Whether this reports every collision rather than a filtered set.
+    pub fn reports_all(&self) -> bool {
+        self.targets.is_empty()
+    }
+}
+
+/// Which pairs produce events, snapshotted from the ECS once per update.
+#[derive(Debug, Clone, Default)]
+pub struct CollisionEventFilter {
+    /// Entities reporting every collision
+    all: HashSet<EntityId>,
+    /// Subscriber → indices into `targets` it accepts
+    filtered: HashMap<EntityId, Vec<usize>>,
+    /// Holders of each filtered component type
+    targets: Vec<HashSet<EntityId>>,
+}
+
+impl CollisionEventFilter {
+    /// Snapshot every [`CollisionEvents`] subscription in `world`. Each
+    /// filtered component type is looked up once, however many entities
+    /// filter on it.
+    pub fn from_world(world: &World) -> Self {
+        let mut filter = Self::default();
+        let mut indices: HashMap<TypeId, usize> = HashMap::new();
+        for entity in world.query_entities::<Single<CollisionEvents>>() {
+            let Some(events) = world.get::<CollisionEvents>(entity) else {
+                continue;
+            };
+            if events.reports_all() {
+                filter.all.insert(entity);
+                continue;
+            }
+            let accepted = events
+                .targets
+                .iter()
+                .map(|target| {
+                    *indices.entry(target.component).or_insert_with(|| {
+                        filter.targets.push((target.holders)(world).into_iter().collect());
+                        filter.targets.len() - 1
+                    })
+                })
+                .collect();
+            filter.filtered.insert(entity, accepted);
+        }
+        filter
+    }
+
+    /// Whether a collision between `a` and `b` produces events: either side
+    /// subscribes to collisions with the other.
+    pub fn accepts(&self, a: EntityId, b: EntityId) -> bool {
+        self.reports(a, b) || self.reports(b, a)
+    }
+
+    fn reports(&self, entity: EntityId, other: EntityId) -> bool {
+        self.all.contains(&entity)
+            || self
+                .filtered
+                .get(&entity)
+                .is_some_and(|targets| targets.iter().any(|&index| self.targets[index].contains(&other)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Enemy;
+    struct Coin;
+
+    #[test]
+    fn test_filter_accepts_pairs_either_side_subscribes_to() {
+        let mut world = World::new();
+        let [player, bullet, enemy, coin, wall] = std::array::from_fn(|_| world.create_entity());
+        world.add_component(&player, CollisionEvents::enabled()).unwrap();
+        world.add_component(&bullet, CollisionEvents::with::<Enemy>()).unwrap();
+        world.add_component(&enemy, Enemy).unwrap();
+        world.add_component(&coin, Coin).unwrap();
+
+        let filter = CollisionEventFilter::from_world(&world);
+        assert!(filter.accepts(wall, player), "the player reports everything");
+        assert!(filter.accepts(enemy, bullet));
+        assert!(!filter.accepts(bullet, coin), "the bullet only reports enemies");
+        assert!(!filter.accepts(enemy, wall), "no subscriber on either side");
+    }
+}
@@ -0,0 +1,62 @@
+//! Velocity, impulse and force requests queued on a [`RigidBody`]
+//! component and flushed to rapier by `PhysicsSystem::update`.
+
+use glam::Vec2;
+
+use super::RigidBody;
+
+/// Requests queued on a [`RigidBody`] component by
+/// [`set_velocity`](RigidBody::set_velocity),
+/// [`apply_impulse`](RigidBody::apply_impulse) and
+/// [`add_force`](RigidBody::add_force), applied to the rapier body (and
+/// cleared) by the next `PhysicsSystem::update` before it steps.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub(crate) struct BodyCommands {
+    /// Linear and angular velocity to set
+    pub(crate) velocity: Option<(Vec2, f32)>,
+    /// Summed impulses, applied after `velocity`
+    pub(crate) impulse: Vec2,
+    /// Summed forces, acting for the steps of one update
+    pub(crate) force: Vec2,
+}
+
+impl BodyCommands {
+    /// Whether nothing is queued.
+    pub(crate) fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+impl RigidBody {
+    /// Set the body's velocity. Takes effect on the next
+    /// `PhysicsSystem::update`, even when the body is spawned this frame,
+    /// and overrides impulses applied earlier this frame. Unlike writing
+    /// `velocity` directly, the value is pushed even when it matches the
+    /// last one read back.
+    pub fn set_velocity(&mut self, linear: Vec2, angular: f32) {
+        self.velocity = linear;
+        self.angular_velocity = angular;
+        self.commands.velocity = Some((linear, angular));
+        self.commands.impulse = Vec2::ZERO;
+    }
+
+    /// Apply a mass-aware impulse (pixel units) on the next
+    /// `PhysicsSystem::update`. Impulses applied in the same frame add up.
+    pub fn apply_impulse(&mut self, impulse: Vec2) {
+        self.commands.impulse += impulse;
+    }
+
+    /// Push the body with a force (pixel units) for the steps of the next
+    /// `PhysicsSystem::update`, like `PhysicsSystem::apply_force`: call it
+    /// every frame for a continuous push. Forces added in the same frame
+    /// add up.
+    pub fn add_force(&mut self, force: Vec2) {
+        self.commands.force += force;
+    }
+
+    /// Whether a velocity, impulse or force is waiting for the next
+    /// `PhysicsSystem::update`.
+    pub fn has_pending_commands(&self) -> bool {
+        !self.commands.is_empty()
+    }
+}
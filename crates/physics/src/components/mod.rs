@@ -5,6 +5,12 @@
 use glam::Vec2;
 use serde::{Deserialize, Serialize};
 
+use crate::material::{CombineRule, PhysicsMaterial};
+
+mod body_commands;
+
+pub(crate) use body_commands::BodyCommands;
+
 /// Body type for physics simulation
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[derive(Default)]
@@ -38,9 +44,20 @@ pub struct RigidBody {
     pub can_rotate: bool,
     /// Enable Continuous Collision Detection (prevents tunneling through thin objects)
     pub ccd_enabled: bool,
+    /// Whether the body may fall asleep once it comes to rest. Sleeping
+    /// bodies skip simulation until something touches or wakes them.
+    #[serde(default = "default_can_sleep")]
+    pub can_sleep: bool,
+    /// Whether rapier has put the body to sleep (written back every update;
+    /// read with [`is_sleeping`](Self::is_sleeping))
+    #[serde(skip)]
+    pub(crate) sleeping: bool,
     /// Handle to the rapier rigid body (set by PhysicsWorld)
     #[serde(skip)]
     pub(crate) handle: Option<rapier2d::dynamics::RigidBodyHandle>,
+    /// Velocity/impulse/force requests not yet applied to the rapier body
+    #[serde(skip)]
+    pub(crate) commands: BodyCommands,
 }
 
 impl Default for RigidBody {
@@ -54,11 +71,16 @@ impl Default for RigidBody {
             angular_damping: 0.0,
             can_rotate: true,
             ccd_enabled: false,
+            can_sleep: true,
+            sleeping: false,
             handle: None,
+            commands: BodyCommands::default(),
         }
     }
 }
 
+fn default_can_sleep() -> bool { true }
+
 impl RigidBody {
     /// Create a new dynamic rigid body
     pub fn new_dynamic() -> Self {
@@ -129,11 +151,18 @@ impl RigidBody {
         self
     }
 
-    // Note: there are intentionally no `apply_impulse`/`apply_force` methods
-    // here. The `velocity` field is only read when the rapier body is first
-    // created, so mutating it on a live body silently does nothing. Use
-    // `PhysicsSystem::set_velocity` / `apply_force`, or
-    // `PhysicsWorld::apply_impulse` for mass-aware impulses.
+    /// Set whether the body may fall asleep when at rest (default `true`).
+    /// Disable for bodies whose logic must keep running while still.
+    pub fn with_sleeping(mut self, enabled: bool) -> Self {
+        self.can_sleep = enabled;
+        self
+    }
+
+    /// Whether the body was asleep after the last physics update. Wake it
+    /// with `PhysicsSystem::wake_up`.
+    pub fn is_sleeping(&self) -> bool {
+        self.sleeping
+    }
 }
 
 /// Collider shape types
@@ -185,6 +214,19 @@ impl ColliderShape {
             radius,
         }
     }
+
+    /// Whether every dimension is positive and finite. Capsules may have a
+    /// zero half-height (they degenerate to a circle) but not a zero radius.
+    pub fn has_extent(&self) -> bool {
+        let positive = |v: f32| v.is_finite() && v > 0.0;
+        match *self {
+            Self::Box { half_extents } => positive(half_extents.x) && positive(half_extents.y),
+            Self::Circle { radius } => positive(radius),
+            Self::CapsuleY { half_height, radius } | Self::CapsuleX { half_height, radius } => {
+                positive(radius) && half_height.is_finite() && half_height >= 0.0
+            }
+        }
+    }
 }
 
 /// Collider component for collision detection
@@ -200,6 +242,16 @@ pub struct Collider {
     pub friction: f32,
     /// Restitution (bounciness, 0.0 = no bounce, 1.0 = perfect bounce)
     pub restitution: f32,
+    /// Name of the [`PhysicsMaterial`] the friction/restitution values came
+    /// from; `None` for hand-tuned values
+    #[serde(default)]
+    pub material: Option<String>,
+    /// How friction mixes with the other collider's at a contact
+    #[serde(default)]
+    pub friction_combine: CombineRule,
+    /// How restitution mixes with the other collider's at a contact
+    #[serde(default)]
+    pub restitution_combine: CombineRule,
     /// Collision groups (which groups this collider belongs to)
     pub collision_groups: u32,
     /// Collision filter (which groups this collider can collide with)
@@ -217,6 +269,9 @@ impl Default for Collider {
             is_sensor: false,
             friction: 0.5,
             restitution: 0.0,
+            material: None,
+            friction_combine: CombineRule::Average,
+            restitution_combine: CombineRule::Average,
             collision_groups: 0xFFFF_FFFF,
             collision_filter: 0xFFFF_FFFF,
             handle: None,
@@ -255,18 +310,36 @@ impl Collider {
         self
     }
 
-    /// Set friction
+    /// Set friction (detaches the collider from its material)
     pub fn with_friction(mut self, friction: f32) -> Self {
         self.friction = friction.clamp(0.0, 1.0);
+        self.material = None;
         self
     }
 
-    /// Set restitution (bounciness)
+    /// Set restitution (bounciness; detaches the collider from its material)
     pub fn with_restitution(mut self, restitution: f32) -> Self {
         self.restitution = restitution.clamp(0.0, 1.0);
+        self.material = None;
+        self
+    }
+
+    /// Take friction, restitution, and mixing rules from a material and
+    /// remember its name
+    pub fn with_material(mut self, material: &PhysicsMaterial) -> Self {
+        self.set_material(material);
         self
     }
 
+    /// Apply a material to this collider in place (see [`with_material`](Self::with_material))
+    pub fn set_material(&mut self, material: &PhysicsMaterial) {
+        self.material = Some(material.name.clone());
+        self.friction = material.friction;
+        self.restitution = material.restitution;
+        self.friction_combine = material.friction_combine;
+        self.restitution_combine = material.restitution_combine;
+    }
+
     /// Set collision groups
     pub fn with_collision_groups(mut self, groups: u32, filter: u32) -> Self {
         self.collision_groups = groups;
@@ -286,6 +359,9 @@ pub struct CollisionEvent {
     pub started: bool,
     /// Whether the collision ended this frame
     pub stopped: bool,
+    /// Simulation tick (`World::simulation_tick`) of the update that
+    /// detected the collision, for correlating with input logs
+    pub tick: u64,
 }
 
 impl CollisionEvent {
@@ -314,6 +390,26 @@ impl CollisionEvent {
     }
 }
 
+/// Emitted on the world event bus when a dynamic body falls asleep — games
+/// can pause per-object logic until the matching [`BodyWake`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BodySleep {
+    /// The body that fell asleep
+    pub entity: ecs::EntityId,
+    /// Simulation tick of the update that noticed it
+    pub tick: u64,
+}
+
+/// Emitted on the world event bus when a sleeping dynamic body wakes up
+/// (hit, pushed, or woken via `PhysicsSystem::wake_up`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BodyWake {
+    /// The body that woke up
+    pub entity: ecs::EntityId,
+    /// Simulation tick of the update that noticed it
+    pub tick: u64,
+}
+
 /// Contact point data for detailed collision information
 #[derive(Debug, Clone)]
 pub struct ContactPoint {
@@ -335,119 +431,4 @@ pub struct CollisionData {
 }
 
 #[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_rigid_body_default() {
-        let body = RigidBody::default();
-        assert_eq!(body.body_type, RigidBodyType::Dynamic);
-        assert_eq!(body.velocity, Vec2::ZERO);
-        assert_eq!(body.gravity_scale, 1.0);
-    }
-
-    #[test]
-    fn test_rigid_body_builder() {
-        let body = RigidBody::new_dynamic()
-            .with_velocity(Vec2::new(10.0, 20.0))
-            .with_gravity_scale(0.5)
-            .with_linear_damping(0.1);
-
-        assert_eq!(body.velocity, Vec2::new(10.0, 20.0));
-        assert_eq!(body.gravity_scale, 0.5);
-        assert_eq!(body.linear_damping, 0.1);
-    }
-
-    #[test]
-    fn test_collider_builder() {
-        let collider = Collider::box_collider(32.0, 64.0)
-            .with_friction(0.8)
-            .with_restitution(0.5)
-            .as_sensor();
-
-        assert!(collider.is_sensor);
-        assert_eq!(collider.friction, 0.8);
-        assert_eq!(collider.restitution, 0.5);
-
-        if let ColliderShape::Box { half_extents } = collider.shape {
-            assert_eq!(half_extents, Vec2::new(16.0, 32.0));
-        } else {
-            panic!("Expected box shape");
-        }
-    }
-
-    #[test]
-    fn test_collider_shapes() {
-        let circle = ColliderShape::circle(25.0);
-        if let ColliderShape::Circle { radius } = circle {
-            assert_eq!(radius, 25.0);
-        } else {
-            panic!("Expected circle shape");
-        }
-
-        let capsule = ColliderShape::capsule_y(50.0, 10.0);
-        if let ColliderShape::CapsuleY { half_height, radius } = capsule {
-            assert_eq!(radius, 10.0);
-            assert_eq!(half_height, 15.0); // (50 - 2*10) / 2 = 15
-        } else {
-            panic!("Expected capsule shape");
-        }
-    }
-
-    // === CollisionEvent helper tests ===
-
-    #[test]
-    fn test_collision_event_involves_matching_pair() {
-        let a = ecs::EntityId::new();
-        let b = ecs::EntityId::new();
-        let event = CollisionEvent { entity_a: a, entity_b: b, started: true, stopped: false };
-        assert!(event.involves(a, b));
-    }
-
-    #[test]
-    fn test_collision_event_involves_reversed_pair() {
-        let a = ecs::EntityId::new();
-        let b = ecs::EntityId::new();
-        let event = CollisionEvent { entity_a: a, entity_b: b, started: true, stopped: false };
-        assert!(event.involves(b, a));
-    }
-
-    #[test]
-    fn test_collision_event_involves_non_matching_pair() {
-        let a = ecs::EntityId::new();
-        let b = ecs::EntityId::new();
-        let c = ecs::EntityId::new();
-        let event = CollisionEvent { entity_a: a, entity_b: b, started: true, stopped: false };
-        assert!(!event.involves(a, c));
-        assert!(!event.involves(c, b));
-    }
-
-    #[test]
-    fn test_collision_event_involves_entity() {
-        let a = ecs::EntityId::new();
-        let b = ecs::EntityId::new();
-        let c = ecs::EntityId::new();
-        let event = CollisionEvent { entity_a: a, entity_b: b, started: true, stopped: false };
-        assert!(event.involves_entity(a));
-        assert!(event.involves_entity(b));
-        assert!(!event.involves_entity(c));
-    }
-
-    #[test]
-    fn test_collision_event_other_returns_partner() {
-        let a = ecs::EntityId::new();
-        let b = ecs::EntityId::new();
-        let event = CollisionEvent { entity_a: a, entity_b: b, started: true, stopped: false };
-        assert_eq!(event.other(a), Some(b));
-        assert_eq!(event.other(b), Some(a));
-    }
-
-    #[test]
-    fn test_collision_event_other_returns_none_for_uninvolved() {
-        let a = ecs::EntityId::new();
-        let b = ecs::EntityId::new();
-        let c = ecs::EntityId::new();
-        let event = CollisionEvent { entity_a: a, entity_b: b, started: true, stopped: false };
-        assert_eq!(event.other(c), None);
-    }
-}
+mod tests;
@@ -0,0 +1,130 @@
+use super::*;
+
+#[test]
+fn test_rigid_body_default() {
+    let body = RigidBody::default();
+    assert_eq!(body.body_type, RigidBodyType::Dynamic);
+    assert_eq!(body.velocity, Vec2::ZERO);
+    assert_eq!(body.gravity_scale, 1.0);
+}
+
+#[test]
+fn test_rigid_body_builder() {
+    let body = RigidBody::new_dynamic()
+        .with_velocity(Vec2::new(10.0, 20.0))
+        .with_gravity_scale(0.5)
+        .with_linear_damping(0.1);
+
+    assert_eq!(body.velocity, Vec2::new(10.0, 20.0));
+    assert_eq!(body.gravity_scale, 0.5);
+    assert_eq!(body.linear_damping, 0.1);
+    assert!(body.can_sleep, "bodies may sleep by default");
+    assert!(!body.with_sleeping(false).can_sleep);
+}
+
+#[test]
+fn test_collider_builder() {
+    let collider = Collider::box_collider(32.0, 64.0)
+        .with_friction(0.8)
+        .with_restitution(0.5)
+        .as_sensor();
+
+    assert!(collider.is_sensor);
+    assert_eq!(collider.friction, 0.8);
+    assert_eq!(collider.restitution, 0.5);
+    assert_eq!(collider.material, None);
+
+    if let ColliderShape::Box { half_extents } = collider.shape {
+        assert_eq!(half_extents, Vec2::new(16.0, 32.0));
+    } else {
+        panic!("Expected box shape");
+    }
+}
+
+#[test]
+fn test_collider_material_sets_values_until_hand_tuned() {
+    let ice = PhysicsMaterial::preset("ice").unwrap();
+    let collider = Collider::box_collider(32.0, 32.0).with_material(&ice);
+    assert_eq!(collider.material.as_deref(), Some("ice"));
+    assert_eq!(collider.friction, ice.friction);
+    assert_eq!(collider.friction_combine, CombineRule::Min);
+
+    let tuned = collider.with_friction(0.4);
+    assert_eq!(tuned.material, None);
+    assert_eq!(tuned.friction_combine, CombineRule::Min, "mixing rule is kept");
+}
+
+#[test]
+fn test_collider_shapes() {
+    let circle = ColliderShape::circle(25.0);
+    if let ColliderShape::Circle { radius } = circle {
+        assert_eq!(radius, 25.0);
+    } else {
+        panic!("Expected circle shape");
+    }
+
+    let capsule = ColliderShape::capsule_y(50.0, 10.0);
+    if let ColliderShape::CapsuleY { half_height, radius } = capsule {
+        assert_eq!(radius, 10.0);
+        assert_eq!(half_height, 15.0); // (50 - 2*10) / 2 = 15
+    } else {
+        panic!("Expected capsule shape");
+    }
+}
+
+// === CollisionEvent helper tests ===
+
+#[test]
+fn test_collision_event_involves_matching_pair() {
+    let a = ecs::EntityId::new();
+    let b = ecs::EntityId::new();
+    let event = CollisionEvent { entity_a: a, entity_b: b, started: true, stopped: false, tick: 0 };
+    assert!(event.involves(a, b));
+}
+
+#[test]
+fn test_collision_event_involves_reversed_pair() {
+    let a = ecs::EntityId::new();
+    let b = ecs::EntityId::new();
+    let event = CollisionEvent { entity_a: a, entity_b: b, started: true, stopped: false, tick: 0 };
+    assert!(event.involves(b, a));
+}
+
+#[test]
+fn test_collision_event_involves_non_matching_pair() {
+    let a = ecs::EntityId::new();
+    let b = ecs::EntityId::new();
+    let c = ecs::EntityId::new();
+    let event = CollisionEvent { entity_a: a, entity_b: b, started: true, stopped: false, tick: 0 };
+    assert!(!event.involves(a, c));
+    assert!(!event.involves(c, b));
+}
+
+#[test]
+fn test_collision_event_involves_entity() {
+    let a = ecs::EntityId::new();
+    let b = ecs::EntityId::new();
+    let c = ecs::EntityId::new();
+    let event = CollisionEvent { entity_a: a, entity_b: b, started: true, stopped: false, tick: 0 };
+    assert!(event.involves_entity(a));
+    assert!(event.involves_entity(b));
+    assert!(!event.involves_entity(c));
+}
+
+#[test]
+fn test_collision_event_other_returns_partner() {
+    let a = ecs::EntityId::new();
+    let b = ecs::EntityId::new();
+    let event = CollisionEvent { entity_a: a, entity_b: b, started: true, stopped: false, tick: 0 };
+    assert_eq!(event.other(a), Some(b));
+    assert_eq!(event.other(b), Some(a));
+}
+
+#[test]
+fn test_collision_event_other_returns_none_for_uninvolved() {
+    let a = ecs::EntityId::new();
+    let b = ecs::EntityId::new();
+    let c = ecs::EntityId::new();
+    let event = CollisionEvent { entity_a: a, entity_b: b, started: true, stopped: false, tick: 0 };
+    assert_eq!(event.other(c), None);
+}
@@ -0,0 +1,269 @@
+//! Physics effectors: zone components that push on the dynamic bodies
+//! inside them, so wind, water and mud need no hand-applied forces.
+//!
+//! - [`AreaForce`] — a constant force (wind, fans, updrafts)
+//! - [`Buoyancy`] — a fluid with a surface line: floats bodies by how much
+//!   of them is below it and slows them while they're in it
+//! - [`Drag`] — slows bodies down (air resistance, mud, syrup)
+//!
+//! Like [`GravityZone`](crate::GravityZone)s they cover a [`ZoneShape`] on
+//! their entity. [`Effectors`] snapshots all of them once per update and
+//! the physics system adds their forces before each fixed step. Only awake
+//! bodies are pushed: like a global gravity change, editing an effector
+//! doesn't wake bodies asleep in it.
+//!
+//! ```
+//! use glam::Vec2;
+//! use physics::{AreaForce, Buoyancy, Drag, ZoneShape};
+//!
+//! let wind = AreaForce::new(ZoneShape::rect(800.0, 200.0), Vec2::new(40.0, 0.0));
+//! // Default bodies float half under the surface of a density 2 fluid
+//! let lake = Buoyancy::new(ZoneShape::rect(600.0, 300.0)).with_density(2.0);
+//! let mud = Drag::new(ZoneShape::rect(200.0, 40.0), 8.0);
+//! # let _ = (wind, lake, mud);
+//! ```
+
+use glam::Vec2;
+use serde::{Deserialize, Serialize};
+
+use ecs::{EntityId, World};
+
+use crate::gravity_zone::GravityZones;
+use crate::physics_world::{BodySample, PhysicsWorld};
+use crate::zone::{placed, ZonePlacement, ZoneShape};
+
+/// Pushes every body inside with a constant force.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AreaForce {
+    /// Covered area
+    pub shape: ZoneShape,
+    /// Force in pixel units (mass × pixels/s²), rotated with the entity —
+    /// light bodies are pushed harder than heavy ones
+    pub force: Vec2,
+}
+
+impl Default for AreaForce {
+    fn default() -> Self {
+        Self::new(ZoneShape::default(), Vec2::new(50.0, 0.0))
+    }
+}
+
+impl AreaForce {
+    /// A zone pushing with `force`.
+    pub fn new(shape: ZoneShape, force: Vec2) -> Self {
+        Self { shape, force }
+    }
+}
+
+/// A fluid volume. Bodies whose lowest point is inside get lifted against
+/// global gravity by `density` × the submerged fraction of their weight, and
+/// slowed by `drag` × that fraction.
+///
+/// Densities are relative to the bodies' (all colliders have density 1):
+/// bodies sink in a fluid below 1 and float above it, a density of 2
+/// leaving them half submerged. Lift follows global gravity, not gravity
+/// zones, and scales with the body's `gravity_scale`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Buoyancy {
+    /// Covered area
+    pub shape: ZoneShape,
+    /// Height of the (horizontal) fluid surface above the entity's position
+    pub surface: f32,
+    /// Fluid density relative to the bodies'
+    pub density: f32,
+    /// Velocity damping per second while fully submerged
+    pub drag: f32,
+}
+
+impl Default for Buoyancy {
+    fn default() -> Self {
+        Self::new(ZoneShape::default())
+    }
+}
+
+impl Buoyancy {
+    /// A fluid filling `shape` up to its top edge, with density 2 and a
+    /// drag of 1.
+    pub fn new(shape: ZoneShape) -> Self {
+        Self { shape, surface: shape.top(), density: 2.0, drag: 1.0 }
+    }
+
+    /// Put the surface `height` pixels above the entity's position.
+    pub fn with_surface(mut self, height: f32) -> Self {
+        self.surface = height;
+        self
+    }
+
+    /// Set the fluid density.
+    pub fn with_density(mut self, density: f32) -> Self {
+        self.density = density;
+        self
+    }
+
+    /// Set the fluid drag.
+    pub fn with_drag(mut self, drag: f32) -> Self {
+        self.drag = drag;
+        self
+    }
+
+    /// Fraction of `body` below the surface; 0 when its lowest point is
+    /// outside the volume.
+    fn submerged(&self, placement: ZonePlacement, body: &BodySample) -> f32 {
+        if !self.shape.contains(placement.to_local(Vec2::new(body.position.x, body.bottom))) {
+            return 0.0;
+        }
+        let surface = placement.center.y + self.surface;
+        if body.top <= body.bottom {
+            return if body.position.y <= surface { 1.0 } else { 0.0 };
+        }
+        ((surface - body.bottom) / (body.top - body.bottom)).clamp(0.0, 1.0)
+    }
+}
+
+/// Slows every body inside.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Drag {
+    /// Covered area
+    pub shape: ZoneShape,
+    /// Velocity damping per second (like `RigidBody::linear_damping`)
+    pub damping: f32,
+}
+
+impl Default for Drag {
+    fn default() -> Self {
+        Self::new(ZoneShape::default(), 2.0)
+    }
+}
+
+impl Drag {
+    /// A zone damping velocity by `damping` per second.
+    pub fn new(shape: ZoneShape, damping: f32) -> Self {
+        Self { shape, damping }
+    }
+}
+
+/// Every gravity zone and effector in the world, snapshotted from the ECS
+/// once per update.
+#[derive(Debug, Clone, Default)]
+pub struct Effectors {
+    gravity: GravityZones,
+    forces: Vec<(AreaForce, ZonePlacement)>,
+    fluids: Vec<(Buoyancy, ZonePlacement)>,
+    drags: Vec<(Drag, ZonePlacement)>,
+}
+
+impl Effectors {
+    /// Snapshot every zone component on an entity with a `Transform2D`.
+    pub fn from_world(world: &World) -> Self {
+        Self {
+            gravity: GravityZones::from_world(world),
+            forces: placed(world),
+            fluids: placed(world),
+            drags: placed(world),
+        }
+    }
+
+    /// Whether there is nothing to apply.
+    pub fn is_empty(&self) -> bool {
+        self.gravity.is_empty() && self.forces.is_empty() && self.fluids.is_empty() && self.drags.is_empty()
+    }
+
+    /// Forces (pixels) for the awake dynamic bodies inside any zone, for one
+    /// step of `dt` seconds.
+    pub(crate) fn forces(&self, physics: &PhysicsWorld, dt: f32) -> Vec<(EntityId, Vec2)> {
+        if self.is_empty() {
+            return Vec::new();
+        }
+        let global = physics.gravity();
+        physics
+            .awake_dynamic_bodies()
+            .filter_map(|body| {
+                let force = self.force_on(&body, global, dt);
+                (force != Vec2::ZERO).then_some((body.entity, force))
+            })
+            .collect()
+    }
+
+    fn force_on(&self, body: &BodySample, global: Vec2, dt: f32) -> Vec2 {
+        // Damping stronger than 1/dt would reverse the velocity in one step
+        let damping = |rate: f32| -body.velocity * rate.clamp(0.0, 1.0 / dt);
+        let gravity = (self.gravity.gravity_at(body.position, global) - global) * body.gravity_scale;
+        let mut acceleration = gravity;
+        for (drag, placement) in &self.drags {
+            if drag.shape.contains(placement.to_local(body.position)) {
+                acceleration += damping(drag.damping);
+            }
+        }
+        for (fluid, placement) in &self.fluids {
+            let submerged = fluid.submerged(*placement, body);
+            if submerged > 0.0 {
+                let lift = -global * fluid.density * body.gravity_scale;
+                acceleration += (lift + damping(fluid.drag)) * submerged;
+            }
+        }
+        let mut force = acceleration * body.mass;
+        for (area, placement) in &self.forces {
+            if area.shape.contains(placement.to_local(body.position)) {
+                force += placement.to_world(area.force);
+            }
+        }
+        force
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ecs::sprite_components::Transform2D;
+
+    use super::*;
+
+    const DT: f32 = 1.0 / 60.0;
+    const GRAVITY: Vec2 = Vec2::new(0.0, -1000.0);
+
+    /// A 2 kg, 20px tall body at `position`.
+    fn body(position: Vec2, velocity: Vec2) -> BodySample {
+        BodySample {
+            entity: EntityId::new(),
+            position,
+            velocity,
+            mass: 2.0,
+            gravity_scale: 1.0,
+            bottom: position.y - 10.0,
+            top: position.y + 10.0,
+        }
+    }
+
+    #[test]
+    fn test_buoyancy_lifts_by_submerged_fraction() {
+        let mut world = World::new();
+        world.spawn((Transform2D::new(Vec2::ZERO), Buoyancy::new(ZoneShape::rect(200.0, 200.0)).with_drag(0.0)));
+        let effectors = Effectors::from_world(&world);
+
+        // The surface is at y = 100: fully under, half under, above
+        let lift = |y: f32| effectors.force_on(&body(Vec2::new(0.0, y), Vec2::ZERO), GRAVITY, DT);
+        assert_eq!(lift(0.0), Vec2::new(0.0, 4000.0), "density 2 × 2 kg × 1000");
+        assert_eq!(lift(100.0), Vec2::new(0.0, 2000.0));
+        assert_eq!(lift(115.0), Vec2::ZERO);
+        assert_eq!(lift(-130.0), Vec2::ZERO, "below the volume");
+    }
+
+    #[test]
+    fn test_area_force_and_drag_apply_inside_only() {
+        let mut world = World::new();
+        let mut turned = Transform2D::new(Vec2::ZERO);
+        turned.rotation = std::f32::consts::FRAC_PI_2;
+        world.spawn((turned, AreaForce::new(ZoneShape::circle(50.0), Vec2::new(30.0, 0.0))));
+        world.spawn((Transform2D::new(Vec2::new(200.0, 0.0)), Drag::new(ZoneShape::circle(50.0), 3.0)));
+        world.spawn((Transform2D::new(Vec2::new(400.0, 0.0)), Drag::new(ZoneShape::circle(50.0), 1000.0)));
+        let effectors = Effectors::from_world(&world);
+        let velocity = Vec2::new(10.0, 0.0);
+
+        let pushed = effectors.force_on(&body(Vec2::ZERO, velocity), GRAVITY, DT);
+        assert!((pushed - Vec2::new(0.0, 30.0)).length() < 1e-4, "rotated with the zone: {pushed}");
+        let dragged = effectors.force_on(&body(Vec2::new(200.0, 0.0), velocity), GRAVITY, DT);
+        assert_eq!(dragged, Vec2::new(-60.0, 0.0), "10 px/s × 3/s × 2 kg");
+        let stopped = effectors.force_on(&body(Vec2::new(400.0, 0.0), velocity), GRAVITY, DT);
+        assert!((stopped - Vec2::new(-1200.0, 0.0)).length() < 1e-2, "capped at stopping in one step: {stopped}");
+        assert_eq!(effectors.force_on(&body(Vec2::new(100.0, 0.0), velocity), GRAVITY, DT), Vec2::ZERO);
+    }
+}
@@ -0,0 +1,221 @@
+//! Gravity zones: areas that replace or add to the global gravity for the
+//! bodies inside them — planets, fans, underwater sections — without
+//! hand-applied forces.
+//!
+//! A [`GravityZone`] covers a [`ZoneShape`] on its entity. Among the
+//! `Override` zones holding a body the highest priority one replaces global
+//! gravity; every `Add` zone holding it adds on top. The result is scaled by
+//! the body's `gravity_scale`, so a body with a scale of 0 ignores zones too.
+//!
+//! ```
+//! use glam::Vec2;
+//! use physics::{GravityFalloff, GravityZone, ZoneShape};
+//!
+//! // Underwater: weak sinking
+//! let water = GravityZone::directional(ZoneShape::rect(400.0, 100.0), Vec2::new(0.0, -150.0));
+//! // A fan blowing up, on top of normal gravity
+//! let fan = GravityZone::directional(ZoneShape::rect(32.0, 200.0), Vec2::new(0.0, 1500.0)).additive();
+//! // A planet pulling everything within 300px toward its center
+//! let planet = GravityZone::radial(ZoneShape::circle(300.0), 980.0, GravityFalloff::InverseSquare { surface: 80.0 })
+//!     .with_priority(1);
+//! # let _ = (water, fan, planet);
+//! ```
+//!
+//! Zone gravity is applied as a per-step force by the
+//! [effectors](crate::effectors) pass.
+
+use glam::Vec2;
+use serde::{Deserialize, Serialize};
+
+use ecs::World;
+
+use crate::zone::{placed, ZonePlacement, ZoneShape};
+
+/// How a radial zone's pull weakens away from its center.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum GravityFalloff {
+    /// Full strength everywhere in the zone
+    Constant,
+    /// Full strength at the center, fading to zero at the zone's edge
+    Linear,
+    /// Full strength up to `surface` pixels from the center, then
+    /// `strength * (surface / distance)²` — a planet of radius `surface`
+    InverseSquare { surface: f32 },
+}
+
+/// The gravity a zone produces.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum GravityField {
+    /// The same acceleration everywhere (pixels/s²), rotated with the entity
+    Directional(Vec2),
+    /// Toward the zone center at `strength` pixels/s² (negative pushes away)
+    Radial { strength: f32, falloff: GravityFalloff },
+}
+
+/// How a zone combines with the global gravity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum GravityZoneMode {
+    /// Replace global gravity (the highest priority override wins)
+    #[default]
+    Override,
+    /// Add to whatever gravity the body already gets
+    Add,
+}
+
+/// Gravity area component (see the [module docs](self)).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GravityZone {
+    /// Covered area
+    pub shape: ZoneShape,
+    /// Gravity inside the area
+    pub field: GravityField,
+    /// Replace or add to global gravity
+    pub mode: GravityZoneMode,
+    /// Which override wins where overrides overlap (higher first)
+    pub priority: i32,
+}
+
+impl GravityZone {
+    /// A zone with uniform `gravity` (pixels/s²) that replaces global gravity.
+    pub fn directional(shape: ZoneShape, gravity: Vec2) -> Self {
+        Self { shape, field: GravityField::Directional(gravity), mode: GravityZoneMode::Override, priority: 0 }
+    }
+
+    /// A zone pulling toward its center that replaces global gravity.
+    pub fn radial(shape: ZoneShape, strength: f32, falloff: GravityFalloff) -> Self {
+        Self { shape, field: GravityField::Radial { strength, falloff }, mode: GravityZoneMode::Override, priority: 0 }
+    }
+
+    /// Add to global gravity instead of replacing it.
+    pub fn additive(mut self) -> Self {
+        self.mode = GravityZoneMode::Add;
+        self
+    }
+
+    /// Set the override priority.
+    pub fn with_priority(mut self, priority: i32) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Gravity at `local` (zone-local space, pixels), or `None` outside.
+    /// Directional gravity is returned unrotated.
+    fn gravity_at(&self, local: Vec2) -> Option<Vec2> {
+        if !self.shape.contains(local) {
+            return None;
+        }
+        Some(match self.field {
+            GravityField::Directional(gravity) => gravity,
+            GravityField::Radial { strength, falloff } => {
+                let distance = local.length();
+                let scale = match falloff {
+                    GravityFalloff::Constant => 1.0,
+                    GravityFalloff::Linear => 1.0 - distance / self.shape.reach(),
+                    GravityFalloff::InverseSquare { surface } => (surface / distance.max(surface)).powi(2),
+                };
+                -local.normalize_or_zero() * strength * scale
+            }
+        })
+    }
+}
+
+/// Every gravity zone in the world, snapshotted from the ECS once per update.
+#[derive(Debug, Clone, Default)]
+pub struct GravityZones {
+    /// Sorted by descending priority, so the first override found wins
+    zones: Vec<(GravityZone, ZonePlacement)>,
+}
+
+impl GravityZones {
+    /// Snapshot every entity with a [`GravityZone`] and a `Transform2D`.
+    pub fn from_world(world: &World) -> Self {
+        let mut zones = placed::<GravityZone>(world);
+        zones.sort_by_key(|(zone, _)| std::cmp::Reverse(zone.priority));
+        Self { zones }
+    }
+
+    /// Whether there are no zones.
+    pub fn is_empty(&self) -> bool {
+        self.zones.is_empty()
+    }
+
+    /// Gravity at `point` given the `global` gravity (pixels/s², before the
+    /// body's gravity scale).
+    pub fn gravity_at(&self, point: Vec2, global: Vec2) -> Vec2 {
+        let mut overridden = None;
+        let mut added = Vec2::ZERO;
+        for (zone, placement) in &self.zones {
+            if zone.mode == GravityZoneMode::Override && overridden.is_some() {
+                continue;
+            }
+            let Some(gravity) = zone.gravity_at(placement.to_local(point)) else {
+                continue;
+            };
+            let gravity = placement.to_world(gravity);
+            match zone.mode {
+                GravityZoneMode::Override => overridden = Some(gravity),
+                GravityZoneMode::Add => added += gravity,
+            }
+        }
+        overridden.unwrap_or(global) + added
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ecs::sprite_components::Transform2D;
+
+    use super::*;
+
+    fn zones(world: &World) -> GravityZones {
+        GravityZones::from_world(world)
+    }
+
+    #[test]
+    fn test_highest_priority_override_wins_and_add_zones_stack() {
+        let mut world = World::new();
+        let global = Vec2::new(0.0, -980.0);
+        let water = GravityZone::directional(ZoneShape::rect(200.0, 200.0), Vec2::new(0.0, -100.0));
+        world.spawn((Transform2D::new(Vec2::ZERO), water));
+        let space = GravityZone::directional(ZoneShape::circle(20.0), Vec2::ZERO).with_priority(1);
+        world.spawn((Transform2D::new(Vec2::ZERO), space));
+        let fan = GravityZone::directional(ZoneShape::rect(50.0, 200.0), Vec2::new(300.0, 0.0)).additive();
+        world.spawn((Transform2D::new(Vec2::new(50.0, 0.0)), fan));
+        let zones = zones(&world);
+
+        assert_eq!(zones.gravity_at(Vec2::new(500.0, 0.0), global), global, "outside every zone");
+        assert_eq!(zones.gravity_at(Vec2::new(-50.0, 0.0), global), Vec2::new(0.0, -100.0));
+        assert_eq!(zones.gravity_at(Vec2::new(10.0, 0.0), global), Vec2::ZERO, "priority 1 beats the water");
+        assert_eq!(zones.gravity_at(Vec2::new(60.0, 0.0), global), Vec2::new(300.0, -100.0));
+        assert_eq!(zones.gravity_at(Vec2::new(150.0, 90.0), global), global, "the fan only reaches x = 75");
+    }
+
+    #[test]
+    fn test_radial_zone_pulls_toward_center_with_falloff() {
+        let shape = ZoneShape::circle(200.0);
+        let linear = GravityZone::radial(shape, 100.0, GravityFalloff::Linear);
+        let pull = linear.gravity_at(Vec2::new(100.0, 0.0)).unwrap();
+        assert!((pull - Vec2::new(-50.0, 0.0)).length() < 1e-4, "{pull}");
+        assert_eq!(linear.gravity_at(Vec2::new(0.0, 201.0)), None);
+
+        let planet = GravityZone::radial(shape, 100.0, GravityFalloff::InverseSquare { surface: 50.0 });
+        assert!((planet.gravity_at(Vec2::new(0.0, 40.0)).unwrap() - Vec2::new(0.0, -100.0)).length() < 1e-4);
+        assert!((planet.gravity_at(Vec2::new(0.0, 100.0)).unwrap() - Vec2::new(0.0, -25.0)).length() < 1e-4);
+        assert_eq!(planet.gravity_at(Vec2::ZERO), Some(Vec2::ZERO), "no direction at the exact center");
+    }
+
+    #[test]
+    fn test_rotated_rect_zone_turns_its_shape_and_direction() {
+        let mut world = World::new();
+        let mut transform = Transform2D::new(Vec2::ZERO);
+        transform.rotation = std::f32::consts::FRAC_PI_2;
+        let fan = GravityZone::directional(ZoneShape::rect(200.0, 20.0), Vec2::new(100.0, 0.0));
+        world.spawn((transform, fan));
+        let zones = zones(&world);
+
+        let global = Vec2::new(0.0, -980.0);
+        let up = zones.gravity_at(Vec2::new(0.0, 80.0), global);
+        assert!((up - Vec2::new(0.0, 100.0)).length() < 1e-3, "{up}");
+        assert_eq!(zones.gravity_at(Vec2::new(80.0, 0.0), global), global, "the long side now runs along y");
+    }
+}
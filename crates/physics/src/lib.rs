@@ -7,7 +7,10 @@
 //! - Rigid body dynamics (dynamic, static, kinematic bodies)
 //! - Collision detection and response
 //! - Multiple collider shapes (box, circle, capsule)
-//! - Collision events and callbacks
+//! - Named physics materials with per-contact mixing rules
+//! - Collision events and callbacks, optionally filtered by marker components
+//! - Gravity zones (directional or radial) overriding or adding to global gravity
+//! - Effectors: area forces, buoyancy and drag volumes
 //! - Raycasting
 //! - Fixed timestep simulation
 //!
@@ -37,20 +40,32 @@
 //! assert!(transform.position.y < 100.0);
 //! ```
 
+pub mod collision_filter;
 pub mod components;
+pub mod effectors;
+pub mod gravity_zone;
+pub mod material;
 pub mod presets;
 pub mod physics_system;
 pub mod physics_world;
+pub mod validation;
+pub mod zone;
 
 pub mod prelude;
 
 // Re-export main types
 pub use components::{
-    Collider, ColliderShape, CollisionData, CollisionEvent, ContactPoint, RigidBody,
-    RigidBodyType,
+    BodySleep, BodyWake, Collider, ColliderShape, CollisionData, CollisionEvent, ContactPoint,
+    RigidBody, RigidBodyType,
 };
-pub use physics_system::PhysicsSystem;
+pub use collision_filter::{CollisionEventFilter, CollisionEvents};
+pub use effectors::{AreaForce, Buoyancy, Drag, Effectors};
+pub use gravity_zone::{GravityFalloff, GravityField, GravityZone, GravityZoneMode, GravityZones};
+pub use zone::ZoneShape;
+pub use material::{CombineRule, PhysicsMaterial};
+pub use physics_system::{pending_physics_edits, PhysicsSyncTick, PhysicsSystem, StepContext, StepHook};
 pub use physics_world::{PhysicsConfig, PhysicsWorld};
+pub use validation::register_validators;
 
 #[cfg(test)]
 mod tests {
@@ -0,0 +1,205 @@
+//! Physics materials: named friction/restitution presets with per-contact
+//! mixing rules.
+//!
+//! A [`Collider`](crate::Collider) references a material by name
+//! (`Collider::material`) and carries the material's values, so the
+//! simulation never looks materials up. Re-applying a material (the scene
+//! loader does this for every named collider) picks up edits to its
+//! definition.
+//!
+//! When two colliders touch, each coefficient is mixed with the
+//! [`CombineRule`] of higher priority (`Average < Min < Multiply < Max`),
+//! matching rapier: ice (`Min`) on rubber keeps the contact slippery.
+
+use serde::{Deserialize, Serialize};
+
+/// How two colliders' coefficients combine at a contact.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum CombineRule {
+    /// `(a + b) / 2`
+    #[default]
+    Average,
+    /// `min(a, b)`
+    Min,
+    /// `a * b`
+    Multiply,
+    /// `max(a, b)`
+    Max,
+}
+
+impl CombineRule {
+    /// All rules, in priority order (index = [`priority`](Self::priority)).
+    pub const ALL: [CombineRule; 4] = [Self::Average, Self::Min, Self::Multiply, Self::Max];
+
+    /// Display name (also the scene-file spelling).
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Average => "Average",
+            Self::Min => "Min",
+            Self::Multiply => "Multiply",
+            Self::Max => "Max",
+        }
+    }
+
+    /// Precedence when the two colliders of a contact disagree.
+    pub fn priority(self) -> usize {
+        match self {
+            Self::Average => 0,
+            Self::Min => 1,
+            Self::Multiply => 2,
+            Self::Max => 3,
+        }
+    }
+
+    /// The rule a contact between colliders using `self` and `other` uses.
+    pub fn resolve(self, other: CombineRule) -> CombineRule {
+        if other.priority() > self.priority() {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// Combine two coefficients with this rule.
+    pub fn combine(self, a: f32, b: f32) -> f32 {
+        match self {
+            Self::Average => (a + b) / 2.0,
+            Self::Min => a.min(b),
+            Self::Multiply => a * b,
+            Self::Max => a.max(b),
+        }
+    }
+
+    pub(crate) fn to_rapier(self) -> rapier2d::dynamics::CoefficientCombineRule {
+        use rapier2d::dynamics::CoefficientCombineRule as Rapier;
+        match self {
+            Self::Average => Rapier::Average,
+            Self::Min => Rapier::Min,
+            Self::Multiply => Rapier::Multiply,
+            Self::Max => Rapier::Max,
+        }
+    }
+}
+
+/// A named surface material.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PhysicsMaterial {
+    /// Name colliders reference the material by
+    pub name: String,
+    /// Friction coefficient (0.0 = no friction, 1.0 = high friction)
+    pub friction: f32,
+    /// Restitution (bounciness, 0.0 = no bounce, 1.0 = perfect bounce)
+    pub restitution: f32,
+    /// How friction mixes with the other collider's at a contact
+    #[serde(default)]
+    pub friction_combine: CombineRule,
+    /// How restitution mixes with the other collider's at a contact
+    #[serde(default)]
+    pub restitution_combine: CombineRule,
+}
+
+impl Default for PhysicsMaterial {
+    fn default() -> Self {
+        Self::new("default", 0.5, 0.0)
+    }
+}
+
+impl PhysicsMaterial {
+    /// Names of the built-in presets, in [`presets`](Self::presets) order.
+    pub const PRESET_NAMES: [&'static str; 6] = ["default", "ice", "rubber", "metal", "wood", "bouncy"];
+
+    /// Create a material with `Average` mixing; values are clamped to 0..=1.
+    pub fn new(name: impl Into<String>, friction: f32, restitution: f32) -> Self {
+        Self {
+            name: name.into(),
+            friction: friction.clamp(0.0, 1.0),
+            restitution: restitution.clamp(0.0, 1.0),
+            friction_combine: CombineRule::Average,
+            restitution_combine: CombineRule::Average,
+        }
+    }
+
+    /// Set the friction mixing rule
+    pub fn with_friction_combine(mut self, rule: CombineRule) -> Self {
+        self.friction_combine = rule;
+        self
+    }
+
+    /// Set the restitution mixing rule
+    pub fn with_restitution_combine(mut self, rule: CombineRule) -> Self {
+        self.restitution_combine = rule;
+        self
+    }
+
+    /// The built-in material presets.
+    pub fn presets() -> Vec<PhysicsMaterial> {
+        Self::PRESET_NAMES.iter().filter_map(|name| Self::preset(name)).collect()
+    }
+
+    /// Look up a built-in preset by name.
+    pub fn preset(name: &str) -> Option<PhysicsMaterial> {
+        let material = match name {
+            "default" => Self::default(),
+            // Min mixing: ice stays slippery whatever it touches
+            "ice" => Self::new(name, 0.02, 0.05).with_friction_combine(CombineRule::Min),
+            // Max mixing: rubber grips and bounces off anything
+            "rubber" => Self::new(name, 0.9, 0.6)
+                .with_friction_combine(CombineRule::Max)
+                .with_restitution_combine(CombineRule::Max),
+            "metal" => Self::new(name, 0.3, 0.1),
+            "wood" => Self::new(name, 0.6, 0.2),
+            "bouncy" => Self::new(name, 0.3, 0.95).with_restitution_combine(CombineRule::Max),
+            _ => return None,
+        };
+        Some(material)
+    }
+
+    /// Effective `(friction, restitution)` of a contact between two colliders
+    /// made of `self` and `other`.
+    pub fn mix(&self, other: &PhysicsMaterial) -> (f32, f32) {
+        let friction = self.friction_combine.resolve(other.friction_combine).combine(self.friction, other.friction);
+        let restitution = self
+            .restitution_combine
+            .resolve(other.restitution_combine)
+            .combine(self.restitution, other.restitution);
+        (friction, restitution)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_presets_are_complete_and_named() {
+        let presets = PhysicsMaterial::presets();
+        assert_eq!(presets.len(), PhysicsMaterial::PRESET_NAMES.len());
+        for (preset, name) in presets.iter().zip(PhysicsMaterial::PRESET_NAMES) {
+            assert_eq!(preset.name, name);
+        }
+        assert_eq!(PhysicsMaterial::preset("lava"), None);
+    }
+
+    #[test]
+    fn test_higher_priority_rule_wins() {
+        assert_eq!(CombineRule::Average.resolve(CombineRule::Min), CombineRule::Min);
+        assert_eq!(CombineRule::Max.resolve(CombineRule::Multiply), CombineRule::Max);
+        for (index, rule) in CombineRule::ALL.iter().enumerate() {
+            assert_eq!(rule.priority(), index);
+        }
+    }
+
+    #[test]
+    fn test_mixing_uses_resolved_rule() {
+        let ice = PhysicsMaterial::preset("ice").unwrap();
+        let wood = PhysicsMaterial::preset("wood").unwrap();
+        let (friction, restitution) = ice.mix(&wood);
+        assert_eq!(friction, 0.02, "Min friction from ice wins over wood's Average");
+        assert!((restitution - 0.125).abs() < 1e-6);
+        assert_eq!(ice.mix(&wood), wood.mix(&ice), "mixing is symmetric");
+
+        let rubber = PhysicsMaterial::preset("rubber").unwrap();
+        assert_eq!(rubber.mix(&ice).0, 0.9, "Max outranks Min");
+        assert_eq!(CombineRule::Multiply.combine(0.5, 0.4), 0.2);
+    }
+}
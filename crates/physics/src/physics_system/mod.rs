@@ -6,6 +6,7 @@
 //! - `mod.rs` — struct, builders, pass-through API
 //! - `sync.rs` — ECS↔rapier synchronization and orphan garbage collection
 //! - `update.rs` — the `System` trait implementation (fixed-timestep driver)
+//! - `step_hooks.rs` — pre/post step callbacks run around every fixed step
 //!
 //! # Collision Event Delivery
 //!
@@ -17,6 +18,23 @@
 //!   physics system is held while reacting, so handlers can freely call
 //!   `set_velocity` / `destroy_entity` / etc.
 //!
+//! Dynamic bodies falling asleep or waking up emit
+//! [`BodySleep`](crate::components::BodySleep) /
+//! [`BodyWake`](crate::components::BodyWake) on the world event bus during
+//! the writeback, and mirror the state into [`RigidBody::is_sleeping`].
+//!
+//! With [`PhysicsConfig::interpolate`] set, the writeback blends each moving
+//! body from its pose before the last step toward its current pose by the
+//! leftover accumulator fraction, so `Transform2D` moves smoothly between
+//! fixed steps. Teleports (external edits, `reset_body`) snap instead.
+//!
+//! # Step Hooks
+//!
+//! [`on_pre_step`](PhysicsSystem::on_pre_step) and
+//! [`on_post_step`](PhysicsSystem::on_post_step) register callbacks that run
+//! right before and after every fixed step, for gameplay that must act on
+//! the simulation at its own rate (see [`StepContext`]).
+//!
 //! # API Design: Pass-Through Methods
 //!
 //! [`PhysicsSystem`] provides several methods that delegate directly to [`PhysicsWorld`]:
@@ -24,7 +42,8 @@
 //! - `set_velocity()` — the single, universal "launch / move this body at
 //!   velocity V" API. Safe on bodies spawned this frame (defers until synced).
 //! - `apply_force()`
-//! - `raycast()`
+//! - `wake_up()` / `is_sleeping()`
+//! - `raycast()` / `overlap_circle()` / `overlap_point()`
 //! - `take_collision_events()`
 //!
 //! These pass-through methods exist intentionally for **API ergonomics**:
@@ -50,13 +69,22 @@
 //! `PhysicsWorld::apply_impulse` remains for the rare case that genuinely
 //! needs mass-aware impulse semantics on a live body.
 //!
+//! Code holding the `RigidBody` component instead of the system can queue
+//! the same requests on it — [`RigidBody::set_velocity`],
+//! [`RigidBody::apply_impulse`] (deferred-safe, unlike the world's) and
+//! [`RigidBody::add_force`] — applied during the next `update()`'s sync.
+//!
 //! Users who need advanced physics operations can still access the underlying
 //! [`PhysicsWorld`] via [`physics_world()`](PhysicsSystem::physics_world) and
 //! [`physics_world_mut()`](PhysicsSystem::physics_world_mut).
 
+mod step_hooks;
 mod sync;
 mod update;
 
+pub use step_hooks::{StepContext, StepHook};
+pub use sync::pending_physics_edits;
+
 #[cfg(test)]
 mod tests;
 
@@ -66,9 +94,11 @@ use glam::Vec2;
 
 use ecs::{EntityId, World};
 
-use crate::components::{Collider, CollisionData};
+use crate::components::{Collider, CollisionData, RigidBody};
 use crate::physics_world::{PhysicsConfig, PhysicsWorld};
 
+use self::step_hooks::StepHooks;
+
 /// A body operation deferred because the entity wasn't synced into rapier
 /// yet (same-frame spawn). Drained in call order during the next `update()`,
 /// so the documented "reset then launch" pattern applies the reset first and
@@ -93,10 +123,37 @@ struct PushedState {
     position: Vec2,
     /// Body rotation as last pushed/read back (radians).
     rotation: f32,
+    /// Body linear velocity as last pushed/read back (pixels/second).
+    velocity: Vec2,
+    /// Body angular velocity as last pushed/read back (radians/second).
+    angular_velocity: f32,
+    /// `RigidBody::can_sleep` as last pushed.
+    can_sleep: bool,
     /// Collider component as last built into rapier (None = no collider).
     collider: Option<Collider>,
 }
 
+impl PushedState {
+    /// Baseline for a body just created from `body` (no collider yet).
+    fn new(position: Vec2, rotation: f32, body: &RigidBody) -> Self {
+        Self {
+            position,
+            rotation,
+            velocity: body.velocity,
+            angular_velocity: body.angular_velocity,
+            can_sleep: body.can_sleep,
+            collider: None,
+        }
+    }
+}
+
+/// World resource holding the change tick taken right after the last
+/// physics writeback (inserted by `PhysicsSystem::update`). Components
+/// changed after it are ECS-side edits rapier hasn't seen yet — see
+/// [`pending_physics_edits`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PhysicsSyncTick(pub u32);
+
 /// Maximum number of fixed-timestep catch-up steps in a single update.
 ///
 /// Bounds the work done after a stall regardless of how small the
@@ -122,6 +179,15 @@ pub struct PhysicsSystem {
     baselines: HashMap<EntityId, PushedState>,
     /// How many external edits were pushed into rapier during the last update.
     pushed_edits_last_update: usize,
+    /// World change tick at the end of the last writeback; only entities
+    /// whose physics components changed after it are re-synced. `None`
+    /// forces a full sync (first update, after `clear`).
+    synced_tick: Option<u32>,
+    /// Body poses (pixels, radians) before the most recent step; only
+    /// captured while interpolating.
+    previous_poses: HashMap<EntityId, (Vec2, f32)>,
+    /// Callbacks run around every fixed step
+    step_hooks: StepHooks,
 }
 
 impl PhysicsSystem {
@@ -140,6 +206,9 @@ impl PhysicsSystem {
             pending_ops: Vec::new(),
             baselines: HashMap::new(),
             pushed_edits_last_update: 0,
+            synced_tick: None,
+            previous_poses: HashMap::new(),
+            step_hooks: StepHooks::default(),
         }
     }
 
@@ -149,9 +218,46 @@ impl PhysicsSystem {
         self
     }
 
+    /// Set the fixed timestep of a running system.
+    pub fn set_fixed_timestep(&mut self, timestep: f32) {
+        self.fixed_timestep = timestep;
+    }
+
+    /// Seconds simulated per fixed step.
+    pub fn fixed_timestep(&self) -> f32 {
+        self.fixed_timestep
+    }
+
+    /// Run exactly one fixed step, whatever time has accumulated (the
+    /// editor's frame stepping). Syncs and emits events like `update`, but
+    /// writes back the stepped poses even when interpolating.
+    pub fn step_once(&mut self, world: &mut World) {
+        self.time_accumulator = 0.0;
+        ecs::System::update(self, world, self.fixed_timestep);
+        if self.physics_world.config().interpolate {
+            // With no leftover time the blend would show the pose before
+            // the step
+            self.previous_poses.clear();
+            self.sync_physics_to_ecs(world);
+            let synced_tick = world.increment_change_tick();
+            self.synced_tick = Some(synced_tick);
+            world.insert_resource(PhysicsSyncTick(synced_tick));
+        }
+    }
+
+    /// Replace the configuration (gravity, scale, iterations, substeps).
+    ///
+    /// Rebuilds the physics world, so like [`clear`](Self::clear) every body
+    /// re-registers from the ECS on the next update — at the new scale. The
+    /// fixed timestep and step hooks are kept.
+    pub fn set_config(&mut self, config: PhysicsConfig) {
+        self.clear();
+        self.physics_world = PhysicsWorld::new(config);
+    }
+
     /// Clear all physics state, forcing re-sync from ECS on next update.
     ///
-    /// Preserves configuration (gravity, scale) but resets all rapier
+    /// Preserves configuration (gravity, scale) and step hooks but resets all rapier
     /// bodies, colliders, and entity mappings. Call this when the editor
     /// restores a world snapshot to ensure physics re-initializes from the
     /// restored ECS component values.
@@ -159,7 +265,28 @@ impl PhysicsSystem {
         self.physics_world.clear();
         self.pending_ops.clear();
         self.baselines.clear();
+        self.synced_tick = None;
         self.time_accumulator = 0.0;
+        self.previous_poses.clear();
+        self.step_hooks.clear_forces();
+    }
+
+    /// Run `hook` right before every fixed step, e.g. to apply thrust with
+    /// [`StepContext::apply_force`] at the simulation's rate. Hooks run in
+    /// registration order.
+    pub fn on_pre_step(&mut self, hook: impl FnMut(&mut StepContext) + Send + Sync + 'static) {
+        self.step_hooks.pre.push(Box::new(hook));
+    }
+
+    /// Run `hook` right after every fixed step, e.g. to read or clamp the
+    /// velocities it produced. Hooks run in registration order.
+    pub fn on_post_step(&mut self, hook: impl FnMut(&mut StepContext) + Send + Sync + 'static) {
+        self.step_hooks.post.push(Box::new(hook));
+    }
+
+    /// Unregister every pre- and post-step hook.
+    pub fn clear_step_hooks(&mut self) {
+        self.step_hooks = StepHooks::default();
     }
 
     /// How many external ECS-side edits (Transform2D teleports, collider
@@ -178,6 +305,7 @@ impl PhysicsSystem {
         world.remove_entity(&entity).ok();
         self.pending_ops.retain(|(e, _)| *e != entity);
         self.baselines.remove(&entity);
+        self.previous_poses.remove(&entity);
     }
 
     /// Get a reference to the physics world
@@ -210,6 +338,17 @@ impl PhysicsSystem {
         self.physics_world.apply_force(entity, force);
     }
 
+    /// Wake a sleeping body (no-op for unknown or already-awake bodies).
+    /// The next update reports the transition as a `BodyWake` event.
+    pub fn wake_up(&mut self, entity: EntityId) {
+        self.physics_world.wake_up(entity);
+    }
+
+    /// Whether the entity's body is asleep right now (`false` without a body).
+    pub fn is_sleeping(&self, entity: EntityId) -> bool {
+        self.physics_world.is_sleeping(entity).unwrap_or(false)
+    }
+
     /// Cast a ray and return the first hit as `(entity, hit_point, distance)`.
     ///
     /// `direction` is normalized internally; `max_distance` and the returned
@@ -219,6 +358,22 @@ impl PhysicsSystem {
         self.physics_world.raycast(origin, direction, max_distance)
     }
 
+    /// Every solid collider on `layers` a ray passes through, as
+    /// `(entity, distance)` nearest first (see [`PhysicsWorld::raycast_all`]).
+    pub fn raycast_all(&self, origin: Vec2, direction: Vec2, max_distance: f32, layers: u32) -> Vec<(EntityId, f32)> {
+        self.physics_world.raycast_all(origin, direction, max_distance, layers)
+    }
+
+    /// Entities whose colliders overlap a circle of `radius` pixels at `center`.
+    pub fn overlap_circle(&self, center: Vec2, radius: f32) -> Vec<EntityId> {
+        self.physics_world.overlap_circle(center, radius)
+    }
+
+    /// Entities whose colliders contain `point`.
+    pub fn overlap_point(&self, point: Vec2) -> Vec<EntityId> {
+        self.physics_world.overlap_point(point)
+    }
+
     /// Take the collision events from the last update's physics steps,
     /// leaving the buffer empty.
     ///
@@ -256,6 +411,7 @@ impl PhysicsSystem {
     /// Set the position and rotation of a rigid body
     pub fn set_body_transform(&mut self, entity: EntityId, position: Vec2, rotation: f32) {
         self.physics_world.set_body_transform(entity, position, rotation);
+        self.previous_poses.remove(&entity);
     }
 
     /// Set the next kinematic position (for kinematic bodies)
@@ -273,10 +429,29 @@ impl PhysicsSystem {
         if self.physics_world.has_rigid_body(entity) {
             self.physics_world.set_body_transform(entity, position, 0.0);
             self.physics_world.set_velocity(entity, Vec2::ZERO, 0.0);
+            self.previous_poses.remove(&entity);
         } else {
             self.pending_ops.push((entity, DeferredBodyOp::Reset { position }));
         }
     }
+
+    /// Follow a floating-origin shift (`ecs::shift_world_origin` with the
+    /// same `shift`): move every body by `-shift` along with the sync
+    /// baselines, so the shifted transforms aren't read as teleports.
+    pub fn shift_origin(&mut self, shift: Vec2) {
+        self.physics_world.shift_origin(shift);
+        for baseline in self.baselines.values_mut() {
+            baseline.position -= shift;
+        }
+        for (position, _) in self.previous_poses.values_mut() {
+            *position -= shift;
+        }
+        for (_, op) in &mut self.pending_ops {
+            if let DeferredBodyOp::Reset { position } = op {
+                *position -= shift;
+            }
+        }
+    }
 }
 
 impl Default for PhysicsSystem {
@@ -0,0 +1,144 @@
+//! Pre/post step callbacks: gameplay code inside the fixed-timestep loop.
+//!
+//! One `update()` runs zero to eight fixed steps, and ECS components only
+//! see the result after the last one. A pre-step hook runs right before
+//! every step (thrust, steering, custom forces) and a post-step hook right
+//! after it (read velocities, clamp speeds), so gameplay lines up with the
+//! simulation instead of lagging a frame behind it. Hooks work on bodies
+//! through [`StepContext::physics`]; the world's `Transform2D`/`RigidBody`
+//! components still hold last frame's values until the writeback after the
+//! final step.
+
+use glam::Vec2;
+
+use ecs::{EntityId, World};
+
+use crate::effectors::Effectors;
+use crate::physics_world::PhysicsWorld;
+
+/// What a step hook can see and touch.
+pub struct StepContext<'a> {
+    /// The simulation; body state here is current as of this step
+    pub physics: &'a mut PhysicsWorld,
+    /// The ECS world (physics components are written back after the last step)
+    pub world: &'a mut World,
+    /// Fixed timestep in seconds
+    pub dt: f32,
+    /// Index of this step within the current update, from 0
+    pub step: u32,
+    /// Forces for the next step only
+    forces: &'a mut Vec<(EntityId, Vec2)>,
+}
+
+impl StepContext<'_> {
+    /// Apply a force for the next step only. `PhysicsWorld::apply_force`
+    /// instead lasts every remaining step of the update, so calling it from
+    /// a pre-step hook would stack the force once per step.
+    pub fn apply_force(&mut self, entity: EntityId, force: Vec2) {
+        self.forces.push((entity, force));
+    }
+}
+
+/// A registered pre- or post-step callback.
+pub type StepHook = Box<dyn FnMut(&mut StepContext) + Send + Sync>;
+
+/// Registered hooks plus the per-step forces they queued.
+#[derive(Default)]
+pub(super) struct StepHooks {
+    pub(super) pre: Vec<StepHook>,
+    pub(super) post: Vec<StepHook>,
+    /// Queued by `StepContext::apply_force` for the next step; forces
+    /// queued by the last post-step hook of an update wait for the next
+    /// update's first step
+    forces: Vec<(EntityId, Vec2)>,
+}
+
+impl StepHooks {
+    /// Drop queued per-step forces (hooks stay registered).
+    pub(super) fn clear_forces(&mut self) {
+        self.forces.clear();
+    }
+
+    /// Run one fixed step of `dt` seconds with the hooks around it. Effector
+    /// forces join the hooks' per-step forces.
+    pub(super) fn step(
+        &mut self,
+        physics: &mut PhysicsWorld,
+        world: &mut World,
+        effectors: &Effectors,
+        dt: f32,
+        step: u32,
+    ) {
+        let Self { pre, post, forces } = self;
+        for hook in pre.iter_mut() {
+            hook(&mut StepContext { physics: &mut *physics, world: &mut *world, dt, step, forces: &mut *forces });
+        }
+        forces.extend(effectors.forces(physics, dt));
+
+        for &(entity, force) in forces.iter() {
+            physics.apply_force(entity, force);
+        }
+        physics.step(dt);
+        // Take the per-step forces back out, leaving forces applied
+        // for the whole update in place
+        for (entity, force) in forces.drain(..) {
+            physics.apply_force(entity, -force);
+        }
+
+        for hook in post.iter_mut() {
+            hook(&mut StepContext { physics: &mut *physics, world: &mut *world, dt, step, forces: &mut *forces });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use ecs::sprite_components::Transform2D;
+    use ecs::System;
+
+    use crate::components::{Collider, RigidBody};
+    use crate::physics_world::PhysicsConfig;
+    use crate::PhysicsSystem;
+
+    use super::*;
+
+    #[test]
+    fn test_hooks_wrap_every_step_and_per_step_forces_do_not_stack() {
+        let mut world = World::new();
+        let ship = world
+            .spawn((Transform2D::new(Vec2::ZERO), RigidBody::new_dynamic(), Collider::circle_collider(8.0)))
+            .id();
+        let mut system = PhysicsSystem::with_config(PhysicsConfig::new(Vec2::ZERO));
+        system.update(&mut world, 0.0);
+
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let pre_log = Arc::clone(&log);
+        system.on_pre_step(move |ctx| {
+            ctx.apply_force(ship, Vec2::new(1000.0, 0.0));
+            pre_log.lock().unwrap().push(format!("pre {}", ctx.step));
+        });
+        let post_log = Arc::clone(&log);
+        system.on_post_step(move |ctx| {
+            let speed = ctx.physics.get_body_velocity(ship).unwrap().0.x;
+            post_log.lock().unwrap().push(format!("post {} {speed:.3}", ctx.step));
+        });
+
+        // Three fixed steps in one update
+        system.update(&mut world, 3.0 / 60.0 + 0.001);
+        let log = log.lock().unwrap();
+        let order: Vec<&str> = log.iter().map(|line| &line[..line.len().min(6)]).collect();
+        assert_eq!(order, ["pre 0", "post 0", "pre 1", "post 1", "pre 2", "post 2"]);
+
+        // Constant acceleration: equal speed gains per step, not 1x, 2x, 3x
+        let speeds: Vec<f32> = log
+            .iter()
+            .filter(|line| line.starts_with("post"))
+            .map(|line| line.rsplit(' ').next().unwrap().parse().unwrap()).collect();
+        let gains = [speeds[0], speeds[1] - speeds[0], speeds[2] - speeds[1]];
+        assert!(gains[0] > 0.0);
+        assert!(gains.iter().all(|gain| (gain - gains[0]).abs() < gains[0] * 0.01), "{gains:?}");
+        assert!((world.get::<RigidBody>(ship).unwrap().velocity.x - speeds[2]).abs() < 1e-3);
+    }
+}
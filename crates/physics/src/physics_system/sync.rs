@@ -8,17 +8,21 @@
 //! baseline, so editing `Transform2D` teleports the live body and editing
 //! `Collider` rebuilds its rapier collider. The writeback refreshes the
 //! baseline, so rapier-driven motion is never mistaken for an edit.
+//! World change ticks narrow the per-frame work to entities whose physics
+//! components were touched; the baseline compare then filters out
+//! `get_mut` accesses that did not actually change anything.
 
 use std::collections::HashSet;
+use std::f32::consts::{PI, TAU};
 
 use glam::Vec2;
 
 use ecs::sprite_components::Transform2D;
 use ecs::{EntityId, World};
 
-use crate::components::{Collider, RigidBody, RigidBodyType};
+use crate::components::{BodyCommands, BodySleep, BodyWake, Collider, RigidBody, RigidBodyType};
 
-use super::{PhysicsSystem, PushedState};
+use super::{PhysicsSyncTick, PhysicsSystem, PushedState};
 
 impl PhysicsSystem {
     /// Garbage-collect physics state for entities that were removed from
@@ -38,6 +42,23 @@ impl PhysicsSystem {
         }
         self.pending_ops.retain(|(e, _)| alive.contains(e));
         self.baselines.retain(|e, _| alive.contains(e));
+        self.previous_poses.retain(|e, _| alive.contains(e));
+    }
+
+    /// Whether `entity` may be out of sync with rapier: not yet tracked, or
+    /// its `Transform2D`/`RigidBody`/`Collider` changed since the last
+    /// writeback (or its collider was removed, which leaves no change tick).
+    pub(super) fn needs_sync(&self, world: &World, entity: EntityId) -> bool {
+        let Some(since) = self.synced_tick else {
+            return true;
+        };
+        if !self.baselines.contains_key(&entity) {
+            return world.get::<RigidBody>(entity).is_some() || world.get::<Collider>(entity).is_some();
+        }
+        world.is_changed_since::<Transform2D>(entity, since)
+            || world.is_changed_since::<RigidBody>(entity, since)
+            || world.is_changed_since::<Collider>(entity, since)
+            || (self.physics_world.has_collider(entity) && world.get::<Collider>(entity).is_none())
     }
 
     /// Sync a single entity from ECS to physics world.
@@ -68,8 +89,7 @@ impl PhysicsSystem {
                     body.handle = rigid_body.handle;
                 }
                 // Fresh baseline for the new body (collider recorded below).
-                self.baselines
-                    .insert(entity, PushedState { position, rotation, collider: None });
+                self.baselines.insert(entity, PushedState::new(position, rotation, &rigid_body));
             } else {
                 match self.baselines.get_mut(&entity) {
                     Some(baseline) => {
@@ -78,22 +98,48 @@ impl PhysicsSystem {
                             && (baseline.position != position || baseline.rotation != rotation)
                         {
                             self.physics_world.set_body_transform(entity, position, rotation);
+                            self.previous_poses.remove(&entity);
                             baseline.position = position;
                             baseline.rotation = rotation;
                             self.pushed_edits_last_update += 1;
                         }
+                        // External velocity edit (inspector, paused editor)
+                        // → set the live body's velocity. Only dynamic bodies
+                        // read velocity back, so only they can be compared.
+                        // A queued `RigidBody::set_velocity` is flushed below.
+                        if rigid_body.body_type == RigidBodyType::Dynamic
+                            && rigid_body.commands.velocity.is_none()
+                            && (baseline.velocity != rigid_body.velocity
+                                || baseline.angular_velocity != rigid_body.angular_velocity)
+                        {
+                            self.physics_world.set_velocity(
+                                entity,
+                                rigid_body.velocity,
+                                rigid_body.angular_velocity,
+                            );
+                            baseline.velocity = rigid_body.velocity;
+                            baseline.angular_velocity = rigid_body.angular_velocity;
+                            self.pushed_edits_last_update += 1;
+                        }
+                        // Sleeping toggled in the inspector or by game code.
+                        if baseline.can_sleep != rigid_body.can_sleep {
+                            self.physics_world.set_can_sleep(entity, rigid_body.can_sleep);
+                            baseline.can_sleep = rigid_body.can_sleep;
+                            self.pushed_edits_last_update += 1;
+                        }
                     }
                     // Body exists but was never baselined (defensive) —
                     // adopt the current state without pushing anything.
                     None => {
-                        self.baselines
-                            .insert(entity, PushedState { position, rotation, collider: None });
+                        self.baselines.insert(entity, PushedState::new(position, rotation, &rigid_body));
                     }
                 }
             }
 
             // Collider: add when missing, rebuild when edited, drop when removed.
             self.sync_collider(world, entity, true);
+            // After the collider, so impulses see the body's full mass.
+            self.flush_body_commands(world, entity, &rigid_body.commands);
         } else if world.get::<Collider>(entity).is_some() {
             // Collider without rigid body (static collision geometry).
             // Note: standalone colliders are placed by their offset only, so
@@ -102,12 +148,40 @@ impl PhysicsSystem {
             self.baselines.entry(entity).or_insert(PushedState {
                 position: Vec2::ZERO,
                 rotation: 0.0,
+                velocity: Vec2::ZERO,
+                angular_velocity: 0.0,
+                can_sleep: true,
                 collider: None,
             });
             self.sync_collider(world, entity, false);
         }
     }
 
+    /// Apply the requests queued on the entity's `RigidBody`
+    /// (`set_velocity`, then `apply_impulse`, then `add_force`) to its live
+    /// body and clear them from the component.
+    fn flush_body_commands(&mut self, world: &mut World, entity: EntityId, commands: &BodyCommands) {
+        if commands.is_empty() {
+            return;
+        }
+        if let Some((linear, angular)) = commands.velocity {
+            self.physics_world.set_velocity(entity, linear, angular);
+            if let Some(baseline) = self.baselines.get_mut(&entity) {
+                baseline.velocity = linear;
+                baseline.angular_velocity = angular;
+            }
+        }
+        if commands.impulse != Vec2::ZERO {
+            self.physics_world.apply_impulse(entity, commands.impulse);
+        }
+        if commands.force != Vec2::ZERO {
+            self.physics_world.apply_force(entity, commands.force);
+        }
+        if let Some(body) = world.get_mut::<RigidBody>(entity) {
+            body.commands = BodyCommands::default();
+        }
+    }
+
     /// Add / rebuild / remove the rapier collider to match the ECS component.
     fn sync_collider(&mut self, world: &mut World, entity: EntityId, attach_to_body: bool) {
         if let Some(mut collider) = world.get::<Collider>(entity).cloned() {
@@ -142,8 +216,11 @@ impl PhysicsSystem {
 
     /// Sync physics results back to ECS transforms (and refresh the
     /// external-edit baselines so this writeback isn't detected as an edit).
+    /// Dynamic bodies whose sleep state changed emit `BodySleep`/`BodyWake`.
     pub(super) fn sync_physics_to_ecs(&mut self, world: &mut World) {
         let entities: Vec<EntityId> = world.entities();
+        let tick = world.simulation_tick();
+        let blend = self.interpolation_alpha();
 
         for entity in entities {
             // Get body type first to avoid borrow conflicts
@@ -154,7 +231,11 @@ impl PhysicsSystem {
                 // Static bodies don't move, so no need to sync them
                 if body_type == RigidBodyType::Dynamic || body_type == RigidBodyType::Kinematic {
                     // Get physics transform
-                    if let Some((position, rotation)) = self.physics_world.get_body_transform(entity) {
+                    if let Some(pose) = self.physics_world.get_body_transform(entity) {
+                        let (position, rotation) = match (blend, self.previous_poses.get(&entity)) {
+                            (Some(alpha), Some(&previous)) => interpolate_pose(previous, pose, alpha),
+                            _ => pose,
+                        };
                         // Update ECS transform
                         if let Some(transform) = world.get_mut::<Transform2D>(entity) {
                             transform.position = position;
@@ -171,9 +252,24 @@ impl PhysicsSystem {
                     // Update velocity in component (for dynamic bodies)
                     if body_type == RigidBodyType::Dynamic {
                         if let Some((linear_vel, angular_vel)) = self.physics_world.get_body_velocity(entity) {
+                            let sleeping = self.physics_world.is_sleeping(entity).unwrap_or(false);
+                            let mut sleep_changed = None;
                             if let Some(rigid_body) = world.get_mut::<RigidBody>(entity) {
                                 rigid_body.velocity = linear_vel;
                                 rigid_body.angular_velocity = angular_vel;
+                                if rigid_body.sleeping != sleeping {
+                                    rigid_body.sleeping = sleeping;
+                                    sleep_changed = Some(sleeping);
+                                }
+                            }
+                            match sleep_changed {
+                                Some(true) => world.emit_event(BodySleep { entity, tick }),
+                                Some(false) => world.emit_event(BodyWake { entity, tick }),
+                                None => {}
+                            }
+                            if let Some(baseline) = self.baselines.get_mut(&entity) {
+                                baseline.velocity = linear_vel;
+                                baseline.angular_velocity = angular_vel;
                             }
                         }
                     }
@@ -181,4 +277,51 @@ impl PhysicsSystem {
             }
         }
     }
+
+    /// Record every body's pose before a step, for interpolation.
+    pub(super) fn capture_previous_poses(&mut self) {
+        self.previous_poses.clear();
+        for entity in self.physics_world.tracked_entities() {
+            if let Some(pose) = self.physics_world.get_body_transform(entity) {
+                self.previous_poses.insert(entity, pose);
+            }
+        }
+    }
+
+    /// How far the writeback blends from the previous pose toward the
+    /// current one: the leftover accumulator fraction, or `None` when
+    /// interpolation is off.
+    fn interpolation_alpha(&self) -> Option<f32> {
+        (self.physics_world.config().interpolate && self.fixed_timestep > 0.0)
+            .then(|| (self.time_accumulator / self.fixed_timestep).clamp(0.0, 1.0))
+    }
+}
+
+/// Blend two body poses, turning through the shorter way round.
+fn interpolate_pose(from: (Vec2, f32), to: (Vec2, f32), alpha: f32) -> (Vec2, f32) {
+    let turn = (to.1 - from.1 + PI).rem_euclid(TAU) - PI;
+    (from.0.lerp(to.0, alpha), from.1 + turn * alpha)
+}
+
+/// Physics entities with ECS-side edits rapier hasn't seen yet: their
+/// `Transform2D`, `RigidBody`, or `Collider` changed after the last physics
+/// writeback ([`PhysicsSyncTick`]). The next `PhysicsSystem::update` pushes
+/// these edits before stepping — the editor marks them while paused so a
+/// resume doesn't surprise anyone. Conservative like all change ticks: a
+/// value written back unchanged still counts. Empty before the first update.
+pub fn pending_physics_edits(world: &World) -> Vec<EntityId> {
+    let Some(&PhysicsSyncTick(since)) = world.resource::<PhysicsSyncTick>() else {
+        return Vec::new();
+    };
+    let mut entities: Vec<EntityId> = world
+        .entity_ids()
+        .filter(|&entity| {
+            world.get::<RigidBody>(entity).is_some()
+                && (world.is_changed_since::<Transform2D>(entity, since)
+                    || world.is_changed_since::<RigidBody>(entity, since)
+                    || world.is_changed_since::<Collider>(entity, since))
+        })
+        .collect();
+    entities.sort_by_key(|e| e.value());
+    entities
 }
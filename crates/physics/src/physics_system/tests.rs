@@ -2,7 +2,7 @@
 use glam::Vec2;
 
 use ecs::sprite_components::Transform2D;
-use ecs::{System, World};
+use ecs::{Inactive, System, World};
 
 use crate::components::{Collider, RigidBody};
 use crate::physics_world::PhysicsConfig;
@@ -170,6 +170,28 @@ fn test_gravity_affects_dynamic_body() {
     assert!(final_y < initial_y, "Entity should have fallen due to gravity");
 }
 
+#[test]
+fn test_inactive_entity_keeps_a_disabled_body() {
+    let mut world = World::new();
+    let mut system = PhysicsSystem::new();
+    let entity = world.spawn((Transform2D::new(Vec2::new(0.0, 100.0)), RigidBody::new_dynamic(), Inactive)).id();
+
+    system.initialize(&mut world).unwrap();
+    for _ in 0..5 {
+        system.update(&mut world, 1.0 / 60.0);
+    }
+    assert!(system.physics_world().has_rigid_body(entity), "registered while parked");
+    assert!(!system.physics_world().is_entity_enabled(entity));
+    assert_eq!(world.get::<Transform2D>(entity).unwrap().position.y, 100.0, "parked bodies don't fall");
+
+    world.remove_component::<Inactive>(&entity).unwrap();
+    for _ in 0..5 {
+        system.update(&mut world, 1.0 / 60.0);
+    }
+    assert!(system.physics_world().is_entity_enabled(entity));
+    assert!(world.get::<Transform2D>(entity).unwrap().position.y < 100.0);
+}
+
 #[test]
 fn test_static_body_does_not_move() {
     let mut world = World::new();
@@ -248,6 +270,30 @@ fn test_clear_allows_resync_from_ecs() {
     assert_eq!(pos, Vec2::new(0.0, 100.0), "Position should match restored ECS state");
 }
 
+#[test]
+fn test_set_config_rebuilds_bodies_with_the_new_settings() {
+    let mut world = World::new();
+    let mut system = PhysicsSystem::new();
+
+    let entity = world.create_entity();
+    world.add_component(&entity, Transform2D::new(Vec2::new(0.0, 100.0))).unwrap();
+    world.add_component(&entity, RigidBody::new_dynamic()).unwrap();
+    world.add_component(&entity, Collider::box_collider(32.0, 32.0)).unwrap();
+    system.initialize(&mut world).unwrap();
+    system.update(&mut world, 1.0 / 60.0);
+
+    system.set_config(PhysicsConfig::new(Vec2::ZERO).with_iterations(4, 2));
+    system.set_fixed_timestep(1.0 / 120.0);
+    assert!(!system.physics_world().has_rigid_body(entity));
+    assert_eq!(system.physics_world().config().solver_iterations, 4);
+    assert_eq!(system.gravity(), Vec2::ZERO);
+    assert_eq!(system.fixed_timestep, 1.0 / 120.0);
+
+    // Re-registered from the ECS on the next update
+    system.update(&mut world, 1.0 / 60.0);
+    assert!(system.physics_world().has_rigid_body(entity));
+}
+
 // === Collision event delivery tests ===
 
 /// Create a world with two overlapping no-gravity bodies.
@@ -357,6 +403,17 @@ fn test_take_collision_events_drains_the_buffer() {
     );
 }
 
+#[test]
+fn test_collision_events_carry_the_world_simulation_tick() {
+    let (mut world, mut system) = overlapping_pair();
+    world.insert_resource(ecs::SimulationTick(7));
+    system.update(&mut world, 1.0 / 60.0);
+
+    let events = system.take_collision_events();
+    assert!(!events.is_empty());
+    assert!(events.iter().all(|e| e.event.tick == 7));
+}
+
 // === Force lifetime tests ===
 
 #[test]
@@ -535,3 +592,68 @@ fn test_parented_entity_with_rigid_body_is_treated_as_world_space() {
         local
     );
 }
+
+#[test]
+fn test_only_entities_changed_since_the_writeback_need_sync() {
+    let mut world = World::new();
+    let mut system = PhysicsSystem::new();
+    let wall = world.create_entity();
+    world.add_component(&wall, Transform2D::new(Vec2::ZERO)).unwrap();
+    world.add_component(&wall, RigidBody::new_static()).unwrap();
+    world.add_component(&wall, Collider::box_collider(32.0, 32.0)).unwrap();
+
+    assert!(system.needs_sync(&world, wall), "first update syncs everything");
+    system.update(&mut world, 1.0 / 60.0);
+    assert!(!system.needs_sync(&world, wall), "untouched since the writeback");
+
+    world.get_mut::<Transform2D>(wall).unwrap().position.x = 50.0;
+    assert!(system.needs_sync(&world, wall));
+
+    system.update(&mut world, 1.0 / 60.0);
+    world.remove_component::<Collider>(&wall).unwrap();
+    assert!(system.needs_sync(&world, wall), "collider removal leaves no tick but still syncs");
+}
+
+#[test]
+fn test_shift_origin_moves_bodies_without_a_resync() {
+    let mut world = World::new();
+    let mut system = PhysicsSystem::new();
+    let entity = world.create_entity();
+    world.add_component(&entity, Transform2D::new(Vec2::new(100_000.0, 0.0))).unwrap();
+    world.add_component(&entity, RigidBody::new_dynamic().with_gravity_scale(0.0)).unwrap();
+    world.add_component(&entity, Collider::box_collider(32.0, 32.0)).unwrap();
+    system.update(&mut world, 1.0 / 60.0);
+    system.set_velocity(entity, Vec2::new(60.0, 0.0), 0.0);
+
+    let shift = Vec2::new(100_000.0, 0.0);
+    world.get_mut::<Transform2D>(entity).unwrap().position -= shift;
+    system.shift_origin(shift);
+    let (pos, _) = system.physics_world().get_body_transform(entity).expect("body exists");
+    assert!(pos.x.abs() < 1.0, "body follows the origin, got {:?}", pos);
+
+    // The shifted transform matches its baseline: no teleport, the body drifts on
+    system.update(&mut world, 1.0 / 60.0);
+    let local = world.get::<Transform2D>(entity).unwrap().position;
+    assert!((local.x - 1.0).abs() < 0.5, "body keeps moving from the new origin, got {:?}", local);
+    let (vel, _) = system.get_body_velocity(entity).expect("body exists");
+    assert!((vel.x - 60.0).abs() < 1.0, "velocity survives the shift, got {:?}", vel);
+}
+
+#[test]
+fn test_step_once_runs_exactly_one_fixed_step() {
+    let mut world = World::new();
+    let mut system = PhysicsSystem::new().with_fixed_timestep(0.05);
+    let entity = world.create_entity();
+    world.add_component(&entity, Transform2D::new(Vec2::ZERO)).unwrap();
+    world.add_component(&entity, RigidBody::new_dynamic().with_gravity_scale(0.0)).unwrap();
+    world.add_component(&entity, Collider::box_collider(32.0, 32.0)).unwrap();
+    system.update(&mut world, 0.0);
+    system.set_velocity(entity, Vec2::new(100.0, 0.0), 0.0);
+
+    // A leftover fraction of a step must not add a second one
+    system.update(&mut world, 0.04);
+    system.step_once(&mut world);
+    let x = world.get::<Transform2D>(entity).unwrap().position.x;
+    assert!((x - 5.0).abs() < 0.5, "one 0.05s step at 100 px/s, got {x}");
+    assert_eq!(system.fixed_timestep(), 0.05);
+}
@@ -6,7 +6,10 @@ use glam::Vec2;
 
 use ecs::{EntityId, System, World};
 
-use super::{DeferredBodyOp, PhysicsSystem, MAX_STEPS_PER_UPDATE};
+use crate::collision_filter::CollisionEventFilter;
+use crate::effectors::Effectors;
+
+use super::{DeferredBodyOp, PhysicsSyncTick, PhysicsSystem, MAX_STEPS_PER_UPDATE};
 
 impl System for PhysicsSystem {
     fn initialize(&mut self, _world: &mut World) -> Result<(), String> {
@@ -20,14 +23,19 @@ impl System for PhysicsSystem {
 
         // Get all entities, garbage-collect physics state for entities no
         // longer in the ECS, sync new ones to physics, and push any external
-        // ECS-side edits (Transform2D/Collider) into rapier.
+        // ECS-side edits (Transform2D/Collider) into rapier. Change ticks
+        // skip entities untouched since the last writeback. Entities parked
+        // with `Inactive` (entity pools) keep their bodies, disabled.
         self.pushed_edits_last_update = 0;
         let entities: Vec<EntityId> = world.entities();
         let alive: HashSet<EntityId> = entities.iter().copied().collect();
         self.prune_removed_entities(&alive);
 
         for entity in entities {
-            self.sync_entity_to_physics(world, entity);
+            if self.needs_sync(world, entity) {
+                self.sync_entity_to_physics(world, entity);
+            }
+            self.physics_world.set_entity_enabled(entity, world.is_active(entity));
         }
 
         // Flush deferred body ops in call order: the documented "reset then
@@ -38,6 +46,7 @@ impl System for PhysicsSystem {
                 DeferredBodyOp::Reset { position } => {
                     self.physics_world.set_body_transform(entity, position, 0.0);
                     self.physics_world.set_velocity(entity, Vec2::ZERO, 0.0);
+                    self.previous_poses.remove(&entity);
                 }
                 DeferredBodyOp::SetVelocity { linear, angular } => {
                     self.physics_world.set_velocity(entity, linear, angular);
@@ -53,10 +62,22 @@ impl System for PhysicsSystem {
         // APPENDS its events, so multiple sub-steps all contribute and a
         // frame with zero steps emits nothing (no stale re-delivery).
         self.physics_world.clear_collision_events();
-
+        self.physics_world.set_event_tick(world.simulation_tick());
+        let event_filter = self
+            .physics_world
+            .config()
+            .filter_collision_events
+            .then(|| CollisionEventFilter::from_world(world));
+        self.physics_world.set_collision_event_filter(event_filter);
+        let effectors = Effectors::from_world(world);
+
+        let interpolate = self.physics_world.config().interpolate;
         let mut steps = 0;
         while self.time_accumulator >= self.fixed_timestep && steps < MAX_STEPS_PER_UPDATE {
-            self.physics_world.step(self.fixed_timestep);
+            if interpolate {
+                self.capture_previous_poses();
+            }
+            self.step_hooks.step(&mut self.physics_world, world, &effectors, self.fixed_timestep, steps);
             self.time_accumulator -= self.fixed_timestep;
             steps += 1;
         }
@@ -76,8 +97,12 @@ impl System for PhysicsSystem {
             self.physics_world.reset_forces();
         }
 
-        // Sync physics results back to ECS
+        // Sync physics results back to ECS, then close the change window so
+        // this writeback is not mistaken for an edit next update.
         self.sync_physics_to_ecs(world);
+        let synced_tick = world.increment_change_tick();
+        self.synced_tick = Some(synced_tick);
+        world.insert_resource(PhysicsSyncTick(synced_tick));
 
         // Emit collision events to the world event bus (available to any
         // system). The buffer itself stays available for game code to drain
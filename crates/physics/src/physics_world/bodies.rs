@@ -9,6 +9,20 @@ use crate::components::{Collider, ColliderShape, RigidBody, RigidBodyType};
 
 use super::PhysicsWorld;
 
+/// An awake dynamic body's state, in pixels.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct BodySample {
+    pub(crate) entity: EntityId,
+    pub(crate) position: Vec2,
+    pub(crate) velocity: Vec2,
+    /// Mass (kg)
+    pub(crate) mass: f32,
+    pub(crate) gravity_scale: f32,
+    /// Vertical span of the collider's bounding box (the position without one)
+    pub(crate) bottom: f32,
+    pub(crate) top: f32,
+}
+
 impl PhysicsWorld {
     /// Add a rigid body for an entity
     pub fn add_rigid_body(&mut self, entity: EntityId, body: &mut RigidBody, position: Vec2, rotation: f32) {
@@ -28,7 +42,8 @@ impl PhysicsWorld {
                     .gravity_scale(body.gravity_scale)
                     .linear_damping(body.linear_damping)
                     .angular_damping(body.angular_damping)
-                    .ccd_enabled(body.ccd_enabled);
+                    .ccd_enabled(body.ccd_enabled)
+                    .can_sleep(body.can_sleep);
                 if !body.can_rotate {
                     builder = builder.lock_rotations();
                 }
@@ -91,6 +106,8 @@ impl PhysicsWorld {
             .translation(vector![offset.x, offset.y])
             .friction(collider.friction)
             .restitution(collider.restitution)
+            .friction_combine_rule(collider.friction_combine.to_rapier())
+            .restitution_combine_rule(collider.restitution_combine.to_rapier())
             .sensor(collider.is_sensor)
             .active_events(ActiveEvents::COLLISION_EVENTS);
 
@@ -103,9 +120,11 @@ impl PhysicsWorld {
 
         let rapier_collider = builder.build();
 
-        // Insert collider, attached to rigid body if available
+        // Insert collider, attached to rigid body if available. A component
+        // rebuilt from serde (which skips the handle) still has its body here.
         let handle = if let Some(body) = rigid_body {
-            if let Some(body_handle) = body.handle {
+            let body_handle = body.handle.or_else(|| self.entity_to_body.get(&entity).copied());
+            if let Some(body_handle) = body_handle {
                 self.collider_set.insert_with_parent(
                     rapier_collider,
                     body_handle,
@@ -248,6 +267,31 @@ impl PhysicsWorld {
         }
     }
 
+    /// Whether the entity's body is asleep (`None` without a body).
+    pub fn is_sleeping(&self, entity: EntityId) -> Option<bool> {
+        self.body(entity).map(|body| body.is_sleeping())
+    }
+
+    /// Wake a sleeping body, restarting its time-until-sleep countdown.
+    pub fn wake_up(&mut self, entity: EntityId) {
+        if let Some(body) = self.body_mut(entity) {
+            body.wake_up(true);
+        }
+    }
+
+    /// Allow or forbid a live body to fall asleep; forbidding wakes it.
+    pub fn set_can_sleep(&mut self, entity: EntityId, can_sleep: bool) {
+        if let Some(body) = self.body_mut(entity) {
+            let thresholds = if can_sleep { RigidBodyActivation::active() } else { RigidBodyActivation::cannot_sleep() };
+            let activation = body.activation_mut();
+            activation.normalized_linear_threshold = thresholds.normalized_linear_threshold;
+            activation.angular_threshold = thresholds.angular_threshold;
+            if !can_sleep {
+                body.wake_up(true);
+            }
+        }
+    }
+
     /// Apply an impulse to a rigid body
     pub fn apply_impulse(&mut self, entity: EntityId, impulse: Vec2) {
         let imp = self.pixels_to_meters(impulse);
@@ -271,6 +315,38 @@ impl PhysicsWorld {
         }
     }
 
+    /// Awake dynamic bodies as effectors see them.
+    pub(crate) fn awake_dynamic_bodies(&self) -> impl Iterator<Item = BodySample> + '_ {
+        self.entity_to_body.iter().filter_map(|(&entity, &handle)| {
+            let body = self.rigid_body_set.get(handle)?;
+            if !body.is_dynamic() || !body.is_enabled() || body.is_sleeping() {
+                return None;
+            }
+            let translation = body.translation();
+            let position = self.meters_to_pixels(Vec2::new(translation.x, translation.y));
+            let (bottom, top) = self
+                .entity_to_collider
+                .get(&entity)
+                .and_then(|handle| self.collider_set.get(*handle))
+                .map(|collider| {
+                    let aabb = collider.compute_aabb();
+                    let ppm = self.config.pixels_per_meter;
+                    (aabb.mins.y * ppm, aabb.maxs.y * ppm)
+                })
+                .unwrap_or((position.y, position.y));
+            let linvel = body.linvel();
+            Some(BodySample {
+                entity,
+                position,
+                velocity: self.meters_to_pixels(Vec2::new(linvel.x, linvel.y)),
+                mass: body.mass(),
+                gravity_scale: body.gravity_scale(),
+                bottom,
+                top,
+            })
+        })
+    }
+
     /// Reset all accumulated external forces on every rigid body.
     ///
     /// Called by `PhysicsSystem::update()` after stepping so that
@@ -281,6 +357,38 @@ impl PhysicsWorld {
         }
     }
 
+    /// Enable or disable an entity's body and collider without removing
+    /// them. Disabled objects don't move, collide or show up in queries;
+    /// re-enabling resumes them with no re-registration.
+    pub fn set_entity_enabled(&mut self, entity: EntityId, enabled: bool) {
+        // Only touch the sets on a real change: mutable access flags the
+        // object as modified for rapier's next step
+        if self.body(entity).is_some_and(|body| body.is_enabled() != enabled) {
+            if let Some(body) = self.body_mut(entity) {
+                body.set_enabled(enabled);
+            }
+        }
+        if let Some(&handle) = self.entity_to_collider.get(&entity) {
+            if self.collider_set.get(handle).is_some_and(|collider| collider.is_enabled() != enabled) {
+                if let Some(collider) = self.collider_set.get_mut(handle) {
+                    collider.set_enabled(enabled);
+                }
+            }
+        }
+    }
+
+    /// Whether the entity's physics objects are enabled (`true` when it
+    /// has none)
+    pub fn is_entity_enabled(&self, entity: EntityId) -> bool {
+        let body_enabled = self.body(entity).is_none_or(|body| body.is_enabled());
+        let collider_enabled = self
+            .entity_to_collider
+            .get(&entity)
+            .and_then(|handle| self.collider_set.get(*handle))
+            .is_none_or(|collider| collider.is_enabled());
+        body_enabled && collider_enabled
+    }
+
     /// Check if an entity has a rigid body
     pub fn has_rigid_body(&self, entity: EntityId) -> bool {
         self.entity_to_body.contains_key(&entity)
@@ -300,4 +408,25 @@ impl PhysicsWorld {
     pub fn collider_count(&self) -> usize {
         self.collider_set.len()
     }
+
+    /// Move every body and free-standing collider by `-shift` pixels, for
+    /// a floating-origin shift. Velocities, contacts and sleep states are
+    /// untouched, so the simulation carries on as if nothing moved.
+    pub fn shift_origin(&mut self, shift: Vec2) {
+        let offset = self.pixels_to_meters(shift);
+        let offset = vector![offset.x, offset.y];
+        for (_, body) in self.rigid_body_set.iter_mut() {
+            let translation = *body.translation() - offset;
+            body.set_translation(translation, false);
+        }
+        for (_, collider) in self.collider_set.iter_mut() {
+            if collider.parent().is_none() {
+                let translation = *collider.translation() - offset;
+                collider.set_translation(translation);
+            }
+        }
+        self.rigid_body_set
+            .propagate_modified_body_positions_to_colliders(&mut self.collider_set);
+        self.query_pipeline.update(&self.collider_set);
+    }
 }
@@ -24,8 +24,10 @@ use serde::{Deserialize, Serialize};
 
 use ecs::EntityId;
 
+use crate::collision_filter::CollisionEventFilter;
 use crate::components::CollisionData;
 
+pub(crate) use self::bodies::BodySample;
 use self::stepping::CollisionPair;
 
 /// Default pixels-per-meter scale used when an invalid value is supplied.
@@ -44,6 +46,32 @@ pub struct PhysicsConfig {
     /// Pixels per meter scale factor (must be finite and > 0; invalid values
     /// fall back to [`DEFAULT_PIXELS_PER_METER`])
     pub pixels_per_meter: f32,
+    /// Full simulation steps per fixed step, each advancing `dt / substeps`
+    /// (at least 1). Unlike solver iterations this re-runs collision
+    /// detection too, so fast bodies tunnel less — at proportional cost.
+    #[serde(default = "default_substeps")]
+    pub substeps: usize,
+    /// Maximum CCD substeps per step for bodies with CCD enabled (maps to
+    /// rapier's `max_ccd_substeps`; at least 1). Raise it when fast CCD
+    /// bodies still miss thin geometry after several impacts in one step.
+    #[serde(default = "default_substeps")]
+    pub ccd_substeps: usize,
+    /// Write interpolated poses back to `Transform2D`: between fixed steps,
+    /// moving bodies are blended from the previous step's pose toward the
+    /// current one by the leftover accumulator fraction. Smooths motion when
+    /// the frame rate and the fixed timestep differ, at the cost of showing
+    /// bodies up to one step behind the simulation.
+    #[serde(default)]
+    pub interpolate: bool,
+    /// Produce collision events only for pairs where an entity's
+    /// [`CollisionEvents`](crate::CollisionEvents) component accepts the
+    /// other, instead of for every touching pair.
+    #[serde(default)]
+    pub filter_collision_events: bool,
+}
+
+fn default_substeps() -> usize {
+    1
 }
 
 impl Default for PhysicsConfig {
@@ -53,6 +81,10 @@ impl Default for PhysicsConfig {
             solver_iterations: 16,   // Increased for better collision resolution
             friction_iterations: 8,  // Increased for better stacking stability
             pixels_per_meter: DEFAULT_PIXELS_PER_METER,
+            substeps: 1,
+            ccd_substeps: 1,
+            interpolate: false,
+            filter_collision_events: false,
         }
     }
 }
@@ -79,6 +111,27 @@ impl PhysicsConfig {
         self
     }
 
+    /// Set simulation substeps per fixed step and maximum CCD substeps
+    /// (both floored at 1 when the world is created)
+    pub fn with_substeps(mut self, substeps: usize, ccd_substeps: usize) -> Self {
+        self.substeps = substeps;
+        self.ccd_substeps = ccd_substeps;
+        self
+    }
+
+    /// Enable or disable transform interpolation between fixed steps
+    pub fn with_interpolation(mut self, interpolate: bool) -> Self {
+        self.interpolate = interpolate;
+        self
+    }
+
+    /// Enable or disable marker-based collision event filtering (see
+    /// [`collision_filter`](crate::collision_filter))
+    pub fn with_collision_event_filtering(mut self, filter: bool) -> Self {
+        self.filter_collision_events = filter;
+        self
+    }
+
     /// Set pixels per meter scale.
     ///
     /// Non-finite or non-positive values are rejected with a warning and
@@ -140,6 +193,10 @@ pub struct PhysicsWorld {
     collision_events: Vec<CollisionData>,
     /// Active collision pairs from the previous step (for detecting start/stop)
     previous_collisions: HashSet<CollisionPair>,
+    /// Simulation tick stamped on collision events from the next step
+    event_tick: u64,
+    /// Pairs allowed to produce events; `None` = every pair
+    event_filter: Option<CollisionEventFilter>,
 }
 
 impl Default for PhysicsWorld {
@@ -154,6 +211,8 @@ impl PhysicsWorld {
         // Re-validate at entry: the config struct has public fields, so an
         // invalid scale can arrive without going through `with_scale`.
         config.pixels_per_meter = sanitize_pixels_per_meter(config.pixels_per_meter);
+        config.substeps = config.substeps.max(1);
+        config.ccd_substeps = config.ccd_substeps.max(1);
 
         let integration_parameters = IntegrationParameters {
             // Rapier requires at least one solver iteration; floor at 1
@@ -161,6 +220,7 @@ impl PhysicsWorld {
             num_solver_iterations: NonZeroUsize::new(config.solver_iterations.max(1))
                 .unwrap_or(NonZeroUsize::MIN),
             num_additional_friction_iterations: config.friction_iterations,
+            max_ccd_substeps: config.ccd_substeps,
             ..IntegrationParameters::default()
         };
 
@@ -183,6 +243,8 @@ impl PhysicsWorld {
             collider_to_entity: HashMap::new(),
             collision_events: Vec::new(),
             previous_collisions: HashSet::new(),
+            event_tick: 0,
+            event_filter: None,
         }
     }
 
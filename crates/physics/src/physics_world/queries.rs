@@ -1,4 +1,4 @@
-//! Spatial queries (raycasts).
+//! Spatial queries (raycasts, overlap tests).
 
 use glam::Vec2;
 use rapier2d::prelude::*;
@@ -44,4 +44,93 @@ impl PhysicsWorld {
 
         None
     }
+
+    /// Every solid collider a ray passes through within `max_distance`
+    /// pixels, as `(entity, distance)` sorted nearest first, one entry per
+    /// entity. Only colliders whose collision groups share a bit with
+    /// `layers` count; sensors never do. Used for audio occlusion, where
+    /// each wall between listener and source muffles a little more.
+    pub fn raycast_all(&self, origin: Vec2, direction: Vec2, max_distance: f32, layers: u32) -> Vec<(EntityId, f32)> {
+        let Some(dir) = direction.try_normalize() else {
+            return Vec::new();
+        };
+        let origin_m = self.pixels_to_meters(origin);
+        let ray = Ray::new(point![origin_m.x, origin_m.y], vector![dir.x, dir.y]);
+        let filter = QueryFilter::default()
+            .exclude_sensors()
+            .groups(InteractionGroups::new(Group::ALL, Group::from_bits_truncate(layers)));
+
+        let mut hits: Vec<(EntityId, f32)> = Vec::new();
+        self.query_pipeline.intersections_with_ray(
+            &self.rigid_body_set,
+            &self.collider_set,
+            &ray,
+            self.pixels_to_meters_scalar(max_distance),
+            true,
+            filter,
+            |handle, intersection| {
+                if let Some(&entity) = self.collider_to_entity.get(&handle) {
+                    let distance = self.meters_to_pixels_scalar(intersection.time_of_impact);
+                    match hits.iter_mut().find(|(hit, _)| *hit == entity) {
+                        Some(existing) => existing.1 = existing.1.min(distance),
+                        None => hits.push((entity, distance)),
+                    }
+                }
+                true
+            },
+        );
+        hits.sort_by(|a, b| a.1.total_cmp(&b.1));
+        hits
+    }
+
+    /// Entities whose colliders overlap a circle of `radius` pixels at
+    /// `center` (sensors included). Empty for a non-positive or non-finite
+    /// radius.
+    pub fn overlap_circle(&self, center: Vec2, radius: f32) -> Vec<EntityId> {
+        if !(radius > 0.0 && radius.is_finite()) {
+            return Vec::new();
+        }
+        let center_m = self.pixels_to_meters(center);
+        let shape = Ball::new(self.pixels_to_meters_scalar(radius));
+        let mut entities = Vec::new();
+        self.query_pipeline.intersections_with_shape(
+            &self.rigid_body_set,
+            &self.collider_set,
+            &Isometry::translation(center_m.x, center_m.y),
+            &shape,
+            QueryFilter::default(),
+            |handle| {
+                self.push_entity(handle, &mut entities);
+                true
+            },
+        );
+        entities
+    }
+
+    /// Entities whose colliders contain `point` (sensors included).
+    pub fn overlap_point(&self, point: Vec2) -> Vec<EntityId> {
+        let point_m = self.pixels_to_meters(point);
+        let mut entities = Vec::new();
+        self.query_pipeline.intersections_with_point(
+            &self.rigid_body_set,
+            &self.collider_set,
+            &point![point_m.x, point_m.y],
+            QueryFilter::default(),
+            |handle| {
+                self.push_entity(handle, &mut entities);
+                true
+            },
+        );
+        entities
+    }
+
+    /// Record the entity owning `handle` once (an entity may own several
+    /// colliders).
+    fn push_entity(&self, handle: ColliderHandle, entities: &mut Vec<EntityId>) {
+        if let Some(&entity) = self.collider_to_entity.get(&handle) {
+            if !entities.contains(&entity) {
+                entities.push(entity);
+            }
+        }
+    }
 }
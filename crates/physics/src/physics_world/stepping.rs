@@ -7,6 +7,7 @@ use rapier2d::prelude::*;
 
 use ecs::EntityId;
 
+use crate::collision_filter::CollisionEventFilter;
 use crate::components::{CollisionData, CollisionEvent, ContactPoint};
 
 use super::PhysicsWorld;
@@ -40,6 +41,9 @@ impl CollisionPair {
 impl PhysicsWorld {
     /// Step the physics simulation.
     ///
+    /// Runs [`PhysicsConfig::substeps`](super::PhysicsConfig::substeps)
+    /// simulation steps of `delta_time / substeps` each.
+    ///
     /// Collision events produced by this step are APPENDED to the event
     /// buffer — they are not cleared here. This lets a fixed-timestep driver
     /// run multiple sub-steps per frame without losing events from earlier
@@ -47,6 +51,15 @@ impl PhysicsWorld {
     /// [`clear_collision_events`](Self::clear_collision_events) once per
     /// frame, before the first step.
     pub fn step(&mut self, delta_time: f32) {
+        let substeps = self.config.substeps.max(1);
+        let dt = delta_time / substeps as f32;
+        for _ in 0..substeps {
+            self.step_once(dt);
+        }
+    }
+
+    /// One rapier pipeline step plus collision event extraction.
+    fn step_once(&mut self, delta_time: f32) {
         self.integration_parameters.dt = delta_time;
 
         let gravity_meters = self.pixels_to_meters(self.config.gravity);
@@ -89,6 +102,9 @@ impl PhysicsWorld {
                 if has_contact {
                     let pair = CollisionPair::new(entity_a, entity_b);
                     current_collisions.insert(pair);
+                    if !self.produces_events(entity_a, entity_b) {
+                        continue;
+                    }
 
                     // Check if this is a new collision (started)
                     let started = !self.previous_collisions.contains(&pair);
@@ -100,6 +116,7 @@ impl PhysicsWorld {
                             entity_b,
                             started,
                             stopped: false,
+                            tick: self.event_tick,
                         },
                         contacts,
                     });
@@ -118,6 +135,9 @@ impl PhysicsWorld {
 
                 if intersecting {
                     current_collisions.insert(pair);
+                    if !self.produces_events(entity_a, entity_b) {
+                        continue;
+                    }
 
                     let started = !self.previous_collisions.contains(&pair);
                     self.collision_events.push(CollisionData {
@@ -126,6 +146,7 @@ impl PhysicsWorld {
                             entity_b,
                             started,
                             stopped: false,
+                            tick: self.event_tick,
                         },
                         contacts: Vec::new(), // Sensors have no contact points
                     });
@@ -137,12 +158,16 @@ impl PhysicsWorld {
         for pair in &self.previous_collisions {
             if !current_collisions.contains(pair) {
                 let (entity_a, entity_b) = pair.entities();
+                if !self.produces_events(entity_a, entity_b) {
+                    continue;
+                }
                 self.collision_events.push(CollisionData {
                     event: CollisionEvent {
                         entity_a,
                         entity_b,
                         started: false,
                         stopped: true,
+                        tick: self.event_tick,
                     },
                     contacts: Vec::new(), // No contacts for ended collisions
                 });
@@ -153,6 +178,11 @@ impl PhysicsWorld {
         self.previous_collisions = current_collisions;
     }
 
+    /// Whether a pair passes the collision event filter (always, without one).
+    fn produces_events(&self, entity_a: EntityId, entity_b: EntityId) -> bool {
+        self.event_filter.as_ref().is_none_or(|filter| filter.accepts(entity_a, entity_b))
+    }
+
     /// Get contact points from a contact pair, in world space (pixels).
     ///
     /// Rapier reports manifold points/normals in collider1's local frame, so
@@ -209,4 +239,20 @@ impl PhysicsWorld {
     pub fn clear_collision_events(&mut self) {
         self.collision_events.clear();
     }
+
+    /// Set the simulation tick stamped on events from subsequent steps
+    /// (`CollisionEvent::tick`). `PhysicsSystem` sets it from the world's
+    /// `SimulationTick` before stepping.
+    pub fn set_event_tick(&mut self, tick: u64) {
+        self.event_tick = tick;
+    }
+
+    /// Restrict subsequent steps' events to pairs `filter` accepts (`None`
+    /// = every pair). `PhysicsSystem` refreshes it from the ECS before
+    /// stepping when `PhysicsConfig::filter_collision_events` is set. Pairs
+    /// are still tracked while filtered out, so one that becomes accepted
+    /// mid-contact isn't reported as newly started.
+    pub fn set_collision_event_filter(&mut self, filter: Option<CollisionEventFilter>) {
+        self.event_filter = filter;
+    }
 }
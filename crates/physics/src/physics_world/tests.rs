@@ -355,6 +355,51 @@ fn test_raycast_normalizes_direction_so_distance_is_in_pixels() {
     assert!((dist_unit - 150.0).abs() < 1.0, "box edge is at x=150, got {dist_unit}");
 }
 
+#[test]
+fn test_overlap_queries_find_colliders() {
+    let mut world = PhysicsWorld::default();
+    let entity = EntityId::new();
+    let mut body = RigidBody::new_static();
+    let mut collider = Collider::box_collider(100.0, 100.0);
+    world.add_rigid_body(entity, &mut body, Vec2::new(200.0, 0.0), 0.0);
+    world.add_collider(entity, &mut collider, Some(&body));
+    world.step(0.0);
+
+    assert_eq!(world.overlap_point(Vec2::new(180.0, 20.0)), vec![entity]);
+    assert!(world.overlap_point(Vec2::ZERO).is_empty());
+    // The box edge is at x=150: a circle reaching past it overlaps
+    assert_eq!(world.overlap_circle(Vec2::new(100.0, 0.0), 60.0), vec![entity]);
+    assert!(world.overlap_circle(Vec2::new(100.0, 0.0), 40.0).is_empty());
+    assert!(world.overlap_circle(Vec2::new(200.0, 0.0), 0.0).is_empty());
+}
+
+#[test]
+fn test_raycast_all_lists_every_wall_on_the_layers() {
+    let mut world = PhysicsWorld::default();
+    let mut add_wall = |x: f32, collider: Collider| {
+        let entity = EntityId::new();
+        let mut body = RigidBody::new_static();
+        let mut collider = collider;
+        world.add_rigid_body(entity, &mut body, Vec2::new(x, 0.0), 0.0);
+        world.add_collider(entity, &mut collider, Some(&body));
+        entity
+    };
+    let far = add_wall(300.0, Collider::box_collider(20.0, 100.0));
+    let near = add_wall(100.0, Collider::box_collider(20.0, 100.0));
+    let glass = add_wall(200.0, Collider::box_collider(20.0, 100.0).with_collision_groups(0b10, u32::MAX));
+    add_wall(250.0, Collider::box_collider(20.0, 100.0).as_sensor());
+    world.step(0.0);
+
+    let hits = world.raycast_all(Vec2::ZERO, Vec2::X, 400.0, u32::MAX);
+    let entities: Vec<EntityId> = hits.iter().map(|(entity, _)| *entity).collect();
+    assert_eq!(entities, vec![near, glass, far], "nearest first, sensors skipped");
+    assert!((hits[0].1 - 90.0).abs() < 1.0);
+
+    let solid_only = world.raycast_all(Vec2::ZERO, Vec2::X, 400.0, 0b01);
+    assert_eq!(solid_only.iter().map(|(entity, _)| *entity).collect::<Vec<_>>(), vec![near, far]);
+    assert!(world.raycast_all(Vec2::ZERO, Vec2::X, 50.0, u32::MAX).is_empty());
+}
+
 #[test]
 fn test_contact_points_are_in_world_space() {
     // Two overlapping boxes far from the origin. If contact points were
@@ -443,3 +488,26 @@ fn test_collision_pair_canonical_order() {
     assert_eq!(e1, e3);
     assert_eq!(e2, e4);
 }
+
+#[test]
+fn test_substeps_are_floored_and_ccd_substeps_reach_rapier() {
+    let world = PhysicsWorld::new(PhysicsConfig::default().with_substeps(0, 4));
+    assert_eq!(world.config().substeps, 1);
+    assert_eq!(world.integration_parameters.max_ccd_substeps, 4);
+}
+
+#[test]
+fn test_substepped_step_covers_the_same_time() {
+    let mut plain = PhysicsWorld::new(PhysicsConfig::new(Vec2::ZERO));
+    let mut substepped = PhysicsWorld::new(PhysicsConfig::new(Vec2::ZERO).with_substeps(4, 1));
+    let entity = EntityId::new();
+    for world in [&mut plain, &mut substepped] {
+        let mut body = RigidBody::new_dynamic().with_velocity(Vec2::new(600.0, 0.0));
+        world.add_rigid_body(entity, &mut body, Vec2::ZERO, 0.0);
+        world.step(1.0 / 60.0);
+    }
+    let (plain_pos, _) = plain.get_body_transform(entity).expect("body exists");
+    let (substepped_pos, _) = substepped.get_body_transform(entity).expect("body exists");
+    assert!((plain_pos.x - 10.0).abs() < 0.01);
+    assert!((substepped_pos - plain_pos).length() < 0.01);
+}
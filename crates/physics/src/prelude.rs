@@ -9,8 +9,13 @@
 //! ```
 
 pub use crate::components::{
-    Collider, ColliderShape, CollisionData, CollisionEvent, ContactPoint, RigidBody,
-    RigidBodyType,
+    BodySleep, BodyWake, Collider, ColliderShape, CollisionData, CollisionEvent, ContactPoint,
+    RigidBody, RigidBodyType,
 };
-pub use crate::physics_system::PhysicsSystem;
+pub use crate::collision_filter::CollisionEvents;
+pub use crate::effectors::{AreaForce, Buoyancy, Drag};
+pub use crate::gravity_zone::{GravityFalloff, GravityZone};
+pub use crate::zone::ZoneShape;
+pub use crate::material::{CombineRule, PhysicsMaterial};
+pub use crate::physics_system::{pending_physics_edits, PhysicsSystem, StepContext};
 pub use crate::physics_world::{PhysicsConfig, PhysicsWorld};
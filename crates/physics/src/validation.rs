@@ -0,0 +1,199 @@
+//! [`Validate`] impls for the physics components, so bad data is reported
+//! at load and edit time instead of reaching rapier as NaNs or degenerate
+//! shapes.
+
+use std::sync::Once;
+
+use ecs::component_registry::ComponentMeta;
+use ecs::validation::{ensure, register_global_validator};
+use ecs::Validate;
+
+use crate::components::{Collider, RigidBody};
+use crate::effectors::{AreaForce, Buoyancy, Drag};
+use crate::gravity_zone::{GravityField, GravityFalloff, GravityZone};
+
+// Physics components are loaded through scene data rather than the
+// component registry, but validators are keyed by the registry name.
+impl ComponentMeta for RigidBody {
+    fn type_name() -> &'static str {
+        "RigidBody"
+    }
+
+    fn field_names() -> &'static [&'static str] {
+        &[
+            "body_type", "velocity", "angular_velocity", "gravity_scale", "linear_damping",
+            "angular_damping", "can_rotate", "ccd_enabled", "can_sleep",
+        ]
+    }
+}
+
+impl ComponentMeta for Collider {
+    fn type_name() -> &'static str {
+        "Collider"
+    }
+
+    fn field_names() -> &'static [&'static str] {
+        &[
+            "shape", "offset", "is_sensor", "friction", "restitution", "material",
+            "friction_combine", "restitution_combine", "collision_groups", "collision_filter",
+        ]
+    }
+}
+
+impl ComponentMeta for GravityZone {
+    fn type_name() -> &'static str {
+        "GravityZone"
+    }
+
+    fn field_names() -> &'static [&'static str] {
+        &["shape", "field", "mode", "priority"]
+    }
+}
+
+impl ComponentMeta for AreaForce {
+    fn type_name() -> &'static str {
+        "AreaForce"
+    }
+
+    fn field_names() -> &'static [&'static str] {
+        &["shape", "force"]
+    }
+}
+
+impl ComponentMeta for Buoyancy {
+    fn type_name() -> &'static str {
+        "Buoyancy"
+    }
+
+    fn field_names() -> &'static [&'static str] {
+        &["shape", "surface", "density", "drag"]
+    }
+}
+
+impl ComponentMeta for Drag {
+    fn type_name() -> &'static str {
+        "Drag"
+    }
+
+    fn field_names() -> &'static [&'static str] {
+        &["shape", "damping"]
+    }
+}
+
+impl Validate for RigidBody {
+    fn validate(&self) -> Result<(), String> {
+        ensure(self.velocity.is_finite() && self.angular_velocity.is_finite(), || {
+            "velocity is not finite".to_string()
+        })?;
+        ensure(self.gravity_scale.is_finite(), || format!("gravity scale {} is not finite", self.gravity_scale))?;
+        let damping_ok = |d: f32| d.is_finite() && d >= 0.0;
+        ensure(damping_ok(self.linear_damping) && damping_ok(self.angular_damping), || {
+            format!(
+                "damping must be zero or more, got linear {} / angular {}",
+                self.linear_damping, self.angular_damping
+            )
+        })
+    }
+}
+
+impl Validate for Collider {
+    fn validate(&self) -> Result<(), String> {
+        ensure(self.shape.has_extent(), || format!("shape {:?} needs positive, finite dimensions", self.shape))?;
+        ensure(self.offset.is_finite(), || format!("offset {} is not finite", self.offset))?;
+        ensure(self.friction.is_finite() && self.friction >= 0.0, || {
+            format!("friction must be zero or more, got {}", self.friction)
+        })?;
+        ensure(self.restitution.is_finite() && self.restitution >= 0.0, || {
+            format!("restitution must be zero or more, got {}", self.restitution)
+        })
+    }
+}
+
+impl Validate for GravityZone {
+    fn validate(&self) -> Result<(), String> {
+        ensure(self.shape.has_extent(), || format!("shape {:?} needs positive, finite dimensions", self.shape))?;
+        let finite = match self.field {
+            GravityField::Directional(gravity) => gravity.is_finite(),
+            GravityField::Radial { strength, falloff: GravityFalloff::InverseSquare { surface } } => {
+                strength.is_finite() && surface.is_finite() && surface > 0.0
+            }
+            GravityField::Radial { strength, .. } => strength.is_finite(),
+        };
+        ensure(finite, || format!("field {:?} needs finite gravity and a positive surface", self.field))
+    }
+}
+
+impl Validate for AreaForce {
+    fn validate(&self) -> Result<(), String> {
+        ensure(self.shape.has_extent(), || format!("shape {:?} needs positive, finite dimensions", self.shape))?;
+        ensure(self.force.is_finite(), || format!("force {} is not finite", self.force))
+    }
+}
+
+impl Validate for Buoyancy {
+    fn validate(&self) -> Result<(), String> {
+        ensure(self.shape.has_extent(), || format!("shape {:?} needs positive, finite dimensions", self.shape))?;
+        ensure(self.surface.is_finite(), || format!("surface {} is not finite", self.surface))?;
+        let rate_ok = |r: f32| r.is_finite() && r >= 0.0;
+        ensure(rate_ok(self.density) && rate_ok(self.drag), || {
+            format!("density and drag must be zero or more, got {} / {}", self.density, self.drag)
+        })
+    }
+}
+
+impl Validate for Drag {
+    fn validate(&self) -> Result<(), String> {
+        ensure(self.shape.has_extent(), || format!("shape {:?} needs positive, finite dimensions", self.shape))?;
+        ensure(self.damping.is_finite() && self.damping >= 0.0, || {
+            format!("damping must be zero or more, got {}", self.damping)
+        })
+    }
+}
+
+/// Register the physics component validators (bodies, colliders, gravity
+/// zones, effectors) with the global component registry (once; later calls
+/// do nothing).
+pub fn register_validators() {
+    static REGISTERED: Once = Once::new();
+    REGISTERED.call_once(|| {
+        register_global_validator::<RigidBody>();
+        register_global_validator::<Collider>();
+        register_global_validator::<GravityZone>();
+        register_global_validator::<AreaForce>();
+        register_global_validator::<Buoyancy>();
+        register_global_validator::<Drag>();
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::ColliderShape;
+    use crate::zone::ZoneShape;
+    use ecs::World;
+    use glam::Vec2;
+
+    #[test]
+    fn test_physics_components_validate() {
+        assert!(Collider::box_collider(32.0, 16.0).validate().is_ok());
+        assert!(Collider::new(ColliderShape::Box { half_extents: Vec2::new(8.0, 0.0) }).validate().is_err());
+        let mut sticky = Collider::circle_collider(4.0);
+        sticky.friction = -1.0;
+        assert!(sticky.validate().is_err());
+        assert!(RigidBody::new_dynamic().with_linear_damping(f32::NAN).validate().is_err());
+        let planet = |surface| {
+            GravityZone::radial(ZoneShape::circle(100.0), 980.0, GravityFalloff::InverseSquare { surface })
+        };
+        assert!(planet(20.0).validate().is_ok());
+        assert!(planet(0.0).validate().is_err());
+        assert!(Buoyancy::default().validate().is_ok());
+        assert!(Drag::new(ZoneShape::circle(10.0), -1.0).validate().is_err());
+
+        register_validators();
+        let mut world = World::new();
+        let entity = world.spawn((RigidBody::new_dynamic(), Collider::circle_collider(0.0))).id();
+        let errors = ecs::validate_components(&world, [entity]);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].component, "Collider");
+    }
+}
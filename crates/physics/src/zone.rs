@@ -0,0 +1,103 @@
+//! Areas shared by the gravity zones and effectors: a [`ZoneShape`] centered
+//! on its entity's `Transform2D` and turned with its rotation. Zones need no
+//! body or collider; a body is inside when its center is.
+
+use glam::Vec2;
+use serde::{Deserialize, Serialize};
+
+use ecs::sprite_components::Transform2D;
+use ecs::{Pair, World};
+
+/// Area covered by a zone, centered on the zone entity.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum ZoneShape {
+    /// Rectangle with the given half extents (pixels), rotated with the entity
+    Rect { half_extents: Vec2 },
+    /// Circle with the given radius (pixels)
+    Circle { radius: f32 },
+}
+
+impl Default for ZoneShape {
+    fn default() -> Self {
+        Self::rect(128.0, 128.0)
+    }
+}
+
+impl ZoneShape {
+    /// A `width` x `height` rectangle.
+    pub fn rect(width: f32, height: f32) -> Self {
+        Self::Rect { half_extents: Vec2::new(width, height) * 0.5 }
+    }
+
+    /// A circle of `radius`.
+    pub fn circle(radius: f32) -> Self {
+        Self::Circle { radius }
+    }
+
+    /// Whether a point in zone-local space is inside.
+    pub fn contains(&self, local: Vec2) -> bool {
+        match *self {
+            Self::Rect { half_extents } => local.x.abs() <= half_extents.x && local.y.abs() <= half_extents.y,
+            Self::Circle { radius } => local.length_squared() <= radius * radius,
+        }
+    }
+
+    /// Distance from the center to the farthest point inside.
+    pub fn reach(&self) -> f32 {
+        match *self {
+            Self::Rect { half_extents } => half_extents.length(),
+            Self::Circle { radius } => radius,
+        }
+    }
+
+    /// Distance from the center up to the top edge, unrotated.
+    pub fn top(&self) -> f32 {
+        match *self {
+            Self::Rect { half_extents } => half_extents.y,
+            Self::Circle { radius } => radius,
+        }
+    }
+
+    /// Whether the dimensions are positive and finite.
+    pub fn has_extent(&self) -> bool {
+        match *self {
+            Self::Rect { half_extents } => half_extents.is_finite() && half_extents.min_element() > 0.0,
+            Self::Circle { radius } => radius.is_finite() && radius > 0.0,
+        }
+    }
+}
+
+/// Where a zone sits: its entity's position and rotation.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ZonePlacement {
+    pub(crate) center: Vec2,
+    /// Unit vector of the entity's rotation
+    rotation: Vec2,
+}
+
+impl ZonePlacement {
+    pub(crate) fn new(transform: &Transform2D) -> Self {
+        Self { center: transform.position, rotation: Vec2::from_angle(transform.rotation) }
+    }
+
+    /// A world point in zone-local space.
+    pub(crate) fn to_local(self, point: Vec2) -> Vec2 {
+        // Undo the rotation: (cos, -sin) turns by the negated angle
+        Vec2::new(self.rotation.x, -self.rotation.y).rotate(point - self.center)
+    }
+
+    /// A zone-local direction in world space.
+    pub(crate) fn to_world(self, direction: Vec2) -> Vec2 {
+        self.rotation.rotate(direction)
+    }
+}
+
+/// Every active entity with zone component `T` and a `Transform2D`.
+pub(crate) fn placed<T: ecs::Component + Clone>(world: &World) -> Vec<(T, ZonePlacement)> {
+    world
+        .query_active::<Pair<T, Transform2D>>()
+        .into_iter()
+        .filter(|&entity| world.is_active(entity))
+        .filter_map(|entity| Some((world.get::<T>(entity)?.clone(), ZonePlacement::new(world.get::<Transform2D>(entity)?))))
+        .collect()
+}
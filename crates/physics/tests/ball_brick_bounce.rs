@@ -16,34 +16,30 @@ const DT: f32 = 1.0 / 60.0;
 
 fn spawn_brick(world: &mut World, pos: Vec2) -> ecs::EntityId {
     world
-        .spawn()
-        .with(Transform2D::new(pos))
-        .with(RigidBody::new_static())
-        .with(
+        .spawn((
+            Transform2D::new(pos),
+            RigidBody::new_static(),
             Collider::box_collider(70.0, 24.0)
                 .with_friction(0.0)
                 .with_restitution(1.0),
-        )
+        ))
         .id()
 }
 
 fn spawn_ball(world: &mut World, pos: Vec2) -> ecs::EntityId {
     world
-        .spawn()
-        .with(Transform2D::new(pos))
-        .with(
+        .spawn((
+            Transform2D::new(pos),
             RigidBody::new_dynamic()
                 .with_gravity_scale(0.0)
                 .with_rotation_locked(true)
                 .with_linear_damping(0.0)
                 .with_angular_damping(0.0)
                 .with_ccd(true),
-        )
-        .with(
             Collider::circle_collider(8.0)
                 .with_friction(0.0)
                 .with_restitution(1.0),
-        )
+        ))
         .id()
 }
 
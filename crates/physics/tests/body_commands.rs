@@ -0,0 +1,149 @@
+//! Component-side body commands: `RigidBody::set_velocity`,
+//! `apply_impulse` and `add_force` queue on the component and reach rapier
+//! on the next `PhysicsSystem::update`, whose writeback then reports the
+//! resulting motion in the same component.
+
+use glam::Vec2;
+
+use ecs::sprite_components::Transform2D;
+use ecs::{EntityId, System, World};
+
+use physics::{Collider, PhysicsConfig, PhysicsSystem, RigidBody};
+
+const DT: f32 = 1.0 / 60.0;
+
+/// A weightless, undamped dynamic box at the origin.
+fn floating_box(world: &mut World) -> EntityId {
+    world
+        .spawn((
+            Transform2D::new(Vec2::ZERO),
+            RigidBody::new_dynamic().with_gravity_scale(0.0).with_rotation_locked(true),
+            Collider::box_collider(16.0, 16.0),
+        ))
+        .id()
+}
+
+fn zero_gravity_system(world: &mut World) -> PhysicsSystem {
+    let mut system = PhysicsSystem::with_config(PhysicsConfig::new(Vec2::ZERO));
+    system.initialize(world).unwrap();
+    system
+}
+
+#[test]
+fn test_component_set_velocity_reaches_rapier_and_reads_back() {
+    let mut world = World::new();
+    let mut system = zero_gravity_system(&mut world);
+    let entity = floating_box(&mut world);
+    system.update(&mut world, DT);
+
+    world.get_mut::<RigidBody>(entity).unwrap().set_velocity(Vec2::new(120.0, 0.0), 0.0);
+    assert!(world.get::<RigidBody>(entity).unwrap().has_pending_commands());
+    system.update(&mut world, DT);
+
+    let (velocity, _) = system.get_body_velocity(entity).unwrap();
+    assert!((velocity.x - 120.0).abs() < 0.5, "rapier got {velocity:?}");
+    let body = world.get::<RigidBody>(entity).unwrap();
+    assert!(!body.has_pending_commands(), "flushed commands are cleared");
+    assert!((body.velocity.x - 120.0).abs() < 0.5, "writeback reports {:?}", body.velocity);
+    assert!(world.get::<Transform2D>(entity).unwrap().position.x > 0.0);
+    assert_eq!(system.external_edits_pushed_last_update(), 0, "a command is not an external edit");
+}
+
+#[test]
+fn test_set_velocity_on_a_body_spawned_this_frame() {
+    let mut world = World::new();
+    let mut system = zero_gravity_system(&mut world);
+    let entity = floating_box(&mut world);
+
+    world.get_mut::<RigidBody>(entity).unwrap().set_velocity(Vec2::new(0.0, 60.0), 0.0);
+    system.update(&mut world, DT);
+
+    let velocity = world.get::<RigidBody>(entity).unwrap().velocity;
+    assert!((velocity.y - 60.0).abs() < 0.5, "got {velocity:?}");
+}
+
+#[test]
+fn test_set_velocity_is_pushed_even_when_unchanged_since_readback() {
+    let mut world = World::new();
+    let mut system = zero_gravity_system(&mut world);
+    let entity = floating_box(&mut world);
+    world.get_mut::<RigidBody>(entity).unwrap().set_velocity(Vec2::new(50.0, 0.0), 0.0);
+    system.update(&mut world, DT);
+
+    // Something outside the ECS changes rapier's velocity; the component
+    // still holds the old readback until the next update
+    system.physics_world_mut().set_velocity(entity, Vec2::ZERO, 0.0);
+    world.get_mut::<RigidBody>(entity).unwrap().set_velocity(Vec2::new(50.0, 0.0), 0.0);
+    system.update(&mut world, DT);
+
+    let (velocity, _) = system.get_body_velocity(entity).unwrap();
+    assert!((velocity.x - 50.0).abs() < 0.5, "got {velocity:?}");
+}
+
+#[test]
+fn test_impulses_add_up_and_scale_with_mass() {
+    let mut world = World::new();
+    let mut system = zero_gravity_system(&mut world);
+    let entity = floating_box(&mut world);
+    system.update(&mut world, DT);
+
+    let body = world.get_mut::<RigidBody>(entity).unwrap();
+    body.apply_impulse(Vec2::new(10.0, 0.0));
+    body.apply_impulse(Vec2::new(10.0, 0.0));
+    system.update(&mut world, DT);
+    let doubled = world.get::<RigidBody>(entity).unwrap().velocity.x;
+    assert!(doubled > 0.0);
+
+    // Same impulse on a box with four times the area (and mass)
+    let heavy = world
+        .spawn((
+            Transform2D::new(Vec2::new(0.0, 200.0)),
+            RigidBody::new_dynamic().with_gravity_scale(0.0).with_rotation_locked(true),
+            Collider::box_collider(32.0, 32.0),
+        ))
+        .id();
+    system.update(&mut world, DT);
+    world.get_mut::<RigidBody>(heavy).unwrap().apply_impulse(Vec2::new(20.0, 0.0));
+    system.update(&mut world, DT);
+    let heavy_speed = world.get::<RigidBody>(heavy).unwrap().velocity.x;
+    assert!((doubled / heavy_speed - 4.0).abs() < 0.1, "light {doubled}, heavy {heavy_speed}");
+}
+
+#[test]
+fn test_set_velocity_overrides_earlier_impulses_but_not_later_ones() {
+    let mut world = World::new();
+    let mut system = zero_gravity_system(&mut world);
+    let entity = floating_box(&mut world);
+    system.update(&mut world, DT);
+
+    let body = world.get_mut::<RigidBody>(entity).unwrap();
+    body.apply_impulse(Vec2::new(0.0, 500.0));
+    body.set_velocity(Vec2::new(30.0, 0.0), 0.0);
+    system.update(&mut world, DT);
+    let velocity = world.get::<RigidBody>(entity).unwrap().velocity;
+    assert!(velocity.y.abs() < 0.01 && (velocity.x - 30.0).abs() < 0.5, "got {velocity:?}");
+
+    let body = world.get_mut::<RigidBody>(entity).unwrap();
+    body.set_velocity(Vec2::ZERO, 0.0);
+    body.apply_impulse(Vec2::new(0.0, 5.0));
+    system.update(&mut world, DT);
+    let velocity = world.get::<RigidBody>(entity).unwrap().velocity;
+    assert!(velocity.x.abs() < 0.01 && velocity.y > 0.0, "got {velocity:?}");
+}
+
+#[test]
+fn test_component_force_lasts_one_update() {
+    let mut world = World::new();
+    let mut system = zero_gravity_system(&mut world);
+    let entity = floating_box(&mut world);
+    system.update(&mut world, DT);
+
+    world.get_mut::<RigidBody>(entity).unwrap().add_force(Vec2::new(10_000.0, 0.0));
+    system.update(&mut world, DT);
+    let pushed = world.get::<RigidBody>(entity).unwrap().velocity.x;
+    assert!(pushed > 0.0, "the force accelerated the body");
+
+    system.update(&mut world, DT);
+    let coasting = world.get::<RigidBody>(entity).unwrap().velocity.x;
+    assert!((coasting - pushed).abs() < 0.5, "no force the update after ({pushed} → {coasting})");
+}
@@ -0,0 +1,79 @@
+//! Marker-based collision event filtering: with
+//! `PhysicsConfig::filter_collision_events`, only pairs a `CollisionEvents`
+//! component subscribes to produce events.
+
+use glam::Vec2;
+
+use ecs::sprite_components::Transform2D;
+use ecs::{EntityId, System, World};
+
+use physics::{Collider, CollisionEvents, PhysicsConfig, PhysicsSystem, RigidBody};
+
+const DT: f32 = 1.0 / 60.0;
+
+struct Ground;
+
+/// A wide static floor with two boxes resting on it, overlapping slightly.
+fn floor_and_boxes(filter: bool) -> (World, PhysicsSystem, [EntityId; 3]) {
+    let mut world = World::new();
+    let config = PhysicsConfig::default().with_collision_event_filtering(filter);
+    let mut system = PhysicsSystem::with_config(config);
+
+    let floor = world.create_entity();
+    world.add_component(&floor, Transform2D::new(Vec2::ZERO)).unwrap();
+    world.add_component(&floor, RigidBody::new_static()).unwrap();
+    world.add_component(&floor, Collider::box_collider(400.0, 20.0)).unwrap();
+    world.add_component(&floor, Ground).unwrap();
+
+    let boxes = [-100.0, 100.0].map(|x| {
+        let entity = world.create_entity();
+        world.add_component(&entity, Transform2D::new(Vec2::new(x, 17.0))).unwrap();
+        world.add_component(&entity, RigidBody::new_dynamic()).unwrap();
+        world.add_component(&entity, Collider::box_collider(16.0, 16.0)).unwrap();
+        entity
+    });
+    system.initialize(&mut world).unwrap();
+    (world, system, [floor, boxes[0], boxes[1]])
+}
+
+/// Entities that took part in any collision event over a few updates.
+fn colliding_entities(world: &mut World, system: &mut PhysicsSystem) -> Vec<EntityId> {
+    let mut entities = Vec::new();
+    for _ in 0..5 {
+        system.update(world, DT);
+        for collision in system.take_collision_events() {
+            entities.extend([collision.event.entity_a, collision.event.entity_b]);
+        }
+    }
+    entities.sort_by_key(|entity| entity.value());
+    entities.dedup();
+    entities
+}
+
+#[test]
+fn test_unfiltered_world_reports_every_pair() {
+    let (mut world, mut system, [floor, left, right]) = floor_and_boxes(false);
+    let mut expected = vec![floor, left, right];
+    expected.sort_by_key(|entity| entity.value());
+    assert_eq!(colliding_entities(&mut world, &mut system), expected);
+}
+
+#[test]
+fn test_filtered_world_reports_only_subscribed_pairs() {
+    let (mut world, mut system, [floor, left, right]) = floor_and_boxes(true);
+    world.add_component(&left, CollisionEvents::enabled()).unwrap();
+
+    let entities = colliding_entities(&mut world, &mut system);
+    assert!(entities.contains(&left) && entities.contains(&floor));
+    assert!(!entities.contains(&right), "the right box has no subscription");
+
+    // A typed subscription on the right box picks up its floor contact too
+    world.add_component(&right, CollisionEvents::with::<Ground>()).unwrap();
+    assert!(colliding_entities(&mut world, &mut system).contains(&right));
+}
+
+#[test]
+fn test_filtered_world_without_subscribers_is_silent() {
+    let (mut world, mut system, _) = floor_and_boxes(true);
+    assert!(colliding_entities(&mut world, &mut system).is_empty());
+}
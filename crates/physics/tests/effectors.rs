@@ -0,0 +1,61 @@
+//! Effectors: `AreaForce`, `Buoyancy` and `Drag` zones push the dynamic
+//! bodies inside them before every fixed step.
+
+use glam::Vec2;
+
+use ecs::sprite_components::Transform2D;
+use ecs::{EntityId, System, World};
+
+use physics::{AreaForce, Buoyancy, Collider, Drag, PhysicsConfig, PhysicsSystem, RigidBody, ZoneShape};
+
+const DT: f32 = 1.0 / 60.0;
+
+fn spawn_box(world: &mut World, position: Vec2, velocity: Vec2) -> EntityId {
+    let mut body = RigidBody::new_dynamic();
+    body.velocity = velocity;
+    world.spawn((Transform2D::new(position), body, Collider::box_collider(20.0, 20.0))).id()
+}
+
+fn run(world: &mut World, system: &mut PhysicsSystem, updates: usize) {
+    for _ in 0..updates {
+        system.update(world, DT);
+    }
+}
+
+fn body(world: &World, entity: EntityId) -> (Vec2, Vec2) {
+    let position = world.get::<Transform2D>(entity).unwrap().position;
+    (position, world.get::<RigidBody>(entity).unwrap().velocity)
+}
+
+#[test]
+fn test_box_settles_half_submerged_in_density_two_fluid() {
+    let mut world = World::new();
+    let mut system = PhysicsSystem::new();
+    // Surface at y = 0
+    let water = Buoyancy::new(ZoneShape::rect(400.0, 400.0)).with_density(2.0).with_drag(3.0);
+    world.spawn((Transform2D::new(Vec2::new(0.0, -200.0)), water));
+    let crate_box = spawn_box(&mut world, Vec2::new(0.0, 60.0), Vec2::ZERO);
+
+    run(&mut world, &mut system, 600);
+    let (position, velocity) = body(&world, crate_box);
+    assert!(position.y.abs() < 2.0, "floats with its center at the surface: {position}");
+    assert!(velocity.length() < 5.0, "{velocity}");
+}
+
+#[test]
+fn test_area_force_pushes_and_drag_slows_bodies_inside() {
+    let mut world = World::new();
+    let mut system = PhysicsSystem::with_config(PhysicsConfig::new(Vec2::ZERO));
+    world.spawn((Transform2D::new(Vec2::ZERO), AreaForce::new(ZoneShape::circle(100.0), Vec2::new(0.0, 20.0))));
+    world.spawn((Transform2D::new(Vec2::new(500.0, 0.0)), Drag::new(ZoneShape::rect(300.0, 100.0), 4.0)));
+    let blown = spawn_box(&mut world, Vec2::ZERO, Vec2::ZERO);
+    let slowed = spawn_box(&mut world, Vec2::new(500.0, 0.0), Vec2::new(100.0, 0.0));
+    let free = spawn_box(&mut world, Vec2::new(0.0, -500.0), Vec2::new(100.0, 0.0));
+
+    run(&mut world, &mut system, 30);
+    assert!(body(&world, blown).1.y > 10.0, "{}", body(&world, blown).1);
+    // e^(-4 × 0.5 s) ≈ 0.14 of the starting speed
+    let slowed_speed = body(&world, slowed).1.x;
+    assert!(slowed_speed > 5.0 && slowed_speed < 25.0, "{slowed_speed}");
+    assert!((body(&world, free).1.x - 100.0).abs() < 1e-3, "outside every zone");
+}
@@ -1,14 +1,15 @@
 //! External ECS-side edit detection (PATTERNS_AUDIT.md GPP-09):
-//! live `Transform2D` edits teleport rapier bodies, live `Collider`
-//! edits rebuild rapier colliders, and the physics writeback is never
-//! mistaken for an external edit.
+//! live `Transform2D` edits teleport rapier bodies, live `RigidBody`
+//! velocity edits set the body's velocity, live `Collider` edits rebuild
+//! rapier colliders, and the physics writeback is never mistaken for an
+//! external edit.
 
 use glam::Vec2;
 
 use ecs::sprite_components::Transform2D;
 use ecs::{System, World};
 
-use physics::{Collider, ColliderShape, PhysicsConfig, PhysicsSystem, RigidBody};
+use physics::{pending_physics_edits, Collider, ColliderShape, PhysicsConfig, PhysicsSystem, RigidBody};
 
 #[test]
 fn test_external_transform_edit_teleports_live_body() {
@@ -165,3 +166,57 @@ fn test_collider_component_removal_drops_rapier_collider() {
     );
     assert_eq!(system.external_edits_pushed_last_update(), 1);
 }
+
+#[test]
+fn test_external_velocity_edit_sets_live_body_velocity() {
+    let mut world = World::new();
+    let mut system = PhysicsSystem::with_config(PhysicsConfig::new(Vec2::ZERO));
+
+    let entity = world.create_entity();
+    world.add_component(&entity, Transform2D::new(Vec2::ZERO)).unwrap();
+    world
+        .add_component(&entity, RigidBody::new_dynamic().with_gravity_scale(0.0))
+        .unwrap();
+    world.add_component(&entity, Collider::box_collider(16.0, 16.0)).unwrap();
+
+    system.initialize(&mut world).unwrap();
+    system.update(&mut world, 1.0 / 60.0);
+
+    // Inspector-style edit of the component's velocity (previously
+    // overwritten by the next writeback).
+    world.get_mut::<RigidBody>(entity).unwrap().velocity = Vec2::new(0.0, 120.0);
+    system.update(&mut world, 1.0 / 60.0);
+
+    assert_eq!(system.external_edits_pushed_last_update(), 1);
+    let (vel, _) = system.get_body_velocity(entity).unwrap();
+    assert!((vel.y - 120.0).abs() < 1.0, "the edited velocity must reach rapier (got {vel:?})");
+    assert!(world.get::<Transform2D>(entity).unwrap().position.y > 0.0);
+}
+
+#[test]
+fn test_paused_edits_are_pending_until_the_next_update() {
+    let mut world = World::new();
+    let mut system = PhysicsSystem::with_config(PhysicsConfig::new(Vec2::ZERO));
+
+    let entity = world.create_entity();
+    world.add_component(&entity, Transform2D::new(Vec2::ZERO)).unwrap();
+    world
+        .add_component(&entity, RigidBody::new_dynamic().with_gravity_scale(0.0))
+        .unwrap();
+    world.add_component(&entity, Collider::box_collider(16.0, 16.0)).unwrap();
+    assert!(pending_physics_edits(&world).is_empty(), "nothing to diverge from before the first update");
+
+    system.initialize(&mut world).unwrap();
+    system.update(&mut world, 1.0 / 60.0);
+    assert!(pending_physics_edits(&world).is_empty(), "the writeback itself is not an edit");
+
+    // Paused: the game (and physics) stop updating while the editor moves
+    // the entity. Resuming must adopt the edit, not snap the body back.
+    world.get_mut::<Transform2D>(entity).unwrap().position = Vec2::new(200.0, 50.0);
+    assert_eq!(pending_physics_edits(&world), vec![entity]);
+
+    system.update(&mut world, 1.0 / 60.0);
+    assert!(pending_physics_edits(&world).is_empty());
+    let pos = world.get::<Transform2D>(entity).unwrap().position;
+    assert!((pos - Vec2::new(200.0, 50.0)).length() < 1.0, "resume kept the paused edit (got {pos:?})");
+}
@@ -0,0 +1,75 @@
+//! Gravity zones: `GravityZone` areas replace or add to the global gravity
+//! for the dynamic bodies inside them.
+
+use glam::Vec2;
+
+use ecs::sprite_components::Transform2D;
+use ecs::{EntityId, System, World};
+
+use physics::{Collider, GravityFalloff, GravityZone, PhysicsConfig, PhysicsSystem, RigidBody, ZoneShape};
+
+const DT: f32 = 1.0 / 60.0;
+
+fn spawn_ball(world: &mut World, position: Vec2) -> EntityId {
+    world.spawn((Transform2D::new(position), RigidBody::new_dynamic(), Collider::circle_collider(8.0))).id()
+}
+
+fn run(world: &mut World, system: &mut PhysicsSystem, updates: usize) {
+    for _ in 0..updates {
+        system.update(world, DT);
+    }
+}
+
+fn position(world: &World, entity: EntityId) -> Vec2 {
+    world.get::<Transform2D>(entity).unwrap().position
+}
+
+#[test]
+fn test_override_zone_replaces_global_gravity_inside_only() {
+    let mut world = World::new();
+    let mut system = PhysicsSystem::new();
+    let space = GravityZone::directional(ZoneShape::rect(200.0, 200.0), Vec2::ZERO);
+    world.spawn((Transform2D::new(Vec2::ZERO), space));
+    let floating = spawn_ball(&mut world, Vec2::ZERO);
+    let falling = spawn_ball(&mut world, Vec2::new(500.0, 0.0));
+
+    run(&mut world, &mut system, 30);
+    assert!(position(&world, floating).length() < 0.5, "{}", position(&world, floating));
+    assert!(position(&world, falling).y < -50.0);
+}
+
+#[test]
+fn test_additive_zone_stacks_on_global_gravity() {
+    let mut world = World::new();
+    let mut system = PhysicsSystem::new();
+    let fan = GravityZone::directional(ZoneShape::rect(400.0, 400.0), Vec2::new(0.0, 1960.0)).additive();
+    world.spawn((Transform2D::new(Vec2::ZERO), fan));
+    let ball = spawn_ball(&mut world, Vec2::ZERO);
+
+    run(&mut world, &mut system, 20);
+    // Net 980 px/s² upward: the global pull is still there
+    let velocity = world.get::<RigidBody>(ball).unwrap().velocity;
+    assert!((velocity.y - 980.0 * 20.0 * DT).abs() < 20.0, "{velocity}");
+}
+
+#[test]
+fn test_radial_zone_pulls_toward_its_center_scaled_by_gravity_scale() {
+    let mut world = World::new();
+    let mut system = PhysicsSystem::with_config(PhysicsConfig::new(Vec2::ZERO));
+    let planet = GravityZone::radial(ZoneShape::circle(300.0), 500.0, GravityFalloff::Constant);
+    world.spawn((Transform2D::new(Vec2::ZERO), planet));
+    let moon = spawn_ball(&mut world, Vec2::new(100.0, 0.0));
+    let ghost = world
+        .spawn((
+            Transform2D::new(Vec2::new(0.0, 100.0)),
+            RigidBody::new_dynamic().with_gravity_scale(0.0),
+            Collider::circle_collider(8.0),
+        ))
+        .id();
+
+    run(&mut world, &mut system, 10);
+    let moon_position = position(&world, moon);
+    assert!(moon_position.x < 95.0 && moon_position.y.abs() < 0.1, "{moon_position}");
+    let ghost_position = position(&world, ghost);
+    assert!((ghost_position - Vec2::new(0.0, 100.0)).length() < 1e-3, "a gravity scale of 0 ignores zones too");
+}
@@ -0,0 +1,60 @@
+//! Transform interpolation (`PhysicsConfig::interpolate`): between fixed
+//! steps the writeback blends each moving body from its previous pose
+//! toward the current one, while rapier keeps the true pose; teleports
+//! snap instead of blending.
+
+use glam::Vec2;
+
+use ecs::sprite_components::Transform2D;
+use ecs::{EntityId, System, World};
+
+use physics::{Collider, PhysicsConfig, PhysicsSystem, RigidBody};
+
+const DT: f32 = 1.0 / 60.0;
+
+/// One body moving right at 600 px/s (10 px per step), after one step.
+fn moving_body(interpolate: bool) -> (World, PhysicsSystem, EntityId) {
+    let mut world = World::new();
+    let config = PhysicsConfig::new(Vec2::ZERO).with_interpolation(interpolate);
+    let mut system = PhysicsSystem::with_config(config);
+    let entity = world.create_entity();
+    world.add_component(&entity, Transform2D::new(Vec2::ZERO)).unwrap();
+    world
+        .add_component(&entity, RigidBody::new_dynamic().with_velocity(Vec2::new(600.0, 0.0)))
+        .unwrap();
+    world.add_component(&entity, Collider::circle_collider(8.0)).unwrap();
+    system.initialize(&mut world).unwrap();
+    system.update(&mut world, DT);
+    (world, system, entity)
+}
+
+fn transform_x(world: &World, entity: EntityId) -> f32 {
+    world.get::<Transform2D>(entity).unwrap().position.x
+}
+
+#[test]
+fn test_interpolated_transform_blends_between_steps() {
+    let (mut world, mut system, entity) = moving_body(true);
+    assert!(transform_x(&world, entity).abs() < 0.01, "no leftover time: previous pose");
+
+    // Half a step of leftover time: halfway between the two poses.
+    system.update(&mut world, DT / 2.0);
+    assert!((transform_x(&world, entity) - 5.0).abs() < 0.1, "got {}", transform_x(&world, entity));
+    let (body_position, _) = system.physics_world().get_body_transform(entity).unwrap();
+    assert!((body_position.x - 10.0).abs() < 0.1, "rapier keeps the true pose");
+}
+
+#[test]
+fn test_without_interpolation_transform_is_the_body_pose() {
+    let (mut world, mut system, entity) = moving_body(false);
+    system.update(&mut world, DT / 2.0);
+    assert!((transform_x(&world, entity) - 10.0).abs() < 0.1, "got {}", transform_x(&world, entity));
+}
+
+#[test]
+fn test_teleport_snaps_instead_of_blending() {
+    let (mut world, mut system, entity) = moving_body(true);
+    world.get_mut::<Transform2D>(entity).unwrap().position = Vec2::new(500.0, 0.0);
+    system.update(&mut world, DT / 2.0);
+    assert!((transform_x(&world, entity) - 500.0).abs() < 0.1, "got {}", transform_x(&world, entity));
+}
@@ -0,0 +1,74 @@
+//! Rigid body sleeping: bodies at rest fall asleep after rapier's
+//! time-until-sleep, report it through `RigidBody::is_sleeping` and
+//! `BodySleep`/`BodyWake` events, and can be kept awake or woken.
+
+use glam::Vec2;
+
+use ecs::sprite_components::Transform2D;
+use ecs::{EntityId, System, World};
+
+use physics::{BodySleep, BodyWake, Collider, PhysicsConfig, PhysicsSystem, RigidBody};
+
+const DT: f32 = 1.0 / 60.0;
+
+/// A zero-gravity world holding one resting dynamic body.
+fn resting_body(body: RigidBody) -> (World, PhysicsSystem, EntityId) {
+    let mut world = World::new();
+    let mut system = PhysicsSystem::with_config(PhysicsConfig::new(Vec2::ZERO));
+    let entity = world.create_entity();
+    world.add_component(&entity, Transform2D::new(Vec2::ZERO)).unwrap();
+    world.add_component(&entity, body).unwrap();
+    world.add_component(&entity, Collider::box_collider(16.0, 16.0)).unwrap();
+    system.initialize(&mut world).unwrap();
+    (world, system, entity)
+}
+
+/// Step long enough (3s) for a resting body to pass rapier's 2s
+/// time-until-sleep.
+fn settle(world: &mut World, system: &mut PhysicsSystem) {
+    for _ in 0..180 {
+        system.update(world, DT);
+    }
+}
+
+#[test]
+fn test_resting_body_falls_asleep_and_emits_body_sleep() {
+    let (mut world, mut system, entity) = resting_body(RigidBody::new_dynamic());
+    system.update(&mut world, DT);
+    assert!(!world.get::<RigidBody>(entity).unwrap().is_sleeping());
+
+    settle(&mut world, &mut system);
+
+    assert!(system.is_sleeping(entity));
+    assert!(world.get::<RigidBody>(entity).unwrap().is_sleeping());
+    let sleeps = world.read_events::<BodySleep>();
+    assert_eq!(sleeps.len(), 1);
+    assert_eq!(sleeps[0].entity, entity);
+    assert!(world.read_events::<BodyWake>().is_empty());
+}
+
+#[test]
+fn test_wake_up_emits_body_wake() {
+    let (mut world, mut system, entity) = resting_body(RigidBody::new_dynamic());
+    settle(&mut world, &mut system);
+    assert!(system.is_sleeping(entity));
+
+    system.wake_up(entity);
+    system.update(&mut world, DT);
+
+    assert!(!world.get::<RigidBody>(entity).unwrap().is_sleeping());
+    assert_eq!(world.read_events::<BodyWake>().iter().map(|e| e.entity).collect::<Vec<_>>(), vec![entity]);
+}
+
+#[test]
+fn test_body_with_sleeping_disabled_stays_awake() {
+    let (mut world, mut system, entity) = resting_body(RigidBody::new_dynamic().with_sleeping(false));
+    settle(&mut world, &mut system);
+    assert!(!system.is_sleeping(entity));
+    assert!(world.read_events::<BodySleep>().is_empty());
+
+    // Re-enabling on the live body lets it sleep without a rebuild
+    world.get_mut::<RigidBody>(entity).unwrap().can_sleep = true;
+    settle(&mut world, &mut system);
+    assert!(system.is_sleeping(entity));
+}
@@ -1,4 +1,5 @@
-//! Texture atlas types: runtime atlas regions and the builder that packs them.
+//! Texture atlas types: runtime atlas regions, the builder that packs them
+//! (up front or image by image at runtime), and the shelf packer both use.
 
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -84,12 +85,47 @@ pub struct AtlasRegion {
     pub data: Option<Vec<u8>>, // RGBA data
 }
 
-/// Builder for creating texture atlases
+/// Pixels written into a [`TextureAtlasBuilder`] that a live atlas texture
+/// has not received yet (see [`TextureAtlasBuilder::take_uploads`]).
+#[derive(Debug, Clone, PartialEq)]
+pub struct AtlasUpload {
+    pub x: u32,
+    pub y: u32,
+    pub width: u32,
+    pub height: u32,
+    /// RGBA rows of the region, padding included
+    pub data: Vec<u8>,
+}
+
+/// Builder for creating texture atlases.
+///
+/// Regions can be staged with [`add_region`](Self::add_region) and packed
+/// by [`build`](Self::build), or packed at runtime with
+/// [`insert`](Self::insert), which places the image straight away and
+/// returns its UV region. Inserted pixels are kept in a CPU-side RGBA
+/// buffer the size of the atlas; `build` uploads it, and an atlas already
+/// on the GPU can be kept current with [`take_uploads`](Self::take_uploads).
+///
+/// ```
+/// use renderer::TextureAtlasBuilder;
+///
+/// let mut atlas = TextureAtlasBuilder::new(64, 64).with_padding(1);
+/// let uv = atlas.insert("coin", 8, 8, &[255; 8 * 8 * 4]).unwrap();
+/// assert_eq!(uv, [1.0 / 64.0, 1.0 / 64.0, 8.0 / 64.0, 8.0 / 64.0]);
+/// assert_eq!(atlas.region("coin"), Some(uv));
+/// ```
 pub struct TextureAtlasBuilder {
     regions: Vec<AtlasRegion>,
     max_width: u32,
     max_height: u32,
     padding: u32,
+    packer: ShelfPacker,
+    /// RGBA pixels of the whole atlas, allocated on the first insert
+    pixels: Vec<u8>,
+    /// Inserted regions by name, as UV rectangles
+    packed: HashMap<String, [f32; 4]>,
+    /// Padded pixel rects written since the last `take_uploads`
+    pending: Vec<(u32, u32, u32, u32)>,
 }
 
 impl TextureAtlasBuilder {
@@ -100,6 +136,10 @@ impl TextureAtlasBuilder {
             max_width,
             max_height,
             padding: 2,
+            packer: ShelfPacker::new(max_width, max_height),
+            pixels: Vec::new(),
+            packed: HashMap::new(),
+            pending: Vec::new(),
         }
     }
 
@@ -120,48 +160,190 @@ impl TextureAtlasBuilder {
         self
     }
 
-    /// Build the texture atlas
-    pub fn build(self, device: &Device, _queue: &Queue) -> Result<TextureAtlas, TextureError> {
-        // Simple packing algorithm - for production, use a proper bin packing algorithm
-        let mut x = 0;
-        let mut y = 0;
-        let mut max_row_height = 0;
+    /// Pack a `width`×`height` RGBA image now and return its UV region as
+    /// `[u, v, width, height]` (the sprite `tex_region` layout).
+    ///
+    /// The image gets a transparent border of the builder's padding so
+    /// linear filtering never samples a neighbour. Inserting a name again
+    /// packs the new image and points the name at it; the old space is
+    /// only reclaimed by [`clear`](Self::clear).
+    pub fn insert(&mut self, name: impl Into<String>, width: u32, height: u32, rgba: &[u8]) -> Result<[f32; 4], TextureError> {
+        if width == 0 || height == 0 || rgba.len() != (width * height * 4) as usize {
+            return Err(TextureError::InvalidFormat);
+        }
+        let (padded_width, padded_height) = (width + 2 * self.padding, height + 2 * self.padding);
+        let (x, y) = self.packer.allocate(padded_width, padded_height).ok_or_else(|| {
+            TextureError::TextureCreationError(format!(
+                "no room for a {}x{} region in the {}x{} atlas",
+                width, height, self.max_width, self.max_height
+            ))
+        })?;
+
+        if self.pixels.is_empty() {
+            self.pixels = vec![0; (self.max_width * self.max_height * 4) as usize];
+        }
+        // Clear the padding ring too: space can be reused after `clear`
+        let stride = (self.max_width * 4) as usize;
+        for row in 0..padded_height {
+            let start = (y + row) as usize * stride + (x * 4) as usize;
+            self.pixels[start..start + (padded_width * 4) as usize].fill(0);
+        }
+        for (row, source) in rgba.chunks_exact((width * 4) as usize).enumerate() {
+            let start = (y + self.padding + row as u32) as usize * stride + ((x + self.padding) * 4) as usize;
+            self.pixels[start..start + source.len()].copy_from_slice(source);
+        }
+        self.pending.push((x, y, padded_width, padded_height));
 
-        let mut atlas_regions = Vec::new();
+        let uv = self.uv(x + self.padding, y + self.padding, width, height);
+        self.packed.insert(name.into(), uv);
+        Ok(uv)
+    }
 
-        for region in &self.regions {
-            if x + region.width + self.padding > self.max_width {
-                // Move to next row
-                x = 0;
-                y += max_row_height + self.padding;
-                max_row_height = 0;
-            }
+    /// UV region of an inserted image.
+    pub fn region(&self, name: &str) -> Option<[f32; 4]> {
+        self.packed.get(name).copied()
+    }
 
-            if y + region.height + self.padding > self.max_height {
-                return Err(TextureError::TextureCreationError("Atlas too small for all regions".to_string()));
-            }
+    /// Atlas dimensions as (width, height).
+    pub fn size(&self) -> (u32, u32) {
+        (self.max_width, self.max_height)
+    }
 
-            atlas_regions.push((region.name.clone(), x, y, region.width, region.height));
+    /// RGBA pixels of the whole atlas (row-major, `width * 4` bytes per
+    /// row); empty until the first [`insert`](Self::insert).
+    pub fn pixels(&self) -> &[u8] {
+        &self.pixels
+    }
 
-            x += region.width + self.padding;
-            max_row_height = max_row_height.max(region.height);
-        }
+    /// Take the regions inserted since the last call, for writing into an
+    /// atlas texture that is already on the GPU.
+    pub fn take_uploads(&mut self) -> Vec<AtlasUpload> {
+        let stride = (self.max_width * 4) as usize;
+        self.pending
+            .drain(..)
+            .map(|(x, y, width, height)| {
+                let mut data = Vec::with_capacity((width * height * 4) as usize);
+                for row in y..y + height {
+                    let start = row as usize * stride + (x * 4) as usize;
+                    data.extend_from_slice(&self.pixels[start..start + (width * 4) as usize]);
+                }
+                AtlasUpload { x, y, width, height, data }
+            })
+            .collect()
+    }
+
+    /// Forget every inserted region (and any not yet uploaded) so the
+    /// whole atlas can be refilled.
+    pub fn clear(&mut self) {
+        self.packer.clear();
+        self.packed.clear();
+        self.pending.clear();
+        self.pixels.fill(0);
+    }
 
-        let atlas_width = self.max_width;
-        let atlas_height = y + max_row_height + self.padding;
+    /// UV rectangle of a pixel rect on the atlas.
+    fn uv(&self, x: u32, y: u32, width: u32, height: u32) -> [f32; 4] {
+        let (atlas_width, atlas_height) = (self.max_width as f32, self.max_height as f32);
+        [
+            x as f32 / atlas_width,
+            y as f32 / atlas_height,
+            width as f32 / atlas_width,
+            height as f32 / atlas_height,
+        ]
+    }
 
-        // Create the atlas texture
-        let mut atlas = TextureAtlas::new(device, atlas_width, atlas_height);
+    /// Build the texture atlas: pack the staged regions (without data they
+    /// stay transparent) and upload every packed pixel.
+    pub fn build(mut self, device: &Device, queue: &Queue) -> Result<TextureAtlas, TextureError> {
+        for region in std::mem::take(&mut self.regions) {
+            let blank;
+            let data = match &region.data {
+                Some(data) => data.as_slice(),
+                None => {
+                    blank = vec![0u8; (region.width * region.height * 4) as usize];
+                    blank.as_slice()
+                }
+            };
+            self.insert(region.name, region.width, region.height, data)?;
+        }
 
-        // Add regions to atlas
-        for (name, x, y, width, height) in atlas_regions {
-            atlas.add_region(name, x, y, width, height, atlas_width, atlas_height);
+        let mut atlas = TextureAtlas::new(device, self.max_width, self.max_height);
+        if !self.pixels.is_empty() {
+            queue.write_texture(
+                wgpu::TexelCopyTextureInfo {
+                    texture: &atlas.texture,
+                    mip_level: 0,
+                    origin: wgpu::Origin3d::ZERO,
+                    aspect: wgpu::TextureAspect::All,
+                },
+                &self.pixels,
+                wgpu::TexelCopyBufferLayout {
+                    offset: 0,
+                    bytes_per_row: Some(self.max_width * 4),
+                    rows_per_image: None,
+                },
+                wgpu::Extent3d { width: self.max_width, height: self.max_height, depth_or_array_layers: 1 },
+            );
         }
+        atlas.regions = self.packed;
 
         Ok(atlas)
     }
 }
 
+/// Incremental shelf packer for atlases filled at runtime (what
+/// [`TextureAtlasBuilder`] packs with). Rectangles go left to right on horizontal shelves; a rectangle
+/// takes the shortest open shelf it fits on, or opens a new shelf below
+/// the last one.
+#[derive(Debug, Clone)]
+pub struct ShelfPacker {
+    width: u32,
+    height: u32,
+    /// Open shelves as (top y, height, next free x)
+    shelves: Vec<(u32, u32, u32)>,
+}
+
+impl ShelfPacker {
+    /// Create an empty packer for a `width`×`height` atlas.
+    pub fn new(width: u32, height: u32) -> Self {
+        Self { width, height, shelves: Vec::new() }
+    }
+
+    /// Reserve a `width`×`height` rectangle, returning its top-left corner,
+    /// or `None` when the atlas has no room left.
+    pub fn allocate(&mut self, width: u32, height: u32) -> Option<(u32, u32)> {
+        if width == 0 || height == 0 || width > self.width {
+            return None;
+        }
+
+        let best = self.shelves.iter_mut()
+            .filter(|(_, shelf_height, next_x)| *shelf_height >= height && self.width - *next_x >= width)
+            .min_by_key(|(_, shelf_height, _)| *shelf_height);
+        if let Some((y, _, next_x)) = best {
+            let position = (*next_x, *y);
+            *next_x += width;
+            return Some(position);
+        }
+
+        let top = self.shelves.last().map_or(0, |(y, shelf_height, _)| y + shelf_height);
+        if self.height - top < height {
+            return None;
+        }
+        self.shelves.push((top, height, width));
+        Some((0, top))
+    }
+
+    /// Forget every allocation.
+    pub fn clear(&mut self) {
+        self.shelves.clear();
+    }
+
+    /// Atlas dimensions as (width, height).
+    pub fn size(&self) -> (u32, u32) {
+        (self.width, self.height)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -239,6 +421,72 @@ mod tests {
         assert_eq!(builder.regions.len(), 3);
     }
 
+    #[test]
+    fn test_insert_packs_with_padding_and_returns_uv() {
+        let mut builder = TextureAtlasBuilder::new(32, 16).with_padding(1);
+        assert_eq!(builder.insert("a", 2, 2, &[255; 16]).unwrap(), [1.0 / 32.0, 1.0 / 16.0, 2.0 / 32.0, 2.0 / 16.0]);
+        assert_eq!(builder.insert("b", 4, 2, &[128; 32]).unwrap()[0], 5.0 / 32.0, "after a's padded box");
+        assert_eq!(builder.region("a").map(|uv| uv[1]), Some(1.0 / 16.0));
+        assert_eq!(builder.region("missing"), None);
+
+        // Pixels land inside the padding ring, which stays transparent
+        let pixel = |x: usize, y: usize| builder.pixels()[(y * 32 + x) * 4];
+        assert_eq!((pixel(0, 0), pixel(1, 1), pixel(2, 2), pixel(3, 1)), (0, 255, 255, 0));
+        assert_eq!(pixel(5, 1), 128);
+    }
+
+    #[test]
+    fn test_insert_rejects_bad_data_and_a_full_atlas() {
+        let mut builder = TextureAtlasBuilder::new(8, 8).with_padding(0);
+        assert!(builder.pixels().is_empty(), "no buffer until something is inserted");
+        assert!(matches!(builder.insert("short", 2, 2, &[0; 15]), Err(TextureError::InvalidFormat)));
+        assert!(matches!(builder.insert("empty", 0, 2, &[]), Err(TextureError::InvalidFormat)));
+        assert!(builder.insert("full", 8, 8, &[1; 256]).is_ok());
+        assert!(matches!(builder.insert("more", 1, 1, &[1; 4]), Err(TextureError::TextureCreationError(_))));
+
+        builder.clear();
+        assert_eq!(builder.region("full"), None);
+        assert!(builder.pixels().iter().all(|&byte| byte == 0));
+        assert!(builder.insert("more", 1, 1, &[1; 4]).is_ok());
+    }
+
+    #[test]
+    fn test_take_uploads_returns_each_padded_insert_once() {
+        let mut builder = TextureAtlasBuilder::new(16, 16).with_padding(1);
+        builder.insert("a", 2, 1, &[9; 8]).unwrap();
+        builder.insert("b", 1, 1, &[7; 4]).unwrap();
+
+        let uploads = builder.take_uploads();
+        assert_eq!(uploads.len(), 2);
+        assert_eq!((uploads[0].x, uploads[0].y, uploads[0].width, uploads[0].height), (0, 0, 4, 3));
+        let middle_row = &uploads[0].data[4 * 4..8 * 4];
+        assert_eq!(middle_row, &[0, 0, 0, 0, 9, 9, 9, 9, 9, 9, 9, 9, 0, 0, 0, 0]);
+        assert_eq!(uploads[1].x, 4);
+        assert!(builder.take_uploads().is_empty());
+    }
+
     // Note: TextureAtlas and TextureAtlasBuilder.build() require a GPU device,
     // so those paths are exercised by ignored GPU tests / examples.
+
+    #[test]
+    fn test_shelf_packer_fills_shelves_left_to_right() {
+        let mut packer = ShelfPacker::new(32, 32);
+        assert_eq!(packer.allocate(10, 8), Some((0, 0)));
+        assert_eq!(packer.allocate(10, 6), Some((10, 0)), "shorter rects share the shelf");
+        assert_eq!(packer.allocate(10, 10), Some((0, 8)), "taller rects open a new shelf");
+        assert_eq!(packer.allocate(20, 8), Some((10, 8)), "shortest shelf with room wins");
+    }
+
+    #[test]
+    fn test_shelf_packer_reports_full_and_clears() {
+        let mut packer = ShelfPacker::new(16, 16);
+        assert_eq!(packer.allocate(17, 1), None);
+        assert_eq!(packer.allocate(16, 12), Some((0, 0)));
+        assert_eq!(packer.allocate(4, 8), None, "no shelf or height left");
+        assert_eq!(packer.allocate(0, 4), None);
+
+        packer.clear();
+        assert_eq!(packer.allocate(16, 16), Some((0, 0)));
+        assert_eq!(packer.size(), (16, 16));
+    }
 }
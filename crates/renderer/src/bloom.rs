@@ -7,8 +7,9 @@
 //!    write to `bloom_ping` (half-resolution).
 //! 2. **Blur** — separable Gaussian, ping-ponging between `bloom_ping` and
 //!    `bloom_pong`. Repeats `blur_iterations` times for a wider glow.
-//! 3. **Composite** — read HDR + final bloom, tonemap, write to the
-//!    swapchain in sRGB.
+//! 3. **Composite** — read HDR + final bloom, apply the
+//!    [`ColorConfig`] (exposure, tonemap, gamma), write to the swapchain in
+//!    sRGB.
 //!
 //! The output of the chain is the sRGB swapchain texture.
 
@@ -17,14 +18,15 @@ use wgpu::{
     PipelineLayout, Queue, RenderPipeline, Sampler, ShaderModule, TextureFormat, TextureView,
 };
 
+use crate::color_config::{ColorConfig, Tonemap};
 use crate::render_targets::{RenderTargets, HDR_FORMAT};
 
 /// Tunables for the bloom pipeline. Mutate at runtime via
 /// [`Renderer::bloom_config_mut`](crate::Renderer::bloom_config_mut).
 #[derive(Debug, Clone, Copy)]
 pub struct BloomConfig {
-    /// Master switch — when false, the composite pass still runs (to do the
-    /// tonemap), but bloom contribution is zeroed.
+    /// Master switch — when false, the composite pass still runs (to apply
+    /// the [`ColorConfig`]), but bloom contribution is zeroed.
     pub enabled: bool,
     /// Luminance threshold for the bright pass. Pixels below this don't bloom.
     pub threshold: f32,
@@ -57,9 +59,33 @@ struct BloomParams {
     threshold: f32,
     knee: f32,
     intensity: f32,
+    exposure: f32,
+    gamma: f32,
+    /// 0 = clamp, 1 = Reinhard
+    tonemap: f32,
+    /// 1 when the swapchain isn't sRGB and the shader must encode
+    encode_srgb: f32,
     _pad: f32,
 }
 
+impl BloomParams {
+    fn new(bloom: &BloomConfig, color: &ColorConfig, encode_srgb: bool) -> Self {
+        Self {
+            threshold: bloom.threshold,
+            knee: bloom.knee,
+            intensity: if bloom.enabled { bloom.intensity } else { 0.0 },
+            exposure: color.exposure,
+            gamma: color.gamma.max(ColorConfig::MIN_GAMMA),
+            tonemap: match color.tonemap {
+                Tonemap::Clamp => 0.0,
+                Tonemap::Reinhard => 1.0,
+            },
+            encode_srgb: if encode_srgb { 1.0 } else { 0.0 },
+            _pad: 0.0,
+        }
+    }
+}
+
 /// GPU layout for the blur uniform buffer.
 #[repr(C)]
 #[derive(Debug, Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
@@ -78,6 +104,17 @@ struct CachedBindGroups {
     composite: BindGroup,
 }
 
+/// What one [`BloomPipeline::run`] reads and writes.
+pub struct BloomPassInputs<'a> {
+    /// HDR scene + bloom ping-pong textures
+    pub targets: &'a RenderTargets,
+    /// Destination view (sRGB)
+    pub swapchain: &'a TextureView,
+    pub config: &'a BloomConfig,
+    /// Applied in the composite
+    pub color: &'a ColorConfig,
+}
+
 /// Owns the bloom render pipelines, samplers, and bind-group layouts.
 pub struct BloomPipeline {
     extract_pipeline: RenderPipeline,
@@ -92,6 +129,9 @@ pub struct BloomPipeline {
 
     /// Uniform buffer reused for the extract and composite passes.
     bloom_params_buffer: Buffer,
+    /// The swapchain format has no sRGB encoding, so the composite shader
+    /// does it.
+    encode_srgb: bool,
     /// Per-direction blur uniform buffers. `queue.write_buffer` flushes all
     /// writes at submit time — before any encoded pass executes — so a single
     /// shared buffer rewritten between passes would make every pass read the
@@ -105,8 +145,10 @@ pub struct BloomPipeline {
 }
 
 impl BloomPipeline {
-    /// Build all three render pipelines. Format must match the swapchain.
+    /// Build all three render pipelines. Format must match the swapchain;
+    /// a non-sRGB one is encoded in the composite shader.
     pub fn new(device: &Device, surface_format: TextureFormat) -> Self {
+        let encode_srgb = !surface_format.is_srgb();
         let extract_shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
             label: Some("Bloom Extract Shader"),
             source: wgpu::ShaderSource::Wgsl(std::borrow::Cow::Borrowed(include_str!("shaders/bloom_extract.wgsl"))),
@@ -161,7 +203,11 @@ impl BloomPipeline {
 
         let bloom_params_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
             label: Some("Bloom Params Buffer"),
-            contents: bytemuck::bytes_of(&BloomParams { threshold: 1.0, knee: 0.5, intensity: 0.8, _pad: 0.0 }),
+            contents: bytemuck::bytes_of(&BloomParams::new(
+                &BloomConfig::default(),
+                &ColorConfig::default(),
+                encode_srgb,
+            )),
             usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
         });
         let blur_params_h_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
@@ -184,36 +230,29 @@ impl BloomPipeline {
             composite_layout,
             sampler,
             bloom_params_buffer,
+            encode_srgb,
             blur_params_h_buffer,
             blur_params_v_buffer,
             cached: None,
         }
     }
 
-    /// Run the full extract → blur → composite chain.
-    ///
-    /// `targets` holds the HDR + bloom ping-pong textures.
-    /// `swapchain` is the destination view (sRGB).
+    /// Run the full extract → blur → composite chain from `inputs.targets`
+    /// into `inputs.swapchain`. Returns the number of fullscreen draw calls
+    /// issued.
     pub fn run(
         &mut self,
         device: &Device,
         queue: &Queue,
         encoder: &mut CommandEncoder,
-        targets: &RenderTargets,
-        swapchain: &TextureView,
-        config: &BloomConfig,
-    ) {
+        inputs: BloomPassInputs<'_>,
+    ) -> usize {
+        let BloomPassInputs { targets, swapchain, config, color } = inputs;
         // 1. Update the extract/composite params (tunable at runtime).
-        let intensity = if config.enabled { config.intensity } else { 0.0 };
         queue.write_buffer(
             &self.bloom_params_buffer,
             0,
-            bytemuck::bytes_of(&BloomParams {
-                threshold: config.threshold,
-                knee: config.knee,
-                intensity,
-                _pad: 0.0,
-            }),
+            bytemuck::bytes_of(&BloomParams::new(config, color, self.encode_srgb)),
         );
 
         // 2. (Re)build bind groups if the targets resized. This also rewrites
@@ -224,7 +263,7 @@ impl BloomPipeline {
             self.cached = Some(self.build_bind_groups(device, queue, targets));
         }
         let Some(cached) = self.cached.as_ref() else {
-            return;
+            return 0;
         };
 
         // 3. Extract bright pass: HDR -> bloom_ping (half-res).
@@ -260,6 +299,8 @@ impl BloomPipeline {
             swapchain,
             "Bloom Composite",
         );
+        // Extract + H/V per iteration + composite
+        2 + 2 * iterations as usize
     }
 
     /// Build the bind groups for the current render targets and write the
@@ -497,9 +538,18 @@ mod tests {
     }
 
     #[test]
-    fn bloom_params_struct_is_16_bytes() {
+    fn bloom_params_struct_is_32_bytes() {
         // Must match the uniform buffer layout the shaders expect.
-        assert_eq!(std::mem::size_of::<BloomParams>(), 16);
+        assert_eq!(std::mem::size_of::<BloomParams>(), 32);
+    }
+
+    #[test]
+    fn bloom_params_carry_color_config() {
+        let disabled = BloomConfig { enabled: false, ..Default::default() };
+        let color = ColorConfig { exposure: 2.0, gamma: 0.0, tonemap: Tonemap::Reinhard };
+        let params = BloomParams::new(&disabled, &color, true);
+        assert_eq!((params.intensity, params.exposure), (0.0, 2.0));
+        assert_eq!((params.gamma, params.tonemap, params.encode_srgb), (ColorConfig::MIN_GAMMA, 1.0, 1.0));
     }
 
     #[test]
@@ -0,0 +1,93 @@
+//! Output color settings: exposure, tonemapping and a gamma adjustment.
+//!
+//! The renderer works in linear light end to end. Textures decode through
+//! their sRGB formats, and sprite, line and trail tints are decoded in their
+//! vertex shaders. Blending then happens in the linear HDR target. The
+//! composite pass applies these settings and writes sRGB to the swapchain.
+//! The GPU encodes when the surface format is sRGB, and the shader encodes
+//! otherwise.
+
+use serde::{Deserialize, Serialize};
+
+/// How HDR values above 1.0 are brought into display range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Tonemap {
+    /// Clip at white. Colors in 0..1 reach the screen exactly as authored,
+    /// which is what UI and unlit sprites want.
+    #[default]
+    Clamp,
+    /// `c / (1 + c)`: keeps detail in bright, blooming scenes at the cost
+    /// of dimming everything (white lands at half brightness).
+    Reinhard,
+}
+
+/// Final-output tunables. Set at startup with `RendererConfig::color`,
+/// mutate at runtime via
+/// [`Renderer::color_config_mut`](crate::Renderer::color_config_mut).
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ColorConfig {
+    /// Linear multiplier applied to the scene before tonemapping.
+    pub exposure: f32,
+    /// Display gamma adjustment on top of the sRGB encoding: above 1.0
+    /// brightens midtones, below darkens them. Black and white stay put.
+    pub gamma: f32,
+    pub tonemap: Tonemap,
+}
+
+impl Default for ColorConfig {
+    fn default() -> Self {
+        Self {
+            exposure: 1.0,
+            gamma: 1.0,
+            tonemap: Tonemap::Clamp,
+        }
+    }
+}
+
+impl ColorConfig {
+    /// Smallest gamma the composite pass accepts.
+    pub const MIN_GAMMA: f32 = 0.1;
+
+    /// One linear channel through exposure, tonemap and gamma, the way the
+    /// composite shader does it. The result is linear light (0..1), before
+    /// the swapchain's sRGB encoding.
+    pub fn map_channel(&self, linear: f32) -> f32 {
+        let exposed = (linear * self.exposure).max(0.0);
+        let mapped = match self.tonemap {
+            Tonemap::Clamp => exposed.min(1.0),
+            Tonemap::Reinhard => exposed / (1.0 + exposed),
+        };
+        mapped.powf(1.0 / self.gamma.max(Self::MIN_GAMMA))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_config_leaves_authored_colors_alone() {
+        let config = ColorConfig::default();
+        for value in [0.0, 0.214, 0.5, 1.0] {
+            assert_eq!(config.map_channel(value), value);
+        }
+        assert_eq!(config.map_channel(4.0), 1.0, "HDR clips at white");
+    }
+
+    #[test]
+    fn test_exposure_gamma_and_reinhard() {
+        let reinhard = ColorConfig { tonemap: Tonemap::Reinhard, ..Default::default() };
+        assert_eq!(reinhard.map_channel(1.0), 0.5);
+        assert!(reinhard.map_channel(100.0) < 1.0);
+
+        let exposed = ColorConfig { exposure: 2.0, ..Default::default() };
+        assert_eq!(exposed.map_channel(0.25), 0.5);
+
+        let bright = ColorConfig { gamma: 2.0, ..Default::default() };
+        assert_eq!(bright.map_channel(0.25), 0.5);
+        assert_eq!((bright.map_channel(0.0), bright.map_channel(1.0)), (0.0, 1.0));
+        let degenerate = ColorConfig { gamma: 0.0, ..Default::default() };
+        assert!(degenerate.map_channel(0.5).is_finite());
+    }
+}
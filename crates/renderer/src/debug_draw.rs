@@ -0,0 +1,194 @@
+//! Immediate-mode world-space debug shapes.
+//!
+//! [`DebugDraw`] collects lines, circles, polylines and boxes as
+//! [`LineVertex`] pairs for one frame; the engine uploads them with
+//! [`Renderer::set_debug_lines`](crate::Renderer::set_debug_lines) and clears
+//! the buffer before the next update. Debug lines go through their own
+//! overlay [`LinePipeline`](crate::line_pipeline::LinePipeline), drawn after
+//! sprites, lines and trails without depth testing, so AI paths and vision
+//! cones stay visible over everything.
+//!
+//! Drawing is on in debug builds and off in release builds; flip it with
+//! [`DebugDraw::set_enabled`] (e.g. behind a console command). While
+//! disabled every call is a no-op, so shipping code can leave its debug
+//! drawing in place.
+//!
+//! ```
+//! use glam::{Vec2, Vec4};
+//! use renderer::debug_draw::DebugDraw;
+//!
+//! let mut debug = DebugDraw::new();
+//! debug.set_enabled(true);
+//! debug.line(Vec2::ZERO, Vec2::new(100.0, 0.0), Vec4::new(1.0, 0.0, 0.0, 1.0));
+//! debug.aabb(Vec2::splat(-8.0), Vec2::splat(8.0), Vec4::ONE);
+//! assert_eq!(debug.vertices().len(), 2 + 8);
+//! ```
+
+use std::f32::consts::TAU;
+
+use glam::{Vec2, Vec4};
+
+use crate::line_pipeline::LineVertex;
+
+/// Segments used for a full circle.
+pub const CIRCLE_SEGMENTS: usize = 32;
+
+/// Per-frame buffer of debug line segments.
+#[derive(Debug, Clone)]
+pub struct DebugDraw {
+    vertices: Vec<LineVertex>,
+    enabled: bool,
+}
+
+impl Default for DebugDraw {
+    fn default() -> Self {
+        Self {
+            vertices: Vec::new(),
+            enabled: cfg!(debug_assertions),
+        }
+    }
+}
+
+impl DebugDraw {
+    /// An empty buffer, enabled in debug builds only.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether draw calls record anything.
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Turn debug drawing on or off. Disabling drops this frame's shapes.
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+        if !enabled {
+            self.vertices.clear();
+        }
+    }
+
+    /// Forget every recorded shape (capacity is kept).
+    pub fn clear(&mut self) {
+        self.vertices.clear();
+    }
+
+    /// Recorded vertices; pairs form line segments.
+    pub fn vertices(&self) -> &[LineVertex] {
+        &self.vertices
+    }
+
+    /// Whether nothing was drawn this frame.
+    pub fn is_empty(&self) -> bool {
+        self.vertices.is_empty()
+    }
+
+    /// A segment from `start` to `end`.
+    pub fn line(&mut self, start: Vec2, end: Vec2, color: Vec4) {
+        if self.enabled {
+            self.push_segment(start, end, color);
+        }
+    }
+
+    /// Connected segments through `points`; a single point draws nothing.
+    pub fn polyline(&mut self, points: &[Vec2], color: Vec4) {
+        if self.enabled {
+            for pair in points.windows(2) {
+                self.push_segment(pair[0], pair[1], color);
+            }
+        }
+    }
+
+    /// A closed outline through `points` (the last point joins the first).
+    pub fn polygon(&mut self, points: &[Vec2], color: Vec4) {
+        self.polyline(points, color);
+        if let (Some(&first), Some(&last), true) = (points.first(), points.last(), points.len() > 2) {
+            self.line(last, first, color);
+        }
+    }
+
+    /// A circle outline of [`CIRCLE_SEGMENTS`] segments.
+    pub fn circle(&mut self, center: Vec2, radius: f32, color: Vec4) {
+        self.arc(center, radius, 0.0, TAU, color);
+    }
+
+    /// An arc from `start_angle` sweeping `sweep` radians counter-clockwise,
+    /// with segment count proportional to the sweep. A vision cone is an arc
+    /// plus two lines from `center`.
+    pub fn arc(&mut self, center: Vec2, radius: f32, start_angle: f32, sweep: f32, color: Vec4) {
+        if !self.enabled {
+            return;
+        }
+        let fraction = (sweep.abs() / TAU).min(1.0);
+        let segments = ((CIRCLE_SEGMENTS as f32 * fraction).ceil() as usize).max(1);
+        let point = |i: usize| {
+            let angle = start_angle + sweep * i as f32 / segments as f32;
+            center + Vec2::from_angle(angle) * radius
+        };
+        for i in 0..segments {
+            self.push_segment(point(i), point(i + 1), color);
+        }
+    }
+
+    /// An axis-aligned box outline between `min` and `max`.
+    pub fn aabb(&mut self, min: Vec2, max: Vec2, color: Vec4) {
+        let corners = [min, Vec2::new(max.x, min.y), max, Vec2::new(min.x, max.y)];
+        self.polygon(&corners, color);
+    }
+
+    fn push_segment(&mut self, start: Vec2, end: Vec2, color: Vec4) {
+        self.vertices.push(LineVertex::new(start, color, 0.0));
+        self.vertices.push(LineVertex::new(end, color, 0.0));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn enabled() -> DebugDraw {
+        let mut debug = DebugDraw::new();
+        debug.set_enabled(true);
+        debug
+    }
+
+    #[test]
+    fn shapes_emit_segment_pairs() {
+        let mut debug = enabled();
+        debug.polyline(&[Vec2::ZERO, Vec2::X, Vec2::ONE], Vec4::ONE);
+        assert_eq!(debug.vertices().len(), 4);
+        debug.clear();
+
+        debug.polygon(&[Vec2::ZERO, Vec2::X, Vec2::ONE], Vec4::ONE);
+        assert_eq!(debug.vertices().len(), 6);
+        assert_eq!(debug.vertices()[5].position, [0.0, 0.0], "closes back to the first point");
+        debug.clear();
+
+        debug.circle(Vec2::new(10.0, 0.0), 5.0, Vec4::ONE);
+        assert_eq!(debug.vertices().len(), CIRCLE_SEGMENTS * 2);
+        let first = Vec2::from(debug.vertices()[0].position);
+        let last = Vec2::from(debug.vertices()[CIRCLE_SEGMENTS * 2 - 1].position);
+        assert!(first.distance(last) < 1e-4, "circle closes");
+        assert!((first.x - 15.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn arcs_scale_segments_with_sweep() {
+        let mut debug = enabled();
+        debug.arc(Vec2::ZERO, 1.0, 0.0, TAU / 4.0, Vec4::ONE);
+        assert_eq!(debug.vertices().len(), CIRCLE_SEGMENTS / 4 * 2);
+        let end = Vec2::from(debug.vertices()[debug.vertices().len() - 1].position);
+        assert!(end.distance(Vec2::Y) < 1e-4);
+    }
+
+    #[test]
+    fn disabled_draw_records_nothing() {
+        let mut debug = enabled();
+        debug.line(Vec2::ZERO, Vec2::ONE, Vec4::ONE);
+        debug.set_enabled(false);
+        assert!(debug.is_empty(), "disabling drops pending shapes");
+        debug.aabb(Vec2::ZERO, Vec2::ONE, Vec4::ONE);
+        debug.circle(Vec2::ZERO, 1.0, Vec4::ONE);
+        assert!(debug.is_empty());
+    }
+}
@@ -0,0 +1,48 @@
+//! Windowless GPU access for headless runs (example auto-play, CI).
+//!
+//! Without a window there is no surface to be compatible with, so any
+//! adapter will do. A hardware adapter is preferred; when none is available
+//! the platform's software fallback (lavapipe, WARP, llvmpipe) is tried
+//! before giving up.
+
+use std::sync::Arc;
+
+use wgpu::{Device, Queue};
+
+use crate::RendererError;
+
+/// Request a device and queue without creating a window or surface.
+pub async fn request_headless_device() -> Result<(Arc<Device>, Arc<Queue>), RendererError> {
+    let instance = wgpu::Instance::default();
+
+    let mut adapter = None;
+    for force_fallback_adapter in [false, true] {
+        let options = wgpu::RequestAdapterOptions {
+            power_preference: wgpu::PowerPreference::default(),
+            force_fallback_adapter,
+            compatible_surface: None,
+        };
+        if let Ok(found) = instance.request_adapter(&options).await {
+            adapter = Some(found);
+            break;
+        }
+    }
+    let adapter = adapter.ok_or_else(|| {
+        RendererError::AdapterCreationError("No adapter available for a headless device".to_string())
+    })?;
+    log::info!("Headless adapter: {}", adapter.get_info().name);
+
+    let (device, queue) = adapter
+        .request_device(&wgpu::DeviceDescriptor {
+            label: Some("Headless device"),
+            required_features: crate::texture::compression::supported_features(adapter.features()),
+            required_limits: wgpu::Limits::default(),
+            experimental_features: Default::default(),
+            memory_hints: Default::default(),
+            trace: Default::default(),
+        })
+        .await
+        .map_err(|e| RendererError::DeviceCreationError(e.to_string()))?;
+
+    Ok((Arc::new(device), Arc::new(queue)))
+}
@@ -7,13 +7,20 @@ use winit::window::Window;
 
 pub mod atlas;
 pub mod bloom;
+pub mod color_config;
+pub mod debug_draw;
 mod error;
+mod headless;
 pub mod line_pipeline;
+pub mod mipmaps;
+pub mod render_stats;
 pub mod render_targets;
 mod renderer;
 pub mod sprite;
 pub mod sprite_data;
+pub mod sprite_sheet;
 pub mod texture;
+pub mod trail_pipeline;
 mod window;
 
 pub mod prelude;
@@ -23,15 +30,21 @@ pub use wgpu;
 
 // Re-export for convenience
 pub use error::*;
+pub use headless::request_headless_device;
 pub use renderer::*;
 pub use sprite_data::*;
 pub use window::*;
 
 // Selective re-exports to avoid conflicts
 // TextureHandle is the canonical definition in texture.rs
-pub use atlas::{AtlasRegion, TextureAtlas, TextureAtlasBuilder};
-pub use sprite::{Sprite, SpriteBatch, SpriteBatcher, SpritePipeline};
+pub use debug_draw::DebugDraw;
+pub use color_config::{ColorConfig, Tonemap};
+pub use atlas::{AtlasRegion, AtlasUpload, ShelfPacker, TextureAtlas, TextureAtlasBuilder};
+pub use render_stats::RenderStats;
+pub use sprite_sheet::{PixelRect, SpriteAtlas};
+pub use sprite::{BatchKey, CameraView, MaskShape, Sprite, SpriteBatch, SpriteBatcher, SpriteLimits, SpriteMask, SpritePipeline};
 pub use texture::{TextureManager, TextureLoadConfig, SamplerConfig, TextureError, TextureHandle};
+pub use texture::compression::TextureCompression;
 
 // Re-export Time from common crate (moved from renderer for proper placement)
 pub use common::Time;
@@ -25,6 +25,7 @@ use crate::sprite_data::{Camera, CameraUniform, DynamicBuffer};
 #[derive(Debug, Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
 pub struct LineVertex {
     pub position: [f32; 2],
+    /// sRGB, decoded to linear in the vertex shader
     pub color: [f32; 4],
     pub emissive: f32,
 }
@@ -80,6 +81,17 @@ impl LinePipeline {
     pub const DEFAULT_CAPACITY: usize = 16_384;
 
     pub fn new(device: &Device, capacity: usize) -> Self {
+        Self::build(device, capacity, wgpu::CompareFunction::LessEqual)
+    }
+
+    /// A line pipeline that ignores the depth buffer, so its lines draw over
+    /// every sprite — used for [`DebugDraw`](crate::debug_draw::DebugDraw)
+    /// overlays (paths, vision cones) that must never be hidden.
+    pub fn new_overlay(device: &Device, capacity: usize) -> Self {
+        Self::build(device, capacity, wgpu::CompareFunction::Always)
+    }
+
+    fn build(device: &Device, capacity: usize, depth_compare: wgpu::CompareFunction) -> Self {
         let device_arc = Arc::new(device.clone());
 
         let camera_bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
@@ -150,13 +162,13 @@ impl LinePipeline {
                 cull_mode: None,
                 ..Default::default()
             },
-            // Test against the depth buffer the sprite pipeline wrote into,
-            // but don't write — lines are 2D background and shouldn't occlude
-            // each other.
+            // Test against the depth buffer the sprite pipeline wrote into
+            // (overlays always pass), but don't write — lines are 2D
+            // background and shouldn't occlude each other.
             depth_stencil: Some(wgpu::DepthStencilState {
                 format: DEPTH_FORMAT,
                 depth_write_enabled: false,
-                depth_compare: wgpu::CompareFunction::LessEqual,
+                depth_compare,
                 stencil: wgpu::StencilState::default(),
                 bias: wgpu::DepthBiasState::default(),
             }),
@@ -180,8 +192,12 @@ impl LinePipeline {
 
     /// Push the camera uniform to the GPU. Call once per frame.
     pub fn update_camera(&self, queue: &Queue, camera: &Camera) {
-        let uniform = CameraUniform::from_camera(camera);
-        queue.write_buffer(&self.camera_buffer, 0, bytemuck::cast_slice(&[uniform]));
+        self.update_camera_uniform(queue, &CameraUniform::from_camera(camera));
+    }
+
+    /// Push an already built camera uniform (e.g. a camera-relative one).
+    pub fn update_camera_uniform(&self, queue: &Queue, uniform: &CameraUniform) {
+        queue.write_buffer(&self.camera_buffer, 0, bytemuck::bytes_of(uniform));
     }
 
     /// Upload a fresh vertex set. Pairs of vertices form line segments.
@@ -199,14 +215,16 @@ impl LinePipeline {
     /// `load_color = false` clears the HDR color before drawing; `true`
     /// preserves whatever the sprite pipeline drew (typical case — lines
     /// composite on top of the sprite frame).
+    ///
+    /// Returns the number of draw calls issued (0 with no vertices).
     pub fn draw(
         &self,
         encoder: &mut CommandEncoder,
         targets: &RenderTargets,
         vertex_count: u32,
-    ) {
+    ) -> usize {
         if vertex_count == 0 {
-            return;
+            return 0;
         }
         let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
             label: Some("Line Render Pass"),
@@ -237,6 +255,7 @@ impl LinePipeline {
         pass.set_bind_group(0, &self.camera_bind_group, &[]);
         pass.set_vertex_buffer(0, self.vertex_buffer.slice());
         pass.draw(0..vertex_count, 0..1);
+        1
     }
 }
 
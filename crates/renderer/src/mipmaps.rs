@@ -0,0 +1,104 @@
+//! CPU mip chain generation for RGBA8 textures.
+//!
+//! Each level halves the previous one (rounding down, never below 1) with a
+//! 2×2 box filter. Colors are averaged weighted by alpha, so transparent
+//! texels don't bleed dark fringes into sprite edges as they shrink. sRGB
+//! textures are averaged in linear light — averaging the encoded values
+//! would darken every mip of a high-contrast texture.
+
+/// Number of mip levels for a full chain down to 1×1.
+pub fn mip_level_count(width: u32, height: u32) -> u32 {
+    32 - width.max(height).max(1).leading_zeros()
+}
+
+/// Size of mip `level` of a `width`×`height` texture.
+pub fn mip_size(width: u32, height: u32, level: u32) -> (u32, u32) {
+    ((width >> level).max(1), (height >> level).max(1))
+}
+
+/// Downsample one RGBA8 level to the next, returning `(width, height, data)`.
+///
+/// Odd edges fold their last row/column into the final output texel.
+/// `srgb` marks RGB as sRGB-encoded (the texture's format `is_srgb`).
+pub fn next_level(width: u32, height: u32, data: &[u8], srgb: bool) -> (u32, u32, Vec<u8>) {
+    let decode: [f32; 256] = std::array::from_fn(|value| {
+        let channel = value as f32 / 255.0;
+        if srgb { common::srgb_to_linear(channel) } else { channel }
+    });
+    let encode = |linear: f32| {
+        let channel = if srgb { common::linear_to_srgb(linear) } else { linear };
+        (channel * 255.0).round() as u8
+    };
+    let (out_width, out_height) = mip_size(width, height, 1);
+    let (width, height) = (width as usize, height as usize);
+    let mut out = Vec::with_capacity(out_width as usize * out_height as usize * 4);
+    for out_y in 0..out_height as usize {
+        let rows = source_span(out_y, out_height as usize, height);
+        for out_x in 0..out_width as usize {
+            let columns = source_span(out_x, out_width as usize, width);
+            let mut color = [0.0f32; 3];
+            let mut alpha = 0.0f32;
+            let mut count = 0.0f32;
+            for y in rows.clone() {
+                for x in columns.clone() {
+                    let texel = &data[(y * width + x) * 4..][..4];
+                    let a = f32::from(texel[3]);
+                    for (sum, channel) in color.iter_mut().zip(texel) {
+                        *sum += decode[usize::from(*channel)] * a;
+                    }
+                    alpha += a;
+                    count += 1.0;
+                }
+            }
+            let average = |sum: f32| if alpha > 0.0 { encode(sum / alpha) } else { 0 };
+            out.extend_from_slice(&[
+                average(color[0]),
+                average(color[1]),
+                average(color[2]),
+                (alpha / count).round() as u8,
+            ]);
+        }
+    }
+    (out_width, out_height, out)
+}
+
+/// Source texels covered by output texel `index` along one axis.
+fn source_span(index: usize, out_len: usize, source_len: usize) -> std::ops::Range<usize> {
+    let start = (index * 2).min(source_len - 1);
+    let end = if index + 1 == out_len { source_len } else { (start + 2).min(source_len) };
+    start..end
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mip_chain_sizes() {
+        assert_eq!(mip_level_count(1, 1), 1);
+        assert_eq!(mip_level_count(256, 64), 9);
+        assert_eq!(mip_level_count(5, 3), 3);
+        assert_eq!(mip_size(5, 3, 2), (1, 1));
+    }
+
+    #[test]
+    fn test_next_level_weights_color_by_alpha() {
+        // Opaque red beside transparent black: no darkening, half coverage
+        let data = [255, 0, 0, 255, 0, 0, 0, 0, 255, 0, 0, 255, 0, 0, 0, 0];
+        let (width, height, level) = next_level(2, 2, &data, false);
+        assert_eq!((width, height), (1, 1));
+        assert_eq!(level, vec![255, 0, 0, 128]);
+
+        // A 3-wide row folds its odd column into the single output texel
+        let row = [30, 30, 30, 255, 60, 60, 60, 255, 90, 90, 90, 255];
+        assert_eq!(next_level(3, 1, &row, false).2, vec![60, 60, 60, 255]);
+    }
+
+    #[test]
+    fn test_next_level_averages_srgb_in_linear_light() {
+        // Black and white checker: half the light, which sRGB encodes as 188
+        let data = [0, 0, 0, 255, 255, 255, 255, 255, 255, 255, 255, 255, 0, 0, 0, 255];
+        assert_eq!(next_level(2, 2, &data, true).2, vec![188, 188, 188, 255]);
+        assert_eq!(next_level(2, 2, &data, false).2, vec![128, 128, 128, 255]);
+    }
+}
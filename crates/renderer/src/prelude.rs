@@ -7,9 +7,11 @@ pub use crate::{
     init,
     window::{create_window_with_active_loop, WindowConfig},
     sprite_data::{Camera, SpriteVertex, SpriteInstance, CameraUniform, TextureResource, DynamicBuffer},
-    sprite::{Sprite, SpriteBatch, SpriteBatcher, SpritePipeline},
+    sprite::{Sprite, SpriteBatch, SpriteBatcher, SpriteLimits, SpritePipeline},
+    render_stats::RenderStats,
     atlas::TextureAtlas,
     texture::{TextureHandle, TextureManager, TextureLoadConfig, TextureError},
+    texture::compression::TextureCompression,
     Time,
     Renderer, RendererConfig, RendererError,
 };
\ No newline at end of file
@@ -0,0 +1,105 @@
+//! Per-frame render statistics.
+
+/// Counters describing what the renderer drew in one frame.
+///
+/// The sprite counters come from
+/// [`SpriteBatcher::stats`](crate::sprite::SpriteBatcher::stats); the GPU
+/// counters (`draw_calls`, `texture_binds`, `texture_memory`) from
+/// [`Renderer::render_with_sprites`](crate::Renderer::render_with_sprites).
+/// Stats from several sources (game + UI batchers, the GPU frame) combine
+/// with [`merge`](Self::merge).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RenderStats {
+    /// Sprites accepted into the batchers
+    pub sprites: usize,
+    /// Non-empty sprite batches (one per texture per batcher)
+    pub batches: usize,
+    /// Sprites rejected because a batcher hit its hard limit
+    pub sprites_dropped: usize,
+    /// Sprites skipped because they lay entirely outside the camera view
+    pub sprites_culled: usize,
+    /// Whether any batcher exceeded its soft limit
+    pub soft_limit_exceeded: bool,
+    /// GPU draw calls issued (sprite batches, lines, bloom passes)
+    pub draw_calls: usize,
+    /// Sprite texture bind-group switches; consecutive batches sharing a
+    /// texture bind it once
+    pub texture_binds: usize,
+    /// Estimated GPU memory held by the textures available to sprites, in
+    /// bytes (all mip levels; the render targets aren't counted)
+    pub texture_memory: u64,
+}
+
+impl RenderStats {
+    /// Fold another source's stats into this one.
+    pub fn merge(&mut self, other: RenderStats) {
+        self.sprites += other.sprites;
+        self.batches += other.batches;
+        self.sprites_dropped += other.sprites_dropped;
+        self.sprites_culled += other.sprites_culled;
+        self.soft_limit_exceeded |= other.soft_limit_exceeded;
+        self.draw_calls += other.draw_calls;
+        self.texture_binds += other.texture_binds;
+        self.texture_memory += other.texture_memory;
+    }
+
+    /// [`texture_memory`](Self::texture_memory) in mebibytes, for display.
+    pub fn texture_memory_mib(&self) -> f32 {
+        self.texture_memory as f32 / (1024.0 * 1024.0)
+    }
+}
+
+/// Bytes used by a texture of `width` × `height` texels with `mip_levels`
+/// levels, each level half the size of the previous (never below 1×1).
+pub fn texture_bytes(width: u32, height: u32, mip_levels: u32, bytes_per_texel: u32) -> u64 {
+    block_texture_bytes(width, height, mip_levels, (1, 1), bytes_per_texel)
+}
+
+/// [`texture_bytes`] for a format stored in `block` (width, height) texel
+/// blocks of `bytes_per_block` bytes, e.g. (4, 4) and 16 for BC3. Levels
+/// smaller than a block still take a whole one.
+pub fn block_texture_bytes(width: u32, height: u32, mip_levels: u32, block: (u32, u32), bytes_per_block: u32) -> u64 {
+    (0..mip_levels.max(1))
+        .map(|level| {
+            let w = u64::from((width >> level).max(1).div_ceil(block.0.max(1)));
+            let h = u64::from((height >> level).max(1).div_ceil(block.1.max(1)));
+            w * h * u64::from(bytes_per_block)
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_merge_sums_counts_and_ors_soft_limit_flag() {
+        let mut total = RenderStats {
+            sprites: 3, batches: 1, sprites_culled: 2, draw_calls: 1, ..Default::default()
+        };
+        total.merge(RenderStats {
+            sprites: 5, batches: 2, sprites_dropped: 4, sprites_culled: 1, soft_limit_exceeded: true,
+            draw_calls: 4, texture_binds: 2, texture_memory: 1024,
+        });
+        assert_eq!(
+            total,
+            RenderStats {
+                sprites: 8, batches: 3, sprites_dropped: 4, sprites_culled: 3, soft_limit_exceeded: true,
+                draw_calls: 5, texture_binds: 2, texture_memory: 1024,
+            }
+        );
+    }
+
+    #[test]
+    fn test_texture_bytes_counts_mip_chain() {
+        assert_eq!(texture_bytes(256, 128, 1, 4), 256 * 128 * 4);
+        // 4x2 + 2x1 + 1x1
+        assert_eq!(texture_bytes(4, 2, 3, 4), (8 + 2 + 1) * 4);
+        assert_eq!(texture_bytes(1, 1, 0, 4), 4, "zero levels counts as one");
+        // BC3: a byte per texel, with the 2×2 and 1×1 levels a block each
+        assert_eq!(block_texture_bytes(8, 8, 4, (4, 4), 16), 64 + 16 + 16 + 16);
+
+        let stats = RenderStats { texture_memory: 3 * 1024 * 1024, ..Default::default() };
+        assert_eq!(stats.texture_memory_mib(), 3.0);
+    }
+}
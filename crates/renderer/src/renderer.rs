@@ -20,10 +20,14 @@ use wgpu::{
 };
 use winit::window::Window;
 
-use crate::bloom::{BloomConfig, BloomPipeline};
+use crate::bloom::{BloomConfig, BloomPassInputs, BloomPipeline};
+use crate::color_config::ColorConfig;
 use crate::error::RendererError;
 use crate::line_pipeline::{LinePipeline, LineVertex};
+use crate::trail_pipeline::{TrailPipeline, TrailVertex};
+use crate::render_stats::RenderStats;
 use crate::render_targets::RenderTargets;
+use crate::sprite_data::CameraUniform;
 
 /// Configuration for creating a [`Renderer`].
 ///
@@ -36,11 +40,16 @@ pub struct RendererConfig {
     /// at the display refresh rate). `false` selects `AutoNoVsync` for the
     /// lowest latency the platform offers.
     pub vsync: bool,
+    /// Starting exposure, tonemap and gamma (see
+    /// [`Renderer::color_config_mut`]).
+    pub color: ColorConfig,
+    /// Draw relative to the camera (see [`Renderer::set_camera_relative`]).
+    pub camera_relative: bool,
 }
 
 impl Default for RendererConfig {
     fn default() -> Self {
-        Self { vsync: true }
+        Self { vsync: true, color: ColorConfig::default(), camera_relative: false }
     }
 }
 
@@ -61,11 +70,23 @@ pub struct Renderer {
     bloom_pipeline: BloomPipeline,
     /// Runtime-tunable bloom knobs.
     bloom_config: BloomConfig,
+    /// Exposure, tonemap and gamma applied by the composite pass.
+    color_config: ColorConfig,
     /// Pipeline + buffer for line-list geometry (e.g. the spring-mass grid).
     line_pipeline: LinePipeline,
     /// Number of line vertices uploaded by the most recent `set_lines` call.
     /// Reset to 0 when no lines are drawn this frame.
     line_vertex_count: u32,
+    /// Pipeline + buffer for trail triangle strips.
+    trail_pipeline: TrailPipeline,
+    /// Number of trail vertices uploaded by the most recent `set_trails` call.
+    trail_vertex_count: u32,
+    /// Overlay line pipeline for `DebugDraw` shapes (no depth test).
+    debug_pipeline: LinePipeline,
+    /// Number of debug vertices uploaded by the most recent `set_debug_lines` call.
+    debug_vertex_count: u32,
+    /// Whether vertices are placed relative to the camera (`CameraUniform::camera_relative`).
+    camera_relative: bool,
 }
 
 impl Renderer {
@@ -106,7 +127,8 @@ impl Renderer {
             .request_device(
                 &wgpu::DeviceDescriptor {
                     label: Some("Primary device"),
-                    required_features: wgpu::Features::empty(),
+                    // Opt-in block-compressed textures (TextureLoadConfig::compression)
+                    required_features: crate::texture::compression::supported_features(adapter.features()),
                     required_limits: wgpu::Limits::default(),
                     experimental_features: Default::default(),
                     memory_hints: Default::default(),
@@ -118,8 +140,12 @@ impl Renderer {
 
         // Configure surface. The bloom composite pass writes the final tonemapped
         // color and relies on the GPU's automatic linear -> sRGB conversion when
-        // writing to an sRGB swapchain, so we prefer an sRGB surface format.
+        // writing to an sRGB swapchain, so we prefer an sRGB surface format
+        // (without one the composite shader encodes instead).
+        // A web canvas can still be 0x0 before the page lays it out, and a
+        // zero-sized surface can't be configured; `resize` fixes it up later
         let size = window.inner_size();
+        let size = winit::dpi::PhysicalSize::new(size.width.max(1), size.height.max(1));
         let surface_caps = surface.get_capabilities(&adapter);
         let format = surface_caps
             .formats
@@ -164,6 +190,8 @@ impl Renderer {
         let bloom_pipeline = BloomPipeline::new(&device, format);
         let bloom_config = BloomConfig::default();
         let line_pipeline = LinePipeline::new(&device, LinePipeline::DEFAULT_CAPACITY);
+        let trail_pipeline = TrailPipeline::new(&device, TrailPipeline::DEFAULT_CAPACITY);
+        let debug_pipeline = LinePipeline::new_overlay(&device, LinePipeline::DEFAULT_CAPACITY);
 
         Ok(Self {
             window,
@@ -182,8 +210,14 @@ impl Renderer {
             render_targets,
             bloom_pipeline,
             bloom_config,
+            color_config: renderer_config.color,
             line_pipeline,
             line_vertex_count: 0,
+            trail_pipeline,
+            trail_vertex_count: 0,
+            debug_pipeline,
+            debug_vertex_count: 0,
+            camera_relative: renderer_config.camera_relative,
         })
     }
 
@@ -197,6 +231,30 @@ impl Renderer {
         &mut self.bloom_config
     }
 
+    /// Read-only view of the output color settings.
+    pub fn color_config(&self) -> &ColorConfig {
+        &self.color_config
+    }
+
+    /// Mutable access to the output color settings (exposure, tonemap,
+    /// gamma). Takes effect next frame.
+    pub fn color_config_mut(&mut self) -> &mut ColorConfig {
+        &mut self.color_config
+    }
+
+    /// Whether frames are drawn relative to the camera.
+    pub fn camera_relative(&self) -> bool {
+        self.camera_relative
+    }
+
+    /// Place every vertex relative to the camera instead of folding the
+    /// camera translation into the view matrix. The image is the same, but
+    /// sprites, lines and trails far from the world origin stop jittering
+    /// as the camera moves. Takes effect next frame.
+    pub fn set_camera_relative(&mut self, camera_relative: bool) {
+        self.camera_relative = camera_relative;
+    }
+
     /// Upload line vertices for the next render. Pairs of vertices form line
     /// segments. The line pipeline draws these into the HDR target after
     /// sprites and before bloom, so emissive lines bloom.
@@ -208,9 +266,31 @@ impl Renderer {
         self.line_pipeline.upload_vertices(&self.queue, vertices);
     }
 
-    /// Set the clear color
+    /// Upload trail strip vertices for the next render — every trail
+    /// stitched into one strip with `trail_pipeline::append_strip`. Drawn
+    /// after lines and before bloom.
+    ///
+    /// Call every frame; an empty slice (or no call) draws no trails.
+    pub fn set_trails(&mut self, vertices: &[TrailVertex]) {
+        self.trail_vertex_count = vertices.len() as u32;
+        self.trail_pipeline.upload_vertices(&self.queue, vertices);
+    }
+
+    /// Upload debug line vertices (a [`DebugDraw`](crate::debug_draw::DebugDraw)
+    /// buffer) for the next render. Drawn last into the HDR target, over
+    /// sprites, lines and trails, ignoring depth.
+    ///
+    /// Call every frame; an empty slice (or no call) draws no debug shapes.
+    pub fn set_debug_lines(&mut self, vertices: &[LineVertex]) {
+        self.debug_vertex_count = vertices.len() as u32;
+        self.debug_pipeline.upload_vertices(&self.queue, vertices);
+    }
+
+    /// Set the clear color. RGB is sRGB like every other color; it is
+    /// decoded to linear light for the HDR target.
     pub fn set_clear_color(&mut self, r: f64, g: f64, b: f64, a: f64) {
-        self.clear_color = wgpu::Color { r, g, b, a };
+        let linear = |channel: f64| f64::from(common::srgb_to_linear(channel as f32));
+        self.clear_color = wgpu::Color { r: linear(r), g: linear(g), b: linear(b), a };
     }
 
     /// Acquire the current surface texture for rendering.
@@ -238,14 +318,18 @@ impl Renderer {
         }
     }
 
-    /// Render a frame with a sprite pipeline
+    /// Render a frame with a sprite pipeline.
+    ///
+    /// Returns the frame's GPU statistics — draw calls, texture binds and
+    /// the memory of `texture_resources`; sprite counts come from the
+    /// batchers. A skipped frame (surface timeout) reports no draws.
     pub fn render_with_sprites(
         &mut self,
         sprite_pipeline: &mut crate::sprite::SpritePipeline,
         camera: &crate::sprite_data::Camera,
         texture_resources: &std::collections::HashMap<crate::texture::TextureHandle, crate::sprite_data::TextureResource>,
         sprite_batches: &[&crate::sprite::SpriteBatch]
-    ) -> Result<(), RendererError> {
+    ) -> Result<RenderStats, RendererError> {
         // Make sure the built-in white texture (for flat-colored sprites) has
         // a cached bind group. Cheap no-op after the first frame — no need to
         // clone the caller's texture map just to splice it in.
@@ -270,11 +354,11 @@ impl Renderer {
         camera: &crate::sprite_data::Camera,
         texture_resources: &std::collections::HashMap<crate::texture::TextureHandle, crate::sprite_data::TextureResource>,
         sprite_batches: &[&crate::sprite::SpriteBatch]
-    ) -> Result<(), RendererError> {
+    ) -> Result<RenderStats, RendererError> {
         // Get a frame (returns None if we should skip this frame)
         let frame = match self.acquire_frame()? {
             Some(frame) => frame,
-            None => return Ok(()),
+            None => return Ok(RenderStats::default()),
         };
 
         // Swapchain view: final destination for the composite pass.
@@ -288,11 +372,18 @@ impl Renderer {
                 label: Some("Render Encoder"),
             });
 
-        sprite_pipeline.update_camera(&self.queue, camera);
-        self.line_pipeline.update_camera(&self.queue, camera);
+        let camera_uniform = if self.camera_relative {
+            CameraUniform::camera_relative(camera)
+        } else {
+            CameraUniform::from_camera(camera)
+        };
+        sprite_pipeline.update_camera_uniform(&self.queue, &camera_uniform);
+        self.line_pipeline.update_camera_uniform(&self.queue, &camera_uniform);
+        self.trail_pipeline.update_camera_uniform(&self.queue, &camera_uniform);
+        self.debug_pipeline.update_camera_uniform(&self.queue, &camera_uniform);
 
         // Pass 1: sprites -> HDR color (+ depth).
-        sprite_pipeline.draw(
+        let mut stats = sprite_pipeline.draw(
             &mut encoder,
             texture_resources,
             sprite_batches,
@@ -302,22 +393,29 @@ impl Renderer {
 
         // Pass 2: lines (e.g. the spring-mass grid) on top of sprites in HDR.
         // No-op when `set_lines` wasn't called this frame.
-        self.line_pipeline.draw(&mut encoder, &self.render_targets, self.line_vertex_count);
+        stats.draw_calls += self.line_pipeline.draw(&mut encoder, &self.render_targets, self.line_vertex_count);
 
-        // Pass 3..N: bloom (extract -> blur -> composite to swapchain).
-        self.bloom_pipeline.run(
-            &self.device,
-            &self.queue,
-            &mut encoder,
-            &self.render_targets,
-            &swapchain_view,
-            &self.bloom_config,
-        );
+        // Pass 3: trails (sword swipes, projectile tails) on top of lines.
+        stats.draw_calls += self.trail_pipeline.draw(&mut encoder, &self.render_targets, self.trail_vertex_count);
+
+        // Pass 4: debug shapes over everything drawn so far.
+        stats.draw_calls += self.debug_pipeline.draw(&mut encoder, &self.render_targets, self.debug_vertex_count);
+
+        // Pass 5..N: bloom (extract -> blur -> composite to swapchain).
+        let bloom = BloomPassInputs {
+            targets: &self.render_targets,
+            swapchain: &swapchain_view,
+            config: &self.bloom_config,
+            color: &self.color_config,
+        };
+        stats.draw_calls += self.bloom_pipeline.run(&self.device, &self.queue, &mut encoder, bloom);
 
         self.queue.submit(std::iter::once(encoder.finish()));
         frame.present();
 
-        Ok(())
+        stats.texture_memory = texture_resources.values().map(|resource| resource.memory_bytes()).sum::<u64>()
+            + self.white_texture.as_ref().map_or(0, |white| white.memory_bytes());
+        Ok(stats)
     }
 
     /// Get a reference to the window
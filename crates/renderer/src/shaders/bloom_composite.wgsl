@@ -1,14 +1,21 @@
 // Final composite: HDR + blurred bloom -> sRGB swapchain.
 //
-// The swapchain is sRGB, so the GPU performs the linear -> sRGB encoding
-// automatically. We just need to tonemap from HDR (values potentially >> 1)
-// down to [0, 1] before writing.
+// Everything up to here is linear light. Exposure scales it, the tonemap
+// brings HDR (values potentially >> 1) into [0, 1] and gamma adjusts the
+// midtones. An sRGB swapchain encodes on write; otherwise `encode_srgb` is
+// set and we encode here. Mirrors `ColorConfig::map_channel`.
 
 struct CompositeParams {
     threshold: f32,    // unused here, kept for layout parity
     knee: f32,         // unused
     // Bloom contribution multiplier. 0 disables bloom entirely.
     intensity: f32,
+    exposure: f32,
+    // Display gamma adjustment, already clamped above zero. 1 = none.
+    gamma: f32,
+    // 0 = clamp at white, 1 = Reinhard
+    tonemap: f32,
+    encode_srgb: f32,
     _pad: f32,
 }
 
@@ -36,17 +43,27 @@ fn vs_main(@builtin(vertex_index) idx: u32) -> VsOut {
     return out;
 }
 
-// Reinhard tonemap — simple and stable. Doesn't crush highlights as hard
-// as ACES, which is what we want for a neon Geometry-Wars look.
+// Clamp keeps authored colors exact. Reinhard — simple and stable, and
+// doesn't crush highlights as hard as ACES — suits a neon Geometry-Wars
+// look where most of the screen blooms.
 fn tonemap(c: vec3<f32>) -> vec3<f32> {
-    return c / (vec3<f32>(1.0) + c);
+    let clamped = min(c, vec3<f32>(1.0));
+    let reinhard = c / (vec3<f32>(1.0) + c);
+    return select(clamped, reinhard, params.tonemap > 0.5);
+}
+
+fn linear_to_srgb(c: vec3<f32>) -> vec3<f32> {
+    let curve = 1.055 * pow(c, vec3<f32>(1.0 / 2.4)) - vec3<f32>(0.055);
+    let toe = c * 12.92;
+    return select(curve, toe, c <= vec3<f32>(0.0031308));
 }
 
 @fragment
 fn fs_main(in: VsOut) -> @location(0) vec4<f32> {
     let scene = textureSample(hdr_tex, linear_sampler, in.uv).rgb;
     let bloom = textureSample(bloom_tex, linear_sampler, in.uv).rgb;
-    let combined = scene + bloom * params.intensity;
-    let mapped = tonemap(combined);
-    return vec4<f32>(mapped, 1.0);
+    let combined = max((scene + bloom * params.intensity) * params.exposure, vec3<f32>(0.0));
+    let mapped = pow(tonemap(combined), vec3<f32>(1.0 / params.gamma));
+    let encoded = select(mapped, linear_to_srgb(mapped), params.encode_srgb > 0.5);
+    return vec4<f32>(encoded, 1.0);
 }
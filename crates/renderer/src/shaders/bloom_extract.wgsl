@@ -10,6 +10,10 @@ struct BloomParams {
     // Unused composite-side knobs; padded so the struct matches the layout
     // used by every pass.
     intensity: f32,
+    exposure: f32,
+    gamma: f32,
+    tonemap: f32,
+    encode_srgb: f32,
     _pad: f32,
 }
 
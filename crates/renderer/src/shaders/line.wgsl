@@ -3,7 +3,9 @@
 struct Camera {
     view_projection: mat4x4<f32>,
     position: vec2<f32>,
-    _padding: vec2<f32>,
+    // Subtracted from world positions before view_projection: zero, or the
+    // camera position when rendering camera-relative
+    origin: vec2<f32>,
 }
 
 @group(0) @binding(0)
@@ -21,14 +23,23 @@ struct VertexOutput {
     @location(1) emissive: f32,
 }
 
+// Vertex colors are sRGB, as for sprites; decoded once per vertex so the
+// HDR target only ever sees linear light.
+fn tint_to_linear(c: vec3<f32>) -> vec3<f32> {
+    let s = clamp(c, vec3<f32>(0.0), vec3<f32>(1.0));
+    let curve = pow((s + vec3<f32>(0.055)) / 1.055, vec3<f32>(2.4));
+    let toe = s / 12.92;
+    return select(curve, toe, s <= vec3<f32>(0.04045)) + max(c - vec3<f32>(1.0), vec3<f32>(0.0));
+}
+
 @vertex
 fn vs_main(in: VertexInput) -> VertexOutput {
     var out: VertexOutput;
     // Lines live in the background plane: z = 0.9 places them behind sprites
     // at depth 0 but in front of the cleared depth buffer.
-    let world = vec4<f32>(in.position, 0.9, 1.0);
+    let world = vec4<f32>(in.position - camera.origin, 0.9, 1.0);
     out.clip_position = camera.view_projection * world;
-    out.color = in.color;
+    out.color = vec4<f32>(tint_to_linear(in.color.rgb), in.color.a);
     out.emissive = in.emissive;
     return out;
 }
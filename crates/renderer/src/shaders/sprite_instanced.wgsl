@@ -1,12 +1,16 @@
-// Instanced sprite rendering shader with camera and emissive support.
+// Instanced sprite rendering shader with camera, emissive and mask support.
 // Writes to an HDR target (Rgba16Float). Bright pixels — driven by the
 // per-instance `emissive` attribute — are picked up by the bloom pipeline.
+// Everything here is linear light: textures decode through their sRGB
+// format, tints through `tint_to_linear`.
 
 // Camera uniform
 struct Camera {
     view_projection: mat4x4<f32>,
     position: vec2<f32>,
-    _padding: vec2<f32>,
+    // Subtracted from world positions before view_projection: zero, or the
+    // camera position when rendering camera-relative
+    origin: vec2<f32>,
 }
 
 @group(0) @binding(0)
@@ -18,6 +22,12 @@ var t_diffuse: texture_2d<f32>;
 @group(1) @binding(1)
 var s_diffuse: sampler;
 
+// Mask texture (the white texture unless the batch is texture-masked)
+@group(2) @binding(0)
+var t_mask: texture_2d<f32>;
+@group(2) @binding(1)
+var s_mask: sampler;
+
 // Vertex attributes (per-vertex)
 struct VertexInput {
     @location(0) position: vec3<f32>,  // Local quad vertex position
@@ -35,6 +45,9 @@ struct InstanceInput {
     @location(8) depth: f32,                 // Depth (0 = near, 1 = far in NDC after camera)
     @location(9) emissive: f32,              // Emissive intensity
     @location(10) shape: vec4<f32>,          // SDF shape [kind, corner_radius, border_width, reserved]
+    @location(11) mask_bounds: vec4<f32>,    // Mask [center_x, center_y, half_w, half_h] in world space
+    @location(12) mask: vec4<f32>,           // Mask [kind, rotation, invert, reserved]
+    @location(13) mask_region: vec4<f32>,    // Mask texture region [u, v, width, height]
 }
 
 // Output to fragment shader
@@ -46,6 +59,19 @@ struct VertexOutput {
     @location(3) local_px: vec2<f32>,   // fragment position in local pixels (pre-rotation)
     @location(4) half_size: vec2<f32>,  // sprite half extents in local pixels
     @location(5) shape: vec4<f32>,      // SDF shape params (constant per instance)
+    @location(6) world_px: vec2<f32>,   // fragment position in world space, less camera.origin
+    @location(7) mask_bounds: vec4<f32>,
+    @location(8) mask: vec4<f32>,
+    @location(9) mask_region: vec4<f32>,
+}
+
+// Tints are sRGB like the textures they multiply. Channels above 1.0 are
+// HDR overdrive and carry on linearly past white.
+fn tint_to_linear(c: vec3<f32>) -> vec3<f32> {
+    let s = clamp(c, vec3<f32>(0.0), vec3<f32>(1.0));
+    let curve = pow((s + vec3<f32>(0.055)) / 1.055, vec3<f32>(2.4));
+    let toe = s / 12.92;
+    return select(curve, toe, s <= vec3<f32>(0.04045)) + max(c - vec3<f32>(1.0), vec3<f32>(0.0));
 }
 
 @vertex
@@ -76,7 +102,9 @@ fn vs_main(
     let transform_matrix = rot_matrix * scale_matrix;
 
     let local_pos = transform_matrix * vec3<f32>(vertex.position.xy, 0.0);
-    let world_pos = vec4<f32>(local_pos.xy + instance.world_position, instance.depth, 1.0);
+    // Relative to the camera origin first, so the small quad offset isn't
+    // lost against a large world position
+    let world_pos = vec4<f32>(local_pos.xy + (instance.world_position - camera.origin), instance.depth, 1.0);
 
     out.clip_position = camera.view_projection * world_pos;
 
@@ -86,7 +114,8 @@ fn vs_main(
         instance.tex_region.y + base_uv.y * instance.tex_region.w
     );
 
-    out.color = vertex.color * instance.color;
+    let tint = vertex.color * instance.color;
+    out.color = vec4<f32>(tint_to_linear(tint.rgb), tint.a);
     out.emissive = instance.emissive;
 
     // Quad vertices span ±0.5, so local pixels = vertex * scale, half
@@ -96,6 +125,12 @@ fn vs_main(
     out.half_size = abs(instance.scale) * 0.5;
     out.shape = instance.shape;
 
+    // Both sides of the mask test share the camera origin
+    out.world_px = world_pos.xy;
+    out.mask_bounds = vec4<f32>(instance.mask_bounds.xy - camera.origin, instance.mask_bounds.zw);
+    out.mask = instance.mask;
+    out.mask_region = instance.mask_region;
+
     return out;
 }
 
@@ -105,15 +140,44 @@ fn sd_rounded_box(p: vec2<f32>, b: vec2<f32>, r: f32) -> f32 {
     return length(max(q, vec2<f32>(0.0, 0.0))) + min(max(q.x, q.y), 0.0) - r;
 }
 
+// Coverage of a world-space mask: kind 0 = no mask, 1 = rect, 2 = ellipse,
+// 3 = mask-texture alpha over the rect. Sampling and derivatives run before
+// any branching — both need uniform control flow.
+fn mask_coverage(in: VertexOutput) -> f32 {
+    let half = max(in.mask_bounds.zw, vec2<f32>(1e-4, 1e-4));
+    let cos_r = cos(in.mask.y);
+    let sin_r = sin(in.mask.y);
+    // Rotate into the mask's own frame (inverse of its CCW rotation)
+    let offset = in.world_px - in.mask_bounds.xy;
+    let p = vec2<f32>(cos_r * offset.x + sin_r * offset.y, -sin_r * offset.x + cos_r * offset.y);
+    let q = p / half;
+
+    // Mask-local +y is up, texture v grows down
+    let mask_uv = in.mask_region.xy + vec2<f32>(0.5 + q.x * 0.5, 0.5 - q.y * 0.5) * in.mask_region.zw;
+    let mask_alpha = textureSample(t_mask, s_mask, mask_uv).a;
+
+    let d_rect = max(abs(p.x) - half.x, abs(p.y) - half.y);
+    let d_ellipse = (length(q) - 1.0) * min(half.x, half.y);
+    let rect = clamp(0.5 - d_rect / max(fwidth(d_rect), 1e-4), 0.0, 1.0);
+    let ellipse = clamp(0.5 - d_ellipse / max(fwidth(d_ellipse), 1e-4), 0.0, 1.0);
+
+    let kind = in.mask.x;
+    var coverage = select(rect, ellipse, kind > 1.5 && kind < 2.5);
+    coverage = select(coverage, rect * mask_alpha, kind > 2.5);
+    coverage = select(coverage, 1.0 - coverage, in.mask.z > 0.5);
+    return select(1.0, coverage, kind > 0.5);
+}
+
 @fragment
 fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
     let tex_color = textureSample(t_diffuse, s_diffuse, in.tex_coords);
+    let mask = mask_coverage(in);
     let base_rgb = tex_color.rgb * in.color.rgb;
     // emissive multiplies RGB so values exceed 1.0 and the bright-pass picks them up.
     // 1.0 + 4.0*intensity scales linearly without a hard threshold.
     let glow_factor = 1.0 + in.emissive * 4.0;
     let out_rgb = base_rgb * glow_factor;
-    var alpha = tex_color.a * in.color.a;
+    var alpha = tex_color.a * in.color.a * mask;
 
     // SDF shape mask: kind 0 = plain quad (no mask), 1 = rounded rect,
     // 2 = circle. Distances are in local pixels, so the ~1.5px smoothstep
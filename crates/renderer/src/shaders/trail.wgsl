@@ -0,0 +1,53 @@
+// Trail render shader. Triangle-strip ribbons (sword swipes, projectile
+// tails, dashes) written to the HDR target so emissive trails bloom.
+
+struct Camera {
+    view_projection: mat4x4<f32>,
+    position: vec2<f32>,
+    // Subtracted from world positions before view_projection: zero, or the
+    // camera position when rendering camera-relative
+    origin: vec2<f32>,
+}
+
+@group(0) @binding(0)
+var<uniform> camera: Camera;
+
+struct VertexInput {
+    @location(0) position: vec2<f32>,
+    @location(1) depth: f32,
+    @location(2) color: vec4<f32>,
+    @location(3) emissive: f32,
+}
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) color: vec4<f32>,
+    @location(1) emissive: f32,
+}
+
+// sRGB vertex color -> linear, so a trail's fade interpolates in linear
+// light. Same overdrive rule as the sprite shader.
+fn tint_to_linear(c: vec3<f32>) -> vec3<f32> {
+    let s = clamp(c, vec3<f32>(0.0), vec3<f32>(1.0));
+    let curve = pow((s + vec3<f32>(0.055)) / 1.055, vec3<f32>(2.4));
+    let toe = s / 12.92;
+    return select(curve, toe, s <= vec3<f32>(0.04045)) + max(c - vec3<f32>(1.0), vec3<f32>(0.0));
+}
+
+@vertex
+fn vs_main(in: VertexInput) -> VertexOutput {
+    var out: VertexOutput;
+    // Same depth convention as sprites: the world z is the entity depth.
+    let world = vec4<f32>(in.position - camera.origin, in.depth, 1.0);
+    out.clip_position = camera.view_projection * world;
+    out.color = vec4<f32>(tint_to_linear(in.color.rgb), in.color.a);
+    out.emissive = in.emissive;
+    return out;
+}
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    // Same emissive multiplier as sprites so bright-pass picks them up.
+    let glow_factor = 1.0 + in.emissive * 4.0;
+    return vec4<f32>(in.color.rgb * glow_factor, in.color.a);
+}
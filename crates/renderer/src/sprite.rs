@@ -10,11 +10,15 @@ use crate::sprite_data::SpriteInstance;
 use crate::texture::TextureHandle;
 
 mod batch;
+mod culling;
 mod instance_cache;
+mod mask;
 mod pipeline;
 
-pub use batch::{SpriteBatch, SpriteBatcher};
+pub use batch::{BatchKey, SpriteBatch, SpriteBatcher, SpriteLimits};
+pub use culling::CameraView;
 pub use instance_cache::InstanceCache;
+pub use mask::{MaskShape, SpriteMask};
 pub use pipeline::SpritePipeline;
 
 /// A single sprite to be rendered
@@ -28,7 +32,8 @@ pub struct Sprite {
     pub scale: Vec2,
     /// Texture region (x, y, width, height) in texture coordinates [0, 1]
     pub tex_region: [f32; 4],
-    /// Color tint
+    /// Color tint, sRGB like the texture it multiplies; RGB above 1.0
+    /// overdrives into HDR
     pub color: Vec4,
     /// Layer depth for sorting (higher values render on top)
     pub depth: f32,
@@ -37,6 +42,8 @@ pub struct Sprite {
     /// SDF shape params `[kind, corner_radius, border_width, reserved]`.
     /// kind 0 = plain quad, 1 = rounded rect, 2 = circle (see [`SpriteShape`]).
     pub shape: [f32; 4],
+    /// Clip the sprite to this world-space mask
+    pub mask: Option<SpriteMask>,
     /// Texture handle
     pub texture_handle: TextureHandle,
 }
@@ -74,6 +81,7 @@ impl Default for Sprite {
             depth: 0.0,
             emissive: 0.0,
             shape: [0.0; 4],
+            mask: None,
             texture_handle: TextureHandle::default(),
         }
     }
@@ -155,9 +163,21 @@ impl Sprite {
         self
     }
 
+    /// Clip the sprite to a world-space mask.
+    pub fn with_mask(mut self, mask: SpriteMask) -> Self {
+        self.mask = Some(mask);
+        self
+    }
+
+    /// Texture the sprite's batch binds as its mask (white when unmasked or
+    /// shape-masked, so those sprites share a batch).
+    pub fn mask_texture(&self) -> TextureHandle {
+        self.mask.map_or(TextureHandle::WHITE, |mask| mask.mask_texture())
+    }
+
     /// Convert to sprite instance for batching
     pub fn to_instance(&self) -> SpriteInstance {
-        SpriteInstance::with_emissive(
+        let instance = SpriteInstance::with_emissive(
             self.position,
             self.rotation,
             self.scale,
@@ -166,7 +186,11 @@ impl Sprite {
             self.depth,
             self.emissive,
         )
-        .with_shape(self.shape)
+        .with_shape(self.shape);
+        match &self.mask {
+            Some(mask) => instance.with_mask(mask),
+            None => instance,
+        }
     }
 }
 
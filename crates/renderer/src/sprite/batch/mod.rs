@@ -0,0 +1,317 @@
+//! CPU-side sprite batching: grouping sprites by texture (and mask texture)
+//! before GPU upload.
+
+use std::collections::HashMap;
+
+use glam::Vec2;
+
+use crate::render_stats::RenderStats;
+use crate::sprite::{CameraView, Sprite};
+use crate::sprite_data::{Camera, SpriteInstance};
+use crate::texture::TextureHandle;
+
+/// What sprites must share to draw in one batch: the texture and the mask
+/// texture bound next to it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct BatchKey {
+    /// Sprite texture
+    pub texture: TextureHandle,
+    /// Mask texture ([`TextureHandle::WHITE`] for unmasked and shape-masked
+    /// sprites)
+    pub mask_texture: TextureHandle,
+}
+
+impl BatchKey {
+    /// Key of `sprite`'s batch.
+    pub fn of(sprite: &Sprite) -> Self {
+        Self { texture: sprite.texture_handle, mask_texture: sprite.mask_texture() }
+    }
+}
+
+impl From<TextureHandle> for BatchKey {
+    /// Key of the unmasked batch for `texture`.
+    fn from(texture: TextureHandle) -> Self {
+        Self { texture, mask_texture: TextureHandle::WHITE }
+    }
+}
+
+/// A batch of sprites using the same texture
+#[derive(Debug, Clone)]
+pub struct SpriteBatch {
+    /// Texture handle for this batch
+    pub texture_handle: TextureHandle,
+    /// Mask texture bound with this batch (white unless texture-masked)
+    pub mask_texture: TextureHandle,
+    /// Sprite instances
+    pub instances: Vec<SpriteInstance>,
+    /// Whether this batch is sorted by depth
+    pub sorted: bool,
+}
+
+impl SpriteBatch {
+    /// Create a new sprite batch
+    pub fn new(texture_handle: TextureHandle) -> Self {
+        Self {
+            texture_handle,
+            mask_texture: TextureHandle::WHITE,
+            instances: Vec::new(),
+            sorted: false,
+        }
+    }
+
+    /// Bind `mask_texture` as this batch's mask (builder-style)
+    pub fn with_mask_texture(mut self, mask_texture: TextureHandle) -> Self {
+        self.mask_texture = mask_texture;
+        self
+    }
+
+    /// Key identifying this batch's texture pair
+    pub fn key(&self) -> BatchKey {
+        BatchKey { texture: self.texture_handle, mask_texture: self.mask_texture }
+    }
+
+    /// Add a sprite instance to the batch
+    pub fn add_instance(&mut self, instance: SpriteInstance) {
+        self.instances.push(instance);
+        self.sorted = false;
+    }
+
+    /// Add multiple sprite instances
+    pub fn add_instances(&mut self, instances: &[SpriteInstance]) {
+        self.instances.extend_from_slice(instances);
+        self.sorted = false;
+    }
+
+    /// Sort instances by depth (for proper alpha blending).
+    ///
+    /// Uses `total_cmp` so NaN depths sort deterministically instead of
+    /// panicking.
+    pub fn sort_by_depth(&mut self) {
+        if !self.sorted {
+            self.instances.sort_by(|a, b| a.depth.total_cmp(&b.depth));
+            self.sorted = true;
+        }
+    }
+
+    /// Get the number of instances
+    pub fn len(&self) -> usize {
+        self.instances.len()
+    }
+
+    /// Check if batch is empty
+    pub fn is_empty(&self) -> bool {
+        self.instances.is_empty()
+    }
+
+    /// Clear all instances
+    pub fn clear(&mut self) {
+        self.instances.clear();
+        self.sorted = false;
+    }
+}
+
+/// Sprite-count limits for a [`SpriteBatcher`].
+///
+/// The batcher grows on demand, so the limits are budgets rather than
+/// capacities: exceeding `soft` logs a warning (once per crossing) and flags
+/// [`RenderStats::soft_limit_exceeded`]; once `hard` is reached further
+/// sprites are dropped and counted in [`RenderStats::sprites_dropped`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SpriteLimits {
+    /// Sprite count above which a frame is reported as over budget
+    pub soft: usize,
+    /// Sprite count at which further sprites are rejected (`None` = unbounded)
+    pub hard: Option<usize>,
+}
+
+impl SpriteLimits {
+    /// Default soft limit — comfortably above what the 20-games titles draw.
+    pub const DEFAULT_SOFT: usize = 10_000;
+
+    /// Limits with the given soft budget and no hard cap.
+    pub fn soft(soft: usize) -> Self {
+        Self { soft, hard: None }
+    }
+
+    /// Set a hard cap (clamped so it is never below the soft limit).
+    pub fn with_hard(mut self, hard: usize) -> Self {
+        self.hard = Some(hard.max(self.soft));
+        self
+    }
+}
+
+impl Default for SpriteLimits {
+    fn default() -> Self {
+        Self::soft(Self::DEFAULT_SOFT)
+    }
+}
+
+/// Sprite batcher for efficient rendering.
+///
+/// Batches are pooled: [`clear`](Self::clear) empties them but keeps their
+/// allocations, so a steady-state frame reuses last frame's buffers instead
+/// of reallocating.
+#[derive(Default)]
+pub struct SpriteBatcher {
+    batches: HashMap<BatchKey, SpriteBatch>,
+    limits: SpriteLimits,
+    /// Sprites accepted since the last clear (avoids summing batches per add).
+    count: usize,
+    /// Sprites rejected by the hard limit since the last clear.
+    dropped: usize,
+    /// Sprites removed by [`cull_to_camera`](Self::cull_to_camera) or
+    /// reported by [`record_culled`](Self::record_culled) since the last clear.
+    culled: usize,
+    /// Whether the soft-limit warning has been logged for the current
+    /// crossing; re-armed by the first frame that stays under the limit.
+    soft_warned: bool,
+}
+
+impl SpriteBatcher {
+    /// Create a new sprite batcher with [`SpriteLimits::default`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a sprite batcher with explicit soft/hard limits
+    pub fn with_limits(limits: SpriteLimits) -> Self {
+        Self {
+            limits,
+            ..Self::default()
+        }
+    }
+
+    /// The batcher's sprite limits
+    pub fn limits(&self) -> SpriteLimits {
+        self.limits
+    }
+
+    /// Replace the batcher's sprite limits (takes effect immediately)
+    pub fn set_limits(&mut self, limits: SpriteLimits) {
+        self.limits = limits;
+    }
+
+    /// Add a sprite to the batcher.
+    ///
+    /// Returns `false` (and counts the sprite as dropped) when the hard
+    /// limit has been reached.
+    pub fn add_sprite(&mut self, sprite: &Sprite) -> bool {
+        if self.limits.hard.is_some_and(|hard| self.count >= hard) {
+            self.dropped += 1;
+            return false;
+        }
+
+        let key = BatchKey::of(sprite);
+        let batch = self.batches
+            .entry(key)
+            .or_insert_with(|| SpriteBatch::new(key.texture).with_mask_texture(key.mask_texture));
+
+        batch.add_instance(sprite.to_instance());
+        self.count += 1;
+
+        if self.count == self.limits.soft + 1 && !self.soft_warned {
+            log::warn!(
+                "SpriteBatcher soft limit exceeded: more than {} sprites this frame",
+                self.limits.soft
+            );
+            self.soft_warned = true;
+        }
+        true
+    }
+
+    /// Add multiple sprites
+    pub fn add_sprites(&mut self, sprites: &[Sprite]) {
+        for sprite in sprites {
+            self.add_sprite(sprite);
+        }
+    }
+
+    /// Sort all batches by depth
+    pub fn sort_all_batches(&mut self) {
+        for batch in self.batches.values_mut() {
+            batch.sort_by_depth();
+        }
+    }
+
+    /// Remove sprites that lie entirely outside `camera`'s view and return
+    /// how many were removed (frustum culling).
+    ///
+    /// Each sprite is tested by its bounding circle against the view
+    /// rectangle, widened to contain the camera's rotation (see
+    /// [`CameraView::may_see`]) — conservative, so nothing visible is culled.
+    /// Culled sprites stop counting toward the sprite limits and are
+    /// reported in [`RenderStats::sprites_culled`]. A camera with a
+    /// non-positive or non-finite zoom culls nothing.
+    pub fn cull_to_camera(&mut self, camera: &Camera) -> usize {
+        let Some(view) = CameraView::of(camera) else {
+            return 0;
+        };
+        let mut culled = 0;
+        for batch in self.batches.values_mut() {
+            let before = batch.instances.len();
+            batch
+                .instances
+                .retain(|instance| view.may_see(Vec2::from(instance.position), Vec2::from(instance.scale)));
+            culled += before - batch.instances.len();
+        }
+        self.count -= culled;
+        self.culled += culled;
+        culled
+    }
+
+    /// Count `count` sprites culled before they were added (e.g. skipped
+    /// by an extractor against a [`CameraView`]), so
+    /// [`RenderStats::sprites_culled`] covers them too.
+    pub fn record_culled(&mut self, count: usize) {
+        self.culled += count;
+    }
+
+    /// Get all batches
+    pub fn batches(&self) -> &HashMap<BatchKey, SpriteBatch> {
+        &self.batches
+    }
+
+    /// Get mutable batches
+    pub fn batches_mut(&mut self) -> &mut HashMap<BatchKey, SpriteBatch> {
+        &mut self.batches
+    }
+
+    /// The unmasked batch for `texture`, if any sprite used it
+    pub fn batch(&self, texture: TextureHandle) -> Option<&SpriteBatch> {
+        self.batches.get(&BatchKey::from(texture))
+    }
+
+    /// Clear all batches, keeping their allocations for the next frame
+    pub fn clear(&mut self) {
+        if self.count <= self.limits.soft {
+            self.soft_warned = false;
+        }
+        for batch in self.batches.values_mut() {
+            batch.clear();
+        }
+        self.count = 0;
+        self.dropped = 0;
+        self.culled = 0;
+    }
+
+    /// Get total sprite count
+    pub fn sprite_count(&self) -> usize {
+        self.batches.values().map(|batch| batch.len()).sum()
+    }
+
+    /// Statistics for the sprites added since the last [`clear`](Self::clear)
+    pub fn stats(&self) -> RenderStats {
+        let sprites = self.sprite_count();
+        RenderStats {
+            sprites,
+            batches: self.batches.values().filter(|batch| !batch.is_empty()).count(),
+            sprites_dropped: self.dropped,
+            sprites_culled: self.culled,
+            soft_limit_exceeded: sprites > self.limits.soft,
+            ..RenderStats::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,355 @@
+use super::*;
+use glam::{Vec2, Vec4};
+
+// ==================== SpriteBatch Tests ====================
+
+#[test]
+fn test_sprite_batch_new() {
+    let handle = TextureHandle::new(5);
+    let batch = SpriteBatch::new(handle);
+    assert_eq!(batch.texture_handle.id, 5);
+    assert!(batch.instances.is_empty());
+    assert!(!batch.sorted);
+}
+
+#[test]
+fn test_sprite_batch_add_instance() {
+    let mut batch = SpriteBatch::new(TextureHandle::default());
+    let instance = SpriteInstance::new(
+        Vec2::new(10.0, 20.0),
+        0.0,
+        Vec2::ONE,
+        [0.0, 0.0, 1.0, 1.0],
+        Vec4::ONE,
+        0.0,
+    );
+    batch.add_instance(instance);
+    assert_eq!(batch.len(), 1);
+    assert!(!batch.sorted);
+}
+
+#[test]
+fn test_sprite_batch_add_instances() {
+    let mut batch = SpriteBatch::new(TextureHandle::default());
+    let instances = vec![
+        SpriteInstance::new(Vec2::ZERO, 0.0, Vec2::ONE, [0.0, 0.0, 1.0, 1.0], Vec4::ONE, 0.0),
+        SpriteInstance::new(Vec2::ONE, 0.0, Vec2::ONE, [0.0, 0.0, 1.0, 1.0], Vec4::ONE, 1.0),
+        SpriteInstance::new(Vec2::new(2.0, 2.0), 0.0, Vec2::ONE, [0.0, 0.0, 1.0, 1.0], Vec4::ONE, 2.0),
+    ];
+    batch.add_instances(&instances);
+    assert_eq!(batch.len(), 3);
+}
+
+#[test]
+fn test_sprite_batch_sort_by_depth() {
+    let mut batch = SpriteBatch::new(TextureHandle::default());
+    batch.add_instance(SpriteInstance::new(Vec2::ZERO, 0.0, Vec2::ONE, [0.0, 0.0, 1.0, 1.0], Vec4::ONE, 3.0));
+    batch.add_instance(SpriteInstance::new(Vec2::ZERO, 0.0, Vec2::ONE, [0.0, 0.0, 1.0, 1.0], Vec4::ONE, 1.0));
+    batch.add_instance(SpriteInstance::new(Vec2::ZERO, 0.0, Vec2::ONE, [0.0, 0.0, 1.0, 1.0], Vec4::ONE, 2.0));
+
+    assert!(!batch.sorted);
+    batch.sort_by_depth();
+    assert!(batch.sorted);
+
+    // Verify sorted order (ascending)
+    assert_eq!(batch.instances[0].depth, 1.0);
+    assert_eq!(batch.instances[1].depth, 2.0);
+    assert_eq!(batch.instances[2].depth, 3.0);
+}
+
+#[test]
+fn test_sprite_batch_sort_handles_nan_depth_without_panicking() {
+    let mut batch = SpriteBatch::new(TextureHandle::default());
+    batch.add_instance(SpriteInstance::new(Vec2::ZERO, 0.0, Vec2::ONE, [0.0, 0.0, 1.0, 1.0], Vec4::ONE, f32::NAN));
+    batch.add_instance(SpriteInstance::new(Vec2::ZERO, 0.0, Vec2::ONE, [0.0, 0.0, 1.0, 1.0], Vec4::ONE, 1.0));
+    batch.add_instance(SpriteInstance::new(Vec2::ZERO, 0.0, Vec2::ONE, [0.0, 0.0, 1.0, 1.0], Vec4::ONE, 0.5));
+
+    batch.sort_by_depth();
+
+    // total_cmp orders NaN after all real numbers; the real values stay sorted.
+    assert!(batch.sorted);
+    assert_eq!(batch.instances[0].depth, 0.5);
+    assert_eq!(batch.instances[1].depth, 1.0);
+    assert!(batch.instances[2].depth.is_nan());
+}
+
+#[test]
+fn test_sprite_batch_sort_idempotent() {
+    let mut batch = SpriteBatch::new(TextureHandle::default());
+    batch.add_instance(SpriteInstance::new(Vec2::ZERO, 0.0, Vec2::ONE, [0.0, 0.0, 1.0, 1.0], Vec4::ONE, 2.0));
+    batch.add_instance(SpriteInstance::new(Vec2::ZERO, 0.0, Vec2::ONE, [0.0, 0.0, 1.0, 1.0], Vec4::ONE, 1.0));
+
+    batch.sort_by_depth();
+    assert!(batch.sorted);
+
+    // Sorting again should be a no-op since already sorted
+    batch.sort_by_depth();
+    assert!(batch.sorted);
+    assert_eq!(batch.instances[0].depth, 1.0);
+    assert_eq!(batch.instances[1].depth, 2.0);
+}
+
+#[test]
+fn test_sprite_batch_len_and_is_empty() {
+    let mut batch = SpriteBatch::new(TextureHandle::default());
+    assert!(batch.is_empty());
+    assert_eq!(batch.len(), 0);
+
+    batch.add_instance(SpriteInstance::new(Vec2::ZERO, 0.0, Vec2::ONE, [0.0, 0.0, 1.0, 1.0], Vec4::ONE, 0.0));
+    assert!(!batch.is_empty());
+    assert_eq!(batch.len(), 1);
+}
+
+#[test]
+fn test_sprite_batch_clear() {
+    let mut batch = SpriteBatch::new(TextureHandle::default());
+    batch.add_instance(SpriteInstance::new(Vec2::ZERO, 0.0, Vec2::ONE, [0.0, 0.0, 1.0, 1.0], Vec4::ONE, 0.0));
+    batch.add_instance(SpriteInstance::new(Vec2::ONE, 0.0, Vec2::ONE, [0.0, 0.0, 1.0, 1.0], Vec4::ONE, 1.0));
+    batch.sort_by_depth();
+
+    assert_eq!(batch.len(), 2);
+    assert!(batch.sorted);
+
+    batch.clear();
+    assert!(batch.is_empty());
+    assert!(!batch.sorted);
+}
+
+#[test]
+fn test_sprite_batch_sorted_flag_reset_on_add() {
+    let mut batch = SpriteBatch::new(TextureHandle::default());
+    batch.add_instance(SpriteInstance::new(Vec2::ZERO, 0.0, Vec2::ONE, [0.0, 0.0, 1.0, 1.0], Vec4::ONE, 0.0));
+    batch.sort_by_depth();
+    assert!(batch.sorted);
+
+    // Adding should reset sorted flag
+    batch.add_instance(SpriteInstance::new(Vec2::ONE, 0.0, Vec2::ONE, [0.0, 0.0, 1.0, 1.0], Vec4::ONE, 1.0));
+    assert!(!batch.sorted);
+}
+
+// ==================== SpriteBatcher Tests ====================
+
+#[test]
+fn test_sprite_batcher_new() {
+    let batcher = SpriteBatcher::new();
+    assert_eq!(batcher.sprite_count(), 0);
+    assert!(batcher.batches().is_empty());
+}
+
+#[test]
+fn test_sprite_batcher_add_sprite() {
+    let mut batcher = SpriteBatcher::new();
+    let sprite = Sprite::new(TextureHandle::new(1));
+    batcher.add_sprite(&sprite);
+    assert_eq!(batcher.sprite_count(), 1);
+}
+
+#[test]
+fn test_sprite_batcher_add_sprites() {
+    let mut batcher = SpriteBatcher::new();
+    let sprites = vec![
+        Sprite::new(TextureHandle::new(1)),
+        Sprite::new(TextureHandle::new(1)),
+        Sprite::new(TextureHandle::new(2)),
+    ];
+    batcher.add_sprites(&sprites);
+    assert_eq!(batcher.sprite_count(), 3);
+}
+
+#[test]
+fn test_sprite_batcher_groups_by_texture() {
+    let mut batcher = SpriteBatcher::new();
+
+    // Add sprites with different textures
+    batcher.add_sprite(&Sprite::new(TextureHandle::new(1)));
+    batcher.add_sprite(&Sprite::new(TextureHandle::new(1)));
+    batcher.add_sprite(&Sprite::new(TextureHandle::new(2)));
+    batcher.add_sprite(&Sprite::new(TextureHandle::new(2)));
+    batcher.add_sprite(&Sprite::new(TextureHandle::new(3)));
+
+    let batches = batcher.batches();
+    assert_eq!(batches.len(), 3); // 3 different textures
+
+    assert_eq!(batches.get(&BatchKey::from(TextureHandle::new(1))).unwrap().len(), 2);
+    assert_eq!(batches.get(&BatchKey::from(TextureHandle::new(2))).unwrap().len(), 2);
+    assert_eq!(batches.get(&BatchKey::from(TextureHandle::new(3))).unwrap().len(), 1);
+}
+
+#[test]
+fn test_sprite_batcher_sprite_count() {
+    let mut batcher = SpriteBatcher::new();
+    assert_eq!(batcher.sprite_count(), 0);
+
+    batcher.add_sprite(&Sprite::new(TextureHandle::new(1)));
+    assert_eq!(batcher.sprite_count(), 1);
+
+    batcher.add_sprite(&Sprite::new(TextureHandle::new(2)));
+    assert_eq!(batcher.sprite_count(), 2);
+
+    batcher.add_sprite(&Sprite::new(TextureHandle::new(1)));
+    assert_eq!(batcher.sprite_count(), 3);
+}
+
+#[test]
+fn test_sprite_batcher_sort_all_batches() {
+    let mut batcher = SpriteBatcher::new();
+
+    batcher.add_sprite(&Sprite::new(TextureHandle::new(1)).with_depth(3.0));
+    batcher.add_sprite(&Sprite::new(TextureHandle::new(1)).with_depth(1.0));
+    batcher.add_sprite(&Sprite::new(TextureHandle::new(2)).with_depth(5.0));
+    batcher.add_sprite(&Sprite::new(TextureHandle::new(2)).with_depth(2.0));
+
+    batcher.sort_all_batches();
+
+    let batch1 = batcher.batch(TextureHandle::new(1)).unwrap();
+    assert!(batch1.sorted);
+    assert_eq!(batch1.instances[0].depth, 1.0);
+    assert_eq!(batch1.instances[1].depth, 3.0);
+
+    let batch2 = batcher.batch(TextureHandle::new(2)).unwrap();
+    assert!(batch2.sorted);
+    assert_eq!(batch2.instances[0].depth, 2.0);
+    assert_eq!(batch2.instances[1].depth, 5.0);
+}
+
+#[test]
+fn test_sprite_batcher_clear() {
+    let mut batcher = SpriteBatcher::new();
+    batcher.add_sprite(&Sprite::new(TextureHandle::new(1)));
+    batcher.add_sprite(&Sprite::new(TextureHandle::new(2)));
+    assert_eq!(batcher.sprite_count(), 2);
+
+    batcher.clear();
+    assert_eq!(batcher.sprite_count(), 0);
+
+    // Batches still exist but are empty
+    assert!(!batcher.batches().is_empty());
+    for batch in batcher.batches().values() {
+        assert!(batch.is_empty());
+    }
+}
+
+#[test]
+fn test_sprite_batcher_default_limits_have_no_hard_cap() {
+    let batcher = SpriteBatcher::new();
+    assert_eq!(batcher.limits().soft, SpriteLimits::DEFAULT_SOFT);
+    assert_eq!(batcher.limits().hard, None);
+}
+
+#[test]
+fn test_sprite_batcher_flags_soft_limit_without_dropping() {
+    let mut batcher = SpriteBatcher::with_limits(SpriteLimits::soft(2));
+    for _ in 0..3 {
+        assert!(batcher.add_sprite(&Sprite::new(TextureHandle::new(1))));
+    }
+
+    let stats = batcher.stats();
+    assert_eq!(stats.sprites, 3);
+    assert!(stats.soft_limit_exceeded);
+    assert_eq!(stats.sprites_dropped, 0);
+}
+
+#[test]
+fn test_sprite_batcher_hard_limit_drops_and_counts_excess() {
+    let mut batcher = SpriteBatcher::with_limits(SpriteLimits::soft(1).with_hard(2));
+    assert!(batcher.add_sprite(&Sprite::new(TextureHandle::new(1))));
+    assert!(batcher.add_sprite(&Sprite::new(TextureHandle::new(2))));
+    assert!(!batcher.add_sprite(&Sprite::new(TextureHandle::new(1))));
+
+    let stats = batcher.stats();
+    assert_eq!(stats.sprites, 2);
+    assert_eq!(stats.sprites_dropped, 1);
+    assert_eq!(stats.batches, 2);
+}
+
+#[test]
+fn test_sprite_limits_hard_is_never_below_soft() {
+    let limits = SpriteLimits::soft(100).with_hard(10);
+    assert_eq!(limits.hard, Some(100));
+}
+
+#[test]
+fn test_sprite_batcher_clear_resets_stats_but_keeps_batch_capacity() {
+    let mut batcher = SpriteBatcher::with_limits(SpriteLimits::soft(1).with_hard(1));
+    batcher.add_sprite(&Sprite::new(TextureHandle::new(1)));
+    batcher.add_sprite(&Sprite::new(TextureHandle::new(1)));
+    let capacity = batcher.batch(TextureHandle::new(1)).unwrap().instances.capacity();
+
+    batcher.clear();
+
+    assert_eq!(batcher.stats(), RenderStats::default());
+    assert_eq!(batcher.batch(TextureHandle::new(1)).unwrap().instances.capacity(), capacity);
+}
+
+#[test]
+fn test_sprite_batcher_batches_mutable() {
+    let mut batcher = SpriteBatcher::new();
+    batcher.add_sprite(&Sprite::new(TextureHandle::new(1)));
+
+    // Verify we can get mutable access
+    let batches = batcher.batches_mut();
+    if let Some(batch) = batches.get_mut(&BatchKey::from(TextureHandle::new(1))) {
+        batch.clear();
+    }
+
+    assert_eq!(batcher.sprite_count(), 0);
+}
+
+#[test]
+fn test_sprite_batcher_culls_sprites_outside_the_camera() {
+    let mut batcher = SpriteBatcher::new();
+    let camera = Camera::new(Vec2::ZERO, Vec2::new(800.0, 600.0));
+    let at = |x: f32, y: f32| {
+        Sprite::new(TextureHandle::new(1)).with_position(Vec2::new(x, y)).with_scale(Vec2::splat(20.0))
+    };
+    batcher.add_sprite(&at(0.0, 0.0));
+    // Center off-screen but the quad still overlaps the right edge.
+    batcher.add_sprite(&at(405.0, 0.0));
+    batcher.add_sprite(&at(1000.0, 0.0));
+    batcher.add_sprite(&at(0.0, -900.0));
+
+    assert_eq!(batcher.cull_to_camera(&camera), 2);
+    let stats = batcher.stats();
+    assert_eq!((stats.sprites, stats.sprites_culled), (2, 2));
+
+    // Sprites an extractor skipped up front count as culled too
+    batcher.record_culled(3);
+    assert_eq!(batcher.stats().sprites_culled, 5);
+}
+
+#[test]
+fn test_sprite_batcher_cull_respects_zoom_and_rotation() {
+    let mut batcher = SpriteBatcher::new();
+    let sprite = Sprite::new(TextureHandle::new(1)).with_position(Vec2::new(500.0, 0.0)).with_scale(Vec2::ONE);
+    batcher.add_sprite(&sprite);
+
+    // Zoomed out 2x the view spans ±800 horizontally.
+    assert_eq!(batcher.cull_to_camera(&Camera::new(Vec2::ZERO, Vec2::new(800.0, 600.0)).with_zoom(0.5)), 0);
+    // A quarter turn swaps the view's extents: ±300 horizontally.
+    let rotated = Camera::new(Vec2::ZERO, Vec2::new(800.0, 600.0)).with_rotation(std::f32::consts::FRAC_PI_2);
+    assert_eq!(batcher.cull_to_camera(&rotated), 1);
+}
+
+#[test]
+fn test_sprite_batcher_splits_batches_by_mask_texture_only() {
+    use crate::sprite::SpriteMask;
+
+    let texture = TextureHandle::new(1);
+    let circle = SpriteMask::ellipse(Vec2::ZERO, Vec2::splat(64.0));
+    let alpha = SpriteMask::texture(TextureHandle::new(9), [0.0, 0.0, 1.0, 1.0], Vec2::ZERO, Vec2::splat(64.0));
+
+    let mut batcher = SpriteBatcher::new();
+    batcher.add_sprite(&Sprite::new(texture));
+    batcher.add_sprite(&Sprite::new(texture).with_mask(circle));
+    batcher.add_sprite(&Sprite::new(texture).with_mask(alpha));
+
+    // Shape masks live in the instance data, so they share the plain batch
+    let plain = batcher.batch(texture).unwrap();
+    assert_eq!(plain.len(), 2);
+    assert_eq!(plain.instances[0].mask, [0.0; 4]);
+    assert_eq!(plain.instances[1].mask[0], 2.0);
+
+    let key = BatchKey { texture, mask_texture: TextureHandle::new(9) };
+    let masked = &batcher.batches()[&key];
+    assert_eq!((masked.len(), masked.key()), (1, key));
+}
@@ -0,0 +1,41 @@
+//! Camera visibility test shared by batch culling and sprite extraction.
+
+use glam::Vec2;
+
+use crate::sprite_data::Camera;
+
+/// The world-space rectangle a camera sees, widened to contain its
+/// rotation, for conservative sprite visibility tests.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CameraView {
+    /// View center (the camera position)
+    pub center: Vec2,
+    /// Half the axis-aligned extent of the rotated view
+    pub half_extents: Vec2,
+}
+
+impl CameraView {
+    /// The view of `camera`, or `None` for a non-positive or non-finite
+    /// zoom (which sees nothing sensible, so callers cull nothing).
+    pub fn of(camera: &Camera) -> Option<Self> {
+        if !camera.zoom.is_finite() || camera.zoom <= 0.0 {
+            return None;
+        }
+        let half = camera.viewport_size * 0.5 / camera.zoom;
+        let (sin, cos) = camera.rotation.sin_cos();
+        let (sin, cos) = (sin.abs(), cos.abs());
+        Some(Self {
+            center: camera.position,
+            half_extents: Vec2::new(half.x * cos + half.y * sin, half.x * sin + half.y * cos),
+        })
+    }
+
+    /// Whether a sprite quad of `scale` centered at `position` may be
+    /// visible. Tested by its bounding circle (half the quad's diagonal), so
+    /// the quad's rotation doesn't matter and nothing visible is rejected.
+    pub fn may_see(&self, position: Vec2, scale: Vec2) -> bool {
+        let radius = scale.length() * 0.5;
+        let distance = (position - self.center).abs();
+        distance.x <= self.half_extents.x + radius && distance.y <= self.half_extents.y + radius
+    }
+}
@@ -2,29 +2,31 @@
 //!
 //! Flattening batches and uploading the instance buffer every frame is pure
 //! waste when nothing on screen moved. [`InstanceCache`] stages the flattened
-//! instances into a reusable buffer and reports whether they (or the batch
-//! layout — texture boundaries) differ from what was last staged; the GPU
-//! upload is skipped when they don't. Instances are compared as raw bytes
-//! (they're `bytemuck::Pod`), so the check is exact and NaN-safe.
+//! instances into a reusable buffer and reports which instances differ from
+//! what was last staged — the dirty range — so only that slice is uploaded,
+//! and nothing at all when the frame is unchanged. Instances are compared as
+//! raw bytes (they're `bytemuck::Pod`), so the check is exact and NaN-safe.
+//!
+//! Draw ranges are derived from the batch list at draw time, so a change in
+//! batch boundaries alone (same flattened bytes) needs no upload.
+
+use std::ops::Range;
 
-use bytemuck::cast_slice;
+use bytemuck::bytes_of;
 
 use crate::sprite::SpriteBatch;
 use crate::sprite_data::SpriteInstance;
-use crate::texture::TextureHandle;
 
 /// Staging buffer + last-uploaded snapshot for sprite instances.
 #[derive(Default)]
 pub struct InstanceCache {
     /// Instances as last staged for upload.
     instances: Vec<SpriteInstance>,
-    /// Batch layout as last staged: (texture, instance count) per batch.
-    layout: Vec<(TextureHandle, usize)>,
-    /// Scratch buffers reused across frames (no per-frame allocations).
+    /// Scratch buffer reused across frames (no per-frame allocations).
     staging: Vec<SpriteInstance>,
-    staging_layout: Vec<(TextureHandle, usize)>,
     uploads_performed: u64,
     uploads_skipped: u64,
+    instances_uploaded: u64,
 }
 
 impl InstanceCache {
@@ -33,34 +35,39 @@ impl InstanceCache {
         Self::default()
     }
 
-    /// Flatten `batches` into the staging buffer and report whether the
-    /// result differs from what was last staged (i.e. whether a GPU upload
-    /// is needed). On change the staged data becomes the new snapshot.
-    pub fn stage(&mut self, batches: &[&SpriteBatch]) -> bool {
+    /// Flatten `batches` into the staging buffer and return the range of
+    /// instances that differ from what was last staged — the slice of
+    /// [`staged`](Self::staged) to upload — or `None` when no upload is
+    /// needed. The staged data always becomes the new snapshot.
+    ///
+    /// The range spans the first to the last differing instance, plus any
+    /// instances past the previous length. A shorter list whose remaining
+    /// prefix is unchanged needs no upload: the stale tail is never drawn.
+    pub fn stage(&mut self, batches: &[&SpriteBatch]) -> Option<Range<usize>> {
         self.staging.clear();
-        self.staging_layout.clear();
         for batch in batches {
             self.staging.extend_from_slice(&batch.instances);
-            self.staging_layout.push((batch.texture_handle, batch.instances.len()));
         }
 
-        let unchanged = self.staging_layout == self.layout
-            && cast_slice::<SpriteInstance, u8>(&self.staging)
-                == cast_slice::<SpriteInstance, u8>(&self.instances);
-
-        if unchanged {
-            self.uploads_skipped += 1;
-            false
-        } else {
-            std::mem::swap(&mut self.instances, &mut self.staging);
-            std::mem::swap(&mut self.layout, &mut self.staging_layout);
-            self.uploads_performed += 1;
-            true
+        let dirty = dirty_range(&self.instances, &self.staging);
+        std::mem::swap(&mut self.instances, &mut self.staging);
+        match &dirty {
+            Some(range) => {
+                self.uploads_performed += 1;
+                self.instances_uploaded += range.len() as u64;
+            }
+            None => self.uploads_skipped += 1,
         }
+        dirty
+    }
+
+    /// Forget the snapshot so the next [`stage`](Self::stage) uploads every
+    /// instance (use after the buffer was written by other means).
+    pub fn invalidate(&mut self) {
+        self.instances.clear();
     }
 
-    /// The instances staged by the last [`stage`](Self::stage) call that
-    /// reported a change — the data to upload.
+    /// The instances staged by the last [`stage`](Self::stage) call.
     pub fn staged(&self) -> &[SpriteInstance] {
         &self.instances
     }
@@ -74,11 +81,34 @@ impl InstanceCache {
     pub fn uploads_skipped(&self) -> u64 {
         self.uploads_skipped
     }
+
+    /// Total number of instances uploaded across all dirty ranges.
+    pub fn instances_uploaded(&self) -> u64 {
+        self.instances_uploaded
+    }
+}
+
+/// Range of `new` that differs from `old`, or `None` if `new` is a prefix of
+/// `old` (byte-for-byte).
+fn dirty_range(old: &[SpriteInstance], new: &[SpriteInstance]) -> Option<Range<usize>> {
+    let common = old.len().min(new.len());
+    let same = |i: usize| bytes_of(&old[i]) == bytes_of(&new[i]);
+    let start = (0..common).find(|&i| !same(i)).unwrap_or(common);
+    if start == new.len() {
+        return None;
+    }
+    let end = if new.len() > common {
+        new.len()
+    } else {
+        (start..common).rev().find(|&i| !same(i)).map_or(start, |i| i + 1)
+    };
+    Some(start..end)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::texture::TextureHandle;
     use glam::{Vec2, Vec4};
 
     fn instance(x: f32) -> SpriteInstance {
@@ -97,53 +127,66 @@ mod tests {
         let batch = batch_with(&[instance(1.0), instance(2.0)], TextureHandle::WHITE);
         let refs = [&batch];
 
-        assert!(cache.stage(&refs), "first stage must upload");
-        assert!(!cache.stage(&refs), "identical restage must skip");
-        assert!(!cache.stage(&refs), "and keep skipping");
+        assert_eq!(cache.stage(&refs), Some(0..2), "first stage uploads everything");
+        assert_eq!(cache.stage(&refs), None, "identical restage must skip");
+        assert_eq!(cache.stage(&refs), None, "and keep skipping");
         assert_eq!(cache.uploads_performed(), 1);
         assert_eq!(cache.uploads_skipped(), 2);
         assert_eq!(cache.staged().len(), 2, "snapshot holds the staged data");
     }
 
     #[test]
-    fn test_instance_change_triggers_upload() {
+    fn test_instance_change_uploads_only_the_dirty_range() {
         let mut cache = InstanceCache::new();
-        let batch = batch_with(&[instance(1.0)], TextureHandle::WHITE);
-        assert!(cache.stage(&[&batch]));
+        let xs: Vec<SpriteInstance> = (0..6).map(|i| instance(i as f32)).collect();
+        assert_eq!(cache.stage(&[&batch_with(&xs, TextureHandle::WHITE)]), Some(0..6));
+
+        // Sprites 2 and 3 moved; the rest are untouched.
+        let mut moved = xs.clone();
+        moved[2] = instance(20.0);
+        moved[3] = instance(30.0);
+        assert_eq!(cache.stage(&[&batch_with(&moved, TextureHandle::WHITE)]), Some(2..4));
+        assert_eq!(cache.staged()[2].position, [20.0, 0.0]);
+        assert_eq!(cache.instances_uploaded(), 8);
+    }
+
+    #[test]
+    fn test_growth_uploads_the_new_tail_and_shrink_uploads_nothing() {
+        let mut cache = InstanceCache::new();
+        let two = batch_with(&[instance(1.0), instance(2.0)], TextureHandle::WHITE);
+        cache.stage(&[&two]);
+
+        let three = batch_with(&[instance(1.0), instance(2.0), instance(3.0)], TextureHandle::WHITE);
+        assert_eq!(cache.stage(&[&three]), Some(2..3), "appended sprite is the only upload");
 
-        // The sprite moved.
-        let moved = batch_with(&[instance(5.0)], TextureHandle::WHITE);
-        assert!(cache.stage(&[&moved]), "a moved instance must re-upload");
-        assert_eq!(cache.staged()[0].position, [5.0, 0.0]);
+        assert_eq!(cache.stage(&[&two]), None, "unchanged prefix needs no upload");
+        assert_eq!(cache.staged().len(), 2, "but the snapshot shrinks");
     }
 
     #[test]
-    fn test_layout_change_triggers_upload_even_with_same_bytes() {
+    fn test_layout_change_with_same_bytes_skips_upload() {
         let mut cache = InstanceCache::new();
         // Two instances on one texture...
         let one = batch_with(&[instance(1.0), instance(2.0)], TextureHandle::WHITE);
-        assert!(cache.stage(&[&one]));
+        assert!(cache.stage(&[&one]).is_some());
 
         // ...vs the same flattened instances split across two textures: the
-        // draw ranges differ, so this must count as changed.
+        // buffer contents are identical and draw ranges come from the batches.
         let a = batch_with(&[instance(1.0)], TextureHandle::WHITE);
         let b = batch_with(&[instance(2.0)], TextureHandle { id: 7 });
-        assert!(
-            cache.stage(&[&a, &b]),
-            "same bytes with different batch boundaries must re-upload"
-        );
+        assert_eq!(cache.stage(&[&a, &b]), None);
     }
 
     #[test]
     fn test_empty_to_content_and_back() {
         let mut cache = InstanceCache::new();
         let empty: [&SpriteBatch; 0] = [];
-        assert!(!cache.stage(&empty), "empty first frame stages nothing new");
+        assert_eq!(cache.stage(&empty), None, "empty first frame stages nothing new");
 
         let batch = batch_with(&[instance(1.0)], TextureHandle::WHITE);
-        assert!(cache.stage(&[&batch]), "content after empty must upload");
+        assert_eq!(cache.stage(&[&batch]), Some(0..1), "content after empty must upload");
 
-        assert!(cache.stage(&empty), "content -> empty is a change");
+        assert_eq!(cache.stage(&empty), None, "nothing left to upload");
         assert!(cache.staged().is_empty());
     }
 }
@@ -0,0 +1,173 @@
+//! Sprite masks: clip a sprite to a world-space rectangle, ellipse, or the
+//! alpha of a mask texture.
+//!
+//! Masks are evaluated per fragment in the sprite shader, so any number of
+//! differently-masked sprites still draw in one batch — except texture
+//! masks, which batch per (texture, mask texture) pair because the mask
+//! texture is bound alongside the sprite's own.
+
+use glam::Vec2;
+
+use crate::sprite_data::SpriteInstance;
+use crate::texture::TextureHandle;
+
+/// The shape a [`SpriteMask`] clips to.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MaskShape {
+    /// Axis-aligned (before the mask's rotation) rectangle
+    Rect,
+    /// Ellipse inscribed in the mask rectangle — a circle for square masks
+    Ellipse,
+    /// The alpha channel of `region` of `texture`, stretched over the mask
+    /// rectangle. Nothing outside the rectangle shows.
+    Texture {
+        /// Mask texture
+        texture: TextureHandle,
+        /// Texture region (x, y, width, height) in texture coordinates [0, 1]
+        region: [f32; 4],
+    },
+}
+
+impl MaskShape {
+    /// Mask kind as the f32 the instance data carries (0 = no mask).
+    fn to_f32(self) -> f32 {
+        match self {
+            MaskShape::Rect => 1.0,
+            MaskShape::Ellipse => 2.0,
+            MaskShape::Texture { .. } => 3.0,
+        }
+    }
+}
+
+/// A world-space mask: the sprite only shows inside it (or only outside,
+/// when inverted).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpriteMask {
+    /// Shape to clip to
+    pub shape: MaskShape,
+    /// Mask center in world space
+    pub center: Vec2,
+    /// Half extents of the mask rectangle, in world units
+    pub half_size: Vec2,
+    /// Mask rotation in radians (counter-clockwise)
+    pub rotation: f32,
+    /// Show the sprite only *outside* the mask
+    pub invert: bool,
+}
+
+impl SpriteMask {
+    /// Rectangular mask of `size` centered on `center`.
+    pub fn rect(center: Vec2, size: Vec2) -> Self {
+        Self { shape: MaskShape::Rect, center, half_size: size.abs() * 0.5, rotation: 0.0, invert: false }
+    }
+
+    /// Elliptical mask inscribed in `size` — a minimap circle.
+    pub fn ellipse(center: Vec2, size: Vec2) -> Self {
+        Self { shape: MaskShape::Ellipse, ..Self::rect(center, size) }
+    }
+
+    /// Texture-alpha mask: `region` of `texture` stretched over `size`.
+    pub fn texture(texture: TextureHandle, region: [f32; 4], center: Vec2, size: Vec2) -> Self {
+        Self { shape: MaskShape::Texture { texture, region }, ..Self::rect(center, size) }
+    }
+
+    /// Rotate the mask (radians, counter-clockwise).
+    pub fn with_rotation(mut self, rotation: f32) -> Self {
+        self.rotation = rotation;
+        self
+    }
+
+    /// Show the sprite only outside the mask.
+    pub fn inverted(mut self) -> Self {
+        self.invert = !self.invert;
+        self
+    }
+
+    /// Texture bound as the mask for this sprite's batch: the mask texture,
+    /// or the built-in white texture for shape masks.
+    pub fn mask_texture(&self) -> TextureHandle {
+        match self.shape {
+            MaskShape::Texture { texture, .. } => texture,
+            MaskShape::Rect | MaskShape::Ellipse => TextureHandle::WHITE,
+        }
+    }
+
+    /// `point` in the mask's normalized space: the mask rectangle spans
+    /// -1..=1 on both axes.
+    fn normalized(&self, point: Vec2) -> Vec2 {
+        let local = Vec2::from_angle(-self.rotation).rotate(point - self.center);
+        local / self.half_size.max(Vec2::splat(f32::EPSILON))
+    }
+
+    /// Whether the mask lets `point` (world space) through, ignoring edge
+    /// anti-aliasing. Texture masks test their rectangle only — the alpha
+    /// lives on the GPU.
+    pub fn contains(&self, point: Vec2) -> bool {
+        let q = self.normalized(point);
+        let inside = match self.shape {
+            MaskShape::Rect | MaskShape::Texture { .. } => q.x.abs() <= 1.0 && q.y.abs() <= 1.0,
+            MaskShape::Ellipse => q.length_squared() <= 1.0,
+        };
+        inside != self.invert
+    }
+
+    /// Instance data for the sprite shader:
+    /// `(bounds [cx, cy, hw, hh], params [kind, rotation, invert, 0], region)`.
+    pub(crate) fn instance_data(&self) -> ([f32; 4], [f32; 4], [f32; 4]) {
+        let bounds = [self.center.x, self.center.y, self.half_size.x, self.half_size.y];
+        let params = [self.shape.to_f32(), self.rotation, if self.invert { 1.0 } else { 0.0 }, 0.0];
+        let region = match self.shape {
+            MaskShape::Texture { region, .. } => region,
+            MaskShape::Rect | MaskShape::Ellipse => [0.0, 0.0, 1.0, 1.0],
+        };
+        (bounds, params, region)
+    }
+}
+
+impl SpriteInstance {
+    /// Clip this instance to `mask` (builder-style): fills `mask_bounds`,
+    /// `mask` and `mask_region`, which stay zeroed (unmasked) otherwise.
+    pub fn with_mask(mut self, mask: &SpriteMask) -> Self {
+        (self.mask_bounds, self.mask, self.mask_region) = mask.instance_data();
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shape_masks_contain_points() {
+        let rect = SpriteMask::rect(Vec2::new(10.0, 0.0), Vec2::new(20.0, 10.0));
+        assert!(rect.contains(Vec2::new(19.0, 4.0)));
+        assert!(!rect.contains(Vec2::new(21.0, 0.0)));
+        assert!(rect.inverted().contains(Vec2::new(21.0, 0.0)));
+
+        // The rectangle's corner lies outside the inscribed ellipse
+        let circle = SpriteMask::ellipse(Vec2::ZERO, Vec2::splat(10.0));
+        assert!(circle.contains(Vec2::new(0.0, 4.9)));
+        assert!(!circle.contains(Vec2::new(4.0, 4.0)));
+
+        // Rotated a quarter turn, a wide rect becomes a tall one
+        let rotated = SpriteMask::rect(Vec2::ZERO, Vec2::new(20.0, 2.0)).with_rotation(std::f32::consts::FRAC_PI_2);
+        assert!(rotated.contains(Vec2::new(0.0, 9.0)));
+        assert!(!rotated.contains(Vec2::new(9.0, 0.0)));
+    }
+
+    #[test]
+    fn test_instance_data_and_mask_texture() {
+        let region = [0.5, 0.0, 0.5, 0.5];
+        let mask = SpriteMask::texture(TextureHandle::new(4), region, Vec2::new(1.0, 2.0), Vec2::new(6.0, 8.0)).inverted();
+        assert_eq!(mask.mask_texture(), TextureHandle::new(4));
+        assert_eq!(mask.instance_data(), ([1.0, 2.0, 3.0, 4.0], [3.0, 0.0, 1.0, 0.0], region));
+
+        let rect = SpriteMask::rect(Vec2::ZERO, Vec2::ONE);
+        assert_eq!(rect.mask_texture(), TextureHandle::WHITE);
+        assert_eq!(rect.instance_data().2, [0.0, 0.0, 1.0, 1.0]);
+
+        let instance: SpriteInstance = bytemuck::Zeroable::zeroed();
+        let masked = instance.with_mask(&mask);
+        assert_eq!((masked.mask_bounds, masked.mask, masked.mask_region), mask.instance_data());
+    }
+}
@@ -5,6 +5,7 @@ use std::sync::Arc;
 use wgpu::{Device, Queue, RenderPipeline, BindGroupLayout, Buffer, CommandEncoder};
 use wgpu::util::DeviceExt;
 
+use crate::render_stats::RenderStats;
 use crate::render_targets::{DEPTH_FORMAT, HDR_FORMAT, RenderTargets};
 use crate::sprite::SpriteBatch;
 use crate::sprite_data::{Camera, CameraUniform, DynamicBuffer, SpriteInstance, SpriteVertex, TextureResource};
@@ -91,7 +92,8 @@ impl SpritePipeline {
         // Create pipeline layout
         let layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
             label: Some("Sprite Pipeline Layout"),
-            bind_group_layouts: &[&camera_bind_group_layout, &texture_bind_group_layout],
+            // Set 2 is the mask texture, sharing the texture layout and bind groups
+            bind_group_layouts: &[&camera_bind_group_layout, &texture_bind_group_layout, &texture_bind_group_layout],
             ..Default::default()
         });
 
@@ -224,8 +226,13 @@ impl SpritePipeline {
 
     /// Update camera uniform
     pub fn update_camera(&self, queue: &Queue, camera: &Camera) {
-        let uniform = CameraUniform::from_camera(camera);
-        queue.write_buffer(&self.camera_buffer, 0, bytemuck::cast_slice(&[uniform]));
+        self.update_camera_uniform(queue, &CameraUniform::from_camera(camera));
+    }
+
+    /// Update camera uniform with an already built one (e.g. a
+    /// camera-relative one)
+    pub fn update_camera_uniform(&self, queue: &Queue, uniform: &CameraUniform) {
+        queue.write_buffer(&self.camera_buffer, 0, bytemuck::bytes_of(uniform));
     }
 
     /// Update instance buffer with sprite data and return the number of instances.
@@ -238,6 +245,8 @@ impl SpritePipeline {
         }
 
         self.instance_buffer.update(&self.device, queue, instances);
+        // The buffer no longer matches the cache's snapshot.
+        self.instance_cache.invalidate();
 
         instances.len()
     }
@@ -294,8 +303,9 @@ impl SpritePipeline {
     /// Draw sprites into the HDR target.
     ///
     /// `targets` provides the HDR color view (Rgba16Float) and matching depth
-    /// view. `clear_color` is applied to the HDR target — it's treated as a
-    /// linear RGB color, so values >1.0 are valid and bloom.
+    /// view. `clear_color` is applied to the HDR target as is — linear
+    /// light ([`Renderer::set_clear_color`](crate::Renderer::set_clear_color)
+    /// decodes its sRGB input), so values >1.0 are valid and bloom.
     ///
     /// The camera uniform is uploaded separately via
     /// [`update_camera`](Self::update_camera) before this call; the caller is
@@ -304,6 +314,8 @@ impl SpritePipeline {
     /// Uses cached bind groups for improved performance. Call
     /// [`cache_texture_bind_groups`](Self::cache_texture_bind_groups) before
     /// drawing if new textures have been loaded.
+    ///
+    /// Returns the draw calls and texture binds issued.
     pub fn draw(
         &mut self,
         encoder: &mut CommandEncoder,
@@ -311,7 +323,7 @@ impl SpritePipeline {
         batches: &[&SpriteBatch],
         targets: &RenderTargets,
         clear_color: wgpu::Color,
-    ) {
+    ) -> RenderStats {
         log::debug!("SPRITE DRAW: batches={}, clear_color={:?}", batches.len(), clear_color);
 
         // Ensure all textures have cached bind groups
@@ -329,6 +341,14 @@ impl SpritePipeline {
                     batch.len()
                 );
             }
+            if !batch.is_empty() && !self.texture_bind_group_cache.contains_key(&batch.mask_texture) {
+                log::warn!(
+                    "Missing mask texture: handle {:?} masking {} sprite(s) has no cached bind group. \
+                     Their texture mask falls back to its rectangle.",
+                    batch.mask_texture,
+                    batch.len()
+                );
+            }
         }
 
         // Begin render pass: clear HDR color + depth, draw sprites with depth-test.
@@ -367,6 +387,9 @@ impl SpritePipeline {
         render_pass.set_bind_group(0, &self.camera_bind_group, &[]);
 
         // Draw each batch
+        let mut stats = RenderStats::default();
+        let mut bound_texture = None;
+        let mut bound_mask = None;
         let mut instance_offset = 0u32;
         for batch in batches {
             if batch.is_empty() {
@@ -382,8 +405,26 @@ impl SpritePipeline {
                 continue;
             };
 
-            // Set texture bind group (set 1)
-            render_pass.set_bind_group(1, texture_bind_group, &[]);
+            // Set texture bind group (set 1), unless the previous batch
+            // already bound it (game and UI batches can share a texture)
+            if bound_texture != Some(batch.texture_handle) {
+                render_pass.set_bind_group(1, texture_bind_group, &[]);
+                bound_texture = Some(batch.texture_handle);
+                stats.texture_binds += 1;
+            }
+
+            // Set mask bind group (set 2); a missing mask texture falls back
+            // to white so the sprite still draws
+            if bound_mask != Some(batch.mask_texture) {
+                let mask_bind_group = self.texture_bind_group_cache
+                    .get(&batch.mask_texture)
+                    .or_else(|| self.texture_bind_group_cache.get(&TextureHandle::WHITE));
+                if let Some(mask_bind_group) = mask_bind_group {
+                    render_pass.set_bind_group(2, mask_bind_group, &[]);
+                    bound_mask = Some(batch.mask_texture);
+                    stats.texture_binds += 1;
+                }
+            }
 
             // Draw the instances for this batch
             let instance_count = batch.len() as u32;
@@ -392,10 +433,12 @@ impl SpritePipeline {
             // 6 indices per quad (2 triangles), draw instances from instance_offset to instance_offset + instance_count
             render_pass.set_index_buffer(self.index_buffer.slice(..), wgpu::IndexFormat::Uint16);
             render_pass.draw_indexed(0..6, 0, instance_offset..(instance_offset + instance_count));
+            stats.draw_calls += 1;
 
             // Update offset for next batch
             instance_offset += instance_count;
         }
+        stats
     }
 
     /// Get the render pipeline
@@ -415,16 +458,16 @@ impl SpritePipeline {
 
     /// Prepare sprite data for rendering by updating the instance buffer.
     ///
-    /// The upload happens only when the flattened instances or batch layout
-    /// actually changed since the last upload (GPP-15) — a static scene
-    /// re-renders from the buffer already on the GPU.
+    /// Only the dirty range of instances — those that differ from the last
+    /// upload — is written (GPP-15); a static scene re-renders from the
+    /// buffer already on the GPU. The buffer persists across frames and
+    /// grows in place, keeping its contents.
     pub fn prepare_sprites(&mut self, queue: &Queue, batches: &[&SpriteBatch]) {
-        if self.instance_cache.stage(batches) {
-            let staged = self.instance_cache.staged();
-            if !staged.is_empty() {
-                log::debug!("Uploading {} sprite instances to GPU", staged.len());
-                self.instance_buffer.update(&self.device, queue, staged);
-            }
-        }
+        let Some(dirty) = self.instance_cache.stage(batches) else {
+            return;
+        };
+        let staged = self.instance_cache.staged();
+        log::debug!("Uploading {} of {} sprite instances to GPU", dirty.len(), staged.len());
+        self.instance_buffer.write_range(&self.device, queue, staged.len(), dirty.start, &staged[dirty]);
     }
 }
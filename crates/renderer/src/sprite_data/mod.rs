@@ -0,0 +1,362 @@
+//! Sprite data structures and management
+
+use glam::{Vec2, Vec3, Vec4};
+use std::sync::Arc;
+use wgpu::{Device, Queue, Texture, TextureView, Sampler, Buffer};
+use crate::texture::SamplerConfig;
+
+// Re-export Camera2D and CameraUniform from common crate
+pub use common::{Camera, camera::CameraUniform};
+
+/// Vertex data for a sprite
+#[repr(C)]
+#[derive(Debug, Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct SpriteVertex {
+    /// Position in world space
+    pub position: [f32; 3],
+    /// Texture coordinates
+    pub tex_coords: [f32; 2],
+    /// Color tint (RGBA as 4 floats)
+    pub color: [f32; 4],
+}
+
+impl SpriteVertex {
+    /// Create a new sprite vertex
+    pub fn new(position: Vec3, tex_coords: Vec2, color: Vec4) -> Self {
+        Self {
+            position: position.to_array(),
+            tex_coords: tex_coords.to_array(),
+            color: color.to_array(),
+        }
+    }
+
+    /// Get the vertex buffer layout
+    pub fn desc() -> wgpu::VertexBufferLayout<'static> {
+        wgpu::VertexBufferLayout {
+            array_stride: std::mem::size_of::<SpriteVertex>() as wgpu::BufferAddress,
+            step_mode: wgpu::VertexStepMode::Vertex,
+            attributes: &[
+                // Position
+                wgpu::VertexAttribute {
+                    offset: 0,
+                    shader_location: 0,
+                    format: wgpu::VertexFormat::Float32x3,
+                },
+                // Texture coordinates
+                wgpu::VertexAttribute {
+                    offset: std::mem::size_of::<[f32; 3]>() as wgpu::BufferAddress,
+                    shader_location: 1,
+                    format: wgpu::VertexFormat::Float32x2,
+                },
+                // Color
+                wgpu::VertexAttribute {
+                    offset: std::mem::size_of::<[f32; 5]>() as wgpu::BufferAddress,
+                    shader_location: 2,
+                    format: wgpu::VertexFormat::Float32x4,
+                },
+            ],
+        }
+    }
+}
+
+/// Sprite instance data for batching
+#[repr(C)]
+#[derive(Debug, Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct SpriteInstance {
+    /// World position
+    pub position: [f32; 2],
+    /// Rotation in radians
+    pub rotation: f32,
+    /// Scale
+    pub scale: [f32; 2],
+    /// Texture region (x, y, width, height) in texture coordinates [0, 1]
+    pub tex_region: [f32; 4],
+    /// Color tint (sRGB; decoded to linear in the vertex shader)
+    pub color: [f32; 4],
+    /// Layer depth for sorting (and depth-test once HDR pipeline is enabled)
+    pub depth: f32,
+    /// Emissive intensity — 0.0 = no glow, >0.0 amplifies RGB above 1.0 so bloom picks it up
+    pub emissive: f32,
+    /// SDF shape parameters: `[kind, corner_radius, border_width, reserved]`.
+    /// kind 0 = plain textured quad (default, zeroed == legacy behavior),
+    /// 1 = rounded rect, 2 = circle. Radius/border are in local pixels.
+    pub shape: [f32; 4],
+    /// Mask rectangle in world space: `[center_x, center_y, half_w, half_h]`
+    pub mask_bounds: [f32; 4],
+    /// Mask parameters: `[kind, rotation, invert, reserved]`. kind 0 = no
+    /// mask (default, zeroed), 1 = rect, 2 = ellipse, 3 = texture alpha.
+    pub mask: [f32; 4],
+    /// Mask texture region (x, y, width, height) for texture masks
+    pub mask_region: [f32; 4],
+}
+
+impl SpriteInstance {
+    /// Create a new sprite instance with no emission
+    pub fn new(
+        position: Vec2,
+        rotation: f32,
+        scale: Vec2,
+        tex_region: [f32; 4],
+        color: Vec4,
+        depth: f32,
+    ) -> Self {
+        Self::with_emissive(position, rotation, scale, tex_region, color, depth, 0.0)
+    }
+
+    /// Create a new sprite instance with explicit emissive intensity
+    pub fn with_emissive(
+        position: Vec2,
+        rotation: f32,
+        scale: Vec2,
+        tex_region: [f32; 4],
+        color: Vec4,
+        depth: f32,
+        emissive: f32,
+    ) -> Self {
+        Self {
+            position: position.to_array(),
+            rotation,
+            scale: scale.to_array(),
+            tex_region,
+            color: color.to_array(),
+            depth,
+            emissive,
+            shape: [0.0; 4],
+            mask_bounds: [0.0; 4],
+            mask: [0.0; 4],
+            mask_region: [0.0; 4],
+        }
+    }
+
+    /// Set the SDF shape parameters (builder-style).
+    pub fn with_shape(mut self, shape: [f32; 4]) -> Self {
+        self.shape = shape;
+        self
+    }
+
+    /// Get the instance buffer layout
+    pub fn desc() -> wgpu::VertexBufferLayout<'static> {
+        wgpu::VertexBufferLayout {
+            array_stride: std::mem::size_of::<SpriteInstance>() as wgpu::BufferAddress,
+            step_mode: wgpu::VertexStepMode::Instance,
+            attributes: &[
+                // Position
+                wgpu::VertexAttribute {
+                    offset: 0,
+                    shader_location: 3,
+                    format: wgpu::VertexFormat::Float32x2,
+                },
+                // Rotation
+                wgpu::VertexAttribute {
+                    offset: std::mem::size_of::<[f32; 2]>() as wgpu::BufferAddress,
+                    shader_location: 4,
+                    format: wgpu::VertexFormat::Float32,
+                },
+                // Scale
+                wgpu::VertexAttribute {
+                    offset: std::mem::size_of::<[f32; 3]>() as wgpu::BufferAddress,
+                    shader_location: 5,
+                    format: wgpu::VertexFormat::Float32x2,
+                },
+                // Texture region
+                wgpu::VertexAttribute {
+                    offset: std::mem::size_of::<[f32; 5]>() as wgpu::BufferAddress,
+                    shader_location: 6,
+                    format: wgpu::VertexFormat::Float32x4,
+                },
+                // Color
+                wgpu::VertexAttribute {
+                    offset: std::mem::size_of::<[f32; 9]>() as wgpu::BufferAddress,
+                    shader_location: 7,
+                    format: wgpu::VertexFormat::Float32x4,
+                },
+                // Depth
+                wgpu::VertexAttribute {
+                    offset: std::mem::size_of::<[f32; 13]>() as wgpu::BufferAddress,
+                    shader_location: 8,
+                    format: wgpu::VertexFormat::Float32,
+                },
+                // Emissive intensity
+                wgpu::VertexAttribute {
+                    offset: std::mem::size_of::<[f32; 14]>() as wgpu::BufferAddress,
+                    shader_location: 9,
+                    format: wgpu::VertexFormat::Float32,
+                },
+                // SDF shape params [kind, corner_radius, border_width, reserved]
+                wgpu::VertexAttribute {
+                    offset: std::mem::size_of::<[f32; 15]>() as wgpu::BufferAddress,
+                    shader_location: 10,
+                    format: wgpu::VertexFormat::Float32x4,
+                },
+                // Mask bounds [center_x, center_y, half_w, half_h]
+                wgpu::VertexAttribute {
+                    offset: std::mem::size_of::<[f32; 19]>() as wgpu::BufferAddress,
+                    shader_location: 11,
+                    format: wgpu::VertexFormat::Float32x4,
+                },
+                // Mask params [kind, rotation, invert, reserved]
+                wgpu::VertexAttribute {
+                    offset: std::mem::size_of::<[f32; 23]>() as wgpu::BufferAddress,
+                    shader_location: 12,
+                    format: wgpu::VertexFormat::Float32x4,
+                },
+                // Mask texture region
+                wgpu::VertexAttribute {
+                    offset: std::mem::size_of::<[f32; 27]>() as wgpu::BufferAddress,
+                    shader_location: 13,
+                    format: wgpu::VertexFormat::Float32x4,
+                },
+            ],
+        }
+    }
+}
+
+// Note: Camera2D and CameraUniform are now re-exported from common crate
+// This eliminates ~100 lines of duplicated code
+
+/// A texture with its view and sampler
+#[derive(Debug, Clone)]
+pub struct TextureResource {
+    pub texture: Arc<Texture>,
+    pub view: TextureView,
+    pub sampler: Sampler,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl TextureResource {
+    /// Create a new texture resource from existing texture
+    pub fn new(device: &Device, texture: Arc<Texture>) -> Self {
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+        let sampler = SamplerConfig::default().create_sampler(device, Some("Texture Sampler"));
+
+        let size = texture.size();
+        
+        Self {
+            texture,
+            view,
+            sampler,
+            width: size.width,
+            height: size.height,
+        }
+    }
+
+    /// Estimated GPU memory of the texture (all mip levels), in bytes.
+    pub fn memory_bytes(&self) -> u64 {
+        let format = self.texture.format();
+        let bytes_per_block = format.block_copy_size(None).unwrap_or(4);
+        crate::render_stats::block_texture_bytes(
+            self.width, self.height, self.texture.mip_level_count(), format.block_dimensions(), bytes_per_block,
+        )
+    }
+}
+
+/// Dynamic buffer for sprite data.
+///
+/// Grows on demand: when a write needs more elements than the current
+/// capacity, the GPU buffer is recreated at the next power of two and the
+/// capacity updated. It never shrinks. [`write_range`](Self::write_range)
+/// uploads only a changed slice and carries the rest over GPU-side on growth.
+pub struct DynamicBuffer<T> {
+    buffer: Buffer,
+    capacity: usize,
+    usage: wgpu::BufferUsages,
+    _phantom: std::marker::PhantomData<T>,
+}
+
+impl<T: bytemuck::Pod> DynamicBuffer<T> {
+    /// Create a new dynamic buffer with the given initial capacity (in elements)
+    pub fn new(device: &Device, capacity: usize, usage: wgpu::BufferUsages) -> Self {
+        Self {
+            buffer: Self::create_buffer(device, capacity, usage),
+            capacity,
+            usage,
+            _phantom: std::marker::PhantomData,
+        }
+    }
+
+    fn create_buffer(device: &Device, capacity: usize, usage: wgpu::BufferUsages) -> Buffer {
+        device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some(&format!("Dynamic Buffer<{}>", std::any::type_name::<T>())),
+            size: (capacity * std::mem::size_of::<T>()) as u64,
+            usage: usage | wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::COPY_SRC,
+            mapped_at_creation: false,
+        })
+    }
+
+    /// Update buffer data, growing the GPU buffer if `data` exceeds capacity
+    pub fn update(&mut self, device: &Device, queue: &Queue, data: &[T]) {
+        if data.len() > self.capacity {
+            let new_capacity = data.len().next_power_of_two();
+            log::debug!(
+                "Growing Dynamic Buffer<{}> from {} to {} elements",
+                std::any::type_name::<T>(),
+                self.capacity,
+                new_capacity
+            );
+            self.buffer = Self::create_buffer(device, new_capacity, self.usage);
+            self.capacity = new_capacity;
+        }
+
+        queue.write_buffer(&self.buffer, 0, bytemuck::cast_slice(data));
+    }
+
+    /// Write `data` at element `offset` of a buffer holding `len` live
+    /// elements, growing it if `len` exceeds capacity.
+    ///
+    /// On growth the live elements outside the written range are copied
+    /// from the old buffer on the GPU, so callers upload only what changed.
+    /// The copied regions never overlap the written one, which keeps the
+    /// result independent of when `queue.write_buffer` flushes.
+    pub fn write_range(&mut self, device: &Device, queue: &Queue, len: usize, offset: usize, data: &[T]) {
+        let end = offset + data.len();
+        let len = len.max(end);
+        if len > self.capacity {
+            let new_capacity = len.next_power_of_two();
+            log::debug!(
+                "Growing Dynamic Buffer<{}> from {} to {} elements (keeping contents)",
+                std::any::type_name::<T>(),
+                self.capacity,
+                new_capacity
+            );
+            let new_buffer = Self::create_buffer(device, new_capacity, self.usage);
+            let size = std::mem::size_of::<T>() as u64;
+            let old_live = len.min(self.capacity);
+            let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                label: Some("Dynamic Buffer Grow"),
+            });
+            for (from, to) in [(0, offset.min(old_live)), (end, old_live)] {
+                if to > from {
+                    let start = from as u64 * size;
+                    encoder.copy_buffer_to_buffer(&self.buffer, start, &new_buffer, start, (to - from) as u64 * size);
+                }
+            }
+            queue.submit(Some(encoder.finish()));
+            self.buffer = new_buffer;
+            self.capacity = new_capacity;
+        }
+
+        if !data.is_empty() {
+            let byte_offset = (offset * std::mem::size_of::<T>()) as u64;
+            queue.write_buffer(&self.buffer, byte_offset, bytemuck::cast_slice(data));
+        }
+    }
+
+    /// Get buffer slice
+    pub fn slice(&self) -> wgpu::BufferSlice<'_> {
+        self.buffer.slice(..)
+    }
+
+    /// Get buffer
+    pub fn buffer(&self) -> &Buffer {
+        &self.buffer
+    }
+
+    /// Get capacity
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,264 @@
+use super::*;
+use glam::{Vec2, Vec3, Vec4, Mat4};
+
+// ==================== SpriteVertex Tests ====================
+
+#[test]
+fn test_sprite_vertex_new() {
+    let vertex = SpriteVertex::new(
+        Vec3::new(1.0, 2.0, 3.0),
+        Vec2::new(0.5, 0.75),
+        Vec4::new(1.0, 0.0, 0.0, 1.0),
+    );
+
+    assert_eq!(vertex.position, [1.0, 2.0, 3.0]);
+    assert_eq!(vertex.tex_coords, [0.5, 0.75]);
+    assert_eq!(vertex.color, [1.0, 0.0, 0.0, 1.0]);
+}
+
+#[test]
+fn test_sprite_vertex_bytemuck_cast() {
+    let vertex = SpriteVertex::new(Vec3::ZERO, Vec2::ZERO, Vec4::ONE);
+    // Verify bytemuck traits work
+    let _bytes: &[u8] = bytemuck::bytes_of(&vertex);
+    assert_eq!(std::mem::size_of::<SpriteVertex>(), 36); // 3*4 + 2*4 + 4*4 = 36 bytes
+}
+
+#[test]
+fn test_sprite_vertex_desc_attributes() {
+    let desc = SpriteVertex::desc();
+    assert_eq!(desc.step_mode, wgpu::VertexStepMode::Vertex);
+    assert_eq!(desc.attributes.len(), 3); // position, tex_coords, color
+    assert_eq!(desc.array_stride, 36);
+}
+
+// ==================== SpriteInstance Tests ====================
+
+#[test]
+fn test_sprite_instance_new() {
+    let instance = SpriteInstance::new(
+        Vec2::new(100.0, 200.0),
+        1.5,
+        Vec2::new(2.0, 3.0),
+        [0.0, 0.0, 1.0, 1.0],
+        Vec4::new(1.0, 0.5, 0.0, 1.0),
+        5.0,
+    );
+
+    assert_eq!(instance.position, [100.0, 200.0]);
+    assert!((instance.rotation - 1.5).abs() < 0.0001);
+    assert_eq!(instance.scale, [2.0, 3.0]);
+    assert_eq!(instance.tex_region, [0.0, 0.0, 1.0, 1.0]);
+    assert_eq!(instance.color, [1.0, 0.5, 0.0, 1.0]);
+    assert_eq!(instance.depth, 5.0);
+    assert_eq!(instance.emissive, 0.0);
+}
+
+#[test]
+fn test_sprite_instance_with_emissive() {
+    let instance = SpriteInstance::with_emissive(
+        Vec2::new(10.0, 20.0),
+        0.0,
+        Vec2::ONE,
+        [0.0, 0.0, 1.0, 1.0],
+        Vec4::ONE,
+        0.0,
+        2.5,
+    );
+    assert_eq!(instance.emissive, 2.5);
+}
+
+#[test]
+fn test_sprite_instance_bytemuck_cast() {
+    let instance = SpriteInstance::new(
+        Vec2::ZERO,
+        0.0,
+        Vec2::ONE,
+        [0.0, 0.0, 1.0, 1.0],
+        Vec4::ONE,
+        0.0,
+    );
+    let _bytes: &[u8] = bytemuck::bytes_of(&instance);
+    // 2*4 + 1*4 + 2*4 + 4*4 + 4*4 + 1*4 + 1*4 + 4*4 + 3 * 4*4 = 124 bytes
+    assert_eq!(std::mem::size_of::<SpriteInstance>(), 124);
+}
+
+#[test]
+fn test_sprite_instance_desc_attributes() {
+    let desc = SpriteInstance::desc();
+    assert_eq!(desc.step_mode, wgpu::VertexStepMode::Instance);
+    assert_eq!(desc.attributes.len(), 11); // + emissive + shape + mask
+    assert_eq!(desc.array_stride, 124);
+}
+
+#[test]
+fn test_sprite_instance_default_shape_is_plain_quad() {
+    let instance = SpriteInstance::new(
+        Vec2::ZERO,
+        0.0,
+        Vec2::ONE,
+        [0.0, 0.0, 1.0, 1.0],
+        Vec4::ONE,
+        0.0,
+    );
+    assert_eq!(instance.shape, [0.0; 4], "zeroed shape == legacy textured quad");
+
+    let shaped = instance.with_shape([2.0, 8.0, 1.5, 0.0]);
+    assert_eq!(shaped.shape, [2.0, 8.0, 1.5, 0.0]);
+    assert_eq!(instance.mask, [0.0; 4], "zeroed mask == unmasked");
+}
+
+// ==================== Camera2D Tests ====================
+
+#[test]
+fn test_camera2d_default() {
+    let camera = Camera::default();
+    assert_eq!(camera.position, Vec2::ZERO);
+    assert_eq!(camera.rotation, 0.0);
+    assert_eq!(camera.zoom, 1.0);
+    assert_eq!(camera.viewport_size, Vec2::new(800.0, 600.0));
+    assert_eq!(camera.near, -1000.0);
+    assert_eq!(camera.far, 1000.0);
+}
+
+#[test]
+fn test_camera2d_new() {
+    let camera = Camera::new(Vec2::new(100.0, 200.0), Vec2::new(1920.0, 1080.0));
+    assert_eq!(camera.position, Vec2::new(100.0, 200.0));
+    assert_eq!(camera.viewport_size, Vec2::new(1920.0, 1080.0));
+    // Other fields should be default
+    assert_eq!(camera.rotation, 0.0);
+    assert_eq!(camera.zoom, 1.0);
+}
+
+#[test]
+fn test_camera2d_view_matrix_identity_at_origin() {
+    let camera = Camera::default();
+    let view = camera.view_matrix();
+    // At origin with no rotation and zoom 1.0, view should be identity
+    let identity = Mat4::IDENTITY;
+    for i in 0..4 {
+        for j in 0..4 {
+            assert!((view.col(i)[j] - identity.col(i)[j]).abs() < 0.0001,
+                "Mismatch at [{},{}]: {} vs {}", i, j, view.col(i)[j], identity.col(i)[j]);
+        }
+    }
+}
+
+#[test]
+fn test_camera2d_view_matrix_with_position() {
+    let camera = Camera {
+        position: Vec2::new(100.0, 50.0),
+        ..Camera::default()
+    };
+    let view = camera.view_matrix();
+
+    // Transform a point at (100, 50) - should become (0, 0) in view space
+    let point = Vec4::new(100.0, 50.0, 0.0, 1.0);
+    let transformed = view * point;
+    assert!((transformed.x).abs() < 0.0001);
+    assert!((transformed.y).abs() < 0.0001);
+}
+
+#[test]
+fn test_camera2d_view_matrix_with_zoom() {
+    let camera = Camera {
+        zoom: 2.0, // 2x zoom in
+        ..Camera::default()
+    };
+    let view = camera.view_matrix();
+
+    // A point at (10, 10) should appear at (20, 20) after zoom
+    let point = Vec4::new(10.0, 10.0, 0.0, 1.0);
+    let transformed = view * point;
+    assert!((transformed.x - 20.0).abs() < 0.0001);
+    assert!((transformed.y - 20.0).abs() < 0.0001);
+}
+
+#[test]
+fn test_camera2d_projection_matrix() {
+    let camera = Camera::default();
+    let proj = camera.projection_matrix();
+
+    // For an 800x600 viewport, half extents are 400x300
+    // A point at (400, 300) should map to (1, 1) in NDC (right-top)
+    let point = Vec4::new(400.0, 300.0, 0.0, 1.0);
+    let projected = proj * point;
+    assert!((projected.x - 1.0).abs() < 0.0001);
+    assert!((projected.y - 1.0).abs() < 0.0001);
+}
+
+#[test]
+fn test_camera2d_view_projection_combines_both() {
+    let camera = Camera::default();
+    let vp = camera.view_projection_matrix();
+    let expected = camera.projection_matrix() * camera.view_matrix();
+
+    for i in 0..4 {
+        for j in 0..4 {
+            assert!((vp.col(i)[j] - expected.col(i)[j]).abs() < 0.0001);
+        }
+    }
+}
+
+#[test]
+fn test_camera2d_screen_to_world_center() {
+    let camera = Camera::default(); // 800x600 viewport, position at origin
+    // Screen center (400, 300) should map to world origin (0, 0)
+    let world_pos = camera.screen_to_world(Vec2::new(400.0, 300.0));
+    assert!((world_pos.x).abs() < 1.0, "x: {}", world_pos.x);
+    assert!((world_pos.y).abs() < 1.0, "y: {}", world_pos.y);
+}
+
+#[test]
+fn test_camera2d_world_to_screen_origin() {
+    let camera = Camera::default(); // 800x600 viewport
+    // World origin (0, 0) should map to screen center (400, 300)
+    let screen_pos = camera.world_to_screen(Vec2::ZERO);
+    assert!((screen_pos.x - 400.0).abs() < 1.0, "x: {}", screen_pos.x);
+    assert!((screen_pos.y - 300.0).abs() < 1.0, "y: {}", screen_pos.y);
+}
+
+#[test]
+fn test_camera2d_world_to_screen_corners() {
+    let camera = Camera::default(); // 800x600 viewport
+
+    // World top-right corner (400, 300) should map to screen top-right (800, 0)
+    let top_right = camera.world_to_screen(Vec2::new(400.0, 300.0));
+    assert!((top_right.x - 800.0).abs() < 1.0, "top_right.x: {}", top_right.x);
+    assert!((top_right.y - 0.0).abs() < 1.0, "top_right.y: {}", top_right.y);
+
+    // World bottom-left corner (-400, -300) should map to screen bottom-left (0, 600)
+    let bottom_left = camera.world_to_screen(Vec2::new(-400.0, -300.0));
+    assert!((bottom_left.x - 0.0).abs() < 1.0, "bottom_left.x: {}", bottom_left.x);
+    assert!((bottom_left.y - 600.0).abs() < 1.0, "bottom_left.y: {}", bottom_left.y);
+}
+
+// ==================== CameraUniform Tests ====================
+
+#[test]
+fn test_camera_uniform_from_camera() {
+    let camera = Camera::new(Vec2::new(50.0, 100.0), Vec2::new(800.0, 600.0));
+    let uniform = CameraUniform::from_camera(&camera);
+
+    assert_eq!(uniform.position, [50.0, 100.0]);
+    assert_eq!(uniform.origin, [0.0, 0.0], "absolute uniforms don't offset vertices");
+
+    // Verify view_projection matches camera's
+    let expected_vp = camera.view_projection_matrix().to_cols_array_2d();
+    for (actual_col, expected_col) in uniform.view_projection.iter().zip(expected_vp.iter()) {
+        for (actual, expected) in actual_col.iter().zip(expected_col.iter()) {
+            assert!((actual - expected).abs() < 0.0001);
+        }
+    }
+}
+
+#[test]
+fn test_camera_uniform_bytemuck() {
+    let camera = Camera::default();
+    let uniform = CameraUniform::from_camera(&camera);
+    // Verify bytemuck traits work
+    let _bytes: &[u8] = bytemuck::bytes_of(&uniform);
+    // 16 floats for matrix (64) + 2 floats position (8) + 2 floats origin (8) = 80 bytes
+    assert_eq!(std::mem::size_of::<CameraUniform>(), 80);
+}
@@ -0,0 +1,259 @@
+//! Sprite-sheet slicing and named-region atlases.
+//!
+//! A sheet is cut into pixel rectangles either by a fixed cell size
+//! ([`slice_grid`]) or by finding the bounding boxes of opaque islands
+//! ([`detect_islands`]). The rectangles become a [`SpriteAtlas`]: named
+//! regions of one texture, saved next to it (see [`atlas_path`]) so sprites
+//! and animations can be set up by region name rather than raw UVs.
+
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::texture::TextureError;
+
+/// A rectangle of texels, origin top-left.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PixelRect {
+    pub x: u32,
+    pub y: u32,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl PixelRect {
+    /// Create a rect from its top-left corner and size.
+    pub fn new(x: u32, y: u32, width: u32, height: u32) -> Self {
+        Self { x, y, width, height }
+    }
+
+    /// Normalized `[x, y, w, h]` within a `texture_width` x `texture_height`
+    /// texture (the `Sprite::tex_region` layout). Zero-size textures give
+    /// the full region.
+    pub fn to_uv(&self, texture_width: u32, texture_height: u32) -> [f32; 4] {
+        if texture_width == 0 || texture_height == 0 {
+            return [0.0, 0.0, 1.0, 1.0];
+        }
+        let (w, h) = (texture_width as f32, texture_height as f32);
+        [self.x as f32 / w, self.y as f32 / h, self.width as f32 / w, self.height as f32 / h]
+    }
+}
+
+/// Cut a `width` x `height` sheet into `cell_width` x `cell_height` cells,
+/// row by row. Only whole cells are returned; a zero cell size gives none.
+pub fn slice_grid(width: u32, height: u32, cell_width: u32, cell_height: u32) -> Vec<PixelRect> {
+    if cell_width == 0 || cell_height == 0 {
+        return Vec::new();
+    }
+    let (columns, rows) = (width / cell_width, height / cell_height);
+    (0..rows)
+        .flat_map(|row| {
+            (0..columns).map(move |column| PixelRect::new(column * cell_width, row * cell_height, cell_width, cell_height))
+        })
+        .collect()
+}
+
+/// Bounding boxes of the 8-connected islands of pixels whose alpha is above
+/// `alpha_threshold`, in reading order (top to bottom, then left to right).
+/// `rgba` holds `width * height` RGBA8 pixels; a shorter buffer gives none.
+pub fn detect_islands(rgba: &[u8], width: u32, height: u32, alpha_threshold: u8) -> Vec<PixelRect> {
+    let (w, h) = (width as usize, height as usize);
+    if rgba.len() < w * h * 4 {
+        return Vec::new();
+    }
+    let solid = |index: usize| rgba[index * 4 + 3] > alpha_threshold;
+    let mut visited = vec![false; w * h];
+    let mut stack = Vec::new();
+    let mut islands = Vec::new();
+
+    for start in 0..w * h {
+        if visited[start] || !solid(start) {
+            continue;
+        }
+        visited[start] = true;
+        stack.push(start);
+        let (mut min_x, mut min_y, mut max_x, mut max_y) = (w, h, 0, 0);
+        while let Some(index) = stack.pop() {
+            let (x, y) = (index % w, index / w);
+            (min_x, min_y, max_x, max_y) = (min_x.min(x), min_y.min(y), max_x.max(x), max_y.max(y));
+            for ny in y.saturating_sub(1)..=(y + 1).min(h - 1) {
+                for nx in x.saturating_sub(1)..=(x + 1).min(w - 1) {
+                    let neighbor = ny * w + nx;
+                    if !visited[neighbor] && solid(neighbor) {
+                        visited[neighbor] = true;
+                        stack.push(neighbor);
+                    }
+                }
+            }
+        }
+        islands.push(PixelRect::new(
+            min_x as u32,
+            min_y as u32,
+            (max_x - min_x + 1) as u32,
+            (max_y - min_y + 1) as u32,
+        ));
+    }
+
+    // Islands are found by their topmost pixel; order rows of sprites by
+    // their top edge, then left to right
+    islands.sort_by_key(|rect| (rect.y, rect.x));
+    islands
+}
+
+/// Decode an image file to RGBA8 pixels, returning `(pixels, width, height)`.
+pub fn load_rgba(path: impl AsRef<Path>) -> Result<(Vec<u8>, u32, u32), TextureError> {
+    let path = path.as_ref();
+    let image = image::open(path)
+        .map_err(|e| TextureError::ImageLoadError(format!("Failed to load {:?}: {}", path, e)))?
+        .to_rgba8();
+    let (width, height) = image.dimensions();
+    Ok((image.into_raw(), width, height))
+}
+
+/// Atlas file for a texture: `hero.png` → `hero.atlas.ron`.
+pub fn atlas_path(texture_path: impl AsRef<Path>) -> PathBuf {
+    texture_path.as_ref().with_extension("atlas.ron")
+}
+
+/// One named region of a [`SpriteAtlas`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AtlasEntry {
+    pub name: String,
+    pub rect: PixelRect,
+}
+
+/// Named pixel regions of one texture.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SpriteAtlas {
+    /// Texture path as passed to `load_texture`
+    pub texture: String,
+    /// Texture size in pixels when sliced
+    pub width: u32,
+    pub height: u32,
+    /// Regions in slicing order
+    pub regions: Vec<AtlasEntry>,
+}
+
+impl SpriteAtlas {
+    /// An empty atlas for a `width` x `height` texture.
+    pub fn new(texture: impl Into<String>, width: u32, height: u32) -> Self {
+        Self { texture: texture.into(), width, height, regions: Vec::new() }
+    }
+
+    /// An atlas naming `rects` `{prefix}_0`, `{prefix}_1`, ... in order.
+    pub fn from_rects(
+        texture: impl Into<String>,
+        width: u32,
+        height: u32,
+        prefix: &str,
+        rects: impl IntoIterator<Item = PixelRect>,
+    ) -> Self {
+        let mut atlas = Self::new(texture, width, height);
+        for (index, rect) in rects.into_iter().enumerate() {
+            atlas.insert(format!("{prefix}_{index}"), rect);
+        }
+        atlas
+    }
+
+    /// Add a region, replacing any region with the same name.
+    pub fn insert(&mut self, name: impl Into<String>, rect: PixelRect) {
+        let name = name.into();
+        match self.regions.iter_mut().find(|entry| entry.name == name) {
+            Some(entry) => entry.rect = rect,
+            None => self.regions.push(AtlasEntry { name, rect }),
+        }
+    }
+
+    /// A region's pixel rect.
+    pub fn rect(&self, name: &str) -> Option<PixelRect> {
+        self.regions.iter().find(|entry| entry.name == name).map(|entry| entry.rect)
+    }
+
+    /// A region as a normalized `[x, y, w, h]` texture region.
+    pub fn uv(&self, name: &str) -> Option<[f32; 4]> {
+        self.rect(name).map(|rect| rect.to_uv(self.width, self.height))
+    }
+
+    /// Every region as `(name, normalized region)`, in order.
+    pub fn uv_regions(&self) -> Vec<(String, [f32; 4])> {
+        self.regions
+            .iter()
+            .map(|entry| (entry.name.clone(), entry.rect.to_uv(self.width, self.height)))
+            .collect()
+    }
+
+    /// Name of the region covering exactly `uv`, if any (within half a texel).
+    pub fn name_of(&self, uv: [f32; 4]) -> Option<&str> {
+        let tolerance = 0.5 / self.width.max(self.height).max(1) as f32;
+        self.regions
+            .iter()
+            .find(|entry| {
+                let region = entry.rect.to_uv(self.width, self.height);
+                region.iter().zip(uv).all(|(a, b)| (a - b).abs() <= tolerance)
+            })
+            .map(|entry| entry.name.as_str())
+    }
+
+    /// Number of regions.
+    pub fn len(&self) -> usize {
+        self.regions.len()
+    }
+
+    /// Whether the atlas has no regions.
+    pub fn is_empty(&self) -> bool {
+        self.regions.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `width` x `height` transparent image with the given opaque pixels.
+    fn image(width: u32, height: u32, opaque: &[(u32, u32)]) -> Vec<u8> {
+        let mut rgba = vec![0; (width * height * 4) as usize];
+        for &(x, y) in opaque {
+            rgba[((y * width + x) * 4 + 3) as usize] = 255;
+        }
+        rgba
+    }
+
+    #[test]
+    fn test_grid_slicing_keeps_whole_cells_in_row_order() {
+        let cells = slice_grid(100, 64, 32, 32);
+        assert_eq!(cells.len(), 6, "the 4 px leftover column is dropped");
+        assert_eq!(cells[1], PixelRect::new(32, 0, 32, 32));
+        assert_eq!(cells[3], PixelRect::new(0, 32, 32, 32));
+        assert!(slice_grid(100, 64, 0, 32).is_empty());
+        assert_eq!(cells[3].to_uv(128, 64), [0.0, 0.5, 0.25, 0.5]);
+    }
+
+    #[test]
+    fn test_islands_merge_diagonals_and_sort_in_reading_order() {
+        // Right island's top pixel is one row lower than the left one's
+        let rgba = image(8, 4, &[(5, 1), (6, 2), (0, 0), (1, 0), (1, 1), (3, 3)]);
+        let islands = detect_islands(&rgba, 8, 4, 0);
+        assert_eq!(
+            islands,
+            [PixelRect::new(0, 0, 2, 2), PixelRect::new(5, 1, 2, 2), PixelRect::new(3, 3, 1, 1)]
+        );
+
+        let mut faint = image(2, 1, &[(0, 0)]);
+        faint[7] = 40;
+        assert_eq!(detect_islands(&faint, 2, 1, 64).len(), 1, "the faint pixel is below the threshold");
+        assert!(detect_islands(&faint, 4, 4, 0).is_empty(), "short buffer");
+    }
+
+    #[test]
+    fn test_atlas_names_regions_and_matches_them_back() {
+        let mut atlas = SpriteAtlas::from_rects("hero.png", 64, 32, "hero", slice_grid(64, 32, 32, 32));
+        assert_eq!(atlas.len(), 2);
+        assert_eq!(atlas.uv("hero_1"), Some([0.5, 0.0, 0.5, 1.0]));
+        assert_eq!(atlas.name_of([0.5, 0.0, 0.5, 1.0]), Some("hero_1"));
+        assert_eq!(atlas.name_of([0.5, 0.0, 0.25, 1.0]), None);
+
+        atlas.insert("hero_1", PixelRect::new(32, 0, 16, 16));
+        assert_eq!(atlas.len(), 2, "same name replaces");
+        assert_eq!(atlas_path("sprites/hero.png"), PathBuf::from("sprites/hero.atlas.ron"));
+    }
+}
@@ -0,0 +1,464 @@
+//! CPU block compression for RGBA8 textures.
+//!
+//! Compressed textures take a quarter of the GPU memory of RGBA8 (one byte
+//! per texel) and stay compressed while sampled, so large backgrounds and
+//! atlases cost less memory and bandwidth. Two formats cover the hardware:
+//! BC3 (DXT5) on desktop GPUs and ETC2 RGBA8 on mobile/GLES ones. Encoding
+//! happens once at load; the encoders favor speed over the last bit of
+//! quality, so keep pixel art and UI uncompressed.
+//!
+//! Both formats store 4×4 texel blocks of 16 bytes. Textures whose size
+//! isn't a multiple of 4 can't use them and load uncompressed; smaller mip
+//! levels are padded by repeating their edge texels.
+
+use wgpu::{Features, TextureFormat};
+
+/// Block compression requested for a texture (see the module docs).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TextureCompression {
+    /// Upload RGBA8 as-is.
+    #[default]
+    None,
+    /// BC3 (DXT5), needs `Features::TEXTURE_COMPRESSION_BC`.
+    Bc,
+    /// ETC2 RGBA8, needs `Features::TEXTURE_COMPRESSION_ETC2`.
+    Etc2,
+    /// BC where supported, else ETC2, else uncompressed.
+    Auto,
+}
+
+impl TextureCompression {
+    /// The block format to upload a `width`×`height` texture as on a device
+    /// with `features`, or `None` to stay uncompressed (not requested,
+    /// unsupported, or a size that isn't a multiple of 4). `srgb` picks the
+    /// sRGB variant.
+    pub fn block_format(self, features: Features, width: u32, height: u32, srgb: bool) -> Option<TextureFormat> {
+        let bc = features.contains(Features::TEXTURE_COMPRESSION_BC);
+        let etc2 = features.contains(Features::TEXTURE_COMPRESSION_ETC2);
+        let format = match self {
+            Self::None => return None,
+            Self::Bc if bc => TextureFormat::Bc3RgbaUnormSrgb,
+            Self::Etc2 if etc2 => TextureFormat::Etc2Rgba8UnormSrgb,
+            Self::Auto if bc => TextureFormat::Bc3RgbaUnormSrgb,
+            Self::Auto if etc2 => TextureFormat::Etc2Rgba8UnormSrgb,
+            _ => {
+                log::debug!("{self:?} texture compression unsupported by the device; loading uncompressed");
+                return None;
+            }
+        };
+        if !width.is_multiple_of(4) || !height.is_multiple_of(4) {
+            log::debug!("{width}x{height} texture isn't a multiple of 4; loading uncompressed");
+            return None;
+        }
+        Some(if srgb { format } else { format.remove_srgb_suffix() })
+    }
+}
+
+/// Compressed features worth requesting from an adapter: whichever of BC
+/// and ETC2 it supports.
+pub fn supported_features(adapter_features: Features) -> Features {
+    adapter_features & (Features::TEXTURE_COMPRESSION_BC | Features::TEXTURE_COMPRESSION_ETC2)
+}
+
+/// Compress one RGBA8 level to `format` (BC3 or ETC2 RGBA8, either color
+/// space), returning the blocks in row order. `None` for other formats.
+pub fn compress(format: TextureFormat, width: u32, height: u32, data: &[u8]) -> Option<Vec<u8>> {
+    let encode: fn(&[[u8; 4]; 16]) -> [u8; 16] = match format.remove_srgb_suffix() {
+        TextureFormat::Bc3RgbaUnorm => encode_bc3_block,
+        TextureFormat::Etc2Rgba8Unorm => encode_etc2_block,
+        _ => return None,
+    };
+    let (blocks_wide, blocks_high) = (width.div_ceil(4), height.div_ceil(4));
+    let mut out = Vec::with_capacity(blocks_wide as usize * blocks_high as usize * 16);
+    for block_y in 0..blocks_high {
+        for block_x in 0..blocks_wide {
+            let texels = std::array::from_fn(|i| {
+                // Row-major within the block; edge texels repeat past the border
+                let x = (block_x * 4 + i as u32 % 4).min(width - 1) as usize;
+                let y = (block_y * 4 + i as u32 / 4).min(height - 1) as usize;
+                let start = (y * width as usize + x) * 4;
+                [data[start], data[start + 1], data[start + 2], data[start + 3]]
+            });
+            out.extend_from_slice(&encode(&texels));
+        }
+    }
+    Some(out)
+}
+
+// ==================== BC3 ====================
+
+/// One BC3 block: BC4-style alpha, then a four-color BC1 color block.
+fn encode_bc3_block(texels: &[[u8; 4]; 16]) -> [u8; 16] {
+    let mut block = [0u8; 16];
+    block[..8].copy_from_slice(&encode_bc3_alpha(texels));
+    block[8..].copy_from_slice(&encode_bc1_color(texels));
+    block
+}
+
+/// Eight interpolated alphas between the block's extremes, 3-bit indices.
+fn encode_bc3_alpha(texels: &[[u8; 4]; 16]) -> [u8; 8] {
+    let (min, max) = texels.iter().fold((255u8, 0u8), |(lo, hi), t| (lo.min(t[3]), hi.max(t[3])));
+    let mut block = [max, min, 0, 0, 0, 0, 0, 0];
+    if max == min {
+        return block;
+    }
+    // max > min selects the 8-alpha mode: index 0 = max, 1 = min, 2..=7 blend
+    let palette: [i32; 8] = std::array::from_fn(|i| match i {
+        0 => i32::from(max),
+        1 => i32::from(min),
+        _ => ((8 - i as i32) * i32::from(max) + (i as i32 - 1) * i32::from(min)) / 7,
+    });
+    let mut bits = 0u64;
+    for (i, texel) in texels.iter().enumerate() {
+        let index = nearest(&palette, |value| i64::from((value - i32::from(texel[3])).pow(2)));
+        bits |= (index as u64) << (3 * i);
+    }
+    block[2..].copy_from_slice(&bits.to_le_bytes()[..6]);
+    block
+}
+
+/// Bounding-box endpoints in RGB565, 2-bit indices into the four-color
+/// palette.
+fn encode_bc1_color(texels: &[[u8; 4]; 16]) -> [u8; 8] {
+    let mut lo = [255u8; 3];
+    let mut hi = [0u8; 3];
+    for texel in texels {
+        for channel in 0..3 {
+            lo[channel] = lo[channel].min(texel[channel]);
+            hi[channel] = hi[channel].max(texel[channel]);
+        }
+    }
+    // Flip channels that fall while the widest one rises, so the endpoints
+    // span the box diagonal the texels actually lie along
+    let widest = (0..3).max_by_key(|&c| hi[c] - lo[c]).unwrap_or(0);
+    let mean: [i32; 3] = std::array::from_fn(|c| texels.iter().map(|t| i32::from(t[c])).sum::<i32>() / 16);
+    for channel in 0..3 {
+        let covariance: i32 = texels
+            .iter()
+            .map(|t| (i32::from(t[channel]) - mean[channel]) * (i32::from(t[widest]) - mean[widest]))
+            .sum();
+        if covariance < 0 {
+            std::mem::swap(&mut lo[channel], &mut hi[channel]);
+        }
+    }
+    let (mut c0, mut c1) = (to_565(hi), to_565(lo));
+    // `c0 > c1` selects the four-color palette
+    if c0 < c1 {
+        std::mem::swap(&mut c0, &mut c1);
+    }
+    let (e0, e1) = (from_565(c0), from_565(c1));
+    let blend = |a: i32, b: i32| (2 * a + b) / 3;
+    let palette: [[i32; 3]; 4] = [
+        e0,
+        e1,
+        std::array::from_fn(|c| blend(e0[c], e1[c])),
+        std::array::from_fn(|c| blend(e1[c], e0[c])),
+    ];
+    let mut bits = 0u32;
+    for (i, texel) in texels.iter().enumerate() {
+        let index = nearest(&palette, |color| color_distance(color, *texel));
+        bits |= (index as u32) << (2 * i);
+    }
+    let mut block = [0u8; 8];
+    block[..2].copy_from_slice(&c0.to_le_bytes());
+    block[2..4].copy_from_slice(&c1.to_le_bytes());
+    block[4..].copy_from_slice(&bits.to_le_bytes());
+    block
+}
+
+fn to_565(color: [u8; 3]) -> u16 {
+    let [r, g, b] = color.map(u16::from);
+    (((r * 31 + 127) / 255) << 11) | (((g * 63 + 127) / 255) << 5) | ((b * 31 + 127) / 255)
+}
+
+fn from_565(color: u16) -> [i32; 3] {
+    let (r, g, b) = (i32::from(color >> 11), i32::from((color >> 5) & 63), i32::from(color & 31));
+    [(r << 3) | (r >> 2), (g << 2) | (g >> 4), (b << 3) | (b >> 2)]
+}
+
+// ==================== ETC2 RGBA8 ====================
+
+/// ETC1 intensity modifiers: index = pixel index bits (msb, lsb).
+const ETC_MODIFIERS: [[i32; 4]; 8] = [
+    [2, 8, -2, -8],
+    [5, 17, -5, -17],
+    [9, 29, -9, -29],
+    [13, 42, -13, -42],
+    [18, 60, -18, -60],
+    [24, 80, -24, -80],
+    [33, 106, -33, -106],
+    [47, 183, -47, -183],
+];
+
+/// EAC alpha modifiers, multiplied by the block's multiplier.
+const EAC_MODIFIERS: [[i32; 8]; 16] = [
+    [-3, -6, -9, -15, 2, 5, 8, 14],
+    [-3, -7, -10, -13, 2, 6, 9, 12],
+    [-2, -5, -8, -13, 1, 4, 7, 12],
+    [-2, -4, -6, -13, 1, 3, 5, 12],
+    [-3, -6, -8, -12, 2, 5, 7, 11],
+    [-3, -7, -9, -11, 2, 6, 8, 10],
+    [-4, -7, -8, -11, 3, 6, 7, 10],
+    [-3, -5, -8, -11, 2, 4, 7, 10],
+    [-2, -6, -8, -10, 1, 5, 7, 9],
+    [-2, -5, -8, -10, 1, 4, 7, 9],
+    [-2, -4, -8, -10, 1, 3, 7, 9],
+    [-2, -5, -7, -10, 1, 4, 6, 9],
+    [-3, -4, -7, -10, 2, 3, 6, 9],
+    [-1, -2, -3, -10, 0, 1, 2, 9],
+    [-4, -6, -8, -9, 3, 5, 7, 8],
+    [-3, -5, -7, -9, 2, 4, 6, 8],
+];
+
+/// One ETC2 RGBA8 block: EAC alpha, then an ETC1-compatible color block.
+fn encode_etc2_block(texels: &[[u8; 4]; 16]) -> [u8; 16] {
+    let mut block = [0u8; 16];
+    block[..8].copy_from_slice(&encode_eac_alpha(texels).to_be_bytes());
+    block[8..].copy_from_slice(&encode_etc1_color(texels).to_be_bytes());
+    block
+}
+
+/// ETC pixel order is column-major: pixel `x * 4 + y`.
+fn etc_pixel(texels: &[[u8; 4]; 16], pixel: usize) -> [u8; 4] {
+    texels[(pixel % 4) * 4 + pixel / 4]
+}
+
+/// Best table and multiplier around the block's alpha range.
+fn encode_eac_alpha(texels: &[[u8; 4]; 16]) -> u64 {
+    let alphas: [i32; 16] = std::array::from_fn(|pixel| i32::from(etc_pixel(texels, pixel)[3]));
+    let (min, max) = (alphas.iter().copied().min().unwrap_or(0), alphas.iter().copied().max().unwrap_or(0));
+    let mut best = (i64::MAX, 0u64);
+    for (table, modifiers) in EAC_MODIFIERS.iter().enumerate() {
+        let span = modifiers[7] - modifiers[3];
+        let guess = ((max - min) as f32 / span as f32).round() as i32;
+        for multiplier in (guess - 1).max(1)..=(guess + 1).min(15) {
+            // Center the table's reach on the block's range
+            let base = ((max + min) - (modifiers[7] + modifiers[3]) * multiplier) / 2;
+            let base = base.clamp(0, 255);
+            let values: [i32; 8] = modifiers.map(|m| (base + m * multiplier).clamp(0, 255));
+            let mut error = 0i64;
+            let mut bits = 0u64;
+            for (pixel, alpha) in alphas.iter().enumerate() {
+                let index = nearest(&values, |value| i64::from((value - alpha).pow(2)));
+                error += i64::from((values[index] - alpha).pow(2));
+                bits |= (index as u64) << (45 - 3 * pixel);
+            }
+            if error < best.0 {
+                let header = (base as u64) << 56 | (multiplier as u64) << 52 | (table as u64) << 48;
+                best = (error, header | bits);
+            }
+        }
+    }
+    best.1
+}
+
+/// Average color per half block (both split directions tried), each shifted
+/// by the best modifier table.
+fn encode_etc1_color(texels: &[[u8; 4]; 16]) -> u64 {
+    let mut best = (i64::MAX, 0u64);
+    for flip in [false, true] {
+        // Pixels of each half: flip = top/bottom, otherwise left/right
+        let halves: [Vec<usize>; 2] = std::array::from_fn(|half| {
+            (0..16)
+                .filter(|&pixel| {
+                    let (x, y) = (pixel / 4, pixel % 4);
+                    (if flip { y } else { x }) / 2 == half
+                })
+                .collect()
+        });
+        let averages = halves.clone().map(|pixels| {
+            let mut sum = [0u32; 3];
+            for &pixel in &pixels {
+                let texel = etc_pixel(texels, pixel);
+                for c in 0..3 {
+                    sum[c] += u32::from(texel[c]);
+                }
+            }
+            sum.map(|s| s as f32 / pixels.len() as f32)
+        });
+
+        // Differential mode when the 5-bit bases are within reach of each
+        // other, else individual 4-bit bases
+        let five = averages.map(|avg| avg.map(|c| (c * 31.0 / 255.0).round() as i32));
+        let deltas: [i32; 3] = std::array::from_fn(|c| five[1][c] - five[0][c]);
+        let differential = deltas.iter().all(|d| (-4..=3).contains(d));
+        let (bases, mut word) = if differential {
+            let expand = |c: i32| (c << 3) | (c >> 2);
+            let mut word = 1u64 << 33;
+            for (c, (base, delta)) in five[0].iter().zip(deltas).enumerate() {
+                word |= (*base as u64) << (59 - 8 * c) | ((delta & 7) as u64) << (56 - 8 * c);
+            }
+            (five.map(|color| color.map(expand)), word)
+        } else {
+            let four = averages.map(|avg| avg.map(|c| (c * 15.0 / 255.0).round() as i32));
+            let mut word = 0u64;
+            for (c, (first, second)) in four[0].iter().zip(four[1]).enumerate() {
+                word |= (*first as u64) << (60 - 8 * c) | (second as u64) << (56 - 8 * c);
+            }
+            (four.map(|color| color.map(|c| (c << 4) | c)), word)
+        };
+        if flip {
+            word |= 1 << 32;
+        }
+
+        let mut error = 0i64;
+        for (half, pixels) in halves.iter().enumerate() {
+            let (table_error, table, indices) = (0..8)
+                .map(|table| {
+                    let palette = ETC_MODIFIERS[table].map(|m| bases[half].map(|c| (c + m).clamp(0, 255)));
+                    let mut table_error = 0i64;
+                    let mut indices = Vec::with_capacity(pixels.len());
+                    for &pixel in pixels {
+                        let texel = etc_pixel(texels, pixel);
+                        let index = nearest(&palette, |color| color_distance(color, texel));
+                        table_error += color_distance(palette[index], texel);
+                        indices.push((pixel, index));
+                    }
+                    (table_error, table, indices)
+                })
+                .min_by_key(|(table_error, ..)| *table_error)
+                .unwrap_or_default();
+            error += table_error;
+            word |= (table as u64) << (37 - 3 * half);
+            for (pixel, index) in indices {
+                word |= ((index >> 1) as u64) << (16 + pixel) | ((index & 1) as u64) << pixel;
+            }
+        }
+        if error < best.0 {
+            best = (error, word);
+        }
+    }
+    best.1
+}
+
+// ==================== Shared ====================
+
+/// Index of the palette entry with the smallest `distance`.
+fn nearest<T: Copy>(palette: &[T], distance: impl Fn(T) -> i64) -> usize {
+    (0..palette.len()).min_by_key(|&i| distance(palette[i])).unwrap_or(0)
+}
+
+/// Squared RGB distance between a palette color and a texel.
+fn color_distance(color: [i32; 3], texel: [u8; 4]) -> i64 {
+    (0..3).map(|c| i64::from((color[c] - i32::from(texel[c])).pow(2))).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Decode a BC3 block back to row-major RGBA texels.
+    fn decode_bc3(block: &[u8]) -> [[u8; 4]; 16] {
+        let (a0, a1) = (i32::from(block[0]), i32::from(block[1]));
+        let alphas: [i32; 8] = std::array::from_fn(|i| match i {
+            0 => a0,
+            1 => a1,
+            _ if a0 > a1 => ((8 - i as i32) * a0 + (i as i32 - 1) * a1) / 7,
+            6 => 0,
+            7 => 255,
+            _ => ((6 - i as i32) * a0 + (i as i32 - 1) * a1) / 5,
+        });
+        let mut alpha_bytes = [0u8; 8];
+        alpha_bytes[..6].copy_from_slice(&block[2..8]);
+        let alpha_bits = u64::from_le_bytes(alpha_bytes);
+        let (e0, e1) = (
+            from_565(u16::from_le_bytes([block[8], block[9]])),
+            from_565(u16::from_le_bytes([block[10], block[11]])),
+        );
+        let palette = [e0, e1, std::array::from_fn(|c| (2 * e0[c] + e1[c]) / 3), std::array::from_fn(|c| (e0[c] + 2 * e1[c]) / 3)];
+        let color_bits = u32::from_le_bytes([block[12], block[13], block[14], block[15]]);
+        std::array::from_fn(|i| {
+            let color = palette[(color_bits >> (2 * i) & 3) as usize];
+            let alpha = alphas[(alpha_bits >> (3 * i) & 7) as usize];
+            [color[0] as u8, color[1] as u8, color[2] as u8, alpha as u8]
+        })
+    }
+
+    /// Decode an ETC2 RGBA8 block (individual/differential modes only)
+    /// back to row-major RGBA texels.
+    fn decode_etc2(block: &[u8]) -> [[u8; 4]; 16] {
+        let alpha = u64::from_be_bytes(block[..8].try_into().unwrap());
+        let color = u64::from_be_bytes(block[8..].try_into().unwrap());
+        let (base, multiplier, table) = ((alpha >> 56) as i32, (alpha >> 52 & 15) as i32, (alpha >> 48 & 15) as usize);
+        let differential = color >> 33 & 1 == 1;
+        let flip = color >> 32 & 1 == 1;
+        let channel = |shift: u32, bits: u32| (color >> shift) as i32 & ((1 << bits) - 1);
+        let bases: [[i32; 3]; 2] = if differential {
+            let first: [i32; 3] = std::array::from_fn(|c| channel(59 - 8 * c as u32, 5));
+            let second: [i32; 3] = std::array::from_fn(|c| first[c] + (channel(56 - 8 * c as u32, 3) << 29 >> 29));
+            [first, second].map(|color| color.map(|c| (c << 3) | (c >> 2)))
+        } else {
+            [60, 56].map(|shift| std::array::from_fn(|c| channel(shift - 8 * c as u32, 4) * 17))
+        };
+        let mut texels = [[0u8; 4]; 16];
+        for pixel in 0..16 {
+            let (x, y) = (pixel / 4, pixel % 4);
+            let half = if flip { y / 2 } else { x / 2 };
+            let codeword = channel(37 - 3 * half as u32, 3) as usize;
+            let index = ((color >> (16 + pixel) & 1) << 1 | (color >> pixel & 1)) as usize;
+            let modifier = ETC_MODIFIERS[codeword][index];
+            let rgb = bases[half].map(|c| (c + modifier).clamp(0, 255) as u8);
+            let alpha_index = (alpha >> (45 - 3 * pixel) & 7) as usize;
+            let a = (base + EAC_MODIFIERS[table][alpha_index] * multiplier).clamp(0, 255) as u8;
+            texels[y * 4 + x] = [rgb[0], rgb[1], rgb[2], a];
+        }
+        texels
+    }
+
+    /// Largest per-channel difference between two texel sets.
+    fn max_error(a: &[[u8; 4]; 16], b: &[[u8; 4]; 16]) -> u8 {
+        a.iter().zip(b).flat_map(|(x, y)| (0..4).map(move |c| x[c].abs_diff(y[c]))).max().unwrap_or(0)
+    }
+
+    /// A soft color ramp with a fading alpha, like a shaded sprite edge.
+    fn gradient() -> [[u8; 4]; 16] {
+        std::array::from_fn(|i| {
+            let (t, y) = (i as u8, (i / 4) as u8);
+            [100 + t * 3, 60 + t * 2, 180 - t * 2, 255 - y * 40]
+        })
+    }
+
+    #[test]
+    fn test_block_format_needs_support_and_whole_blocks() {
+        let bc = Features::TEXTURE_COMPRESSION_BC;
+        let etc2 = Features::TEXTURE_COMPRESSION_ETC2;
+        assert_eq!(TextureCompression::None.block_format(bc, 64, 64, true), None);
+        assert_eq!(TextureCompression::Bc.block_format(bc, 64, 64, true), Some(TextureFormat::Bc3RgbaUnormSrgb));
+        assert_eq!(TextureCompression::Bc.block_format(etc2, 64, 64, true), None, "unsupported");
+        assert_eq!(TextureCompression::Auto.block_format(etc2, 64, 64, false), Some(TextureFormat::Etc2Rgba8Unorm));
+        assert_eq!(TextureCompression::Auto.block_format(bc | etc2, 64, 32, true), Some(TextureFormat::Bc3RgbaUnormSrgb));
+        assert_eq!(TextureCompression::Auto.block_format(bc, 30, 32, true), None, "not a multiple of 4");
+        assert_eq!(supported_features(Features::all()), bc | etc2);
+    }
+
+    #[test]
+    fn test_bc3_round_trips_within_tolerance() {
+        let texels = gradient();
+        let solid = [[40, 200, 90, 255]; 16];
+        for source in [texels, solid] {
+            let block = encode_bc3_block(&source);
+            assert!(max_error(&decode_bc3(&block), &source) <= 12, "{:?}", decode_bc3(&block));
+        }
+    }
+
+    #[test]
+    fn test_etc2_round_trips_within_tolerance() {
+        let texels = gradient();
+        let solid = [[40, 200, 90, 255]; 16];
+        for source in [texels, solid] {
+            let block = encode_etc2_block(&source);
+            assert!(max_error(&decode_etc2(&block), &source) <= 16, "{:?}", decode_etc2(&block));
+        }
+    }
+
+    #[test]
+    fn test_compress_pads_partial_blocks() {
+        // A 2×2 mip level still fills one whole block
+        let level = [255u8; 2 * 2 * 4];
+        let bc3 = compress(TextureFormat::Bc3RgbaUnormSrgb, 2, 2, &level).unwrap();
+        assert_eq!(bc3.len(), 16);
+        assert_eq!(decode_bc3(&bc3), [[255; 4]; 16]);
+        assert_eq!(compress(TextureFormat::Etc2Rgba8Unorm, 8, 4, &[0; 8 * 4 * 4]).map(|b| b.len()), Some(32));
+        assert_eq!(compress(TextureFormat::Rgba8Unorm, 4, 4, &[0; 64]), None);
+    }
+}
@@ -10,7 +10,12 @@ use wgpu::{Device, Queue, Sampler, TextureFormat, Extent3d};
 use thiserror::Error;
 use image::GenericImageView;
 
+use crate::mipmaps;
 use crate::sprite_data::TextureResource;
+pub mod compression;
+mod sampling;
+
+pub use sampling::{SamplerConfig, TextureLoadConfig};
 
 /// Texture loading errors
 #[derive(Debug, Error)]
@@ -47,77 +52,6 @@ impl TextureHandle {
 }
 
 
-/// Texture loading configuration
-///
-/// Note: mipmap generation is intentionally not offered. An earlier
-/// `generate_mipmaps` flag allocated a mip chain but never filled it, so
-/// minified sprites sampled uninitialized levels. Add it back only together
-/// with actual mip generation.
-#[derive(Debug, Clone)]
-#[derive(Default)]
-pub struct TextureLoadConfig {
-    /// Texture format (None to auto-detect)
-    pub format: Option<TextureFormat>,
-    /// Sampler configuration
-    pub sampler_config: SamplerConfig,
-}
-
-
-/// Sampler configuration
-#[derive(Debug, Clone)]
-pub struct SamplerConfig {
-    pub address_mode_u: wgpu::AddressMode,
-    pub address_mode_v: wgpu::AddressMode,
-    pub address_mode_w: wgpu::AddressMode,
-    pub mag_filter: wgpu::FilterMode,
-    pub min_filter: wgpu::FilterMode,
-    pub mipmap_filter: wgpu::MipmapFilterMode,
-    pub lod_min_clamp: f32,
-    pub lod_max_clamp: f32,
-    pub compare: Option<wgpu::CompareFunction>,
-    pub anisotropy_clamp: u16,
-}
-
-impl Default for SamplerConfig {
-    fn default() -> Self {
-        Self {
-            address_mode_u: wgpu::AddressMode::ClampToEdge,
-            address_mode_v: wgpu::AddressMode::ClampToEdge,
-            address_mode_w: wgpu::AddressMode::ClampToEdge,
-            mag_filter: wgpu::FilterMode::Linear,
-            min_filter: wgpu::FilterMode::Linear,
-            mipmap_filter: wgpu::MipmapFilterMode::Linear,
-            lod_min_clamp: 0.0,
-            lod_max_clamp: f32::MAX,
-            compare: None,
-            anisotropy_clamp: 1,
-        }
-    }
-}
-
-impl SamplerConfig {
-    /// Create a WGPU sampler from this configuration.
-    ///
-    /// This is the single place where samplers are created from config,
-    /// eliminating duplicate sampler creation code across the crate.
-    pub fn create_sampler(&self, device: &Device, label: Option<&str>) -> Sampler {
-        device.create_sampler(&wgpu::SamplerDescriptor {
-            label,
-            address_mode_u: self.address_mode_u,
-            address_mode_v: self.address_mode_v,
-            address_mode_w: self.address_mode_w,
-            mag_filter: self.mag_filter,
-            min_filter: self.min_filter,
-            mipmap_filter: self.mipmap_filter,
-            lod_min_clamp: self.lod_min_clamp,
-            lod_max_clamp: self.lod_max_clamp,
-            compare: self.compare,
-            anisotropy_clamp: self.anisotropy_clamp,
-            ..Default::default()
-        })
-    }
-}
-
 /// Texture manager for loading and caching textures
 pub struct TextureManager {
     device: Arc<Device>,
@@ -265,6 +199,50 @@ impl TextureManager {
         Ok(handle)
     }
 
+    /// Overwrite a `width`×`height` block of an existing texture at
+    /// (`x`, `y`) with RGBA data. Lets runtime atlases (the UI glyph cache)
+    /// fill a page incrementally instead of recreating it.
+    pub fn write_texture_region(
+        &mut self,
+        handle: TextureHandle,
+        x: u32,
+        y: u32,
+        width: u32,
+        height: u32,
+        data: &[u8],
+    ) -> Result<(), TextureError> {
+        let resource = self.textures.get(&handle)
+            .ok_or_else(|| TextureError::TextureNotFound(format!("handle {}", handle.id)))?;
+
+        // Compressed textures can only be rewritten in whole blocks
+        if resource.texture.format().is_compressed() {
+            return Err(TextureError::InvalidFormat);
+        }
+
+        let fits = x.checked_add(width).is_some_and(|right| right <= resource.width)
+            && y.checked_add(height).is_some_and(|bottom| bottom <= resource.height);
+        if width == 0 || height == 0 || !fits || data.len() != (width * height * 4) as usize {
+            return Err(TextureError::InvalidFormat);
+        }
+
+        self.queue.write_texture(
+            wgpu::TexelCopyTextureInfo {
+                texture: &resource.texture,
+                mip_level: 0,
+                origin: wgpu::Origin3d { x, y, z: 0 },
+                aspect: wgpu::TextureAspect::All,
+            },
+            data,
+            wgpu::TexelCopyBufferLayout {
+                offset: 0,
+                bytes_per_row: Some(width * 4),
+                rows_per_image: None,
+            },
+            Extent3d { width, height, depth_or_array_layers: 1 },
+        );
+        Ok(())
+    }
+
     /// Create a solid color texture
     pub fn create_solid_color(
         &mut self,
@@ -340,7 +318,12 @@ impl TextureManager {
         config: TextureLoadConfig,
     ) -> Result<TextureResource, TextureError> {
         let format = config.format.unwrap_or(TextureFormat::Rgba8UnormSrgb);
-        
+        let format = config
+            .compression
+            .block_format(self.device.features(), width, height, format.is_srgb())
+            .unwrap_or(format);
+        let mip_level_count = if config.generate_mipmaps { mipmaps::mip_level_count(width, height) } else { 1 };
+
         let texture = Arc::new(self.device.create_texture(&wgpu::TextureDescriptor {
             label: Some("Dynamic Texture"),
             size: Extent3d {
@@ -348,7 +331,7 @@ impl TextureManager {
                 height,
                 depth_or_array_layers: 1,
             },
-            mip_level_count: 1,
+            mip_level_count,
             sample_count: 1,
             dimension: wgpu::TextureDimension::D2,
             format,
@@ -356,17 +339,16 @@ impl TextureManager {
             view_formats: &[],
         }));
 
-        // Upload texture data using write_texture directly with simplified layout
-        self.queue.write_texture(
-            texture.as_image_copy(),
-            data,
-            wgpu::TexelCopyBufferLayout {
-                offset: 0,
-                bytes_per_row: Some(width * 4),
-                rows_per_image: None,
-            },
-            texture.size(),
-        );
+        // Upload the base level, then each generated mip level
+        self.write_mip_level(&texture, 0, width, height, data);
+        let mut level_data = None;
+        let (mut level_width, mut level_height) = (width, height);
+        for level in 1..mip_level_count {
+            let source: &[u8] = level_data.as_deref().unwrap_or(data);
+            let (next_width, next_height, next) = mipmaps::next_level(level_width, level_height, source, format.is_srgb());
+            self.write_mip_level(&texture, level, next_width, next_height, &next);
+            (level_width, level_height, level_data) = (next_width, next_height, Some(next));
+        }
 
         // Create view
         let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
@@ -383,6 +365,50 @@ impl TextureManager {
         })
     }
 
+    /// Write RGBA data covering all of mip `level`, block-compressing it
+    /// first when the texture is compressed.
+    fn write_mip_level(&self, texture: &wgpu::Texture, level: u32, width: u32, height: u32, data: &[u8]) {
+        let format = texture.format();
+        if format.is_compressed() {
+            let Some(blocks) = compression::compress(format, width, height, data) else {
+                log::error!("No encoder for {format:?}; mip level {level} left empty");
+                return;
+            };
+            self.queue.write_texture(
+                wgpu::TexelCopyTextureInfo {
+                    texture,
+                    mip_level: level,
+                    origin: wgpu::Origin3d::ZERO,
+                    aspect: wgpu::TextureAspect::All,
+                },
+                &blocks,
+                wgpu::TexelCopyBufferLayout {
+                    offset: 0,
+                    bytes_per_row: Some(width.div_ceil(4) * 16),
+                    rows_per_image: None,
+                },
+                // Levels below 4×4 still cover one whole block
+                Extent3d { width, height, depth_or_array_layers: 1 }.physical_size(format),
+            );
+            return;
+        }
+        self.queue.write_texture(
+            wgpu::TexelCopyTextureInfo {
+                texture,
+                mip_level: level,
+                origin: wgpu::Origin3d::ZERO,
+                aspect: wgpu::TextureAspect::All,
+            },
+            data,
+            wgpu::TexelCopyBufferLayout {
+                offset: 0,
+                bytes_per_row: Some(width * 4),
+                rows_per_image: None,
+            },
+            Extent3d { width, height, depth_or_array_layers: 1 },
+        );
+    }
+
     /// Create sampler from config (delegates to SamplerConfig::create_sampler)
     fn create_sampler(&self, config: &SamplerConfig) -> Sampler {
         config.create_sampler(&self.device, Some("Texture Sampler"))
@@ -437,58 +463,6 @@ mod tests {
         assert_eq!(handle1.id, handle2.id);
     }
 
-    // ==================== TextureLoadConfig Tests ====================
-
-    #[test]
-    fn test_texture_load_config_default() {
-        let config = TextureLoadConfig::default();
-        assert!(config.format.is_none());
-    }
-
-    #[test]
-    fn test_texture_load_config_with_format() {
-        let config = TextureLoadConfig {
-            format: Some(wgpu::TextureFormat::Rgba8Unorm),
-            ..Default::default()
-        };
-        assert_eq!(config.format, Some(wgpu::TextureFormat::Rgba8Unorm));
-    }
-
-    // ==================== SamplerConfig Tests ====================
-
-    #[test]
-    fn test_sampler_config_default() {
-        let config = SamplerConfig::default();
-        assert_eq!(config.address_mode_u, wgpu::AddressMode::ClampToEdge);
-        assert_eq!(config.address_mode_v, wgpu::AddressMode::ClampToEdge);
-        assert_eq!(config.address_mode_w, wgpu::AddressMode::ClampToEdge);
-        assert_eq!(config.mag_filter, wgpu::FilterMode::Linear);
-        assert_eq!(config.min_filter, wgpu::FilterMode::Linear);
-        assert_eq!(config.mipmap_filter, wgpu::MipmapFilterMode::Linear);
-        assert_eq!(config.lod_min_clamp, 0.0);
-        assert_eq!(config.lod_max_clamp, f32::MAX);
-        assert!(config.compare.is_none());
-        assert_eq!(config.anisotropy_clamp, 1);
-    }
-
-    #[test]
-    fn test_sampler_config_custom() {
-        let config = SamplerConfig {
-            address_mode_u: wgpu::AddressMode::Repeat,
-            address_mode_v: wgpu::AddressMode::MirrorRepeat,
-            mag_filter: wgpu::FilterMode::Nearest,
-            min_filter: wgpu::FilterMode::Nearest,
-            anisotropy_clamp: 4,
-            ..Default::default()
-        };
-
-        assert_eq!(config.address_mode_u, wgpu::AddressMode::Repeat);
-        assert_eq!(config.address_mode_v, wgpu::AddressMode::MirrorRepeat);
-        assert_eq!(config.mag_filter, wgpu::FilterMode::Nearest);
-        assert_eq!(config.min_filter, wgpu::FilterMode::Nearest);
-        assert_eq!(config.anisotropy_clamp, 4);
-    }
-
     // ==================== TextureError Tests ====================
 
     #[test]
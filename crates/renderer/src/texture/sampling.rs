@@ -0,0 +1,216 @@
+//! Per-texture sampling options: filtering and address modes
+//! ([`SamplerConfig`]), plus the mip chain and block compression requested
+//! in a [`TextureLoadConfig`].
+
+use wgpu::{Device, Sampler, TextureFormat};
+
+use super::compression::TextureCompression;
+
+/// Texture loading configuration
+///
+/// ```
+/// # use renderer::{SamplerConfig, TextureLoadConfig};
+/// // Crisp pixel art that tiles across a background
+/// let config = TextureLoadConfig::pixel_art()
+///     .with_sampler(SamplerConfig::nearest().with_address_mode(renderer::wgpu::AddressMode::Repeat));
+/// // Smooth artwork drawn much smaller than its source size
+/// let smooth = TextureLoadConfig::default().with_mipmaps(true);
+/// // A large backdrop in a quarter of the memory, where the GPU supports it
+/// let backdrop = TextureLoadConfig::default().with_compression(TextureCompression::Auto);
+/// # use renderer::TextureCompression;
+/// # assert!(!config.generate_mipmaps && smooth.generate_mipmaps);
+/// # assert_eq!(backdrop.compression, TextureCompression::Auto);
+/// ```
+#[derive(Debug, Clone)]
+#[derive(Default)]
+pub struct TextureLoadConfig {
+    /// Texture format (None to auto-detect)
+    pub format: Option<TextureFormat>,
+    /// Sampler configuration
+    pub sampler_config: SamplerConfig,
+    /// Build a full mip chain on load (see [`crate::mipmaps`]), so sprites
+    /// drawn far below their source size don't shimmer. Off by default.
+    pub generate_mipmaps: bool,
+    /// GPU block compression (see [`compression`](super::compression)). Falls
+    /// back to uncompressed when the device or texture size can't use it.
+    /// Off by default.
+    pub compression: TextureCompression,
+}
+
+impl TextureLoadConfig {
+    /// Nearest filtering and no mipmaps: texels stay crisp squares.
+    pub fn pixel_art() -> Self {
+        Self { sampler_config: SamplerConfig::nearest(), ..Self::default() }
+    }
+
+    /// Use `sampler` for filtering and address modes.
+    pub fn with_sampler(mut self, sampler: SamplerConfig) -> Self {
+        self.sampler_config = sampler;
+        self
+    }
+
+    /// Generate (or skip) a mip chain on load.
+    pub fn with_mipmaps(mut self, generate: bool) -> Self {
+        self.generate_mipmaps = generate;
+        self
+    }
+
+    /// Block-compress the texture on load.
+    pub fn with_compression(mut self, compression: TextureCompression) -> Self {
+        self.compression = compression;
+        self
+    }
+}
+
+/// Sampler configuration
+#[derive(Debug, Clone)]
+pub struct SamplerConfig {
+    pub address_mode_u: wgpu::AddressMode,
+    pub address_mode_v: wgpu::AddressMode,
+    pub address_mode_w: wgpu::AddressMode,
+    pub mag_filter: wgpu::FilterMode,
+    pub min_filter: wgpu::FilterMode,
+    pub mipmap_filter: wgpu::MipmapFilterMode,
+    pub lod_min_clamp: f32,
+    pub lod_max_clamp: f32,
+    pub compare: Option<wgpu::CompareFunction>,
+    pub anisotropy_clamp: u16,
+}
+
+impl Default for SamplerConfig {
+    fn default() -> Self {
+        Self {
+            address_mode_u: wgpu::AddressMode::ClampToEdge,
+            address_mode_v: wgpu::AddressMode::ClampToEdge,
+            address_mode_w: wgpu::AddressMode::ClampToEdge,
+            mag_filter: wgpu::FilterMode::Linear,
+            min_filter: wgpu::FilterMode::Linear,
+            mipmap_filter: wgpu::MipmapFilterMode::Linear,
+            lod_min_clamp: 0.0,
+            lod_max_clamp: f32::MAX,
+            compare: None,
+            anisotropy_clamp: 1,
+        }
+    }
+}
+
+impl SamplerConfig {
+    /// Linear filtering, clamped edges (the default).
+    pub fn linear() -> Self {
+        Self::default()
+    }
+
+    /// Nearest filtering everywhere, for pixel art.
+    pub fn nearest() -> Self {
+        Self::default().with_filter(wgpu::FilterMode::Nearest)
+    }
+
+    /// Use `filter` for magnification, minification and between mip levels.
+    pub fn with_filter(mut self, filter: wgpu::FilterMode) -> Self {
+        self.mag_filter = filter;
+        self.min_filter = filter;
+        self.mipmap_filter = match filter {
+            wgpu::FilterMode::Nearest => wgpu::MipmapFilterMode::Nearest,
+            wgpu::FilterMode::Linear => wgpu::MipmapFilterMode::Linear,
+        };
+        self
+    }
+
+    /// Use `mode` on every axis (e.g. `Repeat` for tiling backgrounds).
+    pub fn with_address_mode(mut self, mode: wgpu::AddressMode) -> Self {
+        self.address_mode_u = mode;
+        self.address_mode_v = mode;
+        self.address_mode_w = mode;
+        self
+    }
+
+    /// Create a WGPU sampler from this configuration.
+    ///
+    /// This is the single place where samplers are created from config,
+    /// eliminating duplicate sampler creation code across the crate.
+    pub fn create_sampler(&self, device: &Device, label: Option<&str>) -> Sampler {
+        device.create_sampler(&wgpu::SamplerDescriptor {
+            label,
+            address_mode_u: self.address_mode_u,
+            address_mode_v: self.address_mode_v,
+            address_mode_w: self.address_mode_w,
+            mag_filter: self.mag_filter,
+            min_filter: self.min_filter,
+            mipmap_filter: self.mipmap_filter,
+            lod_min_clamp: self.lod_min_clamp,
+            lod_max_clamp: self.lod_max_clamp,
+            compare: self.compare,
+            anisotropy_clamp: self.anisotropy_clamp,
+            ..Default::default()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ==================== TextureLoadConfig Tests ====================
+
+    #[test]
+    fn test_texture_load_config_default() {
+        let config = TextureLoadConfig::default();
+        assert!(config.format.is_none());
+    }
+
+    #[test]
+    fn test_texture_load_config_with_format() {
+        let config = TextureLoadConfig {
+            format: Some(wgpu::TextureFormat::Rgba8Unorm),
+            ..Default::default()
+        };
+        assert_eq!(config.format, Some(wgpu::TextureFormat::Rgba8Unorm));
+    }
+
+    // ==================== SamplerConfig Tests ====================
+
+    #[test]
+    fn test_sampler_config_default() {
+        let config = SamplerConfig::default();
+        assert_eq!(config.address_mode_u, wgpu::AddressMode::ClampToEdge);
+        assert_eq!(config.address_mode_v, wgpu::AddressMode::ClampToEdge);
+        assert_eq!(config.address_mode_w, wgpu::AddressMode::ClampToEdge);
+        assert_eq!(config.mag_filter, wgpu::FilterMode::Linear);
+        assert_eq!(config.min_filter, wgpu::FilterMode::Linear);
+        assert_eq!(config.mipmap_filter, wgpu::MipmapFilterMode::Linear);
+        assert_eq!(config.lod_min_clamp, 0.0);
+        assert_eq!(config.lod_max_clamp, f32::MAX);
+        assert!(config.compare.is_none());
+        assert_eq!(config.anisotropy_clamp, 1);
+    }
+
+    #[test]
+    fn test_sampler_config_custom() {
+        let config = SamplerConfig {
+            address_mode_u: wgpu::AddressMode::Repeat,
+            address_mode_v: wgpu::AddressMode::MirrorRepeat,
+            mag_filter: wgpu::FilterMode::Nearest,
+            min_filter: wgpu::FilterMode::Nearest,
+            anisotropy_clamp: 4,
+            ..Default::default()
+        };
+
+        assert_eq!(config.address_mode_u, wgpu::AddressMode::Repeat);
+        assert_eq!(config.address_mode_v, wgpu::AddressMode::MirrorRepeat);
+        assert_eq!(config.mag_filter, wgpu::FilterMode::Nearest);
+        assert_eq!(config.min_filter, wgpu::FilterMode::Nearest);
+        assert_eq!(config.anisotropy_clamp, 4);
+    }
+
+    #[test]
+    fn test_pixel_art_config_uses_nearest_without_mipmaps() {
+        let config = TextureLoadConfig::pixel_art()
+            .with_sampler(SamplerConfig::nearest().with_address_mode(wgpu::AddressMode::Repeat));
+        assert_eq!(config.sampler_config.mag_filter, wgpu::FilterMode::Nearest);
+        assert_eq!(config.sampler_config.min_filter, wgpu::FilterMode::Nearest);
+        assert_eq!(config.sampler_config.mipmap_filter, wgpu::MipmapFilterMode::Nearest);
+        assert_eq!(config.sampler_config.address_mode_v, wgpu::AddressMode::Repeat);
+        assert!(!config.generate_mipmaps);
+        assert!(TextureLoadConfig::default().with_mipmaps(true).generate_mipmaps);
+    }
+}
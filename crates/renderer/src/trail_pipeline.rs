@@ -0,0 +1,316 @@
+//! Trail render pipeline.
+//!
+//! Draws ribbon geometry — sword swipes, projectile tails, dash streaks —
+//! as one triangle strip per trail into the HDR target, after sprites and
+//! lines and before bloom, so emissive trails glow. Callers build each
+//! trail as left/right vertex pairs and stitch them into one vertex list
+//! with [`append_strip`]; the whole frame is a single draw call.
+//!
+//! Like [`LinePipeline`](crate::line_pipeline::LinePipeline) this owns its
+//! camera binding (see DRY-006 in TECH_DEBT.md).
+
+use std::sync::Arc;
+
+use wgpu::{
+    util::DeviceExt, BindGroup, Buffer, CommandEncoder, Device, Queue, RenderPipeline,
+};
+
+use crate::render_targets::{DEPTH_FORMAT, HDR_FORMAT, RenderTargets};
+use crate::sprite_data::{Camera, CameraUniform, DynamicBuffer};
+
+/// One vertex of a trail strip.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct TrailVertex {
+    pub position: [f32; 2],
+    /// World z, same convention as sprite depth.
+    pub depth: f32,
+    /// sRGB, decoded to linear in the vertex shader
+    pub color: [f32; 4],
+    pub emissive: f32,
+}
+
+impl TrailVertex {
+    pub fn new(position: glam::Vec2, depth: f32, color: glam::Vec4, emissive: f32) -> Self {
+        Self {
+            position: position.to_array(),
+            depth,
+            color: color.to_array(),
+            emissive,
+        }
+    }
+
+    fn desc() -> wgpu::VertexBufferLayout<'static> {
+        wgpu::VertexBufferLayout {
+            array_stride: std::mem::size_of::<Self>() as wgpu::BufferAddress,
+            step_mode: wgpu::VertexStepMode::Vertex,
+            attributes: &[
+                wgpu::VertexAttribute {
+                    offset: 0,
+                    shader_location: 0,
+                    format: wgpu::VertexFormat::Float32x2,
+                },
+                wgpu::VertexAttribute {
+                    offset: std::mem::size_of::<[f32; 2]>() as wgpu::BufferAddress,
+                    shader_location: 1,
+                    format: wgpu::VertexFormat::Float32,
+                },
+                wgpu::VertexAttribute {
+                    offset: std::mem::size_of::<[f32; 3]>() as wgpu::BufferAddress,
+                    shader_location: 2,
+                    format: wgpu::VertexFormat::Float32x4,
+                },
+                wgpu::VertexAttribute {
+                    offset: std::mem::size_of::<[f32; 7]>() as wgpu::BufferAddress,
+                    shader_location: 3,
+                    format: wgpu::VertexFormat::Float32,
+                },
+            ],
+        }
+    }
+}
+
+/// Append one trail's strip to `out`, joining it to the previous strip with
+/// two degenerate (zero-area) triangles so every trail shares one draw.
+/// Strips with fewer than 3 vertices cover no area and are skipped.
+pub fn append_strip(out: &mut Vec<TrailVertex>, strip: &[TrailVertex]) {
+    let (Some(&first), true) = (strip.first(), strip.len() >= 3) else {
+        return;
+    };
+    if let Some(&last) = out.last() {
+        out.push(last);
+        out.push(first);
+        // Keep every strip starting on an even index so the winding of its
+        // first triangle doesn't depend on what came before.
+        if out.len() % 2 == 1 {
+            out.push(first);
+        }
+    }
+    out.extend_from_slice(strip);
+}
+
+/// Render pipeline + buffers for drawing trail strips.
+pub struct TrailPipeline {
+    pipeline: RenderPipeline,
+    vertex_buffer: DynamicBuffer<TrailVertex>,
+    camera_buffer: Buffer,
+    camera_bind_group: BindGroup,
+    /// Used to grow the vertex buffer when an upload exceeds its capacity.
+    device: Arc<Device>,
+}
+
+impl TrailPipeline {
+    /// Initial vertex capacity; grows on demand. 64 trails of 32 points is
+    /// ~4k vertices.
+    pub const DEFAULT_CAPACITY: usize = 4_096;
+
+    pub fn new(device: &Device, capacity: usize) -> Self {
+        let device_arc = Arc::new(device.clone());
+
+        let camera_bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Trail Camera Bind Group Layout"),
+            entries: &[wgpu::BindGroupLayoutEntry {
+                binding: 0,
+                visibility: wgpu::ShaderStages::VERTEX,
+                ty: wgpu::BindingType::Buffer {
+                    ty: wgpu::BufferBindingType::Uniform,
+                    has_dynamic_offset: false,
+                    min_binding_size: None,
+                },
+                count: None,
+            }],
+        });
+
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Trail Pipeline Layout"),
+            bind_group_layouts: &[&camera_bind_group_layout],
+            ..Default::default()
+        });
+
+        let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Trail Shader"),
+            source: wgpu::ShaderSource::Wgsl(std::borrow::Cow::Borrowed(include_str!("shaders/trail.wgsl"))),
+        });
+
+        let camera_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Trail Camera Buffer"),
+            contents: bytemuck::cast_slice(&[CameraUniform::from_camera(&Camera::default())]),
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+        });
+
+        let camera_bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Trail Camera Bind Group"),
+            layout: &camera_bind_group_layout,
+            entries: &[wgpu::BindGroupEntry {
+                binding: 0,
+                resource: camera_buffer.as_entire_binding(),
+            }],
+        });
+
+        let vertex_buffer = DynamicBuffer::new(device, capacity, wgpu::BufferUsages::VERTEX);
+
+        let pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("Trail Pipeline"),
+            layout: Some(&pipeline_layout),
+            vertex: wgpu::VertexState {
+                module: &shader,
+                entry_point: Some("vs_main"),
+                buffers: &[TrailVertex::desc()],
+                compilation_options: Default::default(),
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: &shader,
+                entry_point: Some("fs_main"),
+                targets: &[Some(wgpu::ColorTargetState {
+                    format: HDR_FORMAT,
+                    blend: Some(wgpu::BlendState::ALPHA_BLENDING),
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+                compilation_options: Default::default(),
+            }),
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::TriangleStrip,
+                strip_index_format: None,
+                front_face: wgpu::FrontFace::Ccw,
+                // Trails fold over themselves on tight turns; draw both faces.
+                cull_mode: None,
+                ..Default::default()
+            },
+            // Test against sprite depth so trails pass behind foreground
+            // sprites, but don't write — translucent trails must not hide
+            // each other.
+            depth_stencil: Some(wgpu::DepthStencilState {
+                format: DEPTH_FORMAT,
+                depth_write_enabled: false,
+                depth_compare: wgpu::CompareFunction::LessEqual,
+                stencil: wgpu::StencilState::default(),
+                bias: wgpu::DepthBiasState::default(),
+            }),
+            multisample: wgpu::MultisampleState {
+                count: 1,
+                mask: !0,
+                alpha_to_coverage_enabled: false,
+            },
+            cache: None,
+            multiview_mask: None,
+        });
+
+        Self {
+            pipeline,
+            vertex_buffer,
+            camera_buffer,
+            camera_bind_group,
+            device: device_arc,
+        }
+    }
+
+    /// Push the camera uniform to the GPU. Call once per frame.
+    pub fn update_camera(&self, queue: &Queue, camera: &Camera) {
+        self.update_camera_uniform(queue, &CameraUniform::from_camera(camera));
+    }
+
+    /// Push an already built camera uniform (e.g. a camera-relative one).
+    pub fn update_camera_uniform(&self, queue: &Queue, uniform: &CameraUniform) {
+        queue.write_buffer(&self.camera_buffer, 0, bytemuck::bytes_of(uniform));
+    }
+
+    /// Upload the frame's stitched strip vertices (see [`append_strip`]).
+    /// The vertex buffer grows automatically if the set exceeds its capacity.
+    pub fn upload_vertices(&mut self, queue: &Queue, vertices: &[TrailVertex]) {
+        if vertices.is_empty() {
+            return;
+        }
+        self.vertex_buffer.update(&self.device, queue, vertices);
+    }
+
+    /// Draw the uploaded strip into the HDR target on top of what is
+    /// already there, depth-tested against the sprite depth buffer.
+    ///
+    /// Returns the number of draw calls issued (0 with no vertices).
+    pub fn draw(
+        &self,
+        encoder: &mut CommandEncoder,
+        targets: &RenderTargets,
+        vertex_count: u32,
+    ) -> usize {
+        if vertex_count < 3 {
+            return 0;
+        }
+        let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: Some("Trail Render Pass"),
+            color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                view: &targets.hdr_view,
+                resolve_target: None,
+                depth_slice: None,
+                ops: wgpu::Operations {
+                    load: wgpu::LoadOp::Load,
+                    store: wgpu::StoreOp::Store,
+                },
+            })],
+            depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
+                view: &targets.depth_view,
+                depth_ops: Some(wgpu::Operations {
+                    load: wgpu::LoadOp::Load,
+                    store: wgpu::StoreOp::Store,
+                }),
+                stencil_ops: None,
+            }),
+            timestamp_writes: None,
+            occlusion_query_set: None,
+            multiview_mask: None,
+        });
+
+        pass.set_pipeline(&self.pipeline);
+        pass.set_bind_group(0, &self.camera_bind_group, &[]);
+        pass.set_vertex_buffer(0, self.vertex_buffer.slice());
+        pass.draw(0..vertex_count, 0..1);
+        1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use glam::{Vec2, Vec4};
+
+    fn vertex(x: f32) -> TrailVertex {
+        TrailVertex::new(Vec2::new(x, 0.0), 0.0, Vec4::ONE, 0.0)
+    }
+
+    #[test]
+    fn test_trail_vertex_layout_size() {
+        // 2*4 + 1*4 + 4*4 + 1*4 = 32 bytes.
+        assert_eq!(std::mem::size_of::<TrailVertex>(), 32);
+        let desc = TrailVertex::desc();
+        assert_eq!(desc.attributes.len(), 4);
+        assert_eq!(desc.array_stride, 32);
+        assert_eq!(desc.attributes[3].offset, 28);
+    }
+
+    #[test]
+    fn test_append_strip_stitches_with_degenerates() {
+        let mut out = Vec::new();
+        append_strip(&mut out, &[vertex(0.0), vertex(1.0), vertex(2.0), vertex(3.0)]);
+        assert_eq!(out.len(), 4, "first strip is copied as-is");
+
+        append_strip(&mut out, &[vertex(10.0), vertex(11.0), vertex(12.0), vertex(13.0)]);
+        // last of A, first of B, then B — B starts on an even index.
+        assert_eq!(out.len(), 10);
+        assert_eq!(out[4], vertex(3.0));
+        assert_eq!(out[5], vertex(10.0));
+        assert_eq!(&out[6..], &[vertex(10.0), vertex(11.0), vertex(12.0), vertex(13.0)]);
+    }
+
+    #[test]
+    fn test_append_strip_keeps_parity_and_skips_empty_strips() {
+        let mut out = Vec::new();
+        append_strip(&mut out, &[vertex(0.0), vertex(1.0), vertex(2.0)]);
+        append_strip(&mut out, &[vertex(5.0), vertex(6.0)]);
+        assert_eq!(out.len(), 3, "a strip without area is dropped");
+
+        append_strip(&mut out, &[vertex(10.0), vertex(11.0), vertex(12.0)]);
+        // 3 + last + first + parity pad = 6, then the strip from index 6.
+        assert_eq!(out.len(), 9);
+        assert_eq!(&out[3..6], &[vertex(2.0), vertex(10.0), vertex(10.0)]);
+    }
+}
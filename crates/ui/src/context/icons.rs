@@ -0,0 +1,76 @@
+//! Named icons for [`UIContext`]: rich-text `[icon=name]` and `draw_icon`.
+//!
+//! An icon is a texture plus the UV rect it occupies. Register a whole
+//! texture with `register_icon`, a region you packed yourself with
+//! `register_icon_region`, or hand raw RGBA pixels to
+//! `register_icon_image`: the engine packs those into the UI atlas — the
+//! texture the glyphs live in — before the next frame, so icons, text and
+//! panels draw from one texture binding. An image icon is usable from that
+//! next frame on.
+
+use common::{Color, Rect};
+
+use crate::FULL_UV;
+
+use super::UIContext;
+
+/// RGBA pixels waiting to be packed into the UI atlas (see
+/// [`UIContext::take_icon_images`]).
+#[derive(Debug, Clone, PartialEq)]
+pub struct IconImage {
+    /// Name the icon is drawn by
+    pub name: String,
+    pub width: u32,
+    pub height: u32,
+    /// `width * height` RGBA pixels, row-major
+    pub rgba: Vec<u8>,
+}
+
+impl UIContext {
+    /// Register an inline icon for rich-text labels: `[icon=name]` draws
+    /// the texture as a square the height of the font's ascent.
+    pub fn register_icon(&mut self, name: impl Into<String>, texture_id: u32) {
+        self.register_icon_region(name, texture_id, FULL_UV);
+    }
+
+    /// Register an icon that is the `uv_rect` region (0..1, top-left
+    /// origin) of a texture, such as an atlas slot.
+    pub fn register_icon_region(&mut self, name: impl Into<String>, texture_id: u32, uv_rect: Rect) {
+        self.icons.insert(name.into(), (texture_id, uv_rect));
+    }
+
+    /// Queue a `width`×`height` RGBA image to be packed into the UI atlas
+    /// under `name`. Images whose pixel count doesn't match are ignored.
+    pub fn register_icon_image(&mut self, name: impl Into<String>, width: u32, height: u32, rgba: Vec<u8>) {
+        let name = name.into();
+        if width == 0 || height == 0 || rgba.len() != (width * height * 4) as usize {
+            log::warn!("Icon image '{}' is not {}x{} RGBA pixels; ignoring it", name, width, height);
+            return;
+        }
+        self.pending_icon_images.retain(|image| image.name != name);
+        self.pending_icon_images.push(IconImage { name, width, height, rgba });
+    }
+
+    /// Take the icon images queued since the last call. The renderer
+    /// integration packs them and registers each with
+    /// [`register_icon_region`](Self::register_icon_region).
+    pub fn take_icon_images(&mut self) -> Vec<IconImage> {
+        std::mem::take(&mut self.pending_icon_images)
+    }
+
+    /// Texture id and UV rect of a registered icon.
+    pub fn icon(&self, name: &str) -> Option<(u32, Rect)> {
+        self.icons.get(name).copied()
+    }
+
+    /// Draw a registered icon stretched over `bounds`. Returns `false` (and
+    /// draws nothing) while `name` isn't registered — an image icon before
+    /// its first frame in the atlas, say — so callers can draw a fallback.
+    pub fn draw_icon(&mut self, name: &str, bounds: Rect, tint: Color) -> bool {
+        let Some((texture_id, uv_rect)) = self.icon(name) else {
+            return false;
+        };
+        self.draw_list.image_region(bounds, texture_id, uv_rect, tint);
+        true
+    }
+}
@@ -5,11 +5,17 @@
 //!
 //! Split by responsibility:
 //! - `mod.rs` — UIContext struct, construction, frame lifecycle, fonts, core state
-//! - `text.rs` — label/measure family and shared text-drawing helpers
+//! - `text.rs` — label/measure family (plain, wrapped, and rich-text
+//!   labels) and shared text-drawing helpers
 //! - `widgets.rs` — interactive widgets (button, slider, checkbox)
 //!   and container/shape drawing
 //! - `text_input.rs` — the float/text input widget (cursor, selection, caret)
+//! - `popups.rs` — list popups: dropdown, combo box, and context menu
+//! - `icons.rs` — named icons (whole textures, regions, or images packed
+//!   into the UI atlas)
 
+mod icons;
+mod popups;
 mod text;
 mod text_input;
 mod widgets;
@@ -17,9 +23,13 @@ mod widgets;
 #[cfg(test)]
 mod tests;
 
+use std::collections::HashMap;
+
 use glam::Vec2;
 use input::InputHandler;
 
+pub use icons::IconImage;
+
 /// Text alignment within a bounding box.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum TextAlign {
@@ -33,8 +43,8 @@ pub enum TextAlign {
 }
 
 use crate::{
-    Color, DrawList, FontError, FontHandle, FontManager, InteractionManager, InteractionResult,
-    Rect, Theme, WidgetId,
+    ButtonStyle, Color, DrawList, FontError, FontHandle, FontManager, InteractionManager,
+    InteractionResult, Rect, SliderStyle, TextInputStyle, Theme, ThemeError, WidgetId, WidgetStyle,
 };
 
 /// The main UI context for immediate-mode UI rendering.
@@ -67,10 +77,22 @@ pub struct UIContext {
     draw_list: DrawList,
     /// Current theme
     theme: Theme,
+    /// `theme.overrides` keyed by hashed widget id, rebuilt on every theme change
+    widget_styles: HashMap<WidgetId, WidgetStyle>,
     /// Window size for layout calculations
     window_size: Vec2,
     /// Font manager for text rendering
     font_manager: FontManager,
+    /// Font for `[b]` rich-text spans (faux bold when unset)
+    bold_font: Option<FontHandle>,
+    /// Named icons (`[icon=name]`, `draw_icon`) → renderer texture id and
+    /// UV rect
+    icons: HashMap<String, (u32, Rect)>,
+    /// Icon images waiting to be packed into the UI atlas
+    pending_icon_images: Vec<IconImage>,
+    /// Widgets marked as showing mixed values this frame → whether a
+    /// typed value was committed (see [`UIContext::mark_mixed`])
+    mixed_widgets: HashMap<WidgetId, bool>,
 }
 
 impl Default for UIContext {
@@ -86,15 +108,20 @@ impl UIContext {
             interaction: InteractionManager::new(),
             draw_list: DrawList::new(),
             theme: Theme::default(),
+            widget_styles: HashMap::new(),
             window_size: Vec2::new(800.0, 600.0),
             font_manager: FontManager::new(),
+            bold_font: None,
+            icons: HashMap::new(),
+            pending_icon_images: Vec::new(),
+            mixed_widgets: HashMap::new(),
         }
     }
 
     /// Create a new UI context with a custom theme.
     pub fn with_theme(theme: Theme) -> Self {
         let mut ctx = Self::new();
-        ctx.theme = theme;
+        ctx.set_theme(theme);
         ctx
     }
 
@@ -120,6 +147,17 @@ impl UIContext {
         self.font_manager.set_default_font(handle);
     }
 
+    /// Set the font used for `[b]` spans in rich-text labels. Without one,
+    /// bold spans are drawn faux-bold (each glyph stamped twice).
+    pub fn set_bold_font(&mut self, handle: FontHandle) {
+        self.bold_font = Some(handle);
+    }
+
+    /// Get the bold font handle, if one was set.
+    pub fn bold_font(&self) -> Option<FontHandle> {
+        self.bold_font
+    }
+
     /// Get the font manager for advanced operations.
     pub fn font_manager(&self) -> &FontManager {
         &self.font_manager
@@ -148,6 +186,7 @@ impl UIContext {
     pub fn begin_frame(&mut self, input: &InputHandler, window_size: Vec2) {
         self.interaction.begin_frame(input);
         self.draw_list.clear();
+        self.mixed_widgets.clear();
         self.window_size = window_size;
     }
 
@@ -156,6 +195,7 @@ impl UIContext {
     pub fn begin_frame_dt(&mut self, input: &InputHandler, window_size: Vec2, dt: f32) {
         self.interaction.begin_frame_dt(input, dt);
         self.draw_list.clear();
+        self.mixed_widgets.clear();
         self.window_size = window_size;
     }
 
@@ -174,11 +214,64 @@ impl UIContext {
         &self.theme
     }
 
-    /// Set a new theme.
+    /// Set a new theme. Takes effect for every widget drawn after the call,
+    /// so swapping mid-session (e.g. from a [`ThemeWatcher`](crate::ThemeWatcher))
+    /// needs no other bookkeeping.
     pub fn set_theme(&mut self, theme: Theme) {
+        self.widget_styles = theme
+            .overrides
+            .iter()
+            .map(|(id, style)| (WidgetId::from_str(id), style.clone()))
+            .collect();
         self.theme = theme;
     }
 
+    /// Load a `.ron` or `.toml` theme file and make it the current theme.
+    /// On error the current theme is kept.
+    pub fn load_theme(&mut self, path: impl AsRef<std::path::Path>) -> Result<(), ThemeError> {
+        let theme = Theme::load(path)?;
+        self.set_theme(theme);
+        Ok(())
+    }
+
+    /// Override the style of a single widget (by the string its id is made
+    /// from). The override is stored in the theme, so it is saved with it
+    /// and replaced by the next [`set_theme`](Self::set_theme).
+    pub fn set_widget_style(&mut self, id: &str, style: WidgetStyle) {
+        self.widget_styles.insert(WidgetId::from_str(id), style.clone());
+        self.theme.overrides.insert(id.to_string(), style);
+    }
+
+    /// Remove a widget's style override, returning it if there was one.
+    pub fn clear_widget_style(&mut self, id: &str) -> Option<WidgetStyle> {
+        self.widget_styles.remove(&WidgetId::from_str(id));
+        self.theme.overrides.remove(id)
+    }
+
+    /// Button style for `id`: its override if set, else the theme's.
+    pub(super) fn button_style(&self, id: WidgetId) -> &ButtonStyle {
+        self.widget_styles
+            .get(&id)
+            .and_then(|style| style.button.as_ref())
+            .unwrap_or(&self.theme.button)
+    }
+
+    /// Slider style for `id`: its override if set, else the theme's.
+    pub(super) fn slider_style(&self, id: WidgetId) -> &SliderStyle {
+        self.widget_styles
+            .get(&id)
+            .and_then(|style| style.slider.as_ref())
+            .unwrap_or(&self.theme.slider)
+    }
+
+    /// Text input style for `id`: its override if set, else the theme's.
+    pub(super) fn text_input_style(&self, id: WidgetId) -> &TextInputStyle {
+        self.widget_styles
+            .get(&id)
+            .and_then(|style| style.text_input.as_ref())
+            .unwrap_or(&self.theme.text_input)
+    }
+
     /// Get the window size.
     pub fn window_size(&self) -> Vec2 {
         self.window_size
@@ -232,11 +325,12 @@ impl UIContext {
         self.interaction.set_overlay_scope(true);
     }
 
-    /// End the current overlay, returning to the base depth band and
-    /// re-enabling input blocking for subsequent widgets.
+    /// End the current overlay, returning to the enclosing depth band and
+    /// re-enabling input blocking for subsequent widgets once the outermost
+    /// overlay has ended (overlays nest — see [`DrawList::begin_overlay`]).
     pub fn end_overlay(&mut self) {
         self.draw_list.end_overlay();
-        self.interaction.set_overlay_scope(false);
+        self.interaction.set_overlay_scope(self.draw_list.is_overlay());
     }
 
     /// Whether mouse input at `pos` is swallowed by an open overlay
@@ -268,11 +362,27 @@ impl UIContext {
         self.draw_list.image(bounds, texture_id, tint);
     }
 
+    /// Draw part of a texture: `uv_rect` is the normalized sub-region
+    /// (0..1, top-left origin) — atlas icons, portraits, minimap views.
+    pub fn image_region(&mut self, bounds: Rect, texture_id: u32, uv_rect: Rect, tint: Color) {
+        self.draw_list.image_region(bounds, texture_id, uv_rect, tint);
+    }
+
     /// Draw a circle.
     pub fn circle(&mut self, center: Vec2, radius: f32, color: Color) {
         self.draw_list.circle(center, radius, color);
     }
 
+    /// Draw a pointer marker at `position` above all UI, popups included —
+    /// for cursors the OS doesn't draw, like a gamepad-driven one. Call it
+    /// after the frame's other UI.
+    pub fn pointer(&mut self, position: Vec2) {
+        self.draw_list.topmost(|draw| {
+            draw.circle(position, 7.0, Color::BLACK);
+            draw.circle(position, 5.0, Color::WHITE);
+        });
+    }
+
     /// Draw a line.
     pub fn line(&mut self, start: Vec2, end: Vec2, color: Color, width: f32) {
         self.draw_list.line(start, end, color, width);
@@ -304,4 +414,25 @@ impl UIContext {
     pub fn interact(&mut self, id: impl Into<WidgetId>, bounds: Rect, enabled: bool) -> InteractionResult {
         self.interaction.interact(id.into(), bounds, enabled)
     }
+
+    // ================== Mixed Values ==================
+
+    /// Mark widget `id` as standing for several differing values (e.g. a
+    /// field across a multi-selection) for this frame. Call before drawing
+    /// it: a float input shows "—" and starts editing from an empty buffer,
+    /// a checkbox shows a dash. Marks are cleared at the next frame start.
+    pub fn mark_mixed(&mut self, id: impl Into<WidgetId>) {
+        self.mixed_widgets.entry(id.into()).or_insert(false);
+    }
+
+    /// Whether mixed-marked float input `id` committed a typed value this
+    /// frame. The committed value may equal the one the input was given, so
+    /// comparing return values can't tell; this can.
+    pub fn mixed_committed(&self, id: impl Into<WidgetId>) -> bool {
+        self.mixed_widgets.get(&id.into()).copied().unwrap_or(false)
+    }
+
+    fn is_mixed(&self, id: WidgetId) -> bool {
+        self.mixed_widgets.contains_key(&id)
+    }
 }
@@ -0,0 +1,553 @@
+//! List popups for [`UIContext`]: dropdown, combo box, and context menu.
+//!
+//! All three share one popup list: rows drawn in the overlay band (overlays
+//! nest, so a context menu opened over an open dropdown draws on top of it),
+//! hover/click picking, and a blocking rect so clicks never fall through to
+//! the widgets underneath. Open/highlight state persists per widget in
+//! [`PopupState`].
+
+use glam::Vec2;
+
+use crate::{PopupState, Rect, WidgetId, WidgetState};
+
+use super::{TextAlign, UIContext};
+
+/// Minimum context-menu width; menus widen to fit their longest item.
+const CONTEXT_MENU_MIN_WIDTH: f32 = 140.0;
+/// Row height of context-menu items.
+const CONTEXT_MENU_ROW_HEIGHT: f32 = 24.0;
+/// Half-width of the dropdown head's chevron.
+const CHEVRON_HALF_WIDTH: f32 = 4.0;
+/// Width of the accent bar marking the current selection in a list.
+const CURRENT_MARKER_WIDTH: f32 = 3.0;
+
+/// Layout and behavior of one open popup list.
+struct PopupList<'a> {
+    items: &'a [&'a str],
+    rect: Rect,
+    row_height: f32,
+    /// Committed selection to mark (dropdowns); `None` for menus
+    current: Option<usize>,
+    /// Whether arrows/Home/End/Enter drive the highlight
+    keyboard_nav: bool,
+    /// Presses inside this rect (the owning head) don't dismiss
+    exempt: Option<Rect>,
+}
+
+/// What happened to an open popup list this frame.
+enum PopupOutcome {
+    /// Still open
+    Open,
+    /// A row was chosen (click or Enter)
+    Picked(usize),
+    /// Closed without a choice (Escape or a press outside)
+    Dismissed,
+}
+
+impl UIContext {
+    /// Create a dropdown: a button showing `options[selected]` that opens a
+    /// list of all options below it (above it when there's no room below).
+    ///
+    /// Clicking a row selects it; clicking outside or pressing Escape
+    /// closes the list unchanged. Returns the (possibly new) selected index.
+    pub fn dropdown(
+        &mut self,
+        id: impl Into<WidgetId>,
+        selected: usize,
+        options: &[&str],
+        bounds: Rect,
+    ) -> usize {
+        self.dropdown_impl(id.into(), selected, options, bounds, false)
+    }
+
+    /// Create a combo box: a [`dropdown`](Self::dropdown) with keyboard
+    /// navigation.
+    ///
+    /// Clicking focuses it (so [`wants_keyboard`](Self::wants_keyboard) is
+    /// `true`). While focused and closed, Up/Down step the selection and
+    /// Enter opens the list; while open, Up/Down/Home/End move the
+    /// highlight, Enter picks it, and Escape closes. Escape, Tab, or a click
+    /// elsewhere releases focus.
+    pub fn combo_box(
+        &mut self,
+        id: impl Into<WidgetId>,
+        selected: usize,
+        options: &[&str],
+        bounds: Rect,
+    ) -> usize {
+        self.dropdown_impl(id.into(), selected, options, bounds, true)
+    }
+
+    /// Create a context menu for `trigger`: a right-click inside it opens
+    /// `items` at the cursor (shifted to stay inside the window).
+    ///
+    /// While open the menu takes keyboard focus (Up/Down/Enter/Escape).
+    /// Returns the index of the item picked this frame. Call it *before* the
+    /// widgets it can cover: its blocking rect only affects widgets that
+    /// interact after it, and the overlay band draws it on top regardless.
+    pub fn context_menu(
+        &mut self,
+        id: impl Into<WidgetId>,
+        trigger: Rect,
+        items: &[&str],
+    ) -> Option<usize> {
+        let id = id.into();
+        let input = self.interaction.input().clone();
+        let mut popup = self.interaction.get_state(id).popup;
+        let was_open = popup.open;
+
+        if input.right_mouse_just_pressed
+            && trigger.contains(input.mouse_pos)
+            && !self.interaction.is_blocked_at(input.mouse_pos)
+            && !items.is_empty()
+        {
+            popup = PopupState {
+                open: true,
+                highlighted: 0,
+                anchor: input.mouse_pos,
+                last_mouse: input.mouse_pos,
+            };
+            self.interaction.set_focus(id);
+        }
+
+        let mut picked = None;
+        if popup.open {
+            let widest = items
+                .iter()
+                .map(|item| self.measure_text(item).x)
+                .fold(0.0, f32::max);
+            let width = (widest + 2.0 * self.theme.button.padding).max(CONTEXT_MENU_MIN_WIDTH);
+            let size = Vec2::new(width, CONTEXT_MENU_ROW_HEIGHT * items.len() as f32);
+            let list = self.place_popup(popup.anchor, size, None);
+
+            let spec = PopupList {
+                items,
+                rect: list,
+                row_height: CONTEXT_MENU_ROW_HEIGHT,
+                current: None,
+                keyboard_nav: was_open,
+                exempt: None,
+            };
+            match self.popup_list(id, &mut popup, spec) {
+                PopupOutcome::Open => {}
+                PopupOutcome::Picked(index) => {
+                    picked = Some(index);
+                    popup.open = false;
+                }
+                PopupOutcome::Dismissed => popup.open = false,
+            }
+            if !popup.open && self.interaction.is_focused(id) {
+                self.interaction.clear_focus();
+            }
+        }
+
+        self.interaction.get_state(id).popup = popup;
+        picked
+    }
+
+    /// Whether the dropdown, combo box, or context menu `id` is open.
+    pub fn is_popup_open(&self, id: impl Into<WidgetId>) -> bool {
+        self.interaction
+            .get_state_if_exists(id.into())
+            .is_some_and(|state| state.popup.open)
+    }
+
+    /// Shared dropdown / combo-box body; `keyboard` enables focus + arrows.
+    fn dropdown_impl(
+        &mut self,
+        id: WidgetId,
+        selected: usize,
+        options: &[&str],
+        bounds: Rect,
+        keyboard: bool,
+    ) -> usize {
+        let last = options.len().saturating_sub(1);
+        let mut selected = selected.min(last);
+        let result = self.interaction.interact(id, bounds, !options.is_empty());
+        let input = self.interaction.input().clone();
+        let mut popup = self.interaction.get_state(id).popup;
+        let was_open = popup.open;
+
+        if result.clicked {
+            popup.open = !popup.open;
+            popup.highlighted = selected;
+            popup.last_mouse = input.mouse_pos;
+            if keyboard {
+                self.interaction.set_focus(id);
+            }
+        }
+
+        let focused = keyboard && self.interaction.is_focused(id);
+        if focused && !was_open && !result.clicked {
+            if input.up_pressed {
+                selected = selected.saturating_sub(1);
+            }
+            if input.down_pressed {
+                selected = (selected + 1).min(last);
+            }
+            if input.enter_pressed {
+                popup.open = true;
+                popup.highlighted = selected;
+            } else if input.escape_pressed || input.tab_pressed {
+                self.interaction.clear_focus();
+            }
+        }
+
+        let label = options.get(selected).copied().unwrap_or("");
+        self.draw_dropdown_head(id, bounds, label, result.state, popup.open || focused);
+
+        let mut list = None;
+        if popup.open {
+            let size = Vec2::new(bounds.width, bounds.height * options.len() as f32);
+            let rect = self.place_popup(Vec2::new(bounds.x, bounds.bottom()), size, Some(bounds.y));
+            list = Some(rect);
+            let spec = PopupList {
+                items: options,
+                rect,
+                row_height: bounds.height,
+                current: Some(selected),
+                keyboard_nav: focused && was_open,
+                exempt: Some(bounds),
+            };
+            match self.popup_list(id, &mut popup, spec) {
+                PopupOutcome::Open => {}
+                PopupOutcome::Picked(index) => {
+                    selected = index;
+                    popup.open = false;
+                }
+                PopupOutcome::Dismissed => popup.open = false,
+            }
+        }
+
+        // A press anywhere outside the head (and its list) releases focus.
+        let outside = !bounds.contains(input.mouse_pos)
+            && !list.is_some_and(|rect| rect.contains(input.mouse_pos));
+        if focused && input.mouse_just_pressed && outside {
+            self.interaction.clear_focus();
+        }
+
+        self.interaction.get_state(id).popup = popup;
+        selected
+    }
+
+    /// Place a popup of `size` with its top-left at `anchor`, kept inside
+    /// the window. When it would run off the bottom and `flip_above` (the
+    /// owning widget's top edge) is given, it opens above the widget instead.
+    fn place_popup(&self, anchor: Vec2, size: Vec2, flip_above: Option<f32>) -> Rect {
+        let window = self.window_size;
+        let x = anchor.x.min(window.x - size.x).max(0.0);
+        let y = if anchor.y + size.y <= window.y {
+            anchor.y
+        } else {
+            match flip_above {
+                Some(top) if top - size.y >= 0.0 => top - size.y,
+                _ => (window.y - size.y).max(0.0),
+            }
+        };
+        Rect::new(x, y, size.x, size.y)
+    }
+
+    /// Draw and drive an open popup list in the overlay band.
+    fn popup_list(&mut self, id: WidgetId, popup: &mut PopupState, spec: PopupList) -> PopupOutcome {
+        let PopupList { items, rect: list, row_height, current, keyboard_nav, exempt } = spec;
+        let input = self.interaction.input().clone();
+        let mouse = input.mouse_pos;
+        let last = items.len().saturating_sub(1);
+
+        if input.escape_pressed
+            || (input.mouse_just_pressed
+                && !list.contains(mouse)
+                && !exempt.is_some_and(|rect| rect.contains(mouse)))
+        {
+            return PopupOutcome::Dismissed;
+        }
+        if keyboard_nav {
+            if input.up_pressed {
+                popup.highlighted = popup.highlighted.saturating_sub(1);
+            }
+            if input.down_pressed {
+                popup.highlighted = (popup.highlighted + 1).min(last);
+            }
+            if input.home_pressed {
+                popup.highlighted = 0;
+            }
+            if input.end_pressed {
+                popup.highlighted = last;
+            }
+            if input.enter_pressed {
+                return PopupOutcome::Picked(popup.highlighted.min(last));
+            }
+        }
+        // The mouse only steals the highlight when it moves, so arrow keys
+        // keep working while the cursor rests over the list.
+        let mouse_moved = mouse != popup.last_mouse;
+        popup.last_mouse = mouse;
+
+        let button = self.theme.button.clone();
+        let panel = self.theme.panel.clone();
+        let accent = self.theme.slider.track_fill;
+        let font_size = self.theme.text.font_size;
+
+        self.begin_overlay(list);
+        self.draw_list
+            .panel(list, panel.background, panel.border, panel.border_width, panel.corner_radius);
+
+        let mut outcome = PopupOutcome::Open;
+        for (index, item) in items.iter().enumerate() {
+            let row = Rect::new(list.x, list.y + index as f32 * row_height, list.width, row_height);
+            let result = self.interaction.interact(id.child(index), row, true);
+            if mouse_moved && result.state != WidgetState::Normal {
+                popup.highlighted = index;
+            }
+            if result.clicked {
+                outcome = PopupOutcome::Picked(index);
+            }
+
+            if popup.highlighted == index {
+                self.draw_list.rect(row, button.background_hovered);
+            }
+            if current == Some(index) {
+                self.draw_list
+                    .rect(Rect::new(row.x, row.y, CURRENT_MARKER_WIDTH, row.height), accent);
+            }
+            let text_pos = self.text_pos_in_bounds(item, row, TextAlign::Left, font_size, button.padding);
+            self.draw_text_at_baseline(item, text_pos, button.text_color, font_size);
+        }
+        self.end_overlay();
+        outcome
+    }
+
+    /// Draw a dropdown head: button box, left-aligned label, and a chevron.
+    fn draw_dropdown_head(
+        &mut self,
+        id: WidgetId,
+        bounds: Rect,
+        label: &str,
+        state: WidgetState,
+        highlighted: bool,
+    ) {
+        let style = self.button_style(id).clone();
+        let background = Self::widget_background_color(&style, state);
+        let border = if highlighted { self.theme.text_input.border_focused } else { style.border };
+        self.draw_list.rect_rounded(bounds, background, style.corner_radius);
+        if style.border_width > 0.0 {
+            self.draw_list
+                .rect_border_rounded(bounds, border, style.border_width.max(1.0), style.corner_radius);
+        }
+
+        let font_size = self.theme.text.font_size;
+        let text_color = if state == WidgetState::Disabled { style.text_color_disabled } else { style.text_color };
+        self.push_clip_rect(Rect::new(
+            bounds.x,
+            bounds.y,
+            (bounds.width - style.padding - 2.0 * CHEVRON_HALF_WIDTH).max(0.0),
+            bounds.height,
+        ));
+        let text_pos = self.text_pos_in_bounds(label, bounds, TextAlign::Left, font_size, style.padding);
+        self.draw_text_at_baseline(label, text_pos, text_color, font_size);
+        self.pop_clip_rect();
+
+        let center = Vec2::new(
+            bounds.right() - style.padding - CHEVRON_HALF_WIDTH,
+            bounds.y + bounds.height / 2.0,
+        );
+        let half = CHEVRON_HALF_WIDTH;
+        let tip = center + Vec2::new(0.0, half / 2.0);
+        self.draw_list.line(center + Vec2::new(-half, -half / 2.0), tip, text_color, 1.5);
+        self.draw_list.line(tip, center + Vec2::new(half, -half / 2.0), text_color, 1.5);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use input::prelude::{InputHandler, KeyCode, MouseButton};
+
+    use super::*;
+
+    const WINDOW: Vec2 = Vec2::new(800.0, 600.0);
+    const OPTIONS: [&str; 3] = ["Dynamic", "Static", "Kinematic"];
+
+    /// Run one UI frame, then clear the input edges for the next one.
+    fn frame<R>(ui: &mut UIContext, input: &mut InputHandler, f: impl FnOnce(&mut UIContext) -> R) -> R {
+        ui.begin_frame(input, WINDOW);
+        let out = f(ui);
+        ui.end_frame();
+        input.update();
+        out
+    }
+
+    /// Press + release the left button at `pos` (two frames), returning the
+    /// result of the release frame.
+    fn click<R>(ui: &mut UIContext, input: &mut InputHandler, pos: Vec2, mut f: impl FnMut(&mut UIContext) -> R) -> R {
+        input.mouse_mut().update_position(pos.x, pos.y);
+        input.mouse_mut().handle_button_press(MouseButton::Left);
+        frame(ui, input, &mut f);
+        input.mouse_mut().handle_button_release(MouseButton::Left);
+        frame(ui, input, &mut f)
+    }
+
+    /// Run one frame with `key` pressed.
+    fn key<R>(ui: &mut UIContext, input: &mut InputHandler, key: KeyCode, f: impl FnOnce(&mut UIContext) -> R) -> R {
+        input.keyboard_mut().handle_key_press(key);
+        let out = frame(ui, input, f);
+        input.keyboard_mut().handle_key_release(key);
+        out
+    }
+
+    fn head() -> Rect {
+        Rect::new(10.0, 10.0, 120.0, 20.0)
+    }
+
+    /// Center of row `index` of a dropdown list opened below `head()`.
+    fn row_center(index: usize) -> Vec2 {
+        Vec2::new(70.0, 30.0 + index as f32 * 20.0 + 10.0)
+    }
+
+    #[test]
+    fn test_dropdown_click_opens_and_row_click_selects() {
+        let mut ui = UIContext::new();
+        let mut input = InputHandler::new();
+
+        click(&mut ui, &mut input, head().center(), |ui| ui.dropdown("body", 0, &OPTIONS, head()));
+        assert!(ui.is_popup_open("body"));
+
+        let selected = click(&mut ui, &mut input, row_center(2), |ui| ui.dropdown("body", 0, &OPTIONS, head()));
+        assert_eq!(selected, 2);
+        assert!(!ui.is_popup_open("body"));
+    }
+
+    #[test]
+    fn test_dropdown_press_outside_closes_without_changing_selection() {
+        let mut ui = UIContext::new();
+        let mut input = InputHandler::new();
+
+        click(&mut ui, &mut input, head().center(), |ui| ui.dropdown("body", 1, &OPTIONS, head()));
+        let selected = click(&mut ui, &mut input, Vec2::new(500.0, 500.0), |ui| ui.dropdown("body", 1, &OPTIONS, head()));
+        assert_eq!(selected, 1);
+        assert!(!ui.is_popup_open("body"));
+    }
+
+    #[test]
+    fn test_dropdown_flips_above_when_no_room_below() {
+        let mut ui = UIContext::new();
+        let mut input = InputHandler::new();
+        let low = Rect::new(10.0, 580.0, 120.0, 20.0);
+
+        click(&mut ui, &mut input, low.center(), |ui| ui.dropdown("low", 0, &OPTIONS, low));
+        // Rows are drawn above the head: the first row at 580 - 3 * 20.
+        let selected = click(&mut ui, &mut input, Vec2::new(70.0, 530.0), |ui| ui.dropdown("low", 0, &OPTIONS, low));
+        assert_eq!(selected, 0);
+        assert!(!ui.is_popup_open("low"));
+    }
+
+    #[test]
+    fn test_combo_box_keyboard_steps_opens_and_picks() {
+        let mut ui = UIContext::new();
+        let mut input = InputHandler::new();
+        let combo = |ui: &mut UIContext, selected| ui.combo_box("shape", selected, &OPTIONS, head());
+
+        // Click opens + focuses; Escape closes the list but keeps focus.
+        click(&mut ui, &mut input, head().center(), |ui| combo(ui, 0));
+        assert!(ui.wants_keyboard());
+        key(&mut ui, &mut input, KeyCode::Escape, |ui| combo(ui, 0));
+        assert!(!ui.is_popup_open("shape"));
+        assert!(ui.wants_keyboard());
+
+        // Closed + focused: Down steps the selection directly.
+        let selected = key(&mut ui, &mut input, KeyCode::ArrowDown, |ui| combo(ui, 0));
+        assert_eq!(selected, 1);
+
+        // Enter opens, Down moves the highlight, Enter picks it.
+        key(&mut ui, &mut input, KeyCode::Enter, |ui| combo(ui, selected));
+        assert!(ui.is_popup_open("shape"));
+        key(&mut ui, &mut input, KeyCode::ArrowDown, |ui| combo(ui, selected));
+        let picked = key(&mut ui, &mut input, KeyCode::Enter, |ui| combo(ui, selected));
+        assert_eq!(picked, 2);
+        assert!(!ui.is_popup_open("shape"));
+
+        // Escape while closed releases focus.
+        key(&mut ui, &mut input, KeyCode::Escape, |ui| combo(ui, picked));
+        assert!(!ui.wants_keyboard());
+    }
+
+    #[test]
+    fn test_context_menu_opens_on_right_click_and_blocks_widgets_below() {
+        let mut ui = UIContext::new();
+        let mut input = InputHandler::new();
+        let area = Rect::new(0.0, 0.0, 400.0, 400.0);
+        let items = ["Duplicate", "Delete"];
+        let under = Rect::new(100.0, 100.0, 200.0, 60.0);
+
+        input.mouse_mut().update_position(100.0, 100.0);
+        input.mouse_mut().handle_button_press(MouseButton::Right);
+        frame(&mut ui, &mut input, |ui| ui.context_menu("ctx", area, &items));
+        input.mouse_mut().handle_button_release(MouseButton::Right);
+        assert!(ui.is_popup_open("ctx"));
+        assert!(ui.wants_keyboard(), "an open context menu takes keyboard focus");
+
+        // The menu opens at the cursor; clicking its second row picks it and
+        // the button underneath never sees the click.
+        let mut button_clicked = false;
+        let picked = click(&mut ui, &mut input, Vec2::new(110.0, 100.0 + 24.0 + 12.0), |ui| {
+            let picked = ui.context_menu("ctx", area, &items);
+            button_clicked |= ui.button("under", "Under", under);
+            picked
+        });
+        assert_eq!(picked, Some(1));
+        assert!(!button_clicked);
+        assert!(!ui.is_popup_open("ctx"));
+        assert!(!ui.wants_keyboard());
+    }
+
+    #[test]
+    fn test_context_menu_reports_pick_on_release_frame() {
+        let mut ui = UIContext::new();
+        let mut input = InputHandler::new();
+        let area = Rect::new(0.0, 0.0, 400.0, 400.0);
+        let items = ["Duplicate", "Delete"];
+
+        input.mouse_mut().update_position(100.0, 100.0);
+        input.mouse_mut().handle_button_press(MouseButton::Right);
+        frame(&mut ui, &mut input, |ui| ui.context_menu("ctx", area, &items));
+        input.mouse_mut().handle_button_release(MouseButton::Right);
+
+        let target = Vec2::new(110.0, 100.0 + 24.0 + 12.0);
+        input.mouse_mut().update_position(target.x, target.y);
+        input.mouse_mut().handle_button_press(MouseButton::Left);
+        let pressed = frame(&mut ui, &mut input, |ui| {
+            let picked = ui.context_menu("ctx", area, &items);
+            assert!(ui.is_input_blocked_at(target));
+            picked
+        });
+        assert_eq!(pressed, None);
+        input.mouse_mut().handle_button_release(MouseButton::Left);
+        ui.begin_frame(&input, WINDOW);
+        let released = ui.context_menu("ctx", area, &items);
+        ui.end_frame();
+        assert_eq!(released, Some(1));
+    }
+
+    #[test]
+    fn test_context_menu_near_window_edge_stays_on_screen() {
+        let ui = UIContext::new();
+        let placed = ui.place_popup(Vec2::new(790.0, 595.0), Vec2::new(140.0, 48.0), None);
+        assert_eq!(placed.right(), WINDOW.x);
+        assert_eq!(placed.bottom(), WINDOW.y);
+    }
+
+    #[test]
+    fn test_context_menu_escape_dismisses_and_releases_focus() {
+        let mut ui = UIContext::new();
+        let mut input = InputHandler::new();
+        let area = Rect::new(0.0, 0.0, 400.0, 400.0);
+
+        input.mouse_mut().update_position(50.0, 50.0);
+        input.mouse_mut().handle_button_press(MouseButton::Right);
+        frame(&mut ui, &mut input, |ui| ui.context_menu("ctx", area, &["Rename"]));
+        input.mouse_mut().handle_button_release(MouseButton::Right);
+
+        key(&mut ui, &mut input, KeyCode::Escape, |ui| ui.context_menu("ctx", area, &["Rename"]));
+        assert!(!ui.is_popup_open("ctx"));
+        assert!(!ui.wants_keyboard());
+    }
+}
@@ -26,6 +26,55 @@ fn test_ui_context_set_theme() {
     assert_ne!(ui.theme().button.background.r, original_bg.r);
 }
 
+/// Background fill of the first rect command drawn by `draw`.
+fn first_rect_color(ui: &mut UIContext, draw: impl FnOnce(&mut UIContext)) -> Color {
+    ui.begin_frame(&InputHandler::new(), Vec2::new(800.0, 600.0));
+    draw(ui);
+    ui.end_frame();
+    ui.draw_list()
+        .commands()
+        .iter()
+        .find_map(|cmd| match cmd {
+            DrawCommand::Rect { color, .. } => Some(*color),
+            _ => None,
+        })
+        .unwrap()
+}
+
+#[test]
+fn test_widget_style_override_applies_to_that_widget_only() {
+    let mut ui = UIContext::new();
+    let red = ButtonStyle { background: Color::RED, ..ui.theme().button.clone() };
+    ui.set_widget_style("quit", WidgetStyle { button: Some(red), ..Default::default() });
+
+    // Away from the mouse (at the origin) so the buttons draw unhovered
+    let bounds = Rect::new(100.0, 100.0, 80.0, 30.0);
+    assert_eq!(first_rect_color(&mut ui, |ui| { ui.button("quit", "Quit", bounds); }), Color::RED);
+    let plain = first_rect_color(&mut ui, |ui| { ui.button("play", "Play", bounds); });
+    assert_eq!(plain, Theme::default().button.background);
+
+    assert!(ui.clear_widget_style("quit").is_some());
+    let cleared = first_rect_color(&mut ui, |ui| { ui.button("quit", "Quit", bounds); });
+    assert_eq!(cleared, Theme::default().button.background);
+}
+
+#[test]
+fn test_theme_overrides_resolve_on_set_theme() {
+    let mut theme = Theme::light();
+    let slider = SliderStyle { track_background: Color::BLUE, ..theme.slider.clone() };
+    theme.overrides.insert("volume".into(), WidgetStyle { slider: Some(slider), ..Default::default() });
+
+    let mut ui = UIContext::new();
+    ui.set_theme(theme);
+    let track = first_rect_color(&mut ui, |ui| { ui.slider("volume", 0.5, Rect::new(0.0, 0.0, 100.0, 20.0)); });
+    assert_eq!(track, Color::BLUE);
+
+    // Swapping themes at runtime drops the old theme's overrides
+    ui.set_theme(Theme::dark());
+    let track = first_rect_color(&mut ui, |ui| { ui.slider("volume", 0.5, Rect::new(0.0, 0.0, 100.0, 20.0)); });
+    assert_eq!(track, Theme::dark().slider.track_background);
+}
+
 #[test]
 fn test_ui_context_window_size() {
     let ui = UIContext::new();
@@ -46,6 +95,99 @@ fn test_ui_context_label() {
     }
 }
 
+/// UI context with the example font loaded as the default font.
+fn ui_with_font() -> UIContext {
+    let path = concat!(env!("CARGO_MANIFEST_DIR"), "/../../examples/assets/fonts/font.ttf");
+    let mut ui = UIContext::new();
+    let data = std::fs::read(path).expect("example font is checked in");
+    ui.load_font(&data).expect("example font parses");
+    ui
+}
+
+fn text_commands(ui: &UIContext) -> Vec<&crate::TextDrawData> {
+    ui.draw_list().commands().iter().filter_map(|c| match c {
+        DrawCommand::Text { data, .. } => Some(data),
+        _ => None,
+    }).collect()
+}
+
+#[test]
+fn test_rich_label_without_font_draws_plain_placeholder() {
+    let mut ui = UIContext::new();
+    ui.rich_label("[b]Gold[/b] [icon=coin]x", Vec2::new(10.0, 20.0));
+    match ui.draw_list().commands() {
+        [DrawCommand::TextPlaceholder { text, .. }] => assert_eq!(text, "Gold x"),
+        other => panic!("Expected one placeholder, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_rich_label_emits_a_command_per_span_and_icon() {
+    let mut ui = ui_with_font();
+    ui.register_icon("coin", 7);
+    ui.rich_label("Hi [color=#FF0000]there[/color] [icon=coin] [icon=missing]", Vec2::new(10.0, 40.0));
+
+    let texts = text_commands(&ui);
+    assert_eq!(texts.len(), 2, "whitespace-only spans draw nothing");
+    assert_eq!(texts[0].color, ui.theme().text.color);
+    assert_eq!(texts[1].color, Color::from_hex(0xFF0000));
+    let there_left = texts[1].glyphs.iter().map(|g| g.x).fold(f32::MAX, f32::min);
+    assert!(there_left > texts[0].glyphs.iter().map(|g| g.x).fold(f32::MIN, f32::max));
+
+    let images: Vec<_> = ui.draw_list().commands().iter().filter_map(|c| match c {
+        DrawCommand::Image { bounds, texture_id, .. } => Some((*bounds, *texture_id)),
+        _ => None,
+    }).collect();
+    assert_eq!(images.len(), 1, "unregistered icons are skipped");
+    let (bounds, texture_id) = images[0];
+    assert_eq!(texture_id, 7);
+    assert!((bounds.y + bounds.height - 40.0).abs() < 0.01, "icon sits on the baseline");
+}
+
+#[test]
+fn test_icon_images_queue_until_registered_as_atlas_regions() {
+    let mut ui = UIContext::new();
+    ui.register_icon_image("folder", 2, 2, vec![255; 16]);
+    ui.register_icon_image("folder", 1, 1, vec![255; 4]);
+    ui.register_icon_image("bad", 2, 2, vec![255; 3]);
+    let bounds = Rect::new(0.0, 0.0, 16.0, 16.0);
+    assert!(!ui.draw_icon("folder", bounds, Color::WHITE), "not packed yet");
+
+    let images = ui.take_icon_images();
+    assert_eq!(images.len(), 1, "re-registering replaces, bad pixels are dropped");
+    assert_eq!((images[0].width, images[0].height), (1, 1));
+    assert!(ui.take_icon_images().is_empty());
+
+    let uv = Rect::new(0.5, 0.25, 0.125, 0.125);
+    ui.register_icon_region("folder", 3, uv);
+    assert!(ui.draw_icon("folder", bounds, Color::WHITE));
+    match ui.draw_list().commands() {
+        [DrawCommand::Image { texture_id: 3, uv_rect, .. }] => assert_eq!(*uv_rect, uv),
+        other => panic!("Expected one icon image, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_rich_label_faux_bold_stamps_each_glyph_twice() {
+    let mut ui = ui_with_font();
+    ui.rich_label("[b]ab[/b]", Vec2::ZERO);
+    let texts = text_commands(&ui);
+    assert_eq!(texts.len(), 1);
+    assert_eq!(texts[0].glyphs.len(), 4);
+}
+
+#[test]
+fn test_label_wrapped_moves_later_words_down() {
+    let mut ui = ui_with_font();
+    ui.label_wrapped("one two three four five six", Vec2::ZERO, 60.0);
+    let texts = text_commands(&ui);
+    assert_eq!(texts.len(), 1);
+    let first_y = texts[0].glyphs[0].y;
+    let last_y = texts[0].glyphs.last().map(|g| g.y).unwrap_or(first_y);
+    assert!(last_y > first_y, "later words wrap onto lower lines");
+    assert!(texts[0].glyphs.iter().all(|g| g.x < 60.0));
+}
+
 #[test]
 fn test_ui_context_panel() {
     let mut ui = UIContext::new();
@@ -447,3 +589,78 @@ fn test_float_input_commit_clamps_to_range() {
     ui.end_frame();
     assert_eq!(committed, 10.0, "99 must clamp to the max of 10");
 }
+
+#[test]
+fn test_mixed_float_input_reports_commit_of_same_value() {
+    use input::prelude::{KeyCode, MouseButton};
+    let mut ui = UIContext::new();
+    let mut input = input::InputHandler::new();
+    let bounds = Rect::new(10.0, 10.0, 80.0, 20.0);
+    let frame = |ui: &mut UIContext, input: &input::InputHandler| {
+        ui.begin_frame(input, Vec2::new(800.0, 600.0));
+        ui.mark_mixed("mixed");
+        let out = ui.float_input("mixed", 7.0, 0.0, 10.0, bounds);
+        let committed = ui.mixed_committed("mixed");
+        ui.end_frame();
+        (out, committed)
+    };
+
+    input.mouse_mut().update_position(50.0, 20.0);
+    input.mouse_mut().handle_button_press(MouseButton::Left);
+    frame(&mut ui, &input);
+    input.update();
+    input.mouse_mut().handle_button_release(MouseButton::Left);
+    assert_eq!(frame(&mut ui, &input), (7.0, false));
+
+    // Typing the value the input already shows still counts as a commit.
+    for key in [KeyCode::Digit7, KeyCode::Enter] {
+        input.update();
+        input.keyboard_mut().handle_key_press(key);
+        let result = frame(&mut ui, &input);
+        input.keyboard_mut().handle_key_release(key);
+        if key == KeyCode::Enter {
+            assert_eq!(result, (7.0, true));
+        }
+    }
+
+    // A mark lasts one frame.
+    ui.begin_frame(&input, Vec2::new(800.0, 600.0));
+    assert!(!ui.mixed_committed("mixed"));
+}
+
+#[test]
+fn test_text_input_commits_typed_text_once() {
+    use input::prelude::KeyCode;
+    let mut ui = UIContext::new();
+    let mut input = input::InputHandler::new();
+    let bounds = Rect::new(10.0, 10.0, 120.0, 20.0);
+    let mut frame = |ui: &mut UIContext, text: &str, key: Option<KeyCode>| {
+        input.update();
+        input.keyboard_mut().handle_text(text);
+        if let Some(key) = key {
+            input.keyboard_mut().handle_key_press(key);
+        }
+        ui.begin_frame(&input, Vec2::new(800.0, 600.0));
+        let out = ui.text_input("name", "Player", bounds);
+        ui.end_frame();
+        if let Some(key) = key {
+            input.keyboard_mut().handle_key_release(key);
+        }
+        out
+    };
+
+    // Focusing selects "Player", so typing replaces it
+    ui.focus_text_input("name", "Player");
+    assert!(ui.is_editing("name"));
+    assert_eq!(frame(&mut ui, "Boss", None), None);
+    assert_eq!(frame(&mut ui, "_2", None), None);
+    assert_eq!(frame(&mut ui, "", Some(KeyCode::Enter)).as_deref(), Some("Boss_2"));
+    assert!(!ui.is_editing("name"));
+
+    // Escape discards, and committing the unchanged text reports nothing
+    ui.focus_text_input("name", "Player");
+    frame(&mut ui, "x", None);
+    assert_eq!(frame(&mut ui, "", Some(KeyCode::Escape)), None);
+    ui.focus_text_input("name", "Player");
+    assert_eq!(frame(&mut ui, "", Some(KeyCode::Enter)), None);
+}
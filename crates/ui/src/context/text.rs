@@ -3,7 +3,8 @@
 
 use glam::Vec2;
 
-use crate::{Color, FontHandle, GlyphDrawData, Rect, TextDrawData, TextLayout};
+use crate::rich_text::{parse_rich_text, RichSegment};
+use crate::{Color, FontHandle, GlyphDrawData, LayoutRun, Rect, TextDrawData, TextLayout};
 
 use super::{TextAlign, UIContext};
 
@@ -104,16 +105,7 @@ impl UIContext {
         color: Color,
         font_size: f32,
     ) -> TextDrawData {
-        let glyphs: Vec<GlyphDrawData> = layout.glyphs.iter().map(|g| {
-            GlyphDrawData {
-                bitmap: g.info.rasterized.bitmap.clone(),
-                width: g.info.rasterized.width,
-                height: g.info.rasterized.height,
-                x: g.x,
-                y: g.y,
-                character: g.character,
-            }
-        }).collect();
+        let glyphs = layout.glyphs.iter().map(GlyphDrawData::from).collect();
 
         TextDrawData {
             text: text.to_string(),
@@ -126,6 +118,98 @@ impl UIContext {
         }
     }
 
+    /// Lay out rich-text markup at a baseline position, optionally wrapped,
+    /// emitting one text command per styled span and an image per icon.
+    ///
+    /// Without a loaded font the markup's plain text is drawn as a
+    /// placeholder. Icons that were never registered are skipped.
+    fn draw_rich_text(&mut self, markup: &str, position: Vec2, max_width: Option<f32>, color: Color, font_size: f32) {
+        let segments = parse_rich_text(markup);
+        let Some(font) = self.font_manager.default_font() else {
+            let plain: String = segments.iter().filter_map(|s| match s {
+                RichSegment::Text(span) => Some(span.text.as_str()),
+                RichSegment::Icon(_) => None,
+            }).collect();
+            self.draw_list.text_placeholder(&plain, position, color, font_size);
+            return;
+        };
+
+        let icon_size = self.baseline_y(0.0, font_size, Some(font));
+        let mut runs = Vec::with_capacity(segments.len());
+        // Per run: span color and faux-bold flag, or the icon texture and UV
+        let mut styles: Vec<Result<(Color, bool), (u32, Rect)>> = Vec::with_capacity(segments.len());
+        for segment in &segments {
+            match segment {
+                RichSegment::Text(span) => {
+                    let (run_font, faux_bold) = match (span.bold, self.bold_font) {
+                        (true, Some(bold)) => (bold, false),
+                        (bold, _) => (font, bold),
+                    };
+                    runs.push(LayoutRun::Text { text: &span.text, font: run_font });
+                    styles.push(Ok((span.color.unwrap_or(color), faux_bold)));
+                }
+                RichSegment::Icon(name) => match self.icons.get(name) {
+                    Some(&icon) => {
+                        runs.push(LayoutRun::Inline { width: icon_size });
+                        styles.push(Err(icon));
+                    }
+                    None => log::debug!("Rich text icon '{}' is not registered", name),
+                },
+            }
+        }
+
+        let layout = match self.font_manager.layout_runs(&runs, font_size, max_width) {
+            Ok(layout) => layout,
+            Err(e) => {
+                log::warn!("Font layout failed: {}", e);
+                self.draw_list.text_placeholder(markup, position, color, font_size);
+                return;
+            }
+        };
+
+        /// Horizontal offset of the second stamp of a faux-bold glyph, as a
+        /// fraction of font size (at least one pixel).
+        const FAUX_BOLD_FACTOR: f32 = 1.0 / 16.0;
+        let bold_offset = (font_size * FAUX_BOLD_FACTOR).max(1.0);
+        let mut inlines = layout.inlines.iter();
+        for (index, (run, style)) in runs.iter().zip(&styles).enumerate() {
+            match (run, style) {
+                (LayoutRun::Text { text, .. }, Ok((span_color, faux_bold))) => {
+                    let mut glyphs: Vec<GlyphDrawData> = layout.glyphs.iter()
+                        .filter(|g| g.run == index)
+                        .map(GlyphDrawData::from)
+                        .collect();
+                    if glyphs.is_empty() {
+                        continue;
+                    }
+                    if *faux_bold {
+                        let stamps: Vec<GlyphDrawData> = glyphs.iter()
+                            .map(|g| GlyphDrawData { x: g.x + bold_offset, ..g.clone() })
+                            .collect();
+                        glyphs.extend(stamps);
+                    }
+                    self.draw_list.text(TextDrawData {
+                        text: text.to_string(),
+                        position,
+                        color: *span_color,
+                        font_size,
+                        width: layout.width,
+                        height: layout.height,
+                        glyphs,
+                    });
+                }
+                (LayoutRun::Inline { .. }, Err((texture_id, uv_rect))) => {
+                    if let Some(inline) = inlines.find(|i| i.run == index) {
+                        let top = position.y + inline.baseline - inline.width;
+                        let bounds = Rect::new(position.x + inline.x, top, inline.width, inline.width);
+                        self.draw_list.image_region(bounds, *texture_id, *uv_rect, Color::WHITE);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
     // ================== Label Methods ==================
 
     /// Create a text label.
@@ -150,6 +234,43 @@ impl UIContext {
         self.draw_text_with_font(Some(font), text, position, color, font_size);
     }
 
+    /// Create a text label word-wrapped to `max_width` pixels.
+    ///
+    /// `position` is the baseline of the first line; further lines follow
+    /// at the font's line height. `\n` forces a break.
+    pub fn label_wrapped(&mut self, text: &str, position: Vec2, max_width: f32) {
+        let color = self.theme.text.color;
+        let font_size = self.theme.text.font_size;
+        if let Some(font) = self.font_manager.default_font() {
+            match self.font_manager.layout_text_wrapped(font, text, font_size, max_width) {
+                Ok(layout) => {
+                    let text_data = Self::layout_to_draw_data(&layout, text, position, color, font_size);
+                    self.draw_list.text(text_data);
+                    return;
+                }
+                Err(e) => log::warn!("Font layout failed: {}", e),
+            }
+        }
+        self.draw_list.text_placeholder(text, position, color, font_size);
+    }
+
+    /// Create a rich-text label from markup (see [`parse_rich_text`](crate::parse_rich_text)
+    /// for the tags): colored and bold spans plus inline icons, laid out on
+    /// one baseline at `position`.
+    pub fn rich_label(&mut self, markup: &str, position: Vec2) {
+        let color = self.theme.text.color;
+        let font_size = self.theme.text.font_size;
+        self.draw_rich_text(markup, position, None, color, font_size);
+    }
+
+    /// Create a rich-text label word-wrapped to `max_width` pixels. Icons
+    /// wrap like words.
+    pub fn rich_label_wrapped(&mut self, markup: &str, position: Vec2, max_width: f32) {
+        let color = self.theme.text.color;
+        let font_size = self.theme.text.font_size;
+        self.draw_rich_text(markup, position, Some(max_width), color, font_size);
+    }
+
     /// Draw a label centered within bounds.
     ///
     /// This method handles vertical centering automatically using font metrics
@@ -1,16 +1,18 @@
-//! Numeric text-input widget with a real editing model: click-to-focus
-//! selects the whole value, a visible cursor, arrow/Home/End navigation,
-//! shift-selection, and editing at the cursor position.
+//! Numeric and free-text input widgets with a real editing model:
+//! click-to-focus selects the whole value, a visible cursor, arrow/Home/End
+//! navigation, shift-selection, and editing at the cursor position.
 //!
 //! The editing rules live in [`crate::TextEditState`]; this file translates
 //! input-state flags into edit calls and draws the box/selection/caret.
 
-use crate::{Rect, TextEditState, WidgetId, WidgetState};
+use crate::{InputState, Rect, TextEditState, WidgetId, WidgetState};
 
 use super::{TextAlign, UIContext};
 
 /// Caret width in pixels.
 const CARET_WIDTH: f32 = 1.0;
+/// Shown in place of the value by a mixed-marked input.
+const MIXED_TEXT: &str = "—";
 
 impl UIContext {
     /// Create a float text input field.
@@ -23,6 +25,8 @@ impl UIContext {
     /// Escape cancels.
     ///
     /// Returns the current value (unchanged while editing, new value on commit).
+    /// See [`mark_mixed`](Self::mark_mixed) for inputs standing for several
+    /// values.
     pub fn float_input(
         &mut self,
         id: impl Into<WidgetId>,
@@ -34,17 +38,18 @@ impl UIContext {
         let id = id.into();
         let result = self.interaction.interact(id, bounds, true);
         let was_focused = self.interaction.is_focused(id);
+        let mixed = self.is_mixed(id);
 
         // Snapshot keyboard/mouse state before mutating persistent state
         let input = self.interaction.input().clone();
         let mouse_in_bounds = bounds.contains(input.mouse_pos);
-        let padding = self.theme.text_input.padding;
-        let font_size = self.theme.text_input.font_size;
+        let style = self.text_input_style(id);
+        let (padding, font_size) = (style.padding, style.font_size);
 
         if result.clicked && !was_focused {
             // Enter edit mode with the whole value selected — typing replaces it
             self.interaction.set_focus(id);
-            let text = format!("{:.2}", value);
+            let text = if mixed { String::new() } else { format!("{:.2}", value) };
             self.interaction.get_state(id).edit.set_text_select_all(&text);
         } else if result.clicked && was_focused {
             // Click inside while editing: place the cursor at the click
@@ -58,7 +63,7 @@ impl UIContext {
             // Cancel on Escape
             if input.escape_pressed {
                 self.interaction.clear_focus();
-                return self.draw_float_value(bounds, value, false);
+                return self.draw_float_value(id, bounds, value, mixed, false);
             }
 
             // Commit on Enter, Tab, or click outside
@@ -66,55 +71,125 @@ impl UIContext {
                 return self.commit_float_input(id, value, min, max, bounds);
             }
 
-            // Navigation, deletion, then typed characters — all cursor-aware
-            let state = &mut self.interaction.get_state(id).edit;
-            if input.left_pressed {
-                state.move_left(input.shift_down);
-            }
-            if input.right_pressed {
-                state.move_right(input.shift_down);
-            }
-            if input.home_pressed {
-                state.home(input.shift_down);
-            }
-            if input.end_pressed {
-                state.end(input.shift_down);
-            }
-            if input.backspace_pressed {
-                state.backspace();
-            }
-            if input.delete_pressed {
-                state.delete();
-            }
-            for ch in &input.typed_chars {
-                state.insert_char(*ch);
-            }
-
-            let edit = self.interaction.get_state(id).edit.clone();
-            self.draw_float_input_editing(bounds, &edit);
+            let edit = self.apply_edit_keys(id, &input, &input.typed_chars);
+            self.draw_input_editing(id, bounds, &edit);
             return value; // Return original while editing
         }
 
         // Not focused — draw display value
         let hovered = result.state == WidgetState::Hovered;
-        self.draw_float_value(bounds, value, hovered)
+        self.draw_float_value(id, bounds, value, mixed, hovered)
+    }
+
+    /// Create a single-line free-text input field, edited like
+    /// [`float_input`](Self::float_input) but taking any typed text: click
+    /// to focus with the text selected, Enter/Tab or clicking outside
+    /// commits, Escape cancels.
+    ///
+    /// Returns the committed text on the frame an edit commits with a
+    /// different value, `None` otherwise.
+    pub fn text_input(&mut self, id: impl Into<WidgetId>, value: &str, bounds: Rect) -> Option<String> {
+        let id = id.into();
+        let result = self.interaction.interact(id, bounds, true);
+        let was_focused = self.interaction.is_focused(id);
+        let input = self.interaction.input().clone();
+        let font_size = self.text_input_style(id).font_size;
+        let padding = self.text_input_style(id).padding;
+
+        if result.clicked && !was_focused {
+            self.focus_text_input(id, value);
+        } else if result.clicked && was_focused {
+            let text = self.interaction.get_state(id).edit.text.clone();
+            let widths = self.prefix_widths(&text, font_size);
+            let local_x = input.mouse_pos.x - (bounds.x + padding);
+            self.interaction.get_state(id).edit.cursor_from_click(&widths, local_x);
+        }
+
+        if !self.interaction.is_focused(id) {
+            let hovered = result.state == WidgetState::Hovered;
+            self.draw_input_box(id, bounds, value, hovered);
+            return None;
+        }
+        if input.escape_pressed {
+            self.interaction.clear_focus();
+            self.draw_input_box(id, bounds, value, false);
+            return None;
+        }
+        if input.enter_pressed || input.tab_pressed || (input.mouse_just_pressed && !bounds.contains(input.mouse_pos)) {
+            let text = std::mem::take(&mut self.interaction.get_state(id).edit.text);
+            self.interaction.clear_focus();
+            self.draw_input_box(id, bounds, &text, false);
+            return (text != value).then_some(text);
+        }
+
+        let edit = self.apply_edit_keys(id, &input, &input.typed_text);
+        self.draw_input_editing(id, bounds, &edit);
+        None
+    }
+
+    /// Start editing text input `id` without a click (e.g. rename on
+    /// double-click): focus it with `value` selected, so typing replaces it.
+    pub fn focus_text_input(&mut self, id: impl Into<WidgetId>, value: &str) {
+        let id = id.into();
+        self.interaction.set_focus(id);
+        self.interaction.get_state(id).edit.set_text_select_all(value);
+    }
+
+    /// Whether input `id` has keyboard focus (is being edited).
+    pub fn is_editing(&self, id: impl Into<WidgetId>) -> bool {
+        self.interaction.is_focused(id.into())
+    }
+
+    /// Apply this frame's navigation, deletion, then typed `chars` to the
+    /// edit buffer of `id` — all cursor-aware. Returns the updated buffer.
+    fn apply_edit_keys(&mut self, id: WidgetId, input: &InputState, chars: &[char]) -> TextEditState {
+        let state = &mut self.interaction.get_state(id).edit;
+        if input.left_pressed {
+            state.move_left(input.shift_down);
+        }
+        if input.right_pressed {
+            state.move_right(input.shift_down);
+        }
+        if input.home_pressed {
+            state.home(input.shift_down);
+        }
+        if input.end_pressed {
+            state.end(input.shift_down);
+        }
+        if input.backspace_pressed {
+            state.backspace();
+        }
+        if input.delete_pressed {
+            state.delete();
+        }
+        for &ch in chars {
+            state.insert_char(ch);
+        }
+        state.clone()
     }
 
     /// Commit the edit buffer of a float input: parse (falling back to the
     /// pre-edit value), clamp, unfocus, and draw the committed value.
     fn commit_float_input(&mut self, id: WidgetId, fallback: f32, min: f32, max: f32, bounds: Rect) -> f32 {
-        let new_value = self.interaction.get_state(id).edit.text
-            .parse::<f32>()
-            .unwrap_or(fallback)
-            .clamp(min, max);
+        let parsed = self.interaction.get_state(id).edit.text.parse::<f32>().ok();
         self.interaction.clear_focus();
-        self.draw_float_value(bounds, new_value, false)
+        let mixed = match (self.mixed_widgets.get_mut(&id), parsed) {
+            (Some(committed), Some(_)) => {
+                *committed = true;
+                false
+            }
+            (Some(_), None) => true,
+            (None, _) => false,
+        };
+        let new_value = parsed.unwrap_or(fallback).clamp(min, max);
+        self.draw_float_value(id, bounds, new_value, mixed, false)
     }
 
-    /// Draw a float input showing a numeric value; returns the value for
-    /// tail-call convenience.
-    fn draw_float_value(&mut self, bounds: Rect, value: f32, highlighted: bool) -> f32 {
-        self.draw_float_input_box(bounds, &format!("{:.2}", value), highlighted);
+    /// Draw a float input showing a numeric value (or "—" when `mixed`);
+    /// returns the value for tail-call convenience.
+    fn draw_float_value(&mut self, id: WidgetId, bounds: Rect, value: f32, mixed: bool, highlighted: bool) -> f32 {
+        let text = if mixed { MIXED_TEXT.to_string() } else { format!("{:.2}", value) };
+        self.draw_input_box(id, bounds, &text, highlighted);
         value
     }
 
@@ -132,10 +207,10 @@ impl UIContext {
         widths
     }
 
-    /// Draw a focused float input: box, selection band, text, and caret,
+    /// Draw a focused input: box, selection band, text, and caret,
     /// clipped to the bounds so long edits don't overflow.
-    fn draw_float_input_editing(&mut self, bounds: Rect, edit: &TextEditState) {
-        let style = self.theme.text_input.clone();
+    fn draw_input_editing(&mut self, id: WidgetId, bounds: Rect, edit: &TextEditState) {
+        let style = self.text_input_style(id).clone();
 
         self.draw_list.rect_rounded(bounds, style.background_focused, style.corner_radius);
         self.draw_list
@@ -173,9 +248,9 @@ impl UIContext {
         self.pop_clip_rect();
     }
 
-    /// Draw a float input text box (shared by unfocused and committed states).
-    fn draw_float_input_box(&mut self, bounds: Rect, text: &str, highlighted: bool) {
-        let style = self.theme.text_input.clone();
+    /// Draw an input's text box (shared by unfocused and committed states).
+    fn draw_input_box(&mut self, id: WidgetId, bounds: Rect, text: &str, highlighted: bool) {
+        let style = self.text_input_style(id).clone();
         let bg = if highlighted { style.background_focused } else { style.background };
         let border = if highlighted { style.border_focused } else { style.border };
 
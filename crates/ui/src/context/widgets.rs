@@ -4,16 +4,15 @@
 
 use glam::Vec2;
 
-use crate::{Color, Rect, WidgetId, WidgetState};
+use crate::{ButtonStyle, Color, Rect, WidgetId, WidgetState};
 
 use super::{TextAlign, UIContext};
 
 impl UIContext {
     // ================== Widget Helpers ==================
 
-    /// Get the background color for a widget based on its state and the button style
-    fn widget_background_color(&self, state: WidgetState) -> Color {
-        let style = &self.theme.button;
+    /// Get the background color for a widget based on its state and button style
+    pub(super) fn widget_background_color(style: &ButtonStyle, state: WidgetState) -> Color {
         match state {
             WidgetState::Normal => style.background,
             WidgetState::Hovered => style.background_hovered,
@@ -46,8 +45,8 @@ impl UIContext {
     ) -> bool {
         let id = id.into();
         let result = self.interaction.interact(id, bounds, enabled);
-        let style = &self.theme.button;
-        let background = self.widget_background_color(result.state);
+        let style = self.button_style(id);
+        let background = Self::widget_background_color(style, result.state);
         let text_color = if enabled {
             style.text_color
         } else {
@@ -120,7 +119,7 @@ impl UIContext {
         bounds: Rect,
     ) -> f32 {
         let id = id.into();
-        let style = &self.theme.slider;
+        let style = self.slider_style(id).clone();
 
         // Normalize value to 0-1 range
         let normalized = ((value - min) / (max - min)).clamp(0.0, 1.0);
@@ -171,8 +170,8 @@ impl UIContext {
     pub fn checkbox(&mut self, id: impl Into<WidgetId>, checked: bool, bounds: Rect) -> bool {
         let id = id.into();
         let result = self.interaction.interact(id, bounds, true);
-        let style = &self.theme.button;
-        let background = self.widget_background_color(result.state);
+        let style = self.button_style(id).clone();
+        let background = Self::widget_background_color(&style, result.state);
 
         // Draw checkbox background
         self.draw_list
@@ -182,8 +181,16 @@ impl UIContext {
         self.draw_list
             .rect_border_rounded(bounds, style.border, style.border_width, style.corner_radius);
 
-        // Draw check mark if checked
-        if checked {
+        // Draw check mark if checked, a dash if mixed
+        if self.is_mixed(id) {
+            let dash = Rect::new(
+                bounds.x + bounds.width * 0.25,
+                bounds.y + bounds.height * 0.42,
+                bounds.width * 0.5,
+                bounds.height * 0.16,
+            );
+            self.draw_list.rect(dash, style.text_color);
+        } else if checked {
             let inner = bounds.shrink(bounds.width * 0.25);
             self.draw_list
                 .rect_rounded(inner, style.text_color, style.corner_radius * 0.5);
@@ -0,0 +1,59 @@
+//! Textured image commands on [`DrawList`]: whole textures, UV
+//! sub-regions (atlas icons) and rounded images.
+
+use crate::{Color, Rect};
+
+use super::{DrawCommand, DrawList};
+
+/// Normalized UV rect covering a whole texture (for [`DrawCommand::Image`]).
+pub const FULL_UV: Rect = Rect::new(0.0, 0.0, 1.0, 1.0);
+
+impl DrawList {
+    /// Add a textured image by renderer texture id.
+    pub fn image(&mut self, bounds: Rect, texture_id: u32, tint: Color) {
+        self.image_rounded(bounds, texture_id, tint, 0.0);
+    }
+
+    /// Add a textured image sampling only `uv_rect` (normalized) of the texture.
+    pub fn image_region(&mut self, bounds: Rect, texture_id: u32, uv_rect: Rect, tint: Color) {
+        self.commands.push(DrawCommand::Image {
+            bounds,
+            texture_id,
+            uv_rect,
+            tint,
+            corner_radius: 0.0,
+            depth: self.next_depth(),
+        });
+    }
+
+    /// Add a textured image with rounded corners.
+    pub fn image_rounded(&mut self, bounds: Rect, texture_id: u32, tint: Color, corner_radius: f32) {
+        self.commands.push(DrawCommand::Image {
+            bounds,
+            texture_id,
+            uv_rect: FULL_UV,
+            tint,
+            corner_radius,
+            depth: self.next_depth(),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_draw_list_image_region() {
+        let mut list = DrawList::new();
+        list.image(Rect::new(0.0, 0.0, 32.0, 32.0), 4, Color::WHITE);
+        let uv = Rect::new(0.5, 0.0, 0.25, 0.5);
+        list.image_region(Rect::new(0.0, 0.0, 32.0, 32.0), 4, uv, Color::WHITE);
+
+        let uvs: Vec<Rect> = list.commands().iter().filter_map(|cmd| match cmd {
+            DrawCommand::Image { uv_rect, .. } => Some(*uv_rect),
+            _ => None,
+        }).collect();
+        assert_eq!(uvs, [FULL_UV, uv]);
+    }
+}
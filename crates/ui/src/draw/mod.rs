@@ -5,7 +5,11 @@
 
 use std::sync::Arc;
 use glam::Vec2;
-use crate::{Color, Rect};
+use crate::{Color, LayoutGlyph, Rect};
+
+mod image;
+
+pub use image::FULL_UV;
 
 /// Data for rendering a single glyph.
 ///
@@ -25,6 +29,23 @@ pub struct GlyphDrawData {
     pub y: f32,
     /// The character this glyph represents
     pub character: char,
+    /// Font the glyph was rasterized from (part of its atlas cache key)
+    pub font_id: u32,
+}
+
+impl From<&LayoutGlyph> for GlyphDrawData {
+    /// Draw data for one laid-out glyph (shares its bitmap).
+    fn from(glyph: &LayoutGlyph) -> Self {
+        Self {
+            bitmap: glyph.info.rasterized.bitmap.clone(),
+            width: glyph.info.rasterized.width,
+            height: glyph.info.rasterized.height,
+            x: glyph.x,
+            y: glyph.y,
+            character: glyph.character,
+            font_id: glyph.font_id,
+        }
+    }
 }
 
 /// Data for rendering text with rasterized glyphs.
@@ -97,6 +118,9 @@ pub enum DrawCommand {
     Image {
         bounds: Rect,
         texture_id: u32,
+        /// Normalized texture sub-region to sample; [`FULL_UV`] for the
+        /// whole texture (atlas icons, sprite-sheet frames, minimap views)
+        uv_rect: Rect,
         tint: Color,
         corner_radius: f32,
         depth: f32,
@@ -141,12 +165,18 @@ const UI_BASE_DEPTH: f32 = 900.0;
 /// the submission order. The base band would need 50,000 commands to collide.
 const OVERLAY_DEPTH_BOOST: f32 = 50.0;
 
+/// Extra depth per nested overlay level (a context menu opened over an open
+/// dropdown). Nesting deeper than [`MAX_OVERLAY_LEVEL`] shares the top band
+/// so overlay depths always stay below the camera far plane.
+const OVERLAY_LEVEL_STEP: f32 = 15.0;
+const MAX_OVERLAY_LEVEL: u32 = 3;
+
 /// A draw list that collects all UI draw commands for a frame.
 #[derive(Debug, Clone, Default)]
 pub struct DrawList {
     commands: Vec<DrawCommand>,
-    /// Whether subsequent commands belong to the overlay depth band.
-    overlay: bool,
+    /// Overlay nesting level of subsequent commands (0 = base band).
+    overlay: u32,
 }
 
 impl DrawList {
@@ -154,7 +184,7 @@ impl DrawList {
     pub fn new() -> Self {
         Self {
             commands: Vec::new(),
-            overlay: false,
+            overlay: 0,
         }
     }
 
@@ -162,31 +192,51 @@ impl DrawList {
     /// Each command gets slightly increasing depth to maintain draw order.
     #[inline]
     fn next_depth(&self) -> f32 {
-        let boost = if self.overlay { OVERLAY_DEPTH_BOOST } else { 0.0 };
+        let boost = match self.overlay.min(MAX_OVERLAY_LEVEL) {
+            0 => 0.0,
+            level => OVERLAY_DEPTH_BOOST + (level - 1) as f32 * OVERLAY_LEVEL_STEP,
+        };
         UI_BASE_DEPTH + boost + self.commands.len() as f32 * 0.001
     }
 
     /// Record subsequent commands in the overlay depth band so they render
     /// on top of all base-band UI (panels, toolbars) regardless of submission
     /// order. Must be paired with [`end_overlay`](Self::end_overlay).
+    ///
+    /// Overlays nest: beginning an overlay inside another records into a
+    /// higher band, so a popup opened over an open dropdown draws above it.
     pub fn begin_overlay(&mut self) {
-        self.overlay = true;
+        self.overlay += 1;
     }
 
-    /// Return to the base depth band.
+    /// Leave the current overlay level (the base band once all are closed).
     pub fn end_overlay(&mut self) {
-        self.overlay = false;
+        self.overlay = self.overlay.saturating_sub(1);
+    }
+
+    /// Record the commands `draw` adds in the topmost overlay band, whatever
+    /// the current level. Drawn last in a frame, they cover everything.
+    pub fn topmost(&mut self, draw: impl FnOnce(&mut Self)) {
+        let level = self.overlay;
+        self.overlay = MAX_OVERLAY_LEVEL;
+        draw(self);
+        self.overlay = level;
     }
 
     /// Whether overlay mode is currently active.
     pub fn is_overlay(&self) -> bool {
+        self.overlay > 0
+    }
+
+    /// Current overlay nesting level (0 = base band).
+    pub fn overlay_level(&self) -> u32 {
         self.overlay
     }
 
     /// Clear all draw commands.
     pub fn clear(&mut self) {
         self.commands.clear();
-        self.overlay = false;
+        self.overlay = 0;
     }
 
     /// Get all draw commands.
@@ -235,22 +285,6 @@ impl DrawList {
         });
     }
 
-    /// Add a textured image by renderer texture id.
-    pub fn image(&mut self, bounds: Rect, texture_id: u32, tint: Color) {
-        self.image_rounded(bounds, texture_id, tint, 0.0);
-    }
-
-    /// Add a textured image with rounded corners.
-    pub fn image_rounded(&mut self, bounds: Rect, texture_id: u32, tint: Color, corner_radius: f32) {
-        self.commands.push(DrawCommand::Image {
-            bounds,
-            texture_id,
-            tint,
-            corner_radius,
-            depth: self.next_depth(),
-        });
-    }
-
     /// Add text placeholder (renders as approximate rectangle without font).
     pub fn text_placeholder(&mut self, text: impl Into<String>, position: Vec2, color: Color, font_size: f32) {
         self.commands.push(DrawCommand::TextPlaceholder {
@@ -331,191 +365,4 @@ impl DrawList {
 }
 
 #[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_draw_list_new() {
-        let list = DrawList::new();
-        assert!(list.is_empty());
-        assert_eq!(list.len(), 0);
-    }
-
-    #[test]
-    fn test_draw_list_rect() {
-        let mut list = DrawList::new();
-        list.rect(Rect::new(0.0, 0.0, 100.0, 50.0), Color::RED);
-        assert_eq!(list.len(), 1);
-
-        if let DrawCommand::Rect { bounds, color, corner_radius, .. } = &list.commands()[0] {
-            assert_eq!(bounds.width, 100.0);
-            assert_eq!(bounds.height, 50.0);
-            assert_eq!(*color, Color::RED);
-            assert_eq!(*corner_radius, 0.0);
-        } else {
-            panic!("Expected Rect command");
-        }
-    }
-
-    #[test]
-    fn test_draw_list_rect_rounded() {
-        let mut list = DrawList::new();
-        list.rect_rounded(Rect::new(0.0, 0.0, 100.0, 50.0), Color::BLUE, 8.0);
-
-        if let DrawCommand::Rect { corner_radius, .. } = &list.commands()[0] {
-            assert_eq!(*corner_radius, 8.0);
-        } else {
-            panic!("Expected Rect command");
-        }
-    }
-
-    #[test]
-    fn test_draw_list_text_placeholder() {
-        let mut list = DrawList::new();
-        list.text_placeholder("World", Vec2::new(50.0, 60.0), Color::RED, 24.0);
-
-        if let DrawCommand::TextPlaceholder { text, position, font_size, color, .. } = &list.commands()[0] {
-            assert_eq!(text, "World");
-            assert_eq!(*position, Vec2::new(50.0, 60.0));
-            assert_eq!(*font_size, 24.0);
-            assert_eq!(*color, Color::RED);
-        } else {
-            panic!("Expected TextPlaceholder command");
-        }
-    }
-
-    #[test]
-    fn test_draw_list_text_with_data() {
-        let mut list = DrawList::new();
-        let text_data = TextDrawData {
-            text: "Test".to_string(),
-            position: Vec2::new(100.0, 200.0),
-            color: Color::GREEN,
-            font_size: 32.0,
-            width: 80.0,
-            height: 32.0,
-            glyphs: vec![
-                GlyphDrawData {
-                    bitmap: Arc::from([255u8; 16]),
-                    width: 4,
-                    height: 4,
-                    x: 0.0,
-                    y: 0.0,
-                    character: 'T',
-                },
-            ],
-        };
-        list.text(text_data);
-
-        if let DrawCommand::Text { data, .. } = &list.commands()[0] {
-            assert_eq!(data.text, "Test");
-            assert_eq!(data.position, Vec2::new(100.0, 200.0));
-            assert_eq!(data.glyphs.len(), 1);
-            assert_eq!(data.glyphs[0].character, 'T');
-        } else {
-            panic!("Expected Text command");
-        }
-    }
-
-    #[test]
-    fn test_draw_list_circle() {
-        let mut list = DrawList::new();
-        list.circle(Vec2::new(50.0, 50.0), 25.0, Color::GREEN);
-
-        if let DrawCommand::Circle { center, radius, color, .. } = &list.commands()[0] {
-            assert_eq!(*center, Vec2::new(50.0, 50.0));
-            assert_eq!(*radius, 25.0);
-            assert_eq!(*color, Color::GREEN);
-        } else {
-            panic!("Expected Circle command");
-        }
-    }
-
-    #[test]
-    fn test_draw_list_clear() {
-        let mut list = DrawList::new();
-        list.rect(Rect::default(), Color::RED);
-        list.rect(Rect::default(), Color::BLUE);
-        assert_eq!(list.len(), 2);
-
-        list.clear();
-        assert!(list.is_empty());
-    }
-
-    #[test]
-    fn test_draw_command_depth() {
-        let cmd = DrawCommand::Rect {
-            bounds: Rect::default(),
-            color: Color::RED,
-            corner_radius: 0.0,
-            depth: 5.0,
-        };
-        assert_eq!(cmd.depth(), 5.0);
-    }
-
-    #[test]
-    fn test_draw_list_depth_ordering() {
-        let mut list = DrawList::new();
-        list.rect(Rect::default(), Color::RED);
-        list.rect(Rect::default(), Color::BLUE);
-        list.rect(Rect::default(), Color::GREEN);
-
-        // Each command should have increasing depth
-        let depths: Vec<f32> = list.commands().iter().map(|c| c.depth()).collect();
-        assert!(depths[0] < depths[1]);
-        assert!(depths[1] < depths[2]);
-    }
-
-    #[test]
-    fn test_overlay_commands_render_above_base_band() {
-        let mut list = DrawList::new();
-        list.rect(Rect::default(), Color::RED); // base band
-        list.begin_overlay();
-        list.rect(Rect::default(), Color::BLUE); // overlay band
-        list.rect(Rect::default(), Color::GREEN); // overlay band
-        list.end_overlay();
-        list.rect(Rect::default(), Color::RED); // base band again
-
-        let depths: Vec<f32> = list.commands().iter().map(|c| c.depth()).collect();
-        assert!(depths[0] < 950.0, "base command stays in base band");
-        assert!(depths[1] >= 950.0, "overlay command is boosted");
-        assert!(depths[2] > depths[1], "overlay band stays monotonic");
-        assert!(depths[3] < 950.0, "end_overlay returns to base band");
-        assert!(depths[3] > depths[0], "base band stays monotonic");
-    }
-
-    #[test]
-    fn test_clear_resets_overlay_mode() {
-        let mut list = DrawList::new();
-        list.begin_overlay();
-        assert!(list.is_overlay());
-
-        list.clear();
-        assert!(!list.is_overlay());
-
-        list.rect(Rect::default(), Color::RED);
-        assert!(list.commands()[0].depth() < 950.0);
-    }
-
-    #[test]
-    fn test_draw_list_clip_rect() {
-        let mut list = DrawList::new();
-        let bounds = Rect::new(10.0, 10.0, 100.0, 100.0);
-
-        list.push_clip_rect(bounds);
-        list.rect(Rect::new(20.0, 20.0, 50.0, 50.0), Color::RED);
-        list.pop_clip_rect();
-
-        assert_eq!(list.len(), 3);
-
-        // First command should be PushClipRect
-        if let DrawCommand::PushClipRect { bounds: clip_bounds } = &list.commands()[0] {
-            assert_eq!(clip_bounds.x, 10.0);
-        } else {
-            panic!("Expected PushClipRect");
-        }
-
-        // Last command should be PopClipRect
-        assert!(matches!(list.commands()[2], DrawCommand::PopClipRect));
-    }
-}
+mod tests;
@@ -0,0 +1,228 @@
+use super::*;
+
+#[test]
+fn test_draw_list_new() {
+    let list = DrawList::new();
+    assert!(list.is_empty());
+    assert_eq!(list.len(), 0);
+}
+
+#[test]
+fn test_draw_list_rect() {
+    let mut list = DrawList::new();
+    list.rect(Rect::new(0.0, 0.0, 100.0, 50.0), Color::RED);
+    assert_eq!(list.len(), 1);
+
+    if let DrawCommand::Rect { bounds, color, corner_radius, .. } = &list.commands()[0] {
+        assert_eq!(bounds.width, 100.0);
+        assert_eq!(bounds.height, 50.0);
+        assert_eq!(*color, Color::RED);
+        assert_eq!(*corner_radius, 0.0);
+    } else {
+        panic!("Expected Rect command");
+    }
+}
+
+#[test]
+fn test_draw_list_rect_rounded() {
+    let mut list = DrawList::new();
+    list.rect_rounded(Rect::new(0.0, 0.0, 100.0, 50.0), Color::BLUE, 8.0);
+
+    if let DrawCommand::Rect { corner_radius, .. } = &list.commands()[0] {
+        assert_eq!(*corner_radius, 8.0);
+    } else {
+        panic!("Expected Rect command");
+    }
+}
+
+#[test]
+fn test_draw_list_text_placeholder() {
+    let mut list = DrawList::new();
+    list.text_placeholder("World", Vec2::new(50.0, 60.0), Color::RED, 24.0);
+
+    if let DrawCommand::TextPlaceholder { text, position, font_size, color, .. } = &list.commands()[0] {
+        assert_eq!(text, "World");
+        assert_eq!(*position, Vec2::new(50.0, 60.0));
+        assert_eq!(*font_size, 24.0);
+        assert_eq!(*color, Color::RED);
+    } else {
+        panic!("Expected TextPlaceholder command");
+    }
+}
+
+#[test]
+fn test_draw_list_text_with_data() {
+    let mut list = DrawList::new();
+    let text_data = TextDrawData {
+        text: "Test".to_string(),
+        position: Vec2::new(100.0, 200.0),
+        color: Color::GREEN,
+        font_size: 32.0,
+        width: 80.0,
+        height: 32.0,
+        glyphs: vec![
+            GlyphDrawData {
+                bitmap: Arc::from([255u8; 16]),
+                width: 4,
+                height: 4,
+                x: 0.0,
+                y: 0.0,
+                character: 'T',
+                font_id: 1,
+            },
+        ],
+    };
+    list.text(text_data);
+
+    if let DrawCommand::Text { data, .. } = &list.commands()[0] {
+        assert_eq!(data.text, "Test");
+        assert_eq!(data.position, Vec2::new(100.0, 200.0));
+        assert_eq!(data.glyphs.len(), 1);
+        assert_eq!(data.glyphs[0].character, 'T');
+    } else {
+        panic!("Expected Text command");
+    }
+}
+
+#[test]
+fn test_draw_list_circle() {
+    let mut list = DrawList::new();
+    list.circle(Vec2::new(50.0, 50.0), 25.0, Color::GREEN);
+
+    if let DrawCommand::Circle { center, radius, color, .. } = &list.commands()[0] {
+        assert_eq!(*center, Vec2::new(50.0, 50.0));
+        assert_eq!(*radius, 25.0);
+        assert_eq!(*color, Color::GREEN);
+    } else {
+        panic!("Expected Circle command");
+    }
+}
+
+#[test]
+fn test_draw_list_clear() {
+    let mut list = DrawList::new();
+    list.rect(Rect::default(), Color::RED);
+    list.rect(Rect::default(), Color::BLUE);
+    assert_eq!(list.len(), 2);
+
+    list.clear();
+    assert!(list.is_empty());
+}
+
+#[test]
+fn test_draw_command_depth() {
+    let cmd = DrawCommand::Rect {
+        bounds: Rect::default(),
+        color: Color::RED,
+        corner_radius: 0.0,
+        depth: 5.0,
+    };
+    assert_eq!(cmd.depth(), 5.0);
+}
+
+#[test]
+fn test_draw_list_depth_ordering() {
+    let mut list = DrawList::new();
+    list.rect(Rect::default(), Color::RED);
+    list.rect(Rect::default(), Color::BLUE);
+    list.rect(Rect::default(), Color::GREEN);
+
+    // Each command should have increasing depth
+    let depths: Vec<f32> = list.commands().iter().map(|c| c.depth()).collect();
+    assert!(depths[0] < depths[1]);
+    assert!(depths[1] < depths[2]);
+}
+
+#[test]
+fn test_overlay_commands_render_above_base_band() {
+    let mut list = DrawList::new();
+    list.rect(Rect::default(), Color::RED); // base band
+    list.begin_overlay();
+    list.rect(Rect::default(), Color::BLUE); // overlay band
+    list.rect(Rect::default(), Color::GREEN); // overlay band
+    list.end_overlay();
+    list.rect(Rect::default(), Color::RED); // base band again
+
+    let depths: Vec<f32> = list.commands().iter().map(|c| c.depth()).collect();
+    assert!(depths[0] < 950.0, "base command stays in base band");
+    assert!(depths[1] >= 950.0, "overlay command is boosted");
+    assert!(depths[2] > depths[1], "overlay band stays monotonic");
+    assert!(depths[3] < 950.0, "end_overlay returns to base band");
+    assert!(depths[3] > depths[0], "base band stays monotonic");
+}
+
+#[test]
+fn test_nested_overlay_renders_above_outer_overlay() {
+    let mut list = DrawList::new();
+    list.begin_overlay();
+    list.rect(Rect::default(), Color::RED); // dropdown
+    list.begin_overlay();
+    list.rect(Rect::default(), Color::BLUE); // context menu over it
+    list.end_overlay();
+    list.rect(Rect::default(), Color::GREEN); // back in the dropdown band
+    list.end_overlay();
+
+    let depths: Vec<f32> = list.commands().iter().map(|c| c.depth()).collect();
+    assert!(depths[1] >= depths[0] + OVERLAY_LEVEL_STEP - 1.0);
+    assert!(depths[2] < depths[1], "closing the inner overlay drops back a band");
+    assert!(!list.is_overlay());
+}
+
+#[test]
+fn test_topmost_draws_above_nested_overlays_and_restores_level() {
+    let mut list = DrawList::new();
+    list.begin_overlay();
+    list.begin_overlay();
+    list.rect(Rect::default(), Color::RED); // popup over a dropdown
+    list.end_overlay();
+    list.topmost(|list| list.circle(Vec2::ZERO, 5.0, Color::WHITE));
+
+    let depths: Vec<f32> = list.commands().iter().map(|c| c.depth()).collect();
+    assert!(depths[1] > depths[0]);
+    assert_eq!(list.overlay_level(), 1);
+}
+
+#[test]
+fn test_overlay_depth_stays_below_far_plane_when_deeply_nested() {
+    let mut list = DrawList::new();
+    for _ in 0..10 {
+        list.begin_overlay();
+    }
+    list.rect(Rect::default(), Color::RED);
+    assert!(list.commands()[0].depth() < 1000.0);
+}
+
+#[test]
+fn test_clear_resets_overlay_mode() {
+    let mut list = DrawList::new();
+    list.begin_overlay();
+    assert!(list.is_overlay());
+
+    list.clear();
+    assert!(!list.is_overlay());
+
+    list.rect(Rect::default(), Color::RED);
+    assert!(list.commands()[0].depth() < 950.0);
+}
+
+#[test]
+fn test_draw_list_clip_rect() {
+    let mut list = DrawList::new();
+    let bounds = Rect::new(10.0, 10.0, 100.0, 100.0);
+
+    list.push_clip_rect(bounds);
+    list.rect(Rect::new(20.0, 20.0, 50.0, 50.0), Color::RED);
+    list.pop_clip_rect();
+
+    assert_eq!(list.len(), 3);
+
+    // First command should be PushClipRect
+    if let DrawCommand::PushClipRect { bounds: clip_bounds } = &list.commands()[0] {
+        assert_eq!(clip_bounds.x, 10.0);
+    } else {
+        panic!("Expected PushClipRect");
+    }
+
+    // Last command should be PopClipRect
+    assert!(matches!(list.commands()[2], DrawCommand::PopClipRect));
+}
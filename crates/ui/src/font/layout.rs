@@ -1,23 +1,33 @@
 //! Text layout and measurement.
 //!
-//! Lays out a string into positioned glyphs (filling the glyph cache on the
-//! way) and measures text dimensions without rasterizing.
+//! Lays out runs of text (and inline boxes such as icons) into positioned
+//! glyphs, filling the glyph cache on the way, and measures text dimensions
+//! without rasterizing. Layout applies the font's kerning table, breaks
+//! lines at `\n`, and optionally word-wraps to a maximum width.
+
+use std::collections::HashMap;
 
 use fontdue::Font;
 use glam::Vec2;
 
 use super::glyph_cache::GlyphCache;
-use super::{FontError, GlyphInfo};
+use super::{FontError, FontHandle, GlyphInfo};
 
 /// Text layout information for a string of text.
 #[derive(Debug, Clone)]
 pub struct TextLayout {
-    /// Total width of the text in pixels
+    /// Width of the widest line in pixels
     pub width: f32,
     /// Total height of the text in pixels
     pub height: f32,
+    /// Distance between consecutive baselines
+    pub line_height: f32,
+    /// Number of lines (at least 1)
+    pub lines: usize,
     /// Individual glyph positions and info
     pub glyphs: Vec<LayoutGlyph>,
+    /// Inline boxes ([`LayoutRun::Inline`]) in layout order
+    pub inlines: Vec<LayoutInline>,
 }
 
 /// A single glyph in a text layout.
@@ -25,118 +35,327 @@ pub struct TextLayout {
 pub struct LayoutGlyph {
     /// Character this glyph represents
     pub character: char,
+    /// Font the glyph was rasterized from
+    pub font_id: u32,
+    /// Index of the [`LayoutRun`] the glyph came from
+    pub run: usize,
     /// X position relative to text origin
     pub x: f32,
-    /// Y position relative to text origin (baseline)
+    /// Y position relative to text origin (the first line's baseline)
     pub y: f32,
     /// Glyph info with bitmap data
     pub info: GlyphInfo,
 }
 
-/// Layout a string of text, returning positions and glyph info for each character.
+/// Placement of an inline box in a text layout.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LayoutInline {
+    /// Index of the [`LayoutRun`] the box came from
+    pub run: usize,
+    /// Left edge relative to text origin
+    pub x: f32,
+    /// Baseline of the box's line relative to text origin
+    pub baseline: f32,
+    /// Width reserved for the box
+    pub width: f32,
+}
+
+/// One piece of layout input.
+#[derive(Debug, Clone, Copy)]
+pub enum LayoutRun<'a> {
+    /// Text in a given font
+    Text { text: &'a str, font: FontHandle },
+    /// An unbreakable box of the given width (inline icons)
+    Inline { width: f32 },
+}
+
+/// How an atom takes part in line breaking.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum AtomKind {
+    /// Glyph or inline box; consecutive ones form an unbreakable word
+    Word,
+    /// Whitespace: a break opportunity, dropped at the end of a line
+    Space,
+    /// Forced line break
+    Newline,
+}
+
+/// Break `atoms` (kind, advance) into lines no wider than `max_width`.
+///
+/// Greedy word wrap: a word that doesn't fit on a non-empty line moves to
+/// the next one; a word wider than a whole line breaks between glyphs.
+/// Returns each atom's (x, line) and the width of every line, trailing
+/// whitespace excluded.
+pub(super) fn break_lines(atoms: &[(AtomKind, f32)], max_width: Option<f32>) -> (Vec<(f32, usize)>, Vec<f32>) {
+    let overflows = |x: f32, width: f32| x > 0.0 && max_width.is_some_and(|max| x + width > max);
+    let mut positions = Vec::with_capacity(atoms.len());
+    let mut widths = vec![0.0f32];
+    let mut x = 0.0f32;
+
+    let mut i = 0;
+    while i < atoms.len() {
+        let (kind, advance) = atoms[i];
+        match kind {
+            AtomKind::Newline => {
+                positions.push((x, widths.len() - 1));
+                widths.push(0.0);
+                x = 0.0;
+                i += 1;
+            }
+            AtomKind::Space => {
+                positions.push((x, widths.len() - 1));
+                x += advance;
+                i += 1;
+            }
+            AtomKind::Word => {
+                let end = atoms[i..].iter().position(|(k, _)| *k != AtomKind::Word).map_or(atoms.len(), |n| i + n);
+                let word_width: f32 = atoms[i..end].iter().map(|(_, a)| a).sum();
+                if overflows(x, word_width) {
+                    widths.push(0.0);
+                    x = 0.0;
+                }
+                for &(_, advance) in &atoms[i..end] {
+                    // Only a word wider than the whole line breaks inside
+                    if overflows(x, advance) {
+                        widths.push(0.0);
+                        x = 0.0;
+                    }
+                    positions.push((x, widths.len() - 1));
+                    x += advance;
+                    if let Some(width) = widths.last_mut() {
+                        *width = x;
+                    }
+                }
+                i = end;
+            }
+        }
+    }
+    (positions, widths)
+}
+
+/// Line metrics for `font`, with a size-based fallback.
+fn line_metrics(font: &Font, font_size: f32) -> fontdue::LineMetrics {
+    font.horizontal_line_metrics(font_size).unwrap_or_else(|| line_metrics_fallback(font_size))
+}
+
+/// Breaking class of a character.
+fn atom_kind(character: char) -> AtomKind {
+    match character {
+        '\n' => AtomKind::Newline,
+        c if c.is_whitespace() => AtomKind::Space,
+        _ => AtomKind::Word,
+    }
+}
+
+/// What an atom renders as.
+enum AtomContent {
+    Glyph { font_id: u32, info: GlyphInfo },
+    Inline { width: f32 },
+    Empty,
+}
+
+/// Lay out `runs`, returning positions and glyph info for each character.
 ///
 /// Glyphs are pulled from (and inserted into) `cache` so repeated layout of
-/// the same font/size combination never re-rasterizes.
+/// the same font/size combination never re-rasterizes. Kerning applies
+/// between neighbouring characters of the same font.
 ///
 /// Coordinate system:
-/// - The text origin (position.y) is at the BASELINE
-/// - glyph.y is the offset from baseline to glyph top (negative = above baseline)
-/// - The rendering code subtracts glyph.y from position.y to place the glyph correctly
-pub(super) fn layout_text(
-    font: &Font,
-    font_id: u32,
+/// - The text origin (position.y) is at the first line's BASELINE
+/// - glyph.y is the offset from that baseline to glyph top (negative = above
+///   baseline); later lines add `line_height` per line
+pub(super) fn layout_runs(
+    fonts: &HashMap<u32, Font>,
     cache: &mut GlyphCache,
-    text: &str,
+    runs: &[LayoutRun<'_>],
     font_size: f32,
+    max_width: Option<f32>,
 ) -> Result<TextLayout, FontError> {
-    let line_metrics = font.horizontal_line_metrics(font_size).unwrap_or_else(|| fontdue::LineMetrics {
-        ascent: font_size * 0.8,
-        descent: font_size * -0.2,
-        line_gap: 0.0,
-        new_line_size: font_size * 1.2,
-    });
+    let font = |handle: FontHandle| {
+        fonts.get(&handle.id).ok_or_else(|| FontError::NotFound(format!("Font {} not found", handle.id)))
+    };
+    // Line spacing comes from the first text run's font
+    let metrics = match runs.iter().find_map(|run| match run {
+        LayoutRun::Text { font: handle, .. } => Some(*handle),
+        LayoutRun::Inline { .. } => None,
+    }) {
+        Some(handle) => line_metrics(font(handle)?, font_size),
+        None => line_metrics_fallback(font_size),
+    };
 
-    let mut glyphs = Vec::new();
-    let mut cursor_x = 0.0f32;
-    let mut max_descent = 0.0f32;
-
-    for character in text.chars() {
-        // Handle special characters
-        if character == '\n' {
-            // Newlines not fully supported yet, just skip
-            continue;
+    let mut atoms: Vec<(AtomKind, f32)> = Vec::new();
+    let mut contents: Vec<(usize, char, AtomContent)> = Vec::new();
+    for (run_index, run) in runs.iter().enumerate() {
+        match *run {
+            LayoutRun::Text { text, font: handle } => {
+                let face = font(handle)?;
+                let mut previous: Option<char> = None;
+                for character in text.chars() {
+                    let kind = atom_kind(character);
+                    if kind == AtomKind::Newline {
+                        atoms.push((kind, 0.0));
+                        contents.push((run_index, character, AtomContent::Empty));
+                        previous = None;
+                        continue;
+                    }
+                    // Kerning pulls this glyph towards the previous one
+                    if let (Some(prev), Some((AtomKind::Word | AtomKind::Space, advance))) = (previous, atoms.last_mut()) {
+                        *advance += face.horizontal_kern(prev, character, font_size).unwrap_or(0.0);
+                    }
+                    let info = cache.get_or_rasterize(face, handle.id, character, font_size)?.clone();
+                    atoms.push((kind, info.rasterized.advance));
+                    let content = if kind == AtomKind::Word && info.rasterized.width > 0 {
+                        AtomContent::Glyph { font_id: handle.id, info }
+                    } else {
+                        AtomContent::Empty
+                    };
+                    contents.push((run_index, character, content));
+                    previous = Some(character);
+                }
+            }
+            LayoutRun::Inline { width } => {
+                atoms.push((AtomKind::Word, width));
+                contents.push((run_index, '\u{FFFC}', AtomContent::Inline { width }));
+            }
         }
+    }
 
-        // Use the glyph cache, which rasterizes on miss (including spaces)
-        let glyph_info = cache.get_or_rasterize(font, font_id, character, font_size)?;
-
-        // Skip rendering for zero-width glyphs but still advance cursor
-        let advance = glyph_info.rasterized.advance;
-
-        if character != ' ' && glyph_info.rasterized.width > 0 {
-            // glyph.y is the offset from baseline to glyph top
-            // offset_y (ymin) from fontdue is already this: negative = above baseline
-            let glyph_y = glyph_info.rasterized.offset_y;
-
-            // Track max descent to calculate total text height
-            // Descent is how far below baseline the glyph extends
-            let glyph_bottom_from_baseline = glyph_y + glyph_info.rasterized.height as f32;
-            if glyph_bottom_from_baseline > max_descent {
-                max_descent = glyph_bottom_from_baseline;
+    let (positions, widths) = break_lines(&atoms, max_width);
+    let line_height = metrics.new_line_size;
+    let mut glyphs = Vec::new();
+    let mut inlines = Vec::new();
+    let mut max_descent = -metrics.descent;
+    for ((run, character, content), (x, line)) in contents.into_iter().zip(positions) {
+        let baseline = line as f32 * line_height;
+        match content {
+            AtomContent::Glyph { font_id, info } => {
+                // offset_y (from fontdue's ymin) is baseline → glyph top, negative = above
+                let glyph_y = info.rasterized.offset_y;
+                if line + 1 == widths.len() {
+                    // Descent of the last line sets the text's bottom edge
+                    max_descent = max_descent.max(glyph_y + info.rasterized.height as f32);
+                }
+                glyphs.push(LayoutGlyph {
+                    character,
+                    font_id,
+                    run,
+                    x: x + info.rasterized.offset_x,
+                    y: baseline + glyph_y,
+                    info,
+                });
             }
-
-            glyphs.push(LayoutGlyph {
-                character,
-                x: cursor_x + glyph_info.rasterized.offset_x,
-                y: glyph_y,  // Offset from baseline (negative = above baseline)
-                info: glyph_info.clone(),
-            });
+            AtomContent::Inline { width } => inlines.push(LayoutInline { run, x, baseline, width }),
+            AtomContent::Empty => {}
         }
-
-        cursor_x += advance;
     }
 
-    // Text height is from top of highest ascender to bottom of lowest descender
-    // ascent = distance from baseline to top of line
-    // max_descent = distance from baseline to bottom of lowest glyph
-    let text_height = line_metrics.ascent + max_descent.max(-line_metrics.descent);
-
+    // Top of the first line's ascender to the bottom of the last line
+    let lines = widths.len();
+    let text_height = metrics.ascent + (lines - 1) as f32 * line_height + max_descent;
     Ok(TextLayout {
-        width: cursor_x,
-        height: text_height.max(line_metrics.new_line_size),
+        width: widths.iter().copied().fold(0.0, f32::max),
+        height: text_height.max(lines as f32 * line_height),
+        line_height,
+        lines,
         glyphs,
+        inlines,
     })
 }
 
+/// Size-based line metrics for fonts without a horizontal table (and
+/// layouts without a text run).
+fn line_metrics_fallback(font_size: f32) -> fontdue::LineMetrics {
+    fontdue::LineMetrics {
+        ascent: font_size * 0.8,
+        descent: font_size * -0.2,
+        line_gap: 0.0,
+        new_line_size: font_size * 1.2,
+    }
+}
+
 /// Measure the size of a text string without rasterizing.
 ///
 /// Uses `font.metrics()` instead of `font.rasterize()` to get advance widths
-/// without the expensive bitmap generation step.
+/// without the expensive bitmap generation step. Kerning and `\n` line
+/// breaks are applied; the width is that of the widest line.
 pub(super) fn measure_text(font: &Font, text: &str, font_size: f32) -> Vec2 {
-    let mut width = 0.0f32;
-    let line_metrics = font.horizontal_line_metrics(font_size);
-    let height = line_metrics.map(|m| m.new_line_size).unwrap_or(font_size * 1.2);
-
-    for character in text.chars() {
-        let metrics = font.metrics(character, font_size);
-        width += metrics.advance_width;
+    let line_height = line_metrics(font, font_size).new_line_size;
+    let mut widest = 0.0f32;
+    let mut lines = 0;
+    for line in text.split('\n') {
+        let mut width = 0.0f32;
+        let mut previous: Option<char> = None;
+        for character in line.chars() {
+            if let Some(prev) = previous {
+                width += font.horizontal_kern(prev, character, font_size).unwrap_or(0.0);
+            }
+            width += font.metrics(character, font_size).advance_width;
+            previous = Some(character);
+        }
+        widest = widest.max(width);
+        lines += 1;
     }
 
-    Vec2::new(width, height)
+    Vec2::new(widest, lines as f32 * line_height)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn word(advance: f32) -> (AtomKind, f32) {
+        (AtomKind::Word, advance)
+    }
+
+    const SPACE: (AtomKind, f32) = (AtomKind::Space, 5.0);
+    const NEWLINE: (AtomKind, f32) = (AtomKind::Newline, 0.0);
+
     #[test]
     fn test_text_layout() {
         let layout = TextLayout {
             width: 100.0,
             height: 16.0,
+            line_height: 16.0,
+            lines: 1,
             glyphs: vec![],
+            inlines: vec![],
         };
         assert_eq!(layout.width, 100.0);
         assert_eq!(layout.height, 16.0);
     }
+
+    #[test]
+    fn test_unwrapped_text_stays_on_one_line() {
+        let atoms = [word(10.0), word(10.0), SPACE, word(10.0)];
+        let (positions, widths) = break_lines(&atoms, None);
+        assert_eq!(positions, vec![(0.0, 0), (10.0, 0), (20.0, 0), (25.0, 0)]);
+        assert_eq!(widths, vec![35.0]);
+    }
+
+    #[test]
+    fn test_words_wrap_at_spaces_and_trailing_space_is_not_counted() {
+        // "ab cd" with room for "ab c" only: "cd" moves down as a whole
+        let atoms = [word(10.0), word(10.0), SPACE, word(10.0), word(10.0)];
+        let (positions, widths) = break_lines(&atoms, Some(30.0));
+        assert_eq!(positions[3], (0.0, 1));
+        assert_eq!(positions[4], (10.0, 1));
+        assert_eq!(widths, vec![20.0, 20.0]);
+    }
+
+    #[test]
+    fn test_newlines_force_breaks() {
+        let atoms = [word(10.0), NEWLINE, word(10.0), word(10.0)];
+        let (positions, widths) = break_lines(&atoms, Some(100.0));
+        assert_eq!(positions[2], (0.0, 1));
+        assert_eq!(widths, vec![10.0, 20.0]);
+    }
+
+    #[test]
+    fn test_words_wider_than_a_line_break_between_glyphs() {
+        let atoms = [word(10.0), word(10.0), word(10.0), word(10.0), word(10.0)];
+        let (positions, widths) = break_lines(&atoms, Some(25.0));
+        let lines: Vec<usize> = positions.iter().map(|&(_, line)| line).collect();
+        assert_eq!(lines, vec![0, 0, 1, 1, 2]);
+        assert_eq!(widths, vec![20.0, 20.0, 10.0]);
+    }
 }
@@ -6,13 +6,14 @@
 //! Split by responsibility:
 //! - `mod.rs` — `FontManager` facade (font loading/storage) and shared types
 //! - `glyph_cache.rs` — `GlyphCache` (rasterized glyph storage with bounded eviction)
-//! - `layout.rs` — text layout and measurement
+//! - `layout.rs` — text layout (kerning, line breaks, word wrap, inline
+//!   boxes) and measurement
 
 mod glyph_cache;
 mod layout;
 
 pub use glyph_cache::{GlyphInfo, RasterizedGlyph};
-pub use layout::{LayoutGlyph, TextLayout};
+pub use layout::{LayoutGlyph, LayoutInline, LayoutRun, TextLayout};
 
 use std::collections::HashMap;
 use fontdue::{Font, FontSettings};
@@ -158,21 +159,43 @@ impl FontManager {
     /// This method uses the internal glyph cache to avoid re-rasterizing glyphs that have
     /// already been rendered at the same font/size combination. The CPU bitmap cache here
     /// works in tandem with engine_core's GPU texture cache - this caches the rasterized
-    /// bitmaps, while engine_core caches the GPU textures created from those bitmaps.
+    /// bitmaps, while engine_core packs those bitmaps into its GPU glyph atlas.
     ///
     /// Coordinate system:
-    /// - The text origin (position.y) is at the BASELINE
+    /// - The text origin (position.y) is at the BASELINE of the first line
     /// - glyph.y is the offset from baseline to glyph top (negative = above baseline)
-    /// - The rendering code subtracts glyph.y from position.y to place the glyph correctly
+    /// - `\n` starts a new line `line_height` further down; kerning is applied
     pub fn layout_text(
         &mut self,
         handle: FontHandle,
         text: &str,
         font_size: f32,
     ) -> Result<TextLayout, FontError> {
-        let font = self.fonts.get(&handle.id)
-            .ok_or_else(|| FontError::NotFound(format!("Font {} not found", handle.id)))?;
-        layout::layout_text(font, handle.id, &mut self.glyph_cache, text, font_size)
+        self.layout_runs(&[LayoutRun::Text { text, font: handle }], font_size, None)
+    }
+
+    /// Layout text word-wrapped to `max_width` pixels. Lines break at
+    /// whitespace; a word wider than a whole line breaks between glyphs.
+    pub fn layout_text_wrapped(
+        &mut self,
+        handle: FontHandle,
+        text: &str,
+        font_size: f32,
+        max_width: f32,
+    ) -> Result<TextLayout, FontError> {
+        self.layout_runs(&[LayoutRun::Text { text, font: handle }], font_size, Some(max_width))
+    }
+
+    /// Layout a sequence of runs — text in possibly different fonts and
+    /// inline boxes — as one paragraph, optionally wrapped to `max_width`.
+    /// Glyphs and inline boxes record the index of the run they came from.
+    pub fn layout_runs(
+        &mut self,
+        runs: &[LayoutRun<'_>],
+        font_size: f32,
+        max_width: Option<f32>,
+    ) -> Result<TextLayout, FontError> {
+        layout::layout_runs(&self.fonts, &mut self.glyph_cache, runs, font_size, max_width)
     }
 
     /// Measure the size of a text string without rasterizing.
@@ -0,0 +1,326 @@
+//! Localization for UI text: per-locale translation tables, runtime locale
+//! switching and fallback chains, looked up with [`tr!`](crate::tr).
+//!
+//! Translation files are named after their locale (`en.ftl`, `pt-BR.toml`)
+//! and come in two formats, picked from the extension:
+//!
+//! - `.ftl` — a Fluent subset: `id = value` messages, `-term` references,
+//!   multiline values and `.attr` attributes (`menu.play` is the `play`
+//!   attribute of `menu`)
+//! - `.toml` — key-value tables; nested tables give dotted keys
+//!
+//! Values may contain `{ $name }` placeables filled from `tr!` arguments.
+//! A lookup walks the current locale, its parents (`pt-BR` → `pt`) and then
+//! the fallback locales (`en` by default). Keys missing everywhere render as
+//! the key itself, log one warning, and are listed by [`missing_keys`] (the
+//! editor shows them in its Console panel).
+//!
+//! ```
+//! use ui::{i18n, tr};
+//!
+//! i18n::with_localizer_mut(|l| {
+//!     l.add_ftl_str("en", "menu = Menu\n    .play = Play\nscore = Score: { $points }").unwrap();
+//!     l.add_toml_str("fr", "[menu]\nplay = \"Jouer\"").unwrap();
+//!     l.set_locale("fr");
+//! });
+//! assert_eq!(tr!("menu.play"), "Jouer");
+//! // Not translated to French yet: falls back to English
+//! assert_eq!(tr!("score", points = 40), "Score: 40");
+//! ```
+
+mod parse;
+
+use std::collections::{BTreeSet, HashMap};
+use std::path::{Path, PathBuf};
+use std::sync::{OnceLock, PoisonError, RwLock};
+
+/// Errors from loading translation files.
+#[derive(Debug, thiserror::Error)]
+pub enum I18nError {
+    #[error("Translation IO error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Fluent parse error on line {line}: {message}")]
+    Parse { line: usize, message: String },
+
+    #[error("Translation TOML error: {0}")]
+    Toml(String),
+
+    #[error("Unsupported translation file: {0:?} (expected <locale>.ftl or <locale>.toml)")]
+    UnsupportedFormat(PathBuf),
+}
+
+/// Translation tables for every loaded locale plus the active locale.
+#[derive(Debug, Clone)]
+pub struct Localizer {
+    /// Locale → key → pattern
+    tables: HashMap<String, HashMap<String, String>>,
+    locale: String,
+    /// Tried after the locale and its parents, in order
+    fallbacks: Vec<String>,
+    /// `(locale, key)` lookups that found nothing, for the editor
+    missing: BTreeSet<(String, String)>,
+}
+
+impl Default for Localizer {
+    fn default() -> Self {
+        Self::new("en")
+    }
+}
+
+impl Localizer {
+    /// An empty localizer using `locale`, falling back to `en`.
+    pub fn new(locale: impl Into<String>) -> Self {
+        Self {
+            tables: HashMap::new(),
+            locale: locale.into(),
+            fallbacks: vec!["en".to_string()],
+            missing: BTreeSet::new(),
+        }
+    }
+
+    /// The active locale.
+    pub fn locale(&self) -> &str {
+        &self.locale
+    }
+
+    /// Switch the active locale; takes effect on the next lookup.
+    pub fn set_locale(&mut self, locale: impl Into<String>) {
+        self.locale = locale.into();
+    }
+
+    /// Replace the fallback locales tried after the active one.
+    pub fn set_fallbacks(&mut self, fallbacks: Vec<String>) {
+        self.fallbacks = fallbacks;
+    }
+
+    /// Locales with at least one loaded entry, sorted.
+    pub fn locales(&self) -> Vec<&str> {
+        let mut locales: Vec<&str> = self.tables.keys().map(String::as_str).collect();
+        locales.sort_unstable();
+        locales
+    }
+
+    /// Locales a lookup tries, in order: the active locale, its parents
+    /// (`pt-BR` → `pt`), then the fallbacks, without repeats.
+    pub fn chain(&self) -> Vec<&str> {
+        let mut chain = vec![self.locale.as_str()];
+        let mut tag = self.locale.as_str();
+        while let Some((parent, _)) = tag.rsplit_once('-') {
+            chain.push(parent);
+            tag = parent;
+        }
+        for fallback in &self.fallbacks {
+            if !chain.contains(&fallback.as_str()) {
+                chain.push(fallback);
+            }
+        }
+        chain
+    }
+
+    /// Add or replace one entry.
+    pub fn insert(&mut self, locale: &str, key: impl Into<String>, text: impl Into<String>) {
+        self.tables.entry(locale.to_string()).or_default().insert(key.into(), text.into());
+    }
+
+    /// Merge Fluent source into `locale`'s table.
+    pub fn add_ftl_str(&mut self, locale: &str, source: &str) -> Result<(), I18nError> {
+        let entries = parse::parse_ftl(source)?;
+        self.tables.entry(locale.to_string()).or_default().extend(entries);
+        Ok(())
+    }
+
+    /// Merge TOML key-value source into `locale`'s table.
+    pub fn add_toml_str(&mut self, locale: &str, source: &str) -> Result<(), I18nError> {
+        let entries = parse::parse_toml(source)?;
+        self.tables.entry(locale.to_string()).or_default().extend(entries);
+        Ok(())
+    }
+
+    /// Load `<locale>.ftl` or `<locale>.toml`; the locale is the file stem.
+    pub fn load_file(&mut self, path: impl AsRef<Path>) -> Result<(), I18nError> {
+        let path = path.as_ref();
+        let unsupported = || I18nError::UnsupportedFormat(path.to_path_buf());
+        let locale = path.file_stem().and_then(|stem| stem.to_str()).ok_or_else(unsupported)?;
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("ftl") => self.add_ftl_str(locale, &std::fs::read_to_string(path)?),
+            Some(ext) if ext.eq_ignore_ascii_case("toml") => {
+                self.add_toml_str(locale, &std::fs::read_to_string(path)?)
+            }
+            _ => Err(unsupported()),
+        }
+    }
+
+    /// Load every `.ftl`/`.toml` file in `dir` (e.g. `assets/i18n`),
+    /// returning how many were loaded. Other files are ignored.
+    pub fn load_dir(&mut self, dir: impl AsRef<Path>) -> Result<usize, I18nError> {
+        let mut paths: Vec<PathBuf> = std::fs::read_dir(dir)?
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| {
+                path.extension()
+                    .and_then(|ext| ext.to_str())
+                    .is_some_and(|ext| ext.eq_ignore_ascii_case("ftl") || ext.eq_ignore_ascii_case("toml"))
+            })
+            .collect();
+        paths.sort();
+        for path in &paths {
+            self.load_file(path)?;
+        }
+        Ok(paths.len())
+    }
+
+    /// The raw pattern for `key` from the first locale in the
+    /// [`chain`](Self::chain) that has it.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.chain()
+            .into_iter()
+            .find_map(|locale| self.tables.get(locale)?.get(key))
+            .map(String::as_str)
+    }
+
+    /// Look up `key` and fill its `{ $name }` placeables from `args`.
+    /// Returns `None` when no locale in the chain has it.
+    pub fn format(&self, key: &str, args: &[(&str, &str)]) -> Option<String> {
+        let pattern = self.get(key)?;
+        Some(parse::format_pattern(pattern, args, |term| self.get(term).map(str::to_string)))
+    }
+
+    /// [`format`](Self::format), recording a miss and returning `key`
+    /// itself when nothing is found.
+    pub fn translate(&mut self, key: &str, args: &[(&str, &str)]) -> String {
+        match self.format(key, args) {
+            Some(text) => text,
+            None => {
+                self.record_missing(key);
+                key.to_string()
+            }
+        }
+    }
+
+    /// Missing lookups as `(locale, key)`, sorted.
+    pub fn missing_keys(&self) -> Vec<(String, String)> {
+        self.missing.iter().cloned().collect()
+    }
+
+    /// Forget recorded misses (e.g. after reloading translations).
+    pub fn clear_missing(&mut self) {
+        self.missing.clear();
+    }
+
+    fn record_missing(&mut self, key: &str) {
+        if self.missing.insert((self.locale.clone(), key.to_string())) {
+            log::warn!("Missing translation for `{}` in locale `{}`", key, self.locale);
+        }
+    }
+}
+
+/// The process-wide localizer behind [`tr!`](crate::tr).
+static LOCALIZER: OnceLock<RwLock<Localizer>> = OnceLock::new();
+
+fn global() -> &'static RwLock<Localizer> {
+    LOCALIZER.get_or_init(|| RwLock::new(Localizer::default()))
+}
+
+/// Read the global localizer.
+pub fn with_localizer<R>(f: impl FnOnce(&Localizer) -> R) -> R {
+    f(&global().read().unwrap_or_else(PoisonError::into_inner))
+}
+
+/// Modify the global localizer (load files, switch locale).
+pub fn with_localizer_mut<R>(f: impl FnOnce(&mut Localizer) -> R) -> R {
+    f(&mut global().write().unwrap_or_else(PoisonError::into_inner))
+}
+
+/// Switch the global locale.
+pub fn set_locale(locale: impl Into<String>) {
+    with_localizer_mut(|localizer| localizer.set_locale(locale));
+}
+
+/// Missing lookups recorded by the global localizer.
+pub fn missing_keys() -> Vec<(String, String)> {
+    with_localizer(Localizer::missing_keys)
+}
+
+/// Translate `key` with the global localizer (what [`tr!`](crate::tr) expands to).
+pub fn tr(key: &str, args: &[(&str, &str)]) -> String {
+    // Hits only need the read lock; misses take the write lock to record
+    if let Some(text) = with_localizer(|localizer| localizer.format(key, args)) {
+        return text;
+    }
+    with_localizer_mut(|localizer| localizer.translate(key, args))
+}
+
+/// Translate a key with the global localizer: `tr!("menu.play")`, or with
+/// placeable arguments, `tr!("hud.score", points = score)`. Arguments are
+/// formatted with `Display`.
+#[macro_export]
+macro_rules! tr {
+    ($key:expr $(,)?) => {
+        $crate::i18n::tr($key, &[])
+    };
+    ($key:expr, $($name:ident = $value:expr),+ $(,)?) => {
+        $crate::i18n::tr($key, &[$((stringify!($name), $value.to_string().as_str())),+])
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fluent_and_toml_sources() {
+        let mut localizer = Localizer::new("en");
+        let ftl = "# Main menu\n-brand = Insiculous\nmenu = Menu\n    .play = Play\nwelcome = Welcome to { -brand },\n    { $name }!\n";
+        localizer.add_ftl_str("en", ftl).unwrap();
+        localizer.add_toml_str("de", "[menu]\nplay = \"Spielen\"\n[hud]\nscore = \"Punkte: { $points }\"").unwrap();
+
+        assert_eq!(localizer.get("menu"), Some("Menu"));
+        assert_eq!(localizer.get("menu.play"), Some("Play"));
+        assert_eq!(localizer.format("welcome", &[("name", "Ada")]).as_deref(), Some("Welcome to Insiculous,\nAda!"));
+        localizer.set_locale("de");
+        assert_eq!(localizer.format("hud.score", &[("points", "7")]).as_deref(), Some("Punkte: 7"));
+        // Unknown arguments stay visible rather than vanishing
+        assert_eq!(localizer.format("hud.score", &[]).as_deref(), Some("Punkte: { $points }"));
+
+        assert!(matches!(localizer.add_ftl_str("en", "no equals sign"), Err(I18nError::Parse { line: 1, .. })));
+        assert!(matches!(localizer.add_ftl_str("en", "    .orphan = x"), Err(I18nError::Parse { .. })));
+        assert!(matches!(localizer.add_toml_str("en", "count = 3"), Err(I18nError::Toml(_))));
+    }
+
+    #[test]
+    fn test_fallback_chain_and_missing_keys() {
+        let mut localizer = Localizer::new("pt-BR");
+        localizer.insert("en", "menu.quit", "Quit");
+        localizer.insert("en", "menu.play", "Play");
+        localizer.insert("pt", "menu.play", "Jogar");
+        assert_eq!(localizer.chain(), vec!["pt-BR", "pt", "en"]);
+        assert_eq!(localizer.translate("menu.play", &[]), "Jogar");
+        assert_eq!(localizer.translate("menu.quit", &[]), "Quit");
+
+        assert_eq!(localizer.translate("menu.options", &[]), "menu.options");
+        localizer.translate("menu.options", &[]);
+        assert_eq!(localizer.missing_keys(), vec![("pt-BR".to_string(), "menu.options".to_string())]);
+
+        localizer.set_fallbacks(Vec::new());
+        localizer.set_locale("fr");
+        assert_eq!(localizer.get("menu.play"), None);
+        localizer.clear_missing();
+        assert!(localizer.missing_keys().is_empty());
+    }
+
+    #[test]
+    fn test_load_dir_reads_locale_from_file_name() {
+        let dir = std::env::temp_dir().join(format!("insiculous_i18n_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("en.ftl"), "greeting = Hello").unwrap();
+        std::fs::write(dir.join("es.toml"), "greeting = \"Hola\"").unwrap();
+        std::fs::write(dir.join("notes.txt"), "ignored").unwrap();
+
+        let mut localizer = Localizer::new("es");
+        assert_eq!(localizer.load_dir(&dir).unwrap(), 2);
+        assert_eq!(localizer.locales(), vec!["en", "es"]);
+        assert_eq!(localizer.get("greeting"), Some("Hola"));
+        assert!(matches!(localizer.load_file(dir.join("notes.txt")), Err(I18nError::UnsupportedFormat(_))));
+        std::fs::remove_dir_all(&dir).ok();
+    }
+}
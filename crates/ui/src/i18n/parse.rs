@@ -0,0 +1,128 @@
+//! Translation file parsers: a Fluent (`.ftl`) subset and flat TOML tables.
+
+use std::collections::HashMap;
+
+use super::I18nError;
+
+/// Parse Fluent source into `key → pattern` pairs.
+///
+/// Supported: `# comments`, `id = value` messages, `-term = value` terms,
+/// indented continuation lines (joined with `\n`), and attributes
+/// (`.attr = value` under a message, stored as `id.attr` — so `tr!("menu.play")`
+/// finds the `play` attribute of the `menu` message). Selectors and
+/// functions are not; placeables other than `{ $var }` and `{ -term }` stay
+/// literal.
+pub(super) fn parse_ftl(source: &str) -> Result<HashMap<String, String>, I18nError> {
+    let mut entries = HashMap::new();
+    // Key currently receiving continuation lines
+    let mut current: Option<String> = None;
+    // Message id that attributes attach to
+    let mut message: Option<String> = None;
+
+    for (index, line) in source.lines().enumerate() {
+        let line_number = index + 1;
+        if line.trim().is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let indented = line.starts_with([' ', '\t']);
+        let trimmed = line.trim();
+
+        if indented {
+            if let Some(attribute) = trimmed.strip_prefix('.') {
+                let (name, value) = split_entry(attribute, line_number)?;
+                let parent = message.as_ref().ok_or(I18nError::Parse {
+                    line: line_number,
+                    message: "attribute outside a message".to_string(),
+                })?;
+                let key = format!("{parent}.{name}");
+                entries.insert(key.clone(), value.to_string());
+                current = Some(key);
+            } else {
+                let key = current.as_ref().ok_or(I18nError::Parse {
+                    line: line_number,
+                    message: "indented line outside a message".to_string(),
+                })?;
+                if let Some(text) = entries.get_mut(key) {
+                    if !text.is_empty() {
+                        text.push('\n');
+                    }
+                    text.push_str(trimmed);
+                }
+            }
+            continue;
+        }
+
+        let (key, value) = split_entry(trimmed, line_number)?;
+        entries.insert(key.to_string(), value.to_string());
+        current = Some(key.to_string());
+        message = Some(key.to_string());
+    }
+    Ok(entries)
+}
+
+/// Parse TOML into `key → text` pairs; nested tables become dotted keys
+/// (`[menu] play = "Play"` is `menu.play`). Non-string values are rejected.
+pub(super) fn parse_toml(source: &str) -> Result<HashMap<String, String>, I18nError> {
+    let table: toml::Table = toml::from_str(source).map_err(|e| I18nError::Toml(e.to_string()))?;
+    let mut entries = HashMap::new();
+    flatten(&table, "", &mut entries)?;
+    Ok(entries)
+}
+
+fn flatten(table: &toml::Table, prefix: &str, out: &mut HashMap<String, String>) -> Result<(), I18nError> {
+    for (name, value) in table {
+        let key = if prefix.is_empty() { name.clone() } else { format!("{prefix}.{name}") };
+        match value {
+            toml::Value::String(text) => {
+                out.insert(key, text.clone());
+            }
+            toml::Value::Table(inner) => flatten(inner, &key, out)?,
+            _ => return Err(I18nError::Toml(format!("`{key}` is not a string"))),
+        }
+    }
+    Ok(())
+}
+
+/// Split `id = value`, validating the id.
+fn split_entry(text: &str, line: usize) -> Result<(&str, &str), I18nError> {
+    let (key, value) = text.split_once('=').ok_or_else(|| I18nError::Parse {
+        line,
+        message: format!("expected `id = value`, found `{text}`"),
+    })?;
+    let key = key.trim();
+    let valid = key.strip_prefix('-').unwrap_or(key);
+    if valid.is_empty() || !valid.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_') {
+        return Err(I18nError::Parse { line, message: format!("invalid message id `{key}`") });
+    }
+    Ok((key, value.trim()))
+}
+
+/// Resolve `{ $var }` and `{ -term }` placeables in `pattern`.
+pub(super) fn format_pattern(
+    pattern: &str,
+    args: &[(&str, &str)],
+    term: impl Fn(&str) -> Option<String>,
+) -> String {
+    let mut out = String::with_capacity(pattern.len());
+    let mut rest = pattern;
+    while let Some(start) = rest.find('{') {
+        out.push_str(&rest[..start]);
+        let Some(end) = rest[start..].find('}') else {
+            out.push_str(&rest[start..]);
+            return out;
+        };
+        let placeable = &rest[start..start + end + 1];
+        let inner = placeable[1..placeable.len() - 1].trim();
+        let resolved = if let Some(name) = inner.strip_prefix('$') {
+            args.iter().find(|(arg, _)| *arg == name).map(|(_, value)| value.to_string())
+        } else if inner.starts_with('-') {
+            term(inner)
+        } else {
+            None
+        };
+        out.push_str(resolved.as_deref().unwrap_or(placeable));
+        rest = &rest[start + end + 1..];
+    }
+    out.push_str(rest);
+    out
+}
@@ -23,10 +23,15 @@ pub struct InputState {
     pub mouse_just_pressed: bool,
     /// Whether left mouse button was just released this frame
     pub mouse_just_released: bool,
+    /// Whether right mouse button was just pressed this frame (context menus)
+    pub right_mouse_just_pressed: bool,
     /// Mouse scroll delta
     pub scroll_delta: f32,
     /// Characters typed this frame (for text input widgets)
     pub typed_chars: Vec<char>,
+    /// Text typed this frame for free-text inputs: layout-aware, from
+    /// [`KeyboardState::typed_text`](input::prelude::KeyboardState::typed_text)
+    pub typed_text: Vec<char>,
     /// Whether Enter/Return was just pressed
     pub enter_pressed: bool,
     /// Whether Escape was just pressed
@@ -39,6 +44,10 @@ pub struct InputState {
     pub left_pressed: bool,
     /// Whether ArrowRight was just pressed (or repeating)
     pub right_pressed: bool,
+    /// Whether ArrowUp was just pressed (or repeating)
+    pub up_pressed: bool,
+    /// Whether ArrowDown was just pressed (or repeating)
+    pub down_pressed: bool,
     /// Whether Home was just pressed
     pub home_pressed: bool,
     /// Whether End was just pressed
@@ -56,14 +65,18 @@ impl Default for InputState {
             mouse_down: false,
             mouse_just_pressed: false,
             mouse_just_released: false,
+            right_mouse_just_pressed: false,
             scroll_delta: 0.0,
             typed_chars: Vec::new(),
+            typed_text: Vec::new(),
             enter_pressed: false,
             escape_pressed: false,
             backspace_pressed: false,
             tab_pressed: false,
             left_pressed: false,
             right_pressed: false,
+            up_pressed: false,
+            down_pressed: false,
             home_pressed: false,
             end_pressed: false,
             delete_pressed: false,
@@ -154,6 +167,8 @@ impl InputState {
         };
         let left_pressed = repeating(RepeatKey::Left, KeyCode::ArrowLeft);
         let right_pressed = repeating(RepeatKey::Right, KeyCode::ArrowRight);
+        let up_pressed = repeating(RepeatKey::Up, KeyCode::ArrowUp);
+        let down_pressed = repeating(RepeatKey::Down, KeyCode::ArrowDown);
         let backspace_pressed = repeating(RepeatKey::Backspace, KeyCode::Backspace);
         let delete_pressed = repeating(RepeatKey::Delete, KeyCode::Delete);
 
@@ -162,8 +177,10 @@ impl InputState {
             mouse_down: mouse.is_button_pressed(MouseButton::Left),
             mouse_just_pressed: mouse.is_button_just_pressed(MouseButton::Left),
             mouse_just_released: mouse.is_button_just_released(MouseButton::Left),
+            right_mouse_just_pressed: mouse.is_button_just_pressed(MouseButton::Right),
             scroll_delta: mouse.wheel_delta(),
             typed_chars,
+            typed_text: kb.typed_text().chars().collect(),
             enter_pressed: kb.is_key_just_pressed(KeyCode::Enter)
                 || kb.is_key_just_pressed(KeyCode::NumpadEnter),
             escape_pressed: kb.is_key_just_pressed(KeyCode::Escape),
@@ -171,6 +188,8 @@ impl InputState {
             tab_pressed: kb.is_key_just_pressed(KeyCode::Tab),
             left_pressed,
             right_pressed,
+            up_pressed,
+            down_pressed,
             home_pressed: kb.is_key_just_pressed(KeyCode::Home),
             end_pressed: kb.is_key_just_pressed(KeyCode::End),
             delete_pressed,
@@ -186,6 +205,8 @@ pub(crate) enum RepeatKey {
     Right = 1,
     Backspace = 2,
     Delete = 3,
+    Up = 4,
+    Down = 5,
 }
 
 /// Per-key hold timer: fires on the initial press, then after
@@ -230,10 +251,11 @@ impl RepeatTimer {
     }
 }
 
-/// Repeat timers for all navigation/deletion keys a text input uses.
+/// Repeat timers for all navigation/deletion keys text inputs and list
+/// popups (combo boxes, context menus) use.
 #[derive(Debug, Clone, Copy, Default)]
 pub struct KeyRepeat {
-    timers: [RepeatTimer; 4],
+    timers: [RepeatTimer; 6],
 }
 
 impl KeyRepeat {
@@ -260,6 +282,9 @@ mod tests {
         assert!(!input.tab_pressed);
         assert!(!input.left_pressed);
         assert!(!input.right_pressed);
+        assert!(!input.up_pressed);
+        assert!(!input.down_pressed);
+        assert!(!input.right_mouse_just_pressed);
         assert!(!input.home_pressed);
         assert!(!input.end_pressed);
         assert!(!input.delete_pressed);
@@ -0,0 +1,56 @@
+//! Double-click detection, used to start inline renames (hierarchy rows,
+//! the inspector's name field).
+
+use super::{InteractionManager, WidgetId};
+
+/// Longest gap (seconds) between two clicks on a widget that still counts
+/// as a double-click.
+pub const DOUBLE_CLICK_TIME: f32 = 0.4;
+
+impl InteractionManager {
+    /// Record a click on `id`; returns whether it completes a double-click.
+    /// A double-click consumes the pair, so a third click starts over.
+    pub(super) fn register_click(&mut self, id: WidgetId) -> bool {
+        let double = self
+            .last_click
+            .is_some_and(|(last, at)| last == id && self.time - at <= DOUBLE_CLICK_TIME);
+        self.last_click = if double { None } else { Some((id, self.time)) };
+        double
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use glam::Vec2;
+
+    use super::*;
+    use crate::interaction::tests::input_with_mouse;
+    use crate::Rect;
+
+    #[test]
+    fn test_second_quick_click_is_a_double_click() {
+        let mut manager = InteractionManager::new();
+        let (id, other) = (WidgetId::from_str("row"), WidgetId::from_str("other_row"));
+        let bounds = Rect::new(0.0, 0.0, 100.0, 20.0);
+        let click = |manager: &mut InteractionManager, id: WidgetId, dt: f32| {
+            manager.begin_frame_dt(&input_with_mouse(Vec2::new(10.0, 10.0), true), dt);
+            manager.interact(id, bounds, true);
+            manager.end_frame();
+            let mut input = input_with_mouse(Vec2::new(10.0, 10.0), true);
+            input.update();
+            input.mouse_mut().handle_button_release(input::prelude::MouseButton::Left);
+            manager.begin_frame_dt(&input, dt);
+            let result = manager.interact(id, bounds, true);
+            manager.end_frame();
+            result
+        };
+
+        assert!(!click(&mut manager, id, 0.05).double_clicked);
+        let second = click(&mut manager, id, 0.05);
+        assert!(second.clicked && second.double_clicked);
+        assert!(!click(&mut manager, id, 0.05).double_clicked, "a third click starts over");
+
+        assert!(!click(&mut manager, id, 1.0).double_clicked, "too slow");
+        assert!(!click(&mut manager, other, 0.05).double_clicked, "another widget");
+    }
+}
@@ -9,6 +9,10 @@ use crate::input_state::{InputState, KeyRepeat};
 use crate::text_edit::TextEditState;
 use crate::Rect;
 
+mod double_click;
+
+pub use double_click::DOUBLE_CLICK_TIME;
+
 /// Fallback frame delta for [`InteractionManager::begin_frame`] callers that
 /// don't thread a real dt (key repeat paces off this).
 const DEFAULT_FRAME_DT: f32 = 1.0 / 60.0;
@@ -44,6 +48,14 @@ impl WidgetId {
     pub fn value(&self) -> u64 {
         self.0
     }
+
+    /// Derive the ID of this widget's `index`-th child (e.g. a popup row).
+    pub fn child(&self, index: usize) -> Self {
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        self.0.hash(&mut hasher);
+        index.hash(&mut hasher);
+        Self(hasher.finish())
+    }
 }
 
 impl From<&str> for WidgetId {
@@ -84,6 +96,9 @@ pub struct InteractionResult {
     pub state: WidgetState,
     /// True if the widget was clicked (mouse released over it while active)
     pub clicked: bool,
+    /// True if this click is the second within [`DOUBLE_CLICK_TIME`] on the
+    /// same widget (`clicked` is set too)
+    pub double_clicked: bool,
     /// True if the widget is currently being dragged
     pub dragging: bool,
     /// Mouse position relative to widget bounds
@@ -95,6 +110,7 @@ impl Default for InteractionResult {
         Self {
             state: WidgetState::Normal,
             clicked: false,
+            double_clicked: false,
             dragging: false,
             local_mouse: Vec2::ZERO,
         }
@@ -108,6 +124,22 @@ pub struct WidgetPersistentState {
     pub seen_this_frame: bool,
     /// Text-editing state (buffer, cursor, selection) for input widgets
     pub edit: TextEditState,
+    /// Open/highlight state for list popups (dropdown, combo box, context menu)
+    pub popup: PopupState,
+}
+
+/// Persistent state of a list popup (dropdown, combo box, context menu).
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct PopupState {
+    /// Whether the popup list is open
+    pub open: bool,
+    /// Row under the keyboard/mouse highlight while open
+    pub highlighted: usize,
+    /// Top-left of the popup (context menus open at the cursor)
+    pub anchor: Vec2,
+    /// Mouse position last frame — hover only moves the highlight when the
+    /// mouse moves, so arrow-key navigation isn't overridden by a resting cursor
+    pub last_mouse: Vec2,
 }
 
 /// Tracks interaction state for all widgets in the UI.
@@ -130,6 +162,10 @@ pub struct InteractionManager {
     overlay_scope: bool,
     /// Hold timers for key repeat (arrows, Backspace, Delete)
     key_repeat: KeyRepeat,
+    /// Seconds since the manager was created, summed from frame deltas
+    time: f32,
+    /// Last single click, for double-click detection
+    last_click: Option<(WidgetId, f32)>,
 }
 
 impl Default for InteractionManager {
@@ -150,6 +186,8 @@ impl InteractionManager {
             blocking_rects: Vec::new(),
             overlay_scope: false,
             key_repeat: KeyRepeat::default(),
+            time: 0.0,
+            last_click: None,
         }
     }
 
@@ -163,6 +201,7 @@ impl InteractionManager {
     /// frame) paces held-key repeat for text inputs.
     pub fn begin_frame_dt(&mut self, input: &InputHandler, dt: f32) {
         self.input = InputState::from_input_handler_with_repeat(input, &mut self.key_repeat, dt);
+        self.time += dt;
 
         // Clear hot widget at start of frame (will be set by widgets that are hovered)
         self.hot_widget = None;
@@ -304,6 +343,7 @@ impl InteractionManager {
 
         // Click happens when mouse is released while active AND still over the widget
         let clicked = is_active && self.input.mouse_just_released && mouse_in_bounds;
+        let double_clicked = clicked && self.register_click(id);
 
         let state = if is_active && !self.input.mouse_just_released {
             WidgetState::Active
@@ -316,223 +356,12 @@ impl InteractionManager {
         InteractionResult {
             state,
             clicked,
+            double_clicked,
             dragging: is_active && self.input.mouse_down,
             local_mouse,
         }
     }
-
 }
 
 #[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_widget_id_from_str() {
-        let id1 = WidgetId::from_str("button_1");
-        let id2 = WidgetId::from_str("button_1");
-        let id3 = WidgetId::from_str("button_2");
-
-        assert_eq!(id1, id2);
-        assert_ne!(id1, id3);
-    }
-
-    #[test]
-    fn test_widget_id_from_str_index() {
-        let id1 = WidgetId::from_str_index("item", 0);
-        let id2 = WidgetId::from_str_index("item", 1);
-        let id3 = WidgetId::from_str_index("item", 0);
-
-        assert_ne!(id1, id2);
-        assert_eq!(id1, id3);
-    }
-
-    #[test]
-    fn test_widget_id_conversions() {
-        let id1: WidgetId = "test".into();
-        let id2: WidgetId = WidgetId::from_str("test");
-        assert_eq!(id1, id2);
-
-        let id3: WidgetId = 12345u64.into();
-        assert_eq!(id3.value(), 12345);
-
-        let id4: WidgetId = ("list", 5).into();
-        let id5 = WidgetId::from_str_index("list", 5);
-        assert_eq!(id4, id5);
-    }
-
-    /// Build an InputHandler with the mouse at `pos`, optionally pressed.
-    fn input_with_mouse(pos: Vec2, pressed: bool) -> InputHandler {
-        use input::prelude::MouseButton;
-        let mut input = InputHandler::new();
-        input.mouse_mut().update_position(pos.x, pos.y);
-        if pressed {
-            input.mouse_mut().handle_button_press(MouseButton::Left);
-        }
-        input
-    }
-
-    #[test]
-    fn test_blocking_rect_makes_outside_widget_inert() {
-        let mut manager = InteractionManager::new();
-        let input = input_with_mouse(Vec2::new(50.0, 50.0), true);
-        manager.begin_frame(&input);
-
-        // A dropdown covers the widget's area
-        manager.push_blocking_rect(Rect::new(0.0, 0.0, 100.0, 100.0));
-
-        let id = WidgetId::from_str("widget_under_dropdown");
-        let result = manager.interact(id, Rect::new(40.0, 40.0, 50.0, 50.0), true);
-
-        assert_eq!(result.state, WidgetState::Normal, "no hover under a blocking rect");
-        assert!(!result.clicked);
-        assert!(!result.dragging);
-        assert!(manager.active_widget.is_none(), "press must not activate a blocked widget");
-        assert!(manager.hot_widget.is_none());
-    }
-
-    #[test]
-    fn test_overlay_scope_widget_stays_interactive_over_blocking_rect() {
-        let mut manager = InteractionManager::new();
-        let input = input_with_mouse(Vec2::new(50.0, 50.0), true);
-        manager.begin_frame(&input);
-
-        manager.push_blocking_rect(Rect::new(0.0, 0.0, 100.0, 100.0));
-        manager.set_overlay_scope(true);
-
-        let id = WidgetId::from_str("dropdown_item");
-        let result = manager.interact(id, Rect::new(40.0, 40.0, 50.0, 50.0), true);
-
-        assert_eq!(result.state, WidgetState::Active, "overlay widget receives the press");
-        assert!(result.dragging);
-    }
-
-    #[test]
-    fn test_widget_outside_blocking_rect_unaffected() {
-        let mut manager = InteractionManager::new();
-        let input = input_with_mouse(Vec2::new(300.0, 300.0), false);
-        manager.begin_frame(&input);
-
-        manager.push_blocking_rect(Rect::new(0.0, 0.0, 100.0, 100.0));
-
-        let id = WidgetId::from_str("far_widget");
-        let result = manager.interact(id, Rect::new(280.0, 280.0, 50.0, 50.0), true);
-        assert_eq!(result.state, WidgetState::Hovered, "blocking only applies under the rect");
-    }
-
-    #[test]
-    fn test_blocked_widget_persistent_state_survives_frame() {
-        let mut manager = InteractionManager::new();
-        let id = WidgetId::from_str("blocked_text_input");
-        manager.get_state(id).edit.text = "edit buffer".to_string();
-
-        let input = input_with_mouse(Vec2::new(50.0, 50.0), false);
-        manager.begin_frame(&input);
-        manager.push_blocking_rect(Rect::new(0.0, 0.0, 100.0, 100.0));
-        manager.interact(id, Rect::new(40.0, 40.0, 20.0, 20.0), true);
-        manager.end_frame();
-
-        let state = manager.get_state_if_exists(id).expect("blocked widget state retained");
-        assert_eq!(state.edit.text, "edit buffer");
-    }
-
-    #[test]
-    fn test_begin_frame_clears_blocking_state() {
-        let mut manager = InteractionManager::new();
-        manager.push_blocking_rect(Rect::new(0.0, 0.0, 100.0, 100.0));
-        manager.set_overlay_scope(true);
-        assert!(manager.is_blocked_at(Vec2::new(50.0, 50.0)));
-
-        manager.begin_frame(&InputHandler::new());
-        assert!(!manager.is_blocked_at(Vec2::new(50.0, 50.0)));
-        assert!(!manager.overlay_scope);
-    }
-
-    #[test]
-    fn test_has_focus_tracks_any_focused_widget() {
-        let mut manager = InteractionManager::new();
-        assert!(!manager.has_focus());
-
-        manager.set_focus(WidgetId::from_str("field"));
-        assert!(manager.has_focus());
-
-        manager.clear_focus();
-        assert!(!manager.has_focus());
-    }
-
-    #[test]
-    fn test_interaction_manager_new() {
-        let manager = InteractionManager::new();
-        assert!(manager.hot_widget.is_none());
-        assert!(manager.active_widget.is_none());
-        assert!(manager.focus_widget.is_none());
-    }
-
-    #[test]
-    fn test_interaction_manager_state() {
-        let mut manager = InteractionManager::new();
-        let id = WidgetId::from_str("test_widget");
-
-        let state = manager.get_state(id);
-        state.edit.text = "hello".to_string();
-
-        let state = manager.get_state_if_exists(id).unwrap();
-        assert!(state.seen_this_frame);
-        assert_eq!(state.edit.text, "hello");
-    }
-
-    #[test]
-    fn test_unseen_widget_state_is_garbage_collected() {
-        let mut manager = InteractionManager::new();
-        let id = WidgetId::from_str("transient");
-
-        manager.get_state(id).edit.text = "data".to_string();
-        manager.end_frame();
-        assert!(manager.get_state_if_exists(id).is_some(), "seen state survives the frame");
-
-        // Next frame: widget never submitted
-        manager.begin_frame(&InputHandler::new());
-        manager.end_frame();
-        assert!(manager.get_state_if_exists(id).is_none(), "unseen state is collected");
-    }
-
-    #[test]
-    fn test_focused_widget_state_survives_unseen_frame() {
-        let mut manager = InteractionManager::new();
-        let id = WidgetId::from_str("text_input");
-
-        manager.get_state(id).edit.text = "editing".to_string();
-        manager.set_focus(id);
-        manager.end_frame();
-
-        // Next frame: widget not submitted (e.g., panel skipped a frame),
-        // but it holds focus so its edit buffer must be retained.
-        manager.begin_frame(&InputHandler::new());
-        manager.end_frame();
-
-        let state = manager.get_state_if_exists(id).expect("focused state retained");
-        assert_eq!(state.edit.text, "editing");
-    }
-
-    #[test]
-    fn test_interaction_result_default() {
-        let result = InteractionResult::default();
-        assert_eq!(result.state, WidgetState::Normal);
-        assert!(!result.clicked);
-        assert!(!result.dragging);
-    }
-
-    #[test]
-    fn test_focus_management() {
-        let mut manager = InteractionManager::new();
-        let id = WidgetId::from_str("text_input");
-
-        assert!(!manager.is_focused(id));
-
-        manager.set_focus(id);
-        assert!(manager.is_focused(id));
-
-        manager.clear_focus();
-        assert!(!manager.is_focused(id));
-    }
-}
+mod tests;
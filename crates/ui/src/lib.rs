@@ -6,9 +6,15 @@
 //!
 //! # Features
 //! - Immediate-mode API for simplicity
-//! - Common widgets: buttons, labels, sliders, checkboxes, progress bars
-//! - Customizable themes (dark and light included)
+//! - Common widgets: buttons, labels, sliders, checkboxes, progress bars,
+//!   dropdowns, combo boxes, and context menus
+//! - Text with kerning, word wrap, and rich-text markup (colored and bold
+//!   spans, inline icons)
+//! - Customizable themes (dark and light included), loadable from RON/TOML
+//!   files, hot-swappable at runtime, with per-widget style overrides
 //! - Efficient draw command batching
+//! - Localized text: per-locale Fluent/TOML translations with fallback
+//!   chains, looked up with `tr!`
 //! - Mouse interaction with hover, click, and drag support
 //!
 //! # Example
@@ -41,22 +47,34 @@
 mod context;
 mod draw;
 mod font;
+pub mod i18n;
 mod input_state;
 mod interaction;
+mod rich_text;
 mod style;
 mod text_edit;
+mod theme_io;
 
 // Re-export main types
-pub use context::{TextAlign, UIContext};
-pub use draw::{DrawCommand, DrawList, TextDrawData, GlyphDrawData};
-pub use font::{FontError, FontHandle, FontManager, FontMetrics, GlyphInfo, LayoutGlyph, RasterizedGlyph, TextLayout};
+pub use context::{IconImage, TextAlign, UIContext};
+pub use draw::{DrawCommand, DrawList, TextDrawData, GlyphDrawData, FULL_UV};
+pub use font::{
+    FontError, FontHandle, FontManager, FontMetrics, GlyphInfo, LayoutGlyph, LayoutInline, LayoutRun, RasterizedGlyph,
+    TextLayout,
+};
+pub use i18n::{I18nError, Localizer};
 pub use input_state::{InputState, KeyRepeat, REPEAT_DELAY, REPEAT_INTERVAL};
 pub use interaction::{
-    InteractionManager, InteractionResult, WidgetId, WidgetPersistentState, WidgetState,
+    InteractionManager, InteractionResult, PopupState, WidgetId, WidgetPersistentState, WidgetState,
+    DOUBLE_CLICK_TIME,
 };
+pub use rich_text::{parse_rich_text, RichSegment, RichSpan};
 pub use text_edit::TextEditState;
 pub use common::Rect;
-pub use style::{ButtonStyle, Color, PanelStyle, SliderStyle, TextInputStyle, TextStyle, Theme};
+pub use style::{
+    ButtonStyle, Color, PanelStyle, SliderStyle, TextInputStyle, TextStyle, Theme, WidgetStyle,
+};
+pub use theme_io::{ThemeError, ThemeWatcher};
 
 /// Prelude module for convenient imports.
 pub mod prelude {
@@ -0,0 +1,169 @@
+//! Rich-text markup for labels: a small BBCode-style markup parsed into
+//! styled segments that [`UIContext::rich_label`](crate::UIContext::rich_label)
+//! lays out as one paragraph. See [`parse_rich_text`] for the tags.
+
+use crate::Color;
+
+/// A run of text with uniform style.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RichSpan {
+    /// The text
+    pub text: String,
+    /// Color override (`None` = the label's color)
+    pub color: Option<Color>,
+    /// Whether the span is bold
+    pub bold: bool,
+}
+
+/// One piece of parsed rich text.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RichSegment {
+    /// Styled text
+    Text(RichSpan),
+    /// Inline icon by registered name
+    Icon(String),
+}
+
+/// A recognized markup tag.
+enum Tag {
+    Color(Color),
+    EndColor,
+    Bold,
+    EndBold,
+    Icon(String),
+}
+
+/// Parse a tag body (the text between `[` and `]`).
+fn parse_tag(body: &str) -> Option<Tag> {
+    match body {
+        "b" => Some(Tag::Bold),
+        "/b" => Some(Tag::EndBold),
+        "/color" => Some(Tag::EndColor),
+        _ => {
+            if let Some(hex) = body.strip_prefix("color=#") {
+                parse_hex_color(hex).map(Tag::Color)
+            } else {
+                body.strip_prefix("icon=").filter(|name| !name.is_empty()).map(|name| Tag::Icon(name.to_string()))
+            }
+        }
+    }
+}
+
+/// `RRGGBB` or `RRGGBBAA` hex digits.
+fn parse_hex_color(hex: &str) -> Option<Color> {
+    let value = u32::from_str_radix(hex, 16).ok()?;
+    match hex.len() {
+        6 => Some(Color::from_hex(value)),
+        8 => Some(Color::from_hex_rgba(value)),
+        _ => None,
+    }
+}
+
+/// Parse rich-text markup into styled segments.
+///
+/// - `[color=#RRGGBB]…[/color]` (or `#RRGGBBAA`) — span color
+/// - `[b]…[/b]` — bold (the context's bold font, or faux bold without one)
+/// - `[icon=name]` — inline icon registered with
+///   [`UIContext::register_icon`](crate::UIContext::register_icon)
+/// - `[[` — a literal `[`
+///
+/// Tags nest; anything that isn't a well-formed tag is kept as text, so
+/// arbitrary strings never fail to parse. Empty spans are dropped.
+///
+/// ```
+/// use ui::{parse_rich_text, RichSegment};
+///
+/// let segments = parse_rich_text("Gold: [color=#FFD700][b]250[/b][/color] [icon=coin]");
+/// assert_eq!(segments.len(), 4);
+/// assert!(matches!(&segments[1], RichSegment::Text(span) if span.bold && span.text == "250"));
+/// assert_eq!(segments[3], RichSegment::Icon("coin".to_string()));
+/// ```
+pub fn parse_rich_text(markup: &str) -> Vec<RichSegment> {
+    let mut segments = Vec::new();
+    let mut colors: Vec<Color> = Vec::new();
+    let mut bold_depth = 0usize;
+    let mut text = String::new();
+
+    let flush = |text: &mut String, segments: &mut Vec<RichSegment>, color: Option<Color>, bold: bool| {
+        if !text.is_empty() {
+            segments.push(RichSegment::Text(RichSpan { text: std::mem::take(text), color, bold }));
+        }
+    };
+
+    let mut rest = markup;
+    while let Some(open) = rest.find('[') {
+        text.push_str(&rest[..open]);
+        let after = &rest[open + 1..];
+        if let Some(stripped) = after.strip_prefix('[') {
+            text.push('[');
+            rest = stripped;
+            continue;
+        }
+        let tag = after.find(']').and_then(|close| parse_tag(&after[..close]).map(|tag| (tag, close)));
+        let Some((tag, close)) = tag else {
+            text.push('[');
+            rest = after;
+            continue;
+        };
+
+        flush(&mut text, &mut segments, colors.last().copied(), bold_depth > 0);
+        match tag {
+            Tag::Color(color) => colors.push(color),
+            Tag::EndColor => {
+                colors.pop();
+            }
+            Tag::Bold => bold_depth += 1,
+            Tag::EndBold => bold_depth = bold_depth.saturating_sub(1),
+            Tag::Icon(name) => segments.push(RichSegment::Icon(name)),
+        }
+        rest = &after[close + 1..];
+    }
+    text.push_str(rest);
+    flush(&mut text, &mut segments, colors.last().copied(), bold_depth > 0);
+    segments
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn span(text: &str, color: Option<Color>, bold: bool) -> RichSegment {
+        RichSegment::Text(RichSpan { text: text.to_string(), color, bold })
+    }
+
+    #[test]
+    fn test_plain_text_is_one_span() {
+        assert_eq!(parse_rich_text("Hello world"), vec![span("Hello world", None, false)]);
+        assert!(parse_rich_text("").is_empty());
+    }
+
+    #[test]
+    fn test_color_and_bold_tags_nest() {
+        let red = Color::from_hex(0xFF0000);
+        let segments = parse_rich_text("a[color=#FF0000]b[b]c[/b][/color]d");
+        assert_eq!(
+            segments,
+            vec![span("a", None, false), span("b", Some(red), false), span("c", Some(red), true), span("d", None, false)]
+        );
+    }
+
+    #[test]
+    fn test_icons_split_text() {
+        assert_eq!(
+            parse_rich_text("x[icon=coin]y"),
+            vec![span("x", None, false), RichSegment::Icon("coin".to_string()), span("y", None, false)]
+        );
+    }
+
+    #[test]
+    fn test_malformed_tags_and_escapes_stay_literal() {
+        assert_eq!(parse_rich_text("[[b] [x] [color=#12] [b"), vec![span("[b] [x] [color=#12] [b", None, false)]);
+        assert_eq!(parse_rich_text("[/b]ok"), vec![span("ok", None, false)], "unbalanced close is ignored");
+    }
+
+    #[test]
+    fn test_alpha_colors() {
+        let segments = parse_rich_text("[color=#00FF0080]g");
+        assert_eq!(segments, vec![span("g", Some(Color::from_hex_rgba(0x00FF0080)), false)]);
+    }
+}
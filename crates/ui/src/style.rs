@@ -1,4 +1,12 @@
 //! UI styling system with colors and visual properties.
+//!
+//! Every style struct is serde-ready with `#[serde(default)]`, so theme
+//! files (see `theme_io.rs`) only need the fields they change; anything
+//! omitted keeps the dark default.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
 
 // Re-export Color from common crate
 pub use common::Color;
@@ -27,7 +35,8 @@ mod palette {
 }
 
 /// Style configuration for buttons.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct ButtonStyle {
     /// Background color in normal state
     pub background: Color,
@@ -70,7 +79,8 @@ impl Default for ButtonStyle {
 }
 
 /// Style configuration for panels/containers.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct PanelStyle {
     /// Background color
     pub background: Color,
@@ -98,7 +108,8 @@ impl Default for PanelStyle {
 }
 
 /// Style configuration for sliders.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct SliderStyle {
     /// Track background color
     pub track_background: Color,
@@ -132,7 +143,8 @@ impl Default for SliderStyle {
 }
 
 /// Style configuration for text input fields (e.g. `float_input`).
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct TextInputStyle {
     /// Background color in normal state
     pub background: Color,
@@ -178,7 +190,8 @@ impl Default for TextInputStyle {
 }
 
 /// Style configuration for text labels.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct TextStyle {
     /// Text color
     pub color: Color,
@@ -198,9 +211,25 @@ impl Default for TextStyle {
     }
 }
 
+/// Per-widget style override, keyed by widget id in [`Theme::overrides`].
+///
+/// Each `Some` style replaces the theme's style of that kind for the one
+/// widget; `None` keeps the theme's. Buttons and checkboxes read `button`,
+/// sliders `slider`, float inputs `text_input`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WidgetStyle {
+    /// Button/checkbox style for this widget
+    pub button: Option<ButtonStyle>,
+    /// Slider style for this widget
+    pub slider: Option<SliderStyle>,
+    /// Text input style for this widget
+    pub text_input: Option<TextInputStyle>,
+}
+
 /// Global UI theme containing all widget styles.
-#[derive(Debug, Clone)]
-#[derive(Default)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct Theme {
     /// Button style
     pub button: ButtonStyle,
@@ -212,6 +241,10 @@ pub struct Theme {
     pub text: TextStyle,
     /// Text input style
     pub text_input: TextInputStyle,
+    /// Per-widget overrides, keyed by the string the widget id was made from
+    /// (`ui.button("play_btn", ..)` → `"play_btn"`)
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    pub overrides: HashMap<String, WidgetStyle>,
 }
 
 
@@ -271,6 +304,7 @@ impl Theme {
                 selection_color: Color::from_hex(light::ACCENT).with_alpha(0.35),
                 cursor_color: Color::BLACK,
             },
+            overrides: HashMap::new(),
         }
     }
 }
@@ -0,0 +1,215 @@
+//! Theme files: load/save [`Theme`] as RON or TOML, plus a polling watcher
+//! for hot-swapping a skin while the game runs.
+//!
+//! The format is picked from the file extension (`.ron` or `.toml`). Every
+//! style field is optional in the file (missing fields keep the dark
+//! default), so a skin can be as small as a single color:
+//!
+//! ```ron
+//! (
+//!     button: (background: (r: 0.8, g: 0.2, b: 0.2, a: 1.0), corner_radius: 0.0),
+//!     overrides: {
+//!         "quit_btn": (button: Some((text_color: (r: 1.0, g: 0.9, b: 0.0, a: 1.0)))),
+//!     },
+//! )
+//! ```
+
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use crate::Theme;
+
+/// Errors from loading or saving a theme file.
+#[derive(Debug, thiserror::Error)]
+pub enum ThemeError {
+    #[error("Theme IO error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Theme parse error: {0}")]
+    Parse(String),
+
+    #[error("Unsupported theme file extension: {0:?} (expected .ron or .toml)")]
+    UnsupportedFormat(PathBuf),
+}
+
+/// On-disk theme formats.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ThemeFormat {
+    Ron,
+    Toml,
+}
+
+impl ThemeFormat {
+    fn of(path: &Path) -> Result<Self, ThemeError> {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("ron") => Ok(Self::Ron),
+            Some(ext) if ext.eq_ignore_ascii_case("toml") => Ok(Self::Toml),
+            _ => Err(ThemeError::UnsupportedFormat(path.to_path_buf())),
+        }
+    }
+}
+
+impl Theme {
+    /// Parse a theme from RON text.
+    pub fn from_ron_str(text: &str) -> Result<Self, ThemeError> {
+        ron::from_str(text).map_err(|e| ThemeError::Parse(e.to_string()))
+    }
+
+    /// Parse a theme from TOML text.
+    pub fn from_toml_str(text: &str) -> Result<Self, ThemeError> {
+        toml::from_str(text).map_err(|e| ThemeError::Parse(e.to_string()))
+    }
+
+    /// Serialize this theme as pretty RON (a starting point for hand-edited skins).
+    pub fn to_ron_string(&self) -> Result<String, ThemeError> {
+        ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())
+            .map_err(|e| ThemeError::Parse(e.to_string()))
+    }
+
+    /// Serialize this theme as TOML.
+    pub fn to_toml_string(&self) -> Result<String, ThemeError> {
+        toml::to_string_pretty(self).map_err(|e| ThemeError::Parse(e.to_string()))
+    }
+
+    /// Load a theme file; `.ron` and `.toml` are supported.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, ThemeError> {
+        let path = path.as_ref();
+        let format = ThemeFormat::of(path)?;
+        let text = std::fs::read_to_string(path)?;
+        match format {
+            ThemeFormat::Ron => Self::from_ron_str(&text),
+            ThemeFormat::Toml => Self::from_toml_str(&text),
+        }
+    }
+
+    /// Save this theme, in the format matching the path's extension.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), ThemeError> {
+        let path = path.as_ref();
+        let text = match ThemeFormat::of(path)? {
+            ThemeFormat::Ron => self.to_ron_string()?,
+            ThemeFormat::Toml => self.to_toml_string()?,
+        };
+        std::fs::write(path, text)?;
+        Ok(())
+    }
+}
+
+/// Polls a theme file's modification time so edits can be hot-swapped.
+///
+/// ```no_run
+/// # use ui::{ThemeWatcher, UIContext};
+/// # let mut ui = UIContext::new();
+/// let mut watcher = ThemeWatcher::new("assets/ui_theme.ron");
+/// // Each frame (or every few frames):
+/// if let Some(Ok(theme)) = watcher.poll() {
+///     ui.set_theme(theme);
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ThemeWatcher {
+    path: PathBuf,
+    /// Modification time of the last load attempt (`None` = never loaded)
+    last_modified: Option<SystemTime>,
+}
+
+impl ThemeWatcher {
+    /// Watch `path`. The first [`poll`](Self::poll) loads it.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            last_modified: None,
+        }
+    }
+
+    /// The watched file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Reload the theme if the file changed since the last poll.
+    ///
+    /// Returns `None` when the file is unchanged or missing. A file that
+    /// fails to parse is reported once and retried after its next edit.
+    pub fn poll(&mut self) -> Option<Result<Theme, ThemeError>> {
+        let modified = std::fs::metadata(&self.path).and_then(|meta| meta.modified()).ok()?;
+        if self.last_modified == Some(modified) {
+            return None;
+        }
+        self.last_modified = Some(modified);
+        Some(Theme::load(&self.path))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Color, WidgetStyle};
+
+    #[test]
+    fn test_partial_ron_theme_keeps_defaults() {
+        let theme = Theme::from_ron_str(
+            "(button: (corner_radius: 0.0), text: (font_size: 20.0))",
+        )
+        .unwrap();
+        assert_eq!(theme.button.corner_radius, 0.0);
+        assert_eq!(theme.text.font_size, 20.0);
+        assert_eq!(theme.button.background, Theme::default().button.background);
+        assert_eq!(theme.slider, Theme::default().slider);
+    }
+
+    #[test]
+    fn test_toml_theme_with_override() {
+        let theme = Theme::from_toml_str(
+            r#"
+            [panel]
+            padding = 12.0
+
+            [overrides.quit_btn.button]
+            background = { r = 1.0, g = 0.0, b = 0.0, a = 1.0 }
+            "#,
+        )
+        .unwrap();
+        assert_eq!(theme.panel.padding, 12.0);
+        let button = theme.overrides["quit_btn"].button.as_ref().unwrap();
+        assert_eq!(button.background, Color::RED);
+        assert_eq!(button.padding, Theme::default().button.padding);
+    }
+
+    #[test]
+    fn test_round_trip_both_formats() {
+        let mut theme = Theme::light();
+        theme.overrides.insert(
+            "ok".into(),
+            WidgetStyle { slider: Some(Theme::dark().slider), ..Default::default() },
+        );
+        let ron = theme.to_ron_string().unwrap();
+        assert_eq!(Theme::from_ron_str(&ron).unwrap(), theme);
+        let toml = theme.to_toml_string().unwrap();
+        assert_eq!(Theme::from_toml_str(&toml).unwrap(), theme);
+    }
+
+    #[test]
+    fn test_load_rejects_unknown_extension_and_bad_text() {
+        assert!(matches!(
+            Theme::load("theme.json"),
+            Err(ThemeError::UnsupportedFormat(_))
+        ));
+        assert!(matches!(Theme::from_ron_str("(button: 3)"), Err(ThemeError::Parse(_))));
+    }
+
+    #[test]
+    fn test_watcher_reloads_only_on_change() {
+        let dir = std::env::temp_dir().join(format!("ui_theme_watch_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("skin.ron");
+
+        let mut watcher = ThemeWatcher::new(&path);
+        assert!(watcher.poll().is_none(), "missing file is not an error");
+
+        Theme::light().save(&path).unwrap();
+        assert_eq!(watcher.poll().unwrap().unwrap(), Theme::light());
+        assert!(watcher.poll().is_none(), "unchanged file is not reloaded");
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
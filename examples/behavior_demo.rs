@@ -10,9 +10,11 @@
 //! Controls: WASD/Arrow keys to move, ESC to exit
 //!
 //! Run with: cargo run --example behavior_demo
+//! Headless auto-play: cargo run --example behavior_demo -- --headless
 
 use engine_core::prelude::*;
 use std::path::Path;
+use std::process::ExitCode;
 
 /// Anchor asset paths to the repository so the example runs from any
 /// working directory.
@@ -54,9 +56,7 @@ impl Game for BehaviorDemo {
 
                 // Create physics system from scene settings (zero gravity for top-down)
                 if let Some(settings) = &instance.physics {
-                    let config = PhysicsConfig::new(Vec2::new(settings.gravity.0, settings.gravity.1))
-                        .with_scale(settings.pixels_per_meter);
-                    self.physics = Some(PhysicsSystem::with_config(config));
+                    self.physics = Some(settings.physics_system(PhysicsConfig::default()));
                 }
 
                 // Set named entities for FollowEntity behavior
@@ -99,11 +99,23 @@ impl Game for BehaviorDemo {
     // render() uses default implementation
 }
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// Headless auto-play (`-- --headless`): walk up-right and check the player
+/// left the origin while the chasers and companion are still around.
+fn headless_script() -> HeadlessScript {
+    HeadlessScript::new(150)
+        .hold(KeyCode::KeyD, 5, 100)
+        .hold(KeyCode::KeyW, 5, 60)
+        .expect_spawned(&["player", "companion", "chaser_1"])
+        .expect_final("player moved", |world| {
+            named_position(world, "player").is_some_and(|position| position.length() > 20.0)
+        })
+}
+
+fn main() -> ExitCode {
     let config = GameConfig::new("Behavior Demo - Insiculous 2D")
         .with_size(800, 600)
         .with_clear_color(0.1, 0.1, 0.12, 1.0)
         .with_asset_base_path(EXAMPLES_DIR);
 
-    run_game(BehaviorDemo::new(), config)
+    run_example(BehaviorDemo::new(), config, headless_script())
 }
@@ -1,6 +1,7 @@
 //! Editor demo - wraps the Hello World platformer in the full editor UI.
 //!
 //! Run with: cargo run --example editor_demo --features editor
+//! Headless auto-play: cargo run --example editor_demo --features editor -- --headless
 //!
 //! This loads the same scene and game logic as hello_world.rs, but wrapped
 //! inside the editor. Use Play/Pause/Stop (Ctrl+P / Ctrl+Shift+P) to run
@@ -18,9 +19,10 @@
 //!   G            Toggle grid
 
 use engine_core::prelude::*;
-use editor_integration::run_game_with_editor;
+use editor_integration::run_example_with_editor;
 use input::{InputMapping, InputSource};
 use std::path::Path;
+use std::process::ExitCode;
 
 /// Anchor all asset paths to the repository so the example runs from any
 /// working directory.
@@ -315,34 +317,34 @@ impl Game for PlatformerGame {
 }
 
 impl PlatformerGame {
-    /// Add Name + GlobalTransform2D to entities that lack them, so the editor
-    /// hierarchy and inspector work nicely.
+    /// Add Name to entities that lack it, so the editor hierarchy and
+    /// inspector work nicely.
     fn add_editor_names(&self, ctx: &mut GameContext) {
-        use ecs::{GlobalTransform2D, Name};
+        use ecs::Name;
 
         if let Some(instance) = &self.scene_instance {
             for (name, &entity_id) in &instance.named_entities {
                 if ctx.world.get::<Name>(entity_id).is_none() {
                     ctx.world.add_component(&entity_id, Name::new(name)).ok();
                 }
-                if ctx.world.get::<GlobalTransform2D>(entity_id).is_none() {
-                    ctx.world.add_component(&entity_id, GlobalTransform2D::default()).ok();
-                }
-            }
-        }
-
-        // Ensure all entities have a GlobalTransform2D for the editor gizmo
-        for entity_id in ctx.world.entities() {
-            if ctx.world.get::<Transform2D>(entity_id).is_some()
-                && ctx.world.get::<GlobalTransform2D>(entity_id).is_none()
-            {
-                ctx.world.add_component(&entity_id, GlobalTransform2D::default()).ok();
             }
         }
     }
 }
 
-fn main() {
+/// Headless auto-play (`-- --headless`): press F5 to enter play mode, walk
+/// right, and check the wrapped game received the input.
+fn headless_script() -> HeadlessScript {
+    HeadlessScript::new(180)
+        .tap(KeyCode::F5, 5)
+        .hold(KeyCode::KeyD, 20, 140)
+        .expect_spawned(&["player"])
+        .expect_final("player walked right in play mode", |world| {
+            named_position(world, "player").is_some_and(|position| position.x > PLAYER_SPAWN.x + 20.0)
+        })
+}
+
+fn main() -> ExitCode {
     env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("info")).init();
 
     let config = GameConfig::new("Insiculous 2D - Editor Demo")
@@ -350,7 +352,5 @@ fn main() {
         .with_clear_color(0.1, 0.1, 0.15, 1.0)
         .with_asset_base_path(EXAMPLES_DIR);
 
-    if let Err(e) = run_game_with_editor(PlatformerGame::new(), config) {
-        log::error!("Editor error: {}", e);
-    }
+    run_example_with_editor(PlatformerGame::new(), config, headless_script())
 }
@@ -25,10 +25,13 @@
 //!
 //! Scene file: examples/assets/scenes/hello_world.scene.ron
 //! Font file: examples/assets/fonts/font.ttf (optional - download any TTF font)
+//!
+//! Headless auto-play: cargo run --example hello_world -- --headless
 
 use engine_core::prelude::*;
 use input::{InputMapping, InputSource};
 use std::path::Path;
+use std::process::ExitCode;
 
 /// Anchor all asset paths to the repository so the example runs from any
 /// working directory (`GameConfig::with_asset_base_path` resolves textures
@@ -190,14 +193,7 @@ impl Game for HelloWorld {
                 self.behaviors.set_named_entities(instance.named_entities.clone());
 
                 // Create a physics system from scene settings
-                let physics_config = if let Some(settings) = &instance.physics {
-                    PhysicsConfig::new(Vec2::new(settings.gravity.0, settings.gravity.1))
-                        .with_scale(settings.pixels_per_meter)
-                } else {
-                    PhysicsConfig::platformer()
-                };
-
-                self.physics = Some(PhysicsSystem::with_config(physics_config));
+                self.physics = Some(instance.physics_system(PhysicsConfig::platformer()));
                 self.scene_instance = Some(instance);
             }
             Err(e) => {
@@ -492,7 +488,19 @@ impl Game for HelloWorld {
     // render() uses the default implementation which extracts sprites from ECS
 }
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// Headless auto-play (`-- --headless`): walk right, jump, and check the
+/// player actually travelled.
+fn headless_script() -> HeadlessScript {
+    HeadlessScript::new(180)
+        .hold(KeyCode::KeyD, 10, 120)
+        .tap(KeyCode::Space, 40)
+        .expect_spawned(&["player"])
+        .expect_final("player walked right", |world| {
+            named_position(world, "player").is_some_and(|position| position.x > PLAYER_SPAWN.x + 20.0)
+        })
+}
+
+fn main() -> ExitCode {
     // Create game configuration. The asset base path makes texture paths in
     // the scene file resolve correctly regardless of the working directory.
     let game_config = GameConfig::new("Hello World - Insiculous 2D Physics Demo")
@@ -500,7 +508,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         .with_clear_color(0.1, 0.1, 0.15, 1.0)
         .with_asset_base_path(EXAMPLES_DIR);
 
-    // Create and run the game
+    // Create and run the game (or auto-play it with --headless)
     let game = HelloWorld::new();
-    run_game(game, game_config)
+    run_example(game, game_config, headless_script())
 }
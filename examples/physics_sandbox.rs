@@ -0,0 +1,202 @@
+//! Physics sandbox - drop boxes and balls onto a walled floor.
+//!
+//! Run with: cargo run --example physics_sandbox
+//! Headless auto-play: cargo run --example physics_sandbox -- --headless
+//!
+//! Controls:
+//!   SPACE  Drop a bouncy ball
+//!   B      Drop a crate
+//!   R      Clear everything that was dropped
+//!   ESC    Exit
+//!
+//! Everything is built in code from tinted white quads (balls draw as their
+//! bounding square), so no asset files are needed.
+//!
+//! Demonstrates:
+//! - `PhysicsSystem` with gravity, static walls, dynamic bodies
+//! - Adding bodies at runtime (picked up on the next physics update)
+//! - Removing bodies with `PhysicsSystem::destroy_entity`
+//! - `ctx.validation.check_physics` for orphaned physics state
+
+use engine_core::prelude::*;
+use ecs::Single;
+use std::process::ExitCode;
+
+const GRAVITY: Vec2 = Vec2::new(0.0, -980.0);
+const PIXELS_PER_METER: f32 = 100.0;
+/// Top surface of the floor, in world pixels.
+const FLOOR_TOP: f32 = -240.0;
+/// Inner faces of the side walls.
+const WALL_X: f32 = 360.0;
+const CRATE_SIZE: f32 = 40.0;
+const BALL_RADIUS: f32 = 14.0;
+/// Height bodies are dropped from.
+const DROP_Y: f32 = 260.0;
+
+/// Marks bodies the player dropped (cleared by R).
+struct Dropped;
+
+/// A white-quad sprite `size` pixels across.
+fn quad(size: Vec2, color: Vec4) -> Sprite {
+    Sprite::new(TextureHandle::WHITE.id)
+        .with_color(color)
+        .with_scale(size / RENDER_UNIT)
+}
+
+/// Spawn a static wall or floor box centered at `center`.
+fn spawn_static(world: &mut World, center: Vec2, size: Vec2) {
+    world
+        .spawn((
+            Transform2D::new(center),
+            quad(size, Vec4::new(0.35, 0.35, 0.4, 1.0)),
+            RigidBody::new_static(),
+            Collider::box_collider(size.x, size.y),
+        ))
+        .id();
+}
+
+struct PhysicsSandbox {
+    physics: PhysicsSystem,
+    /// Drops so far, used to spread drop positions across the floor
+    drops: u32,
+}
+
+impl PhysicsSandbox {
+    fn new() -> Self {
+        let config = PhysicsConfig::new(GRAVITY).with_scale(PIXELS_PER_METER);
+        Self { physics: PhysicsSystem::with_config(config), drops: 0 }
+    }
+
+    /// Next drop position: a deterministic sweep across the play area.
+    fn next_drop_x(&mut self) -> f32 {
+        self.drops += 1;
+        let sweep = (self.drops as f32 * 0.618).fract();
+        (sweep * 2.0 - 1.0) * (WALL_X - CRATE_SIZE)
+    }
+
+    fn drop_ball(&mut self, world: &mut World) {
+        let x = self.next_drop_x();
+        world
+            .spawn((
+                Name::new("ball"),
+                Transform2D::new(Vec2::new(x, DROP_Y)),
+                quad(Vec2::splat(BALL_RADIUS * 2.0), Vec4::new(1.0, 0.55, 0.2, 1.0)),
+                RigidBody::new_dynamic(),
+                Collider::circle_collider(BALL_RADIUS).with_restitution(0.6),
+                Dropped,
+            ))
+            .id();
+    }
+
+    fn drop_crate(&mut self, world: &mut World) {
+        let x = self.next_drop_x();
+        world
+            .spawn((
+                Name::new("crate"),
+                Transform2D::new(Vec2::new(x, DROP_Y)),
+                quad(Vec2::splat(CRATE_SIZE), Vec4::new(0.75, 0.55, 0.3, 1.0)),
+                RigidBody::new_dynamic(),
+                Collider::box_collider(CRATE_SIZE, CRATE_SIZE),
+                Dropped,
+            ))
+            .id();
+    }
+
+    fn clear_dropped(&mut self, world: &mut World) {
+        for entity in world.query_entities::<Single<Dropped>>() {
+            self.physics.destroy_entity(world, entity);
+        }
+    }
+}
+
+impl Game for PhysicsSandbox {
+    fn init(&mut self, ctx: &mut GameContext) {
+        spawn_static(ctx.world, Vec2::new(0.0, FLOOR_TOP - 20.0), Vec2::new(2.0 * WALL_X + 40.0, 40.0));
+        spawn_static(ctx.world, Vec2::new(-WALL_X - 20.0, 0.0), Vec2::new(40.0, 560.0));
+        spawn_static(ctx.world, Vec2::new(WALL_X + 20.0, 0.0), Vec2::new(40.0, 560.0));
+
+        // A small pyramid of crates to knock over
+        for row in 0..4 {
+            for col in 0..(4 - row) {
+                let x = (col as f32 - (3 - row) as f32 / 2.0) * CRATE_SIZE;
+                let y = FLOOR_TOP + CRATE_SIZE / 2.0 + row as f32 * CRATE_SIZE;
+                ctx.world
+                    .spawn((
+                        Name::new("pyramid"),
+                        Transform2D::new(Vec2::new(x, y)),
+                        quad(Vec2::splat(CRATE_SIZE), Vec4::new(0.45, 0.65, 0.9, 1.0)),
+                        RigidBody::new_dynamic(),
+                        Collider::box_collider(CRATE_SIZE, CRATE_SIZE),
+                    ))
+                    .id();
+            }
+        }
+
+        if let Err(e) = self.physics.initialize(ctx.world) {
+            log::error!("Failed to initialize physics: {}", e);
+        }
+
+        println!("=== Physics Sandbox ===");
+        println!("SPACE: drop ball, B: drop crate, R: clear, ESC: exit");
+    }
+
+    fn update(&mut self, ctx: &mut GameContext) {
+        let keyboard = ctx.input.keyboard();
+        if keyboard.is_key_just_pressed(KeyCode::Escape) {
+            ctx.exit_requested = true;
+            return;
+        }
+        if keyboard.is_key_just_pressed(KeyCode::Space) {
+            self.drop_ball(ctx.world);
+        }
+        if keyboard.is_key_just_pressed(KeyCode::KeyB) {
+            self.drop_crate(ctx.world);
+        }
+        if keyboard.is_key_just_pressed(KeyCode::KeyR) {
+            self.clear_dropped(ctx.world);
+        }
+
+        self.physics.update(ctx.world, ctx.delta_time);
+        ctx.validation.check_physics(ctx.world, self.physics.physics_world());
+
+        let bodies = ctx.world.query_entities::<Single<RigidBody>>().len();
+        ctx.ui.label(&format!("Bodies: {bodies}"), Vec2::new(12.0, 12.0));
+    }
+}
+
+/// Every dynamic body sits above the floor and between the walls.
+fn bodies_contained(world: &World) -> bool {
+    world.query_entities::<Single<RigidBody>>().into_iter().all(|entity| {
+        let dynamic = world.get::<RigidBody>(entity).is_some_and(|b| b.body_type == RigidBodyType::Dynamic);
+        !dynamic
+            || world.get::<Transform2D>(entity).is_some_and(|t| {
+                t.position.is_finite() && t.position.y > FLOOR_TOP && t.position.x.abs() < WALL_X
+            })
+    })
+}
+
+/// Headless auto-play (`-- --headless`): drop a mix of balls and crates,
+/// clear them, drop a few more, and check nothing fell through the floor.
+fn headless_script() -> HeadlessScript {
+    HeadlessScript::new(360)
+        .tap(KeyCode::Space, 10)
+        .tap(KeyCode::KeyB, 20)
+        .tap(KeyCode::Space, 30)
+        .tap(KeyCode::KeyR, 150)
+        .tap(KeyCode::Space, 160)
+        .tap(KeyCode::KeyB, 170)
+        .tap(KeyCode::KeyB, 180)
+        .expect(0, "pyramid built", |world| count_named(world, "pyramid") == 10)
+        .expect(40, "three bodies dropped", |world| count_named(world, "ball") + count_named(world, "crate") == 3)
+        .expect(150, "R cleared the drops", |world| world.query_entities::<Single<Dropped>>().is_empty())
+        .expect_final("bodies stay inside the box", bodies_contained)
+        .expect_final("second round dropped", |world| count_named(world, "ball") == 1 && count_named(world, "crate") == 2)
+}
+
+fn main() -> ExitCode {
+    let config = GameConfig::new("Physics Sandbox - Insiculous 2D")
+        .with_size(800, 600)
+        .with_clear_color(0.08, 0.08, 0.1, 1.0);
+
+    run_example(PhysicsSandbox::new(), config, headless_script())
+}
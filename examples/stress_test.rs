@@ -0,0 +1,162 @@
+//! Stress test - thousands of drifting sprites with live frame stats.
+//!
+//! Run with: cargo run --release --example stress_test
+//! Headless auto-play: cargo run --example stress_test -- --headless
+//!
+//! Controls:
+//!   SPACE  Spawn another batch of sprites
+//!   R      Despawn everything back to the first batch
+//!   ESC    Exit
+//!
+//! Every sprite is a tinted white quad moving in a straight line and
+//! bouncing off the screen edges, so the whole crowd stays on screen and
+//! nothing is culled. The overlay and the once-a-second log line show the
+//! entity count next to `ctx.profile` timings and `ctx.render_stats`, which
+//! is the quickest way to see how a change scales before reaching for
+//! `cargo bench -p benchmarks`.
+
+use ecs::Pair;
+use engine_core::prelude::*;
+use std::process::ExitCode;
+
+/// Sprites spawned at startup and per SPACE press.
+const BATCH: usize = 10_000;
+/// Half the visible area, in world pixels (the window is 800x600).
+const HALF_EXTENT: Vec2 = Vec2::new(400.0, 300.0);
+const SPRITE_SIZE: f32 = 6.0;
+const MAX_SPEED: f32 = 120.0;
+/// Frames between log lines (about once a second at 60 FPS).
+const LOG_INTERVAL: u32 = 60;
+
+/// Straight-line velocity in pixels per second.
+struct Drift(Vec2);
+
+struct StressTest {
+    rng: Rng,
+    frames: u32,
+}
+
+impl StressTest {
+    fn new() -> Self {
+        Self { rng: Rng::new(0x5eed), frames: 0 }
+    }
+
+    fn spawn_batch(&mut self, world: &mut World) {
+        for _ in 0..BATCH {
+            let position = Vec2::new(
+                self.rng.range_f32(-HALF_EXTENT.x, HALF_EXTENT.x),
+                self.rng.range_f32(-HALF_EXTENT.y, HALF_EXTENT.y),
+            );
+            let velocity = Vec2::new(self.rng.range_f32(-1.0, 1.0), self.rng.range_f32(-1.0, 1.0)) * MAX_SPEED;
+            let color = Vec4::new(self.rng.next_f32(), self.rng.next_f32(), 0.9, 1.0);
+            world
+                .spawn((
+                    Transform2D::new(position),
+                    Sprite::new(TextureHandle::WHITE.id)
+                        .with_color(color)
+                        .with_scale(Vec2::splat(SPRITE_SIZE / RENDER_UNIT)),
+                    Drift(velocity),
+                ))
+                .id();
+        }
+    }
+
+    fn despawn_extra_batches(&mut self, world: &mut World) {
+        for entity in world.query_entities::<Pair<Transform2D, Drift>>().into_iter().skip(BATCH) {
+            world.remove_entity(&entity).ok();
+        }
+    }
+}
+
+/// Move every drifting sprite, reflecting it off the screen edges.
+fn drift(world: &mut World, delta_time: f32) {
+    for entity in world.query_entities::<Pair<Transform2D, Drift>>() {
+        let Some(mut velocity) = world.get::<Drift>(entity).map(|drift| drift.0) else { continue };
+        let Some(transform) = world.get_mut::<Transform2D>(entity) else { continue };
+        let mut position = transform.position + velocity * delta_time;
+        if position.x.abs() > HALF_EXTENT.x {
+            velocity.x = -velocity.x;
+            position.x = position.x.clamp(-HALF_EXTENT.x, HALF_EXTENT.x);
+        }
+        if position.y.abs() > HALF_EXTENT.y {
+            velocity.y = -velocity.y;
+            position.y = position.y.clamp(-HALF_EXTENT.y, HALF_EXTENT.y);
+        }
+        transform.position = position;
+        if let Some(drift) = world.get_mut::<Drift>(entity) {
+            drift.0 = velocity;
+        }
+    }
+}
+
+impl Game for StressTest {
+    fn init(&mut self, ctx: &mut GameContext) {
+        self.spawn_batch(ctx.world);
+        println!("=== Stress Test ===");
+        println!("SPACE: spawn {BATCH} more, R: reset, ESC: exit");
+    }
+
+    fn update(&mut self, ctx: &mut GameContext) {
+        let keyboard = ctx.input.keyboard();
+        if keyboard.is_key_just_pressed(KeyCode::Escape) {
+            ctx.exit_requested = true;
+            return;
+        }
+        if keyboard.is_key_just_pressed(KeyCode::Space) {
+            self.spawn_batch(ctx.world);
+        }
+        if keyboard.is_key_just_pressed(KeyCode::KeyR) {
+            self.despawn_extra_batches(ctx.world);
+        }
+
+        drift(ctx.world, ctx.delta_time);
+
+        // Last frame's numbers: the profile and render stats lag one frame
+        let profile = ctx.profile;
+        let stats = &ctx.render_stats;
+        let summary = format!(
+            "Entities {}   update {:.2} ms   total {:.2} ms   sprites {}   draw calls {}",
+            profile.entities,
+            profile.update * 1000.0,
+            profile.total() * 1000.0,
+            stats.sprites,
+            stats.draw_calls,
+        );
+        ctx.ui.label(&summary, Vec2::new(12.0, 12.0));
+
+        self.frames += 1;
+        if self.frames % LOG_INTERVAL == 0 {
+            log::info!("{summary}");
+        }
+    }
+}
+
+fn drifting(world: &World) -> usize {
+    world.query_entities::<Pair<Transform2D, Drift>>().len()
+}
+
+/// Headless auto-play (`-- --headless`): spawn two more batches, reset,
+/// and check every sprite stayed on screen.
+fn headless_script() -> HeadlessScript {
+    let on_screen = |world: &World| {
+        positions_with::<Drift>(world).all(|(_, position)| position.abs().cmple(HALF_EXTENT).all())
+    };
+    HeadlessScript::new(120)
+        .tap(KeyCode::Space, 10)
+        .tap(KeyCode::Space, 20)
+        .tap(KeyCode::KeyR, 80)
+        .expect(0, "first batch spawned", |world| drifting(world) == BATCH)
+        .expect(30, "two more batches", |world| drifting(world) == 3 * BATCH)
+        .expect_final("reset to one batch", |world| drifting(world) == BATCH)
+        .expect_final("sprites stay on screen", on_screen)
+}
+
+fn main() -> ExitCode {
+    let config = GameConfig::new("Stress Test - Insiculous 2D")
+        .with_size(800, 600)
+        .with_clear_color(0.05, 0.05, 0.07, 1.0)
+        // Tens of thousands of sprites is the point, not a warning
+        .with_sprite_limits(200_000, None);
+
+    run_example(StressTest::new(), config, headless_script())
+}
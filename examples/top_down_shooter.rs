@@ -0,0 +1,214 @@
+//! Top-down shooter - a minimal arcade loop built entirely in code.
+//!
+//! Run with: cargo run --example top_down_shooter
+//! Headless auto-play: cargo run --example top_down_shooter -- --headless
+//!
+//! Enemies drift down from the top of the screen in waves; move with
+//! WASD/Arrow keys and hold SPACE to fire. No asset files are needed: every
+//! sprite is a tinted white quad.
+//!
+//! Demonstrates:
+//! - Spawning and despawning entities at runtime
+//! - Game-defined marker components and world resources
+//! - `Lifetime` for self-expiring bullets
+//! - Reading raw keyboard state through `ctx.input`
+
+use engine_core::prelude::*;
+use ecs::Single;
+use std::process::ExitCode;
+
+/// Half the playfield size (matches the 800x600 window at zoom 1).
+const HALF_FIELD: Vec2 = Vec2::new(400.0, 300.0);
+const PLAYER_SIZE: f32 = 32.0;
+const PLAYER_SPEED: f32 = 260.0;
+const BULLET_SIZE: f32 = 8.0;
+const BULLET_SPEED: f32 = 600.0;
+const FIRE_INTERVAL: f32 = 0.12;
+const ENEMY_SIZE: f32 = 28.0;
+const ENEMY_SPEED: f32 = 70.0;
+const ENEMIES_PER_WAVE: u32 = 6;
+
+/// Marks the player ship.
+struct Ship;
+
+/// A bullet travelling at a fixed velocity.
+struct Bullet {
+    velocity: Vec2,
+}
+
+/// An enemy drifting towards the bottom of the screen.
+struct Enemy;
+
+/// Enemies destroyed so far (a world resource, so headless checks can read it).
+#[derive(Debug, Default)]
+struct Score(u32);
+
+/// A white-quad sprite `size` pixels across.
+fn quad(size: f32, color: Vec4) -> Sprite {
+    Sprite::new(TextureHandle::WHITE.id)
+        .with_color(color)
+        .with_scale(Vec2::splat(size / RENDER_UNIT))
+}
+
+struct TopDownShooter {
+    fire_cooldown: f32,
+    wave: u32,
+    lifetimes: LifetimeSystem,
+}
+
+impl TopDownShooter {
+    fn new() -> Self {
+        Self { fire_cooldown: 0.0, wave: 0, lifetimes: LifetimeSystem::new() }
+    }
+
+    fn spawn_wave(&mut self, world: &mut World) {
+        self.wave += 1;
+        let spacing = 2.0 * HALF_FIELD.x / (ENEMIES_PER_WAVE + 1) as f32;
+        for i in 0..ENEMIES_PER_WAVE {
+            let x = -HALF_FIELD.x + spacing * (i + 1) as f32;
+            // Stagger alternate enemies so the wave isn't a flat line
+            let y = HALF_FIELD.y - 40.0 - (i % 2) as f32 * 40.0;
+            world
+                .spawn((
+                    Name::new("enemy"),
+                    Transform2D::new(Vec2::new(x, y)),
+                    quad(ENEMY_SIZE, Vec4::new(0.95, 0.3, 0.3, 1.0)),
+                    Enemy,
+                ))
+                .id();
+        }
+    }
+
+    fn move_ship(&mut self, ctx: &mut GameContext) -> Option<Vec2> {
+        let keyboard = ctx.input.keyboard();
+        let axis = |neg: [KeyCode; 2], pos: [KeyCode; 2]| {
+            let held = |keys: [KeyCode; 2]| keys.iter().any(|&k| keyboard.is_key_pressed(k));
+            held(pos) as i32 as f32 - held(neg) as i32 as f32
+        };
+        let direction = Vec2::new(
+            axis([KeyCode::KeyA, KeyCode::ArrowLeft], [KeyCode::KeyD, KeyCode::ArrowRight]),
+            axis([KeyCode::KeyS, KeyCode::ArrowDown], [KeyCode::KeyW, KeyCode::ArrowUp]),
+        );
+
+        let ship = find_named(ctx.world, "ship")?;
+        let transform = ctx.world.get_mut::<Transform2D>(ship)?;
+        let limit = HALF_FIELD - Vec2::splat(PLAYER_SIZE / 2.0);
+        transform.position = (transform.position + direction.normalize_or_zero() * PLAYER_SPEED * ctx.delta_time)
+            .clamp(-limit, limit);
+        Some(transform.position)
+    }
+
+    fn fire(&mut self, ctx: &mut GameContext, from: Vec2) {
+        self.fire_cooldown -= ctx.delta_time;
+        if !ctx.input.keyboard().is_key_pressed(KeyCode::Space) || self.fire_cooldown > 0.0 {
+            return;
+        }
+        self.fire_cooldown = FIRE_INTERVAL;
+        ctx.world
+            .spawn((
+                Transform2D::new(from + Vec2::new(0.0, PLAYER_SIZE / 2.0)),
+                quad(BULLET_SIZE, Vec4::new(1.0, 0.95, 0.5, 1.0)).with_emissive(1.0),
+                Bullet { velocity: Vec2::new(0.0, BULLET_SPEED) },
+                Lifetime::new(2.0 * HALF_FIELD.y / BULLET_SPEED),
+            ))
+            .id();
+    }
+
+    /// Move bullets and enemies, then resolve bullet hits (circle overlap).
+    fn step_entities(&mut self, world: &mut World, dt: f32) {
+        for bullet in world.query_entities::<Single<Bullet>>() {
+            let velocity = world.get::<Bullet>(bullet).map_or(Vec2::ZERO, |b| b.velocity);
+            if let Some(transform) = world.get_mut::<Transform2D>(bullet) {
+                transform.position += velocity * dt;
+            }
+        }
+        for enemy in world.query_entities::<Single<Enemy>>() {
+            if let Some(transform) = world.get_mut::<Transform2D>(enemy) {
+                transform.position.y -= ENEMY_SPEED * dt;
+                // Wrap enemies that slip past the player back to the top
+                if transform.position.y < -HALF_FIELD.y - ENEMY_SIZE {
+                    transform.position.y = HALF_FIELD.y + ENEMY_SIZE;
+                }
+            }
+        }
+
+        let position = |world: &World, entity| world.get::<Transform2D>(entity).map(|t| t.position);
+        let hit_distance = (ENEMY_SIZE + BULLET_SIZE) / 2.0;
+        let mut destroyed = 0;
+        for bullet in world.query_entities::<Single<Bullet>>() {
+            let Some(bullet_pos) = position(world, bullet) else { continue };
+            let hit = world.query_entities::<Single<Enemy>>().into_iter().find(|&enemy| {
+                position(world, enemy).is_some_and(|p| p.distance(bullet_pos) < hit_distance)
+            });
+            if let Some(enemy) = hit {
+                world.remove_entity(&enemy).ok();
+                world.remove_entity(&bullet).ok();
+                destroyed += 1;
+            }
+        }
+        if let Some(score) = world.resource_mut::<Score>() {
+            score.0 += destroyed;
+        }
+    }
+}
+
+impl Game for TopDownShooter {
+    fn init(&mut self, ctx: &mut GameContext) {
+        ctx.world.insert_resource(Score::default());
+        ctx.world
+            .spawn((
+                Name::new("ship"),
+                Transform2D::new(Vec2::new(0.0, -HALF_FIELD.y + 60.0)),
+                quad(PLAYER_SIZE, Vec4::new(0.3, 0.7, 1.0, 1.0)),
+                Ship,
+            ))
+            .id();
+        self.spawn_wave(ctx.world);
+
+        println!("=== Top-Down Shooter ===");
+        println!("WASD/Arrows to move, SPACE to fire, ESC to exit");
+    }
+
+    fn update(&mut self, ctx: &mut GameContext) {
+        if ctx.input.keyboard().is_key_just_pressed(KeyCode::Escape) {
+            ctx.exit_requested = true;
+            return;
+        }
+
+        if let Some(ship_pos) = self.move_ship(ctx) {
+            self.fire(ctx, ship_pos);
+        }
+        self.step_entities(ctx.world, ctx.delta_time);
+        self.lifetimes.update(ctx.world, ctx.delta_time);
+
+        if ctx.world.query_entities::<Single<Enemy>>().is_empty() {
+            self.spawn_wave(ctx.world);
+        }
+
+        let score = ctx.world.resource::<Score>().map_or(0, |s| s.0);
+        ctx.ui.label(&format!("Wave {}   Score {}", self.wave, score), Vec2::new(12.0, 12.0));
+    }
+}
+
+/// Headless auto-play (`-- --headless`): strafe under the first wave while
+/// firing, and check enemies went down.
+fn headless_script() -> HeadlessScript {
+    HeadlessScript::new(240)
+        .hold(KeyCode::Space, 5, 230)
+        .hold(KeyCode::KeyA, 20, 60)
+        .hold(KeyCode::KeyD, 60, 140)
+        .expect_spawned(&["ship"])
+        .expect(0, "first wave spawned", |world| {
+            world.query_entities::<Single<Enemy>>().len() == ENEMIES_PER_WAVE as usize
+        })
+        .expect(30, "bullets in flight", |world| !world.query_entities::<Single<Bullet>>().is_empty())
+        .expect_final("enemies were shot down", |world| world.resource::<Score>().is_some_and(|s| s.0 >= 2))
+}
+
+fn main() -> ExitCode {
+    let config = GameConfig::new("Top-Down Shooter - Insiculous 2D")
+        .with_size(800, 600)
+        .with_clear_color(0.05, 0.05, 0.1, 1.0);
+
+    run_example(TopDownShooter::new(), config, headless_script())
+}
@@ -0,0 +1,117 @@
+//! UI showcase - the immediate-mode widgets on one panel.
+//!
+//! Run with: cargo run --example ui_showcase
+//! Headless auto-play: cargo run --example ui_showcase -- --headless
+//!
+//! Shows buttons (including a disabled one), a checkbox, sliders, a
+//! progress bar, and labels. Widget state lives in a world resource, which
+//! is also what the headless checks read.
+//!
+//! Font file: examples/assets/fonts/font.ttf (optional - widgets work
+//! without it, labels just don't draw)
+
+use engine_core::prelude::*;
+use input::InputEvent;
+use std::process::ExitCode;
+
+/// Anchor the font path to the repository so the example runs from any
+/// working directory.
+const EXAMPLES_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/examples");
+
+// Widget layout in screen pixels (the headless script clicks these).
+const PANEL: Rect = Rect::new(200.0, 100.0, 400.0, 400.0);
+const COUNT_BUTTON: Rect = Rect::new(230.0, 160.0, 160.0, 40.0);
+const RESET_BUTTON: Rect = Rect::new(410.0, 160.0, 160.0, 40.0);
+const CHECKBOX_POS: Vec2 = Vec2::new(230.0, 230.0);
+const VOLUME_SLIDER: Rect = Rect::new(230.0, 300.0, 340.0, 24.0);
+const PROGRESS_BAR: Rect = Rect::new(230.0, 380.0, 340.0, 20.0);
+const DISABLED_BUTTON: Rect = Rect::new(230.0, 430.0, 340.0, 40.0);
+
+/// Widget state (a world resource, so headless checks can read it).
+#[derive(Debug)]
+struct ShowcaseState {
+    clicks: u32,
+    show_progress: bool,
+    volume: f32,
+    /// Progress bar fill, animated while `show_progress` is on
+    progress: f32,
+}
+
+impl Default for ShowcaseState {
+    fn default() -> Self {
+        Self { clicks: 0, show_progress: true, volume: 0.5, progress: 0.0 }
+    }
+}
+
+struct UiShowcase;
+
+impl Game for UiShowcase {
+    fn init(&mut self, ctx: &mut GameContext) {
+        ctx.world.insert_resource(ShowcaseState::default());
+        if ctx.ui.load_font_file(&format!("{EXAMPLES_DIR}/assets/fonts/font.ttf")).is_err() {
+            log::warn!("No font at examples/assets/fonts/font.ttf; labels will not draw");
+        }
+    }
+
+    fn update(&mut self, ctx: &mut GameContext) {
+        let Some(state) = ctx.world.resource_mut::<ShowcaseState>() else { return };
+        let ui = &mut *ctx.ui;
+
+        ui.panel(PANEL);
+        ui.label("UI Showcase", Vec2::new(PANEL.x + 30.0, PANEL.y + 24.0));
+
+        if ui.button("count", &format!("Clicked {}", state.clicks), COUNT_BUTTON) {
+            state.clicks += 1;
+        }
+        if ui.button("reset", "Reset", RESET_BUTTON) {
+            state.clicks = 0;
+        }
+        if ui.checkbox_labeled("progress", "Animate progress", state.show_progress, CHECKBOX_POS) {
+            state.show_progress = !state.show_progress;
+        }
+
+        let volume_label = Vec2::new(VOLUME_SLIDER.x, VOLUME_SLIDER.y - 16.0);
+        ui.label(&format!("Volume {:.0}%", state.volume * 100.0), volume_label);
+        state.volume = ui.slider("volume", state.volume, VOLUME_SLIDER);
+
+        if state.show_progress {
+            state.progress = (state.progress + ctx.time.unscaled_delta() * 0.25).fract();
+        }
+        ui.progress_bar(state.progress, PROGRESS_BAR);
+
+        ui.button_styled("disabled", "Disabled button", DISABLED_BUTTON, false);
+    }
+}
+
+/// Headless auto-play (`-- --headless`): click the counter three times,
+/// untick the checkbox, drag the slider to 90%, and check the state.
+fn headless_script() -> HeadlessScript {
+    let checkbox = (CHECKBOX_POS.x + 8.0, CHECKBOX_POS.y + 8.0);
+    let slider = (VOLUME_SLIDER.x + VOLUME_SLIDER.width * 0.9, VOLUME_SLIDER.y + VOLUME_SLIDER.height / 2.0);
+    let state = |world: &World| world.resource::<ShowcaseState>().map(|s| (s.clicks, s.show_progress, s.volume));
+
+    HeadlessScript::new(90)
+        .click_center(10, COUNT_BUTTON)
+        .click_center(20, COUNT_BUTTON)
+        .click_center(30, COUNT_BUTTON)
+        .click_center(40, DISABLED_BUTTON)
+        .click(50, checkbox.0, checkbox.1)
+        // Sliders track the mouse while dragged, so hold the button a few frames
+        .event(60, InputEvent::MouseMoved(slider.0, slider.1))
+        .event(60, InputEvent::MouseButtonPressed(MouseButton::Left))
+        .event(65, InputEvent::MouseButtonReleased(MouseButton::Left))
+        .expect(12, "first click counted", move |world| state(world).is_some_and(|(clicks, ..)| clicks == 1))
+        .expect_final("clicks, checkbox, and slider applied", move |world| {
+            state(world).is_some_and(|(clicks, show_progress, volume)| {
+                clicks == 3 && !show_progress && (volume - 0.9).abs() < 0.05
+            })
+        })
+}
+
+fn main() -> ExitCode {
+    let config = GameConfig::new("UI Showcase - Insiculous 2D")
+        .with_size(800, 600)
+        .with_clear_color(0.12, 0.12, 0.14, 1.0);
+
+    run_example(UiShowcase, config, headless_script())
+}
@@ -0,0 +1,36 @@
+#!/bin/bash
+# Run every example headless (scripted input, fixed frames, no window) and
+# report which ones failed. Exits non-zero if any example fails.
+#
+# Usage: ./run_examples.sh [example ...]   (default: the whole gallery)
+
+cd "$(dirname "$0")" || exit 1
+
+EXAMPLES=(hello_world behavior_demo top_down_shooter physics_sandbox ui_showcase stress_test editor_demo)
+if [ $# -gt 0 ]; then
+    EXAMPLES=("$@")
+fi
+
+failed=()
+for example in "${EXAMPLES[@]}"; do
+    features=()
+    if [ "$example" = "editor_demo" ]; then
+        features=(--features editor)
+    fi
+
+    echo "=== $example ==="
+    if cargo run --quiet --example "$example" "${features[@]}" -- --headless; then
+        echo "✅ $example"
+    else
+        echo "❌ $example (exit code $?)"
+        failed+=("$example")
+    fi
+    echo ""
+done
+
+echo "==========================================="
+echo "$(( ${#EXAMPLES[@]} - ${#failed[@]} ))/${#EXAMPLES[@]} examples passed"
+if [ ${#failed[@]} -gt 0 ]; then
+    echo "Failed: ${failed[*]}"
+    exit 1
+fi
@@ -0,0 +1,137 @@
+//! Headless scene validation and batch export for content pipelines.
+//!
+//! Usage:
+//!   cargo run --bin scene_tools -- validate [--assets DIR] [--json] <scene.ron|dir>...
+//!   cargo run --bin scene_tools -- export --out DIR [--format ron|json] [--assets DIR] [--json] <scene.ron|dir>...
+//!
+//! Directories expand to the `.ron` files directly inside them. `--json`
+//! prints the machine-readable report array instead of one summary line per
+//! scene. Exit code: 0 = no errors, 1 = a scene has errors, 2 = bad usage.
+
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use engine_core::scene_tools::{batch_export, scene_files, ExportFormat, SceneReport, SceneValidator};
+
+const USAGE: &str = "usage: scene_tools validate [--assets DIR] [--json] <scene.ron|dir>...\n       \
+                     scene_tools export --out DIR [--format ron|json] [--assets DIR] [--json] <scene.ron|dir>...";
+
+/// Parsed command line.
+struct Options {
+    export: bool,
+    json: bool,
+    assets: Option<PathBuf>,
+    out: Option<PathBuf>,
+    format: ExportFormat,
+    inputs: Vec<PathBuf>,
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Options, String> {
+    let export = match args.next().as_deref() {
+        Some("validate") => false,
+        Some("export") => true,
+        Some(other) => return Err(format!("unknown command '{}'", other)),
+        None => return Err("missing command".into()),
+    };
+    let mut options = Options {
+        export,
+        json: false,
+        assets: None,
+        out: None,
+        format: ExportFormat::Ron,
+        inputs: Vec::new(),
+    };
+    while let Some(arg) = args.next() {
+        let mut value = |flag: &str| args.next().ok_or_else(|| format!("{} needs a value", flag));
+        match arg.as_str() {
+            "--json" => options.json = true,
+            "--assets" => options.assets = Some(value("--assets")?.into()),
+            "--out" => options.out = Some(value("--out")?.into()),
+            "--format" => {
+                let name = value("--format")?;
+                options.format = ExportFormat::from_name(&name).ok_or_else(|| format!("unknown format '{}'", name))?;
+            }
+            flag if flag.starts_with("--") => return Err(format!("unknown option '{}'", flag)),
+            input => options.inputs.push(input.into()),
+        }
+    }
+    if options.inputs.is_empty() {
+        return Err("no scenes given".into());
+    }
+    if options.export && options.out.is_none() {
+        return Err("export needs --out DIR".into());
+    }
+    Ok(options)
+}
+
+/// Scene files named on the command line, with directories expanded.
+fn collect_scene_files(inputs: &[PathBuf]) -> Result<Vec<PathBuf>, String> {
+    let mut files = Vec::new();
+    for input in inputs {
+        if input.is_dir() {
+            files.extend(scene_files(input).map_err(|e| format!("{}: {}", input.display(), e))?);
+        } else {
+            files.push(input.clone());
+        }
+    }
+    Ok(files)
+}
+
+fn print_reports<'a>(reports: impl Iterator<Item = &'a SceneReport>, json: bool) {
+    let reports: Vec<&SceneReport> = reports.collect();
+    if json {
+        match serde_json::to_string_pretty(&reports) {
+            Ok(text) => println!("{}", text),
+            Err(e) => eprintln!("failed to encode report: {}", e),
+        }
+        return;
+    }
+    for report in reports {
+        println!("{}", report.summary());
+        for issue in &report.issues {
+            let location = issue.location.as_deref().map(|l| format!(" [{}]", l)).unwrap_or_default();
+            println!("  {:?} {:?}{}: {}", issue.severity, issue.kind, location, issue.message);
+        }
+    }
+}
+
+fn run(options: Options) -> Result<bool, String> {
+    let files = collect_scene_files(&options.inputs)?;
+    let mut validator = SceneValidator::new();
+    if let Some(assets) = &options.assets {
+        validator = validator.with_assets_root(assets);
+    }
+
+    if let (true, Some(out)) = (options.export, options.out.as_deref()) {
+        let outcomes = batch_export(&files, out, options.format, &validator)
+            .map_err(|e| format!("{}: {}", out.display(), e))?;
+        print_reports(outcomes.iter().map(|outcome| &outcome.report), options.json);
+        if !options.json {
+            let written: Vec<&Path> = outcomes.iter().filter_map(|outcome| outcome.output.as_deref()).collect();
+            println!("exported {} of {} scenes to {}", written.len(), outcomes.len(), out.display());
+        }
+        return Ok(outcomes.iter().all(|outcome| outcome.report.is_ok() && outcome.output.is_some()));
+    }
+
+    let reports: Vec<SceneReport> = files.iter().map(|file| validator.validate_file(file)).collect();
+    print_reports(reports.iter(), options.json);
+    Ok(reports.iter().all(SceneReport::is_ok))
+}
+
+fn main() -> ExitCode {
+    let options = match parse_args(std::env::args().skip(1)) {
+        Ok(options) => options,
+        Err(e) => {
+            eprintln!("scene_tools: {}\n{}", e, USAGE);
+            return ExitCode::from(2);
+        }
+    };
+    match run(options) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::from(1),
+        Err(e) => {
+            eprintln!("scene_tools: {}", e);
+            ExitCode::from(2)
+        }
+    }
+}